@@ -0,0 +1,327 @@
+//! Datalog Pull Analysis
+//! 
+//! 🔗 T4-CLI-021: Post-Hoc Datalog Analysis
+//! Derived From: T4-CORE-138 (Rotating SD Card Datalogs) + T4-CLI-017 (Telemetry Monitor)
+//! AI Traceability: Judge how well boost follows its target pull by pull from the card, without a dyno
+//! 
+//! Reads a datalog off the SD card (CSV or binary) or a `monitor --csv`
+//! file, splits it into pulls and reports how boost tracked its target in
+//! each. A pull is a stretch with the target at or above `min_target_psi`;
+//! it ends once the target has been below that for half a second, and
+//! pulls shorter than a second are ignored.
+
+use std::error::Error;
+
+use rumbledome_core::{TelemetryChannel, BINARY_DATALOG_MAGIC};
+
+/// Target below the threshold for this long ends a pull (ms)
+const PULL_END_MS: u32 = 500;
+
+/// Shortest pull reported (ms)
+const MIN_PULL_MS: u32 = 1_000;
+
+/// State code the datalog records for an overboost cut
+const OVERBOOST_CUT_STATE: u8 = 5;
+
+/// One datalog row with the channels the analysis uses
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRow {
+    pub timestamp_ms: u32,
+    pub rpm: Option<f32>,
+    pub boost_psi: f32,
+    pub target_psi: f32,
+    pub duty_percent: Option<f32>,
+    /// State code, when the log has a numeric state column
+    pub state: Option<u8>,
+}
+
+/// Analysis thresholds
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisSettings {
+    /// Target at which a pull starts (PSI)
+    pub min_target_psi: f32,
+    /// Boost within this of target counts as settled (PSI)
+    pub settle_band_psi: f32,
+    /// Duty at or above this counts as saturated high (%)
+    pub saturated_high_percent: f32,
+    /// Duty at or below this counts as saturated low (%)
+    pub saturated_low_percent: f32,
+}
+
+impl Default for AnalysisSettings {
+    fn default() -> Self {
+        Self { min_target_psi: 2.0, settle_band_psi: 0.5, saturated_high_percent: 99.0, saturated_low_percent: 1.0 }
+    }
+}
+
+/// How boost tracked its target through one pull
+#[derive(Debug, Clone, PartialEq)]
+pub struct PullReport {
+    /// Time of the pull's first row (ms)
+    pub start_ms: u32,
+    pub duration_ms: u32,
+    /// RPM at the start and end, when logged
+    pub rpm_range: Option<(f32, f32)>,
+    pub peak_target_psi: f32,
+    pub peak_boost_psi: f32,
+    /// Largest excess of boost over target (PSI, 0 if never above)
+    pub overshoot_psi: f32,
+    /// Time from the start until boost stays within the settle band (`None` if it never does)
+    pub settle_ms: Option<u32>,
+    /// Root mean square of target minus boost (PSI)
+    pub rms_error_psi: f32,
+    /// Share of rows at or above the high saturation duty (%, when duty is logged)
+    pub duty_high_percent: Option<f32>,
+    /// Share of rows at or below the low saturation duty (%, when duty is logged)
+    pub duty_low_percent: Option<f32>,
+    /// Overboost cuts entered during the pull (when the state is logged)
+    pub overboost_cuts: Option<u32>,
+}
+
+/// Read a datalog: binary when it starts with the binary header, CSV otherwise
+pub fn read_log(bytes: &[u8]) -> Result<Vec<LogRow>, Box<dyn Error>> {
+    if bytes.starts_with(&BINARY_DATALOG_MAGIC) {
+        parse_binary(bytes)
+    } else {
+        parse_csv(std::str::from_utf8(bytes).map_err(|_| "Log is neither a binary datalog nor CSV text")?)
+    }
+}
+
+/// Rows of a CSV log, columns found by name
+fn parse_csv(text: &str) -> Result<Vec<LogRow>, Box<dyn Error>> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<&str> = lines.next().ok_or("Log is empty")?.split(',').map(str::trim).collect();
+    let column = |name: &str| header.iter().position(|column| *column == name);
+    let required = |name: &str| column(name).ok_or_else(|| format!("Log has no {} column", name));
+    let (time, boost, target) = (required("timestamp_ms")?, required("boost_psi")?, required("target_psi")?);
+    let (rpm, duty, state) = (column("rpm"), column("duty_percent"), column("state"));
+    
+    lines.enumerate().map(|(index, line)| {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let number = |at: usize| -> Result<f32, Box<dyn Error>> {
+            let field = fields.get(at).ok_or_else(|| format!("Row {} is short", index + 1))?;
+            Ok(field.parse().map_err(|_| format!("Row {}: '{}' is not a number", index + 1, field))?)
+        };
+        Ok(LogRow {
+            timestamp_ms: number(time)? as u32,
+            rpm: rpm.map(number).transpose()?,
+            boost_psi: number(boost)?,
+            target_psi: number(target)?,
+            duty_percent: duty.map(number).transpose()?,
+            // The monitor writes the state as text - only the datalog's code is usable
+            state: state.and_then(|at| fields.get(at)?.parse().ok()),
+        })
+    }).collect()
+}
+
+/// Rows of a binary log
+fn parse_binary(bytes: &[u8]) -> Result<Vec<LogRow>, Box<dyn Error>> {
+    let count = *bytes.get(BINARY_DATALOG_MAGIC.len()).ok_or("Binary log header is cut short")? as usize;
+    let header_len = BINARY_DATALOG_MAGIC.len() + 1 + count;
+    let channels = bytes.get(BINARY_DATALOG_MAGIC.len() + 1..header_len).ok_or("Binary log header is cut short")?
+        .iter()
+        .map(|code| TelemetryChannel::from_code(*code).ok_or_else(|| format!("Unknown channel code {}", code)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let position = |wanted: TelemetryChannel| channels.iter().position(|channel| *channel == wanted);
+    let boost = position(TelemetryChannel::BoostPsi).ok_or("Log has no boost_psi channel")?;
+    let target = position(TelemetryChannel::TargetPsi).ok_or("Log has no target_psi channel")?;
+    let (rpm, duty, state) = (position(TelemetryChannel::Rpm), position(TelemetryChannel::DutyPercent), position(TelemetryChannel::State));
+    
+    // A card pulled mid-write can leave a partial last row - it is dropped
+    Ok(bytes[header_len..].chunks_exact(4 + 2 * count).map(|row| {
+        let value = |at: usize| {
+            let raw = i16::from_le_bytes([row[4 + at * 2], row[5 + at * 2]]);
+            raw as f32 / channels[at].can_scale()
+        };
+        LogRow {
+            timestamp_ms: u32::from_le_bytes([row[0], row[1], row[2], row[3]]),
+            rpm: rpm.map(value),
+            boost_psi: value(boost),
+            target_psi: value(target),
+            duty_percent: duty.map(value),
+            state: state.map(|at| value(at) as u8),
+        }
+    }).collect())
+}
+
+/// Split a log into pulls and report each
+pub fn analyze(rows: &[LogRow], settings: &AnalysisSettings) -> Vec<PullReport> {
+    let mut reports = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut last_above: usize = 0;
+    for (index, row) in rows.iter().enumerate() {
+        if row.target_psi >= settings.min_target_psi {
+            start.get_or_insert(index);
+            last_above = index;
+        } else if let Some(first) = start {
+            if row.timestamp_ms.wrapping_sub(rows[last_above].timestamp_ms) >= PULL_END_MS {
+                reports.extend(report_pull(&rows[first..=last_above], settings));
+                start = None;
+            }
+        }
+    }
+    if let Some(first) = start {
+        reports.extend(report_pull(&rows[first..=last_above], settings));
+    }
+    reports
+}
+
+/// Report one pull's rows, unless it is too short to judge
+fn report_pull(rows: &[LogRow], settings: &AnalysisSettings) -> Option<PullReport> {
+    let (first, last) = (rows.first()?, rows.last()?);
+    let duration_ms = last.timestamp_ms.wrapping_sub(first.timestamp_ms);
+    if duration_ms < MIN_PULL_MS {
+        return None;
+    }
+    
+    let errors: Vec<f32> = rows.iter().map(|row| row.target_psi - row.boost_psi).collect();
+    let settled_from = match errors.iter().rposition(|error| error.abs() > settings.settle_band_psi) {
+        None => Some(first.timestamp_ms),
+        Some(index) => rows.get(index + 1).map(|row| row.timestamp_ms),
+    };
+    let share = |matches: &dyn Fn(f32) -> bool| {
+        let duties: Vec<f32> = rows.iter().filter_map(|row| row.duty_percent).collect();
+        (!duties.is_empty()).then(|| duties.iter().filter(|duty| matches(**duty)).count() as f32 * 100.0 / duties.len() as f32)
+    };
+    let overboost_cuts = rows.iter().all(|row| row.state.is_some()).then(|| {
+        rows.windows(2)
+            .filter(|pair| pair[0].state != Some(OVERBOOST_CUT_STATE) && pair[1].state == Some(OVERBOOST_CUT_STATE))
+            .count() as u32
+            + u32::from(first.state == Some(OVERBOOST_CUT_STATE))
+    });
+    
+    Some(PullReport {
+        start_ms: first.timestamp_ms,
+        duration_ms,
+        rpm_range: first.rpm.zip(last.rpm),
+        peak_target_psi: rows.iter().map(|row| row.target_psi).fold(f32::MIN, f32::max),
+        peak_boost_psi: rows.iter().map(|row| row.boost_psi).fold(f32::MIN, f32::max),
+        overshoot_psi: errors.iter().map(|error| -error).fold(0.0, f32::max),
+        settle_ms: settled_from.map(|settled_ms| settled_ms.wrapping_sub(first.timestamp_ms)),
+        rms_error_psi: (errors.iter().map(|error| error * error).sum::<f32>() / errors.len() as f32).sqrt(),
+        duty_high_percent: share(&|duty| duty >= settings.saturated_high_percent),
+        duty_low_percent: share(&|duty| duty <= settings.saturated_low_percent),
+        overboost_cuts,
+    })
+}
+
+/// Report column headings
+pub fn report_header() -> String {
+    format!(
+        "{:>4} {:>8} {:>6} {:>11} {:>7} {:>7} {:>9} {:>7} {:>7} {:>6} {:>6} {:>5}",
+        "Pull", "Start s", "Len s", "RPM", "Target", "Peak", "Overshoot", "Settle", "RMS err", "Duty▲", "Duty▼", "Cuts"
+    )
+}
+
+/// One report row, lined up with `report_header`
+pub fn format_report(number: usize, report: &PullReport) -> String {
+    let optional = |value: Option<String>| value.unwrap_or_else(|| String::from("-"));
+    format!(
+        "{:>4} {:>8.1} {:>6.1} {:>11} {:>7.1} {:>7.1} {:>9.1} {:>7} {:>7.2} {:>6} {:>6} {:>5}",
+        number,
+        report.start_ms as f32 / 1000.0,
+        report.duration_ms as f32 / 1000.0,
+        optional(report.rpm_range.map(|(from, to)| format!("{:.0}-{:.0}", from, to))),
+        report.peak_target_psi,
+        report.peak_boost_psi,
+        report.overshoot_psi,
+        optional(report.settle_ms.map(|ms| format!("{:.2}", ms as f32 / 1000.0))),
+        report.rms_error_psi,
+        optional(report.duty_high_percent.map(|share| format!("{:.0}%", share))),
+        optional(report.duty_low_percent.map(|share| format!("{:.0}%", share))),
+        optional(report.overboost_cuts.map(|cuts| cuts.to_string())),
+    )
+}
+
+/// Print the per-pull table and a summary line
+pub fn print_report(reports: &[PullReport], settings: &AnalysisSettings) {
+    if reports.is_empty() {
+        println!("No pulls found (target never held at {:.1} PSI or more for a second)", settings.min_target_psi);
+        return;
+    }
+    println!("{}", report_header());
+    for (index, report) in reports.iter().enumerate() {
+        println!("{}", format_report(index + 1, report));
+    }
+    
+    let worst = reports.iter().map(|report| report.overshoot_psi).fold(0.0, f32::max);
+    let unsettled = reports.iter().filter(|report| report.settle_ms.is_none()).count();
+    let cuts: u32 = reports.iter().filter_map(|report| report.overboost_cuts).sum();
+    println!(
+        "{} pulls: worst overshoot {:.1} PSI, {} never settled within {:.1} PSI, {} overboost cuts",
+        reports.len(), worst, unsettled, settings.settle_band_psi, cuts
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Log of one 3 s pull: boost overshoots by 1 PSI, settles at 1.2 s, an overboost cut at 1 s
+    fn pull_csv() -> String {
+        let mut csv = String::from("timestamp_ms,rpm,boost_psi,target_psi,duty_percent,state\n");
+        for t in (0..5_000).step_by(100) {
+            let pulling = (1_000..4_000).contains(&t);
+            let target = if pulling { 10.0 } else { 0.0 };
+            let boost = match t {
+                1_000..=1_100 => 6.0,
+                1_200..=1_300 => 11.0,
+                _ => target,
+            };
+            let duty = if t == 1_000 { 100.0 } else { 40.0 };
+            let state = if t == 1_200 { 5 } else { 2 };
+            csv.push_str(&format!("{},{},{},{},{},{}\n", t, 2000 + t, boost, target, duty, state));
+        }
+        csv
+    }
+    
+    #[test]
+    fn test_pull_metrics_from_csv() {
+        let rows = read_log(pull_csv().as_bytes()).unwrap();
+        let reports = analyze(&rows, &AnalysisSettings::default());
+        assert_eq!(reports.len(), 1);
+        
+        let pull = &reports[0];
+        assert_eq!((pull.start_ms, pull.duration_ms), (1_000, 2_900));
+        assert_eq!(pull.rpm_range, Some((3_000.0, 5_900.0)));
+        assert_eq!(pull.overshoot_psi, 1.0);
+        assert_eq!(pull.settle_ms, Some(400));
+        // Two rows 4 PSI short and two 1 PSI over out of 30
+        assert!((pull.rms_error_psi - (34.0f32 / 30.0).sqrt()).abs() < 1e-4);
+        assert_eq!(pull.overboost_cuts, Some(1));
+        assert!((pull.duty_high_percent.unwrap() - 100.0 / 30.0).abs() < 1e-4);
+        assert_eq!(format_report(1, pull).chars().count(), report_header().chars().count());
+    }
+    
+    #[test]
+    fn test_binary_log_matches_csv() {
+        let channels = [TelemetryChannel::Rpm, TelemetryChannel::BoostPsi, TelemetryChannel::TargetPsi];
+        let mut bytes = Vec::from(BINARY_DATALOG_MAGIC);
+        bytes.push(channels.len() as u8);
+        bytes.extend(channels.iter().map(|channel| *channel as u8));
+        for t in (0..3_000u32).step_by(50) {
+            bytes.extend_from_slice(&t.to_le_bytes());
+            for value in [4_000i16, 95, 100] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        // Partial row from a pulled card
+        bytes.extend_from_slice(&[1, 2, 3]);
+        
+        let rows = read_log(&bytes).unwrap();
+        assert_eq!(rows.len(), 60);
+        assert_eq!(rows[1], LogRow {
+            timestamp_ms: 50, rpm: Some(4_000.0), boost_psi: 9.5, target_psi: 10.0, duty_percent: None, state: None,
+        });
+        let pull = &analyze(&rows, &AnalysisSettings::default())[0];
+        assert_eq!(pull.settle_ms, Some(0));
+        assert_eq!(pull.duty_high_percent, None);
+    }
+    
+    #[test]
+    fn test_short_blips_and_missing_columns() {
+        let blip = "timestamp_ms,boost_psi,target_psi\n0,0,5\n500,0,5\n1500,0,0\n";
+        assert!(analyze(&read_log(blip.as_bytes()).unwrap(), &AnalysisSettings::default()).is_empty());
+        assert!(read_log(b"timestamp_ms,rpm\n0,1000\n").is_err());
+    }
+}
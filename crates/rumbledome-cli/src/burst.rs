@@ -0,0 +1,195 @@
+//! Burst Log Capture
+//! 
+//! 🔗 T4-CLI-009: Burst Log Offload
+//! Derived From: T4-CORE-095 (Full-Rate Burst Capture)
+//! AI Traceability: Start a full-rate capture, wait it out, pull it down in chunks and save it for plotting
+
+use std::error::Error;
+use std::fs;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{BurstLogStatus, BurstSample};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Interval between capture progress checks
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Time allowed past the window before the capture counts as stalled (ms)
+const STALL_MARGIN_MS: u32 = 5_000;
+
+/// Burst capture as written to disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurstCapture {
+    /// Creation time (seconds since the Unix epoch)
+    pub created_unix_s: u64,
+    /// Requested window (ms)
+    pub window_ms: u32,
+    /// One sample per control cycle
+    pub samples: Vec<BurstSample>,
+}
+
+impl BurstCapture {
+    /// Default output file name
+    pub fn default_file_name(&self) -> String {
+        format!("rumbledome-burst-{}.json", self.created_unix_s)
+    }
+}
+
+/// Send a burst request that answers with capture progress; device errors come back as `Err`
+fn burst_status(exchange: &mut Exchange, message: ProtocolMessage) -> Result<BurstLogStatus, Box<dyn Error>> {
+    match exchange(message)? {
+        ProtocolMessage::BurstLogStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected burst log response: {:?}", other).into()),
+    }
+}
+
+/// Start a capture and wait until the device has filled the window
+/// 
+/// `wait` sleeps between progress checks.
+pub fn record(exchange: &mut Exchange, window_ms: u32, wait: &mut dyn FnMut(Duration)) -> Result<BurstLogStatus, Box<dyn Error>> {
+    let mut status = burst_status(exchange, ProtocolMessage::StartBurstLog { window_ms })?;
+    let mut waited_ms: u32 = 0;
+    while status.recording {
+        if waited_ms > window_ms + STALL_MARGIN_MS {
+            return Err(format!("Capture stalled after {} samples - is the control loop running?", status.samples).into());
+        }
+        wait(POLL_INTERVAL);
+        waited_ms += POLL_INTERVAL.as_millis() as u32;
+        status = burst_status(exchange, ProtocolMessage::GetBurstLogStatus)?;
+    }
+    Ok(status)
+}
+
+/// Pull the whole capture off the device
+pub fn offload(exchange: &mut Exchange, window_ms: u32) -> Result<BurstCapture, Box<dyn Error>> {
+    let mut samples = Vec::new();
+    loop {
+        let chunk = match exchange(ProtocolMessage::GetBurstLog { offset: samples.len() as u32 })? {
+            ProtocolMessage::BurstLog(chunk) => chunk,
+            ProtocolMessage::Error(e) => return Err(e.into()),
+            other => return Err(format!("Unexpected burst log response: {:?}", other).into()),
+        };
+        if chunk.samples.is_empty() {
+            break;
+        }
+        samples.extend(chunk.samples);
+        if samples.len() >= chunk.total as usize {
+            break;
+        }
+    }
+    
+    let created_unix_s = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    Ok(BurstCapture { created_unix_s, window_ms, samples })
+}
+
+/// Write a capture as JSON
+pub fn write_capture(path: &str, capture: &BurstCapture) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string(capture)?)?;
+    Ok(())
+}
+
+/// Print what the capture covers
+pub fn print_summary(capture: &BurstCapture) {
+    let (Some(first), Some(last)) = (capture.samples.first(), capture.samples.last()) else {
+        println!("Capture is empty");
+        return;
+    };
+    
+    let span_ms = last.inputs.timestamp_ms.wrapping_sub(first.inputs.timestamp_ms);
+    let rate_hz = if span_ms > 0 { (capture.samples.len() - 1) as f32 * 1000.0 / span_ms as f32 } else { 0.0 };
+    println!("{} samples over {} ms ({:.0} Hz)", capture.samples.len(), span_ms, rate_hz);
+    
+    let range = |value: fn(&BurstSample) -> f32| {
+        capture.samples.iter().map(value).fold((f32::MAX, f32::MIN), |(low, high), v| (low.min(v), high.max(v)))
+    };
+    let (boost_low, boost_high) = range(|sample| sample.inputs.manifold_pressure);
+    let (duty_low, duty_high) = range(|sample| sample.duty_percent);
+    println!("  Boost      {:>6.1} - {:<6.1} PSI", boost_low, boost_high);
+    println!("  Duty       {:>6.1} - {:<6.1} %", duty_low, duty_high);
+    
+    let max_cycle_us = capture.samples.iter().map(|sample| sample.cycle_time_us).max().unwrap_or(0);
+    let outer_cycles = capture.samples.iter().filter(|sample| sample.outer_cycle).count();
+    println!("  Cycle time up to {} µs, {} outer loop cycles", max_cycle_us, outer_cycles);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{BurstLog, InputSource, SystemInputs, SystemState};
+    
+    fn sample(timestamp_ms: u32) -> BurstSample {
+        BurstSample {
+            state: SystemState::Armed,
+            inputs: SystemInputs {
+                rpm: 4000,
+                desired_torque: 300.0,
+                actual_torque: 280.0,
+                manifold_pressure: 8.0,
+                throttle_position: Some(80.0),
+                dome_input_pressure: 60.0,
+                upper_dome_pressure: 10.0,
+                lower_dome_pressure: 2.0,
+                intake_air_temp_c: None,
+                coolant_temp_c: None,
+                bank_dome_pressure: None,
+                exhaust_backpressure_psi: None,
+                aggression: 0.3,
+                scramble_active: false,
+                timestamp_ms,
+            },
+            map_source: InputSource::Analog,
+            map_zero_psi: 0.0,
+            can_authority: 1.0,
+            thermal_derate: 1.0,
+            outer_cycle: false,
+            level1_target_psi: 9.0,
+            target_psi: 9.0,
+            authority_request: Some(40.0),
+            duty_ceiling_percent: None,
+            idle_venting: false,
+            duty_percent: 40.0,
+            cycle_time_us: 120,
+            boost_trim_psi: None,
+        }
+    }
+    
+    #[test]
+    fn test_record_and_offload_whole_capture() {
+        // Device side: every progress check finds another 250 ms of 100 Hz cycles recorded
+        let mut log = BurstLog::new();
+        let mut now_ms = 0;
+        let mut exchange = |message| Ok(match message {
+            ProtocolMessage::StartBurstLog { window_ms } => match log.start(window_ms) {
+                Ok(()) => ProtocolMessage::BurstLogStatus(log.status()),
+                Err(e) => ProtocolMessage::Error(format!("{:?}", e)),
+            },
+            ProtocolMessage::GetBurstLogStatus => {
+                for _ in 0..25 {
+                    log.record(sample(now_ms));
+                    now_ms += 10;
+                }
+                ProtocolMessage::BurstLogStatus(log.status())
+            },
+            ProtocolMessage::GetBurstLog { offset } => ProtocolMessage::BurstLog(log.chunk(offset)),
+            _ => ProtocolMessage::Error("Unexpected".to_string()),
+        });
+        
+        let mut waits = 0;
+        assert!(record(&mut exchange, 0, &mut |_| waits += 1).is_err());
+        let status = record(&mut exchange, 1000, &mut |_| waits += 1).unwrap();
+        assert!(!status.recording);
+        assert_eq!(waits, 5);
+        
+        let capture = offload(&mut exchange, 1000).unwrap();
+        assert_eq!(capture.samples.len(), status.samples as usize);
+        assert_eq!(capture.samples.last().unwrap().inputs.timestamp_ms, 1000);
+    }
+}
@@ -0,0 +1,49 @@
+//! Calibration Session Control
+//! 
+//! 🔗 T4-CLI-015: Calibration Start, Status and Abort
+//! Derived From: T4-CORE-119 (Calibration Session Control)
+//! AI Traceability: Start auto-calibration from a laptop and stop it at once if anything looks wrong
+
+use std::error::Error;
+
+use rumbledome_core::CalibrationProgress;
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Send a calibration request and return the progress it reports
+pub fn send(exchange: &mut Exchange, request: ProtocolMessage) -> Result<Option<CalibrationProgress>, Box<dyn Error>> {
+    match exchange(request)? {
+        ProtocolMessage::CalibrationStatus { progress } => Ok(progress),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected calibration response: {:?}", other).into()),
+    }
+}
+
+/// One-line progress summary
+pub fn format_progress(progress: &CalibrationProgress) -> String {
+    format!(
+        "Phase {}: {} ({:.0}% overall, target {:.1} PSI at {} RPM, {} validation runs)",
+        progress.phase, progress.description, progress.overall_progress * 100.0,
+        progress.current_target_psi, progress.current_rpm, progress.validation_runs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_start_and_abort_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        device.handle(ProtocolMessage::SkipCommissioning, 0);
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        let progress = send(&mut exchange, ProtocolMessage::StartCalibration).unwrap().unwrap();
+        assert_eq!(format_progress(&progress), "Phase 1: Full-throttle pull through 2000-3000 RPM (0% overall, target 6.0 PSI at 2500 RPM, 0 validation runs)");
+        assert!(send(&mut exchange, ProtocolMessage::StartCalibration).is_err());
+        assert!(send(&mut exchange, ProtocolMessage::AbortCalibration).unwrap().is_none());
+    }
+}
@@ -0,0 +1,246 @@
+//! Commissioning Wizard
+//! 
+//! 🔗 T4-CLI-006: Interactive Commissioning
+//! Derived From: T4-CORE-073 (Commissioning Sequence) + T4-CORE-075 (Commissioning Mode)
+//! AI Traceability: Walk the installer through first-run checks one step at a time from the laptop
+
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+
+use rumbledome_core::{CommissioningStatus, CommissioningStep, PressureRole, SensorMappingReport, SensorMappingStatus, StepOutcome};
+use rumbledome_protocol::ProtocolMessage;
+
+/// Sends one request to the device and returns its response
+pub type Exchange<'a> = dyn FnMut(ProtocolMessage) -> Result<ProtocolMessage, Box<dyn Error>> + 'a;
+
+/// Send a commissioning request; device errors come back as `Err`
+fn commissioning_request(exchange: &mut Exchange, message: ProtocolMessage) -> Result<CommissioningStatus, Box<dyn Error>> {
+    match exchange(message)? {
+        ProtocolMessage::CommissioningStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected commissioning response: {:?}", other).into()),
+    }
+}
+
+/// Send a sensor mapping request; device errors come back as `Err`
+fn sensor_mapping_request(exchange: &mut Exchange, message: ProtocolMessage) -> Result<SensorMappingStatus, Box<dyn Error>> {
+    match exchange(message)? {
+        ProtocolMessage::SensorMappingStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected sensor mapping response: {:?}", other).into()),
+    }
+}
+
+/// Run the wizard from the next unfinished step until done or the installer quits
+/// 
+/// Progress is kept on the device after every step, so quitting and running
+/// the wizard again resumes where it left off.
+pub fn run(exchange: &mut Exchange, input: &mut dyn BufRead) -> Result<(), Box<dyn Error>> {
+    let mut status = commissioning_request(exchange, ProtocolMessage::GetCommissioningStatus)?;
+    if status.complete {
+        print_status(&status);
+        return Ok(());
+    }
+    
+    status = commissioning_request(exchange, ProtocolMessage::BeginCommissioning)?;
+    while let Some(step) = status.current_step {
+        println!();
+        println!("Step {}/{}: {}", step.number(), CommissioningStep::ALL.len(), step.display_text());
+        println!("  {}", step.instructions());
+        
+        match prompt(input, "  [Enter] done, [s] skip step, [q] quit: ")?.as_str() {
+            "" => match commissioning_request(exchange, ProtocolMessage::CompleteCommissioningStep { step }) {
+                Ok(updated) => {
+                    if step == CommissioningStep::SpringPressure {
+                        if let Some(psi) = updated.detected_spring_psi {
+                            println!("  Spring pressure set to {:.1} PSI", psi);
+                        }
+                    }
+                    status = updated;
+                }
+                // Evidence not there yet - stay on the step
+                Err(e) => println!("  Not done yet: {}", e),
+            },
+            "s" => status = commissioning_request(exchange, ProtocolMessage::SkipCommissioningStep { step })?,
+            "q" => {
+                commissioning_request(exchange, ProtocolMessage::ExitCommissioning)?;
+                println!("Commissioning paused - run again to continue from this step");
+                return Ok(());
+            }
+            _ => println!("  Enter, s or q"),
+        }
+    }
+    
+    println!();
+    print_status(&status);
+    Ok(())
+}
+
+/// Skip every remaining step after confirmation
+pub fn skip_all(exchange: &mut Exchange, input: &mut dyn BufRead) -> Result<(), Box<dyn Error>> {
+    println!("Skipping commissioning permits arming without any first-run checks.");
+    if prompt(input, "Skip all remaining steps? [y/N]: ")? != "y" {
+        println!("Nothing skipped");
+        return Ok(());
+    }
+    
+    let status = commissioning_request(exchange, ProtocolMessage::SkipCommissioning)?;
+    print_status(&status);
+    Ok(())
+}
+
+/// Find which input each pressure sensor is wired to, one line at a time
+/// 
+/// The device detects the responding channel itself; the installer only
+/// pressurizes the prompted line and confirms.
+pub fn map_sensors(exchange: &mut Exchange, input: &mut dyn BufRead) -> Result<(), Box<dyn Error>> {
+    println!("Engine off. Leave every sensor line open to atmosphere until the first prompt.");
+    let mut status = sensor_mapping_request(exchange, ProtocolMessage::BeginSensorMapping)?;
+    
+    loop {
+        if status.awaiting_release {
+            if let Some(found) = status.assignments.last() {
+                println!("  {} found on channel {}", found.role.display_text(), found.channel.unwrap_or_default());
+            }
+            if prompt(input, "  Release the pressure, then [Enter]: ")? == "q" {
+                return cancel_sensor_mapping(exchange);
+            }
+            status = sensor_mapping_request(exchange, ProtocolMessage::GetSensorMappingStatus)?;
+            continue;
+        }
+        let Some(role) = status.current_role else {
+            break;
+        };
+        
+        println!();
+        println!("Sensor {}/{}: {}", status.assignments.len() + 1, PressureRole::ALL.len(), role.display_text());
+        println!("  {}", role.instructions());
+        match prompt(input, "  Hold the pressure and press [Enter], [s] nothing responds, [q] quit: ")?.as_str() {
+            "" => {
+                let found = status.assignments.len();
+                status = sensor_mapping_request(exchange, ProtocolMessage::GetSensorMappingStatus)?;
+                if !status.active {
+                    return Err("Sensor mapping stopped - was the engine started?".into());
+                }
+                if status.assignments.len() == found {
+                    println!("  Nothing detected yet - check the line is pressurized");
+                }
+            }
+            "s" => status = sensor_mapping_request(exchange, ProtocolMessage::SkipSensorRole)?,
+            "q" => return cancel_sensor_mapping(exchange),
+            _ => println!("  Enter, s or q"),
+        }
+    }
+    
+    match exchange(ProtocolMessage::FinishSensorMapping)? {
+        ProtocolMessage::SensorMappingReport(report) => {
+            println!();
+            print_sensor_report(&report);
+            Ok(())
+        }
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected sensor mapping response: {:?}", other).into()),
+    }
+}
+
+fn cancel_sensor_mapping(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    sensor_mapping_request(exchange, ProtocolMessage::CancelSensorMapping)?;
+    println!("Sensor mapping cancelled - configured channels kept");
+    Ok(())
+}
+
+/// Print the detected channel map with wiring findings
+pub fn print_sensor_report(report: &SensorMappingReport) {
+    for role in PressureRole::ALL {
+        let marker = if report.swapped.contains(&role) { " (rewired)" } else { "" };
+        println!("  {:<11} channel {}{}", role.display_text(), report.map.channel(role), marker);
+    }
+    for channel in &report.dead_channels {
+        println!("⚠️  Channel {} did not respond - check the sensor, its connector and supply", channel);
+    }
+    println!("Channel map stored in the active configuration");
+}
+
+/// Print per-step progress
+pub fn print_status(status: &CommissioningStatus) {
+    for step in CommissioningStep::ALL {
+        let outcome = status.record.steps
+            .iter()
+            .find(|record| record.step == step)
+            .map(|record| record.outcome);
+        let marker = match outcome {
+            Some(StepOutcome::Completed) => "✅",
+            Some(StepOutcome::Skipped) => "⏭ ",
+            None if status.current_step == Some(step) => "▶ ",
+            None => "· ",
+        };
+        println!("{} {}", marker, step.display_text());
+    }
+    
+    if status.complete {
+        println!("Commissioning complete - boost control may be armed");
+    }
+}
+
+/// Print a prompt and read the answer (trimmed, lowercase)
+pub fn prompt(input: &mut dyn BufRead, text: &str) -> Result<String, Box<dyn Error>> {
+    print!("{}", text);
+    io::stdout().flush()?;
+    
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err("Input closed".into());
+    }
+    Ok(line.trim().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    use std::io::Cursor;
+    
+    #[test]
+    fn test_wizard_resumes_after_quit() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        // No MAP zero captured on the bench - the step stays until skipped
+        run(&mut exchange, &mut Cursor::new("\ns\ns\nq\n")).unwrap();
+        let status = commissioning_request(&mut exchange, ProtocolMessage::GetCommissioningStatus).unwrap();
+        assert_eq!(status.current_step, Some(CommissioningStep::FrequencySweep));
+        assert_eq!(status.record.steps[1].outcome, StepOutcome::Skipped);
+        
+        run(&mut exchange, &mut Cursor::new("\n\ns\n\n")).unwrap();
+        let status = commissioning_request(&mut exchange, ProtocolMessage::GetCommissioningStatus).unwrap();
+        assert!(status.complete);
+    }
+    
+    #[test]
+    fn test_skip_all_needs_confirmation() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        skip_all(&mut exchange, &mut Cursor::new("n\n")).unwrap();
+        assert!(!commissioning_request(&mut exchange, ProtocolMessage::GetCommissioningStatus).unwrap().complete);
+        
+        skip_all(&mut exchange, &mut Cursor::new("y\n")).unwrap();
+        assert!(commissioning_request(&mut exchange, ProtocolMessage::GetCommissioningStatus).unwrap().complete);
+    }
+    
+    #[test]
+    fn test_sensor_mapping_reports_unresponsive_lines() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        // Nothing pressurized on the bench - quitting keeps the configured map
+        map_sensors(&mut exchange, &mut Cursor::new("\nq\n")).unwrap();
+        assert!(!sensor_mapping_request(&mut exchange, ProtocolMessage::GetSensorMappingStatus).unwrap().active);
+        
+        // Every role skipped: finished with the configured channels and all of them flagged
+        map_sensors(&mut exchange, &mut Cursor::new("s\ns\ns\ns\n")).unwrap();
+        assert!(!sensor_mapping_request(&mut exchange, ProtocolMessage::GetSensorMappingStatus).unwrap().active);
+        assert!(matches!(exchange(ProtocolMessage::FinishSensorMapping).unwrap(), ProtocolMessage::Error(_)));
+    }
+}
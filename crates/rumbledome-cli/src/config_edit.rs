@@ -0,0 +1,172 @@
+//! Configuration Editing
+//! 
+//! 🔗 T4-CLI-010: Schema-Assisted Configuration Editing
+//! Derived From: T4-CORE-104 (Configuration Schema Generation) + T4-CLI-001 (Configuration Management Tool)
+//! AI Traceability: Hand-edited configuration files get completion while typing and are checked before they reach the car
+//! 
+//! The schema files are generated from the configuration types compiled into
+//! this CLI, so they always match the firmware it talks to. A file being
+//! edited points at its schema through a `$schema` key, which editors with
+//! JSON Schema support (VS Code, Neovim/Helix with a JSON language server)
+//! pick up for completion and inline errors. The key is ignored when the
+//! configuration is read back.
+
+use std::error::Error;
+use std::fs;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rumbledome_core::{ConfigSchema, SystemConfig};
+
+use crate::commissioning::prompt;
+
+/// Key editors read the schema location from
+const SCHEMA_KEY: &str = "$schema";
+
+/// Write every configuration schema into a directory
+pub fn write_schemas(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    ConfigSchema::ALL.iter().map(|schema| {
+        let path = dir.join(schema.file_name());
+        fs::write(&path, serde_json::to_string_pretty(&schema.generate())?)?;
+        Ok(path)
+    }).collect()
+}
+
+/// Editor to launch: `$VISUAL`, then `$EDITOR`, then the platform default
+pub fn editor_command() -> String {
+    std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| if cfg!(windows) { "notepad".to_string() } else { "vi".to_string() })
+}
+
+/// Run an editor command (e.g. `code --wait`) on a file and wait for it to close
+pub fn launch_editor(editor: &str, path: &Path) -> Result<(), Box<dyn Error>> {
+    let mut words = editor.split_whitespace();
+    let program = words.next().ok_or("No editor set (set $EDITOR)")?;
+    let status = Command::new(program).args(words).arg(path).status()
+        .map_err(|e| format!("Could not start editor \"{}\": {}", editor, e))?;
+    if !status.success() {
+        return Err(format!("Editor exited with {}", status).into());
+    }
+    Ok(())
+}
+
+/// Opens the configuration file in an editor and waits for it to close
+pub type Launcher<'a> = dyn FnMut(&Path) -> Result<(), Box<dyn Error>> + 'a;
+
+/// Edit a configuration file until it is valid, returning the configuration
+/// 
+/// A missing file starts from the default configuration. The schema is
+/// written next to the file and referenced from it. After each edit the file
+/// is parsed and validated; on an error the user can open it again or give
+/// up, leaving the file as it is.
+pub fn edit(path: &Path, input: &mut dyn BufRead, launch: &mut Launcher<'_>) -> Result<SystemConfig, Box<dyn Error>> {
+    let schema_path = path.parent().unwrap_or(Path::new("")).join(ConfigSchema::SystemConfig.file_name());
+    fs::write(&schema_path, serde_json::to_string_pretty(&ConfigSchema::SystemConfig.generate())?)?;
+    println!("  Schema: {}", schema_path.display());
+    
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            println!("  {} not found - starting from the default configuration", path.display());
+            SystemConfig::default().to_json().map_err(|e| format!("{:?}", e))?
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if !text.contains(SCHEMA_KEY) {
+        fs::write(path, with_schema_reference(&text, ConfigSchema::SystemConfig.file_name()))?;
+    }
+    
+    loop {
+        launch(path)?;
+        
+        match SystemConfig::from_json(&fs::read_to_string(path)?) {
+            Ok(config) => return Ok(config),
+            Err(e) => println!("✗ Invalid configuration: {:?}", e),
+        }
+        if prompt(input, "Edit again? [Y/n]: ")? == "n" {
+            return Err("Configuration not uploaded - fix the file and run `config edit` again".into());
+        }
+    }
+}
+
+/// Add a `$schema` reference as the first key of a JSON object
+fn with_schema_reference(json: &str, schema_file: &str) -> String {
+    let Some(brace) = json.find('{') else {
+        return json.to_string();
+    };
+    
+    let (head, rest) = json.split_at(brace + 1);
+    let separator = if rest.trim_start().starts_with('}') { "" } else { "," };
+    format!("{}\n  \"{}\": \"./{}\"{}{}", head, SCHEMA_KEY, schema_file, separator, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+    
+    #[test]
+    fn test_schema_reference_is_ignored_when_read_back() {
+        let json = SystemConfig::default().to_json().unwrap();
+        let annotated = with_schema_reference(&json, "system-config.schema.json");
+        assert!(annotated.contains("\"$schema\": \"./system-config.schema.json\","));
+        assert_eq!(SystemConfig::from_json(&annotated).unwrap(), SystemConfig::default());
+        
+        assert_eq!(with_schema_reference("{}", "s.json"), "{\n  \"$schema\": \"./s.json\"}");
+    }
+    
+    #[test]
+    fn test_edit_reopens_until_valid() {
+        let dir = scratch_dir("rumbledome-config-edit");
+        let path = dir.join("config.json");
+        let mut edits = 0;
+        let mut launch = |path: &Path| -> Result<(), Box<dyn Error>> {
+            edits += 1;
+            let text = fs::read_to_string(path)?;
+            assert!(text.contains("$schema"));
+            // First save has a syntax error, second an out-of-range value, third is fixed
+            let edited = match edits {
+                1 => text.replacen("\"aggression\": 0.3", "\"aggression\": 0.8,", 1),
+                2 => text.replacen("\"aggression\": 0.8,", "\"aggression\": 2.0", 1),
+                _ => text.replacen("\"aggression\": 2.0", "\"aggression\": 0.8", 1),
+            };
+            fs::write(path, edited)?;
+            Ok(())
+        };
+        
+        let config = edit(&path, &mut Cursor::new("\ny\n"), &mut launch).unwrap();
+        assert_eq!(edits, 3);
+        assert_eq!(config.aggression, 0.8);
+        assert!(dir.join("system-config.schema.json").exists());
+    }
+    
+    #[test]
+    fn test_edit_can_be_abandoned() {
+        let dir = scratch_dir("rumbledome-config-edit-abandon");
+        let path = dir.join("config.json");
+        fs::write(&path, "{ \"aggression\": }").unwrap();
+        
+        let mut launch = |_: &Path| -> Result<(), Box<dyn Error>> { Ok(()) };
+        assert!(edit(&path, &mut Cursor::new("n\n"), &mut launch).is_err());
+    }
+    
+    #[test]
+    fn test_write_schemas_writes_each_file() {
+        let dir = scratch_dir("rumbledome-config-schemas");
+        let written = write_schemas(&dir).unwrap();
+        assert_eq!(written.len(), ConfigSchema::ALL.len());
+        
+        let schema: serde_json::Value = serde_json::from_str(&fs::read_to_string(dir.join("profile.schema.json")).unwrap()).unwrap();
+        assert_eq!(schema["title"], "NamedConfig");
+    }
+}
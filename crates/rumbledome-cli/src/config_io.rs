@@ -0,0 +1,226 @@
+//! Configuration Round-Trip
+//! 
+//! 🔗 T4-CLI-018: Configuration Get, Set, Import and Export
+//! Derived From: T4-CLI-001 (Configuration Management Tool) + T4-CORE-122 (Conservative Shake-Down Mode)
+//! AI Traceability: Read or change one setting without hand-editing a whole file, and move a complete configuration between car and laptop
+//! 
+//! Settings are addressed by their JSON path, joined with dots
+//! (`max_boost_psi`, `shakedown.minutes`, `boost_targets.points.2.psi`). A
+//! value is read as JSON where it can be, so numbers, booleans and objects
+//! work as typed and a bare word is taken as a string (enum variants such as
+//! `BoostByTarget`). Pressures may carry a unit - `17psi`, `1.2bar`, `85kPa` -
+//! and are stored in PSI. Every change is checked the way the firmware will
+//! read it: unknown settings are refused, and the result must deserialize as
+//! a SystemConfig and pass its validation.
+
+use std::error::Error;
+
+use rumbledome_core::{is_safety_relevant, SystemConfig};
+use rumbledome_hal::PSI_PER_KPA;
+use rumbledome_protocol::ProtocolMessage;
+use serde_json::Value;
+
+use crate::commissioning::Exchange;
+use crate::dbc::PSI_PER_BAR;
+
+/// Key editors read the schema location from; not a setting
+const SCHEMA_KEY: &str = "$schema";
+
+/// Pressure units accepted on a value, with their size in PSI
+const PRESSURE_UNITS: [(&str, f32); 3] = [("kpa", PSI_PER_KPA), ("bar", PSI_PER_BAR), ("psi", 1.0)];
+
+/// Fetch the configuration the device is running
+pub fn fetch_running(exchange: &mut Exchange) -> Result<SystemConfig, Box<dyn Error>> {
+    let name = match exchange(ProtocolMessage::ListConfigurations)? {
+        ProtocolMessage::Configurations { active, .. } => active,
+        ProtocolMessage::Error(e) => return Err(e.into()),
+        other => return Err(format!("Unexpected configuration list response: {:?}", other).into()),
+    };
+    match exchange(ProtocolMessage::GetConfiguration { name })? {
+        ProtocolMessage::Configuration(entry) => Ok(entry.config),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected configuration response: {:?}", other).into()),
+    }
+}
+
+/// Setting at a dotted path (the whole configuration for an empty key)
+pub fn get(config: &SystemConfig, key: &str) -> Result<Value, Box<dyn Error>> {
+    let mut value = serde_json::to_value(config)?;
+    Ok(lookup(&mut value, key)?.take())
+}
+
+/// Configuration with one setting changed, validated
+pub fn set(config: &SystemConfig, key: &str, text: &str) -> Result<SystemConfig, Box<dyn Error>> {
+    if key.is_empty() {
+        return Err("Name the setting to change (e.g. max_boost_psi)".into());
+    }
+    let mut value = serde_json::to_value(config)?;
+    *lookup(&mut value, key)? = parse_value(key, text)?;
+    
+    let updated: SystemConfig = serde_json::from_value(value)
+        .map_err(|e| format!("{} cannot be {}: {}", key, text, e))?;
+    updated.validate().map_err(|e| format!("Invalid configuration: {:?}", e))?;
+    Ok(updated)
+}
+
+/// Read a configuration file, refusing settings the firmware does not know
+pub fn import(text: &str) -> Result<SystemConfig, Box<dyn Error>> {
+    let mut value: Value = serde_json::from_str(text)?;
+    if let Value::Object(fields) = &mut value {
+        fields.remove(SCHEMA_KEY);
+    }
+    
+    let config: SystemConfig = serde_json::from_value(value.clone())?;
+    if let Some(path) = unknown_key(&value, &serde_json::to_value(&config)?, "") {
+        return Err(format!("Unknown setting {}", path).into());
+    }
+    config.validate().map_err(|e| format!("Invalid configuration: {:?}", e))?;
+    Ok(config)
+}
+
+/// Configuration file contents
+pub fn export(config: &SystemConfig) -> Result<String, Box<dyn Error>> {
+    Ok(serde_json::to_string_pretty(config)?)
+}
+
+/// Settings that differ, with their old and new values (`path: old -> new`)
+pub fn changes(running: &SystemConfig, updated: &SystemConfig) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut changes = Vec::new();
+    differences(&serde_json::to_value(running)?, &serde_json::to_value(updated)?, "", &mut changes);
+    Ok(changes)
+}
+
+/// Refuse a change to boost limits or control unless confirmed
+pub fn require_confirmation(running: &SystemConfig, updated: &SystemConfig, confirmed: bool) -> Result<(), Box<dyn Error>> {
+    if is_safety_relevant(running, updated) && !confirmed {
+        return Err("This changes boost limits or control behaviour - check the changes and run again with --confirm".into());
+    }
+    Ok(())
+}
+
+/// Setting at a dotted path; array elements are addressed by index
+fn lookup<'a>(value: &'a mut Value, key: &str) -> Result<&'a mut Value, Box<dyn Error>> {
+    if key.is_empty() {
+        return Ok(value);
+    }
+    key.split('.').try_fold(value, |value, segment| {
+        let next = match value {
+            Value::Object(fields) => fields.get_mut(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|index| items.get_mut(index)),
+            _ => None,
+        };
+        next.ok_or_else(|| format!("Unknown setting {}", key).into())
+    })
+}
+
+/// Whether a setting holds a pressure, and so takes a unit
+fn is_pressure_key(key: &str) -> bool {
+    let name = key.rsplit('.').next().unwrap_or(key);
+    name.ends_with("_psi") || name.contains("pressure") || name == "overboost_limit"
+}
+
+/// Value typed on the command line
+fn parse_value(key: &str, text: &str) -> Result<Value, Box<dyn Error>> {
+    let lower = text.trim().to_ascii_lowercase();
+    for (unit, psi_per_unit) in PRESSURE_UNITS {
+        let Some(number) = lower.strip_suffix(unit).and_then(|number| number.trim().parse::<f32>().ok()) else {
+            continue;
+        };
+        if !is_pressure_key(key) {
+            return Err(format!("{} is not a pressure - give it without a unit", key).into());
+        }
+        let psi = (number * psi_per_unit * 100.0).round() / 100.0;
+        return Ok(serde_json::json!(psi));
+    }
+    Ok(serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string())))
+}
+
+/// Path of the first key in `given` that `known` does not have
+fn unknown_key(given: &Value, known: &Value, path: &str) -> Option<String> {
+    match (given, known) {
+        (Value::Object(given), Value::Object(known)) => given.iter().find_map(|(key, value)| {
+            let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+            match known.get(key) {
+                Some(known) => unknown_key(value, known, &path),
+                None => Some(path),
+            }
+        }),
+        (Value::Array(given), Value::Array(known)) => given.iter().zip(known).enumerate()
+            .find_map(|(index, (given, known))| unknown_key(given, known, &format!("{}.{}", path, index))),
+        _ => None,
+    }
+}
+
+/// Collect `path: old -> new` for every leaf that differs
+fn differences(old: &Value, new: &Value, path: &str, out: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            for (key, new_value) in new_fields {
+                let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                differences(old_fields.get(key).unwrap_or(&Value::Null), new_value, &path, out);
+            }
+        }
+        _ if old == new => {}
+        _ => out.push(format!("{}: {} -> {}", path, old, new)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_get_nested_setting() {
+        let config = SystemConfig::default();
+        assert_eq!(get(&config, "shakedown.minutes").unwrap(), serde_json::json!(10));
+        assert_eq!(get(&config, "").unwrap(), serde_json::to_value(&config).unwrap());
+        assert!(get(&config, "shakedown.hours").is_err());
+    }
+    
+    #[test]
+    fn test_set_parses_units_and_types() {
+        let config = SystemConfig::default();
+        assert_eq!(set(&config, "max_boost_psi", "0.9 bar").unwrap().max_boost_psi, 13.05);
+        assert_eq!(set(&config, "spring_pressure", "42kPa").unwrap().spring_pressure, 6.09);
+        assert_eq!(set(&config, "aggression", "0.5").unwrap().aggression, 0.5);
+        assert!(!set(&config, "shakedown.enabled", "false").unwrap().shakedown.enabled);
+        assert_eq!(set(&config, "control_mode", "BoostByTarget").unwrap().control_mode, rumbledome_core::ControlMode::BoostByTarget);
+        
+        // A unit on something that is not a pressure, a wrong type, an out-of-range value
+        assert!(set(&config, "aggression", "1bar").is_err());
+        assert!(set(&config, "shakedown.minutes", "soon").is_err());
+        assert!(set(&config, "aggression", "2.0").is_err());
+    }
+    
+    #[test]
+    fn test_import_refuses_unknown_settings() {
+        let exported = export(&SystemConfig::default()).unwrap();
+        assert_eq!(import(&exported).unwrap(), SystemConfig::default());
+        
+        let mut value: Value = serde_json::from_str(&exported).unwrap();
+        value["$schema"] = serde_json::json!("./system-config.schema.json");
+        assert!(import(&value.to_string()).is_ok());
+        value["shakedown"]["hours"] = serde_json::json!(2);
+        assert_eq!(import(&value.to_string()).unwrap_err().to_string(), "Unknown setting shakedown.hours");
+    }
+    
+    #[test]
+    fn test_safety_relevant_changes_need_confirmation() {
+        let running = SystemConfig::default();
+        let raised = set(&running, "max_boost_psi", "13").unwrap();
+        assert_eq!(changes(&running, &raised).unwrap(), vec!["max_boost_psi: 12.0 -> 13.0".to_string()]);
+        assert!(require_confirmation(&running, &raised, false).is_err());
+        assert!(require_confirmation(&running, &raised, true).is_ok());
+        
+        let longer = set(&running, "shakedown.minutes", "20").unwrap();
+        assert!(require_confirmation(&running, &longer, false).is_ok());
+    }
+    
+    #[test]
+    fn test_fetch_running_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        assert_eq!(fetch_running(&mut exchange).unwrap(), SystemConfig::default());
+    }
+}
@@ -0,0 +1,350 @@
+//! Device Connections
+//! 
+//! 🔗 T4-CLI-005: Device Discovery and Selection
+//! Derived From: Protocols.md Communication Transport + T4-PROTOCOL-003 (Request Timeout and Retry) + T4-CORE-062 (Device Identification)
+//! AI Traceability: Find controllers on serial ports and TCP endpoints, pick one by name for users with more than one
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+use log::debug;
+use serialport::SerialPortType;
+use rumbledome_protocol::{
+    encode_frame, DeviceIdentity, FrameDecoder, ProtocolMessage, RequestSequencer, RequestTracker, RetryAction,
+    RetryPolicy, MAX_REQUEST_BYTES, MAX_RESPONSE_BYTES, PROTOCOL_VERSION,
+};
+
+/// Serial link settings (Protocols.md: 115200 8N1, no flow control)
+pub const SERIAL_BAUD_RATE: u32 = 115_200;
+
+/// USB vendor ID of the controller's native USB serial port (Teensy 4.1)
+pub const USB_VENDOR_ID: u16 = 0x16C0;
+
+/// USB product ID of the controller's native USB serial port (Teensy USB serial)
+pub const USB_PRODUCT_ID: u16 = 0x0483;
+
+/// Longest wait for a TCP connection to be accepted
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Blocking read granularity - retry timeouts are checked between reads
+const READ_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Retry policy while scanning - most ports are not a RumbleDome, so give up quickly
+fn probe_policy() -> RetryPolicy {
+    RetryPolicy { attempt_timeout_ms: 500, max_attempts: 2 }
+}
+
+/// Where a device can be reached
+#[derive(Debug, Clone, PartialEq)]
+pub enum Endpoint {
+    /// Serial port path (USB serial or paired Bluetooth SPP port)
+    Serial(String),
+    /// TCP address (host:port), e.g. a serial-over-network bridge
+    Tcp(String),
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Endpoint::Serial(path) => write!(f, "{}", path),
+            Endpoint::Tcp(address) => write!(f, "tcp://{}", address),
+        }
+    }
+}
+
+/// Byte stream to a device
+trait Stream: Read + Write {}
+
+impl<T: Read + Write> Stream for T {}
+
+/// Open request/response connection to one device
+pub struct Connection {
+    endpoint: Endpoint,
+    stream: Box<dyn Stream>,
+    decoder: FrameDecoder,
+    /// Decoded messages not yet returned by `receive`
+    received: VecDeque<ProtocolMessage>,
+    tracker: RequestTracker,
+    sequencer: RequestSequencer,
+    opened: Instant,
+    identity: DeviceIdentity,
+}
+
+impl Connection {
+    /// Open an endpoint and identify the device behind it
+    pub fn open(endpoint: &Endpoint, policy: RetryPolicy) -> Result<Self, Box<dyn Error>> {
+        let stream: Box<dyn Stream> = match endpoint {
+            Endpoint::Serial(path) => Box::new(
+                serialport::new(path, SERIAL_BAUD_RATE).timeout(READ_TIMEOUT).open()?
+            ),
+            Endpoint::Tcp(address) => {
+                let address: SocketAddr = address.parse()?;
+                let stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+                stream.set_read_timeout(Some(READ_TIMEOUT))?;
+                Box::new(stream)
+            }
+        };
+        
+        let mut connection = Self {
+            endpoint: endpoint.clone(),
+            stream,
+            decoder: FrameDecoder::new(MAX_RESPONSE_BYTES),
+            received: VecDeque::new(),
+            tracker: RequestTracker::new(policy),
+            sequencer: RequestSequencer::default(),
+            opened: Instant::now(),
+            // Filled in from the hello response
+            identity: DeviceIdentity::default(),
+        };
+        
+        match connection.request(ProtocolMessage::Hello)? {
+            ProtocolMessage::Identity { protocol_version, identity } if protocol_version == PROTOCOL_VERSION => {
+                connection.identity = identity;
+                Ok(connection)
+            }
+            ProtocolMessage::Identity { protocol_version, identity } => Err(format!(
+                "\"{}\" speaks protocol v{}, this CLI speaks v{} - update the {}",
+                identity.device_name, protocol_version, PROTOCOL_VERSION,
+                if protocol_version > PROTOCOL_VERSION { "CLI" } else { "firmware" },
+            ).into()),
+            other => Err(format!("Unexpected hello response: {:?}", other).into()),
+        }
+    }
+    
+    /// Where this device was reached
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+    
+    /// Identification the device sent in its hello response
+    pub fn identity(&self) -> &DeviceIdentity {
+        &self.identity
+    }
+    
+    /// Send a request and wait for its response, resending on timeout
+    /// 
+    /// Messages that do not answer the request (e.g. a screen stream frame) are
+    /// discarded. Mutating requests are sequenced so a resend is applied once.
+    pub fn request(&mut self, request: ProtocolMessage) -> Result<ProtocolMessage, Box<dyn Error>> {
+        let request = self.sequencer.prepare(request);
+        self.tracker.begin(request.clone(), self.now_ms()).map_err(|e| format!("{:?}", e))?;
+        self.send(&request)?;
+        
+        let mut buffer = [0u8; 512];
+        loop {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => return Err(format!("{} closed the connection", self.endpoint).into()),
+                Ok(len) => {
+                    for message in self.decoder.push(&buffer[..len]) {
+                        if self.tracker.accept(&message) {
+                            return Ok(message);
+                        }
+                    }
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock | ErrorKind::Interrupted) => {}
+                Err(e) => return Err(e.into()),
+            }
+            
+            match self.tracker.poll(self.now_ms()) {
+                Some(RetryAction::Resend(message)) => self.send(&message)?,
+                Some(RetryAction::GiveUp(_)) => return Err(format!("No response from {}", self.endpoint).into()),
+                None => {}
+            }
+        }
+    }
+    
+    /// Next message the device sends on its own (e.g. a stream frame), waiting up to `timeout`
+    pub fn receive(&mut self, timeout: Duration) -> Result<Option<ProtocolMessage>, Box<dyn Error>> {
+        let deadline = Instant::now() + timeout;
+        let mut buffer = [0u8; 512];
+        while self.received.is_empty() && Instant::now() < deadline {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => return Err(format!("{} closed the connection", self.endpoint).into()),
+                Ok(len) => self.received.extend(self.decoder.push(&buffer[..len])),
+                Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock | ErrorKind::Interrupted) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(self.received.pop_front())
+    }
+    
+    fn send(&mut self, message: &ProtocolMessage) -> Result<(), Box<dyn Error>> {
+        let frame = encode_frame(message, MAX_REQUEST_BYTES).map_err(|e| format!("{:?}", e))?;
+        self.stream.write_all(&frame)?;
+        self.stream.flush()?;
+        Ok(())
+    }
+    
+    fn now_ms(&self) -> u32 {
+        self.opened.elapsed().as_millis() as u32
+    }
+}
+
+/// Device found while scanning
+#[derive(Debug, Clone)]
+pub struct DiscoveredDevice {
+    /// Where the device answered
+    pub endpoint: Endpoint,
+    /// Identification from its hello response
+    pub identity: DeviceIdentity,
+}
+
+/// Whether a serial port could be a RumbleDome
+/// 
+/// USB ports are recognized by their vendor and product ID, so other USB
+/// serial adapters (GPS receivers, OBD dongles) are never sent a hello. Ports
+/// without USB IDs - paired Bluetooth SPP ports - can only be probed.
+fn may_be_device(port_type: &SerialPortType) -> bool {
+    match port_type {
+        SerialPortType::UsbPort(usb) => usb.vid == USB_VENDOR_ID && usb.pid == USB_PRODUCT_ID,
+        _ => true,
+    }
+}
+
+/// Serial ports on this machine that could be a RumbleDome plus the given TCP endpoints
+pub fn candidate_endpoints(tcp: &[String]) -> Vec<Endpoint> {
+    let ports = serialport::available_ports().unwrap_or_else(|e| {
+        debug!("Serial port enumeration failed: {}", e);
+        Vec::new()
+    });
+    
+    ports.into_iter()
+        .filter(|port| {
+            let candidate = may_be_device(&port.port_type);
+            if !candidate {
+                debug!("Skipping {}: not a RumbleDome USB ID", port.port_name);
+            }
+            candidate
+        })
+        .map(|port| Endpoint::Serial(port.port_name))
+        .chain(tcp.iter().cloned().map(Endpoint::Tcp))
+        .collect()
+}
+
+/// Probe each endpoint with a hello; endpoints that do not answer are skipped
+pub fn discover(endpoints: &[Endpoint]) -> Vec<DiscoveredDevice> {
+    endpoints.iter()
+        .filter_map(|endpoint| match Connection::open(endpoint, probe_policy()) {
+            Ok(connection) => Some(DiscoveredDevice {
+                endpoint: endpoint.clone(),
+                identity: connection.identity,
+            }),
+            Err(e) => {
+                debug!("No RumbleDome on {}: {}", endpoint, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Pick the device to talk to
+/// 
+/// Without a name the only device found is used. Names are matched without
+/// regard to case.
+pub fn select<'a>(devices: &'a [DiscoveredDevice], name: Option<&str>) -> Result<&'a DiscoveredDevice, String> {
+    let names = || devices.iter()
+        .map(|device| format!("\"{}\"", device.identity.device_name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    
+    let Some(name) = name else {
+        return match devices {
+            [] => Err("No RumbleDome found".to_string()),
+            [device] => Ok(device),
+            _ => Err(format!("Found {} devices ({}) - choose one with --device <name>", devices.len(), names())),
+        };
+    };
+    
+    let matching: Vec<_> = devices.iter()
+        .filter(|device| device.identity.device_name.eq_ignore_ascii_case(name))
+        .collect();
+    match matching.as_slice() {
+        [] if devices.is_empty() => Err("No RumbleDome found".to_string()),
+        [] => Err(format!("No device named \"{}\" (found {})", name, names())),
+        [device] => Ok(device),
+        _ => Err(format!(
+            "{} devices are named \"{}\" - give each a unique name with `rumbledome-cli name`",
+            matching.len(), name
+        )),
+    }
+}
+
+/// Connect to the selected device
+/// 
+/// With a serial port given there is no scan: the port is opened directly,
+/// whatever its USB IDs (e.g. a device behind a USB-serial adapter), and a
+/// name, if also given, must match the device found there.
+pub fn connect(name: Option<&str>, port: Option<&str>, tcp: &[String]) -> Result<Connection, Box<dyn Error>> {
+    if let Some(port) = port {
+        let connection = Connection::open(&Endpoint::Serial(port.to_string()), RetryPolicy::default())?;
+        let found = &connection.identity().device_name;
+        return match name {
+            Some(name) if !found.eq_ignore_ascii_case(name) =>
+                Err(format!("Device on {} is named \"{}\", not \"{}\"", port, found, name).into()),
+            _ => Ok(connection),
+        };
+    }
+    
+    let devices = discover(&candidate_endpoints(tcp));
+    let device = select(&devices, name)?;
+    Connection::open(&device.endpoint, RetryPolicy::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_protocol::FirmwareInfo;
+    
+    fn device(name: &str, port: &str) -> DiscoveredDevice {
+        DiscoveredDevice {
+            endpoint: Endpoint::Serial(port.to_string()),
+            identity: DeviceIdentity {
+                device_name: name.to_string(),
+                firmware: FirmwareInfo::default(),
+                active_configuration: "default".to_string(),
+            },
+        }
+    }
+    
+    #[test]
+    fn test_select_by_name() {
+        let devices = [device("Mustang", "/dev/ttyACM0"), device("F150", "/dev/ttyACM1")];
+        
+        assert_eq!(select(&devices, Some("f150")).unwrap().endpoint, Endpoint::Serial("/dev/ttyACM1".to_string()));
+        assert!(select(&devices, Some("Bronco")).unwrap_err().contains("\"Mustang\", \"F150\""));
+        assert!(select(&devices, None).unwrap_err().contains("--device"));
+    }
+    
+    #[test]
+    fn test_single_device_needs_no_name() {
+        let devices = [device("RumbleDome", "/dev/ttyACM0")];
+        assert!(select(&devices, None).is_ok());
+        assert!(select(&[], None).is_err());
+    }
+    
+    #[test]
+    fn test_only_rumbledome_usb_ports_are_probed() {
+        assert!(may_be_device(&SerialPortType::BluetoothPort));
+        assert!(may_be_device(&SerialPortType::Unknown));
+        
+        let usb = |vid, pid| SerialPortType::UsbPort(serialport::UsbPortInfo {
+            vid,
+            pid,
+            serial_number: None,
+            manufacturer: None,
+            product: None,
+        });
+        assert!(may_be_device(&usb(USB_VENDOR_ID, USB_PRODUCT_ID)));
+        assert!(!may_be_device(&usb(0x0403, 0x6001)));
+    }
+    
+    #[test]
+    fn test_duplicate_names_are_ambiguous() {
+        let devices = [device("RumbleDome", "/dev/ttyACM0"), device("RumbleDome", "/dev/ttyACM1")];
+        assert!(select(&devices, Some("RumbleDome")).unwrap_err().contains("unique name"));
+    }
+}
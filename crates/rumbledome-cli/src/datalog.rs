@@ -0,0 +1,50 @@
+//! SD Card Datalog Control
+//! 
+//! 🔗 T4-CLI-020: Datalog Start, Stop and Status
+//! Derived From: T4-CORE-138 (Rotating SD Card Datalogs)
+//! AI Traceability: Log a whole drive to the card from the laptop, then pull the card to read it
+
+use std::error::Error;
+
+use rumbledome_core::{DatalogStatus, DatalogTrigger};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Send a datalog request and return the state it reports
+pub fn send(exchange: &mut Exchange, request: ProtocolMessage) -> Result<DatalogStatus, Box<dyn Error>> {
+    match exchange(request)? {
+        ProtocolMessage::DatalogStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected datalog response: {:?}", other).into()),
+    }
+}
+
+/// One-line datalog summary
+pub fn format_status(status: &DatalogStatus) -> String {
+    let logging = match (&status.file, status.trigger) {
+        (Some(file), Some(DatalogTrigger::Wot)) => format!("Logging {} until the throttle lifts ({} KB)", file, status.file_bytes / 1024),
+        (Some(file), _) => format!("Logging {} until stopped ({} KB)", file, status.file_bytes / 1024),
+        (None, _) if !status.card_present => String::from("Not logging - no SD card"),
+        (None, _) => String::from("Not logging"),
+    };
+    format!("{} - {} rows in {} files, {} write errors", logging, status.rows, status.files_opened, status.write_errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_start_and_stop_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        let status = send(&mut exchange, ProtocolMessage::StartDatalog).unwrap();
+        assert_eq!(format_status(&status), "Logging RUMBLEDOME/logs/00001.csv until stopped (0 KB) - 0 rows in 1 files, 0 write errors");
+        let status = send(&mut exchange, ProtocolMessage::StopDatalog).unwrap();
+        assert_eq!(format_status(&status), "Not logging - 0 rows in 1 files, 0 write errors");
+    }
+}
@@ -0,0 +1,320 @@
+//! DBC Signal Import
+//! 
+//! 🔗 T4-CLI-008: DBC Import for CAN Signal Maps
+//! Derived From: T4-CORE-090 (CAN Signal Map) + T4-CLI-001 (Configuration Management Tool)
+//! AI Traceability: Users with their platform's DBC configure torque/RPM sources without hand-computing bit offsets
+//! 
+//! Only message (`BO_`) and signal (`SG_`) definitions are read - everything
+//! else in a DBC file (nodes, attributes, comments, value tables) is skipped.
+
+use std::error::Error;
+use std::fs;
+
+use rumbledome_core::{ByteOrder, CanSignal, CanSignalMap, MAX_CAN_ID};
+use rumbledome_hal::PSI_PER_KPA;
+
+/// Flag DBC files set on extended (29-bit) message identifiers
+const DBC_EXTENDED_ID_FLAG: u32 = 0x8000_0000;
+
+/// PSI per bar
+pub const PSI_PER_BAR: f32 = 14.5038;
+
+/// Signal definition read from a DBC file
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcSignal {
+    /// Signal name
+    pub name: String,
+    /// Location and scaling
+    pub signal: CanSignal,
+    /// Present only for some multiplexer values
+    pub multiplexed: bool,
+    /// Engineering unit
+    pub unit: String,
+}
+
+/// Message definition read from a DBC file
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcMessage {
+    /// Identifier (extended flag removed)
+    pub id: u32,
+    /// Message name
+    pub name: String,
+    /// Signals in definition order
+    pub signals: Vec<DbcSignal>,
+}
+
+/// Parsed DBC database
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dbc {
+    pub messages: Vec<DbcMessage>,
+}
+
+impl Dbc {
+    /// Parse DBC text
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let mut dbc = Dbc::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let located = |e: Box<dyn Error>| -> Box<dyn Error> { format!("DBC line {}: {}", index + 1, e).into() };
+            
+            if let Some(definition) = line.strip_prefix("BO_ ") {
+                dbc.messages.push(parse_message(definition).map_err(located)?);
+            } else if let Some(definition) = line.strip_prefix("SG_ ") {
+                let mut signal = parse_signal(definition).map_err(located)?;
+                let Some(message) = dbc.messages.last_mut() else {
+                    return Err(located(format!("Signal {} appears before any message", signal.name).into()));
+                };
+                signal.signal.message_id = message.id;
+                message.signals.push(signal);
+            }
+        }
+        Ok(dbc)
+    }
+    
+    /// Load and parse a DBC file
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+    
+    /// Look up a signal by `MESSAGE.SIGNAL`, where the message is a name or an ID (e.g. `0x167`)
+    pub fn find(&self, selector: &str) -> Result<&DbcSignal, Box<dyn Error>> {
+        let Some((message_name, signal_name)) = selector.split_once('.') else {
+            return Err(format!("Signal \"{}\" must be given as MESSAGE.SIGNAL", selector).into());
+        };
+        let id = parse_id(message_name).ok();
+        let message = self.messages.iter()
+            .find(|message| message.name == message_name || Some(message.id) == id)
+            .ok_or_else(|| format!("No message \"{}\" in the DBC file", message_name))?;
+        let signal = message.signals.iter()
+            .find(|signal| signal.name == signal_name)
+            .ok_or_else(|| format!("Message {} has no signal \"{}\"", message.name, signal_name))?;
+        
+        // The signal map has no notion of multiplexer values
+        if signal.multiplexed {
+            return Err(format!("{} is multiplexed and cannot be used as a control input", selector).into());
+        }
+        Ok(signal)
+    }
+}
+
+fn parse_id(text: &str) -> Result<u32, Box<dyn Error>> {
+    let id = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => text.parse()?,
+    };
+    Ok(id)
+}
+
+/// `<id> <name>: <dlc> <sender>`
+fn parse_message(definition: &str) -> Result<DbcMessage, Box<dyn Error>> {
+    let mut fields = definition.split_whitespace();
+    let id: u32 = fields.next().ok_or("Message ID missing")?.parse()?;
+    let name = fields.next().ok_or("Message name missing")?.trim_end_matches(':');
+    
+    let id = id & !DBC_EXTENDED_ID_FLAG;
+    if id > MAX_CAN_ID {
+        return Err(format!("Message ID {} is out of range", id).into());
+    }
+    Ok(DbcMessage { id, name: name.into(), signals: Vec::new() })
+}
+
+/// `<name> [M|m<n>] : <start>|<length>@<order><sign> (<scale>,<offset>) [<min>|<max>] "<unit>" <receivers>`
+fn parse_signal(definition: &str) -> Result<DbcSignal, Box<dyn Error>> {
+    let (head, layout) = definition.split_once(':').ok_or("Signal definition missing ':'")?;
+    let mut head = head.split_whitespace();
+    let name = head.next().ok_or("Signal name missing")?;
+    let multiplexed = head.next().is_some_and(|indicator| indicator.starts_with('m'));
+    
+    let mut fields = layout.split_whitespace();
+    let position = fields.next().ok_or("Signal position missing")?;
+    let (start_bit, rest) = position.split_once('|').ok_or("Signal start bit missing")?;
+    let (length, format) = rest.split_once('@').ok_or("Signal byte order missing")?;
+    let byte_order = match format.chars().next() {
+        Some('1') => ByteOrder::LittleEndian,
+        Some('0') => ByteOrder::BigEndian,
+        _ => return Err(format!("Unknown byte order in \"{}\"", position).into()),
+    };
+    let signed = match format.chars().nth(1) {
+        Some('-') => true,
+        Some('+') => false,
+        _ => return Err(format!("Unknown value type in \"{}\"", position).into()),
+    };
+    
+    let factor = fields.next().ok_or("Signal scaling missing")?;
+    let (scale, offset) = factor.trim_start_matches('(').trim_end_matches(')')
+        .split_once(',').ok_or("Signal scaling must be (scale,offset)")?;
+    
+    let unit = layout.split('"').nth(1).unwrap_or("");
+    
+    Ok(DbcSignal {
+        name: name.into(),
+        signal: CanSignal {
+            message_id: 0,
+            start_bit: start_bit.parse()?,
+            length: length.parse()?,
+            byte_order,
+            signed,
+            scale: scale.parse()?,
+            offset: offset.parse()?,
+        },
+        multiplexed,
+        unit: unit.into(),
+    })
+}
+
+/// Signals selected for each control input (`MESSAGE.SIGNAL`)
+#[derive(Debug, Clone, Default)]
+pub struct SignalSelection {
+    pub rpm: Option<String>,
+    pub desired_torque: Option<String>,
+    pub actual_torque: Option<String>,
+    pub manifold_pressure: Option<String>,
+}
+
+impl SignalSelection {
+    /// Whether no input was selected
+    pub fn is_empty(&self) -> bool {
+        self.rpm.is_none() && self.desired_torque.is_none()
+            && self.actual_torque.is_none() && self.manifold_pressure.is_none()
+    }
+}
+
+/// Build signal map entries for the selected signals, replacing those entries in `map`
+/// 
+/// Manifold pressure is converted to PSI when the DBC gives kPa or bar.
+/// Whether it is absolute is not recorded in DBC files - the caller warns.
+pub fn import(dbc: &Dbc, selection: &SignalSelection, map: &mut CanSignalMap) -> Result<(), Box<dyn Error>> {
+    let lookup = |selector: &Option<String>| -> Result<Option<&DbcSignal>, Box<dyn Error>> {
+        selector.as_deref().map(|selector| dbc.find(selector)).transpose()
+    };
+    
+    let mut updated = map.clone();
+    if let Some(signal) = lookup(&selection.rpm)? {
+        updated.rpm = Some(signal.signal.clone());
+    }
+    if let Some(signal) = lookup(&selection.desired_torque)? {
+        updated.desired_torque = Some(signal.signal.clone());
+    }
+    if let Some(signal) = lookup(&selection.actual_torque)? {
+        updated.actual_torque = Some(signal.signal.clone());
+    }
+    if let Some(signal) = lookup(&selection.manifold_pressure)? {
+        let factor = match signal.unit.to_ascii_lowercase().as_str() {
+            "kpa" => PSI_PER_KPA,
+            "bar" => PSI_PER_BAR,
+            "psi" | "" => 1.0,
+            unit => return Err(format!("Manifold pressure unit \"{}\" is not supported", unit).into()),
+        };
+        let mut converted = signal.signal.clone();
+        converted.scale *= factor;
+        converted.offset *= factor;
+        updated.manifold_pressure = Some(converted);
+    }
+    
+    updated.validate().map_err(|e| format!("{:?}", e))?;
+    *map = updated;
+    Ok(())
+}
+
+/// Print every message and signal in the DBC file
+pub fn print_messages(dbc: &Dbc) {
+    for message in &dbc.messages {
+        println!("0x{:03X} {}", message.id, message.name);
+        for signal in &message.signals {
+            let order = match signal.signal.byte_order {
+                ByteOrder::LittleEndian => "Intel",
+                ByteOrder::BigEndian => "Motorola",
+            };
+            let multiplexed = if signal.multiplexed { " (multiplexed)" } else { "" };
+            println!("    {:<28} bit {:>2} len {:>2} {:<8} x{} {:+} {}{}",
+                signal.name, signal.signal.start_bit, signal.signal.length, order,
+                signal.signal.scale, signal.signal.offset, signal.unit, multiplexed);
+        }
+    }
+}
+
+/// Print the signal map entries
+pub fn print_map(map: &CanSignalMap) {
+    for (name, signal) in map.signals() {
+        println!("  {:<18} 0x{:03X} bit {:>2} len {:>2} {:?} x{} {:+}",
+            name, signal.message_id, signal.start_bit, signal.length, signal.byte_order, signal.scale, signal.offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const DBC: &str = r#"
+VERSION ""
+
+BU_: PCM ABS
+
+BO_ 359 EngineTorque: 8 PCM
+ SG_ TorqueDesired : 7|12@0+ (0.5,-500) [-500|1547.5] "Nm" ABS
+ SG_ TorqueActual : 23|12@0+ (0.5,-500) [-500|1547.5] "Nm" ABS
+
+BO_ 516 EngineData: 8 PCM
+ SG_ EngineSpeed : 0|16@1+ (0.25,0) [0|16383.75] "rpm" ABS
+ SG_ Mux M : 16|4@1+ (1,0) [0|15] "" ABS
+ SG_ ManifoldPressure m1 : 24|8@1+ (1,0) [0|255] "kPa" ABS
+ SG_ ManifoldAbsolute : 32|8@1+ (2,0) [0|510] "kPa" ABS
+
+BO_ 2566844901 Extended: 8 PCM
+ SG_ Temp : 0|8@1- (1,0) [-128|127] "degC" ABS
+
+CM_ SG_ 359 TorqueDesired "Driver-demanded torque";
+"#;
+    
+    #[test]
+    fn test_parses_messages_and_signals() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        assert_eq!(dbc.messages.len(), 3);
+        
+        let torque = &dbc.messages[0];
+        assert_eq!((torque.id, torque.name.as_str(), torque.signals.len()), (0x167, "EngineTorque", 2));
+        let desired = &torque.signals[0].signal;
+        assert_eq!((desired.message_id, desired.start_bit, desired.length), (0x167, 7, 12));
+        assert_eq!((desired.byte_order, desired.scale, desired.offset), (ByteOrder::BigEndian, 0.5, -500.0));
+        
+        assert!(dbc.messages[1].signals[2].multiplexed);
+        assert!(!dbc.messages[1].signals[1].multiplexed);
+        
+        // Extended flag stripped from the identifier
+        assert_eq!(dbc.messages[2].id, 0x18FEF1E5);
+        assert!(dbc.messages[2].signals[0].signal.signed);
+    }
+    
+    #[test]
+    fn test_import_builds_signal_map() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let selection = SignalSelection {
+            rpm: Some("EngineData.EngineSpeed".into()),
+            desired_torque: Some("0x167.TorqueDesired".into()),
+            manifold_pressure: Some("EngineData.ManifoldAbsolute".into()),
+            ..Default::default()
+        };
+        let mut map = CanSignalMap::default();
+        import(&dbc, &selection, &mut map).unwrap();
+        
+        assert_eq!(map.rpm.as_ref().unwrap().decode(&[0x80, 0x3E]), Some(4000.0));
+        assert_eq!(map.desired_torque.as_ref().unwrap().message_id, 0x167);
+        assert!(map.actual_torque.is_none());
+        // kPa converted to PSI
+        let pressure = map.manifold_pressure.as_ref().unwrap();
+        assert!((pressure.scale - 2.0 * PSI_PER_KPA).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn test_unusable_selections_are_refused() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let mut map = CanSignalMap::default();
+        for selector in ["EngineSpeed", "Nope.EngineSpeed", "EngineData.Nope", "EngineData.ManifoldPressure"] {
+            let selection = SignalSelection { rpm: Some(selector.into()), ..Default::default() };
+            assert!(import(&dbc, &selection, &mut map).is_err(), "{}", selector);
+        }
+        assert_eq!(map, CanSignalMap::default());
+        
+        assert!(Dbc::parse(" SG_ Orphan : 0|8@1+ (1,0) [0|255] \"\" PCM").is_err());
+    }
+}
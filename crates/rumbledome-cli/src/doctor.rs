@@ -0,0 +1,362 @@
+//! System Health Check
+//! 
+//! 🔗 T4-CLI-003: Doctor Diagnostics
+//! Derived From: T4-CORE-009 (System Status Reporting) + Safety.md fault response hierarchy
+//! AI Traceability: One-shot plain-language summary of everything needing attention
+
+use std::error::Error;
+use std::fs;
+
+use rumbledome_core::{
+    AlertSeverity, CanHealthLevel, CommissioningStep, MapZeroAdvisory, OutputDegradation, StepOutcome, SystemState,
+    SystemStatus,
+};
+
+/// One diagnostic finding
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// How urgent the finding is
+    pub severity: AlertSeverity,
+    /// What was found
+    pub message: String,
+    /// What the user should do about it
+    pub action: Option<String>,
+}
+
+impl Finding {
+    fn new(severity: AlertSeverity, message: impl Into<String>, action: Option<&str>) -> Self {
+        Self { severity, message: message.into(), action: action.map(String::from) }
+    }
+}
+
+/// Load a system status snapshot saved as JSON
+pub fn load_snapshot(path: &str) -> Result<SystemStatus, Box<dyn Error>> {
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Inspect a status snapshot, most severe findings first
+pub fn diagnose(status: &SystemStatus) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    
+    match &status.state {
+        SystemState::Fault(fault) => {
+            let severity = if fault.is_critical() { AlertSeverity::Critical } else { AlertSeverity::Warning };
+            findings.push(Finding {
+                severity,
+                message: format!("{}: {}", fault.display_code(), fault.description()),
+                action: Some(fault.recommended_action()),
+            });
+        }
+        SystemState::OverboostCut => findings.push(match &status.last_overboost {
+            Some(event) => Finding {
+                severity: AlertSeverity::Critical,
+                message: format!("Overboost protection active: {}", event.cause.description()),
+                action: Some(event.cause.recommended_action().to_string()),
+            },
+            None => Finding::new(
+                AlertSeverity::Critical,
+                "Overboost protection active",
+                Some("Reduce boost targets or check wastegate operation"),
+            ),
+        }),
+        _ => {}
+    }
+    
+    match status.output_stage.degradation {
+        OutputDegradation::Nominal => {}
+        OutputDegradation::ReducedAuthority => findings.push(Finding::new(
+            AlertSeverity::Warning,
+            "Upper dome solenoid failed - limp mode limited to spring pressure",
+            Some("Check upper dome solenoid wiring"),
+        )),
+        OutputDegradation::Failsafe => findings.push(Finding::new(
+            AlertSeverity::Critical,
+            "No usable solenoid output - boost control disabled",
+            Some("Check solenoid wiring and driver circuit"),
+        )),
+    }
+    
+    match status.can_health.level {
+        CanHealthLevel::Healthy => {}
+        CanHealthLevel::NoData => findings.push(Finding::new(
+            AlertSeverity::Warning,
+            "No CAN data received",
+            Some("Check CAN bus connections and ECU power"),
+        )),
+        level => findings.push(Finding::new(
+            if level == CanHealthLevel::Lost { AlertSeverity::Critical } else { AlertSeverity::Warning },
+            format!("CAN bus health {:?} (score {})", level, status.can_health.score),
+            Some("Check CAN wiring and termination"),
+        )),
+    }
+    
+    if status.map_source.mismatch_pending {
+        findings.push(Finding::new(
+            AlertSeverity::Warning,
+            format!("CAN and analog MAP disagree by {:.1} PSI", status.map_source.last_difference),
+            Some("Check analog sensor air line and CAN signal scaling"),
+        ));
+    }
+    
+    match status.map_zero.advisory {
+        Some(MapZeroAdvisory::DriftExceeded { drift_psi }) => findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("MAP sensor zero drifted {:+.2} PSI since installation", drift_psi),
+            Some("Sensor may be failing - replace it, then reset the MAP zero baseline"),
+        )),
+        Some(MapZeroAdvisory::ZeroOutOfRange { reading_psi }) => findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("MAP sensor read {:.1} PSI at atmosphere - zero not updated", reading_psi),
+            Some("Check sensor wiring and supply voltage"),
+        )),
+        None => {}
+    }
+    
+    if let Some(bank) = status.bank_balance.needs_more_duty {
+        findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("{} wastegate consistently needs more duty than the other bank", bank.display_text()),
+            Some("Check that bank's dome line, diaphragm and valve for leaks"),
+        ));
+    }
+    
+    if let Some(advisory) = status.actuation.advisory {
+        findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            advisory.description(),
+            Some(advisory.recommended_action()),
+        ));
+    }
+    
+    let protection = &status.turbine_protection;
+    if protection.interventions > 0 {
+        findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("Boost limited {} times for exhaust backpressure above {:.2}:1",
+                protection.interventions, status.config.backpressure.max_ratio),
+            Some("Check for a restricted catalytic converter or exhaust; persistent on every pull points to an undersized turbine"),
+        ));
+    }
+    
+    let rpm = &status.rpm_validation;
+    let rejected = rpm.rejected_rate + rpm.rejected_out_of_range;
+    if rejected > 0 {
+        findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("{} implausible CAN RPM samples rejected", rejected),
+            None,
+        ));
+    }
+    
+    if let Some(step) = status.commissioning.current_step {
+        findings.push(Finding {
+            severity: AlertSeverity::Warning,
+            message: "Commissioning not complete - boost control cannot be armed".to_string(),
+            action: Some(format!("Run the commission command (next step: {})", step.display_text())),
+        });
+    }
+    
+    for task in &status.maintenance_due {
+        findings.push(Finding::new(
+            AlertSeverity::Advisory,
+            format!("Maintenance due: {}", task),
+            Some("Acknowledge once done to restart the interval"),
+        ));
+    }
+    
+    findings.sort_by_key(|finding| match finding.severity {
+        AlertSeverity::Critical => 0,
+        AlertSeverity::Warning => 1,
+        AlertSeverity::Advisory => 2,
+    });
+    findings
+}
+
+/// Print a doctor report
+pub fn print_report(status: &SystemStatus) {
+    let usage = &status.usage;
+    println!("Device: {} (configuration \"{}\")", status.device_name, status.active_configuration);
+    println!("State: {}", status.state.display_text());
+    println!("Control mode: {}", status.control_mode.display_text());
+    println!("Actuator: {}", status.actuator_type.display_text());
+    println!("Usage: {} h powered, {} h boost active, {} solenoid actuations",
+        usage.powered_hours(), usage.boost_active_hours(), usage.actuation_cycles);
+    println!("Learning: {} samples accepted, {} rejected as transient or settling",
+        status.learning.accepted_samples, status.learning.rejected_samples);
+    match status.commissioning.current_step {
+        Some(step) => println!("Commissioning: step {}/{} next ({})",
+            step.number(), CommissioningStep::ALL.len(), step.display_text()),
+        None => {
+            let skipped = status.commissioning.record.steps
+                .iter()
+                .filter(|record| record.outcome == StepOutcome::Skipped)
+                .count();
+            println!("Commissioning: complete ({} steps skipped)", skipped);
+        }
+    }
+    if status.config.solenoid_exercise.enabled {
+        println!("Solenoid exercise: {} completed, {} cut short",
+            status.solenoid_exercise.completed, status.solenoid_exercise.aborted);
+    } else {
+        println!("Solenoid exercise: disabled");
+    }
+    if let Some(score) = status.tracking_score {
+        if status.gain_suggestions_withheld {
+            println!("Boost tracking: {}/100 this drive, tuning suggestions need cascaded dome control", score);
+        } else {
+            println!("Boost tracking: {}/100 this drive, {} tuning suggestions", score, status.gain_suggestions.len());
+        }
+    }
+    let ceilings = &status.duty_ceiling;
+    let learned_bands = ceilings.ceilings_percent.iter().filter(|ceiling| ceiling.is_some()).count();
+    if learned_bands > 0 {
+        println!("Duty ceiling: learned in {} of {} RPM bands, {} commands limited",
+            learned_bands, ceilings.ceilings_percent.len(), ceilings.limited_cycles);
+    }
+    if let Some(volts) = status.supply_voltage {
+        let factor = status.config.voltage_compensation.factor(Some(volts));
+        println!("Supply voltage: {:.1} V (solenoid duty x{:.2})", volts, factor);
+    }
+    let derate = &status.thermal_derate;
+    if derate.multiplier < 1.0 {
+        println!("Heat derate: boost range x{:.2} (intake air x{:.2}, coolant x{:.2})",
+            derate.multiplier, derate.intake_air_multiplier, derate.coolant_multiplier);
+    }
+    let protection = &status.turbine_protection;
+    if let Some(backpressure) = protection.backpressure_psi {
+        let ratio = protection.ratio.map(|ratio| format!("{:.2}:1", ratio)).unwrap_or_else(|| "-".to_string());
+        println!("Exhaust backpressure: {:.1} PSI (ratio to boost {}, limit {:.2}:1, {} pulls limited)",
+            backpressure, ratio, status.config.backpressure.max_ratio, protection.interventions);
+    }
+    if let Some(cap) = protection.cap_psi {
+        println!("Turbine protection: boost capped at {:.1} PSI", cap);
+    }
+    if status.soft_ceiling_active {
+        println!("Soft ceiling: tapering boost target within {:.1} PSI of the {:.1} PSI overboost limit",
+            status.config.soft_ceiling.approach_band_psi, status.config.overboost_limit);
+    }
+    for change in &status.pending_restart {
+        println!("Restart required: {} {} → {} {}",
+            change.parameter.display_text(), change.running, change.pending, change.parameter.unit());
+    }
+    let banks = &status.bank_balance;
+    if banks.layout.is_twin() {
+        println!("Turbo: {} (duty shortfall bank 1 {:+.1}%, bank 2 {:+.1}%)",
+            banks.layout.display_text(), banks.shortfall_percent[0], banks.shortfall_percent[1]);
+    }
+    if let (Some(effectiveness), Some(window)) = (status.actuation.effectiveness, status.actuation.last_window) {
+        println!("Actuation effectiveness: {:.2} (baseline {:.2}, duty/dome correlation {:.2}, domes hold {:.0}% of feed)",
+            effectiveness, status.actuation.baseline.unwrap_or(effectiveness), window.correlation, window.dome_retention * 100.0);
+    }
+    if let Some(event) = &status.last_overboost {
+        let frame = &event.freeze_frame;
+        println!("Last overboost: {:.1} PSI at {} RPM (target {:.1}, duty {:.0}%) - {}",
+            frame.manifold_pressure, frame.rpm, frame.target_boost_psi, frame.duty_percent, event.cause.description());
+    }
+    if !status.recent_events.is_empty() {
+        println!("Recent events:");
+        for event in &status.recent_events {
+            println!("  [{} ms] {}", event.timestamp_ms, event.event.description());
+        }
+    }
+    
+    let findings = diagnose(status);
+    if findings.is_empty() {
+        println!("✅ No problems found");
+        return;
+    }
+    
+    for finding in findings {
+        let marker = match finding.severity {
+            AlertSeverity::Critical => "❌",
+            AlertSeverity::Warning => "⚠️ ",
+            AlertSeverity::Advisory => "ℹ️ ",
+        };
+        println!("{} {}", marker, finding.message);
+        if let Some(action) = finding.action {
+            println!("   → {}", action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{BackpressureConfig, FreezeFrame, OverboostCause, RumbleDomeCore, SystemConfig, SystemInputs, UsageCounters};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_maintenance_due_is_reported_after_problems() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.usage.restore(UsageCounters { powered_ms: 200 * 3_600_000, ..Default::default() });
+        
+        let findings = diagnose(&core.get_system_status());
+        let last = findings.last().unwrap();
+        assert_eq!(last.severity, AlertSeverity::Advisory);
+        assert_eq!(last.message, "Maintenance due: Inspect dome lines");
+        
+        // No CAN traffic yet on a fresh core
+        assert_eq!(findings[0].severity, AlertSeverity::Warning);
+    }
+    
+    #[test]
+    fn test_uncommissioned_install_is_reported() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        let commissioning_warning = |core: &RumbleDomeCore<MockHal>| diagnose(&core.get_system_status())
+            .iter()
+            .any(|finding| finding.message.starts_with("Commissioning not complete"));
+        assert!(commissioning_warning(&core));
+        
+        core.commissioning.skip_remaining();
+        assert!(!commissioning_warning(&core));
+    }
+    
+    #[test]
+    fn test_backpressure_limit_is_reported() {
+        let config = SystemConfig {
+            backpressure: BackpressureConfig { enabled: true, sustain_ms: 0, ..BackpressureConfig::default() },
+            ..SystemConfig::default()
+        };
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.manifold_pressure = 10.0;
+        inputs.exhaust_backpressure_psi = Some(26.0);
+        core.turbine_protection.update(&inputs, 5.0);
+        
+        let findings = diagnose(&core.get_system_status());
+        assert!(findings.iter().any(|finding| finding.message == "Boost limited 1 times for exhaust backpressure above 2.00:1"));
+    }
+    
+    #[test]
+    fn test_overboost_reports_likely_cause() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        let frame = |timestamp_ms, duty| {
+            let inputs = SystemInputs {
+                rpm: 5200,
+                desired_torque: 450.0,
+                actual_torque: 440.0,
+                manifold_pressure: 14.0,
+                throttle_position: Some(90.0),
+                dome_input_pressure: 60.0,
+                upper_dome_pressure: 20.0,
+                lower_dome_pressure: 2.0,
+                intake_air_temp_c: None,
+                coolant_temp_c: None,
+                bank_dome_pressure: None,
+                exhaust_backpressure_psi: None,
+                aggression: 0.8,
+                scramble_active: false,
+                timestamp_ms,
+            };
+            FreezeFrame::capture(&inputs, 13.0, duty)
+        };
+        core.overboost.observe(&SystemState::Armed, frame(0, 96.0));
+        core.overboost.observe(&SystemState::OverboostCut, frame(10, 0.0));
+        core.state = SystemState::OverboostCut;
+        
+        let findings = diagnose(&core.get_system_status());
+        let expected = format!("Overboost protection active: {}", OverboostCause::ControlSaturated.description());
+        assert_eq!(findings[0].message, expected);
+    }
+}
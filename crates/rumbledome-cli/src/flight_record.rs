@@ -0,0 +1,112 @@
+//! Flight Recorder Offload
+//! 
+//! 🔗 T4-CLI-011: Flight Recorder Offload
+//! Derived From: T4-CORE-107 (EEPROM Flight Recorder) + T4-CLI-009 (Burst Log Offload)
+//! AI Traceability: After an incident, pull the EEPROM recording off the car and save it before more driving overwrites it
+
+use std::error::Error;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{FlightSample, FlightState};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Flight recording as written to disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightRecording {
+    /// Creation time (seconds since the Unix epoch)
+    pub created_unix_s: u64,
+    /// Decimated samples, oldest first
+    pub samples: Vec<FlightSample>,
+}
+
+impl FlightRecording {
+    /// Default output file name
+    pub fn default_file_name(&self) -> String {
+        format!("rumbledome-flight-{}.json", self.created_unix_s)
+    }
+}
+
+/// Pull the whole recording off the device, one block per request
+pub fn offload(exchange: &mut Exchange) -> Result<FlightRecording, Box<dyn Error>> {
+    let mut samples = Vec::new();
+    let mut offset = 0;
+    loop {
+        let chunk = match exchange(ProtocolMessage::GetFlightRecord { offset })? {
+            ProtocolMessage::FlightRecord(chunk) => chunk,
+            ProtocolMessage::Error(e) => return Err(e.into()),
+            other => return Err(format!("Unexpected flight record response: {:?}", other).into()),
+        };
+        if offset >= chunk.total {
+            break;
+        }
+        samples.extend(chunk.samples);
+        offset += 1;
+    }
+    
+    let created_unix_s = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    Ok(FlightRecording { created_unix_s, samples })
+}
+
+/// Write a recording as JSON
+pub fn write_recording(path: &str, recording: &FlightRecording) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string(recording)?)?;
+    Ok(())
+}
+
+/// Print each session's span and peaks, and where it left normal operation
+pub fn print_summary(recording: &FlightRecording) {
+    if recording.samples.is_empty() {
+        println!("Flight recording is empty");
+        return;
+    }
+    
+    for session in recording.samples.chunk_by(|a, b| a.session == b.session) {
+        let (first, last) = (&session[0], &session[session.len() - 1]);
+        let peak_boost = session.iter().map(|sample| sample.boost_psi).fold(f32::MIN, f32::max);
+        let peak_duty = session.iter().map(|sample| sample.duty_percent).fold(f32::MIN, f32::max);
+        println!(
+            "Session {}: {} samples, {:.0}-{:.0} s after power-up, peak {:.1} PSI at up to {:.1}% duty",
+            first.session, session.len(), first.timestamp_ms as f32 / 1000.0, last.timestamp_ms as f32 / 1000.0, peak_boost, peak_duty
+        );
+        for pair in session.windows(2) {
+            if pair[1].state != pair[0].state && matches!(pair[1].state, FlightState::OverboostCut | FlightState::Fault(_)) {
+                println!("  {:>7.1} s  {:?} at {:.1} PSI", pair[1].timestamp_ms as f32 / 1000.0, pair[1].state, pair[1].boost_psi);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{FlightRecorder, FlightRecorderConfig, SystemState};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_offload_whole_recording() {
+        // Device side: two full blocks in EEPROM and a third still being filled
+        let mut hal = MockHal::new();
+        let mut recorder = FlightRecorder::new(FlightRecorderConfig { sample_interval_ms: 1_000, ..Default::default() });
+        for t in (0..60_000).step_by(100) {
+            recorder.record(&mut hal, &SystemState::Armed, 6.0, 35.0, t);
+        }
+        assert_eq!(recorder.status().blocks_written, 2);
+        
+        let mut exchange = |message| Ok(match message {
+            ProtocolMessage::GetFlightRecord { offset } => ProtocolMessage::FlightRecord(recorder.chunk(&mut hal, offset).unwrap()),
+            _ => ProtocolMessage::Error("Unexpected".to_string()),
+        });
+        
+        let recording = offload(&mut exchange).unwrap();
+        assert_eq!(recording.samples.len(), 59);
+        assert_eq!(recording.samples.last().unwrap().timestamp_ms, 59_000);
+    }
+}
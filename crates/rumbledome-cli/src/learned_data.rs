@@ -0,0 +1,99 @@
+//! Learned Data Transfer
+//! 
+//! 🔗 T4-CLI-014: Learned Data Export, Import and Reset
+//! Derived From: T4-CORE-108 (Learned Duty Calibration Map) + T4-PROTOCOL-008 (COBS Framing with CRC)
+//! AI Traceability: Back up what the car has learned, carry it to a replacement controller, or start learning over
+//! 
+//! The file is the image exactly as the device stores it, CRC included, so a
+//! damaged or foreign file is refused by the device before anything changes.
+
+use std::error::Error;
+
+use rumbledome_core::{LearnedDataImport, LEARNED_DATA_CHUNK_BYTES};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Fetch the learned-data image
+pub fn export(exchange: &mut Exchange) -> Result<Vec<u8>, Box<dyn Error>> {
+    match exchange(ProtocolMessage::ExportLearnedData)? {
+        ProtocolMessage::LearnedDataImage { image } => Ok(image),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected learned data response: {:?}", other).into()),
+    }
+}
+
+/// Send an image in chunks; the device replaces its map after the last one
+pub fn import(exchange: &mut Exchange, image: &[u8]) -> Result<LearnedDataImport, Box<dyn Error>> {
+    let mut progress = LearnedDataImport::default();
+    for (index, chunk) in image.chunks(LEARNED_DATA_CHUNK_BYTES).enumerate() {
+        let offset = (index * LEARNED_DATA_CHUNK_BYTES) as u32;
+        progress = match exchange(ProtocolMessage::ImportLearnedData { offset, chunk: chunk.to_vec() })? {
+            ProtocolMessage::LearnedDataImported(progress) => progress,
+            ProtocolMessage::Error(e) => return Err(e.into()),
+            other => return Err(format!("Unexpected learned data response: {:?}", other).into()),
+        };
+    }
+    
+    if progress.received != progress.total {
+        return Err(format!("Learned data file holds {} of the {} bytes the device expects", progress.received, progress.total).into());
+    }
+    Ok(progress)
+}
+
+/// Forget everything the device has learned
+pub fn reset(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    match exchange(ProtocolMessage::ResetLearnedData)? {
+        ProtocolMessage::LearnedDataReset => Ok(()),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected learned data response: {:?}", other).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    /// Send a request, entering the code from the device display if asked
+    fn confirmed(device: &mut VirtualDevice, message: ProtocolMessage) -> ProtocolMessage {
+        match device.handle(message, 0) {
+            ProtocolMessage::ConfirmationRequired { .. } => {
+                let code = device.core().presence.display_code().unwrap();
+                device.handle(ProtocolMessage::ConfirmPresence { code }, 0)
+            },
+            response => response,
+        }
+    }
+    
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(confirmed(&mut device, message));
+        let image = export(&mut exchange).unwrap();
+        let progress = import(&mut exchange, &image).unwrap();
+        assert_eq!(progress.received as usize, image.len());
+        reset(&mut exchange).unwrap();
+    }
+    
+    #[test]
+    fn test_reset_waits_for_code_from_display() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut unconfirmed = |message| Ok(device.handle(message, 0));
+        assert!(reset(&mut unconfirmed).is_err());
+        assert!(device.core().presence.pending().is_some());
+    }
+    
+    #[test]
+    fn test_truncated_file_is_refused() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(confirmed(&mut device, message));
+        let image = export(&mut exchange).unwrap();
+        assert!(import(&mut exchange, &image[..200]).is_err());
+        
+        let mut damaged = image.clone();
+        damaged[20] ^= 0x01;
+        assert!(import(&mut exchange, &damaged).is_err());
+    }
+}
@@ -5,35 +5,417 @@
 //! Decision Type: 🔗 Direct Derivation - User configuration interface
 //! AI Traceability: Enables system configuration, diagnostics, calibration management
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
-use rumbledome_core::SystemConfig;
-use rumbledome_protocol::ProtocolMessage;
+use rumbledome_core::{validate_config_name, validate_device_name, ControlMode, Language, SystemConfig};
+use rumbledome_protocol::{ErrorCode, ProtocolMessage};
+use rumbledome_sim::{DryRunReport, VirtualDevice};
+
+mod analyze;
+mod burst;
+mod calibration;
+mod commissioning;
+mod config_edit;
+mod config_io;
+mod connection;
+mod datalog;
+mod dbc;
+mod doctor;
+mod flight_record;
+mod learned_data;
+mod monitor;
+mod safety_log;
+mod screen;
+mod spring_check;
+mod status;
+mod support_bundle;
+mod trends;
+mod tuning;
 
 #[derive(Parser)]
 #[command(name = "rumbledome-cli")]
 #[command(about = "Configuration tool for RumbleDome boost controller")]
 #[command(version = "0.1.0")]
 struct Cli {
+    /// Name of the device to talk to (required when more than one is found)
+    #[arg(short, long, global = true)]
+    device: Option<String>,
+    /// Serial port of the device (skips the scan, e.g. for a USB-serial adapter)
+    #[arg(long, global = true)]
+    port: Option<String>,
+    /// TCP endpoint (host:port) to scan in addition to serial ports
+    #[arg(long, global = true)]
+    tcp: Vec<String>,
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// List devices found on serial ports and TCP endpoints
+    Devices,
+    /// Assign a name to the device (to tell several controllers apart)
+    Name {
+        /// New device name
+        name: String,
+    },
+    /// Select the language of the device display
+    Language {
+        #[arg(value_enum)]
+        language: LanguageArg,
+    },
     /// Get current system status
     Status,
     /// Update system configuration  
+    #[command(args_conflicts_with_subcommands = true)]
     Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
         /// Configuration file path
         #[arg(short, long)]
         file: Option<String>,
+        /// Learned-data export to seed the simulation with
+        #[arg(long)]
+        learned_data: Option<String>,
+        /// Upload even if the simulated dry run fails
+        #[arg(long)]
+        force: bool,
+    },
+    /// Select how the boost target is produced (system must not be armed)
+    Mode {
+        /// Control mode
+        #[arg(value_enum)]
+        mode: ModeArg,
+    },
+    /// Manage named configurations (e.g. "stock turbo" vs "new turbo")
+    Configs {
+        #[command(subcommand)]
+        action: ConfigsAction,
+    },
+    /// Run the first-run commissioning wizard (required before boost control can be armed)
+    Commission {
+        /// Skip all remaining steps instead of running them
+        #[arg(long)]
+        skip: bool,
+        /// Find which input each pressure sensor is wired to (engine off)
+        #[arg(long, conflicts_with = "skip")]
+        map_sensors: bool,
+    },
+    /// Show this drive's boost tracking score and suggested gain changes
+    Tune {
+        #[arg(value_enum, default_value = "suggest")]
+        action: TuneArg,
+    },
+    /// Show where in the rev range boost assistance was needed and where it fought the ECU
+    Distribution {
+        /// Merge the recent sessions instead of showing this drive only
+        #[arg(long)]
+        all: bool,
+    },
+    /// Show weekly tracking error, overboost rate, learning churn and storage writes as sparklines
+    Trends {
+        /// Monthly records instead of weekly
+        #[arg(long)]
+        monthly: bool,
+    },
+    /// Start an auto-calibration session (system idle, commissioning complete)
+    Calibrate {
+        /// Show progress of the running session instead of starting one
+        #[arg(long, conflicts_with = "abort")]
+        status: bool,
+        /// Abandon the running session (learned cells are kept)
+        #[arg(long)]
+        abort: bool,
+    },
+    /// Start logging telemetry to the SD card (runs until stopped)
+    Datalog {
+        /// Show the datalog state instead of starting a log
+        #[arg(long, conflicts_with = "stop")]
+        status: bool,
+        /// Write out and close the running log
+        #[arg(long)]
+        stop: bool,
+    },
+    /// Report overshoot, settle time, boost error, duty saturation and overboost cuts per pull from a datalog file
+    Analyze {
+        /// Datalog file from the SD card (CSV or binary) or a `monitor --csv` file
+        file: String,
+        /// Boost target at which a pull starts (PSI)
+        #[arg(long, default_value = "2.0")]
+        min_target: f32,
+        /// Boost within this of target counts as settled (PSI)
+        #[arg(long, default_value = "0.5")]
+        settle_band: f32,
+    },
+    /// Measure the wastegate spring with one 0 %-duty pull and compare it with the configured spring pressure
+    SpringCheck {
+        /// Show progress or the last result instead of starting a check
+        #[arg(long, conflicts_with = "cancel")]
+        status: bool,
+        /// Abandon the running check (boost control resumes)
+        #[arg(long)]
+        cancel: bool,
+    },
+    /// Back up learned data to a file or load it onto a device
+    Learned {
+        #[command(subcommand)]
+        action: LearnedAction,
     },
-    /// Start calibration session
-    Calibrate,
     /// Reset learned data
-    Reset,
+    Reset {
+        /// Do not ask for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Check system health, faults and maintenance reminders
+    Doctor {
+        /// Inspect a saved status snapshot (JSON) instead of a live device
+        #[arg(short, long)]
+        snapshot: Option<String>,
+    },
+    /// Save a diagnostics snapshot to attach to bug reports
+    SupportBundle {
+        /// Output file (defaults to rumbledome-support-<time>.json)
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Gather from a simulated device instead of a live one (shows the bundle format)
+        #[arg(long)]
+        simulated: bool,
+        /// Summarize an existing bundle file instead of creating one
+        #[arg(long)]
+        inspect: Option<String>,
+    },
+    /// Capture every control value at the full loop rate for a few seconds (oscillation debugging)
+    Burst {
+        /// Capture length in seconds (at most 10)
+        #[arg(short, long, default_value = "10")]
+        seconds: f32,
+        /// Output file (defaults to rumbledome-burst-<time>.json)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Pull the always-on EEPROM flight recording off the device (incident review without an SD card)
+    FlightRecord {
+        /// Output file (defaults to rumbledome-flight-<time>.json)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Dump the overboost cuts and faults logged to EEPROM
+    Log {
+        /// Erase the log after printing it
+        #[arg(long)]
+        clear: bool,
+    },
+    /// Run the display demo to check gauge wiring and orientation on the bench (engine stopped)
+    Demo {
+        /// Stop the demo
+        #[arg(long)]
+        stop: bool,
+    },
+    /// Build the CAN signal map from a DBC file (lists its messages when no signal is selected)
+    Dbc {
+        /// DBC file path
+        file: String,
+        /// Engine speed signal (MESSAGE.SIGNAL, message by name or ID)
+        #[arg(long)]
+        rpm: Option<String>,
+        /// ECU desired torque signal (MESSAGE.SIGNAL)
+        #[arg(long)]
+        desired_torque: Option<String>,
+        /// ECU actual torque signal (MESSAGE.SIGNAL)
+        #[arg(long)]
+        actual_torque: Option<String>,
+        /// Manifold pressure signal (MESSAGE.SIGNAL)
+        #[arg(long)]
+        manifold_pressure: Option<String>,
+        /// Configuration file to write the signal map into (prints it when omitted)
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+    /// Mirror the on-device display in the terminal
+    Screen {
+        /// Render a saved screen description (JSON) instead of a live device
+        #[arg(short, long)]
+        snapshot: Option<String>,
+        /// Refresh interval when mirroring a live device (ms)
+        #[arg(short, long, default_value_t = 100)]
+        interval: u32,
+        /// Internal variables to show beneath the display (comma-separated names)
+        #[arg(short, long, value_delimiter = ',')]
+        watch: Vec<String>,
+        /// Stop after this many seconds (runs until interrupted otherwise)
+        #[arg(long)]
+        duration: Option<u64>,
+    },
+    /// Stream live boost, target, duty and torque gap from the device
+    Monitor {
+        /// Sample interval (ms, 50 or more)
+        #[arg(short, long, default_value_t = 100)]
+        interval: u32,
+        /// Redraw a dashboard in place instead of scrolling a table
+        #[arg(long)]
+        dashboard: bool,
+        /// Also log every sample to a CSV file
+        #[arg(long)]
+        csv: Option<String>,
+        /// Stop after this many seconds (runs until interrupted otherwise)
+        #[arg(long)]
+        duration: Option<u64>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ModeArg {
+    /// Follow the ECU torque request (requires CAN torque data)
+    TorqueFollowing,
+    /// Boost by RPM target curve - for ECUs without usable torque data
+    BoostByTarget,
+}
+
+impl From<ModeArg> for ControlMode {
+    fn from(mode: ModeArg) -> Self {
+        match mode {
+            ModeArg::TorqueFollowing => ControlMode::TorqueFollowing,
+            ModeArg::BoostByTarget => ControlMode::BoostByTarget,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LanguageArg {
+    /// English
+    En,
+    /// Spanish
+    Es,
+    /// German
+    De,
+}
+
+impl From<LanguageArg> for Language {
+    fn from(language: LanguageArg) -> Self {
+        match language {
+            LanguageArg::En => Language::English,
+            LanguageArg::Es => Language::Spanish,
+            LanguageArg::De => Language::German,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TuneArg {
+    /// Show the score and suggestions
+    Suggest,
+    /// Try the suggestions as live-tune test values (revert unless committed)
+    Apply,
+    /// Store the test values in effect
+    Commit,
+    /// Discard test values and restore stored tuning
+    Revert,
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Show a setting of the running configuration, by dotted path (all settings without one)
+    Get {
+        /// Setting, e.g. max_boost_psi or shakedown.minutes
+        key: Option<String>,
+    },
+    /// Change one setting of the running configuration (pressures take psi, bar or kPa)
+    Set {
+        /// Setting, e.g. max_boost_psi or shakedown.minutes
+        key: String,
+        /// New value, e.g. 14, 1.1bar, BoostByTarget
+        value: String,
+        /// Required when the change affects boost limits or control
+        #[arg(long)]
+        confirm: bool,
+    },
+    /// Save the running configuration to a file
+    Export {
+        /// Output file
+        file: String,
+    },
+    /// Replace the running configuration with a file
+    Import {
+        /// Configuration file
+        file: String,
+        /// Required when the file changes boost limits or control
+        #[arg(long)]
+        confirm: bool,
+    },
+    /// Edit a configuration file in $EDITOR with schema completion, then upload it
+    Edit {
+        /// Configuration file path (created from the defaults if missing)
+        file: String,
+        /// Upload even if the simulated dry run fails
+        #[arg(long)]
+        force: bool,
+    },
+    /// Write the configuration JSON schemas (for editor completion and validation)
+    Schema {
+        /// Output directory
+        #[arg(short, long, default_value = ".")]
+        output: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum LearnedAction {
+    /// Save the device's learned data to a file
+    Export {
+        /// Output file
+        file: String,
+    },
+    /// Replace the device's learned data with an exported file (system must not be armed)
+    Import {
+        /// Exported learned data file
+        file: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigsAction {
+    /// List stored configurations
+    List,
+    /// Store a configuration file under a name
+    Save {
+        /// Configuration name
+        name: String,
+        /// Configuration file path
+        #[arg(short, long)]
+        file: String,
+    },
+    /// Store a configuration inheriting from another, changing only the fields in a file
+    Inherit {
+        /// Configuration name
+        name: String,
+        /// Configuration to inherit from
+        #[arg(short, long)]
+        parent: String,
+        /// Partial configuration file path (JSON object of the fields to change)
+        #[arg(short, long)]
+        file: String,
+    },
+    /// Show a stored configuration, resolved through the configurations it inherits from
+    Show {
+        /// Configuration name
+        name: String,
+    },
+    /// Switch to a stored configuration (system must not be armed)
+    Select {
+        /// Configuration name
+        name: String,
+    },
+    /// Delete a stored configuration and its learned data
+    Delete {
+        /// Configuration name
+        name: String,
+    },
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -42,28 +424,506 @@ fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
     
     println!("RumbleDome CLI v0.1.0");
+    let target = Target { device: cli.device, port: cli.port, tcp: cli.tcp };
     
     match cli.command {
+        Commands::Devices => {
+            let devices = connection::discover(&connection::candidate_endpoints(&target.tcp));
+            if devices.is_empty() {
+                println!("No RumbleDome found");
+            }
+            for device in devices {
+                let identity = &device.identity;
+                println!("  {:<24} {:<24} firmware {:<8} config \"{}\"",
+                    identity.device_name, device.endpoint.to_string(),
+                    identity.firmware.firmware_version, identity.active_configuration);
+            }
+        }
+        Commands::Name { name } => {
+            validate_device_name(&name).map_err(|e| format!("{:?}", e))?;
+            request(&target, ProtocolMessage::SetDeviceName { name: name.clone() })?;
+            println!("Device renamed to \"{}\"", name);
+        }
+        Commands::Language { language } => {
+            let language = Language::from(language);
+            request(&target, ProtocolMessage::SetLanguage { language })?;
+            println!("Display language: {}", language.code());
+        }
         Commands::Status => {
-            // TODO: Connect to RumbleDome and get status
-            println!("System status: Not implemented yet");
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            status::print(&status::fetch_status(&mut exchange)?);
+        }
+        Commands::Config { action: Some(ConfigAction::Edit { file, force }), .. } => {
+            println!("  Config file: {}", file);
+            let editor = config_edit::editor_command();
+            let config = config_edit::edit(Path::new(&file), &mut io::stdin().lock(), &mut |path| config_edit::launch_editor(&editor, path))?;
+            upload_config(&target, config, None, force)?;
+        }
+        Commands::Config { action: Some(ConfigAction::Schema { output }), .. } => {
+            for path in config_edit::write_schemas(Path::new(&output))? {
+                println!("Schema written to {}", path.display());
+            }
+        }
+        Commands::Config { action: Some(ConfigAction::Get { key }), .. } => {
+            let config = fetch_running_config(&target)?;
+            println!("{}", serde_json::to_string_pretty(&config_io::get(&config, key.as_deref().unwrap_or(""))?)?);
+        }
+        Commands::Config { action: Some(ConfigAction::Set { key, value, confirm }), .. } => {
+            let running = fetch_running_config(&target)?;
+            let updated = config_io::set(&running, &key, &value)?;
+            apply_config_change(&target, &running, updated, confirm)?;
+        }
+        Commands::Config { action: Some(ConfigAction::Export { file }), .. } => {
+            let config = fetch_running_config(&target)?;
+            fs::write(&file, config_io::export(&config)?)?;
+            println!("Configuration written to {}", file);
+        }
+        Commands::Config { action: Some(ConfigAction::Import { file, confirm }), .. } => {
+            let updated = config_io::import(&fs::read_to_string(&file)?)?;
+            let running = fetch_running_config(&target)?;
+            apply_config_change(&target, &running, updated, confirm)?;
+        }
+        Commands::Config { action: None, file, learned_data, force } => {
+            let Some(path) = file else {
+                let config = fetch_running_config(&target)?;
+                println!("{}", config_io::export(&config)?);
+                return Ok(());
+            };
+            
+            println!("  Config file: {}", path);
+            let config: SystemConfig = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            
+            let learned = match learned_data {
+                Some(learned) => {
+                    println!("  Learned data: {}", learned);
+                    Some(fs::read(&learned)?)
+                }
+                None => None,
+            };
+            
+            upload_config(&target, config, learned.as_deref(), force)?;
+        }
+        Commands::Mode { mode } => {
+            let mode = ControlMode::from(mode);
+            request(&target, ProtocolMessage::SetControlMode { mode })?;
+            println!("Control mode: {}", mode.display_text());
+        }
+        Commands::Configs { action } => {
+            let message = match action {
+                ConfigsAction::List => ProtocolMessage::ListConfigurations,
+                ConfigsAction::Save { name, file } => {
+                    let config: SystemConfig = serde_json::from_str(&fs::read_to_string(&file)?)?;
+                    validate_config_name(&name).map_err(|e| format!("{:?}", e))?;
+                    config.validate().map_err(|e| format!("Invalid configuration: {:?}", e))?;
+                    ProtocolMessage::SaveConfiguration { name, config }
+                }
+                ConfigsAction::Inherit { name, parent, file } => {
+                    let overrides: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&fs::read_to_string(&file)?)?;
+                    validate_config_name(&name).map_err(|e| format!("{:?}", e))?;
+                    ProtocolMessage::SaveInheritedConfiguration { name, parent, overrides }
+                }
+                ConfigsAction::Show { name } => ProtocolMessage::GetConfiguration { name },
+                ConfigsAction::Select { name } => ProtocolMessage::SelectConfiguration { name },
+                ConfigsAction::Delete { name } => ProtocolMessage::DeleteConfiguration { name },
+            };
+            match request(&target, message)? {
+                ProtocolMessage::Configurations { active, names } => {
+                    for name in names {
+                        let marker = if name == active { "*" } else { " " };
+                        println!("{} {}", marker, name);
+                    }
+                }
+                ProtocolMessage::Configuration(entry) => {
+                    if let Some(parent) = &entry.parent {
+                        println!("\"{}\" inherits from \"{}\", changing:", entry.name, parent);
+                        println!("{}", serde_json::to_string_pretty(&entry.overrides)?);
+                        println!("Resolved:");
+                    }
+                    println!("{}", serde_json::to_string_pretty(&entry.config)?);
+                }
+                _ => println!("Named configurations updated"),
+            }
+        }
+        Commands::Commission { skip, map_sensors } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let mut input = io::stdin().lock();
+            if skip {
+                commissioning::skip_all(&mut exchange, &mut input)?;
+            } else if map_sensors {
+                commissioning::map_sensors(&mut exchange, &mut input)?;
+            } else {
+                commissioning::run(&mut exchange, &mut input)?;
+            }
+        }
+        Commands::Tune { action } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            match action {
+                TuneArg::Suggest => tuning::print_suggestions(&tuning::fetch_status(&mut exchange)?),
+                TuneArg::Apply => {
+                    let status = tuning::fetch_status(&mut exchange)?;
+                    match tuning::withheld_note(&status) {
+                        Some(note) => println!("{}", note),
+                        None => tuning::apply(&mut exchange, &status.gain_suggestions)?,
+                    }
+                }
+                TuneArg::Commit => tuning::commit(&mut exchange)?,
+                TuneArg::Revert => tuning::revert(&mut exchange)?,
+            }
+        }
+        Commands::Distribution { all } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let report = tuning::fetch_distribution(&mut exchange)?;
+            if all {
+                println!("Torque gap by RPM band, last {} sessions:", report.sessions);
+                tuning::print_distribution(&report.recent);
+            } else {
+                println!("Torque gap by RPM band, this drive:");
+                tuning::print_distribution(&report.current);
+            }
         }
-        Commands::Config { file } => {
-            // TODO: Load/save configuration
-            println!("Configuration: Not implemented yet");
-            if let Some(path) = file {
-                println!("  Config file: {}", path);
+        Commands::Trends { monthly } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let report = trends::fetch_trends(&mut exchange, trends::today())?;
+            if monthly {
+                trends::print_trends(&report.months, "month");
+            } else {
+                trends::print_trends(&report.weeks, "week");
             }
         }
-        Commands::Calibrate => {
-            // TODO: Start calibration session
-            println!("Calibration: Not implemented yet");
+        Commands::Calibrate { status, abort } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let request = match (status, abort) {
+                (true, _) => ProtocolMessage::GetCalibrationStatus,
+                (_, true) => ProtocolMessage::AbortCalibration,
+                _ => ProtocolMessage::StartCalibration,
+            };
+            match calibration::send(&mut exchange, request)? {
+                Some(progress) => println!("Calibrating - {}", calibration::format_progress(&progress)),
+                None if abort => println!("Calibration aborted"),
+                None => println!("No calibration in progress"),
+            }
+        }
+        Commands::Datalog { status, stop } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let request = match (status, stop) {
+                (true, _) => ProtocolMessage::GetDatalogStatus,
+                (_, true) => ProtocolMessage::StopDatalog,
+                _ => ProtocolMessage::StartDatalog,
+            };
+            println!("{}", datalog::format_status(&datalog::send(&mut exchange, request)?));
+        }
+        Commands::Analyze { file, min_target, settle_band } => {
+            let rows = analyze::read_log(&fs::read(&file)?)?;
+            let settings = analyze::AnalysisSettings {
+                min_target_psi: min_target,
+                settle_band_psi: settle_band,
+                ..analyze::AnalysisSettings::default()
+            };
+            analyze::print_report(&analyze::analyze(&rows, &settings), &settings);
         }
-        Commands::Reset => {
-            // TODO: Reset learned data
-            println!("Reset: Not implemented yet");
+        Commands::SpringCheck { status, cancel } => {
+            let message = match (status, cancel) {
+                (true, _) => ProtocolMessage::GetSpringCheckStatus,
+                (_, true) => ProtocolMessage::CancelSpringCheck,
+                _ => ProtocolMessage::StartSpringCheck,
+            };
+            let status = spring_check::status_of(request(&target, message)?)?;
+            println!("{}", spring_check::format_status(&status));
+        }
+        Commands::Learned { action } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| confirmed_request(&mut connection, message);
+            match action {
+                LearnedAction::Export { file } => {
+                    let image = learned_data::export(&mut exchange)?;
+                    fs::write(&file, &image)?;
+                    println!("Learned data ({} bytes) written to {}", image.len(), file);
+                }
+                LearnedAction::Import { file } => {
+                    let progress = learned_data::import(&mut exchange, &fs::read(&file)?)?;
+                    println!("Learned data imported ({} bytes)", progress.total);
+                }
+            }
         }
+        Commands::Reset { yes } => {
+            if !yes && commissioning::prompt(&mut io::stdin().lock(), "Forget everything the controller has learned? [y/N]: ")? != "y" {
+                println!("Learned data kept");
+                return Ok(());
+            }
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| confirmed_request(&mut connection, message);
+            learned_data::reset(&mut exchange)?;
+            println!("Learned data reset - the controller starts from conservative estimates");
+        }
+        Commands::Doctor { snapshot } => {
+            if let Some(path) = snapshot {
+                doctor::print_report(&doctor::load_snapshot(&path)?);
+            } else {
+                let ProtocolMessage::Status(status) = request(&target, ProtocolMessage::GetStatus)? else {
+                    return Err("Device did not return its status".into());
+                };
+                doctor::print_report(&status);
+            }
+        }
+        Commands::SupportBundle { output, simulated, inspect } => {
+            if let Some(path) = inspect {
+                support_bundle::print_summary(&support_bundle::read_archive(&path)?);
+                return Ok(());
+            }
+            
+            let response = if simulated {
+                let mut device = VirtualDevice::new(SystemConfig::default())
+                    .map_err(|e| format!("Simulated device failed to start: {:?}", e))?;
+                device.handle(ProtocolMessage::GetSupportBundle, 0)
+            } else {
+                request(&target, ProtocolMessage::GetSupportBundle)?
+            };
+            let ProtocolMessage::SupportBundle(bundle) = response else {
+                return Err("Device did not return a support bundle".into());
+            };
+            
+            let archive = support_bundle::SupportArchive::new(*bundle);
+            let path = output.unwrap_or_else(|| archive.default_file_name());
+            support_bundle::write_archive(&path, &archive)?;
+            support_bundle::print_summary(&archive);
+            println!("Support bundle written to {}", path);
+        }
+        Commands::Burst { seconds, output } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let window_ms = (seconds * 1000.0) as u32;
+            println!("Capturing {} ms at the full control loop rate...", window_ms);
+            burst::record(&mut exchange, window_ms, &mut std::thread::sleep)?;
+            
+            let capture = burst::offload(&mut exchange, window_ms)?;
+            let path = output.unwrap_or_else(|| capture.default_file_name());
+            burst::write_capture(&path, &capture)?;
+            burst::print_summary(&capture);
+            println!("Burst log written to {}", path);
+        }
+        Commands::FlightRecord { output } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            let recording = flight_record::offload(&mut exchange)?;
+            let path = output.unwrap_or_else(|| recording.default_file_name());
+            flight_record::write_recording(&path, &recording)?;
+            flight_record::print_summary(&recording);
+            println!("Flight recording written to {}", path);
+        }
+        Commands::Log { clear } => {
+            let mut connection = connect(&target)?;
+            let mut exchange = |message| connection.request(message);
+            safety_log::print_log(&safety_log::fetch_log(&mut exchange)?);
+            if clear {
+                safety_log::clear(&mut exchange)?;
+                println!("Safety log cleared");
+            }
+        }
+        Commands::Demo { stop } => {
+            let ProtocolMessage::DemoMode { active } = request(&target, ProtocolMessage::SetDemoMode { active: !stop })? else {
+                return Err("Device did not confirm the demo mode".into());
+            };
+            println!("Display demo {}", if active { "running - hold scramble for 5 s or run `demo --stop` to end it" } else { "stopped" });
+        }
+        Commands::Dbc { file, rpm, desired_torque, actual_torque, manifold_pressure, config } => {
+            let database = dbc::Dbc::load(&file)?;
+            let selection = dbc::SignalSelection { rpm, desired_torque, actual_torque, manifold_pressure };
+            if selection.is_empty() {
+                dbc::print_messages(&database);
+                return Ok(());
+            }
+            
+            let mut system_config = match &config {
+                Some(path) => SystemConfig::from_json(&fs::read_to_string(path)?)
+                    .map_err(|e| format!("Invalid configuration: {:?}", e))?,
+                None => SystemConfig::default(),
+            };
+            dbc::import(&database, &selection, &mut system_config.can_signals)?;
+            
+            println!("CAN signal map:");
+            dbc::print_map(&system_config.can_signals);
+            if selection.manifold_pressure.is_some() {
+                println!("⚠️  Manifold pressure must be gauge - adjust the offset if the DBC signal is absolute");
+            }
+            match config {
+                Some(path) => {
+                    fs::write(&path, system_config.to_json().map_err(|e| format!("{:?}", e))?)?;
+                    println!("Signal map written to {}", path);
+                }
+                None => println!("{}", serde_json::to_string_pretty(&system_config.can_signals)?),
+            }
+        }
+        Commands::Screen { snapshot, interval, watch, duration } => {
+            let variables = screen::parse_watch_list(&watch)?;
+            if let Some(path) = snapshot {
+                let description = screen::load_snapshot(&path)?;
+                screen::print(&description);
+                return Ok(());
+            }
+            
+            let mut connection = connect(&target)?;
+            let first = screen::subscribe(&mut |message| connection.request(message), &variables, interval)?;
+            screen::redraw(&first)?;
+            
+            let started = Instant::now();
+            while duration.is_none_or(|seconds| started.elapsed() < Duration::from_secs(seconds)) {
+                if let Some(ProtocolMessage::Screen(description)) = connection.receive(Duration::from_millis(500))? {
+                    screen::redraw(&description)?;
+                }
+            }
+            screen::unsubscribe(&mut |message| connection.request(message))?;
+        }
+        Commands::Monitor { interval, dashboard, csv, duration } => {
+            let view = if dashboard { monitor::View::Dashboard } else { monitor::View::Table };
+            let log: Option<Box<dyn Write>> = match &csv {
+                Some(path) => Some(Box::new(io::BufWriter::new(fs::File::create(path)?))),
+                None => None,
+            };
+            let mut monitor = monitor::Monitor::new(view, log)?;
+            
+            let mut connection = connect(&target)?;
+            let first = monitor::subscribe(&mut |message| connection.request(message), interval)?;
+            if let Some(path) = &csv {
+                println!("Logging to {}", path);
+            }
+            monitor.show(&first)?;
+            
+            let started = Instant::now();
+            while duration.is_none_or(|seconds| started.elapsed() < Duration::from_secs(seconds)) {
+                if let Some(ProtocolMessage::Telemetry(sample)) = connection.receive(Duration::from_millis(500))? {
+                    monitor.show(&sample)?;
+                }
+            }
+            monitor::unsubscribe(&mut |message| connection.request(message))?;
+        }
+    }
+    
+    Ok(())
+}
+
+/// Device selection from the global command-line options
+struct Target {
+    device: Option<String>,
+    port: Option<String>,
+    tcp: Vec<String>,
+}
+
+/// Connect to the selected device
+fn connect(target: &Target) -> Result<connection::Connection, Box<dyn Error>> {
+    let connection = connection::connect(target.device.as_deref(), target.port.as_deref(), &target.tcp)?;
+    println!("Connected to \"{}\" on {}", connection.identity().device_name, connection.endpoint());
+    Ok(connection)
+}
+
+/// Connect to the selected device and send one request
+/// 
+/// Error responses from the device are returned as errors.
+/// 
+/// Protected requests (e.g. raising safety limits) are confirmed with the
+/// code shown on the device display, on the same connection. A Bluetooth
+/// connection starts read-only: a refused request authenticates the
+/// connection the same way and is sent once more.
+fn request(target: &Target, message: ProtocolMessage) -> Result<ProtocolMessage, Box<dyn Error>> {
+    let mut connection = connect(target)?;
+    let mut response = confirmed_request(&mut connection, message.clone())?;
+    if response.error_code() == Some(ErrorCode::NOT_PERMITTED) {
+        println!("🔒 Read-only connection - authenticating");
+        if let ProtocolMessage::Authenticated { safety_writes } = confirmed_request(&mut connection, ProtocolMessage::Authenticate)? {
+            if !safety_writes {
+                println!("ℹ️  Safety-relevant changes are disabled over Bluetooth");
+            }
+            response = confirmed_request(&mut connection, message)?;
+        }
+    }
+    
+    match response {
+        ProtocolMessage::Error(e) => Err(format!("Device error: {}", e).into()),
+        response => Ok(response),
+    }
+}
+
+/// Send one request, confirming it with the displayed code if the device asks
+fn confirmed_request(connection: &mut connection::Connection, message: ProtocolMessage) -> Result<ProtocolMessage, Box<dyn Error>> {
+    match connection.request(message)? {
+        ProtocolMessage::ConfirmationRequired { action, expires_in_ms } => {
+            println!("⚠️  {}", action.description());
+            let code = prompt_confirmation_code(&mut io::stdin().lock(), expires_in_ms)?;
+            connection.request(ProtocolMessage::ConfirmPresence { code })
+        }
+        response => Ok(response),
     }
+}
+
+/// Ask for the confirmation code shown on the device display
+fn prompt_confirmation_code(input: &mut dyn BufRead, expires_in_ms: u32) -> Result<u16, Box<dyn Error>> {
+    print!("Enter the code shown on the device display (within {} s): ", expires_in_ms / 1000);
+    io::stdout().flush()?;
+    
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err("Input closed".into());
+    }
+    line.trim().parse().map_err(|_| format!("Not a confirmation code: {}", line.trim()).into())
+}
+
+/// Dry-run a configuration in the simulator, then upload it
+/// 
+/// Simulate-then-apply: the config is exercised on the desktop twin before it
+/// reaches the car. A failed dry run blocks the upload unless forced.
+fn upload_config(target: &Target, config: SystemConfig, learned_data: Option<&[u8]>, force: bool) -> Result<(), Box<dyn Error>> {
+    let report = rumbledome_sim::dry_run(&config, learned_data)
+        .map_err(|e| format!("Dry run could not start: {:?}", e))?;
+    print_dry_run_report(&report);
     
+    if !report.passed() {
+        if !force {
+            return Err("Upload blocked: dry run failed (use --force to upload anyway)".into());
+        }
+        println!("⚠️  Dry run failed - uploading anyway (--force)");
+    }
+    
+    request(target, ProtocolMessage::SetConfig(config))?;
+    println!("Configuration uploaded");
     Ok(())
+}
+
+/// Configuration the device is running
+fn fetch_running_config(target: &Target) -> Result<SystemConfig, Box<dyn Error>> {
+    let mut connection = connect(target)?;
+    let mut exchange = |message| connection.request(message);
+    config_io::fetch_running(&mut exchange)
+}
+
+/// Show what a configuration change alters, then dry-run and upload it
+fn apply_config_change(target: &Target, running: &SystemConfig, updated: SystemConfig, confirm: bool) -> Result<(), Box<dyn Error>> {
+    let changes = config_io::changes(running, &updated)?;
+    if changes.is_empty() {
+        println!("No settings changed");
+        return Ok(());
+    }
+    for change in &changes {
+        println!("  {}", change);
+    }
+    config_io::require_confirmation(running, &updated, confirm)?;
+    upload_config(target, updated, None, false)
+}
+
+/// Print per-scenario dry-run results
+fn print_dry_run_report(report: &DryRunReport) {
+    println!("Dry run ({} scenarios):", report.results.len());
+    for result in &report.results {
+        let verdict = if result.passed { "PASS" } else { "FAIL" };
+        println!("  [{}] {:<20} peak {:>5.1} PSI  overshoot {:>4.1} PSI  reversals {}",
+            verdict, result.name, result.peak_boost_psi, result.max_overshoot_psi, result.oscillation_reversals);
+        for failure in &result.failures {
+            println!("         {}", failure);
+        }
+    }
 }
\ No newline at end of file
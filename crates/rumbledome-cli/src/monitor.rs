@@ -0,0 +1,203 @@
+//! Live Telemetry Monitor
+//! 
+//! 🔗 T4-CLI-017: Telemetry Monitor
+//! Derived From: T4-CORE-121 (Streaming Telemetry Sample) + T4-PROTOCOL-004 (Link-Aware Telemetry Throttling)
+//! AI Traceability: Watch boost follow its target pull by pull, and keep a CSV of the drive for a spreadsheet
+//! 
+//! The table scrolls one row per sample, reprinting its header every screenful.
+//! The dashboard redraws in place with bars and a boost history line instead.
+//! Either way a CSV copy can be written alongside; rows are flushed as they
+//! arrive so an interrupted run keeps everything received.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::io::Write;
+
+use console::Term;
+use rumbledome_core::TelemetrySample;
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+use crate::trends::sparkline;
+
+/// Table rows between header repeats
+const HEADER_EVERY: usize = 20;
+
+/// Samples in the dashboard boost history
+const HISTORY_SAMPLES: usize = 60;
+
+/// Width of the dashboard bars (characters)
+const BAR_WIDTH: usize = 40;
+
+/// CSV column names
+pub const CSV_HEADER: &str = "timestamp_ms,state,rpm,boost_psi,target_psi,duty_percent,torque_gap_nm,aggression,aggression_source";
+
+/// How samples are shown
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum View {
+    /// Scrolling table, one row per sample
+    Table,
+    /// Panel redrawn in place
+    Dashboard,
+}
+
+/// Start the telemetry stream; the response is its first sample
+pub fn subscribe(exchange: &mut Exchange, interval_ms: u32) -> Result<TelemetrySample, Box<dyn Error>> {
+    match exchange(ProtocolMessage::SubscribeTelemetry { interval_ms })? {
+        ProtocolMessage::Telemetry(sample) => Ok(sample),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected telemetry response: {:?}", other).into()),
+    }
+}
+
+/// Stop the telemetry stream
+pub fn unsubscribe(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    match exchange(ProtocolMessage::UnsubscribeTelemetry)? {
+        ProtocolMessage::TelemetryUnsubscribed => Ok(()),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected telemetry response: {:?}", other).into()),
+    }
+}
+
+/// Table column headings
+pub fn table_header() -> String {
+    format!("{:>9}  {:<12} {:>5} {:>7} {:>7} {:>6} {:>8}", "time s", "state", "rpm", "boost", "target", "duty%", "gap Nm")
+}
+
+/// One table row
+pub fn format_row(sample: &TelemetrySample) -> String {
+    format!(
+        "{:>9.2}  {:<12} {:>5} {:>7.1} {:>7.1} {:>6.1} {:>8.1}",
+        sample.timestamp_ms as f32 / 1000.0, sample.state.display_text(), sample.rpm,
+        sample.boost_psi, sample.target_psi, sample.duty_percent, sample.torque_gap_nm
+    )
+}
+
+/// One CSV row, columns as in `CSV_HEADER`
+pub fn csv_row(sample: &TelemetrySample) -> String {
+    format!(
+        "{},{},{},{:.2},{:.2},{:.1},{:.1},{:.2},{}",
+        sample.timestamp_ms, sample.state.display_text(), sample.rpm,
+        sample.boost_psi, sample.target_psi, sample.duty_percent, sample.torque_gap_nm,
+        sample.aggression, sample.aggression_source.display_text()
+    )
+}
+
+/// Bar of `value` out of `max`
+fn bar(value: f32, max: f32) -> String {
+    let filled = if max > 0.0 { ((value / max).clamp(0.0, 1.0) * BAR_WIDTH as f32).round() as usize } else { 0 };
+    format!("[{}{}]", "#".repeat(filled), ".".repeat(BAR_WIDTH - filled))
+}
+
+/// Dashboard panel for the latest sample and the recent boost history
+pub fn render_dashboard(sample: &TelemetrySample, history: &[f32]) -> String {
+    let scale = history.iter().fold(sample.target_psi.max(sample.boost_psi), |max, &psi| max.max(psi)).max(1.0);
+    let boost: Vec<Option<f32>> = history.iter().map(|&psi| Some(psi)).collect();
+    [
+        format!("{}  {} RPM  {:.2} s", sample.state.display_text(), sample.rpm, sample.timestamp_ms as f32 / 1000.0),
+        String::new(),
+        format!("boost  {:>6.1} PSI {}", sample.boost_psi, bar(sample.boost_psi, scale)),
+        format!("target {:>6.1} PSI {}", sample.target_psi, bar(sample.target_psi, scale)),
+        format!("duty   {:>6.1} %   {}", sample.duty_percent, bar(sample.duty_percent, 100.0)),
+        format!("torque gap {:+.1} Nm", sample.torque_gap_nm),
+        format!("aggression {:.0}% ({})", sample.aggression * 100.0, sample.aggression_source.display_text()),
+        String::new(),
+        format!("boost history  {}", sparkline(&boost)),
+    ].join("\n")
+}
+
+/// Shows each sample as it arrives and copies it to the CSV log
+pub struct Monitor {
+    view: View,
+    csv: Option<Box<dyn Write>>,
+    rows: usize,
+    history: VecDeque<f32>,
+}
+
+impl Monitor {
+    /// Start a monitor, writing the CSV header if logging
+    pub fn new(view: View, mut csv: Option<Box<dyn Write>>) -> Result<Self, Box<dyn Error>> {
+        if let Some(out) = csv.as_mut() {
+            writeln!(out, "{}", CSV_HEADER)?;
+        }
+        Ok(Self { view, csv, rows: 0, history: VecDeque::with_capacity(HISTORY_SAMPLES) })
+    }
+    
+    /// Show one sample
+    pub fn show(&mut self, sample: &TelemetrySample) -> Result<(), Box<dyn Error>> {
+        if self.history.len() == HISTORY_SAMPLES {
+            self.history.pop_front();
+        }
+        self.history.push_back(sample.boost_psi);
+        
+        match self.view {
+            View::Table => {
+                if self.rows.is_multiple_of(HEADER_EVERY) {
+                    println!("{}", table_header());
+                }
+                println!("{}", format_row(sample));
+            }
+            View::Dashboard => {
+                let history: Vec<f32> = self.history.iter().copied().collect();
+                Term::stdout().clear_screen()?;
+                println!("{}", render_dashboard(sample, &history));
+            }
+        }
+        self.rows += 1;
+        
+        if let Some(out) = self.csv.as_mut() {
+            writeln!(out, "{}", csv_row(sample))?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{AggressionSource, SystemConfig, SystemState};
+    use rumbledome_sim::VirtualDevice;
+    
+    fn sample() -> TelemetrySample {
+        TelemetrySample {
+            timestamp_ms: 12_340,
+            state: SystemState::Armed,
+            rpm: 4500,
+            boost_psi: 9.25,
+            target_psi: 10.0,
+            duty_percent: 47.5,
+            torque_gap_nm: 22.0,
+            aggression: 0.5,
+            aggression_source: AggressionSource::Knob,
+        }
+    }
+    
+    #[test]
+    fn test_csv_row_matches_header() {
+        let row = csv_row(&sample());
+        assert_eq!(row.split(',').count(), CSV_HEADER.split(',').count());
+        assert_eq!(row, format!("12340,{},4500,9.25,10.00,47.5,22.0,0.50,knob", SystemState::Armed.display_text()));
+    }
+    
+    #[test]
+    fn test_table_row_lines_up_with_header() {
+        assert_eq!(format_row(&sample()).chars().count(), table_header().chars().count());
+    }
+    
+    #[test]
+    fn test_dashboard_bars_scale_to_history() {
+        let panel = render_dashboard(&sample(), &[5.0, 20.0]);
+        // Boost 9.25 of a 20 PSI scale fills 19 of 40 cells
+        assert!(panel.contains(&format!("[{}{}]", "#".repeat(19), ".".repeat(21))));
+    }
+    
+    #[test]
+    fn test_subscribe_and_unsubscribe_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let first = subscribe(&mut exchange, 100).unwrap();
+        assert_eq!(first.state, SystemState::Idle);
+        unsubscribe(&mut exchange).unwrap();
+    }
+}
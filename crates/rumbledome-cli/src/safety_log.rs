@@ -0,0 +1,81 @@
+//! Safety Event Log
+//! 
+//! 🔗 T4-CLI-013: Safety Log Dump
+//! Derived From: T4-CORE-117 (Persistent Safety Event Log)
+//! AI Traceability: Read back every overboost cut and fault the car has had, with what tripped it and how fast it was handled
+
+use std::error::Error;
+
+use rumbledome_core::{SafetyEventKind, SafetyLogEntry};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Fetch the logged events, oldest first
+pub fn fetch_log(exchange: &mut Exchange) -> Result<Vec<SafetyLogEntry>, Box<dyn Error>> {
+    match exchange(ProtocolMessage::GetSafetyLog)? {
+        ProtocolMessage::SafetyLog { events } => Ok(events),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected safety log response: {:?}", other).into()),
+    }
+}
+
+/// Erase the log on the device
+pub fn clear(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    match exchange(ProtocolMessage::ClearSafetyLog)? {
+        ProtocolMessage::SafetyLogCleared => Ok(()),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected safety log response: {:?}", other).into()),
+    }
+}
+
+/// One line per event
+pub fn format_entry(entry: &SafetyLogEntry) -> String {
+    let event = match entry.kind {
+        SafetyEventKind::OverboostCut => "Overboost cut".to_string(),
+        SafetyEventKind::Fault(number) => format!("Fault F{:02}", number),
+    };
+    format!(
+        "  session {:>3}  {:>9.1} s  {:<13}  trigger {:>7.1}  response {:>5} µs",
+        entry.session, entry.timestamp_ms as f32 / 1000.0, event, entry.trigger_value, entry.response_time_us
+    )
+}
+
+/// Print the log
+pub fn print_log(entries: &[SafetyLogEntry]) {
+    if entries.is_empty() {
+        println!("Safety log is empty");
+        return;
+    }
+    println!("{} safety events, oldest first (time since power-up):", entries.len());
+    for entry in entries {
+        println!("{}", format_entry(entry));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_entry_line() {
+        let entry = SafetyLogEntry {
+            session: 3,
+            timestamp_ms: 61_250,
+            kind: SafetyEventKind::Fault(6),
+            trigger_value: 17.3,
+            response_time_us: 412,
+        };
+        assert_eq!(format_entry(&entry), "  session   3       61.2 s  Fault F06      trigger    17.3  response   412 µs");
+    }
+    
+    #[test]
+    fn test_fetch_and_clear_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        assert!(fetch_log(&mut exchange).unwrap().is_empty());
+        clear(&mut exchange).unwrap();
+    }
+}
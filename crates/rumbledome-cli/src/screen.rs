@@ -0,0 +1,332 @@
+//! Terminal Display Mirror
+//! 
+//! 🔗 T4-CLI-002: Display Mirror Renderer
+//! Derived From: T4-CORE-035 (Semantic Screen Description)
+//! AI Traceability: Remote support - see exactly what the driver sees on the gauge pod
+
+use std::error::Error;
+use std::fs;
+
+use console::{Style, Term};
+use rumbledome_core::{
+    AccentColor, AlertSeverity, ControlMode, DisplayString, LimitKind, LimitMargin, ScreenDescription, ScreenLayout,
+    WatchVariable,
+};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Inner width of the rendered gauge pod (characters)
+const SCREEN_WIDTH: usize = 24;
+
+/// Load a screen description saved as JSON
+pub fn load_snapshot(path: &str) -> Result<ScreenDescription, Box<dyn Error>> {
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Watch variables from their names
+pub fn parse_watch_list(names: &[String]) -> Result<Vec<WatchVariable>, Box<dyn Error>> {
+    names
+        .iter()
+        .map(|name| WatchVariable::from_name(name.trim()).ok_or_else(|| {
+            let known: Vec<&str> = WatchVariable::ALL.iter().map(|variable| variable.name()).collect();
+            format!("Unknown watch variable \"{}\" (one of: {})", name, known.join(", ")).into()
+        }))
+        .collect()
+}
+
+/// Register the watch list and start the screen stream; the response is its first frame
+/// 
+/// An empty watch list still replaces one left registered by an earlier session.
+pub fn subscribe(exchange: &mut Exchange, variables: &[WatchVariable], interval_ms: u32) -> Result<ScreenDescription, Box<dyn Error>> {
+    match exchange(ProtocolMessage::SetWatchList { variables: variables.to_vec() })? {
+        ProtocolMessage::WatchList { .. } => {}
+        ProtocolMessage::Error(e) => return Err(e.into()),
+        other => return Err(format!("Unexpected watch list response: {:?}", other).into()),
+    }
+    match exchange(ProtocolMessage::SubscribeScreen { interval_ms })? {
+        ProtocolMessage::Screen(screen) => Ok(screen),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected screen response: {:?}", other).into()),
+    }
+}
+
+/// Stop the screen stream
+pub fn unsubscribe(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    match exchange(ProtocolMessage::UnsubscribeScreen)? {
+        ProtocolMessage::ScreenUnsubscribed => Ok(()),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected screen response: {:?}", other).into()),
+    }
+}
+
+/// Replace the terminal contents with a streamed frame
+pub fn redraw(screen: &ScreenDescription) -> Result<(), Box<dyn Error>> {
+    Term::stdout().clear_screen()?;
+    print(screen);
+    Ok(())
+}
+
+/// Render a screen description as a framed text block
+pub fn render(screen: &ScreenDescription) -> String {
+    let text = |id: DisplayString| id.text(screen.language);
+    let mut rows: Vec<String> = Vec::new();
+    
+    match screen.layout {
+        ScreenLayout::Startup => {
+            rows.push(String::new());
+            rows.push(center("RUMBLEDOME"));
+            rows.push(center(&screen.status_text));
+            rows.push(String::new());
+        }
+        ScreenLayout::Fault => {
+            rows.push(String::new());
+            rows.push(center(text(DisplayString::ScreenSystemFault)));
+            rows.push(center(text(DisplayString::ScreenBoostDisabled)));
+            rows.push(String::new());
+        }
+        ScreenLayout::Gauge | ScreenLayout::Calibration => {
+            if screen.profile_label.is_empty() {
+                rows.push(center(&screen.status_text));
+            } else {
+                rows.push(center(&format!("[{}] {}", screen.profile_label, screen.status_text)));
+            }
+            rows.push(String::new());
+            rows.push(center(&format!("{:.1} PSI", screen.boost_psi)));
+            rows.push(gauge_bar(screen));
+            rows.push(format!(" {} {:.1} / {} {:.1}",
+                text(DisplayString::LabelTarget), screen.target_psi, text(DisplayString::LabelMax), screen.gauge_max_psi));
+            rows.push(format!(" {} {}%", text(DisplayString::LabelAggression), screen.aggression_percent));
+            if screen.control_mode == ControlMode::BoostByTarget {
+                rows.push(format!(" {} {}", text(DisplayString::LabelMode), screen.control_mode.localized_text(screen.language)));
+            }
+            if let Some(psi) = screen.exhaust_backpressure_psi {
+                rows.push(format!(" {} {:.1} PSI", text(DisplayString::LabelExhaust), psi));
+            }
+            if let Some(headroom) = &screen.headroom {
+                let closest = headroom.closest();
+                rows.push(format!(" {}: {}", closest.limit.localized_text(screen.language), format_margin(closest)));
+            }
+        }
+    }
+    
+    if let Some(alert) = &screen.alert {
+        let marker = match alert.severity {
+            AlertSeverity::Advisory => "i",
+            AlertSeverity::Warning => "!",
+            AlertSeverity::Critical => "X",
+        };
+        rows.push(String::new());
+        rows.push(format!(" {} {}", marker, alert.text));
+    }
+    
+    let border = "-".repeat(SCREEN_WIDTH);
+    let mut out = format!("+{}+\n", border);
+    for row in rows {
+        out.push_str(&format!("|{:<width$}|\n", truncate(&row), width = SCREEN_WIDTH));
+    }
+    out.push_str(&format!("+{}+\n", border));
+    out
+}
+
+/// Print a rendered screen with the frame in the profile's accent color
+/// 
+/// The frame stands in for the gauge ring. Color is dropped automatically when
+/// stdout is not a terminal.
+pub fn print(screen: &ScreenDescription) {
+    let ring = Style::new().color256(ansi256(screen.accent_color));
+    
+    for line in render(screen).lines() {
+        if line.starts_with('+') {
+            println!("{}", ring.apply_to(line));
+        } else {
+            let inner = &line[1..line.len() - 1];
+            println!("{}{}{}", ring.apply_to("|"), inner, ring.apply_to("|"));
+        }
+    }
+    
+    if let Some(headroom) = &screen.headroom {
+        let closest = headroom.closest().limit;
+        println!("Headroom:");
+        for margin in headroom.margins() {
+            let marker = if margin.limit == closest { "<" } else { " " };
+            println!("  {:<12} {:>9} {:>4.0}% {}",
+                margin.limit.display_text(), format_margin(margin), margin.fraction * 100.0, marker);
+        }
+    }
+    
+    if !screen.watches.is_empty() {
+        println!("Watch:");
+        for reading in &screen.watches {
+            println!("  {:<15} {}", reading.variable.name(), reading.value);
+        }
+    }
+}
+
+/// Margin in the limit's own unit
+fn format_margin(margin: &LimitMargin) -> String {
+    match margin.limit {
+        LimitKind::Overboost | LimitKind::ProfileMax | LimitKind::ThermalDerate => format!("{:.1} {}", margin.margin, margin.limit.unit()),
+        LimitKind::RevLimitTaper => format!("{:.0} {}", margin.margin, margin.limit.unit()),
+        LimitKind::DutySaturation => format!("{:.0}{}", margin.margin, margin.limit.unit()),
+    }
+}
+
+/// Nearest color in the xterm 256-color cube
+fn ansi256(color: AccentColor) -> u8 {
+    let level = |channel: u8| (channel as u16 * 5 + 127) / 255;
+    (16 + 36 * level(color.r) + 6 * level(color.g) + level(color.b)) as u8
+}
+
+/// Horizontal boost gauge with target marker
+fn gauge_bar(screen: &ScreenDescription) -> String {
+    let cells = SCREEN_WIDTH - 4;
+    let scale = |psi: f32| -> usize {
+        if screen.gauge_max_psi <= 0.0 {
+            return 0;
+        }
+        ((psi / screen.gauge_max_psi).clamp(0.0, 1.0) * cells as f32).round() as usize
+    };
+    
+    let filled = scale(screen.boost_psi);
+    let target = scale(screen.target_psi).min(cells - 1);
+    
+    let bar: String = (0..cells)
+        .map(|i| match (i < filled, i == target) {
+            (_, true) => '|',
+            (true, false) => '#',
+            (false, false) => '.',
+        })
+        .collect();
+    
+    format!(" [{}]", bar)
+}
+
+fn center(text: &str) -> String {
+    format!("{:^width$}", text, width = SCREEN_WIDTH)
+}
+
+fn truncate(text: &str) -> String {
+    text.chars().take(SCREEN_WIDTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{Headroom, Language, ScreenAlert, SystemConfig};
+    use rumbledome_sim::VirtualDevice;
+    
+    fn gauge_screen() -> ScreenDescription {
+        ScreenDescription {
+            layout: ScreenLayout::Gauge,
+            status_text: "ARMED".to_string(),
+            boost_psi: 7.5,
+            target_psi: 10.0,
+            gauge_max_psi: 15.0,
+            aggression_percent: 30,
+            control_mode: ControlMode::TorqueFollowing,
+            accent_color: AccentColor::default(),
+            profile_label: String::new(),
+            exhaust_backpressure_psi: None,
+            alert: None,
+            headroom: None,
+            language: Language::English,
+            timestamp_ms: 0,
+            watches: Vec::new(),
+        }
+    }
+    
+    #[test]
+    fn test_rows_have_fixed_width() {
+        let mut screen = gauge_screen();
+        screen.alert = Some(ScreenAlert {
+            severity: AlertSeverity::Warning,
+            text: "A VERY LONG ALERT THAT DOES NOT FIT".to_string(),
+        });
+        
+        for line in render(&screen).lines() {
+            assert_eq!(line.chars().count(), SCREEN_WIDTH + 2);
+        }
+    }
+    
+    #[test]
+    fn test_closest_limit_shown() {
+        let mut screen = gauge_screen();
+        assert!(!render(&screen).contains("profile max"));
+        
+        screen.headroom = Some(Headroom::compute(&SystemConfig::default(), 10.5, 4000, 55.0, None));
+        assert!(render(&screen).contains("| profile max: 1.5 PSI"));
+    }
+    
+    #[test]
+    fn test_gauge_shows_boost_and_target() {
+        let bar = gauge_bar(&gauge_screen());
+        assert_eq!(bar, " [##########...|......]");
+    }
+    
+    #[test]
+    fn test_boost_by_target_mode_flagged() {
+        let mut screen = gauge_screen();
+        assert!(!render(&screen).contains("BOOST TARGET"));
+        
+        screen.control_mode = ControlMode::BoostByTarget;
+        assert!(render(&screen).contains("mode BOOST TARGET"));
+    }
+    
+    #[test]
+    fn test_exhaust_backpressure_row_when_fitted() {
+        let mut screen = gauge_screen();
+        assert!(!render(&screen).contains("exhaust"));
+        
+        screen.exhaust_backpressure_psi = Some(18.25);
+        assert!(render(&screen).contains("| exhaust 18.2 PSI"));
+    }
+    
+    #[test]
+    fn test_profile_label_beside_state() {
+        let mut screen = gauge_screen();
+        screen.profile_label = "VALET".to_string();
+        assert!(render(&screen).lines().nth(1).unwrap().contains("[VALET] ARMED"));
+    }
+    
+    #[test]
+    fn test_accent_maps_to_color_cube() {
+        assert_eq!(ansi256(AccentColor::rgb(0xFF, 0x00, 0x00)), 196);
+        assert_eq!(ansi256(AccentColor::rgb(0x00, 0x00, 0x00)), 16);
+        assert_eq!(ansi256(AccentColor::rgb(0xFF, 0xFF, 0xFF)), 231);
+    }
+    
+    #[test]
+    fn test_fault_layout() {
+        let mut screen = gauge_screen();
+        screen.layout = ScreenLayout::Fault;
+        assert!(render(&screen).contains("BOOST DISABLED"));
+    }
+    
+    #[test]
+    fn test_labels_follow_screen_language() {
+        let mut screen = gauge_screen();
+        screen.language = Language::Spanish;
+        assert!(render(&screen).contains(" agresividad 30%"));
+    }
+    
+    #[test]
+    fn test_watch_names_parse() {
+        let names = vec!["duty".to_string(), " map_source".to_string()];
+        assert_eq!(parse_watch_list(&names).unwrap(), vec![WatchVariable::DutyPercent, WatchVariable::MapSource]);
+        
+        let error = parse_watch_list(&["pid".to_string()]).unwrap_err().to_string();
+        assert!(error.contains("level1_target"));
+    }
+    
+    #[test]
+    fn test_subscribe_with_watches_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let first = subscribe(&mut exchange, &[WatchVariable::DutyPercent], 100).unwrap();
+        assert_eq!(first.watches.len(), 1);
+        assert_eq!(first.watches[0].variable, WatchVariable::DutyPercent);
+        unsubscribe(&mut exchange).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+//! Wastegate Spring Check
+//! 
+//! 🔗 T4-CLI-019: Spring Check Start, Status and Cancel
+//! Derived From: T4-CORE-128 (Spring Check Mode) + T4-PROTOCOL-007 (Physical Presence Authorization)
+//! AI Traceability: Catch a mistyped spring pressure with one pull instead of a season of odd boost behavior
+
+use rumbledome_core::{SpringCheckPhase, SpringCheckStatus};
+use rumbledome_protocol::ProtocolMessage;
+
+/// Spring check status carried by a response
+pub fn status_of(response: ProtocolMessage) -> Result<SpringCheckStatus, String> {
+    match response {
+        ProtocolMessage::SpringCheckStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e),
+        other => Err(format!("Unexpected spring check response: {:?}", other)),
+    }
+}
+
+/// What the check is doing, or what it found
+pub fn format_status(status: &SpringCheckStatus) -> String {
+    match status.phase {
+        SpringCheckPhase::NotRun => "No spring check run since power-up".to_string(),
+        SpringCheckPhase::WaitingForPull =>
+            "Boost control off - make one wide-open pull in a high gear past 3000 RPM".to_string(),
+        SpringCheckPhase::PlateauFound => format!(
+            "Plateau at {:.1} PSI - lift to finish", status.plateau_psi.unwrap_or(0.0)
+        ),
+        SpringCheckPhase::Passed | SpringCheckPhase::Mismatch => match &status.report {
+            Some(report) if report.within_tolerance => format!(
+                "Spring OK: measured {:.1} PSI, configured {:.1} PSI", report.measured_psi, report.configured_psi
+            ),
+            Some(report) => format!(
+                "Spring mismatch: {}\nRun `rumbledome-cli config set spring_pressure {:.1}` and re-check",
+                report.correction(), report.suggested_spring_psi()
+            ),
+            None => "Spring check finished without a report".to_string(),
+        },
+        SpringCheckPhase::Aborted => format!(
+            "Spring check abandoned: {}", status.abort_reason.as_deref().unwrap_or("unknown reason")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SpringCheckReport;
+    
+    #[test]
+    fn test_mismatch_names_the_correction() {
+        let status = SpringCheckStatus {
+            phase: SpringCheckPhase::Mismatch,
+            plateau_psi: Some(7.34),
+            report: Some(SpringCheckReport { configured_psi: 5.0, measured_psi: 7.34, difference_psi: 2.34, within_tolerance: false }),
+            abort_reason: None,
+        };
+        let text = format_status(&status);
+        assert!(text.contains("set spring_pressure to 7.3"));
+        assert!(text.contains("config set spring_pressure 7.3"));
+    }
+    
+    #[test]
+    fn test_start_needs_presence_confirmation() {
+        use rumbledome_core::SystemConfig;
+        use rumbledome_sim::VirtualDevice;
+        
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        assert!(matches!(device.handle(ProtocolMessage::StartSpringCheck, 0), ProtocolMessage::ConfirmationRequired { .. }));
+        let status = status_of(device.handle(ProtocolMessage::GetSpringCheckStatus, 0)).unwrap();
+        assert_eq!(format_status(&status), "No spring check run since power-up");
+    }
+}
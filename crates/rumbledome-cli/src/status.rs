@@ -0,0 +1,165 @@
+//! System Status Display
+//! 
+//! 🔗 T4-CLI-016: Status Report
+//! Derived From: T4-CORE-009 (System Status Reporting) + T4-CLI-005 (Device Discovery and Selection)
+//! AI Traceability: Quick look at what the controller is doing right now, without the full doctor findings
+
+use std::error::Error;
+
+use rumbledome_core::{PerformanceTier, PerformanceTierStatus, ShakedownStatus, SystemState, SystemStatus};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Fetch the device's current status
+pub fn fetch_status(exchange: &mut Exchange) -> Result<SystemStatus, Box<dyn Error>> {
+    match exchange(ProtocolMessage::GetStatus)? {
+        ProtocolMessage::Status(status) => Ok(*status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected status response: {:?}", other).into()),
+    }
+}
+
+/// Uptime as hours, minutes and seconds
+pub fn format_uptime(uptime_ms: u32) -> String {
+    let seconds = uptime_ms / 1000;
+    format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+/// Shake-down countdown, or `None` once new settings run at full strength
+pub fn format_shakedown(shakedown: &ShakedownStatus) -> Option<String> {
+    if shakedown.startup_hold {
+        return Some("holding at spring pressure after power-up".to_string());
+    }
+    if !shakedown.active {
+        return None;
+    }
+    let mut left = Vec::new();
+    if let Some(remaining_ms) = shakedown.remaining_ms {
+        left.push(format!("{} min", remaining_ms.div_ceil(60_000)));
+    }
+    if let Some(pulls) = shakedown.remaining_pulls {
+        left.push(format!("{} pulls", pulls));
+    }
+    Some(format!("reduced limits, {} left", left.join(" or ")))
+}
+
+/// Background work tier with the headroom and temperature behind it
+pub fn format_tier(tier: &PerformanceTierStatus) -> String {
+    let name = match tier.tier {
+        PerformanceTier::Full => "full",
+        PerformanceTier::Reduced => "reduced (display and telemetry at half rate)",
+        PerformanceTier::Minimal => "minimal (display and telemetry at quarter rate, learning paused)",
+    };
+    let mut text = format!("{}, {:.0}% headroom", name, tier.headroom_percent);
+    if let Some(celsius) = tier.mcu_temperature_c {
+        text.push_str(&format!(", MCU {:.0}°C", celsius));
+    }
+    if tier.downshifts > 0 {
+        text.push_str(&format!(", {} downshifts", tier.downshifts));
+    }
+    text
+}
+
+/// Status report, one line per item
+pub fn render(status: &SystemStatus) -> String {
+    let stats = &status.stats;
+    let mut lines = vec![
+        format!("Device:         {} (configuration \"{}\")", status.device_name, status.active_configuration),
+        format!("State:          {}", status.state.display_text()),
+        format!("Control mode:   {}", status.control_mode.display_text()),
+        format!("Uptime:         {}", format_uptime(status.uptime_ms)),
+    ];
+    if let Some(reason) = status.spring_only {
+        lines.push(format!("Wastegate:      {}", reason.display_text()));
+    }
+    if let Some(shakedown) = format_shakedown(&status.shakedown) {
+        lines.push(format!("Shake-down:     {}", shakedown));
+    }
+    
+    lines.push(match &status.state {
+        SystemState::Fault(fault) => format!("Fault:          {} {}", fault.display_code(), fault.description()),
+        _ => "Fault:          none".to_string(),
+    });
+    let log = &status.safety_log;
+    lines.push(format!("Safety log:     {} events this power-up ({} dropped, {} write errors)",
+        log.logged, log.dropped, log.write_errors));
+    
+    lines.push("Control loop:".to_string());
+    lines.push(format!("  cycles        {} ({} outer)", stats.cycles_executed, stats.outer_cycles_executed));
+    lines.push(format!("  cycle time    {} µs average, {} µs max, {} late",
+        stats.avg_cycle_time_us, stats.max_cycle_time_us, stats.timing_violations));
+    lines.push(format!("  safety        {} interventions", stats.safety_interventions));
+    lines.push(format!("  learning      {} updates", stats.learning_updates));
+    lines.push(format!("  tier          {}", format_tier(&status.performance_tier)));
+    
+    if !status.telemetry_sinks.is_empty() {
+        lines.push("Telemetry sinks:".to_string());
+        for sink in &status.telemetry_sinks {
+            lines.push(format!("  {:<15} {} frames, {} errors", sink.name, sink.frames, sink.errors));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Print the status report
+pub fn print(status: &SystemStatus) {
+    println!("{}", render(status));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{FaultCode, SystemConfig};
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_uptime_format() {
+        assert_eq!(format_uptime(0), "0:00:00");
+        assert_eq!(format_uptime(3_725_400), "1:02:05");
+    }
+    
+    #[test]
+    fn test_status_through_device() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let status = fetch_status(&mut exchange).unwrap();
+        
+        let report = render(&status);
+        assert!(report.contains("Fault:          none"));
+        assert!(report.contains("Control loop:"));
+    }
+    
+    #[test]
+    fn test_fault_code_is_shown() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let mut status = fetch_status(&mut exchange).unwrap();
+        status.state = SystemState::Fault(FaultCode::CanCommunicationLost);
+        
+        assert!(render(&status).contains("Fault:          F04 CAN bus communication lost"));
+    }
+    
+    #[test]
+    fn test_shakedown_countdown() {
+        let mut status = ShakedownStatus { active: true, remaining_ms: Some(90_000), remaining_pulls: Some(3), startup_hold: false };
+        assert_eq!(format_shakedown(&status).unwrap(), "reduced limits, 2 min or 3 pulls left");
+        
+        status.remaining_ms = None;
+        assert_eq!(format_shakedown(&status).unwrap(), "reduced limits, 3 pulls left");
+        assert_eq!(format_shakedown(&ShakedownStatus::default()), None);
+    }
+    
+    #[test]
+    fn test_tier_line() {
+        let tier = PerformanceTierStatus {
+            tier: PerformanceTier::Reduced,
+            headroom_percent: 18.4,
+            mcu_temperature_c: Some(88.0),
+            downshifts: 1,
+            learning_samples_skipped: 0,
+        };
+        assert_eq!(format_tier(&tier), "reduced (display and telemetry at half rate), 18% headroom, MCU 88°C, 1 downshifts");
+        assert_eq!(format_tier(&PerformanceTierStatus { headroom_percent: 70.0, ..PerformanceTierStatus::default() }), "full, 70% headroom");
+    }
+}
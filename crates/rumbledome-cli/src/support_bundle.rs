@@ -0,0 +1,95 @@
+//! Support Bundle Archive
+//! 
+//! 🔗 T4-CLI-004: Support Bundle Export
+//! Derived From: T4-CORE-056 (Diagnostics Snapshot Bundle)
+//! AI Traceability: One file users attach to bug reports
+
+use std::error::Error;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::SupportBundle;
+
+/// Support bundle as written to disk, with host-side context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportArchive {
+    /// CLI version that fetched the bundle
+    pub cli_version: String,
+    /// Creation time (seconds since the Unix epoch)
+    pub created_unix_s: u64,
+    /// Bundle gathered by the device
+    pub bundle: SupportBundle,
+}
+
+impl SupportArchive {
+    /// Wrap a device bundle, stamped with the current time
+    pub fn new(bundle: SupportBundle) -> Self {
+        let created_unix_s = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        
+        Self {
+            cli_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_unix_s,
+            bundle,
+        }
+    }
+    
+    /// Default archive file name
+    pub fn default_file_name(&self) -> String {
+        format!("rumbledome-support-{}.json", self.created_unix_s)
+    }
+}
+
+/// Write an archive as pretty-printed JSON
+pub fn write_archive(path: &str, archive: &SupportArchive) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string_pretty(archive)?)?;
+    Ok(())
+}
+
+/// Read an archive back (e.g. when triaging a report)
+pub fn read_archive(path: &str) -> Result<SupportArchive, Box<dyn Error>> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Print a short summary of what an archive contains
+pub fn print_summary(archive: &SupportArchive) {
+    let bundle = &archive.bundle;
+    println!("Firmware {} on {} ({})",
+        bundle.firmware.firmware_version, bundle.firmware.platform, bundle.firmware.platform_version);
+    println!("Active configuration: {} ({} stored)",
+        bundle.configurations.active_name(), bundle.configurations.names().len());
+    println!("State: {}", bundle.status.state.display_text());
+    println!("Faults recorded: {}", bundle.fault_history.len());
+    for event in &bundle.overboost_events {
+        println!("Overboost at {} ms: {:.1} PSI - {}",
+            event.freeze_frame.timestamp_ms, event.freeze_frame.manifold_pressure, event.cause.description());
+    }
+    println!("Sessions included: {}", bundle.sessions.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_archive_round_trip() {
+        let device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let archive = SupportArchive::new(device.core().support_bundle());
+        
+        let path = std::env::temp_dir().join(archive.default_file_name());
+        let path = path.to_str().unwrap();
+        write_archive(path, &archive).unwrap();
+        let restored = read_archive(path).unwrap();
+        fs::remove_file(path).unwrap();
+        
+        assert_eq!(restored.bundle.configurations, archive.bundle.configurations);
+        assert_eq!(restored.bundle.storage.self_test_passed, Some(true));
+        assert_eq!(restored.bundle.sessions.len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+//! Long-Term Trends
+//! 
+//! 🔗 T4-CLI-012: Trend Sparklines
+//! Derived From: T4-CORE-116 (Weekly and Monthly Trend Records)
+//! AI Traceability: One line per metric across weeks or months, so a slow drift stands out at a glance
+//! 
+//! Sparklines are plain ASCII so they survive any terminal and a paste into a
+//! forum post. Each line is scaled from zero to its own maximum; a period
+//! without data for the metric is left blank.
+
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rumbledome_core::{civil_date, TrendRecord, TrendReport};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Sparkline levels, lowest first
+const LEVELS: &[u8] = b"_.-~=+*#";
+
+/// Today on this computer's clock (days since 1970-01-01, UTC)
+pub fn today() -> u32 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|since| (since.as_secs() / 86_400) as u32).unwrap_or(0)
+}
+
+/// Fetch trends, telling the device today's date
+pub fn fetch_trends(exchange: &mut Exchange, day: u32) -> Result<TrendReport, Box<dyn Error>> {
+    match exchange(ProtocolMessage::GetTrends { day })? {
+        ProtocolMessage::Trends(report) => Ok(report),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected trends response: {:?}", other).into()),
+    }
+}
+
+/// One character per value, scaled to the largest; `None` is a blank
+pub fn sparkline(values: &[Option<f32>]) -> String {
+    let max = values.iter().flatten().fold(0.0f32, |max, &value| max.max(value));
+    values.iter().map(|value| match value {
+        None => ' ',
+        Some(_) if max <= 0.0 => LEVELS[0] as char,
+        Some(value) => {
+            let level = (value / max * (LEVELS.len() - 1) as f32).round() as usize;
+            LEVELS[level.min(LEVELS.len() - 1)] as char
+        }
+    }).collect()
+}
+
+/// Value a sparkline row plots for one period
+type Metric = fn(&TrendRecord) -> Option<f32>;
+
+/// Print one sparkline per metric, oldest period on the left
+pub fn print_trends(records: &[TrendRecord], period: &str) {
+    let (Some(first), Some(latest)) = (records.first(), records.last()) else {
+        println!("No trends recorded yet");
+        return;
+    };
+    
+    let (year, month, day) = civil_date(first.start_day);
+    println!("{} {}s from {}-{:02}-{:02} (oldest left, latest value on the right)", records.len(), period, year, month, day);
+    
+    let metrics: [(&str, Metric, &str); 5] = [
+        ("Tracking error", TrendRecord::rms_tracking_error_psi, "PSI RMS"),
+        ("Overboost cuts", TrendRecord::overboost_per_hour, "per armed hour"),
+        ("Learning churn", TrendRecord::learning_per_hour, "updates per armed hour"),
+        ("Storage writes", |record| Some(record.storage_writes as f32), "records"),
+        ("Armed time", |record| Some(record.armed_s as f32 / 3600.0), "hours"),
+    ];
+    for (name, metric, unit) in metrics {
+        let values: Vec<Option<f32>> = records.iter().map(metric).collect();
+        let latest = match metric(latest) {
+            Some(value) => format!("{:.2} {}", value, unit),
+            None => "-".to_string(),
+        };
+        println!("  {:<15} |{}|  {}", name, sparkline(&values), latest);
+    }
+    println!("  {:<15}  {}", "Sessions", records.iter().map(|record| record.sessions).sum::<u16>());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_sparkline_scales_to_the_largest_value() {
+        assert_eq!(sparkline(&[Some(0.0), Some(0.5), None, Some(1.0)]), "_= #");
+        assert_eq!(sparkline(&[Some(0.0), Some(0.0)]), "__");
+        assert_eq!(sparkline(&[]), "");
+    }
+    
+    #[test]
+    fn test_fetch_dates_the_trends() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let report = fetch_trends(&mut exchange, 20_742).unwrap();
+        assert_eq!(report.weeks.len(), 1);
+        assert_eq!(civil_date(report.months[0].start_day), (2026, 10, 1));
+    }
+}
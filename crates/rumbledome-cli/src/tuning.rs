@@ -0,0 +1,187 @@
+//! Tuning Suggestions
+//! 
+//! 🔗 T4-CLI-007: Tracking Score and Gain Suggestions
+//! Derived From: T4-CORE-083 (Gain Suggestions) + T4-CORE-034 (Live-Tune Session Management)
+//! AI Traceability: Show how well the drive tracked and try the suggested nudges without committing to them
+
+use std::error::Error;
+
+use rumbledome_core::{DistributionReport, GainSuggestion, GapDistribution, LiveTuneStatus, RpmBand, SystemStatus};
+use rumbledome_protocol::ProtocolMessage;
+
+use crate::commissioning::Exchange;
+
+/// Send a live-tune request; device errors come back as `Err`
+fn live_tune_request(exchange: &mut Exchange, message: ProtocolMessage) -> Result<LiveTuneStatus, Box<dyn Error>> {
+    match exchange(message)? {
+        ProtocolMessage::LiveTuneStatus(status) => Ok(status),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected live-tune response: {:?}", other).into()),
+    }
+}
+
+/// Fetch the device status
+pub fn fetch_status(exchange: &mut Exchange) -> Result<SystemStatus, Box<dyn Error>> {
+    match exchange(ProtocolMessage::GetStatus)? {
+        ProtocolMessage::Status(status) => Ok(*status),
+        other => Err(format!("Unexpected status response: {:?}", other).into()),
+    }
+}
+
+/// Why the device offers no gain suggestions, if it withholds them
+pub fn withheld_note(status: &SystemStatus) -> Option<&'static str> {
+    status.gain_suggestions_withheld.then_some(
+        "Gain suggestions need cascaded dome control (dome_control.enabled) - the PID gains only act on its boost loop"
+    )
+}
+
+/// Print this drive's score and suggestions
+pub fn print_suggestions(status: &SystemStatus) {
+    match status.tracking_score {
+        Some(score) => println!("Boost tracking score: {}/100", score),
+        None => {
+            println!("No boost tracking yet - drive with boost held on target first");
+            return;
+        }
+    }
+    
+    if let Some(note) = withheld_note(status) {
+        println!("{}", note);
+        return;
+    }
+    
+    if status.gain_suggestions.is_empty() {
+        println!("No tuning changes suggested");
+        return;
+    }
+    
+    println!("Suggested changes:");
+    for suggestion in &status.gain_suggestions {
+        println!("  {}", suggestion);
+    }
+    println!("Run `tune apply` to try them as test values");
+}
+
+/// Fetch the torque gap and boost error distributions
+pub fn fetch_distribution(exchange: &mut Exchange) -> Result<DistributionReport, Box<dyn Error>> {
+    match exchange(ProtocolMessage::GetDistribution)? {
+        ProtocolMessage::Distribution(report) => Ok(report),
+        ProtocolMessage::Error(e) => Err(e.into()),
+        other => Err(format!("Unexpected distribution response: {:?}", other).into()),
+    }
+}
+
+/// Print where in the rev range assistance was needed and where boost fought the ECU
+/// 
+/// Percentages are of the armed time spent in each band. "On target" is boost
+/// within 0.3 PSI of target while boost was under active control.
+pub fn print_distribution(distribution: &GapDistribution) {
+    if distribution.is_empty() {
+        println!("No armed driving recorded yet");
+        return;
+    }
+    
+    let total: u32 = distribution.bands.iter().map(|band| band.torque_gap.total()).sum();
+    println!("  RPM          time  assist  fighting  on target");
+    for (index, band) in distribution.bands.iter().enumerate() {
+        let samples = band.torque_gap.total();
+        if samples == 0 {
+            continue;
+        }
+        let range = RpmBand::of(index);
+        let on_target = if band.boost_error.total() == 0 {
+            "-".to_string()
+        } else {
+            format!("{:.0}%", band.boost_error.fraction(3..4) * 100.0)
+        };
+        println!("  {:>5}-{:<5} {:>4.0}%  {:>5.0}%  {:>7.0}%  {:>9}",
+            range.low_rpm, range.high_rpm, samples as f32 / total as f32 * 100.0,
+            band.assist_fraction() * 100.0, band.fighting_fraction() * 100.0, on_target);
+    }
+}
+
+/// Apply suggestions as volatile live-tune test values
+/// 
+/// Nothing is stored: the values revert on timeout, disconnect or restart
+/// unless committed with `tune commit`.
+pub fn apply(exchange: &mut Exchange, suggestions: &[GainSuggestion]) -> Result<(), Box<dyn Error>> {
+    if suggestions.is_empty() {
+        println!("No tuning changes suggested");
+        return Ok(());
+    }
+    
+    let mut status = None;
+    for suggestion in suggestions {
+        let message = ProtocolMessage::SetTestValue { parameter: suggestion.parameter, value: suggestion.suggested };
+        status = Some(live_tune_request(exchange, message)?);
+        println!("  {} = {:.3} (test value)", suggestion.parameter.short_name(), suggestion.suggested);
+    }
+    
+    if let Some(status) = status {
+        println!("Test values revert in {} s unless committed with `tune commit`", status.revert_in_ms / 1000);
+    }
+    Ok(())
+}
+
+/// Store the test values in effect
+pub fn commit(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    live_tune_request(exchange, ProtocolMessage::CommitTestValues)?;
+    println!("Test values committed");
+    Ok(())
+}
+
+/// Discard test values and restore stored tuning
+pub fn revert(exchange: &mut Exchange) -> Result<(), Box<dyn Error>> {
+    live_tune_request(exchange, ProtocolMessage::RevertTestValues)?;
+    println!("Stored tuning restored");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{DomeControlConfig, RpmBand, SystemConfig, TuneParameter};
+    use rumbledome_sim::VirtualDevice;
+    
+    #[test]
+    fn test_apply_goes_through_live_tune() {
+        let dome_control = DomeControlConfig { enabled: true, ..DomeControlConfig::default() };
+        let mut device = VirtualDevice::new(SystemConfig { dome_control, ..SystemConfig::default() }).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        let suggestion = GainSuggestion {
+            parameter: TuneParameter::PidKi,
+            current: 1.0,
+            suggested: 1.1,
+            band: RpmBand { low_rpm: 3000, high_rpm: 4000 },
+            reason: "persistent steady-state error of 0.4 PSI below target".to_string(),
+        };
+        
+        apply(&mut exchange, &[suggestion]).unwrap();
+        let status = live_tune_request(&mut exchange, ProtocolMessage::GetLiveTuneStatus).unwrap();
+        assert!(status.volatile);
+        assert_eq!(status.active.pid_ki, 1.1);
+        
+        revert(&mut exchange).unwrap();
+        assert!(!live_tune_request(&mut exchange, ProtocolMessage::GetLiveTuneStatus).unwrap().volatile);
+    }
+    
+    #[test]
+    fn test_default_config_explains_missing_suggestions() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        let status = fetch_status(&mut exchange).unwrap();
+        assert!(status.gain_suggestions.is_empty());
+        assert!(withheld_note(&status).unwrap().contains("dome_control.enabled"));
+    }
+    
+    #[test]
+    fn test_fetch_distribution() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut exchange = |message| Ok(device.handle(message, 0));
+        
+        let report = fetch_distribution(&mut exchange).unwrap();
+        assert!(report.current.is_empty());
+        assert_eq!(report.sessions, 1);
+    }
+}
@@ -0,0 +1,374 @@
+//! Actuation Effectiveness Monitor
+//! 
+//! 🔗 T4-CORE-105: Statistical PWM Output Verification
+//! Derived From: T4-CORE-100 (Twin Turbo Bank Control) + T4-CORE-086 (Overboost Cause Classifier)
+//! AI Traceability: A tiring solenoid or a developing dome leak shows up as a trend in dome response long before it shows up as an overboost
+//! 
+//! A 4-port valve at a given duty should put roughly that fraction of the
+//! feed pressure on the upper dome and the rest on the lower dome. Over each
+//! rolling window the upper dome's share of feed is regressed against the
+//! commanded duty; the slope is the actuation effectiveness (1.0 = the dome
+//! follows duty exactly). Windows where duty barely moved say nothing about
+//! the slope and are discarded.
+//! 
+//! Window results are smoothed and compared with an absolute floor and with
+//! the first usable window since power-up. When effectiveness declines, an
+//! advisory names the likely cause: if the two domes together hold well under
+//! the feed pressure, air is escaping from the dome circuit; if the total is
+//! intact but the split no longer follows duty, the valve is wearing out.
+//! Monitoring never changes the output.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemInputs};
+use crate::twin_turbo::{BALANCE_DUTY_RANGE, MIN_FEED_PSI};
+
+/// Fewest samples in a window worth a regression
+const MIN_WINDOW_SAMPLES: u32 = 200;
+
+/// Duty standard deviation a window needs for the slope to mean anything (%)
+const MIN_DUTY_SPREAD_PERCENT: f32 = 5.0;
+
+/// Share of feed pressure both domes together should hold - below is a leak
+const MIN_DOME_RETENTION: f32 = 0.85;
+
+/// Weight of each new window in the smoothed effectiveness
+const EFFECTIVENESS_FILTER: f32 = 0.3;
+
+/// Windows needed before an advisory can be raised
+const MIN_WINDOWS_FOR_ADVISORY: u32 = 3;
+
+/// Actuation monitor settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActuationMonitorConfig {
+    /// Correlate commanded duty with dome pressure while boosting
+    pub enabled: bool,
+    /// Length of each rolling window (ms)
+    pub window_ms: u32,
+    /// Effectiveness below which an advisory is raised
+    pub min_effectiveness: f32,
+    /// Drop from the power-up baseline that raises an advisory
+    pub max_decline: f32,
+}
+
+impl Default for ActuationMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_ms: 30_000,
+            min_effectiveness: 0.7,
+            max_decline: 0.2,
+        }
+    }
+}
+
+impl ActuationMonitorConfig {
+    /// Validate window length and thresholds
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(5_000..=300_000).contains(&self.window_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Actuation monitor window must be 5000-300000 ms, got {}", self.window_ms)
+            ));
+        }
+        if !(0.3..=0.95).contains(&self.min_effectiveness) {
+            return Err(CoreError::ConfigurationError(
+                format!("Minimum actuation effectiveness must be 0.3-0.95, got {}", self.min_effectiveness)
+            ));
+        }
+        if !(0.05..=0.5).contains(&self.max_decline) {
+            return Err(CoreError::ConfigurationError(
+                format!("Actuation effectiveness decline limit must be 0.05-0.5, got {}", self.max_decline)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Likely cause of declining actuation effectiveness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActuationAdvisory {
+    /// Dome split no longer follows duty, with dome pressure intact
+    SolenoidWear,
+    /// Domes together hold well under the feed pressure
+    DomeLeak,
+}
+
+impl ActuationAdvisory {
+    /// Short description for logs and diagnostics
+    pub fn description(&self) -> &'static str {
+        match self {
+            ActuationAdvisory::SolenoidWear => "Dome pressure no longer follows solenoid duty - valve may be wearing out",
+            ActuationAdvisory::DomeLeak => "Domes hold less than the feed pressure - likely dome circuit leak",
+        }
+    }
+    
+    /// What the user should check
+    pub fn recommended_action(&self) -> &'static str {
+        match self {
+            ActuationAdvisory::SolenoidWear => "Clean or replace the solenoid valve",
+            ActuationAdvisory::DomeLeak => "Check dome lines, fittings and wastegate diaphragm for leaks",
+        }
+    }
+}
+
+/// Regression of one window
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActuationWindow {
+    /// Dome share of feed per unit duty (1.0 = follows duty exactly)
+    pub effectiveness: f32,
+    /// Correlation between duty and dome share (-1 to 1)
+    pub correlation: f32,
+    /// Mean share of feed pressure held by both domes together
+    pub dome_retention: f32,
+    /// Samples in the window
+    pub samples: u32,
+}
+
+/// Running duty/dome statistics (Welford, so long windows stay precise in f32)
+#[derive(Debug, Clone, Default)]
+struct Regression {
+    count: u32,
+    mean_duty: f32,
+    mean_dome: f32,
+    duty_moment: f32,
+    dome_moment: f32,
+    co_moment: f32,
+    retention_sum: f32,
+}
+
+impl Regression {
+    fn add(&mut self, duty: f32, dome: f32, retention: f32) {
+        self.count += 1;
+        let n = self.count as f32;
+        let duty_delta = duty - self.mean_duty;
+        let dome_delta = dome - self.mean_dome;
+        self.mean_duty += duty_delta / n;
+        self.mean_dome += dome_delta / n;
+        self.duty_moment += duty_delta * (duty - self.mean_duty);
+        self.dome_moment += dome_delta * (dome - self.mean_dome);
+        self.co_moment += duty_delta * (dome - self.mean_dome);
+        self.retention_sum += retention;
+    }
+    
+    fn result(&self) -> Option<ActuationWindow> {
+        let n = self.count as f32;
+        if self.count < MIN_WINDOW_SAMPLES || self.duty_moment / n < MIN_DUTY_SPREAD_PERCENT * MIN_DUTY_SPREAD_PERCENT {
+            return None;
+        }
+        let correlation = if self.dome_moment > 0.0 {
+            self.co_moment / libm::sqrtf(self.duty_moment * self.dome_moment)
+        } else {
+            0.0
+        };
+        Some(ActuationWindow {
+            effectiveness: self.co_moment / self.duty_moment,
+            correlation,
+            dome_retention: self.retention_sum / n,
+            samples: self.count,
+        })
+    }
+}
+
+/// Actuation monitor status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActuationStatus {
+    /// Smoothed effectiveness (None until a window completes)
+    pub effectiveness: Option<f32>,
+    /// First usable window's effectiveness since power-up
+    pub baseline: Option<f32>,
+    /// Most recent usable window
+    pub last_window: Option<ActuationWindow>,
+    /// Usable windows since power-up
+    pub windows: u32,
+    /// Advisory currently raised
+    pub advisory: Option<ActuationAdvisory>,
+    /// Advisories raised since power-up
+    pub advisories: u32,
+}
+
+/// Correlates commanded duty with dome pressure over rolling windows
+#[derive(Debug, Clone)]
+pub struct ActuationMonitor {
+    config: ActuationMonitorConfig,
+    window: Regression,
+    window_started_ms: Option<u32>,
+    effectiveness: Option<f32>,
+    baseline: Option<f32>,
+    last_window: Option<ActuationWindow>,
+    windows: u32,
+    advisory: Option<ActuationAdvisory>,
+    advisories: u32,
+}
+
+impl ActuationMonitor {
+    /// Create with no history
+    pub fn new(config: ActuationMonitorConfig) -> Self {
+        Self {
+            config,
+            window: Regression::default(),
+            window_started_ms: None,
+            effectiveness: None,
+            baseline: None,
+            last_window: None,
+            windows: 0,
+            advisory: None,
+            advisories: 0,
+        }
+    }
+    
+    /// Apply changed settings; history describes the valve, so it is kept
+    pub fn reconfigure(&mut self, config: ActuationMonitorConfig) {
+        self.config = config;
+    }
+    
+    /// Observe one cycle of a single 4-port valve driven at `duty` (%)
+    /// 
+    /// Returns the advisory when one is newly raised.
+    pub fn observe(&mut self, inputs: &SystemInputs, duty: f32) -> Option<ActuationAdvisory> {
+        if !self.config.enabled {
+            return None;
+        }
+        
+        let feed = inputs.dome_input_pressure;
+        if feed >= MIN_FEED_PSI && BALANCE_DUTY_RANGE.contains(&duty) {
+            let retention = (inputs.upper_dome_pressure + inputs.lower_dome_pressure) / feed;
+            self.window.add(duty, 100.0 * inputs.upper_dome_pressure / feed, retention);
+        }
+        
+        let started = *self.window_started_ms.get_or_insert(inputs.timestamp_ms);
+        if inputs.timestamp_ms.wrapping_sub(started) < self.config.window_ms {
+            return None;
+        }
+        let window = core::mem::take(&mut self.window);
+        self.window_started_ms = Some(inputs.timestamp_ms);
+        self.close_window(window.result()?)
+    }
+    
+    fn close_window(&mut self, window: ActuationWindow) -> Option<ActuationAdvisory> {
+        self.windows += 1;
+        self.last_window = Some(window);
+        let baseline = *self.baseline.get_or_insert(window.effectiveness);
+        let effectiveness = match self.effectiveness {
+            Some(previous) => previous + (window.effectiveness - previous) * EFFECTIVENESS_FILTER,
+            None => window.effectiveness,
+        };
+        self.effectiveness = Some(effectiveness);
+        
+        let decline = baseline - effectiveness;
+        if effectiveness >= self.config.min_effectiveness && decline <= self.config.max_decline / 2.0 {
+            // Recovered - clear with hysteresis so the advisory does not flicker
+            self.advisory = None;
+            return None;
+        }
+        let declined = effectiveness < self.config.min_effectiveness || decline > self.config.max_decline;
+        if !declined || self.advisory.is_some() || self.windows < MIN_WINDOWS_FOR_ADVISORY {
+            return None;
+        }
+        
+        let advisory = if window.dome_retention < MIN_DOME_RETENTION {
+            ActuationAdvisory::DomeLeak
+        } else {
+            ActuationAdvisory::SolenoidWear
+        };
+        self.advisory = Some(advisory);
+        self.advisories += 1;
+        Some(advisory)
+    }
+    
+    /// Advisory currently raised
+    pub fn advisory(&self) -> Option<ActuationAdvisory> {
+        self.advisory
+    }
+    
+    /// Status snapshot for diagnostics
+    pub fn status(&self) -> ActuationStatus {
+        ActuationStatus {
+            effectiveness: self.effectiveness,
+            baseline: self.baseline,
+            last_window: self.last_window,
+            windows: self.windows,
+            advisory: self.advisory,
+            advisories: self.advisories,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    const FEED_PSI: f32 = 40.0;
+    
+    /// Boosting cycle with duty swept 20-80% and domes answering it
+    /// 
+    /// `response` scales how far the dome split moves with duty around 50%;
+    /// `retention` is the share of feed both domes hold together.
+    fn cycle(timestamp_ms: u32, response: f32, retention: f32) -> (SystemInputs, f32) {
+        let duty = 20.0 + ((timestamp_ms / 10) % 60) as f32;
+        let share = 0.5 + (duty / 100.0 - 0.5) * response;
+        let inputs = SystemInputs {
+            rpm: 4500,
+            manifold_pressure: 8.0,
+            dome_input_pressure: FEED_PSI,
+            upper_dome_pressure: share * FEED_PSI * retention,
+            lower_dome_pressure: (1.0 - share) * FEED_PSI * retention,
+            ..test_support::inputs(timestamp_ms)
+        };
+        (inputs, duty)
+    }
+    
+    fn run(monitor: &mut ActuationMonitor, from_ms: u32, to_ms: u32, response: f32, retention: f32) -> Option<ActuationAdvisory> {
+        let mut raised = None;
+        for t in (from_ms..to_ms).step_by(10) {
+            let (inputs, duty) = cycle(t, response, retention);
+            if let Some(advisory) = monitor.observe(&inputs, duty) {
+                assert!(raised.is_none(), "advisory raised twice");
+                raised = Some(advisory);
+            }
+        }
+        raised
+    }
+    
+    #[test]
+    fn test_healthy_valve_tracks_duty() {
+        let mut monitor = ActuationMonitor::new(ActuationMonitorConfig::default());
+        assert_eq!(run(&mut monitor, 0, 200_000, 1.0, 1.0), None);
+        
+        let status = monitor.status();
+        assert!(status.windows >= 5);
+        assert!((status.effectiveness.unwrap() - 1.0).abs() < 0.01);
+        assert!(status.last_window.unwrap().correlation > 0.99);
+        assert_eq!(status.advisories, 0);
+    }
+    
+    #[test]
+    fn test_decline_from_baseline_names_worn_valve() {
+        let mut monitor = ActuationMonitor::new(ActuationMonitorConfig::default());
+        assert_eq!(run(&mut monitor, 0, 100_000, 1.0, 1.0), None);
+        
+        // Still above the floor, but well down on how it started
+        assert_eq!(run(&mut monitor, 100_000, 400_000, 0.75, 1.0), Some(ActuationAdvisory::SolenoidWear));
+        assert!((monitor.status().baseline.unwrap() - 1.0).abs() < 0.01);
+        
+        // A serviced valve clears the advisory
+        run(&mut monitor, 400_000, 700_000, 1.0, 1.0);
+        assert_eq!(monitor.advisory(), None);
+        assert_eq!(monitor.status().advisories, 1);
+    }
+    
+    #[test]
+    fn test_leak_detected_and_steady_duty_ignored() {
+        let mut monitor = ActuationMonitor::new(ActuationMonitorConfig::default());
+        assert_eq!(run(&mut monitor, 0, 300_000, 0.6, 0.7), Some(ActuationAdvisory::DomeLeak));
+        
+        // Duty held steady gives no spread to regress against
+        let mut steady = ActuationMonitor::new(ActuationMonitorConfig::default());
+        for t in (0..200_000).step_by(10) {
+            let (inputs, _) = cycle(t, 0.5, 0.5);
+            assert_eq!(steady.observe(&inputs, 50.0), None);
+        }
+        assert_eq!(steady.status().windows, 0);
+    }
+}
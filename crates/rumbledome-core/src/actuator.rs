@@ -0,0 +1,302 @@
+//! Wastegate Actuator Drivers
+//! 
+//! 🔗 T4-CORE-069: Pluggable Output Stage
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + T4-HAL-013 (Electronic Actuator Output) + T4-CORE-024 (Dual-Solenoid Limp Control)
+//! AI Traceability: Control hierarchy commands wastegate authority; the configured driver turns it into solenoid duty or actuator position
+
+use alloc::boxed::Box;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{ActuatorInterface, HalResult, HalTrait, SolenoidChannel};
+use crate::{PulseShaper, PwmDither, SolenoidFailover, SolenoidTopology, SystemConfig, VoltageCompensation};
+
+/// Wastegate actuator hardware (hardware description, not a tuning parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ActuatorType {
+    /// Pneumatic dome control through PWM solenoid(s) - see `SolenoidTopology`
+    #[default]
+    PwmSolenoid,
+    /// Electronic actuator driven by the controller (H-bridge motor or stepper)
+    MotorPosition,
+    /// Electronic actuator with its own position control, commanded over CAN
+    CanActuator,
+}
+
+impl ActuatorType {
+    /// Short description for status displays
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            ActuatorType::PwmSolenoid => "PWM solenoid",
+            ActuatorType::MotorPosition => "Motor actuator",
+            ActuatorType::CanActuator => "CAN actuator",
+        }
+    }
+}
+
+/// Output stage driver
+/// 
+/// 🔗 T4-CORE-070: Actuator Driver Interface
+/// Derived From: T4-CORE-069
+/// 
+/// Authority is wastegate closure: 0% = wastegate open (failsafe),
+/// 100% = fully closed. `outputs` carries solenoid channel state, which only
+/// the solenoid driver uses to route around failed channels.
+pub trait ActuatorDriver<H: HalTrait> {
+    /// Hardware this driver controls
+    fn actuator_type(&self) -> ActuatorType;
+    
+    /// Command wastegate authority (0.0-100.0 %)
+    fn command_authority(&mut self, hal: &mut H, authority_percent: f32, outputs: &SolenoidFailover) -> HalResult<()>;
+    
+    /// Command each turbo bank's authority (0.0-100.0 %, bank 1 first)
+    /// 
+    /// Only twin layouts drive banks separately, and only on solenoid valves;
+    /// other drivers command bank 1's authority.
+    fn command_bank_authorities(&mut self, hal: &mut H, authorities: [f32; 2], outputs: &SolenoidFailover) -> HalResult<()> {
+        self.command_authority(hal, authorities[0], outputs)
+    }
+    
+    /// Open the wastegate; `immediate` skips any output timing synchronization
+    fn command_failsafe(&mut self, hal: &mut H, immediate: bool, outputs: &SolenoidFailover) -> HalResult<()>;
+    
+    /// Authority currently applied (%)
+    fn current_authority(&self, hal: &H) -> f32;
+}
+
+/// Pneumatic solenoid driver - authority is PWM duty at nominal supply voltage
+#[derive(Debug, Clone)]
+pub struct PwmSolenoidDriver {
+    dither: PwmDither,
+    compensation: VoltageCompensation,
+    /// Primary and secondary valve
+    shapers: [PulseShaper; 2],
+}
+
+impl PwmSolenoidDriver {
+    /// Create driver with the given duty dither, supply voltage compensation and pulse shaping
+    pub fn new(dither: PwmDither, compensation: VoltageCompensation, shaper: PulseShaper) -> Self {
+        Self { dither, compensation, shapers: [shaper.clone(), shaper] }
+    }
+}
+
+impl<H: HalTrait> ActuatorDriver<H> for PwmSolenoidDriver {
+    fn actuator_type(&self) -> ActuatorType {
+        ActuatorType::PwmSolenoid
+    }
+    
+    fn command_authority(&mut self, hal: &mut H, authority_percent: f32, outputs: &SolenoidFailover) -> HalResult<()> {
+        // Route authority command to the solenoid channels still in service
+        let duty = self.compensation.apply(self.dither.apply(authority_percent), hal.supply_voltage().ok());
+        let duty = self.shapers[0].apply(duty);
+        let duties = outputs.channel_duties(duty);
+        
+        // Update PWM with timing synchronization
+        hal.set_duty_cycle_synchronized(duties.primary, hal.now_us())?;
+        if outputs.topology() != SolenoidTopology::SingleFourPort {
+            hal.set_channel_duty_cycle(SolenoidChannel::Secondary, duties.secondary)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn command_bank_authorities(&mut self, hal: &mut H, authorities: [f32; 2], outputs: &SolenoidFailover) -> HalResult<()> {
+        // One dither offset for both valves keeps the banks in step
+        let offset = self.dither.apply(authorities[0]) - authorities[0];
+        let supply = hal.supply_voltage().ok();
+        let [bank1, bank2] = authorities.map(|authority| self.compensation.apply(authority + offset, supply));
+        let (bank1, bank2) = (self.shapers[0].apply(bank1), self.shapers[1].apply(bank2));
+        
+        // Failover zeroes both banks when either valve is lost
+        hal.set_duty_cycle_synchronized(outputs.channel_duties(bank1).primary, hal.now_us())?;
+        hal.set_channel_duty_cycle(SolenoidChannel::Secondary, outputs.channel_duties(bank2).secondary)?;
+        
+        Ok(())
+    }
+    
+    fn command_failsafe(&mut self, hal: &mut H, immediate: bool, outputs: &SolenoidFailover) -> HalResult<()> {
+        self.shapers.iter_mut().for_each(PulseShaper::reset);
+        if immediate {
+            hal.set_duty_cycle_immediate(0.0)?;
+        } else {
+            hal.set_duty_cycle(0.0)?;
+        }
+        
+        if outputs.topology() != SolenoidTopology::SingleFourPort {
+            hal.set_channel_duty_cycle(SolenoidChannel::Secondary, 0.0)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn current_authority(&self, hal: &H) -> f32 {
+        hal.get_current_duty() / self.compensation.last_factor()
+    }
+}
+
+/// Electronic actuator driver - authority is wastegate position
+#[derive(Debug, Clone, Copy)]
+pub struct PositionActuatorDriver {
+    interface: ActuatorInterface,
+}
+
+impl PositionActuatorDriver {
+    /// Create driver for an actuator on the given interface
+    pub fn new(interface: ActuatorInterface) -> Self {
+        Self { interface }
+    }
+}
+
+impl<H: HalTrait> ActuatorDriver<H> for PositionActuatorDriver {
+    fn actuator_type(&self) -> ActuatorType {
+        match self.interface {
+            ActuatorInterface::MotorDriver => ActuatorType::MotorPosition,
+            ActuatorInterface::Can => ActuatorType::CanActuator,
+        }
+    }
+    
+    fn command_authority(&mut self, hal: &mut H, authority_percent: f32, _outputs: &SolenoidFailover) -> HalResult<()> {
+        hal.set_actuator_position(self.interface, authority_percent.clamp(0.0, 100.0))
+    }
+    
+    fn command_failsafe(&mut self, hal: &mut H, _immediate: bool, _outputs: &SolenoidFailover) -> HalResult<()> {
+        // Position commands take effect at once - there is no PWM cycle to wait for
+        hal.set_actuator_position(self.interface, 0.0)
+    }
+    
+    fn current_authority(&self, hal: &H) -> f32 {
+        hal.get_actuator_position(self.interface).unwrap_or(0.0)
+    }
+}
+
+/// Driver for the configured actuator hardware
+pub fn actuator_driver<H: HalTrait>(config: &SystemConfig) -> Box<dyn ActuatorDriver<H>> {
+    match config.actuator_type {
+        ActuatorType::PwmSolenoid => Box::new(PwmSolenoidDriver::new(
+            PwmDither::new(config.pwm_dither.clone(), config.control_rates.inner_hz),
+            VoltageCompensation::new(config.voltage_compensation.clone()),
+            PulseShaper::new(&config.pulse_shaping, config.pwm_frequency_hz),
+        )),
+        ActuatorType::MotorPosition => Box::new(PositionActuatorDriver::new(ActuatorInterface::MotorDriver)),
+        ActuatorType::CanActuator => Box::new(PositionActuatorDriver::new(ActuatorInterface::Can)),
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use rumbledome_hal::{ActuatorOutput, MockHal, PwmControl};
+    use crate::PwmDitherConfig;
+    
+    fn driver(actuator_type: ActuatorType) -> Box<dyn ActuatorDriver<MockHal>> {
+        actuator_driver(&SystemConfig { actuator_type, ..SystemConfig::default() })
+    }
+    
+    #[test]
+    fn test_solenoid_driver_routes_authority_to_channels() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::DualSolenoid);
+        let mut driver = driver(ActuatorType::PwmSolenoid);
+        
+        driver.command_authority(&mut hal, 60.0, &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Primary), 60.0);
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 60.0);
+        assert_eq!(driver.current_authority(&hal), 60.0);
+        
+        driver.command_failsafe(&mut hal, true, &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 0.0);
+        assert_eq!(driver.current_authority(&hal), 0.0);
+    }
+    
+    #[test]
+    fn test_solenoid_driver_drives_banks_separately() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::TwinFourPort);
+        let mut driver = driver(ActuatorType::PwmSolenoid);
+        
+        driver.command_bank_authorities(&mut hal, [55.0, 48.0], &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Primary), 55.0);
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 48.0);
+        
+        driver.command_failsafe(&mut hal, true, &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Primary), 0.0);
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 0.0);
+    }
+    
+    #[test]
+    fn test_position_drivers_command_their_interface() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::SingleFourPort);
+        let mut motor = driver(ActuatorType::MotorPosition);
+        let mut can = driver(ActuatorType::CanActuator);
+        
+        motor.command_authority(&mut hal, 45.0, &outputs).unwrap();
+        can.command_authority(&mut hal, 120.0, &outputs).unwrap();
+        assert_eq!(hal.get_actuator_position(ActuatorInterface::MotorDriver).unwrap(), 45.0);
+        assert_eq!(hal.get_actuator_position(ActuatorInterface::Can).unwrap(), 100.0);
+        
+        // Solenoid output untouched
+        assert_eq!(hal.get_current_duty(), 0.0);
+        
+        motor.command_failsafe(&mut hal, false, &outputs).unwrap();
+        assert_eq!(motor.current_authority(&hal), 0.0);
+        assert_eq!(can.actuator_type(), ActuatorType::CanActuator);
+    }
+    
+    #[test]
+    fn test_solenoid_driver_applies_dither() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::SingleFourPort);
+        let config = SystemConfig {
+            pwm_dither: PwmDitherConfig { enabled: true, amplitude_percent: 3.0, frequency_hz: 50 },
+            ..SystemConfig::default()
+        };
+        let mut driver = actuator_driver::<MockHal>(&config);
+        
+        driver.command_authority(&mut hal, 20.0, &outputs).unwrap();
+        assert_eq!(hal.get_current_duty(), 23.0);
+        driver.command_authority(&mut hal, 20.0, &outputs).unwrap();
+        assert_eq!(hal.get_current_duty(), 17.0);
+        
+        // Failsafe is never dithered
+        driver.command_failsafe(&mut hal, true, &outputs).unwrap();
+        assert_eq!(hal.get_current_duty(), 0.0);
+    }
+    
+    #[test]
+    fn test_solenoid_driver_compensates_voltage_sag() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::SingleFourPort);
+        let mut driver = driver(ActuatorType::PwmSolenoid);
+        
+        driver.command_authority(&mut hal, 40.0, &outputs).unwrap();
+        assert_eq!(hal.get_current_duty(), 40.0);
+        
+        // Sagging supply needs more duty for the same coil voltage; authority is reported uncompensated
+        hal.set_supply_voltage(12.0);
+        driver.command_authority(&mut hal, 40.0, &outputs).unwrap();
+        assert!((hal.get_current_duty() - 46.0).abs() < 0.01);
+        assert!((driver.current_authority(&hal) - 40.0).abs() < 0.01);
+    }
+    
+    #[test]
+    fn test_solenoid_driver_shapes_short_pulses_per_valve() {
+        let mut hal = MockHal::new();
+        let outputs = SolenoidFailover::new(SolenoidTopology::TwinFourPort);
+        let mut driver = driver(ActuatorType::PwmSolenoid);
+        
+        // 3 ms minimum pulse at 30 Hz is 9 % duty
+        driver.command_bank_authorities(&mut hal, [4.0, 12.0], &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Primary), 0.0);
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 12.0);
+        
+        // Bank 2 stays open at the minimum pulse; bank 1 has not opened
+        driver.command_bank_authorities(&mut hal, [7.0, 7.0], &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Primary), 0.0);
+        assert!((hal.get_channel_duty(SolenoidChannel::Secondary) - 9.0).abs() < 0.01);
+        
+        // Failsafe clears the held state
+        driver.command_failsafe(&mut hal, true, &outputs).unwrap();
+        driver.command_bank_authorities(&mut hal, [7.0, 7.0], &outputs).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 0.0);
+    }
+}
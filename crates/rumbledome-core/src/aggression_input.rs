@@ -0,0 +1,499 @@
+//! Aggression Input Arbitration
+//! 
+//! 🔗 T4-CORE-123: Aggression Input Arbitration
+//! Derived From: T4-CORE-011 (5-Parameter Configuration Structure) + T4-CORE-037 (CAN vs Analog Source Arbitration)
+//! AI Traceability: One knob, several hands on it - a dash knob, the car's drive mode switch and a phone all get a say, and exactly one of them decides
+//! 
+//! Besides the stored setting, aggression can come from a potentiometer on an
+//! expansion input, from the vehicle's drive mode CAN signal (each mode value
+//! mapped to an aggression) or from a connected app. The configured policy
+//! picks one: the first usable source in priority order, the source changed
+//! most recently, or one locked source. A drive mode or app value not heard
+//! from for the timeout drops out - apps keep their value by sending it
+//! again - as does a knob whose wiring reads open or shorted. With no usable
+//! source the stored setting applies.
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::ANALOG_CHANNELS;
+use crate::{CoreError, ANALOG_PRESSURE_INPUTS};
+
+/// Knob movement that counts as a change rather than noise (aggression 0.0-1.0)
+const CHANGE_DEADBAND: f32 = 0.02;
+
+/// Knob voltage beyond its end stops that still reads as an end stop (V)
+/// Further out, the wiring is open or shorted.
+const KNOB_END_STOP_MARGIN_V: f32 = 0.3;
+
+/// Where an aggression value comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AggressionSource {
+    /// Stored `aggression` of the running configuration
+    Setting,
+    /// Potentiometer on an expansion input
+    Knob,
+    /// Vehicle drive mode CAN signal
+    DriveMode,
+    /// Connected app (`SetAggression`)
+    App,
+}
+
+impl AggressionSource {
+    /// Short name for displays and logs
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            AggressionSource::Setting => "setting",
+            AggressionSource::Knob => "knob",
+            AggressionSource::DriveMode => "drive mode",
+            AggressionSource::App => "app",
+        }
+    }
+}
+
+/// How one source is chosen when several are usable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AggressionPolicy {
+    /// First usable source in the priority list
+    #[default]
+    Priority,
+    /// Usable source changed most recently; priority order until one changes
+    LastWriter,
+    /// Only this source (the stored setting while it is unusable)
+    Locked(AggressionSource),
+}
+
+/// Potentiometer wiring
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KnobConfig {
+    /// Expansion input the wiper is wired to
+    pub channel: u8,
+    /// Wiper voltage at aggression 0.0 (V)
+    pub min_volts: f32,
+    /// Wiper voltage at aggression 1.0 (V)
+    pub max_volts: f32,
+}
+
+impl Default for KnobConfig {
+    fn default() -> Self {
+        Self {
+            channel: ANALOG_PRESSURE_INPUTS as u8 + 1,
+            min_volts: 0.5,
+            max_volts: 4.5,
+        }
+    }
+}
+
+impl KnobConfig {
+    /// Aggression for a wiper voltage, or `None` when the wiring is open or shorted
+    pub fn position(&self, volts: f32) -> Option<f32> {
+        let (low, high) = (self.min_volts.min(self.max_volts), self.min_volts.max(self.max_volts));
+        if volts < low - KNOB_END_STOP_MARGIN_V || volts > high + KNOB_END_STOP_MARGIN_V {
+            return None;
+        }
+        Some(((volts - self.min_volts) / (self.max_volts - self.min_volts)).clamp(0.0, 1.0))
+    }
+}
+
+/// Aggression for one drive mode signal value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DriveModeAggression {
+    /// Decoded drive mode signal value
+    pub mode: u16,
+    /// Aggression while the vehicle is in this mode (0.0-1.0)
+    pub aggression: f32,
+}
+
+/// Aggression inputs and the policy arbitrating between them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AggressionInputConfig {
+    /// How one source is chosen
+    pub policy: AggressionPolicy,
+    /// Sources taking part, most preferred first (the stored setting is always the last resort)
+    pub priority: Vec<AggressionSource>,
+    /// A drive mode or app value not heard from for this long drops out (ms)
+    pub timeout_ms: u32,
+    /// Aggression potentiometer, if fitted
+    #[serde(default)]
+    pub knob: Option<KnobConfig>,
+    /// Aggression per drive mode (needs the `drive_mode` CAN signal); unlisted modes drop out
+    #[serde(default)]
+    pub drive_modes: Vec<DriveModeAggression>,
+}
+
+impl Default for AggressionInputConfig {
+    fn default() -> Self {
+        Self {
+            policy: AggressionPolicy::Priority,
+            priority: vec![AggressionSource::Knob, AggressionSource::DriveMode, AggressionSource::App],
+            timeout_ms: 3_000,
+            knob: None,
+            drive_modes: Vec::new(),
+        }
+    }
+}
+
+impl AggressionInputConfig {
+    /// Validate the policy, timeout, knob wiring and drive mode table
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for (index, source) in self.priority.iter().enumerate() {
+            if *source == AggressionSource::Setting {
+                return Err(CoreError::ConfigurationError(
+                    "Aggression priority must not list the stored setting - it is always the last resort".to_string()
+                ));
+            }
+            if self.priority[..index].contains(source) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Aggression priority lists {} twice", source.display_text())
+                ));
+            }
+        }
+        if !(100..=60_000).contains(&self.timeout_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Aggression input timeout must be 100-60000 ms, got {}", self.timeout_ms)
+            ));
+        }
+        
+        if let Some(knob) = &self.knob {
+            // Channels below the expansion inputs carry the boost and dome sensors
+            if !(ANALOG_PRESSURE_INPUTS..ANALOG_CHANNELS).contains(&(knob.channel as usize)) {
+                return Err(CoreError::ConfigurationError(format!(
+                    "Aggression knob channel must be {}-{}, got {}", ANALOG_PRESSURE_INPUTS, ANALOG_CHANNELS - 1, knob.channel
+                )));
+            }
+            for volts in [knob.min_volts, knob.max_volts] {
+                if !(0.0..=5.0).contains(&volts) {
+                    return Err(CoreError::ConfigurationError(
+                        format!("Aggression knob voltages must be 0.0-5.0 V, got {}", volts)
+                    ));
+                }
+            }
+            if libm::fabsf(knob.max_volts - knob.min_volts) < 1.0 {
+                return Err(CoreError::ConfigurationError(
+                    format!("Aggression knob travel must span at least 1 V, got {}-{} V", knob.min_volts, knob.max_volts)
+                ));
+            }
+        }
+        
+        for (index, entry) in self.drive_modes.iter().enumerate() {
+            if !(0.0..=1.0).contains(&entry.aggression) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Drive mode {} aggression must be 0.0-1.0, got {}", entry.mode, entry.aggression)
+                ));
+            }
+            if self.drive_modes[..index].iter().any(|earlier| earlier.mode == entry.mode) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Drive mode {} is listed twice", entry.mode)
+                ));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Aggression for a decoded drive mode signal value, if the mode is listed
+    pub fn drive_mode_aggression(&self, value: f32) -> Option<f32> {
+        let mode = libm::roundf(value);
+        self.drive_modes.iter().find(|entry| entry.mode as f32 == mode).map(|entry| entry.aggression)
+    }
+}
+
+/// Aggression chosen for a cycle
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AggressionReading {
+    pub source: AggressionSource,
+    /// Aggression (0.0-1.0)
+    pub value: f32,
+}
+
+/// Aggression arbitration status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggressionInputStatus {
+    pub policy: AggressionPolicy,
+    /// Source and value in control
+    pub active: AggressionReading,
+    /// Knob position, while usable
+    pub knob: Option<f32>,
+    /// Drive mode aggression, while usable
+    pub drive_mode: Option<f32>,
+    /// App value, while usable
+    pub app: Option<f32>,
+}
+
+/// Latest value of one source
+#[derive(Debug, Clone, Copy, Default)]
+struct SourceInput {
+    value: Option<f32>,
+    /// Value at the last change, which later readings are compared with
+    written: Option<f32>,
+    seen_ms: u32,
+    changed_ms: Option<u32>,
+}
+
+impl SourceInput {
+    /// Record a reading; the first reading of a source is its starting point, not a change
+    fn observe(&mut self, value: f32, now_ms: u32, first_is_change: bool) {
+        let changed = match self.written {
+            Some(written) => libm::fabsf(value - written) > CHANGE_DEADBAND,
+            None => first_is_change,
+        };
+        if changed {
+            self.changed_ms = Some(now_ms);
+        }
+        if changed || self.written.is_none() {
+            self.written = Some(value);
+        }
+        self.value = Some(value);
+        self.seen_ms = now_ms;
+    }
+    
+    /// Value, unless not heard from for the timeout
+    fn current(&self, now_ms: u32, timeout_ms: u32) -> Option<f32> {
+        self.value.filter(|_| now_ms.wrapping_sub(self.seen_ms) <= timeout_ms)
+    }
+}
+
+/// Chooses the aggression source each cycle
+#[derive(Debug, Clone)]
+pub struct AggressionArbiter {
+    config: AggressionInputConfig,
+    knob: SourceInput,
+    drive_mode: SourceInput,
+    app: SourceInput,
+    active: AggressionReading,
+    now_ms: u32,
+}
+
+impl AggressionArbiter {
+    /// Create with nothing heard from any input
+    pub fn new(config: AggressionInputConfig, setting: f32) -> Self {
+        Self {
+            config,
+            knob: SourceInput::default(),
+            drive_mode: SourceInput::default(),
+            app: SourceInput::default(),
+            active: AggressionReading { source: AggressionSource::Setting, value: setting },
+            now_ms: 0,
+        }
+    }
+    
+    /// Apply changed settings; values already heard are kept
+    pub fn reconfigure(&mut self, config: AggressionInputConfig) {
+        self.config = config;
+    }
+    
+    /// Value sent by a connected app
+    pub fn set_app(&mut self, value: f32, now_ms: u32) {
+        self.app.observe(value, now_ms, true);
+    }
+    
+    /// Choose this cycle's aggression from the stored setting and the inputs read
+    /// 
+    /// `drive_mode` is the mapped aggression with the arrival time of its frame.
+    pub fn update(&mut self, setting: f32, knob: Option<f32>, drive_mode: Option<(f32, u32)>, now_ms: u32) -> AggressionReading {
+        // The knob is read every cycle, so an unreadable one drops out at once
+        match knob {
+            Some(position) => self.knob.observe(position, now_ms, false),
+            None => self.knob.value = None,
+        }
+        if let Some((aggression, received_ms)) = drive_mode {
+            self.drive_mode.observe(aggression, received_ms, false);
+        }
+        self.now_ms = now_ms;
+        
+        let timeout_ms = self.config.timeout_ms;
+        let usable = |source: AggressionSource| -> Option<(AggressionSource, f32)> {
+            let value = match source {
+                AggressionSource::Setting => Some(setting),
+                AggressionSource::Knob => self.knob.current(now_ms, timeout_ms),
+                AggressionSource::DriveMode => self.drive_mode.current(now_ms, timeout_ms),
+                AggressionSource::App => self.app.current(now_ms, timeout_ms),
+            };
+            value.map(|value| (source, value))
+        };
+        let by_priority = || self.config.priority.iter().find_map(|&source| usable(source));
+        
+        let chosen = match self.config.policy {
+            AggressionPolicy::Priority => by_priority(),
+            AggressionPolicy::LastWriter => self.config.priority.iter()
+                .filter_map(|&source| Some((usable(source)?, self.input(source)?.changed_ms?)))
+                // Earlier in the priority list wins a tie
+                .min_by_key(|(_, changed_ms)| now_ms.wrapping_sub(*changed_ms))
+                .map(|(chosen, _)| chosen)
+                .or_else(by_priority),
+            AggressionPolicy::Locked(source) => usable(source),
+        };
+        let (source, value) = chosen.unwrap_or((AggressionSource::Setting, setting));
+        
+        self.active = AggressionReading { source, value: value.clamp(0.0, 1.0) };
+        self.active
+    }
+    
+    /// Latest reading of a physical input
+    fn input(&self, source: AggressionSource) -> Option<&SourceInput> {
+        match source {
+            AggressionSource::Setting => None,
+            AggressionSource::Knob => Some(&self.knob),
+            AggressionSource::DriveMode => Some(&self.drive_mode),
+            AggressionSource::App => Some(&self.app),
+        }
+    }
+    
+    /// Source and value chosen on the last update
+    pub fn active(&self) -> AggressionReading {
+        self.active
+    }
+    
+    /// Status snapshot for diagnostics
+    pub fn status(&self) -> AggressionInputStatus {
+        let timeout_ms = self.config.timeout_ms;
+        AggressionInputStatus {
+            policy: self.config.policy,
+            active: self.active,
+            knob: self.knob.current(self.now_ms, timeout_ms),
+            drive_mode: self.drive_mode.current(self.now_ms, timeout_ms),
+            app: self.app.current(self.now_ms, timeout_ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn arbiter(policy: AggressionPolicy) -> AggressionArbiter {
+        AggressionArbiter::new(AggressionInputConfig { policy, ..AggressionInputConfig::default() }, 0.3)
+    }
+    
+    fn reading(source: AggressionSource, value: f32) -> AggressionReading {
+        AggressionReading { source, value }
+    }
+    
+    #[test]
+    fn test_priority_falls_back_to_setting() {
+        let mut arbiter = arbiter(AggressionPolicy::Priority);
+        assert_eq!(arbiter.update(0.3, None, None, 0), reading(AggressionSource::Setting, 0.3));
+        
+        arbiter.set_app(0.9, 10);
+        assert_eq!(arbiter.update(0.3, None, Some((0.6, 10)), 10), reading(AggressionSource::DriveMode, 0.6));
+        assert_eq!(arbiter.update(0.3, Some(0.5), Some((0.6, 20)), 20), reading(AggressionSource::Knob, 0.5));
+        
+        // Knob unplugged - out at once; the last drive mode frame holds for the timeout
+        assert_eq!(arbiter.update(0.3, None, None, 2_000), reading(AggressionSource::DriveMode, 0.6));
+        
+        // Bus quiet - the app kept sending
+        arbiter.set_app(0.9, 3_000);
+        assert_eq!(arbiter.update(0.3, None, None, 3_500), reading(AggressionSource::App, 0.9));
+        assert_eq!(arbiter.update(0.3, None, None, 7_000), reading(AggressionSource::Setting, 0.3));
+    }
+    
+    #[test]
+    fn test_last_writer_wins() {
+        let mut arbiter = arbiter(AggressionPolicy::LastWriter);
+        
+        // Nothing has changed yet - priority order
+        assert_eq!(arbiter.update(0.3, Some(0.5), Some((0.6, 0)), 0).source, AggressionSource::Knob);
+        
+        arbiter.set_app(0.8, 100);
+        assert_eq!(arbiter.update(0.3, Some(0.5), Some((0.6, 100)), 100), reading(AggressionSource::App, 0.8));
+        
+        // Knob noise is not a change; turning it is
+        assert_eq!(arbiter.update(0.3, Some(0.51), Some((0.6, 200)), 200).source, AggressionSource::App);
+        assert_eq!(arbiter.update(0.3, Some(0.7), Some((0.6, 300)), 300), reading(AggressionSource::Knob, 0.7));
+        
+        // Drive mode switched
+        assert_eq!(arbiter.update(0.3, Some(0.7), Some((0.2, 400)), 400), reading(AggressionSource::DriveMode, 0.2));
+    }
+    
+    #[test]
+    fn test_locked_source_ignores_others() {
+        let mut arbiter = arbiter(AggressionPolicy::Locked(AggressionSource::App));
+        assert_eq!(arbiter.update(0.3, Some(0.9), None, 0), reading(AggressionSource::Setting, 0.3));
+        
+        arbiter.set_app(0.4, 10);
+        assert_eq!(arbiter.update(0.3, Some(0.9), None, 10), reading(AggressionSource::App, 0.4));
+        assert_eq!(arbiter.status().knob, Some(0.9));
+    }
+    
+    #[test]
+    fn test_knob_wiring_faults_read_nothing() {
+        let knob = KnobConfig::default();
+        assert_eq!(knob.position(0.5), Some(0.0));
+        assert_eq!(knob.position(2.5), Some(0.5));
+        assert_eq!(knob.position(4.7), Some(1.0));
+        assert_eq!(knob.position(0.0), None);
+        assert_eq!(knob.position(5.0), None);
+        
+        // Wired backwards
+        let reversed = KnobConfig { min_volts: 4.5, max_volts: 0.5, ..knob };
+        assert_eq!(reversed.position(4.5), Some(0.0));
+    }
+    
+    #[test]
+    fn test_drive_mode_table_and_validation() {
+        let config = AggressionInputConfig {
+            drive_modes: vec![
+                DriveModeAggression { mode: 0, aggression: 0.2 },
+                DriveModeAggression { mode: 3, aggression: 0.9 },
+            ],
+            ..AggressionInputConfig::default()
+        };
+        config.validate().unwrap();
+        assert_eq!(config.drive_mode_aggression(3.0), Some(0.9));
+        assert_eq!(config.drive_mode_aggression(1.0), None);
+        
+        let duplicate = AggressionInputConfig { priority: vec![AggressionSource::App, AggressionSource::App], ..config.clone() };
+        assert!(duplicate.validate().is_err());
+        let setting = AggressionInputConfig { priority: vec![AggressionSource::Setting], ..config.clone() };
+        assert!(setting.validate().is_err());
+        let knob_on_map_sensor = AggressionInputConfig { knob: Some(KnobConfig { channel: 0, ..KnobConfig::default() }), ..config };
+        assert!(knob_on_map_sensor.validate().is_err());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::{RumbleDomeCore, SystemConfig};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_knob_outranks_app_until_unplugged() {
+        let config = SystemConfig {
+            aggression_inputs: AggressionInputConfig { knob: Some(KnobConfig::default()), ..AggressionInputConfig::default() },
+            ..SystemConfig::default()
+        };
+        config.validate().unwrap();
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize().unwrap();
+        
+        core.hal.set_analog_voltage(KnobConfig::default().channel, 2.5);
+        core.set_app_aggression(0.9).unwrap();
+        assert_eq!(core.read_inputs().unwrap().aggression, 0.5);
+        assert_eq!(core.telemetry_sample().aggression_source, AggressionSource::Knob);
+        
+        core.hal.set_analog_voltage(KnobConfig::default().channel, 0.0);
+        assert_eq!(core.read_inputs().unwrap().aggression, 0.9);
+        assert_eq!(core.get_system_status().aggression_input.active.source, AggressionSource::App);
+        assert!(core.set_app_aggression(1.5).is_err());
+    }
+    
+    #[test]
+    fn test_knob_and_backpressure_sensor_cannot_share_a_channel() {
+        let mut config = SystemConfig::default();
+        config.backpressure.enabled = true;
+        config.aggression_inputs.knob = Some(KnobConfig { channel: config.backpressure.channel, ..KnobConfig::default() });
+        assert!(config.validate().is_err());
+        
+        // Drive modes need the signal they are read from
+        let mut config = SystemConfig::default();
+        config.aggression_inputs.drive_modes.push(DriveModeAggression { mode: 2, aggression: 0.8 });
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,305 @@
+//! Exhaust Backpressure Protection
+//! 
+//! 🔗 T4-CORE-120: Turbine Protection from Exhaust Backpressure
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-096 (Temperature-Based Boost Derate)
+//! AI Traceability: An undersized turbine or clogged cat shows up as exhaust pressure climbing far past boost - pull boost before the turbine is driven into choke
+//! 
+//! An optional pressure sensor on an expansion input reads exhaust manifold
+//! pressure. While on boost, the exhaust-to-boost pressure ratio is compared
+//! with a configurable limit; once it has stayed above the limit for the
+//! sustain time, boost is capped a little below where it was. A ratio that
+//! stays high under the cap pulls it down again. Lifting off boost ends the
+//! pull and releases the cap. Without the sensor nothing is limited.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{PressureCalibration, ANALOG_CHANNELS};
+use crate::{CoreError, SystemInputs, ANALOG_PRESSURE_INPUTS};
+
+/// Exhaust backpressure sensor and protection rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BackpressureConfig {
+    /// Exhaust backpressure sensor fitted
+    pub enabled: bool,
+    /// Expansion input the sensor is wired to
+    pub channel: u8,
+    /// Sensor pressure at 4.5 V (PSI gauge, 0.5-4.5 V ratiometric)
+    pub full_scale_psi: f32,
+    /// Exhaust-to-boost pressure ratio above which boost is limited
+    pub max_ratio: f32,
+    /// Boost below which the ratio is not judged - both pressures are small and noisy (PSI)
+    pub min_boost_psi: f32,
+    /// Ratio must stay above the limit this long before boost is limited (ms)
+    pub sustain_ms: u32,
+    /// Boost taken off the pressure at which the limit was exceeded (PSI)
+    pub pullback_psi: f32,
+}
+
+impl Default for BackpressureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            channel: ANALOG_PRESSURE_INPUTS as u8,
+            full_scale_psi: 100.0,
+            max_ratio: 2.0,
+            min_boost_psi: 4.0,
+            sustain_ms: 500,
+            pullback_psi: 1.0,
+        }
+    }
+}
+
+impl BackpressureConfig {
+    /// Validate the sensor input and protection rule
+    pub fn validate(&self) -> Result<(), CoreError> {
+        // Channels below the expansion inputs carry the boost and dome sensors
+        if !(ANALOG_PRESSURE_INPUTS..ANALOG_CHANNELS).contains(&(self.channel as usize)) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Backpressure sensor channel must be {}-{}, got {}", ANALOG_PRESSURE_INPUTS, ANALOG_CHANNELS - 1, self.channel
+            )));
+        }
+        if !(15.0..=300.0).contains(&self.full_scale_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Backpressure sensor full scale must be 15-300 PSI, got {}", self.full_scale_psi)
+            ));
+        }
+        if !(1.0..=5.0).contains(&self.max_ratio) {
+            return Err(CoreError::ConfigurationError(
+                format!("Backpressure ratio limit must be 1.0-5.0, got {}", self.max_ratio)
+            ));
+        }
+        if !(1.0..=30.0).contains(&self.min_boost_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Backpressure minimum boost must be 1-30 PSI, got {}", self.min_boost_psi)
+            ));
+        }
+        if self.sustain_ms > 10_000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Backpressure sustain time must be at most 10000 ms, got {}", self.sustain_ms)
+            ));
+        }
+        if !(0.0..=10.0).contains(&self.pullback_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Backpressure pullback must be 0-10 PSI, got {}", self.pullback_psi)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Calibration curve for the sensor's channel
+    pub fn sensor_calibration(&self) -> PressureCalibration {
+        PressureCalibration { full_scale_psi: self.full_scale_psi, ..PressureCalibration::default() }
+    }
+}
+
+/// Turbine protection status (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurbineProtectionStatus {
+    /// Latest exhaust backpressure reading (PSI gauge), if the sensor is fitted and readable
+    pub backpressure_psi: Option<f32>,
+    /// Latest exhaust-to-boost ratio, while on boost
+    pub ratio: Option<f32>,
+    /// Boost cap in effect (PSI)
+    pub cap_psi: Option<f32>,
+    /// Pulls limited since power-up
+    pub interventions: u32,
+}
+
+/// Tracks the backpressure ratio across control cycles
+#[derive(Debug, Clone)]
+pub struct TurbineProtection {
+    config: BackpressureConfig,
+    status: TurbineProtectionStatus,
+    high_since_ms: Option<u32>,
+}
+
+impl TurbineProtection {
+    /// Create with no cap in effect
+    pub fn new(config: BackpressureConfig) -> Self {
+        Self { config, status: TurbineProtectionStatus::default(), high_since_ms: None }
+    }
+    
+    /// Apply changed rules; a cap in effect is kept until the pull ends
+    pub fn reconfigure(&mut self, config: BackpressureConfig) {
+        self.config = config;
+    }
+    
+    /// Update from one cycle's pressures; `spring_psi` is the lowest cap worth setting
+    pub fn update(&mut self, inputs: &SystemInputs, spring_psi: f32) {
+        if !self.config.enabled {
+            let interventions = self.status.interventions;
+            self.status = TurbineProtectionStatus { interventions, ..TurbineProtectionStatus::default() };
+            self.high_since_ms = None;
+            return;
+        }
+        
+        let boost_psi = inputs.manifold_pressure;
+        self.status.backpressure_psi = inputs.exhaust_backpressure_psi;
+        self.status.ratio = None;
+        
+        if boost_psi < self.config.min_boost_psi {
+            self.status.cap_psi = None;
+            self.high_since_ms = None;
+            return;
+        }
+        
+        // A missing reading holds the current cap rather than releasing it
+        let Some(backpressure_psi) = inputs.exhaust_backpressure_psi else {
+            self.high_since_ms = None;
+            return;
+        };
+        
+        let ratio = backpressure_psi / boost_psi;
+        self.status.ratio = Some(ratio);
+        if ratio <= self.config.max_ratio {
+            self.high_since_ms = None;
+            return;
+        }
+        
+        let since = *self.high_since_ms.get_or_insert(inputs.timestamp_ms);
+        if inputs.timestamp_ms.wrapping_sub(since) >= self.config.sustain_ms {
+            let cap_psi = (boost_psi - self.config.pullback_psi).max(spring_psi);
+            if self.status.cap_psi.is_none() {
+                self.status.interventions += 1;
+            }
+            self.status.cap_psi = Some(self.status.cap_psi.map_or(cap_psi, |held| held.min(cap_psi)));
+            self.high_since_ms = None;
+        }
+    }
+    
+    /// Boost cap, or `None` while the ratio is within its limit (PSI)
+    pub fn boost_cap_psi(&self) -> Option<f32> {
+        self.status.cap_psi
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &TurbineProtectionStatus {
+        &self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(manifold_pressure: f32, exhaust_backpressure_psi: Option<f32>, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { manifold_pressure, exhaust_backpressure_psi, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    fn enabled() -> BackpressureConfig {
+        BackpressureConfig { enabled: true, ..BackpressureConfig::default() }
+    }
+    
+    #[test]
+    fn test_sustained_high_ratio_caps_boost_until_the_pull_ends() {
+        let mut protection = TurbineProtection::new(enabled());
+        
+        // 2.5:1 for less than the sustain time: a spool transient, not a restriction
+        protection.update(&inputs(8.0, Some(20.0), 0), 5.0);
+        protection.update(&inputs(8.0, Some(20.0), 499), 5.0);
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert!((protection.status().ratio.unwrap() - 2.5).abs() < 0.001);
+        
+        protection.update(&inputs(9.0, Some(22.5), 500), 5.0);
+        assert_eq!(protection.boost_cap_psi(), Some(8.0));
+        assert_eq!(protection.status().interventions, 1);
+        
+        // Still too high under the cap: pulled down again after another sustain time
+        protection.update(&inputs(8.0, Some(19.0), 600), 5.0);
+        assert_eq!(protection.boost_cap_psi(), Some(8.0));
+        protection.update(&inputs(8.0, Some(19.0), 1_100), 5.0);
+        assert_eq!(protection.boost_cap_psi(), Some(7.0));
+        assert_eq!(protection.status().interventions, 1);
+        
+        // Lost reading holds; lifting releases
+        protection.update(&inputs(7.0, None, 1_200), 5.0);
+        assert_eq!(protection.boost_cap_psi(), Some(7.0));
+        protection.update(&inputs(1.0, Some(2.0), 1_300), 5.0);
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert_eq!(protection.status().ratio, None);
+    }
+    
+    #[test]
+    fn test_healthy_ratio_and_low_boost_are_left_alone() {
+        let mut protection = TurbineProtection::new(enabled());
+        for timestamp_ms in (0..2_000).step_by(100) {
+            protection.update(&inputs(10.0, Some(15.0), timestamp_ms), 5.0);
+            // Off boost the ratio is meaningless
+            protection.update(&inputs(2.0, Some(8.0), timestamp_ms + 50), 5.0);
+        }
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert_eq!(protection.status().interventions, 0);
+    }
+    
+    #[test]
+    fn test_cap_never_below_spring_pressure() {
+        let config = BackpressureConfig { sustain_ms: 0, pullback_psi: 3.0, ..enabled() };
+        let mut protection = TurbineProtection::new(config);
+        protection.update(&inputs(6.0, Some(18.0), 0), 5.0);
+        assert_eq!(protection.boost_cap_psi(), Some(5.0));
+        
+        protection.reconfigure(BackpressureConfig::default());
+        protection.update(&inputs(6.0, Some(18.0), 10), 5.0);
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert_eq!(protection.status().backpressure_psi, None);
+    }
+    
+    #[test]
+    fn test_validation() {
+        assert!(BackpressureConfig::default().validate().is_ok());
+        // The boost and dome sensors' channels are taken
+        assert!(BackpressureConfig { channel: 2, ..enabled() }.validate().is_err());
+        assert!(BackpressureConfig { channel: ANALOG_CHANNELS as u8, ..enabled() }.validate().is_err());
+        assert!(BackpressureConfig { max_ratio: 0.5, ..enabled() }.validate().is_err());
+        assert_eq!(enabled().sensor_calibration().voltage_to_psi(2.5).unwrap(), 50.0);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{ControlMode, RumbleDomeCore, SystemConfig, WatchValue, WatchVariable};
+    use rumbledome_hal::MockHal;
+    
+    fn armed_core() -> RumbleDomeCore<MockHal> {
+        let config = SystemConfig {
+            control_mode: ControlMode::BoostByTarget,
+            backpressure: BackpressureConfig { enabled: true, sustain_ms: 0, ..BackpressureConfig::default() },
+            ..SystemConfig::default()
+        };
+        config.validate().unwrap();
+        test_support::armed_core(config)
+    }
+    
+    #[test]
+    fn test_sensor_read_on_its_own_scale() {
+        let mut core = armed_core();
+        core.hal.set_pressure_psi(ANALOG_PRESSURE_INPUTS as u8, 45.0);
+        let inputs = core.read_inputs().unwrap();
+        assert!((inputs.exhaust_backpressure_psi.unwrap() - 45.0).abs() < 0.1);
+        
+        // An unplugged sensor reads nothing rather than zero
+        core.hal.set_analog_voltage(ANALOG_PRESSURE_INPUTS as u8, 0.0);
+        assert_eq!(core.read_inputs().unwrap().exhaust_backpressure_psi, None);
+    }
+    
+    #[test]
+    fn test_high_ratio_caps_the_boost_target() {
+        let mut core = armed_core();
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 5000;
+        inputs.manifold_pressure = 9.0;
+        inputs.exhaust_backpressure_psi = Some(24.0);
+        inputs.timestamp_ms = 0;
+        core.execute_control_cycle_with_inputs(inputs).unwrap();
+        
+        assert!(core.last_target_boost_psi <= 8.0);
+        assert_eq!(core.get_system_status().turbine_protection.cap_psi, Some(8.0));
+        assert_eq!(core.read_watch(WatchVariable::ExhaustBackpressurePsi), WatchValue::Number(24.0));
+        assert!(core.describe_screen().alert.is_some_and(|alert| alert.text == "BACKPRESSURE - BOOST REDUCED"));
+    }
+}
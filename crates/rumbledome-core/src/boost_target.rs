@@ -0,0 +1,166 @@
+//! Boost-by-Target Control Mode
+//! 
+//! 🔗 T4-CORE-057: Control Mode Selection
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + ECUs without usable torque broadcast
+//! AI Traceability: Level 1 torque logic can be bypassed entirely; Levels 2-3 and all safety remain
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, DisplayString, Language};
+
+/// Maximum number of points in the boost target curve
+pub const MAX_BOOST_TARGET_POINTS: usize = 16;
+
+/// How the boost target is produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ControlMode {
+    /// Level 1 follows the ECU's torque request (requires CAN torque data)
+    #[default]
+    TorqueFollowing,
+    /// Level 1 bypassed - boost target comes from the RPM target curve
+    BoostByTarget,
+}
+
+impl ControlMode {
+    /// Short label for display and telemetry
+    pub fn display_text(&self) -> &'static str {
+        self.localized_text(Language::English)
+    }
+    
+    /// Short label in the given language
+    pub fn localized_text(&self, language: Language) -> &'static str {
+        match self {
+            ControlMode::TorqueFollowing => DisplayString::ModeTorqueFollowing.text(language),
+            ControlMode::BoostByTarget => DisplayString::ModeBoostByTarget.text(language),
+        }
+    }
+}
+
+/// One point of the boost target curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BoostTargetPoint {
+    /// Engine RPM
+    pub rpm: u16,
+    /// Full-aggression boost target at this RPM (PSI)
+    pub boost_psi: f32,
+}
+
+/// RPM → boost target curve used in boost-by-target mode
+/// 
+/// 🔗 T4-CORE-058: RPM Boost Target Curve
+/// Derived From: T4-CORE-057 + T1-UI-001 (Single Parameter Philosophy)
+/// 
+/// The curve is the full-aggression target. Aggression scales the portion above
+/// spring pressure, so 0% aggression still means as close to naturally aspirated
+/// as the wastegate spring allows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BoostTargetCurve {
+    /// Curve points in ascending RPM order
+    pub points: Vec<BoostTargetPoint>,
+}
+
+impl Default for BoostTargetCurve {
+    fn default() -> Self {
+        Self {
+            points: alloc::vec![
+                BoostTargetPoint { rpm: 2500, boost_psi: 6.0 },
+                BoostTargetPoint { rpm: 3500, boost_psi: 10.0 },
+                BoostTargetPoint { rpm: 6500, boost_psi: 10.0 },
+            ],
+        }
+    }
+}
+
+impl BoostTargetCurve {
+    /// Validate curve shape against the boost ceiling
+    pub fn validate(&self, max_boost_psi: f32) -> Result<(), CoreError> {
+        if self.points.len() < 2 || self.points.len() > MAX_BOOST_TARGET_POINTS {
+            return Err(CoreError::ConfigurationError(
+                format!("Boost target curve needs 2-{} points, got {}", MAX_BOOST_TARGET_POINTS, self.points.len())
+            ));
+        }
+        
+        if self.points.windows(2).any(|pair| pair[1].rpm <= pair[0].rpm) {
+            return Err(CoreError::ConfigurationError(
+                "Boost target curve RPM points must be strictly ascending".into()
+            ));
+        }
+        
+        if let Some(point) = self.points.iter().find(|p| !(0.0..=max_boost_psi).contains(&p.boost_psi)) {
+            return Err(CoreError::ConfigurationError(
+                format!("Boost target {} PSI at {} RPM outside 0-{} PSI", point.boost_psi, point.rpm, max_boost_psi)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Full-aggression target at an RPM (PSI), linearly interpolated and held flat past the ends
+    pub fn full_target_psi(&self, rpm: u16) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0.0;
+        };
+        
+        if rpm <= first.rpm {
+            return first.boost_psi;
+        }
+        if rpm >= last.rpm {
+            return last.boost_psi;
+        }
+        
+        self.points
+            .windows(2)
+            .find(|pair| rpm <= pair[1].rpm)
+            .map(|pair| {
+                let span = (pair[1].rpm - pair[0].rpm) as f32;
+                let t = (rpm - pair[0].rpm) as f32 / span;
+                pair[0].boost_psi + (pair[1].boost_psi - pair[0].boost_psi) * t
+            })
+            .unwrap_or(last.boost_psi)
+    }
+    
+    /// Aggression-scaled target at an RPM (PSI)
+    pub fn target_psi(&self, rpm: u16, aggression: f32, spring_pressure: f32) -> f32 {
+        let full = self.full_target_psi(rpm);
+        if full <= spring_pressure {
+            return full;
+        }
+        
+        spring_pressure + (full - spring_pressure) * aggression.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_interpolation_and_end_hold() {
+        let curve = BoostTargetCurve::default();
+        assert_eq!(curve.full_target_psi(1500), 6.0);
+        assert!((curve.full_target_psi(3000) - 8.0).abs() < 1e-4);
+        assert_eq!(curve.full_target_psi(7000), 10.0);
+    }
+    
+    #[test]
+    fn test_aggression_scales_above_spring() {
+        let curve = BoostTargetCurve::default();
+        assert_eq!(curve.target_psi(4000, 0.0, 5.0), 5.0);
+        assert!((curve.target_psi(4000, 0.5, 5.0) - 7.5).abs() < 1e-4);
+        assert_eq!(curve.target_psi(4000, 1.0, 5.0), 10.0);
+    }
+    
+    #[test]
+    fn test_invalid_curves_rejected() {
+        let mut curve = BoostTargetCurve::default();
+        assert!(curve.validate(12.0).is_ok());
+        assert!(curve.validate(9.0).is_err());
+        
+        curve.points[1].rpm = 2500;
+        assert!(curve.validate(12.0).is_err());
+    }
+}
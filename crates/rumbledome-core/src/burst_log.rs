@@ -0,0 +1,239 @@
+//! Burst Logging
+//! 
+//! 🔗 T4-CORE-095: Full-Rate Burst Capture
+//! Derived From: T4-CORE-008 (Main Control Loop Implementation) + T4-CORE-005 (Performance Monitoring)
+//! AI Traceability: Oscillations at the loop rate alias into nonsense at telemetry rates - capture every cycle for a few seconds instead
+//! 
+//! A burst records every intermediate control value of every control cycle
+//! into RAM for a short, bounded window, then stops on its own. The capture
+//! is offloaded afterwards in chunks small enough for one protocol response,
+//! so recording never competes with the link for bandwidth.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, InputSource, SystemInputs, SystemState};
+
+/// Longest burst window (ms)
+pub const MAX_BURST_MS: u32 = 10_000;
+
+/// Sample limit - a full window at the fastest inner loop rate (200 Hz)
+pub const MAX_BURST_SAMPLES: usize = 2_000;
+
+/// Samples per offload chunk, sized to fit one protocol response
+pub const BURST_CHUNK_SAMPLES: usize = 20;
+
+/// Every intermediate value of one control cycle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurstSample {
+    /// State after the cycle
+    pub state: SystemState,
+    /// Inputs the cycle ran with
+    pub inputs: SystemInputs,
+    /// Manifold pressure source chosen by arbitration
+    pub map_source: InputSource,
+    /// MAP zero offset applied to the analog sensor (PSI)
+    pub map_zero_psi: f32,
+    /// Torque assistance authority allowed by CAN health (0.0-1.0)
+    pub can_authority: f32,
+    /// Heat derate multiplier on the controllable boost range (1.0 = none)
+    pub thermal_derate: f32,
+    /// Level 1 and learning ran this cycle
+    pub outer_cycle: bool,
+    /// Level 1 boost target (PSI)
+    pub level1_target_psi: f32,
+    /// Boost target after hardware and rev-limit caps (PSI)
+    pub target_psi: f32,
+    /// Authority requested by the control hierarchy, before aggression scaling and the duty ceiling (%)
+    pub authority_request: Option<f32>,
+    /// Learned duty ceiling at this RPM (%)
+    pub duty_ceiling_percent: Option<f32>,
+    /// Domes vented at idle with PWM off
+    pub idle_venting: bool,
+    /// Solenoid duty actually commanded (%)
+    pub duty_percent: f32,
+    /// Control cycle execution time (microseconds)
+    pub cycle_time_us: u32,
+    /// Level 2 boost loop correction (P + I) in the dome pressure setpoint, while the dome loop runs (PSI)
+    #[serde(default)]
+    pub boost_trim_psi: Option<f32>,
+}
+
+/// Burst capture progress
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BurstLogStatus {
+    /// Capture running
+    pub recording: bool,
+    /// Requested window (ms)
+    pub window_ms: u32,
+    /// Samples captured so far
+    pub samples: u32,
+    /// Time covered by the captured samples (ms)
+    pub captured_ms: u32,
+}
+
+/// One offload chunk of a capture
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurstLogChunk {
+    /// Index of the first sample in this chunk
+    pub offset: u32,
+    /// Samples in the whole capture
+    pub total: u32,
+    pub samples: Vec<BurstSample>,
+}
+
+/// Bounded full-rate capture buffer
+/// 
+/// The buffer holds the most recent capture until the next one starts.
+#[derive(Debug, Clone, Default)]
+pub struct BurstLog {
+    recording: bool,
+    window_ms: u32,
+    samples: Vec<BurstSample>,
+}
+
+impl BurstLog {
+    /// Create with nothing captured
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Start a capture of `window_ms`, discarding the previous one
+    pub fn start(&mut self, window_ms: u32) -> Result<(), CoreError> {
+        if !(1..=MAX_BURST_MS).contains(&window_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Burst window must be 1-{} ms, got {}", MAX_BURST_MS, window_ms)
+            ));
+        }
+        
+        self.samples.clear();
+        self.samples.reserve(MAX_BURST_SAMPLES);
+        self.window_ms = window_ms;
+        self.recording = true;
+        Ok(())
+    }
+    
+    /// End the capture early, keeping what was recorded
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+    
+    /// Whether samples are being captured
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+    
+    /// Add one cycle's sample; the capture stops once the window or buffer is full
+    pub fn record(&mut self, sample: BurstSample) {
+        if !self.recording {
+            return;
+        }
+        
+        self.samples.push(sample);
+        if self.captured_ms() >= self.window_ms || self.samples.len() >= MAX_BURST_SAMPLES {
+            self.recording = false;
+        }
+    }
+    
+    /// Current progress
+    pub fn status(&self) -> BurstLogStatus {
+        BurstLogStatus {
+            recording: self.recording,
+            window_ms: self.window_ms,
+            samples: self.samples.len() as u32,
+            captured_ms: self.captured_ms(),
+        }
+    }
+    
+    /// Samples from `offset`, at most `BURST_CHUNK_SAMPLES` of them
+    pub fn chunk(&self, offset: u32) -> BurstLogChunk {
+        let start = (offset as usize).min(self.samples.len());
+        let end = (start + BURST_CHUNK_SAMPLES).min(self.samples.len());
+        BurstLogChunk {
+            offset: start as u32,
+            total: self.samples.len() as u32,
+            samples: self.samples[start..end].to_vec(),
+        }
+    }
+    
+    fn captured_ms(&self) -> u32 {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(first), Some(last)) => last.inputs.timestamp_ms.wrapping_sub(first.inputs.timestamp_ms),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn sample(timestamp_ms: u32) -> BurstSample {
+        BurstSample {
+            state: SystemState::Armed,
+            inputs: test_support::inputs(timestamp_ms),
+            map_source: InputSource::Analog,
+            map_zero_psi: 0.0,
+            can_authority: 1.0,
+            thermal_derate: 1.0,
+            outer_cycle: timestamp_ms.is_multiple_of(50),
+            level1_target_psi: 9.0,
+            target_psi: 9.0,
+            authority_request: Some(40.0),
+            duty_ceiling_percent: None,
+            idle_venting: false,
+            duty_percent: 40.0,
+            cycle_time_us: 120,
+            boost_trim_psi: None,
+        }
+    }
+    
+    #[test]
+    fn test_capture_stops_at_window() {
+        let mut log = BurstLog::new();
+        assert!(log.start(0).is_err());
+        assert!(log.start(MAX_BURST_MS + 1).is_err());
+        
+        // Nothing is kept until a burst is started
+        log.record(sample(0));
+        assert_eq!(log.status().samples, 0);
+        
+        log.start(1000).unwrap();
+        for t in (5000..7000).step_by(10) {
+            log.record(sample(t));
+        }
+        let status = log.status();
+        assert!(!status.recording);
+        assert_eq!(status.samples, 101);
+        assert_eq!(status.captured_ms, 1000);
+        
+        // A new burst replaces the previous capture
+        log.start(100).unwrap();
+        log.record(sample(9000));
+        assert!(log.is_recording());
+        assert_eq!(log.status().samples, 1);
+    }
+    
+    #[test]
+    fn test_chunks_cover_capture() {
+        let mut log = BurstLog::new();
+        log.start(MAX_BURST_MS).unwrap();
+        for t in (0..450).step_by(10) {
+            log.record(sample(t));
+        }
+        
+        let mut offloaded = Vec::new();
+        loop {
+            let chunk = log.chunk(offloaded.len() as u32);
+            assert!(chunk.samples.len() <= BURST_CHUNK_SAMPLES);
+            if chunk.samples.is_empty() {
+                break;
+            }
+            offloaded.extend(chunk.samples);
+        }
+        assert_eq!(offloaded.len(), 45);
+        assert_eq!(offloaded[44].inputs.timestamp_ms, 440);
+        assert_eq!(log.chunk(1000).offset, 45);
+    }
+}
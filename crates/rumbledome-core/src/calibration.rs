@@ -0,0 +1,409 @@
+//! Guided Auto-Calibration
+//! 
+//! 🔗 T4-CORE-134: Progressive Auto-Calibration Sessions
+//! Derived From: T2-CONTROL-007 (Progressive Safety Auto-Calibration) + T4-CORE-108 (Learned Duty Calibration Map)
+//! AI Traceability: Fill the learned map from deliberate, bounded duty sweeps instead of waiting for everyday driving to visit every cell
+//! 
+//! A session works through the RPM bands three times, once per phase. Each
+//! phase has a boost ceiling: spring pressure + 1 PSI, then halfway to the
+//! configured maximum, then the maximum itself - always at least 1 PSI short
+//! of the overboost limit.
+//! 
+//! The driver makes wide-open pulls through the band the display names; wide
+//! open and lifted are read from the throttle signal where one is mapped and
+//! from the ECU torque request otherwise. While the pull stays in the band,
+//! duty starts well below the learned estimate for the ceiling and steps up,
+//! dwelling on each step long enough for boost to answer; every dwell's duty
+//! and boost go to the learned map, whose cells keep their own confidence.
+//! The run ends when boost reaches the ceiling. A band is done once three
+//! runs in a row reached it at duties within 3 % of each other. Lifting or
+//! leaving the band drops the output to 0 % and the run is repeated;
+//! overshooting the ceiling drops the output and counts as an inconsistent
+//! run. A band that cannot reach the ceiling at full duty (the turbo is not
+//! spooled there) is passed over.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use crate::{CalibrationProgress, CoreError, LearnedData, SystemConfig, SystemInputs};
+
+/// RPM bands calibrated, in order (band centers)
+pub const CALIBRATION_RPM_BANDS: [u16; 4] = [2500, 3500, 4500, 5500];
+
+/// Half the width of an RPM band
+pub const BAND_HALF_WIDTH_RPM: u16 = 500;
+
+/// Consistent runs that complete a band
+pub const VALIDATION_RUNS: u8 = 3;
+
+/// Calibration phases
+pub const CALIBRATION_PHASES: u8 = 3;
+
+/// Throttle treated as wide open (%)
+const WIDE_OPEN_THROTTLE_PERCENT: f32 = 90.0;
+
+/// Throttle below which the driver has lifted (%)
+const LIFTED_THROTTLE_PERCENT: f32 = 20.0;
+
+/// First phase ceiling above spring pressure (PSI)
+const FIRST_PHASE_MARGIN_PSI: f32 = 1.0;
+
+/// Ceilings stay this far below the overboost limit (PSI)
+const OVERBOOST_MARGIN_PSI: f32 = 1.0;
+
+/// Boost above the ceiling that ends a run as an overshoot (PSI)
+const OVERSHOOT_PSI: f32 = 0.75;
+
+/// Share of the learned estimate for the ceiling a run starts from
+const START_DUTY_FRACTION: f32 = 0.5;
+
+/// Duty added per step (%)
+const DUTY_STEP_PERCENT: f32 = 1.0;
+
+/// Time on each duty step before boost is sampled (ms)
+const STEP_DWELL_MS: u32 = 100;
+
+/// Largest spread between the ceiling duties of consistent runs (%)
+const CONSISTENT_DUTY_PERCENT: f32 = 3.0;
+
+/// Where the current run is
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RunStage {
+    /// Output at 0 % - waiting for a wide-open pull in the band
+    WaitingForPull,
+    /// Stepping duty up
+    Sweeping { duty: f32, step_started_ms: u32 },
+    /// Output at 0 % - waiting for the driver to lift
+    Recovering,
+}
+
+/// Auto-calibration session state machine
+#[derive(Debug, Clone)]
+pub struct AutoCalibration {
+    spring_psi: f32,
+    max_boost_psi: f32,
+    overboost_limit_psi: f32,
+    phase: u8,
+    band: usize,
+    stage: RunStage,
+    /// Consistent runs at the current band and phase
+    runs: u8,
+    /// Duty the last completed run reached the ceiling at (%)
+    reference_duty: Option<f32>,
+    /// Bands passed over because full duty fell short of the ceiling
+    bands_skipped: u8,
+    complete: bool,
+    description: String,
+}
+
+impl Default for AutoCalibration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoCalibration {
+    /// Create with no session running
+    pub fn new() -> Self {
+        Self {
+            spring_psi: 0.0,
+            max_boost_psi: 0.0,
+            overboost_limit_psi: 0.0,
+            phase: 1,
+            band: 0,
+            stage: RunStage::WaitingForPull,
+            runs: 0,
+            reference_duty: None,
+            bands_skipped: 0,
+            complete: false,
+            description: String::new(),
+        }
+    }
+    
+    /// Start a session from phase 1 with the running configuration's limits
+    pub fn start(&mut self, config: &SystemConfig) {
+        *self = Self {
+            spring_psi: config.spring_pressure,
+            max_boost_psi: config.max_boost_psi,
+            overboost_limit_psi: config.overboost_limit,
+            ..Self::new()
+        };
+        self.description = self.pull_instruction();
+    }
+    
+    /// Whether every band of every phase is done
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+    
+    /// Bands passed over because full duty fell short of the ceiling
+    pub fn bands_skipped(&self) -> u8 {
+        self.bands_skipped
+    }
+    
+    /// Boost the current phase calibrates up to (PSI)
+    pub fn ceiling_psi(&self) -> f32 {
+        let target = match self.phase {
+            1 => self.spring_psi + FIRST_PHASE_MARGIN_PSI,
+            2 => (self.spring_psi + self.max_boost_psi) / 2.0,
+            _ => self.max_boost_psi,
+        };
+        target.min(self.overboost_limit_psi - OVERBOOST_MARGIN_PSI).max(self.spring_psi)
+    }
+    
+    /// RPM band being calibrated (low, high)
+    pub fn rpm_band(&self) -> (u16, u16) {
+        let center = CALIBRATION_RPM_BANDS[self.band];
+        (center - BAND_HALF_WIDTH_RPM, center + BAND_HALF_WIDTH_RPM)
+    }
+    
+    /// Progress for `SystemState::Calibrating`
+    pub fn progress(&self) -> CalibrationProgress {
+        let bands = CALIBRATION_RPM_BANDS.len() as f32;
+        let phase_progress = if self.complete {
+            1.0
+        } else {
+            (self.band as f32 + self.runs as f32 / VALIDATION_RUNS as f32) / bands
+        };
+        CalibrationProgress {
+            phase: self.phase,
+            phase_progress,
+            overall_progress: ((self.phase - 1) as f32 + phase_progress) / CALIBRATION_PHASES as f32,
+            current_target_psi: self.ceiling_psi(),
+            current_rpm: CALIBRATION_RPM_BANDS[self.band],
+            validation_runs: self.runs,
+            description: self.description.clone(),
+        }
+    }
+    
+    /// Run one control cycle of the session; returns the duty to command (%)
+    /// 
+    /// Samples taken on the way are learned into `learned`.
+    pub fn execute_step(&mut self, inputs: &SystemInputs, learned: &mut LearnedData) -> Result<f32, CoreError> {
+        if self.complete {
+            return Ok(0.0);
+        }
+        
+        let ceiling_psi = self.ceiling_psi();
+        let (low_rpm, high_rpm) = self.rpm_band();
+        let in_band = (low_rpm..=high_rpm).contains(&inputs.rpm);
+        let now_ms = inputs.timestamp_ms;
+        
+        match self.stage {
+            RunStage::WaitingForPull => {
+                if inputs.wide_open(WIDE_OPEN_THROTTLE_PERCENT) && in_band {
+                    let estimate = learned.boost_to_duty_conversion(ceiling_psi, inputs)?;
+                    let duty = estimate * START_DUTY_FRACTION;
+                    self.stage = RunStage::Sweeping { duty, step_started_ms: now_ms };
+                    self.description = format!("Sweeping duty toward {:.1} PSI - hold the throttle", ceiling_psi);
+                    return Ok(duty);
+                }
+                Ok(0.0)
+            },
+            
+            RunStage::Sweeping { duty, step_started_ms } => {
+                // Boost closing the torque gap is not a lift; only the request falling is
+                if inputs.lifted(WIDE_OPEN_THROTTLE_PERCENT) || !in_band {
+                    self.end_run("Pull ended early - lift, then pull through the band again".to_string());
+                    return Ok(0.0);
+                }
+                
+                let boost_psi = inputs.manifold_pressure;
+                if boost_psi > ceiling_psi + OVERSHOOT_PSI {
+                    // Nothing learned from an overshoot except that the last runs do not repeat
+                    self.runs = 0;
+                    self.reference_duty = None;
+                    self.end_run(format!("Overshot {:.1} PSI at {:.0}% duty - lift", ceiling_psi, duty));
+                    return Ok(0.0);
+                }
+                
+                if now_ms.wrapping_sub(step_started_ms) < STEP_DWELL_MS {
+                    return Ok(duty);
+                }
+                
+                // Boost has answered this step - learn it as the boost this duty holds
+                learned.update_from_operation(inputs, boost_psi, duty)?;
+                
+                if boost_psi >= ceiling_psi {
+                    self.record_run(duty);
+                    return Ok(0.0);
+                }
+                if duty >= 100.0 {
+                    self.description = format!(
+                        "{}-{} RPM cannot reach {:.1} PSI - skipped", low_rpm, high_rpm, ceiling_psi
+                    );
+                    self.bands_skipped = self.bands_skipped.saturating_add(1);
+                    self.advance();
+                    self.stage = RunStage::Recovering;
+                    return Ok(0.0);
+                }
+                
+                let duty = (duty + DUTY_STEP_PERCENT).min(100.0);
+                self.stage = RunStage::Sweeping { duty, step_started_ms: now_ms };
+                Ok(duty)
+            },
+            
+            RunStage::Recovering => {
+                if inputs.lifted(LIFTED_THROTTLE_PERCENT) {
+                    self.stage = RunStage::WaitingForPull;
+                    self.description = self.pull_instruction();
+                }
+                Ok(0.0)
+            },
+        }
+    }
+    
+    /// Abandon the session; the output drops to 0 %
+    pub fn abort(&mut self) {
+        self.stage = RunStage::Recovering;
+        self.description = "Aborted".to_string();
+    }
+    
+    /// A run reached the ceiling at `duty`
+    fn record_run(&mut self, duty: f32) {
+        let consistent = self.reference_duty.is_some_and(|reference| (duty - reference).abs() <= CONSISTENT_DUTY_PERCENT);
+        self.runs = if consistent { self.runs + 1 } else { 1 };
+        self.reference_duty = Some(duty);
+        
+        if self.runs >= VALIDATION_RUNS {
+            self.advance();
+            self.end_run(match self.complete {
+                true => "Calibration complete".to_string(),
+                false => "Band done - lift".to_string(),
+            });
+        } else {
+            self.end_run(format!("Run {} of {} reached target at {:.0}% duty - lift", self.runs, VALIDATION_RUNS, duty));
+        }
+    }
+    
+    /// Move on to the next band, or the next phase after the last band
+    fn advance(&mut self) {
+        self.runs = 0;
+        self.reference_duty = None;
+        self.band += 1;
+        if self.band < CALIBRATION_RPM_BANDS.len() {
+            return;
+        }
+        
+        self.band = 0;
+        if self.phase < CALIBRATION_PHASES {
+            self.phase += 1;
+        } else {
+            self.band = CALIBRATION_RPM_BANDS.len() - 1;
+            self.complete = true;
+        }
+    }
+    
+    fn end_run(&mut self, description: String) {
+        self.stage = RunStage::Recovering;
+        self.description = description;
+    }
+    
+    fn pull_instruction(&self) -> String {
+        let (low_rpm, high_rpm) = self.rpm_band();
+        format!("Full-throttle pull through {}-{} RPM", low_rpm, high_rpm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(throttle: f32, rpm: u16, boost_psi: f32, t_ms: u32) -> SystemInputs {
+        SystemInputs { rpm, manifold_pressure: boost_psi, throttle_position: Some(throttle), ..test_support::inputs(t_ms) }
+    }
+    
+    /// One pull in the current band against a plant where each % duty makes `psi_per_duty` over spring
+    fn pull(calibration: &mut AutoCalibration, learned: &mut LearnedData, psi_per_duty: f32, t_ms: &mut u32) {
+        let rpm = CALIBRATION_RPM_BANDS[calibration.band];
+        let mut duty = 0.0;
+        for _ in 0..2000 {
+            let boost = calibration.spring_psi + duty * psi_per_duty;
+            duty = calibration.execute_step(&inputs(100.0, rpm, boost, *t_ms), learned).unwrap();
+            *t_ms += 10;
+            if calibration.stage == RunStage::Recovering {
+                break;
+            }
+        }
+        calibration.execute_step(&inputs(0.0, rpm, 0.0, *t_ms), learned).unwrap();
+    }
+    
+    #[test]
+    fn test_session_walks_bands_and_phases() {
+        let config = SystemConfig::default();
+        let mut calibration = AutoCalibration::new();
+        let mut learned = LearnedData::new(config.spring_pressure);
+        calibration.start(&config);
+        assert_eq!(calibration.ceiling_psi(), config.spring_pressure + 1.0);
+        
+        let mut t_ms = 0;
+        for _ in 0..VALIDATION_RUNS {
+            pull(&mut calibration, &mut learned, 0.1, &mut t_ms);
+        }
+        assert_eq!(calibration.progress().current_rpm, CALIBRATION_RPM_BANDS[1]);
+        assert!(learned.status().cells_learned > 0);
+        
+        for _ in 0..(CALIBRATION_RPM_BANDS.len() * CALIBRATION_PHASES as usize - 1) * VALIDATION_RUNS as usize {
+            pull(&mut calibration, &mut learned, 0.1, &mut t_ms);
+        }
+        assert!(calibration.is_complete());
+        assert_eq!(calibration.progress().overall_progress, 1.0);
+        assert!(calibration.ceiling_psi() < config.overboost_limit);
+    }
+    
+    #[test]
+    fn test_lift_and_overshoot_end_the_run() {
+        let config = SystemConfig::default();
+        let mut calibration = AutoCalibration::new();
+        let mut learned = LearnedData::new(config.spring_pressure);
+        calibration.start(&config);
+        let rpm = CALIBRATION_RPM_BANDS[0];
+        
+        assert!(calibration.execute_step(&inputs(100.0, rpm, 5.0, 0), &mut learned).unwrap() > 0.0);
+        assert_eq!(calibration.execute_step(&inputs(50.0, rpm, 5.0, 10), &mut learned).unwrap(), 0.0);
+        assert_eq!(calibration.progress().validation_runs, 0);
+        
+        // Out of the band nothing starts
+        calibration.execute_step(&inputs(0.0, rpm, 0.0, 20), &mut learned).unwrap();
+        assert_eq!(calibration.execute_step(&inputs(100.0, 6000, 5.0, 30), &mut learned).unwrap(), 0.0);
+        
+        calibration.execute_step(&inputs(100.0, rpm, 5.0, 40), &mut learned).unwrap();
+        let overshoot = calibration.ceiling_psi() + 1.0;
+        assert_eq!(calibration.execute_step(&inputs(100.0, rpm, overshoot, 50), &mut learned).unwrap(), 0.0);
+        assert!(calibration.progress().description.starts_with("Overshot"));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::{RumbleDomeCore, SystemConfig};
+    use rumbledome_hal::{CanFrame, MockHal, COYOTE_LOAD_ID, COYOTE_RPM_ID, COYOTE_TORQUE_ID};
+    
+    /// One cycle of Coyote broadcast at 2500 RPM, the engine making 271 Nm
+    fn cycle(core: &mut RumbleDomeCore<MockHal>, torque_request: [u8; 3], timestamp_ms: u32) {
+        core.hal.set_now_ms(timestamp_ms);
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_RPM_ID, &[0x27, 0x10]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_TORQUE_ID, &torque_request).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]).unwrap());
+        core.execute_control_cycle().unwrap();
+    }
+    
+    #[test]
+    fn test_torque_request_drives_pulls_without_throttle_signal() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        core.skip_commissioning().unwrap();
+        core.start_calibration().unwrap();
+        assert_eq!(core.read_inputs().unwrap().throttle_position, None);
+        
+        // ECU asking for 400 Nm - a wide-open pull in the first band
+        for timestamp_ms in (1_000..1_050).step_by(10) {
+            cycle(&mut core, [0, 134, 0x40], timestamp_ms);
+        }
+        assert!(core.calibration_progress().unwrap().description.starts_with("Sweeping"));
+        
+        // Request falling to 200 Nm, below what the engine makes, is the lift
+        cycle(&mut core, [0, 131, 0x20], 1_050);
+        assert!(core.calibration_progress().unwrap().description.starts_with("Pull ended early"));
+    }
+}
@@ -0,0 +1,430 @@
+//! CAN Bus Health Monitoring
+//! 
+//! 🔗 T4-CORE-028: CAN Bus Health Model
+//! Derived From: SY-10 (CAN Dependency Management) + T2-SIM-015 (CAN bus jitter and timing variations)
+//! AI Traceability: Per-message timing statistics, health scoring, adaptive staleness thresholds
+
+use heapless::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of distinct CAN message IDs tracked
+pub const MAX_TRACKED_CAN_MESSAGES: usize = 8;
+
+/// Nominal staleness threshold before any timing statistics exist (ms)
+pub const NOMINAL_STALE_THRESHOLD_MS: u32 = 200;
+
+/// Tightest staleness threshold regardless of how fast a message arrives (ms)
+pub const MIN_STALE_THRESHOLD_MS: u32 = 50;
+
+/// Hard ceiling on CAN loss detection - SY-10 requires detection within 500ms
+pub const MAX_LOSS_THRESHOLD_MS: u32 = 500;
+
+/// Number of expected intervals (plus jitter allowance) before a message is stale
+const STALE_INTERVAL_MULTIPLIER: f32 = 3.0;
+
+/// Number of jitter deviations allowed on top of the expected interval
+const STALE_JITTER_MULTIPLIER: f32 = 4.0;
+
+/// Loss threshold as a multiple of the staleness threshold
+const LOSS_STALE_MULTIPLIER: f32 = 2.5;
+
+/// Smoothing factor for inter-arrival statistics (exponential moving average)
+const STATS_SMOOTHING: f32 = 0.1;
+
+/// Consecutive long gaps after which the expected interval is reseeded
+/// (a steady run of "dropouts" means the seed interval was wrong)
+const RESEED_LONG_GAPS: u32 = 4;
+
+/// Received + missed frames kept before the completeness counts are halved
+const COMPLETENESS_WINDOW: u32 = 100;
+
+/// Timing statistics for a single CAN message ID
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanMessageStats {
+    /// CAN arbitration ID
+    pub id: u32,
+    /// Timestamp of last reception (ms)
+    pub last_rx_ms: u32,
+    /// Smoothed inter-arrival interval (ms)
+    pub mean_interval_ms: f32,
+    /// Smoothed absolute deviation from the mean interval (ms)
+    pub jitter_ms: f32,
+    /// Total frames received
+    pub frames_received: u32,
+    /// Frames received within the decaying completeness window
+    pub window_frames: u32,
+    /// Estimated frames missed within the decaying completeness window
+    pub frames_missed: u32,
+    /// Consecutive intervals counted as dropouts
+    pub long_gaps: u32,
+}
+
+impl CanMessageStats {
+    fn new(id: u32, timestamp_ms: u32) -> Self {
+        Self {
+            id,
+            last_rx_ms: timestamp_ms,
+            mean_interval_ms: 0.0,
+            jitter_ms: 0.0,
+            frames_received: 1,
+            window_frames: 1,
+            frames_missed: 0,
+            long_gaps: 0,
+        }
+    }
+    
+    /// Whether enough frames have arrived to trust the timing statistics
+    pub fn has_statistics(&self) -> bool {
+        self.frames_received >= 3
+    }
+    
+    /// Adaptive staleness threshold for this message (ms)
+    /// 
+    /// Fast, regular messages get tight thresholds so dropouts are caught quickly;
+    /// slow or jittery messages get more slack, never exceeding the nominal limit.
+    pub fn stale_threshold_ms(&self) -> u32 {
+        if !self.has_statistics() {
+            return NOMINAL_STALE_THRESHOLD_MS;
+        }
+        
+        let threshold = self.mean_interval_ms * STALE_INTERVAL_MULTIPLIER
+            + self.jitter_ms * STALE_JITTER_MULTIPLIER;
+        (threshold as u32).clamp(MIN_STALE_THRESHOLD_MS, NOMINAL_STALE_THRESHOLD_MS)
+    }
+    
+    /// Adaptive loss threshold for this message (ms)
+    pub fn loss_threshold_ms(&self) -> u32 {
+        if !self.has_statistics() {
+            return MAX_LOSS_THRESHOLD_MS;
+        }
+        
+        ((self.stale_threshold_ms() as f32 * LOSS_STALE_MULTIPLIER) as u32).min(MAX_LOSS_THRESHOLD_MS)
+    }
+    
+    /// Halve the completeness counts once the window fills so old dropouts fade
+    fn decay_window(&mut self) {
+        if self.window_frames + self.frames_missed > COMPLETENESS_WINDOW {
+            self.window_frames /= 2;
+            self.frames_missed /= 2;
+        }
+    }
+    
+    /// Age of the most recent frame (ms)
+    pub fn age_ms(&self, now_ms: u32) -> u32 {
+        now_ms.wrapping_sub(self.last_rx_ms)
+    }
+    
+    /// Message health score (0.0-1.0)
+    fn score(&self, now_ms: u32) -> f32 {
+        let age = self.age_ms(now_ms);
+        let loss_threshold = self.loss_threshold_ms();
+        if age >= loss_threshold {
+            return 0.0;
+        }
+        
+        // Freshness: full credit until stale, then linear decay to zero at loss
+        let stale_threshold = self.stale_threshold_ms();
+        let freshness = if age <= stale_threshold {
+            1.0
+        } else {
+            1.0 - (age - stale_threshold) as f32 / (loss_threshold - stale_threshold).max(1) as f32
+        };
+        
+        if !self.has_statistics() {
+            return freshness;
+        }
+        
+        // Regularity: jitter relative to the expected interval
+        let regularity = if self.mean_interval_ms > 0.0 {
+            (1.0 - self.jitter_ms / self.mean_interval_ms).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        
+        // Completeness: fraction of recently expected frames that actually arrived
+        let expected = self.window_frames + self.frames_missed;
+        let completeness = self.window_frames as f32 / expected.max(1) as f32;
+        
+        freshness * (0.5 + 0.5 * regularity) * completeness
+    }
+}
+
+/// Overall CAN bus health classification
+/// 
+/// 🔗 T4-CORE-029: CAN Degradation Behavior
+/// Derived From: SY-10 (CAN Dependency Management) + Safety.md fault response hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CanHealthLevel {
+    /// No CAN frames received yet
+    NoData,
+    /// All tracked messages fresh and regular
+    Healthy,
+    /// Jitter, dropped frames, or stale data - torque assistance reduced
+    Degraded,
+    /// Data barely usable - no torque assistance, baseline boost only
+    Critical,
+    /// At least one tracked message exceeded its loss threshold
+    Lost,
+}
+
+impl CanHealthLevel {
+    /// Fraction of Level 1 torque assistance permitted at this health level
+    pub fn torque_authority(&self) -> f32 {
+        match self {
+            CanHealthLevel::Healthy => 1.0,
+            CanHealthLevel::Degraded => 0.5,
+            CanHealthLevel::Critical | CanHealthLevel::Lost | CanHealthLevel::NoData => 0.0,
+        }
+    }
+    
+    /// Whether torque-following must stop and the system fault (SY-10)
+    pub fn requires_fault(&self) -> bool {
+        matches!(self, CanHealthLevel::Lost | CanHealthLevel::NoData)
+    }
+}
+
+/// CAN health snapshot for diagnostics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanHealthReport {
+    /// Overall health score (0-100)
+    pub score: u8,
+    /// Health classification
+    pub level: CanHealthLevel,
+    /// Per-message timing statistics
+    pub messages: Vec<CanMessageStats, MAX_TRACKED_CAN_MESSAGES>,
+    /// Frames ignored because the tracking table was full
+    pub untracked_frames: u32,
+}
+
+/// CAN bus health monitor
+/// 
+/// 🔗 T4-CORE-030: CAN Bus Health Monitor
+/// Derived From: T4-CORE-028 + SY-10 500ms loss detection requirement
+/// The bus score is the worst per-message score - one missing torque message
+/// is enough to compromise torque-following regardless of how healthy RPM is.
+#[derive(Debug, Clone, Default)]
+pub struct CanBusHealth {
+    messages: Vec<CanMessageStats, MAX_TRACKED_CAN_MESSAGES>,
+    untracked_frames: u32,
+}
+
+impl CanBusHealth {
+    /// Create empty health monitor
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Record reception of a CAN frame
+    pub fn record_message(&mut self, id: u32, timestamp_ms: u32) {
+        if let Some(stats) = self.messages.iter_mut().find(|m| m.id == id) {
+            let interval = timestamp_ms.wrapping_sub(stats.last_rx_ms) as f32;
+            stats.last_rx_ms = timestamp_ms;
+            stats.frames_received = stats.frames_received.saturating_add(1);
+            stats.window_frames = stats.window_frames.saturating_add(1);
+            
+            if stats.frames_received == 2 {
+                // First interval seeds the statistics
+                stats.mean_interval_ms = interval;
+                return;
+            }
+            
+            // Gaps spanning several expected intervals imply dropped frames
+            if stats.mean_interval_ms > 0.0 && interval > stats.mean_interval_ms * 1.5 {
+                stats.long_gaps += 1;
+                if stats.long_gaps >= RESEED_LONG_GAPS {
+                    // Steady long gaps are the real rate - the seed was short, not the bus lossy
+                    stats.mean_interval_ms = interval;
+                    stats.jitter_ms = 0.0;
+                    stats.frames_missed = 0;
+                    stats.long_gaps = 0;
+                    return;
+                }
+                
+                let missed = (interval / stats.mean_interval_ms) as u32 - 1;
+                stats.frames_missed = stats.frames_missed.saturating_add(missed.max(1));
+                stats.decay_window();
+                // Don't let dropouts inflate the expected interval
+                return;
+            }
+            
+            stats.long_gaps = 0;
+            stats.decay_window();
+            let deviation = libm::fabsf(interval - stats.mean_interval_ms);
+            stats.mean_interval_ms += STATS_SMOOTHING * (interval - stats.mean_interval_ms);
+            stats.jitter_ms += STATS_SMOOTHING * (deviation - stats.jitter_ms);
+        } else if self.messages.push(CanMessageStats::new(id, timestamp_ms)).is_err() {
+            self.untracked_frames = self.untracked_frames.saturating_add(1);
+        }
+    }
+    
+    /// Statistics for a specific message ID
+    pub fn message(&self, id: u32) -> Option<&CanMessageStats> {
+        self.messages.iter().find(|m| m.id == id)
+    }
+    
+    /// Whether a message is fresh enough to use (within adaptive stale threshold)
+    pub fn is_fresh(&self, id: u32, now_ms: u32) -> bool {
+        self.message(id)
+            .map(|m| m.age_ms(now_ms) <= m.stale_threshold_ms())
+            .unwrap_or(false)
+    }
+    
+    /// Overall bus health score (0.0-1.0)
+    pub fn score(&self, now_ms: u32) -> f32 {
+        self.messages
+            .iter()
+            .map(|m| m.score(now_ms))
+            .fold(None, |worst: Option<f32>, s| Some(worst.map_or(s, |w| w.min(s))))
+            .unwrap_or(0.0)
+    }
+    
+    /// Classify current bus health
+    pub fn level(&self, now_ms: u32) -> CanHealthLevel {
+        if self.messages.is_empty() {
+            return CanHealthLevel::NoData;
+        }
+        
+        if self.messages.iter().any(|m| m.age_ms(now_ms) >= m.loss_threshold_ms()) {
+            return CanHealthLevel::Lost;
+        }
+        
+        match self.score(now_ms) {
+            s if s >= 0.8 => CanHealthLevel::Healthy,
+            s if s >= 0.4 => CanHealthLevel::Degraded,
+            _ => CanHealthLevel::Critical,
+        }
+    }
+    
+    /// Diagnostics snapshot
+    pub fn report(&self, now_ms: u32) -> CanHealthReport {
+        CanHealthReport {
+            score: (self.score(now_ms) * 100.0) as u8,
+            level: self.level(now_ms),
+            messages: self.messages.clone(),
+            untracked_frames: self.untracked_frames,
+        }
+    }
+    
+    /// Forget all statistics (e.g. after CAN controller re-initialization)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const RPM_ID: u32 = 0x109;
+    const TORQUE_ID: u32 = 0x167;
+    
+    fn feed_regular(health: &mut CanBusHealth, id: u32, interval_ms: u32, count: u32) -> u32 {
+        let mut t = 0;
+        for _ in 0..count {
+            health.record_message(id, t);
+            t += interval_ms;
+        }
+        t - interval_ms
+    }
+    
+    #[test]
+    fn test_no_data_requires_fault() {
+        let health = CanBusHealth::new();
+        assert_eq!(health.level(1000), CanHealthLevel::NoData);
+        assert!(health.level(1000).requires_fault());
+    }
+    
+    #[test]
+    fn test_regular_fast_message_gets_tight_thresholds() {
+        let mut health = CanBusHealth::new();
+        let last = feed_regular(&mut health, RPM_ID, 20, 50);
+        
+        let stats = health.message(RPM_ID).unwrap();
+        assert!(stats.stale_threshold_ms() < NOMINAL_STALE_THRESHOLD_MS);
+        assert!(stats.stale_threshold_ms() >= MIN_STALE_THRESHOLD_MS);
+        assert_eq!(health.level(last + 10), CanHealthLevel::Healthy);
+        assert!(health.report(last + 10).score >= 95);
+    }
+    
+    #[test]
+    fn test_loss_detected_within_safety_limit() {
+        let mut health = CanBusHealth::new();
+        let last = feed_regular(&mut health, TORQUE_ID, 100, 20);
+        
+        let stats = health.message(TORQUE_ID).unwrap();
+        assert!(stats.loss_threshold_ms() <= MAX_LOSS_THRESHOLD_MS);
+        assert_eq!(health.level(last + MAX_LOSS_THRESHOLD_MS), CanHealthLevel::Lost);
+    }
+    
+    #[test]
+    fn test_dropped_frames_degrade_score() {
+        let mut health = CanBusHealth::new();
+        let mut t = 0;
+        for i in 0..60 {
+            // Drop every third frame
+            if i % 3 != 2 {
+                health.record_message(RPM_ID, t);
+            }
+            t += 20;
+        }
+        
+        let stats = health.message(RPM_ID).unwrap();
+        assert!(stats.frames_missed > 0);
+        assert_ne!(health.level(t - 20), CanHealthLevel::Healthy);
+    }
+    
+    #[test]
+    fn test_short_seed_recovers_at_real_rate() {
+        let mut health = CanBusHealth::new();
+        // Cycle-time stamping can make the first interval one cycle short
+        health.record_message(RPM_ID, 0);
+        health.record_message(RPM_ID, 10);
+        let mut t = 10;
+        for _ in 0..500 {
+            t += 20;
+            health.record_message(RPM_ID, t);
+        }
+        
+        let stats = health.message(RPM_ID).unwrap();
+        assert!((stats.mean_interval_ms - 20.0).abs() < 1.0);
+        assert_eq!(stats.frames_missed, 0);
+        assert_eq!(health.level(t), CanHealthLevel::Healthy);
+        assert_eq!(health.level(t).torque_authority(), 1.0);
+    }
+    
+    #[test]
+    fn test_completeness_recovers_after_dropouts_stop() {
+        let mut health = CanBusHealth::new();
+        let mut t = 0;
+        for i in 0..60 {
+            if i % 3 != 2 {
+                health.record_message(RPM_ID, t);
+            }
+            t += 20;
+        }
+        for _ in 0..400 {
+            health.record_message(RPM_ID, t);
+            t += 20;
+        }
+        
+        assert_eq!(health.level(t - 20), CanHealthLevel::Healthy);
+    }
+    
+    #[test]
+    fn test_worst_message_dominates_bus_score() {
+        let mut health = CanBusHealth::new();
+        feed_regular(&mut health, RPM_ID, 20, 50);
+        let mut t = 0;
+        for _ in 0..10 {
+            health.record_message(TORQUE_ID, t);
+            t += 20;
+        }
+        
+        // RPM keeps arriving while torque goes quiet
+        let now = 2000;
+        health.record_message(RPM_ID, now);
+        assert_eq!(health.level(now), CanHealthLevel::Lost);
+        assert!(health.is_fresh(RPM_ID, now));
+        assert!(!health.is_fresh(TORQUE_ID, now));
+    }
+}
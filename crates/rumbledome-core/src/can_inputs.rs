@@ -0,0 +1,520 @@
+//! CAN Input Decoding
+//! 
+//! 🔗 T4-CORE-106: CAN Input Decoding
+//! Derived From: T4-CORE-090 (CAN Signal Map) + T4-HAL-016 (CAN Bus Interface and Coyote Frame Parsing)
+//! AI Traceability: Engine speed and ECU torque reach the control inputs from the bus - mapped signals where configured, the vehicle protocol otherwise
+//! 
+//! Each control cycle drains the CAN receive buffer. Inputs with a signal in
+//! the configured CAN signal map are decoded from that signal; unmapped
+//! inputs fall back to the configured vehicle protocol's decoder in the HAL.
+//! Protocols that have to ask for their data (OBD-II) are given a chance to
+//! send a request every cycle. RPM is reported only for the
+//! cycle a frame carried it, so the RPM validator sees real dropouts. Torque,
+//! CAN MAP, throttle and engine load are held between frames with their arrival time,
+//! so the core can drop values older than the CAN loss threshold.
+//! Temperatures are broadcast far less often, so they are held for
+//! `MAX_TEMPERATURE_AGE_MS` instead.
+//! 
+//! Only frames carrying RPM, torque or MAP count towards CAN health. Those
+//! are the inputs loss detection protects; a slow message such as a
+//! temperature would read as lost between broadcasts and fault the system.
+//! 
+//! With OBD-II fallback configured, a broadcast platform whose torque frames
+//! have been missing for `OBD_FALLBACK_AFTER_MS` is polled over OBD-II
+//! instead. Polled values fill any input the broadcast leaves empty, and
+//! polling stops as soon as broadcast torque is back.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use rumbledome_hal::{CanFilter, CanFrame, Obd2Poller, VehicleCanProtocol, VehicleProtocol, vehicle_can_protocol};
+use crate::{CanSignal, CanSignalMap};
+
+/// Most frames taken from the receive buffer in one cycle
+pub const MAX_CAN_FRAMES_PER_CYCLE: usize = 32;
+
+/// Time without broadcast torque before OBD-II fallback polling starts (ms)
+pub const OBD_FALLBACK_AFTER_MS: u32 = 1_000;
+
+/// Age past which a CAN temperature is no longer used (ms)
+pub const MAX_TEMPERATURE_AGE_MS: u32 = 5_000;
+
+/// Latest engine data decoded from the bus
+#[derive(Debug)]
+pub struct CanInputs {
+    signals: CanSignalMap,
+    parser: Box<dyn VehicleCanProtocol>,
+    fallback: Option<Obd2Poller>,
+    /// Last broadcast torque, or the first cycle until one arrives
+    broadcast_torque_ms: Option<u32>,
+    rpm: Option<u16>,
+    desired_torque: Option<(f32, u32)>,
+    actual_torque: Option<(f32, u32)>,
+    manifold_pressure: Option<(f32, u32)>,
+    throttle_position: Option<(f32, u32)>,
+    engine_load: Option<(f32, u32)>,
+    drive_mode: Option<(f32, u32)>,
+    barometric_pressure: Option<(f32, u32)>,
+    vehicle_speed: Option<(f32, u32)>,
+    intake_air_temp: Option<(f32, u32)>,
+    coolant_temp: Option<(f32, u32)>,
+}
+
+impl CanInputs {
+    /// Create with nothing received
+    pub fn new(signals: &CanSignalMap) -> Self {
+        Self {
+            signals: signals.clone(),
+            parser: vehicle_can_protocol(signals.vehicle, signals.coyote_generation, signals.obd_polling),
+            fallback: Self::fallback_poller(signals),
+            broadcast_torque_ms: None,
+            rpm: None,
+            desired_torque: None,
+            actual_torque: None,
+            manifold_pressure: None,
+            throttle_position: None,
+            engine_load: None,
+            drive_mode: None,
+            barometric_pressure: None,
+            vehicle_speed: None,
+            intake_air_temp: None,
+            coolant_temp: None,
+        }
+    }
+    
+    /// Apply a changed signal map; values already received are kept
+    pub fn reconfigure(&mut self, signals: &CanSignalMap) {
+        self.signals = signals.clone();
+        self.parser = vehicle_can_protocol(signals.vehicle, signals.coyote_generation, signals.obd_polling);
+        self.fallback = Self::fallback_poller(signals);
+    }
+    
+    /// OBD-II poller standing in for missing broadcast torque, if configured
+    fn fallback_poller(signals: &CanSignalMap) -> Option<Obd2Poller> {
+        (signals.obd_fallback && signals.vehicle != VehicleProtocol::Obd2).then(|| Obd2Poller::new(signals.obd_polling))
+    }
+    
+    /// Whether OBD-II fallback polling is standing in for broadcast torque
+    pub fn obd_fallback_active(&self, now_ms: u32) -> bool {
+        self.fallback.is_some()
+            && self.broadcast_torque_ms.is_some_and(|at_ms| now_ms.wrapping_sub(at_ms) > OBD_FALLBACK_AFTER_MS)
+    }
+    
+    /// Acceptance filters for every message an input is decoded from
+    pub fn filters(&self) -> Vec<CanFilter> {
+        let mut filters: Vec<CanFilter> = self.signals.signals().map(|(_, signal)| CanFilter::exact(signal.message_id)).collect();
+        let unmapped = self.signals.rpm.is_none() || self.signals.desired_torque.is_none()
+            || self.signals.actual_torque.is_none() || self.signals.manifold_pressure.is_none();
+        if unmapped {
+            filters.extend(self.parser.filters());
+        }
+        if let Some(fallback) = &self.fallback {
+            filters.extend(fallback.filters());
+        }
+        filters.sort_unstable_by_key(|filter| (filter.id, filter.mask));
+        filters.dedup();
+        filters
+    }
+    
+    /// Request frame the vehicle protocol or fallback poller wants sent this cycle (OBD-II polling)
+    pub fn poll_request(&mut self, now_ms: u32) -> Option<CanFrame> {
+        // The fallback grace period runs from the first cycle
+        self.broadcast_torque_ms.get_or_insert(now_ms);
+        let fallback_active = self.obd_fallback_active(now_ms);
+        
+        self.parser.poll_request(now_ms).or_else(|| match &mut self.fallback {
+            Some(fallback) if fallback_active => fallback.poll_request(now_ms),
+            _ => None,
+        })
+    }
+    
+    /// Start a new cycle - RPM is only reported for the cycle it arrived in
+    pub fn begin_cycle(&mut self) {
+        self.rpm = None;
+    }
+    
+    /// Decode one received frame
+    /// 
+    /// Returns whether the frame carried RPM, torque or MAP - the inputs CAN
+    /// health tracks. Slower signals are stored but not reported.
+    pub fn decode(&mut self, frame: &CanFrame, now_ms: u32) -> bool {
+        let payload = frame.payload();
+        let platform = self.parser.parse(frame);
+        let polled = self.fallback.as_mut().map(|fallback| fallback.parse(frame)).unwrap_or_default();
+        let mapped = |signal: &Option<CanSignal>| {
+            signal.as_ref().filter(|signal| signal.message_id == frame.id).and_then(|signal| signal.decode(payload))
+        };
+        
+        let rpm = match &self.signals.rpm {
+            Some(_) => mapped(&self.signals.rpm).map(|rpm| rpm.max(0.0) as u16),
+            None => platform.rpm,
+        };
+        let desired_torque = match &self.signals.desired_torque {
+            Some(_) => mapped(&self.signals.desired_torque),
+            None => platform.desired_torque_nm,
+        };
+        let actual_torque = match &self.signals.actual_torque {
+            Some(_) => mapped(&self.signals.actual_torque),
+            None => platform.actual_torque_nm,
+        };
+        let manifold_pressure = match &self.signals.manifold_pressure {
+            Some(_) => mapped(&self.signals.manifold_pressure),
+            None => platform.manifold_pressure_psi,
+        };
+        if desired_torque.is_some() || actual_torque.is_some() {
+            self.broadcast_torque_ms = Some(now_ms);
+        }
+        
+        // Fallback answers only fill what the broadcast leaves empty
+        let rpm = rpm.or(polled.rpm);
+        let desired_torque = desired_torque.or(polled.desired_torque_nm);
+        let actual_torque = actual_torque.or(polled.actual_torque_nm);
+        let manifold_pressure = manifold_pressure.or(polled.manifold_pressure_psi);
+        let engine_load = platform.engine_load_pct.or(polled.engine_load_pct);
+        let throttle_position = mapped(&self.signals.throttle_position);
+        let drive_mode = mapped(&self.signals.drive_mode);
+        let barometric_pressure = mapped(&self.signals.barometric_pressure);
+        let vehicle_speed = mapped(&self.signals.vehicle_speed);
+        let intake_air_temp = mapped(&self.signals.intake_air_temp);
+        let coolant_temp = mapped(&self.signals.coolant_temp);
+        
+        if rpm.is_some() {
+            self.rpm = rpm;
+        }
+        for (value, slot) in [
+            (desired_torque, &mut self.desired_torque),
+            (actual_torque, &mut self.actual_torque),
+            (manifold_pressure, &mut self.manifold_pressure),
+            (throttle_position, &mut self.throttle_position),
+            (engine_load, &mut self.engine_load),
+            (drive_mode, &mut self.drive_mode),
+            (barometric_pressure, &mut self.barometric_pressure),
+            (vehicle_speed, &mut self.vehicle_speed),
+            (intake_air_temp, &mut self.intake_air_temp),
+            (coolant_temp, &mut self.coolant_temp),
+        ] {
+            if let Some(value) = value {
+                *slot = Some((value, now_ms));
+            }
+        }
+        rpm.is_some() || desired_torque.is_some() || actual_torque.is_some() || manifold_pressure.is_some()
+    }
+    
+    /// RPM received this cycle
+    pub fn rpm(&self) -> Option<u16> {
+        self.rpm
+    }
+    
+    /// Latest ECU desired torque and when it arrived (Nm, ms)
+    pub fn desired_torque(&self) -> Option<(f32, u32)> {
+        self.desired_torque
+    }
+    
+    /// Latest actual engine torque and when it arrived (Nm, ms)
+    pub fn actual_torque(&self) -> Option<(f32, u32)> {
+        self.actual_torque
+    }
+    
+    /// Latest CAN manifold pressure and when it arrived (PSI, ms)
+    pub fn manifold_pressure(&self) -> Option<(f32, u32)> {
+        self.manifold_pressure
+    }
+    
+    /// Latest throttle position and when it arrived (%, ms)
+    pub fn throttle_position(&self) -> Option<(f32, u32)> {
+        self.throttle_position
+    }
+    
+    /// Latest calculated engine load and when it arrived (%, ms)
+    pub fn engine_load(&self) -> Option<(f32, u32)> {
+        self.engine_load
+    }
+    
+    /// Latest drive mode signal value and when it arrived (ms)
+    pub fn drive_mode(&self) -> Option<(f32, u32)> {
+        self.drive_mode
+    }
+    
+    /// Latest barometric pressure and when it arrived (kPa, ms)
+    pub fn barometric_pressure(&self) -> Option<(f32, u32)> {
+        self.barometric_pressure
+    }
+    
+    /// Latest vehicle speed and when it arrived (km/h, ms)
+    pub fn vehicle_speed(&self) -> Option<(f32, u32)> {
+        self.vehicle_speed
+    }
+    
+    /// Latest intake air temperature and when it arrived (°C, ms)
+    pub fn intake_air_temp(&self) -> Option<(f32, u32)> {
+        self.intake_air_temp
+    }
+    
+    /// Latest coolant temperature and when it arrived (°C, ms)
+    pub fn coolant_temp(&self) -> Option<(f32, u32)> {
+        self.coolant_temp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ByteOrder;
+    use rumbledome_hal::{
+        COYOTE_LOAD_ID, COYOTE_RPM_ID, COYOTE_TORQUE_ID, OBD_PID_ACTUAL_TORQUE, OBD_PID_ENGINE_LOAD, OBD_PID_REFERENCE_TORQUE,
+        OBD_REQUEST_ID,
+    };
+    
+    fn frame(id: u32, payload: &[u8]) -> CanFrame {
+        CanFrame::new(id, payload).unwrap()
+    }
+    
+    #[test]
+    fn test_unmapped_inputs_use_coyote_broadcast() {
+        let mut inputs = CanInputs::new(&CanSignalMap::default());
+        assert_eq!(inputs.filters().len(), 3);
+        
+        assert!(inputs.decode(&frame(COYOTE_RPM_ID, &[0x3E, 0x80]), 10));
+        assert!(inputs.decode(&frame(COYOTE_TORQUE_ID, &[0, 134, 0x40]), 12));
+        assert!(!inputs.decode(&frame(0x204, &[0; 8]), 14));
+        assert_eq!(inputs.rpm(), Some(4000));
+        assert_eq!(inputs.desired_torque(), Some((400.0, 12)));
+        assert_eq!(inputs.actual_torque(), None);
+        
+        // RPM is per cycle; torque is held until the next frame
+        inputs.begin_cycle();
+        assert_eq!(inputs.rpm(), None);
+        assert_eq!(inputs.desired_torque(), Some((400.0, 12)));
+    }
+    
+    #[test]
+    fn test_obd_fallback_stands_in_for_missing_broadcast_torque() {
+        let map = CanSignalMap { obd_fallback: true, ..Default::default() };
+        let mut inputs = CanInputs::new(&map);
+        assert!(inputs.filters().iter().any(|filter| filter.matches(0x7E8)));
+        
+        // Broadcast torque keeps the fallback quiet
+        assert!(inputs.poll_request(0).is_none());
+        inputs.decode(&frame(COYOTE_TORQUE_ID, &[0, 134, 0x40]), 500);
+        assert!(inputs.poll_request(1_200).is_none());
+        
+        // Missing for longer than the grace period - poll, reference torque first
+        let request = inputs.poll_request(1_600).unwrap();
+        assert!(inputs.obd_fallback_active(1_600));
+        assert_eq!(request.id, OBD_REQUEST_ID);
+        assert_eq!(request.payload()[2], OBD_PID_REFERENCE_TORQUE);
+        inputs.decode(&frame(0x7E8, &[0x04, 0x41, OBD_PID_REFERENCE_TORQUE, 0x02, 0x58, 0, 0, 0]), 1_610);
+        assert!(inputs.decode(&frame(0x7E8, &[0x03, 0x41, OBD_PID_ACTUAL_TORQUE, 175, 0, 0, 0, 0]), 1_620));
+        assert_eq!(inputs.actual_torque(), Some((300.0, 1_620)));
+        assert!(!inputs.decode(&frame(0x7E8, &[0x03, 0x41, OBD_PID_ENGINE_LOAD, 51, 0, 0, 0, 0]), 1_630));
+        assert_eq!(inputs.engine_load(), Some((20.0, 1_630)));
+        
+        // Broadcast torque back - polling stops
+        inputs.decode(&frame(COYOTE_TORQUE_ID, &[0, 134, 0x40]), 1_700);
+        assert!(!inputs.obd_fallback_active(1_700));
+        assert!(inputs.poll_request(1_800).is_none());
+    }
+    
+    #[test]
+    fn test_mapped_signal_replaces_platform_default() {
+        let actual = CanSignal {
+            message_id: 0x204,
+            start_bit: 0,
+            length: 16,
+            byte_order: ByteOrder::LittleEndian,
+            signed: true,
+            scale: 0.5,
+            offset: 0.0,
+        };
+        let map = CanSignalMap { actual_torque: Some(actual), ..Default::default() };
+        let mut inputs = CanInputs::new(&map);
+        assert!(inputs.filters().iter().any(|filter| filter.matches(0x204)));
+        
+        // The Coyote load frame no longer feeds actual torque
+        inputs.decode(&frame(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]), 0);
+        assert_eq!(inputs.actual_torque(), None);
+        inputs.decode(&frame(0x204, &[0x20, 0x03]), 5);
+        assert_eq!(inputs.actual_torque(), Some((400.0, 5)));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::test_support::armed_core;
+    use crate::{ByteOrder, CanSignal, ControlMode, CoreEvent, RumbleDomeCore, SystemConfig, SystemState, MAX_LOSS_THRESHOLD_MS};
+    use rumbledome_hal::{
+        CanFrame, CanInterface, MockHal, VehicleProtocol, COYOTE_LOAD_ID, COYOTE_RPM_ID, COYOTE_TORQUE_ID,
+        OBD_PID_ACTUAL_TORQUE, OBD_PID_DEMAND_TORQUE, OBD_PID_REFERENCE_TORQUE, OBD_PID_RPM, OBD_REQUEST_ID,
+    };
+    
+    #[test]
+    fn test_coyote_broadcast_reaches_system_inputs() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.hal.can_bitrate(), core.config.can_bitrate_bps);
+        
+        // Filters set at initialization keep unrelated traffic out of the buffer
+        core.hal.receive_can_frame(CanFrame::new(0x204, &[0; 8]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_RPM_ID, &[0x3E, 0x80, 0, 0, 0, 0, 0, 0]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_TORQUE_ID, &[0, 134, 0x40, 0, 0, 0, 0, 0]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]).unwrap());
+        assert_eq!(core.hal.can_stats().frames_received, 3);
+        
+        let inputs = core.read_inputs().unwrap();
+        assert_eq!(inputs.rpm, 4000);
+        assert_eq!(inputs.desired_torque, 400.0);
+        assert!((inputs.actual_torque - 271.0).abs() < 0.01);
+        assert!(core.can_health.message(COYOTE_TORQUE_ID).is_some());
+        
+        // A cycle without frames holds the last values
+        let inputs = core.read_inputs().unwrap();
+        assert_eq!(inputs.rpm, 4000);
+        assert_eq!(inputs.desired_torque, 400.0);
+        assert!(core.hal.sent_can_frames().is_empty());
+    }
+    
+    #[test]
+    fn test_obd2_polling_feeds_system_inputs() {
+        let mut config = SystemConfig::default();
+        config.can_signals.vehicle = VehicleProtocol::Obd2;
+        config.validate().unwrap();
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize().unwrap();
+        
+        // The poller asks for the reference torque first
+        core.read_inputs().unwrap();
+        let request = core.hal.sent_can_frames()[0];
+        assert_eq!(request.id, OBD_REQUEST_ID);
+        assert_eq!(request.payload()[2], OBD_PID_REFERENCE_TORQUE);
+        
+        core.hal.receive_can_frame(CanFrame::new(0x7E8, &[0x04, 0x41, OBD_PID_REFERENCE_TORQUE, 0x02, 0x58, 0, 0, 0]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(0x7E8, &[0x04, 0x41, OBD_PID_RPM, 0x3E, 0x80, 0, 0, 0]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(0x7E8, &[0x03, 0x41, OBD_PID_DEMAND_TORQUE, 200, 0, 0, 0, 0]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(0x7E8, &[0x03, 0x41, OBD_PID_ACTUAL_TORQUE, 175, 0, 0, 0, 0]).unwrap());
+        let inputs = core.read_inputs().unwrap();
+        assert_eq!(inputs.rpm, 4000);
+        assert_eq!(inputs.desired_torque, 450.0);
+        assert_eq!(inputs.actual_torque, 300.0);
+    }
+    
+    #[test]
+    fn test_platform_without_torque_needs_map_only_control() {
+        let mut config = SystemConfig::default();
+        config.can_signals.vehicle = VehicleProtocol::Mopar;
+        assert!(config.validate().is_err());
+        config.control_mode = ControlMode::BoostByTarget;
+        config.validate().unwrap();
+        
+        // Or torque polled over OBD-II
+        config.control_mode = ControlMode::TorqueFollowing;
+        config.can_signals.obd_fallback = true;
+        config.validate().unwrap();
+    }
+    
+    #[test]
+    fn test_missing_torque_runs_boost_target_only() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        
+        core.read_inputs().unwrap();
+        assert!(core.torque_degraded);
+        assert_eq!(core.control_mode_in_effect(), ControlMode::BoostByTarget);
+        assert_eq!(core.get_system_status().control_mode, ControlMode::BoostByTarget);
+        
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_TORQUE_ID, &[0, 134, 0x40]).unwrap());
+        core.hal.receive_can_frame(CanFrame::new(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]).unwrap());
+        core.read_inputs().unwrap();
+        assert_eq!(core.control_mode_in_effect(), ControlMode::TorqueFollowing);
+        core.events.dispatch();
+        assert_eq!(core.events.recent().last().unwrap().event, CoreEvent::TorqueData { available: true });
+    }
+    
+    #[test]
+    fn test_mapped_throttle_reaches_inputs_until_stale() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.read_inputs().unwrap().throttle_position, None);
+        
+        let mut config = SystemConfig::default();
+        config.can_signals.throttle_position = Some(CanSignal {
+            message_id: 0x3B0,
+            start_bit: 0,
+            length: 8,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 0.5,
+            offset: 0.0,
+        });
+        config.validate().unwrap();
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize().unwrap();
+        
+        core.hal.receive_can_frame(CanFrame::new(0x3B0, &[180, 0, 0, 0, 0, 0, 0, 0]).unwrap());
+        assert_eq!(core.read_inputs().unwrap().throttle_position, Some(90.0));
+        core.hal.set_now_ms(1_000 + MAX_LOSS_THRESHOLD_MS + 10);
+        assert_eq!(core.read_inputs().unwrap().throttle_position, None);
+    }
+    
+    #[test]
+    fn test_can_temperatures_reach_thermal_derate() {
+        let temperature = |start_bit| Some(CanSignal {
+            message_id: 0x3A0,
+            start_bit,
+            length: 8,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 1.0,
+            offset: -40.0,
+        });
+        let mut config = SystemConfig::default();
+        config.can_signals.intake_air_temp = temperature(0);
+        config.can_signals.coolant_temp = temperature(8);
+        config.validate().unwrap();
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize().unwrap();
+        
+        core.hal.receive_can_frame(CanFrame::new(0x3A0, &[105, 160, 0, 0, 0, 0, 0, 0]).unwrap());
+        let mut inputs = core.read_inputs().unwrap();
+        assert_eq!(inputs.intake_air_temp_c, Some(65.0));
+        assert_eq!(inputs.coolant_temp_c, Some(120.0));
+        // Held between the slow broadcasts
+        assert_eq!(core.read_inputs().unwrap().coolant_temp_c, Some(120.0));
+        
+        // Sustained heat pulls the boost ceiling down
+        for timestamp_ms in [1_000, 6_000] {
+            inputs.timestamp_ms = timestamp_ms;
+            core.execute_control_cycle_with_inputs(inputs.clone()).unwrap();
+        }
+        assert_eq!(core.thermal_derate.multiplier(), 0.3);
+    }
+    
+    #[test]
+    fn test_slow_signal_does_not_read_as_can_loss() {
+        let mut config = SystemConfig::default();
+        config.can_signals.coolant_temp = Some(CanSignal {
+            message_id: 0x3A0,
+            start_bit: 0,
+            length: 8,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 1.0,
+            offset: -40.0,
+        });
+        config.validate().unwrap();
+        let mut core = armed_core(config);
+        
+        // Torque and RPM every 10 ms, coolant temperature once a second
+        for timestamp_ms in (1_000..4_000).step_by(10) {
+            core.hal.set_now_ms(timestamp_ms);
+            core.hal.receive_can_frame(CanFrame::new(COYOTE_RPM_ID, &[0x3E, 0x80, 0, 0, 0, 0, 0, 0]).unwrap());
+            core.hal.receive_can_frame(CanFrame::new(COYOTE_TORQUE_ID, &[0, 134, 0x40, 0, 0, 0, 0, 0]).unwrap());
+            core.hal.receive_can_frame(CanFrame::new(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]).unwrap());
+            if timestamp_ms.is_multiple_of(1_000) {
+                core.hal.receive_can_frame(CanFrame::new(0x3A0, &[130, 0, 0, 0, 0, 0, 0, 0]).unwrap());
+            }
+            core.execute_control_cycle().unwrap();
+        }
+        assert_eq!(core.state, SystemState::Armed);
+        assert!(core.can_health.message(0x3A0).is_none());
+        assert_eq!(core.read_inputs().unwrap().coolant_temp_c, Some(90.0));
+    }
+}
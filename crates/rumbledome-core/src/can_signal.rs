@@ -0,0 +1,268 @@
+//! CAN Signal Map
+//! 
+//! 🔗 T4-CORE-090: CAN Signal Map
+//! Derived From: T2-HAL-005 (Ford S550 CAN Signal Integration) + T4-CORE-037 (CAN vs Analog Source Arbitration)
+//! AI Traceability: Platform CAN layouts are configuration, not code - one firmware for every ECU that broadcasts torque
+//! 
+//! Each input read from the bus is described by where it sits in which
+//! message and how raw bits scale to engineering units, the same way a DBC
+//! file describes it. Bit numbering follows DBC conventions: Intel signals
+//! give the least significant bit, Motorola signals the most significant bit
+//! in the byte-wise "sawtooth" numbering.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{CoyoteGeneration, ObdPollRates, VehicleProtocol, OBD_POLL_INTERVAL_MS, OBD_SLOW_POLL_INTERVAL_MS};
+use crate::CoreError;
+
+/// Classic CAN payload size (bytes)
+pub const CAN_PAYLOAD_BYTES: usize = 8;
+
+/// Highest extended (29-bit) CAN identifier
+pub const MAX_CAN_ID: u32 = 0x1FFF_FFFF;
+
+/// Bit order of a signal within its message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ByteOrder {
+    /// Intel - least significant byte first (DBC `@1`)
+    LittleEndian,
+    /// Motorola - most significant byte first (DBC `@0`)
+    BigEndian,
+}
+
+/// Location and scaling of one signal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CanSignal {
+    /// Identifier of the message carrying the signal
+    pub message_id: u32,
+    /// Start bit (DBC numbering)
+    pub start_bit: u8,
+    /// Length (bits)
+    pub length: u8,
+    /// Bit order
+    pub byte_order: ByteOrder,
+    /// Raw value is two's complement
+    pub signed: bool,
+    /// Engineering value = raw * scale + offset
+    pub scale: f32,
+    /// Engineering value = raw * scale + offset
+    pub offset: f32,
+}
+
+impl CanSignal {
+    /// Payload bit positions (byte * 8 + bit), most significant first
+    fn bit_positions(&self) -> impl Iterator<Item = usize> + '_ {
+        let start = self.start_bit as usize;
+        let length = self.length as usize;
+        let mut position = start;
+        (0..length).map(move |index| match self.byte_order {
+            ByteOrder::LittleEndian => start + length - 1 - index,
+            ByteOrder::BigEndian => {
+                let current = position;
+                // Next less significant bit: down within the byte, then to bit 7 of the next byte
+                position = if position.is_multiple_of(8) { position + 15 } else { position - 1 };
+                current
+            },
+        })
+    }
+    
+    /// Whether the signal fits in a classic CAN payload
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.message_id > MAX_CAN_ID {
+            return Err(CoreError::ConfigurationError(
+                format!("CAN message ID 0x{:X} is not a valid 29-bit identifier", self.message_id)
+            ));
+        }
+        if !(1..=32).contains(&self.length) {
+            return Err(CoreError::ConfigurationError(
+                format!("CAN signal length must be 1-32 bits, got {}", self.length)
+            ));
+        }
+        if self.scale == 0.0 || !self.scale.is_finite() || !self.offset.is_finite() {
+            return Err(CoreError::ConfigurationError(
+                format!("CAN signal scale {} / offset {} is not usable", self.scale, self.offset)
+            ));
+        }
+        if self.bit_positions().any(|position| position >= CAN_PAYLOAD_BYTES * 8) {
+            return Err(CoreError::ConfigurationError(
+                format!("CAN signal at bit {} ({} bits) does not fit in an 8-byte payload", self.start_bit, self.length)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Decode the engineering value from a payload, or `None` if the payload is too short
+    pub fn decode(&self, payload: &[u8]) -> Option<f32> {
+        if !(1..=32).contains(&self.length) {
+            return None;
+        }
+        let mut raw: u32 = 0;
+        for position in self.bit_positions() {
+            let byte = *payload.get(position / 8)?;
+            raw = (raw << 1) | ((byte >> (position % 8)) & 1) as u32;
+        }
+        
+        let value = if self.signed && self.length < 32 && raw & (1 << (self.length - 1)) != 0 {
+            raw as i64 - (1i64 << self.length)
+        } else if self.signed {
+            raw as i32 as i64
+        } else {
+            raw as i64
+        };
+        Some(value as f32 * self.scale + self.offset)
+    }
+}
+
+/// CAN signals feeding the control inputs
+/// 
+/// Inputs left unmapped use the vehicle protocol's decoder built into the HAL.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CanSignalMap {
+    /// Engine speed (RPM)
+    #[serde(default)]
+    pub rpm: Option<CanSignal>,
+    /// ECU desired torque (Nm)
+    #[serde(default)]
+    pub desired_torque: Option<CanSignal>,
+    /// ECU actual torque (Nm)
+    #[serde(default)]
+    pub actual_torque: Option<CanSignal>,
+    /// Manifold pressure (PSI gauge)
+    #[serde(default)]
+    pub manifold_pressure: Option<CanSignal>,
+    /// Throttle position (0-100 %) for the wide-open-throttle features
+    #[serde(default)]
+    pub throttle_position: Option<CanSignal>,
+    /// Vehicle drive mode (mapped to aggression by `aggression_inputs.drive_modes`)
+    #[serde(default)]
+    pub drive_mode: Option<CanSignal>,
+    /// Barometric pressure (kPa absolute) for the turbo speed estimate
+    #[serde(default)]
+    pub barometric_pressure: Option<CanSignal>,
+    /// Vehicle speed (km/h) for the learning load check
+    #[serde(default)]
+    pub vehicle_speed: Option<CanSignal>,
+    /// Intake air temperature (°C) for the thermal derate and density compensation
+    #[serde(default)]
+    pub intake_air_temp: Option<CanSignal>,
+    /// Coolant temperature (°C) for the thermal derate
+    #[serde(default)]
+    pub coolant_temp: Option<CanSignal>,
+    /// Coyote generation broadcasting the unmapped inputs (scales engine load to torque)
+    #[serde(default)]
+    pub coyote_generation: CoyoteGeneration,
+    /// Platform decoding the unmapped inputs
+    #[serde(default)]
+    pub vehicle: VehicleProtocol,
+    /// Poll OBD-II for engine data while the broadcast carries no torque
+    #[serde(default)]
+    pub obd_fallback: bool,
+    /// OBD-II poll rates (OBD-II platform or fallback polling)
+    #[serde(default)]
+    pub obd_polling: ObdPollRates,
+}
+
+impl CanSignalMap {
+    /// Mapped signals with their input names
+    pub fn signals(&self) -> impl Iterator<Item = (&'static str, &CanSignal)> {
+        [
+            ("rpm", &self.rpm),
+            ("desired_torque", &self.desired_torque),
+            ("actual_torque", &self.actual_torque),
+            ("manifold_pressure", &self.manifold_pressure),
+            ("throttle_position", &self.throttle_position),
+            ("drive_mode", &self.drive_mode),
+            ("barometric_pressure", &self.barometric_pressure),
+            ("vehicle_speed", &self.vehicle_speed),
+            ("intake_air_temp", &self.intake_air_temp),
+            ("coolant_temp", &self.coolant_temp),
+        ]
+        .into_iter()
+        .filter_map(|(name, signal)| signal.as_ref().map(|signal| (name, signal)))
+    }
+    
+    /// Whether ECU desired and actual torque both reach the inputs (mapped, from the platform or polled)
+    pub fn provides_torque(&self) -> bool {
+        let polled = self.obd_polling.torque_interval_ms > 0;
+        match self.vehicle {
+            VehicleProtocol::Obd2 => polled,
+            vehicle => vehicle.provides_torque() || (self.desired_torque.is_some() && self.actual_torque.is_some())
+                || (self.obd_fallback && polled),
+        }
+    }
+    
+    /// Validate every mapped signal and the OBD-II poll rates
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for (name, signal) in self.signals() {
+            signal.validate().map_err(|e| match e {
+                CoreError::ConfigurationError(message) => CoreError::ConfigurationError(format!("{}: {}", name, message)),
+                other => other,
+            })?;
+        }
+        
+        let rates = &self.obd_polling;
+        for (name, interval_ms) in [
+            ("rpm_interval_ms", rates.rpm_interval_ms),
+            ("map_interval_ms", rates.map_interval_ms),
+            ("load_interval_ms", rates.load_interval_ms),
+            ("torque_interval_ms", rates.torque_interval_ms),
+        ] {
+            if interval_ms != 0 && !(OBD_POLL_INTERVAL_MS..=OBD_SLOW_POLL_INTERVAL_MS).contains(&interval_ms) {
+                return Err(CoreError::ConfigurationError(format!(
+                    "obd_polling.{} must be 0 (off) or {}-{} ms, got {}",
+                    name, OBD_POLL_INTERVAL_MS, OBD_SLOW_POLL_INTERVAL_MS, interval_ms
+                )));
+            }
+        }
+        if self.vehicle == VehicleProtocol::Obd2 && self.rpm.is_none() && rates.rpm_interval_ms == 0 {
+            return Err(CoreError::ConfigurationError(
+                "obd_polling.rpm_interval_ms cannot be 0 - engine speed has no other source".into()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn signal(start_bit: u8, length: u8, byte_order: ByteOrder, signed: bool) -> CanSignal {
+        CanSignal { message_id: 0x204, start_bit, length, byte_order, signed, scale: 1.0, offset: 0.0 }
+    }
+    
+    #[test]
+    fn test_decode_both_byte_orders() {
+        let payload = [0x12, 0x34, 0x56, 0x78, 0, 0, 0, 0];
+        
+        // Intel 16 bits from bit 8: bytes 1..2 little-endian
+        assert_eq!(signal(8, 16, ByteOrder::LittleEndian, false).decode(&payload), Some(0x5634 as f32));
+        // Motorola 16 bits with MSB at bit 15: bytes 1..2 big-endian
+        assert_eq!(signal(15, 16, ByteOrder::BigEndian, false).decode(&payload), Some(0x3456 as f32));
+        // Motorola 12 bits with MSB at bit 3: low nibble of byte 0, then byte 1
+        assert_eq!(signal(3, 12, ByteOrder::BigEndian, false).decode(&payload), Some(0x234 as f32));
+        
+        // RPM-style scaling and a signed torque value
+        let mut rpm = signal(15, 16, ByteOrder::BigEndian, false);
+        rpm.scale = 0.25;
+        assert_eq!(rpm.decode(&[0x00, 0x3E, 0x80, 0, 0, 0, 0, 0]), Some(4000.0));
+        assert_eq!(signal(0, 8, ByteOrder::LittleEndian, true).decode(&[0xF6]), Some(-10.0));
+        
+        assert_eq!(signal(56, 8, ByteOrder::LittleEndian, false).decode(&payload[..4]), None);
+    }
+    
+    #[test]
+    fn test_signals_must_fit_payload() {
+        assert!(signal(56, 8, ByteOrder::LittleEndian, false).validate().is_ok());
+        assert!(signal(60, 8, ByteOrder::LittleEndian, false).validate().is_err());
+        assert!(signal(7, 64, ByteOrder::BigEndian, false).validate().is_err());
+        // Motorola MSB in the last byte runs past the end of the payload
+        assert!(signal(59, 8, ByteOrder::BigEndian, false).validate().is_err());
+        
+        let map = CanSignalMap { rpm: Some(signal(60, 8, ByteOrder::LittleEndian, false)), ..Default::default() };
+        assert!(matches!(map.validate(), Err(CoreError::ConfigurationError(message)) if message.starts_with("rpm")));
+    }
+}
@@ -0,0 +1,262 @@
+//! Output Command Integrity
+//! 
+//! 🔗 T4-CORE-101: Command Token Heartbeat
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-097 (Phased Cycle Execution)
+//! AI Traceability: A corrupted duty or a skipped evaluation must never reach the solenoid - the output stage only applies what the control hierarchy sealed
+//! 
+//! `evaluate()` seals every plan with a token: the next sequence number and a
+//! CRC over the output command and that number. `apply_outputs()` checks the
+//! token before touching any output. A CRC mismatch means the command changed
+//! after it was decided (memory corruption, a stray write); a sequence gap
+//! means a plan was skipped or applied twice. Either one forces the wastegate
+//! open at once and faults the system.
+
+use serde::{Deserialize, Serialize};
+use crate::DriveCommand;
+
+/// Seal on one cycle's output command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandToken {
+    /// Cycle sequence number (wraps)
+    pub sequence: u32,
+    /// CRC-16/CCITT over the output command and sequence number
+    pub crc: u16,
+}
+
+impl CommandToken {
+    /// Seal an output command
+    pub fn seal(drive: &DriveCommand, pwm_enable: Option<bool>, sequence: u32) -> Self {
+        Self { sequence, crc: command_crc(drive, pwm_enable, sequence) }
+    }
+    
+    /// Whether the command is the one this token sealed
+    pub fn matches(&self, drive: &DriveCommand, pwm_enable: Option<bool>) -> bool {
+        self.crc == command_crc(drive, pwm_enable, self.sequence)
+    }
+}
+
+/// Why the output stage refused a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenRejection {
+    /// Command does not match its CRC
+    CrcMismatch,
+    /// Plan out of order - one was skipped or repeated
+    SequenceGap { expected: u32, received: u32 },
+}
+
+impl TokenRejection {
+    /// Short description for fault records
+    pub fn description(&self) -> &'static str {
+        match self {
+            TokenRejection::CrcMismatch => "command CRC mismatch",
+            TokenRejection::SequenceGap { .. } => "command sequence gap",
+        }
+    }
+}
+
+/// Control hierarchy side - numbers and seals each plan
+#[derive(Debug, Clone, Default)]
+pub struct CommandSealer {
+    next_sequence: u32,
+}
+
+impl CommandSealer {
+    /// Start from sequence zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Seal the next command
+    pub fn seal(&mut self, drive: &DriveCommand, pwm_enable: Option<bool>) -> CommandToken {
+        let token = CommandToken::seal(drive, pwm_enable, self.next_sequence);
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        token
+    }
+}
+
+/// Output stage side - accepts each sealed command once, in order
+#[derive(Debug, Clone, Default)]
+pub struct OutputGuard {
+    expected_sequence: u32,
+    rejected: u32,
+}
+
+impl OutputGuard {
+    /// Expect sequence zero first
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Check a command before it is applied
+    /// 
+    /// After a rejection the guard follows the received sequence, so one
+    /// bad plan is reported once rather than failing every plan after it.
+    pub fn check(&mut self, token: &CommandToken, drive: &DriveCommand, pwm_enable: Option<bool>) -> Result<(), TokenRejection> {
+        let expected = self.expected_sequence;
+        self.expected_sequence = token.sequence.wrapping_add(1);
+        
+        let result = if !token.matches(drive, pwm_enable) {
+            Err(TokenRejection::CrcMismatch)
+        } else if token.sequence != expected {
+            Err(TokenRejection::SequenceGap { expected, received: token.sequence })
+        } else {
+            Ok(())
+        };
+        
+        if result.is_err() {
+            self.rejected += 1;
+        }
+        result
+    }
+    
+    /// Commands refused since power-up
+    pub fn rejected(&self) -> u32 {
+        self.rejected
+    }
+}
+
+/// CRC-16/CCITT-FALSE over the command, PWM switch and sequence number
+fn command_crc(drive: &DriveCommand, pwm_enable: Option<bool>, sequence: u32) -> u16 {
+    let mut crc = Crc16::new();
+    match drive {
+        DriveCommand::Hold => crc.update(&[0]),
+        DriveCommand::Failsafe { immediate } => crc.update(&[1, *immediate as u8]),
+        DriveCommand::Authority(authority) => {
+            crc.update(&[2]);
+            crc.update(&authority.to_bits().to_le_bytes());
+        },
+        DriveCommand::BankAuthority(authorities) => {
+            crc.update(&[3]);
+            for authority in authorities {
+                crc.update(&authority.to_bits().to_le_bytes());
+            }
+        },
+    }
+    crc.update(&[match pwm_enable {
+        None => 0,
+        Some(false) => 1,
+        Some(true) => 2,
+    }]);
+    crc.update(&sequence.to_le_bytes());
+    crc.value()
+}
+
+/// Bitwise CRC-16/CCITT-FALSE (polynomial 0x1021, initial 0xFFFF)
+pub struct Crc16(u16);
+
+impl Default for Crc16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Crc16 {
+    /// Start a checksum
+    pub fn new() -> Self {
+        Self(0xFFFF)
+    }
+    
+    /// Add bytes to the checksum
+    pub fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                self.0 = if self.0 & 0x8000 != 0 { (self.0 << 1) ^ 0x1021 } else { self.0 << 1 };
+            }
+        }
+    }
+    
+    /// Checksum of the bytes added so far
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_crc_matches_reference() {
+        let mut crc = Crc16::new();
+        crc.update(b"123456789");
+        assert_eq!(crc.value(), 0x29B1);
+    }
+    
+    #[test]
+    fn test_guard_accepts_sealed_commands_in_order() {
+        let mut sealer = CommandSealer::new();
+        let mut guard = OutputGuard::new();
+        let drive = DriveCommand::Authority(42.0);
+        
+        for _ in 0..3 {
+            let token = sealer.seal(&drive, None);
+            assert_eq!(guard.check(&token, &drive, None), Ok(()));
+        }
+        assert_eq!(guard.rejected(), 0);
+    }
+    
+    #[test]
+    fn test_guard_rejects_altered_and_skipped_commands() {
+        let mut sealer = CommandSealer::new();
+        let mut guard = OutputGuard::new();
+        
+        // Duty changed after sealing
+        let token = sealer.seal(&DriveCommand::Authority(42.0), None);
+        assert_eq!(guard.check(&token, &DriveCommand::Authority(82.0), None), Err(TokenRejection::CrcMismatch));
+        
+        // PWM switch changed after sealing
+        let token = sealer.seal(&DriveCommand::Hold, Some(false));
+        assert_eq!(guard.check(&token, &DriveCommand::Hold, Some(true)), Err(TokenRejection::CrcMismatch));
+        
+        // A plan never applied
+        let _skipped = sealer.seal(&DriveCommand::Hold, None);
+        let drive = DriveCommand::Failsafe { immediate: false };
+        let token = sealer.seal(&drive, None);
+        assert_eq!(guard.check(&token, &drive, None), Err(TokenRejection::SequenceGap { expected: 2, received: 3 }));
+        
+        // Reported once - the next plan in order is accepted
+        let token = sealer.seal(&drive, None);
+        assert_eq!(guard.check(&token, &drive, None), Ok(()));
+        assert_eq!(guard.rejected(), 3);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{armed_core, inputs, TORQUE_MESSAGE_ID};
+    use crate::{FaultCode, RumbleDomeCore, SystemConfig, SystemState};
+    use rumbledome_hal::MockHal;
+    
+    fn assert_control_fault(core: &RumbleDomeCore<MockHal>) {
+        assert!(matches!(core.state, SystemState::Fault(FaultCode::ControlFault(_))), "state {:?}", core.state);
+        assert_eq!(core.cycle_outputs().duty_percent, 0.0);
+        assert_eq!(core.fault_history.records().len(), 1);
+    }
+    
+    #[test]
+    fn test_altered_command_fails_safe() {
+        let mut core = armed_core(SystemConfig::default());
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(inputs(0)).unwrap();
+        
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 10);
+        let mut plan = core.evaluate(inputs(10)).unwrap();
+        plan.drive = DriveCommand::Authority(100.0);
+        core.apply_outputs(plan).unwrap();
+        assert_control_fault(&core);
+    }
+    
+    #[test]
+    fn test_skipped_plan_fails_safe() {
+        let mut core = armed_core(SystemConfig::default());
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        let _skipped = core.evaluate(inputs(0)).unwrap();
+        
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 10);
+        let plan = core.evaluate(inputs(10)).unwrap();
+        core.apply_outputs(plan).unwrap();
+        assert_control_fault(&core);
+    }
+}
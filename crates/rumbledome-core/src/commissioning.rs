@@ -0,0 +1,374 @@
+//! First-Run Commissioning
+//! 
+//! 🔗 T4-CORE-073: Commissioning Sequence
+//! Derived From: T2-CONTROL-007 (Progressive Safety Auto-Calibration) + T1-SAFETY-002 (Defense in Depth)
+//! AI Traceability: A fresh install is checked step by step before it may ever control boost
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, DisplayString, Language, SystemInputs};
+
+/// Throttle treated as wide open while detecting spring pressure (%)
+const WIDE_OPEN_THROTTLE_PERCENT: f32 = 90.0;
+
+/// Engine-running driving time required by the observe-only drive (ms)
+pub const OBSERVE_DRIVE_MIN_MS: u32 = 10 * 60_000;
+
+/// Longest gap between samples still counted as continuous driving (ms)
+const MAX_OBSERVE_GAP_MS: u32 = 1_000;
+
+/// Commissioning steps, in the order they are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommissioningStep {
+    /// Key on, engine off - capture the MAP sensor zero at atmosphere
+    SensorZero,
+    /// Wastegate held open - wide-open pull reveals the spring pressure
+    SpringPressure,
+    /// Engine off - sweep solenoid PWM frequency for the quietest stable response
+    FrequencySweep,
+    /// Engine off - pressurize the dome lines and check they hold
+    LeakTest,
+    /// Normal driving with boost control disabled - verify CAN and sensor inputs
+    ObserveDrive,
+    /// First auto-calibration run
+    FirstCalibration,
+}
+
+impl CommissioningStep {
+    /// All steps in sequence order
+    pub const ALL: [CommissioningStep; 6] = [
+        CommissioningStep::SensorZero,
+        CommissioningStep::SpringPressure,
+        CommissioningStep::FrequencySweep,
+        CommissioningStep::LeakTest,
+        CommissioningStep::ObserveDrive,
+        CommissioningStep::FirstCalibration,
+    ];
+    
+    /// Position in the sequence (1-based)
+    pub fn number(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0) + 1
+    }
+    
+    /// Short step name
+    pub fn display_text(&self) -> &'static str {
+        self.localized_text(Language::English)
+    }
+    
+    /// Short step name in the given language
+    pub fn localized_text(&self, language: Language) -> &'static str {
+        match self {
+            CommissioningStep::SensorZero => DisplayString::StepSensorZero.text(language),
+            CommissioningStep::SpringPressure => DisplayString::StepSpringPressure.text(language),
+            CommissioningStep::FrequencySweep => DisplayString::StepFrequencySweep.text(language),
+            CommissioningStep::LeakTest => DisplayString::StepLeakTest.text(language),
+            CommissioningStep::ObserveDrive => DisplayString::StepObserveDrive.text(language),
+            CommissioningStep::FirstCalibration => DisplayString::StepFirstCalibration.text(language),
+        }
+    }
+    
+    /// What the installer has to do during this step
+    pub fn instructions(&self) -> &'static str {
+        match self {
+            CommissioningStep::SensorZero =>
+                "Key on, engine off. Leave the car still until the MAP zero is captured.",
+            CommissioningStep::SpringPressure =>
+                "Wastegate is held open. Make one wide-open-throttle pull in a high gear; peak boost is the spring pressure.",
+            CommissioningStep::FrequencySweep =>
+                "Engine off. Run the solenoid frequency sweep and pick the quietest frequency with stable dome pressure.",
+            CommissioningStep::LeakTest =>
+                "Engine off. Pressurize the dome lines and confirm pressure holds for 60 seconds.",
+            CommissioningStep::ObserveDrive =>
+                "Drive normally for 10 minutes. Boost control stays off while inputs are recorded.",
+            CommissioningStep::FirstCalibration =>
+                "Run the first auto-calibration session.",
+        }
+    }
+}
+
+/// How a step was finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StepOutcome {
+    /// Step performed and confirmed
+    Completed,
+    /// Installer chose to skip the step
+    Skipped,
+}
+
+impl StepOutcome {
+    /// Outcome in the given language
+    pub fn localized_text(&self, language: Language) -> &'static str {
+        match self {
+            StepOutcome::Completed => DisplayString::OutcomeCompleted.text(language),
+            StepOutcome::Skipped => DisplayString::OutcomeSkipped.text(language),
+        }
+    }
+}
+
+/// Finished step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub step: CommissioningStep,
+    pub outcome: StepOutcome,
+}
+
+/// Persisted commissioning progress
+/// 
+/// Steps are finished in sequence order, so the record is a prefix of
+/// `CommissioningStep::ALL`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommissioningRecord {
+    pub steps: Vec<StepRecord>,
+}
+
+/// Commissioning status (diagnostics and CLI wizard)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommissioningStatus {
+    /// Every step completed or skipped - arming permitted
+    pub complete: bool,
+    /// Next step to run
+    pub current_step: Option<CommissioningStep>,
+    /// Finished steps
+    pub record: CommissioningRecord,
+    /// Peak boost seen at wide-open throttle with the wastegate open (PSI)
+    pub detected_spring_psi: Option<f32>,
+    /// Engine-running time observed during the observe-only drive (s)
+    pub observed_drive_s: u32,
+}
+
+/// Commissioning progress tracker
+/// 
+/// 🔗 T4-CORE-074: Commissioning Progress
+/// Derived From: T4-CORE-073
+/// 
+/// Steps run one at a time in sequence. Evidence the core can gather itself
+/// (spring pressure, observed driving time) is collected while the step is
+/// active; everything else is confirmed by the installer.
+#[derive(Debug, Clone, Default)]
+pub struct Commissioning {
+    record: CommissioningRecord,
+    detected_spring_psi: Option<f32>,
+    observed_drive_ms: u32,
+    last_observed_ms: Option<u32>,
+}
+
+impl Commissioning {
+    /// Create tracker for an uncommissioned installation
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Restore persisted progress (at startup)
+    pub fn restore(&mut self, record: CommissioningRecord) {
+        self.record = record;
+    }
+    
+    /// Progress to persist
+    pub fn record(&self) -> &CommissioningRecord {
+        &self.record
+    }
+    
+    /// Next step to run, if any
+    pub fn current_step(&self) -> Option<CommissioningStep> {
+        CommissioningStep::ALL.get(self.record.steps.len()).copied()
+    }
+    
+    /// Every step completed or skipped
+    pub fn is_complete(&self) -> bool {
+        self.current_step().is_none()
+    }
+    
+    /// Peak boost seen at wide-open throttle during spring pressure detection (PSI)
+    pub fn detected_spring_psi(&self) -> Option<f32> {
+        self.detected_spring_psi
+    }
+    
+    /// Collect evidence for the active step (every control cycle while commissioning)
+    pub fn observe(&mut self, inputs: &SystemInputs) {
+        match self.current_step() {
+            // Without a throttle signal, the torque request shows the wide-open pull
+            Some(CommissioningStep::SpringPressure) if inputs.wide_open(WIDE_OPEN_THROTTLE_PERCENT) => {
+                let peak = self.detected_spring_psi.unwrap_or(0.0).max(inputs.manifold_pressure);
+                self.detected_spring_psi = Some(peak);
+            },
+            Some(CommissioningStep::ObserveDrive) => {
+                if inputs.rpm > 0 {
+                    if let Some(last_ms) = self.last_observed_ms {
+                        let gap_ms = inputs.timestamp_ms.wrapping_sub(last_ms);
+                        if gap_ms <= MAX_OBSERVE_GAP_MS {
+                            self.observed_drive_ms = self.observed_drive_ms.saturating_add(gap_ms);
+                        }
+                    }
+                    self.last_observed_ms = Some(inputs.timestamp_ms);
+                } else {
+                    self.last_observed_ms = None;
+                }
+            },
+            _ => {},
+        }
+    }
+    
+    /// Record the active step as done
+    /// 
+    /// Fails if `step` is not the active step or the step's evidence is missing.
+    pub fn complete_step(&mut self, step: CommissioningStep) -> Result<(), CoreError> {
+        self.ensure_current(step)?;
+        
+        match step {
+            CommissioningStep::SpringPressure if self.detected_spring_psi.is_none() => {
+                return Err(CoreError::CalibrationError(
+                    "No wide-open-throttle pull observed yet".to_string()
+                ));
+            },
+            CommissioningStep::ObserveDrive if self.observed_drive_ms < OBSERVE_DRIVE_MIN_MS => {
+                return Err(CoreError::CalibrationError(format!(
+                    "Observed {} of {} minutes of driving",
+                    self.observed_drive_ms / 60_000, OBSERVE_DRIVE_MIN_MS / 60_000
+                )));
+            },
+            _ => {},
+        }
+        
+        self.finish(step, StepOutcome::Completed);
+        Ok(())
+    }
+    
+    /// Record the active step as skipped
+    pub fn skip_step(&mut self, step: CommissioningStep) -> Result<(), CoreError> {
+        self.ensure_current(step)?;
+        self.finish(step, StepOutcome::Skipped);
+        Ok(())
+    }
+    
+    /// Skip every remaining step (installer takes responsibility)
+    pub fn skip_remaining(&mut self) {
+        while let Some(step) = self.current_step() {
+            self.finish(step, StepOutcome::Skipped);
+        }
+    }
+    
+    /// Forget all progress (reinstall or hardware change)
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+    
+    /// Current status
+    pub fn status(&self) -> CommissioningStatus {
+        CommissioningStatus {
+            complete: self.is_complete(),
+            current_step: self.current_step(),
+            record: self.record.clone(),
+            detected_spring_psi: self.detected_spring_psi,
+            observed_drive_s: self.observed_drive_ms / 1000,
+        }
+    }
+    
+    fn ensure_current(&self, step: CommissioningStep) -> Result<(), CoreError> {
+        match self.current_step() {
+            Some(current) if current == step => Ok(()),
+            Some(current) => Err(CoreError::InvalidState(format!(
+                "Commissioning step {} is next, not {}", current.display_text(), step.display_text()
+            ))),
+            None => Err(CoreError::InvalidState("Commissioning already complete".to_string())),
+        }
+    }
+    
+    fn finish(&mut self, step: CommissioningStep, outcome: StepOutcome) {
+        self.record.steps.push(StepRecord { step, outcome });
+        self.last_observed_ms = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(rpm: u16, throttle_position: f32, manifold_pressure: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { rpm, throttle_position: Some(throttle_position), manifold_pressure, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_steps_run_in_order() {
+        let mut commissioning = Commissioning::new();
+        assert_eq!(commissioning.current_step(), Some(CommissioningStep::SensorZero));
+        assert!(commissioning.complete_step(CommissioningStep::LeakTest).is_err());
+        
+        commissioning.complete_step(CommissioningStep::SensorZero).unwrap();
+        commissioning.skip_step(CommissioningStep::SpringPressure).unwrap();
+        assert_eq!(commissioning.current_step(), Some(CommissioningStep::FrequencySweep));
+        assert_eq!(commissioning.record().steps[1].outcome, StepOutcome::Skipped);
+        
+        // Progress survives a restart
+        let mut restored = Commissioning::new();
+        restored.restore(commissioning.record().clone());
+        assert_eq!(restored.current_step(), Some(CommissioningStep::FrequencySweep));
+        
+        restored.skip_remaining();
+        assert!(restored.is_complete());
+        assert_eq!(restored.record().steps.len(), CommissioningStep::ALL.len());
+        assert!(restored.skip_step(CommissioningStep::FirstCalibration).is_err());
+    }
+    
+    #[test]
+    fn test_spring_pressure_from_wide_open_peak() {
+        let mut commissioning = Commissioning::new();
+        commissioning.complete_step(CommissioningStep::SensorZero).unwrap();
+        assert!(commissioning.complete_step(CommissioningStep::SpringPressure).is_err());
+        
+        // Part-throttle boost does not count
+        commissioning.observe(&inputs(3000, 50.0, 7.0, 0));
+        assert_eq!(commissioning.detected_spring_psi(), None);
+        
+        commissioning.observe(&inputs(4000, 100.0, 5.2, 10));
+        commissioning.observe(&inputs(5000, 100.0, 5.6, 20));
+        commissioning.observe(&inputs(5500, 100.0, 5.4, 30));
+        assert_eq!(commissioning.detected_spring_psi(), Some(5.6));
+        commissioning.complete_step(CommissioningStep::SpringPressure).unwrap();
+    }
+    
+    #[test]
+    fn test_spring_pressure_from_torque_request_without_throttle_signal() {
+        let mut commissioning = Commissioning::new();
+        commissioning.complete_step(CommissioningStep::SensorZero).unwrap();
+        let unmapped = |desired_torque, manifold_pressure, t| SystemInputs {
+            throttle_position: None,
+            desired_torque,
+            actual_torque: 300.0,
+            ..inputs(4500, 0.0, manifold_pressure, t)
+        };
+        
+        // Cruising boost does not count; asking for far more than the engine makes does
+        commissioning.observe(&unmapped(320.0, 7.0, 0));
+        assert_eq!(commissioning.detected_spring_psi(), None);
+        commissioning.observe(&unmapped(450.0, 5.4, 10));
+        assert_eq!(commissioning.detected_spring_psi(), Some(5.4));
+    }
+    
+    #[test]
+    fn test_observe_drive_needs_engine_running_time() {
+        let mut commissioning = Commissioning::new();
+        commissioning.restore(CommissioningRecord {
+            steps: CommissioningStep::ALL[..4]
+                .iter()
+                .map(|&step| StepRecord { step, outcome: StepOutcome::Completed })
+                .collect(),
+        });
+        assert_eq!(commissioning.current_step(), Some(CommissioningStep::ObserveDrive));
+        
+        // 5 minutes running, engine off, 5 more minutes
+        for t in (0..=300_000).step_by(100) {
+            commissioning.observe(&inputs(2000, 20.0, -5.0, t));
+        }
+        commissioning.observe(&inputs(0, 0.0, 0.0, 300_100));
+        assert!(commissioning.complete_step(CommissioningStep::ObserveDrive).is_err());
+        
+        for t in (3_600_000..=3_900_000).step_by(100) {
+            commissioning.observe(&inputs(2000, 20.0, -5.0, t));
+        }
+        assert_eq!(commissioning.status().observed_drive_s, 600);
+        commissioning.complete_step(CommissioningStep::ObserveDrive).unwrap();
+    }
+}
@@ -4,9 +4,19 @@
 //! Derived From: T3-BUILD-004 (5-Parameter Configuration Implementation) + T2-HAL-003
 //! AI Traceability: Single-knob philosophy implementation, parameter validation
 
+use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
-use crate::CoreError;
+use rumbledome_hal::constants::PWM_FREQUENCY_HZ;
+use crate::{
+    CoreError, ActuatorType, SolenoidTopology, TuningParameters, InputSourceConfig, AggressionInputConfig, CanSignalMap, SensorChannelMap, RevLimitTaper, MapZeroConfig,
+    ControlMode, BoostTargetCurve, IdleVentConfig, SolenoidExerciseConfig, PwmDitherConfig, ProfileTheme, LearningGateConfig, ControlRates, PerformanceTierConfig,
+    VoltageCompensationConfig, PulseShapingConfig, ThermalDerateConfig, BackpressureConfig, TurboSpeedConfig, DensityCompensationConfig, GearBoostConfig, DomeControlConfig, ShakedownConfig, TwinTurboConfig, ActuationMonitorConfig, FlightRecorderConfig, SoftCeiling,
+    TelemetrySinkConfig, ConsoleConfig,
+    TorqueFollowingConfig,
+    MaintenanceReminder, default_maintenance_reminders, validate_maintenance_reminders,
+};
 
 /// User configuration structure - exactly 5 parameters
 /// 
@@ -14,6 +24,7 @@ use crate::CoreError;
 /// Derived From: T1-UI-001 (Single Parameter Philosophy) + T2-CONFIG-001 (Pressure-Based Configuration)
 /// AI Traceability: Implements single-knob control philosophy
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SystemConfig {
     /// Aggression level (0.0-1.0) - scales all system behavior
     /// 0.0 = OFF (as close to naturally aspirated as physically possible)
@@ -32,8 +43,177 @@ pub struct SystemConfig {
     /// Overboost protection triggers fault condition if exceeded
     pub overboost_limit: f32,
     
+    /// Highest solenoid duty ever commanded (%) - stored redundantly with the overboost limit
+    #[serde(default = "default_max_duty_percent")]
+    pub max_duty_percent: f32,
+    
     /// Enable scramble button feature (temporary maximum aggression override)
     pub scramble_enabled: bool,
+    
+    /// Level 1 control mode - boost-by-target for ECUs without usable torque data
+    #[serde(default)]
+    pub control_mode: ControlMode,
+    
+    /// RPM boost target curve (boost-by-target mode only)
+    #[serde(default)]
+    pub boost_targets: BoostTargetCurve,
+    
+    /// Wastegate actuator hardware driven by the output stage
+    #[serde(default)]
+    pub actuator_type: ActuatorType,
+    
+    /// Solenoid plumbing topology (hardware description, not a tuning parameter)
+    /// Determines which degraded modes are available when a solenoid channel fails
+    #[serde(default)]
+    pub solenoid_topology: SolenoidTopology,
+    
+    /// Stored control tuning (system-derived defaults, adjusted only via live-tune commit)
+    #[serde(default)]
+    pub tuning: TuningParameters,
+    
+    /// Torque gap to boost conversion for torque-following mode
+    #[serde(default)]
+    pub torque_following: TorqueFollowingConfig,
+    
+    /// Source arbitration for inputs available from both CAN and analog sensors
+    #[serde(default)]
+    pub input_sources: InputSourceConfig,
+    
+    /// Aggression from a knob, the drive mode CAN signal or an app, and how one is chosen
+    #[serde(default)]
+    pub aggression_inputs: AggressionInputConfig,
+    
+    /// Where the CAN inputs sit in the platform's messages (e.g. imported from a DBC file)
+    #[serde(default)]
+    pub can_signals: CanSignalMap,
+    
+    /// Physical input channel of each pressure sensor (e.g. found by the mapping assistant)
+    #[serde(default)]
+    pub sensor_channels: SensorChannelMap,
+    
+    /// Boost taper approaching the ECU rev limiter
+    #[serde(default)]
+    pub rev_limit_taper: RevLimitTaper,
+    
+    /// Boost target taper as boost approaches the overboost limit
+    #[serde(default)]
+    pub soft_ceiling: SoftCeiling,
+    
+    /// Key-on MAP sensor auto-zero and drift advisory
+    #[serde(default)]
+    pub map_zero: MapZeroConfig,
+    
+    /// Dome venting with PWM disabled while idling off-boost
+    #[serde(default)]
+    pub idle_vent: IdleVentConfig,
+    
+    /// Periodic solenoid self-cleaning pulses while vented at idle
+    #[serde(default)]
+    pub solenoid_exercise: SolenoidExerciseConfig,
+    
+    /// Small-amplitude dither on the solenoid duty against valve stiction
+    #[serde(default)]
+    pub pwm_dither: PwmDitherConfig,
+    
+    /// Solenoid duty scaling with supply voltage
+    #[serde(default)]
+    pub voltage_compensation: VoltageCompensationConfig,
+    
+    /// Duty snapping to the shortest pulses the fitted valve acts on
+    #[serde(default)]
+    pub pulse_shaping: PulseShapingConfig,
+    
+    /// Boost ceiling derate on sustained high intake air or coolant temperature
+    #[serde(default)]
+    pub thermal_derate: ThermalDerateConfig,
+    
+    /// Optional exhaust backpressure sensor and the turbine protection limit it feeds
+    #[serde(default)]
+    pub backpressure: BackpressureConfig,
+    
+    /// Turbo shaft speed estimate from a compressor map approximation, and the overspeed boost limit
+    #[serde(default)]
+    pub turbo_speed: TurboSpeedConfig,
+    
+    /// Learned duty and boost target correction for barometric pressure and intake air temperature
+    #[serde(default)]
+    pub density_compensation: DensityCompensationConfig,
+    
+    /// Gear estimate from RPM and vehicle speed, and boost target scaling per gear
+    #[serde(default)]
+    pub gear_boost: GearBoostConfig,
+    
+    /// Cascaded control: boost loop setting a dome pressure setpoint, dome loop holding it with duty
+    #[serde(default)]
+    pub dome_control: DomeControlConfig,
+    
+    /// Conservative period after safety-relevant configuration changes, and startup boost delay
+    #[serde(default)]
+    pub shakedown: ShakedownConfig,
+    
+    /// Twin turbo plumbing, per-bank trims and bank imbalance detection
+    #[serde(default)]
+    pub twin_turbo: TwinTurboConfig,
+    
+    /// Commanded duty vs dome pressure correlation (solenoid wear and dome leak advisories)
+    #[serde(default)]
+    pub actuation_monitor: ActuationMonitorConfig,
+    
+    /// Always-on EEPROM flight recorder for cars without an SD card
+    #[serde(default)]
+    pub flight_recorder: FlightRecorderConfig,
+    
+    /// Inner (boost loop) and outer (Level 1 and learning) control rates (takes effect at the next boot)
+    #[serde(default)]
+    pub control_rates: ControlRates,
+    
+    /// Display, telemetry and learning slowed in steps when cycle time headroom shrinks or the MCU runs hot
+    #[serde(default)]
+    pub performance_tiers: PerformanceTierConfig,
+    
+    /// Interval and channels of each telemetry output (CAN broadcast, datalogger)
+    #[serde(default)]
+    pub telemetry_sinks: TelemetrySinkConfig,
+    
+    /// USB and Bluetooth console access
+    #[serde(default)]
+    pub console: ConsoleConfig,
+    
+    /// Solenoid PWM frequency in Hz (takes effect at the next boot)
+    #[serde(default = "default_pwm_frequency_hz")]
+    pub pwm_frequency_hz: u32,
+    
+    /// CAN bus bitrate in bit/s (takes effect at the next boot)
+    #[serde(default = "default_can_bitrate_bps")]
+    pub can_bitrate_bps: u32,
+    
+    /// Transient thresholds that keep samples out of learning
+    #[serde(default)]
+    pub learning_gate: LearningGateConfig,
+    
+    /// Gauge accent color and label identifying this profile on the display
+    #[serde(default)]
+    pub theme: ProfileTheme,
+    
+    /// Maintenance reminders (e.g. inspect dome lines every 200 hours)
+    #[serde(default = "default_maintenance_reminders")]
+    pub maintenance_reminders: Vec<MaintenanceReminder>,
+}
+
+/// CAN bitrates the transceiver supports (bit/s)
+pub const CAN_BITRATES_BPS: [u32; 4] = [125_000, 250_000, 500_000, 1_000_000];
+
+fn default_max_duty_percent() -> f32 {
+    100.0
+}
+
+fn default_pwm_frequency_hz() -> u32 {
+    PWM_FREQUENCY_HZ
+}
+
+/// Ford Gen2 Coyote high-speed CAN
+fn default_can_bitrate_bps() -> u32 {
+    500_000
 }
 
 impl Default for SystemConfig {
@@ -43,7 +223,45 @@ impl Default for SystemConfig {
             spring_pressure: 5.0,      // Typical wastegate spring pressure
             max_boost_psi: 12.0,       // Conservative boost ceiling
             overboost_limit: 15.0,     // Hard safety limit
+            max_duty_percent: default_max_duty_percent(),
             scramble_enabled: true,    // Enable scramble override
+            control_mode: ControlMode::TorqueFollowing,
+            boost_targets: BoostTargetCurve::default(),
+            actuator_type: ActuatorType::PwmSolenoid,
+            solenoid_topology: SolenoidTopology::SingleFourPort,
+            tuning: TuningParameters::default(),
+            torque_following: TorqueFollowingConfig::default(),
+            input_sources: InputSourceConfig::default(),
+            aggression_inputs: AggressionInputConfig::default(),
+            can_signals: CanSignalMap::default(),
+            sensor_channels: SensorChannelMap::default(),
+            rev_limit_taper: RevLimitTaper::default(),
+            soft_ceiling: SoftCeiling::default(),
+            map_zero: MapZeroConfig::default(),
+            idle_vent: IdleVentConfig::default(),
+            solenoid_exercise: SolenoidExerciseConfig::default(),
+            pwm_dither: PwmDitherConfig::default(),
+            voltage_compensation: VoltageCompensationConfig::default(),
+            pulse_shaping: PulseShapingConfig::default(),
+            thermal_derate: ThermalDerateConfig::default(),
+            backpressure: BackpressureConfig::default(),
+            turbo_speed: TurboSpeedConfig::default(),
+            density_compensation: DensityCompensationConfig::default(),
+            gear_boost: GearBoostConfig::default(),
+            dome_control: DomeControlConfig::default(),
+            shakedown: ShakedownConfig::default(),
+            twin_turbo: TwinTurboConfig::default(),
+            actuation_monitor: ActuationMonitorConfig::default(),
+            flight_recorder: FlightRecorderConfig::default(),
+            control_rates: ControlRates::default(),
+            performance_tiers: PerformanceTierConfig::default(),
+            telemetry_sinks: TelemetrySinkConfig::default(),
+            console: ConsoleConfig::default(),
+            pwm_frequency_hz: default_pwm_frequency_hz(),
+            can_bitrate_bps: default_can_bitrate_bps(),
+            learning_gate: LearningGateConfig::default(),
+            theme: ProfileTheme::default(),
+            maintenance_reminders: default_maintenance_reminders(),
         }
     }
 }
@@ -97,6 +315,126 @@ impl SystemConfig {
             ));
         }
         
+        if !(10.0..=100.0).contains(&self.max_duty_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Max duty must be 10-100%, got {}", self.max_duty_percent)
+            ));
+        }
+        
+        // Curve only matters (and only has to fit under the ceiling) when it is in use
+        if self.control_mode == ControlMode::BoostByTarget {
+            self.boost_targets.validate(self.max_boost_psi)?;
+        }
+        
+        // Stored tuning must respect the same hard bounds as live test values
+        self.tuning.validate()?;
+        
+        self.torque_following.validate()?;
+        
+        self.input_sources.validate()?;
+        self.can_signals.validate()?;
+        if self.control_mode == ControlMode::TorqueFollowing && !self.can_signals.provides_torque() {
+            return Err(CoreError::ConfigurationError(format!(
+                "{} broadcasts no ECU torque - map the torque signals or use boost-by-target (MAP-only) control",
+                self.can_signals.vehicle.display_text()
+            )));
+        }
+        self.sensor_channels.validate()?;
+        
+        self.aggression_inputs.validate()?;
+        if !self.aggression_inputs.drive_modes.is_empty() && self.can_signals.drive_mode.is_none() {
+            return Err(CoreError::ConfigurationError(
+                String::from("Drive mode aggression needs the drive_mode CAN signal mapped")
+            ));
+        }
+        
+        self.rev_limit_taper.validate()?;
+        
+        // A band reaching down to spring pressure would taper every boost target
+        self.soft_ceiling.validate()?;
+        if self.soft_ceiling.enabled && self.overboost_limit - self.soft_ceiling.approach_band_psi <= self.spring_pressure {
+            return Err(CoreError::ConfigurationError(
+                format!("Soft ceiling band ({} PSI) must end above spring pressure ({} PSI)",
+                    self.overboost_limit - self.soft_ceiling.approach_band_psi, self.spring_pressure)
+            ));
+        }
+        
+        self.map_zero.validate()?;
+        
+        self.idle_vent.validate()?;
+        self.solenoid_exercise.validate()?;
+        self.control_rates.validate()?;
+        self.performance_tiers.validate()?;
+        self.pwm_dither.validate()?;
+        
+        // Duty changes once per control cycle - a faster dither would alias
+        if self.pwm_dither.enabled && self.pwm_dither.frequency_hz * 2 > self.control_rates.inner_hz {
+            return Err(CoreError::ConfigurationError(
+                format!("Dither frequency ({} Hz) must be at most half the inner loop rate ({} Hz)",
+                    self.pwm_dither.frequency_hz, self.control_rates.inner_hz)
+            ));
+        }
+        
+        // Range MAC solenoids respond to cleanly
+        if !(20..=50).contains(&self.pwm_frequency_hz) {
+            return Err(CoreError::ConfigurationError(
+                format!("PWM frequency must be 20-50 Hz, got {}", self.pwm_frequency_hz)
+            ));
+        }
+        
+        if !CAN_BITRATES_BPS.contains(&self.can_bitrate_bps) {
+            return Err(CoreError::ConfigurationError(
+                format!("CAN bitrate must be one of {:?} bit/s, got {}", CAN_BITRATES_BPS, self.can_bitrate_bps)
+            ));
+        }
+        
+        self.voltage_compensation.validate()?;
+        self.pulse_shaping.validate(self.pwm_frequency_hz)?;
+        self.thermal_derate.validate()?;
+        self.backpressure.validate()?;
+        if let Some(knob) = self.aggression_inputs.knob.as_ref().filter(|_| self.backpressure.enabled) {
+            if knob.channel == self.backpressure.channel {
+                return Err(CoreError::ConfigurationError(
+                    format!("Aggression knob and backpressure sensor are both on channel {}", knob.channel)
+                ));
+            }
+        }
+        self.turbo_speed.validate()?;
+        self.density_compensation.validate()?;
+        self.gear_boost.validate()?;
+        self.dome_control.validate()?;
+        if self.density_compensation.baro_channel.is_some_and(|channel| self.backpressure.enabled && channel == self.backpressure.channel) {
+            return Err(CoreError::ConfigurationError(
+                format!("Barometric and backpressure sensors are both on channel {}", self.backpressure.channel)
+            ));
+        }
+        self.shakedown.validate()?;
+        
+        // Banks are driven separately only through a valve each
+        self.twin_turbo.validate()?;
+        let twin_valves = self.actuator_type == ActuatorType::PwmSolenoid && self.solenoid_topology == SolenoidTopology::TwinFourPort;
+        if self.twin_turbo.layout.is_twin() && !twin_valves {
+            return Err(CoreError::ConfigurationError(
+                format!("{} layout needs a 4-port solenoid valve per bank (TwinFourPort topology)", self.twin_turbo.layout.display_text())
+            ));
+        }
+        if twin_valves && !self.twin_turbo.layout.is_twin() {
+            return Err(CoreError::ConfigurationError(
+                String::from("TwinFourPort topology needs a parallel or sequential twin turbo layout")
+            ));
+        }
+        
+        self.actuation_monitor.validate()?;
+        
+        self.flight_recorder.validate()?;
+        self.telemetry_sinks.validate()?;
+        
+        self.learning_gate.validate()?;
+        
+        self.theme.validate()?;
+        
+        validate_maintenance_reminders(&self.maintenance_reminders)?;
+        
         Ok(())
     }
     
@@ -106,25 +444,7 @@ impl SystemConfig {
     /// Derived From: T2-CONTROL-001 (Priority Hierarchy) + behavioral scaling requirements
     /// All complex system behavior derived from single aggression parameter
     pub fn get_response_characteristics(&self) -> ResponseProfile {
-        ResponseProfile {
-            // Tip-in sensitivity: how quickly system responds to torque requests
-            tip_in_sensitivity: self.aggression * 2.0,
-            
-            // Tip-out decay: how quickly system backs off when torque demand drops
-            tip_out_decay_rate: self.aggression * 0.5 + 0.2,
-            
-            // Torque following gain: amplification of ECU assistance
-            torque_following_gain: self.aggression * 1.5 + 0.3,
-            
-            // Boost ramp rate: maximum rate of boost pressure increase
-            boost_ramp_rate: self.aggression * 3.0 + 1.0,
-            
-            // Safety margin: how close to limits before backing off
-            safety_margin_factor: 1.0 - (self.aggression * 0.2),
-            
-            // PID aggressiveness: how hard PID controller pushes
-            pid_aggressiveness: self.aggression * 0.8 + 0.2,
-        }
+        ResponseProfile::for_aggression(self.aggression)
     }
     
     /// Get OFF behavior settings (aggression = 0.0)
@@ -211,6 +531,33 @@ pub struct ResponseProfile {
     pub pid_aggressiveness: f32,
 }
 
+impl ResponseProfile {
+    /// Response characteristics at an aggression setting (0.0-1.0)
+    /// 
+    /// Also used with the live knob position, which can differ from the stored setting.
+    pub fn for_aggression(aggression: f32) -> Self {
+        Self {
+            // Tip-in sensitivity: how quickly system responds to torque requests
+            tip_in_sensitivity: aggression * 2.0,
+            
+            // Tip-out decay: how quickly system backs off when torque demand drops
+            tip_out_decay_rate: aggression * 0.5 + 0.2,
+            
+            // Torque following gain: amplification of ECU assistance
+            torque_following_gain: aggression * 1.5 + 0.3,
+            
+            // Boost ramp rate: maximum rate of boost pressure increase
+            boost_ramp_rate: aggression * 3.0 + 1.0,
+            
+            // Safety margin: how close to limits before backing off
+            safety_margin_factor: 1.0 - (aggression * 0.2),
+            
+            // PID aggressiveness: how hard PID controller pushes
+            pid_aggressiveness: aggression * 0.8 + 0.2,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,6 +617,16 @@ mod tests {
         assert!(profile_max.boost_ramp_rate > profile_min.boost_ramp_rate);
     }
     
+    #[test]
+    fn test_boost_targets_validated_only_when_in_use() {
+        let mut config = SystemConfig { max_boost_psi: 8.0, overboost_limit: 11.0, ..Default::default() };
+        assert!(config.validate().is_ok());
+        
+        // Default curve peaks at 10 PSI - above this config's ceiling
+        config.control_mode = ControlMode::BoostByTarget;
+        assert!(config.validate().is_err());
+    }
+    
     #[test]
     fn test_json_serialization() {
         let config = SystemConfig::default();
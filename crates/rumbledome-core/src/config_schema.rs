@@ -0,0 +1,79 @@
+//! Configuration JSON Schema
+//! 
+//! 🔗 T4-CORE-104: Configuration Schema Generation
+//! Derived From: T4-CORE-010 (Configuration Implementation) + T4-CORE-048 (Named Configuration Library)
+//! AI Traceability: Editors complete and check configuration files against the same types the firmware deserializes
+//! 
+//! Built only with the `schema` feature (host tools - never the firmware).
+//! The schemas are derived from the serde types, so a field added to the
+//! configuration shows up in editor completion without a hand-written schema
+//! to keep in step. Range limits enforced by `validate()` are not part of the
+//! schema; the CLI still validates before anything is uploaded.
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use crate::{NamedConfig, SensorChannelMap, SystemConfig};
+
+/// Configuration document a schema describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSchema {
+    /// Complete system configuration (what `SetConfig` uploads)
+    SystemConfig,
+    /// Named configuration profile (name plus system configuration)
+    Profile,
+    /// Pressure sensor channel assignment
+    SensorChannels,
+}
+
+impl ConfigSchema {
+    /// Every schema, in the order they are written out
+    pub const ALL: [ConfigSchema; 3] = [
+        ConfigSchema::SystemConfig,
+        ConfigSchema::Profile,
+        ConfigSchema::SensorChannels,
+    ];
+    
+    /// File name the schema is saved under
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ConfigSchema::SystemConfig => "system-config.schema.json",
+            ConfigSchema::Profile => "profile.schema.json",
+            ConfigSchema::SensorChannels => "sensor-channels.schema.json",
+        }
+    }
+    
+    /// Generate the schema from the Rust types
+    pub fn generate(&self) -> RootSchema {
+        match self {
+            ConfigSchema::SystemConfig => schema_for!(SystemConfig),
+            ConfigSchema::Profile => schema_for!(NamedConfig),
+            ConfigSchema::SensorChannels => schema_for!(SensorChannelMap),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_system_config_schema_lists_every_field() {
+        let schema = ConfigSchema::SystemConfig.generate();
+        let properties = &schema.schema.object.as_ref().unwrap().properties;
+        
+        let value = serde_json::to_value(SystemConfig::default()).unwrap();
+        for field in value.as_object().unwrap().keys() {
+            assert!(properties.contains_key(field), "{} missing from schema", field);
+        }
+        assert!(schema.definitions.contains_key("SoftCeiling"));
+    }
+    
+    #[test]
+    fn test_profile_schema_nests_system_config() {
+        let schema = ConfigSchema::Profile.generate();
+        let properties = &schema.schema.object.as_ref().unwrap().properties;
+        assert!(properties.contains_key("name"));
+        assert!(properties.contains_key("config"));
+        assert!(schema.definitions.contains_key("SystemConfig"));
+    }
+}
@@ -0,0 +1,32 @@
+//! Console Access Settings
+//! 
+//! 🔗 T4-CORE-133: Bluetooth Console Access
+//! Derived From: T2-HAL-012 (Wireless CLI Console Access) + T4-CORE-103 (Presence-Confirmed Dangerous Commands)
+//! AI Traceability: A paired phone is handy for tuning from the passenger seat - and reachable from the next car over
+//! 
+//! USB needs a cable into the controller, so it always has full access.
+//! Bluetooth starts every connection read-only. A connection becomes
+//! authenticated once its client sends back the code shown on the device
+//! display; even then, changes that affect boost control or its limits are
+//! refused over Bluetooth unless the configuration allows them.
+
+use serde::{Deserialize, Serialize};
+
+/// Console port settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsoleConfig {
+    /// Advertise the Bluetooth console
+    pub bluetooth_enabled: bool,
+    /// Let an authenticated Bluetooth connection change configuration, limits, calibration and learned data
+    pub bluetooth_safety_writes: bool,
+}
+
+impl Default for ConsoleConfig {
+    fn default() -> Self {
+        Self {
+            bluetooth_enabled: true,
+            bluetooth_safety_writes: false,
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Multi-Rate Control Scheduling
+//! 
+//! 🔗 T4-CORE-067: Inner and Outer Control Rates
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + Physics.md pneumatic response timing
+//! AI Traceability: Fast boost loop, slow torque/learning loop on decimated data - less noise in torque logic, less CPU
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemInputs};
+
+/// Control loop rates
+/// 
+/// The inner loop (boost target caps, duty conversion, output) runs every
+/// control cycle. Level 1 target selection and learning run at the outer
+/// rate on inputs averaged over the outer period - ECU torque signals are
+/// too noisy to act on sample by sample.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ControlRates {
+    /// Inner loop rate - control cycle rate (Hz)
+    pub inner_hz: u16,
+    /// Outer loop rate - Level 1 and learning (Hz)
+    pub outer_hz: u16,
+}
+
+impl Default for ControlRates {
+    fn default() -> Self {
+        Self {
+            inner_hz: 100,
+            outer_hz: 20,
+        }
+    }
+}
+
+impl ControlRates {
+    /// Validate control rates
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(100..=200).contains(&self.inner_hz) {
+            return Err(CoreError::ConfigurationError(
+                format!("Inner loop rate must be 100-200 Hz, got {}", self.inner_hz)
+            ));
+        }
+        
+        if !(10..=20).contains(&self.outer_hz) {
+            return Err(CoreError::ConfigurationError(
+                format!("Outer loop rate must be 10-20 Hz, got {}", self.outer_hz)
+            ));
+        }
+        
+        if !self.inner_hz.is_multiple_of(self.outer_hz) {
+            return Err(CoreError::ConfigurationError(
+                format!("Inner loop rate ({} Hz) must be a multiple of the outer loop rate ({} Hz)",
+                    self.inner_hz, self.outer_hz)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Control cycle period (microseconds)
+    pub fn inner_period_us(&self) -> u32 {
+        1_000_000 / self.inner_hz as u32
+    }
+    
+    /// Inner cycles per outer cycle
+    pub fn decimation(&self) -> u16 {
+        self.inner_hz / self.outer_hz
+    }
+}
+
+/// Running sums of the inputs averaged for the outer loop
+#[derive(Debug, Clone, Default)]
+struct InputAccumulator {
+    samples: u16,
+    rpm: u32,
+    desired_torque: f32,
+    actual_torque: f32,
+    manifold_pressure: f32,
+    throttle_position: f32,
+    throttle_samples: u16,
+}
+
+impl InputAccumulator {
+    fn add(&mut self, inputs: &SystemInputs) {
+        self.samples += 1;
+        self.rpm += inputs.rpm as u32;
+        self.desired_torque += inputs.desired_torque;
+        self.actual_torque += inputs.actual_torque;
+        self.manifold_pressure += inputs.manifold_pressure;
+        if let Some(throttle_position) = inputs.throttle_position {
+            self.throttle_position += throttle_position;
+            self.throttle_samples += 1;
+        }
+    }
+    
+    /// Averaged inputs; discrete and setting inputs are taken from `latest`
+    fn average(&self, latest: &SystemInputs) -> SystemInputs {
+        let n = self.samples as f32;
+        SystemInputs {
+            rpm: (self.rpm / self.samples as u32) as u16,
+            desired_torque: self.desired_torque / n,
+            actual_torque: self.actual_torque / n,
+            manifold_pressure: self.manifold_pressure / n,
+            throttle_position: (self.throttle_samples > 0).then(|| self.throttle_position / self.throttle_samples as f32),
+            ..latest.clone()
+        }
+    }
+}
+
+/// Outer loop scheduler
+/// 
+/// 🔗 T4-CORE-068: Outer Loop Decimation
+/// Derived From: T4-CORE-067
+/// 
+/// Fed every control cycle; releases averaged inputs once per outer period.
+/// The first cycle after a reset releases immediately so Level 1 has a target
+/// before the inner loop first drives the output.
+#[derive(Debug, Clone)]
+pub struct RateScheduler {
+    rates: ControlRates,
+    accumulator: InputAccumulator,
+    primed: bool,
+}
+
+impl RateScheduler {
+    /// Create scheduler with the outer loop due on the first cycle
+    pub fn new(rates: ControlRates) -> Self {
+        Self {
+            rates,
+            accumulator: InputAccumulator::default(),
+            primed: false,
+        }
+    }
+    
+    /// Configured rates
+    pub fn rates(&self) -> &ControlRates {
+        &self.rates
+    }
+    
+    /// Feed one control cycle; returns decimated inputs when the outer loop is due
+    pub fn tick(&mut self, inputs: &SystemInputs) -> Option<SystemInputs> {
+        self.accumulator.add(inputs);
+        
+        if self.primed && self.accumulator.samples < self.rates.decimation() {
+            return None;
+        }
+        
+        self.primed = true;
+        let averaged = self.accumulator.average(inputs);
+        self.accumulator = InputAccumulator::default();
+        Some(averaged)
+    }
+    
+    /// Discard partial averages (control no longer active)
+    pub fn reset(&mut self) {
+        self.accumulator = InputAccumulator::default();
+        self.primed = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(rpm: u16, desired_torque: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs {
+            rpm,
+            desired_torque,
+            actual_torque: 200.0,
+            manifold_pressure: 5.0,
+            throttle_position: Some(60.0),
+            ..test_support::inputs(timestamp_ms)
+        }
+    }
+    
+    #[test]
+    fn test_outer_loop_runs_every_decimation_cycles() {
+        let mut scheduler = RateScheduler::new(ControlRates::default());
+        let due: u32 = (0..21)
+            .map(|cycle| scheduler.tick(&inputs(3000, 250.0, cycle * 10)).is_some() as u32)
+            .sum();
+        
+        // Immediately, then after cycles 5, 10, 15 and 20
+        assert_eq!(due, 5);
+    }
+    
+    #[test]
+    fn test_outer_inputs_are_averaged() {
+        let mut scheduler = RateScheduler::new(ControlRates { inner_hz: 200, outer_hz: 20 });
+        scheduler.tick(&inputs(3000, 250.0, 0));
+        
+        // Torque noise alternating ±20 Nm averages out
+        let mut averaged = None;
+        for cycle in 1..=10 {
+            let noise = if cycle % 2 == 0 { 20.0 } else { -20.0 };
+            averaged = scheduler.tick(&inputs(3000 + cycle as u16 * 10, 250.0 + noise, cycle * 5));
+        }
+        
+        let averaged = averaged.unwrap();
+        assert_eq!(averaged.desired_torque, 250.0);
+        assert_eq!(averaged.rpm, 3055);
+        assert_eq!(averaged.timestamp_ms, 50);
+    }
+    
+    #[test]
+    fn test_reset_runs_outer_loop_on_next_cycle() {
+        let mut scheduler = RateScheduler::new(ControlRates::default());
+        scheduler.tick(&inputs(3000, 250.0, 0));
+        scheduler.tick(&inputs(3000, 250.0, 10));
+        scheduler.reset();
+        assert!(scheduler.tick(&inputs(1500, 80.0, 1000)).is_some());
+    }
+    
+    #[test]
+    fn test_rate_validation() {
+        assert!(ControlRates::default().validate().is_ok());
+        assert!(ControlRates { inner_hz: 200, outer_hz: 10 }.validate().is_ok());
+        assert!(ControlRates { inner_hz: 150, outer_hz: 20 }.validate().is_err());
+        assert!(ControlRates { inner_hz: 50, outer_hz: 10 }.validate().is_err());
+        assert_eq!(ControlRates { inner_hz: 200, outer_hz: 20 }.inner_period_us(), 5000);
+    }
+}
@@ -0,0 +1,142 @@
+//! Phased Control Cycle
+//! 
+//! 🔗 T4-CORE-097: Phased Cycle Execution
+//! Derived From: T4-CORE-008 (Main Control Loop Implementation)
+//! AI Traceability: Integrators with their own executors schedule input acquisition, control decisions and output writes separately
+//! 
+//! `execute_control_cycle()` is `read_inputs()`, `evaluate()` and
+//! `apply_outputs()` run back to back. Each phase hands the next one an
+//! explicit value - `SystemInputs`, then a `CyclePlan` - so an executor can
+//! run them from different tasks or interrupt contexts, as long as every
+//! plan is applied, in order, before the next cycle is evaluated.
+//! 
+//! `evaluate()` decides everything and touches no output; `apply_outputs()`
+//! commands the actuator and PWM stage, then records what the cycle
+//! actually commanded (usage, headroom, fault history, events, statistics).
+//! Each plan carries a token sealing its command; see `command_token`.
+
+use serde::{Deserialize, Serialize};
+use crate::{CommandToken, SystemInputs};
+
+/// Actuator command decided by `evaluate()`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DriveCommand {
+    /// Leave the output as last commanded
+    Hold,
+    /// Open the wastegate (0% authority on every configured output)
+    Failsafe {
+        /// Skip any output ramp
+        immediate: bool,
+    },
+    /// Command this authority (%) - aggression scaling and the duty ceiling already applied
+    Authority(f32),
+    /// Command each turbo bank's authority (%, bank 1 first) - twin layouts
+    BankAuthority([f32; 2]),
+}
+
+/// Decisions of one control cycle, ready to be applied
+/// 
+/// Only `evaluate()` creates plans; a plan must be applied before the next
+/// cycle is evaluated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CyclePlan {
+    /// Inputs the cycle was evaluated with
+    pub inputs: SystemInputs,
+    /// Actuator command
+    pub drive: DriveCommand,
+    /// Switch the PWM stage on before driving (`Some(true)`) or off after it (`Some(false)`)
+    pub pwm_enable: Option<bool>,
+    /// Authority requested by the control hierarchy, before aggression scaling and the duty ceiling (%)
+    pub authority_request: Option<f32>,
+    /// Level 1 and learning ran this cycle
+    pub outer_cycle: bool,
+    /// Duty ceiling learning observes the applied command
+    pub(crate) observe_duty_ceiling: bool,
+    /// Cycle start, for cycle time statistics (microseconds)
+    pub(crate) started_us: u64,
+    /// Seal checked by `apply_outputs()` - any change to the command after evaluation faults the system
+    pub token: CommandToken,
+}
+
+impl CyclePlan {
+    pub(crate) fn new(inputs: SystemInputs, started_us: u64) -> Self {
+        Self {
+            inputs,
+            drive: DriveCommand::Hold,
+            pwm_enable: None,
+            authority_request: None,
+            outer_cycle: false,
+            observe_duty_ceiling: false,
+            started_us,
+            token: CommandToken { sequence: 0, crc: 0 },
+        }
+    }
+    
+    /// Open the wastegate
+    pub(crate) fn failsafe(&mut self, immediate: bool) {
+        self.drive = DriveCommand::Failsafe { immediate };
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, armed_core, TORQUE_MESSAGE_ID};
+    use crate::SystemConfig;
+    
+    fn inputs(timestamp_ms: u32, idle: bool) -> SystemInputs {
+        if idle {
+            test_support::idle_inputs(timestamp_ms)
+        } else {
+            test_support::inputs(timestamp_ms)
+        }
+    }
+    
+    #[test]
+    fn test_plan_carries_pwm_switching() {
+        let mut core = armed_core(SystemConfig::default());
+        
+        // Idle long enough to vent: the plan parks the output, then switches PWM off
+        let mut vent_plan = None;
+        for t in (0..1500).step_by(10) {
+            core.can_health.record_message(TORQUE_MESSAGE_ID, t);
+            let plan = core.evaluate(inputs(t, true)).unwrap();
+            if plan.pwm_enable.is_some() {
+                vent_plan = Some(plan.clone());
+            }
+            core.apply_outputs(plan).unwrap();
+        }
+        let vent_plan = vent_plan.unwrap();
+        assert_eq!(vent_plan.pwm_enable, Some(false));
+        assert_eq!(vent_plan.drive, DriveCommand::Failsafe { immediate: false });
+        
+        // Tip-in rearms PWM in the same cycle the hierarchy drives the output
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 1500);
+        let plan = core.evaluate(inputs(1500, false)).unwrap();
+        assert_eq!(plan.pwm_enable, Some(true));
+        assert!(matches!(plan.drive, DriveCommand::Authority(_)));
+        assert!(plan.authority_request.is_some());
+    }
+    
+    #[test]
+    fn test_evaluate_leaves_outputs_alone() {
+        let mut core = armed_core(SystemConfig::default());
+        let before = core.cycle_outputs().duty_percent;
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        
+        let plan = core.evaluate(inputs(0, false)).unwrap();
+        let DriveCommand::Authority(authority) = plan.drive else {
+            panic!("expected an authority command, got {:?}", plan.drive);
+        };
+        assert_eq!(core.cycle_outputs().duty_percent, before);
+        
+        // The composed cycle is exactly the phases back to back
+        let mut composed = armed_core(SystemConfig::default());
+        composed.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        composed.execute_control_cycle_with_inputs(inputs(0, false)).unwrap();
+        core.apply_outputs(plan).unwrap();
+        assert_eq!(core.cycle_outputs(), composed.cycle_outputs());
+        assert!((core.cycle_outputs().duty_percent - authority).abs() < 0.5);
+        assert_eq!(core.stats.cycles_executed, 1);
+    }
+}
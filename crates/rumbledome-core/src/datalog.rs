@@ -0,0 +1,530 @@
+//! SD Card Datalogger
+//! 
+//! 🔗 T4-CORE-138: Rotating SD Card Datalogs
+//! Derived From: T4-CORE-132 (Telemetry Fan-Out) + T4-HAL-021 (SD Card File Storage)
+//! AI Traceability: Every pull on the card for later review, without a laptop in the car
+//! 
+//! The datalogger takes the same per-cycle `TelemetrySample` as the other
+//! telemetry sinks and writes the configured channels at 10-100 Hz into
+//! numbered files under `RUMBLEDOME/logs`, as CSV or as a compact binary
+//! format. Rows are buffered and written a card sector at a time; a file
+//! that reaches the rotation size is closed and the next one opened, and
+//! the oldest files are deleted to keep the configured count.
+//! 
+//! Logging starts and stops on command, or on its own when the throttle
+//! goes wide open and a few seconds after it lifts. Without a throttle
+//! signal, wide open and the lift are read from the ECU torque request. A missing or pulled
+//! card ends the log without affecting control - the next start tries
+//! again.
+//! 
+//! Binary files start with `RDL1`, a channel count and one code per
+//! channel (its position in `TelemetryChannel::ALL`). Each row is the
+//! timestamp (u32 ms) then one i16 per channel in
+//! `TelemetryChannel::can_scale` units, all little-endian.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::PortableStorage;
+use crate::{csv_header, csv_row, ChannelSet, CoreError, SystemInputs, TelemetryChannel, TelemetryFrame, TelemetrySample};
+
+/// Directory datalogs are written to
+pub const DATALOG_DIR: &str = "RUMBLEDOME/logs";
+
+/// Buffered bytes written at once (one card sector)
+pub const DATALOG_FLUSH_BYTES: usize = 512;
+
+/// First bytes of a binary datalog
+pub const BINARY_DATALOG_MAGIC: [u8; 4] = *b"RDL1";
+
+/// Datalog file format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DatalogFormat {
+    /// Header line then one text row per sample
+    #[default]
+    Csv,
+    /// Channel header then fixed-size little-endian rows
+    Binary,
+}
+
+impl DatalogFormat {
+    /// File name extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DatalogFormat::Csv => "csv",
+            DatalogFormat::Binary => "rdl",
+        }
+    }
+}
+
+/// Datalog file rotation settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DatalogRotationConfig {
+    /// Size at which a file is closed and the next one opened (KB)
+    pub max_file_kb: u32,
+    /// Files kept; the oldest are deleted
+    pub max_files: u16,
+}
+
+impl Default for DatalogRotationConfig {
+    fn default() -> Self {
+        Self { max_file_kb: 4096, max_files: 50 }
+    }
+}
+
+/// Automatic logging on wide-open throttle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WotTriggerConfig {
+    /// Start logging when the throttle goes wide open
+    pub enabled: bool,
+    /// Throttle counted as wide open (%)
+    pub throttle_percent: f32,
+    /// Time logging continues after the throttle lifts (ms)
+    pub stop_after_lift_ms: u32,
+}
+
+impl Default for WotTriggerConfig {
+    fn default() -> Self {
+        Self { enabled: true, throttle_percent: 90.0, stop_after_lift_ms: 5_000 }
+    }
+}
+
+/// Datalogger settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DatalogSinkConfig {
+    /// Interval between rows (ms, 10-100 for 100-10 Hz)
+    pub interval_ms: u32,
+    /// Columns logged after the timestamp
+    pub channels: Vec<TelemetryChannel>,
+    #[serde(default)]
+    pub format: DatalogFormat,
+    #[serde(default)]
+    pub rotation: DatalogRotationConfig,
+    #[serde(default)]
+    pub wot_trigger: WotTriggerConfig,
+}
+
+impl Default for DatalogSinkConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 50,
+            channels: Vec::from(TelemetryChannel::ALL),
+            format: DatalogFormat::Csv,
+            rotation: DatalogRotationConfig::default(),
+            wot_trigger: WotTriggerConfig::default(),
+        }
+    }
+}
+
+impl DatalogSinkConfig {
+    /// Validate rate, channels, rotation and trigger
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.channels.is_empty() {
+            return Err(CoreError::ConfigurationError(String::from("Datalog needs at least one channel")));
+        }
+        if !(10..=100).contains(&self.interval_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Datalog interval must be 10-100 ms (10-100 Hz), got {}", self.interval_ms)
+            ));
+        }
+        if !(16..=1_048_576).contains(&self.rotation.max_file_kb) {
+            return Err(CoreError::ConfigurationError(
+                format!("Datalog file size must be 16 KB-1 GB, got {} KB", self.rotation.max_file_kb)
+            ));
+        }
+        if !(1..=1000).contains(&self.rotation.max_files) {
+            return Err(CoreError::ConfigurationError(
+                format!("Datalog file count must be 1-1000, got {}", self.rotation.max_files)
+            ));
+        }
+        if !(50.0..=100.0).contains(&self.wot_trigger.throttle_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Datalog WOT throttle must be 50-100%, got {}", self.wot_trigger.throttle_percent)
+            ));
+        }
+        if self.wot_trigger.stop_after_lift_ms > 60_000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Datalog stop delay must be at most 60000 ms, got {}", self.wot_trigger.stop_after_lift_ms)
+            ));
+        }
+        Ok(())
+    }
+    
+    fn channel_set(&self) -> ChannelSet {
+        ChannelSet::only(&self.channels)
+    }
+}
+
+/// What started the running log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DatalogTrigger {
+    /// Start command - runs until the stop command
+    Manual,
+    /// Wide-open throttle - stops on its own after the lift
+    Wot,
+}
+
+/// Datalogger state for status reports
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatalogStatus {
+    /// What started the running log (`None` while not logging)
+    pub trigger: Option<DatalogTrigger>,
+    /// File being written
+    pub file: Option<String>,
+    /// Size of the file being written, buffered rows included (bytes)
+    pub file_bytes: u64,
+    /// Rows logged since power-up
+    pub rows: u32,
+    /// Files opened since power-up
+    pub files_opened: u32,
+    /// Writes the card refused (the buffered rows are lost)
+    pub write_errors: u32,
+    /// A card was mounted at the last attempt to use it
+    pub card_present: bool,
+}
+
+/// File being written
+#[derive(Debug, Clone)]
+struct LogFile {
+    trigger: DatalogTrigger,
+    path: String,
+    bytes: u64,
+}
+
+/// Writes telemetry rows to rotating files on the SD card
+#[derive(Debug, Clone)]
+pub struct Datalogger {
+    config: DatalogSinkConfig,
+    file: Option<LogFile>,
+    buffer: Vec<u8>,
+    last_row_ms: Option<u32>,
+    lifted_since_ms: Option<u32>,
+    status: DatalogStatus,
+}
+
+impl Datalogger {
+    /// Create, not logging
+    pub fn new(config: DatalogSinkConfig) -> Self {
+        Self {
+            config,
+            file: None,
+            buffer: Vec::with_capacity(DATALOG_FLUSH_BYTES * 2),
+            last_row_ms: None,
+            lifted_since_ms: None,
+            status: DatalogStatus { card_present: true, ..DatalogStatus::default() },
+        }
+    }
+    
+    /// Apply changed settings; a running log continues in a new file with the new columns
+    pub fn reconfigure<S: PortableStorage>(&mut self, storage: &mut S, config: DatalogSinkConfig) {
+        let trigger = self.file.as_ref().map(|file| file.trigger);
+        self.stop(storage);
+        self.config = config;
+        if let Some(trigger) = trigger {
+            let _ = self.start(storage, trigger);
+        }
+    }
+    
+    /// Whether a log is running
+    pub fn is_logging(&self) -> bool {
+        self.file.is_some()
+    }
+    
+    /// Start logging into a new file
+    /// 
+    /// A start command during a WOT log keeps that file and makes the log
+    /// run until the stop command.
+    pub fn start<S: PortableStorage>(&mut self, storage: &mut S, trigger: DatalogTrigger) -> Result<(), CoreError> {
+        if let Some(file) = self.file.as_mut() {
+            if trigger == DatalogTrigger::Manual {
+                file.trigger = trigger;
+            }
+            return Ok(());
+        }
+        self.open(storage, trigger)
+    }
+    
+    /// Write out buffered rows and close the file
+    pub fn stop<S: PortableStorage>(&mut self, storage: &mut S) {
+        self.flush(storage);
+        self.file = None;
+        self.lifted_since_ms = None;
+    }
+    
+    /// Log one sample when due, starting and stopping WOT logs
+    /// 
+    /// `load_multiplier` stretches the row interval (from the performance tier).
+    pub fn record<S: PortableStorage>(&mut self, storage: &mut S, sample: &TelemetrySample, inputs: &SystemInputs, load_multiplier: u32) {
+        let now_ms = sample.timestamp_ms;
+        self.follow_throttle(storage, inputs, now_ms);
+        if self.file.is_none() {
+            return;
+        }
+        
+        let interval_ms = self.config.interval_ms.saturating_mul(load_multiplier.max(1));
+        if self.last_row_ms.is_some_and(|last_ms| now_ms.wrapping_sub(last_ms) < interval_ms) {
+            return;
+        }
+        self.last_row_ms = Some(now_ms);
+        
+        let frame = TelemetryFrame { sample, channels: self.config.channel_set() };
+        let before = self.buffer.len();
+        match self.config.format {
+            DatalogFormat::Csv => {
+                self.buffer.extend_from_slice(csv_row(&frame).as_bytes());
+                self.buffer.push(b'\n');
+            },
+            DatalogFormat::Binary => {
+                self.buffer.extend_from_slice(&now_ms.to_le_bytes());
+                for (channel, value) in frame.values() {
+                    let scaled = libm::roundf(value * channel.can_scale()).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+                    self.buffer.extend_from_slice(&scaled.to_le_bytes());
+                }
+            },
+        }
+        self.status.rows += 1;
+        
+        let row_bytes = (self.buffer.len() - before) as u64;
+        let full = self.file.as_mut().map(|file| {
+            file.bytes += row_bytes;
+            file.bytes >= self.config.rotation.max_file_kb as u64 * 1024
+        });
+        if full == Some(true) {
+            let trigger = self.file.as_ref().map_or(DatalogTrigger::Manual, |file| file.trigger);
+            self.stop(storage);
+            let _ = self.open(storage, trigger);
+        } else if self.buffer.len() >= DATALOG_FLUSH_BYTES {
+            self.flush(storage);
+        }
+    }
+    
+    /// Current status
+    pub fn status(&self) -> DatalogStatus {
+        DatalogStatus {
+            trigger: self.file.as_ref().map(|file| file.trigger),
+            file: self.file.as_ref().map(|file| file.path.clone()),
+            file_bytes: self.file.as_ref().map_or(0, |file| file.bytes),
+            ..self.status.clone()
+        }
+    }
+    
+    /// Start a WOT log on wide-open throttle; stop it once lifted long enough
+    fn follow_throttle<S: PortableStorage>(&mut self, storage: &mut S, inputs: &SystemInputs, now_ms: u32) {
+        let trigger = &self.config.wot_trigger;
+        if !trigger.enabled {
+            return;
+        }
+        match self.file.as_ref().map(|file| file.trigger) {
+            None if inputs.wide_open(trigger.throttle_percent) => {
+                // No card - try again on the next cycle at WOT
+                let _ = self.open(storage, DatalogTrigger::Wot);
+            },
+            Some(DatalogTrigger::Wot) if !inputs.lifted(trigger.throttle_percent) => self.lifted_since_ms = None,
+            Some(DatalogTrigger::Wot) => {
+                let since = *self.lifted_since_ms.get_or_insert(now_ms);
+                if now_ms.wrapping_sub(since) >= trigger.stop_after_lift_ms {
+                    self.stop(storage);
+                }
+            },
+            _ => {},
+        }
+    }
+    
+    /// Open the next numbered file, deleting the oldest beyond the kept count
+    fn open<S: PortableStorage>(&mut self, storage: &mut S, trigger: DatalogTrigger) -> Result<(), CoreError> {
+        self.status.card_present = storage.is_mounted();
+        let existing = storage.list_files(DATALOG_DIR)?;
+        let mut numbered: Vec<(u32, &String)> = existing.iter()
+            .filter_map(|name| name.split('.').next()?.parse::<u32>().ok().map(|number| (number, name)))
+            .collect();
+        // Files are numbered in the order written, so the lowest numbers are the oldest
+        numbered.sort();
+        let next = numbered.last().map_or(1, |(number, _)| number + 1);
+        let excess = (numbered.len() + 1).saturating_sub(self.config.rotation.max_files as usize);
+        for (_, name) in numbered.iter().take(excess) {
+            storage.remove_file(&format!("{}/{}", DATALOG_DIR, name))?;
+        }
+        
+        let channels = self.config.channel_set();
+        let header = match self.config.format {
+            DatalogFormat::Csv => {
+                let mut header = csv_header(channels).into_bytes();
+                header.push(b'\n');
+                header
+            },
+            DatalogFormat::Binary => {
+                let mut header = Vec::from(BINARY_DATALOG_MAGIC);
+                header.push(channels.channels().count() as u8);
+                header.extend(channels.channels().map(|channel| channel as u8));
+                header
+            },
+        };
+        let path = format!("{}/{:05}.{}", DATALOG_DIR, next, self.config.format.extension());
+        storage.append_file(&path, &header)?;
+        
+        self.file = Some(LogFile { trigger, path, bytes: header.len() as u64 });
+        self.last_row_ms = None;
+        self.lifted_since_ms = None;
+        self.status.files_opened += 1;
+        Ok(())
+    }
+    
+    /// Append buffered rows to the file; a refused write loses them, a pulled card ends the log
+    fn flush<S: PortableStorage>(&mut self, storage: &mut S) {
+        let Some(file) = self.file.as_ref() else {
+            self.buffer.clear();
+            return;
+        };
+        if self.buffer.is_empty() {
+            return;
+        }
+        if storage.append_file(&file.path, &self.buffer).is_err() {
+            self.status.write_errors += 1;
+            self.status.card_present = storage.is_mounted();
+            if !self.status.card_present {
+                self.file = None;
+            }
+        }
+        self.buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_hal::MockHal;
+    use crate::{test_support, AggressionSource, SystemState};
+    
+    fn sample(timestamp_ms: u32) -> TelemetrySample {
+        TelemetrySample {
+            timestamp_ms,
+            state: SystemState::Armed,
+            rpm: 4200,
+            boost_psi: 8.46,
+            target_psi: 9.0,
+            duty_percent: 42.5,
+            torque_gap_nm: 35.0,
+            aggression: 0.7,
+            aggression_source: AggressionSource::Knob,
+        }
+    }
+    
+    fn throttle(throttle_percent: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { throttle_position: Some(throttle_percent), ..test_support::inputs(timestamp_ms) }
+    }
+    
+    fn boost_only(format: DatalogFormat) -> DatalogSinkConfig {
+        DatalogSinkConfig {
+            interval_ms: 50,
+            channels: Vec::from([TelemetryChannel::Rpm, TelemetryChannel::BoostPsi]),
+            format,
+            ..DatalogSinkConfig::default()
+        }
+    }
+    
+    fn file_text(hal: &MockHal, path: &str) -> String {
+        String::from_utf8(hal.sd_file(path).unwrap().to_vec()).unwrap()
+    }
+    
+    #[test]
+    fn test_wot_pull_logged_at_configured_rate() {
+        let mut hal = MockHal::new();
+        let mut logger = Datalogger::new(boost_only(DatalogFormat::Csv));
+        
+        // Cruising - nothing logged
+        for t in (0..500).step_by(10) {
+            logger.record(&mut hal, &sample(t), &throttle(30.0, t), 1);
+        }
+        assert!(!logger.is_logging());
+        
+        // One second at WOT, then the lift
+        for t in (500..1500).step_by(10) {
+            logger.record(&mut hal, &sample(t), &throttle(100.0, t), 1);
+        }
+        assert_eq!(logger.status().trigger, Some(DatalogTrigger::Wot));
+        for t in (1500..7000).step_by(10) {
+            logger.record(&mut hal, &sample(t), &throttle(20.0, t), 1);
+        }
+        assert!(!logger.is_logging());
+        
+        // 20 Hz from the start of the pull until 5 s after the lift
+        let text = file_text(&hal, "RUMBLEDOME/logs/00001.csv");
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "timestamp_ms,rpm,boost_psi");
+        assert_eq!(lines[1], "500,4200,8.5");
+        assert_eq!(lines.len(), 1 + 120);
+    }
+    
+    #[test]
+    fn test_torque_request_triggers_wot_log_without_throttle_signal() {
+        let mut hal = MockHal::new();
+        let mut logger = Datalogger::new(boost_only(DatalogFormat::Csv));
+        let unmapped = |desired_torque, timestamp_ms| SystemInputs {
+            throttle_position: None,
+            desired_torque,
+            actual_torque: 300.0,
+            ..test_support::inputs(timestamp_ms)
+        };
+        
+        logger.record(&mut hal, &sample(0), &unmapped(320.0, 0), 1);
+        assert!(!logger.is_logging());
+        logger.record(&mut hal, &sample(10), &unmapped(450.0, 10), 1);
+        assert_eq!(logger.status().trigger, Some(DatalogTrigger::Wot));
+        
+        // Boost closing the torque gap is not a lift; the request falling is
+        for t in [20, 6_000] {
+            logger.record(&mut hal, &sample(t), &unmapped(350.0, t), 1);
+        }
+        assert!(logger.is_logging());
+        for t in [6_010, 11_010] {
+            logger.record(&mut hal, &sample(t), &unmapped(200.0, t), 1);
+        }
+        assert!(!logger.is_logging());
+    }
+    
+    #[test]
+    fn test_manual_log_rotates_and_prunes() {
+        let mut hal = MockHal::new();
+        let mut config = boost_only(DatalogFormat::Binary);
+        config.rotation = DatalogRotationConfig { max_file_kb: 16, max_files: 2 };
+        config.wot_trigger.enabled = false;
+        let mut logger = Datalogger::new(config);
+        
+        logger.start(&mut hal, DatalogTrigger::Manual).unwrap();
+        // 8-byte rows, a little over two 16 KB files
+        for t in 0..4_500u32 {
+            logger.record(&mut hal, &sample(t * 50), &throttle(0.0, t * 50), 1);
+        }
+        logger.stop(&mut hal);
+        
+        assert_eq!(hal.list_files(DATALOG_DIR).unwrap(), ["00002.rdl", "00003.rdl"]);
+        let second = hal.sd_file("RUMBLEDOME/logs/00002.rdl").unwrap();
+        assert_eq!(second[..7], [b'R', b'D', b'L', b'1', 2, 0, 1]);
+        assert!(second.len() as u64 >= 16 * 1024);
+        assert_eq!(logger.status().files_opened, 3);
+    }
+    
+    #[test]
+    fn test_pulled_card_ends_log_without_error() {
+        let mut hal = MockHal::new();
+        hal.remove_sd_card();
+        let mut logger = Datalogger::new(boost_only(DatalogFormat::Csv));
+        
+        assert!(logger.start(&mut hal, DatalogTrigger::Manual).is_err());
+        assert!(!logger.status().card_present);
+        
+        hal.insert_sd_card();
+        logger.start(&mut hal, DatalogTrigger::Manual).unwrap();
+        hal.remove_sd_card();
+        for t in (0..5_000).step_by(50) {
+            logger.record(&mut hal, &sample(t), &throttle(100.0, t), 1);
+        }
+        assert!(!logger.is_logging());
+        assert_eq!(logger.status().write_errors, 1);
+    }
+}
@@ -0,0 +1,208 @@
+//! Display Demo Mode
+//! 
+//! 🔗 T4-CORE-091: Bench Display Demo
+//! Derived From: T4-CORE-035 (Semantic Screen Description) + Hardware.md display requirements
+//! AI Traceability: Check display wiring, orientation and legibility on the bench with no CAN or sensors connected
+//! 
+//! The demo only replaces what the display shows - it never touches the
+//! solenoid output. It can only run with the engine stopped and the system
+//! idle (or faulted, as it will be on a bench with nothing connected), and
+//! ends on its own the moment either stops being true.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use crate::{AccentColor, AlertSeverity, DisplayString, Language, ScreenAlert, ScreenDescription, ScreenLayout, SystemConfig};
+
+/// Time each demo scene is shown (ms)
+pub const DEMO_SCENE_MS: u32 = 4000;
+
+/// Scramble button hold that starts or stops the demo with the engine stopped (ms)
+pub const DEMO_BUTTON_HOLD_MS: u32 = 5000;
+
+/// Demo scenes, shown in order and repeated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemoScene {
+    /// Needle, target marker and aggression fill sweep full scale
+    GaugeSweep,
+    /// Alert banner in each severity color
+    Banners,
+    /// Startup splash
+    Startup,
+    /// Calibration progress layout
+    Calibration,
+    /// Full-screen fault layout
+    Fault,
+}
+
+impl DemoScene {
+    /// Every scene in display order
+    pub const ALL: [DemoScene; 5] = [
+        DemoScene::GaugeSweep, DemoScene::Banners, DemoScene::Startup, DemoScene::Calibration, DemoScene::Fault,
+    ];
+}
+
+/// Demo mode state
+#[derive(Debug, Clone, Default)]
+pub struct DemoMode {
+    started_ms: Option<u32>,
+    press_started_ms: Option<u32>,
+    press_handled: bool,
+}
+
+impl DemoMode {
+    /// Create with the demo off
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Whether the demo is running
+    pub fn is_active(&self) -> bool {
+        self.started_ms.is_some()
+    }
+    
+    /// Start the demo from its first scene
+    pub fn start(&mut self, now_ms: u32) {
+        self.started_ms = Some(now_ms);
+    }
+    
+    /// Stop the demo
+    pub fn stop(&mut self) {
+        self.started_ms = None;
+    }
+    
+    /// Observe the scramble button once per control cycle
+    /// 
+    /// `bench` is whether the demo is allowed to run right now. Returns the new
+    /// demo state when it changed.
+    pub fn observe(&mut self, button_pressed: bool, bench: bool, now_ms: u32) -> Option<bool> {
+        if !bench {
+            self.press_started_ms = None;
+            self.press_handled = false;
+            return self.is_active().then(|| {
+                self.stop();
+                false
+            });
+        }
+        
+        if !button_pressed {
+            self.press_started_ms = None;
+            self.press_handled = false;
+            return None;
+        }
+        
+        // One toggle per press, however long the button stays down
+        let pressed_since = *self.press_started_ms.get_or_insert(now_ms);
+        if self.press_handled || now_ms.wrapping_sub(pressed_since) < DEMO_BUTTON_HOLD_MS {
+            return None;
+        }
+        self.press_handled = true;
+        if self.is_active() {
+            self.stop();
+        } else {
+            self.start(now_ms);
+        }
+        Some(self.is_active())
+    }
+    
+    /// Scene showing at `now_ms` and how far through it (0.0-1.0)
+    pub fn scene(&self, now_ms: u32) -> Option<(DemoScene, f32)> {
+        let elapsed = now_ms.wrapping_sub(self.started_ms?);
+        let index = (elapsed / DEMO_SCENE_MS) as usize % DemoScene::ALL.len();
+        let progress = (elapsed % DEMO_SCENE_MS) as f32 / DEMO_SCENE_MS as f32;
+        Some((DemoScene::ALL[index], progress))
+    }
+    
+    /// Scripted display contents, or `None` when the demo is off
+    pub fn screen(&self, now_ms: u32, config: &SystemConfig, language: Language) -> Option<ScreenDescription> {
+        let (scene, progress) = self.scene(now_ms)?;
+        let gauge_max_psi = config.overboost_limit;
+        // Up and back down over the scene
+        let sweep = 1.0 - (2.0 * progress - 1.0).abs();
+        
+        let demo_alert = |severity| Some(ScreenAlert { severity, text: DisplayString::AlertDemo.text(language).to_string() });
+        let (layout, boost_psi, target_psi, aggression, alert) = match scene {
+            DemoScene::GaugeSweep => (ScreenLayout::Gauge, sweep * gauge_max_psi, (1.0 - sweep) * gauge_max_psi, sweep, None),
+            DemoScene::Banners => {
+                let severity = match (progress * 3.0) as u32 {
+                    0 => AlertSeverity::Advisory,
+                    1 => AlertSeverity::Warning,
+                    _ => AlertSeverity::Critical,
+                };
+                (ScreenLayout::Gauge, gauge_max_psi * 0.5, gauge_max_psi * 0.5, 0.5, demo_alert(severity))
+            },
+            DemoScene::Startup => (ScreenLayout::Startup, 0.0, 0.0, 0.0, None),
+            DemoScene::Calibration => (ScreenLayout::Calibration, progress * gauge_max_psi, gauge_max_psi * 0.5, 0.0, None),
+            DemoScene::Fault => (ScreenLayout::Fault, 0.0, 0.0, 0.0, demo_alert(AlertSeverity::Critical)),
+        };
+        
+        // The accent ring cycles through the primary colors to show up swapped color channels
+        const ACCENTS: [AccentColor; 3] = [AccentColor::RED, AccentColor::GREEN, AccentColor::BLUE];
+        let accent_color = ACCENTS[(now_ms / 500) as usize % ACCENTS.len()];
+        
+        Some(ScreenDescription {
+            layout,
+            status_text: DisplayString::StateDemo.text(language).to_string(),
+            boost_psi,
+            target_psi,
+            gauge_max_psi,
+            aggression_percent: (aggression * 100.0) as u8,
+            control_mode: config.control_mode,
+            accent_color,
+            profile_label: config.theme.label.clone(),
+            exhaust_backpressure_psi: None,
+            alert,
+            headroom: None,
+            language,
+            timestamp_ms: now_ms,
+            watches: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_scenes_cycle_and_sweep() {
+        let mut demo = DemoMode::new();
+        let config = SystemConfig::default();
+        assert!(demo.screen(0, &config, Language::English).is_none());
+        
+        demo.start(1000);
+        let start = demo.screen(1000, &config, Language::English).unwrap();
+        assert_eq!(start.layout, ScreenLayout::Gauge);
+        assert_eq!(start.status_text, "DEMO");
+        assert_eq!(start.boost_psi, 0.0);
+        
+        let peak = demo.screen(1000 + DEMO_SCENE_MS / 2, &config, Language::English).unwrap();
+        assert_eq!(peak.boost_psi, config.overboost_limit);
+        assert_eq!(peak.aggression_percent, 100);
+        
+        let banners = demo.screen(1000 + DEMO_SCENE_MS * 2 - 1, &config, Language::English).unwrap();
+        assert_eq!(banners.alert.unwrap().severity, AlertSeverity::Critical);
+        
+        let layouts: alloc::vec::Vec<_> = (0..6)
+            .map(|scene| demo.screen(1000 + scene * DEMO_SCENE_MS, &config, Language::English).unwrap().layout)
+            .collect();
+        assert_eq!(layouts[2..], [ScreenLayout::Startup, ScreenLayout::Calibration, ScreenLayout::Fault, ScreenLayout::Gauge]);
+    }
+    
+    #[test]
+    fn test_button_hold_toggles_on_bench_only() {
+        let mut demo = DemoMode::new();
+        
+        // Short press does nothing; a held press toggles once
+        assert_eq!(demo.observe(true, true, 0), None);
+        assert_eq!(demo.observe(false, true, 1000), None);
+        assert_eq!(demo.observe(true, true, 2000), None);
+        assert_eq!(demo.observe(true, true, 2000 + DEMO_BUTTON_HOLD_MS), Some(true));
+        assert_eq!(demo.observe(true, true, 4000 + DEMO_BUTTON_HOLD_MS * 2), None);
+        assert!(demo.is_active());
+        
+        // Engine start ends the demo, and the held button cannot restart it
+        assert_eq!(demo.observe(true, false, 20_000), Some(false));
+        assert_eq!(demo.observe(true, false, 30_000), None);
+        assert!(!demo.is_active());
+    }
+}
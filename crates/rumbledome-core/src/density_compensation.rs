@@ -0,0 +1,404 @@
+//! Air Density Compensation
+//! 
+//! 🔗 T4-CORE-136: Barometric and Intake Temperature Density Compensation
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + T4-CORE-108 (Learned Duty Calibration Map)
+//! AI Traceability: A duty map learned at sea level on a mild day holds less boost in the mountains or the cold - correct for air density instead of relearning
+//! 
+//! Inlet air density relative to reference conditions comes from ambient
+//! pressure and intake air temperature. Ambient pressure is read from a
+//! mapped barometric CAN signal, else from an absolute pressure sensor on an
+//! expansion input, else the reference is assumed; intake air temperature
+//! is read from a mapped CAN signal, else from a linear temperature sensor
+//! on an expansion input, else the reference is assumed too.
+//! 
+//! Thinner air carries less exhaust energy per PSI of boost, so the turbo
+//! needs the wastegate shut further to hold the same boost: the learned
+//! duty is scaled up by `duty_gain` for each unit of density lost (and down
+//! for dense air). Learning takes the correction back out, so the map stays
+//! at reference conditions whatever the weather it was learned in.
+//! 
+//! Boost gauge pressure is relative to the local atmosphere, so at altitude
+//! the same boost puts less air in the cylinders. With `hold_absolute_pressure`
+//! the boost target is raised by the ambient pressure lost against the
+//! reference, up to the configured maximum boost - the turbo speed limit
+//! still applies on top.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{PressureCalibration, ANALOG_CHANNELS, PSI_PER_KPA};
+use crate::{CoreError, SystemInputs, ANALOG_PRESSURE_INPUTS, REFERENCE_PRESSURE_KPA, REFERENCE_TEMP_C};
+
+/// Kelvin at 0 °C
+const ZERO_C_K: f32 = 273.15;
+
+/// Plausible ambient pressure (kPa absolute) - outside it the reading is ignored
+const AMBIENT_KPA_RANGE: core::ops::RangeInclusive<f32> = 50.0..=110.0;
+
+/// Output span of the linear temperature sensor (V)
+const IAT_SENSOR_VOLTS: core::ops::RangeInclusive<f32> = 0.5..=4.5;
+
+/// Distance outside the output span still read as the end of the scale (V); further out the wiring is open or shorted
+const IAT_END_STOP_MARGIN_V: f32 = 0.2;
+
+/// Air density compensation settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DensityCompensationConfig {
+    /// Correct learned duty (and optionally the boost target) for air density
+    pub enabled: bool,
+    /// Expansion input with an absolute pressure sensor, used while no barometric CAN signal is received
+    #[serde(default)]
+    pub baro_channel: Option<u8>,
+    /// Barometric sensor pressure at 4.5 V (PSI absolute, 0.5-4.5 V ratiometric)
+    pub baro_full_scale_psi: f32,
+    /// Expansion input with a linear intake air temperature sensor, used while no intake air temperature CAN signal is received
+    #[serde(default)]
+    pub iat_channel: Option<u8>,
+    /// Intake air temperature sensor reading at 0.5 V (°C)
+    #[serde(default = "default_iat_min_c")]
+    pub iat_min_c: f32,
+    /// Intake air temperature sensor reading at 4.5 V (°C)
+    #[serde(default = "default_iat_max_c")]
+    pub iat_max_c: f32,
+    /// Ambient pressure the learned map is taken to be at (kPa absolute)
+    pub reference_pressure_kpa: f32,
+    /// Intake air temperature the learned map is taken to be at (°C)
+    pub reference_temp_c: f32,
+    /// Duty change per unit of density ratio lost (0 = duty uncorrected)
+    pub duty_gain: f32,
+    /// Smallest duty correction factor
+    pub min_duty_factor: f32,
+    /// Largest duty correction factor
+    pub max_duty_factor: f32,
+    /// Raise the boost target by the ambient pressure lost against the reference
+    pub hold_absolute_pressure: bool,
+}
+
+impl Default for DensityCompensationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            baro_channel: None,
+            baro_full_scale_psi: 30.0,
+            iat_channel: None,
+            iat_min_c: default_iat_min_c(),
+            iat_max_c: default_iat_max_c(),
+            reference_pressure_kpa: REFERENCE_PRESSURE_KPA,
+            reference_temp_c: REFERENCE_TEMP_C,
+            duty_gain: 0.5,
+            min_duty_factor: 0.85,
+            max_duty_factor: 1.2,
+            hold_absolute_pressure: false,
+        }
+    }
+}
+
+fn default_iat_min_c() -> f32 {
+    -40.0
+}
+
+fn default_iat_max_c() -> f32 {
+    150.0
+}
+
+impl DensityCompensationConfig {
+    /// Validate the sensor inputs and correction limits
+    pub fn validate(&self) -> Result<(), CoreError> {
+        // Channels below the expansion inputs carry the boost and dome sensors
+        for (name, channel) in [("Barometric", self.baro_channel), ("Intake air temperature", self.iat_channel)] {
+            if let Some(channel) = channel {
+                if !(ANALOG_PRESSURE_INPUTS..ANALOG_CHANNELS).contains(&(channel as usize)) {
+                    return Err(CoreError::ConfigurationError(format!(
+                        "{} sensor channel must be {}-{}, got {}", name, ANALOG_PRESSURE_INPUTS, ANALOG_CHANNELS - 1, channel
+                    )));
+                }
+            }
+        }
+        if self.iat_channel.is_some() && self.iat_channel == self.baro_channel {
+            return Err(CoreError::ConfigurationError(
+                "Barometric and intake air temperature sensors cannot share a channel".into()
+            ));
+        }
+        if !(-60.0..=250.0).contains(&self.iat_min_c) || !(-60.0..=250.0).contains(&self.iat_max_c) || self.iat_min_c == self.iat_max_c {
+            return Err(CoreError::ConfigurationError(format!(
+                "Intake air temperature sensor span must be two different temperatures in -60-250 °C, got {} and {}", self.iat_min_c, self.iat_max_c
+            )));
+        }
+        if !(15.0..=50.0).contains(&self.baro_full_scale_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Barometric sensor full scale must be 15-50 PSI, got {}", self.baro_full_scale_psi)
+            ));
+        }
+        if !AMBIENT_KPA_RANGE.contains(&self.reference_pressure_kpa) {
+            return Err(CoreError::ConfigurationError(
+                format!("Density reference pressure must be 50-110 kPa, got {}", self.reference_pressure_kpa)
+            ));
+        }
+        if !(-20.0..=50.0).contains(&self.reference_temp_c) {
+            return Err(CoreError::ConfigurationError(
+                format!("Density reference temperature must be -20-50 °C, got {}", self.reference_temp_c)
+            ));
+        }
+        if !(0.0..=2.0).contains(&self.duty_gain) {
+            return Err(CoreError::ConfigurationError(
+                format!("Density duty gain must be 0-2, got {}", self.duty_gain)
+            ));
+        }
+        if !(0.5..=1.0).contains(&self.min_duty_factor) || !(1.0..=1.5).contains(&self.max_duty_factor) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Density duty factor limits must be 0.5-1.0 and 1.0-1.5, got {} and {}", self.min_duty_factor, self.max_duty_factor
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Calibration curve for the barometric sensor's channel
+    pub fn sensor_calibration(&self) -> PressureCalibration {
+        PressureCalibration { full_scale_psi: self.baro_full_scale_psi, ..PressureCalibration::default() }
+    }
+    
+    /// Intake air temperature for a sensor voltage, or `None` when the wiring is open or shorted (°C)
+    pub fn intake_air_temp_c(&self, volts: f32) -> Option<f32> {
+        let (low, high) = (*IAT_SENSOR_VOLTS.start(), *IAT_SENSOR_VOLTS.end());
+        if volts < low - IAT_END_STOP_MARGIN_V || volts > high + IAT_END_STOP_MARGIN_V {
+            return None;
+        }
+        let position = ((volts - low) / (high - low)).clamp(0.0, 1.0);
+        Some(self.iat_min_c + position * (self.iat_max_c - self.iat_min_c))
+    }
+}
+
+/// Where the ambient pressure came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum AmbientPressureSource {
+    /// Barometric CAN signal
+    Can,
+    /// Absolute pressure sensor on an expansion input
+    Analog,
+    /// No reading - reference pressure assumed
+    #[default]
+    Reference,
+}
+
+/// Density compensation status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DensityCompensationStatus {
+    /// Ambient pressure in use (kPa absolute)
+    pub ambient_kpa: f32,
+    /// Where the ambient pressure came from
+    pub ambient_source: AmbientPressureSource,
+    /// Intake air temperature in use (°C)
+    pub inlet_temp_c: f32,
+    /// Air density relative to the reference conditions
+    pub density_ratio: f32,
+    /// Factor applied to learned duty
+    pub duty_factor: f32,
+    /// Added to the boost target to hold manifold absolute pressure (PSI)
+    pub target_offset_psi: f32,
+}
+
+impl Default for DensityCompensationStatus {
+    fn default() -> Self {
+        Self {
+            ambient_kpa: REFERENCE_PRESSURE_KPA,
+            ambient_source: AmbientPressureSource::Reference,
+            inlet_temp_c: REFERENCE_TEMP_C,
+            density_ratio: 1.0,
+            duty_factor: 1.0,
+            target_offset_psi: 0.0,
+        }
+    }
+}
+
+/// Tracks ambient conditions and the corrections they call for
+#[derive(Debug, Clone)]
+pub struct DensityCompensation {
+    config: DensityCompensationConfig,
+    status: DensityCompensationStatus,
+}
+
+impl DensityCompensation {
+    /// Create with reference conditions assumed
+    pub fn new(config: DensityCompensationConfig) -> Self {
+        Self { config, status: DensityCompensationStatus::default() }
+    }
+    
+    /// Apply changed settings; takes effect from the next update
+    pub fn reconfigure(&mut self, config: DensityCompensationConfig) {
+        self.config = config;
+    }
+    
+    /// Update from one cycle's inputs
+    /// 
+    /// `barometric_kpa` is the latest barometric CAN reading and `analog_baro_psi`
+    /// the expansion input sensor's reading (PSI absolute), if any.
+    pub fn update(&mut self, inputs: &SystemInputs, barometric_kpa: Option<f32>, analog_baro_psi: Option<f32>) {
+        let config = &self.config;
+        let (ambient_kpa, ambient_source) = match (
+            barometric_kpa.filter(|kpa| AMBIENT_KPA_RANGE.contains(kpa)),
+            analog_baro_psi.map(|psi| psi / PSI_PER_KPA).filter(|kpa| AMBIENT_KPA_RANGE.contains(kpa)),
+        ) {
+            (Some(kpa), _) => (kpa, AmbientPressureSource::Can),
+            (None, Some(kpa)) => (kpa, AmbientPressureSource::Analog),
+            (None, None) => (config.reference_pressure_kpa, AmbientPressureSource::Reference),
+        };
+        let inlet_temp_c = inputs.intake_air_temp_c.unwrap_or(config.reference_temp_c);
+        
+        let density_ratio = (ambient_kpa / config.reference_pressure_kpa)
+            * (config.reference_temp_c + ZERO_C_K) / (inlet_temp_c + ZERO_C_K);
+        let (duty_factor, target_offset_psi) = if config.enabled {
+            let duty_factor = (1.0 + config.duty_gain * (1.0 - density_ratio))
+                .clamp(config.min_duty_factor, config.max_duty_factor);
+            let target_offset_psi = match config.hold_absolute_pressure {
+                true => ((config.reference_pressure_kpa - ambient_kpa) * PSI_PER_KPA).max(0.0),
+                false => 0.0,
+            };
+            (duty_factor, target_offset_psi)
+        } else {
+            (1.0, 0.0)
+        };
+        
+        self.status = DensityCompensationStatus {
+            ambient_kpa, ambient_source, inlet_temp_c, density_ratio, duty_factor, target_offset_psi,
+        };
+    }
+    
+    /// Learned (reference conditions) duty corrected for the current air (%)
+    pub fn correct_duty(&self, duty_percent: f32) -> f32 {
+        (duty_percent * self.status.duty_factor).clamp(0.0, 100.0)
+    }
+    
+    /// Duty commanded in the current air taken back to reference conditions, for learning (%)
+    pub fn normalize_duty(&self, duty_percent: f32) -> f32 {
+        duty_percent / self.status.duty_factor
+    }
+    
+    /// Boost target raised to hold manifold absolute pressure, up to `ceiling_psi`
+    /// 
+    /// A target already above the ceiling is left alone.
+    pub fn compensate_target(&self, target_psi: f32, ceiling_psi: f32) -> f32 {
+        (target_psi + self.status.target_offset_psi).min(ceiling_psi.max(target_psi))
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &DensityCompensationStatus {
+        &self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(intake_air_temp_c: Option<f32>) -> SystemInputs {
+        SystemInputs { intake_air_temp_c, ..test_support::inputs(0) }
+    }
+    
+    fn enabled() -> DensityCompensationConfig {
+        DensityCompensationConfig { enabled: true, hold_absolute_pressure: true, ..DensityCompensationConfig::default() }
+    }
+    
+    #[test]
+    fn test_reference_conditions_change_nothing() {
+        let mut compensation = DensityCompensation::new(enabled());
+        compensation.update(&inputs(None), None, None);
+        assert_eq!(compensation.status().ambient_source, AmbientPressureSource::Reference);
+        assert!((compensation.correct_duty(40.0) - 40.0).abs() < 1e-3);
+        assert_eq!(compensation.compensate_target(8.0, 12.0), 8.0);
+    }
+    
+    #[test]
+    fn test_altitude_raises_duty_and_target() {
+        let mut compensation = DensityCompensation::new(enabled());
+        // About 1500 m: ~85 kPa
+        compensation.update(&inputs(Some(25.0)), Some(85.0), None);
+        let status = compensation.status().clone();
+        assert_eq!(status.ambient_source, AmbientPressureSource::Can);
+        assert!((status.density_ratio - 0.839).abs() < 0.01);
+        assert!(compensation.correct_duty(40.0) > 43.0);
+        assert!((compensation.normalize_duty(compensation.correct_duty(40.0)) - 40.0).abs() < 1e-3);
+        assert!((status.target_offset_psi - 2.35).abs() < 0.05);
+        
+        // Held to the ceiling
+        assert_eq!(compensation.compensate_target(11.0, 12.0), 12.0);
+    }
+    
+    #[test]
+    fn test_analog_baro_and_cold_air() {
+        let mut compensation = DensityCompensation::new(enabled());
+        // Cold dense air at sea level needs less duty; the factor is clamped
+        compensation.update(&inputs(Some(-50.0)), None, Some(14.7));
+        let status = compensation.status().clone();
+        assert_eq!(status.ambient_source, AmbientPressureSource::Analog);
+        assert!(status.density_ratio > 1.2);
+        assert_eq!(status.duty_factor, DensityCompensationConfig::default().min_duty_factor);
+        assert_eq!(status.target_offset_psi, 0.0);
+        
+        // Disabled reports conditions but corrects nothing
+        let mut compensation = DensityCompensation::new(DensityCompensationConfig::default());
+        compensation.update(&inputs(Some(-50.0)), Some(85.0), None);
+        assert!(compensation.status().density_ratio < 1.2);
+        assert_eq!(compensation.correct_duty(40.0), 40.0);
+    }
+    
+    #[test]
+    fn test_iat_sensor_scale_and_channel() {
+        let config = DensityCompensationConfig::default();
+        assert_eq!(config.intake_air_temp_c(0.5), Some(-40.0));
+        assert_eq!(config.intake_air_temp_c(4.5), Some(150.0));
+        assert_eq!(config.intake_air_temp_c(4.6), Some(150.0));
+        assert_eq!(config.intake_air_temp_c(4.9), None);
+        
+        let shared = DensityCompensationConfig {
+            baro_channel: Some(ANALOG_PRESSURE_INPUTS as u8),
+            iat_channel: Some(ANALOG_PRESSURE_INPUTS as u8),
+            ..DensityCompensationConfig::default()
+        };
+        assert!(shared.validate().is_err());
+        assert!(DensityCompensationConfig { iat_channel: Some(0), ..DensityCompensationConfig::default() }.validate().is_err());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::{ByteOrder, CanSignal, DensityCompensationConfig, RumbleDomeCore, SystemConfig, ANALOG_PRESSURE_INPUTS};
+    use rumbledome_hal::{CanFrame, MockHal};
+    
+    #[test]
+    fn test_intake_air_temperature_from_analog_sensor_or_can() {
+        let channel = ANALOG_PRESSURE_INPUTS as u8;
+        let mut config = SystemConfig {
+            density_compensation: DensityCompensationConfig { enabled: true, iat_channel: Some(channel), ..DensityCompensationConfig::default() },
+            ..SystemConfig::default()
+        };
+        config.can_signals.intake_air_temp = Some(CanSignal {
+            message_id: 0x3A0,
+            start_bit: 0,
+            length: 8,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 1.0,
+            offset: -40.0,
+        });
+        config.validate().unwrap();
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize().unwrap();
+        
+        // 1.5 V is a quarter of the way from -40 to 150 °C
+        core.hal.set_analog_voltage(channel, 1.5);
+        let inputs = core.read_inputs().unwrap();
+        assert_eq!(inputs.intake_air_temp_c, Some(7.5));
+        core.execute_control_cycle_with_inputs(inputs).unwrap();
+        assert_eq!(core.density_compensation.status().inlet_temp_c, 7.5);
+        
+        // Open wiring reads nothing rather than the coldest temperature
+        core.hal.set_analog_voltage(channel, 0.0);
+        assert_eq!(core.read_inputs().unwrap().intake_air_temp_c, None);
+        
+        // A received CAN signal takes precedence
+        core.hal.set_analog_voltage(channel, 1.5);
+        core.hal.receive_can_frame(CanFrame::new(0x3A0, &[75, 0, 0, 0, 0, 0, 0, 0]).unwrap());
+        assert_eq!(core.read_inputs().unwrap().intake_air_temp_c, Some(35.0));
+    }
+}
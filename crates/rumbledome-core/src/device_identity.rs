@@ -0,0 +1,69 @@
+//! Device Identity
+//! 
+//! 🔗 T4-CORE-061: User-Assignable Device Name
+//! Derived From: T4-CORE-048 (Named Configuration Library) + multi-controller installations
+//! AI Traceability: Tell controllers apart when one user owns more than one (e.g. two cars)
+
+use alloc::format;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, FirmwareInfo};
+
+/// Name of a controller that has not been named yet
+pub const DEFAULT_DEVICE_NAME: &str = "RumbleDome";
+
+/// Maximum device name length (characters)
+pub const MAX_DEVICE_NAME_LEN: usize = 24;
+
+/// Default device name for libraries stored before naming existed
+pub fn default_device_name() -> String {
+    String::from(DEFAULT_DEVICE_NAME)
+}
+
+/// Validate a device name
+/// 
+/// The name is matched by the CLI's `--device` option, so it is restricted to
+/// characters that are easy to type on a command line.
+pub fn validate_device_name(name: &str) -> Result<(), CoreError> {
+    if name.is_empty() || name.len() > MAX_DEVICE_NAME_LEN {
+        return Err(CoreError::ConfigurationError(
+            format!("Device name must be 1-{} characters, got \"{}\"", MAX_DEVICE_NAME_LEN, name)
+        ));
+    }
+    
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '_') {
+        return Err(CoreError::ConfigurationError(
+            format!("Device name may only contain letters, digits, space, '-' and '_', got \"{}\"", name)
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Identification advertised to a connecting client
+/// 
+/// 🔗 T4-CORE-062: Device Identification
+/// Derived From: T4-CORE-061 + T4-CORE-056 (Diagnostics Snapshot Bundle)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceIdentity {
+    /// User-assigned device name
+    pub device_name: String,
+    /// Firmware and platform identification
+    pub firmware: FirmwareInfo,
+    /// Name of the active named configuration
+    pub active_configuration: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_device_name_validation() {
+        assert!(validate_device_name(DEFAULT_DEVICE_NAME).is_ok());
+        assert!(validate_device_name("Mustang GT").is_ok());
+        assert!(validate_device_name("").is_err());
+        assert!(validate_device_name("car/1").is_err());
+        assert!(validate_device_name("a name that is far too long").is_err());
+    }
+}
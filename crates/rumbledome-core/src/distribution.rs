@@ -0,0 +1,170 @@
+//! Torque Gap Distribution
+//! 
+//! 🔗 T4-CORE-084: Torque Gap and Boost Error Histograms
+//! Derived From: T4-CORE-055 (Drive Session Summary) + T4-CORE-082 (Tracking Score)
+//! AI Traceability: Show where in the rev range assistance is actually needed and where it fights the ECU
+
+use serde::{Deserialize, Serialize};
+use crate::{RpmBand, RPM_BANDS};
+
+/// Bins per histogram
+pub const HISTOGRAM_BINS: usize = 8;
+
+/// Torque gap bin edges, desired minus actual (Nm)
+/// 
+/// Negative gap is the engine making more torque than the ECU asked for -
+/// boost the ECU is pulling timing or throttle against.
+pub const TORQUE_GAP_EDGES_NM: [f32; HISTOGRAM_BINS - 1] = [-50.0, -20.0, -5.0, 5.0, 20.0, 50.0, 100.0];
+
+/// Boost error bin edges, target minus boost (PSI)
+pub const BOOST_ERROR_EDGES_PSI: [f32; HISTOGRAM_BINS - 1] = [-2.0, -1.0, -0.3, 0.3, 1.0, 2.0, 4.0];
+
+/// Torque gap bin treated as no assistance needed either way (-5 to 5 Nm)
+const GAP_DEADBAND_BIN: usize = 3;
+
+/// Sample counts between fixed bin edges
+/// 
+/// The first bin collects everything below the first edge and the last
+/// everything above the last edge.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    pub counts: [u32; HISTOGRAM_BINS],
+}
+
+impl Histogram {
+    /// Count one sample
+    pub fn add(&mut self, value: f32, edges: &[f32; HISTOGRAM_BINS - 1]) {
+        let bin = edges.iter().take_while(|&&edge| value >= edge).count();
+        self.counts[bin] = self.counts[bin].saturating_add(1);
+    }
+    
+    /// Samples counted
+    pub fn total(&self) -> u32 {
+        self.counts.iter().fold(0u32, |total, &count| total.saturating_add(count))
+    }
+    
+    /// Fraction of samples in bins `bins`
+    pub fn fraction(&self, bins: core::ops::Range<usize>) -> f32 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        self.counts[bins].iter().sum::<u32>() as f32 / total as f32
+    }
+    
+    /// Add another histogram's counts
+    pub fn merge(&mut self, other: &Histogram) {
+        for (count, other) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *count = count.saturating_add(*other);
+        }
+    }
+}
+
+/// Histograms for one RPM band
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BandDistribution {
+    /// Torque gap while armed (control cycles)
+    pub torque_gap: Histogram,
+    /// Boost error while boost is under active control (control cycles)
+    pub boost_error: Histogram,
+}
+
+impl BandDistribution {
+    /// Fraction of armed time the ECU wanted more torque than it got
+    pub fn assist_fraction(&self) -> f32 {
+        self.torque_gap.fraction(GAP_DEADBAND_BIN + 1..HISTOGRAM_BINS)
+    }
+    
+    /// Fraction of armed time the engine made more torque than the ECU wanted
+    pub fn fighting_fraction(&self) -> f32 {
+        self.torque_gap.fraction(0..GAP_DEADBAND_BIN)
+    }
+}
+
+/// Torque gap and boost error distributions per RPM band
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GapDistribution {
+    pub bands: [BandDistribution; RPM_BANDS],
+}
+
+impl GapDistribution {
+    /// Whether anything was recorded
+    pub fn is_empty(&self) -> bool {
+        self.bands.iter().all(|band| band.torque_gap.total() == 0)
+    }
+    
+    /// Record one armed control cycle; `boost_error_psi` is `None` when boost is not under active control
+    pub fn record(&mut self, rpm: u16, torque_gap_nm: f32, boost_error_psi: Option<f32>) {
+        let band = &mut self.bands[RpmBand::index(rpm)];
+        band.torque_gap.add(torque_gap_nm, &TORQUE_GAP_EDGES_NM);
+        if let Some(error) = boost_error_psi {
+            band.boost_error.add(error, &BOOST_ERROR_EDGES_PSI);
+        }
+    }
+    
+    /// Add another distribution (e.g. an earlier session)
+    pub fn merge(&mut self, other: &GapDistribution) {
+        for (band, other) in self.bands.iter_mut().zip(other.bands.iter()) {
+            band.torque_gap.merge(&other.torque_gap);
+            band.boost_error.merge(&other.boost_error);
+        }
+    }
+}
+
+/// Distributions for the current drive and the recent sessions together
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistributionReport {
+    /// This power-on session
+    pub current: GapDistribution,
+    /// Recent sessions merged, including this one
+    pub recent: GapDistribution,
+    /// Sessions merged into `recent`
+    pub sessions: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_histogram_binning() {
+        let mut histogram = Histogram::default();
+        for value in [-100.0, -50.0, 0.0, 4.9, 5.0, 150.0] {
+            histogram.add(value, &TORQUE_GAP_EDGES_NM);
+        }
+        
+        assert_eq!(histogram.counts, [1, 1, 0, 2, 1, 0, 0, 1]);
+        assert_eq!(histogram.total(), 6);
+    }
+    
+    #[test]
+    fn test_distribution_by_rpm_band() {
+        let mut distribution = GapDistribution::default();
+        // Low RPM: ECU wants torque boost cannot yet give; high RPM: too much boost
+        for _ in 0..10 {
+            distribution.record(2500, 40.0, None);
+            distribution.record(6200, -30.0, Some(-1.5));
+        }
+        distribution.record(6200, 0.0, Some(0.0));
+        
+        let low = &distribution.bands[2];
+        let high = &distribution.bands[6];
+        assert_eq!(low.assist_fraction(), 1.0);
+        assert_eq!(low.boost_error.total(), 0);
+        assert!((high.fighting_fraction() - 10.0 / 11.0).abs() < 1e-6);
+        assert_eq!(high.boost_error.counts[1], 10);
+    }
+    
+    #[test]
+    fn test_merge_adds_counts() {
+        let mut first = GapDistribution::default();
+        first.record(3000, 10.0, Some(0.5));
+        let mut merged = GapDistribution::default();
+        merged.merge(&first);
+        merged.merge(&first);
+        
+        assert_eq!(merged.bands[3].torque_gap.total(), 2);
+        assert_eq!(merged.bands[3].boost_error.total(), 2);
+        assert!(GapDistribution::default().is_empty());
+    }
+}
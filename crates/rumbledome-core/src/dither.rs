@@ -0,0 +1,133 @@
+//! PWM Dither
+//! 
+//! 🔗 T4-CORE-078: Solenoid Duty Dither
+//! Derived From: T4-CORE-070 (Actuator Driver Interface) + Hardware.md MAC solenoid characteristics
+//! AI Traceability: Keep the valve spool moving so small duty changes are not swallowed by stiction
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Dither settings
+/// 
+/// A square wave of ±`amplitude_percent` is superimposed on the commanded
+/// duty. The average duty is unchanged; the spool never comes to rest, so it
+/// answers small duty changes at low duty instead of sticking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PwmDitherConfig {
+    /// Superimpose dither on the solenoid duty
+    pub enabled: bool,
+    /// Dither amplitude (± duty %)
+    pub amplitude_percent: f32,
+    /// Dither frequency (Hz) - at most half the inner loop rate
+    pub frequency_hz: u16,
+}
+
+impl Default for PwmDitherConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            amplitude_percent: 2.0,
+            frequency_hz: 10,
+        }
+    }
+}
+
+impl PwmDitherConfig {
+    /// Validate dither settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(0.5..=10.0).contains(&self.amplitude_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Dither amplitude must be 0.5-10.0 %, got {}", self.amplitude_percent)
+            ));
+        }
+        
+        if !(1..=50).contains(&self.frequency_hz) {
+            return Err(CoreError::ConfigurationError(
+                format!("Dither frequency must be 1-50 Hz, got {}", self.frequency_hz)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Dither generator
+/// 
+/// 🔗 T4-CORE-079: Dither Waveform
+/// Derived From: T4-CORE-078
+/// 
+/// Duty is updated once per control cycle, so the waveform advances one step
+/// per output command and its half period is a whole number of cycles.
+#[derive(Debug, Clone)]
+pub struct PwmDither {
+    config: PwmDitherConfig,
+    half_period_cycles: u32,
+    cycle: u32,
+}
+
+impl PwmDither {
+    /// Create generator for the given control cycle rate
+    pub fn new(config: PwmDitherConfig, inner_hz: u16) -> Self {
+        let half_period_cycles = (inner_hz as u32 / (2 * config.frequency_hz.max(1) as u32)).max(1);
+        Self {
+            config,
+            half_period_cycles,
+            cycle: 0,
+        }
+    }
+    
+    /// Commanded duty with dither applied (%)
+    /// 
+    /// A 0% command is left alone - dither must never crack open a failsafe output.
+    pub fn apply(&mut self, duty_percent: f32) -> f32 {
+        if !self.config.enabled || duty_percent <= 0.0 {
+            return duty_percent;
+        }
+        
+        let high = (self.cycle / self.half_period_cycles).is_multiple_of(2);
+        self.cycle = self.cycle.wrapping_add(1);
+        
+        let offset = if high { self.config.amplitude_percent } else { -self.config.amplitude_percent };
+        (duty_percent + offset).clamp(0.0, 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    
+    fn enabled() -> PwmDitherConfig {
+        PwmDitherConfig { enabled: true, ..Default::default() }
+    }
+    
+    #[test]
+    fn test_square_wave_averages_to_command() {
+        // 10 Hz at 100 Hz - 5 cycles high, 5 cycles low
+        let mut dither = PwmDither::new(enabled(), 100);
+        let duties: Vec<f32> = (0..10).map(|_| dither.apply(20.0)).collect();
+        
+        assert_eq!(&duties[..5], &[22.0; 5]);
+        assert_eq!(&duties[5..], &[18.0; 5]);
+        assert_eq!(duties.iter().sum::<f32>() / 10.0, 20.0);
+    }
+    
+    #[test]
+    fn test_failsafe_and_disabled_pass_through() {
+        let mut dither = PwmDither::new(enabled(), 100);
+        assert_eq!(dither.apply(0.0), 0.0);
+        assert_eq!(dither.apply(99.0), 100.0);
+        
+        let mut disabled = PwmDither::new(PwmDitherConfig::default(), 100);
+        assert_eq!(disabled.apply(20.0), 20.0);
+    }
+    
+    #[test]
+    fn test_dither_validation() {
+        assert!(PwmDitherConfig::default().validate().is_ok());
+        assert!(PwmDitherConfig { amplitude_percent: 15.0, ..Default::default() }.validate().is_err());
+        assert!(PwmDitherConfig { frequency_hz: 0, ..Default::default() }.validate().is_err());
+    }
+}
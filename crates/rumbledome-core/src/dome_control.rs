@@ -0,0 +1,427 @@
+//! Cascaded Dome Pressure Control
+//! 
+//! 🔗 T4-CORE-139: Closed-Loop Dome Pressure Inner Loop
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + T4-CORE-105 (Statistical PWM Output Verification)
+//! AI Traceability: Feed pressure sag and solenoid wear move dome pressure long before they move boost - correct them where they happen
+//! 
+//! Without this loop the learned map turns the boost target straight into
+//! duty, so anything between duty and dome pressure (feed pressure, valve
+//! wear, a slow leak) shows up as a boost error the map has to relearn.
+//! With it, control is split in two:
+//! 
+//! - The boost loop turns the target into a dome pressure setpoint: the
+//!   differential (upper minus lower dome) the wastegate needs for that much
+//!   boost over spring, trimmed by a PID term on the boost error. The target
+//!   it works to rises no faster than the boost slew rate. The gains and the
+//!   slew rate are the live-tuned ones, so tuning acts on this loop.
+//! - The dome loop holds that differential with duty: the duty a 4-port
+//!   valve needs for it at the current feed pressure, corrected by a PI term
+//!   on the measured dome differential.
+//! 
+//! The loop needs a single 4-port valve and a feed pressure worth
+//! regulating; otherwise (and while disabled) the learned duty is used as
+//! before. Learning keeps recording the duty actually applied, so the map
+//! stays a valid fallback.
+//! 
+//! The loop is only as good as the dome sensors it closes on. A reading no
+//! 4-port valve can produce from the measured feed and manifold pressure
+//! hands the output back to the learned duty, and the loop may never move
+//! duty further than `max_correction_percent` from the learned duty, so a
+//! sensor that drifts while still reading plausibly cannot run away with it.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::twin_turbo::MIN_FEED_PSI;
+use crate::{CoreError, SystemInputs, TuningParameters};
+
+/// Gap between cycles after which both integrators restart (ms)
+const MAX_CYCLE_GAP_MS: u32 = 100;
+
+/// Sensor tolerance on the dome pressure checks (PSI)
+const DOME_SENSOR_MARGIN_PSI: f32 = 3.0;
+
+/// How long dome readings must stay implausible before the loop drops out (ms)
+/// 
+/// Long enough to ride out the domes venting down after a sudden feed drop.
+const IMPLAUSIBLE_CONFIRM_MS: u32 = 100;
+
+/// Time constant of the boost rate filter feeding the derivative term (s)
+/// 
+/// Cycle-to-cycle boost steps are mostly sensor noise and dome lag.
+const BOOST_RATE_FILTER_S: f32 = 0.1;
+
+/// Cascaded dome pressure control settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DomeControlConfig {
+    /// Regulate dome pressure in an inner loop instead of commanding learned duty
+    pub enabled: bool,
+    /// Dome differential needed per PSI of boost over spring
+    pub dome_psi_per_boost_psi: f32,
+    /// Largest correction the boost loop makes to the dome setpoint (PSI)
+    pub max_boost_trim_psi: f32,
+    /// Dome loop: duty % per PSI of dome error
+    pub dome_kp: f32,
+    /// Dome loop: duty % per PSI·s of dome error
+    pub dome_ki: f32,
+    /// Furthest the loop may move duty from the learned duty (%)
+    #[serde(default = "default_max_correction_percent")]
+    pub max_correction_percent: f32,
+}
+
+fn default_max_correction_percent() -> f32 {
+    25.0
+}
+
+impl Default for DomeControlConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dome_psi_per_boost_psi: 1.0,
+            max_boost_trim_psi: 4.0,
+            dome_kp: 2.0,
+            dome_ki: 20.0,
+            max_correction_percent: default_max_correction_percent(),
+        }
+    }
+}
+
+impl DomeControlConfig {
+    /// Validate gains and limits
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(0.1..=10.0).contains(&self.dome_psi_per_boost_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Dome PSI per boost PSI must be 0.1-10, got {}", self.dome_psi_per_boost_psi)
+            ));
+        }
+        if !(0.0..=20.0).contains(&self.max_boost_trim_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Dome boost trim limit must be 0-20 PSI, got {}", self.max_boost_trim_psi)
+            ));
+        }
+        if !(0.0..=20.0).contains(&self.dome_kp) || !(0.0..=200.0).contains(&self.dome_ki) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Dome pressure loop gains must be kp 0-20 and ki 0-200, got {} and {}", self.dome_kp, self.dome_ki
+            )));
+        }
+        if !(0.0..=100.0).contains(&self.max_correction_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Dome loop correction limit must be 0-100 %, got {}", self.max_correction_percent)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Dome loop state (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DomeControlStatus {
+    /// The dome loop set the duty this cycle
+    pub active: bool,
+    /// Dome differential setpoint, upper minus lower (PSI)
+    pub setpoint_psi: f32,
+    /// Measured dome differential (PSI)
+    pub measured_psi: f32,
+    /// Boost loop correction included in the setpoint (PSI)
+    pub boost_trim_psi: f32,
+    /// Duty the dome loop commanded (%)
+    pub duty_percent: f32,
+    /// Dome sensor readings are implausible - the learned duty is in control
+    pub sensor_fault: bool,
+}
+
+/// Boost loop setting a dome pressure setpoint, dome loop holding it with duty
+#[derive(Debug, Clone)]
+pub struct DomeController {
+    config: DomeControlConfig,
+    boost_integral_psi: f32,
+    dome_integral_percent: f32,
+    last_ms: Option<u32>,
+    /// Boost target after the slew limit (PSI)
+    slewed_target_psi: f32,
+    /// Manifold pressure last cycle, for the derivative term (PSI)
+    last_boost_psi: f32,
+    /// Filtered rate of boost rise (PSI/s)
+    boost_rate_psi_s: f32,
+    /// When the dome readings first went implausible (ms)
+    implausible_since_ms: Option<u32>,
+    status: DomeControlStatus,
+}
+
+impl DomeController {
+    pub fn new(config: DomeControlConfig) -> Self {
+        Self {
+            config,
+            boost_integral_psi: 0.0,
+            dome_integral_percent: 0.0,
+            last_ms: None,
+            slewed_target_psi: 0.0,
+            last_boost_psi: 0.0,
+            boost_rate_psi_s: 0.0,
+            implausible_since_ms: None,
+            status: DomeControlStatus::default(),
+        }
+    }
+    
+    /// Apply changed settings; both integrators restart
+    pub fn reconfigure(&mut self, config: DomeControlConfig) {
+        *self = Self::new(config);
+    }
+    
+    /// Duty that holds the dome pressure this boost target needs (%)
+    /// 
+    /// `None` when the loop is disabled, the output is not a single 4-port
+    /// valve, the feed pressure is too low to regulate, or the dome sensors
+    /// have read implausibly for `IMPLAUSIBLE_CONFIRM_MS` - the caller then
+    /// commands the learned duty. Otherwise the duty stays within
+    /// `max_correction_percent` of `learned_duty`. The boost loop's gains
+    /// and slew rate come from `tuning`.
+    pub fn update(&mut self, target_psi: f32, spring_psi: f32, learned_duty: f32, inputs: &SystemInputs, single_valve: bool, tuning: &TuningParameters) -> Option<f32> {
+        let feed_psi = inputs.dome_input_pressure;
+        if !self.config.enabled || !single_valve || feed_psi < MIN_FEED_PSI {
+            self.reset();
+            self.implausible_since_ms = None;
+            return None;
+        }
+        
+        if Self::domes_plausible(inputs) {
+            self.implausible_since_ms = None;
+        } else {
+            let since_ms = *self.implausible_since_ms.get_or_insert(inputs.timestamp_ms);
+            if inputs.timestamp_ms.wrapping_sub(since_ms) >= IMPLAUSIBLE_CONFIRM_MS {
+                self.reset();
+                self.status.sensor_fault = true;
+                return None;
+            }
+        }
+        
+        // Integrate only over an unbroken run of cycles
+        let dt_s = match self.last_ms {
+            Some(last_ms) if inputs.timestamp_ms.wrapping_sub(last_ms) <= MAX_CYCLE_GAP_MS => {
+                inputs.timestamp_ms.wrapping_sub(last_ms) as f32 / 1000.0
+            },
+            _ => {
+                self.boost_integral_psi = 0.0;
+                self.dome_integral_percent = 0.0;
+                // Climb from the boost there is now - spring pressure at least
+                self.slewed_target_psi = inputs.manifold_pressure.max(spring_psi);
+                self.last_boost_psi = inputs.manifold_pressure;
+                self.boost_rate_psi_s = 0.0;
+                0.0
+            },
+        };
+        self.last_ms = Some(inputs.timestamp_ms);
+        
+        // The target rises at the slew rate; a lower target takes effect at once
+        let target_psi = target_psi.min(self.slewed_target_psi + tuning.boost_slew_rate * dt_s);
+        self.slewed_target_psi = target_psi;
+        if dt_s > 0.0 {
+            let rate = (inputs.manifold_pressure - self.last_boost_psi) / dt_s;
+            self.boost_rate_psi_s += (rate - self.boost_rate_psi_s) * dt_s / (BOOST_RATE_FILTER_S + dt_s);
+        }
+        self.last_boost_psi = inputs.manifold_pressure;
+        
+        // Boost loop: dome differential for the target, trimmed on boost error
+        let limit = self.config.max_boost_trim_psi;
+        let boost_error = target_psi - inputs.manifold_pressure;
+        self.boost_integral_psi = (self.boost_integral_psi + tuning.pid_ki * boost_error * dt_s).clamp(-limit, limit);
+        let boost_trim = (tuning.pid_kp * boost_error + self.boost_integral_psi - tuning.pid_kd * self.boost_rate_psi_s).clamp(-limit, limit);
+        let setpoint = ((target_psi - spring_psi) * self.config.dome_psi_per_boost_psi + boost_trim).clamp(-feed_psi, feed_psi);
+        
+        // Dome loop: a 4-port valve at duty d splits feed as d on the upper dome, 1 - d on the lower
+        let measured = inputs.upper_dome_pressure - inputs.lower_dome_pressure;
+        let dome_error = setpoint - measured;
+        let feedforward = 50.0 + 50.0 * setpoint / feed_psi;
+        let unclamped = feedforward + self.config.dome_kp * dome_error + self.dome_integral_percent;
+        let band = self.config.max_correction_percent;
+        let min_duty = (learned_duty - band).clamp(0.0, 100.0);
+        let max_duty = (learned_duty + band).clamp(0.0, 100.0);
+        let duty = unclamped.clamp(min_duty, max_duty);
+        // Hold the integrator while the duty is pinned and the error pushes further
+        let pinned = (unclamped > max_duty && dome_error > 0.0) || (unclamped < min_duty && dome_error < 0.0);
+        if !pinned {
+            self.dome_integral_percent = (self.dome_integral_percent + self.config.dome_ki * dome_error * dt_s).clamp(-50.0, 50.0);
+        }
+        
+        self.status = DomeControlStatus {
+            active: true,
+            setpoint_psi: setpoint,
+            measured_psi: measured,
+            boost_trim_psi: boost_trim,
+            duty_percent: duty,
+            sensor_fault: false,
+        };
+        Some(duty)
+    }
+    
+    /// Whether the dome readings are ones a 4-port valve can produce
+    /// 
+    /// Each dome sees at most the supply (feed, or manifold pressure when the
+    /// feed is taken from boost) and never meaningfully less than ambient,
+    /// and the valve splits that one supply between the two domes.
+    fn domes_plausible(inputs: &SystemInputs) -> bool {
+        let supply = inputs.dome_input_pressure.max(inputs.manifold_pressure);
+        let range = -DOME_SENSOR_MARGIN_PSI..=supply + DOME_SENSOR_MARGIN_PSI;
+        range.contains(&inputs.upper_dome_pressure)
+            && range.contains(&inputs.lower_dome_pressure)
+            && inputs.upper_dome_pressure + inputs.lower_dome_pressure <= supply + DOME_SENSOR_MARGIN_PSI
+    }
+    
+    /// Drop the integrators (output handed back to the learned duty)
+    pub fn reset(&mut self) {
+        self.boost_integral_psi = 0.0;
+        self.dome_integral_percent = 0.0;
+        self.last_ms = None;
+        self.status = DomeControlStatus::default();
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &DomeControlStatus {
+        &self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    /// 4-port valve with a dome lag and a wastegate giving 1 PSI of boost per dome PSI over spring
+    struct Plant {
+        feed_psi: f32,
+        /// Share of the duty the valve actually passes (1.0 = healthy)
+        valve_gain: f32,
+        upper: f32,
+        lower: f32,
+    }
+    
+    impl Plant {
+        fn step(&mut self, duty: f32) {
+            let share = (0.5 + (duty / 100.0 - 0.5) * self.valve_gain).clamp(0.0, 1.0);
+            self.upper += (share * self.feed_psi - self.upper) * 0.2;
+            self.lower += ((1.0 - share) * self.feed_psi - self.lower) * 0.2;
+        }
+        
+        fn inputs(&self, timestamp_ms: u32) -> SystemInputs {
+            SystemInputs {
+                manifold_pressure: 5.0 + (self.upper - self.lower),
+                dome_input_pressure: self.feed_psi,
+                upper_dome_pressure: self.upper,
+                lower_dome_pressure: self.lower,
+                ..test_support::inputs(timestamp_ms)
+            }
+        }
+    }
+    
+    /// Learned duty for 12 PSI on the healthy plant
+    const LEARNED_DUTY: f32 = 58.75;
+    
+    fn run(controller: &mut DomeController, plant: &mut Plant, start_ms: u32, cycles: u32) -> f32 {
+        let mut duty = 0.0;
+        for cycle in 0..cycles {
+            let inputs = plant.inputs(start_ms + cycle * 10);
+            duty = controller.update(12.0, 5.0, LEARNED_DUTY, &inputs, true, &TuningParameters::default()).unwrap();
+            plant.step(duty);
+        }
+        duty
+    }
+    
+    #[test]
+    fn test_holds_boost_through_feed_sag_and_valve_wear() {
+        let mut controller = DomeController::new(DomeControlConfig { enabled: true, ..DomeControlConfig::default() });
+        let mut plant = Plant { feed_psi: 40.0, valve_gain: 1.0, upper: 0.0, lower: 0.0 };
+        
+        run(&mut controller, &mut plant, 0, 600);
+        assert!((plant.inputs(0).manifold_pressure - 12.0).abs() < 0.2);
+        let healthy_duty = controller.status().duty_percent;
+        
+        // Feed sags and the valve passes only 80 % of its duty - more duty holds the same boost
+        plant.feed_psi = 25.0;
+        plant.valve_gain = 0.8;
+        run(&mut controller, &mut plant, 6_000, 500);
+        assert!((plant.inputs(0).manifold_pressure - 12.0).abs() < 0.2);
+        assert!(controller.status().duty_percent > healthy_duty + 5.0);
+        assert!((controller.status().setpoint_psi - controller.status().measured_psi).abs() < 0.2);
+    }
+    
+    #[test]
+    fn test_target_climbs_at_tuned_slew_rate() {
+        let config = DomeControlConfig { enabled: true, ..DomeControlConfig::default() };
+        let boost_after = |boost_slew_rate: f32| {
+            let tuning = TuningParameters { boost_slew_rate, ..TuningParameters::default() };
+            let mut controller = DomeController::new(config.clone());
+            let mut plant = Plant { feed_psi: 40.0, valve_gain: 1.0, upper: 0.0, lower: 0.0 };
+            for cycle in 0..100 {
+                let duty = controller.update(12.0, 5.0, LEARNED_DUTY, &plant.inputs(cycle * 10), true, &tuning).unwrap();
+                plant.step(duty);
+            }
+            plant.inputs(0).manifold_pressure
+        };
+        
+        // One second in, the default 2 PSI/s has the target near 7 PSI; 10 PSI/s has it at 12
+        let gentle = boost_after(2.0);
+        assert!(gentle < 8.0);
+        assert!(boost_after(10.0) > gentle + 3.0);
+    }
+    
+    #[test]
+    fn test_falls_back_without_feed_or_single_valve() {
+        let mut controller = DomeController::new(DomeControlConfig { enabled: true, ..DomeControlConfig::default() });
+        let plant = Plant { feed_psi: 40.0, valve_gain: 1.0, upper: 0.0, lower: 0.0 };
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &plant.inputs(0), true, &TuningParameters::default()).is_some());
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &plant.inputs(10), false, &TuningParameters::default()).is_none());
+        assert!(!controller.status().active);
+        
+        let starved = Plant { feed_psi: 3.0, ..plant };
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &starved.inputs(20), true, &TuningParameters::default()).is_none());
+        assert!(DomeController::new(DomeControlConfig::default()).update(12.0, 5.0, LEARNED_DUTY, &starved.inputs(0), true, &TuningParameters::default()).is_none());
+    }
+    
+    #[test]
+    fn test_failed_dome_sensor_hands_back_to_learned_duty() {
+        let mut controller = DomeController::new(DomeControlConfig { enabled: true, ..DomeControlConfig::default() });
+        let mut plant = Plant { feed_psi: 40.0, valve_gain: 1.0, upper: 0.0, lower: 0.0 };
+        run(&mut controller, &mut plant, 0, 300);
+        
+        // Upper dome sensor shorts high - above anything the feed can supply
+        let mut shorted = plant.inputs(3_000);
+        shorted.upper_dome_pressure = 95.0;
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &shorted, true, &TuningParameters::default()).is_some());
+        shorted.timestamp_ms = 3_100;
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &shorted, true, &TuningParameters::default()).is_none());
+        assert!(controller.status().sensor_fault);
+        assert!(!controller.status().active);
+        
+        // Both domes reading more than the valve can split between them
+        let mut stuck = plant.inputs(3_200);
+        stuck.lower_dome_pressure = stuck.upper_dome_pressure + 10.0;
+        assert!(DomeController::domes_plausible(&plant.inputs(0)));
+        assert!(!DomeController::domes_plausible(&stuck));
+        
+        // Sensor recovers - the loop takes over again
+        assert!(controller.update(12.0, 5.0, LEARNED_DUTY, &plant.inputs(3_300), true, &TuningParameters::default()).is_some());
+        assert!(!controller.status().sensor_fault);
+    }
+    
+    #[test]
+    fn test_drifted_sensor_cannot_move_duty_past_band() {
+        let mut controller = DomeController::new(DomeControlConfig { enabled: true, ..DomeControlConfig::default() });
+        let plant = Plant { feed_psi: 40.0, valve_gain: 1.0, upper: 0.0, lower: 0.0 };
+        
+        // Upper dome reads zero but still plausibly - the loop winds up as far as the band allows
+        let mut duty = 0.0;
+        for cycle in 0..300 {
+            duty = controller.update(12.0, 5.0, LEARNED_DUTY, &plant.inputs(cycle * 10), true, &TuningParameters::default()).unwrap();
+        }
+        assert!((duty - (LEARNED_DUTY + 25.0)).abs() < 0.01);
+        assert!(!controller.status().sensor_fault);
+    }
+    
+    #[test]
+    fn test_gain_limits_validated() {
+        assert!(DomeControlConfig::default().validate().is_ok());
+        assert!(DomeControlConfig { dome_ki: 500.0, ..DomeControlConfig::default() }.validate().is_err());
+        assert!(DomeControlConfig { dome_psi_per_boost_psi: 0.0, ..DomeControlConfig::default() }.validate().is_err());
+        assert!(DomeControlConfig { max_correction_percent: 150.0, ..DomeControlConfig::default() }.validate().is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Wastegate Duty Ceiling Learning
+//! 
+//! 🔗 T4-CORE-088: Per-RPM Duty Ceiling (Anti-Saturation Map)
+//! Derived From: LearnedData.md duty cycle calibration + T4-CORE-084 (Torque Gap and Boost Error Histograms)
+//! AI Traceability: Stop commanding duty the wastegate cannot use - no integral windup, no pointless 100% before spool
+//! 
+//! Once the wastegate is fully shut, more duty makes no more boost. At low
+//! RPM before spool that point can be a small duty; near redline it is
+//! usually close to 100%. Wide-open-throttle boost (from the torque request
+//! when no throttle signal is mapped) is learned per duty bin in each RPM
+//! band, and the lowest duty past which boost stops rising (plus one bin of
+//! margin) becomes the band's ceiling. The margin bin keeps being sampled, so
+//! a ceiling that is too low rises again on its own.
+
+use serde::{Deserialize, Serialize};
+use crate::{RpmBand, SystemInputs, RPM_BANDS};
+
+/// Duty bins per RPM band
+pub const DUTY_BINS: usize = 10;
+
+/// Width of one duty bin (%)
+const DUTY_BIN_WIDTH: f32 = 100.0 / DUTY_BINS as f32;
+
+/// Boost gain over a higher duty bin that still counts as no gain (PSI)
+pub const PLATEAU_PSI: f32 = 0.3;
+
+/// Samples before a duty bin is trusted
+const MIN_BIN_SAMPLES: u16 = 20;
+
+/// Throttle above which boost is limited by the wastegate, not the throttle plate (%)
+const WOT_THROTTLE_PERCENT: f32 = 90.0;
+
+/// Boost rate below which boost has settled for the commanded duty (PSI/s)
+const STEADY_RATE_PSI_PER_S: f32 = 3.0;
+
+/// Boost filter weight of each new sample
+const BOOST_FILTER_ALPHA: f32 = 0.1;
+
+/// Settled boost seen at one duty range
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DutyBin {
+    /// Filtered boost (PSI)
+    pub boost_psi: f32,
+    /// Samples taken (saturating)
+    pub samples: u16,
+}
+
+/// Duty-to-boost response for one RPM band
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BandResponse {
+    pub bins: [DutyBin; DUTY_BINS],
+}
+
+impl BandResponse {
+    fn add(&mut self, duty_percent: f32, boost_psi: f32) {
+        let index = ((duty_percent / DUTY_BIN_WIDTH) as usize).min(DUTY_BINS - 1);
+        let bin = &mut self.bins[index];
+        bin.boost_psi = if bin.samples == 0 {
+            boost_psi
+        } else {
+            bin.boost_psi + (boost_psi - bin.boost_psi) * BOOST_FILTER_ALPHA
+        };
+        bin.samples = bin.samples.saturating_add(1);
+    }
+    
+    /// Learned ceiling (%), or `None` while more duty has not been shown to be useless
+    pub fn ceiling_percent(&self) -> Option<f32> {
+        let trusted = |index: usize| self.bins[index].samples >= MIN_BIN_SAMPLES;
+        
+        for index in (0..DUTY_BINS).filter(|&index| trusted(index)) {
+            let boost = self.bins[index].boost_psi;
+            let mut higher = (index + 1..DUTY_BINS).filter(|&higher| trusted(higher)).peekable();
+            higher.peek()?;
+            
+            if higher.all(|higher| self.bins[higher].boost_psi <= boost + PLATEAU_PSI) {
+                let ceiling = (index + 2) as f32 * DUTY_BIN_WIDTH;
+                return (ceiling < 100.0).then_some(ceiling);
+            }
+        }
+        None
+    }
+}
+
+/// Learned duty response (persisted by the storage layer)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DutyCeilingRecord {
+    pub bands: [BandResponse; RPM_BANDS],
+}
+
+/// Duty ceiling status for diagnostics
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DutyCeilingStatus {
+    /// Ceiling per RPM band (%), `None` where nothing has been learned
+    pub ceilings_percent: [Option<f32>; RPM_BANDS],
+    /// Armed cycles whose duty command was cut to the ceiling
+    pub limited_cycles: u32,
+}
+
+/// Learns and applies the per-RPM duty ceiling
+/// 
+/// 🔗 T4-CORE-089: Duty Ceiling Enforcement
+/// Derived From: T4-CORE-088
+/// 
+/// Level 2 integrators must treat `ceiling_percent` as their saturation limit,
+/// the same as 100%, so they stop accumulating once the wastegate is shut.
+#[derive(Debug, Clone, Default)]
+pub struct DutyCeilingLearner {
+    record: DutyCeilingRecord,
+    last_boost: Option<(u32, f32)>,
+    limited_cycles: u32,
+}
+
+impl DutyCeilingLearner {
+    /// Create learner with nothing learned
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume from persisted learning
+    pub fn restore(&mut self, record: DutyCeilingRecord) {
+        self.record = record;
+    }
+    
+    /// Learning to persist
+    pub fn record(&self) -> &DutyCeilingRecord {
+        &self.record
+    }
+    
+    /// Forget everything learned (e.g. after wastegate or spring changes)
+    pub fn reset(&mut self) {
+        self.record = DutyCeilingRecord::default();
+    }
+    
+    /// Duty ceiling at `rpm` (%), if learned
+    pub fn ceiling_percent(&self, rpm: u16) -> Option<f32> {
+        self.record.bands[RpmBand::index(rpm)].ceiling_percent()
+    }
+    
+    /// Cut a duty command to the ceiling at `rpm`
+    pub fn limit(&mut self, rpm: u16, duty_percent: f32) -> f32 {
+        match self.ceiling_percent(rpm) {
+            Some(ceiling) if duty_percent > ceiling => {
+                self.limited_cycles = self.limited_cycles.saturating_add(1);
+                ceiling
+            },
+            _ => duty_percent,
+        }
+    }
+    
+    /// Learn from one armed control cycle and the duty commanded in it
+    pub fn observe(&mut self, inputs: &SystemInputs, duty_percent: f32) {
+        let boost = inputs.manifold_pressure;
+        let previous = self.last_boost.replace((inputs.timestamp_ms, boost));
+        
+        let Some((last_ms, last_boost)) = previous else {
+            return;
+        };
+        let elapsed_ms = inputs.timestamp_ms.wrapping_sub(last_ms);
+        if elapsed_ms == 0 || !inputs.wide_open(WOT_THROTTLE_PERCENT) {
+            return;
+        }
+        
+        let rate = (boost - last_boost).abs() * 1000.0 / elapsed_ms as f32;
+        if rate <= STEADY_RATE_PSI_PER_S {
+            self.record.bands[RpmBand::index(inputs.rpm)].add(duty_percent, boost);
+        }
+    }
+    
+    /// Drop rate tracking while boost is not under active control
+    pub fn cancel(&mut self) {
+        self.last_boost = None;
+    }
+    
+    /// Diagnostics snapshot
+    pub fn status(&self) -> DutyCeilingStatus {
+        DutyCeilingStatus {
+            ceilings_percent: core::array::from_fn(|band| self.record.bands[band].ceiling_percent()),
+            limited_cycles: self.limited_cycles,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn wot(rpm: u16, boost_psi: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs {
+            rpm,
+            desired_torque: 450.0,
+            actual_torque: 400.0,
+            manifold_pressure: boost_psi,
+            throttle_position: Some(100.0),
+            ..test_support::inputs(timestamp_ms)
+        }
+    }
+    
+    /// Hold each duty long enough to fill its bin
+    fn sweep(learner: &mut DutyCeilingLearner, rpm: u16, response: impl Fn(f32) -> f32) {
+        let mut t = 0;
+        for duty in [5.0, 15.0, 25.0, 35.0, 45.0, 55.0, 65.0, 75.0, 85.0, 95.0] {
+            for _ in 0..30 {
+                learner.observe(&wot(rpm, response(duty), t), duty);
+                t += 10;
+            }
+        }
+    }
+    
+    #[test]
+    fn test_pre_spool_ceiling_is_low() {
+        let mut learner = DutyCeilingLearner::new();
+        // 2500 RPM: boost stops rising above 30% duty; 6000 RPM: every bit of duty helps
+        sweep(&mut learner, 2500, |duty| duty.min(30.0) * 0.1);
+        sweep(&mut learner, 6000, |duty| duty * 0.15);
+        
+        assert_eq!(learner.ceiling_percent(2500), Some(50.0));
+        assert_eq!(learner.ceiling_percent(6000), None);
+        assert_eq!(learner.ceiling_percent(4000), None);
+        
+        assert_eq!(learner.limit(2500, 100.0), 50.0);
+        assert_eq!(learner.limit(2500, 20.0), 20.0);
+        assert_eq!(learner.limit(6000, 100.0), 100.0);
+        assert_eq!(learner.status().limited_cycles, 1);
+    }
+    
+    #[test]
+    fn test_part_throttle_and_transients_are_ignored() {
+        let mut learner = DutyCeilingLearner::new();
+        for t in 0..300 {
+            let mut inputs = wot(3000, 4.0, t * 10);
+            inputs.throttle_position = Some(40.0);
+            learner.observe(&inputs, if t < 150 { 20.0 } else { 90.0 });
+        }
+        // Boost jumping between samples never settles
+        for t in 0..300 {
+            learner.observe(&wot(3000, if t % 2 == 0 { 2.0 } else { 6.0 }, 3000 + t * 10), 90.0);
+        }
+        
+        assert_eq!(learner.record(), &DutyCeilingRecord::default());
+    }
+    
+    #[test]
+    fn test_torque_request_stands_in_for_throttle() {
+        let mut learner = DutyCeilingLearner::new();
+        // Cruising without a throttle signal - the ECU asks for little more than it gets
+        for t in 0..300 {
+            let inputs = SystemInputs { throttle_position: None, desired_torque: 320.0, actual_torque: 300.0, ..wot(3000, 4.0, t * 10) };
+            learner.observe(&inputs, 50.0);
+        }
+        assert_eq!(learner.record(), &DutyCeilingRecord::default());
+        
+        // Asking for far more than it gets is a wide-open pull
+        for t in 0..300 {
+            let inputs = SystemInputs { throttle_position: None, desired_torque: 450.0, actual_torque: 300.0, ..wot(3000, 4.0, 3000 + t * 10) };
+            learner.observe(&inputs, 50.0);
+        }
+        assert_ne!(learner.record(), &DutyCeilingRecord::default());
+    }
+    
+    #[test]
+    fn test_ceiling_rises_when_margin_bin_gains() {
+        let mut response = BandResponse::default();
+        for _ in 0..MIN_BIN_SAMPLES {
+            response.add(25.0, 3.0);
+            response.add(85.0, 3.1);
+        }
+        assert_eq!(response.ceiling_percent(), Some(40.0));
+        
+        // Samples in the margin bin show more boost - the plateau was not real
+        for _ in 0..100 {
+            response.add(35.0, 5.0);
+        }
+        assert_eq!(response.ceiling_percent(), Some(50.0));
+    }
+}
@@ -0,0 +1,341 @@
+//! Error Code Registry
+//! 
+//! 🔗 T4-CORE-118: Shared Error Code Registry
+//! Derived From: T4-CORE-021 (Critical Fault Classification) + T4-CORE-085 (Localized Display String Table)
+//! AI Traceability: One identifier per failure, whether it shows on the gauge, in the CLI or in a log - searchable in the docs and forums
+//! 
+//! Every HAL error, core error, fault and protocol error maps to exactly one
+//! entry here. An identifier is a source letter and a two-digit number - `H`
+//! HAL, `C` core, `F` fault, `P` protocol - so fault identifiers are the codes
+//! the gauge has always shown ("F04"). Numbers are never reused or renumbered;
+//! a new error takes the next free number in its source.
+//! 
+//! The key is a stable dotted name for the user-facing short text, kept apart
+//! from the wording so the text can change or be translated without breaking
+//! anything that matches on it. Severity is the worst the error can be - a
+//! fault whose criticality depends on the sensor is registered as critical.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use rumbledome_hal::HalError;
+use crate::{CoreError, FaultCode};
+
+/// Where an error is raised - the identifier's letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSource {
+    Hal,
+    Core,
+    Fault,
+    Protocol,
+}
+
+impl ErrorSource {
+    /// Identifier prefix
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Hal => 'H',
+            Self::Core => 'C',
+            Self::Fault => 'F',
+            Self::Protocol => 'P',
+        }
+    }
+}
+
+/// How bad it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Request refused, nothing wrong with the system
+    Info,
+    /// Degraded operation or a failed operation worth looking at
+    Warning,
+    /// Boost control disabled for safety
+    Critical,
+}
+
+/// Part of the system the error concerns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+    Hardware,
+    Sensors,
+    Can,
+    Storage,
+    Control,
+    Safety,
+    Configuration,
+    Learning,
+    Communication,
+}
+
+/// One registered error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub source: ErrorSource,
+    /// Stable number within the source
+    pub number: u8,
+    pub severity: Severity,
+    pub subsystem: Subsystem,
+    /// Short text key (e.g. "fault.can_lost")
+    pub key: &'static str,
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:02}", self.source.letter(), self.number)
+    }
+}
+
+const fn code(source: ErrorSource, number: u8, severity: Severity, subsystem: Subsystem, key: &'static str) -> ErrorCode {
+    ErrorCode { source, number, severity, subsystem, key }
+}
+
+use ErrorSource::{Core, Fault, Hal, Protocol};
+use Severity::{Critical, Info, Warning};
+
+impl ErrorCode {
+    pub const HAL_INITIALIZATION_FAILED: ErrorCode = code(Hal, 1, Critical, Subsystem::Hardware, "hal.initialization_failed");
+    pub const HAL_TIMEOUT: ErrorCode = code(Hal, 2, Warning, Subsystem::Hardware, "hal.timeout");
+    pub const HAL_INVALID_PARAMETER: ErrorCode = code(Hal, 3, Warning, Subsystem::Hardware, "hal.invalid_parameter");
+    pub const HAL_HARDWARE_FAULT: ErrorCode = code(Hal, 4, Critical, Subsystem::Hardware, "hal.hardware_fault");
+    pub const HAL_NOT_SUPPORTED: ErrorCode = code(Hal, 5, Warning, Subsystem::Hardware, "hal.not_supported");
+    pub const HAL_COMMUNICATION: ErrorCode = code(Hal, 6, Warning, Subsystem::Communication, "hal.communication");
+    
+    pub const CONFIGURATION: ErrorCode = code(Core, 1, Warning, Subsystem::Configuration, "core.configuration");
+    pub const SAFETY_VIOLATION: ErrorCode = code(Core, 2, Critical, Subsystem::Safety, "core.safety_violation");
+    pub const LEARNING: ErrorCode = code(Core, 3, Warning, Subsystem::Learning, "core.learning");
+    pub const CAN: ErrorCode = code(Core, 4, Warning, Subsystem::Can, "core.can");
+    pub const INVALID_STATE: ErrorCode = code(Core, 5, Info, Subsystem::Control, "core.invalid_state");
+    pub const CALIBRATION: ErrorCode = code(Core, 6, Warning, Subsystem::Learning, "core.calibration");
+    pub const SENSOR: ErrorCode = code(Core, 7, Warning, Subsystem::Sensors, "core.sensor");
+    pub const STORAGE: ErrorCode = code(Core, 8, Warning, Subsystem::Storage, "core.storage");
+    
+    pub const FAULT_SELF_TEST: ErrorCode = code(Fault, 1, Critical, Subsystem::Hardware, "fault.self_test");
+    pub const FAULT_PWM_HARDWARE: ErrorCode = code(Fault, 2, Critical, Subsystem::Hardware, "fault.pwm_hardware");
+    pub const FAULT_PRESSURE_SENSOR: ErrorCode = code(Fault, 3, Critical, Subsystem::Sensors, "fault.pressure_sensor");
+    pub const FAULT_CAN_LOST: ErrorCode = code(Fault, 4, Critical, Subsystem::Can, "fault.can_lost");
+    pub const FAULT_STORAGE: ErrorCode = code(Fault, 5, Critical, Subsystem::Storage, "fault.storage");
+    pub const FAULT_OVERBOOST: ErrorCode = code(Fault, 6, Critical, Subsystem::Safety, "fault.overboost");
+    pub const FAULT_PNEUMATIC: ErrorCode = code(Fault, 7, Critical, Subsystem::Control, "fault.pneumatic");
+    pub const FAULT_RESPONSE_TOO_SLOW: ErrorCode = code(Fault, 8, Critical, Subsystem::Safety, "fault.response_too_slow");
+    pub const FAULT_INVALID_CONFIGURATION: ErrorCode = code(Fault, 9, Warning, Subsystem::Configuration, "fault.invalid_configuration");
+    pub const FAULT_CALIBRATION_CORRUPTED: ErrorCode = code(Fault, 10, Warning, Subsystem::Learning, "fault.calibration_corrupted");
+    pub const FAULT_TORQUE_SIGNALS: ErrorCode = code(Fault, 11, Warning, Subsystem::Can, "fault.torque_signals");
+    pub const FAULT_IMPLAUSIBLE_SENSOR: ErrorCode = code(Fault, 12, Warning, Subsystem::Sensors, "fault.implausible_sensor");
+    pub const FAULT_INPUT_MISMATCH: ErrorCode = code(Fault, 13, Critical, Subsystem::Sensors, "fault.input_mismatch");
+    pub const FAULT_CALIBRATION_FAILED: ErrorCode = code(Fault, 14, Warning, Subsystem::Learning, "fault.calibration_failed");
+    pub const FAULT_LEARNING_INCONSISTENT: ErrorCode = code(Fault, 15, Warning, Subsystem::Learning, "fault.learning_inconsistent");
+    pub const FAULT_CONTROL: ErrorCode = code(Fault, 16, Critical, Subsystem::Control, "fault.control");
+    pub const FAULT_SAFETY_LIMITS: ErrorCode = code(Fault, 17, Critical, Subsystem::Storage, "fault.safety_limits");
+    
+    pub const NOT_PERMITTED: ErrorCode = code(Protocol, 1, Info, Subsystem::Communication, "protocol.not_permitted");
+    pub const NESTED_SEQUENCE: ErrorCode = code(Protocol, 2, Warning, Subsystem::Communication, "protocol.nested_sequence");
+    pub const STALE_SEQUENCE: ErrorCode = code(Protocol, 3, Info, Subsystem::Communication, "protocol.stale_sequence");
+    pub const NO_PENDING_CONFIRMATION: ErrorCode = code(Protocol, 4, Info, Subsystem::Communication, "protocol.no_pending_confirmation");
+    pub const NOT_A_REQUEST: ErrorCode = code(Protocol, 5, Warning, Subsystem::Communication, "protocol.not_a_request");
+    
+    /// Message for logs and error responses: identifier, then detail
+    pub fn message(&self, detail: &str) -> String {
+        format!("{}: {}", self, detail)
+    }
+    
+    /// Registered code for an identifier such as "F04" (case-insensitive)
+    pub fn lookup(id: &str) -> Option<ErrorCode> {
+        ERROR_CODES.iter().copied().find(|code| code.to_string().eq_ignore_ascii_case(id.trim()))
+    }
+    
+    /// Registered code at the start of a message made by `message()`
+    pub fn of_message(message: &str) -> Option<ErrorCode> {
+        Self::lookup(message.split(':').next()?)
+    }
+}
+
+/// Every registered error
+pub const ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode::HAL_INITIALIZATION_FAILED,
+    ErrorCode::HAL_TIMEOUT,
+    ErrorCode::HAL_INVALID_PARAMETER,
+    ErrorCode::HAL_HARDWARE_FAULT,
+    ErrorCode::HAL_NOT_SUPPORTED,
+    ErrorCode::HAL_COMMUNICATION,
+    ErrorCode::CONFIGURATION,
+    ErrorCode::SAFETY_VIOLATION,
+    ErrorCode::LEARNING,
+    ErrorCode::CAN,
+    ErrorCode::INVALID_STATE,
+    ErrorCode::CALIBRATION,
+    ErrorCode::SENSOR,
+    ErrorCode::STORAGE,
+    ErrorCode::FAULT_SELF_TEST,
+    ErrorCode::FAULT_PWM_HARDWARE,
+    ErrorCode::FAULT_PRESSURE_SENSOR,
+    ErrorCode::FAULT_CAN_LOST,
+    ErrorCode::FAULT_STORAGE,
+    ErrorCode::FAULT_OVERBOOST,
+    ErrorCode::FAULT_PNEUMATIC,
+    ErrorCode::FAULT_RESPONSE_TOO_SLOW,
+    ErrorCode::FAULT_INVALID_CONFIGURATION,
+    ErrorCode::FAULT_CALIBRATION_CORRUPTED,
+    ErrorCode::FAULT_TORQUE_SIGNALS,
+    ErrorCode::FAULT_IMPLAUSIBLE_SENSOR,
+    ErrorCode::FAULT_INPUT_MISMATCH,
+    ErrorCode::FAULT_CALIBRATION_FAILED,
+    ErrorCode::FAULT_LEARNING_INCONSISTENT,
+    ErrorCode::FAULT_CONTROL,
+    ErrorCode::FAULT_SAFETY_LIMITS,
+    ErrorCode::NOT_PERMITTED,
+    ErrorCode::NESTED_SEQUENCE,
+    ErrorCode::STALE_SEQUENCE,
+    ErrorCode::NO_PENDING_CONFIRMATION,
+    ErrorCode::NOT_A_REQUEST,
+];
+
+/// Errors that map into the registry
+pub trait ErrorCoded {
+    /// Registered code
+    fn error_code(&self) -> ErrorCode;
+    
+    /// Detail text, without the identifier
+    fn detail(&self) -> String;
+    
+    /// Identifier and detail, as shown in logs and error responses
+    fn coded_message(&self) -> String {
+        self.error_code().message(&self.detail())
+    }
+}
+
+impl ErrorCoded for HalError {
+    fn error_code(&self) -> ErrorCode {
+        match self {
+            HalError::InitializationFailed(_) => ErrorCode::HAL_INITIALIZATION_FAILED,
+            HalError::Timeout => ErrorCode::HAL_TIMEOUT,
+            HalError::InvalidParameter(_) => ErrorCode::HAL_INVALID_PARAMETER,
+            HalError::HardwareFault(_) => ErrorCode::HAL_HARDWARE_FAULT,
+            HalError::NotSupported => ErrorCode::HAL_NOT_SUPPORTED,
+            HalError::CommunicationError(_) => ErrorCode::HAL_COMMUNICATION,
+        }
+    }
+    
+    fn detail(&self) -> String {
+        match self {
+            HalError::InitializationFailed(detail) | HalError::InvalidParameter(detail)
+                | HalError::HardwareFault(detail) | HalError::CommunicationError(detail) => detail.clone(),
+            HalError::Timeout => "Hardware operation timed out".to_string(),
+            HalError::NotSupported => "Not supported on this hardware".to_string(),
+        }
+    }
+}
+
+impl ErrorCoded for CoreError {
+    /// A wrapped HAL error keeps its HAL code
+    fn error_code(&self) -> ErrorCode {
+        match self {
+            CoreError::ConfigurationError(_) => ErrorCode::CONFIGURATION,
+            CoreError::HalError(error) => error.error_code(),
+            CoreError::SafetyViolation(_) => ErrorCode::SAFETY_VIOLATION,
+            CoreError::LearningError(_) => ErrorCode::LEARNING,
+            CoreError::CanError(_) => ErrorCode::CAN,
+            CoreError::InvalidState(_) => ErrorCode::INVALID_STATE,
+            CoreError::CalibrationError(_) => ErrorCode::CALIBRATION,
+            CoreError::SensorError(_) => ErrorCode::SENSOR,
+            CoreError::StorageError(_) => ErrorCode::STORAGE,
+        }
+    }
+    
+    fn detail(&self) -> String {
+        match self {
+            CoreError::HalError(error) => error.detail(),
+            CoreError::ConfigurationError(detail) | CoreError::SafetyViolation(detail) | CoreError::LearningError(detail)
+                | CoreError::CanError(detail) | CoreError::InvalidState(detail) | CoreError::CalibrationError(detail)
+                | CoreError::SensorError(detail) | CoreError::StorageError(detail) => detail.clone(),
+        }
+    }
+}
+
+impl ErrorCoded for FaultCode {
+    fn error_code(&self) -> ErrorCode {
+        match self {
+            FaultCode::SelfTestFailed => ErrorCode::FAULT_SELF_TEST,
+            FaultCode::PwmHardwareFault => ErrorCode::FAULT_PWM_HARDWARE,
+            FaultCode::PressureSensorFault(_) => ErrorCode::FAULT_PRESSURE_SENSOR,
+            FaultCode::CanCommunicationLost => ErrorCode::FAULT_CAN_LOST,
+            FaultCode::StorageSystemFault => ErrorCode::FAULT_STORAGE,
+            FaultCode::OverboostLimitExceeded { .. } => ErrorCode::FAULT_OVERBOOST,
+            FaultCode::PneumaticSystemFailure => ErrorCode::FAULT_PNEUMATIC,
+            FaultCode::SafetyResponseTooSlow => ErrorCode::FAULT_RESPONSE_TOO_SLOW,
+            FaultCode::InvalidConfiguration(_) => ErrorCode::FAULT_INVALID_CONFIGURATION,
+            FaultCode::CalibrationDataCorrupted => ErrorCode::FAULT_CALIBRATION_CORRUPTED,
+            FaultCode::TorqueSignalsInvalid => ErrorCode::FAULT_TORQUE_SIGNALS,
+            FaultCode::ImplausibleSensorReading { .. } => ErrorCode::FAULT_IMPLAUSIBLE_SENSOR,
+            FaultCode::InputSourceMismatch { .. } => ErrorCode::FAULT_INPUT_MISMATCH,
+            FaultCode::CalibrationFailed(_) => ErrorCode::FAULT_CALIBRATION_FAILED,
+            FaultCode::LearningInconsistency => ErrorCode::FAULT_LEARNING_INCONSISTENT,
+            FaultCode::ControlFault(_) => ErrorCode::FAULT_CONTROL,
+            FaultCode::SafetyLimitsCorrupted => ErrorCode::FAULT_SAFETY_LIMITS,
+        }
+    }
+    
+    fn detail(&self) -> String {
+        self.description()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    
+    fn faults() -> Vec<FaultCode> {
+        let text = || String::from("manifold");
+        alloc::vec![
+            FaultCode::SelfTestFailed,
+            FaultCode::PwmHardwareFault,
+            FaultCode::PressureSensorFault(text()),
+            FaultCode::CanCommunicationLost,
+            FaultCode::StorageSystemFault,
+            FaultCode::OverboostLimitExceeded { pressure_psi: 17.0, limit_psi: 16.0 },
+            FaultCode::PneumaticSystemFailure,
+            FaultCode::SafetyResponseTooSlow,
+            FaultCode::InvalidConfiguration(text()),
+            FaultCode::CalibrationDataCorrupted,
+            FaultCode::TorqueSignalsInvalid,
+            FaultCode::ImplausibleSensorReading { sensor: text(), value: 0.0 },
+            FaultCode::InputSourceMismatch { input: text(), can_value: 0.0, analog_value: 1.0 },
+            FaultCode::CalibrationFailed(text()),
+            FaultCode::LearningInconsistency,
+            FaultCode::ControlFault(text()),
+            FaultCode::SafetyLimitsCorrupted,
+        ]
+    }
+    
+    #[test]
+    fn test_identifiers_and_keys_are_unique() {
+        for (i, a) in ERROR_CODES.iter().enumerate() {
+            for b in &ERROR_CODES[i + 1..] {
+                assert_ne!(a.to_string(), b.to_string());
+                assert_ne!(a.key, b.key);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_faults_keep_their_display_codes() {
+        for fault in faults() {
+            let code = fault.error_code();
+            assert_eq!(code.to_string(), fault.display_code());
+            assert_eq!(code.number, fault.number());
+            assert!(ERROR_CODES.contains(&code));
+            assert!(!fault.is_critical() || code.severity == Severity::Critical, "{}", code);
+        }
+        assert_eq!(faults().len(), ERROR_CODES.iter().filter(|code| code.source == ErrorSource::Fault).count());
+    }
+    
+    #[test]
+    fn test_wrapped_hal_error_keeps_hal_code() {
+        let error = CoreError::from(HalError::HardwareFault(String::from("Storage write")));
+        assert_eq!(error.error_code(), ErrorCode::HAL_HARDWARE_FAULT);
+        assert_eq!(error.coded_message(), "H04: Storage write");
+        assert_eq!(ErrorCode::of_message(&error.coded_message()), Some(ErrorCode::HAL_HARDWARE_FAULT));
+        assert_eq!(ErrorCode::lookup("f04"), Some(ErrorCode::FAULT_CAN_LOST));
+        assert_eq!(ErrorCode::lookup("X99"), None);
+    }
+}
@@ -0,0 +1,336 @@
+//! Core Event Bus
+//! 
+//! 🔗 T4-CORE-080: Core Event Bus
+//! Derived From: T3-BUILD-003 (Core Control State Machine) + Architecture.md component separation
+//! AI Traceability: Subsystems announce what happened; display, telemetry and logging decide what to do about it
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use heapless::Deque;
+use serde::{Deserialize, Serialize};
+use crate::{
+    CoreError, SystemState, OutputDegradation, CommissioningStep, StepOutcome, ExerciseRecord, OverboostEvent, TurboBank, ActuationAdvisory,
+};
+
+/// Events held between control cycles (oldest dropped first when full)
+pub const EVENT_QUEUE_CAPACITY: usize = 32;
+
+/// Subscribed sinks
+pub const MAX_EVENT_SINKS: usize = 4;
+
+/// Recently dispatched events kept for telemetry
+pub const RECENT_EVENTS: usize = 8;
+
+/// Event grouping for subscription filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventCategory {
+    /// Ordinary state machine transitions
+    State,
+    /// Faults, overboost cut and output degradation
+    Safety,
+    /// Learning samples
+    Learning,
+    /// Commissioning and calibration progress
+    Commissioning,
+    /// Maintenance routines
+    Maintenance,
+}
+
+impl EventCategory {
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Something a subsystem reports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CoreEvent {
+    /// System state changed
+    StateChanged { from: SystemState, to: SystemState },
+    /// Solenoid output stage lost capability
+    OutputDegraded(OutputDegradation),
+    /// Steady-state sample passed to learning
+    LearningSample { rpm: u16, target_psi: f32 },
+    /// Commissioning step completed or skipped
+    CommissioningStep { step: CommissioningStep, outcome: StepOutcome },
+    /// Solenoid self-cleaning exercise ended
+    SolenoidExercise(ExerciseRecord),
+    /// Overboost response began, with its likely cause
+    Overboost(OverboostEvent),
+    /// One turbo bank has consistently needed more duty than the other
+    BankImbalance(TurboBank),
+    /// Dome pressure has been following solenoid duty less and less
+    ActuationDecline(ActuationAdvisory),
+    /// ECU torque lost (torque following runs boost-by-target) or back
+    TorqueData { available: bool },
+    /// A gear with reduced boost was selected
+    GearBoostReduced { gear: u8, scale: f32 },
+}
+
+impl CoreEvent {
+    /// Category used for subscription filtering
+    pub fn category(&self) -> EventCategory {
+        match self {
+            CoreEvent::StateChanged { to: SystemState::Fault(_) | SystemState::OverboostCut, .. } => EventCategory::Safety,
+            CoreEvent::StateChanged { .. } | CoreEvent::GearBoostReduced { .. } => EventCategory::State,
+            CoreEvent::OutputDegraded(_) | CoreEvent::Overboost(_) | CoreEvent::TorqueData { .. } => EventCategory::Safety,
+            CoreEvent::LearningSample { .. } => EventCategory::Learning,
+            CoreEvent::CommissioningStep { .. } => EventCategory::Commissioning,
+            CoreEvent::SolenoidExercise(_) | CoreEvent::BankImbalance(_) | CoreEvent::ActuationDecline(_) => EventCategory::Maintenance,
+        }
+    }
+    
+    /// One-line description for logs and event lists
+    pub fn description(&self) -> String {
+        match self {
+            CoreEvent::StateChanged { from, to } => format!("{} -> {}", from.display_text(), to.display_text()),
+            CoreEvent::OutputDegraded(degradation) => format!("Output stage degraded: {:?}", degradation),
+            CoreEvent::LearningSample { rpm, target_psi } => format!("Learning sample at {} RPM, {:.1} PSI", rpm, target_psi),
+            CoreEvent::CommissioningStep { step, outcome } => format!("{}: {:?}", step.display_text(), outcome),
+            CoreEvent::SolenoidExercise(record) if record.aborted => {
+                format!("Solenoid exercise cut short after {} pulses", record.pulses_completed)
+            },
+            CoreEvent::SolenoidExercise(record) => format!("Solenoid exercise completed ({} pulses)", record.pulses_completed),
+            CoreEvent::Overboost(event) => format!("Overboost at {:.1} PSI (target {:.1}): {}",
+                event.freeze_frame.manifold_pressure, event.freeze_frame.target_boost_psi, event.cause.description()),
+            CoreEvent::BankImbalance(bank) => format!("{} needs more duty than the other bank", bank.display_text()),
+            CoreEvent::ActuationDecline(advisory) => String::from(advisory.description()),
+            CoreEvent::TorqueData { available: true } => String::from("ECU torque restored - torque following resumed"),
+            CoreEvent::TorqueData { available: false } => String::from("No ECU torque - boost-by-target until it returns"),
+            CoreEvent::GearBoostReduced { gear, scale } => format!("Gear {}: boost over spring scaled to {:.0}%", gear, scale * 100.0),
+        }
+    }
+}
+
+/// Event with the time it was published
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimedEvent {
+    pub timestamp_ms: u32,
+    pub event: CoreEvent,
+}
+
+/// Categories a sink receives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventFilter(u8);
+
+impl EventFilter {
+    /// Every category
+    pub const ALL: EventFilter = EventFilter(u8::MAX);
+    
+    /// Only the listed categories
+    pub fn only(categories: &[EventCategory]) -> Self {
+        Self(categories.iter().fold(0, |mask, category| mask | category.bit()))
+    }
+    
+    /// Whether events of this category pass
+    pub fn accepts(&self, category: EventCategory) -> bool {
+        self.0 & category.bit() != 0
+    }
+}
+
+/// Consumer of core events (display, telemetry, logging)
+/// 
+/// 🔗 T4-CORE-081: Event Sinks
+/// Derived From: T4-CORE-080
+/// 
+/// Sinks run inside the control cycle, so handling must be quick and must not
+/// block - queue anything slow for the main loop.
+pub trait EventSink {
+    /// Handle one event
+    fn handle(&mut self, event: &TimedEvent);
+}
+
+/// Event bus statistics
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventBusStats {
+    /// Events published
+    pub published: u32,
+    /// Events lost because the queue was full
+    pub dropped: u32,
+}
+
+struct Subscription {
+    filter: EventFilter,
+    sink: Box<dyn EventSink + Send>,
+}
+
+/// Fixed-capacity publish/subscribe bus
+/// 
+/// Publishing only queues the event, so subsystems never call into display or
+/// logging code mid-cycle. Queued events are delivered to every subscribed
+/// sink whose filter accepts them once per control cycle.
+pub struct EventBus {
+    queue: Deque<TimedEvent, EVENT_QUEUE_CAPACITY>,
+    sinks: heapless::Vec<Subscription, MAX_EVENT_SINKS>,
+    recent: Deque<TimedEvent, RECENT_EVENTS>,
+    last_state: Option<SystemState>,
+    stats: EventBusStats,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    /// Create bus with no sinks
+    pub fn new() -> Self {
+        Self {
+            queue: Deque::new(),
+            sinks: heapless::Vec::new(),
+            recent: Deque::new(),
+            last_state: None,
+            stats: EventBusStats::default(),
+        }
+    }
+    
+    /// Bus statistics
+    pub fn stats(&self) -> &EventBusStats {
+        &self.stats
+    }
+    
+    /// Events waiting for the next dispatch
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+    
+    /// Subscribe a sink to the categories its filter accepts
+    pub fn subscribe(&mut self, filter: EventFilter, sink: Box<dyn EventSink + Send>) -> Result<(), CoreError> {
+        self.sinks
+            .push(Subscription { filter, sink })
+            .map_err(|_| CoreError::ConfigurationError(format!("At most {} event sinks", MAX_EVENT_SINKS)))
+    }
+    
+    /// Queue an event for the next dispatch
+    pub fn publish(&mut self, timestamp_ms: u32, event: CoreEvent) {
+        self.stats.published += 1;
+        if self.queue.is_full() {
+            self.queue.pop_front();
+            self.stats.dropped += 1;
+        }
+        let _ = self.queue.push_back(TimedEvent { timestamp_ms, event });
+    }
+    
+    /// Publish a state change if `state` differs from the last one observed
+    /// 
+    /// State is observed once per control cycle, so transitions made and undone
+    /// between two observations are not reported.
+    pub fn observe_state(&mut self, state: &SystemState, timestamp_ms: u32) {
+        match &self.last_state {
+            Some(last) if last == state => return,
+            Some(last) => {
+                let event = CoreEvent::StateChanged { from: last.clone(), to: state.clone() };
+                self.publish(timestamp_ms, event);
+            },
+            None => {},
+        }
+        self.last_state = Some(state.clone());
+    }
+    
+    /// Deliver queued events to the sinks; returns the number delivered
+    pub fn dispatch(&mut self) -> usize {
+        let mut delivered = 0;
+        while let Some(event) = self.queue.pop_front() {
+            let category = event.event.category();
+            for subscription in self.sinks.iter_mut().filter(|subscription| subscription.filter.accepts(category)) {
+                subscription.sink.handle(&event);
+            }
+            
+            if self.recent.is_full() {
+                self.recent.pop_front();
+            }
+            let _ = self.recent.push_back(event);
+            delivered += 1;
+        }
+        delivered
+    }
+    
+    /// Most recently dispatched events, oldest first
+    pub fn recent(&self) -> Vec<TimedEvent> {
+        self.recent.iter().cloned().collect()
+    }
+    
+    /// Latest dispatched event
+    pub fn latest(&self) -> Option<&TimedEvent> {
+        self.recent.back()
+    }
+}
+
+/// Sink writing every event to the `log` facade
+#[cfg(feature = "std")]
+pub struct LogSink;
+
+#[cfg(feature = "std")]
+impl EventSink for LogSink {
+    fn handle(&mut self, event: &TimedEvent) {
+        match event.event.category() {
+            EventCategory::Safety => log::warn!("[{} ms] {}", event.timestamp_ms, event.event.description()),
+            _ => log::info!("[{} ms] {}", event.timestamp_ms, event.event.description()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicU32, Ordering};
+    use crate::FaultCode;
+    
+    /// Counts events it receives
+    struct CountingSink(Arc<AtomicU32>);
+    
+    impl EventSink for CountingSink {
+        fn handle(&mut self, _event: &TimedEvent) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    
+    #[test]
+    fn test_sinks_receive_filtered_events() {
+        let everything = Arc::new(AtomicU32::new(0));
+        let safety = Arc::new(AtomicU32::new(0));
+        let mut bus = EventBus::new();
+        bus.subscribe(EventFilter::ALL, Box::new(CountingSink(everything.clone()))).unwrap();
+        bus.subscribe(EventFilter::only(&[EventCategory::Safety]), Box::new(CountingSink(safety.clone()))).unwrap();
+        
+        bus.observe_state(&SystemState::Idle, 0);
+        bus.observe_state(&SystemState::Armed, 10);
+        bus.observe_state(&SystemState::Armed, 20);
+        bus.observe_state(&SystemState::Fault(FaultCode::PwmHardwareFault), 30);
+        bus.publish(30, CoreEvent::LearningSample { rpm: 4000, target_psi: 8.0 });
+        
+        // Nothing reaches a sink until dispatch
+        assert_eq!(everything.load(Ordering::Relaxed), 0);
+        assert_eq!(bus.dispatch(), 3);
+        assert_eq!(everything.load(Ordering::Relaxed), 3);
+        assert_eq!(safety.load(Ordering::Relaxed), 1);
+        assert_eq!(bus.latest().unwrap().event.category(), EventCategory::Learning);
+    }
+    
+    #[test]
+    fn test_full_queue_drops_oldest() {
+        let mut bus = EventBus::new();
+        for rpm in 0..(EVENT_QUEUE_CAPACITY as u16 + 3) {
+            bus.publish(0, CoreEvent::LearningSample { rpm, target_psi: 8.0 });
+        }
+        
+        assert_eq!(bus.stats().dropped, 3);
+        bus.dispatch();
+        let recent = bus.recent();
+        assert_eq!(recent.len(), RECENT_EVENTS);
+        assert_eq!(recent.last().unwrap().event, CoreEvent::LearningSample { rpm: EVENT_QUEUE_CAPACITY as u16 + 2, target_psi: 8.0 });
+    }
+    
+    #[test]
+    fn test_sink_limit() {
+        let mut bus = EventBus::new();
+        for _ in 0..MAX_EVENT_SINKS {
+            bus.subscribe(EventFilter::ALL, Box::new(CountingSink(Arc::new(AtomicU32::new(0))))).unwrap();
+        }
+        assert!(bus.subscribe(EventFilter::ALL, Box::new(CountingSink(Arc::new(AtomicU32::new(0))))).is_err());
+    }
+}
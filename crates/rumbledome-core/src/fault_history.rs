@@ -0,0 +1,173 @@
+//! Fault History and Freeze Frames
+//! 
+//! 🔗 T4-CORE-053: Fault History
+//! Derived From: Safety.md fault response hierarchy + field diagnostics needs
+//! AI Traceability: Every fault is kept with a snapshot of the conditions that caused it
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{FaultCode, SystemInputs, SystemState};
+
+/// Fault records kept (oldest dropped first)
+pub const MAX_FAULT_RECORDS: usize = 16;
+
+/// Snapshot of operating conditions when a fault was detected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FreezeFrame {
+    /// Capture timestamp (ms since power-on)
+    pub timestamp_ms: u32,
+    /// Engine RPM
+    pub rpm: u16,
+    /// ECU desired torque (Nm)
+    pub desired_torque: f32,
+    /// ECU actual torque (Nm)
+    pub actual_torque: f32,
+    /// Manifold pressure (PSI gauge)
+    pub manifold_pressure: f32,
+    /// Dome input pressure (PSI gauge)
+    pub dome_input_pressure: f32,
+    /// Upper dome pressure (PSI gauge)
+    pub upper_dome_pressure: f32,
+    /// Lower dome pressure (PSI gauge)
+    pub lower_dome_pressure: f32,
+    /// Boost target at the time (PSI)
+    pub target_boost_psi: f32,
+    /// Solenoid duty at the time (%)
+    pub duty_percent: f32,
+    /// Aggression setting
+    pub aggression: f32,
+    /// Scramble button state
+    pub scramble_active: bool,
+}
+
+impl FreezeFrame {
+    /// Capture from one control cycle
+    pub fn capture(inputs: &SystemInputs, target_boost_psi: f32, duty_percent: f32) -> Self {
+        Self {
+            timestamp_ms: inputs.timestamp_ms,
+            rpm: inputs.rpm,
+            desired_torque: inputs.desired_torque,
+            actual_torque: inputs.actual_torque,
+            manifold_pressure: inputs.manifold_pressure,
+            dome_input_pressure: inputs.dome_input_pressure,
+            upper_dome_pressure: inputs.upper_dome_pressure,
+            lower_dome_pressure: inputs.lower_dome_pressure,
+            target_boost_psi,
+            duty_percent,
+            aggression: inputs.aggression,
+            scramble_active: inputs.scramble_active,
+        }
+    }
+}
+
+/// One fault occurrence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaultRecord {
+    /// Fault raised
+    pub fault: FaultCode,
+    /// Conditions when it was detected
+    pub freeze_frame: FreezeFrame,
+}
+
+/// Bounded fault history (persisted by the storage layer)
+/// 
+/// 🔗 T4-CORE-054: Fault Recording
+/// Derived From: T4-CORE-053
+#[derive(Debug, Clone, Default)]
+pub struct FaultHistory {
+    records: Vec<FaultRecord>,
+    in_fault: bool,
+}
+
+impl FaultHistory {
+    /// Create empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume from persisted records
+    pub fn restore(&mut self, mut records: Vec<FaultRecord>) {
+        let excess = records.len().saturating_sub(MAX_FAULT_RECORDS);
+        records.drain(..excess);
+        self.records = records;
+    }
+    
+    /// Recorded faults, oldest first
+    pub fn records(&self) -> &[FaultRecord] {
+        &self.records
+    }
+    
+    /// Freeze frame of the most recent fault
+    pub fn last_freeze_frame(&self) -> Option<&FreezeFrame> {
+        self.records.last().map(|record| &record.freeze_frame)
+    }
+    
+    /// Erase all records
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+    
+    /// Observe the system state once per cycle; records each entry into a fault
+    pub fn observe(&mut self, state: &SystemState, freeze_frame: impl FnOnce() -> FreezeFrame) {
+        let SystemState::Fault(fault) = state else {
+            self.in_fault = false;
+            return;
+        };
+        
+        if self.in_fault {
+            return;
+        }
+        self.in_fault = true;
+        
+        if self.records.len() >= MAX_FAULT_RECORDS {
+            self.records.remove(0);
+        }
+        self.records.push(FaultRecord { fault: fault.clone(), freeze_frame: freeze_frame() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn frame(timestamp_ms: u32) -> FreezeFrame {
+        let inputs = SystemInputs {
+            rpm: 4200,
+            actual_torque: 310.0,
+            manifold_pressure: 16.2,
+            throttle_position: Some(85.0),
+            upper_dome_pressure: 2.0,
+            lower_dome_pressure: 14.0,
+            ..test_support::inputs(timestamp_ms)
+        };
+        FreezeFrame::capture(&inputs, 12.0, 48.0)
+    }
+    
+    #[test]
+    fn test_fault_recorded_once_per_occurrence() {
+        let mut history = FaultHistory::new();
+        let fault = SystemState::Fault(FaultCode::CanCommunicationLost);
+        
+        history.observe(&SystemState::Armed, || frame(0));
+        history.observe(&fault, || frame(10));
+        history.observe(&fault, || frame(20));
+        history.observe(&SystemState::Idle, || frame(30));
+        history.observe(&fault, || frame(40));
+        
+        assert_eq!(history.records().len(), 2);
+        assert_eq!(history.last_freeze_frame().unwrap().timestamp_ms, 40);
+    }
+    
+    #[test]
+    fn test_history_bounded() {
+        let mut history = FaultHistory::new();
+        for t in 0..20 {
+            history.observe(&SystemState::Fault(FaultCode::CanCommunicationLost), || frame(t));
+            history.observe(&SystemState::Idle, || frame(t));
+        }
+        
+        assert_eq!(history.records().len(), MAX_FAULT_RECORDS);
+        assert_eq!(history.records()[0].freeze_frame.timestamp_ms, 4);
+    }
+}
@@ -0,0 +1,646 @@
+//! Flight Recorder
+//! 
+//! 🔗 T4-CORE-107: EEPROM Flight Recorder
+//! Derived From: T4-HAL-017 (On-Chip EEPROM Storage) + T4-CORE-095 (Full-Rate Burst Capture)
+//! AI Traceability: Cars without an SD card still keep the minutes before an incident, in EEPROM that survives the key-off
+//! 
+//! The recorder runs whenever the controller is powered. Each sample is the
+//! peak boost and duty over one decimation interval (2.5 s by default) with
+//! the state it ended in, so a short spike is never decimated away. Samples
+//! are packed into 64-byte blocks - the first sample in full, the rest as
+//! one-byte boost and duty deltas - and the blocks form a ring in the flight
+//! recorder region, oldest overwritten first.
+//! 
+//! Every block carries a sequence number and a CRC and is written whole, so
+//! power lost mid-write leaves only that block unreadable, and it was the
+//! oldest in the ring anyway. At boot the ring is scanned and recording
+//! resumes after the newest valid block as a new session. A state change, or
+//! a delta too large to encode, closes the block early, so the block leading
+//! up to an overboost or fault is in EEPROM as soon as it happens.
+//! 
+//! Block writes come out of a budget refilling at `blocks_per_hour`; a block
+//! closed with the budget spent is dropped and counted. At the default budget
+//! each ring block is rewritten under twelve times an hour, so the rated
+//! 100,000 cycles last over 8,000 hours of running.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{HalResult, NonVolatileStorage, StorageRegion};
+use crate::command_token::Crc16;
+use crate::{CoreError, SystemState};
+
+/// Size of one ring block (bytes)
+pub const FLIGHT_BLOCK_SIZE: usize = 64;
+
+/// Blocks in the flight recorder region
+pub const FLIGHT_RECORDER_BLOCKS: usize = StorageRegion::FLIGHT_RECORDER.len / FLIGHT_BLOCK_SIZE;
+
+/// Samples one block holds - the first in full, the rest as deltas
+pub const FLIGHT_SAMPLES_PER_BLOCK: usize = 1 + (FLIGHT_BLOCK_SIZE - HEADER_SIZE - CRC_SIZE) / 2;
+
+/// First byte of every written block
+const BLOCK_MAGIC: u8 = 0xB7;
+
+/// Magic, state, sequence, session, end time, interval, first boost, first duty, count
+const HEADER_SIZE: usize = 18;
+
+const CRC_SIZE: usize = 2;
+
+/// Boost resolution (PSI)
+const BOOST_STEP_PSI: f32 = 0.1;
+
+/// Duty resolution (%)
+const DUTY_STEP_PERCENT: f32 = 0.5;
+
+/// Flight recorder settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FlightRecorderConfig {
+    /// Record to EEPROM whenever the controller is powered
+    pub enabled: bool,
+    /// Decimation interval - each sample is the peak over one interval (ms)
+    pub sample_interval_ms: u32,
+    /// EEPROM block writes allowed per hour
+    pub blocks_per_hour: u32,
+}
+
+impl Default for FlightRecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sample_interval_ms: 2_500,
+            blocks_per_hour: 90,
+        }
+    }
+}
+
+impl FlightRecorderConfig {
+    /// Validate interval and write budget
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(500..=60_000).contains(&self.sample_interval_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Flight recorder sample interval must be 500-60000 ms, got {}", self.sample_interval_ms)
+            ));
+        }
+        if !(10..=360).contains(&self.blocks_per_hour) {
+            return Err(CoreError::ConfigurationError(
+                format!("Flight recorder write budget must be 10-360 blocks per hour, got {}", self.blocks_per_hour)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// System state as kept by the recorder - fault details reduced to the fault number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlightState {
+    Initializing,
+    Idle,
+    Armed,
+    Calibrating,
+    Commissioning,
+    OverboostCut,
+    /// Fault with its display number (`FaultCode::number`)
+    Fault(u8),
+}
+
+impl FlightState {
+    /// Recorded form of a system state
+    pub fn from_state(state: &SystemState) -> Self {
+        match state {
+            SystemState::Initializing => FlightState::Initializing,
+            SystemState::Idle => FlightState::Idle,
+            SystemState::Armed => FlightState::Armed,
+            SystemState::Calibrating(_) => FlightState::Calibrating,
+            SystemState::Commissioning(_) => FlightState::Commissioning,
+            SystemState::OverboostCut => FlightState::OverboostCut,
+            SystemState::Fault(fault) => FlightState::Fault(fault.number()),
+        }
+    }
+    
+    pub(crate) fn code(&self) -> u8 {
+        match self {
+            FlightState::Initializing => 0,
+            FlightState::Idle => 1,
+            FlightState::Armed => 2,
+            FlightState::Calibrating => 3,
+            FlightState::Commissioning => 4,
+            FlightState::OverboostCut => 5,
+            FlightState::Fault(number) => 0x80 | number,
+        }
+    }
+    
+    fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => FlightState::Initializing,
+            1 => FlightState::Idle,
+            2 => FlightState::Armed,
+            3 => FlightState::Calibrating,
+            4 => FlightState::Commissioning,
+            5 => FlightState::OverboostCut,
+            0x80..=0xFF => FlightState::Fault(code & 0x7F),
+            _ => return None,
+        })
+    }
+}
+
+/// One decimated sample
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightSample {
+    /// Power-up session the sample was recorded in
+    pub session: u16,
+    /// End of the sample's interval (ms since power-up)
+    pub timestamp_ms: u32,
+    /// State at the end of the interval
+    pub state: FlightState,
+    /// Peak manifold pressure over the interval (PSI, 0.1 PSI resolution)
+    pub boost_psi: f32,
+    /// Peak commanded duty over the interval (%, 0.5% resolution)
+    pub duty_percent: f32,
+}
+
+/// One block of the recording, oldest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightRecordChunk {
+    /// Index of this block
+    pub offset: u32,
+    /// Blocks in the recording, including the one still being filled
+    pub total: u32,
+    pub samples: Vec<FlightSample>,
+}
+
+/// Recorder counters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlightRecorderStatus {
+    /// Recording enabled
+    pub enabled: bool,
+    /// Current power-up session
+    pub session: u16,
+    /// Valid blocks in the ring
+    pub blocks_stored: u32,
+    /// Blocks written since power-up
+    pub blocks_written: u32,
+    /// Blocks dropped because the write budget was spent
+    pub blocks_dropped: u32,
+    /// Block writes the storage refused
+    pub write_errors: u32,
+}
+
+/// One ring block, quantized
+#[derive(Debug, Clone, PartialEq)]
+struct FlightBlock {
+    sequence: u32,
+    session: u16,
+    end_ms: u32,
+    interval_ms: u16,
+    state: FlightState,
+    boost: Vec<i16>,
+    duty: Vec<u8>,
+}
+
+impl FlightBlock {
+    fn new(session: u16, interval_ms: u16, state: FlightState) -> Self {
+        Self { sequence: 0, session, end_ms: 0, interval_ms, state, boost: Vec::new(), duty: Vec::new() }
+    }
+    
+    /// Add a sample; refused when it belongs in a new block
+    fn push(&mut self, state: FlightState, interval_ms: u16, boost: i16, duty: u8, end_ms: u32) -> bool {
+        if state != self.state || interval_ms != self.interval_ms || self.boost.len() >= FLIGHT_SAMPLES_PER_BLOCK {
+            return false;
+        }
+        if let (Some(&last_boost), Some(&last_duty)) = (self.boost.last(), self.duty.last()) {
+            let fits = i8::try_from(boost as i32 - last_boost as i32).is_ok()
+                && i8::try_from(duty as i16 - last_duty as i16).is_ok();
+            if !fits {
+                return false;
+            }
+        }
+        self.boost.push(boost);
+        self.duty.push(duty);
+        self.end_ms = end_ms;
+        true
+    }
+    
+    fn is_full(&self) -> bool {
+        self.boost.len() >= FLIGHT_SAMPLES_PER_BLOCK
+    }
+    
+    fn encode(&self) -> [u8; FLIGHT_BLOCK_SIZE] {
+        let mut bytes = [0; FLIGHT_BLOCK_SIZE];
+        bytes[0] = BLOCK_MAGIC;
+        bytes[1] = self.state.code();
+        bytes[2..6].copy_from_slice(&self.sequence.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.session.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.end_ms.to_le_bytes());
+        bytes[12..14].copy_from_slice(&self.interval_ms.to_le_bytes());
+        bytes[14..16].copy_from_slice(&self.boost[0].to_le_bytes());
+        bytes[16] = self.duty[0];
+        bytes[17] = self.boost.len() as u8;
+        for i in 1..self.boost.len() {
+            let at = HEADER_SIZE + (i - 1) * 2;
+            bytes[at] = (self.boost[i] as i32 - self.boost[i - 1] as i32) as i8 as u8;
+            bytes[at + 1] = (self.duty[i] as i16 - self.duty[i - 1] as i16) as i8 as u8;
+        }
+        let crc = block_crc(&bytes);
+        bytes[FLIGHT_BLOCK_SIZE - CRC_SIZE..].copy_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+    
+    /// Decode a stored block; erased, torn and corrupt blocks decode to `None`
+    fn decode(bytes: &[u8; FLIGHT_BLOCK_SIZE]) -> Option<Self> {
+        let crc = u16::from_le_bytes([bytes[FLIGHT_BLOCK_SIZE - 2], bytes[FLIGHT_BLOCK_SIZE - 1]]);
+        if bytes[0] != BLOCK_MAGIC || crc != block_crc(bytes) {
+            return None;
+        }
+        let count = bytes[17] as usize;
+        if !(1..=FLIGHT_SAMPLES_PER_BLOCK).contains(&count) {
+            return None;
+        }
+        
+        let mut boost = Vec::with_capacity(count);
+        let mut duty = Vec::with_capacity(count);
+        boost.push(i16::from_le_bytes([bytes[14], bytes[15]]));
+        duty.push(bytes[16]);
+        for i in 1..count {
+            let at = HEADER_SIZE + (i - 1) * 2;
+            boost.push(boost[i - 1].wrapping_add(bytes[at] as i8 as i16));
+            duty.push(duty[i - 1].wrapping_add(bytes[at + 1]));
+        }
+        Some(Self {
+            sequence: u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            session: u16::from_le_bytes([bytes[6], bytes[7]]),
+            end_ms: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            interval_ms: u16::from_le_bytes([bytes[12], bytes[13]]),
+            state: FlightState::from_code(bytes[1])?,
+            boost,
+            duty,
+        })
+    }
+    
+    /// Samples with their times, counted back from the block's last sample
+    fn samples(&self) -> Vec<FlightSample> {
+        let last = self.boost.len() - 1;
+        self.boost.iter().zip(&self.duty).enumerate().map(|(i, (&boost, &duty))| FlightSample {
+            session: self.session,
+            timestamp_ms: self.end_ms.wrapping_sub((last - i) as u32 * self.interval_ms as u32),
+            state: self.state,
+            boost_psi: boost as f32 * BOOST_STEP_PSI,
+            duty_percent: duty as f32 * DUTY_STEP_PERCENT,
+        }).collect()
+    }
+}
+
+/// CRC-16 over everything but the stored CRC
+fn block_crc(bytes: &[u8; FLIGHT_BLOCK_SIZE]) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update(&bytes[..FLIGHT_BLOCK_SIZE - CRC_SIZE]);
+    crc.value()
+}
+
+/// Peaks of the decimation interval in progress
+#[derive(Debug, Clone, Copy)]
+struct Interval {
+    started_ms: u32,
+    state: FlightState,
+    peak_boost_psi: f32,
+    peak_duty_percent: f32,
+}
+
+/// Always-on EEPROM ring recorder
+#[derive(Debug, Clone)]
+pub struct FlightRecorder {
+    config: FlightRecorderConfig,
+    session: u16,
+    next_slot: usize,
+    next_sequence: u32,
+    occupied: [bool; FLIGHT_RECORDER_BLOCKS],
+    interval: Option<Interval>,
+    block: Option<FlightBlock>,
+    /// Block writes available now
+    budget: f32,
+    last_refill_ms: Option<u32>,
+    blocks_written: u32,
+    blocks_dropped: u32,
+    write_errors: u32,
+}
+
+impl FlightRecorder {
+    /// Create for an empty ring - `restore()` picks up an existing one
+    pub fn new(config: FlightRecorderConfig) -> Self {
+        Self {
+            config,
+            session: 0,
+            next_slot: 0,
+            next_sequence: 0,
+            occupied: [false; FLIGHT_RECORDER_BLOCKS],
+            interval: None,
+            block: None,
+            budget: FLIGHT_RECORDER_BLOCKS as f32,
+            last_refill_ms: None,
+            blocks_written: 0,
+            blocks_dropped: 0,
+            write_errors: 0,
+        }
+    }
+    
+    /// Apply changed settings; a new interval takes effect from the next block
+    pub fn reconfigure(&mut self, config: FlightRecorderConfig) {
+        if !config.enabled {
+            self.interval = None;
+            self.block = None;
+        }
+        self.config = config;
+    }
+    
+    /// Resume after the newest valid block in the ring, as a new session
+    pub fn restore<S: NonVolatileStorage>(&mut self, storage: &mut S) -> HalResult<()> {
+        let blocks = read_ring(storage)?;
+        self.occupied = [false; FLIGHT_RECORDER_BLOCKS];
+        for (slot, _) in &blocks {
+            self.occupied[*slot] = true;
+        }
+        if let Some((slot, newest)) = blocks.iter().max_by_key(|(_, block)| block.sequence) {
+            self.next_slot = (slot + 1) % FLIGHT_RECORDER_BLOCKS;
+            self.next_sequence = newest.sequence.wrapping_add(1);
+            self.session = newest.session.wrapping_add(1);
+        }
+        Ok(())
+    }
+    
+    /// Feed one control cycle's boost and duty
+    pub fn record<S: NonVolatileStorage>(&mut self, storage: &mut S, state: &SystemState, boost_psi: f32, duty_percent: f32, now_ms: u32) {
+        if !self.config.enabled {
+            return;
+        }
+        self.refill_budget(now_ms);
+        
+        let state = FlightState::from_state(state);
+        if let Some(interval) = self.interval {
+            let state_changed = interval.state != state;
+            if state_changed || now_ms.wrapping_sub(interval.started_ms) >= self.config.sample_interval_ms {
+                self.finish_interval(storage, interval, now_ms, state_changed);
+            }
+        }
+        
+        let interval = self.interval.get_or_insert(Interval {
+            started_ms: now_ms,
+            state,
+            peak_boost_psi: boost_psi,
+            peak_duty_percent: duty_percent,
+        });
+        interval.peak_boost_psi = interval.peak_boost_psi.max(boost_psi);
+        interval.peak_duty_percent = interval.peak_duty_percent.max(duty_percent);
+    }
+    
+    /// One block of the recording - stored blocks oldest first, then the block being filled
+    pub fn chunk<S: NonVolatileStorage>(&self, storage: &mut S, offset: u32) -> HalResult<FlightRecordChunk> {
+        let mut blocks: Vec<FlightBlock> = read_ring(storage)?.into_iter().map(|(_, block)| block).collect();
+        blocks.sort_by_key(|block| block.sequence);
+        blocks.extend(self.block.clone());
+        
+        let samples = blocks.get(offset as usize).map(FlightBlock::samples).unwrap_or_default();
+        Ok(FlightRecordChunk { offset, total: blocks.len() as u32, samples })
+    }
+    
+    /// Current counters
+    pub fn status(&self) -> FlightRecorderStatus {
+        FlightRecorderStatus {
+            enabled: self.config.enabled,
+            session: self.session,
+            blocks_stored: self.occupied.iter().filter(|occupied| **occupied).count() as u32,
+            blocks_written: self.blocks_written,
+            blocks_dropped: self.blocks_dropped,
+            write_errors: self.write_errors,
+        }
+    }
+    
+    /// Blocks written since power-up
+    pub fn blocks_written(&self) -> u32 {
+        self.blocks_written
+    }
+    
+    fn refill_budget(&mut self, now_ms: u32) {
+        if let Some(last_ms) = self.last_refill_ms {
+            let per_ms = self.config.blocks_per_hour as f32 / 3_600_000.0;
+            self.budget = (self.budget + now_ms.wrapping_sub(last_ms) as f32 * per_ms).min(FLIGHT_RECORDER_BLOCKS as f32);
+        }
+        self.last_refill_ms = Some(now_ms);
+    }
+    
+    /// Turn the finished interval into a sample, closing blocks as needed
+    fn finish_interval<S: NonVolatileStorage>(&mut self, storage: &mut S, interval: Interval, now_ms: u32, close: bool) {
+        self.interval = None;
+        let interval_ms = self.config.sample_interval_ms as u16;
+        let boost = libm::roundf(interval.peak_boost_psi / BOOST_STEP_PSI).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        let duty = libm::roundf(interval.peak_duty_percent / DUTY_STEP_PERCENT).clamp(0.0, 200.0) as u8;
+        
+        let accepted = self.block.as_mut().is_some_and(|block| block.push(interval.state, interval_ms, boost, duty, now_ms));
+        if !accepted {
+            self.close_block(storage);
+            let mut block = FlightBlock::new(self.session, interval_ms, interval.state);
+            block.push(interval.state, interval_ms, boost, duty, now_ms);
+            self.block = Some(block);
+        }
+        if close || self.block.as_ref().is_some_and(FlightBlock::is_full) {
+            self.close_block(storage);
+        }
+    }
+    
+    /// Write the open block to the next ring slot, budget permitting
+    fn close_block<S: NonVolatileStorage>(&mut self, storage: &mut S) {
+        let Some(mut block) = self.block.take() else { return };
+        if self.budget < 1.0 {
+            self.blocks_dropped += 1;
+            return;
+        }
+        self.budget -= 1.0;
+        
+        block.sequence = self.next_sequence;
+        let slot = self.next_slot;
+        let address = StorageRegion::FLIGHT_RECORDER.offset + slot * FLIGHT_BLOCK_SIZE;
+        match storage.write(address, &block.encode()) {
+            Ok(()) => {
+                self.blocks_written += 1;
+                self.occupied[slot] = true;
+            },
+            Err(_) => {
+                // The slot may hold a torn block now - its CRC keeps it out of the recording
+                self.write_errors += 1;
+                self.occupied[slot] = false;
+            },
+        }
+        self.next_slot = (slot + 1) % FLIGHT_RECORDER_BLOCKS;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+    }
+}
+
+/// Every valid block in the ring with its slot
+fn read_ring<S: NonVolatileStorage>(storage: &mut S) -> HalResult<Vec<(usize, FlightBlock)>> {
+    let mut blocks = Vec::new();
+    for slot in 0..FLIGHT_RECORDER_BLOCKS {
+        let mut bytes = [0; FLIGHT_BLOCK_SIZE];
+        storage.read(StorageRegion::FLIGHT_RECORDER.offset + slot * FLIGHT_BLOCK_SIZE, &mut bytes)?;
+        if let Some(block) = FlightBlock::decode(&bytes) {
+            blocks.push((slot, block));
+        }
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::RamStorage;
+    use rumbledome_hal::ERASED_BYTE;
+    
+    fn offload(recorder: &FlightRecorder, storage: &mut RamStorage) -> Vec<FlightSample> {
+        let mut samples = Vec::new();
+        let mut offset = 0;
+        loop {
+            let chunk = recorder.chunk(storage, offset).unwrap();
+            if offset >= chunk.total {
+                return samples;
+            }
+            samples.extend(chunk.samples);
+            offset += 1;
+        }
+    }
+    
+    #[test]
+    fn test_block_round_trip_and_integrity() {
+        let mut block = FlightBlock::new(3, 2_500, FlightState::Armed);
+        assert!(block.push(FlightState::Armed, 2_500, 85, 80, 10_000));
+        assert!(block.push(FlightState::Armed, 2_500, 140, 110, 12_500));
+        assert!(block.push(FlightState::Armed, 2_500, 20, 0, 15_000));
+        // Deltas beyond one byte, another state or another interval start a new block
+        assert!(!block.push(FlightState::Armed, 2_500, 200, 0, 17_500));
+        assert!(!block.push(FlightState::OverboostCut, 2_500, 20, 0, 17_500));
+        assert!(!block.push(FlightState::Armed, 1_000, 20, 0, 17_500));
+        
+        let bytes = block.encode();
+        assert_eq!(FlightBlock::decode(&bytes), Some(block.clone()));
+        let samples = block.samples();
+        assert_eq!(samples[0].timestamp_ms, 10_000);
+        assert!((samples[1].boost_psi - 14.0).abs() < 1e-4);
+        assert_eq!(samples[1].duty_percent, 55.0);
+        
+        let mut corrupt = bytes;
+        corrupt[20] ^= 0x01;
+        assert_eq!(FlightBlock::decode(&corrupt), None);
+        assert_eq!(FlightBlock::decode(&[ERASED_BYTE; FLIGHT_BLOCK_SIZE]), None);
+    }
+    
+    #[test]
+    fn test_ring_survives_torn_write_and_power_cycle() {
+        let mut storage = RamStorage::new();
+        let config = FlightRecorderConfig { sample_interval_ms: 1_000, blocks_per_hour: 360, ..Default::default() };
+        let mut recorder = FlightRecorder::new(config.clone());
+        
+        // Steady boost fills a block every 23 samples; twenty blocks wrap the ring
+        let mut now_ms = 0;
+        let mut run = |recorder: &mut FlightRecorder, storage: &mut RamStorage, seconds: u32| {
+            for _ in 0..seconds * 10 {
+                recorder.record(storage, &SystemState::Armed, 8.0 + (now_ms / 1_000 % 7) as f32, 40.0, now_ms);
+                now_ms += 100;
+            }
+        };
+        run(&mut recorder, &mut storage, 20 * FLIGHT_SAMPLES_PER_BLOCK as u32 + 1);
+        assert_eq!(recorder.status().blocks_written, 20);
+        assert_eq!(recorder.status().blocks_stored, FLIGHT_RECORDER_BLOCKS as u32);
+        
+        // Key-off halfway through the next block write
+        storage.tear_after = Some(FLIGHT_BLOCK_SIZE / 2);
+        run(&mut recorder, &mut storage, FLIGHT_SAMPLES_PER_BLOCK as u32);
+        assert_eq!(recorder.status().write_errors, 1);
+        
+        let mut restarted = FlightRecorder::new(config);
+        restarted.restore(&mut storage).unwrap();
+        assert_eq!(restarted.status().session, 1);
+        assert_eq!(restarted.status().blocks_stored, FLIGHT_RECORDER_BLOCKS as u32 - 1);
+        
+        // The torn block overwrote the oldest; the rest reads back in order
+        let samples = offload(&restarted, &mut storage);
+        assert_eq!(samples.len(), (FLIGHT_RECORDER_BLOCKS - 1) * FLIGHT_SAMPLES_PER_BLOCK);
+        assert!(samples.windows(2).all(|pair| pair[1].timestamp_ms == pair[0].timestamp_ms + 1_000));
+        assert!(samples.iter().all(|sample| (8.0..=14.0).contains(&sample.boost_psi) && sample.duty_percent == 40.0));
+        
+        // The new session writes into the torn slot first
+        restarted.record(&mut storage, &SystemState::Idle, 0.0, 0.0, 0);
+        restarted.record(&mut storage, &SystemState::Fault(crate::FaultCode::CanCommunicationLost), 0.0, 0.0, 50);
+        let samples = offload(&restarted, &mut storage);
+        let last = samples.last().unwrap();
+        assert_eq!((last.session, last.state), (1, FlightState::Idle));
+        assert_eq!(restarted.status().blocks_stored, FLIGHT_RECORDER_BLOCKS as u32);
+    }
+    
+    #[test]
+    fn test_state_change_writes_block_immediately() {
+        let mut storage = RamStorage::new();
+        let mut recorder = FlightRecorder::new(FlightRecorderConfig::default());
+        for t in (0..10_000).step_by(10) {
+            recorder.record(&mut storage, &SystemState::Armed, if t == 6_000 { 22.5 } else { 12.0 }, 60.0, t);
+        }
+        assert_eq!(recorder.status().blocks_written, 0);
+        
+        recorder.record(&mut storage, &SystemState::OverboostCut, 23.0, 0.0, 10_000);
+        assert_eq!(recorder.status().blocks_written, 1);
+        
+        // The spike survives decimation as its interval's peak
+        let stored = recorder.chunk(&mut storage, 0).unwrap();
+        assert_eq!(stored.total, 1);
+        assert_eq!(stored.samples.len(), 4);
+        assert!((stored.samples[2].boost_psi - 22.5).abs() < 1e-4);
+        assert_eq!(stored.samples[3].timestamp_ms, 10_000);
+    }
+    
+    #[test]
+    fn test_write_budget_drops_blocks() {
+        let mut storage = RamStorage::new();
+        let config = FlightRecorderConfig { sample_interval_ms: 500, blocks_per_hour: 10, ..Default::default() };
+        let mut recorder = FlightRecorder::new(config);
+        
+        // Toggling state closes a block every sample - far faster than ten an hour
+        for i in 0..40u32 {
+            let state = if i % 2 == 0 { SystemState::Armed } else { SystemState::Idle };
+            recorder.record(&mut storage, &state, 5.0, 20.0, i * 500);
+        }
+        let status = recorder.status();
+        assert_eq!(status.blocks_written, FLIGHT_RECORDER_BLOCKS as u32);
+        assert_eq!(status.blocks_written + status.blocks_dropped, 39);
+        
+        assert!(FlightRecorderConfig { blocks_per_hour: 5, ..Default::default() }.validate().is_err());
+        assert!(FlightRecorderConfig { sample_interval_ms: 100, ..Default::default() }.validate().is_err());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::idle_inputs;
+    use crate::{RumbleDomeCore, SystemConfig};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_recording_survives_power_cycle() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        for t in (0..60_000).step_by(50) {
+            core.execute_control_cycle_with_inputs(idle_inputs(t)).unwrap();
+        }
+        assert_eq!(core.flight_recorder.status().blocks_written, 1);
+        
+        // Key-off, then key-on with the same EEPROM
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.flight_recorder.status().session, 1);
+        
+        let chunk = core.flight_record(0).unwrap();
+        assert_eq!(chunk.total, 1);
+        assert_eq!(chunk.samples.len(), FLIGHT_SAMPLES_PER_BLOCK);
+        assert!(chunk.samples.iter().all(|sample| sample.session == 0 && sample.state == FlightState::Idle));
+        assert!((chunk.samples[0].boost_psi + 10.0).abs() < 1e-4);
+    }
+}
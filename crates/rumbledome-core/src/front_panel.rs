@@ -0,0 +1,160 @@
+//! Front Panel Buttons and Status LED
+//! 
+//! 🔗 T4-CORE-129: Scramble and Profile Buttons
+//! Derived From: T4-HAL-025 (Button and Status LED GPIO) + T4-CORE-050 (Configuration Switch)
+//! AI Traceability: Physical controls the driver can use without a phone or laptop, with state visible at a glance
+//! 
+//! The scramble button is a level: held is scramble, released is not. The
+//! profile button steps through the stored configurations - a short press
+//! selects the next, a double press the previous, and a long press the
+//! built-in spring-only profile. Switching follows the same rule as any
+//! other switch, so a press while boost is controlled is dropped rather than
+//! held until later.
+//! 
+//! The status LED shows the system state for a controller mounted where the
+//! display cannot be seen.
+
+use alloc::string::{String, ToString};
+use rumbledome_hal::{Button, ButtonEvent, GpioControl, GpioInput, LedPattern, StatusLed};
+use crate::{SystemState, SPRING_ONLY_PROFILE};
+
+/// Status LED pattern for a system state
+pub fn led_pattern(state: &SystemState) -> LedPattern {
+    match state {
+        SystemState::Initializing => LedPattern::On,
+        SystemState::Idle => LedPattern::SlowBlink,
+        SystemState::Armed => LedPattern::On,
+        SystemState::Calibrating(_) | SystemState::Commissioning(_) => LedPattern::DoubleBlink,
+        SystemState::OverboostCut | SystemState::Fault(_) => LedPattern::FastBlink,
+    }
+}
+
+/// Configuration a profile button press selects
+/// 
+/// `names` are the stored configurations in creation order and `active` the
+/// entry in use (the one the spring-only profile runs on while it is
+/// selected). Any press other than a long one leaves the spring-only profile
+/// for the entry it ran on. Returns `None` when the press changes nothing.
+pub fn profile_for_press(event: ButtonEvent, names: &[String], active: &str, spring_only: bool) -> Option<String> {
+    if event == ButtonEvent::LongPress {
+        return (!spring_only).then(|| SPRING_ONLY_PROFILE.to_string());
+    }
+    if spring_only {
+        return Some(active.to_string());
+    }
+    
+    let index = names.iter().position(|name| name == active)?;
+    let step = match event {
+        ButtonEvent::DoublePress => names.len() - 1,
+        _ => 1,
+    };
+    let next = &names[(index + step) % names.len()];
+    (next != active).then(|| next.clone())
+}
+
+/// Debounced buttons and the status LED
+#[derive(Debug, Clone, Default)]
+pub struct FrontPanel {
+    scramble: Button,
+    profile: Button,
+    led: StatusLed,
+    profile_press: Option<ButtonEvent>,
+}
+
+impl FrontPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Sample both buttons (input phase); returns whether scramble is held
+    /// 
+    /// A button the platform cannot read counts as released.
+    pub fn read(&mut self, gpio: &impl GpioControl, now_ms: u32) -> bool {
+        let level = |input| gpio.read_input(input).unwrap_or(false);
+        let scramble_raw = level(GpioInput::ScrambleButton);
+        let profile_raw = level(GpioInput::ProfileButton);
+        
+        // Scramble only acts while held; its presses need no classification
+        self.scramble.update(scramble_raw, now_ms);
+        if let Some(press) = self.profile.update(profile_raw, now_ms) {
+            self.profile_press = Some(press);
+        }
+        self.scramble.is_pressed()
+    }
+    
+    /// Profile button press not yet acted on
+    pub fn take_profile_press(&mut self) -> Option<ButtonEvent> {
+        self.profile_press.take()
+    }
+    
+    /// Show the system state on the status LED (output phase)
+    /// 
+    /// A platform without the LED is not an error.
+    pub fn show(&mut self, state: &SystemState, gpio: &mut impl GpioControl, now_ms: u32) {
+        self.led.set_pattern(led_pattern(state));
+        let _ = self.led.update(gpio, now_ms);
+    }
+    
+    /// Pattern on the status LED
+    pub fn led_pattern(&self) -> LedPattern {
+        self.led.pattern()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use rumbledome_hal::MockHal;
+    use crate::FaultCode;
+    
+    fn names() -> Vec<String> {
+        vec!["valet".to_string(), "daily".to_string(), "track".to_string()]
+    }
+    
+    #[test]
+    fn test_profile_presses_step_through_library() {
+        let names = names();
+        assert_eq!(profile_for_press(ButtonEvent::ShortPress, &names, "track", false).as_deref(), Some("valet"));
+        assert_eq!(profile_for_press(ButtonEvent::DoublePress, &names, "valet", false).as_deref(), Some("track"));
+        assert_eq!(profile_for_press(ButtonEvent::LongPress, &names, "daily", false).as_deref(), Some(SPRING_ONLY_PROFILE));
+        // Any other press leaves the spring-only profile for the entry it ran on
+        assert_eq!(profile_for_press(ButtonEvent::ShortPress, &names, "daily", true).as_deref(), Some("daily"));
+        assert_eq!(profile_for_press(ButtonEvent::LongPress, &names, "daily", true), None);
+        assert_eq!(profile_for_press(ButtonEvent::ShortPress, &names[..1], "valet", false), None);
+    }
+    
+    #[test]
+    fn test_led_follows_state() {
+        assert_eq!(led_pattern(&SystemState::Armed), LedPattern::On);
+        assert_eq!(led_pattern(&SystemState::Fault(FaultCode::SelfTestFailed)), LedPattern::FastBlink);
+        
+        let mut panel = FrontPanel::new();
+        let mut hal = MockHal::new();
+        panel.show(&SystemState::Idle, &mut hal, 100);
+        assert!(hal.status_led());
+        panel.show(&SystemState::Idle, &mut hal, 600);
+        assert!(!hal.status_led());
+    }
+    
+    #[test]
+    fn test_buttons_read_through_debounce() {
+        let mut panel = FrontPanel::new();
+        let mut hal = MockHal::new();
+        hal.set_button(GpioInput::ScrambleButton, true);
+        assert!(!panel.read(&hal, 0));
+        assert!(panel.read(&hal, 50));
+        
+        hal.set_button(GpioInput::ProfileButton, true);
+        panel.read(&hal, 100);
+        panel.read(&hal, 200);
+        hal.set_button(GpioInput::ProfileButton, false);
+        panel.read(&hal, 300);
+        panel.read(&hal, 400);
+        assert_eq!(panel.take_profile_press(), None);
+        panel.read(&hal, 800);
+        assert_eq!(panel.take_profile_press(), Some(ButtonEvent::ShortPress));
+        assert_eq!(panel.take_profile_press(), None);
+    }
+}
@@ -0,0 +1,294 @@
+//! Gear Estimation and Per-Gear Boost Scaling
+//! 
+//! 🔗 T4-CORE-137: Gear-Aware Boost Targeting
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + T4-CORE-135 (Load Check for Learning)
+//! AI Traceability: Full boost in first and second only spins the tyres - scale the target by gear without needing a gear signal on the bus
+//! 
+//! No gear position is broadcast, so the gear is estimated from engine RPM
+//! per km/h of vehicle speed. Each gear is a cluster of that ratio; the
+//! clusters start at the configured ratios and follow what is measured, so
+//! a different tyre size or final drive is taken up over a few drives. A
+//! ratio within `tolerance` of a cluster for `stable_ms` selects that gear;
+//! a ratio between clusters (clutch slipping, mid-shift, neutral) keeps the
+//! last gear. Pulling away below `min_speed_kph` counts as first gear.
+//! 
+//! Each gear's `boost_scale` shrinks the Level 1 boost target above spring
+//! pressure - 0.7 in first gives 70 % of the boost the target asks for over
+//! the spring. Without a vehicle speed reading nothing is scaled.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Most gears supported
+pub const MAX_GEARS: usize = 10;
+
+/// Share of each measurement a cluster moves toward
+const CLUSTER_RATE: f32 = 0.01;
+
+/// Gear estimation and per-gear boost scaling settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GearBoostConfig {
+    /// Scale the boost target by the estimated gear
+    pub enabled: bool,
+    /// RPM per km/h in each gear, first gear first (initial cluster positions)
+    pub rpm_per_kph: Vec<f32>,
+    /// Boost target scale above spring pressure in each gear, first gear first (missing gears: 1.0)
+    pub boost_scale: Vec<f32>,
+    /// Largest relative distance from a cluster that still matches it
+    pub tolerance: f32,
+    /// Time a ratio must match a gear before it is selected (ms)
+    pub stable_ms: u32,
+    /// Speed below which the car is pulling away in first gear (km/h)
+    pub min_speed_kph: f32,
+}
+
+impl Default for GearBoostConfig {
+    /// MT82 six-speed, 3.55 final drive, 275/40R19 tyres
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rpm_per_kph: vec![105.6, 70.1, 48.8, 38.1, 28.9, 18.8],
+            boost_scale: vec![1.0; 6],
+            tolerance: 0.08,
+            stable_ms: 300,
+            min_speed_kph: 5.0,
+        }
+    }
+}
+
+impl GearBoostConfig {
+    /// Validate gear ratios and scales
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.rpm_per_kph.is_empty() || self.rpm_per_kph.len() > MAX_GEARS {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear ratios must list 1-{} gears, got {}", MAX_GEARS, self.rpm_per_kph.len())
+            ));
+        }
+        if self.rpm_per_kph.iter().any(|ratio| !(5.0..=250.0).contains(ratio)) {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear ratios must be 5-250 RPM per km/h, got {:?}", self.rpm_per_kph)
+            ));
+        }
+        // Neighbouring gears must not overlap within the match tolerance
+        if self.rpm_per_kph.windows(2).any(|pair| pair[1] * (1.0 + self.tolerance) >= pair[0] * (1.0 - self.tolerance)) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Gear ratios must fall from first gear and stay {:.0}% apart, got {:?}", self.tolerance * 200.0, self.rpm_per_kph
+            )));
+        }
+        if self.boost_scale.len() > self.rpm_per_kph.len() {
+            return Err(CoreError::ConfigurationError(format!(
+                "Gear boost scales list {} gears but only {} ratios are configured", self.boost_scale.len(), self.rpm_per_kph.len()
+            )));
+        }
+        if self.boost_scale.iter().any(|scale| !(0.0..=1.0).contains(scale)) {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear boost scales must be 0.0-1.0, got {:?}", self.boost_scale)
+            ));
+        }
+        if !(0.02..=0.3).contains(&self.tolerance) {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear match tolerance must be 0.02-0.3, got {}", self.tolerance)
+            ));
+        }
+        if self.stable_ms > 5000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear stable time must be at most 5000 ms, got {}", self.stable_ms)
+            ));
+        }
+        if !(1.0..=30.0).contains(&self.min_speed_kph) {
+            return Err(CoreError::ConfigurationError(
+                format!("Gear minimum speed must be 1-30 km/h, got {}", self.min_speed_kph)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Boost scale for a gear (1-based)
+    pub fn scale_for(&self, gear: u8) -> f32 {
+        self.boost_scale.get(gear as usize - 1).copied().unwrap_or(1.0)
+    }
+}
+
+/// Gear estimate status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GearStatus {
+    /// Estimated gear (1 = first), once known
+    pub gear: Option<u8>,
+    /// Latest RPM per km/h, while moving
+    pub rpm_per_kph: Option<f32>,
+    /// Scale applied to the boost target above spring pressure
+    pub boost_scale: f32,
+    /// A gear-based boost reduction is in effect
+    pub reduction_active: bool,
+    /// Current cluster positions, first gear first (RPM per km/h)
+    pub clusters: Vec<f32>,
+}
+
+impl Default for GearStatus {
+    fn default() -> Self {
+        Self { gear: None, rpm_per_kph: None, boost_scale: 1.0, reduction_active: false, clusters: Vec::new() }
+    }
+}
+
+/// Estimates the gear from RPM and vehicle speed
+#[derive(Debug, Clone)]
+pub struct GearEstimator {
+    config: GearBoostConfig,
+    clusters: Vec<f32>,
+    /// Gear the ratio currently matches, and since when
+    candidate: Option<(u8, u32)>,
+    status: GearStatus,
+}
+
+impl GearEstimator {
+    /// Create with clusters at the configured ratios and no gear known
+    pub fn new(config: GearBoostConfig) -> Self {
+        let clusters = config.rpm_per_kph.clone();
+        Self { config, clusters, candidate: None, status: GearStatus::default() }
+    }
+    
+    /// Apply changed settings; clusters restart from the configured ratios
+    pub fn reconfigure(&mut self, config: GearBoostConfig) {
+        *self = Self::new(config);
+    }
+    
+    /// Update from one cycle; returns true when a gear with a boost reduction is selected
+    pub fn update(&mut self, rpm: u16, vehicle_speed_kph: Option<f32>, now_ms: u32) -> bool {
+        let previous_gear = self.status.gear;
+        if !self.config.enabled {
+            self.status = GearStatus::default();
+            return false;
+        }
+        
+        let (gear, rpm_per_kph) = match vehicle_speed_kph {
+            None => (None, None),
+            Some(kph) if kph < self.config.min_speed_kph => {
+                self.candidate = None;
+                (Some(1), None)
+            },
+            Some(kph) => {
+                let ratio = rpm as f32 / kph;
+                (self.track(ratio, now_ms), Some(ratio))
+            },
+        };
+        
+        let boost_scale = gear.map_or(1.0, |gear| self.config.scale_for(gear));
+        self.status = GearStatus {
+            gear,
+            rpm_per_kph,
+            boost_scale,
+            reduction_active: boost_scale < 1.0,
+            clusters: self.clusters.clone(),
+        };
+        self.status.reduction_active && gear != previous_gear
+    }
+    
+    /// Scale a boost target above spring pressure for the estimated gear (PSI)
+    pub fn scale_target(&self, target_psi: f32, spring_psi: f32) -> f32 {
+        if target_psi <= spring_psi {
+            return target_psi;
+        }
+        spring_psi + (target_psi - spring_psi) * self.status.boost_scale
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &GearStatus {
+        &self.status
+    }
+    
+    /// Match a ratio to a cluster; returns the gear in effect
+    fn track(&mut self, ratio: f32, now_ms: u32) -> Option<u8> {
+        let nearest = self.clusters.iter().enumerate()
+            .map(|(index, center)| (index, (ratio - center).abs() / center))
+            .filter(|(_, distance)| *distance <= self.config.tolerance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+        
+        let Some(index) = nearest else {
+            // Between gears - keep the last one until a ratio settles
+            self.candidate = None;
+            return self.status.gear;
+        };
+        
+        let gear = index as u8 + 1;
+        let since = match self.candidate {
+            Some((candidate, since)) if candidate == gear => since,
+            _ => now_ms,
+        };
+        self.candidate = Some((gear, since));
+        if now_ms.wrapping_sub(since) < self.config.stable_ms {
+            return self.status.gear;
+        }
+        
+        // Held in gear - the cluster follows the measured ratio
+        self.clusters[index] += (ratio - self.clusters[index]) * CLUSTER_RATE;
+        Some(gear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn traction_config() -> GearBoostConfig {
+        GearBoostConfig { enabled: true, boost_scale: vec![0.6, 0.8], ..GearBoostConfig::default() }
+    }
+    
+    /// Hold a speed for `duration_ms`; returns whether a reduction began
+    fn cruise(estimator: &mut GearEstimator, rpm: u16, kph: f32, start_ms: u32, duration_ms: u32) -> bool {
+        (start_ms..start_ms + duration_ms).step_by(10)
+            .filter(|&t| estimator.update(rpm, Some(kph), t))
+            .count() > 0
+    }
+    
+    #[test]
+    fn test_gears_selected_after_stable_ratio() {
+        let mut estimator = GearEstimator::new(traction_config());
+        
+        // Pulling away counts as first gear
+        assert!(estimator.update(2000, Some(2.0), 0));
+        assert_eq!(estimator.status().gear, Some(1));
+        assert_eq!(estimator.scale_target(15.0, 5.0), 11.0);
+        
+        // Second gear at 70 RPM per km/h, once held for the stable time
+        assert!(!estimator.update(3500, Some(50.0), 10));
+        assert_eq!(estimator.status().gear, Some(1));
+        assert!(cruise(&mut estimator, 3500, 50.0, 20, 400));
+        assert_eq!(estimator.status().gear, Some(2));
+        assert_eq!(estimator.status().boost_scale, 0.8);
+        
+        // Clutch in mid-shift keeps second; third has no reduction
+        estimator.update(1500, Some(60.0), 500);
+        assert_eq!(estimator.status().gear, Some(2));
+        assert!(!cruise(&mut estimator, 2900, 60.0, 510, 400));
+        assert_eq!(estimator.status().gear, Some(3));
+        assert!(!estimator.status().reduction_active);
+        assert_eq!(estimator.scale_target(15.0, 5.0), 15.0);
+    }
+    
+    #[test]
+    fn test_clusters_follow_measured_ratio() {
+        let mut estimator = GearEstimator::new(traction_config());
+        
+        // Taller tyres: third gear reads 46 instead of 48.8
+        cruise(&mut estimator, 2760, 60.0, 0, 10_000);
+        assert_eq!(estimator.status().gear, Some(3));
+        assert!((estimator.status().clusters[2] - 46.0).abs() < 0.5);
+        
+        // No speed reading - nothing known, nothing scaled
+        estimator.update(2760, None, 10_000);
+        assert_eq!(estimator.status().gear, None);
+        assert_eq!(estimator.status().boost_scale, 1.0);
+    }
+    
+    #[test]
+    fn test_overlapping_ratios_rejected() {
+        let config = GearBoostConfig { rpm_per_kph: vec![50.0, 48.0], ..traction_config() };
+        assert!(config.validate().is_err());
+        assert!(traction_config().validate().is_ok());
+    }
+}
@@ -0,0 +1,247 @@
+//! Golden Trace Regression
+//! 
+//! 🔗 T4-CORE-087: Golden Trace Format
+//! Derived From: T4-CORE-008 (Main Control Loop Implementation) + T4-SIMULATOR-005 (Headless Scenario Execution)
+//! AI Traceability: Catch unintended control behavior shifts by replaying recorded cycles against expected outputs
+//! 
+//! A trace records the inputs of every control cycle from a known-good run
+//! and the outputs the core produced for them. Replaying the inputs open-loop
+//! against changed control code must reproduce those outputs within the
+//! trace's tolerances. Core unit tests and the simulator replay the same
+//! format, so a trace captured from a simulator run can guard either.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::HalTrait;
+use crate::{CoreError, RumbleDomeCore, SystemConfig, SystemInputs, SystemState};
+
+/// Trace format version - bumped when fields change incompatibly
+pub const GOLDEN_TRACE_VERSION: u32 = 1;
+
+/// Mismatches kept in detail in a report (later ones are only counted)
+pub const MAX_REPORTED_MISMATCHES: usize = 20;
+
+/// Allowed difference between expected and replayed outputs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TraceTolerance {
+    /// Boost target (PSI)
+    pub target_boost_psi: f32,
+    /// Solenoid duty (%)
+    pub duty_percent: f32,
+}
+
+impl Default for TraceTolerance {
+    fn default() -> Self {
+        Self {
+            target_boost_psi: 0.05,
+            duty_percent: 0.5,
+        }
+    }
+}
+
+/// Outputs compared for each control cycle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CycleOutputs {
+    /// System state after the cycle
+    pub state: SystemState,
+    /// Boost target (PSI)
+    pub target_boost_psi: f32,
+    /// Solenoid duty commanded (%)
+    pub duty_percent: f32,
+}
+
+impl CycleOutputs {
+    /// Whether these outputs match `expected` within tolerance (state must match exactly)
+    pub fn matches(&self, expected: &CycleOutputs, tolerance: &TraceTolerance) -> bool {
+        self.state == expected.state
+            && (self.target_boost_psi - expected.target_boost_psi).abs() <= tolerance.target_boost_psi
+            && (self.duty_percent - expected.duty_percent).abs() <= tolerance.duty_percent
+    }
+}
+
+/// One recorded control cycle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceCycle {
+    /// CAN message IDs received since the previous cycle
+    #[serde(default)]
+    pub can_messages: Vec<u32>,
+    /// Inputs the cycle ran with
+    pub inputs: SystemInputs,
+    /// Outputs the known-good run produced
+    pub expected: CycleOutputs,
+}
+
+/// Recorded run with expected outputs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenTrace {
+    /// Trace format version
+    pub version: u32,
+    /// Trace name
+    pub name: String,
+    /// Configuration the run used
+    pub config: SystemConfig,
+    /// State the core must be in before the first cycle
+    pub start_state: SystemState,
+    /// Allowed output differences
+    pub tolerance: TraceTolerance,
+    /// Recorded cycles, in order
+    pub cycles: Vec<TraceCycle>,
+}
+
+impl GoldenTrace {
+    /// Start an empty trace for a run beginning in `start_state`
+    pub fn new(name: &str, config: SystemConfig, start_state: SystemState) -> Self {
+        Self {
+            version: GOLDEN_TRACE_VERSION,
+            name: name.into(),
+            config,
+            start_state,
+            tolerance: TraceTolerance::default(),
+            cycles: Vec::new(),
+        }
+    }
+    
+    /// Append one cycle
+    pub fn record(&mut self, can_messages: &[u32], inputs: SystemInputs, expected: CycleOutputs) {
+        self.cycles.push(TraceCycle { can_messages: can_messages.to_vec(), inputs, expected });
+    }
+    
+    /// Replay the recorded inputs and compare outputs cycle by cycle
+    /// 
+    /// The core must be built from the trace configuration and brought to the
+    /// start state by the caller. Inputs are replayed open-loop: once outputs
+    /// diverge, later cycles usually diverge too, so the first mismatch is the
+    /// one to look at.
+    pub fn replay<H: HalTrait>(&self, core: &mut RumbleDomeCore<H>) -> Result<TraceReport, CoreError> {
+        if self.version != GOLDEN_TRACE_VERSION {
+            return Err(CoreError::ConfigurationError(
+                format!("Unsupported golden trace version {}", self.version)
+            ));
+        }
+        if core.state != self.start_state {
+            return Err(CoreError::InvalidState(format!(
+                "Trace starts in state {} but core is in {}", self.start_state.display_text(), core.state.display_text()
+            )));
+        }
+        
+        let mut report = TraceReport::default();
+        for (index, cycle) in self.cycles.iter().enumerate() {
+            for &id in &cycle.can_messages {
+                core.can_health.record_message(id, cycle.inputs.timestamp_ms);
+            }
+            core.execute_control_cycle_with_inputs(cycle.inputs.clone())?;
+            
+            let actual = core.cycle_outputs();
+            report.cycles_checked += 1;
+            if !actual.matches(&cycle.expected, &self.tolerance) {
+                report.mismatch_count += 1;
+                if report.mismatches.len() < MAX_REPORTED_MISMATCHES {
+                    report.mismatches.push(TraceMismatch {
+                        cycle: index,
+                        timestamp_ms: cycle.inputs.timestamp_ms,
+                        expected: cycle.expected.clone(),
+                        actual,
+                    });
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Cycle whose replayed outputs left tolerance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceMismatch {
+    /// Cycle index in the trace
+    pub cycle: usize,
+    /// Cycle timestamp (ms)
+    pub timestamp_ms: u32,
+    /// Recorded outputs
+    pub expected: CycleOutputs,
+    /// Replayed outputs
+    pub actual: CycleOutputs,
+}
+
+/// Result of replaying a golden trace
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TraceReport {
+    /// Cycles replayed
+    pub cycles_checked: usize,
+    /// Cycles out of tolerance
+    pub mismatch_count: usize,
+    /// First mismatches in detail
+    pub mismatches: Vec<TraceMismatch>,
+}
+
+impl TraceReport {
+    /// Whether every cycle matched
+    pub fn passed(&self) -> bool {
+        self.mismatch_count == 0
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, armed_core, TORQUE_MESSAGE_ID};
+    use rumbledome_hal::MockHal;
+    
+    /// Tip-in at 4000 RPM with boost building
+    fn inputs(timestamp_ms: u32) -> SystemInputs {
+        let progress = (timestamp_ms as f32 / 1000.0).min(1.0);
+        SystemInputs {
+            desired_torque: 450.0,
+            actual_torque: 300.0 + 120.0 * progress,
+            manifold_pressure: 8.0 * progress,
+            throttle_position: Some(100.0),
+            dome_input_pressure: 0.0,
+            ..test_support::inputs(timestamp_ms)
+        }
+    }
+    
+    fn capture(config: &SystemConfig) -> GoldenTrace {
+        let mut core = armed_core(config.clone());
+        let mut trace = GoldenTrace::new("tip-in", config.clone(), core.state.clone());
+        for t in (0..2000).step_by(10) {
+            core.can_health.record_message(TORQUE_MESSAGE_ID, t);
+            core.execute_control_cycle_with_inputs(inputs(t)).unwrap();
+            trace.record(&[TORQUE_MESSAGE_ID], inputs(t), core.cycle_outputs());
+        }
+        trace
+    }
+    
+    #[test]
+    fn test_replay_reproduces_captured_run() {
+        let config = SystemConfig::default();
+        let trace = capture(&config);
+        
+        let report = trace.replay(&mut armed_core(config.clone())).unwrap();
+        assert_eq!(report.cycles_checked, 200);
+        assert!(report.passed(), "{:?}", report.mismatches.first());
+    }
+    
+    #[test]
+    fn test_behavior_shift_is_caught() {
+        let trace = capture(&SystemConfig::default());
+        
+        // Stand-in for a control code change: the same inputs now produce a lower target
+        let shifted = SystemConfig { max_boost_psi: 4.0, ..SystemConfig::default() };
+        let report = trace.replay(&mut armed_core(shifted)).unwrap();
+        
+        assert!(!report.passed());
+        assert!(report.mismatches.len() <= MAX_REPORTED_MISMATCHES);
+        let first = &report.mismatches[0];
+        assert!(first.actual.target_boost_psi < first.expected.target_boost_psi);
+    }
+    
+    #[test]
+    fn test_start_state_must_match() {
+        let config = SystemConfig::default();
+        let trace = capture(&config);
+        
+        let mut idle = RumbleDomeCore::new(MockHal::new(), config);
+        assert!(matches!(trace.replay(&mut idle), Err(CoreError::InvalidState(_))));
+    }
+}
@@ -0,0 +1,176 @@
+//! Limit Headroom
+//! 
+//! 🔗 T4-CORE-076: Soft-Limit Headroom
+//! Derived From: T1-SAFETY-001 (Overboost as Fault Condition) + T4-CORE-042 (Boost Taper Approaching Redline)
+//! AI Traceability: Show how close each pull runs to every limit before one of them intervenes
+
+use serde::{Deserialize, Serialize};
+use crate::{DisplayString, Language, SystemConfig};
+
+/// Limit the controller can run into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LimitKind {
+    /// Overboost cut pressure
+    Overboost,
+    /// Profile maximum boost
+    ProfileMax,
+    /// Start of the rev limit taper band
+    RevLimitTaper,
+    /// Full output authority (100% duty or actuator fully closed)
+    DutySaturation,
+    /// Boost cap pulled down by intake air or coolant temperature
+    ThermalDerate,
+}
+
+impl LimitKind {
+    /// Short label for status displays
+    pub fn display_text(&self) -> &'static str {
+        self.localized_text(Language::English)
+    }
+    
+    /// Short label in the given language
+    pub fn localized_text(&self, language: Language) -> &'static str {
+        match self {
+            LimitKind::Overboost => DisplayString::LimitOverboost.text(language),
+            LimitKind::ProfileMax => DisplayString::LimitProfileMax.text(language),
+            LimitKind::RevLimitTaper => DisplayString::LimitRevTaper.text(language),
+            LimitKind::DutySaturation => DisplayString::LimitDuty.text(language),
+            LimitKind::ThermalDerate => DisplayString::LimitThermal.text(language),
+        }
+    }
+    
+    /// Unit the margin is expressed in
+    pub fn unit(&self) -> &'static str {
+        match self {
+            LimitKind::Overboost | LimitKind::ProfileMax | LimitKind::ThermalDerate => "PSI",
+            LimitKind::RevLimitTaper => "RPM",
+            LimitKind::DutySaturation => "%",
+        }
+    }
+}
+
+/// Distance to one limit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LimitMargin {
+    pub limit: LimitKind,
+    /// Distance to the limit in its own unit (negative once past it)
+    pub margin: f32,
+    /// Fraction of the limit still available (0.0 = at or past the limit, 1.0 = far away)
+    /// Makes margins in different units comparable
+    pub fraction: f32,
+}
+
+impl LimitMargin {
+    fn new(limit: LimitKind, value: f32, limit_value: f32) -> Self {
+        let margin = limit_value - value;
+        let fraction = if limit_value > 0.0 { (margin / limit_value).clamp(0.0, 1.0) } else { 0.0 };
+        Self { limit, margin, fraction }
+    }
+}
+
+/// Headroom to every limit in one control cycle
+/// 
+/// 🔗 T4-CORE-077: Headroom Computation
+/// Derived From: T4-CORE-076
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Headroom {
+    pub overboost: LimitMargin,
+    pub profile_max: LimitMargin,
+    /// Only when rev limit taper is enabled
+    pub rev_limit_taper: Option<LimitMargin>,
+    pub duty_saturation: LimitMargin,
+    /// Only while a temperature derate is in effect
+    pub thermal_derate: Option<LimitMargin>,
+}
+
+impl Headroom {
+    /// Headroom for the current boost, RPM and output authority
+    /// 
+    /// `thermal_cap_psi` is the derated boost cap, if temperature derate is in effect.
+    pub fn compute(config: &SystemConfig, boost_psi: f32, rpm: u16, authority_percent: f32, thermal_cap_psi: Option<f32>) -> Self {
+        let taper = &config.rev_limit_taper;
+        let rev_limit_taper = taper.enabled.then(|| {
+            let band_start = taper.redline_rpm.saturating_sub(taper.band_rpm);
+            LimitMargin::new(LimitKind::RevLimitTaper, rpm as f32, band_start as f32)
+        });
+        
+        Self {
+            overboost: LimitMargin::new(LimitKind::Overboost, boost_psi, config.overboost_limit),
+            profile_max: LimitMargin::new(LimitKind::ProfileMax, boost_psi, config.max_boost_psi),
+            rev_limit_taper,
+            duty_saturation: LimitMargin::new(LimitKind::DutySaturation, authority_percent, 100.0),
+            thermal_derate: thermal_cap_psi.map(|cap_psi| LimitMargin::new(LimitKind::ThermalDerate, boost_psi, cap_psi)),
+        }
+    }
+    
+    /// Every limit being tracked
+    pub fn margins(&self) -> impl Iterator<Item = &LimitMargin> {
+        [
+            Some(&self.overboost),
+            Some(&self.profile_max),
+            self.rev_limit_taper.as_ref(),
+            Some(&self.duty_saturation),
+            self.thermal_derate.as_ref(),
+        ]
+            .into_iter()
+            .flatten()
+    }
+    
+    /// Limit with the least headroom left
+    pub fn closest(&self) -> &LimitMargin {
+        self.margins()
+            .fold(&self.overboost, |closest, margin| if margin.fraction < closest.fraction { margin } else { closest })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RevLimitTaper;
+    
+    #[test]
+    fn test_margins_to_each_limit() {
+        let config = SystemConfig::default();
+        let headroom = Headroom::compute(&config, 9.0, 4500, 40.0, None);
+        
+        assert_eq!(headroom.overboost.margin, 6.0);
+        assert_eq!(headroom.profile_max.margin, 3.0);
+        assert_eq!(headroom.duty_saturation.margin, 60.0);
+        assert_eq!(headroom.rev_limit_taper, None);
+        
+        // 3 of 12 PSI left is tighter than 6 of 15 or 60 of 100
+        assert_eq!(headroom.closest().limit, LimitKind::ProfileMax);
+    }
+    
+    #[test]
+    fn test_rev_taper_and_saturation_can_be_closest() {
+        let config = SystemConfig {
+            rev_limit_taper: RevLimitTaper { enabled: true, redline_rpm: 7000, band_rpm: 500 },
+            ..SystemConfig::default()
+        };
+        
+        let near_redline = Headroom::compute(&config, 6.0, 6400, 50.0, None);
+        assert_eq!(near_redline.rev_limit_taper.unwrap().margin, 100.0);
+        assert_eq!(near_redline.closest().limit, LimitKind::RevLimitTaper);
+        
+        let saturated = Headroom::compute(&config, 6.0, 3000, 100.0, None);
+        assert_eq!(saturated.closest().limit, LimitKind::DutySaturation);
+        assert_eq!(saturated.closest().fraction, 0.0);
+        
+        // Past a limit the margin goes negative, the fraction stops at zero
+        let over = Headroom::compute(&config, 16.0, 3000, 0.0, None);
+        assert_eq!(over.overboost.margin, -1.0);
+        assert_eq!(over.overboost.fraction, 0.0);
+    }
+    
+    #[test]
+    fn test_thermal_derate_reported_as_binding_limit() {
+        let config = SystemConfig::default();
+        assert_eq!(Headroom::compute(&config, 7.0, 4500, 40.0, None).thermal_derate, None);
+        
+        // Derated to 7.1 PSI: 0.1 PSI left is tighter than anything else
+        let derated = Headroom::compute(&config, 7.0, 4500, 40.0, Some(7.1));
+        assert_eq!(derated.closest().limit, LimitKind::ThermalDerate);
+        assert!((derated.thermal_derate.unwrap().margin - 0.1).abs() < 0.001);
+    }
+}
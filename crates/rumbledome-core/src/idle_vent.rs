@@ -0,0 +1,211 @@
+//! Idle Dome Venting
+//! 
+//! 🔗 T4-CORE-059: Off-Boost Idle Vent Stage
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + Hardware.md MAC solenoid service life
+//! AI Traceability: No solenoid modulation while idling off-boost - domes vented, PWM disabled, immediate rearm on tip-in
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemInputs};
+
+/// Torque request above delivered torque that counts as tip-in (Nm)
+/// Catches a tip-in from the ECU torque request before RPM or throttle cross their thresholds
+pub const TIP_IN_TORQUE_GAP_NM: f32 = 20.0;
+
+/// Idle vent thresholds
+/// 
+/// Small idle duties do nothing for boost - manifold pressure is below
+/// atmosphere - but still click the solenoid at the PWM rate. Below these
+/// thresholds the domes are vented and the PWM output is switched off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IdleVentConfig {
+    /// Vent the domes while idling off-boost
+    pub enabled: bool,
+    /// Highest RPM treated as idle
+    pub max_rpm: u16,
+    /// Highest throttle position treated as closed (%)
+    pub max_throttle_percent: f32,
+    /// Highest manifold pressure treated as off-boost (PSI gauge)
+    pub max_manifold_psi: f32,
+    /// Time all idle conditions must hold before venting (ms)
+    pub entry_delay_ms: u32,
+}
+
+impl Default for IdleVentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_rpm: 1200,
+            max_throttle_percent: 3.0,
+            max_manifold_psi: 0.0,
+            entry_delay_ms: 1000,
+        }
+    }
+}
+
+impl IdleVentConfig {
+    /// Validate idle vent settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(500..=3000).contains(&self.max_rpm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Idle vent RPM threshold must be 500-3000 RPM, got {}", self.max_rpm)
+            ));
+        }
+        
+        if !(0.0..=20.0).contains(&self.max_throttle_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Idle vent throttle threshold must be 0-20%, got {}", self.max_throttle_percent)
+            ));
+        }
+        
+        if !(-10.0..=1.0).contains(&self.max_manifold_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Idle vent manifold threshold must be -10.0-1.0 PSI, got {}", self.max_manifold_psi)
+            ));
+        }
+        
+        if !(100..=10_000).contains(&self.entry_delay_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Idle vent entry delay must be 100-10000 ms, got {}", self.entry_delay_ms)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Engine is idling off-boost with no torque being requested
+    fn at_idle(&self, inputs: &SystemInputs) -> bool {
+        inputs.rpm <= self.max_rpm
+            && inputs.throttle_position.is_none_or(|throttle| throttle <= self.max_throttle_percent)
+            && inputs.manifold_pressure <= self.max_manifold_psi
+            && inputs.desired_torque - inputs.actual_torque <= TIP_IN_TORQUE_GAP_NM
+    }
+}
+
+/// Idle vent status (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdleVentStatus {
+    /// Domes currently vented with PWM disabled
+    pub venting: bool,
+    /// Times the vent stage was entered
+    pub vent_entries: u32,
+    /// Times the vent stage was left on tip-in or rising boost
+    pub rearms: u32,
+}
+
+/// Idle vent manager
+/// 
+/// 🔗 T4-CORE-060: Idle Vent Entry and Rearm
+/// Derived From: T4-CORE-059
+/// 
+/// Entry waits for the idle conditions to hold for the entry delay so a brief
+/// lift between shifts keeps control active. Leaving is immediate - the cycle
+/// that sees tip-in already runs the normal control hierarchy.
+#[derive(Debug, Clone)]
+pub struct IdleVentManager {
+    config: IdleVentConfig,
+    idle_since_ms: Option<u32>,
+    status: IdleVentStatus,
+}
+
+impl IdleVentManager {
+    /// Create manager with control active
+    pub fn new(config: IdleVentConfig) -> Self {
+        Self {
+            config,
+            idle_since_ms: None,
+            status: IdleVentStatus::default(),
+        }
+    }
+    
+    /// Apply new settings, keeping counters
+    pub fn reconfigure(&mut self, config: IdleVentConfig) {
+        self.config = config;
+        self.idle_since_ms = None;
+    }
+    
+    /// Domes currently vented
+    pub fn is_venting(&self) -> bool {
+        self.status.venting
+    }
+    
+    /// Current status
+    pub fn status(&self) -> IdleVentStatus {
+        self.status.clone()
+    }
+    
+    /// Feed one armed control cycle; returns true while the domes should be vented
+    pub fn update(&mut self, inputs: &SystemInputs) -> bool {
+        if !self.config.enabled || !self.config.at_idle(inputs) {
+            self.idle_since_ms = None;
+            if self.status.venting {
+                self.status.venting = false;
+                self.status.rearms += 1;
+            }
+            return false;
+        }
+        
+        let since = *self.idle_since_ms.get_or_insert(inputs.timestamp_ms);
+        if !self.status.venting && inputs.timestamp_ms.wrapping_sub(since) >= self.config.entry_delay_ms {
+            self.status.venting = true;
+            self.status.vent_entries += 1;
+        }
+        
+        self.status.venting
+    }
+    
+    /// Abandon idle tracking (control no longer active)
+    /// 
+    /// Returns true if the domes were being vented, so the caller can
+    /// re-enable the PWM output.
+    pub fn cancel(&mut self) -> bool {
+        self.idle_since_ms = None;
+        core::mem::replace(&mut self.status.venting, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::idle_inputs;
+    
+    #[test]
+    fn test_vents_after_entry_delay() {
+        let mut manager = IdleVentManager::new(IdleVentConfig::default());
+        assert!(!manager.update(&idle_inputs(0)));
+        assert!(!manager.update(&idle_inputs(990)));
+        assert!(manager.update(&idle_inputs(1000)));
+        assert_eq!(manager.status().vent_entries, 1);
+    }
+    
+    #[test]
+    fn test_tip_in_rearms_immediately() {
+        let mut manager = IdleVentManager::new(IdleVentConfig::default());
+        manager.update(&idle_inputs(0));
+        assert!(manager.update(&idle_inputs(1000)));
+        
+        // Throttle opens - RPM and boost have not moved yet
+        let tip_in = SystemInputs { throttle_position: Some(25.0), ..idle_inputs(1010) };
+        assert!(!manager.update(&tip_in));
+        assert_eq!(manager.status().rearms, 1);
+        
+        // Torque request alone also rearms
+        manager.update(&idle_inputs(2000));
+        assert!(manager.update(&idle_inputs(3000)));
+        let torque_request = SystemInputs { desired_torque: 120.0, ..idle_inputs(3010) };
+        assert!(!manager.update(&torque_request));
+        
+        // Back to idle needs the full entry delay again
+        assert!(!manager.update(&idle_inputs(3020)));
+        assert!(!manager.update(&idle_inputs(3500)));
+    }
+    
+    #[test]
+    fn test_disabled_never_vents() {
+        let config = IdleVentConfig { enabled: false, ..Default::default() };
+        let mut manager = IdleVentManager::new(config);
+        manager.update(&idle_inputs(0));
+        assert!(!manager.update(&idle_inputs(5000)));
+    }
+}
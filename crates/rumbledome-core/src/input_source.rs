@@ -0,0 +1,256 @@
+//! Input Source Arbitration
+//! 
+//! 🔗 T4-CORE-037: CAN vs Analog Source Arbitration
+//! Derived From: T2-HAL-005 (Ford S550 CAN Signal Integration) + T1-SAFETY-002 (Defense in Depth)
+//! AI Traceability: Redundant MAP sources - automatic failover and cross-check mismatch detection
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Source priority policy for an input available from both CAN and an analog sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SourcePriority {
+    /// Use the analog sensor, fail over to CAN when the sensor is unavailable
+    #[default]
+    PreferAnalog,
+    /// Use the CAN signal, fail over to the analog sensor when CAN is stale
+    PreferCan,
+    /// Require both sources - losing either one is a fault (no failover)
+    CrossCheck,
+}
+
+/// Physical source currently feeding an input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputSource {
+    /// Dedicated analog sensor
+    Analog,
+    /// ECU-broadcast CAN signal
+    Can,
+    /// No usable source
+    None,
+}
+
+/// Arbitration settings for one redundant input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SourceArbitrationConfig {
+    /// Which source is authoritative
+    pub priority: SourcePriority,
+    /// Maximum allowed disagreement between sources (input units)
+    pub mismatch_threshold: f32,
+    /// Disagreement must persist this long before faulting (ms)
+    /// Absorbs the latency difference between CAN broadcast and direct sampling
+    pub mismatch_persist_ms: u32,
+}
+
+impl Default for SourceArbitrationConfig {
+    fn default() -> Self {
+        Self {
+            priority: SourcePriority::PreferAnalog,
+            mismatch_threshold: 1.5,
+            mismatch_persist_ms: 250,
+        }
+    }
+}
+
+/// Per-input source arbitration configuration
+/// 
+/// 🔗 T4-CORE-038: Input Source Configuration
+/// Derived From: T4-CORE-037
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InputSourceConfig {
+    /// Manifold pressure (PSI gauge) - CAN MAP vs dedicated analog MAP sensor
+    pub manifold_pressure: SourceArbitrationConfig,
+}
+
+impl InputSourceConfig {
+    /// Validate arbitration settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        let map = &self.manifold_pressure;
+        if !(0.1..=10.0).contains(&map.mismatch_threshold) {
+            return Err(CoreError::ConfigurationError(
+                format!("MAP mismatch threshold must be 0.1-10.0 PSI, got {}", map.mismatch_threshold)
+            ));
+        }
+        
+        // A persistence window longer than CAN loss detection would hide a wrong boost reading
+        if map.mismatch_persist_ms > 500 {
+            return Err(CoreError::ConfigurationError(
+                format!("MAP mismatch persistence must be <= 500 ms, got {}", map.mismatch_persist_ms)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Arbitration outcome for one sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArbitrationResult {
+    /// Value from the selected source
+    Value { value: f32, source: InputSource },
+    /// Both sources present but disagree beyond threshold for too long
+    Mismatch { can_value: f32, analog_value: f32 },
+    /// Required source(s) unavailable
+    Unavailable,
+}
+
+/// Arbitration status for diagnostics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceArbitrationStatus {
+    /// Configured priority
+    pub priority: SourcePriority,
+    /// Source used for the last sample
+    pub selected: InputSource,
+    /// CAN source available on last sample
+    pub can_available: bool,
+    /// Analog source available on last sample
+    pub analog_available: bool,
+    /// Last absolute difference between sources (0 when only one present)
+    pub last_difference: f32,
+    /// Sources currently disagree beyond threshold (may not have faulted yet)
+    pub mismatch_pending: bool,
+}
+
+/// Arbiter for a single redundant input
+/// 
+/// 🔗 T4-CORE-039: Source Failover and Cross-Check
+/// Derived From: T4-CORE-037 + SY-10 (CAN Dependency Management)
+/// 
+/// Callers pass `None` for a source that is stale or out of range; the arbiter
+/// only decides between the readings it is given.
+#[derive(Debug, Clone)]
+pub struct SourceArbiter {
+    config: SourceArbitrationConfig,
+    selected: InputSource,
+    can_available: bool,
+    analog_available: bool,
+    last_difference: f32,
+    mismatch_since_ms: Option<u32>,
+}
+
+impl SourceArbiter {
+    /// Create arbiter with configuration
+    pub fn new(config: SourceArbitrationConfig) -> Self {
+        Self {
+            config,
+            selected: InputSource::None,
+            can_available: false,
+            analog_available: false,
+            last_difference: 0.0,
+            mismatch_since_ms: None,
+        }
+    }
+    
+    /// Source used for the last sample
+    pub fn selected(&self) -> InputSource {
+        self.selected
+    }
+    
+    /// Arbitrate one sample
+    pub fn arbitrate(&mut self, can: Option<f32>, analog: Option<f32>, now_ms: u32) -> ArbitrationResult {
+        self.can_available = can.is_some();
+        self.analog_available = analog.is_some();
+        
+        // Cross-check whenever both sources are present, regardless of priority
+        if let (Some(can_value), Some(analog_value)) = (can, analog) {
+            self.last_difference = libm::fabsf(can_value - analog_value);
+            
+            if self.last_difference > self.config.mismatch_threshold {
+                let since = *self.mismatch_since_ms.get_or_insert(now_ms);
+                if now_ms.wrapping_sub(since) >= self.config.mismatch_persist_ms {
+                    self.selected = InputSource::None;
+                    return ArbitrationResult::Mismatch { can_value, analog_value };
+                }
+            } else {
+                self.mismatch_since_ms = None;
+            }
+        } else {
+            self.last_difference = 0.0;
+            self.mismatch_since_ms = None;
+        }
+        
+        let (value, source) = match (self.config.priority, can, analog) {
+            (SourcePriority::CrossCheck, Some(_), Some(analog_value)) => (Some(analog_value), InputSource::Analog),
+            (SourcePriority::CrossCheck, _, _) => (None, InputSource::None),
+            (SourcePriority::PreferAnalog, _, Some(analog_value)) => (Some(analog_value), InputSource::Analog),
+            (SourcePriority::PreferAnalog, Some(can_value), None) => (Some(can_value), InputSource::Can),
+            (SourcePriority::PreferCan, Some(can_value), _) => (Some(can_value), InputSource::Can),
+            (SourcePriority::PreferCan, None, Some(analog_value)) => (Some(analog_value), InputSource::Analog),
+            (_, None, None) => (None, InputSource::None),
+        };
+        
+        self.selected = source;
+        match value {
+            Some(value) => ArbitrationResult::Value { value, source },
+            None => ArbitrationResult::Unavailable,
+        }
+    }
+    
+    /// Status snapshot for diagnostics
+    pub fn status(&self) -> SourceArbitrationStatus {
+        SourceArbitrationStatus {
+            priority: self.config.priority,
+            selected: self.selected,
+            can_available: self.can_available,
+            analog_available: self.analog_available,
+            last_difference: self.last_difference,
+            mismatch_pending: self.mismatch_since_ms.is_some(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn arbiter(priority: SourcePriority) -> SourceArbiter {
+        SourceArbiter::new(SourceArbitrationConfig { priority, ..Default::default() })
+    }
+    
+    #[test]
+    fn test_prefer_analog_fails_over_to_can() {
+        let mut arbiter = arbiter(SourcePriority::PreferAnalog);
+        
+        assert_eq!(arbiter.arbitrate(Some(8.2), Some(8.0), 0), ArbitrationResult::Value { value: 8.0, source: InputSource::Analog });
+        assert_eq!(arbiter.arbitrate(Some(8.2), None, 10), ArbitrationResult::Value { value: 8.2, source: InputSource::Can });
+        assert_eq!(arbiter.arbitrate(None, None, 20), ArbitrationResult::Unavailable);
+    }
+    
+    #[test]
+    fn test_prefer_can_fails_over_to_analog() {
+        let mut arbiter = arbiter(SourcePriority::PreferCan);
+        
+        assert_eq!(arbiter.arbitrate(Some(8.2), Some(8.0), 0), ArbitrationResult::Value { value: 8.2, source: InputSource::Can });
+        assert_eq!(arbiter.arbitrate(None, Some(8.0), 10), ArbitrationResult::Value { value: 8.0, source: InputSource::Analog });
+    }
+    
+    #[test]
+    fn test_cross_check_requires_both_sources() {
+        let mut arbiter = arbiter(SourcePriority::CrossCheck);
+        
+        assert!(matches!(arbiter.arbitrate(Some(8.2), Some(8.0), 0), ArbitrationResult::Value { .. }));
+        assert_eq!(arbiter.arbitrate(None, Some(8.0), 10), ArbitrationResult::Unavailable);
+    }
+    
+    #[test]
+    fn test_mismatch_faults_only_after_persistence() {
+        let mut arbiter = arbiter(SourcePriority::PreferAnalog);
+        
+        // Transient disagreement during a fast boost transient
+        assert!(matches!(arbiter.arbitrate(Some(12.0), Some(8.0), 0), ArbitrationResult::Value { .. }));
+        assert!(arbiter.status().mismatch_pending);
+        assert!(matches!(arbiter.arbitrate(Some(8.1), Some(8.0), 100), ArbitrationResult::Value { .. }));
+        assert!(!arbiter.status().mismatch_pending);
+        
+        // Sustained disagreement
+        arbiter.arbitrate(Some(12.0), Some(8.0), 200);
+        assert_eq!(
+            arbiter.arbitrate(Some(12.0), Some(8.0), 450),
+            ArbitrationResult::Mismatch { can_value: 12.0, analog_value: 8.0 }
+        );
+    }
+}
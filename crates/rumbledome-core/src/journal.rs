@@ -0,0 +1,573 @@
+//! Journaled EEPROM Records
+//! 
+//! 🔗 T4-CORE-115: Double-Buffered Record Commit
+//! Derived From: T4-HAL-017 (On-Chip EEPROM Storage) + Hardware.md Power Loss Reality
+//! AI Traceability: A key-off mid-write never leaves a torn record - the previous copy stays in force until the new one is complete
+//! 
+//! A region is split into equal copies. A write goes to the copy that is not
+//! the newest, in three steps: its commit byte (the last byte of the copy) is
+//! cleared, the record and its CRC are written, and only then is the commit
+//! byte set. Power lost before the last step leaves the copy uncommitted, so
+//! the reader ignores it and takes the other one; a CRC failure on a
+//! committed copy means worn cells rather than a torn write.
+//! 
+//! The commit byte of a copy that was never written reads erased, which is
+//! also what records written before commit bytes existed show. Those copies
+//! are accepted on their CRC alone.
+//! 
+//! Records too large for the EEPROM are kept the same way in two files on the
+//! SD card (`FileJournal`). A file has no commit byte; one that does not hold
+//! a whole record with a matching CRC is skipped.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{de::DeserializeOwned, Serialize};
+use rumbledome_hal::{NonVolatileStorage, PortableStorage, StorageRegion, ERASED_BYTE};
+use crate::command_token::Crc16;
+use crate::CoreError;
+
+/// Commit byte of a complete copy
+const COMMITTED: u8 = 0xA5;
+
+/// Commit byte of a copy being written
+const UNCOMMITTED: u8 = 0x00;
+
+const MAGIC: u8 = 0x4A;
+/// Magic, sequence number and payload length
+const HEADER_SIZE: usize = 7;
+const CRC_SIZE: usize = 2;
+/// Header, CRC and commit byte
+const RECORD_OVERHEAD: usize = HEADER_SIZE + CRC_SIZE + 1;
+
+/// Commit state of one copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyState {
+    /// Write completed
+    Committed,
+    /// Write started and never completed - ignore the contents
+    Uncommitted,
+    /// Commit byte erased - a fresh copy, or one written before commit bytes
+    Unmarked,
+}
+
+/// Write `body` at the start of `copy` so power loss leaves it whole or uncommitted
+pub fn write_copy<S: NonVolatileStorage>(storage: &mut S, copy: StorageRegion, body: &[u8]) -> Result<(), CoreError> {
+    let commit_address = copy.address(copy.len - 1, 1)?;
+    copy.address(0, body.len() + 1)?;
+    storage.write(commit_address, &[UNCOMMITTED])?;
+    storage.write(copy.offset, body)?;
+    storage.sync()?;
+    storage.write(commit_address, &[COMMITTED])?;
+    Ok(())
+}
+
+/// Mark `copy` as holding nothing; it reads as a torn write until written again
+pub fn discard_copy<S: NonVolatileStorage>(storage: &mut S, copy: StorageRegion) -> Result<(), CoreError> {
+    storage.write(copy.address(copy.len - 1, 1)?, &[UNCOMMITTED])?;
+    Ok(())
+}
+
+/// Commit state of `copy`
+pub fn copy_state<S: NonVolatileStorage>(storage: &mut S, copy: StorageRegion) -> Result<CopyState, CoreError> {
+    let mut commit = [0];
+    storage.read(copy.address(copy.len - 1, 1)?, &mut commit)?;
+    Ok(match commit[0] {
+        COMMITTED => CopyState::Committed,
+        ERASED_BYTE => CopyState::Unmarked,
+        _ => CopyState::Uncommitted,
+    })
+}
+
+/// `index`th of `copies` equal copies of `region`
+pub fn copy_of(region: StorageRegion, copies: usize, index: usize) -> StorageRegion {
+    let len = region.len / copies;
+    StorageRegion { offset: region.offset + index * len, len }
+}
+
+/// Double-buffered record in one storage region
+/// 
+/// Holds which copy is newest so the next commit goes to the other one.
+#[derive(Debug, Clone)]
+pub struct RecordJournal {
+    region: StorageRegion,
+    copies: usize,
+    sequence: u32,
+    next_copy: usize,
+}
+
+impl RecordJournal {
+    /// Configuration record (regions 0-1)
+    pub const CONFIG: RecordJournal = RecordJournal::new(StorageRegion::CONFIG, 2);
+    /// Sensor calibration record (region 5)
+    pub const CALIBRATION: RecordJournal = RecordJournal::new(StorageRegion::CALIBRATION, 2);
+    
+    /// Journal of `copies` equal copies over `region`, before anything is loaded
+    pub const fn new(region: StorageRegion, copies: usize) -> Self {
+        Self { region, copies, sequence: 0, next_copy: 0 }
+    }
+    
+    /// Largest payload a copy holds (bytes)
+    pub fn capacity(&self) -> usize {
+        self.region.len / self.copies - RECORD_OVERHEAD
+    }
+    
+    /// Sequence number of the newest copy loaded or committed
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+    
+    /// Payload of the newest intact copy; `None` when nothing was ever committed
+    /// 
+    /// Fails only when copies were written but none of them is intact.
+    pub fn load<S: NonVolatileStorage>(&mut self, storage: &mut S) -> Result<Option<Vec<u8>>, CoreError> {
+        let mut newest: Option<(usize, u32, Vec<u8>)> = None;
+        let mut first_error = None;
+        for index in 0..self.copies {
+            match self.read_copy(storage, copy_of(self.region, self.copies, index)) {
+                Ok(Some((sequence, payload))) => {
+                    if newest.as_ref().is_none_or(|(_, best, _)| newer(sequence, *best)) {
+                        newest = Some((index, sequence, payload));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => first_error = first_error.or(Some(e)),
+            }
+        }
+        
+        match (newest, first_error) {
+            (Some((index, sequence, payload)), _) => {
+                self.sequence = sequence;
+                self.next_copy = (index + 1) % self.copies;
+                Ok(Some(payload))
+            }
+            (None, Some(e)) => Err(e),
+            (None, None) => Ok(None),
+        }
+    }
+    
+    /// Write `payload` over the oldest copy
+    /// 
+    /// Moves on to the next copy even when the write fails - the copy it
+    /// tore is no longer the newest.
+    pub fn commit<S: NonVolatileStorage>(&mut self, storage: &mut S, payload: &[u8]) -> Result<(), CoreError> {
+        if payload.len() > self.capacity() {
+            return Err(CoreError::StorageError(format!(
+                "{}-byte record exceeds the {}-byte copy", payload.len(), self.capacity()
+            )));
+        }
+        
+        let sequence = self.sequence.wrapping_add(1);
+        let copy = copy_of(self.region, self.copies, self.next_copy);
+        self.next_copy = (self.next_copy + 1) % self.copies;
+        self.sequence = sequence;
+        write_copy(storage, copy, &frame(sequence, payload))
+    }
+    
+    /// Newest intact value, deserialized from JSON
+    pub fn load_value<T: DeserializeOwned, S: NonVolatileStorage>(&mut self, storage: &mut S) -> Result<Option<T>, CoreError> {
+        self.load(storage)?
+            .map(|payload| serde_json::from_slice(&payload)
+                .map_err(|e| CoreError::StorageError(format!("Stored record does not decode: {}", e))))
+            .transpose()
+    }
+    
+    /// Commit a value serialized as JSON
+    pub fn commit_value<T: Serialize, S: NonVolatileStorage>(&mut self, storage: &mut S, value: &T) -> Result<(), CoreError> {
+        let payload = serde_json::to_vec(value)
+            .map_err(|e| CoreError::StorageError(format!("Record does not encode: {}", e)))?;
+        self.commit(storage, &payload)
+    }
+    
+    /// Sequence number and payload of a copy (`None` if never committed or torn)
+    fn read_copy<S: NonVolatileStorage>(&self, storage: &mut S, copy: StorageRegion) -> Result<Option<(u32, Vec<u8>)>, CoreError> {
+        if copy_state(storage, copy)? == CopyState::Uncommitted {
+            return Ok(None);
+        }
+        
+        let mut header = [0; HEADER_SIZE];
+        storage.read(copy.offset, &mut header)?;
+        if header.iter().all(|&byte| byte == ERASED_BYTE) {
+            return Ok(None);
+        }
+        
+        let corrupt = |problem: &str| CoreError::StorageError(format!("Stored record at {} {}", copy.offset, problem));
+        let len = u16::from_le_bytes([header[5], header[6]]) as usize;
+        if header[0] != MAGIC || len > self.capacity() {
+            return Err(corrupt("has an unknown layout"));
+        }
+        
+        let mut body = alloc::vec![0; HEADER_SIZE + len + CRC_SIZE];
+        storage.read(copy.offset, &mut body)?;
+        let (sequence, payload) = unframe(&body).ok_or_else(|| corrupt("failed its checksum"))?;
+        Ok(Some((sequence, payload.to_vec())))
+    }
+}
+
+/// Double-buffered record in two files on the SD card
+/// 
+/// A commit deletes the older file and writes the record in its place, so
+/// the newer one stays intact until the new one is complete. Records cost
+/// nothing when there is no card, and they start over on a new card.
+#[derive(Debug, Clone)]
+pub struct FileJournal {
+    paths: [&'static str; 2],
+    sequence: u32,
+    next_copy: usize,
+}
+
+impl FileJournal {
+    /// Weekly and monthly trend records
+    pub const TRENDS: FileJournal = FileJournal::new(["RUMBLEDOME/trends/a.rec", "RUMBLEDOME/trends/b.rec"]);
+    
+    /// Journal over two files, before anything is loaded
+    pub const fn new(paths: [&'static str; 2]) -> Self {
+        Self { paths, sequence: 0, next_copy: 0 }
+    }
+    
+    /// Sequence number of the newest copy loaded or committed
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+    
+    /// Payload of the newest intact file; `None` when neither holds a whole record
+    /// 
+    /// Fails only when the card cannot be read.
+    pub fn load<S: PortableStorage>(&mut self, storage: &mut S) -> Result<Option<Vec<u8>>, CoreError> {
+        let mut newest: Option<(usize, u32, Vec<u8>)> = None;
+        for (index, path) in self.paths.iter().enumerate() {
+            let Some(size) = storage.file_size(path)? else { continue };
+            let mut body = alloc::vec![0; size as usize];
+            if storage.read_file(path, 0, &mut body)? < body.len() {
+                continue;
+            }
+            if let Some((sequence, payload)) = unframe(&body) {
+                if newest.as_ref().is_none_or(|(_, best, _)| newer(sequence, *best)) {
+                    newest = Some((index, sequence, payload.to_vec()));
+                }
+            }
+        }
+        
+        Ok(newest.map(|(index, sequence, payload)| {
+            self.sequence = sequence;
+            self.next_copy = (index + 1) % self.paths.len();
+            payload
+        }))
+    }
+    
+    /// Write `payload` over the older file
+    /// 
+    /// Moves on to the next file even when the write fails - the file it
+    /// tore is no longer the newest.
+    pub fn commit<S: PortableStorage>(&mut self, storage: &mut S, payload: &[u8]) -> Result<(), CoreError> {
+        if payload.len() > u16::MAX as usize {
+            return Err(CoreError::StorageError(format!("{}-byte record is too large for a file copy", payload.len())));
+        }
+        
+        let sequence = self.sequence.wrapping_add(1);
+        let path = self.paths[self.next_copy];
+        self.next_copy = (self.next_copy + 1) % self.paths.len();
+        self.sequence = sequence;
+        storage.remove_file(path)?;
+        storage.append_file(path, &frame(sequence, payload))?;
+        Ok(())
+    }
+    
+    /// Newest intact value, deserialized from JSON
+    pub fn load_value<T: DeserializeOwned, S: PortableStorage>(&mut self, storage: &mut S) -> Result<Option<T>, CoreError> {
+        self.load(storage)?
+            .map(|payload| serde_json::from_slice(&payload)
+                .map_err(|e| CoreError::StorageError(format!("Stored record does not decode: {}", e))))
+            .transpose()
+    }
+    
+    /// Commit a value serialized as JSON
+    pub fn commit_value<T: Serialize, S: PortableStorage>(&mut self, storage: &mut S, value: &T) -> Result<(), CoreError> {
+        let payload = serde_json::to_vec(value)
+            .map_err(|e| CoreError::StorageError(format!("Record does not encode: {}", e)))?;
+        self.commit(storage, &payload)
+    }
+}
+
+/// Header, payload and CRC of a record
+fn frame(sequence: u32, payload: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(payload.len() + HEADER_SIZE + CRC_SIZE);
+    body.push(MAGIC);
+    body.extend_from_slice(&sequence.to_le_bytes());
+    body.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    body.extend_from_slice(payload);
+    let mut crc = Crc16::new();
+    crc.update(&body);
+    body.extend_from_slice(&crc.value().to_le_bytes());
+    body
+}
+
+/// Sequence number and payload of a framed record; `None` unless it is whole and its CRC matches
+fn unframe(body: &[u8]) -> Option<(u32, &[u8])> {
+    let len = body.len().checked_sub(HEADER_SIZE + CRC_SIZE)?;
+    let (record, crc) = body.split_at(HEADER_SIZE + len);
+    if record[0] != MAGIC || u16::from_le_bytes([record[5], record[6]]) as usize != len {
+        return None;
+    }
+    let mut expected = Crc16::new();
+    expected.update(record);
+    if u16::from_le_bytes([crc[0], crc[1]]) != expected.value() {
+        return None;
+    }
+    
+    let sequence = u32::from_le_bytes([record[1], record[2], record[3], record[4]]);
+    Some((sequence, &record[HEADER_SIZE..]))
+}
+
+/// Whether sequence `a` follows `b`, allowing for wraparound
+pub(crate) fn newer(a: u32, b: u32) -> bool {
+    a.wrapping_sub(b) as i32 > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_hal::{HalResult, EEPROM_SIZE};
+    
+    /// EEPROM that loses power after a number of writes
+    struct FlakyStorage {
+        bytes: Vec<u8>,
+        writes_left: Option<usize>,
+    }
+    
+    impl FlakyStorage {
+        fn new() -> Self {
+            Self { bytes: alloc::vec![ERASED_BYTE; EEPROM_SIZE], writes_left: None }
+        }
+    }
+    
+    impl NonVolatileStorage for FlakyStorage {
+        fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize> {
+            buffer.copy_from_slice(&self.bytes[offset..offset + buffer.len()]);
+            Ok(buffer.len())
+        }
+        
+        fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()> {
+            match self.writes_left {
+                Some(0) => return Err(rumbledome_hal::HalError::HardwareFault("power lost".into())),
+                Some(ref mut left) => *left -= 1,
+                None => {}
+            }
+            self.bytes[offset..offset + data.len()].copy_from_slice(data);
+            Ok(())
+        }
+        
+        fn erase_all(&mut self) -> HalResult<()> {
+            self.bytes.fill(ERASED_BYTE);
+            Ok(())
+        }
+        
+        fn sync(&mut self) -> HalResult<()> {
+            Ok(())
+        }
+        
+        fn get_size(&self) -> usize {
+            EEPROM_SIZE
+        }
+    }
+    
+    #[test]
+    fn test_commits_alternate_and_newest_wins() {
+        let mut storage = FlakyStorage::new();
+        let mut journal = RecordJournal::CALIBRATION;
+        assert_eq!(journal.load(&mut storage).unwrap(), None);
+        
+        journal.commit(&mut storage, b"first").unwrap();
+        journal.commit(&mut storage, b"second").unwrap();
+        journal.commit(&mut storage, b"third").unwrap();
+        
+        let mut reloaded = RecordJournal::CALIBRATION;
+        assert_eq!(reloaded.load(&mut storage).unwrap().as_deref(), Some(&b"third"[..]));
+        assert_eq!(reloaded.sequence(), 3);
+        
+        // The next commit replaces "second", not the newest copy
+        reloaded.commit(&mut storage, b"fourth").unwrap();
+        let region = StorageRegion::CALIBRATION;
+        assert_eq!(&storage.bytes[region.offset + HEADER_SIZE..][..5], b"third");
+    }
+    
+    #[test]
+    fn test_power_loss_at_any_write_keeps_a_whole_record() {
+        // Write steps of one commit: clear commit byte, body, set commit byte
+        for writes_before_loss in 0..3 {
+            let mut storage = FlakyStorage::new();
+            let mut journal = RecordJournal::CONFIG;
+            journal.commit(&mut storage, b"older").unwrap();
+            journal.commit(&mut storage, b"old").unwrap();
+            
+            // Lands on the committed "older" copy
+            storage.writes_left = Some(writes_before_loss);
+            assert!(journal.commit(&mut storage, b"new").is_err());
+            storage.writes_left = None;
+            
+            let mut reloaded = RecordJournal::CONFIG;
+            assert_eq!(reloaded.load(&mut storage).unwrap().as_deref(), Some(&b"old"[..]));
+            assert_eq!(reloaded.sequence(), 2);
+        }
+    }
+    
+    #[test]
+    fn test_values_round_trip_and_oversize_is_refused() {
+        let mut storage = FlakyStorage::new();
+        let mut journal = RecordJournal::CALIBRATION;
+        journal.commit_value(&mut storage, &(1.5f32, 7u32)).unwrap();
+        let mut reloaded = RecordJournal::CALIBRATION;
+        let value: Option<(f32, u32)> = reloaded.load_value(&mut storage).unwrap();
+        assert_eq!(value, Some((1.5, 7)));
+        
+        assert!(journal.commit(&mut storage, &alloc::vec![0; journal.capacity() + 1]).is_err());
+        
+        // Corrupt committed copies with nothing intact is an error, not a fresh start
+        let region = StorageRegion::CALIBRATION;
+        storage.bytes[region.offset + HEADER_SIZE] ^= 0xFF;
+        assert!(reloaded.load(&mut storage).is_err());
+    }
+    
+    #[test]
+    fn test_sequence_order_survives_wraparound() {
+        assert!(newer(0, u32::MAX));
+        assert!(newer(5, 3));
+        assert!(!newer(3, 5));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::{changes_from_defaults, load_stored_config, ByteOrder, CanSignal, CanSignalMap, CoreError, DomeControlConfig, FileJournal, MapZeroRecord, RecordJournal, RumbleDomeCore, SystemConfig, TuningParameters};
+    use rumbledome_hal::{MockHal, PortableStorage};
+    
+    #[test]
+    fn test_file_journal_keeps_the_newer_file_through_a_torn_write() {
+        let mut hal = MockHal::new();
+        let mut journal = FileJournal::TRENDS;
+        assert_eq!(journal.load(&mut hal).unwrap(), None);
+        
+        journal.commit(&mut hal, b"first").unwrap();
+        journal.commit(&mut hal, b"second").unwrap();
+        let mut reloaded = FileJournal::TRENDS;
+        assert_eq!(reloaded.load(&mut hal).unwrap().as_deref(), Some(&b"second"[..]));
+        assert_eq!(reloaded.sequence(), 2);
+        
+        // Key-off partway through the third write leaves a short file in place of "first"
+        reloaded.commit(&mut hal, b"third").unwrap();
+        let torn = hal.sd_file("RUMBLEDOME/trends/a.rec").unwrap().to_vec();
+        hal.remove_file("RUMBLEDOME/trends/a.rec").unwrap();
+        hal.append_file("RUMBLEDOME/trends/a.rec", &torn[..torn.len() - 3]).unwrap();
+        let mut reloaded = FileJournal::TRENDS;
+        assert_eq!(reloaded.load(&mut hal).unwrap().as_deref(), Some(&b"second"[..]));
+        
+        // The next commit goes over the torn file, not the intact one
+        reloaded.commit(&mut hal, b"fourth").unwrap();
+        let mut rebooted = FileJournal::TRENDS;
+        assert_eq!(rebooted.load(&mut hal).unwrap().as_deref(), Some(&b"fourth"[..]));
+        assert!(hal.sd_file("RUMBLEDOME/trends/b.rec").unwrap().windows(6).any(|bytes| bytes == b"second"));
+        
+        hal.remove_sd_card();
+        assert!(rebooted.load(&mut hal).is_err());
+        assert!(journal.commit(&mut hal, b"lost").is_err());
+    }
+    
+    #[test]
+    fn test_map_zero_history_survives_power_cycle() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        core.map_zero.restore(MapZeroRecord { baseline_psi: Some(0.2), latest_psi: Some(0.6), captures: 9, max_drift_psi: 0.4 });
+        core.reset_map_zero_baseline();
+        
+        // Same EEPROM, new boot
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.map_zero.record().baseline_psi, Some(0.6));
+        assert_eq!(core.map_zero.zero_psi(), 0.6);
+    }
+    
+    #[test]
+    fn test_configuration_changes_are_journaled() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(load_stored_config(&mut core.hal).unwrap(), None);
+        
+        let new_turbo = SystemConfig { spring_pressure: 7.0, max_boost_psi: 18.0, overboost_limit: 21.0, ..SystemConfig::default() };
+        let active = core.configurations.active_name().to_string();
+        core.save_configuration(&active, new_turbo.clone()).unwrap();
+        assert_eq!(core.storage_health.config_stored, Some(true));
+        assert_eq!(load_stored_config(&mut core.hal).unwrap(), Some(new_turbo.clone()));
+        
+        // After a reboot the next save goes to the other copy, so the newest stays in force until it is complete
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, new_turbo.clone());
+        core.initialize().unwrap();
+        core.set_control_mode(crate::ControlMode::BoostByTarget).unwrap();
+        let mut journal = RecordJournal::CONFIG;
+        journal.load(&mut core.hal).unwrap();
+        assert_eq!(journal.sequence(), 2);
+        assert_eq!(load_stored_config(&mut core.hal).unwrap().unwrap().control_mode, crate::ControlMode::BoostByTarget);
+        
+        // A configuration changed well beyond the basics still fits a copy
+        let sprawling = SystemConfig {
+            dome_control: DomeControlConfig {
+                enabled: true, dome_psi_per_boost_psi: 1.25, max_boost_trim_psi: 3.5,
+                dome_kp: 2.5, dome_ki: 25.0, max_correction_percent: 20.0,
+            },
+            tuning: TuningParameters { pid_kp: 0.75, pid_ki: 1.5, ..new_turbo.tuning.clone() },
+            aggression: 0.65, scramble_enabled: !new_turbo.scramble_enabled, max_duty_percent: 90.0,
+            ..new_turbo.clone()
+        };
+        core.save_configuration(&active, sprawling.clone()).unwrap();
+        assert_eq!(core.storage_health.config_stored, Some(true));
+        assert_eq!(load_stored_config(&mut core.hal).unwrap(), Some(sprawling.clone()));
+        
+        // Too many changes to fit a copy: the configuration runs, the previous record stays and the caller is told
+        let signal = CanSignal { message_id: 0x201, start_bit: 0, length: 16, byte_order: ByteOrder::LittleEndian, signed: false, scale: 0.25, offset: 0.0 };
+        let imported = SystemConfig {
+            can_signals: CanSignalMap {
+                rpm: Some(signal.clone()), desired_torque: Some(signal.clone()), actual_torque: Some(signal.clone()),
+                manifold_pressure: Some(signal.clone()), throttle_position: Some(signal), ..CanSignalMap::default()
+            },
+            ..sprawling.clone()
+        };
+        assert!(serde_json::to_vec(&changes_from_defaults(&imported).unwrap()).unwrap().len() > RecordJournal::CONFIG.capacity());
+        assert!(matches!(core.save_configuration(&active, imported.clone()), Err(CoreError::StorageError(_))));
+        assert_eq!(core.config.can_signals, imported.can_signals);
+        assert_eq!(core.storage_health.config_stored, Some(false));
+        assert_eq!(load_stored_config(&mut core.hal).unwrap().unwrap().dome_control, sprawling.dome_control);
+    }
+    
+    #[test]
+    fn test_stored_configuration_runs_after_power_cycle() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        let new_turbo = SystemConfig {
+            spring_pressure: 7.0, max_boost_psi: 18.0, overboost_limit: 21.0, pwm_frequency_hz: 40,
+            tuning: TuningParameters { pid_kp: 0.75, ..TuningParameters::default() },
+            ..SystemConfig::default()
+        };
+        let active = core.configurations.active_name().to_string();
+        core.save_configuration(&active, new_turbo.clone()).unwrap();
+        // The PWM frequency is restart-staged, so it waits for the next boot
+        assert_eq!(core.config.pwm_frequency_hz, 30);
+        
+        // Same EEPROM, new boot with the firmware's built-in configuration
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.config, new_turbo);
+        assert_eq!(core.configurations.active().config, new_turbo);
+        assert!(core.pending_restart().is_empty());
+        assert_eq!(core.limits_in_force().overboost_limit_psi, 21.0);
+        
+        // The restored record is the newest, so the next save goes to the other copy
+        core.set_control_mode(crate::ControlMode::BoostByTarget).unwrap();
+        let mut journal = RecordJournal::CONFIG;
+        journal.load(&mut core.hal).unwrap();
+        assert_eq!(journal.sequence(), 2);
+    }
+}
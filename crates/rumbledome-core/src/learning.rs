@@ -0,0 +1,1039 @@
+//! Learned Boost-to-Duty Calibration
+//! 
+//! 🔗 T4-CORE-108: Learned Duty Calibration Map
+//! Derived From: LearnedData.md Duty Cycle Calibration Maps + T4-HAL-017 (On-Chip EEPROM Storage)
+//! AI Traceability: Level 3 turns a boost target into the duty this car has actually needed, starting from a conservative estimate
+//! 
+//! The map covers 2000-7000 RPM and 0-20 PSI. An unlearned cell answers with
+//! a conservative estimate from spring and dome supply pressure. Steady-state
+//! samples update the cells around the operating point the way an ECU trims
+//! fuel: the short-term trim follows quickly and is lost at power-off, the
+//! long-term trim migrates from it slowly once the cell's results are
+//! consistent. Both trims are bounded.
+//! 
+//! Baselines, long-term trims and confidence are stored as one CRC-checked
+//! image that fits a single EEPROM region. Each named configuration keeps its
+//! own map, so the learned data area is a pool of one copy per region, each
+//! tagged with the namespace of the configuration it belongs to. A save never
+//! overwrites the newest copy of its own map: it takes an empty slot, then an
+//! older copy of the same map, and only then the least recently saved older
+//! copy of another configuration's map. A configuration's only copy is never
+//! given up, so once every slot holds one the save fails until a
+//! configuration is deleted. The newest intact copy of a namespace (by
+//! wrap-safe sequence number) is the one loaded, so a write torn at key-off
+//! falls back to the previous copy. Each
+//! copy ends in a commit byte set only once the image is complete
+//! (T4-CORE-115), so a torn copy is skipped without relying on its CRC.
+//! 
+//! Copies stored before maps had namespaces carry no tag; the first
+//! configuration loaded claims them.
+//! 
+//! The same image is the export format. An import arrives in chunks, is
+//! checked whole and only then replaces the cells.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{NonVolatileStorage, StorageRegion, EEPROM_REGION_SIZE, ERASED_BYTE};
+use crate::command_token::Crc16;
+use crate::journal::newer;
+use crate::{copy_of, copy_state, discard_copy, write_copy, CellCorrection, CopyState, CoreError, SystemInputs};
+
+/// RPM of the first map row
+pub const LEARNED_RPM_MIN: u16 = 2000;
+
+/// RPM between map rows
+pub const LEARNED_RPM_STEP: u16 = 500;
+
+/// Map rows (2000-7000 RPM)
+pub const LEARNED_RPM_POINTS: usize = 11;
+
+/// Boost between map columns (PSI)
+pub const LEARNED_BOOST_STEP_PSI: f32 = 2.0;
+
+/// Map columns (0-20 PSI)
+pub const LEARNED_BOOST_POINTS: usize = 11;
+
+/// Stored image size (bytes)
+pub const LEARNED_DATA_SIZE: usize = HEADER_SIZE + LEARNED_RPM_POINTS * LEARNED_BOOST_POINTS * CELL_SIZE + CRC_SIZE;
+
+/// Stored copies shared by every configuration's map, one per region of the learned data area
+pub const LEARNED_DATA_SLOTS: usize = StorageRegion::LEARNED_DATA.len / EEPROM_REGION_SIZE;
+
+/// Most image bytes one import request carries (keeps the request under the 1KB limit)
+pub const LEARNED_DATA_CHUNK_BYTES: usize = 128;
+
+/// Short-term trim weight of each sample's duty error
+pub const FAST_LEARN_RATE: f32 = 0.05;
+
+/// Share of the short-term trim migrated to the long-term trim per sample
+pub const SLOW_LEARN_RATE: f32 = 0.02;
+
+/// Confidence required before the long-term trim moves
+pub const CONFIDENCE_THRESHOLD: f32 = 0.8;
+
+/// Short-term trim limit (% duty)
+pub const MAX_SHORT_TERM_TRIM: f32 = 10.0;
+
+/// Long-term trim limit (% duty)
+pub const MAX_LONG_TERM_TRIM: f32 = 20.0;
+
+/// Learning samples that make a write to storage due
+pub const SAVE_AFTER_UPDATES: u32 = 50;
+
+/// Longest a learned change waits to be written (ms)
+pub const SAVE_INTERVAL_MS: u32 = 600_000;
+
+/// Shortest time between writes, whatever the reason (ms)
+const MIN_SAVE_SPACING_MS: u32 = 60_000;
+
+/// Duty error that still counts as a consistent result (%)
+const CONSISTENT_ERROR_PERCENT: f32 = 3.0;
+
+/// Confidence weight of each sample
+const CONFIDENCE_RATE: f32 = 0.1;
+
+/// Share of the physics estimate an unlearned cell answers with
+const CONSERVATIVE_FACTOR: f32 = 0.8;
+
+/// Dome supply below which the valve has no authority to estimate from (PSI)
+const MIN_FEED_PSI: f32 = 5.0;
+
+/// Boost above spring pressure before duty decides boost (PSI)
+const MIN_CONTROL_MARGIN_PSI: f32 = 0.5;
+
+/// Shortfall from target past which boost is held by spool or throttle, not the wastegate (PSI)
+const MAX_SHORTFALL_PSI: f32 = 2.0;
+
+const MAGIC: u8 = 0x4C;
+const FORMAT_VERSION: u8 = 1;
+const HEADER_SIZE: usize = 8;
+const CELL_SIZE: usize = 4;
+const CRC_SIZE: usize = 2;
+/// Namespace tag stored after the image in each copy
+const TAG_SIZE: usize = 2;
+/// Tag of a copy stored before maps had namespaces (the tag bytes read erased)
+const UNCLAIMED: u16 = 0xFFFF;
+
+/// Highest boost covered by the map (PSI)
+const MAX_MAP_BOOST_PSI: f32 = (LEARNED_BOOST_POINTS - 1) as f32 * LEARNED_BOOST_STEP_PSI;
+
+/// One map cell
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CalibrationPoint {
+    /// Duty from the cell's first sample (%)
+    pub baseline_duty: f32,
+    /// Fast adaptation, not stored (% duty)
+    pub short_term_trim: f32,
+    /// Slow adaptation (% duty)
+    pub long_term_trim: f32,
+    /// Consistency of recent results (0.0-1.0)
+    pub confidence: f32,
+    /// Samples taken (saturating)
+    pub sample_count: u8,
+}
+
+impl CalibrationPoint {
+    /// Whether the cell has been seeded from a sample
+    pub fn is_learned(&self) -> bool {
+        self.sample_count > 0
+    }
+    
+    /// Baseline plus both trims (%)
+    pub fn duty_percent(&self) -> f32 {
+        (self.baseline_duty + self.long_term_trim + self.short_term_trim).clamp(0.0, 100.0)
+    }
+}
+
+/// Learning counters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LearnedDataStatus {
+    /// Cells seeded from a sample
+    pub cells_learned: u16,
+    /// Cells at or above the confidence threshold
+    pub cells_confident: u16,
+    /// Learning samples applied since power-up
+    pub updates: u32,
+    /// Changes not yet written to storage
+    pub unsaved_updates: u32,
+}
+
+/// Learned data import progress
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LearnedDataImport {
+    /// Image bytes received
+    pub received: u32,
+    /// Image size - the map is replaced once all of it has arrived
+    pub total: u32,
+}
+
+/// Learned-data image arriving in chunks
+#[derive(Debug, Clone, Default)]
+pub struct LearnedDataUpload {
+    image: Vec<u8>,
+}
+
+impl LearnedDataUpload {
+    /// Image bytes received so far
+    pub fn received(&self) -> usize {
+        self.image.len()
+    }
+    
+    /// Append a chunk; offset 0 starts a new image
+    /// 
+    /// Returns the whole image once its last byte has arrived. A chunk that
+    /// does not follow on from the bytes received is refused.
+    pub fn accept(&mut self, offset: u32, chunk: &[u8]) -> Result<Option<Vec<u8>>, CoreError> {
+        if offset == 0 {
+            self.image.clear();
+        }
+        if offset as usize != self.image.len() {
+            return Err(CoreError::LearningError(format!(
+                "Learned data chunk at byte {} does not follow the {} bytes received", offset, self.image.len()
+            )));
+        }
+        if chunk.is_empty() || chunk.len() > LEARNED_DATA_CHUNK_BYTES || self.image.len() + chunk.len() > LEARNED_DATA_SIZE {
+            return Err(CoreError::LearningError(format!(
+                "Learned data chunk of {} bytes at byte {} does not fit a {} byte image", chunk.len(), offset, LEARNED_DATA_SIZE
+            )));
+        }
+        
+        self.image.extend_from_slice(chunk);
+        if self.image.len() < LEARNED_DATA_SIZE {
+            return Ok(None);
+        }
+        Ok(Some(core::mem::take(&mut self.image)))
+    }
+}
+
+/// Learned RPM × boost duty map
+#[derive(Debug, Clone, PartialEq)]
+pub struct LearnedData {
+    cells: [[CalibrationPoint; LEARNED_BOOST_POINTS]; LEARNED_RPM_POINTS],
+    spring_pressure_psi: f32,
+    sequence: u32,
+    /// Tag of the configuration namespace the map is stored under
+    namespace: u16,
+    /// Copy holding the newest save of this map, which the next save must not overwrite
+    slot: Option<usize>,
+    updates: u32,
+    unsaved_updates: u32,
+    last_save_ms: u32,
+}
+
+impl LearnedData {
+    /// Create with every cell unlearned
+    pub fn new(spring_pressure_psi: f32) -> Self {
+        Self {
+            cells: [[CalibrationPoint::default(); LEARNED_BOOST_POINTS]; LEARNED_RPM_POINTS],
+            spring_pressure_psi,
+            sequence: 0,
+            namespace: UNCLAIMED,
+            slot: None,
+            updates: 0,
+            unsaved_updates: 0,
+            last_save_ms: 0,
+        }
+    }
+    
+    /// Store the map under a configuration's namespace
+    /// 
+    /// A map never given one is stored unclaimed, and the next configuration loaded takes it.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = namespace_tag(namespace);
+        self
+    }
+    
+    /// Apply a changed wastegate spring; learned cells are kept
+    pub fn set_spring_pressure(&mut self, spring_pressure_psi: f32) {
+        self.spring_pressure_psi = spring_pressure_psi;
+    }
+    
+    /// Cell at a map row and column
+    pub fn cell(&self, rpm_index: usize, boost_index: usize) -> &CalibrationPoint {
+        &self.cells[rpm_index][boost_index]
+    }
+    
+    /// Duty expected to hold a boost target at the current RPM and dome supply (%)
+    pub fn boost_to_duty_conversion(&self, target_boost: f32, inputs: &SystemInputs) -> Result<f32, CoreError> {
+        if !target_boost.is_finite() {
+            return Err(CoreError::LearningError(format!("Boost target {} is not a number", target_boost)));
+        }
+        
+        // The spring alone holds the wastegate shut up to its pressure
+        if target_boost <= self.spring_pressure_psi {
+            return Ok(0.0);
+        }
+        
+        let feed_psi = inputs.dome_input_pressure;
+        let map_boost = target_boost.min(MAX_MAP_BOOST_PSI);
+        let duty = self.interpolate(inputs.rpm, map_boost, feed_psi);
+        
+        // Targets past the map carry on along the physics estimate
+        let beyond = self.estimated_duty(target_boost, feed_psi) - self.estimated_duty(map_boost, feed_psi);
+        Ok((duty + beyond).clamp(0.0, 100.0))
+    }
+    
+    /// Learn from a steady-state sample: the duty held and the boost it settled at
+    pub fn update_from_operation(&mut self, inputs: &SystemInputs, target_boost: f32, duty_percent: f32) -> Result<(), CoreError> {
+        if !duty_percent.is_finite() || !inputs.manifold_pressure.is_finite() {
+            return Err(CoreError::LearningError(format!(
+                "Learning sample {}% duty at {} PSI is not a number", duty_percent, inputs.manifold_pressure
+            )));
+        }
+        
+        // Below spring pressure the wastegate rides its spring, well short of
+        // target the turbo or throttle is what limits boost, and without dome
+        // supply duty does nothing - none of these say what duty makes boost
+        let boost_psi = inputs.manifold_pressure;
+        let feed_psi = inputs.dome_input_pressure;
+        let on_spring = boost_psi < self.spring_pressure_psi + MIN_CONTROL_MARGIN_PSI;
+        let short_of_target = boost_psi < target_boost - MAX_SHORTFALL_PSI;
+        if on_spring || short_of_target || feed_psi < MIN_FEED_PSI {
+            return Ok(());
+        }
+        
+        // Unlearned cells are seeded from the sample, shifted along the physics estimate to the cell's boost
+        let neighbours = neighbours(inputs.rpm, boost_psi);
+        let mut seeded = [false; 4];
+        for (seed, &(rpm_index, boost_index, weight)) in seeded.iter_mut().zip(&neighbours) {
+            let cell_boost = boost_index as f32 * LEARNED_BOOST_STEP_PSI;
+            let shift = self.estimated_duty(cell_boost, feed_psi) - self.estimated_duty(boost_psi, feed_psi);
+            let cell = &mut self.cells[rpm_index][boost_index];
+            if weight > 0.0 && !cell.is_learned() {
+                cell.baseline_duty = (duty_percent + shift).clamp(0.0, 100.0);
+                cell.sample_count = 1;
+                *seed = true;
+            }
+        }
+        
+        let error = duty_percent - self.interpolate(inputs.rpm, boost_psi, feed_psi);
+        let consistent = if error.abs() <= CONSISTENT_ERROR_PERCENT { 1.0 } else { 0.0 };
+        for ((rpm_index, boost_index, weight), seeded) in neighbours.into_iter().zip(seeded) {
+            if weight <= 0.0 {
+                continue;
+            }
+            
+            let cell = &mut self.cells[rpm_index][boost_index];
+            cell.short_term_trim = (cell.short_term_trim + error * FAST_LEARN_RATE * weight)
+                .clamp(-MAX_SHORT_TERM_TRIM, MAX_SHORT_TERM_TRIM);
+            cell.confidence += (consistent - cell.confidence) * CONFIDENCE_RATE * weight;
+            
+            // A trim pinned at its limit is a lasting shift even while results disagree
+            let saturated = cell.short_term_trim.abs() >= MAX_SHORT_TERM_TRIM;
+            if cell.confidence >= CONFIDENCE_THRESHOLD || saturated {
+                cell.long_term_trim = (cell.long_term_trim + cell.short_term_trim * SLOW_LEARN_RATE * weight)
+                    .clamp(-MAX_LONG_TERM_TRIM, MAX_LONG_TERM_TRIM);
+            }
+            if !seeded {
+                cell.sample_count = cell.sample_count.saturating_add(1);
+            }
+        }
+        
+        self.updates += 1;
+        self.unsaved_updates += 1;
+        Ok(())
+    }
+    
+    /// Take duty out of the cells around an overshoot's operating point
+    /// 
+    /// Unlearned cells answer from the conservative estimate and are left alone.
+    pub fn apply_cell_correction(&mut self, correction: &CellCorrection) -> Result<(), CoreError> {
+        if !correction.duty_delta.is_finite() || !correction.boost_psi.is_finite() {
+            return Err(CoreError::LearningError(format!(
+                "Cell correction {}% at {} PSI is not a number", correction.duty_delta, correction.boost_psi
+            )));
+        }
+        
+        for (rpm_index, boost_index, weight) in neighbours(correction.rpm, correction.boost_psi) {
+            let cell = &mut self.cells[rpm_index][boost_index];
+            if weight > 0.0 && cell.is_learned() {
+                cell.long_term_trim = (cell.long_term_trim + correction.duty_delta * weight)
+                    .clamp(-MAX_LONG_TERM_TRIM, MAX_LONG_TERM_TRIM);
+            }
+        }
+        
+        self.unsaved_updates += 1;
+        Ok(())
+    }
+    
+    /// Take the cells of another map (an imported image)
+    /// 
+    /// The storage position is kept, so the next save supersedes every stored copy.
+    pub fn adopt(&mut self, imported: &LearnedData) {
+        self.cells = imported.cells;
+        self.unsaved_updates += 1;
+    }
+    
+    /// Forget every cell; the conservative estimates answer again
+    pub fn clear(&mut self) {
+        self.cells = [[CalibrationPoint::default(); LEARNED_BOOST_POINTS]; LEARNED_RPM_POINTS];
+        self.unsaved_updates += 1;
+    }
+    
+    /// Learning counters
+    pub fn status(&self) -> LearnedDataStatus {
+        let cells = self.cells.iter().flatten();
+        LearnedDataStatus {
+            cells_learned: cells.clone().filter(|cell| cell.is_learned()).count() as u16,
+            cells_confident: cells.filter(|cell| cell.confidence >= CONFIDENCE_THRESHOLD).count() as u16,
+            updates: self.updates,
+            unsaved_updates: self.unsaved_updates,
+        }
+    }
+    
+    /// Whether unsaved changes should be written now
+    /// 
+    /// Changes are written in batches, and never more often than once a
+    /// minute, so a failing write is not retried every cycle.
+    pub fn save_due(&self, now_ms: u32) -> bool {
+        let elapsed_ms = now_ms.wrapping_sub(self.last_save_ms);
+        self.unsaved_updates > 0
+            && elapsed_ms >= MIN_SAVE_SPACING_MS
+            && (self.unsaved_updates >= SAVE_AFTER_UPDATES || elapsed_ms >= SAVE_INTERVAL_MS)
+    }
+    
+    /// Stored image: header, cells and CRC
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LEARNED_DATA_SIZE);
+        bytes.extend_from_slice(&[MAGIC, FORMAT_VERSION, LEARNED_RPM_POINTS as u8, LEARNED_BOOST_POINTS as u8]);
+        bytes.extend_from_slice(&self.sequence.to_le_bytes());
+        for cell in self.cells.iter().flatten() {
+            // 0.5% baseline, 0.25% trim, 1/255 confidence
+            bytes.push(libm::roundf(cell.baseline_duty * 2.0).clamp(0.0, 200.0) as u8);
+            bytes.push(libm::roundf(cell.long_term_trim * 4.0).clamp(i8::MIN as f32, i8::MAX as f32) as i8 as u8);
+            bytes.push(libm::roundf(cell.confidence * 255.0).clamp(0.0, 255.0) as u8);
+            bytes.push(cell.sample_count);
+        }
+        let mut crc = Crc16::new();
+        crc.update(&bytes);
+        bytes.extend_from_slice(&crc.value().to_le_bytes());
+        bytes
+    }
+    
+    /// Restore from a stored image; short-term trims start at zero
+    pub fn from_bytes(bytes: &[u8], spring_pressure_psi: f32) -> Result<Self, CoreError> {
+        let corrupt = |problem: &str| CoreError::LearningError(format!("Stored learned data {}", problem));
+        if bytes.len() < LEARNED_DATA_SIZE {
+            return Err(corrupt("is truncated"));
+        }
+        
+        let (body, crc) = bytes[..LEARNED_DATA_SIZE].split_at(LEARNED_DATA_SIZE - CRC_SIZE);
+        let mut expected = Crc16::new();
+        expected.update(body);
+        if u16::from_le_bytes([crc[0], crc[1]]) != expected.value() {
+            return Err(corrupt("failed its checksum"));
+        }
+        if body[..4] != [MAGIC, FORMAT_VERSION, LEARNED_RPM_POINTS as u8, LEARNED_BOOST_POINTS as u8] {
+            return Err(corrupt("has an unknown layout"));
+        }
+        
+        let mut data = Self::new(spring_pressure_psi);
+        data.sequence = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+        for (cell, raw) in data.cells.iter_mut().flatten().zip(body[HEADER_SIZE..].chunks_exact(CELL_SIZE)) {
+            *cell = CalibrationPoint {
+                baseline_duty: raw[0] as f32 / 2.0,
+                short_term_trim: 0.0,
+                long_term_trim: raw[1] as i8 as f32 / 4.0,
+                confidence: raw[2] as f32 / 255.0,
+                sample_count: raw[3],
+            };
+        }
+        Ok(data)
+    }
+    
+    /// Load the newest intact copy stored under `namespace`; none is a fresh start
+    /// 
+    /// Fails only when copies were written but none of them passes its check.
+    /// Untagged copies from before namespaces are claimed for `namespace`.
+    pub fn load_from_storage<S: NonVolatileStorage>(storage: &mut S, namespace: &str, spring_pressure_psi: f32) -> Result<Self, CoreError> {
+        let tag = namespace_tag(namespace);
+        let mut newest: Option<(usize, Self)> = None;
+        let mut latest_sequence = None;
+        let mut first_error = None;
+        for slot in 0..LEARNED_DATA_SLOTS {
+            let Some((owner, image)) = read_slot(storage, slot)? else {
+                continue;
+            };
+            let data = match Self::from_bytes(&image, spring_pressure_psi) {
+                Ok(data) => data,
+                Err(e) => {
+                    first_error = first_error.or(Some(e));
+                    continue;
+                }
+            };
+            
+            if latest_sequence.is_none_or(|latest| newer(data.sequence, latest)) {
+                latest_sequence = Some(data.sequence);
+            }
+            if owner == UNCLAIMED {
+                write_copy(storage, slot_copy(slot), &tagged(&image, tag))?;
+            } else if owner != tag {
+                continue;
+            }
+            if newest.as_ref().is_none_or(|(_, best)| newer(data.sequence, best.sequence)) {
+                newest = Some((slot, data));
+            }
+        }
+        
+        let mut data = match (newest, latest_sequence, first_error) {
+            (Some((slot, data)), _, _) => Self { slot: Some(slot), ..data },
+            (None, None, Some(e)) => return Err(e),
+            (None, _, _) => Self::new(spring_pressure_psi),
+        };
+        // Later saves are the newest of any namespace, so the oldest copy is the one given up
+        data.sequence = latest_sequence.unwrap_or(0);
+        data.namespace = tag;
+        Ok(data)
+    }
+    
+    /// Write over a copy that is not the newest of this map
+    /// 
+    /// A failed write leaves the previous copy the newest; the torn one is free for the next save.
+    pub fn save_to_storage<S: NonVolatileStorage>(&mut self, storage: &mut S, now_ms: u32) -> Result<(), CoreError> {
+        self.last_save_ms = now_ms;
+        self.sequence = self.sequence.wrapping_add(1);
+        let slot = self.slot_to_overwrite(storage)?;
+        write_copy(storage, slot_copy(slot), &tagged(&self.to_bytes(), self.namespace))?;
+        self.slot = Some(slot);
+        self.unsaved_updates = 0;
+        Ok(())
+    }
+    
+    /// Discard every stored copy of a namespace's map (its configuration was deleted)
+    pub fn erase_namespace<S: NonVolatileStorage>(storage: &mut S, namespace: &str) -> Result<(), CoreError> {
+        let tag = namespace_tag(namespace);
+        for slot in 0..LEARNED_DATA_SLOTS {
+            if matches!(read_slot(storage, slot)?, Some((owner, _)) if owner == tag) {
+                discard_copy(storage, slot_copy(slot))?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Slot the next save goes to: empty first, then this map's oldest copy, then another map's oldest spare copy
+    /// 
+    /// Another map's copy is only spare while a newer copy of that map is
+    /// stored. With every slot holding this map's newest copy or another
+    /// map's only one, the save is refused.
+    fn slot_to_overwrite<S: NonVolatileStorage>(&self, storage: &mut S) -> Result<usize, CoreError> {
+        let mut stored = [None; LEARNED_DATA_SLOTS];
+        for (slot, copy) in stored.iter_mut().enumerate() {
+            *copy = read_slot(storage, slot)?
+                .and_then(|(owner, image)| Some((owner, Self::from_bytes(&image, self.spring_pressure_psi).ok()?.sequence)));
+        }
+        
+        let mut own: Option<(usize, u32)> = None;
+        let mut other: Option<(usize, u32)> = None;
+        for slot in (0..LEARNED_DATA_SLOTS).filter(|&slot| Some(slot) != self.slot) {
+            // Never written, torn or unreadable - nothing to lose
+            let Some((owner, sequence)) = stored[slot] else {
+                return Ok(slot);
+            };
+            let superseded = stored.iter().flatten().any(|&(other_owner, other_sequence)| other_owner == owner && newer(other_sequence, sequence));
+            let oldest = if owner == self.namespace {
+                &mut own
+            } else if superseded {
+                &mut other
+            } else {
+                // Another configuration's only map
+                continue;
+            };
+            if oldest.is_none_or(|(_, oldest_sequence)| newer(oldest_sequence, sequence)) {
+                *oldest = Some((slot, sequence));
+            }
+        }
+        own.or(other).map(|(slot, _)| slot).ok_or_else(|| CoreError::StorageError(format!(
+            "All {} learned data slots hold other configurations' only maps - delete a configuration to free one", LEARNED_DATA_SLOTS
+        )))
+    }
+    
+    /// Bilinear duty at an operating point (%)
+    fn interpolate(&self, rpm: u16, boost_psi: f32, feed_psi: f32) -> f32 {
+        neighbours(rpm, boost_psi)
+            .iter()
+            .map(|&(rpm_index, boost_index, weight)| weight * self.cell_duty(rpm_index, boost_index, feed_psi))
+            .sum()
+    }
+    
+    /// Learned duty of a cell, or the conservative estimate while unlearned (%)
+    fn cell_duty(&self, rpm_index: usize, boost_index: usize, feed_psi: f32) -> f32 {
+        let cell = &self.cells[rpm_index][boost_index];
+        if cell.is_learned() {
+            cell.duty_percent()
+        } else {
+            self.estimated_duty(boost_index as f32 * LEARNED_BOOST_STEP_PSI, feed_psi)
+        }
+    }
+    
+    /// Conservative duty for a boost from spring and dome supply pressure (%)
+    /// 
+    /// The upper dome needs roughly the boost above spring pressure to hold the
+    /// wastegate, and full duty passes the whole supply to it.
+    fn estimated_duty(&self, boost_psi: f32, feed_psi: f32) -> f32 {
+        if feed_psi < MIN_FEED_PSI {
+            return 0.0;
+        }
+        (CONSERVATIVE_FACTOR * 100.0 * (boost_psi - self.spring_pressure_psi) / feed_psi).clamp(0.0, 100.0)
+    }
+}
+
+/// Area of one stored copy
+fn slot_copy(slot: usize) -> StorageRegion {
+    copy_of(StorageRegion::LEARNED_DATA, LEARNED_DATA_SLOTS, slot)
+}
+
+/// Namespace tag and image of a stored copy (`None` if never written or torn)
+fn read_slot<S: NonVolatileStorage>(storage: &mut S, slot: usize) -> Result<Option<(u16, [u8; LEARNED_DATA_SIZE])>, CoreError> {
+    if copy_state(storage, slot_copy(slot))? == CopyState::Uncommitted {
+        return Ok(None);
+    }
+    let mut bytes = [0; LEARNED_DATA_SIZE + TAG_SIZE];
+    storage.read(slot_copy(slot).offset, &mut bytes)?;
+    let (image, tag) = bytes.split_at(LEARNED_DATA_SIZE);
+    if image.iter().all(|&byte| byte == ERASED_BYTE) {
+        return Ok(None);
+    }
+    let mut stored = [0; LEARNED_DATA_SIZE];
+    stored.copy_from_slice(image);
+    Ok(Some((u16::from_le_bytes([tag[0], tag[1]]), stored)))
+}
+
+/// Copy contents: the image followed by its namespace tag
+fn tagged(image: &[u8], tag: u16) -> Vec<u8> {
+    let mut body = Vec::with_capacity(image.len() + TAG_SIZE);
+    body.extend_from_slice(image);
+    body.extend_from_slice(&tag.to_le_bytes());
+    body
+}
+
+/// Storage tag of a configuration namespace
+/// 
+/// A checksum of the name; the unclaimed tag is never produced.
+fn namespace_tag(namespace: &str) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update(namespace.as_bytes());
+    match crc.value() {
+        UNCLAIMED => UNCLAIMED - 1,
+        tag => tag,
+    }
+}
+
+/// Map cells around an operating point with their bilinear weights
+fn neighbours(rpm: u16, boost_psi: f32) -> [(usize, usize, f32); 4] {
+    let rpm_position = (rpm as f32 - LEARNED_RPM_MIN as f32) / LEARNED_RPM_STEP as f32;
+    let (rpm_index, rpm_fraction) = axis_position(rpm_position, LEARNED_RPM_POINTS);
+    let (boost_index, boost_fraction) = axis_position(boost_psi / LEARNED_BOOST_STEP_PSI, LEARNED_BOOST_POINTS);
+    [
+        (rpm_index, boost_index, (1.0 - rpm_fraction) * (1.0 - boost_fraction)),
+        (rpm_index, boost_index + 1, (1.0 - rpm_fraction) * boost_fraction),
+        (rpm_index + 1, boost_index, rpm_fraction * (1.0 - boost_fraction)),
+        (rpm_index + 1, boost_index + 1, rpm_fraction * boost_fraction),
+    ]
+}
+
+/// Lower point and fraction toward the next, clamped to the axis
+fn axis_position(position: f32, points: usize) -> (usize, f32) {
+    let position = position.clamp(0.0, (points - 1) as f32);
+    let index = (position as usize).min(points - 2);
+    (index, position - index as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, RamStorage};
+    use rumbledome_hal::EEPROM_REGION_SIZE;
+    
+    const NAMESPACE: &str = "learned/default";
+    
+    fn inputs(rpm: u16, boost_psi: f32) -> SystemInputs {
+        SystemInputs { rpm, manifold_pressure: boost_psi, dome_input_pressure: 20.0, ..test_support::inputs(0) }
+    }
+    
+    /// Stored cell bytes, without the sequence number and CRC that change with every save
+    fn stored_cells(data: &LearnedData) -> Vec<u8> {
+        data.to_bytes()[HEADER_SIZE..LEARNED_DATA_SIZE - CRC_SIZE].to_vec()
+    }
+    
+    #[test]
+    fn test_unlearned_map_is_conservative() {
+        let data = LearnedData::new(5.0);
+        assert_eq!(data.boost_to_duty_conversion(4.0, &inputs(4000, 0.0)).unwrap(), 0.0);
+        
+        // 5 PSI over spring on a 20 PSI supply needs ~25%; the estimate stays below
+        let duty = data.boost_to_duty_conversion(10.0, &inputs(4000, 0.0)).unwrap();
+        assert!((duty - 20.0).abs() < 0.01);
+        
+        // No supply, no authority to estimate from
+        let no_feed = SystemInputs { dome_input_pressure: 0.0, ..inputs(4000, 0.0) };
+        assert_eq!(data.boost_to_duty_conversion(10.0, &no_feed).unwrap(), 0.0);
+        assert!(data.boost_to_duty_conversion(f32::NAN, &no_feed).is_err());
+    }
+    
+    #[test]
+    fn test_learning_converges_on_observed_duty() {
+        let mut data = LearnedData::new(5.0);
+        
+        // This car holds 10 PSI at 4000 RPM on 35% duty
+        data.update_from_operation(&inputs(4000, 10.0), 10.0, 35.0).unwrap();
+        let duty = data.boost_to_duty_conversion(10.0, &inputs(4000, 0.0)).unwrap();
+        assert!((duty - 35.0).abs() < 0.5);
+        
+        // The car now needs more duty (supply regulator drift); the trims follow
+        for _ in 0..400 {
+            data.update_from_operation(&inputs(4000, 10.0), 10.0, 40.0).unwrap();
+        }
+        let duty = data.boost_to_duty_conversion(10.0, &inputs(4000, 0.0)).unwrap();
+        assert!((duty - 40.0).abs() < 0.5);
+        let cell = data.cell(4, 5);
+        assert!(cell.long_term_trim > 1.0);
+        assert!(cell.confidence >= CONFIDENCE_THRESHOLD);
+        assert_eq!(data.status().cells_learned, 1);
+        
+        // Boost on the spring, or held short of target by the throttle, says nothing about duty
+        data.update_from_operation(&inputs(4000, 4.0), 4.0, 60.0).unwrap();
+        data.update_from_operation(&inputs(4000, 7.0), 12.0, 60.0).unwrap();
+        assert_eq!(data.status().updates, 401);
+    }
+    
+    #[test]
+    fn test_overshoot_correction_lowers_learned_cells() {
+        let mut data = LearnedData::new(5.0);
+        data.update_from_operation(&inputs(3000, 12.0), 12.0, 45.0).unwrap();
+        let before = data.boost_to_duty_conversion(12.0, &inputs(3000, 0.0)).unwrap();
+        
+        data.apply_cell_correction(&CellCorrection { rpm: 3000, boost_psi: 12.0, duty_delta: -3.0 }).unwrap();
+        let after = data.boost_to_duty_conversion(12.0, &inputs(3000, 0.0)).unwrap();
+        assert!((before - after - 3.0).abs() < 0.01);
+        
+        // Trims stay bounded
+        for _ in 0..20 {
+            data.apply_cell_correction(&CellCorrection { rpm: 3000, boost_psi: 12.0, duty_delta: -5.0 }).unwrap();
+        }
+        assert_eq!(data.cell(2, 6).long_term_trim, -MAX_LONG_TERM_TRIM);
+    }
+    
+    #[test]
+    fn test_storage_round_trip_and_corruption() {
+        const { assert!(LEARNED_DATA_SIZE <= EEPROM_REGION_SIZE) };
+        let mut storage = RamStorage::new();
+        
+        // Never written - fresh map
+        let mut data = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(data.status().cells_learned, 0);
+        
+        for _ in 0..SAVE_AFTER_UPDATES {
+            data.update_from_operation(&inputs(5000, 14.0), 14.0, 52.0).unwrap();
+        }
+        assert!(!data.save_due(30_000));
+        assert!(data.save_due(60_000));
+        data.save_to_storage(&mut storage, 60_000).unwrap();
+        assert!(!data.save_due(60_000));
+        
+        let loaded = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(loaded.status().cells_learned, 1);
+        let duty = loaded.boost_to_duty_conversion(14.0, &inputs(5000, 0.0)).unwrap();
+        assert!((duty - 52.0).abs() < 1.0);
+        
+        storage.bytes[StorageRegion::LEARNED_DATA.offset + HEADER_SIZE + 10] ^= 0x01;
+        assert!(LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).is_err());
+    }
+    
+    #[test]
+    fn test_saves_rotate_through_copies() {
+        let mut storage = RamStorage::new();
+        let sequence_in = |storage: &RamStorage, slot: usize| {
+            let at = StorageRegion::LEARNED_DATA.offset + slot * EEPROM_REGION_SIZE + 4;
+            u32::from_le_bytes(storage.bytes[at..at + 4].try_into().unwrap())
+        };
+        
+        let mut data = LearnedData::new(5.0).with_namespace(NAMESPACE);
+        for save in 1..=4 {
+            data.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0 + save as f32).unwrap();
+            data.save_to_storage(&mut storage, save * 60_000).unwrap();
+        }
+        // Fourth save wrapped round to the first copy
+        assert_eq!(LEARNED_DATA_SLOTS, 3);
+        assert_eq!([sequence_in(&storage, 0), sequence_in(&storage, 1), sequence_in(&storage, 2)], [4, 2, 3]);
+        // The safety limits copies are never written over
+        for copy in StorageRegion::SAFETY_LIMITS {
+            assert!(storage.bytes[copy.offset..copy.offset + copy.len].iter().all(|&byte| byte == ERASED_BYTE));
+        }
+        
+        let mut loaded = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(loaded.to_bytes(), data.to_bytes());
+        
+        // Saving after a reboot carries on with the oldest copy
+        loaded.save_to_storage(&mut storage, 300_000).unwrap();
+        assert_eq!(sequence_in(&storage, 1), 5);
+        
+        // A damaged newest copy falls back to the one before
+        storage.bytes[StorageRegion::LEARNED_DATA.offset + EEPROM_REGION_SIZE + HEADER_SIZE] ^= 0x01;
+        let fallback = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(fallback.to_bytes(), data.to_bytes());
+    }
+    
+    #[test]
+    fn test_uncommitted_copy_is_skipped() {
+        let mut storage = RamStorage::new();
+        let mut data = LearnedData::new(5.0).with_namespace(NAMESPACE);
+        data.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0).unwrap();
+        data.save_to_storage(&mut storage, 60_000).unwrap();
+        let saved = data.to_bytes();
+        data.update_from_operation(&inputs(6000, 14.0), 14.0, 55.0).unwrap();
+        data.save_to_storage(&mut storage, 120_000).unwrap();
+        
+        // Key-off after the image was written but before its commit byte was set
+        let commit_byte = StorageRegion::LEARNED_DATA.offset + 2 * EEPROM_REGION_SIZE - 1;
+        storage.bytes[commit_byte] = 0x00;
+        let loaded = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(loaded.to_bytes(), saved);
+    }
+    
+    #[test]
+    fn test_newest_copy_found_across_sequence_wrap() {
+        let mut storage = RamStorage::new();
+        let mut data = LearnedData::new(5.0).with_namespace(NAMESPACE);
+        data.sequence = u32::MAX - 1;
+        data.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0).unwrap();
+        data.save_to_storage(&mut storage, 60_000).unwrap();
+        data.update_from_operation(&inputs(3000, 8.0), 8.0, 20.0).unwrap();
+        data.save_to_storage(&mut storage, 120_000).unwrap();
+        
+        // Sequence 0 follows u32::MAX
+        let loaded = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(loaded.sequence, 0);
+        assert_eq!(loaded.to_bytes(), data.to_bytes());
+    }
+    
+    #[test]
+    fn test_namespaces_keep_separate_maps() {
+        let mut storage = RamStorage::new();
+        let mut stock = LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap();
+        for save in 1..=2 {
+            stock.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0).unwrap();
+            stock.save_to_storage(&mut storage, save * 60_000).unwrap();
+        }
+        
+        // Another configuration starts from the estimates and never sees this map
+        let mut big = LearnedData::load_from_storage(&mut storage, "learned/big turbo", 8.0).unwrap();
+        assert_eq!(big.status().cells_learned, 0);
+        big.update_from_operation(&inputs(4000, 18.0), 18.0, 30.0).unwrap();
+        big.save_to_storage(&mut storage, 180_000).unwrap();
+        
+        // With no slot free, a third map gives up the stock map's older copy, never its newest
+        let mut e85 = LearnedData::load_from_storage(&mut storage, "learned/e85", 5.0).unwrap();
+        e85.update_from_operation(&inputs(6000, 16.0), 16.0, 60.0).unwrap();
+        e85.save_to_storage(&mut storage, 240_000).unwrap();
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap()), stored_cells(&stock));
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/big turbo", 8.0).unwrap()), stored_cells(&big));
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/e85", 5.0).unwrap()), stored_cells(&e85));
+        
+        // Every slot now holds a configuration's only map, so a save is refused rather than evict one
+        let saved_big = stored_cells(&big);
+        big.update_from_operation(&inputs(4500, 18.0), 18.0, 32.0).unwrap();
+        assert!(matches!(big.save_to_storage(&mut storage, 300_000), Err(CoreError::StorageError(_))));
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap()), stored_cells(&stock));
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/big turbo", 8.0).unwrap()), saved_big);
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/e85", 5.0).unwrap()), stored_cells(&e85));
+        
+        // Deleting a configuration frees its copies
+        LearnedData::erase_namespace(&mut storage, "learned/big turbo").unwrap();
+        assert_eq!(LearnedData::load_from_storage(&mut storage, "learned/big turbo", 8.0).unwrap().status().cells_learned, 0);
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap()), stored_cells(&stock));
+        e85.save_to_storage(&mut storage, 360_000).unwrap();
+    }
+    
+    #[test]
+    fn test_untagged_copy_claimed_by_first_namespace_loaded() {
+        let mut storage = RamStorage::new();
+        let mut data = LearnedData::new(5.0);
+        data.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0).unwrap();
+        // Stored the way copies were before namespaces - no tag after the image
+        write_copy(&mut storage, slot_copy(0), &data.to_bytes()).unwrap();
+        
+        let claimed = LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap();
+        assert_eq!(claimed.status().cells_learned, 1);
+        assert_eq!(LearnedData::load_from_storage(&mut storage, "learned/e85", 5.0).unwrap().status().cells_learned, 0);
+        assert_eq!(stored_cells(&LearnedData::load_from_storage(&mut storage, "learned/stock", 5.0).unwrap()), stored_cells(&claimed));
+    }
+    
+    #[test]
+    fn test_import_in_chunks_supersedes_stored_copies() {
+        let mut storage = RamStorage::new();
+        let mut data = LearnedData::new(5.0).with_namespace(NAMESPACE);
+        for save in 1..=4 {
+            data.update_from_operation(&inputs(5000, 14.0), 14.0, 50.0).unwrap();
+            data.save_to_storage(&mut storage, save * 60_000).unwrap();
+        }
+        
+        // Another car's map, exported at a lower sequence number
+        let mut other = LearnedData::new(5.0);
+        other.update_from_operation(&inputs(3000, 8.0), 8.0, 20.0).unwrap();
+        let image = other.to_bytes();
+        
+        let mut upload = LearnedDataUpload::default();
+        let mut complete = None;
+        for (index, chunk) in image.chunks(LEARNED_DATA_CHUNK_BYTES).enumerate() {
+            assert!(complete.is_none());
+            complete = upload.accept((index * LEARNED_DATA_CHUNK_BYTES) as u32, chunk).unwrap();
+        }
+        let imported = LearnedData::from_bytes(&complete.unwrap(), 5.0).unwrap();
+        data.adopt(&imported);
+        data.save_to_storage(&mut storage, 300_000).unwrap();
+        
+        let loaded = LearnedData::load_from_storage(&mut storage, NAMESPACE, 5.0).unwrap();
+        assert_eq!(loaded.cell(2, 4), imported.cell(2, 4));
+        assert!(loaded.cell(2, 4).is_learned());
+        assert_eq!(loaded.cell(6, 7).sample_count, 0);
+        
+        data.clear();
+        assert_eq!(data.status().cells_learned, 0);
+    }
+    
+    #[test]
+    fn test_upload_refuses_gaps_and_overruns() {
+        let mut upload = LearnedDataUpload::default();
+        assert!(upload.accept(0, &[0; 64]).unwrap().is_none());
+        assert!(upload.accept(128, &[0; 64]).is_err());
+        assert!(upload.accept(64, &[0; LEARNED_DATA_CHUNK_BYTES + 1]).is_err());
+        assert_eq!(upload.received(), 64);
+        
+        // Offset 0 starts over
+        assert!(upload.accept(0, &[0; 16]).unwrap().is_none());
+        assert_eq!(upload.received(), 16);
+        let mut offset = 16;
+        while LEARNED_DATA_SIZE - offset > LEARNED_DATA_CHUNK_BYTES {
+            upload.accept(offset as u32, &[0; LEARNED_DATA_CHUNK_BYTES]).unwrap();
+            offset += LEARNED_DATA_CHUNK_BYTES;
+        }
+        assert!(upload.accept(offset as u32, &[0; LEARNED_DATA_CHUNK_BYTES]).is_err());
+        assert_eq!(upload.accept(offset as u32, &alloc::vec![0; LEARNED_DATA_SIZE - offset]).unwrap().unwrap().len(), LEARNED_DATA_SIZE);
+        assert_eq!(upload.received(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::{RumbleDomeCore, SystemConfig, SystemState, LEARNED_DATA_CHUNK_BYTES};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_learned_data_survives_power_cycle() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 4500;
+        inputs.manifold_pressure = 11.0;
+        inputs.dome_input_pressure = 25.0;
+        core.learned_data.update_from_operation(&inputs, 11.0, 38.0).unwrap();
+        core.learned_data.save_to_storage(&mut core.hal, 60_000).unwrap();
+        
+        // Same EEPROM, new boot
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 2);
+        let duty = core.learned_data.boost_to_duty_conversion(11.0, &inputs).unwrap();
+        assert!((duty - 38.0).abs() < 1.0);
+    }
+    
+    #[test]
+    fn test_torn_save_keeps_previous_copy() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 4500;
+        inputs.manifold_pressure = 11.0;
+        inputs.dome_input_pressure = 25.0;
+        core.learned_data.update_from_operation(&inputs, 11.0, 38.0).unwrap();
+        core.learned_data.save_to_storage(&mut core.hal, 60_000).unwrap();
+        
+        // Key-off in the middle of the next save
+        inputs.rpm = 6000;
+        core.learned_data.update_from_operation(&inputs, 11.0, 45.0).unwrap();
+        core.hal.tear_next_write(40);
+        assert!(core.learned_data.save_to_storage(&mut core.hal, 120_000).is_err());
+        
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 2);
+    }
+    
+    #[test]
+    fn test_import_and_reset_survive_power_cycle() {
+        let mut donor = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        donor.initialize().unwrap();
+        let mut inputs = donor.read_inputs().unwrap();
+        inputs.rpm = 4500;
+        inputs.manifold_pressure = 11.0;
+        inputs.dome_input_pressure = 25.0;
+        donor.learned_data.update_from_operation(&inputs, 11.0, 38.0).unwrap();
+        let image = donor.export_learned_data();
+        
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        core.state = SystemState::Armed;
+        assert!(core.import_learned_data(0, &image[..LEARNED_DATA_CHUNK_BYTES]).is_err());
+        core.state = SystemState::Idle;
+        
+        let mut progress = None;
+        for (index, chunk) in image.chunks(LEARNED_DATA_CHUNK_BYTES).enumerate() {
+            progress = Some(core.import_learned_data((index * LEARNED_DATA_CHUNK_BYTES) as u32, chunk).unwrap());
+        }
+        let progress = progress.unwrap();
+        assert_eq!(progress.received, progress.total);
+        
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 2);
+        
+        core.reset_learned_data().unwrap();
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 0);
+    }
+    
+    #[test]
+    fn test_each_configuration_keeps_its_own_learned_data() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        core.save_configuration("big turbo", SystemConfig { spring_pressure: 8.0, ..SystemConfig::default() }).unwrap();
+        
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 4500;
+        inputs.manifold_pressure = 11.0;
+        inputs.dome_input_pressure = 25.0;
+        core.learned_data.update_from_operation(&inputs, 11.0, 38.0).unwrap();
+        
+        // Unsaved samples go with the map being left, and the new configuration starts from the estimates
+        core.switch_configuration("big turbo").unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 0);
+        inputs.manifold_pressure = 14.0;
+        core.learned_data.update_from_operation(&inputs, 14.0, 30.0).unwrap();
+        
+        core.switch_configuration("default").unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 2);
+        let duty = core.learned_data.boost_to_duty_conversion(11.0, &inputs).unwrap();
+        assert!((duty - 38.0).abs() < 1.0);
+        core.switch_configuration("big turbo").unwrap();
+        assert!(core.learned_data.status().cells_learned > 0);
+        core.switch_configuration("default").unwrap();
+        
+        // A configuration saved again under a deleted name does not inherit its map
+        core.delete_configuration("big turbo").unwrap();
+        core.save_configuration("big turbo", SystemConfig { spring_pressure: 8.0, ..SystemConfig::default() }).unwrap();
+        core.switch_configuration("big turbo").unwrap();
+        assert_eq!(core.learned_data.status().cells_learned, 0);
+    }
+}
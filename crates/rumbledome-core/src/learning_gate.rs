@@ -0,0 +1,373 @@
+//! Learning Sample Gate
+//! 
+//! 🔗 T4-CORE-065: Transient Rejection for Learning
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + learned calibration convergence needs
+//! AI Traceability: Keep mid-transient samples out of the steady-state learned duty table
+//! 
+//! 🔗 T4-CORE-135: Load Check for Learning
+//! Derived From: T4-CORE-065 + T4-CORE-108 (Learned Duty Calibration Map)
+//! AI Traceability: Free-revving in neutral spools the turbo against almost no load - its duty/boost pairs do not hold on the road
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Transient thresholds for learning
+/// 
+/// The learned table maps steady operating points to duty. While throttle,
+/// RPM or the boost target are moving quickly the duty that held boost says
+/// more about turbo lag than about the operating point, so those samples are
+/// kept out of the table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LearningGateConfig {
+    /// Throttle rate above which a transient is active (%/s)
+    pub max_throttle_rate: f32,
+    /// RPM rate above which a transient is active (RPM/s)
+    pub max_rpm_rate: f32,
+    /// Boost target rate above which target slew is active (PSI/s)
+    pub max_target_rate: f32,
+    /// Time after the last transient before samples are accepted again (ms)
+    pub settle_ms: u32,
+    /// Load conditions a sample must have been taken under
+    #[serde(default)]
+    pub load: LearningLoadConfig,
+}
+
+impl Default for LearningGateConfig {
+    fn default() -> Self {
+        Self {
+            max_throttle_rate: 50.0,
+            max_rpm_rate: 1500.0,
+            max_target_rate: 1.0,
+            settle_ms: 500,
+            load: LearningLoadConfig::default(),
+        }
+    }
+}
+
+impl LearningGateConfig {
+    /// Validate learning gate settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(5.0..=1000.0).contains(&self.max_throttle_rate) {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning throttle rate limit must be 5-1000 %/s, got {}", self.max_throttle_rate)
+            ));
+        }
+        
+        if !(100.0..=20_000.0).contains(&self.max_rpm_rate) {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning RPM rate limit must be 100-20000 RPM/s, got {}", self.max_rpm_rate)
+            ));
+        }
+        
+        if !(0.1..=20.0).contains(&self.max_target_rate) {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning target rate limit must be 0.1-20.0 PSI/s, got {}", self.max_target_rate)
+            ));
+        }
+        
+        if self.settle_ms > 5000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning settle time must be at most 5000 ms, got {}", self.settle_ms)
+            ));
+        }
+        
+        self.load.validate()
+    }
+}
+
+/// Load check for learning
+/// 
+/// A sample counts as loaded when the engine makes at least `min_torque_nm`,
+/// or the car is moving at a speed that fits the RPM in some gear - RPM per
+/// km/h within the range the gearbox and final drive allow. Revving in
+/// neutral, parked or rolling, fails both. A dyno session skips the check
+/// (an engine dyno reports no vehicle speed); it is meant to be switched off
+/// again afterwards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LearningLoadConfig {
+    /// Accept samples without the load check (dedicated dyno sessions)
+    pub dyno_session: bool,
+    /// Actual torque that counts as loaded whatever the vehicle speed (Nm)
+    pub min_torque_nm: f32,
+    /// Vehicle speed below which speed does not show load (km/h)
+    pub min_vehicle_speed_kph: f32,
+    /// RPM per km/h in the tallest gear
+    pub min_rpm_per_kph: f32,
+    /// RPM per km/h in first gear
+    pub max_rpm_per_kph: f32,
+}
+
+impl Default for LearningLoadConfig {
+    fn default() -> Self {
+        Self {
+            dyno_session: false,
+            min_torque_nm: 150.0,
+            min_vehicle_speed_kph: 10.0,
+            min_rpm_per_kph: 10.0,
+            max_rpm_per_kph: 150.0,
+        }
+    }
+}
+
+impl LearningLoadConfig {
+    /// Validate load check settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(0.0..=1000.0).contains(&self.min_torque_nm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning load torque must be 0-1000 Nm, got {}", self.min_torque_nm)
+            ));
+        }
+        
+        if !(1.0..=100.0).contains(&self.min_vehicle_speed_kph) {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning minimum vehicle speed must be 1-100 km/h, got {}", self.min_vehicle_speed_kph)
+            ));
+        }
+        
+        if self.min_rpm_per_kph <= 0.0 || self.max_rpm_per_kph <= self.min_rpm_per_kph {
+            return Err(CoreError::ConfigurationError(
+                format!("Learning RPM per km/h range must be positive and increasing, got {}-{}",
+                    self.min_rpm_per_kph, self.max_rpm_per_kph)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether a sample was taken under plausible load
+    pub fn is_loaded(&self, rpm: u16, load: &LoadSample) -> bool {
+        if self.dyno_session || load.torque_nm >= self.min_torque_nm {
+            return true;
+        }
+        load.vehicle_speed_kph
+            .filter(|&kph| kph >= self.min_vehicle_speed_kph)
+            .is_some_and(|kph| (self.min_rpm_per_kph..=self.max_rpm_per_kph).contains(&(rpm as f32 / kph)))
+    }
+}
+
+/// Load conditions of a control cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadSample {
+    /// Vehicle speed, if a fresh reading is available (km/h)
+    pub vehicle_speed_kph: Option<f32>,
+    /// ECU actual torque (Nm)
+    pub torque_nm: f32,
+}
+
+/// Learning sample counters (diagnostics)
+/// 
+/// A rejected sample counts against every transient active in that cycle, so
+/// the per-cause counters can add up to more than `rejected_samples`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LearningGateStats {
+    /// Samples passed to the learned table
+    pub accepted_samples: u32,
+    /// Samples kept out of the learned table
+    pub rejected_samples: u32,
+    /// Rejections with throttle moving too fast
+    pub throttle_rejections: u32,
+    /// Rejections with RPM moving too fast
+    pub rpm_rejections: u32,
+    /// Rejections with the boost target slewing
+    pub target_rejections: u32,
+    /// Rejections while settling after a transient
+    pub settling_rejections: u32,
+    /// Steady samples rejected for lack of load
+    #[serde(default)]
+    pub load_rejections: u32,
+}
+
+/// Previous cycle's operating point
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    throttle: Option<f32>,
+    rpm: u16,
+    target_psi: f32,
+    timestamp_ms: u32,
+}
+
+/// Learning sample gate
+/// 
+/// 🔗 T4-CORE-066: Learning Sample Gate
+/// Derived From: T4-CORE-065
+/// 
+/// Rates are taken between consecutive control cycles. After any transient,
+/// samples stay rejected for the settle time so the tail of a spool event
+/// does not leak into the table. Steady samples must then pass the load
+/// check.
+#[derive(Debug, Clone)]
+pub struct LearningGate {
+    config: LearningGateConfig,
+    previous: Option<Sample>,
+    transient_ms: Option<u32>,
+    stats: LearningGateStats,
+}
+
+impl LearningGate {
+    /// Create gate with no history
+    pub fn new(config: LearningGateConfig) -> Self {
+        Self {
+            config,
+            previous: None,
+            transient_ms: None,
+            stats: LearningGateStats::default(),
+        }
+    }
+    
+    /// Apply new settings, keeping counters
+    pub fn reconfigure(&mut self, config: LearningGateConfig) {
+        self.config = config;
+        self.cancel();
+    }
+    
+    /// Sample counters
+    pub fn stats(&self) -> &LearningGateStats {
+        &self.stats
+    }
+    
+    /// Feed one control cycle; returns true if its sample may feed learning
+    /// 
+    /// Throttle rate is only checked while throttle is known on both cycles.
+    pub fn update(&mut self, throttle: Option<f32>, rpm: u16, target_psi: f32, load: &LoadSample, timestamp_ms: u32) -> bool {
+        let sample = Sample { throttle, rpm, target_psi, timestamp_ms };
+        
+        // The first cycle has nothing to measure rates against
+        let Some(previous) = self.previous.replace(sample) else {
+            self.transient_ms = Some(timestamp_ms);
+            return self.reject(false, false, false);
+        };
+        
+        let dt_s = timestamp_ms.wrapping_sub(previous.timestamp_ms) as f32 / 1000.0;
+        if dt_s > 0.0 {
+            let throttle_transient = throttle.zip(previous.throttle)
+                .is_some_and(|(throttle, previous)| (throttle - previous).abs() / dt_s > self.config.max_throttle_rate);
+            let rpm_transient = (rpm as f32 - previous.rpm as f32).abs() / dt_s > self.config.max_rpm_rate;
+            let target_transient = (target_psi - previous.target_psi).abs() / dt_s > self.config.max_target_rate;
+            
+            if throttle_transient || rpm_transient || target_transient {
+                self.transient_ms = Some(timestamp_ms);
+                return self.reject(throttle_transient, rpm_transient, target_transient);
+            }
+        }
+        
+        let settled = self.transient_ms
+            .is_none_or(|since| timestamp_ms.wrapping_sub(since) >= self.config.settle_ms);
+        if !settled {
+            return self.reject(false, false, false);
+        }
+        
+        self.transient_ms = None;
+        if !self.config.load.is_loaded(rpm, load) {
+            self.stats.rejected_samples += 1;
+            self.stats.load_rejections += 1;
+            return false;
+        }
+        self.stats.accepted_samples += 1;
+        true
+    }
+    
+    /// Forget history (control no longer active)
+    /// 
+    /// Rates are not measured across the gap; the next sample starts a new
+    /// settle period.
+    pub fn cancel(&mut self) {
+        self.previous = None;
+        self.transient_ms = None;
+    }
+    
+    fn reject(&mut self, throttle: bool, rpm: bool, target: bool) -> bool {
+        self.stats.rejected_samples += 1;
+        self.stats.throttle_rejections += throttle as u32;
+        self.stats.rpm_rejections += rpm as u32;
+        self.stats.target_rejections += target as u32;
+        self.stats.settling_rejections += !(throttle || rpm || target) as u32;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Cruising load - 3000 RPM at 80 km/h
+    const LOADED: LoadSample = LoadSample { vehicle_speed_kph: Some(80.0), torque_nm: 100.0 };
+    
+    /// Run steady cycles from `start_ms` to `end_ms`, returning accepted count
+    fn steady(gate: &mut LearningGate, start_ms: u32, end_ms: u32) -> u32 {
+        (start_ms..end_ms).step_by(10)
+            .filter(|&t| gate.update(Some(40.0), 3000, 8.0, &LOADED, t))
+            .count() as u32
+    }
+    
+    #[test]
+    fn test_steady_state_accepted_after_settling() {
+        let mut gate = LearningGate::new(LearningGateConfig::default());
+        
+        // 500 ms settle from the first sample, then every cycle accepted
+        assert_eq!(steady(&mut gate, 0, 1000), 50);
+        assert_eq!(gate.stats().accepted_samples, 50);
+        assert_eq!(gate.stats().settling_rejections, 50);
+    }
+    
+    #[test]
+    fn test_transients_rejected_by_cause() {
+        let mut gate = LearningGate::new(LearningGateConfig::default());
+        steady(&mut gate, 0, 1000);
+        
+        // Throttle stab: 40% -> 80% in one cycle
+        assert!(!gate.update(Some(80.0), 3000, 8.0, &LOADED, 1000));
+        assert_eq!(gate.stats().throttle_rejections, 1);
+        
+        // Downshift: 3000 -> 4500 RPM in one cycle
+        assert!(!gate.update(Some(80.0), 4500, 8.0, &LOADED, 1010));
+        assert_eq!(gate.stats().rpm_rejections, 1);
+        
+        // Target slewing at 2 PSI/s
+        assert!(!gate.update(Some(80.0), 4500, 8.02, &LOADED, 1020));
+        assert_eq!(gate.stats().target_rejections, 1);
+        
+        // Settling after the last transient, then accepted again
+        assert!(!gate.update(Some(80.0), 4500, 8.02, &LOADED, 1030));
+        assert!(!gate.update(Some(80.0), 4500, 8.02, &LOADED, 1510));
+        assert!(gate.update(Some(80.0), 4500, 8.02, &LOADED, 1520));
+    }
+    
+    #[test]
+    fn test_cancel_restarts_settling() {
+        let mut gate = LearningGate::new(LearningGateConfig::default());
+        steady(&mut gate, 0, 1000);
+        
+        // A jump across a gap is not a transient, but needs a fresh settle period
+        gate.cancel();
+        assert!(!gate.update(Some(10.0), 1500, 3.0, &LOADED, 5000));
+        assert_eq!(gate.stats().throttle_rejections, 0);
+        assert!(gate.update(Some(10.0), 1500, 3.0, &LOADED, 5500));
+    }
+    
+    #[test]
+    fn test_unloaded_revving_rejected() {
+        let mut gate = LearningGate::new(LearningGateConfig::default());
+        steady(&mut gate, 0, 1000);
+        
+        // Parked, and rolling in neutral at 3000 RPM - neither fits a gear
+        let parked = LoadSample { vehicle_speed_kph: Some(0.0), torque_nm: 40.0 };
+        let coasting = LoadSample { vehicle_speed_kph: Some(15.0), torque_nm: 40.0 };
+        assert!(!gate.update(Some(40.0), 3000, 8.0, &parked, 1000));
+        assert!(!gate.update(Some(40.0), 3000, 8.0, &coasting, 1010));
+        assert_eq!(gate.stats().load_rejections, 2);
+        
+        // Torque shows load without a speed signal
+        let pulling = LoadSample { vehicle_speed_kph: None, torque_nm: 300.0 };
+        assert!(gate.update(Some(40.0), 3000, 8.0, &pulling, 1020));
+        
+        // A dyno session skips the check
+        let mut config = LearningGateConfig::default();
+        config.load.dyno_session = true;
+        gate.reconfigure(config);
+        steady(&mut gate, 2000, 2500);
+        assert!(gate.update(Some(40.0), 3000, 8.0, &parked, 2500));
+    }
+}
@@ -5,24 +5,180 @@
 //! Decision Type: 🔗 Direct Derivation - Implementation of hardware-independent control logic
 //! AI Traceability: Enables desktop testing, safety-critical algorithm validation
 
-#![no_std]
+// Host builds link std - the schema derives expand to std paths, and state.rs imports from std
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 use alloc::string::{String, ToString};
+use alloc::format;
+use serde::{Deserialize, Serialize};
 
 pub mod config;
 pub mod state;
+pub mod solenoid_failover;
+pub mod can_health;
+pub mod live_tune;
+pub mod screen;
+pub mod screen_render;
+pub mod demo;
+pub mod sensor_mapping;
+pub mod spring_check;
+pub mod front_panel;
+pub mod burst_log;
+pub mod flight_recorder;
+pub mod cycle_phase;
+pub mod watch;
+pub mod restart_staging;
+pub mod twin_turbo;
+pub mod actuation_monitor;
+pub mod command_token;
+pub mod soft_ceiling;
+pub mod presence;
+#[cfg(feature = "schema")]
+pub mod config_schema;
+pub mod thermal_derate;
+pub mod backpressure;
+pub mod turbo_speed;
+pub mod shakedown;
+pub mod strings;
+pub mod input_source;
+pub mod aggression_input;
+pub mod can_signal;
+pub mod can_inputs;
+pub mod rpm_validation;
+pub mod rev_limit;
+pub mod overshoot;
+pub mod maintenance;
+pub mod named_config;
+pub mod map_zero;
+pub mod fault_history;
+pub mod overboost_cause;
+pub mod golden_trace;
+pub mod duty_ceiling;
+pub mod session_summary;
+pub mod support_bundle;
+pub mod boost_target;
+pub mod idle_vent;
+pub mod solenoid_exercise;
+pub mod commissioning;
+pub mod headroom;
+pub mod dither;
+pub mod voltage_compensation;
+pub mod pulse_shaping;
+pub mod device_identity;
+pub mod profile_theme;
+pub mod learning_gate;
+pub mod control_rates;
+pub mod performance_tier;
+pub mod actuator;
+pub mod events;
+pub mod performance;
+pub mod distribution;
+pub mod learning;
+pub mod torque_following;
+pub mod profile_schedule;
+pub mod spring_only;
+pub mod journal;
+pub mod trends;
+pub mod safety_log;
+pub mod safety_limits;
+pub mod error_codes;
+pub mod telemetry;
+pub mod telemetry_sinks;
+pub mod console_access;
+pub mod calibration;
+pub mod density_compensation;
+pub mod gear_estimate;
+pub mod datalog;
+pub mod dome_control;
+#[cfg(test)]
+mod test_support;
 // TODO: Implement remaining core modules
 // pub mod control;
-// pub mod learning;
 // pub mod safety;
-// pub mod torque_following;
 
 pub use config::*;
 pub use state::*;
+pub use solenoid_failover::*;
+pub use can_health::*;
+pub use live_tune::*;
+pub use screen::*;
+pub use screen_render::*;
+pub use demo::*;
+pub use sensor_mapping::*;
+pub use spring_check::*;
+pub use front_panel::*;
+pub use burst_log::*;
+pub use flight_recorder::*;
+pub use cycle_phase::*;
+pub use watch::*;
+pub use restart_staging::*;
+pub use twin_turbo::*;
+pub use actuation_monitor::*;
+pub use command_token::*;
+pub use soft_ceiling::*;
+pub use presence::*;
+#[cfg(feature = "schema")]
+pub use config_schema::*;
+pub use thermal_derate::*;
+pub use backpressure::*;
+pub use turbo_speed::*;
+pub use shakedown::*;
+pub use strings::*;
+pub use input_source::*;
+pub use aggression_input::*;
+pub use can_signal::*;
+pub use can_inputs::*;
+pub use rpm_validation::*;
+pub use rev_limit::*;
+pub use overshoot::*;
+pub use maintenance::*;
+pub use named_config::*;
+pub use map_zero::*;
+pub use fault_history::*;
+pub use overboost_cause::*;
+pub use golden_trace::*;
+pub use duty_ceiling::*;
+pub use torque_following::*;
+pub use session_summary::*;
+pub use support_bundle::*;
+pub use boost_target::*;
+pub use idle_vent::*;
+pub use solenoid_exercise::*;
+pub use commissioning::*;
+pub use headroom::*;
+pub use dither::*;
+pub use voltage_compensation::*;
+pub use pulse_shaping::*;
+pub use device_identity::*;
+pub use profile_theme::*;
+pub use learning_gate::*;
+pub use control_rates::*;
+pub use performance_tier::*;
+pub use actuator::*;
+pub use events::*;
+pub use performance::*;
+pub use distribution::*;
+pub use learning::*;
+pub use profile_schedule::*;
+pub use spring_only::*;
+pub use journal::*;
+pub use trends::*;
+pub use safety_log::*;
+pub use safety_limits::*;
+pub use error_codes::*;
+pub use telemetry::*;
+pub use telemetry_sinks::*;
+pub use console_access::*;
+pub use calibration::*;
+pub use density_compensation::*;
+pub use gear_estimate::*;
+pub use datalog::*;
+pub use dome_control::*;
 
-use rumbledome_hal::{HalTrait, HalResult, HalError};
+use rumbledome_hal::{HalTrait, HalError, SolenoidChannel, ButtonEvent};
 
 /// Core system error types
 /// 
@@ -46,6 +202,8 @@ pub enum CoreError {
     CalibrationError(String),
     /// Sensor validation failed
     SensorError(String),
+    /// Stored record unreadable or too large
+    StorageError(String),
 }
 
 impl From<HalError> for CoreError {
@@ -64,26 +222,139 @@ pub struct RumbleDomeCore<H: HalTrait> {
     pub state: SystemState,
     /// User configuration (5 parameters)
     pub config: SystemConfig,
+    /// Stored named configurations (`config` is the running copy of the active entry)
+    pub configurations: ConfigLibrary,
     /// Hardware abstraction layer
     pub hal: H,
     /// Control loop statistics  
     pub stats: ControlLoopStats,
-    // TODO: Add these back when modules are implemented
-    // /// Learned calibration data
-    // pub learned_data: LearnedData,
-    // /// Torque-following control logic
-    // pub torque_following: TorqueFollowing,
-    // /// Safety monitoring system
-    // pub safety_monitor: SafetyMonitor,
-    // /// Auto-calibration system
-    // pub calibration: AutoCalibration,
+    /// Output stage driver for the configured wastegate actuator
+    pub actuator: Box<dyn ActuatorDriver<H>>,
+    /// Solenoid channel failover manager
+    pub solenoid_failover: SolenoidFailover,
+    /// CAN bus health model (per-message timing statistics)
+    pub can_health: CanBusHealth,
+    /// Engine speed and torque decoded from the bus
+    pub can_inputs: CanInputs,
+    /// Live-tune session (volatile test values over stored tuning)
+    pub live_tune: LiveTuneSession,
+    /// Physical presence challenge for protected commands
+    pub presence: PresenceGate,
+    /// Manifold pressure source arbitration (CAN vs analog MAP)
+    pub map_arbiter: SourceArbiter,
+    /// Aggression source arbitration (setting, knob, drive mode, app)
+    pub aggression_input: AggressionArbiter,
+    /// CAN RPM plausibility validation
+    pub rpm_validator: RpmValidator,
+    /// Key-on analog MAP zero capture and drift tracking
+    pub map_zero: MapAutoZero,
+    /// Double-buffered sensor calibration record holding the MAP zero history
+    calibration_journal: RecordJournal,
+    /// Double-buffered record of the running configuration's changes from the defaults
+    config_journal: RecordJournal,
+    /// Spool overshoot detection for learned-cell correction
+    pub overshoot_detector: OvershootDetector,
+    /// Off-boost idle dome venting
+    pub idle_vent: IdleVentManager,
+    /// Periodic solenoid self-cleaning while vented at idle
+    pub solenoid_exercise: SolenoidExercise,
+    /// Transient rejection for learning samples
+    pub learning_gate: LearningGate,
+    /// Boost ceiling derate on sustained high intake air or coolant temperature
+    pub thermal_derate: ThermalDerate,
+    /// Boost limit on a high exhaust-to-boost pressure ratio
+    pub turbine_protection: TurbineProtection,
+    /// Turbo shaft speed estimate and boost limit at the speed limit
+    pub turbo_speed: TurboSpeedProtection,
+    /// Learned duty and boost target correction for air density
+    pub density_compensation: DensityCompensation,
+    /// Gear estimate and per-gear boost target scaling
+    pub gear_estimator: GearEstimator,
+    /// Cascaded boost-to-dome-pressure loop below the learned duty
+    pub dome_control: DomeController,
+    /// Conservative limits after safety-relevant configuration changes
+    pub shakedown: Shakedown,
+    /// Twin turbo bank imbalance detection
+    pub bank_balance: BankBalance,
+    /// Duty-to-dome-pressure correlation (solenoid wear and dome leak advisories)
+    pub actuation_monitor: ActuationMonitor,
+    
+    /// Seals each cycle's output command
+    command_sealer: CommandSealer,
+    
+    /// Checks each output command before it is applied
+    output_guard: OutputGuard,
+    /// Outer loop (Level 1 and learning) scheduling
+    pub rate_scheduler: RateScheduler,
+    /// Background work stepped down under cycle time or MCU temperature stress
+    pub tier_governor: TierGovernor,
+    /// Power-on hour meter and solenoid actuation counter
+    pub usage: UsageMeter,
+    /// Fault occurrences with freeze frames
+    pub fault_history: FaultHistory,
+    /// Overboost occurrences with likely cause
+    pub overboost: OverboostClassifier,
+    /// Learned per-RPM duty above which the wastegate is already shut
+    pub duty_ceiling: DutyCeilingLearner,
+    /// Current and recent session summaries
+    pub sessions: SessionTracker,
+    /// Weekly and monthly trend records
+    pub trends: TrendLog,
+    /// Double-buffered trend records on the SD card
+    trends_journal: FileJournal,
+    /// Non-volatile storage health
+    pub storage_health: StorageHealth,
+    /// Bench display demo (display only, engine stopped)
+    pub demo: DemoMode,
+    /// Engine-off pressure sensor wiring assistant, while running
+    pub sensor_mapping: Option<SensorMapping>,
+    /// Wastegate spring verification, running or last finished
+    pub spring_check: Option<SpringCheck>,
+    /// Scramble and profile buttons, status LED
+    pub front_panel: FrontPanel,
+    /// Full-rate capture of every control value for a short window
+    pub burst_log: BurstLog,
+    /// Always-on decimated boost and duty recording in EEPROM
+    pub flight_recorder: FlightRecorder,
+    /// Overboost cuts and faults logged to EEPROM
+    pub safety_log: SafetyLog,
+    /// Triplicated overboost limit and duty cap in EEPROM
+    pub safety_limits: SafetyLimitStore,
+    /// First-run commissioning progress (arming blocked until complete)
+    pub commissioning: Commissioning,
+    /// Inputs from the most recent control cycle
+    pub last_inputs: Option<SystemInputs>,
+    /// Boost target from the most recent control cycle (PSI)
+    pub last_target_boost_psi: f32,
+    /// Level 1 boost target from the most recent outer loop cycle (PSI)
+    pub level1_target_boost_psi: f32,
+    /// Margin to each limit after the most recent control cycle
+    pub headroom: Option<Headroom>,
+    /// Subsystem events for display, telemetry and logging sinks
+    pub events: EventBus,
+    /// Per-cycle telemetry fanned out to the protocol stream, datalogger and CAN broadcast
+    pub telemetry_sinks: TelemetryRegistry,
+    /// Telemetry rows logged to the SD card on command and at wide-open throttle
+    pub datalogger: Datalogger,
+    /// Learned calibration data
+    pub learned_data: LearnedData,
+    /// Learned-data import waiting for its remaining chunks
+    learned_upload: LearnedDataUpload,
+    /// Torque-following control logic
+    pub torque_following: TorqueFollowing,
+    /// Context hints from a connected app and the profile schedule state
+    pub profile_schedule: ProfileScheduler,
+    /// Torque following is running boost-by-target for lack of fresh ECU torque
+    pub torque_degraded: bool,
+    /// Auto-calibration session (runs while in `SystemState::Calibrating`)
+    pub calibration: AutoCalibration,
 }
 
 /// System inputs from sensors and CAN
 /// 
 /// 🔗 T4-CORE-004: System Input Structure
 /// Derived From: T2-HAL-005 (Ford S550 CAN Signal Integration) + sensor specifications
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SystemInputs {
     /// Engine RPM from CAN
     pub rpm: u16,
@@ -93,12 +364,26 @@ pub struct SystemInputs {
     pub actual_torque: f32,
     /// Manifold pressure (PSI gauge)
     pub manifold_pressure: f32,
+    /// Throttle position (0-100 %), when a throttle signal is mapped
+    pub throttle_position: Option<f32>,
     /// Dome input pressure (PSI gauge)
     pub dome_input_pressure: f32,
     /// Upper dome pressure (PSI gauge) 
     pub upper_dome_pressure: f32,
     /// Lower dome pressure (PSI gauge)
     pub lower_dome_pressure: f32,
+    /// Intake air temperature (°C), when the platform provides it
+    #[serde(default)]
+    pub intake_air_temp_c: Option<f32>,
+    /// Engine coolant temperature (°C), when the platform provides it
+    #[serde(default)]
+    pub coolant_temp_c: Option<f32>,
+    /// Dome pressure of each turbo bank (PSI gauge, bank 1 first) - twin layouts
+    #[serde(default)]
+    pub bank_dome_pressure: Option<[f32; 2]>,
+    /// Exhaust manifold pressure (PSI gauge) - optional backpressure sensor
+    #[serde(default)]
+    pub exhaust_backpressure_psi: Option<f32>,
     /// Current aggression setting (0.0-1.0)
     pub aggression: f32,
     /// Scramble button state
@@ -107,11 +392,39 @@ pub struct SystemInputs {
     pub timestamp_ms: u32,
 }
 
+/// ECU torque request above actual torque read as a wide-open pull without a throttle signal (Nm)
+pub const WIDE_OPEN_TORQUE_GAP_NM: f32 = 100.0;
+
+impl SystemInputs {
+    /// Whether the driver is at wide-open throttle
+    /// 
+    /// Throttle at `throttle_percent` or more where a throttle signal is
+    /// mapped. Without one, the ECU asking for `WIDE_OPEN_TORQUE_GAP_NM` more
+    /// than the engine makes is taken as a wide-open pull.
+    pub fn wide_open(&self, throttle_percent: f32) -> bool {
+        match self.throttle_position {
+            Some(throttle) => throttle >= throttle_percent,
+            None => self.desired_torque - self.actual_torque >= WIDE_OPEN_TORQUE_GAP_NM,
+        }
+    }
+    
+    /// Whether the driver has lifted
+    /// 
+    /// Throttle below `throttle_percent` where a throttle signal is mapped;
+    /// otherwise the ECU asking for no more torque than the engine makes.
+    pub fn lifted(&self, throttle_percent: f32) -> bool {
+        match self.throttle_position {
+            Some(throttle) => throttle < throttle_percent,
+            None => self.desired_torque <= self.actual_torque,
+        }
+    }
+}
+
 /// Control loop performance statistics
 /// 
 /// 🔗 T4-CORE-005: Performance Monitoring
 /// Derived From: Performance requirements + diagnostic needs
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ControlLoopStats {
     /// Total control cycles executed
     pub cycles_executed: u64,
@@ -125,6 +438,8 @@ pub struct ControlLoopStats {
     pub safety_interventions: u32,
     /// Learning updates applied
     pub learning_updates: u32,
+    /// Outer loop (Level 1 and learning) cycles executed
+    pub outer_cycles_executed: u64,
     /// Last update timestamp
     pub last_update_ms: u32,
 }
@@ -135,14 +450,112 @@ impl<H: HalTrait> RumbleDomeCore<H> {
     /// 🔗 T4-CORE-006: System Initialization
     /// Derived From: T3-BUILD-003 (Core Control State Machine)
     pub fn new(hal: H, config: SystemConfig) -> Self {
+        let actuator = actuator_driver(&config);
+        let solenoid_failover = SolenoidFailover::new(config.solenoid_topology);
+        let live_tune = LiveTuneSession::new(config.tuning.clone());
+        let map_arbiter = SourceArbiter::new(config.input_sources.manifold_pressure.clone());
+        let aggression_input = AggressionArbiter::new(config.aggression_inputs.clone(), config.aggression);
+        let map_zero = MapAutoZero::new(config.map_zero.clone());
+        let idle_vent = IdleVentManager::new(config.idle_vent.clone());
+        let solenoid_exercise = SolenoidExercise::new(config.solenoid_exercise.clone());
+        let learning_gate = LearningGate::new(config.learning_gate.clone());
+        let thermal_derate = ThermalDerate::new(config.thermal_derate.clone());
+        let turbine_protection = TurbineProtection::new(config.backpressure.clone());
+        let turbo_speed = TurboSpeedProtection::new(config.turbo_speed.clone());
+        let density_compensation = DensityCompensation::new(config.density_compensation.clone());
+        let gear_estimator = GearEstimator::new(config.gear_boost.clone());
+        let dome_control = DomeController::new(config.dome_control.clone());
+        let shakedown = Shakedown::new(config.shakedown.clone());
+        let bank_balance = BankBalance::new(config.twin_turbo.clone());
+        let actuation_monitor = ActuationMonitor::new(config.actuation_monitor.clone());
+        let can_inputs = CanInputs::new(&config.can_signals);
+        let rate_scheduler = RateScheduler::new(config.control_rates.clone());
+        let tier_governor = TierGovernor::new(config.performance_tiers.clone());
+        let flight_recorder = FlightRecorder::new(config.flight_recorder.clone());
+        let learned_data = LearnedData::new(config.spring_pressure);
+        let torque_following = TorqueFollowing::new(&config);
+        let mut telemetry_sinks = TelemetryRegistry::new();
+        telemetry_sinks.configure_can_broadcast(&config.telemetry_sinks.can_broadcast);
+        let datalogger = Datalogger::new(config.telemetry_sinks.datalog.clone());
+        
         Self {
             state: SystemState::Initializing,
+            configurations: ConfigLibrary::single(DEFAULT_CONFIG_NAME, config.clone()),
             config,
             hal,
             stats: ControlLoopStats::default(),
+            actuator,
+            solenoid_failover,
+            can_health: CanBusHealth::new(),
+            can_inputs,
+            live_tune,
+            presence: PresenceGate::new(),
+            map_arbiter,
+            aggression_input,
+            rpm_validator: RpmValidator::new(),
+            map_zero,
+            calibration_journal: RecordJournal::CALIBRATION,
+            config_journal: RecordJournal::CONFIG,
+            overshoot_detector: OvershootDetector::new(),
+            idle_vent,
+            solenoid_exercise,
+            learning_gate,
+            thermal_derate,
+            turbine_protection,
+            turbo_speed,
+            density_compensation,
+            gear_estimator,
+            dome_control,
+            shakedown,
+            bank_balance,
+            actuation_monitor,
+            command_sealer: CommandSealer::new(),
+            output_guard: OutputGuard::new(),
+            rate_scheduler,
+            tier_governor,
+            usage: UsageMeter::new(),
+            fault_history: FaultHistory::new(),
+            overboost: OverboostClassifier::new(),
+            duty_ceiling: DutyCeilingLearner::new(),
+            sessions: SessionTracker::new(),
+            trends: TrendLog::new(),
+            trends_journal: FileJournal::TRENDS,
+            storage_health: StorageHealth::default(),
+            demo: DemoMode::new(),
+            sensor_mapping: None,
+            spring_check: None,
+            calibration: AutoCalibration::new(),
+            front_panel: FrontPanel::new(),
+            burst_log: BurstLog::new(),
+            flight_recorder,
+            safety_log: SafetyLog::new(),
+            safety_limits: SafetyLimitStore::new(),
+            commissioning: Commissioning::new(),
+            last_inputs: None,
+            last_target_boost_psi: 0.0,
+            level1_target_boost_psi: 0.0,
+            headroom: None,
+            events: EventBus::new(),
+            telemetry_sinks,
+            datalogger,
+            learned_data,
+            learned_upload: LearnedDataUpload::default(),
+            torque_following,
+            profile_schedule: ProfileScheduler::new(),
+            torque_degraded: false,
         }
     }
     
+    /// Create core running the active entry of a stored configuration library
+    pub fn with_configurations(hal: H, mut configurations: ConfigLibrary) -> Self {
+        // Inherited entries follow their parents as stored; a chain that no longer
+        // resolves keeps the configurations it was saved with
+        let _ = configurations.resolve();
+        let mut core = Self::new(hal, configurations.active().config.clone());
+        core.configurations = configurations;
+        core
+    }
+    
     /// Initialize system and perform self-test
     /// 
     /// 🔗 T4-CORE-007: System Initialization Process
@@ -153,18 +566,54 @@ impl<H: HalTrait> RumbleDomeCore<H> {
         // Initialize hardware
         self.hal.init()?;
         
+        // The configuration last stored comes back with the unit; without an intact record the one given runs
+        self.restore_config();
+        
+        // Restart-staged parameters are only taken up here
+        self.hal.set_frequency(self.config.pwm_frequency_hz)?;
+        self.hal.set_bitrate(self.config.can_bitrate_bps)?;
+        self.hal.set_filters(&self.can_inputs.filters())?;
+        if self.config.backpressure.enabled {
+            self.hal.set_calibration(self.config.backpressure.channel, self.config.backpressure.sensor_calibration())?;
+        }
+        if let Some(channel) = self.config.density_compensation.baro_channel {
+            self.hal.set_calibration(channel, self.config.density_compensation.sensor_calibration())?;
+        }
+        
+        // Clear latched solenoid channel failures from any previous session
+        self.solenoid_failover.reset();
+        
         // Perform self-test
         let self_test = self.hal.self_test()?;
+        self.storage_health.self_test_passed = Some(self_test.storage_test == rumbledome_hal::TestStatus::Pass);
         if self_test.overall_status != rumbledome_hal::TestStatus::Pass {
             self.state = SystemState::Fault(FaultCode::SelfTestFailed);
             return Err(CoreError::SafetyViolation("Hardware self-test failed".to_string()));
         }
         
-        // TODO: Load learned data when learning module is implemented
-        // self.learned_data = LearnedData::load_from_storage(&mut self.hal)?;
+        // An unreadable ring only costs the previous recording - the new one starts over
+        let _ = self.flight_recorder.restore(&mut self.hal);
+        let _ = self.safety_log.restore(&mut self.hal);
         
-        // TODO: Initialize safety monitor when safety module is implemented  
-        // self.safety_monitor.initialize(&self.config)?;
+        // Corrupt learned data starts over from the conservative estimates
+        let namespace = self.configurations.active().learned_data_namespace();
+        self.learned_data = LearnedData::load_from_storage(&mut self.hal, &namespace, self.config.spring_pressure)
+            .unwrap_or_else(|_| LearnedData::new(self.config.spring_pressure).with_namespace(&namespace));
+        
+        // Without an intact zero record the next key-on capture sets a new baseline
+        if let Ok(Some(record)) = self.calibration_journal.load_value(&mut self.hal) {
+            self.map_zero.restore(record);
+        }
+        // Without a card or an intact copy on it the trends start over
+        if let Ok(Some(report)) = self.trends_journal.load_value(&mut self.hal) {
+            self.trends.restore(report);
+        }
+        
+        // Limits no two copies agree on are unknown - stay in limp mode until they are written again
+        if !self.safety_limits.load(&mut self.hal, SafetyLimits::of(&self.config)) {
+            self.state = SystemState::Fault(FaultCode::SafetyLimitsCorrupted);
+            return Ok(());
+        }
         
         // Transition to idle state
         self.state = SystemState::Idle;
@@ -172,124 +621,867 @@ impl<H: HalTrait> RumbleDomeCore<H> {
         Ok(())
     }
     
+    /// Arm boost control
+    /// 
+    /// Only permitted from states where `can_transition_to_armed()` holds, and
+    /// only once commissioning is complete or has been explicitly skipped.
+    pub fn arm(&mut self) -> Result<(), CoreError> {
+        if !self.state.can_transition_to_armed() {
+            return Err(CoreError::InvalidState(
+                format!("Cannot arm from state {}", self.state.display_text())
+            ));
+        }
+        
+        if let Some(step) = self.commissioning.current_step() {
+            return Err(CoreError::InvalidState(
+                format!("Cannot arm before commissioning is complete (next step: {})", step.display_text())
+            ));
+        }
+        
+        // The gauge has to show real boost from the first armed cycle
+        self.demo.stop();
+        self.state = SystemState::Armed;
+        Ok(())
+    }
+    
     /// Execute one control cycle
     /// 
     /// 🔗 T4-CORE-008: Main Control Loop Implementation
     /// Derived From: T3-BUILD-005 (3-Level Control Hierarchy Implementation)
-    /// Must be called at 100 Hz for proper system operation
+    /// Must be called at the configured inner loop rate (`control_rates.inner_hz`,
+    /// 100-200 Hz) for proper system operation
+    /// 
+    /// Composes the three cycle phases: `read_inputs()`, `evaluate()` and
+    /// `apply_outputs()`.
     pub fn execute_control_cycle(&mut self) -> Result<(), CoreError> {
-        let cycle_start = self.hal.now_us();
+        // Read system inputs
+        let inputs = self.read_inputs()?;
+        
+        self.execute_control_cycle_with_inputs(inputs)
+    }
+    
+    /// Execute one control cycle with externally supplied inputs
+    /// 
+    /// Used by the simulator, where inputs come from a plant model instead of
+    /// the HAL. Identical to `execute_control_cycle()` after input acquisition.
+    pub fn execute_control_cycle_with_inputs(&mut self, inputs: SystemInputs) -> Result<(), CoreError> {
+        let plan = self.evaluate(inputs)?;
+        self.apply_outputs(plan)
+    }
+    
+    /// Decide this cycle's outputs without commanding them (second cycle phase)
+    /// 
+    /// Runs safety checks, state handling, the control hierarchy and learning.
+    /// The returned plan must be passed to `apply_outputs()` before the next
+    /// cycle is evaluated.
+    pub fn evaluate(&mut self, inputs: SystemInputs) -> Result<CyclePlan, CoreError> {
+        let mut plan = CyclePlan::new(inputs.clone(), self.hal.now_us());
         self.stats.cycles_executed += 1;
+        self.last_inputs = Some(inputs.clone());
         
-        // Read system inputs
-        let inputs = self.read_system_inputs()?;
+        // Check solenoid drive channels before commanding any output
+        self.update_solenoid_failover(inputs.timestamp_ms)?;
+        
+        // Lost CAN torque data is a fault condition while controlling boost (SY-10)
+        self.check_can_health(inputs.timestamp_ms);
+        
+        // Boost at the overboost limit cuts the output whatever the controller asked for (T1-SAFETY-001)
+        self.check_overboost(&inputs);
+        
+        // Heat derate tracks temperature in every state so its hold timers stay meaningful
+        self.thermal_derate.update(&inputs);
+        self.turbine_protection.update(&inputs, self.config.spring_pressure);
+        // Barometric pressure drifts slowly, so the last reading stands until the next
+        let barometric_kpa = self.can_inputs.barometric_pressure().map(|(kpa, _)| kpa);
+        self.turbo_speed.update(&inputs, barometric_kpa, self.config.max_boost_psi, self.config.spring_pressure);
+        let analog_baro_psi = self.config.density_compensation.baro_channel
+            .and_then(|channel| self.hal.read_pressure_psi(channel).ok());
+        self.density_compensation.update(&inputs, barometric_kpa, analog_baro_psi);
+        let vehicle_speed_kph = self.vehicle_speed_kph(inputs.timestamp_ms);
+        if self.gear_estimator.update(inputs.rpm, vehicle_speed_kph, inputs.timestamp_ms) {
+            let status = self.gear_estimator.status();
+            if let Some(gear) = status.gear {
+                let scale = status.boost_scale;
+                self.events.publish(inputs.timestamp_ms, CoreEvent::GearBoostReduced { gear, scale });
+            }
+        }
+        self.shakedown.update(&inputs, self.state == SystemState::Armed, self.config.spring_pressure);
         
-        // Validate inputs and check safety conditions
-        self.safety_monitor.validate_inputs(&inputs)?;
+        // Abandoned live-tune test values revert to stored tuning
+        self.live_tune.tick(inputs.timestamp_ms);
+        
+        // An unanswered presence code leaves the display
+        self.presence.expire(inputs.timestamp_ms);
+        
+        // Holding scramble on the bench toggles the display demo; the engine starting ends it
+        let bench = self.on_bench(inputs.rpm);
+        self.demo.observe(inputs.scramble_active, bench, inputs.timestamp_ms);
+        
+        // Profile button presses switch configuration like any other switch (refused while controlling)
+        if let Some(press) = self.front_panel.take_profile_press() {
+            self.select_profile_by_button(press);
+        }
+        
+        // Sensor mapping only makes sense with the engine off; starting it abandons the run
+        if self.sensor_mapping.is_some() {
+            if self.engine_off_setup(inputs.rpm) {
+                let channels = self.config.sensor_channels.channels(&inputs);
+                if let Some(mapping) = self.sensor_mapping.as_mut() {
+                    mapping.observe(&channels, inputs.timestamp_ms);
+                }
+            } else {
+                self.sensor_mapping = None;
+            }
+        }
+        
+        // A spring check only measures on the spring alone; a cut or fault ends it
+        if let Some(check) = self.spring_check.as_mut().filter(|check| check.is_running()) {
+            match self.state {
+                SystemState::Armed | SystemState::Idle => {
+                    if let Some(report) = check.observe(&inputs) {
+                        if !report.within_tolerance {
+                            self.state = SystemState::Fault(FaultCode::InvalidConfiguration(report.correction()));
+                        }
+                    }
+                },
+                _ => check.abort(&format!("Ended by {}", self.state.display_text())),
+            }
+        }
+        
+        // Overshoot events only make sense while boost control is active
+        if !self.boost_control_active() {
+            self.overshoot_detector.cancel();
+            self.learning_gate.cancel();
+            self.rate_scheduler.reset();
+            self.solenoid_exercise.cancel();
+            self.duty_ceiling.cancel();
+            self.bank_balance.cancel();
+            
+            // Next armed cycle drives the output, so PWM must be back on
+            if self.idle_vent.cancel() {
+                plan.pwm_enable = Some(true);
+            }
+        }
         
         // Execute control based on current state
         match self.state {
             SystemState::Idle => {
                 // System idle - minimal boost operation
-                self.hal.set_duty_cycle(0.0)?;
+                plan.failsafe(false);
+            },
+            
+            SystemState::Armed if self.configurations.is_spring_only() || self.spring_check_running() => {
+                // Built-in spring-only profile or spring check - wastegate on its spring, nothing to control
+                self.last_target_boost_psi = 0.0;
+                plan.failsafe(false);
             },
             
             SystemState::Armed => {
-                // Normal operation - execute 3-level control hierarchy
-                let duty_cycle = self.execute_control_hierarchy(&inputs)?;
-                self.update_output(duty_cycle, &inputs)?;
-                
-                // Update learning system
-                self.learned_data.update_from_operation(&inputs, duty_cycle)?;
+                if self.update_idle_vent(&inputs, &mut plan) {
+                    // Idling off-boost - domes vented, no modulation to chatter the solenoid
+                    self.last_target_boost_psi = 0.0;
+                    self.overshoot_detector.cancel();
+                    self.learning_gate.cancel();
+                    self.rate_scheduler.reset();
+                    self.duty_ceiling.cancel();
+                    self.update_solenoid_exercise(&inputs, &mut plan);
+                } else {
+                    // Tip-in ends any exercise - the control hierarchy takes the output this cycle
+                    self.solenoid_exercise.cancel();
+                    
+                    // Normal operation - execute 3-level control hierarchy
+                    // Level 1 and learning only run on outer loop cycles, on decimated inputs
+                    let outer_inputs = self.rate_scheduler.tick(&inputs);
+                    let authority = self.execute_control_hierarchy(&inputs, outer_inputs.as_ref())?;
+                    plan.drive = if self.config.twin_turbo.layout.is_twin() {
+                        DriveCommand::BankAuthority(self.bank_authorities(&inputs)?)
+                    } else {
+                        DriveCommand::Authority(self.output_authority(authority, &inputs)?)
+                    };
+                    plan.authority_request = Some(authority);
+                    plan.outer_cycle = outer_inputs.is_some();
+                    
+                    // Learn where more duty stops making more boost, once the command is applied
+                    plan.observe_duty_ceiling = true;
+                    
+                    // Update learning system - steady-state samples only
+                    if let Some(outer_inputs) = &outer_inputs {
+                        self.stats.outer_cycles_executed += 1;
+                        // Revving in neutral makes boost with no load behind it - not learned
+                        let now_ms = outer_inputs.timestamp_ms;
+                        let load = LoadSample {
+                            vehicle_speed_kph: self.vehicle_speed_kph(now_ms),
+                            torque_nm: outer_inputs.actual_torque,
+                        };
+                        let steady = self.learning_gate.update(
+                            outer_inputs.throttle_position, outer_inputs.rpm,
+                            self.level1_target_boost_psi, &load, now_ms
+                        );
+                        if steady && self.tier_governor.admit_learning() {
+                            let reference_duty = self.density_compensation.normalize_duty(authority);
+                            self.learned_data.update_from_operation(outer_inputs, self.last_target_boost_psi, reference_duty)?;
+                            self.stats.learning_updates += 1;
+                            self.sessions.count_learning_update();
+                            self.events.publish(outer_inputs.timestamp_ms, CoreEvent::LearningSample {
+                                rpm: outer_inputs.rpm,
+                                target_psi: self.level1_target_boost_psi,
+                            });
+                        }
+                    }
+                    
+                    // Spool overshoot corrects the responsible cells directly instead of
+                    // waiting for many PID-corrected samples
+                    self.overshoot_detector.update(
+                        inputs.rpm, self.last_target_boost_psi, inputs.manifold_pressure, inputs.timestamp_ms
+                    );
+                    for correction in self.overshoot_detector.drain_corrections() {
+                        self.learned_data.apply_cell_correction(&correction)?;
+                    }
+                }
             },
             
             SystemState::Calibrating(_) => {
-                // Auto-calibration in progress
-                let duty_cycle = self.calibration.execute_step(&inputs, &mut self.learned_data)?;
-                self.update_output(duty_cycle, &inputs)?;
+                // Auto-calibration in progress - the sweep duty is applied unscaled,
+                // since it is the duty the learned map records
+                let authority = self.calibration.execute_step(&inputs, &mut self.learned_data)?;
+                plan.drive = DriveCommand::Authority(authority.min(self.limits_in_force().max_duty_percent));
+                plan.authority_request = Some(authority);
+                self.state = if self.calibration.is_complete() {
+                    SystemState::Idle
+                } else {
+                    SystemState::Calibrating(self.calibration.progress())
+                };
             },
             
             SystemState::OverboostCut => {
                 // Overboost protection active - force 0% duty
-                self.hal.set_duty_cycle_immediate(0.0)?;
+                plan.failsafe(true);
                 
                 // Check if we can return to normal operation
-                if inputs.manifold_pressure < (self.config.overboost_limit - 0.5) {
+                if inputs.manifold_pressure < (self.limits_in_force().overboost_limit_psi - 0.5) {
                     self.state = SystemState::Armed;
                 }
             },
             
             SystemState::Fault(_) => {
                 // System fault - maintain failsafe state
-                self.hal.set_duty_cycle_immediate(0.0)?;
+                plan.failsafe(true);
             },
             
             SystemState::Initializing => {
                 // Still initializing - maintain safe state
-                self.hal.set_duty_cycle(0.0)?;
+                plan.failsafe(false);
+            },
+            
+            SystemState::Commissioning(_) => {
+                // Observe only - wastegate open while step evidence is collected
+                plan.failsafe(false);
+                self.commissioning.observe(&inputs);
+            },
+        }
+        
+        // The output stage only applies what was sealed here
+        plan.token = self.command_sealer.seal(&plan.drive, plan.pwm_enable);
+        Ok(plan)
+    }
+    
+    /// Command the planned outputs and record the cycle (third cycle phase)
+    /// 
+    /// Usage, headroom, fault history, sessions, events and statistics all
+    /// reflect the output actually commanded.
+    pub fn apply_outputs(&mut self, plan: CyclePlan) -> Result<(), CoreError> {
+        let CyclePlan { inputs, mut drive, mut pwm_enable, authority_request, outer_cycle, mut observe_duty_ceiling, started_us, token } = plan;
+        
+        // A command altered after evaluation, or a skipped plan, never reaches the solenoid
+        if let Err(rejection) = self.output_guard.check(&token, &drive, pwm_enable) {
+            self.state = SystemState::Fault(FaultCode::ControlFault(String::from(rejection.description())));
+            drive = DriveCommand::Failsafe { immediate: true };
+            pwm_enable = None;
+            observe_duty_ceiling = false;
+        }
+        
+        // PWM comes on before the output is driven and goes off after it is parked
+        if pwm_enable == Some(true) {
+            self.hal.enable()?;
+        }
+        match drive {
+            DriveCommand::Hold => {},
+            DriveCommand::Failsafe { immediate } => self.set_failsafe_output(immediate)?,
+            DriveCommand::Authority(authority) => {
+                self.actuator.command_authority(&mut self.hal, authority, &self.solenoid_failover)?;
+            },
+            DriveCommand::BankAuthority(authorities) => {
+                self.actuator.command_bank_authorities(&mut self.hal, authorities, &self.solenoid_failover)?;
             },
         }
+        if pwm_enable == Some(false) {
+            self.hal.disable()?;
+        }
+        
+        let duty = self.actuator.current_authority(&self.hal);
+        if observe_duty_ceiling {
+            self.duty_ceiling.observe(&inputs, duty);
+        }
+        
+        // Twin banks are compared only while both are driven by the control hierarchy
+        match drive {
+            DriveCommand::BankAuthority(authorities) => {
+                if let Some(bank) = self.bank_balance.observe(&inputs, authorities) {
+                    self.events.publish(inputs.timestamp_ms, CoreEvent::BankImbalance(bank));
+                }
+            },
+            _ => self.bank_balance.cancel(),
+        }
+        
+        // The dome response model is for one 4-port valve driven by the control hierarchy
+        let single_valve = self.actuator.actuator_type() == ActuatorType::PwmSolenoid
+            && self.solenoid_failover.active_topology() == ActiveOutputTopology::SingleFourPort;
+        if single_valve && matches!(drive, DriveCommand::Authority(_)) {
+            if let Some(advisory) = self.actuation_monitor.observe(&inputs, duty) {
+                self.events.publish(inputs.timestamp_ms, CoreEvent::ActuationDecline(advisory));
+            }
+        }
+        
+        // Accumulate operating hours and solenoid wear
+        self.usage.update(inputs.timestamp_ms, duty);
+        let thermal_cap_psi = self.thermal_derate.boost_cap_psi(&self.config);
+        self.headroom = Some(Headroom::compute(&self.config, inputs.manifold_pressure, inputs.rpm, duty, thermal_cap_psi));
+        
+        // Fault history and session summary for support bundles
+        let target_boost_psi = self.last_target_boost_psi;
+        self.fault_history.observe(&self.state, || FreezeFrame::capture(&inputs, target_boost_psi, duty));
+        self.sessions.update(inputs.timestamp_ms, &self.state, inputs.manifold_pressure);
+        
+        // Boost below spring pressure cannot be controlled, so it does not count against tracking
+        let tracking = (self.state == SystemState::Armed && target_boost_psi > self.config.spring_pressure)
+            .then_some(TrackingSample { rpm: inputs.rpm, target_psi: target_boost_psi, boost_psi: inputs.manifold_pressure });
+        self.sessions.track(tracking, inputs.timestamp_ms);
+        
+        // Torque gap only means something while the ECU torque data is trusted
+        if self.state == SystemState::Armed && self.can_health.level(inputs.timestamp_ms).torque_authority() > 0.0 {
+            let boost_error = tracking.map(|sample| sample.target_psi - sample.boost_psi);
+            self.sessions.record_gap(inputs.rpm, inputs.desired_torque - inputs.actual_torque, boost_error);
+        }
+        
+        // Hand this cycle's events to display, telemetry and logging sinks
+        self.events.observe_state(&self.state, inputs.timestamp_ms);
+        if let Some(event) = self.overboost.observe(&self.state, FreezeFrame::capture(&inputs, target_boost_psi, duty)) {
+            self.events.publish(inputs.timestamp_ms, CoreEvent::Overboost(event));
+        }
+        self.events.dispatch();
+        self.front_panel.show(&self.state, &mut self.hal, inputs.timestamp_ms);
+        
+        // One sample for every telemetry consumer, thinned per sink and by the performance tier
+        let sample = self.telemetry_sample();
+        let load_multiplier = self.tier_governor.tier().interval_multiplier();
+        self.telemetry_sinks.publish(&sample, load_multiplier, &mut self.hal);
+        self.datalogger.record(&mut self.hal, &sample, &inputs, load_multiplier);
         
         // Update performance statistics
-        let cycle_time = (self.hal.now_us() - cycle_start) as u32;
+        let cycle_time = (self.hal.now_us() - started_us) as u32;
         self.update_performance_stats(cycle_time);
         
+        // Background work gives way when the cycle runs short of headroom or the MCU runs hot
+        let mcu_temperature = self.hal.mcu_temperature().ok();
+        self.tier_governor.update(
+            self.stats.avg_cycle_time_us, self.config.control_rates.inner_period_us(), mcu_temperature, inputs.timestamp_ms
+        );
+        
+        // Written at once - the event must be in EEPROM even if power goes next
+        if self.safety_log.observe(&mut self.hal, &self.state, inputs.manifold_pressure, cycle_time, inputs.timestamp_ms) {
+            self.sessions.count_storage_writes(1);
+        }
+        
+        let blocks_written = self.flight_recorder.blocks_written();
+        self.flight_recorder.record(&mut self.hal, &self.state, inputs.manifold_pressure, duty, inputs.timestamp_ms);
+        self.sessions.count_storage_writes(self.flight_recorder.blocks_written() - blocks_written);
+        
+        // Learned data is written in batches, and only off boost where a slow EEPROM write costs nothing
+        if inputs.manifold_pressure < self.config.spring_pressure && self.learned_data.save_due(inputs.timestamp_ms) {
+            // A failed write keeps the changes for the next attempt
+            let _ = self.learned_data.save_to_storage(&mut self.hal, inputs.timestamp_ms);
+            self.sessions.count_storage_writes(1);
+        }
+        self.trends.follow(self.sessions.current());
+        // Saved when the engine stops, ahead of key-off; without a card the drive is only lost from the history
+        if self.trends.save_due(inputs.rpm) {
+            let _ = self.trends_journal.commit_value(&mut self.hal, &self.trends.report());
+        }
+        
+        if self.burst_log.is_recording() {
+            self.burst_log.record(BurstSample {
+                state: self.state.clone(),
+                map_source: self.map_arbiter.selected(),
+                map_zero_psi: self.map_zero.zero_psi(),
+                can_authority: self.can_health.level(inputs.timestamp_ms).torque_authority(),
+                thermal_derate: self.thermal_derate.multiplier(),
+                outer_cycle,
+                level1_target_psi: self.level1_target_boost_psi,
+                target_psi: target_boost_psi,
+                authority_request,
+                duty_ceiling_percent: self.duty_ceiling.ceiling_percent(inputs.rpm),
+                idle_venting: self.idle_vent.is_venting(),
+                duty_percent: duty,
+                cycle_time_us: cycle_time,
+                boost_trim_psi: self.dome_control.status().active.then_some(self.dome_control.status().boost_trim_psi),
+                inputs,
+            });
+        }
+        
         Ok(())
     }
     
-    /// Read all system inputs from sensors and CAN
-    fn read_system_inputs(&mut self) -> Result<SystemInputs, CoreError> {
+    /// Latest vehicle speed from CAN, unless older than the loss threshold (km/h)
+    fn vehicle_speed_kph(&self, now_ms: u32) -> Option<f32> {
+        self.can_inputs.vehicle_speed()
+            .filter(|(_, at_ms)| now_ms.wrapping_sub(*at_ms) <= MAX_LOSS_THRESHOLD_MS)
+            .map(|(kph, _)| kph)
+    }
+    
+    /// Read all system inputs from sensors and CAN (first cycle phase)
+    /// 
+    /// Also tracks the MAP zero and arbitrates the manifold pressure source,
+    /// which can fault the system.
+    pub fn read_inputs(&mut self) -> Result<SystemInputs, CoreError> {
         // Implementation would read from HAL interfaces
         // This is a placeholder structure
+        let now_ms = self.hal.now_ms();
+        
+        // Drain the bus; frames carrying RPM, torque or MAP count towards CAN health
+        self.can_inputs.begin_cycle();
+        for _ in 0..MAX_CAN_FRAMES_PER_CYCLE {
+            let Some(frame) = self.hal.receive_frame()? else { break };
+            if self.can_inputs.decode(&frame, now_ms) {
+                self.can_health.record_message(frame.id, now_ms);
+            }
+        }
+        // Protocols that poll (OBD-II) ask for their next value; a failed send only delays it
+        if let Some(request) = self.can_inputs.poll_request(now_ms) {
+            let _ = self.hal.send_frame(&request);
+        }
+        
+        // Torque and throttle older than their message's loss threshold are not used
+        let fresh = |value: Option<(f32, u32)>| value
+            .filter(|(_, at_ms)| now_ms.wrapping_sub(*at_ms) <= MAX_LOSS_THRESHOLD_MS)
+            .map(|(value, _)| value);
+        let desired_torque = fresh(self.can_inputs.desired_torque());
+        let actual_torque = fresh(self.can_inputs.actual_torque());
+        self.update_torque_availability(desired_torque.is_some() && actual_torque.is_some(), now_ms);
+        let desired_torque = desired_torque.unwrap_or(0.0);
+        let actual_torque = actual_torque.unwrap_or(0.0);
+        
+        // Glitched CAN RPM frames are rejected; short dropouts hold the last good value
+        let can_rpm = self.can_inputs.rpm();
+        let validated_rpm = self.rpm_validator.validate(can_rpm, now_ms);
+        let rpm = validated_rpm.unwrap_or(0);
+        
+        // Analog pressure inputs in physical channel order; the channel map assigns them to roles.
+        // A channel the HAL cannot convert (sensor open or shorted) reads None.
+        let readings: [Option<f32>; ANALOG_PRESSURE_INPUTS] =
+            core::array::from_fn(|channel| self.hal.read_pressure_psi(channel as u8).ok());
+        let analog = readings.map(|reading| reading.unwrap_or(0.0));
+        let channels: [f32; PRESSURE_CHANNELS] = core::array::from_fn(|channel| analog[channel]);
+        let [_, dome_input_pressure, upper_dome_pressure, lower_dome_pressure] =
+            self.config.sensor_channels.assign(&channels);
+        let bank_dome_pressure = self.config.twin_turbo.bank_dome_pressures(&analog);
+        let backpressure = &self.config.backpressure;
+        let exhaust_backpressure_psi = backpressure.enabled
+            .then(|| self.hal.read_pressure_psi(backpressure.channel).ok())
+            .flatten();
+        
+        // Analog sensor's reading at atmosphere (key-on, engine off) is its zero offset
+        let raw_analog_map: Option<f32> = readings[self.config.sensor_channels.manifold as usize % PRESSURE_CHANNELS];
+        if let Some(raw) = raw_analog_map {
+            let pre_arm = matches!(self.state, SystemState::Initializing | SystemState::Idle | SystemState::Commissioning(_));
+            let previous = self.map_zero.record().clone();
+            self.map_zero.update(raw, validated_rpm, pre_arm, now_ms);
+            if *self.map_zero.record() != previous {
+                self.save_map_zero();
+            }
+        }
+        
+        let can_map = fresh(self.can_inputs.manifold_pressure());
+        let analog_map = raw_analog_map.map(|raw| self.map_zero.apply(raw));
+        let manifold_pressure = self.arbitrate_manifold_pressure(can_map, analog_map, now_ms);
+        
+        let aggression_inputs = &self.config.aggression_inputs;
+        let knob = aggression_inputs.knob.as_ref()
+            .and_then(|knob| self.hal.read_voltage(knob.channel).ok().and_then(|volts| knob.position(volts)));
+        let drive_mode = self.can_inputs.drive_mode()
+            .and_then(|(mode, at_ms)| aggression_inputs.drive_mode_aggression(mode).map(|aggression| (aggression, at_ms)));
+        let aggression = self.aggression_input.update(self.config.aggression, knob, drive_mode, now_ms).value;
+        let scramble_active = self.front_panel.read(&self.hal, now_ms);
+        
+        // Temperatures change slowly and are broadcast slowly; a missing one holds the thermal derate
+        let recent = |value: Option<(f32, u32)>| value
+            .filter(|(_, at_ms)| now_ms.wrapping_sub(*at_ms) <= MAX_TEMPERATURE_AGE_MS)
+            .map(|(value, _)| value);
+        let iat_sensor = &self.config.density_compensation;
+        let analog_iat_c = iat_sensor.iat_channel
+            .and_then(|channel| self.hal.read_voltage(channel).ok())
+            .and_then(|volts| iat_sensor.intake_air_temp_c(volts));
+        let intake_air_temp_c = recent(self.can_inputs.intake_air_temp()).or(analog_iat_c);
+        let coolant_temp_c = recent(self.can_inputs.coolant_temp());
+        
         Ok(SystemInputs {
-            rpm: 0,
-            desired_torque: 0.0,
-            actual_torque: 0.0,
-            manifold_pressure: 0.0,
-            dome_input_pressure: 0.0,
-            upper_dome_pressure: 0.0,
-            lower_dome_pressure: 0.0,
-            aggression: self.config.aggression,
-            scramble_active: false,
-            timestamp_ms: self.hal.now_ms(),
+            rpm,
+            desired_torque,
+            actual_torque,
+            manifold_pressure,
+            throttle_position: fresh(self.can_inputs.throttle_position()),
+            dome_input_pressure,
+            upper_dome_pressure,
+            lower_dome_pressure,
+            intake_air_temp_c,
+            coolant_temp_c,
+            bank_dome_pressure,
+            exhaust_backpressure_psi,
+            aggression,
+            scramble_active,
+            timestamp_ms: now_ms,
         })
     }
     
-    /// Execute 3-level control hierarchy
-    fn execute_control_hierarchy(&mut self, inputs: &SystemInputs) -> Result<f32, CoreError> {
-        // LEVEL 1: Torque-Based Boost Target Adjustment
-        let torque_gap = inputs.desired_torque - inputs.actual_torque;
-        let assistance_needed = self.torque_following.analyze_assistance_need(torque_gap, inputs)?;
-        
-        // LEVEL 2: Precise Boost Delivery (PID + Learned Calibration)
-        let target_boost = if assistance_needed {
-            self.torque_following.calculate_boost_assistance(torque_gap, inputs)?
+    /// Drop torque following to boost-by-target while ECU torque is missing
+    /// 
+    /// 🔗 T4-CORE-111: Degraded Boost-Target-Only Control
+    /// Derived From: T4-CORE-106 (CAN Input Decoding) + T1-SAFETY-002 (Defense in Depth)
+    /// Zero torque would read as "no gap" and hold the baseline target whatever
+    /// the driver asks for; the RPM target curve at least follows the pedal's
+    /// effect on engine speed. Torque following resumes with the first cycle
+    /// that has both torque values again.
+    fn update_torque_availability(&mut self, available: bool, now_ms: u32) {
+        let degraded = self.config.control_mode == ControlMode::TorqueFollowing && !available;
+        if degraded != self.torque_degraded {
+            self.torque_degraded = degraded;
+            self.events.publish(now_ms, CoreEvent::TorqueData { available: !degraded });
+        }
+    }
+    
+    /// Whether the control hierarchy drives the output this cycle
+    fn boost_control_active(&self) -> bool {
+        self.state == SystemState::Armed && !self.configurations.is_spring_only() && !self.spring_check_running()
+    }
+    
+    /// Whether a spring check is holding the output at 0 %
+    fn spring_check_running(&self) -> bool {
+        self.spring_check.as_ref().is_some_and(|check| check.is_running())
+    }
+    
+    /// Why the wastegate is on its spring alone, if it is
+    /// 
+    /// Every fallback to 0 % duty reports the built-in spring-only profile
+    /// (T4-CORE-114); the reason tells how it was reached.
+    pub fn spring_only_reason(&self) -> Option<SpringOnlyReason> {
+        if self.solenoid_failover.degradation() == OutputDegradation::Failsafe {
+            return Some(SpringOnlyReason::OutputFailed);
+        }
+        match &self.state {
+            SystemState::Fault(FaultCode::CanCommunicationLost) => Some(SpringOnlyReason::CanLost),
+            SystemState::Fault(_) => Some(SpringOnlyReason::Fault),
+            _ if self.configurations.is_spring_only() => Some(SpringOnlyReason::Selected),
+            _ => None,
+        }
+    }
+    
+    /// Level 1 control mode in effect - boost-by-target while torque following has no torque data
+    pub fn control_mode_in_effect(&self) -> ControlMode {
+        if self.torque_degraded {
+            ControlMode::BoostByTarget
         } else {
-            self.torque_following.get_baseline_boost(inputs)?
+            self.config.control_mode
+        }
+    }
+    
+    /// Select manifold pressure from the configured source(s)
+    /// 
+    /// Losing the only usable source or a sustained source disagreement faults the
+    /// system; the returned value is then only used while output is held failsafe.
+    fn arbitrate_manifold_pressure(&mut self, can_psi: Option<f32>, analog_psi: Option<f32>, now_ms: u32) -> f32 {
+        let fault = match self.map_arbiter.arbitrate(can_psi, analog_psi, now_ms) {
+            ArbitrationResult::Value { value, .. } => return value,
+            ArbitrationResult::Mismatch { can_value, analog_value } => FaultCode::InputSourceMismatch {
+                input: "manifold pressure".to_string(),
+                can_value,
+                analog_value,
+            },
+            ArbitrationResult::Unavailable => FaultCode::PressureSensorFault("manifold pressure unavailable".to_string()),
+        };
+        
+        if !matches!(self.state, SystemState::Fault(_)) {
+            self.state = SystemState::Fault(fault);
+            self.stats.safety_interventions += 1;
+        }
+        
+        0.0
+    }
+    
+    /// Execute 3-level control hierarchy
+    /// 
+    /// `outer_inputs` are the decimated inputs on outer loop cycles; between them
+    /// the Level 1 target holds while the caps and duty conversion follow the
+    /// per-cycle `inputs`. Returns the wastegate authority to command (%).
+    fn execute_control_hierarchy(&mut self, inputs: &SystemInputs, outer_inputs: Option<&SystemInputs>) -> Result<f32, CoreError> {
+        if let Some(outer_inputs) = outer_inputs {
+            // New settings run at reduced aggression until their shake-down period is over
+            let outer_inputs = SystemInputs {
+                aggression: self.shakedown.aggression(outer_inputs.aggression),
+                ..outer_inputs.clone()
+            };
+            // Low gears get a share of the boost over spring, for traction
+            let target_boost = self.select_boost_target(&outer_inputs)?;
+            let target_boost = self.gear_estimator.scale_target(target_boost, self.config.spring_pressure);
+            // At altitude the same gauge boost is less air - optionally hold manifold absolute pressure
+            self.level1_target_boost_psi = self.density_compensation.compensate_target(target_boost, self.config.max_boost_psi);
+        }
+        
+        // Never target more boost than the remaining solenoid hardware can deliver
+        let target_boost = self.level1_target_boost_psi.min(self.solenoid_failover.boost_ceiling_psi(&self.config));
+        
+        // Wastegate should already be opening when the ECU hits its rev limiter
+        let target_boost = self.config.rev_limit_taper.apply(target_boost, inputs.rpm, self.config.spring_pressure);
+        
+        // Sustained heat pulls the ceiling down
+        let target_boost = match self.thermal_derate.boost_cap_psi(&self.config) {
+            Some(cap_psi) => target_boost.min(cap_psi),
+            None => target_boost,
+        };
+        
+        // Exhaust pressure running away from boost means the turbine is choking
+        let target_boost = match self.turbine_protection.boost_cap_psi() {
+            Some(cap_psi) => target_boost.min(cap_psi),
+            None => target_boost,
+        };
+        
+        // Thin or hot air spins the turbo faster for the same boost
+        let target_boost = match self.turbo_speed.boost_cap_psi() {
+            Some(cap_psi) => target_boost.min(cap_psi),
+            None => target_boost,
+        };
+        
+        // Startup delay and shake-down period after a configuration change
+        let target_boost = match self.shakedown.boost_cap_psi(&self.config) {
+            Some(cap_psi) => target_boost.min(cap_psi),
+            None => target_boost,
         };
         
-        // LEVEL 3: Safety and Output
+        // Boost closing in on the overboost limit backs the target off before the hard cut
+        let target_boost = self.config.soft_ceiling.apply(
+            target_boost, inputs.manifold_pressure, self.limits_in_force().overboost_limit_psi, self.config.spring_pressure
+        );
+        self.last_target_boost_psi = target_boost;
+        
+        self.level3_duty(target_boost, inputs)
+    }
+    
+    /// LEVEL 3: Safety and Output - duty for a capped boost target (%)
+    /// 
+    /// Shared by the single output and each bank of a twin layout, so every
+    /// commanded duty gets the same corrections and safety limiting.
+    fn level3_duty(&mut self, target_boost: f32, inputs: &SystemInputs) -> Result<f32, CoreError> {
+        // The learned map is at reference air density
         let target_duty = self.learned_data.boost_to_duty_conversion(target_boost, inputs)?;
-        let safe_duty = self.safety_monitor.validate_and_limit(target_duty, inputs)?;
+        let target_duty = self.density_compensation.correct_duty(target_duty);
+        // Optionally regulate the dome pressure the target needs instead - the learned duty stays the fallback
+        // (twin layouts have a valve per bank, never a single 4-port valve)
+        let single_valve = self.actuator.actuator_type() == ActuatorType::PwmSolenoid
+            && self.solenoid_failover.active_topology() == ActiveOutputTopology::SingleFourPort;
+        let target_duty = self.dome_control.update(target_boost, self.config.spring_pressure, target_duty, inputs, single_valve, self.live_tune.active())
+            .unwrap_or(target_duty);
+        
+        Ok(target_duty.clamp(0.0, self.limits_in_force().max_duty_percent))
+    }
+    
+    /// Select the uncapped boost target for the active control mode (outer loop)
+    fn select_boost_target(&mut self, inputs: &SystemInputs) -> Result<f32, CoreError> {
+        let target_boost = match self.control_mode_in_effect() {
+            ControlMode::TorqueFollowing => {
+                // LEVEL 1: Torque-Based Boost Target Adjustment
+                // Torque assistance is scaled back as CAN data quality degrades
+                let can_authority = self.can_health.level(inputs.timestamp_ms).torque_authority();
+                let torque_gap = (inputs.desired_torque - inputs.actual_torque) * can_authority;
+                self.torque_following.set_tuning(self.live_tune.active());
+                let assistance_needed = self.torque_following.analyze_assistance_need(torque_gap, inputs)?;
+                
+                // LEVEL 2: Precise Boost Delivery (PID + Learned Calibration)
+                if assistance_needed {
+                    self.torque_following.calculate_boost_assistance(torque_gap, inputs)?
+                } else {
+                    self.torque_following.get_baseline_boost(inputs)?
+                }
+            },
+            ControlMode::BoostByTarget => {
+                // LEVEL 1 bypassed - no usable ECU torque data (configured or lost), target follows the RPM curve
+                let aggression = if inputs.scramble_active && self.config.scramble_enabled { 1.0 } else { inputs.aggression };
+                self.config.boost_targets.target_psi(inputs.rpm, aggression, self.config.spring_pressure)
+            },
+        };
         
-        Ok(safe_duty)
+        Ok(target_boost)
     }
     
-    /// Update PWM output with safety validation
-    fn update_output(&mut self, duty_cycle: f32, inputs: &SystemInputs) -> Result<(), CoreError> {
+    /// Wastegate authority to command for a control hierarchy request
+    fn output_authority(&mut self, authority: f32, inputs: &SystemInputs) -> Result<f32, CoreError> {
         // Apply aggression scaling
-        let final_duty = self.apply_aggression_scaling(duty_cycle)?;
+        let final_authority = self.apply_aggression_scaling(authority, inputs.aggression)?;
         
-        // Update PWM with timing synchronization
-        self.hal.set_duty_cycle_synchronized(final_duty, self.hal.now_us())?;
+        // Duty past the learned ceiling only winds the wastegate tighter than shut
+        // (calibration keeps the full range - it is what finds the response)
+        let final_authority = if self.state == SystemState::Armed {
+            self.duty_ceiling.limit(inputs.rpm, final_authority)
+        } else {
+            final_authority
+        };
         
+        Ok(final_authority.min(self.limits_in_force().max_duty_percent))
+    }
+    
+    /// Overboost limit and duty cap in force - the stricter of the configuration and the stored copies
+    pub fn limits_in_force(&self) -> SafetyLimits {
+        self.safety_limits.in_force(SafetyLimits::of(&self.config))
+    }
+    
+    /// Wastegate authority for each turbo bank (twin layouts)
+    /// 
+    /// Each bank follows the capped shared target plus its trim, through the
+    /// same Level 3 pipeline as a single output. A sequential layout's
+    /// secondary bank is held open until it comes in.
+    fn bank_authorities(&mut self, inputs: &SystemInputs) -> Result<[f32; 2], CoreError> {
+        let ceiling_psi = self.solenoid_failover.boost_ceiling_psi(&self.config);
+        let mut authorities = [0.0; 2];
+        
+        for bank in TurboBank::ALL {
+            if !self.config.twin_turbo.bank_active(bank, inputs.rpm) {
+                continue;
+            }
+            
+            let trim_psi = self.config.twin_turbo.bank(bank).trim_psi;
+            let target_boost = (self.last_target_boost_psi + trim_psi).clamp(0.0, ceiling_psi);
+            let target_duty = self.level3_duty(target_boost, inputs)?;
+            authorities[bank.index()] = self.output_authority(target_duty, inputs)?;
+        }
+        
+        Ok(authorities)
+    }
+    
+    /// Open the wastegate (0% authority on every configured output)
+    fn set_failsafe_output(&mut self, immediate: bool) -> Result<(), CoreError> {
+        self.actuator.command_failsafe(&mut self.hal, immediate, &self.solenoid_failover)?;
         Ok(())
     }
     
-    /// Apply aggression-based scaling to duty cycle
-    fn apply_aggression_scaling(&self, base_duty: f32) -> Result<f32, CoreError> {
+    /// Run the idle vent stage and plan the PWM output switch on its transitions
+    /// 
+    /// Returns true while the domes are vented. Rearm happens in the same cycle
+    /// that sees tip-in, so the control hierarchy runs without a missed cycle.
+    fn update_idle_vent(&mut self, inputs: &SystemInputs, plan: &mut CyclePlan) -> bool {
+        let was_venting = self.idle_vent.is_venting();
+        let venting = self.idle_vent.update(inputs);
+        
+        match (was_venting, venting) {
+            (false, true) => {
+                plan.failsafe(false);
+                plan.pwm_enable = Some(false);
+            },
+            (true, false) => plan.pwm_enable = Some(true),
+            _ => {},
+        }
+        
+        venting
+    }
+    
+    /// Cycle the solenoid while vented at idle when an exercise is due
+    /// 
+    /// PWM is switched back on only for the exercise; the domes return to
+    /// vented with the output disabled when it completes.
+    fn update_solenoid_exercise(&mut self, inputs: &SystemInputs, plan: &mut CyclePlan) {
+        // Electronic actuators have no valve to gum up
+        if self.actuator.actuator_type() != ActuatorType::PwmSolenoid {
+            return;
+        }
+        
+        let was_active = self.solenoid_exercise.is_active();
+        match self.solenoid_exercise.update(inputs) {
+            Some(duty) => {
+                if !was_active {
+                    plan.pwm_enable = Some(true);
+                }
+                plan.drive = DriveCommand::Authority(duty.min(self.limits_in_force().max_duty_percent));
+            },
+            None if was_active => {
+                plan.failsafe(false);
+                plan.pwm_enable = Some(false);
+                if let Some(record) = self.solenoid_exercise.status().recent.last() {
+                    self.events.publish(inputs.timestamp_ms, CoreEvent::SolenoidExercise(record.clone()));
+                }
+            },
+            None => {},
+        }
+    }
+    
+    /// Fault on CAN loss while torque-following is active
+    /// 
+    /// 🔗 T4-CORE-031: Adaptive CAN Loss Detection
+    /// Derived From: SY-10 (CAN Dependency Management) + T4-CORE-030
+    fn check_can_health(&mut self, now_ms: u32) {
+        if self.state == SystemState::Armed && self.can_health.level(now_ms).requires_fault() {
+            self.state = SystemState::Fault(FaultCode::CanCommunicationLost);
+            self.stats.safety_interventions += 1;
+        }
+    }
+    
+    /// Cut the output once boost reaches the overboost limit in force
+    /// 
+    /// Applies while anything is driving the wastegate shut - armed control or
+    /// a calibration sweep, which is abandoned. The cut clears back to armed
+    /// once boost falls below the limit.
+    fn check_overboost(&mut self, inputs: &SystemInputs) {
+        if !matches!(self.state, SystemState::Armed | SystemState::Calibrating(_))
+            || inputs.manifold_pressure < self.limits_in_force().overboost_limit_psi {
+            return;
+        }
+        
+        if matches!(self.state, SystemState::Calibrating(_)) {
+            self.calibration.abort();
+        }
+        self.state = SystemState::OverboostCut;
+        self.stats.safety_interventions += 1;
+    }
+    
+    /// Poll solenoid drive status and apply failover decisions
+    /// 
+    /// 🔗 T4-CORE-027: Solenoid Failover Integration
+    /// Derived From: T1-SAFETY-001 - loss of safe control authority is a critical fault
+    fn update_solenoid_failover(&mut self, now_ms: u32) -> Result<(), CoreError> {
+        // Electronic actuators have no solenoid channels to monitor
+        if self.actuator.actuator_type() != ActuatorType::PwmSolenoid {
+            return Ok(());
+        }
+        
+        let primary = self.hal.get_channel_status(SolenoidChannel::Primary)?;
+        let secondary = match self.solenoid_failover.topology() {
+            SolenoidTopology::DualSolenoid | SolenoidTopology::TwinFourPort => self.hal.get_channel_status(SolenoidChannel::Secondary)?,
+            SolenoidTopology::SingleFourPort => rumbledome_hal::SolenoidDriveStatus::Ok,
+        };
+        
+        let previous = self.solenoid_failover.degradation();
+        let degradation = self.solenoid_failover.update(primary, secondary);
+        if degradation != previous {
+            self.events.publish(now_ms, CoreEvent::OutputDegraded(degradation));
+        }
+        
+        if degradation == OutputDegradation::Failsafe && !matches!(self.state, SystemState::Fault(_)) {
+            self.state = SystemState::Fault(FaultCode::PwmHardwareFault);
+            self.stats.safety_interventions += 1;
+        }
+        
+        Ok(())
+    }
+    
+    /// Apply aggression-based scaling to wastegate authority
+    fn apply_aggression_scaling(&self, base_duty: f32, aggression: f32) -> Result<f32, CoreError> {
         // Aggression scales response characteristics
-        let response_profile = self.config.get_response_characteristics();
+        let response_profile = ResponseProfile::for_aggression(self.shakedown.aggression(aggression));
         let scaled_duty = base_duty * response_profile.torque_following_gain;
         
         Ok(scaled_duty.clamp(0.0, 100.0))
@@ -304,21 +1496,949 @@ impl<H: HalTrait> RumbleDomeCore<H> {
             self.stats.max_cycle_time_us = cycle_time_us;
         }
         
-        // Check for timing violations (>10ms for 100Hz loop = timing issue)
-        if cycle_time_us > 10_000 {
+        // Check for timing violations (cycle longer than the inner loop period)
+        if cycle_time_us > self.config.control_rates.inner_period_us() {
             self.stats.timing_violations += 1;
         }
         
         self.stats.last_update_ms = self.hal.now_ms();
     }
     
+    /// Hold a protected command and show its confirmation code on the display
+    /// 
+    /// The caller keeps the command and runs it once `presence.confirm()`
+    /// accepts the code sent back on the same session.
+    pub fn request_presence(&mut self, action: ProtectedAction, now_ms: u32) {
+        let entropy = self.hal.now_us() as u32 ^ self.stats.cycles_executed as u32;
+        self.presence.issue(action, entropy, now_ms);
+    }
+    
+    /// Apply a live-tune test value
+    /// 
+    /// The boost loop gains and slew rate act only on the cascaded dome
+    /// control, so while it is off they are refused rather than accepted to no effect.
+    pub fn set_test_value(&mut self, parameter: TuneParameter, value: f32, now_ms: u32) -> Result<(), CoreError> {
+        if parameter.dome_control_only() && !self.config.dome_control.enabled {
+            return Err(CoreError::ConfigurationError(format!(
+                "The {} only acts on the cascaded dome control, which is disabled", parameter.short_name()
+            )));
+        }
+        self.live_tune.set_test_value(parameter, value, now_ms)
+    }
+    
+    /// Persist live-tune test values into the stored configuration
+    pub fn commit_live_tune(&mut self) -> Result<(), CoreError> {
+        let mut updated = self.config.clone();
+        updated.tuning = self.live_tune.active().clone();
+        updated.validate()?;
+        
+        self.config.tuning = self.live_tune.commit();
+        self.save_config()
+    }
+    
+    /// Switch to another stored named configuration
+    /// 
+    /// 🔗 T4-CORE-050: Configuration Switch
+    /// Derived From: T4-CORE-049 + T1-SAFETY-001 - never swap calibration under active boost control
+    /// 
+    /// Changes made to the running configuration (committed live tune, maintenance
+    /// acknowledgements) are kept in the entry being left. Caller is responsible for
+    /// writing the library to storage.
+    pub fn switch_configuration(&mut self, name: &str) -> Result<(), CoreError> {
+        self.activate_configuration(name, ConfigLibrary::select)
+    }
+    
+    /// Aggression sent by a connected app
+    /// 
+    /// Takes effect when the arbitration policy gives the app control, and
+    /// drops out unless sent again within the aggression input timeout.
+    pub fn set_app_aggression(&mut self, value: f32) -> Result<AggressionInputStatus, CoreError> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(CoreError::ConfigurationError(format!("Aggression must be 0.0-1.0, got {}", value)));
+        }
+        self.aggression_input.set_app(value, self.hal.now_ms());
+        Ok(self.aggression_input.status())
+    }
+    
+    /// Apply context hints pushed by a connected app and follow the profile schedule
+    /// 
+    /// A scheduled switch is subject to the same restriction as switching by
+    /// hand; while boost is controlled it is reported as deferred and retried
+    /// on the next push. Caller is responsible for writing the library to
+    /// storage after a switch.
+    pub fn apply_context_hints(&mut self, hints: ContextHints) -> Result<ScheduleStatus, CoreError> {
+        hints.validate()?;
+        
+        let mut status = self.profile_schedule.evaluate(hints, &self.configurations);
+        if let ScheduleOutcome::Switched { config } = &status.outcome {
+            let config = config.clone();
+            match self.activate_configuration(&config, ConfigLibrary::select_scheduled) {
+                Ok(()) => self.profile_schedule.switched(&config),
+                Err(CoreError::InvalidState(_)) => status.outcome = ScheduleOutcome::Deferred { config },
+                Err(e) => return Err(e),
+            }
+            status.active = self.configurations.active_name().to_string();
+        }
+        
+        Ok(status)
+    }
+    
+    /// Replace the profile scheduling rules (applied from the next context hints)
+    pub fn set_profile_schedule(&mut self, rules: Vec<ProfileRule>) -> Result<(), CoreError> {
+        self.configurations.set_schedule(rules)
+    }
+    
+    /// Act on a profile button press
+    /// 
+    /// 🔗 T4-CORE-130: Profile Button Selection
+    /// Derived From: T4-CORE-129 + T4-CORE-050
+    /// 
+    /// A press refused while boost is controlled is dropped, not queued - a
+    /// switch happening later by surprise is worse than pressing again.
+    fn select_profile_by_button(&mut self, press: ButtonEvent) {
+        let names = self.configurations.names();
+        let active = self.configurations.active().name.clone();
+        if let Some(name) = profile_for_press(press, &names, &active, self.configurations.is_spring_only()) {
+            let _ = self.switch_configuration(&name);
+        }
+    }
+    
+    /// Leave the active configuration for `name`, chosen through `select`
+    fn activate_configuration(&mut self, name: &str, select: for<'a> fn(&'a mut ConfigLibrary, &str) -> Result<&'a NamedConfig, CoreError>) -> Result<(), CoreError> {
+        self.ensure_not_controlling("switch configuration")?;
+        
+        // The spring-only profile runs on the active entry, so that is where changes belong
+        let leaving = self.configurations.active().name.clone();
+        self.configurations.save(&leaving, self.config_to_store())?;
+        let config = select(&mut self.configurations, name)?.config.clone();
+        // The map being left is stored under its own namespace; a failed write only loses the unsaved samples
+        if self.learned_data.status().unsaved_updates > 0 {
+            let _ = self.save_learned_data();
+        }
+        let stored = self.apply_configuration(config);
+        
+        // Each configuration learns its own turbo and wastegate; a corrupt map starts over
+        let namespace = self.configurations.active().learned_data_namespace();
+        self.learned_data = LearnedData::load_from_storage(&mut self.hal, &namespace, self.config.spring_pressure)
+            .unwrap_or_else(|_| LearnedData::new(self.config.spring_pressure).with_namespace(&namespace));
+        
+        stored
+    }
+    
+    /// Switch Level 1 between torque following and boost-by-target
+    pub fn set_control_mode(&mut self, mode: ControlMode) -> Result<(), CoreError> {
+        self.ensure_not_controlling("change control mode")?;
+        
+        let mut updated = self.config.clone();
+        updated.control_mode = mode;
+        updated.validate()?;
+        
+        if updated.control_mode != self.config.control_mode {
+            self.shakedown.begin();
+        }
+        self.config = updated;
+        self.save_config()
+    }
+    
+    /// Store a named configuration
+    /// 
+    /// Replacing the active configuration, or one it inherits from, takes
+    /// effect immediately, so it is subject to the same restriction as switching.
+    pub fn save_configuration(&mut self, name: &str, config: SystemConfig) -> Result<(), CoreError> {
+        self.update_configurations(name, |configurations| configurations.save(name, config))
+    }
+    
+    /// Store a named configuration inheriting from `parent`, changing only the given fields
+    /// 
+    /// Same restriction as `save_configuration`.
+    pub fn save_inherited_configuration(&mut self, name: &str, parent: &str, overrides: serde_json::Map<String, serde_json::Value>) -> Result<(), CoreError> {
+        self.update_configurations(name, |configurations| configurations.save_inherited(name, parent, overrides))
+    }
+    
+    /// Change the entry `name` in the library, running the result if the active configuration depends on it
+    fn update_configurations(&mut self, name: &str, update: impl FnOnce(&mut ConfigLibrary) -> Result<(), CoreError>) -> Result<(), CoreError> {
+        let affects_active = self.configurations.inherits_from(&self.configurations.active().name, name);
+        if affects_active {
+            self.ensure_not_controlling("replace active configuration")?;
+        }
+        
+        update(&mut self.configurations)?;
+        if affects_active {
+            let running = self.config.clone();
+            let stored = self.apply_configuration(self.configurations.active().config.clone());
+            if is_safety_relevant(&running, &self.config) {
+                self.shakedown.begin();
+            }
+            stored?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Delete a stored named configuration (not the active one) and the learned data stored under its namespace
+    pub fn delete_configuration(&mut self, name: &str) -> Result<NamedConfig, CoreError> {
+        let removed = self.configurations.remove(name)?;
+        // Copies left behind by a failed erase are given up to the next map that needs the space
+        self.sessions.count_storage_writes(1);
+        let _ = LearnedData::erase_namespace(&mut self.hal, &removed.learned_data_namespace());
+        Ok(removed)
+    }
+    
+    /// Reject configuration changes while boost is being controlled
+    fn ensure_not_controlling(&self, action: &str) -> Result<(), CoreError> {
+        if matches!(self.state, SystemState::Armed | SystemState::OverboostCut | SystemState::Calibrating(_)) {
+            return Err(CoreError::InvalidState(
+                format!("Cannot {} in state {}", action, self.state.display_text())
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Make a configuration the running one and write it to the configuration record
+    /// 
+    /// The configuration runs even when the write fails; the error says it
+    /// will not survive a power cycle.
+    fn apply_configuration(&mut self, config: SystemConfig) -> Result<(), CoreError> {
+        // Writing the limits afresh is also how copies that could not be reconciled are recovered
+        if self.safety_limits.store(&mut self.hal, SafetyLimits::of(&config))
+            && self.state == SystemState::Fault(FaultCode::SafetyLimitsCorrupted) {
+            self.state = SystemState::Idle;
+        }
+        self.run_configuration(config);
+        self.save_config()
+    }
+    
+    /// Make a configuration the running one and rebuild config-derived components
+    /// 
+    /// Restart-staged parameters keep their booted values; the stored entry
+    /// carries the new ones to the next boot.
+    fn run_configuration(&mut self, mut config: SystemConfig) {
+        copy_restart_parameters(&self.config, &mut config);
+        self.actuator = actuator_driver(&config);
+        self.solenoid_failover = SolenoidFailover::new(config.solenoid_topology);
+        self.live_tune = LiveTuneSession::new(config.tuning.clone());
+        self.map_arbiter = SourceArbiter::new(config.input_sources.manifold_pressure.clone());
+        self.aggression_input.reconfigure(config.aggression_inputs.clone());
+        self.map_zero.reconfigure(config.map_zero.clone());
+        self.can_inputs.reconfigure(&config.can_signals);
+        // A failed filter update only costs receive buffer space - decoding still matches each frame
+        let _ = self.hal.set_filters(&self.can_inputs.filters());
+        self.idle_vent.reconfigure(config.idle_vent.clone());
+        self.solenoid_exercise.reconfigure(config.solenoid_exercise.clone());
+        self.learning_gate.reconfigure(config.learning_gate.clone());
+        self.thermal_derate.reconfigure(config.thermal_derate.clone());
+        self.turbine_protection.reconfigure(config.backpressure.clone());
+        self.turbo_speed.reconfigure(config.turbo_speed.clone());
+        self.density_compensation.reconfigure(config.density_compensation.clone());
+        if config.gear_boost != self.config.gear_boost {
+            self.gear_estimator.reconfigure(config.gear_boost.clone());
+        }
+        if config.dome_control != self.config.dome_control {
+            self.dome_control.reconfigure(config.dome_control.clone());
+        }
+        self.shakedown.reconfigure(config.shakedown.clone());
+        if config.backpressure.enabled {
+            // An unaccepted curve leaves the channel reading on its previous scale until the next boot
+            let _ = self.hal.set_calibration(config.backpressure.channel, config.backpressure.sensor_calibration());
+        }
+        if let Some(channel) = config.density_compensation.baro_channel {
+            let _ = self.hal.set_calibration(channel, config.density_compensation.sensor_calibration());
+        }
+        self.bank_balance.reconfigure(config.twin_turbo.clone());
+        self.actuation_monitor.reconfigure(config.actuation_monitor.clone());
+        self.flight_recorder.reconfigure(config.flight_recorder.clone());
+        self.learned_data.set_spring_pressure(config.spring_pressure);
+        self.torque_following.reconfigure(&config);
+        self.rate_scheduler = RateScheduler::new(config.control_rates.clone());
+        self.tier_governor.reconfigure(config.performance_tiers.clone());
+        if config.telemetry_sinks.can_broadcast != self.config.telemetry_sinks.can_broadcast {
+            self.telemetry_sinks.configure_can_broadcast(&config.telemetry_sinks.can_broadcast);
+        }
+        if config.telemetry_sinks.datalog != self.config.telemetry_sinks.datalog {
+            self.datalogger.reconfigure(&mut self.hal, config.telemetry_sinks.datalog.clone());
+        }
+        self.overshoot_detector.cancel();
+        self.config = config;
+    }
+    
+    /// Write the running configuration to the EEPROM configuration record
+    /// 
+    /// Kept as its changes from the defaults. A configuration changing too
+    /// much to fit a copy fails with the previous record left in place; the
+    /// storage health in the support bundle also shows whether the last write made it.
+    fn save_config(&mut self) -> Result<(), CoreError> {
+        let stored = changes_from_defaults(&self.config_to_store())
+            .and_then(|changes| self.config_journal.commit_value(&mut self.hal, &changes));
+        self.storage_health.config_stored = Some(stored.is_ok());
+        self.sessions.count_storage_writes(1);
+        stored
+    }
+    
+    /// Run the configuration in the EEPROM configuration record, if there is an intact one
+    /// 
+    /// It is the configuration this unit last ran, so it replaces the one the
+    /// core was created with, restart-staged values included. Loading also
+    /// finds the newest copy, so the next save goes to the other one.
+    fn restore_config(&mut self) {
+        let Ok(Some(changes)) = self.config_journal.load_value(&mut self.hal) else {
+            return;
+        };
+        let Ok(stored) = config_from_changes(&changes) else {
+            return;
+        };
+        let active = self.configurations.active().name.clone();
+        if stored == self.config || self.configurations.save(&active, stored.clone()).is_err() {
+            return;
+        }
+        copy_restart_parameters(&stored, &mut self.config);
+        self.run_configuration(stored);
+    }
+    
+    /// Running configuration as it should be written back to the library
+    /// 
+    /// Keeps the stored restart-staged values so a pending change isn't
+    /// overwritten by the values the system booted with.
+    fn config_to_store(&self) -> SystemConfig {
+        let mut config = self.config.clone();
+        copy_restart_parameters(&self.configurations.active().config, &mut config);
+        config
+    }
+    
+    /// Restart-staged parameters whose stored value waits for the next boot
+    pub fn pending_restart(&self) -> Vec<StagedChange> {
+        staged_changes(&self.config, &self.configurations.active().config)
+    }
+    
+    /// Maintenance reminders currently due
+    pub fn maintenance_due(&self) -> Vec<&MaintenanceReminder> {
+        self.config.maintenance_reminders
+            .iter()
+            .filter(|reminder| reminder.is_due(self.usage.counters()))
+            .collect()
+    }
+    
+    /// Mark a maintenance task as done at the current usage reading
+    pub fn acknowledge_maintenance(&mut self, name: &str) -> Result<(), CoreError> {
+        let usage = self.usage.counters().clone();
+        let reminder = self.config.maintenance_reminders
+            .iter_mut()
+            .find(|reminder| reminder.name == name)
+            .ok_or_else(|| CoreError::ConfigurationError(format!("No maintenance reminder named \"{}\"", name)))?;
+        
+        reminder.mark_serviced(&usage);
+        self.save_config()
+    }
+    
+    /// Accept the current MAP zero as the new drift baseline (after sensor replacement)
+    pub fn reset_map_zero_baseline(&mut self) {
+        self.map_zero.reset_baseline();
+        self.save_map_zero();
+    }
+    
+    /// Write the MAP zero history to the sensor calibration record
+    /// 
+    /// A failed write keeps the previous record; the zero is captured again next key-on.
+    fn save_map_zero(&mut self) {
+        let _ = self.calibration_journal.commit_value(&mut self.hal, self.map_zero.record());
+        self.sessions.count_storage_writes(1);
+    }
+    
+    /// Stored learned-data image, for export
+    pub fn export_learned_data(&self) -> Vec<u8> {
+        self.learned_data.to_bytes()
+    }
+    
+    /// Receive one chunk of an exported learned-data image
+    /// 
+    /// The image is checked whole once its last chunk arrives, and only then
+    /// replaces the map and is written to storage.
+    pub fn import_learned_data(&mut self, offset: u32, chunk: &[u8]) -> Result<LearnedDataImport, CoreError> {
+        self.ensure_not_controlling("import learned data")?;
+        
+        let received = match self.learned_upload.accept(offset, chunk)? {
+            Some(image) => {
+                let imported = LearnedData::from_bytes(&image, self.config.spring_pressure)?;
+                self.learned_data.adopt(&imported);
+                self.save_learned_data()?;
+                image.len()
+            },
+            None => self.learned_upload.received(),
+        };
+        Ok(LearnedDataImport { received: received as u32, total: LEARNED_DATA_SIZE as u32 })
+    }
+    
+    /// Forget everything learned and store the empty map
+    pub fn reset_learned_data(&mut self) -> Result<(), CoreError> {
+        self.ensure_not_controlling("reset learned data")?;
+        self.learned_data.clear();
+        self.save_learned_data()
+    }
+    
+    /// Write the learned map now instead of waiting for the next batch
+    fn save_learned_data(&mut self) -> Result<(), CoreError> {
+        let now_ms = self.hal.now_ms();
+        self.sessions.count_storage_writes(1);
+        self.learned_data.save_to_storage(&mut self.hal, now_ms)
+    }
+    
+    /// Start an auto-calibration session
+    /// 
+    /// 🔗 T4-CORE-119: Calibration Session Control
+    /// Derived From: T2-CONTROL-007 (Progressive Safety Auto-Calibration) + T4-CORE-022 (State Transition Validation)
+    /// Calibration drives the wastegate, so it takes the same preconditions as arming.
+    pub fn start_calibration(&mut self) -> Result<(), CoreError> {
+        if self.state != SystemState::Idle {
+            return Err(CoreError::InvalidState(
+                format!("Cannot start calibration in state {}", self.state.display_text())
+            ));
+        }
+        
+        if let Some(step) = self.commissioning.current_step() {
+            return Err(CoreError::InvalidState(
+                format!("Cannot calibrate before commissioning is complete (next step: {})", step.display_text())
+            ));
+        }
+        
+        self.demo.stop();
+        self.calibration.start(&self.config);
+        self.state = SystemState::Calibrating(self.calibration.progress());
+        Ok(())
+    }
+    
+    /// Abandon calibration; the output drops to 0% duty from the next cycle
+    /// 
+    /// Cells learned so far are kept.
+    pub fn abort_calibration(&mut self) -> Result<(), CoreError> {
+        if !matches!(self.state, SystemState::Calibrating(_)) {
+            return Err(CoreError::InvalidState(
+                format!("No calibration in progress (state {})", self.state.display_text())
+            ));
+        }
+        
+        self.calibration.abort();
+        self.state = SystemState::Idle;
+        Ok(())
+    }
+    
+    /// Progress of the running calibration session
+    pub fn calibration_progress(&self) -> Option<CalibrationProgress> {
+        match &self.state {
+            SystemState::Calibrating(progress) => Some(progress.clone()),
+            _ => None,
+        }
+    }
+    
+    /// Restore persisted commissioning progress (at startup)
+    pub fn restore_commissioning(&mut self, record: CommissioningRecord) {
+        self.commissioning.restore(record);
+    }
+    
+    /// Enter commissioning at the next unfinished step
+    /// 
+    /// 🔗 T4-CORE-075: Commissioning Mode
+    /// Derived From: T4-CORE-073 + T4-CORE-022 (State Transition Validation)
+    pub fn begin_commissioning(&mut self) -> Result<(), CoreError> {
+        if self.state != SystemState::Idle {
+            return Err(CoreError::InvalidState(
+                format!("Cannot begin commissioning in state {}", self.state.display_text())
+            ));
+        }
+        
+        let step = self.commissioning.current_step()
+            .ok_or_else(|| CoreError::InvalidState("Commissioning already complete".to_string()))?;
+        self.state = SystemState::Commissioning(step);
+        Ok(())
+    }
+    
+    /// Confirm the active commissioning step and move on
+    /// 
+    /// Completing spring pressure detection stores the detected pressure in the
+    /// running configuration. Caller is responsible for writing the commissioning
+    /// record to storage.
+    pub fn complete_commissioning_step(&mut self, step: CommissioningStep) -> Result<(), CoreError> {
+        self.ensure_commissioning_step(step)?;
+        
+        let mut updated = None;
+        match step {
+            CommissioningStep::SensorZero if self.config.map_zero.enabled && self.map_zero.record().latest_psi.is_none() => {
+                return Err(CoreError::CalibrationError("MAP zero not captured yet".to_string()));
+            },
+            CommissioningStep::SpringPressure => {
+                if let Some(psi) = self.commissioning.detected_spring_psi() {
+                    let mut config = self.config.clone();
+                    config.spring_pressure = libm::roundf(psi * 10.0) / 10.0;
+                    config.validate()?;
+                    updated = Some(config);
+                }
+            },
+            _ => {},
+        }
+        
+        self.commissioning.complete_step(step)?;
+        let mut stored = Ok(());
+        if let Some(config) = updated {
+            self.config = config;
+            stored = self.save_config();
+        }
+        self.events.publish(self.hal.now_ms(), CoreEvent::CommissioningStep { step, outcome: StepOutcome::Completed });
+        
+        self.advance_commissioning();
+        stored
+    }
+    
+    /// Skip the active commissioning step
+    /// 
+    /// Caller is responsible for writing the commissioning record to storage.
+    pub fn skip_commissioning_step(&mut self, step: CommissioningStep) -> Result<(), CoreError> {
+        self.ensure_commissioning_step(step)?;
+        self.commissioning.skip_step(step)?;
+        self.events.publish(self.hal.now_ms(), CoreEvent::CommissioningStep { step, outcome: StepOutcome::Skipped });
+        self.advance_commissioning();
+        Ok(())
+    }
+    
+    /// Skip all remaining commissioning steps, permitting arming
+    /// 
+    /// Caller is responsible for writing the commissioning record to storage.
+    pub fn skip_commissioning(&mut self) -> Result<(), CoreError> {
+        if !matches!(self.state, SystemState::Idle | SystemState::Commissioning(_)) {
+            return Err(CoreError::InvalidState(
+                format!("Cannot skip commissioning in state {}", self.state.display_text())
+            ));
+        }
+        
+        self.commissioning.skip_remaining();
+        self.state = SystemState::Idle;
+        Ok(())
+    }
+    
+    /// Leave commissioning without finishing it; progress so far is kept
+    pub fn exit_commissioning(&mut self) {
+        if matches!(self.state, SystemState::Commissioning(_)) {
+            self.state = SystemState::Idle;
+        }
+    }
+    
+    fn ensure_commissioning_step(&self, step: CommissioningStep) -> Result<(), CoreError> {
+        if self.state != SystemState::Commissioning(step) {
+            return Err(CoreError::InvalidState(
+                format!("{} is not the active commissioning step (state {})", step.display_text(), self.state.display_text())
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Move to the next commissioning step, or back to idle when finished
+    fn advance_commissioning(&mut self) {
+        self.state = match self.commissioning.current_step() {
+            Some(next) => SystemState::Commissioning(next),
+            None => SystemState::Idle,
+        };
+    }
+    
+    /// Whether the display demo may run: engine stopped and boost control not in use
+    fn on_bench(&self, rpm: u16) -> bool {
+        rpm == 0 && matches!(self.state, SystemState::Idle | SystemState::Fault(_))
+    }
+    
+    /// Whether sensor wiring may be checked: engine stopped, boost control not in use
+    fn engine_off_setup(&self, rpm: u16) -> bool {
+        self.on_bench(rpm) || (rpm == 0 && matches!(self.state, SystemState::Commissioning(_)))
+    }
+    
+    /// Start the pressure sensor wiring assistant
+    /// 
+    /// 🔗 T4-CORE-094: Sensor Mapping Mode
+    /// Derived From: T4-CORE-093 + T4-CORE-075 (Commissioning Mode)
+    pub fn begin_sensor_mapping(&mut self) -> Result<(), CoreError> {
+        let rpm = self.last_inputs.as_ref().map(|inputs| inputs.rpm).unwrap_or(0);
+        if !self.engine_off_setup(rpm) {
+            return Err(CoreError::InvalidState(format!(
+                "Sensor mapping needs the engine stopped and the system idle (state {}, {} RPM)", self.state.display_text(), rpm
+            )));
+        }
+        self.sensor_mapping = Some(SensorMapping::new(self.config.sensor_channels.clone()));
+        Ok(())
+    }
+    
+    /// Mark the prompted sensor role as not responding and move on
+    pub fn skip_sensor_role(&mut self) -> Result<(), CoreError> {
+        self.sensor_mapping
+            .as_mut()
+            .ok_or_else(|| CoreError::InvalidState("Sensor mapping is not running".to_string()))?
+            .skip_role()
+    }
+    
+    /// Sensor mapping progress
+    pub fn sensor_mapping_status(&self) -> SensorMappingStatus {
+        self.sensor_mapping.as_ref().map(|mapping| mapping.status()).unwrap_or_default()
+    }
+    
+    /// Finish sensor mapping and store the detected channels in the running configuration
+    pub fn finish_sensor_mapping(&mut self) -> Result<SensorMappingReport, CoreError> {
+        let report = self.sensor_mapping
+            .as_ref()
+            .ok_or_else(|| CoreError::InvalidState("Sensor mapping is not running".to_string()))?
+            .report()?;
+        
+        let mut config = self.config.clone();
+        config.sensor_channels = report.map.clone();
+        config.validate()?;
+        self.config = config;
+        self.sensor_mapping = None;
+        self.save_config()?;
+        Ok(report)
+    }
+    
+    /// Abandon sensor mapping; the configured channels are kept
+    pub fn cancel_sensor_mapping(&mut self) {
+        self.sensor_mapping = None;
+    }
+    
+    /// Start a wastegate spring verification run
+    /// 
+    /// 🔗 T4-CORE-128: Spring Check Mode
+    /// Derived From: T4-CORE-127 + T4-CORE-103 (Presence-Confirmed Dangerous Commands)
+    /// 
+    /// Output is held at 0 % until the driver lifts after a wide-open pull. A
+    /// measurement outside the tolerance faults the system with the spring
+    /// pressure to configure.
+    pub fn start_spring_check(&mut self) -> Result<(), CoreError> {
+        if !matches!(self.state, SystemState::Idle | SystemState::Armed) {
+            return Err(CoreError::InvalidState(format!(
+                "Spring check needs the system idle or armed (state {})", self.state.display_text()
+            )));
+        }
+        if self.spring_check_running() {
+            return Err(CoreError::InvalidState("Spring check already running".to_string()));
+        }
+        self.spring_check = Some(SpringCheck::new(self.config.spring_pressure));
+        Ok(())
+    }
+    
+    /// Spring check progress, or the result of the last one
+    pub fn spring_check_status(&self) -> SpringCheckStatus {
+        self.spring_check.as_ref().map(|check| check.status().clone()).unwrap_or_default()
+    }
+    
+    /// Abandon a running spring check; boost control resumes next cycle
+    pub fn cancel_spring_check(&mut self) {
+        if let Some(check) = self.spring_check.as_mut() {
+            check.abort("Cancelled");
+        }
+    }
+    
+    /// Start a full-rate burst capture of `window_ms`
+    /// 
+    /// Runs in any state; the capture stops on its own when the window is full.
+    pub fn start_burst_log(&mut self, window_ms: u32) -> Result<(), CoreError> {
+        self.burst_log.start(window_ms)
+    }
+    
+    /// Log telemetry to the SD card until stopped (a running WOT log carries on in its file)
+    pub fn start_datalog(&mut self) -> Result<(), CoreError> {
+        self.datalogger.start(&mut self.hal, DatalogTrigger::Manual)
+    }
+    
+    /// Write out and close the running datalog
+    pub fn stop_datalog(&mut self) {
+        self.datalogger.stop(&mut self.hal);
+    }
+    
+    /// One block of the EEPROM flight recording from `offset`, oldest first
+    pub fn flight_record(&mut self, offset: u32) -> Result<FlightRecordChunk, CoreError> {
+        Ok(self.flight_recorder.chunk(&mut self.hal, offset)?)
+    }
+    
+    /// Overboost cuts and faults logged to EEPROM, oldest first
+    pub fn safety_log_entries(&mut self) -> Result<Vec<SafetyLogEntry>, CoreError> {
+        Ok(self.safety_log.entries(&mut self.hal)?)
+    }
+    
+    /// Erase the safety log (after the events have been reviewed)
+    pub fn clear_safety_log(&mut self) -> Result<(), CoreError> {
+        Ok(self.safety_log.clear(&mut self.hal)?)
+    }
+    
+    /// Start or stop the bench display demo
+    pub fn set_demo_mode(&mut self, active: bool) -> Result<(), CoreError> {
+        if !active {
+            self.demo.stop();
+            return Ok(());
+        }
+        
+        let rpm = self.last_inputs.as_ref().map(|inputs| inputs.rpm).unwrap_or(0);
+        if !self.on_bench(rpm) {
+            return Err(CoreError::InvalidState(format!(
+                "Demo mode needs the engine stopped and the system idle (state {}, {} RPM)", self.state.display_text(), rpm
+            )));
+        }
+        self.demo.start(self.hal.now_ms());
+        Ok(())
+    }
+    
+    /// Describe what the display is currently showing (display mirroring)
+    pub fn describe_screen(&self) -> ScreenDescription {
+        let now_ms = self.hal.now_ms();
+        if let Some(demo) = self.demo.screen(now_ms, &self.config, self.configurations.language()) {
+            return demo;
+        }
+        let boost_psi = self.last_inputs.as_ref().map(|i| i.manifold_pressure).unwrap_or(0.0);
+        let output_stage = self.solenoid_failover.status();
+        
+        ScreenDescription::build(&ScreenInputs {
+            state: &self.state,
+            config: &self.config,
+            control_mode: self.control_mode_in_effect(),
+            aggression: self.aggression_input.active().value,
+            boost_psi,
+            target_psi: self.last_target_boost_psi,
+            output_stage: &output_stage,
+            can_health: self.can_health.level(now_ms),
+            live_tune_active: self.live_tune.is_volatile(),
+            map_sensor_advisory: self.map_zero.status().advisory.is_some(),
+            bank_imbalance: self.bank_balance.flagged(),
+            confirmation_code: self.presence.display_code(),
+            maintenance_due: self.maintenance_due().first().map(|reminder| reminder.name.as_str()),
+            headroom: self.headroom.as_ref(),
+            exhaust_backpressure_psi: self.turbine_protection.status().backpressure_psi,
+            turbine_protection_active: self.turbine_protection.boost_cap_psi().is_some(),
+            latest_event: self.events.latest(),
+            language: self.configurations.language(),
+            timestamp_ms: now_ms,
+        })
+    }
+    
+    /// Draw the current screen on the display
+    /// 
+    /// Called from the main loop at the display refresh rate, outside the
+    /// control cycle. The frame is the one `describe_screen` mirrors.
+    pub fn refresh_display(&mut self) -> Result<(), CoreError> {
+        let screen = self.describe_screen();
+        render_screen(&mut self.hal, &screen)?;
+        Ok(())
+    }
+    
+    /// Headline values of the most recent control cycle (live telemetry stream)
+    pub fn telemetry_sample(&self) -> TelemetrySample {
+        TelemetrySample::capture(
+            &self.state,
+            self.last_inputs.as_ref(),
+            self.last_target_boost_psi,
+            self.actuator.current_authority(&self.hal),
+            self.aggression_input.active(),
+        )
+    }
+    
+    /// Current value of a watchable internal variable
+    pub fn read_watch(&self, variable: WatchVariable) -> WatchValue {
+        let rpm = self.last_inputs.as_ref().map(|inputs| inputs.rpm).unwrap_or(0);
+        let now_ms = self.last_inputs.as_ref().map(|inputs| inputs.timestamp_ms).unwrap_or(0);
+        
+        match variable {
+            WatchVariable::Level1TargetPsi => WatchValue::Number(self.level1_target_boost_psi),
+            WatchVariable::TargetPsi => WatchValue::Number(self.last_target_boost_psi),
+            WatchVariable::DutyPercent => WatchValue::Number(self.actuator.current_authority(&self.hal)),
+            WatchVariable::DutyCeilingPercent => self.duty_ceiling.ceiling_percent(rpm)
+                .map(WatchValue::Number)
+                .unwrap_or(WatchValue::Unavailable),
+            WatchVariable::MapSource => WatchValue::Source(self.map_arbiter.selected()),
+            WatchVariable::MapZeroPsi => WatchValue::Number(self.map_zero.zero_psi()),
+            WatchVariable::CanAuthority => WatchValue::Number(self.can_health.level(now_ms).torque_authority()),
+            WatchVariable::ThermalDerate => WatchValue::Number(self.thermal_derate.multiplier()),
+            WatchVariable::ExhaustBackpressurePsi => self.turbine_protection.status().backpressure_psi
+                .map(WatchValue::Number)
+                .unwrap_or(WatchValue::Unavailable),
+            WatchVariable::BackpressureRatio => self.turbine_protection.status().ratio
+                .map(WatchValue::Number)
+                .unwrap_or(WatchValue::Unavailable),
+            WatchVariable::TurboShaftRpm => self.turbo_speed.status().shaft_rpm
+                .map(WatchValue::Number)
+                .unwrap_or(WatchValue::Unavailable),
+            WatchVariable::SoftCeiling => WatchValue::Number(self.soft_ceiling_authority()),
+            WatchVariable::IdleVenting => WatchValue::Flag(self.idle_vent.is_venting()),
+            WatchVariable::CycleTimeUs => WatchValue::Number(self.stats.avg_cycle_time_us as f32),
+            WatchVariable::EngineLoadPercent => self.can_inputs.engine_load()
+                .filter(|(_, at_ms)| now_ms.wrapping_sub(*at_ms) <= MAX_LOSS_THRESHOLD_MS)
+                .map(|(load, _)| WatchValue::Number(load))
+                .unwrap_or(WatchValue::Unavailable),
+            WatchVariable::BoostTrimPsi => match self.dome_control.status() {
+                status if status.active => WatchValue::Number(status.boost_trim_psi),
+                _ => WatchValue::Unavailable,
+            },
+        }
+    }
+    
+    /// Above-spring target authority the soft ceiling allows at the latest boost (1.0 when not tapering)
+    /// 
+    /// Only meaningful while the control hierarchy is running.
+    pub fn soft_ceiling_authority(&self) -> f32 {
+        match (&self.state, &self.last_inputs) {
+            (SystemState::Armed, Some(inputs)) if !self.idle_vent.is_venting() => {
+                self.config.soft_ceiling.authority(inputs.manifold_pressure, self.limits_in_force().overboost_limit_psi)
+            },
+            _ => 1.0,
+        }
+    }
+    
+    /// Current values of the watched variables, in order
+    pub fn watch_readings(&self, variables: &[WatchVariable]) -> Vec<WatchReading> {
+        variables
+            .iter()
+            .map(|&variable| WatchReading { variable, value: self.read_watch(variable) })
+            .collect()
+    }
+    
+    /// Rename the device
+    /// 
+    /// Caller is responsible for writing the configuration library to storage.
+    pub fn set_device_name(&mut self, name: &str) -> Result<(), CoreError> {
+        self.configurations.set_device_name(name)
+    }
+    
+    /// Select the display language
+    /// 
+    /// Caller is responsible for writing the configuration library to storage.
+    pub fn set_language(&mut self, language: Language) {
+        self.configurations.set_language(language);
+    }
+    
+    /// Identification advertised to connecting clients
+    pub fn identity(&self) -> DeviceIdentity {
+        DeviceIdentity {
+            device_name: self.configurations.device_name().to_string(),
+            firmware: self.firmware_info(),
+            active_configuration: self.configurations.active_name().to_string(),
+        }
+    }
+    
+    fn firmware_info(&self) -> FirmwareInfo {
+        let platform = self.hal.get_platform_info();
+        FirmwareInfo {
+            firmware_version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: platform.platform_name.to_string(),
+            platform_version: platform.version.to_string(),
+        }
+    }
+    
+    /// Outputs of the most recent control cycle, as compared by golden traces
+    pub fn cycle_outputs(&self) -> CycleOutputs {
+        CycleOutputs {
+            state: self.state.clone(),
+            target_boost_psi: self.last_target_boost_psi,
+            duty_percent: self.actuator.current_authority(&self.hal),
+        }
+    }
+    
+    /// Gather everything needed to diagnose a field report
+    pub fn support_bundle(&self) -> SupportBundle {
+        // Running config may hold changes not yet written back to the library
+        let mut configurations = self.configurations.clone();
+        let active = configurations.active().name.clone();
+        let _ = configurations.save(&active, self.config_to_store());
+        
+        SupportBundle {
+            bundle_version: SUPPORT_BUNDLE_VERSION,
+            firmware: self.firmware_info(),
+            configurations,
+            status: self.get_system_status(),
+            fault_history: self.fault_history.records().to_vec(),
+            last_freeze_frame: self.fault_history.last_freeze_frame().cloned(),
+            overboost_events: self.overboost.records().to_vec(),
+            sessions: self.sessions.recent(),
+            storage: self.storage_health.clone(),
+        }
+    }
+    
+    /// Gain changes suggested by this drive's boost tracking
+    /// 
+    /// Relative to the tuning in effect. Apply them as live-tune test values so
+    /// they revert unless committed. Empty while the cascaded dome control is
+    /// off, since the gains act only on its boost loop.
+    pub fn gain_suggestions(&self) -> Vec<GainSuggestion> {
+        if !self.config.dome_control.enabled {
+            return Vec::new();
+        }
+        self.sessions.current().tracking.suggestions(self.live_tune.active())
+    }
+    
+    /// Torque gap and boost error distributions for this drive and recent sessions
+    pub fn distribution_report(&self) -> DistributionReport {
+        let sessions = self.sessions.recent();
+        let mut recent = GapDistribution::default();
+        for session in &sessions {
+            recent.merge(&session.distribution);
+        }
+        
+        DistributionReport {
+            current: self.sessions.current().distribution.clone(),
+            recent,
+            sessions: sessions.len() as u32,
+        }
+    }
+    
+    /// Weekly and monthly trends, filed from `day` on (days since 1970-01-01, from the client's clock)
+    pub fn trend_report(&mut self, day: u32) -> TrendReport {
+        self.trends.set_day(day);
+        self.trends.report()
+    }
+    
     /// Get current system status for diagnostics
     pub fn get_system_status(&self) -> SystemStatus {
         SystemStatus {
             state: self.state.clone(),
             config: self.config.clone(),
+            device_name: self.configurations.device_name().to_string(),
+            active_configuration: self.configurations.active_name().to_string(),
+            spring_only: self.spring_only_reason(),
+            control_mode: self.control_mode_in_effect(),
+            obd_fallback_active: self.can_inputs.obd_fallback_active(self.hal.now_ms()),
             stats: self.stats.clone(),
             uptime_ms: self.hal.now_ms(),
+            actuator_type: self.actuator.actuator_type(),
+            output_stage: self.solenoid_failover.status(),
+            can_health: self.can_health.report(self.hal.now_ms()),
+            map_source: self.map_arbiter.status(),
+            aggression_input: self.aggression_input.status(),
+            rpm_validation: self.rpm_validator.stats().clone(),
+            map_zero: self.map_zero.status(),
+            overshoot: self.overshoot_detector.stats().clone(),
+            idle_vent: self.idle_vent.status(),
+            solenoid_exercise: self.solenoid_exercise.status().clone(),
+            commissioning: self.commissioning.status(),
+            headroom: self.headroom.clone(),
+            learning: self.learning_gate.stats().clone(),
+            usage: self.usage.counters().clone(),
+            maintenance_due: self.maintenance_due().iter().map(|reminder| reminder.name.clone()).collect(),
+            events: self.events.stats().clone(),
+            recent_events: self.events.recent(),
+            tracking_score: self.sessions.current().tracking.score(),
+            gain_suggestions: self.gain_suggestions(),
+            gain_suggestions_withheld: !self.config.dome_control.enabled,
+            last_overboost: self.overboost.last().cloned(),
+            duty_ceiling: self.duty_ceiling.status(),
+            supply_voltage: self.hal.supply_voltage().ok(),
+            thermal_derate: self.thermal_derate.status(),
+            turbine_protection: self.turbine_protection.status().clone(),
+            turbo_speed: self.turbo_speed.status().clone(),
+            density_compensation: self.density_compensation.status().clone(),
+            gear: self.gear_estimator.status().clone(),
+            dome_control: self.dome_control.status().clone(),
+            shakedown: self.shakedown.status(),
+            soft_ceiling_active: self.soft_ceiling_authority() < 1.0,
+            bank_balance: self.bank_balance.status(),
+            actuation: self.actuation_monitor.status(),
+            flight_recorder: self.flight_recorder.status(),
+            safety_log: self.safety_log.status(),
+            safety_limits: self.safety_limits.status(SafetyLimits::of(&self.config)),
+            performance_tier: self.tier_governor.status().clone(),
+            telemetry_sinks: self.telemetry_sinks.status(),
+            datalog: self.datalogger.status(),
+            spring_check: self.spring_check_status(),
+            learned_data: self.learned_data.status(),
+            pending_restart: self.pending_restart(),
         }
     }
 }
@@ -327,10 +2447,104 @@ impl<H: HalTrait> RumbleDomeCore<H> {
 /// 
 /// 🔗 T4-CORE-009: System Status Reporting
 /// Derived From: Diagnostic and monitoring requirements
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemStatus {
     pub state: SystemState,
     pub config: SystemConfig,
+    /// User-assigned device name
+    pub device_name: String,
+    /// Name of the active named configuration
+    pub active_configuration: String,
+    /// Why the wastegate is on its spring alone (`None` while boost is controlled or idle)
+    pub spring_only: Option<SpringOnlyReason>,
+    /// Level 1 control mode in effect
+    pub control_mode: ControlMode,
+    /// OBD-II polling is standing in for missing broadcast torque
+    pub obd_fallback_active: bool,
     pub stats: ControlLoopStats,
     pub uptime_ms: u32,
+    /// Wastegate actuator hardware being driven
+    pub actuator_type: ActuatorType,
+    /// Active solenoid topology and degradation level
+    pub output_stage: OutputStageStatus,
+    /// CAN bus health score and per-message timing statistics
+    pub can_health: CanHealthReport,
+    /// Manifold pressure source arbitration status
+    pub map_source: SourceArbitrationStatus,
+    /// Aggression source in control and each input's value
+    pub aggression_input: AggressionInputStatus,
+    /// RPM plausibility validation counters
+    pub rpm_validation: RpmValidationStats,
+    /// Analog MAP auto-zero and drift tracking
+    pub map_zero: MapZeroStatus,
+    /// Spool overshoot detection statistics
+    pub overshoot: OvershootStats,
+    /// Idle dome venting state and counters
+    pub idle_vent: IdleVentStatus,
+    /// Solenoid self-cleaning exercise state and log
+    pub solenoid_exercise: SolenoidExerciseStatus,
+    /// First-run commissioning progress
+    pub commissioning: CommissioningStatus,
+    /// Margin to each limit in the most recent control cycle
+    pub headroom: Option<Headroom>,
+    /// Accepted and rejected learning sample counters
+    pub learning: LearningGateStats,
+    /// Lifetime operating hours and solenoid actuation counts
+    pub usage: UsageCounters,
+    /// Names of maintenance reminders currently due
+    pub maintenance_due: Vec<String>,
+    /// Event bus counters
+    pub events: EventBusStats,
+    /// Most recently dispatched events, oldest first
+    pub recent_events: Vec<TimedEvent>,
+    /// Boost tracking score for this drive (0-100), once boost has been held on target
+    pub tracking_score: Option<u8>,
+    /// Gain changes suggested by this drive's tracking
+    pub gain_suggestions: Vec<GainSuggestion>,
+    /// No gain suggestions are offered - the gains act only on the cascaded dome control's boost loop, which is off
+    pub gain_suggestions_withheld: bool,
+    /// Most recent overboost event with its likely cause
+    pub last_overboost: Option<OverboostEvent>,
+    /// Learned duty ceiling per RPM band
+    pub duty_ceiling: DutyCeilingStatus,
+    /// Supply voltage at the solenoid drivers (V), if the platform measures it
+    pub supply_voltage: Option<f32>,
+    /// Boost derate from intake air and coolant temperature
+    pub thermal_derate: ThermalDerateStatus,
+    /// Exhaust backpressure reading and boost limit from the backpressure ratio
+    pub turbine_protection: TurbineProtectionStatus,
+    /// Estimated turbo shaft speed and boost limit from the overspeed rule
+    pub turbo_speed: TurboSpeedStatus,
+    /// Ambient conditions and the duty and boost target corrections for air density
+    pub density_compensation: DensityCompensationStatus,
+    /// Estimated gear and the boost target scale it applies
+    pub gear: GearStatus,
+    /// Dome pressure setpoint, measurement and commanded duty of the cascaded loop
+    pub dome_control: DomeControlStatus,
+    /// Shake-down countdown and startup delay after power-up
+    pub shakedown: ShakedownStatus,
+    /// Soft ceiling is tapering the boost target near the overboost limit
+    pub soft_ceiling_active: bool,
+    /// Twin turbo bank shortfall and imbalance advisory
+    pub bank_balance: BankBalanceStatus,
+    /// Actuation effectiveness and solenoid wear / dome leak advisory
+    pub actuation: ActuationStatus,
+    /// EEPROM flight recorder counters
+    pub flight_recorder: FlightRecorderStatus,
+    /// Safety event log counters
+    pub safety_log: SafetyLogStatus,
+    /// Redundant overboost limit and duty cap copies
+    pub safety_limits: SafetyLimitsStatus,
+    /// Background work tier and the headroom and temperature driving it
+    pub performance_tier: PerformanceTierStatus,
+    /// Frames written and failed per telemetry sink
+    pub telemetry_sinks: Vec<TelemetrySinkStatus>,
+    /// SD card datalog file and counters
+    pub datalog: DatalogStatus,
+    /// Wastegate spring check progress or last result
+    pub spring_check: SpringCheckStatus,
+    /// Learned duty map counters
+    pub learned_data: LearnedDataStatus,
+    /// Stored parameter changes that take effect at the next boot
+    pub pending_restart: Vec<StagedChange>,
 }
\ No newline at end of file
@@ -0,0 +1,372 @@
+//! Live Parameter Tuning
+//! 
+//! 🔗 T4-CORE-032: Volatile Live-Tune Session
+//! Derived From: Protocols.md system configuration commands + SY-11 (bounded adjustments)
+//! AI Traceability: Immediate-effect test values, explicit commit, automatic revert on timeout/disconnect
+
+use alloc::format;
+use heapless::Vec;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Default inactivity timeout before volatile test values revert (ms)
+pub const DEFAULT_LIVE_TUNE_TIMEOUT_MS: u32 = 120_000;
+
+/// Maximum relative deviation of a test value from its stored value
+/// Large jumps must be made in several committed steps
+pub const MAX_TEST_VALUE_DEVIATION: f32 = 0.5;
+
+/// Number of tunable parameters
+pub const TUNE_PARAMETER_COUNT: usize = 6;
+
+/// Tunable control parameters
+/// 
+/// 🔗 T4-CORE-033: Tunable Control Parameters
+/// Derived From: Implementation.md system-derived parameters (torque target percentage, boost slew rate)
+/// Defaults are the system-derived values; users only touch these via live-tune.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TuningParameters {
+    /// Cascaded dome control boost loop proportional gain (dome PSI per PSI of boost error)
+    pub pid_kp: f32,
+    /// Cascaded dome control boost loop integral gain (dome PSI per PSI·s of boost error)
+    pub pid_ki: f32,
+    /// Cascaded dome control boost loop derivative gain (dome PSI per PSI/s of boost rise)
+    pub pid_kd: f32,
+    /// Fastest the cascaded dome control's boost target rises (PSI/second)
+    pub boost_slew_rate: f32,
+    /// Torque gap that triggers boost assistance (Nm)
+    pub torque_gap_threshold_nm: f32,
+    /// Fraction of ECU torque ceiling to target (0.0-1.0)
+    pub torque_ceiling_fraction: f32,
+}
+
+impl Default for TuningParameters {
+    fn default() -> Self {
+        Self {
+            pid_kp: 0.5,
+            pid_ki: 1.0,
+            pid_kd: 0.05,
+            boost_slew_rate: 2.0,
+            torque_gap_threshold_nm: 10.0,
+            torque_ceiling_fraction: 0.95,
+        }
+    }
+}
+
+impl TuningParameters {
+    /// Get parameter value
+    pub fn get(&self, parameter: TuneParameter) -> f32 {
+        match parameter {
+            TuneParameter::PidKp => self.pid_kp,
+            TuneParameter::PidKi => self.pid_ki,
+            TuneParameter::PidKd => self.pid_kd,
+            TuneParameter::BoostSlewRate => self.boost_slew_rate,
+            TuneParameter::TorqueGapThreshold => self.torque_gap_threshold_nm,
+            TuneParameter::TorqueCeilingFraction => self.torque_ceiling_fraction,
+        }
+    }
+    
+    fn set(&mut self, parameter: TuneParameter, value: f32) {
+        match parameter {
+            TuneParameter::PidKp => self.pid_kp = value,
+            TuneParameter::PidKi => self.pid_ki = value,
+            TuneParameter::PidKd => self.pid_kd = value,
+            TuneParameter::BoostSlewRate => self.boost_slew_rate = value,
+            TuneParameter::TorqueGapThreshold => self.torque_gap_threshold_nm = value,
+            TuneParameter::TorqueCeilingFraction => self.torque_ceiling_fraction = value,
+        }
+    }
+    
+    /// Validate every parameter against its hard bounds
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for parameter in TuneParameter::ALL {
+            parameter.check_bounds(self.get(parameter))?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifier for a tunable parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TuneParameter {
+    /// Boost PID proportional gain
+    PidKp,
+    /// Boost PID integral gain
+    PidKi,
+    /// Boost PID derivative gain
+    PidKd,
+    /// Boost target slew rate
+    BoostSlewRate,
+    /// Torque gap assistance threshold
+    TorqueGapThreshold,
+    /// Fraction of ECU torque ceiling targeted
+    TorqueCeilingFraction,
+}
+
+impl TuneParameter {
+    /// All tunable parameters
+    pub const ALL: [TuneParameter; TUNE_PARAMETER_COUNT] = [
+        TuneParameter::PidKp,
+        TuneParameter::PidKi,
+        TuneParameter::PidKd,
+        TuneParameter::BoostSlewRate,
+        TuneParameter::TorqueGapThreshold,
+        TuneParameter::TorqueCeilingFraction,
+    ];
+    
+    /// Short name for reports
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            TuneParameter::PidKp => "Kp",
+            TuneParameter::PidKi => "Ki",
+            TuneParameter::PidKd => "Kd",
+            TuneParameter::BoostSlewRate => "boost slew rate",
+            TuneParameter::TorqueGapThreshold => "torque gap threshold",
+            TuneParameter::TorqueCeilingFraction => "torque ceiling fraction",
+        }
+    }
+    
+    /// Whether the parameter acts only on the cascaded dome control's boost loop
+    pub fn dome_control_only(&self) -> bool {
+        matches!(self, TuneParameter::PidKp | TuneParameter::PidKi | TuneParameter::PidKd | TuneParameter::BoostSlewRate)
+    }
+    
+    /// Hard (min, max) bounds - never exceeded by stored or test values
+    pub fn bounds(&self) -> (f32, f32) {
+        match self {
+            TuneParameter::PidKp => (0.0, 5.0),
+            TuneParameter::PidKi => (0.0, 10.0),
+            TuneParameter::PidKd => (0.0, 1.0),
+            TuneParameter::BoostSlewRate => (0.5, 10.0),
+            TuneParameter::TorqueGapThreshold => (2.0, 50.0),
+            // Never target past the ECU ceiling - harsh interventions follow
+            TuneParameter::TorqueCeilingFraction => (0.80, 0.98),
+        }
+    }
+    
+    fn check_bounds(&self, value: f32) -> Result<(), CoreError> {
+        let (min, max) = self.bounds();
+        if !value.is_finite() || value < min || value > max {
+            return Err(CoreError::ConfigurationError(
+                format!("{:?} must be {}-{}, got {}", self, min, max, value)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Live-tune status for protocol responses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LiveTuneStatus {
+    /// Whether any volatile (uncommitted) test values are in effect
+    pub volatile: bool,
+    /// Parameters currently differing from stored values
+    pub modified: Vec<TuneParameter, TUNE_PARAMETER_COUNT>,
+    /// Values currently in effect
+    pub active: TuningParameters,
+    /// Time remaining before automatic revert (ms), 0 when not volatile
+    pub revert_in_ms: u32,
+    /// Number of automatic reverts since startup
+    pub auto_reverts: u32,
+}
+
+/// Live-tune session
+/// 
+/// 🔗 T4-CORE-034: Live-Tune Session Management
+/// Derived From: T1-SAFETY-002 (Defense in Depth) - experimental values never outlive the session
+/// 
+/// Test values take effect immediately but are never persisted by this type.
+/// `commit()` hands back the parameter set for the caller to store; anything
+/// not committed reverts on timeout, disconnect, or restart.
+#[derive(Debug, Clone)]
+pub struct LiveTuneSession {
+    stored: TuningParameters,
+    active: TuningParameters,
+    last_activity_ms: u32,
+    timeout_ms: u32,
+    auto_reverts: u32,
+}
+
+impl LiveTuneSession {
+    /// Create session from persisted parameters
+    pub fn new(stored: TuningParameters) -> Self {
+        Self {
+            active: stored.clone(),
+            stored,
+            last_activity_ms: 0,
+            timeout_ms: DEFAULT_LIVE_TUNE_TIMEOUT_MS,
+            auto_reverts: 0,
+        }
+    }
+    
+    /// Parameters currently in effect (stored values overlaid with test values)
+    pub fn active(&self) -> &TuningParameters {
+        &self.active
+    }
+    
+    /// Persisted parameters
+    pub fn stored(&self) -> &TuningParameters {
+        &self.stored
+    }
+    
+    /// Whether uncommitted test values are in effect
+    pub fn is_volatile(&self) -> bool {
+        self.active != self.stored
+    }
+    
+    /// Apply a volatile test value
+    /// 
+    /// Value must be within hard bounds and within `MAX_TEST_VALUE_DEVIATION`
+    /// of the stored value.
+    pub fn set_test_value(&mut self, parameter: TuneParameter, value: f32, now_ms: u32) -> Result<(), CoreError> {
+        parameter.check_bounds(value)?;
+        
+        let stored = self.stored.get(parameter);
+        let max_delta = libm::fabsf(stored) * MAX_TEST_VALUE_DEVIATION;
+        // Parameters stored at zero may be brought up to a small fraction of their range
+        let (min, max) = parameter.bounds();
+        let max_delta = max_delta.max((max - min) * 0.1);
+        
+        if libm::fabsf(value - stored) > max_delta {
+            return Err(CoreError::ConfigurationError(
+                format!("{:?} test value {} deviates more than {} from stored {}", parameter, value, max_delta, stored)
+            ));
+        }
+        
+        self.active.set(parameter, value);
+        self.last_activity_ms = now_ms;
+        Ok(())
+    }
+    
+    /// Refresh the inactivity timer (client still connected and tuning)
+    pub fn keepalive(&mut self, now_ms: u32) {
+        self.last_activity_ms = now_ms;
+    }
+    
+    /// Make test values permanent, returning the parameter set to persist
+    pub fn commit(&mut self) -> TuningParameters {
+        self.stored = self.active.clone();
+        self.stored.clone()
+    }
+    
+    /// Discard test values and restore stored values
+    pub fn revert(&mut self) {
+        self.active = self.stored.clone();
+    }
+    
+    /// Tuning client disconnected - volatile values must not outlive the session
+    pub fn on_disconnect(&mut self) {
+        if self.is_volatile() {
+            self.revert();
+            self.auto_reverts += 1;
+        }
+    }
+    
+    /// Periodic update - reverts test values after inactivity timeout
+    /// 
+    /// Returns true if a revert happened this call.
+    pub fn tick(&mut self, now_ms: u32) -> bool {
+        if self.is_volatile() && now_ms.wrapping_sub(self.last_activity_ms) >= self.timeout_ms {
+            self.revert();
+            self.auto_reverts += 1;
+            return true;
+        }
+        false
+    }
+    
+    /// Status snapshot for protocol responses
+    pub fn status(&self, now_ms: u32) -> LiveTuneStatus {
+        let mut modified = Vec::new();
+        for parameter in TuneParameter::ALL {
+            if self.active.get(parameter) != self.stored.get(parameter) {
+                // Capacity equals parameter count - cannot overflow
+                let _ = modified.push(parameter);
+            }
+        }
+        
+        let revert_in_ms = if self.is_volatile() {
+            self.timeout_ms.saturating_sub(now_ms.wrapping_sub(self.last_activity_ms))
+        } else {
+            0
+        };
+        
+        LiveTuneStatus {
+            volatile: self.is_volatile(),
+            modified,
+            active: self.active.clone(),
+            revert_in_ms,
+            auto_reverts: self.auto_reverts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_default_parameters_within_bounds() {
+        assert!(TuningParameters::default().validate().is_ok());
+    }
+    
+    #[test]
+    fn test_test_value_takes_effect_without_persisting() {
+        let mut session = LiveTuneSession::new(TuningParameters::default());
+        
+        session.set_test_value(TuneParameter::PidKp, 0.7, 1000).unwrap();
+        assert_eq!(session.active().pid_kp, 0.7);
+        assert_eq!(session.stored().pid_kp, 0.5);
+        assert!(session.is_volatile());
+        assert_eq!(session.status(1000).modified.as_slice(), &[TuneParameter::PidKp]);
+    }
+    
+    #[test]
+    fn test_test_values_are_bounded() {
+        let mut session = LiveTuneSession::new(TuningParameters::default());
+        
+        // Outside hard bounds
+        assert!(session.set_test_value(TuneParameter::TorqueCeilingFraction, 1.05, 0).is_err());
+        // Within bounds but too far from stored value
+        assert!(session.set_test_value(TuneParameter::PidKp, 2.0, 0).is_err());
+        assert!(!session.is_volatile());
+    }
+    
+    #[test]
+    fn test_commit_makes_values_permanent() {
+        let mut session = LiveTuneSession::new(TuningParameters::default());
+        
+        session.set_test_value(TuneParameter::BoostSlewRate, 2.8, 0).unwrap();
+        let persisted = session.commit();
+        assert_eq!(persisted.boost_slew_rate, 2.8);
+        assert!(!session.is_volatile());
+        
+        // Committed values survive timeout and disconnect
+        assert!(!session.tick(DEFAULT_LIVE_TUNE_TIMEOUT_MS * 2));
+        session.on_disconnect();
+        assert_eq!(session.active().boost_slew_rate, 2.8);
+    }
+    
+    #[test]
+    fn test_timeout_reverts_test_values() {
+        let mut session = LiveTuneSession::new(TuningParameters::default());
+        
+        session.set_test_value(TuneParameter::PidKi, 1.2, 1000).unwrap();
+        assert!(!session.tick(1000 + DEFAULT_LIVE_TUNE_TIMEOUT_MS - 1));
+        
+        session.keepalive(50_000);
+        assert!(!session.tick(1000 + DEFAULT_LIVE_TUNE_TIMEOUT_MS));
+        assert!(session.tick(50_000 + DEFAULT_LIVE_TUNE_TIMEOUT_MS));
+        assert_eq!(session.active().pid_ki, 1.0);
+        assert_eq!(session.status(0).auto_reverts, 1);
+    }
+    
+    #[test]
+    fn test_disconnect_reverts_test_values() {
+        let mut session = LiveTuneSession::new(TuningParameters::default());
+        
+        session.set_test_value(TuneParameter::TorqueGapThreshold, 12.0, 0).unwrap();
+        session.on_disconnect();
+        assert_eq!(session.active(), session.stored());
+    }
+}
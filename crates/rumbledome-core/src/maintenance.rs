@@ -0,0 +1,218 @@
+//! Usage Metering and Maintenance Reminders
+//! 
+//! 🔗 T4-CORE-045: Power-On Hour Meter
+//! Derived From: Hardware.md MAC solenoid service life + pneumatic system maintenance needs
+//! AI Traceability: Operating hours and solenoid actuation counts drive user maintenance reminders
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::constants::{PWM_FREQUENCY_HZ, MIN_DUTY_RESOLUTION};
+use crate::CoreError;
+
+/// Maximum number of configured maintenance reminders
+pub const MAX_MAINTENANCE_REMINDERS: usize = 8;
+
+const MS_PER_HOUR: u64 = 3_600_000;
+
+/// Lifetime usage counters (persisted by the storage layer)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageCounters {
+    /// Total powered-on time (ms)
+    pub powered_ms: u64,
+    /// Time with the solenoid being modulated (ms)
+    pub boost_active_ms: u64,
+    /// Solenoid open/close actuations (one per PWM period while modulating)
+    pub actuation_cycles: u64,
+}
+
+impl UsageCounters {
+    /// Powered-on hours
+    pub fn powered_hours(&self) -> u32 {
+        (self.powered_ms / MS_PER_HOUR) as u32
+    }
+    
+    /// Boost-active hours
+    pub fn boost_active_hours(&self) -> u32 {
+        (self.boost_active_ms / MS_PER_HOUR) as u32
+    }
+}
+
+/// What a maintenance interval is measured in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MaintenanceInterval {
+    /// Powered-on hours
+    Hours(u32),
+    /// Solenoid actuation cycles
+    ActuationCycles(u64),
+}
+
+/// User-configurable maintenance reminder
+/// 
+/// 🔗 T4-CORE-046: Maintenance Reminder Definition
+/// Derived From: T4-CORE-045
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MaintenanceReminder {
+    /// Short task description shown on the display
+    pub name: String,
+    /// Service interval
+    pub interval: MaintenanceInterval,
+    /// Usage reading (in interval units) when last serviced
+    #[serde(default)]
+    pub last_serviced_at: u64,
+}
+
+impl MaintenanceReminder {
+    /// Usage reading in this reminder's interval units
+    fn reading(&self, usage: &UsageCounters) -> u64 {
+        match self.interval {
+            MaintenanceInterval::Hours(_) => usage.powered_hours() as u64,
+            MaintenanceInterval::ActuationCycles(_) => usage.actuation_cycles,
+        }
+    }
+    
+    fn interval_units(&self) -> u64 {
+        match self.interval {
+            MaintenanceInterval::Hours(hours) => hours as u64,
+            MaintenanceInterval::ActuationCycles(cycles) => cycles,
+        }
+    }
+    
+    /// Reminder is due
+    pub fn is_due(&self, usage: &UsageCounters) -> bool {
+        self.reading(usage).saturating_sub(self.last_serviced_at) >= self.interval_units()
+    }
+    
+    /// Record service at the current usage reading
+    pub fn mark_serviced(&mut self, usage: &UsageCounters) {
+        self.last_serviced_at = self.reading(usage);
+    }
+}
+
+/// Default reminders for a new installation
+pub fn default_maintenance_reminders() -> Vec<MaintenanceReminder> {
+    alloc::vec![MaintenanceReminder {
+        name: String::from("Inspect dome lines"),
+        interval: MaintenanceInterval::Hours(200),
+        last_serviced_at: 0,
+    }]
+}
+
+/// Validate a reminder list
+pub fn validate_maintenance_reminders(reminders: &[MaintenanceReminder]) -> Result<(), CoreError> {
+    if reminders.len() > MAX_MAINTENANCE_REMINDERS {
+        return Err(CoreError::ConfigurationError(
+            format!("At most {} maintenance reminders supported, got {}", MAX_MAINTENANCE_REMINDERS, reminders.len())
+        ));
+    }
+    
+    for reminder in reminders {
+        if reminder.name.is_empty() || reminder.name.len() > 24 {
+            return Err(CoreError::ConfigurationError(
+                format!("Maintenance reminder name must be 1-24 characters, got \"{}\"", reminder.name)
+            ));
+        }
+        if reminder.interval_units() == 0 {
+            return Err(CoreError::ConfigurationError(
+                format!("Maintenance reminder \"{}\" interval must be non-zero", reminder.name)
+            ));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Usage meter
+/// 
+/// 🔗 T4-CORE-047: Usage Accumulation
+/// Derived From: T4-CORE-045 + T2-PWM-001 (30 Hz PWM)
+#[derive(Debug, Clone, Default)]
+pub struct UsageMeter {
+    counters: UsageCounters,
+    last_update_ms: Option<u32>,
+    /// Fractional actuation cycles carried between updates (milli-cycles)
+    cycle_remainder: u64,
+}
+
+impl UsageMeter {
+    /// Create meter starting from zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume from persisted counters
+    pub fn restore(&mut self, counters: UsageCounters) {
+        self.counters = counters;
+        self.last_update_ms = None;
+        self.cycle_remainder = 0;
+    }
+    
+    /// Current counters
+    pub fn counters(&self) -> &UsageCounters {
+        &self.counters
+    }
+    
+    /// Accumulate usage since the last update
+    pub fn update(&mut self, now_ms: u32, duty_percent: f32) {
+        let elapsed_ms = match self.last_update_ms {
+            Some(last) => now_ms.wrapping_sub(last),
+            None => 0,
+        };
+        self.last_update_ms = Some(now_ms);
+        self.counters.powered_ms += elapsed_ms as u64;
+        
+        // 0% and 100% hold the valve in one position - no actuation wear
+        let modulating = (MIN_DUTY_RESOLUTION..=100.0 - MIN_DUTY_RESOLUTION).contains(&duty_percent);
+        if modulating {
+            self.counters.boost_active_ms += elapsed_ms as u64;
+            
+            self.cycle_remainder += PWM_FREQUENCY_HZ as u64 * elapsed_ms as u64;
+            self.counters.actuation_cycles += self.cycle_remainder / 1000;
+            self.cycle_remainder %= 1000;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_actuation_cycles_follow_pwm_frequency() {
+        let mut meter = UsageMeter::new();
+        meter.update(0, 50.0);
+        for t in 1..=100 {
+            meter.update(t * 10, 50.0);
+        }
+        
+        // 1 second at 30 Hz
+        assert_eq!(meter.counters().actuation_cycles, 30);
+        assert_eq!(meter.counters().boost_active_ms, 1000);
+    }
+    
+    #[test]
+    fn test_failsafe_duty_is_not_actuation() {
+        let mut meter = UsageMeter::new();
+        meter.update(0, 0.0);
+        meter.update(1000, 0.0);
+        
+        assert_eq!(meter.counters().powered_ms, 1000);
+        assert_eq!(meter.counters().actuation_cycles, 0);
+    }
+    
+    #[test]
+    fn test_reminder_due_and_serviced() {
+        let mut reminder = default_maintenance_reminders().remove(0);
+        let mut usage = UsageCounters { powered_ms: 199 * MS_PER_HOUR, ..Default::default() };
+        assert!(!reminder.is_due(&usage));
+        
+        usage.powered_ms = 200 * MS_PER_HOUR;
+        assert!(reminder.is_due(&usage));
+        
+        reminder.mark_serviced(&usage);
+        assert!(!reminder.is_due(&usage));
+    }
+}
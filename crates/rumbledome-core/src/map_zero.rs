@@ -0,0 +1,353 @@
+//! Ambient MAP Auto-Zero
+//! 
+//! 🔗 T4-CORE-051: Pre-Arm MAP Auto-Zero
+//! Derived From: Hardware.md boost gauge sensor specification + T1-SAFETY-002 (Defense in Depth)
+//! AI Traceability: Key-on/engine-off atmospheric capture as gauge zero, zero drift tracking across power cycles
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Reading must stay within this band for the whole capture window (PSI)
+const STABILITY_BAND_PSI: f32 = 0.1;
+
+/// Length of a stable capture window (ms)
+const CAPTURE_WINDOW_MS: u32 = 500;
+
+/// Auto-zero settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MapZeroConfig {
+    /// Capture the zero automatically at key-on
+    pub enabled: bool,
+    /// Zero drift from the installation baseline that raises an advisory (PSI)
+    pub drift_advisory_psi: f32,
+    /// Largest reading at atmosphere accepted as a zero (PSI)
+    /// A capture beyond this is a failed sensor or a running engine, not an offset
+    pub max_zero_offset_psi: f32,
+}
+
+impl Default for MapZeroConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            drift_advisory_psi: 0.5,
+            max_zero_offset_psi: 2.0,
+        }
+    }
+}
+
+impl MapZeroConfig {
+    /// Validate auto-zero settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(0.1..=2.0).contains(&self.drift_advisory_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("MAP zero drift advisory must be 0.1-2.0 PSI, got {}", self.drift_advisory_psi)
+            ));
+        }
+        
+        if self.max_zero_offset_psi < self.drift_advisory_psi || self.max_zero_offset_psi > 5.0 {
+            return Err(CoreError::ConfigurationError(
+                format!("MAP max zero offset must be {}-5.0 PSI, got {}", self.drift_advisory_psi, self.max_zero_offset_psi)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Zero capture history (persisted by the storage layer)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MapZeroRecord {
+    /// First accepted zero since installation or last baseline reset (PSI)
+    pub baseline_psi: Option<f32>,
+    /// Most recent accepted zero (PSI)
+    pub latest_psi: Option<f32>,
+    /// Accepted captures since the baseline was set
+    pub captures: u32,
+    /// Largest drift from baseline seen (PSI)
+    pub max_drift_psi: f32,
+}
+
+impl MapZeroRecord {
+    /// Drift of the latest zero from the baseline (PSI)
+    pub fn drift_psi(&self) -> f32 {
+        match (self.baseline_psi, self.latest_psi) {
+            (Some(baseline), Some(latest)) => latest - baseline,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Auto-zero progress for this power cycle
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MapZeroPhase {
+    /// Waiting for a stable reading with the engine off
+    Capturing,
+    /// Zero captured this power cycle
+    Captured,
+    /// Capture rejected as implausible - previous zero kept
+    Rejected,
+    /// Engine started (or system armed) before a stable capture - previous zero kept
+    Skipped,
+    /// Auto-zero disabled in configuration
+    Disabled,
+}
+
+/// Sensor health advisory raised by auto-zero
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MapZeroAdvisory {
+    /// Zero has drifted from the installation baseline
+    DriftExceeded { drift_psi: f32 },
+    /// Reading at atmosphere too far from zero to be an offset
+    ZeroOutOfRange { reading_psi: f32 },
+}
+
+/// Auto-zero status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapZeroStatus {
+    /// Progress for this power cycle
+    pub phase: MapZeroPhase,
+    /// Zero offset currently applied (PSI)
+    pub zero_psi: f32,
+    /// Capture history
+    pub record: MapZeroRecord,
+    /// Sensor health advisory, if any
+    pub advisory: Option<MapZeroAdvisory>,
+}
+
+/// Ambient MAP auto-zero
+/// 
+/// 🔗 T4-CORE-052: MAP Zero Drift Tracking
+/// Derived From: T4-CORE-051
+/// 
+/// The boost sensor is gauge-referenced, so with the engine off it should read
+/// zero regardless of weather or altitude. Whatever it reads at atmosphere is its
+/// offset, and any movement of that offset between power cycles is sensor drift.
+#[derive(Debug, Clone)]
+pub struct MapAutoZero {
+    config: MapZeroConfig,
+    phase: MapZeroPhase,
+    record: MapZeroRecord,
+    advisory: Option<MapZeroAdvisory>,
+    window_start_ms: Option<u32>,
+    window_min: f32,
+    window_max: f32,
+    window_sum: f32,
+    window_samples: u32,
+}
+
+impl MapAutoZero {
+    /// Create auto-zero with no capture history
+    pub fn new(config: MapZeroConfig) -> Self {
+        let phase = if config.enabled { MapZeroPhase::Capturing } else { MapZeroPhase::Disabled };
+        
+        Self {
+            config,
+            phase,
+            record: MapZeroRecord::default(),
+            advisory: None,
+            window_start_ms: None,
+            window_min: 0.0,
+            window_max: 0.0,
+            window_sum: 0.0,
+            window_samples: 0,
+        }
+    }
+    
+    /// Resume from persisted capture history (previous zero applies until a new capture)
+    pub fn restore(&mut self, record: MapZeroRecord) {
+        self.record = record;
+        self.check_drift();
+    }
+    
+    /// Capture history to persist
+    pub fn record(&self) -> &MapZeroRecord {
+        &self.record
+    }
+    
+    /// Zero offset currently applied (PSI)
+    pub fn zero_psi(&self) -> f32 {
+        if self.config.enabled { self.record.latest_psi.unwrap_or(0.0) } else { 0.0 }
+    }
+    
+    /// Convert a raw sensor reading to gauge pressure (PSI)
+    pub fn apply(&self, raw_psi: f32) -> f32 {
+        raw_psi - self.zero_psi()
+    }
+    
+    /// Forget the baseline after the sensor has been replaced
+    pub fn reset_baseline(&mut self) {
+        self.record = MapZeroRecord {
+            baseline_psi: self.record.latest_psi,
+            latest_psi: self.record.latest_psi,
+            captures: 0,
+            max_drift_psi: 0.0,
+        };
+        self.advisory = None;
+    }
+    
+    /// Apply changed settings, keeping capture history
+    pub fn reconfigure(&mut self, config: MapZeroConfig) {
+        if !config.enabled {
+            self.phase = MapZeroPhase::Disabled;
+        }
+        self.config = config;
+        if matches!(self.advisory, Some(MapZeroAdvisory::DriftExceeded { .. })) {
+            self.advisory = None;
+        }
+        self.check_drift();
+    }
+    
+    /// Feed one raw reading while waiting for the capture
+    /// 
+    /// `pre_arm` must only be true before boost control has been armed this power
+    /// cycle. `rpm` is `None` while engine speed is unknown (e.g. ECU still booting) -
+    /// capture waits rather than risk zeroing against a running engine.
+    pub fn update(&mut self, raw_psi: f32, rpm: Option<u16>, pre_arm: bool, now_ms: u32) {
+        if self.phase != MapZeroPhase::Capturing {
+            return;
+        }
+        
+        match rpm {
+            Some(0) if pre_arm => {},
+            None if pre_arm => {
+                self.window_start_ms = None;
+                return;
+            },
+            _ => {
+                self.phase = MapZeroPhase::Skipped;
+                return;
+            },
+        }
+        
+        let start_ms = match self.window_start_ms {
+            Some(start_ms) if (raw_psi - self.window_min).abs() <= STABILITY_BAND_PSI
+                && (raw_psi - self.window_max).abs() <= STABILITY_BAND_PSI => start_ms,
+            // Unstable (ignition noise, door slam, pump priming) - restart the window
+            _ => {
+                self.window_start_ms = Some(now_ms);
+                self.window_min = raw_psi;
+                self.window_max = raw_psi;
+                self.window_sum = 0.0;
+                self.window_samples = 0;
+                now_ms
+            },
+        };
+        
+        self.window_min = self.window_min.min(raw_psi);
+        self.window_max = self.window_max.max(raw_psi);
+        self.window_sum += raw_psi;
+        self.window_samples += 1;
+        
+        if now_ms.wrapping_sub(start_ms) >= CAPTURE_WINDOW_MS {
+            self.finish_capture(self.window_sum / self.window_samples as f32);
+        }
+    }
+    
+    /// Current status
+    pub fn status(&self) -> MapZeroStatus {
+        MapZeroStatus {
+            phase: self.phase,
+            zero_psi: self.zero_psi(),
+            record: self.record.clone(),
+            advisory: self.advisory,
+        }
+    }
+    
+    fn finish_capture(&mut self, reading_psi: f32) {
+        if reading_psi.abs() > self.config.max_zero_offset_psi {
+            self.phase = MapZeroPhase::Rejected;
+            self.advisory = Some(MapZeroAdvisory::ZeroOutOfRange { reading_psi });
+            return;
+        }
+        
+        self.phase = MapZeroPhase::Captured;
+        if self.record.baseline_psi.is_none() {
+            self.record.baseline_psi = Some(reading_psi);
+        }
+        self.record.latest_psi = Some(reading_psi);
+        self.record.captures += 1;
+        self.record.max_drift_psi = self.record.max_drift_psi.max(self.record.drift_psi().abs());
+        self.advisory = None;
+        self.check_drift();
+    }
+    
+    fn check_drift(&mut self) {
+        let drift_psi = self.record.drift_psi();
+        if self.config.enabled && drift_psi.abs() > self.config.drift_advisory_psi {
+            self.advisory = Some(MapZeroAdvisory::DriftExceeded { drift_psi });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn capture(zero: &mut MapAutoZero, reading: f32) {
+        for t in 0..=60 {
+            zero.update(reading, Some(0), true, t * 10);
+        }
+    }
+    
+    #[test]
+    fn test_stable_reading_becomes_zero() {
+        let mut zero = MapAutoZero::new(MapZeroConfig::default());
+        capture(&mut zero, 0.2);
+        
+        assert_eq!(zero.status().phase, MapZeroPhase::Captured);
+        assert!((zero.apply(10.2) - 10.0).abs() < 1e-4);
+        assert!((zero.record().baseline_psi.unwrap() - 0.2).abs() < 1e-4);
+    }
+    
+    #[test]
+    fn test_engine_start_skips_capture_and_keeps_previous_zero() {
+        let mut zero = MapAutoZero::new(MapZeroConfig::default());
+        zero.restore(MapZeroRecord { baseline_psi: Some(0.1), latest_psi: Some(0.15), captures: 4, max_drift_psi: 0.05 });
+        
+        zero.update(0.3, Some(0), true, 0);
+        zero.update(-8.0, Some(750), true, 10);
+        
+        assert_eq!(zero.status().phase, MapZeroPhase::Skipped);
+        assert_eq!(zero.zero_psi(), 0.15);
+    }
+    
+    #[test]
+    fn test_drift_across_power_cycles_raises_advisory() {
+        let mut record = MapZeroRecord::default();
+        for reading in [0.10, 0.25, 0.45, 0.70] {
+            let mut zero = MapAutoZero::new(MapZeroConfig::default());
+            zero.restore(record);
+            capture(&mut zero, reading);
+            record = zero.record().clone();
+            
+            let drifted = reading - 0.10 > 0.5;
+            assert_eq!(zero.status().advisory.is_some(), drifted);
+        }
+        assert_eq!(record.captures, 4);
+    }
+    
+    #[test]
+    fn test_unknown_rpm_waits() {
+        let mut zero = MapAutoZero::new(MapZeroConfig::default());
+        for t in 0..=60 {
+            zero.update(0.2, None, true, t * 10);
+        }
+        assert_eq!(zero.status().phase, MapZeroPhase::Capturing);
+        
+        capture(&mut zero, 0.2);
+        assert_eq!(zero.status().phase, MapZeroPhase::Captured);
+    }
+    
+    #[test]
+    fn test_implausible_zero_rejected() {
+        let mut zero = MapAutoZero::new(MapZeroConfig::default());
+        capture(&mut zero, 4.0);
+        
+        let status = zero.status();
+        assert_eq!(status.phase, MapZeroPhase::Rejected);
+        assert_eq!(status.zero_psi, 0.0);
+        assert_eq!(status.advisory, Some(MapZeroAdvisory::ZeroOutOfRange { reading_psi: 4.0 }));
+    }
+}
@@ -0,0 +1,694 @@
+//! Named Configurations
+//! 
+//! 🔗 T4-CORE-048: Named Configuration Library
+//! Derived From: T4-CORE-010 (Configuration Implementation) + hardware swap workflow
+//! AI Traceability: Complete configurations per hardware setup (e.g. "stock turbo" vs "new turbo") with quick switching
+//! 
+//! A configuration can inherit from another and store only the fields it
+//! changes (e.g. "track e85" inherits "track" but raises max boost). Inherited
+//! entries are resolved against their parent chain whenever the library
+//! changes, so `config` is always the full configuration that would run.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use rumbledome_hal::NonVolatileStorage;
+use crate::{default_device_name, RecordJournal, validate_device_name, CoreError, Language, ProfileRule, SystemConfig, MAX_SCHEDULE_RULES, SPRING_ONLY_PROFILE, spring_only_config};
+
+/// Maximum number of stored named configurations
+pub const MAX_NAMED_CONFIGS: usize = 4;
+
+/// Maximum configuration name length (characters)
+pub const MAX_CONFIG_NAME_LEN: usize = 24;
+
+/// Name given to the configuration of a fresh installation
+pub const DEFAULT_CONFIG_NAME: &str = "default";
+
+/// Complete system configuration stored under a user-chosen name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NamedConfig {
+    /// Configuration name (e.g. "stock turbo")
+    pub name: String,
+    /// Configuration this one inherits from (`None` when standalone)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Fields changed from the parent, as a partial configuration
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub overrides: Map<String, Value>,
+    /// Full system configuration (resolved through the parent chain when inherited)
+    pub config: SystemConfig,
+}
+
+impl NamedConfig {
+    /// Storage namespace for learned data belonging to this configuration
+    /// 
+    /// Learned calibration describes one physical turbo/wastegate setup, so it is
+    /// never shared between configurations.
+    pub fn learned_data_namespace(&self) -> String {
+        format!("learned/{}", self.name)
+    }
+}
+
+/// Validate a configuration name
+/// 
+/// Names become part of storage keys, so only letters, digits, space, '-' and '_'
+/// are allowed. The built-in spring-only profile's name is reserved.
+pub fn validate_config_name(name: &str) -> Result<(), CoreError> {
+    if name == SPRING_ONLY_PROFILE {
+        return Err(CoreError::ConfigurationError(
+            format!("\"{}\" is the built-in spring pressure profile", name)
+        ));
+    }
+    
+    if name.is_empty() || name.len() > MAX_CONFIG_NAME_LEN {
+        return Err(CoreError::ConfigurationError(
+            format!("Configuration name must be 1-{} characters, got \"{}\"", MAX_CONFIG_NAME_LEN, name)
+        ));
+    }
+    
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '_') {
+        return Err(CoreError::ConfigurationError(
+            format!("Configuration name may only contain letters, digits, space, '-' and '_', got \"{}\"", name)
+        ));
+    }
+    
+    Ok(())
+}
+
+/// Library of named configurations with one active entry
+/// 
+/// 🔗 T4-CORE-049: Configuration Quick Switching
+/// Derived From: T4-CORE-048
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigLibrary {
+    /// User-assigned device name
+    /// 
+    /// Stored with the library rather than in `SystemConfig` - the name belongs to
+    /// the controller and must not change when switching configurations.
+    #[serde(default = "default_device_name")]
+    device_name: String,
+    /// Display language, likewise a preference of the controller
+    #[serde(default)]
+    language: Language,
+    /// Name of the active configuration
+    active: String,
+    /// Built-in spring-only profile running on the active configuration (T4-CORE-114)
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    spring_only: bool,
+    /// Configuration the driver selected, while a schedule rule runs another one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected: Option<String>,
+    /// Profile scheduling rules, first match wins (T4-CORE-110)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    schedule: Vec<ProfileRule>,
+    /// Stored configurations
+    entries: Vec<NamedConfig>,
+}
+
+impl Default for ConfigLibrary {
+    fn default() -> Self {
+        Self::single(DEFAULT_CONFIG_NAME, SystemConfig::default())
+    }
+}
+
+impl ConfigLibrary {
+    /// Library holding a single active configuration
+    pub fn single(name: &str, config: SystemConfig) -> Self {
+        Self {
+            device_name: default_device_name(),
+            language: Language::default(),
+            active: String::from(name),
+            spring_only: false,
+            selected: None,
+            schedule: Vec::new(),
+            entries: alloc::vec![NamedConfig { name: String::from(name), parent: None, overrides: Map::new(), config }],
+        }
+    }
+    
+    /// User-assigned device name
+    pub fn device_name(&self) -> &str {
+        &self.device_name
+    }
+    
+    /// Rename the device
+    pub fn set_device_name(&mut self, name: &str) -> Result<(), CoreError> {
+        validate_device_name(name)?;
+        self.device_name = String::from(name);
+        Ok(())
+    }
+    
+    /// Display language
+    pub fn language(&self) -> Language {
+        self.language
+    }
+    
+    /// Select the display language
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+    
+    /// Name of the active configuration (`SPRING_ONLY_PROFILE` while that runs)
+    pub fn active_name(&self) -> &str {
+        if self.spring_only { SPRING_ONLY_PROFILE } else { &self.active }
+    }
+    
+    /// Active configuration entry
+    /// 
+    /// While the spring-only profile runs this is the entry it runs on.
+    pub fn active(&self) -> &NamedConfig {
+        self.get(&self.active).expect("active configuration always present")
+    }
+    
+    /// Whether the built-in spring-only profile is selected
+    pub fn is_spring_only(&self) -> bool {
+        self.spring_only
+    }
+    
+    /// Stored configuration names, in creation order
+    pub fn names(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+    
+    /// Look up a configuration by name
+    pub fn get(&self, name: &str) -> Option<&NamedConfig> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+    
+    /// Whether `name` can be selected - a stored configuration or the built-in profile
+    pub fn contains(&self, name: &str) -> bool {
+        name == SPRING_ONLY_PROFILE || self.get(name).is_some()
+    }
+    
+    /// Limits selecting `name` would run with, for comparing against the running ones
+    /// 
+    /// The spring-only profile's are those of the active entry capped at spring pressure.
+    pub fn limits_of(&self, name: &str) -> Option<SystemConfig> {
+        if name == SPRING_ONLY_PROFILE {
+            return Some(spring_only_config(&self.active().config));
+        }
+        self.get(name).map(|entry| entry.config.clone())
+    }
+    
+    /// Store a configuration, replacing any existing entry with the same name
+    /// 
+    /// An inherited entry stays inherited: the fields that differ from its
+    /// parent become its overrides. Configurations inheriting from this one
+    /// are resolved again.
+    pub fn save(&mut self, name: &str, config: SystemConfig) -> Result<(), CoreError> {
+        validate_config_name(name)?;
+        config.validate()?;
+        
+        let mut updated = self.clone();
+        match updated.entries.iter().position(|entry| entry.name == name) {
+            Some(index) => {
+                if let Some(parent) = updated.entries[index].parent.clone() {
+                    let base = to_value(&updated.resolved(&parent)?)?;
+                    updated.entries[index].overrides = changed_fields(&base, &to_value(&config)?);
+                }
+                updated.entries[index].config = config;
+            },
+            None => updated.push(NamedConfig { name: String::from(name), parent: None, overrides: Map::new(), config })?,
+        }
+        
+        updated.resolve()?;
+        *self = updated;
+        Ok(())
+    }
+    
+    /// Store a configuration that inherits from `parent`, changing only the given fields
+    /// 
+    /// `overrides` is a partial configuration: nested sections only need the
+    /// fields that change (e.g. `{"tuning": {"pid_ki": 0.3}}`).
+    pub fn save_inherited(&mut self, name: &str, parent: &str, overrides: Map<String, Value>) -> Result<(), CoreError> {
+        validate_config_name(name)?;
+        let base = self.resolved(parent)?;
+        
+        let mut updated = self.clone();
+        let entry = NamedConfig { name: String::from(name), parent: Some(String::from(parent)), overrides, config: base };
+        match updated.entries.iter().position(|entry| entry.name == name) {
+            Some(index) => updated.entries[index] = entry,
+            None => updated.push(entry)?,
+        }
+        
+        updated.resolve()?;
+        *self = updated;
+        Ok(())
+    }
+    
+    /// Resolve every inherited configuration against its parent chain
+    /// 
+    /// Run after loading a library from storage. Fails on a missing parent,
+    /// an inheritance cycle, an override naming no configuration field, or a
+    /// resolved configuration that does not validate.
+    pub fn resolve(&mut self) -> Result<(), CoreError> {
+        let resolved = self.entries.iter()
+            .map(|entry| self.resolved(&entry.name))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (entry, config) in self.entries.iter_mut().zip(resolved) {
+            entry.config = config;
+        }
+        Ok(())
+    }
+    
+    /// Full configuration of an entry, following its parent chain
+    pub fn resolved(&self, name: &str) -> Result<SystemConfig, CoreError> {
+        let mut chain: Vec<&NamedConfig> = Vec::new();
+        let mut next = Some(name);
+        while let Some(name) = next {
+            if chain.iter().any(|entry| entry.name == name) {
+                let path: Vec<&str> = chain.iter().map(|entry| entry.name.as_str()).chain([name]).collect();
+                return Err(CoreError::ConfigurationError(
+                    format!("Configuration inheritance cycle: {}", path.join(" -> "))
+                ));
+            }
+            let entry = self.get(name)
+                .ok_or_else(|| CoreError::ConfigurationError(format!("No configuration named \"{}\"", name)))?;
+            chain.push(entry);
+            next = entry.parent.as_deref();
+        }
+        
+        // The root is standalone; each descendant applies its overrides on top
+        let mut chain = chain.into_iter().rev();
+        let root = chain.next().expect("chain holds at least the requested entry");
+        let mut config = to_value(&root.config)?;
+        for entry in chain {
+            apply_overrides(&mut config, &entry.overrides, &entry.name)?;
+        }
+        
+        let config: SystemConfig = serde_json::from_value(config).map_err(|e| CoreError::ConfigurationError(
+            format!("Configuration \"{}\" does not resolve: {}", name, e)
+        ))?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Whether `name` is `ancestor` or inherits from it, directly or through other entries
+    pub fn inherits_from(&self, name: &str, ancestor: &str) -> bool {
+        let mut next = Some(name);
+        // Bounded so a cycle in an unvalidated library cannot loop forever
+        for _ in 0..=self.entries.len() {
+            let Some(current) = next else { return false };
+            if current == ancestor {
+                return true;
+            }
+            next = self.get(current).and_then(|entry| entry.parent.as_deref());
+        }
+        false
+    }
+    
+    /// Add a new entry, within the library size limit
+    fn push(&mut self, entry: NamedConfig) -> Result<(), CoreError> {
+        if self.entries.len() >= MAX_NAMED_CONFIGS {
+            return Err(CoreError::ConfigurationError(
+                format!("At most {} named configurations supported", MAX_NAMED_CONFIGS)
+            ));
+        }
+        
+        self.entries.push(entry);
+        Ok(())
+    }
+    
+    /// Make a stored configuration active as the driver's selection
+    /// 
+    /// Selecting the spring-only profile keeps the active entry - the profile
+    /// runs on its hardware settings.
+    pub fn select(&mut self, name: &str) -> Result<&NamedConfig, CoreError> {
+        if !self.contains(name) {
+            return Err(CoreError::ConfigurationError(format!("No configuration named \"{}\"", name)));
+        }
+        
+        self.spring_only = name == SPRING_ONLY_PROFILE;
+        if !self.spring_only {
+            self.active = String::from(name);
+        }
+        self.selected = None;
+        Ok(self.active())
+    }
+    
+    /// Make a stored configuration active on behalf of a schedule rule
+    /// 
+    /// The driver's selection is remembered so the schedule can return to it.
+    pub fn select_scheduled(&mut self, name: &str) -> Result<&NamedConfig, CoreError> {
+        let selected = String::from(self.driver_selection());
+        self.select(name)?;
+        if selected != name {
+            self.selected = Some(selected);
+        }
+        Ok(self.active())
+    }
+    
+    /// Configuration the driver selected (differs from the active one while a schedule rule applies)
+    pub fn driver_selection(&self) -> &str {
+        self.selected.as_deref().unwrap_or(self.active_name())
+    }
+    
+    /// Profile scheduling rules
+    pub fn schedule(&self) -> &[ProfileRule] {
+        &self.schedule
+    }
+    
+    /// Replace the profile scheduling rules
+    pub fn set_schedule(&mut self, rules: Vec<ProfileRule>) -> Result<(), CoreError> {
+        self.validate_schedule(&rules)?;
+        self.schedule = rules;
+        Ok(())
+    }
+    
+    /// Validate scheduling rules against the stored configurations
+    fn validate_schedule(&self, rules: &[ProfileRule]) -> Result<(), CoreError> {
+        if rules.len() > MAX_SCHEDULE_RULES {
+            return Err(CoreError::ConfigurationError(
+                format!("At most {} schedule rules supported", MAX_SCHEDULE_RULES)
+            ));
+        }
+        
+        for rule in rules {
+            rule.validate()?;
+            if !self.contains(&rule.config) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Schedule rule names unknown configuration \"{}\"", rule.config)
+                ));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Delete a stored configuration (not the active one, nor one that others inherit from)
+    pub fn remove(&mut self, name: &str) -> Result<NamedConfig, CoreError> {
+        if name == SPRING_ONLY_PROFILE {
+            return Err(CoreError::InvalidState(
+                format!("Cannot delete \"{}\": it is built in", name)
+            ));
+        }
+        
+        if name == self.active {
+            return Err(CoreError::InvalidState(
+                format!("Cannot delete active configuration \"{}\"", name)
+            ));
+        }
+        
+        if name == self.driver_selection() {
+            return Err(CoreError::InvalidState(
+                format!("Cannot delete \"{}\": it is the driver's selection", name)
+            ));
+        }
+        
+        if self.schedule.iter().any(|rule| rule.config == name) {
+            return Err(CoreError::InvalidState(
+                format!("Cannot delete \"{}\": a schedule rule runs it", name)
+            ));
+        }
+        
+        if let Some(child) = self.entries.iter().find(|entry| entry.parent.as_deref() == Some(name)) {
+            return Err(CoreError::InvalidState(
+                format!("Cannot delete \"{}\": \"{}\" inherits from it", name, child.name)
+            ));
+        }
+        
+        let index = self.entries.iter()
+            .position(|entry| entry.name == name)
+            .ok_or_else(|| CoreError::ConfigurationError(format!("No configuration named \"{}\"", name)))?;
+        Ok(self.entries.remove(index))
+    }
+    
+    /// Validate a library loaded from storage
+    pub fn validate(&self) -> Result<(), CoreError> {
+        validate_device_name(&self.device_name)?;
+        
+        if self.entries.is_empty() || self.entries.len() > MAX_NAMED_CONFIGS {
+            return Err(CoreError::ConfigurationError(
+                format!("Configuration library must hold 1-{} entries, got {}", MAX_NAMED_CONFIGS, self.entries.len())
+            ));
+        }
+        
+        for (index, entry) in self.entries.iter().enumerate() {
+            validate_config_name(&entry.name)?;
+            if self.entries[..index].iter().any(|other| other.name == entry.name) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Duplicate configuration name \"{}\"", entry.name)
+                ));
+            }
+            entry.config.validate()?;
+            self.resolved(&entry.name)?;
+        }
+        
+        if self.get(&self.active).is_none() {
+            return Err(CoreError::ConfigurationError(
+                format!("Active configuration \"{}\" not found", self.active)
+            ));
+        }
+        
+        if !self.contains(self.driver_selection()) {
+            return Err(CoreError::ConfigurationError(
+                format!("Selected configuration \"{}\" not found", self.driver_selection())
+            ));
+        }
+        
+        self.validate_schedule(&self.schedule)?;
+        
+        Ok(())
+    }
+}
+
+/// Fields of `config` that differ from the defaults, as a partial configuration
+/// 
+/// The running configuration is stored on EEPROM in this form: the full JSON
+/// is larger than the whole EEPROM, while a car's changes from the defaults
+/// fit a copy of the configuration record.
+pub fn changes_from_defaults(config: &SystemConfig) -> Result<Map<String, Value>, CoreError> {
+    Ok(changed_fields(&to_value(&SystemConfig::default())?, &to_value(config)?))
+}
+
+/// The defaults with a partial configuration applied
+pub fn config_from_changes(changes: &Map<String, Value>) -> Result<SystemConfig, CoreError> {
+    let mut config = to_value(&SystemConfig::default())?;
+    apply_overrides(&mut config, changes, "stored")?;
+    let config: SystemConfig = serde_json::from_value(config).map_err(|e| CoreError::ConfigurationError(
+        format!("Stored configuration does not resolve: {}", e)
+    ))?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Running configuration last written to the EEPROM configuration record
+/// 
+/// For boot code choosing what to start with when there is no SD card;
+/// `None` when no record was ever written.
+pub fn load_stored_config<S: NonVolatileStorage>(storage: &mut S) -> Result<Option<SystemConfig>, CoreError> {
+    let mut journal = RecordJournal::CONFIG;
+    journal.load_value::<Map<String, Value>, S>(storage)?
+        .map(|changes| config_from_changes(&changes))
+        .transpose()
+}
+
+/// Configuration as a JSON object, for overlaying and comparing fields
+fn to_value(config: &SystemConfig) -> Result<Value, CoreError> {
+    serde_json::to_value(config)
+        .map_err(|e| CoreError::ConfigurationError(format!("JSON serialization failed: {}", e)))
+}
+
+/// Overlay a partial configuration; nested objects merge field by field
+fn apply_overrides(base: &mut Value, overrides: &Map<String, Value>, name: &str) -> Result<(), CoreError> {
+    for (field, value) in overrides {
+        // A misspelled field would otherwise be dropped silently when deserializing
+        let Some(current) = base.get_mut(field) else {
+            return Err(CoreError::ConfigurationError(
+                format!("Configuration \"{}\" overrides unknown field \"{}\"", name, field)
+            ));
+        };
+        match (current, value) {
+            (current @ Value::Object(_), Value::Object(nested)) => apply_overrides(current, nested, name)?,
+            (current, value) => *current = value.clone(),
+        }
+    }
+    Ok(())
+}
+
+/// Fields of `config` that differ from `base`, as a partial configuration
+fn changed_fields(base: &Value, config: &Value) -> Map<String, Value> {
+    let mut changed = Map::new();
+    let (Value::Object(base), Value::Object(config)) = (base, config) else {
+        return changed;
+    };
+    for (field, value) in config {
+        match (base.get(field), value) {
+            (Some(old), _) if old == value => {},
+            (Some(old @ Value::Object(_)), Value::Object(_)) => {
+                changed.insert(field.clone(), Value::Object(changed_fields(old, value)));
+            },
+            _ => {
+                changed.insert(field.clone(), value.clone());
+            },
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_DEVICE_NAME;
+    
+    fn new_turbo() -> SystemConfig {
+        SystemConfig { spring_pressure: 7.0, max_boost_psi: 18.0, overboost_limit: 21.0, ..Default::default() }
+    }
+    
+    #[test]
+    fn test_save_and_select() {
+        let mut library = ConfigLibrary::single("stock turbo", SystemConfig::default());
+        library.save("new turbo", new_turbo()).unwrap();
+        
+        assert_eq!(library.names(), alloc::vec!["stock turbo", "new turbo"]);
+        assert_eq!(library.active_name(), "stock turbo");
+        
+        let selected = library.select("new turbo").unwrap();
+        assert_eq!(selected.config.max_boost_psi, 18.0);
+        assert_eq!(selected.learned_data_namespace(), "learned/new turbo");
+        assert!(library.select("big turbo").is_err());
+    }
+    
+    #[test]
+    fn test_invalid_entries_rejected() {
+        let mut library = ConfigLibrary::default();
+        assert!(library.save("new/turbo", new_turbo()).is_err());
+        assert!(library.save("", new_turbo()).is_err());
+        
+        let invalid = SystemConfig { overboost_limit: 10.0, ..Default::default() };
+        assert!(library.save("new turbo", invalid).is_err());
+        
+        for name in ["a", "b", "c"] {
+            library.save(name, new_turbo()).unwrap();
+        }
+        assert!(library.save("d", new_turbo()).is_err());
+        assert!(library.validate().is_ok());
+    }
+    
+    #[test]
+    fn test_active_configuration_cannot_be_removed() {
+        let mut library = ConfigLibrary::default();
+        library.save("new turbo", new_turbo()).unwrap();
+        
+        assert!(library.remove(DEFAULT_CONFIG_NAME).is_err());
+        assert!(library.remove("new turbo").is_ok());
+        assert_eq!(library.names(), alloc::vec![DEFAULT_CONFIG_NAME]);
+    }
+    
+    #[test]
+    fn test_device_name_survives_switching() {
+        let mut library = ConfigLibrary::default();
+        assert_eq!(library.device_name(), DEFAULT_DEVICE_NAME);
+        
+        library.set_device_name("Mustang").unwrap();
+        library.save("new turbo", new_turbo()).unwrap();
+        library.select("new turbo").unwrap();
+        assert_eq!(library.device_name(), "Mustang");
+        assert!(library.set_device_name("").is_err());
+        
+        // Libraries stored before naming existed load with the default name
+        let mut json = serde_json::to_value(&library).unwrap();
+        json.as_object_mut().unwrap().remove("device_name");
+        let restored: ConfigLibrary = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.device_name(), DEFAULT_DEVICE_NAME);
+    }
+    
+    fn overrides(json: serde_json::Value) -> Map<String, Value> {
+        json.as_object().unwrap().clone()
+    }
+    
+    #[test]
+    fn test_inherited_configuration_resolves_through_parent() {
+        let mut library = ConfigLibrary::single("track", new_turbo());
+        library.save_inherited("track e85", "track", overrides(serde_json::json!({
+            "max_boost_psi": 19.0,
+            "tuning": { "pid_ki": 0.25 },
+        }))).unwrap();
+        
+        let e85 = &library.get("track e85").unwrap().config;
+        assert_eq!(e85.max_boost_psi, 19.0);
+        assert_eq!(e85.tuning.pid_ki, 0.25);
+        assert_eq!(e85.tuning.pid_kp, new_turbo().tuning.pid_kp);
+        assert_eq!(e85.spring_pressure, 7.0);
+        
+        // Parent changes reach the child; the child's own fields stay
+        library.save("track", SystemConfig { spring_pressure: 8.0, ..new_turbo() }).unwrap();
+        let e85 = &library.get("track e85").unwrap().config;
+        assert_eq!(e85.spring_pressure, 8.0);
+        assert_eq!(e85.max_boost_psi, 19.0);
+        
+        // Saving a full configuration over an inherited entry keeps only what differs
+        library.save("track e85", SystemConfig { overboost_limit: 22.0, ..e85.clone() }).unwrap();
+        let entry = library.get("track e85").unwrap();
+        assert_eq!(entry.parent.as_deref(), Some("track"));
+        assert_eq!(entry.overrides.keys().collect::<Vec<_>>(), ["max_boost_psi", "overboost_limit", "tuning"]);
+        assert_eq!(entry.overrides["tuning"], serde_json::json!({ "pid_ki": 0.25 }));
+        
+        assert!(library.inherits_from("track e85", "track"));
+        assert!(!library.inherits_from("track", "track e85"));
+        
+        // The parent cannot be deleted out from under its child
+        assert!(library.remove("track").is_err());
+        assert!(library.validate().is_ok());
+    }
+    
+    #[test]
+    fn test_broken_inheritance_rejected() {
+        let mut library = ConfigLibrary::single("street", SystemConfig::default());
+        library.save_inherited("track", "street", Map::new()).unwrap();
+        
+        let result = library.save_inherited("street", "track", Map::new());
+        assert!(matches!(result, Err(CoreError::ConfigurationError(ref message)) if message.contains("street -> track -> street")));
+        assert!(library.save_inherited("e85", "drag", Map::new()).is_err());
+        assert!(library.save_inherited("e85", "track", overrides(serde_json::json!({ "max_boost": 20.0 }))).is_err());
+        
+        // Overrides producing an invalid configuration are refused like any other
+        assert!(library.save_inherited("e85", "track", overrides(serde_json::json!({ "overboost_limit": 1.0 }))).is_err());
+        assert_eq!(library.names(), alloc::vec!["street", "track"]);
+        assert!(library.get("street").unwrap().parent.is_none());
+    }
+    
+    #[test]
+    fn test_changes_from_defaults_round_trip() {
+        assert!(changes_from_defaults(&SystemConfig::default()).unwrap().is_empty());
+        
+        let changes = changes_from_defaults(&new_turbo()).unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(config_from_changes(&changes).unwrap(), new_turbo());
+        
+        let mut invalid = changes.clone();
+        invalid.insert(String::from("overboost_limit"), serde_json::json!(5.0));
+        assert!(config_from_changes(&invalid).is_err());
+    }
+    
+    #[test]
+    fn test_spring_only_profile_is_built_in() {
+        let mut library = ConfigLibrary::single("stock turbo", SystemConfig::default());
+        library.save("new turbo", new_turbo()).unwrap();
+        library.select("new turbo").unwrap();
+        
+        // Runs on the entry that was active, under its own name
+        assert_eq!(library.select(SPRING_ONLY_PROFILE).unwrap().name, "new turbo");
+        assert!(library.is_spring_only());
+        assert_eq!(library.active_name(), SPRING_ONLY_PROFILE);
+        assert_eq!(library.driver_selection(), SPRING_ONLY_PROFILE);
+        assert_eq!(library.limits_of(SPRING_ONLY_PROFILE).unwrap().max_boost_psi, 7.0);
+        
+        // Neither saved over nor deleted, and its entry stays while it runs
+        assert!(library.save(SPRING_ONLY_PROFILE, SystemConfig::default()).is_err());
+        assert!(library.save_inherited(SPRING_ONLY_PROFILE, "stock turbo", Map::new()).is_err());
+        assert!(matches!(library.remove(SPRING_ONLY_PROFILE), Err(CoreError::InvalidState(_))));
+        assert!(library.remove("new turbo").is_err());
+        assert_eq!(library.names(), alloc::vec!["stock turbo", "new turbo"]);
+        
+        // Survives storage
+        let restored: ConfigLibrary = serde_json::from_str(&serde_json::to_string(&library).unwrap()).unwrap();
+        assert!(restored.validate().is_ok());
+        assert_eq!(restored.active_name(), SPRING_ONLY_PROFILE);
+        
+        library.select("stock turbo").unwrap();
+        assert!(!library.is_spring_only());
+        assert!(!serde_json::to_string(&library).unwrap().contains("spring_only"));
+    }
+}
@@ -0,0 +1,227 @@
+//! Overboost Cause Classification
+//! 
+//! 🔗 T4-CORE-086: Overboost Cause Classifier
+//! Derived From: T4-CORE-053 (Fault History) + T1-SAFETY-001 (Overboost as Fault Condition)
+//! AI Traceability: Point the user at the tune, the hardware or the profile instead of just reporting "overboost"
+//! 
+//! Rules, applied to the last armed freeze frame and one from shortly before:
+//! - Duty near 0% while boost kept rising: the wastegate is not responding to
+//!   the domes - stuck, or dome lines swapped.
+//! - Target stepped up sharply: the profile or configuration asked for it.
+//! - Duty saturated high: the controller drove boost past target - gains or
+//!   tune too aggressive for the setup.
+
+use alloc::vec::Vec;
+use heapless::Deque;
+use serde::{Deserialize, Serialize};
+use crate::{FaultCode, FreezeFrame, SystemState};
+
+/// Overboost events kept (oldest dropped first)
+pub const MAX_OVERBOOST_RECORDS: usize = 8;
+
+/// Duty at or above which the controller is saturated (%)
+pub const DUTY_SATURATED_PERCENT: f32 = 90.0;
+
+/// Duty at or below which the domes are holding the wastegate open (%)
+pub const DUTY_OPEN_PERCENT: f32 = 2.0;
+
+/// Boost rise over the lead-in that counts as still climbing (PSI)
+pub const BOOST_RISE_PSI: f32 = 0.5;
+
+/// Target increase over the lead-in that counts as a sharp step (PSI)
+pub const TARGET_STEP_PSI: f32 = 3.0;
+
+/// Spacing of lead-in frames (ms)
+const LEAD_IN_INTERVAL_MS: u32 = 100;
+
+/// Lead-in frames kept - the oldest is 300-400 ms before the overboost
+const LEAD_IN_FRAMES: usize = 4;
+
+/// Likely cause of an overboost event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverboostCause {
+    /// Controller saturated high - control or tune issue
+    ControlSaturated,
+    /// Boost rose with no duty - wastegate or dome plumbing issue
+    StuckWastegate,
+    /// Target stepped up sharply - profile or configuration issue
+    TargetStep,
+    /// Freeze frame matched no rule
+    Unclassified,
+}
+
+impl OverboostCause {
+    /// Plain-language description
+    pub fn description(&self) -> &'static str {
+        match self {
+            OverboostCause::ControlSaturated => "Duty saturated high - control or tune issue",
+            OverboostCause::StuckWastegate => "Boost rose with duty at 0% - mechanical or stuck wastegate",
+            OverboostCause::TargetStep => "Boost target stepped sharply - profile or configuration issue",
+            OverboostCause::Unclassified => "Cause not identified",
+        }
+    }
+    
+    /// What the user should check
+    pub fn recommended_action(&self) -> &'static str {
+        match self {
+            OverboostCause::ControlSaturated => "Reduce aggression or PID gains and recheck boost tracking",
+            OverboostCause::StuckWastegate => "Inspect the wastegate for binding and check dome line routing",
+            OverboostCause::TargetStep => "Smooth the boost profile or lower the profile maximum",
+            OverboostCause::Unclassified => "Save a support bundle and review the freeze frame",
+        }
+    }
+}
+
+/// One classified overboost occurrence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverboostEvent {
+    /// Last armed control cycle before the overboost response
+    pub freeze_frame: FreezeFrame,
+    /// Boost change over the lead-in (PSI)
+    pub boost_rise_psi: f32,
+    /// Target change over the lead-in (PSI)
+    pub target_step_psi: f32,
+    /// Classification
+    pub cause: OverboostCause,
+}
+
+impl OverboostEvent {
+    /// Classify from a lead-in frame and the last frame before the overboost
+    pub fn classify(earlier: &FreezeFrame, freeze_frame: FreezeFrame) -> Self {
+        let boost_rise_psi = freeze_frame.manifold_pressure - earlier.manifold_pressure;
+        let target_step_psi = freeze_frame.target_boost_psi - earlier.target_boost_psi;
+        
+        let cause = if freeze_frame.duty_percent <= DUTY_OPEN_PERCENT && boost_rise_psi >= BOOST_RISE_PSI {
+            OverboostCause::StuckWastegate
+        } else if target_step_psi >= TARGET_STEP_PSI {
+            OverboostCause::TargetStep
+        } else if freeze_frame.duty_percent >= DUTY_SATURATED_PERCENT {
+            OverboostCause::ControlSaturated
+        } else {
+            OverboostCause::Unclassified
+        };
+        
+        Self { freeze_frame, boost_rise_psi, target_step_psi, cause }
+    }
+}
+
+/// Watches for overboost responses and classifies each one
+/// 
+/// The overboost response forces 0% duty, so the frame captured on entry
+/// would always look like a stuck wastegate. Frames are therefore kept from
+/// the cycles before it.
+#[derive(Debug, Clone, Default)]
+pub struct OverboostClassifier {
+    lead_in: Deque<FreezeFrame, LEAD_IN_FRAMES>,
+    previous: Option<FreezeFrame>,
+    in_overboost: bool,
+    records: Vec<OverboostEvent>,
+}
+
+impl OverboostClassifier {
+    /// Create classifier with no history
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Classified events, oldest first
+    pub fn records(&self) -> &[OverboostEvent] {
+        &self.records
+    }
+    
+    /// Most recent classified event
+    pub fn last(&self) -> Option<&OverboostEvent> {
+        self.records.last()
+    }
+    
+    /// Observe one control cycle; returns the event when an overboost response begins
+    pub fn observe(&mut self, state: &SystemState, frame: FreezeFrame) -> Option<OverboostEvent> {
+        let overboost = matches!(state,
+            SystemState::OverboostCut | SystemState::Fault(FaultCode::OverboostLimitExceeded { .. }));
+        let entered = overboost && !self.in_overboost;
+        self.in_overboost = overboost;
+        
+        if overboost {
+            let event = entered.then(|| {
+                let last = self.previous.take().unwrap_or(frame);
+                let earlier = self.lead_in.front().cloned().unwrap_or_else(|| last.clone());
+                OverboostEvent::classify(&earlier, last)
+            });
+            self.lead_in.clear();
+            self.previous = None;
+            
+            if let Some(event) = &event {
+                if self.records.len() >= MAX_OVERBOOST_RECORDS {
+                    self.records.remove(0);
+                }
+                self.records.push(event.clone());
+            }
+            return event;
+        }
+        
+        let due = self.lead_in.back()
+            .map(|last| frame.timestamp_ms.wrapping_sub(last.timestamp_ms) >= LEAD_IN_INTERVAL_MS)
+            .unwrap_or(true);
+        if due {
+            if self.lead_in.is_full() {
+                self.lead_in.pop_front();
+            }
+            let _ = self.lead_in.push_back(frame.clone());
+        }
+        self.previous = Some(frame);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn frame(timestamp_ms: u32, boost: f32, target: f32, duty: f32) -> FreezeFrame {
+        FreezeFrame {
+            timestamp_ms,
+            rpm: 4500,
+            desired_torque: 420.0,
+            actual_torque: 400.0,
+            manifold_pressure: boost,
+            dome_input_pressure: 60.0,
+            upper_dome_pressure: 4.0,
+            lower_dome_pressure: 10.0,
+            target_boost_psi: target,
+            duty_percent: duty,
+            aggression: 0.5,
+            scramble_active: false,
+        }
+    }
+    
+    #[test]
+    fn test_classification_rules() {
+        let earlier = frame(0, 10.0, 10.0, 50.0);
+        let cause = |at: FreezeFrame| OverboostEvent::classify(&earlier, at).cause;
+        
+        assert_eq!(cause(frame(300, 14.0, 10.0, 0.0)), OverboostCause::StuckWastegate);
+        assert_eq!(cause(frame(300, 14.0, 14.0, 95.0)), OverboostCause::TargetStep);
+        assert_eq!(cause(frame(300, 14.0, 11.0, 95.0)), OverboostCause::ControlSaturated);
+        assert_eq!(cause(frame(300, 14.0, 11.0, 60.0)), OverboostCause::Unclassified);
+    }
+    
+    #[test]
+    fn test_classifies_from_cycles_before_the_cut() {
+        let mut classifier = OverboostClassifier::new();
+        for t in (0..500).step_by(10) {
+            let boost = 10.0 + t as f32 / 100.0;
+            assert!(classifier.observe(&SystemState::Armed, frame(t, boost, 10.0, 0.0)).is_none());
+        }
+        
+        // The cut itself commands 0% duty - classification must use the armed frames
+        let event = classifier.observe(&SystemState::OverboostCut, frame(500, 15.0, 10.0, 0.0)).unwrap();
+        assert_eq!(event.freeze_frame.timestamp_ms, 490);
+        assert_eq!(event.cause, OverboostCause::StuckWastegate);
+        assert!(classifier.observe(&SystemState::OverboostCut, frame(510, 15.0, 10.0, 0.0)).is_none());
+        
+        classifier.observe(&SystemState::Armed, frame(520, 12.0, 10.0, 95.0));
+        classifier.observe(&SystemState::OverboostCut, frame(530, 15.0, 10.0, 0.0));
+        assert_eq!(classifier.records().len(), 2);
+        assert_eq!(classifier.last().unwrap().cause, OverboostCause::ControlSaturated);
+    }
+}
@@ -0,0 +1,290 @@
+//! Spool Overshoot Detection
+//! 
+//! 🔗 T4-CORE-043: Target Overshoot Detector
+//! Derived From: T2-CONTROL-003 (3-Level Control Hierarchy) + learned calibration convergence needs
+//! AI Traceability: Attribute spool overshoot to operating points and issue bounded learned-cell corrections
+
+use heapless::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Boost must be this far below target for a spool event to start (PSI)
+const SPOOL_ENTRY_GAP_PSI: f32 = 1.5;
+
+/// Target drop that abandons an event - boost above a collapsed target is a
+/// throttle lift, not spool overshoot (PSI)
+const TARGET_DROP_ABORT_PSI: f32 = 1.5;
+
+/// Overshoot must exceed this before any correction is issued (PSI)
+pub const OVERSHOOT_THRESHOLD_PSI: f32 = 1.0;
+
+/// Overshoot event ends once boost is back within this band above target (PSI)
+const SETTLE_BAND_PSI: f32 = 0.3;
+
+/// Boost must have fallen this far below its peak before the event can settle (PSI)
+const SETTLE_RETREAT_PSI: f32 = 0.2;
+
+/// Longest time an overshoot event is tracked after crossing target (ms)
+const MAX_EVENT_MS: u32 = 1500;
+
+/// Duty correction per PSI of overshoot above threshold (% duty)
+const CORRECTION_DUTY_PER_PSI: f32 = 2.0;
+
+/// Largest single correction (% duty) - keeps one bad event from wrecking a cell
+pub const MAX_CELL_CORRECTION_DUTY: f32 = 5.0;
+
+/// Corrections queued for the learned table
+pub const MAX_PENDING_CORRECTIONS: usize = 8;
+
+/// Bounded duty correction for the learned cell covering an operating point
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CellCorrection {
+    /// Engine RPM of the operating point
+    pub rpm: u16,
+    /// Boost target of the operating point (PSI)
+    pub boost_psi: f32,
+    /// Duty change to apply to the cell (% duty, negative = less duty)
+    pub duty_delta: f32,
+}
+
+/// Operating point captured during an overshoot event
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OperatingPoint {
+    rpm: u16,
+    target_psi: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EventPhase {
+    /// Waiting for a spool event
+    Idle,
+    /// Boost building toward target
+    Spooling {
+        max_target_psi: f32,
+    },
+    /// Boost crossed target - tracking peak overshoot
+    Overshooting {
+        max_target_psi: f32,
+        crossed_ms: u32,
+        crossing: OperatingPoint,
+        peak: OperatingPoint,
+        peak_overshoot_psi: f32,
+    },
+}
+
+/// Overshoot detector statistics (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OvershootStats {
+    /// Spool events that crossed target
+    pub spool_events: u32,
+    /// Events whose overshoot exceeded the threshold
+    pub overshoot_events: u32,
+    /// Largest overshoot observed (PSI)
+    pub worst_overshoot_psi: f32,
+    /// Corrections dropped because the queue was full
+    pub corrections_dropped: u32,
+}
+
+/// Spool overshoot detector
+/// 
+/// 🔗 T4-CORE-044: Overshoot Attribution to Learned Cells
+/// Derived From: T4-CORE-043
+/// 
+/// The duty commanded where boost crossed target was too high, so that cell
+/// takes the full correction. The cell where boost peaked takes half - the
+/// overshoot there is partly lag from the crossing cell.
+#[derive(Debug, Clone)]
+pub struct OvershootDetector {
+    phase: EventPhase,
+    pending: Vec<CellCorrection, MAX_PENDING_CORRECTIONS>,
+    stats: OvershootStats,
+}
+
+impl Default for OvershootDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OvershootDetector {
+    /// Create detector with no event in progress
+    pub fn new() -> Self {
+        Self {
+            phase: EventPhase::Idle,
+            pending: Vec::new(),
+            stats: OvershootStats::default(),
+        }
+    }
+    
+    /// Detector statistics
+    pub fn stats(&self) -> &OvershootStats {
+        &self.stats
+    }
+    
+    /// Feed one control cycle
+    pub fn update(&mut self, rpm: u16, target_psi: f32, manifold_psi: f32, now_ms: u32) {
+        let point = OperatingPoint { rpm, target_psi };
+        
+        self.phase = match self.phase {
+            EventPhase::Idle if target_psi - manifold_psi > SPOOL_ENTRY_GAP_PSI => {
+                EventPhase::Spooling { max_target_psi: target_psi }
+            },
+            EventPhase::Idle => EventPhase::Idle,
+            
+            EventPhase::Spooling { max_target_psi } | EventPhase::Overshooting { max_target_psi, .. }
+                if target_psi < max_target_psi - TARGET_DROP_ABORT_PSI => EventPhase::Idle,
+            
+            EventPhase::Spooling { max_target_psi } if manifold_psi >= target_psi => {
+                self.stats.spool_events += 1;
+                EventPhase::Overshooting {
+                    max_target_psi: max_target_psi.max(target_psi),
+                    crossed_ms: now_ms,
+                    crossing: point,
+                    peak: point,
+                    peak_overshoot_psi: manifold_psi - target_psi,
+                }
+            },
+            EventPhase::Spooling { max_target_psi } => EventPhase::Spooling {
+                max_target_psi: max_target_psi.max(target_psi),
+            },
+            
+            EventPhase::Overshooting { max_target_psi, crossed_ms, crossing, peak, peak_overshoot_psi } => {
+                let max_target_psi = max_target_psi.max(target_psi);
+                let overshoot = manifold_psi - target_psi;
+                let (peak, peak_overshoot_psi) = if overshoot > peak_overshoot_psi {
+                    (point, overshoot)
+                } else {
+                    (peak, peak_overshoot_psi)
+                };
+                
+                // Settled only once boost has peaked and come back down into the band -
+                // at the real cycle rate boost sits inside the band just after crossing
+                let settled = overshoot < SETTLE_BAND_PSI
+                    && overshoot <= peak_overshoot_psi - SETTLE_RETREAT_PSI;
+                let timed_out = now_ms.wrapping_sub(crossed_ms) >= MAX_EVENT_MS;
+                if settled || timed_out {
+                    self.finish_event(crossing, peak, peak_overshoot_psi);
+                    EventPhase::Idle
+                } else {
+                    EventPhase::Overshooting { max_target_psi, crossed_ms, crossing, peak, peak_overshoot_psi }
+                }
+            },
+        };
+    }
+    
+    /// Take all queued corrections
+    pub fn drain_corrections(&mut self) -> Vec<CellCorrection, MAX_PENDING_CORRECTIONS> {
+        core::mem::take(&mut self.pending)
+    }
+    
+    /// Abandon any event in progress (control no longer active)
+    pub fn cancel(&mut self) {
+        self.phase = EventPhase::Idle;
+    }
+    
+    fn finish_event(&mut self, crossing: OperatingPoint, peak: OperatingPoint, overshoot_psi: f32) {
+        self.stats.worst_overshoot_psi = self.stats.worst_overshoot_psi.max(overshoot_psi);
+        if overshoot_psi <= OVERSHOOT_THRESHOLD_PSI {
+            return;
+        }
+        self.stats.overshoot_events += 1;
+        
+        let delta = -((overshoot_psi - OVERSHOOT_THRESHOLD_PSI) * CORRECTION_DUTY_PER_PSI)
+            .min(MAX_CELL_CORRECTION_DUTY);
+        
+        self.queue(CellCorrection { rpm: crossing.rpm, boost_psi: crossing.target_psi, duty_delta: delta });
+        if peak != crossing {
+            self.queue(CellCorrection { rpm: peak.rpm, boost_psi: peak.target_psi, duty_delta: delta * 0.5 });
+        }
+    }
+    
+    fn queue(&mut self, correction: CellCorrection) {
+        if self.pending.push(correction).is_err() {
+            self.stats.corrections_dropped += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Drive a spool event toward 10 PSI peaking at `peak_psi`
+    fn spool_event(detector: &mut OvershootDetector, peak_psi: f32) {
+        let mut t = 0;
+        for boost in [5.0, 7.0, 9.0, 10.0] {
+            detector.update(3000 + t as u16, 10.0, boost, t);
+            t += 10;
+        }
+        detector.update(3100, 10.0, peak_psi, t);
+        detector.update(3200, 10.0, 10.1, t + 10);
+    }
+    
+    #[test]
+    fn test_large_overshoot_produces_bounded_corrections() {
+        let mut detector = OvershootDetector::new();
+        spool_event(&mut detector, 12.5);
+        
+        let corrections = detector.drain_corrections();
+        assert_eq!(corrections.len(), 2);
+        assert!((corrections[0].duty_delta + 3.0).abs() < 1e-4);
+        assert!((corrections[1].duty_delta + 1.5).abs() < 1e-4);
+        assert_eq!(corrections[1].rpm, 3100);
+        assert_eq!(detector.stats().overshoot_events, 1);
+    }
+    
+    #[test]
+    fn test_correction_clamped() {
+        let mut detector = OvershootDetector::new();
+        spool_event(&mut detector, 20.0);
+        
+        let corrections = detector.drain_corrections();
+        assert_eq!(corrections[0].duty_delta, -MAX_CELL_CORRECTION_DUTY);
+    }
+    
+    #[test]
+    fn test_small_overshoot_ignored() {
+        let mut detector = OvershootDetector::new();
+        spool_event(&mut detector, 10.6);
+        
+        assert!(detector.drain_corrections().is_empty());
+        assert_eq!(detector.stats().spool_events, 1);
+        assert_eq!(detector.stats().overshoot_events, 0);
+    }
+    
+    #[test]
+    fn test_overshoot_detected_at_real_cycle_rate() {
+        let mut detector = OvershootDetector::new();
+        // 100Hz cycles, boost ramping 10 PSI/s to a 2.5 PSI overshoot of a 10 PSI target
+        let mut t = 0;
+        for i in 0..=75 {
+            detector.update(3000 + i as u16 * 4, 10.0, 5.0 + i as f32 * 0.1, t);
+            t += 10;
+        }
+        for i in 1..=25 {
+            detector.update(3300, 10.0, 12.5 - i as f32 * 0.1, t);
+            t += 10;
+        }
+        
+        let corrections = detector.drain_corrections();
+        assert!((detector.stats().worst_overshoot_psi - 2.5).abs() < 1e-3);
+        assert_eq!(detector.stats().overshoot_events, 1);
+        assert_eq!(corrections.len(), 2);
+        assert!((corrections[0].duty_delta + 3.0).abs() < 1e-2);
+        assert_eq!(corrections[1].rpm, 3300);
+    }
+    
+    #[test]
+    fn test_throttle_lift_is_not_overshoot() {
+        let mut detector = OvershootDetector::new();
+        detector.update(3000, 10.0, 5.0, 0);
+        detector.update(3050, 10.0, 8.0, 10);
+        
+        // Target collapses on lift - boost is now far above it
+        detector.update(3000, 0.0, 8.0, 20);
+        detector.update(2950, 0.0, 6.0, 30);
+        detector.update(2900, 0.0, 0.1, 40);
+        
+        assert!(detector.drain_corrections().is_empty());
+        assert_eq!(detector.stats().spool_events, 0);
+    }
+}
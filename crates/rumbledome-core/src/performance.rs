@@ -0,0 +1,391 @@
+//! Boost Tracking Performance
+//! 
+//! 🔗 T4-CORE-082: Tracking Score
+//! Derived From: T4-CORE-055 (Drive Session Summary) + T4-CORE-034 (Live-Tune Session Management)
+//! AI Traceability: Turn a drive's boost tracking into a score and small, explained tuning nudges
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use crate::{TuneParameter, TuningParameters};
+
+/// Width of each RPM band tracking is attributed to
+pub const RPM_BAND_WIDTH: u16 = 1000;
+
+/// RPM bands tracked (the last band collects everything above)
+pub const RPM_BANDS: usize = 8;
+
+/// Largest change a single suggestion makes to a gain (fraction of current value)
+pub const MAX_GAIN_NUDGE: f32 = 0.10;
+
+/// Target must hold this long before the error counts as steady-state (ms)
+const SETTLE_MS: u32 = 750;
+
+/// Target movement that restarts settling (PSI)
+const TARGET_MOVE_PSI: f32 = 0.3;
+
+/// Steady-state time a band needs before it can drive a suggestion (ms)
+const MIN_STEADY_MS: u32 = 3000;
+
+/// Mean steady-state error worth correcting (PSI)
+const STEADY_ERROR_PSI: f32 = 0.3;
+
+/// Transient overshoot worth correcting (PSI)
+const OVERSHOOT_PSI: f32 = 1.0;
+
+/// Error excursion counted when looking for oscillation (PSI)
+const OSCILLATION_BAND_PSI: f32 = 0.25;
+
+/// Error sign reversals per steady-state second treated as oscillation
+const OSCILLATION_REVERSALS_PER_S: f32 = 0.5;
+
+/// One control cycle of active boost control
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackingSample {
+    pub rpm: u16,
+    /// Boost target (PSI)
+    pub target_psi: f32,
+    /// Manifold pressure (PSI)
+    pub boost_psi: f32,
+}
+
+/// Tracking accumulated in one RPM band
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BandTracking {
+    /// Time spent settled on a target (ms)
+    pub steady_ms: u32,
+    /// Settled samples
+    pub steady_samples: u32,
+    /// Sum of settled error, target minus boost (PSI)
+    pub steady_error_sum: f32,
+    /// Sum of squared settled error (PSI²)
+    pub steady_error_sq_sum: f32,
+    /// Largest boost above target while settling (PSI)
+    pub peak_overshoot_psi: f32,
+    /// Settled error sign reversals beyond the oscillation band
+    pub reversals: u32,
+}
+
+impl BandTracking {
+    /// Mean settled error, positive when boost runs short (PSI)
+    pub fn mean_steady_error_psi(&self) -> f32 {
+        if self.steady_samples == 0 { 0.0 } else { self.steady_error_sum / self.steady_samples as f32 }
+    }
+    
+    /// Settled error sign reversals per second
+    pub fn reversal_rate(&self) -> f32 {
+        if self.steady_ms == 0 { 0.0 } else { self.reversals as f32 * 1000.0 / self.steady_ms as f32 }
+    }
+    
+    fn has_evidence(&self) -> bool {
+        self.steady_ms >= MIN_STEADY_MS
+    }
+}
+
+/// RPM range of a band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpmBand {
+    pub low_rpm: u16,
+    pub high_rpm: u16,
+}
+
+impl RpmBand {
+    /// Band at `index`
+    pub fn of(index: usize) -> Self {
+        let low_rpm = index as u16 * RPM_BAND_WIDTH;
+        Self { low_rpm, high_rpm: low_rpm + RPM_BAND_WIDTH }
+    }
+    
+    /// Index of the band `rpm` falls in
+    pub fn index(rpm: u16) -> usize {
+        ((rpm / RPM_BAND_WIDTH) as usize).min(RPM_BANDS - 1)
+    }
+}
+
+/// Bounded gain change with the evidence behind it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GainSuggestion {
+    pub parameter: TuneParameter,
+    /// Value in effect during the drive
+    pub current: f32,
+    /// Suggested value (within `MAX_GAIN_NUDGE` and the parameter bounds)
+    pub suggested: f32,
+    /// Band the evidence came from
+    pub band: RpmBand,
+    /// What was observed
+    pub reason: String,
+}
+
+impl GainSuggestion {
+    /// Change as a percentage of the current value
+    pub fn change_percent(&self) -> f32 {
+        if self.current == 0.0 { 0.0 } else { (self.suggested - self.current) / self.current * 100.0 }
+    }
+}
+
+impl fmt::Display for GainSuggestion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let change = self.change_percent();
+        write!(f, "{} {} by {:.0}% ({:.3} -> {:.3}) in {}-{} RPM band: {}",
+            if change >= 0.0 { "Increase" } else { "Decrease" }, self.parameter.short_name(), libm::fabsf(change),
+            self.current, self.suggested, self.band.low_rpm, self.band.high_rpm, self.reason)
+    }
+}
+
+/// Boost tracking over one drive, per RPM band
+/// 
+/// 🔗 T4-CORE-083: Gain Suggestions
+/// Derived From: T4-CORE-082
+/// 
+/// Only settled error counts against the score - spool-up lag is the turbo,
+/// not the tune. Overshoot while settling is scored separately.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrackingStats {
+    pub bands: [BandTracking; RPM_BANDS],
+}
+
+impl TrackingStats {
+    /// Whether any settled tracking was recorded
+    pub fn is_empty(&self) -> bool {
+        self.bands.iter().all(|band| band.steady_samples == 0)
+    }
+    
+    /// Settled RMS tracking error over all bands (PSI)
+    pub fn steady_rms_error_psi(&self) -> f32 {
+        let samples: u32 = self.bands.iter().map(|band| band.steady_samples).sum();
+        if samples == 0 {
+            return 0.0;
+        }
+        let sq_sum: f32 = self.bands.iter().map(|band| band.steady_error_sq_sum).sum();
+        libm::sqrtf(sq_sum / samples as f32)
+    }
+    
+    /// Worst overshoot while settling (PSI)
+    pub fn peak_overshoot_psi(&self) -> f32 {
+        self.bands.iter().map(|band| band.peak_overshoot_psi).fold(0.0, f32::max)
+    }
+    
+    /// Tracking score 0-100, None before any settled tracking
+    /// 
+    /// Each PSI of settled RMS error costs 50 points; each PSI of overshoot
+    /// beyond 0.5 PSI costs 20.
+    pub fn score(&self) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+        let penalty = self.steady_rms_error_psi() * 50.0 + (self.peak_overshoot_psi() - 0.5).max(0.0) * 20.0;
+        Some((100.0 - penalty).clamp(0.0, 100.0) as u8)
+    }
+    
+    /// Gain nudges for the problems seen, at most one per gain
+    /// 
+    /// Each suggestion comes from the band with the strongest evidence and
+    /// changes its gain by `MAX_GAIN_NUDGE`; gains already at a bound are left
+    /// out. The gains are global, so the band only says where it showed.
+    pub fn suggestions(&self, tuning: &TuningParameters) -> Vec<GainSuggestion> {
+        let mut suggestions = Vec::new();
+        let mut nudge = |parameter: TuneParameter, factor: f32, band: usize, reason: String| {
+            let current = tuning.get(parameter);
+            let (min, max) = parameter.bounds();
+            let suggested = (current * factor).clamp(min, max);
+            if suggested != current {
+                suggestions.push(GainSuggestion { parameter, current, suggested, band: RpmBand::of(band), reason });
+            }
+        };
+        
+        // Integral too slow to remove a persistent offset
+        if let Some((band, error)) = self.worst(|band| libm::fabsf(band.mean_steady_error_psi()), STEADY_ERROR_PSI) {
+            let side = if self.bands[band].mean_steady_error_psi() > 0.0 { "below" } else { "above" };
+            nudge(TuneParameter::PidKi, 1.0 + MAX_GAIN_NUDGE, band,
+                format!("persistent steady-state error of {:.1} PSI {} target", error, side));
+        }
+        
+        // Proportional action driving boost past target
+        if let Some((band, overshoot)) = self.worst(|band| band.peak_overshoot_psi, OVERSHOOT_PSI) {
+            nudge(TuneParameter::PidKp, 1.0 - MAX_GAIN_NUDGE, band,
+                format!("overshoot of {:.1} PSI while settling", overshoot));
+        }
+        
+        // Hunting around a settled target needs more damping
+        if let Some((band, rate)) = self.worst(BandTracking::reversal_rate, OSCILLATION_REVERSALS_PER_S) {
+            nudge(TuneParameter::PidKd, 1.0 + MAX_GAIN_NUDGE, band,
+                format!("boost oscillating around target ({:.1} reversals/s)", rate));
+        }
+        
+        suggestions
+    }
+    
+    /// Band with the largest metric above `threshold`, among bands with enough evidence
+    fn worst(&self, metric: impl Fn(&BandTracking) -> f32, threshold: f32) -> Option<(usize, f32)> {
+        self.bands.iter()
+            .enumerate()
+            .filter(|(_, band)| band.has_evidence())
+            .map(|(index, band)| (index, metric(band)))
+            .filter(|(_, value)| *value > threshold)
+            .fold(None, |worst: Option<(usize, f32)>, (index, value)| match worst {
+                Some((_, worst_value)) if worst_value >= value => worst,
+                _ => Some((index, value)),
+            })
+    }
+}
+
+/// Settling state carried between control cycles
+#[derive(Debug, Clone, Default)]
+pub struct TrackingMonitor {
+    settle_target_psi: f32,
+    settling_since_ms: Option<u32>,
+    last_ms: Option<u32>,
+    last_error_sign: i8,
+}
+
+impl TrackingMonitor {
+    /// Accumulate one control cycle; `None` when boost is not under active control
+    pub fn update(&mut self, stats: &mut TrackingStats, sample: Option<TrackingSample>, now_ms: u32) {
+        let Some(sample) = sample else {
+            *self = Self::default();
+            return;
+        };
+        
+        let elapsed_ms = self.last_ms.map(|last| now_ms.wrapping_sub(last)).unwrap_or(0);
+        self.last_ms = Some(now_ms);
+        
+        let settling_since_ms = match self.settling_since_ms {
+            Some(since) if libm::fabsf(sample.target_psi - self.settle_target_psi) <= TARGET_MOVE_PSI => since,
+            _ => {
+                self.settle_target_psi = sample.target_psi;
+                self.settling_since_ms = Some(now_ms);
+                self.last_error_sign = 0;
+                now_ms
+            },
+        };
+        
+        let band = &mut stats.bands[RpmBand::index(sample.rpm)];
+        let error = sample.target_psi - sample.boost_psi;
+        
+        if now_ms.wrapping_sub(settling_since_ms) < SETTLE_MS {
+            band.peak_overshoot_psi = band.peak_overshoot_psi.max(-error);
+            return;
+        }
+        
+        band.steady_ms += elapsed_ms;
+        band.steady_samples += 1;
+        band.steady_error_sum += error;
+        band.steady_error_sq_sum += error * error;
+        
+        let sign = if error > OSCILLATION_BAND_PSI { 1 } else if error < -OSCILLATION_BAND_PSI { -1 } else { 0 };
+        if sign != 0 {
+            if self.last_error_sign != 0 && sign != self.last_error_sign {
+                band.reversals += 1;
+            }
+            self.last_error_sign = sign;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    
+    /// Hold a target at 3500 RPM for `duration_ms`, boost following `boost(t)`
+    fn drive(stats: &mut TrackingStats, duration_ms: u32, target_psi: f32, boost: impl Fn(u32) -> f32) {
+        let mut monitor = TrackingMonitor::default();
+        for t in (0..duration_ms).step_by(10) {
+            let sample = TrackingSample { rpm: 3500, target_psi, boost_psi: boost(t) };
+            monitor.update(stats, Some(sample), t);
+        }
+    }
+    
+    #[test]
+    fn test_good_tracking_scores_high_without_suggestions() {
+        let mut stats = TrackingStats::default();
+        assert_eq!(stats.score(), None);
+        
+        drive(&mut stats, 10_000, 10.0, |_| 9.95);
+        assert!(stats.score().unwrap() >= 95);
+        assert!(stats.suggestions(&TuningParameters::default()).is_empty());
+    }
+    
+    #[test]
+    fn test_steady_offset_suggests_more_integral() {
+        let mut stats = TrackingStats::default();
+        drive(&mut stats, 10_000, 10.0, |_| 9.6);
+        
+        let suggestions = stats.suggestions(&TuningParameters::default());
+        assert_eq!(suggestions.len(), 1);
+        let ki = &suggestions[0];
+        assert_eq!(ki.parameter, TuneParameter::PidKi);
+        assert_eq!(ki.band, RpmBand { low_rpm: 3000, high_rpm: 4000 });
+        assert!((ki.change_percent() - 10.0).abs() < 0.01);
+        assert_eq!(
+            ki.to_string(),
+            "Increase Ki by 10% (1.000 -> 1.100) in 3000-4000 RPM band: persistent steady-state error of 0.4 PSI below target"
+        );
+        assert!(stats.score().unwrap() < 85);
+    }
+    
+    #[test]
+    fn test_overshoot_and_oscillation_suggestions() {
+        let mut stats = TrackingStats::default();
+        // Overshoots by 1.5 PSI while settling, then hunts ±0.5 PSI at 1 Hz
+        drive(&mut stats, 10_000, 10.0, |t| if t < 500 { 11.5 } else if (t / 500) % 2 == 0 { 10.5 } else { 9.5 });
+        
+        let parameters: Vec<TuneParameter> = stats.suggestions(&TuningParameters::default())
+            .iter()
+            .map(|suggestion| suggestion.parameter)
+            .collect();
+        assert_eq!(parameters, [TuneParameter::PidKp, TuneParameter::PidKd]);
+    }
+    
+    #[test]
+    fn test_gain_at_bound_is_not_suggested() {
+        let mut stats = TrackingStats::default();
+        drive(&mut stats, 10_000, 10.0, |_| 9.0);
+        
+        let tuning = TuningParameters { pid_ki: 10.0, ..Default::default() };
+        assert!(stats.suggestions(&tuning).is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support;
+    use crate::{DomeControlConfig, SystemConfig};
+    
+    #[test]
+    fn test_suggestions_only_while_the_dome_loop_uses_the_gains() {
+        let suggestions = |dome_control: DomeControlConfig| {
+            let mut core = test_support::armed_core(SystemConfig { dome_control, ..SystemConfig::default() });
+            // Ten seconds held 0.4 PSI short of target
+            for t in (0..10_000).step_by(10) {
+                core.sessions.track(Some(TrackingSample { rpm: 3500, target_psi: 10.0, boost_psi: 9.6 }), t);
+            }
+            (core.gain_suggestions(), core.get_system_status().gain_suggestions_withheld)
+        };
+        
+        // Withheld, and the status says why rather than showing nothing
+        assert_eq!(suggestions(DomeControlConfig::default()), (Vec::new(), true));
+        let (suggested, withheld) = suggestions(DomeControlConfig { enabled: true, ..DomeControlConfig::default() });
+        assert!(!withheld);
+        assert_eq!(suggested.len(), 1);
+        assert_eq!(suggested[0].parameter, TuneParameter::PidKi);
+    }
+    
+    #[test]
+    fn test_boost_loop_test_values_refused_while_the_dome_loop_is_off() {
+        let mut core = test_support::armed_core(SystemConfig::default());
+        assert!(core.set_test_value(TuneParameter::PidKi, 1.1, 0).is_err());
+        assert!(core.set_test_value(TuneParameter::BoostSlewRate, 2.5, 0).is_err());
+        assert!(!core.live_tune.is_volatile());
+        // The torque parameters act on Level 1 whatever the dome loop does
+        core.set_test_value(TuneParameter::TorqueGapThreshold, 12.0, 0).unwrap();
+        
+        let dome_control = DomeControlConfig { enabled: true, ..DomeControlConfig::default() };
+        let mut core = test_support::armed_core(SystemConfig { dome_control, ..SystemConfig::default() });
+        core.set_test_value(TuneParameter::PidKi, 1.1, 0).unwrap();
+        assert_eq!(core.live_tune.active().pid_ki, 1.1);
+    }
+}
@@ -0,0 +1,294 @@
+//! Performance Tiers
+//! 
+//! 🔗 T4-CORE-126: Background Load Shedding
+//! Derived From: T4-CORE-005 (Performance Monitoring) + T4-CORE-067 (Inner and Outer Control Rates)
+//! AI Traceability: Heavy comms, logging or a heat-soaked MCU slow the display and telemetry, never the boost loop
+//! 
+//! The governor watches two signs of stress: control cycle time eating into
+//! the inner loop period, and MCU die temperature. Stress held for the dwell
+//! time steps down one tier, and calm held as long steps back up one. Each
+//! tier below full stretches the background work - display refresh,
+//! telemetry streams and learning - by a fixed step. The control cycle, and
+//! every protection in it, runs at the configured inner rate in every tier.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Load shedding thresholds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PerformanceTierConfig {
+    /// Step background work down under stress
+    pub enabled: bool,
+    /// Cycle time headroom below which the tier steps down (% of the inner loop period)
+    pub min_headroom_percent: f32,
+    /// Headroom above which the tier steps back up (%)
+    pub recover_headroom_percent: f32,
+    /// MCU die temperature above which the tier steps down (°C)
+    pub max_mcu_temp_c: f32,
+    /// MCU die temperature below which the tier steps back up (°C)
+    pub recover_mcu_temp_c: f32,
+    /// Time stress or calm must hold before each step (ms)
+    pub dwell_ms: u32,
+}
+
+impl Default for PerformanceTierConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_headroom_percent: 25.0,
+            recover_headroom_percent: 50.0,
+            max_mcu_temp_c: 85.0,
+            recover_mcu_temp_c: 75.0,
+            dwell_ms: 2000,
+        }
+    }
+}
+
+impl PerformanceTierConfig {
+    /// Validate load shedding thresholds
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(self.min_headroom_percent > 0.0 && self.min_headroom_percent < self.recover_headroom_percent && self.recover_headroom_percent < 100.0) {
+            return Err(CoreError::ConfigurationError(
+                format!("Headroom thresholds must satisfy 0 < step down ({}%) < recover ({}%) < 100",
+                    self.min_headroom_percent, self.recover_headroom_percent)
+            ));
+        }
+        
+        // The i.MX RT1062 is rated to 105°C junction
+        if !(50.0..=105.0).contains(&self.max_mcu_temp_c) || self.recover_mcu_temp_c >= self.max_mcu_temp_c {
+            return Err(CoreError::ConfigurationError(
+                format!("MCU temperature limit must be 50-105°C and above its recovery temperature, got {}°C / {}°C",
+                    self.max_mcu_temp_c, self.recover_mcu_temp_c)
+            ));
+        }
+        
+        if !(100..=60_000).contains(&self.dwell_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Tier dwell time must be 100-60000 ms, got {}", self.dwell_ms)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// How much background work runs alongside the control cycle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PerformanceTier {
+    /// Everything at its configured rate
+    #[default]
+    Full,
+    /// Display and telemetry at half rate, every other learning sample
+    Reduced,
+    /// Display and telemetry at quarter rate, learning paused
+    Minimal,
+}
+
+impl PerformanceTier {
+    /// Multiple of the requested display refresh and telemetry stream intervals
+    pub fn interval_multiplier(&self) -> u32 {
+        match self {
+            PerformanceTier::Full => 1,
+            PerformanceTier::Reduced => 2,
+            PerformanceTier::Minimal => 4,
+        }
+    }
+    
+    /// Steady-state samples per learning update (`None` while learning is paused)
+    pub fn learning_interval(&self) -> Option<u32> {
+        match self {
+            PerformanceTier::Full => Some(1),
+            PerformanceTier::Reduced => Some(2),
+            PerformanceTier::Minimal => None,
+        }
+    }
+    
+    fn lower(&self) -> Self {
+        match self {
+            PerformanceTier::Full => PerformanceTier::Reduced,
+            _ => PerformanceTier::Minimal,
+        }
+    }
+    
+    fn higher(&self) -> Self {
+        match self {
+            PerformanceTier::Minimal => PerformanceTier::Reduced,
+            _ => PerformanceTier::Full,
+        }
+    }
+}
+
+/// Load shedding state (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceTierStatus {
+    pub tier: PerformanceTier,
+    /// Share of the inner loop period the average cycle leaves unused (%)
+    pub headroom_percent: f32,
+    /// MCU die temperature, where the platform reads it (°C)
+    pub mcu_temperature_c: Option<f32>,
+    /// Steps down since power-up
+    pub downshifts: u32,
+    /// Steady-state samples not learned from because of the tier
+    pub learning_samples_skipped: u32,
+}
+
+/// Steps background work down and up with cycle time headroom and MCU temperature
+#[derive(Debug, Clone)]
+pub struct TierGovernor {
+    config: PerformanceTierConfig,
+    status: PerformanceTierStatus,
+    stressed_since_ms: Option<u32>,
+    calm_since_ms: Option<u32>,
+    learning_samples: u32,
+}
+
+impl TierGovernor {
+    /// Create at full tier
+    pub fn new(config: PerformanceTierConfig) -> Self {
+        Self {
+            config,
+            status: PerformanceTierStatus::default(),
+            stressed_since_ms: None,
+            calm_since_ms: None,
+            learning_samples: 0,
+        }
+    }
+    
+    /// Apply new thresholds; disabling returns to full tier
+    pub fn reconfigure(&mut self, config: PerformanceTierConfig) {
+        if !config.enabled {
+            self.status.tier = PerformanceTier::Full;
+        }
+        self.config = config;
+    }
+    
+    /// Tier in effect
+    pub fn tier(&self) -> PerformanceTier {
+        self.status.tier
+    }
+    
+    /// Follow one control cycle's average cycle time and the MCU temperature
+    pub fn update(&mut self, avg_cycle_time_us: u32, period_us: u32, mcu_temperature_c: Option<f32>, now_ms: u32) {
+        let headroom_percent = (1.0 - avg_cycle_time_us as f32 / period_us.max(1) as f32) * 100.0;
+        self.status.headroom_percent = headroom_percent;
+        self.status.mcu_temperature_c = mcu_temperature_c;
+        if !self.config.enabled {
+            return;
+        }
+        
+        let hot = mcu_temperature_c.is_some_and(|celsius| celsius > self.config.max_mcu_temp_c);
+        let cool = mcu_temperature_c.is_none_or(|celsius| celsius < self.config.recover_mcu_temp_c);
+        let stressed = headroom_percent < self.config.min_headroom_percent || hot;
+        let calm = headroom_percent > self.config.recover_headroom_percent && cool;
+        self.stressed_since_ms = if stressed { Some(self.stressed_since_ms.unwrap_or(now_ms)) } else { None };
+        self.calm_since_ms = if calm { Some(self.calm_since_ms.unwrap_or(now_ms)) } else { None };
+        
+        let held = |since: Option<u32>| since.is_some_and(|since| now_ms.wrapping_sub(since) >= self.config.dwell_ms);
+        if held(self.stressed_since_ms) && self.status.tier != PerformanceTier::Minimal {
+            self.status.tier = self.status.tier.lower();
+            self.status.downshifts += 1;
+            self.stressed_since_ms = Some(now_ms);
+        } else if held(self.calm_since_ms) && self.status.tier != PerformanceTier::Full {
+            self.status.tier = self.status.tier.higher();
+            self.calm_since_ms = Some(now_ms);
+        }
+    }
+    
+    /// Whether this steady-state sample is learned from in the current tier
+    pub fn admit_learning(&mut self) -> bool {
+        let admitted = match self.status.tier.learning_interval() {
+            Some(interval) => {
+                self.learning_samples = (self.learning_samples + 1) % interval;
+                self.learning_samples == 0
+            },
+            None => false,
+        };
+        if !admitted {
+            self.status.learning_samples_skipped += 1;
+        }
+        admitted
+    }
+    
+    /// Current state
+    pub fn status(&self) -> &PerformanceTierStatus {
+        &self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const PERIOD_US: u32 = 10_000;
+    
+    /// Hold a cycle time and temperature for `ms` in 10 ms cycles from `start_ms`
+    fn hold(governor: &mut TierGovernor, cycle_us: u32, celsius: Option<f32>, start_ms: u32, ms: u32) {
+        for t in (start_ms..start_ms + ms).step_by(10) {
+            governor.update(cycle_us, PERIOD_US, celsius, t);
+        }
+    }
+    
+    #[test]
+    fn test_short_headroom_steps_down_one_tier_per_dwell() {
+        let mut governor = TierGovernor::new(PerformanceTierConfig::default());
+        hold(&mut governor, 8_000, Some(50.0), 0, 1_990);
+        assert_eq!(governor.tier(), PerformanceTier::Full);
+        assert!((governor.status().headroom_percent - 20.0).abs() < 1e-3);
+        
+        hold(&mut governor, 8_000, Some(50.0), 2_000, 10);
+        assert_eq!(governor.tier(), PerformanceTier::Reduced);
+        hold(&mut governor, 8_000, Some(50.0), 2_010, 10_000);
+        assert_eq!(governor.tier(), PerformanceTier::Minimal);
+        assert_eq!(governor.status().downshifts, 2);
+    }
+    
+    #[test]
+    fn test_hot_mcu_steps_down_and_recovers_once_cool() {
+        let mut governor = TierGovernor::new(PerformanceTierConfig::default());
+        hold(&mut governor, 2_000, Some(90.0), 0, 2_100);
+        assert_eq!(governor.tier(), PerformanceTier::Reduced);
+        
+        // Between the thresholds neither stress nor calm - the tier holds
+        hold(&mut governor, 2_000, Some(80.0), 2_100, 5_000);
+        assert_eq!(governor.tier(), PerformanceTier::Reduced);
+        
+        hold(&mut governor, 2_000, Some(70.0), 7_100, 2_100);
+        assert_eq!(governor.tier(), PerformanceTier::Full);
+    }
+    
+    #[test]
+    fn test_learning_thinned_then_paused() {
+        let mut governor = TierGovernor::new(PerformanceTierConfig::default());
+        assert!(governor.admit_learning());
+        
+        hold(&mut governor, 9_000, None, 0, 2_100);
+        let admitted = (0..10).filter(|_| governor.admit_learning()).count();
+        assert_eq!(admitted, 5);
+        
+        hold(&mut governor, 9_000, None, 2_100, 2_100);
+        assert!(!governor.admit_learning());
+        assert_eq!(governor.status().learning_samples_skipped, 6);
+    }
+    
+    #[test]
+    fn test_disabled_stays_full() {
+        let mut governor = TierGovernor::new(PerformanceTierConfig::default());
+        hold(&mut governor, 9_500, Some(100.0), 0, 2_100);
+        assert_eq!(governor.tier(), PerformanceTier::Reduced);
+        
+        governor.reconfigure(PerformanceTierConfig { enabled: false, ..PerformanceTierConfig::default() });
+        hold(&mut governor, 9_500, Some(100.0), 2_100, 10_000);
+        assert_eq!(governor.tier(), PerformanceTier::Full);
+    }
+    
+    #[test]
+    fn test_validation() {
+        assert!(PerformanceTierConfig::default().validate().is_ok());
+        assert!(PerformanceTierConfig { recover_headroom_percent: 20.0, ..PerformanceTierConfig::default() }.validate().is_err());
+        assert!(PerformanceTierConfig { recover_mcu_temp_c: 90.0, ..PerformanceTierConfig::default() }.validate().is_err());
+        assert!(PerformanceTierConfig { max_mcu_temp_c: 120.0, ..PerformanceTierConfig::default() }.validate().is_err());
+        assert!(PerformanceTierConfig { dwell_ms: 0, ..PerformanceTierConfig::default() }.validate().is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Physical Presence Confirmation
+//! 
+//! 🔗 T4-CORE-103: Presence-Confirmed Dangerous Commands
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-035 (Semantic Screen Description)
+//! AI Traceability: Raising safety limits must take someone standing at the car, not just a connection to it
+//! 
+//! A protected command is held instead of executed, and the device shows a
+//! one-time code on its own display. The command only runs if the same code
+//! is sent back on the session that asked, before the challenge expires.
+//! Anyone who can read the code can see the gauge, so a stray or remote
+//! client cannot raise the limits on its own. One attempt per challenge: a
+//! wrong code cancels it.
+//! 
+//! The same goes for anything that replaces what the car has learned: a
+//! reset or an imported map changes the duty every cell commands. There is
+//! no factory reset or fixed-duty dyno mode; a command that wipes the
+//! controller or drives the output directly belongs behind this gate too.
+
+use alloc::format;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemConfig};
+
+/// How long a challenge code stays valid (ms)
+pub const CONFIRMATION_TIMEOUT_MS: u32 = 30_000;
+
+/// Command that needs physical presence confirmation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ProtectedAction {
+    /// Configuration change raising the overboost limit, boost ceiling (PSI) or duty ceiling (%)
+    SafetyLimitRaise {
+        overboost_from: f32,
+        overboost_to: f32,
+        max_boost_from: f32,
+        max_boost_to: f32,
+        max_duty_from: f32,
+        max_duty_to: f32,
+    },
+    /// Wastegate spring check - output held at 0 % through a wide-open pull
+    SpringCheck {
+        configured_psi: f32,
+    },
+    /// Full access for the Bluetooth connection asking
+    ConsoleAccess,
+    /// Forget every learned cell
+    ResetLearnedData,
+    /// Replace the learned map with an uploaded image
+    ImportLearnedData,
+}
+
+impl ProtectedAction {
+    /// Protected action taken by replacing the running configuration, if any
+    pub fn for_config_change(running: &SystemConfig, applied: &SystemConfig) -> Option<Self> {
+        let raises = applied.overboost_limit > running.overboost_limit
+            || applied.max_boost_psi > running.max_boost_psi
+            || applied.max_duty_percent > running.max_duty_percent;
+        raises.then_some(ProtectedAction::SafetyLimitRaise {
+            overboost_from: running.overboost_limit,
+            overboost_to: applied.overboost_limit,
+            max_boost_from: running.max_boost_psi,
+            max_boost_to: applied.max_boost_psi,
+            max_duty_from: running.max_duty_percent,
+            max_duty_to: applied.max_duty_percent,
+        })
+    }
+    
+    /// What the user is confirming
+    pub fn description(&self) -> String {
+        match self {
+            ProtectedAction::SafetyLimitRaise {
+                overboost_from, overboost_to, max_boost_from, max_boost_to, max_duty_from, max_duty_to,
+            } => format!(
+                "Raise safety limits: overboost {:.1} → {:.1} PSI, max boost {:.1} → {:.1} PSI, max duty {:.0} → {:.0} %",
+                overboost_from, overboost_to, max_boost_from, max_boost_to, max_duty_from, max_duty_to
+            ),
+            ProtectedAction::SpringCheck { configured_psi } => format!(
+                "Spring check: boost control off for one wide-open pull, comparing against the configured {:.1} PSI spring",
+                configured_psi
+            ),
+            ProtectedAction::ConsoleAccess => String::from("Authenticate this Bluetooth connection"),
+            ProtectedAction::ResetLearnedData => String::from("Forget everything the controller has learned"),
+            ProtectedAction::ImportLearnedData => String::from("Replace the learned map with the uploaded image"),
+        }
+    }
+}
+
+/// Outstanding challenge
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Challenge {
+    action: ProtectedAction,
+    code: u16,
+    issued_ms: u32,
+}
+
+/// Issues and checks presence challenges - at most one outstanding
+#[derive(Debug, Clone, Default)]
+pub struct PresenceGate {
+    challenge: Option<Challenge>,
+    rng: u32,
+}
+
+impl PresenceGate {
+    /// Create with no challenge outstanding
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Hold a protected action and pick the code to display (replaces any earlier challenge)
+    /// 
+    /// `entropy` is mixed into the code generator; the code only has to be
+    /// unguessable from the link, not cryptographically strong.
+    pub fn issue(&mut self, action: ProtectedAction, entropy: u32, now_ms: u32) {
+        let mut x = (self.rng ^ entropy ^ now_ms.rotate_left(16)) | 1;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        
+        let code = 1000 + (x % 9000) as u16;
+        self.challenge = Some(Challenge { action, code, issued_ms: now_ms });
+    }
+    
+    /// Check a code sent back by the client; the challenge is used up either way
+    pub fn confirm(&mut self, code: u16, now_ms: u32) -> Result<ProtectedAction, CoreError> {
+        self.expire(now_ms);
+        let Some(challenge) = self.challenge.take() else {
+            return Err(CoreError::InvalidState(String::from("No confirmation pending (it may have expired)")));
+        };
+        
+        if challenge.code != code {
+            return Err(CoreError::SafetyViolation(String::from("Confirmation code does not match - command cancelled")));
+        }
+        Ok(challenge.action)
+    }
+    
+    /// Drop a challenge that has timed out
+    pub fn expire(&mut self, now_ms: u32) {
+        if self.challenge.is_some_and(|challenge| now_ms.wrapping_sub(challenge.issued_ms) >= CONFIRMATION_TIMEOUT_MS) {
+            self.challenge = None;
+        }
+    }
+    
+    /// Withdraw any outstanding challenge
+    pub fn cancel(&mut self) {
+        self.challenge = None;
+    }
+    
+    /// Action waiting for confirmation
+    pub fn pending(&self) -> Option<ProtectedAction> {
+        self.challenge.map(|challenge| challenge.action)
+    }
+    
+    /// Code to show on the device display
+    pub fn display_code(&self) -> Option<u16> {
+        self.challenge.map(|challenge| challenge.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn raise() -> ProtectedAction {
+        let running = SystemConfig::default();
+        let applied = SystemConfig { overboost_limit: running.overboost_limit + 1.0, ..running.clone() };
+        ProtectedAction::for_config_change(&running, &applied).unwrap()
+    }
+    
+    #[test]
+    fn test_only_limit_raises_are_protected() {
+        let running = SystemConfig::default();
+        let lowered = SystemConfig { overboost_limit: 14.0, aggression: 0.9, ..running.clone() };
+        assert_eq!(ProtectedAction::for_config_change(&running, &lowered), None);
+        
+        let higher_ceiling = SystemConfig { max_boost_psi: 13.0, ..running.clone() };
+        assert!(ProtectedAction::for_config_change(&running, &higher_ceiling).is_some());
+        
+        let lower_duty = SystemConfig { max_duty_percent: running.max_duty_percent - 10.0, ..running.clone() };
+        assert_eq!(ProtectedAction::for_config_change(&running, &lower_duty), None);
+    }
+    
+    #[test]
+    fn test_duty_ceiling_raise_is_protected() {
+        let running = SystemConfig { max_duty_percent: 80.0, ..SystemConfig::default() };
+        let applied = SystemConfig { max_duty_percent: 95.0, ..running.clone() };
+        let action = ProtectedAction::for_config_change(&running, &applied).unwrap();
+        assert!(matches!(action, ProtectedAction::SafetyLimitRaise { max_duty_from, max_duty_to, .. } if max_duty_from == 80.0 && max_duty_to == 95.0));
+        assert!(action.description().contains("max duty 80 → 95 %"));
+    }
+    
+    #[test]
+    fn test_matching_code_within_timeout_confirms() {
+        let mut gate = PresenceGate::new();
+        gate.issue(raise(), 0x1234, 0);
+        let code = gate.display_code().unwrap();
+        assert!((1000..=9999).contains(&code));
+        
+        assert_eq!(gate.confirm(code, CONFIRMATION_TIMEOUT_MS - 1).unwrap(), raise());
+        assert_eq!(gate.display_code(), None);
+    }
+    
+    #[test]
+    fn test_wrong_or_late_code_cancels() {
+        let mut gate = PresenceGate::new();
+        gate.issue(raise(), 0x1234, 0);
+        let code = gate.display_code().unwrap();
+        assert!(gate.confirm(code.wrapping_add(1), 100).is_err());
+        assert!(gate.confirm(code, 200).is_err());
+        
+        gate.issue(raise(), 0x1234, 1000);
+        let code = gate.display_code().unwrap();
+        assert!(gate.confirm(code, 1000 + CONFIRMATION_TIMEOUT_MS).is_err());
+    }
+    
+    #[test]
+    fn test_consecutive_codes_differ() {
+        let mut gate = PresenceGate::new();
+        gate.issue(raise(), 0, 0);
+        let first = gate.display_code();
+        gate.issue(raise(), 0, 0);
+        assert_ne!(gate.display_code(), first);
+    }
+}
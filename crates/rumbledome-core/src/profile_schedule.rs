@@ -0,0 +1,363 @@
+//! Profile Scheduling
+//! 
+//! 🔗 T4-CORE-110: Context-Driven Profile Scheduling
+//! Derived From: T4-CORE-049 (Configuration Quick Switching) + T4-CORE-103 (Presence-Confirmed Dangerous Commands)
+//! AI Traceability: "Valet mode at home" without GPS on the device - a connected phone supplies where and when
+//! 
+//! The device has no GPS and no wall clock. A connected app pushes
+//! `ContextHints` (track-day flag, geofence zone, local time of day) when they
+//! change, and periodically while time-of-day rules are stored. Rules are
+//! checked in order on every push: the first match names the configuration to
+//! run, and no match returns to the configuration the driver selected.
+//! 
+//! Rules only ever step down. A scheduled configuration with higher safety
+//! limits than the driver's own selection is refused - raising the limits
+//! takes someone at the car (T4-CORE-103), and the app may be a guest session.
+//! A switch is only made when the scheduled choice changes, so a configuration
+//! selected by hand sticks until the context does. A switch blocked while armed
+//! is retried on the next push.
+
+use alloc::format;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use crate::{ConfigLibrary, CoreError, ProtectedAction};
+
+/// Maximum number of scheduling rules
+pub const MAX_SCHEDULE_RULES: usize = 8;
+
+/// Maximum geofence zone name length (characters)
+pub const MAX_ZONE_NAME_LEN: usize = 24;
+
+/// Minutes in a day (time-of-day hints and windows are below this)
+pub const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Context pushed by a connected app
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContextHints {
+    /// Driver has flagged a track day
+    #[serde(default)]
+    pub track_day: bool,
+    /// Geofence zone the phone is in (app-defined name, e.g. "home"); `None` outside all zones
+    #[serde(default)]
+    pub zone: Option<String>,
+    /// Local time of day (minutes since midnight), if the app shares it
+    #[serde(default)]
+    pub minute_of_day: Option<u16>,
+}
+
+impl ContextHints {
+    /// Validate hints received from a client
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if let Some(zone) = &self.zone {
+            validate_zone_name(zone)?;
+        }
+        if let Some(minute) = self.minute_of_day {
+            validate_minute(minute)?;
+        }
+        Ok(())
+    }
+}
+
+/// Local time window in minutes since midnight (wraps past midnight when `end < start`)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimeWindow {
+    /// First minute inside the window
+    pub start: u16,
+    /// First minute after the window
+    pub end: u16,
+}
+
+impl TimeWindow {
+    /// Whether a time of day falls inside the window
+    pub fn contains(&self, minute: u16) -> bool {
+        if self.start <= self.end {
+            minute >= self.start && minute < self.end
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+}
+
+/// Scheduling rule - runs `config` when every condition given holds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProfileRule {
+    /// Named configuration to run
+    pub config: String,
+    /// Required track-day flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_day: Option<bool>,
+    /// Required geofence zone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    /// Required local time window (never matches when the app shares no time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<TimeWindow>,
+}
+
+impl ProfileRule {
+    /// Whether the rule applies in this context
+    pub fn matches(&self, hints: &ContextHints) -> bool {
+        self.track_day.is_none_or(|track_day| track_day == hints.track_day)
+            && self.zone.as_ref().is_none_or(|zone| hints.zone.as_ref() == Some(zone))
+            && self.time.is_none_or(|window| hints.minute_of_day.is_some_and(|minute| window.contains(minute)))
+    }
+    
+    /// Validate rule contents (the configuration name is checked against the library)
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.track_day.is_none() && self.zone.is_none() && self.time.is_none() {
+            return Err(CoreError::ConfigurationError(
+                format!("Schedule rule for \"{}\" has no conditions", self.config)
+            ));
+        }
+        if let Some(zone) = &self.zone {
+            validate_zone_name(zone)?;
+        }
+        if let Some(window) = self.time {
+            validate_minute(window.start)?;
+            validate_minute(window.end)?;
+            if window.start == window.end {
+                return Err(CoreError::ConfigurationError(
+                    format!("Schedule rule for \"{}\" has an empty time window", self.config)
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validate a geofence zone name
+fn validate_zone_name(zone: &str) -> Result<(), CoreError> {
+    if zone.is_empty() || zone.chars().count() > MAX_ZONE_NAME_LEN {
+        return Err(CoreError::ConfigurationError(
+            format!("Zone name must be 1-{} characters, got \"{}\"", MAX_ZONE_NAME_LEN, zone)
+        ));
+    }
+    Ok(())
+}
+
+/// Validate a time of day
+fn validate_minute(minute: u16) -> Result<(), CoreError> {
+    if minute >= MINUTES_PER_DAY {
+        return Err(CoreError::ConfigurationError(
+            format!("Time of day must be below {} minutes, got {}", MINUTES_PER_DAY, minute)
+        ));
+    }
+    Ok(())
+}
+
+/// What the schedule did with the last hints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScheduleOutcome {
+    /// Nothing to change - the scheduled choice is unchanged or already running
+    Unchanged,
+    /// Switched to the named configuration
+    Switched { config: String },
+    /// Switch waits until boost control stops
+    Deferred { config: String },
+    /// Scheduled configuration has higher safety limits than the driver's selection
+    Refused { config: String },
+}
+
+/// Schedule evaluation result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleStatus {
+    /// Hints the schedule was evaluated against
+    pub hints: ContextHints,
+    /// Index of the matching rule (`None` when the driver's selection applies)
+    pub rule: Option<usize>,
+    /// Configuration the driver selected
+    pub selected: String,
+    /// Configuration running after evaluation
+    pub active: String,
+    /// What was done
+    pub outcome: ScheduleOutcome,
+}
+
+/// Volatile schedule state - latest hints and the choice last put in place
+#[derive(Debug, Clone, Default)]
+pub struct ProfileScheduler {
+    hints: ContextHints,
+    applied: Option<String>,
+}
+
+impl ProfileScheduler {
+    /// Create with no hints received
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Latest hints received
+    pub fn hints(&self) -> &ContextHints {
+        &self.hints
+    }
+    
+    /// Record new hints and decide what the library should run
+    /// 
+    /// Returns `Switched` for a switch the caller still has to make; report it
+    /// with `switched` once made.
+    pub fn evaluate(&mut self, hints: ContextHints, library: &ConfigLibrary) -> ScheduleStatus {
+        let rule = library.schedule().iter().position(|rule| rule.matches(&hints));
+        let selected = String::from(library.driver_selection());
+        let target = rule.map_or_else(|| selected.clone(), |index| library.schedule()[index].config.clone());
+        self.hints = hints;
+        
+        let outcome = if self.applied.as_ref() == Some(&target) {
+            ScheduleOutcome::Unchanged
+        } else if target == library.active_name() {
+            self.applied = Some(target);
+            ScheduleOutcome::Unchanged
+        } else if raises_limits(library, &selected, &target) {
+            ScheduleOutcome::Refused { config: target }
+        } else {
+            ScheduleOutcome::Switched { config: target }
+        };
+        
+        ScheduleStatus { hints: self.hints.clone(), rule, selected, active: String::from(library.active_name()), outcome }
+    }
+    
+    /// A scheduled switch was made
+    pub fn switched(&mut self, config: &str) {
+        self.applied = Some(String::from(config));
+    }
+}
+
+/// Whether running `target` would raise safety limits above the driver's selection
+fn raises_limits(library: &ConfigLibrary, selected: &str, target: &str) -> bool {
+    match (library.limits_of(selected), library.limits_of(target)) {
+        (Some(selected), Some(target)) => ProtectedAction::for_config_change(&selected, &target).is_some(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use crate::{SystemConfig, SPRING_ONLY_PROFILE};
+    
+    fn valet_at_home() -> ProfileRule {
+        ProfileRule { config: String::from("valet"), track_day: None, zone: Some(String::from("home")), time: None }
+    }
+    
+    fn library() -> ConfigLibrary {
+        let daily = SystemConfig::default();
+        let valet = SystemConfig { max_boost_psi: daily.spring_pressure + 1.0, ..daily.clone() };
+        let mut library = ConfigLibrary::single("daily", daily.clone());
+        library.save("valet", valet).unwrap();
+        library.save("track", SystemConfig { overboost_limit: daily.overboost_limit + 1.0, ..daily }).unwrap();
+        library
+    }
+    
+    fn at(zone: Option<&str>) -> ContextHints {
+        ContextHints { zone: zone.map(String::from), ..ContextHints::default() }
+    }
+    
+    #[test]
+    fn test_rule_conditions() {
+        let night = TimeWindow { start: 22 * 60, end: 6 * 60 };
+        assert!(night.contains(23 * 60) && night.contains(60));
+        assert!(!night.contains(12 * 60));
+        
+        let rule = ProfileRule { time: Some(night), ..valet_at_home() };
+        assert!(rule.matches(&ContextHints { minute_of_day: Some(23 * 60), ..at(Some("home")) }));
+        assert!(!rule.matches(&ContextHints { minute_of_day: Some(12 * 60), ..at(Some("home")) }));
+        // No time shared - a time condition cannot hold
+        assert!(!rule.matches(&at(Some("home"))));
+        assert!(!rule.matches(&ContextHints { minute_of_day: Some(23 * 60), ..at(Some("work")) }));
+        
+        assert!(ProfileRule { zone: None, ..valet_at_home() }.validate().is_err());
+        assert!(ContextHints { minute_of_day: Some(MINUTES_PER_DAY), ..ContextHints::default() }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_schedule_steps_down_and_back() {
+        let mut library = library();
+        library.set_schedule(vec![valet_at_home()]).unwrap();
+        let mut scheduler = ProfileScheduler::new();
+        
+        let status = scheduler.evaluate(at(Some("home")), &library);
+        assert_eq!(status.rule, Some(0));
+        assert_eq!(status.outcome, ScheduleOutcome::Switched { config: String::from("valet") });
+        library.select_scheduled("valet").unwrap();
+        scheduler.switched("valet");
+        assert_eq!(scheduler.evaluate(at(Some("home")), &library).outcome, ScheduleOutcome::Unchanged);
+        
+        // Leaving the zone returns to the driver's selection
+        let status = scheduler.evaluate(at(None), &library);
+        assert_eq!(status.selected, "daily");
+        assert_eq!(status.outcome, ScheduleOutcome::Switched { config: String::from("daily") });
+    }
+    
+    #[test]
+    fn test_schedule_never_raises_limits() {
+        let mut library = library();
+        let track_day = ProfileRule { config: String::from("track"), track_day: Some(true), zone: None, time: None };
+        library.set_schedule(vec![track_day]).unwrap();
+        let mut scheduler = ProfileScheduler::new();
+        
+        let hints = ContextHints { track_day: true, ..ContextHints::default() };
+        assert_eq!(scheduler.evaluate(hints, &library).outcome, ScheduleOutcome::Refused { config: String::from("track") });
+    }
+    
+    #[test]
+    fn test_schedule_locks_spring_only() {
+        let mut library = library();
+        let lock = ProfileRule { config: String::from(SPRING_ONLY_PROFILE), ..valet_at_home() };
+        library.set_schedule(vec![lock]).unwrap();
+        let mut scheduler = ProfileScheduler::new();
+        
+        let status = scheduler.evaluate(at(Some("home")), &library);
+        assert_eq!(status.outcome, ScheduleOutcome::Switched { config: String::from(SPRING_ONLY_PROFILE) });
+        library.select_scheduled(SPRING_ONLY_PROFILE).unwrap();
+        scheduler.switched(SPRING_ONLY_PROFILE);
+        assert_eq!(library.driver_selection(), "daily");
+        assert_eq!(scheduler.evaluate(at(Some("home")), &library).outcome, ScheduleOutcome::Unchanged);
+        
+        // A driver who picked spring only is never moved off it by a rule
+        library.select(SPRING_ONLY_PROFILE).unwrap();
+        library.set_schedule(vec![valet_at_home()]).unwrap();
+        let mut scheduler = ProfileScheduler::new();
+        let status = scheduler.evaluate(at(Some("home")), &library);
+        assert_eq!(status.outcome, ScheduleOutcome::Refused { config: String::from("valet") });
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use alloc::vec;
+    use crate::{RumbleDomeCore, SystemConfig, SystemState};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_valet_at_home_waits_for_disarm() {
+        let daily = SystemConfig::default();
+        let valet = SystemConfig { max_boost_psi: daily.spring_pressure + 1.0, ..daily.clone() };
+        let mut library = ConfigLibrary::single("daily", daily.clone());
+        library.save("valet", valet.clone()).unwrap();
+        library.set_schedule(vec![ProfileRule {
+            config: String::from("valet"), track_day: None, zone: Some(String::from("home")), time: None,
+        }]).unwrap();
+        let mut core = RumbleDomeCore::with_configurations(MockHal::new(), library);
+        core.initialize().unwrap();
+        
+        let home = ContextHints { zone: Some(String::from("home")), ..ContextHints::default() };
+        core.state = SystemState::Armed;
+        let status = core.apply_context_hints(home.clone()).unwrap();
+        assert_eq!(status.outcome, ScheduleOutcome::Deferred { config: String::from("valet") });
+        assert_eq!(core.config.max_boost_psi, daily.max_boost_psi);
+        
+        core.state = SystemState::Idle;
+        let status = core.apply_context_hints(home).unwrap();
+        assert_eq!(status.active, "valet");
+        assert_eq!(core.config.max_boost_psi, valet.max_boost_psi);
+        assert_eq!(core.configurations.driver_selection(), "daily");
+        
+        core.apply_context_hints(ContextHints::default()).unwrap();
+        assert_eq!(core.configurations.active_name(), "daily");
+        assert_eq!(core.config.max_boost_psi, daily.max_boost_psi);
+    }
+}
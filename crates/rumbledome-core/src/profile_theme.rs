@@ -0,0 +1,110 @@
+//! Profile Display Theme
+//! 
+//! 🔗 T4-CORE-063: Per-Profile Accent Color and Label
+//! Derived From: T4-CORE-048 (Named Configuration Library) + Hardware.md display requirements (ST7735R gauge pod)
+//! AI Traceability: Driver sees at a glance (gauge ring color) which profile is active - valet, daily or track
+
+use alloc::format;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Maximum profile label length (characters) - fits beside the state text on the gauge
+pub const MAX_PROFILE_LABEL_LEN: usize = 6;
+
+/// 24-bit accent color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AccentColor {
+    /// Red channel
+    pub r: u8,
+    /// Green channel
+    pub g: u8,
+    /// Blue channel
+    pub b: u8,
+}
+
+impl AccentColor {
+    /// Suggested valet profile color
+    pub const GREEN: Self = Self::rgb(0x20, 0xC0, 0x40);
+    /// Suggested daily profile color (also the default)
+    pub const BLUE: Self = Self::rgb(0x20, 0x90, 0xFF);
+    /// Suggested track profile color
+    pub const RED: Self = Self::rgb(0xFF, 0x30, 0x20);
+    
+    /// Color from 8-bit channels
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+    
+    /// Native ST7735R pixel format
+    pub fn to_rgb565(self) -> u16 {
+        ((self.r as u16 >> 3) << 11) | ((self.g as u16 >> 2) << 5) | (self.b as u16 >> 3)
+    }
+}
+
+impl Default for AccentColor {
+    fn default() -> Self {
+        Self::BLUE
+    }
+}
+
+/// Display identity of a configuration profile
+/// 
+/// 🔗 T4-CORE-064: Profile Theme Configuration
+/// Derived From: T4-CORE-063
+/// 
+/// The accent color is drawn on the gauge ring; the label is shown beside the
+/// state text. Both are passed to telemetry clients in the screen description.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProfileTheme {
+    /// Gauge ring accent color
+    pub accent: AccentColor,
+    /// Short label (e.g. "VALET", "TRACK"); empty shows no label
+    #[serde(default)]
+    pub label: String,
+}
+
+impl ProfileTheme {
+    /// Validate theme settings
+    /// 
+    /// The gauge font only has upper-case letters, digits, space and '-'.
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.label.len() > MAX_PROFILE_LABEL_LEN {
+            return Err(CoreError::ConfigurationError(
+                format!("Profile label must be at most {} characters, got \"{}\"", MAX_PROFILE_LABEL_LEN, self.label)
+            ));
+        }
+        
+        if !self.label.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == ' ' || c == '-') {
+            return Err(CoreError::ConfigurationError(
+                format!("Profile label may only contain A-Z, 0-9, space and '-', got \"{}\"", self.label)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_rgb565_packing() {
+        assert_eq!(AccentColor::rgb(0xFF, 0xFF, 0xFF).to_rgb565(), 0xFFFF);
+        assert_eq!(AccentColor::rgb(0xFF, 0x00, 0x00).to_rgb565(), 0xF800);
+        assert_eq!(AccentColor::rgb(0x00, 0xFF, 0x00).to_rgb565(), 0x07E0);
+        assert_eq!(AccentColor::rgb(0x00, 0x00, 0xFF).to_rgb565(), 0x001F);
+    }
+    
+    #[test]
+    fn test_label_validation() {
+        let theme = |label: &str| ProfileTheme { accent: AccentColor::RED, label: label.into() };
+        assert!(theme("TRACK").validate().is_ok());
+        assert!(theme("").validate().is_ok());
+        assert!(theme("track").validate().is_err());
+        assert!(theme("TRACKDAY").validate().is_err());
+    }
+}
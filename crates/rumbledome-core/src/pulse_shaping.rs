@@ -0,0 +1,276 @@
+//! Solenoid Pulse Shaping
+//! 
+//! 🔗 T4-CORE-112: Minimum Pulse Width Duty Shaping
+//! Derived From: T4-CORE-070 (Actuator Driver Interface) + Hardware.md MAC solenoid response time
+//! AI Traceability: Never command a pulse shorter than the valve can act on - the commanded duty is what the spool actually does
+//! 
+//! A valve needs a few milliseconds of coil current to shift its spool and a
+//! few more to return. At 30 Hz a 3 % duty is a 1 ms pulse: the spool never
+//! moves, so the dome sees nothing while the controller believes it is
+//! bleeding. The same happens near 100 %, where the off gap is too short for
+//! the spool to return. Duty inside those bands is snapped to 0 %, to the
+//! shortest pulse the valve acts on, or to 100 %.
+//! 
+//! Each snap point has hysteresis: once the output has left 0 % it stays at
+//! the minimum pulse until the command falls below half of it, and likewise
+//! at the top. A command hovering on a threshold - controller noise or dither -
+//! therefore cannot flip the valve between two states every cycle.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Shortest pulse any supported valve is configured with (µs)
+const MIN_PULSE_RANGE_US: core::ops::RangeInclusive<u32> = 500..=15_000;
+
+/// Solenoid valve model - sets the shortest on and off pulses it acts on
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SolenoidModel {
+    /// MAC 35A-series 4-port valve (Hardware.md reference valve)
+    #[default]
+    MacFourPort,
+    /// Typical aftermarket 3-port boost control valve - heavier spool, slower return
+    GenericThreePort,
+    /// Measured pulse widths for another valve
+    Custom {
+        /// Shortest on pulse the spool shifts on (µs)
+        min_on_us: u32,
+        /// Shortest off gap the spool returns in (µs)
+        min_off_us: u32,
+    },
+}
+
+impl SolenoidModel {
+    /// Shortest on pulse and off gap (µs)
+    pub fn min_pulses_us(&self) -> (u32, u32) {
+        match *self {
+            Self::MacFourPort => (3_000, 2_000),
+            Self::GenericThreePort => (5_000, 4_000),
+            Self::Custom { min_on_us, min_off_us } => (min_on_us, min_off_us),
+        }
+    }
+}
+
+/// Pulse shaping settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PulseShapingConfig {
+    /// Snap duty that would produce pulses the valve cannot act on
+    pub enabled: bool,
+    /// Valve fitted
+    pub solenoid: SolenoidModel,
+}
+
+impl Default for PulseShapingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            solenoid: SolenoidModel::default(),
+        }
+    }
+}
+
+impl PulseShapingConfig {
+    /// Validate pulse widths against the PWM period
+    pub fn validate(&self, pwm_frequency_hz: u32) -> Result<(), CoreError> {
+        let (min_on_us, min_off_us) = self.solenoid.min_pulses_us();
+        if !MIN_PULSE_RANGE_US.contains(&min_on_us) || !MIN_PULSE_RANGE_US.contains(&min_off_us) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Solenoid minimum pulses must be {}-{} µs, got {} µs on / {} µs off",
+                MIN_PULSE_RANGE_US.start(), MIN_PULSE_RANGE_US.end(), min_on_us, min_off_us
+            )));
+        }
+        
+        // Both bands together must leave a usable duty range
+        let period_us = 1_000_000 / pwm_frequency_hz.max(1);
+        if self.enabled && (min_on_us + min_off_us) * 2 > period_us {
+            return Err(CoreError::ConfigurationError(format!(
+                "Solenoid minimum pulses ({} µs on + {} µs off) leave no usable duty range at {} Hz PWM",
+                min_on_us, min_off_us, pwm_frequency_hz
+            )));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Duty quantizer for one solenoid output
+/// 
+/// 🔗 T4-CORE-113: Pulse Width Quantizer
+/// Derived From: T4-CORE-112
+#[derive(Debug, Clone)]
+pub struct PulseShaper {
+    enabled: bool,
+    /// Smallest duty that is not 0 % (%)
+    min_duty: f32,
+    /// Largest duty that is not 100 % (%)
+    max_duty: f32,
+    /// Output currently held off the 0 % stop
+    opened: bool,
+    /// Output currently held at 100 %
+    saturated: bool,
+}
+
+impl PulseShaper {
+    /// Create quantizer for the configured valve at a PWM frequency
+    pub fn new(config: &PulseShapingConfig, pwm_frequency_hz: u32) -> Self {
+        let (min_on_us, min_off_us) = config.solenoid.min_pulses_us();
+        let period_us = 1_000_000.0 / pwm_frequency_hz.max(1) as f32;
+        Self {
+            enabled: config.enabled,
+            min_duty: min_on_us as f32 / period_us * 100.0,
+            max_duty: 100.0 - min_off_us as f32 / period_us * 100.0,
+            opened: false,
+            saturated: false,
+        }
+    }
+    
+    /// Duty (%) to put on the output for a commanded duty
+    pub fn apply(&mut self, duty_percent: f32) -> f32 {
+        let duty = duty_percent.clamp(0.0, 100.0);
+        if !self.enabled {
+            return duty;
+        }
+        
+        // Bottom band: open at the minimum pulse, close again only below half of it
+        self.opened = if self.opened { duty >= self.min_duty / 2.0 } else { duty >= self.min_duty };
+        if !self.opened {
+            self.saturated = false;
+            return 0.0;
+        }
+        
+        // Top band: same, mirrored about 100 %
+        let off_gap = 100.0 - self.max_duty;
+        self.saturated = if self.saturated { duty > self.max_duty } else { duty > 100.0 - off_gap / 2.0 };
+        if self.saturated {
+            return 100.0;
+        }
+        
+        duty.clamp(self.min_duty, self.max_duty)
+    }
+    
+    /// Forget the held state - the output was forced to 0 % elsewhere
+    pub fn reset(&mut self) {
+        self.opened = false;
+        self.saturated = false;
+    }
+    
+    /// Smallest duty (%) other than 0 %
+    pub fn min_duty(&self) -> f32 {
+        self.min_duty
+    }
+    
+    /// Largest duty (%) other than 100 %
+    pub fn max_duty(&self) -> f32 {
+        self.max_duty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn shaper() -> PulseShaper {
+        // 3 ms / 2 ms at 30 Hz: 9 % minimum, 94 % maximum
+        PulseShaper::new(&PulseShapingConfig::default(), 30)
+    }
+    
+    #[test]
+    fn test_short_pulses_snap_to_a_usable_duty() {
+        let mut shaper = shaper();
+        assert!((shaper.min_duty() - 9.0).abs() < 0.01);
+        assert!((shaper.max_duty() - 94.0).abs() < 0.01);
+        
+        assert_eq!(shaper.apply(0.0), 0.0);
+        assert_eq!(shaper.apply(5.0), 0.0);
+        assert_eq!(shaper.apply(9.5), 9.5);
+        // Held open at the minimum pulse rather than sent a pulse the spool ignores
+        assert!((shaper.apply(6.0) - 9.0).abs() < 0.01);
+        assert_eq!(shaper.apply(3.0), 0.0);
+        
+        assert_eq!(shaper.apply(50.0), 50.0);
+        assert!((shaper.apply(96.0) - 94.0).abs() < 0.01);
+        assert_eq!(shaper.apply(97.5), 100.0);
+        assert_eq!(shaper.apply(96.0), 100.0);
+        assert_eq!(shaper.apply(93.0), 93.0);
+        assert_eq!(shaper.apply(100.0), 100.0);
+    }
+    
+    #[test]
+    fn test_noisy_command_on_a_threshold_does_not_chatter() {
+        let mut shaper = shaper();
+        let mut transitions = 0;
+        let mut last = 0.0;
+        
+        // Command wandering ±3 % around the minimum pulse (dither, sensor noise)
+        for cycle in 0..500 {
+            let noise = [3.0, -1.5, 0.5, -3.0, 2.0, -0.5][cycle % 6];
+            let out = shaper.apply(9.0 + noise);
+            if (out == 0.0) != (last == 0.0) {
+                transitions += 1;
+            }
+            last = out;
+        }
+        assert_eq!(transitions, 1);
+        
+        // Same at the top
+        let mut transitions = 0;
+        let mut last = 0.0;
+        for cycle in 0..500 {
+            let noise = [1.0, -1.0, 0.5, -0.5][cycle % 4];
+            let out = shaper.apply(97.0 + noise);
+            if (out == 100.0) != (last == 100.0) {
+                transitions += 1;
+            }
+            last = out;
+        }
+        assert!(transitions <= 1);
+    }
+    
+    #[test]
+    fn test_closed_loop_settles_without_limit_cycle() {
+        // Integral controller on a first-order dome; the target needs duty just
+        // above the minimum pulse, so the command crosses the threshold on the way
+        let mut shaper = shaper();
+        let target = 11.0;
+        let (mut command, mut dome) = (0.0f32, 0.0f32);
+        let mut outputs = alloc::vec::Vec::new();
+        
+        for cycle in 0..2000 {
+            // Measurement noise of ±1.5 %
+            let measured = dome + [1.5, -1.5, 0.75, -0.75][cycle % 4];
+            command = (command + 0.05 * (target - measured)).clamp(0.0, 100.0);
+            let duty = shaper.apply(command);
+            dome += (duty - dome) * 0.1;
+            outputs.push(duty);
+        }
+        
+        // One opening, never closed again, and settled on target
+        let openings = outputs.windows(2).filter(|w| w[0] == 0.0 && w[1] > 0.0).count();
+        let closings = outputs.windows(2).filter(|w| w[0] > 0.0 && w[1] == 0.0).count();
+        assert_eq!((openings, closings), (1, 0));
+        assert!((dome - target).abs() < 1.0);
+    }
+    
+    #[test]
+    fn test_disabled_passes_duty_through() {
+        let config = PulseShapingConfig { enabled: false, ..PulseShapingConfig::default() };
+        let mut shaper = PulseShaper::new(&config, 30);
+        assert_eq!(shaper.apply(2.0), 2.0);
+        assert_eq!(shaper.apply(99.0), 99.0);
+    }
+    
+    #[test]
+    fn test_validation_needs_a_usable_duty_range() {
+        assert!(PulseShapingConfig::default().validate(50).is_ok());
+        let slow = PulseShapingConfig {
+            enabled: true,
+            solenoid: SolenoidModel::Custom { min_on_us: 8_000, min_off_us: 4_000 },
+        };
+        assert!(slow.validate(30).is_ok());
+        assert!(slow.validate(50).is_err());
+        let implausible = PulseShapingConfig { solenoid: SolenoidModel::Custom { min_on_us: 100, min_off_us: 2_000 }, ..slow };
+        assert!(implausible.validate(30).is_err());
+    }
+}
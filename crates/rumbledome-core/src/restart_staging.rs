@@ -0,0 +1,165 @@
+//! Restart-Staged Parameters
+//! 
+//! 🔗 T4-CORE-099: Restart-Staged Configuration
+//! Derived From: T4-CORE-049 (Configuration Quick Switching) + T4-CORE-067 (Inner and Outer Control Rates)
+//! AI Traceability: Timing the running control loop and buses are built on must not shift under them - it changes at the next boot instead
+//! 
+//! PWM frequency, CAN bitrate and the control loop rates are set up once at
+//! boot. A configuration change that touches them is stored in the
+//! configuration library as usual - that stored entry is the pending area -
+//! but the running configuration keeps the values it booted with. The next
+//! boot loads the stored entry whole, so every staged value takes effect
+//! together.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::SystemConfig;
+
+/// Parameter that only takes effect at the next boot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RestartParameter {
+    /// Solenoid PWM frequency
+    PwmFrequency,
+    /// CAN bus bitrate
+    CanBitrate,
+    /// Inner (boost) control loop rate
+    InnerLoopRate,
+    /// Outer (Level 1 and learning) control loop rate
+    OuterLoopRate,
+}
+
+impl RestartParameter {
+    /// Every restart-staged parameter
+    pub const ALL: [RestartParameter; 4] = [
+        RestartParameter::PwmFrequency,
+        RestartParameter::CanBitrate,
+        RestartParameter::InnerLoopRate,
+        RestartParameter::OuterLoopRate,
+    ];
+    
+    /// Short label for status displays
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            RestartParameter::PwmFrequency => "PWM frequency",
+            RestartParameter::CanBitrate => "CAN bitrate",
+            RestartParameter::InnerLoopRate => "Inner loop rate",
+            RestartParameter::OuterLoopRate => "Outer loop rate",
+        }
+    }
+    
+    /// Unit the value is expressed in
+    pub fn unit(&self) -> &'static str {
+        match self {
+            RestartParameter::CanBitrate => "bit/s",
+            _ => "Hz",
+        }
+    }
+    
+    /// Value in a configuration
+    pub fn value(&self, config: &SystemConfig) -> u32 {
+        match self {
+            RestartParameter::PwmFrequency => config.pwm_frequency_hz,
+            RestartParameter::CanBitrate => config.can_bitrate_bps,
+            RestartParameter::InnerLoopRate => config.control_rates.inner_hz as u32,
+            RestartParameter::OuterLoopRate => config.control_rates.outer_hz as u32,
+        }
+    }
+    
+    fn copy(&self, from: &SystemConfig, to: &mut SystemConfig) {
+        match self {
+            RestartParameter::PwmFrequency => to.pwm_frequency_hz = from.pwm_frequency_hz,
+            RestartParameter::CanBitrate => to.can_bitrate_bps = from.can_bitrate_bps,
+            RestartParameter::InnerLoopRate => to.control_rates.inner_hz = from.control_rates.inner_hz,
+            RestartParameter::OuterLoopRate => to.control_rates.outer_hz = from.control_rates.outer_hz,
+        }
+    }
+}
+
+/// Parameter whose stored value waits for the next boot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StagedChange {
+    pub parameter: RestartParameter,
+    /// Value in effect since boot
+    pub running: u32,
+    /// Value that takes effect at the next boot
+    pub pending: u32,
+}
+
+/// Restart-staged parameters that differ between the running and stored configuration
+pub fn staged_changes(running: &SystemConfig, stored: &SystemConfig) -> Vec<StagedChange> {
+    RestartParameter::ALL
+        .into_iter()
+        .filter(|parameter| parameter.value(running) != parameter.value(stored))
+        .map(|parameter| StagedChange { parameter, running: parameter.value(running), pending: parameter.value(stored) })
+        .collect()
+}
+
+/// Replace every restart-staged parameter in `config` with the value from `source`
+pub fn copy_restart_parameters(source: &SystemConfig, config: &mut SystemConfig) {
+    for parameter in RestartParameter::ALL {
+        parameter.copy(source, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ControlRates;
+    
+    #[test]
+    fn test_only_restart_parameters_are_staged() {
+        let running = SystemConfig::default();
+        let mut stored = SystemConfig {
+            aggression: 0.8,
+            pwm_frequency_hz: 40,
+            control_rates: ControlRates { inner_hz: 200, outer_hz: 20 },
+            ..SystemConfig::default()
+        };
+        
+        let changes = staged_changes(&running, &stored);
+        assert_eq!(changes, alloc::vec![
+            StagedChange { parameter: RestartParameter::PwmFrequency, running: 30, pending: 40 },
+            StagedChange { parameter: RestartParameter::InnerLoopRate, running: 100, pending: 200 },
+        ]);
+        
+        // Holding the running values leaves the rest of the change in place
+        copy_restart_parameters(&running, &mut stored);
+        assert!(staged_changes(&running, &stored).is_empty());
+        assert_eq!(stored.aggression, 0.8);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::{ControlRates, RumbleDomeCore, DEFAULT_CONFIG_NAME};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_restart_parameters_wait_for_next_boot() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        
+        let changed = SystemConfig {
+            aggression: 0.8,
+            control_rates: ControlRates { inner_hz: 200, outer_hz: 20 },
+            ..SystemConfig::default()
+        };
+        core.save_configuration(DEFAULT_CONFIG_NAME, changed).unwrap();
+        
+        // Everything else applies now; the loop rate is pending
+        assert_eq!(core.config.aggression, 0.8);
+        assert_eq!(core.config.control_rates.inner_hz, 100);
+        assert_eq!(core.pending_restart(), alloc::vec![
+            StagedChange { parameter: RestartParameter::InnerLoopRate, running: 100, pending: 200 },
+        ]);
+        
+        // Writing the running configuration back keeps the pending value
+        assert_eq!(core.config_to_store().control_rates.inner_hz, 200);
+        
+        // Next boot loads the stored entry whole
+        let rebooted = RumbleDomeCore::with_configurations(MockHal::new(), core.configurations.clone());
+        assert_eq!(rebooted.config.control_rates.inner_hz, 200);
+        assert!(rebooted.pending_restart().is_empty());
+    }
+}
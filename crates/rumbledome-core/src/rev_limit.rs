@@ -0,0 +1,109 @@
+//! Rev Limiter Cooperation
+//! 
+//! 🔗 T4-CORE-042: Boost Taper Approaching Redline
+//! Derived From: T1-SAFETY-001 (Overboost as Fault Condition) + ECU rev limiter interaction
+//! AI Traceability: Wastegate already opening when the ECU cuts fuel/spark - no boost spike at the limiter
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Boost taper band below the rev limiter
+/// 
+/// When the ECU cuts fuel/spark at the limiter the torque gap jumps, the
+/// controller closes the wastegate, and boost spikes as soon as combustion
+/// resumes. Tapering the above-spring portion of the target to zero across the
+/// band means the wastegate is already open by the time the cut happens.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RevLimitTaper {
+    /// Enable boost taper near redline
+    pub enabled: bool,
+    /// ECU rev limiter RPM
+    pub redline_rpm: u16,
+    /// Width of the taper band below redline (RPM)
+    pub band_rpm: u16,
+}
+
+impl Default for RevLimitTaper {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redline_rpm: 7000,
+            band_rpm: 500,
+        }
+    }
+}
+
+impl RevLimitTaper {
+    /// Validate taper settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(3000..=10_000).contains(&self.redline_rpm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Redline must be 3000-10000 RPM, got {}", self.redline_rpm)
+            ));
+        }
+        
+        if !(100..=2000).contains(&self.band_rpm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Rev limit taper band must be 100-2000 RPM, got {}", self.band_rpm)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Fraction of above-spring boost authority allowed at this RPM (0.0-1.0)
+    pub fn authority(&self, rpm: u16) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        
+        let band_start = self.redline_rpm.saturating_sub(self.band_rpm);
+        if rpm <= band_start {
+            return 1.0;
+        }
+        
+        let into_band = (rpm - band_start) as f32 / self.band_rpm as f32;
+        (1.0 - into_band).clamp(0.0, 1.0)
+    }
+    
+    /// Apply taper to a boost target (PSI)
+    /// 
+    /// Spring pressure is the floor - the wastegate cannot open further than 0% duty.
+    pub fn apply(&self, target_psi: f32, rpm: u16, spring_pressure: f32) -> f32 {
+        if target_psi <= spring_pressure {
+            return target_psi;
+        }
+        
+        spring_pressure + (target_psi - spring_pressure) * self.authority(rpm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn taper() -> RevLimitTaper {
+        RevLimitTaper { enabled: true, redline_rpm: 7000, band_rpm: 500 }
+    }
+    
+    #[test]
+    fn test_no_taper_below_band() {
+        assert_eq!(taper().apply(12.0, 6500, 5.0), 12.0);
+    }
+    
+    #[test]
+    fn test_linear_taper_to_spring_at_redline() {
+        let taper = taper();
+        assert!((taper.apply(12.0, 6750, 5.0) - 8.5).abs() < 1e-4);
+        assert_eq!(taper.apply(12.0, 7000, 5.0), 5.0);
+        assert_eq!(taper.apply(12.0, 7400, 5.0), 5.0);
+    }
+    
+    #[test]
+    fn test_disabled_taper_passes_through() {
+        let taper = RevLimitTaper { enabled: false, ..taper() };
+        assert_eq!(taper.apply(12.0, 7000, 5.0), 12.0);
+    }
+}
@@ -0,0 +1,192 @@
+//! RPM Plausibility Validation
+//! 
+//! 🔗 T4-CORE-040: RPM Plausibility Model
+//! Derived From: T2-HAL-005 (Ford S550 CAN Signal Integration) + T1-SAFETY-002 (Defense in Depth)
+//! AI Traceability: Reject CAN RPM glitches, bridge short dropouts with last-good value
+
+use serde::{Deserialize, Serialize};
+
+/// Highest RPM accepted as physically plausible
+pub const MAX_PLAUSIBLE_RPM: u16 = 9000;
+
+/// Fastest plausible engine acceleration (RPM per second, free-revving in neutral)
+pub const MAX_RPM_RATE_PER_S: f32 = 15_000.0;
+
+/// Fixed allowance on top of the rate limit for signal quantization (RPM)
+const RATE_MARGIN_RPM: f32 = 100.0;
+
+/// Longest dropout bridged with the last good value (ms)
+pub const MAX_RPM_HOLD_MS: u32 = 200;
+
+/// Consecutive self-consistent rejected samples needed to re-sync onto a new value
+const RESYNC_SAMPLES: u8 = 3;
+
+/// Counters for RPM validation (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RpmValidationStats {
+    /// Samples accepted as plausible
+    pub accepted: u32,
+    /// Samples rejected for exceeding the plausible RPM range
+    pub rejected_out_of_range: u32,
+    /// Samples rejected for an implausible rate of change
+    pub rejected_rate: u32,
+    /// Cycles where a missing or rejected sample was replaced by the last good value
+    pub substituted: u32,
+    /// Cycles with no usable RPM (dropout longer than the hold time)
+    pub unavailable: u32,
+    /// Times the validator re-synced onto a consistent new value after rejecting it
+    pub resyncs: u32,
+}
+
+/// RPM validator
+/// 
+/// 🔗 T4-CORE-041: RPM Glitch Rejection
+/// Derived From: T4-CORE-040
+/// 
+/// If the reference value itself was a glitch that slipped through, a pure rate
+/// limit would reject every correct sample after it. A run of rejected samples
+/// that agree with each other is therefore accepted as the new truth.
+#[derive(Debug, Clone, Default)]
+pub struct RpmValidator {
+    last_good: Option<(u16, u32)>,
+    candidate: Option<(u16, u32)>,
+    candidate_count: u8,
+    stats: RpmValidationStats,
+}
+
+impl RpmValidator {
+    /// Create validator with no history
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Validation counters
+    pub fn stats(&self) -> &RpmValidationStats {
+        &self.stats
+    }
+    
+    /// Validate a raw RPM sample (`None` when no frame arrived this cycle)
+    /// 
+    /// Returns the RPM to use, or `None` when no trustworthy value is available.
+    pub fn validate(&mut self, raw: Option<u16>, now_ms: u32) -> Option<u16> {
+        match raw {
+            Some(rpm) if rpm > MAX_PLAUSIBLE_RPM => {
+                self.stats.rejected_out_of_range += 1;
+                self.substitute(now_ms)
+            },
+            Some(rpm) => {
+                let plausible = match self.fresh_last_good(now_ms) {
+                    Some((last_rpm, last_ms)) => Self::rate_plausible(last_rpm, last_ms, rpm, now_ms),
+                    // No recent reference - any in-range value is accepted
+                    None => true,
+                };
+                
+                if plausible {
+                    self.accept(rpm, now_ms)
+                } else if self.track_candidate(rpm, now_ms) {
+                    self.stats.resyncs += 1;
+                    self.accept(rpm, now_ms)
+                } else {
+                    self.stats.rejected_rate += 1;
+                    self.substitute(now_ms)
+                }
+            },
+            None => self.substitute(now_ms),
+        }
+    }
+    
+    fn accept(&mut self, rpm: u16, now_ms: u32) -> Option<u16> {
+        self.stats.accepted += 1;
+        self.last_good = Some((rpm, now_ms));
+        self.candidate = None;
+        self.candidate_count = 0;
+        Some(rpm)
+    }
+    
+    fn substitute(&mut self, now_ms: u32) -> Option<u16> {
+        match self.fresh_last_good(now_ms) {
+            Some((rpm, _)) => {
+                self.stats.substituted += 1;
+                Some(rpm)
+            },
+            None => {
+                self.stats.unavailable += 1;
+                None
+            },
+        }
+    }
+    
+    fn fresh_last_good(&self, now_ms: u32) -> Option<(u16, u32)> {
+        self.last_good.filter(|(_, at_ms)| now_ms.wrapping_sub(*at_ms) <= MAX_RPM_HOLD_MS)
+    }
+    
+    /// Track rejected samples; true once enough consistent ones have been seen
+    fn track_candidate(&mut self, rpm: u16, now_ms: u32) -> bool {
+        let consistent = self.candidate
+            .map(|(c_rpm, c_ms)| Self::rate_plausible(c_rpm, c_ms, rpm, now_ms))
+            .unwrap_or(false);
+        
+        self.candidate_count = if consistent { self.candidate_count + 1 } else { 1 };
+        self.candidate = Some((rpm, now_ms));
+        self.candidate_count >= RESYNC_SAMPLES
+    }
+    
+    fn rate_plausible(from_rpm: u16, from_ms: u32, to_rpm: u16, to_ms: u32) -> bool {
+        let dt_s = to_ms.wrapping_sub(from_ms).max(1) as f32 / 1000.0;
+        let delta = (to_rpm as f32 - from_rpm as f32).abs();
+        delta <= MAX_RPM_RATE_PER_S * dt_s + RATE_MARGIN_RPM
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_single_glitch_rejected_and_substituted() {
+        let mut validator = RpmValidator::new();
+        assert_eq!(validator.validate(Some(3000), 0), Some(3000));
+        assert_eq!(validator.validate(Some(3050), 10), Some(3050));
+        
+        // Glitch to an in-range but implausible value
+        assert_eq!(validator.validate(Some(7800), 20), Some(3050));
+        assert_eq!(validator.validate(Some(3100), 30), Some(3100));
+        
+        assert_eq!(validator.stats().rejected_rate, 1);
+        assert_eq!(validator.stats().substituted, 1);
+    }
+    
+    #[test]
+    fn test_out_of_range_rejected() {
+        let mut validator = RpmValidator::new();
+        validator.validate(Some(2500), 0);
+        assert_eq!(validator.validate(Some(u16::MAX), 10), Some(2500));
+        assert_eq!(validator.stats().rejected_out_of_range, 1);
+    }
+    
+    #[test]
+    fn test_dropout_bridged_then_unavailable() {
+        let mut validator = RpmValidator::new();
+        validator.validate(Some(4000), 0);
+        
+        assert_eq!(validator.validate(None, 100), Some(4000));
+        assert_eq!(validator.validate(None, 200), Some(4000));
+        assert_eq!(validator.validate(None, 210), None);
+        assert_eq!(validator.stats().unavailable, 1);
+        
+        // Fresh value after a long dropout is accepted without rate check
+        assert_eq!(validator.validate(Some(1200), 500), Some(1200));
+    }
+    
+    #[test]
+    fn test_consistent_step_change_resyncs() {
+        let mut validator = RpmValidator::new();
+        validator.validate(Some(6000), 0);
+        
+        // Accepted reference was wrong - real RPM is ~2000 and stays there
+        assert_eq!(validator.validate(Some(2000), 10), Some(6000));
+        assert_eq!(validator.validate(Some(2010), 20), Some(6000));
+        assert_eq!(validator.validate(Some(2020), 30), Some(2020));
+        assert_eq!(validator.stats().resyncs, 1);
+    }
+}
@@ -0,0 +1,419 @@
+//! Redundant Safety Limits
+//! 
+//! 🔗 T4-CORE-125: Two-out-of-Three Safety Limit Storage
+//! Derived From: T4-HAL-017 (On-Chip EEPROM Storage) + T1-SAFETY-002 (Defense in Depth)
+//! AI Traceability: A flipped bit in EEPROM can never quietly raise the overboost limit or the duty cap
+//! 
+//! The overboost limit and the duty cap are stored three times, each copy in
+//! a region of its own at the end of a different EEPROM wear region, and
+//! voted on at boot. A value two copies agree on is taken and the odd copy rewritten. When no two
+//! copies agree the limits are unknown: the controller comes up in a fault
+//! that refuses arming until a configuration is applied again.
+//! 
+//! A copy is 8 bytes - magic, overboost limit and duty cap in hundredths, a
+//! reserved byte and a CRC-16. Copies that were never written take no part
+//! in the vote: three erased copies are a fresh unit and are written from
+//! the configuration, and a single copy beside erased ones is provisioning
+//! cut short by key-off, which is completed. The copies are only rewritten
+//! when the limits change, so power lost in the middle of that write is the
+//! one way to reach an unreconcilable state without corruption.
+//! 
+//! The limits in force are the stricter of the stored copies and the running
+//! configuration, so neither can loosen the other.
+
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{NonVolatileStorage, StorageRegion, ERASED_BYTE, SAFETY_LIMITS_COPY_SIZE};
+use crate::command_token::Crc16;
+use crate::SystemConfig;
+
+/// First byte of every written copy
+const COPY_MAGIC: u8 = 0x5C;
+
+/// Stored steps per PSI and per percent
+const STEPS_PER_UNIT: f32 = 100.0;
+
+/// Overboost limit and duty cap
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SafetyLimits {
+    /// Boost that cuts to 0% duty (PSI)
+    pub overboost_limit_psi: f32,
+    /// Highest duty ever commanded (%)
+    pub max_duty_percent: f32,
+}
+
+impl SafetyLimits {
+    /// Limits a configuration sets, at stored resolution
+    pub fn of(config: &SystemConfig) -> Self {
+        Self {
+            overboost_limit_psi: quantize(config.overboost_limit),
+            max_duty_percent: quantize(config.max_duty_percent),
+        }
+    }
+    
+    /// Lower of each limit
+    pub fn stricter(&self, other: &SafetyLimits) -> Self {
+        Self {
+            overboost_limit_psi: self.overboost_limit_psi.min(other.overboost_limit_psi),
+            max_duty_percent: self.max_duty_percent.min(other.max_duty_percent),
+        }
+    }
+    
+    fn encode(&self) -> [u8; SAFETY_LIMITS_COPY_SIZE] {
+        let mut bytes = [0; SAFETY_LIMITS_COPY_SIZE];
+        bytes[0] = COPY_MAGIC;
+        bytes[1..3].copy_from_slice(&steps(self.overboost_limit_psi).to_le_bytes());
+        bytes[3..5].copy_from_slice(&steps(self.max_duty_percent).to_le_bytes());
+        let crc = copy_crc(&bytes);
+        bytes[6..].copy_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+    
+    /// Decode a stored copy; erased, torn and corrupt copies decode to `None`
+    fn decode(bytes: &[u8; SAFETY_LIMITS_COPY_SIZE]) -> Option<Self> {
+        let crc = u16::from_le_bytes([bytes[6], bytes[7]]);
+        if bytes[0] != COPY_MAGIC || crc != copy_crc(bytes) {
+            return None;
+        }
+        Some(Self {
+            overboost_limit_psi: u16::from_le_bytes([bytes[1], bytes[2]]) as f32 / STEPS_PER_UNIT,
+            max_duty_percent: u16::from_le_bytes([bytes[3], bytes[4]]) as f32 / STEPS_PER_UNIT,
+        })
+    }
+}
+
+fn steps(value: f32) -> u16 {
+    libm::roundf(value * STEPS_PER_UNIT).clamp(0.0, u16::MAX as f32) as u16
+}
+
+fn quantize(value: f32) -> f32 {
+    steps(value) as f32 / STEPS_PER_UNIT
+}
+
+/// CRC-16 over everything but the stored CRC
+fn copy_crc(bytes: &[u8; SAFETY_LIMITS_COPY_SIZE]) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update(&bytes[..SAFETY_LIMITS_COPY_SIZE - 2]);
+    crc.value()
+}
+
+/// Where the stored limits came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LimitsSource {
+    /// Copies not read yet
+    #[default]
+    NotLoaded,
+    /// All three copies agreed at boot
+    Unanimous,
+    /// Two copies agreed at boot; the third was rewritten
+    Majority,
+    /// Copies were blank at boot and were written from the configuration
+    Provisioned,
+    /// Copies were written from a configuration applied since boot
+    Rewritten,
+    /// No two copies agree - the limits are unknown
+    Unreconcilable,
+}
+
+/// Redundant limit storage (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SafetyLimitsStatus {
+    pub source: LimitsSource,
+    /// Limits the copies hold
+    pub stored: Option<SafetyLimits>,
+    /// Copies rewritten at boot to match the others
+    pub repaired: u8,
+    /// Stored limits differ from the running configuration - the stricter of each is in force
+    pub config_mismatch: bool,
+    /// Copy writes the storage refused
+    pub write_errors: u32,
+}
+
+/// Triplicated overboost limit and duty cap
+#[derive(Debug, Clone, Default)]
+pub struct SafetyLimitStore {
+    source: LimitsSource,
+    stored: Option<SafetyLimits>,
+    repaired: u8,
+    write_errors: u32,
+}
+
+impl SafetyLimitStore {
+    /// Create before the copies are read
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Vote on the stored copies, repairing the odd one out or provisioning blank ones
+    /// 
+    /// Returns false when no two copies agree. A copy that cannot be read
+    /// counts as corrupt.
+    pub fn load<S: NonVolatileStorage>(&mut self, storage: &mut S, configured: SafetyLimits) -> bool {
+        let mut copies = [None; 3];
+        let mut erased = [false; 3];
+        for (index, region) in StorageRegion::SAFETY_LIMITS.iter().enumerate() {
+            let mut bytes = [0; SAFETY_LIMITS_COPY_SIZE];
+            if storage.read(region.offset, &mut bytes).is_ok() {
+                erased[index] = bytes.iter().all(|byte| *byte == ERASED_BYTE);
+                copies[index] = SafetyLimits::decode(&bytes);
+            }
+        }
+        
+        let votes = |limits: SafetyLimits| copies.iter().filter(|copy| **copy == Some(limits)).count();
+        let leader = copies.iter().flatten().copied().max_by_key(|limits| votes(*limits));
+        let blank = erased.iter().filter(|erased| **erased).count();
+        let (limits, source) = match leader {
+            Some(limits) if votes(limits) == 3 => (limits, LimitsSource::Unanimous),
+            Some(limits) if votes(limits) == 2 => (limits, LimitsSource::Majority),
+            Some(limits) if blank == 2 => (limits, LimitsSource::Provisioned),
+            None if blank == 3 => (configured, LimitsSource::Provisioned),
+            _ => {
+                self.source = LimitsSource::Unreconcilable;
+                self.stored = None;
+                return false;
+            }
+        };
+        
+        for (index, region) in StorageRegion::SAFETY_LIMITS.iter().enumerate() {
+            if copies[index] != Some(limits) {
+                if storage.write(region.offset, &limits.encode()).is_ok() {
+                    self.repaired += !erased[index] as u8;
+                } else {
+                    self.write_errors += 1;
+                }
+            }
+        }
+        self.source = source;
+        self.stored = Some(limits);
+        true
+    }
+    
+    /// Write all three copies, unless they already hold these limits
+    pub fn store<S: NonVolatileStorage>(&mut self, storage: &mut S, limits: SafetyLimits) -> bool {
+        if self.source != LimitsSource::Unreconcilable && self.stored == Some(limits) {
+            return true;
+        }
+        
+        let mut written = true;
+        for region in StorageRegion::SAFETY_LIMITS {
+            if storage.write(region.offset, &limits.encode()).is_err() {
+                self.write_errors += 1;
+                written = false;
+            }
+        }
+        if written {
+            self.source = LimitsSource::Rewritten;
+            self.stored = Some(limits);
+        }
+        written
+    }
+    
+    /// Limits in force - the stricter of the stored copies and the configuration
+    pub fn in_force(&self, configured: SafetyLimits) -> SafetyLimits {
+        match &self.stored {
+            Some(stored) => stored.stricter(&configured),
+            None => configured,
+        }
+    }
+    
+    /// Current state against the running configuration's limits
+    pub fn status(&self, configured: SafetyLimits) -> SafetyLimitsStatus {
+        SafetyLimitsStatus {
+            source: self.source,
+            stored: self.stored,
+            repaired: self.repaired,
+            config_mismatch: self.stored.is_some_and(|stored| stored != configured),
+            write_errors: self.write_errors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::RamStorage;
+    
+    fn copy(storage: &RamStorage, index: usize) -> &[u8] {
+        let offset = StorageRegion::SAFETY_LIMITS[index].offset;
+        &storage.bytes[offset..offset + SAFETY_LIMITS_COPY_SIZE]
+    }
+    
+    fn write_copy(storage: &mut RamStorage, index: usize, limits: SafetyLimits) {
+        let offset = StorageRegion::SAFETY_LIMITS[index].offset;
+        storage.bytes[offset..offset + SAFETY_LIMITS_COPY_SIZE].copy_from_slice(&limits.encode());
+    }
+    
+    fn flip_bit(storage: &mut RamStorage, index: usize, byte: usize) {
+        storage.bytes[StorageRegion::SAFETY_LIMITS[index].offset + byte] ^= 0x01;
+    }
+    
+    fn limits(overboost_limit_psi: f32, max_duty_percent: f32) -> SafetyLimits {
+        SafetyLimits { overboost_limit_psi, max_duty_percent }
+    }
+    
+    #[test]
+    fn test_round_trip_at_stored_resolution() {
+        let config = SystemConfig { overboost_limit: 15.3, max_duty_percent: 87.25, ..SystemConfig::default() };
+        let configured = SafetyLimits::of(&config);
+        assert_eq!(SafetyLimits::decode(&configured.encode()), Some(configured));
+        
+        let mut bytes = configured.encode();
+        bytes[2] ^= 0x01;
+        assert_eq!(SafetyLimits::decode(&bytes), None);
+        assert_eq!(SafetyLimits::decode(&[ERASED_BYTE; SAFETY_LIMITS_COPY_SIZE]), None);
+    }
+    
+    #[test]
+    fn test_blank_copies_provisioned_from_configuration() {
+        let mut storage = RamStorage::new();
+        let mut store = SafetyLimitStore::new();
+        assert!(store.load(&mut storage, limits(15.0, 100.0)));
+        
+        let status = store.status(limits(15.0, 100.0));
+        assert_eq!(status.source, LimitsSource::Provisioned);
+        assert_eq!(status.repaired, 0);
+        for index in 0..3 {
+            assert_eq!(copy(&storage, index), limits(15.0, 100.0).encode());
+        }
+        
+        let mut reloaded = SafetyLimitStore::new();
+        assert!(reloaded.load(&mut storage, limits(15.0, 100.0)));
+        assert_eq!(reloaded.status(limits(15.0, 100.0)).source, LimitsSource::Unanimous);
+    }
+    
+    #[test]
+    fn test_corrupt_copy_outvoted_and_repaired() {
+        let mut storage = RamStorage::new();
+        SafetyLimitStore::new().store(&mut storage, limits(15.0, 100.0));
+        
+        // A flipped bit that happens to keep a valid CRC is still one vote against two
+        write_copy(&mut storage, 1, limits(25.0, 100.0));
+        let mut store = SafetyLimitStore::new();
+        assert!(store.load(&mut storage, limits(15.0, 100.0)));
+        assert_eq!(store.in_force(limits(15.0, 100.0)), limits(15.0, 100.0));
+        let status = store.status(limits(15.0, 100.0));
+        assert_eq!(status.source, LimitsSource::Majority);
+        assert_eq!(status.repaired, 1);
+        assert_eq!(copy(&storage, 1), limits(15.0, 100.0).encode());
+        
+        flip_bit(&mut storage, 2, 3);
+        assert!(SafetyLimitStore::new().load(&mut storage, limits(15.0, 100.0)));
+        assert_eq!(copy(&storage, 2), limits(15.0, 100.0).encode());
+    }
+    
+    #[test]
+    fn test_no_majority_is_unreconcilable() {
+        let mut storage = RamStorage::new();
+        write_copy(&mut storage, 0, limits(15.0, 100.0));
+        write_copy(&mut storage, 1, limits(16.0, 100.0));
+        flip_bit(&mut storage, 2, 0);
+        
+        let mut store = SafetyLimitStore::new();
+        assert!(!store.load(&mut storage, limits(15.0, 100.0)));
+        assert_eq!(store.status(limits(15.0, 100.0)).source, LimitsSource::Unreconcilable);
+        assert_eq!(store.status(limits(15.0, 100.0)).stored, None);
+        
+        // One good copy beside a corrupt one is not a vote either
+        let mut storage = RamStorage::new();
+        write_copy(&mut storage, 0, limits(15.0, 100.0));
+        write_copy(&mut storage, 1, limits(15.0, 100.0));
+        flip_bit(&mut storage, 1, 1);
+        assert!(!SafetyLimitStore::new().load(&mut storage, limits(15.0, 100.0)));
+        
+        // Applying limits again rewrites every copy
+        assert!(store.store(&mut storage, limits(15.0, 100.0)));
+        assert!(SafetyLimitStore::new().load(&mut storage, limits(15.0, 100.0)));
+    }
+    
+    #[test]
+    fn test_interrupted_provisioning_completed() {
+        let mut storage = RamStorage::new();
+        write_copy(&mut storage, 0, limits(14.0, 90.0));
+        
+        let mut store = SafetyLimitStore::new();
+        assert!(store.load(&mut storage, limits(15.0, 100.0)));
+        assert_eq!(copy(&storage, 2), limits(14.0, 90.0).encode());
+        
+        // The stored limits were stricter than the configuration, so they stay in force
+        let status = store.status(limits(15.0, 100.0));
+        assert_eq!(status.source, LimitsSource::Provisioned);
+        assert!(status.config_mismatch);
+        assert_eq!(store.in_force(limits(15.0, 80.0)), limits(14.0, 80.0));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use crate::{ConfigLibrary, FaultCode, RumbleDomeCore, SystemState};
+    use rumbledome_hal::MockHal;
+    
+    /// Core booted from an EEPROM image
+    fn boot(image: &[u8], config: &SystemConfig) -> RumbleDomeCore<MockHal> {
+        let mut hal = MockHal::new();
+        hal.set_storage_image(image);
+        let mut core = RumbleDomeCore::with_configurations(hal, ConfigLibrary::single("daily", config.clone()));
+        core.initialize().unwrap();
+        core
+    }
+    
+    #[test]
+    fn test_corrupt_copy_repaired_and_disagreement_blocks_arming() {
+        let config = SystemConfig::default();
+        let core = boot(&[], &config);
+        assert_eq!(core.get_system_status().safety_limits.source, LimitsSource::Provisioned);
+        
+        let mut image: Vec<u8> = core.hal.storage_image().to_vec();
+        image[StorageRegion::SAFETY_LIMITS[1].offset + 1] ^= 0x04;
+        let core = boot(&image, &config);
+        assert_eq!(core.state, SystemState::Idle);
+        let status = core.get_system_status().safety_limits;
+        assert_eq!((status.source, status.repaired), (LimitsSource::Majority, 1));
+        
+        // Two copies damaged differently leave nothing to vote for
+        let mut image: Vec<u8> = core.hal.storage_image().to_vec();
+        image[StorageRegion::SAFETY_LIMITS[0].offset + 1] ^= 0x04;
+        image[StorageRegion::SAFETY_LIMITS[2].offset + 3] ^= 0x01;
+        let mut core = boot(&image, &config);
+        assert_eq!(core.state, SystemState::Fault(FaultCode::SafetyLimitsCorrupted));
+        assert!(core.skip_commissioning().is_err());
+        assert!(core.arm().is_err());
+        
+        // Saving the configuration writes the limits afresh
+        core.save_configuration("daily", config.clone()).unwrap();
+        assert_eq!(core.state, SystemState::Idle);
+        core.skip_commissioning().unwrap();
+        core.arm().unwrap();
+        let core = boot(core.hal.storage_image(), &config);
+        assert_eq!(core.get_system_status().safety_limits.source, LimitsSource::Unanimous);
+    }
+    
+    #[test]
+    fn test_learned_data_saves_leave_the_copies_intact() {
+        let config = SystemConfig::default();
+        let mut core = boot(&[], &config);
+        let copies = |core: &RumbleDomeCore<MockHal>| StorageRegion::SAFETY_LIMITS
+            .map(|copy| core.hal.storage_image()[copy.offset..copy.offset + copy.len].to_vec());
+        let written = copies(&core);
+        
+        // Enough saves to go over every learned-data slot
+        for save in 1..=2 * crate::LEARNED_DATA_SLOTS as u32 {
+            core.learned_data.save_to_storage(&mut core.hal, save * 60_000).unwrap();
+        }
+        assert_eq!(copies(&core), written);
+        let core = boot(core.hal.storage_image(), &config);
+        let status = core.get_system_status().safety_limits;
+        assert_eq!((status.source, status.repaired), (LimitsSource::Unanimous, 0));
+    }
+    
+    #[test]
+    fn test_stricter_stored_limits_stay_in_force() {
+        let strict = SystemConfig { overboost_limit: 14.0, max_duty_percent: 80.0, ..SystemConfig::default() };
+        let core = boot(&[], &strict);
+        
+        let config = SystemConfig::default();
+        let core = boot(core.hal.storage_image(), &config);
+        assert!(core.get_system_status().safety_limits.config_mismatch);
+        assert_eq!(core.limits_in_force(), SafetyLimits { overboost_limit_psi: 14.0, max_duty_percent: 80.0 });
+    }
+}
@@ -0,0 +1,389 @@
+//! Safety Event Log
+//! 
+//! 🔗 T4-CORE-117: Persistent Safety Event Log
+//! Derived From: Safety.md (safety logs preserved across power cycles) + T4-HAL-017 (On-Chip EEPROM Storage)
+//! AI Traceability: Every overboost cut and fault survives key-off with what tripped it and how fast the output was made safe
+//! 
+//! Each entry into an overboost cut or a fault state is written at once to
+//! the safety log region as one 18-byte record: power-up session and time,
+//! event, trigger value and response time. The records form a ring, oldest
+//! overwritten first, and each carries a sequence number and a CRC, so power
+//! lost mid-write costs only the record being written.
+//! 
+//! The trigger value is the measurement the fault carries (the overboost
+//! pressure, the implausible reading, the CAN/analog disagreement), or the
+//! manifold pressure when it carries none. The response time is the length
+//! of the control cycle that applied the safe output, from reading inputs to
+//! the outputs being written.
+//! 
+//! A power-up logs at most `MAX_SAFETY_EVENTS_PER_SESSION` events, so a fault
+//! flapping on one drive cannot push every earlier drive out of the ring or
+//! wear the region; the rest are counted as dropped.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{HalResult, NonVolatileStorage, StorageRegion, ERASED_BYTE};
+use crate::command_token::Crc16;
+use crate::{FaultCode, SystemState};
+
+/// Size of one record (bytes)
+pub const SAFETY_RECORD_SIZE: usize = 18;
+
+/// Records in the safety log region
+pub const SAFETY_LOG_RECORDS: usize = StorageRegion::SAFETY_LOG.len / SAFETY_RECORD_SIZE;
+
+/// Events logged per power-up at most
+pub const MAX_SAFETY_EVENTS_PER_SESSION: u32 = 16;
+
+/// First byte of every written record
+const RECORD_MAGIC: u8 = 0x5A;
+
+/// Trigger value resolution
+const TRIGGER_STEP: f32 = 0.1;
+
+/// What was logged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SafetyEventKind {
+    /// Overboost cut engaged
+    OverboostCut,
+    /// Fault with its display number (`FaultCode::number`)
+    Fault(u8),
+}
+
+impl SafetyEventKind {
+    /// Logged event for a state, if it is one
+    pub fn of(state: &SystemState) -> Option<Self> {
+        match state {
+            SystemState::OverboostCut => Some(Self::OverboostCut),
+            SystemState::Fault(fault) => Some(Self::Fault(fault.number())),
+            _ => None,
+        }
+    }
+    
+    fn code(&self) -> u8 {
+        match self {
+            Self::OverboostCut => 1,
+            Self::Fault(number) => 0x80 | number,
+        }
+    }
+    
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Self::OverboostCut),
+            0x80..=0xFE => Some(Self::Fault(code & 0x7F)),
+            _ => None,
+        }
+    }
+}
+
+/// One logged event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafetyLogEntry {
+    /// Power-up session the event happened in
+    pub session: u16,
+    /// Time of the event (ms since power-up)
+    pub timestamp_ms: u32,
+    pub kind: SafetyEventKind,
+    /// Value that tripped it (0.1 resolution)
+    pub trigger_value: f32,
+    /// Cycle time that applied the safe output (µs)
+    pub response_time_us: u16,
+}
+
+/// Logger counters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SafetyLogStatus {
+    /// Current power-up session
+    pub session: u16,
+    /// Events written this power-up
+    pub logged: u32,
+    /// Events not written because this power-up's share was used
+    pub dropped: u32,
+    /// Record writes the storage refused
+    pub write_errors: u32,
+}
+
+/// Stored record, with its ring position
+#[derive(Debug, Clone, PartialEq)]
+struct SafetyRecord {
+    sequence: u32,
+    entry: SafetyLogEntry,
+}
+
+impl SafetyRecord {
+    fn encode(&self) -> [u8; SAFETY_RECORD_SIZE] {
+        let trigger = libm::roundf(self.entry.trigger_value / TRIGGER_STEP).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        let mut bytes = [0; SAFETY_RECORD_SIZE];
+        bytes[0] = RECORD_MAGIC;
+        bytes[1] = self.entry.kind.code();
+        bytes[2..6].copy_from_slice(&self.sequence.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.entry.session.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.entry.timestamp_ms.to_le_bytes());
+        bytes[12..14].copy_from_slice(&trigger.to_le_bytes());
+        bytes[14..16].copy_from_slice(&self.entry.response_time_us.to_le_bytes());
+        let crc = record_crc(&bytes);
+        bytes[16..].copy_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+    
+    /// Decode a stored record; erased, torn and corrupt records decode to `None`
+    fn decode(bytes: &[u8; SAFETY_RECORD_SIZE]) -> Option<Self> {
+        let crc = u16::from_le_bytes([bytes[16], bytes[17]]);
+        if bytes[0] != RECORD_MAGIC || crc != record_crc(bytes) {
+            return None;
+        }
+        Some(Self {
+            sequence: u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            entry: SafetyLogEntry {
+                session: u16::from_le_bytes([bytes[6], bytes[7]]),
+                timestamp_ms: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+                kind: SafetyEventKind::from_code(bytes[1])?,
+                trigger_value: i16::from_le_bytes([bytes[12], bytes[13]]) as f32 * TRIGGER_STEP,
+                response_time_us: u16::from_le_bytes([bytes[14], bytes[15]]),
+            },
+        })
+    }
+}
+
+/// CRC-16 over everything but the stored CRC
+fn record_crc(bytes: &[u8; SAFETY_RECORD_SIZE]) -> u16 {
+    let mut crc = Crc16::new();
+    crc.update(&bytes[..SAFETY_RECORD_SIZE - 2]);
+    crc.value()
+}
+
+/// Value that tripped a fault, when the fault carries one
+fn fault_trigger(fault: &FaultCode) -> Option<f32> {
+    match fault {
+        FaultCode::OverboostLimitExceeded { pressure_psi, .. } => Some(*pressure_psi),
+        FaultCode::ImplausibleSensorReading { value, .. } => Some(*value),
+        FaultCode::InputSourceMismatch { can_value, analog_value, .. } => Some(can_value - analog_value),
+        _ => None,
+    }
+}
+
+/// Ring logger for the safety log region
+#[derive(Debug, Clone, Default)]
+pub struct SafetyLog {
+    session: u16,
+    next_slot: usize,
+    next_sequence: u32,
+    /// Event of the state last seen, so a state is logged once on entry
+    current: Option<SafetyEventKind>,
+    logged: u32,
+    dropped: u32,
+    write_errors: u32,
+}
+
+impl SafetyLog {
+    /// Create for an empty log - `restore()` picks up an existing one
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume after the newest valid record, as a new session
+    pub fn restore<S: NonVolatileStorage>(&mut self, storage: &mut S) -> HalResult<()> {
+        let records = read_ring(storage)?;
+        if let Some((slot, newest)) = records.iter().max_by_key(|(_, record)| record.sequence) {
+            self.next_slot = (slot + 1) % SAFETY_LOG_RECORDS;
+            self.next_sequence = newest.sequence.wrapping_add(1);
+            self.session = newest.entry.session.wrapping_add(1);
+        }
+        Ok(())
+    }
+    
+    /// Log the state if this cycle entered an overboost cut or a fault; true when a record was written
+    pub fn observe<S: NonVolatileStorage>(
+        &mut self, storage: &mut S, state: &SystemState, manifold_psi: f32, response_time_us: u32, now_ms: u32,
+    ) -> bool {
+        let kind = SafetyEventKind::of(state);
+        let entered = kind.is_some() && kind != self.current;
+        self.current = kind;
+        let Some(kind) = kind.filter(|_| entered) else { return false };
+        
+        if self.logged >= MAX_SAFETY_EVENTS_PER_SESSION {
+            self.dropped += 1;
+            return false;
+        }
+        
+        let trigger_value = match state {
+            SystemState::Fault(fault) => fault_trigger(fault).unwrap_or(manifold_psi),
+            _ => manifold_psi,
+        };
+        let record = SafetyRecord {
+            sequence: self.next_sequence,
+            entry: SafetyLogEntry {
+                session: self.session,
+                timestamp_ms: now_ms,
+                kind,
+                trigger_value,
+                response_time_us: response_time_us.min(u16::MAX as u32) as u16,
+            },
+        };
+        let address = StorageRegion::SAFETY_LOG.offset + self.next_slot * SAFETY_RECORD_SIZE;
+        let written = storage.write(address, &record.encode()).is_ok();
+        if written {
+            self.logged += 1;
+        } else {
+            // The slot may hold a torn record now - its CRC keeps it out of the log
+            self.write_errors += 1;
+        }
+        self.next_slot = (self.next_slot + 1) % SAFETY_LOG_RECORDS;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        written
+    }
+    
+    /// Logged events, oldest first
+    pub fn entries<S: NonVolatileStorage>(&self, storage: &mut S) -> HalResult<Vec<SafetyLogEntry>> {
+        let mut records: Vec<SafetyRecord> = read_ring(storage)?.into_iter().map(|(_, record)| record).collect();
+        records.sort_by_key(|record| record.sequence);
+        Ok(records.into_iter().map(|record| record.entry).collect())
+    }
+    
+    /// Erase every record
+    pub fn clear<S: NonVolatileStorage>(&mut self, storage: &mut S) -> HalResult<()> {
+        storage.write(StorageRegion::SAFETY_LOG.offset, &[ERASED_BYTE; StorageRegion::SAFETY_LOG.len])?;
+        self.next_slot = 0;
+        Ok(())
+    }
+    
+    /// Current counters
+    pub fn status(&self) -> SafetyLogStatus {
+        SafetyLogStatus {
+            session: self.session,
+            logged: self.logged,
+            dropped: self.dropped,
+            write_errors: self.write_errors,
+        }
+    }
+}
+
+/// Every valid record in the ring with its slot
+fn read_ring<S: NonVolatileStorage>(storage: &mut S) -> HalResult<Vec<(usize, SafetyRecord)>> {
+    let mut records = Vec::new();
+    for slot in 0..SAFETY_LOG_RECORDS {
+        let mut bytes = [0; SAFETY_RECORD_SIZE];
+        storage.read(StorageRegion::SAFETY_LOG.offset + slot * SAFETY_RECORD_SIZE, &mut bytes)?;
+        if let Some(record) = SafetyRecord::decode(&bytes) {
+            records.push((slot, record));
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use crate::test_support::RamStorage;
+    
+    fn overboost_fault() -> SystemState {
+        SystemState::Fault(FaultCode::OverboostLimitExceeded { pressure_psi: 17.3, limit_psi: 16.0 })
+    }
+    
+    #[test]
+    fn test_events_logged_once_on_entry_and_survive_restart() {
+        let mut storage = RamStorage::new();
+        let mut log = SafetyLog::new();
+        assert!(!log.observe(&mut storage, &SystemState::Armed, 9.0, 300, 1_000));
+        assert!(log.observe(&mut storage, &SystemState::OverboostCut, 15.8, 420, 1_010));
+        assert!(!log.observe(&mut storage, &SystemState::OverboostCut, 16.2, 400, 1_020));
+        assert!(log.observe(&mut storage, &overboost_fault(), 16.5, 380, 1_030));
+        
+        // Key-off, key-on
+        let mut log = SafetyLog::new();
+        log.restore(&mut storage).unwrap();
+        assert_eq!(log.status().session, 1);
+        log.observe(&mut storage, &SystemState::Fault(FaultCode::PressureSensorFault(String::from("MAP"))), -14.0, 250, 50);
+        
+        let entries = log.entries(&mut storage).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!((entries[0].session, entries[0].timestamp_ms, entries[0].kind), (0, 1_010, SafetyEventKind::OverboostCut));
+        assert!((entries[0].trigger_value - 15.8).abs() < 1e-4);
+        assert_eq!(entries[0].response_time_us, 420);
+        assert_eq!(entries[1].kind, SafetyEventKind::Fault(6));
+        assert!((entries[1].trigger_value - 17.3).abs() < 1e-4);
+        assert_eq!((entries[2].session, entries[2].kind), (1, SafetyEventKind::Fault(3)));
+        assert!((entries[2].trigger_value + 14.0).abs() < 1e-4);
+    }
+    
+    #[test]
+    fn test_ring_wraps_and_torn_record_is_skipped() {
+        let mut storage = RamStorage::new();
+        for session in 0..4 {
+            let mut log = SafetyLog::new();
+            log.restore(&mut storage).unwrap();
+            for event in 0..10 {
+                log.observe(&mut storage, &SystemState::Armed, 0.0, 0, event * 100);
+                log.observe(&mut storage, &SystemState::OverboostCut, session as f32, 0, event * 100 + 10);
+            }
+        }
+        
+        // 40 events through a 28-record ring: the newest 28 remain, in order
+        let mut log = SafetyLog::new();
+        log.restore(&mut storage).unwrap();
+        let entries = log.entries(&mut storage).unwrap();
+        assert_eq!(entries.len(), SAFETY_LOG_RECORDS);
+        assert_eq!(entries.last().unwrap().session, 3);
+        assert_eq!(entries.iter().filter(|entry| entry.session == 1).count(), 8);
+        
+        storage.tear_after = Some(6);
+        assert!(!log.observe(&mut storage, &overboost_fault(), 16.0, 0, 5));
+        assert_eq!(log.status().write_errors, 1);
+        assert_eq!(log.entries(&mut storage).unwrap().len(), SAFETY_LOG_RECORDS - 1);
+    }
+    
+    #[test]
+    fn test_flapping_fault_is_capped_per_session() {
+        let mut storage = RamStorage::new();
+        let mut log = SafetyLog::new();
+        for cycle in 0..100 {
+            log.observe(&mut storage, &SystemState::Idle, 0.0, 0, cycle * 20);
+            log.observe(&mut storage, &overboost_fault(), 16.0, 0, cycle * 20 + 10);
+        }
+        let status = log.status();
+        assert_eq!((status.logged, status.dropped), (MAX_SAFETY_EVENTS_PER_SESSION, 100 - MAX_SAFETY_EVENTS_PER_SESSION));
+        
+        log.clear(&mut storage).unwrap();
+        assert!(log.entries(&mut storage).unwrap().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{ConfigLibrary, ControlMode, RumbleDomeCore, SystemConfig, SystemInputs};
+    use rumbledome_hal::MockHal;
+    
+    fn boosting(timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { rpm: 5000, manifold_pressure: 6.0, throttle_position: Some(100.0), aggression: 1.0, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_can_loss_is_logged_across_power_cycle() {
+        let config = SystemConfig { control_mode: ControlMode::TorqueFollowing, ..SystemConfig::default() };
+        let mut core = RumbleDomeCore::with_configurations(MockHal::new(), ConfigLibrary::single("daily", config.clone()));
+        core.initialize().unwrap();
+        core.skip_commissioning().unwrap();
+        core.arm().unwrap();
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(0)).unwrap();
+        core.execute_control_cycle_with_inputs(boosting(2_000)).unwrap();
+        core.execute_control_cycle_with_inputs(boosting(2_010)).unwrap();
+        assert_eq!(core.get_system_status().safety_log.logged, 1);
+        
+        let mut hal = MockHal::new();
+        hal.set_storage_image(core.hal.storage_image());
+        let mut core = RumbleDomeCore::with_configurations(hal, ConfigLibrary::single("daily", config));
+        core.initialize().unwrap();
+        let entries = core.safety_log_entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].session, entries[0].timestamp_ms), (0, 2_000));
+        assert_eq!(entries[0].kind, SafetyEventKind::Fault(FaultCode::CanCommunicationLost.number()));
+        assert!((entries[0].trigger_value - 6.0).abs() < 1e-4);
+        
+        core.clear_safety_log().unwrap();
+        assert!(core.safety_log_entries().unwrap().is_empty());
+    }
+}
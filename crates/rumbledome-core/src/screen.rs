@@ -0,0 +1,420 @@
+//! Screen Description
+//! 
+//! 🔗 T4-CORE-035: Semantic Screen Description
+//! Derived From: Hardware.md display requirements (ST7735R gauge pod) + remote support needs
+//! AI Traceability: Display mirroring to CLI/desktop without streaming raw framebuffer pixels
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{
+    SystemState, SystemConfig, OutputStageStatus, OutputDegradation, CanHealthLevel, ControlMode, AccentColor,
+    Headroom, TimedEvent, CoreEvent, DisplayString, Language, WatchReading, TurboBank,
+};
+
+/// How long a commissioning or maintenance event stays on the banner (ms)
+const EVENT_NOTICE_MS: u32 = 5000;
+
+/// Screen layout currently shown on the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScreenLayout {
+    /// Startup splash while hardware initializes
+    Startup,
+    /// Normal boost gauge
+    Gauge,
+    /// Auto-calibration progress screen
+    Calibration,
+    /// Full-screen fault display
+    Fault,
+}
+
+/// Alert banner severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertSeverity {
+    /// Informational - normal operation continues
+    Advisory,
+    /// Reduced performance mode
+    Warning,
+    /// Boost disabled
+    Critical,
+}
+
+/// Alert banner shown across the bottom of the gauge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenAlert {
+    /// Alert severity (drives banner color)
+    pub severity: AlertSeverity,
+    /// Banner text
+    pub text: String,
+}
+
+/// Semantic description of what the device display is showing
+/// 
+/// 🔗 T4-CORE-036: Display Mirror Content
+/// Derived From: T4-CORE-035
+/// Carries the same values the renderer draws so clients can reproduce the
+/// screen at a fraction of the bandwidth of a 128×160 RGB565 framebuffer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenDescription {
+    /// Active layout
+    pub layout: ScreenLayout,
+    /// State text shown at the top of the gauge
+    pub status_text: String,
+    /// Gauge needle value - manifold pressure (PSI gauge)
+    pub boost_psi: f32,
+    /// Target marker on the gauge ring (PSI)
+    pub target_psi: f32,
+    /// Gauge full-scale value (PSI)
+    pub gauge_max_psi: f32,
+    /// Aggression shown as background fill (0-100%)
+    pub aggression_percent: u8,
+    /// Level 1 control mode (boost-by-target is flagged on the gauge)
+    #[serde(default)]
+    pub control_mode: ControlMode,
+    /// Active profile's gauge ring color
+    #[serde(default)]
+    pub accent_color: AccentColor,
+    /// Active profile's label shown beside the state text (empty for none)
+    #[serde(default)]
+    pub profile_label: String,
+    /// Exhaust manifold pressure, when a backpressure sensor is fitted (PSI gauge)
+    #[serde(default)]
+    pub exhaust_backpressure_psi: Option<f32>,
+    /// Highest priority alert banner, if any
+    pub alert: Option<ScreenAlert>,
+    /// Margin to each limit (none before the first control cycle)
+    #[serde(default)]
+    pub headroom: Option<Headroom>,
+    /// Language the text is in (renderers draw their own labels in it too)
+    #[serde(default)]
+    pub language: Language,
+    /// Display timestamp (ms since start)
+    pub timestamp_ms: u32,
+    /// Watched internal variables, appended for the client that registered them
+    #[serde(default)]
+    pub watches: Vec<WatchReading>,
+}
+
+/// Everything the screen description is derived from
+pub struct ScreenInputs<'a> {
+    /// Current system state
+    pub state: &'a SystemState,
+    /// Active configuration
+    pub config: &'a SystemConfig,
+    /// Level 1 control mode in effect (boost-by-target while torque data is missing)
+    pub control_mode: ControlMode,
+    /// Aggression in control (knob, drive mode or app, else the stored setting)
+    pub aggression: f32,
+    /// Latest manifold pressure (PSI gauge)
+    pub boost_psi: f32,
+    /// Latest boost target (PSI)
+    pub target_psi: f32,
+    /// Output stage status
+    pub output_stage: &'a OutputStageStatus,
+    /// CAN health classification
+    pub can_health: CanHealthLevel,
+    /// Whether uncommitted live-tune values are in effect
+    pub live_tune_active: bool,
+    /// MAP auto-zero has flagged the sensor (drift or implausible zero)
+    pub map_sensor_advisory: bool,
+    /// Twin turbo bank consistently needing more duty than the other
+    pub bank_imbalance: Option<TurboBank>,
+    /// Code a client must send back to confirm a protected command
+    pub confirmation_code: Option<u16>,
+    /// First maintenance reminder currently due, if any
+    pub maintenance_due: Option<&'a str>,
+    /// Margin to each limit from the latest control cycle
+    pub headroom: Option<&'a Headroom>,
+    /// Latest exhaust backpressure reading (PSI gauge)
+    pub exhaust_backpressure_psi: Option<f32>,
+    /// Boost is being limited for a high exhaust-to-boost ratio
+    pub turbine_protection_active: bool,
+    /// Most recent event from the event bus
+    pub latest_event: Option<&'a TimedEvent>,
+    /// Display language preference
+    pub language: Language,
+    /// Display timestamp (ms)
+    pub timestamp_ms: u32,
+}
+
+impl ScreenDescription {
+    /// Build screen description from current system data
+    pub fn build(inputs: &ScreenInputs) -> Self {
+        let layout = match inputs.state {
+            SystemState::Initializing => ScreenLayout::Startup,
+            SystemState::Calibrating(_) | SystemState::Commissioning(_) => ScreenLayout::Calibration,
+            SystemState::Fault(fault) if fault.is_critical() => ScreenLayout::Fault,
+            _ => ScreenLayout::Gauge,
+        };
+        
+        Self {
+            layout,
+            status_text: inputs.state.localized_text(inputs.language),
+            boost_psi: inputs.boost_psi,
+            target_psi: inputs.target_psi,
+            gauge_max_psi: inputs.config.overboost_limit,
+            aggression_percent: (inputs.aggression * 100.0) as u8,
+            control_mode: inputs.control_mode,
+            accent_color: inputs.config.theme.accent,
+            profile_label: inputs.config.theme.label.clone(),
+            exhaust_backpressure_psi: inputs.exhaust_backpressure_psi,
+            alert: Self::select_alert(inputs),
+            headroom: inputs.headroom.cloned(),
+            language: inputs.language,
+            timestamp_ms: inputs.timestamp_ms,
+            watches: Vec::new(),
+        }
+    }
+    
+    /// Pick the single most important alert banner
+    fn select_alert(inputs: &ScreenInputs) -> Option<ScreenAlert> {
+        let language = inputs.language;
+        let alert = |severity, id: DisplayString| Some(ScreenAlert { severity, text: id.text(language).to_string() });
+        
+        // Only shown while someone is confirming a command, and only readable at the device
+        if let Some(code) = inputs.confirmation_code {
+            let text = format!("{} {:04}", DisplayString::AlertConfirmCode.text(language), code);
+            return Some(ScreenAlert { severity: AlertSeverity::Warning, text });
+        }
+        
+        match inputs.state {
+            SystemState::Fault(fault) => {
+                // Numeric code, so the banner reads the same in every language
+                let severity = if fault.is_critical() { AlertSeverity::Critical } else { AlertSeverity::Warning };
+                let text = format!("{} {}", DisplayString::StateFault.text(language), fault.display_code());
+                return Some(ScreenAlert { severity, text });
+            },
+            SystemState::OverboostCut => return alert(AlertSeverity::Critical, DisplayString::AlertOverboostCut),
+            _ => {},
+        }
+        
+        if inputs.output_stage.degradation == OutputDegradation::ReducedAuthority {
+            return alert(AlertSeverity::Warning, DisplayString::AlertSolenoidLimp);
+        }
+        
+        if matches!(inputs.can_health, CanHealthLevel::Degraded | CanHealthLevel::Critical) {
+            return alert(AlertSeverity::Warning, DisplayString::AlertCanDegraded);
+        }
+        
+        if inputs.turbine_protection_active {
+            return alert(AlertSeverity::Advisory, DisplayString::AlertBackpressure);
+        }
+        
+        if inputs.headroom.is_some_and(|headroom| headroom.thermal_derate.is_some()) {
+            return alert(AlertSeverity::Advisory, DisplayString::AlertHeatDerate);
+        }
+        
+        if inputs.live_tune_active {
+            return alert(AlertSeverity::Advisory, DisplayString::AlertLiveTune);
+        }
+        
+        if inputs.map_sensor_advisory {
+            return alert(AlertSeverity::Advisory, DisplayString::AlertCheckMapSensor);
+        }
+        
+        if let Some(bank) = inputs.bank_imbalance {
+            let text = format!("{} {}", DisplayString::AlertBankImbalance.text(language), bank.index() + 1);
+            return Some(ScreenAlert { severity: AlertSeverity::Advisory, text });
+        }
+        
+        // Reminder names are user-entered and shown as written
+        if let Some(task) = inputs.maintenance_due {
+            let text = format!("{}: {}", DisplayString::AlertService.text(language), task);
+            return Some(ScreenAlert { severity: AlertSeverity::Advisory, text });
+        }
+        
+        // Brief confirmation of commissioning progress and maintenance routines
+        if let Some(latest) = inputs.latest_event {
+            let fresh = inputs.timestamp_ms.wrapping_sub(latest.timestamp_ms) < EVENT_NOTICE_MS;
+            if let Some(text) = Self::event_notice(&latest.event, language).filter(|_| fresh) {
+                return Some(ScreenAlert { severity: AlertSeverity::Advisory, text });
+            }
+        }
+        
+        None
+    }
+    
+    /// Banner text for events worth a brief notice
+    fn event_notice(event: &CoreEvent, language: Language) -> Option<String> {
+        match event {
+            CoreEvent::CommissioningStep { step, outcome } => Some(format!("{}: {}",
+                step.localized_text(language), outcome.localized_text(language)).to_uppercase()),
+            CoreEvent::SolenoidExercise(record) if record.aborted => {
+                Some(DisplayString::AlertExerciseCutShort.text(language).to_string())
+            },
+            CoreEvent::SolenoidExercise(_) => Some(DisplayString::AlertExerciseDone.text(language).to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FaultCode, ProfileTheme, SolenoidFailover, SolenoidTopology};
+    
+    fn inputs<'a>(state: &'a SystemState, config: &'a SystemConfig, output_stage: &'a OutputStageStatus) -> ScreenInputs<'a> {
+        ScreenInputs {
+            state,
+            config,
+            control_mode: config.control_mode,
+            aggression: config.aggression,
+            boost_psi: 8.5,
+            target_psi: 9.0,
+            output_stage,
+            can_health: CanHealthLevel::Healthy,
+            live_tune_active: false,
+            map_sensor_advisory: false,
+            bank_imbalance: None,
+            confirmation_code: None,
+            maintenance_due: None,
+            headroom: None,
+            exhaust_backpressure_psi: None,
+            turbine_protection_active: false,
+            latest_event: None,
+            language: Language::English,
+            timestamp_ms: 1234,
+        }
+    }
+    
+    #[test]
+    fn test_normal_gauge_screen() {
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Armed;
+        
+        let screen = ScreenDescription::build(&inputs(&state, &config, &output));
+        assert_eq!(screen.layout, ScreenLayout::Gauge);
+        assert_eq!(screen.status_text, "ARMED");
+        assert_eq!(screen.aggression_percent, 30);
+        assert!(screen.alert.is_none());
+    }
+    
+    #[test]
+    fn test_profile_theme_carried_to_screen() {
+        let config = SystemConfig {
+            theme: ProfileTheme { accent: AccentColor::RED, label: "TRACK".to_string() },
+            ..SystemConfig::default()
+        };
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Armed;
+        
+        let screen = ScreenDescription::build(&inputs(&state, &config, &output));
+        assert_eq!(screen.accent_color, AccentColor::RED);
+        assert_eq!(screen.profile_label, "TRACK");
+    }
+    
+    #[test]
+    fn test_fault_takes_alert_priority() {
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Fault(FaultCode::CanCommunicationLost);
+        let mut screen_inputs = inputs(&state, &config, &output);
+        screen_inputs.live_tune_active = true;
+        
+        let screen = ScreenDescription::build(&screen_inputs);
+        assert_eq!(screen.layout, ScreenLayout::Fault);
+        let alert = screen.alert.unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert_eq!(alert.text, "FAULT F04");
+    }
+    
+    #[test]
+    fn test_confirmation_code_shown_over_everything() {
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Fault(FaultCode::CanCommunicationLost);
+        let mut screen_inputs = inputs(&state, &config, &output);
+        screen_inputs.confirmation_code = Some(4821);
+        
+        let screen = ScreenDescription::build(&screen_inputs);
+        assert_eq!(screen.layout, ScreenLayout::Fault);
+        assert_eq!(screen.alert.unwrap().text, "CONFIRM CODE 4821");
+    }
+    
+    #[test]
+    fn test_screen_text_follows_language() {
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Armed;
+        let mut screen_inputs = inputs(&state, &config, &output);
+        screen_inputs.language = Language::German;
+        screen_inputs.live_tune_active = true;
+        
+        let screen = ScreenDescription::build(&screen_inputs);
+        assert_eq!(screen.status_text, "AKTIV");
+        assert_eq!(screen.alert.unwrap().text, "LIVE-TUNING AKTIV");
+        assert_eq!(screen.language, Language::German);
+        
+        // Fault codes do not change with the language
+        let fault = SystemState::Fault(FaultCode::CanCommunicationLost);
+        screen_inputs.state = &fault;
+        assert!(ScreenDescription::build(&screen_inputs).alert.unwrap().text.ends_with("F04"));
+    }
+    
+    #[test]
+    fn test_limp_mode_warning_banner() {
+        let config = SystemConfig::default();
+        let mut failover = SolenoidFailover::new(SolenoidTopology::DualSolenoid);
+        failover.update(
+            rumbledome_hal::SolenoidDriveStatus::OpenCircuit,
+            rumbledome_hal::SolenoidDriveStatus::Ok,
+        );
+        let output = failover.status();
+        let state = SystemState::Armed;
+        
+        let screen = ScreenDescription::build(&inputs(&state, &config, &output));
+        assert_eq!(screen.alert.unwrap().text, "SOLENOID FAULT - LIMP MODE");
+    }
+    
+    #[test]
+    fn test_maintenance_is_lowest_priority_advisory() {
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Armed;
+        let mut screen_inputs = inputs(&state, &config, &output);
+        screen_inputs.maintenance_due = Some("Inspect dome lines");
+        
+        let alert = ScreenDescription::build(&screen_inputs).alert.unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Advisory);
+        assert_eq!(alert.text, "SERVICE: Inspect dome lines");
+        
+        screen_inputs.bank_imbalance = Some(TurboBank::Bank2);
+        assert_eq!(ScreenDescription::build(&screen_inputs).alert.unwrap().text, "CHECK WASTEGATE BANK 2");
+        
+        screen_inputs.map_sensor_advisory = true;
+        assert_eq!(ScreenDescription::build(&screen_inputs).alert.unwrap().text, "CHECK MAP SENSOR");
+        
+        screen_inputs.exhaust_backpressure_psi = Some(21.5);
+        screen_inputs.turbine_protection_active = true;
+        let screen = ScreenDescription::build(&screen_inputs);
+        assert_eq!(screen.alert.unwrap().text, "BACKPRESSURE - BOOST REDUCED");
+        assert_eq!(screen.exhaust_backpressure_psi, Some(21.5));
+        
+        screen_inputs.can_health = CanHealthLevel::Degraded;
+        assert_eq!(ScreenDescription::build(&screen_inputs).alert.unwrap().text, "CAN DATA DEGRADED");
+    }
+    
+    #[test]
+    fn test_recent_event_notice_expires() {
+        use crate::{CoreEvent, CommissioningStep, StepOutcome};
+        
+        let config = SystemConfig::default();
+        let output = SolenoidFailover::new(SolenoidTopology::SingleFourPort).status();
+        let state = SystemState::Idle;
+        let event = TimedEvent {
+            timestamp_ms: 1000,
+            event: CoreEvent::CommissioningStep { step: CommissioningStep::LeakTest, outcome: StepOutcome::Completed },
+        };
+        let mut screen_inputs = inputs(&state, &config, &output);
+        screen_inputs.latest_event = Some(&event);
+        
+        let alert = ScreenDescription::build(&screen_inputs).alert.unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Advisory);
+        assert!(alert.text.starts_with("LEAK TEST"), "{}", alert.text);
+        
+        screen_inputs.timestamp_ms = 1000 + EVENT_NOTICE_MS;
+        assert!(ScreenDescription::build(&screen_inputs).alert.is_none());
+    }
+}
@@ -0,0 +1,281 @@
+//! Screen Rendering
+//! 
+//! 🔗 T4-CORE-131: Gauge Renderer
+//! Derived From: T4-CORE-035 (Semantic Screen Description) + T4-HAL-022 (Display Interface)
+//! AI Traceability: The firmware and the simulator draw the same pixels from the same description
+//! 
+//! Every frame is drawn whole from a `ScreenDescription`: the gauge ring in
+//! the profile's accent color with the needle value in the middle, the target
+//! marker, aggression bar and alert banner. Text uses a 5×7 font; lower case
+//! is drawn as upper case and characters outside it as `?`.
+
+use alloc::format;
+use alloc::string::String;
+use rumbledome_hal::{rgb565, DisplayInterface, HalResult};
+use crate::{AlertSeverity, ControlMode, DisplayString, ScreenDescription, ScreenLayout};
+
+const BLACK: u16 = rgb565(0x00, 0x00, 0x00);
+const WHITE: u16 = rgb565(0xFF, 0xFF, 0xFF);
+const GREY: u16 = rgb565(0x90, 0x90, 0x90);
+const RING_TRACK: u16 = rgb565(0x30, 0x30, 0x30);
+const FAULT_RED: u16 = rgb565(0xB0, 0x10, 0x10);
+const ADVISORY_BLUE: u16 = rgb565(0x20, 0x60, 0xC0);
+const WARNING_AMBER: u16 = rgb565(0xFF, 0xA0, 0x00);
+const CRITICAL_RED: u16 = rgb565(0xE0, 0x20, 0x20);
+
+/// Aggression bar color per quarter (puppy dog, daily, spirited, brimstone)
+const AGGRESSION_COLORS: [u16; 4] = [
+    rgb565(0x20, 0xC0, 0x40), rgb565(0xC0, 0xC0, 0x20), rgb565(0xFF, 0x80, 0x00), rgb565(0xFF, 0x20, 0x20),
+];
+
+/// Gauge ring center and radius (pixels)
+const RING_CENTER: (f32, f32) = (64.0, 70.0);
+const RING_RADIUS: f32 = 46.0;
+
+/// Ring sweep: starts lower left, runs clockwise over the top to lower right (degrees)
+const RING_START_DEG: f32 = 150.0;
+const RING_SWEEP_DEG: f32 = 240.0;
+
+/// Alert banner height at the bottom of the screen (pixels)
+const BANNER_HEIGHT: u16 = 16;
+
+/// Glyph cell width including spacing (pixels at scale 1)
+const GLYPH_ADVANCE: u16 = 6;
+
+/// 5×7 glyphs for ' ' to '_', one byte per column, least significant bit at the top
+const FONT: [[u8; 5]; 64] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5F, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00], [0x14, 0x7F, 0x14, 0x7F, 0x14],
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62], [0x36, 0x49, 0x56, 0x20, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00],
+    [0x00, 0x1C, 0x22, 0x41, 0x00], [0x00, 0x41, 0x22, 0x1C, 0x00], [0x14, 0x08, 0x3E, 0x08, 0x14], [0x08, 0x08, 0x3E, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00], [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], [0x00, 0x42, 0x7F, 0x40, 0x00], [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4B, 0x31],
+    [0x18, 0x14, 0x12, 0x7F, 0x10], [0x27, 0x45, 0x45, 0x45, 0x39], [0x3C, 0x4A, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1E], [0x00, 0x36, 0x36, 0x00, 0x00], [0x00, 0x56, 0x36, 0x00, 0x00],
+    [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14], [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06],
+    [0x32, 0x49, 0x79, 0x41, 0x3E], [0x7E, 0x11, 0x11, 0x11, 0x7E], [0x7F, 0x49, 0x49, 0x49, 0x36], [0x3E, 0x41, 0x41, 0x41, 0x22],
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], [0x7F, 0x49, 0x49, 0x49, 0x41], [0x7F, 0x09, 0x09, 0x09, 0x01], [0x3E, 0x41, 0x49, 0x49, 0x7A],
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], [0x00, 0x41, 0x7F, 0x41, 0x00], [0x20, 0x40, 0x41, 0x3F, 0x01], [0x7F, 0x08, 0x14, 0x22, 0x41],
+    [0x7F, 0x40, 0x40, 0x40, 0x40], [0x7F, 0x02, 0x0C, 0x02, 0x7F], [0x7F, 0x04, 0x08, 0x10, 0x7F], [0x3E, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x09, 0x09, 0x09, 0x06], [0x3E, 0x41, 0x51, 0x21, 0x5E], [0x7F, 0x09, 0x19, 0x29, 0x46], [0x46, 0x49, 0x49, 0x49, 0x31],
+    [0x01, 0x01, 0x7F, 0x01, 0x01], [0x3F, 0x40, 0x40, 0x40, 0x3F], [0x1F, 0x20, 0x40, 0x20, 0x1F], [0x3F, 0x40, 0x38, 0x40, 0x3F],
+    [0x63, 0x14, 0x08, 0x14, 0x63], [0x07, 0x08, 0x70, 0x08, 0x07], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7F, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7F, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04], [0x40, 0x40, 0x40, 0x40, 0x40],
+];
+
+/// Glyph for a character
+fn glyph(c: char) -> &'static [u8; 5] {
+    let c = c.to_ascii_uppercase();
+    match c {
+        ' '..='_' => &FONT[c as usize - ' ' as usize],
+        _ => &FONT['?' as usize - ' ' as usize],
+    }
+}
+
+/// Width of `text` drawn at `scale` (pixels)
+fn text_width(text: &str, scale: u16) -> u16 {
+    (text.chars().count() as u16 * GLYPH_ADVANCE).saturating_sub(1) * scale
+}
+
+/// Draw `text` with its top left corner at (x, y)
+fn draw_text(display: &mut impl DisplayInterface, x: u16, y: u16, text: &str, color: u16, scale: u16) -> HalResult<()> {
+    for (index, c) in text.chars().enumerate() {
+        let left = x + index as u16 * GLYPH_ADVANCE * scale;
+        for (column, bits) in glyph(c).iter().enumerate() {
+            for row in 0..7 {
+                if bits & (1 << row) != 0 {
+                    display.fill_rect(left + column as u16 * scale, y + row * scale, scale, scale, color)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Draw `text` centered horizontally, cut to what fits the panel
+fn draw_centered(display: &mut impl DisplayInterface, y: u16, text: &str, color: u16, scale: u16) -> HalResult<()> {
+    let (width, _) = display.display_size();
+    let fits = ((width + 1) / (GLYPH_ADVANCE * scale)) as usize;
+    let text: String = text.chars().take(fits).collect();
+    let x = width.saturating_sub(text_width(&text, scale)) / 2;
+    draw_text(display, x, y, &text, color, scale)
+}
+
+/// Point on the gauge ring for a fraction of full scale
+fn ring_point(fraction: f32, radius: f32) -> (u16, u16) {
+    let degrees = RING_START_DEG + RING_SWEEP_DEG * fraction.clamp(0.0, 1.0);
+    let radians = degrees * core::f32::consts::PI / 180.0;
+    let x = RING_CENTER.0 + radius * libm::cosf(radians);
+    let y = RING_CENTER.1 + radius * libm::sinf(radians);
+    (libm::roundf(x).max(0.0) as u16, libm::roundf(y).max(0.0) as u16)
+}
+
+/// Gauge ring: track, boost fill in the accent color, target marker
+fn draw_ring(display: &mut impl DisplayInterface, screen: &ScreenDescription) -> HalResult<()> {
+    let full_scale = screen.gauge_max_psi.max(1.0);
+    let boost = screen.boost_psi / full_scale;
+    let accent = screen.accent_color.to_rgb565();
+    
+    // One 3×3 dot every 2 degrees
+    let steps = (RING_SWEEP_DEG / 2.0) as u16;
+    for step in 0..=steps {
+        let fraction = step as f32 / steps as f32;
+        let (x, y) = ring_point(fraction, RING_RADIUS);
+        let color = if fraction <= boost { accent } else { RING_TRACK };
+        display.fill_rect(x.saturating_sub(1), y.saturating_sub(1), 3, 3, color)?;
+    }
+    
+    let target = screen.target_psi / full_scale;
+    if target > 0.0 {
+        for radius in 40..=52 {
+            let (x, y) = ring_point(target, radius as f32);
+            display.fill_rect(x, y, 2, 2, WHITE)?;
+        }
+    }
+    Ok(())
+}
+
+/// Status text on the left of the top line, profile label in its accent color on the right
+fn draw_title(display: &mut impl DisplayInterface, screen: &ScreenDescription) -> HalResult<()> {
+    let (width, _) = display.display_size();
+    draw_text(display, 2, 2, &screen.status_text, WHITE, 1)?;
+    if !screen.profile_label.is_empty() {
+        let x = width.saturating_sub(text_width(&screen.profile_label, 1) + 2);
+        draw_text(display, x, 2, &screen.profile_label, screen.accent_color.to_rgb565(), 1)?;
+    }
+    Ok(())
+}
+
+/// Boost gauge (also shown, without the aggression bar, while calibrating)
+fn draw_gauge(display: &mut impl DisplayInterface, screen: &ScreenDescription) -> HalResult<()> {
+    let text = |id: DisplayString| id.text(screen.language);
+    let (width, _) = display.display_size();
+    
+    draw_title(display, screen)?;
+    draw_ring(display, screen)?;
+    draw_centered(display, 60, &format!("{:.1}", screen.boost_psi), WHITE, 3)?;
+    draw_centered(display, 86, "PSI", GREY, 1)?;
+    draw_text(display, 2, 112, &format!("{} {:.1}", text(DisplayString::LabelTarget), screen.target_psi), GREY, 1)?;
+    
+    let mut notes = String::new();
+    if screen.control_mode == ControlMode::BoostByTarget {
+        notes.push_str(screen.control_mode.localized_text(screen.language));
+    }
+    if let Some(psi) = screen.exhaust_backpressure_psi {
+        notes = format!("{} {} {:.1}", notes, text(DisplayString::LabelExhaust), psi).trim_start().into();
+    }
+    draw_text(display, 2, 121, &notes, GREY, 1)?;
+    
+    if screen.layout == ScreenLayout::Gauge {
+        let bar_width = width - 4;
+        let filled = (bar_width as u32 * screen.aggression_percent.min(100) as u32 / 100) as u16;
+        let color = AGGRESSION_COLORS[(screen.aggression_percent.min(99) / 25) as usize];
+        display.fill_rect(2, 132, bar_width, 6, RING_TRACK)?;
+        display.fill_rect(2, 132, filled, 6, color)?;
+    }
+    Ok(())
+}
+
+/// Draw one complete frame and show it
+pub fn render_screen(display: &mut impl DisplayInterface, screen: &ScreenDescription) -> HalResult<()> {
+    let text = |id: DisplayString| id.text(screen.language);
+    let (width, height) = display.display_size();
+    display.fill_rect(0, 0, width, height, BLACK)?;
+    
+    match screen.layout {
+        ScreenLayout::Startup => {
+            draw_centered(display, 60, "RUMBLEDOME", WHITE, 2)?;
+            draw_centered(display, 84, &screen.status_text, GREY, 1)?;
+        },
+        ScreenLayout::Fault => {
+            display.fill_rect(0, 0, width, height - BANNER_HEIGHT, FAULT_RED)?;
+            draw_centered(display, 56, text(DisplayString::ScreenSystemFault), WHITE, 1)?;
+            draw_centered(display, 72, text(DisplayString::ScreenBoostDisabled), WHITE, 1)?;
+        },
+        ScreenLayout::Gauge | ScreenLayout::Calibration => draw_gauge(display, screen)?,
+    }
+    
+    if let Some(alert) = &screen.alert {
+        let (background, foreground) = match alert.severity {
+            AlertSeverity::Advisory => (ADVISORY_BLUE, WHITE),
+            AlertSeverity::Warning => (WARNING_AMBER, BLACK),
+            AlertSeverity::Critical => (CRITICAL_RED, WHITE),
+        };
+        let top = height - BANNER_HEIGHT;
+        display.fill_rect(0, top, width, BANNER_HEIGHT, background)?;
+        draw_centered(display, top + 4, &alert.text, foreground, 1)?;
+    }
+    
+    display.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use rumbledome_hal::Framebuffer;
+    use crate::{AccentColor, Language, ScreenAlert};
+    
+    fn gauge_screen() -> ScreenDescription {
+        ScreenDescription {
+            layout: ScreenLayout::Gauge,
+            status_text: "ARMED".to_string(),
+            boost_psi: 7.5,
+            target_psi: 9.0,
+            gauge_max_psi: 15.0,
+            aggression_percent: 30,
+            control_mode: ControlMode::TorqueFollowing,
+            accent_color: AccentColor::RED,
+            profile_label: "TRACK".to_string(),
+            exhaust_backpressure_psi: None,
+            alert: None,
+            headroom: None,
+            language: Language::English,
+            timestamp_ms: 0,
+            watches: Vec::new(),
+        }
+    }
+    
+    fn count(fb: &Framebuffer, color: u16) -> usize {
+        fb.pixels().iter().filter(|&&pixel| pixel == color).count()
+    }
+    
+    #[test]
+    fn test_ring_fill_follows_boost() {
+        let mut fb = Framebuffer::default();
+        let mut screen = gauge_screen();
+        render_screen(&mut fb, &screen).unwrap();
+        let accent = AccentColor::RED.to_rgb565();
+        let half = count(&fb, accent);
+        assert!(half > 0);
+        assert_eq!(fb.frames(), 1);
+        
+        screen.boost_psi = 15.0;
+        render_screen(&mut fb, &screen).unwrap();
+        assert!(count(&fb, accent) > half);
+        
+        // Vacuum leaves the ring empty; only the profile label is in the accent color
+        screen.boost_psi = -8.0;
+        screen.profile_label.clear();
+        render_screen(&mut fb, &screen).unwrap();
+        assert_eq!(count(&fb, accent), 0);
+    }
+    
+    #[test]
+    fn test_alert_banner_colored_by_severity() {
+        let mut fb = Framebuffer::default();
+        let mut screen = gauge_screen();
+        screen.alert = Some(ScreenAlert { severity: AlertSeverity::Critical, text: "OVERBOOST CUT".to_string() });
+        render_screen(&mut fb, &screen).unwrap();
+        assert_eq!(fb.pixel(0, 159), Some(CRITICAL_RED));
+        assert_eq!(fb.pixel(0, 100), Some(BLACK));
+    }
+    
+    #[test]
+    fn test_text_metrics() {
+        assert_eq!(text_width("ABC", 1), 17);
+        assert_eq!(text_width("ABC", 2), 34);
+        assert_eq!(glyph('a'), glyph('A'));
+        assert_eq!(glyph('ü'), glyph('?'));
+    }
+}
@@ -0,0 +1,435 @@
+//! Pressure Sensor Channel Mapping
+//! 
+//! 🔗 T4-CORE-093: Sensor Wiring Auto-Detect
+//! Derived From: T4-CORE-073 (Commissioning Sequence) + Hardware.md pressure sensor inputs
+//! AI Traceability: Four identical sensors on four identical connectors - find out which line is on which input instead of trusting the harness
+//! 
+//! The four pressure inputs are read in physical channel order and assigned
+//! to their roles through a channel map. The mapping assistant runs with the
+//! engine off: the installer pressurizes one line at a time (hand pump or
+//! shop air through a regulator) and the channel that responds is assigned to
+//! the prompted role. Channels that never respond, or that read far from
+//! atmosphere with every line open, are reported as dead.
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemInputs};
+
+/// Number of analog pressure inputs
+pub const PRESSURE_CHANNELS: usize = 4;
+
+/// Rise over the at-rest reading that identifies the pressurized channel (PSI)
+const DETECT_RISE_PSI: f32 = 3.0;
+
+/// Rise must hold this long before a channel is assigned (ms)
+const DETECT_HOLD_MS: u32 = 500;
+
+/// Every channel back within this of its at-rest reading counts as released (PSI)
+const RELEASE_PSI: f32 = 1.0;
+
+/// At-rest reading further than this from atmosphere marks a channel dead (PSI)
+const PLAUSIBLE_REST_PSI: f32 = 3.0;
+
+/// Logical role of a pressure sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PressureRole {
+    /// Intake manifold (boost) pressure
+    Manifold,
+    /// Regulated air supply feeding the solenoid
+    DomeInput,
+    /// Upper wastegate dome
+    UpperDome,
+    /// Lower wastegate dome
+    LowerDome,
+}
+
+impl PressureRole {
+    /// Every role, in the order the assistant prompts for them
+    pub const ALL: [PressureRole; PRESSURE_CHANNELS] = [
+        PressureRole::Manifold,
+        PressureRole::DomeInput,
+        PressureRole::UpperDome,
+        PressureRole::LowerDome,
+    ];
+    
+    /// Short role name
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            PressureRole::Manifold => "Manifold",
+            PressureRole::DomeInput => "Dome input",
+            PressureRole::UpperDome => "Upper dome",
+            PressureRole::LowerDome => "Lower dome",
+        }
+    }
+    
+    /// Which line the installer pressurizes for this role
+    pub fn instructions(&self) -> &'static str {
+        match self {
+            PressureRole::Manifold =>
+                "Apply 5-10 PSI to the manifold reference line (disconnect it at the manifold).",
+            PressureRole::DomeInput =>
+                "Apply 5-10 PSI to the air supply line at the solenoid input.",
+            PressureRole::UpperDome =>
+                "Apply 5-10 PSI to the upper dome line at the wastegate.",
+            PressureRole::LowerDome =>
+                "Apply 5-10 PSI to the lower dome line at the wastegate.",
+        }
+    }
+}
+
+/// Physical input channel (0-3) wired to each pressure sensor role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SensorChannelMap {
+    pub manifold: u8,
+    pub dome_input: u8,
+    pub upper_dome: u8,
+    pub lower_dome: u8,
+}
+
+impl Default for SensorChannelMap {
+    /// Harness as documented: channels in role order
+    fn default() -> Self {
+        Self { manifold: 0, dome_input: 1, upper_dome: 2, lower_dome: 3 }
+    }
+}
+
+impl SensorChannelMap {
+    /// Channel wired to a role
+    pub fn channel(&self, role: PressureRole) -> u8 {
+        match role {
+            PressureRole::Manifold => self.manifold,
+            PressureRole::DomeInput => self.dome_input,
+            PressureRole::UpperDome => self.upper_dome,
+            PressureRole::LowerDome => self.lower_dome,
+        }
+    }
+    
+    /// Wire a role to a channel
+    pub fn set_channel(&mut self, role: PressureRole, channel: u8) {
+        match role {
+            PressureRole::Manifold => self.manifold = channel,
+            PressureRole::DomeInput => self.dome_input = channel,
+            PressureRole::UpperDome => self.upper_dome = channel,
+            PressureRole::LowerDome => self.lower_dome = channel,
+        }
+    }
+    
+    /// Every role on its own existing channel
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for (index, role) in PressureRole::ALL.iter().enumerate() {
+            let channel = self.channel(*role);
+            if channel as usize >= PRESSURE_CHANNELS {
+                return Err(CoreError::ConfigurationError(format!(
+                    "{} sensor channel {} does not exist (0-{})", role.display_text(), channel, PRESSURE_CHANNELS - 1
+                )));
+            }
+            if let Some(other) = PressureRole::ALL[..index].iter().find(|other| self.channel(**other) == channel) {
+                return Err(CoreError::ConfigurationError(format!(
+                    "{} and {} sensors are both mapped to channel {}", other.display_text(), role.display_text(), channel
+                )));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Role readings (`PressureRole::ALL` order) from physical channel readings
+    pub fn assign(&self, channels: &[f32; PRESSURE_CHANNELS]) -> [f32; PRESSURE_CHANNELS] {
+        PressureRole::ALL.map(|role| channels[self.channel(role) as usize % PRESSURE_CHANNELS])
+    }
+    
+    /// Physical channel readings recovered from role-assigned inputs
+    pub fn channels(&self, inputs: &SystemInputs) -> [f32; PRESSURE_CHANNELS] {
+        let roles = [
+            inputs.manifold_pressure,
+            inputs.dome_input_pressure,
+            inputs.upper_dome_pressure,
+            inputs.lower_dome_pressure,
+        ];
+        let mut channels = [0.0; PRESSURE_CHANNELS];
+        for (role, value) in PressureRole::ALL.iter().zip(roles) {
+            channels[self.channel(*role) as usize % PRESSURE_CHANNELS] = value;
+        }
+        channels
+    }
+}
+
+/// Channel found for a role (`None` - nothing responded and the role was skipped)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoleAssignment {
+    pub role: PressureRole,
+    pub channel: Option<u8>,
+}
+
+/// Mapping assistant progress (diagnostics and CLI)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SensorMappingStatus {
+    /// Assistant running
+    pub active: bool,
+    /// Role whose line should be pressurized now
+    pub current_role: Option<PressureRole>,
+    /// Channel found - waiting for the line to be released before the next role
+    pub awaiting_release: bool,
+    /// Roles finished so far
+    pub assignments: Vec<RoleAssignment>,
+}
+
+/// Outcome of a completed mapping run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SensorMappingReport {
+    /// Mapping written to the configuration
+    pub map: SensorChannelMap,
+    /// Roles that were wired to a different channel than configured
+    pub swapped: Vec<PressureRole>,
+    /// Channels that never responded or read implausibly at rest
+    pub dead_channels: Vec<u8>,
+}
+
+/// Engine-off sensor mapping assistant
+/// 
+/// Prompts for one role at a time. Pressure must be released before the
+/// next role is prompted, so a line still held from the previous role cannot
+/// be mistaken for the next one.
+#[derive(Debug, Clone)]
+pub struct SensorMapping {
+    configured: SensorChannelMap,
+    rest: Option<[f32; PRESSURE_CHANNELS]>,
+    assignments: Vec<RoleAssignment>,
+    candidate: Option<(u8, u32)>,
+    awaiting_release: bool,
+}
+
+impl SensorMapping {
+    /// Start with every line open to atmosphere; the first readings are the at-rest values
+    pub fn new(configured: SensorChannelMap) -> Self {
+        Self { configured, rest: None, assignments: Vec::new(), candidate: None, awaiting_release: false }
+    }
+    
+    /// Role being prompted for, if any
+    pub fn current_role(&self) -> Option<PressureRole> {
+        PressureRole::ALL.get(self.assignments.len()).copied()
+    }
+    
+    /// Every role assigned or skipped
+    pub fn is_complete(&self) -> bool {
+        self.current_role().is_none() && !self.awaiting_release
+    }
+    
+    /// Observe physical channel readings (every control cycle while running)
+    pub fn observe(&mut self, channels: &[f32; PRESSURE_CHANNELS], now_ms: u32) {
+        let Some(rest) = self.rest else {
+            self.rest = Some(*channels);
+            return;
+        };
+        let rise = |channel: usize| channels[channel] - rest[channel];
+        
+        if self.awaiting_release {
+            if (0..PRESSURE_CHANNELS).all(|channel| rise(channel).abs() <= RELEASE_PSI) {
+                self.awaiting_release = false;
+            }
+            return;
+        }
+        let Some(role) = self.current_role() else {
+            return;
+        };
+        
+        // Channels already assigned may be teed into the same line - only free channels count
+        let responding = (0..PRESSURE_CHANNELS)
+            .filter(|channel| !self.is_assigned(*channel as u8))
+            .filter(|channel| rise(*channel) >= DETECT_RISE_PSI)
+            .max_by(|a, b| rise(*a).total_cmp(&rise(*b)))
+            .map(|channel| channel as u8);
+        
+        self.candidate = match (responding, self.candidate) {
+            (Some(channel), Some((candidate, since_ms))) if channel == candidate => {
+                if now_ms.wrapping_sub(since_ms) >= DETECT_HOLD_MS {
+                    self.assignments.push(RoleAssignment { role, channel: Some(channel) });
+                    self.awaiting_release = true;
+                    None
+                } else {
+                    Some((candidate, since_ms))
+                }
+            },
+            (Some(channel), _) => Some((channel, now_ms)),
+            (None, _) => None,
+        };
+    }
+    
+    /// Give up on the prompted role - nothing responds to its line
+    pub fn skip_role(&mut self) -> Result<(), CoreError> {
+        let role = self.current_role()
+            .ok_or_else(|| CoreError::InvalidState("Every sensor role is already mapped".to_string()))?;
+        if self.awaiting_release {
+            return Err(CoreError::InvalidState("Release the pressurized line first".to_string()));
+        }
+        
+        self.assignments.push(RoleAssignment { role, channel: None });
+        self.candidate = None;
+        Ok(())
+    }
+    
+    /// Current progress
+    pub fn status(&self) -> SensorMappingStatus {
+        SensorMappingStatus {
+            active: true,
+            current_role: self.current_role(),
+            awaiting_release: self.awaiting_release,
+            assignments: self.assignments.clone(),
+        }
+    }
+    
+    /// Resulting channel map and wiring findings
+    /// 
+    /// Skipped roles keep their configured channel where it is still free and
+    /// otherwise take the first free one, so the map stays a valid permutation.
+    pub fn report(&self) -> Result<SensorMappingReport, CoreError> {
+        if !self.is_complete() {
+            return Err(CoreError::InvalidState(format!(
+                "Sensor mapping not finished ({} of {} roles)", self.assignments.len(), PRESSURE_CHANNELS
+            )));
+        }
+        
+        let mut map = self.configured.clone();
+        for assignment in &self.assignments {
+            if let Some(channel) = assignment.channel {
+                map.set_channel(assignment.role, channel);
+            }
+        }
+        let mut taken: Vec<u8> = self.assignments.iter().filter_map(|assignment| assignment.channel).collect();
+        for assignment in self.assignments.iter().filter(|assignment| assignment.channel.is_none()) {
+            let configured = self.configured.channel(assignment.role);
+            let channel = if taken.contains(&configured) {
+                (0..PRESSURE_CHANNELS as u8).find(|channel| !taken.contains(channel)).unwrap_or(configured)
+            } else {
+                configured
+            };
+            map.set_channel(assignment.role, channel);
+            taken.push(channel);
+        }
+        map.validate()?;
+        
+        let swapped = PressureRole::ALL
+            .into_iter()
+            .filter(|role| map.channel(*role) != self.configured.channel(*role))
+            .collect();
+        let rest = self.rest.unwrap_or([0.0; PRESSURE_CHANNELS]);
+        let dead_channels = (0..PRESSURE_CHANNELS as u8)
+            .filter(|channel| !self.is_assigned(*channel) || rest[*channel as usize].abs() > PLAUSIBLE_REST_PSI)
+            .collect();
+        
+        Ok(SensorMappingReport { map, swapped, dead_channels })
+    }
+    
+    fn is_assigned(&self, channel: u8) -> bool {
+        self.assignments.iter().any(|assignment| assignment.channel == Some(channel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Hold `psi` on one channel until it is assigned, then release
+    fn pressurize(mapping: &mut SensorMapping, channel: usize, psi: f32, start_ms: u32) {
+        let mut channels = [0.0; PRESSURE_CHANNELS];
+        channels[channel] = psi;
+        for t in (start_ms..=start_ms + DETECT_HOLD_MS).step_by(10) {
+            mapping.observe(&channels, t);
+        }
+        mapping.observe(&[0.0; PRESSURE_CHANNELS], start_ms + DETECT_HOLD_MS + 10);
+    }
+    
+    #[test]
+    fn test_channel_map_round_trip() {
+        let map = SensorChannelMap { manifold: 1, dome_input: 0, upper_dome: 3, lower_dome: 2 };
+        assert!(map.validate().is_ok());
+        assert_eq!(map.assign(&[10.0, 11.0, 12.0, 13.0]), [11.0, 10.0, 13.0, 12.0]);
+        
+        let duplicate = SensorChannelMap { upper_dome: 1, ..map.clone() };
+        assert!(duplicate.validate().is_err());
+        let missing = SensorChannelMap { lower_dome: 4, ..map };
+        assert!(missing.validate().is_err());
+    }
+    
+    #[test]
+    fn test_detects_swapped_domes() {
+        let mut mapping = SensorMapping::new(SensorChannelMap::default());
+        mapping.observe(&[0.0; PRESSURE_CHANNELS], 0);
+        
+        pressurize(&mut mapping, 0, 8.0, 100);
+        pressurize(&mut mapping, 1, 8.0, 1000);
+        // A brief bump is not enough
+        mapping.observe(&[0.0, 0.0, 0.0, 8.0], 2000);
+        mapping.observe(&[0.0, 0.0, 0.0, 0.0], 2100);
+        assert_eq!(mapping.current_role(), Some(PressureRole::UpperDome));
+        pressurize(&mut mapping, 3, 8.0, 3000);
+        pressurize(&mut mapping, 2, 8.0, 4000);
+        assert!(mapping.is_complete());
+        
+        let report = mapping.report().unwrap();
+        assert_eq!(report.map, SensorChannelMap { manifold: 0, dome_input: 1, upper_dome: 3, lower_dome: 2 });
+        assert_eq!(report.swapped, [PressureRole::UpperDome, PressureRole::LowerDome]);
+        assert!(report.dead_channels.is_empty());
+    }
+    
+    #[test]
+    fn test_unresponsive_channel_reported_dead() {
+        let mut mapping = SensorMapping::new(SensorChannelMap::default());
+        assert!(mapping.report().is_err());
+        mapping.observe(&[0.0; PRESSURE_CHANNELS], 0);
+        
+        // Manifold line lands on the dome input channel; the dome input sensor never responds
+        pressurize(&mut mapping, 1, 8.0, 100);
+        mapping.skip_role().unwrap();
+        pressurize(&mut mapping, 2, 8.0, 1000);
+        pressurize(&mut mapping, 3, 8.0, 2000);
+        assert!(mapping.skip_role().is_err());
+        
+        let report = mapping.report().unwrap();
+        assert_eq!(report.map, SensorChannelMap { manifold: 1, dome_input: 0, upper_dome: 2, lower_dome: 3 });
+        assert_eq!(report.swapped, [PressureRole::Manifold, PressureRole::DomeInput]);
+        assert_eq!(report.dead_channels, [0]);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::{FaultCode, RumbleDomeCore, SystemConfig, SystemState};
+    use rumbledome_hal::{AnalogInput, MockHal, PressureCalibration};
+    
+    #[test]
+    fn test_inputs_read_from_hal_through_channel_map() {
+        let config = SystemConfig {
+            sensor_channels: SensorChannelMap { manifold: 1, dome_input: 0, upper_dome: 2, lower_dome: 3 },
+            ..SystemConfig::default()
+        };
+        let mut hal = MockHal::new();
+        // 0-100 PSI feed sensor on channel 0
+        hal.set_calibration(0, PressureCalibration { full_scale_psi: 100.0, ..PressureCalibration::default() }).unwrap();
+        hal.set_pressure_psi(0, 60.0);
+        hal.set_pressure_psi(1, 3.0);
+        hal.set_pressure_psi(2, 5.0);
+        hal.set_analog_voltage(3, 0.0);
+        
+        let mut core = RumbleDomeCore::new(hal, config);
+        let inputs = core.read_inputs().unwrap();
+        assert!((inputs.dome_input_pressure - 60.0).abs() < 1e-3);
+        assert!((inputs.manifold_pressure - 3.0).abs() < 1e-3);
+        assert!((inputs.upper_dome_pressure - 5.0).abs() < 1e-3);
+        // Unplugged sensor reads nothing rather than a made-up pressure
+        assert_eq!(inputs.lower_dome_pressure, 0.0);
+    }
+    
+    #[test]
+    fn test_failed_manifold_sensor_faults() {
+        let mut hal = MockHal::new();
+        hal.set_analog_voltage(0, 5.0);
+        
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.read_inputs().unwrap();
+        assert!(matches!(core.state, SystemState::Fault(FaultCode::PressureSensorFault(_))), "state {:?}", core.state);
+    }
+}
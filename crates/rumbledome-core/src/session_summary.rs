@@ -0,0 +1,148 @@
+//! Session Summaries
+//! 
+//! 🔗 T4-CORE-055: Drive Session Summary
+//! Derived From: T4-CORE-009 (System Status Reporting) + field diagnostics needs
+//! AI Traceability: Compact per-power-cycle record of how the system was used and what went wrong
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{GapDistribution, SystemState, TrackingMonitor, TrackingSample, TrackingStats};
+
+/// Session summaries kept, including the current one
+pub const MAX_RECENT_SESSIONS: usize = 8;
+
+/// Summary of one power-on session
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionSummary {
+    /// Session length (ms)
+    pub duration_ms: u32,
+    /// Time spent armed (ms)
+    pub armed_ms: u32,
+    /// Highest manifold pressure seen (PSI)
+    pub peak_boost_psi: f32,
+    /// Entries into a fault state
+    pub faults: u32,
+    /// Overboost cut activations
+    pub overboost_cuts: u32,
+    /// Boost tracking per RPM band (score and gain suggestions derive from it)
+    #[serde(default)]
+    pub tracking: TrackingStats,
+    /// Torque gap and boost error histograms per RPM band
+    #[serde(default)]
+    pub distribution: GapDistribution,
+    /// Learning updates applied
+    #[serde(default)]
+    pub learning_updates: u32,
+    /// Records written to EEPROM (learned data, calibration, flight recorder blocks)
+    #[serde(default)]
+    pub storage_writes: u32,
+}
+
+/// Tracks the current session and remembers recent ones (persisted by the storage layer)
+#[derive(Debug, Clone, Default)]
+pub struct SessionTracker {
+    current: SessionSummary,
+    previous: Vec<SessionSummary>,
+    last_update_ms: Option<u32>,
+    was_fault: bool,
+    was_overboost_cut: bool,
+    tracking: TrackingMonitor,
+}
+
+impl SessionTracker {
+    /// Start tracking a new session
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume with summaries of earlier sessions, oldest first
+    pub fn restore(&mut self, mut previous: Vec<SessionSummary>) {
+        let excess = previous.len().saturating_sub(MAX_RECENT_SESSIONS - 1);
+        previous.drain(..excess);
+        self.previous = previous;
+    }
+    
+    /// Current session so far
+    pub fn current(&self) -> &SessionSummary {
+        &self.current
+    }
+    
+    /// Earlier sessions followed by the current one
+    pub fn recent(&self) -> Vec<SessionSummary> {
+        let mut sessions = self.previous.clone();
+        sessions.push(self.current.clone());
+        sessions
+    }
+    
+    /// Accumulate one control cycle
+    pub fn update(&mut self, now_ms: u32, state: &SystemState, manifold_psi: f32) {
+        let elapsed_ms = self.last_update_ms.map(|last| now_ms.wrapping_sub(last)).unwrap_or(0);
+        self.last_update_ms = Some(now_ms);
+        
+        self.current.duration_ms += elapsed_ms;
+        if *state == SystemState::Armed {
+            self.current.armed_ms += elapsed_ms;
+        }
+        self.current.peak_boost_psi = self.current.peak_boost_psi.max(manifold_psi);
+        
+        let is_fault = matches!(state, SystemState::Fault(_));
+        if is_fault && !self.was_fault {
+            self.current.faults += 1;
+        }
+        self.was_fault = is_fault;
+        
+        let is_overboost_cut = *state == SystemState::OverboostCut;
+        if is_overboost_cut && !self.was_overboost_cut {
+            self.current.overboost_cuts += 1;
+        }
+        self.was_overboost_cut = is_overboost_cut;
+    }
+    
+    /// Accumulate boost tracking for one control cycle; `None` when boost is not under active control
+    pub fn track(&mut self, sample: Option<TrackingSample>, now_ms: u32) {
+        self.tracking.update(&mut self.current.tracking, sample, now_ms);
+    }
+    
+    /// Count one armed control cycle's torque gap (Nm) and, under active control, boost error (PSI)
+    pub fn record_gap(&mut self, rpm: u16, torque_gap_nm: f32, boost_error_psi: Option<f32>) {
+        self.current.distribution.record(rpm, torque_gap_nm, boost_error_psi);
+    }
+    
+    /// Count one applied learning update
+    pub fn count_learning_update(&mut self) {
+        self.current.learning_updates += 1;
+    }
+    
+    /// Count records written to EEPROM
+    pub fn count_storage_writes(&mut self, writes: u32) {
+        self.current.storage_writes += writes;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_session_accumulates() {
+        let mut tracker = SessionTracker::new();
+        tracker.update(0, &SystemState::Idle, 0.0);
+        tracker.update(1000, &SystemState::Armed, 9.5);
+        tracker.update(1010, &SystemState::OverboostCut, 15.2);
+        tracker.update(1020, &SystemState::OverboostCut, 14.0);
+        tracker.update(1030, &SystemState::Armed, 12.0);
+        
+        let session = tracker.current();
+        assert_eq!(session.duration_ms, 1030);
+        assert_eq!(session.armed_ms, 1010);
+        assert_eq!(session.peak_boost_psi, 15.2);
+        assert_eq!(session.overboost_cuts, 1);
+    }
+    
+    #[test]
+    fn test_recent_sessions_bounded() {
+        let mut tracker = SessionTracker::new();
+        tracker.restore(alloc::vec![SessionSummary::default(); 12]);
+        assert_eq!(tracker.recent().len(), MAX_RECENT_SESSIONS);
+    }
+}
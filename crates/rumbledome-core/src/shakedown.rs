@@ -0,0 +1,346 @@
+//! Shake-Down Period After Configuration Changes
+//! 
+//! 🔗 T4-CORE-122: Conservative Shake-Down Mode
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-049 (Named Configurations)
+//! AI Traceability: New settings earn full boost by surviving a few minutes of driving first, instead of meeting their first wide-open pull at full strength
+//! 
+//! Saving a change to the running configuration that affects how hard the
+//! engine is boosted starts a shake-down period: boost is capped at a
+//! fraction of the controllable range (spring pressure up to the profile
+//! maximum) and aggression is scaled back. The period ends after the
+//! configured armed driving time or number of boost pulls, whichever comes
+//! first. Independently, boost can be held at spring pressure for a fixed
+//! delay after every power-up.
+
+use alloc::format;
+use alloc::string::ToString;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemConfig, SystemInputs};
+
+/// Boost above spring pressure that counts as a pull (PSI)
+const PULL_THRESHOLD_PSI: f32 = 1.0;
+
+/// Shake-down period and startup delay settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShakedownConfig {
+    /// Run conservatively after a safety-relevant configuration change
+    pub enabled: bool,
+    /// Armed driving time the period lasts (minutes, 0 = not time-limited)
+    pub minutes: u16,
+    /// Boost pulls the period lasts (0 = not pull-limited)
+    pub pulls: u16,
+    /// Share of the controllable boost range allowed during the period (0.0-1.0)
+    pub boost_fraction: f32,
+    /// Multiplier on aggression during the period (0.0-1.0)
+    pub aggression_scale: f32,
+    /// Boost held at spring pressure for this long after every power-up (s)
+    pub startup_delay_s: u16,
+}
+
+impl Default for ShakedownConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            minutes: 10,
+            pulls: 5,
+            boost_fraction: 0.7,
+            aggression_scale: 0.7,
+            startup_delay_s: 0,
+        }
+    }
+}
+
+impl ShakedownConfig {
+    /// Validate period length and limits
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.enabled && self.minutes == 0 && self.pulls == 0 {
+            return Err(CoreError::ConfigurationError(
+                "Shake-down period needs a length in minutes or pulls".to_string()
+            ));
+        }
+        if self.minutes > 240 {
+            return Err(CoreError::ConfigurationError(
+                format!("Shake-down period must be at most 240 minutes, got {}", self.minutes)
+            ));
+        }
+        if self.pulls > 100 {
+            return Err(CoreError::ConfigurationError(
+                format!("Shake-down period must be at most 100 pulls, got {}", self.pulls)
+            ));
+        }
+        for (name, value) in [("boost fraction", self.boost_fraction), ("aggression scale", self.aggression_scale)] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(CoreError::ConfigurationError(
+                    format!("Shake-down {} must be 0.0-1.0, got {}", name, value)
+                ));
+            }
+        }
+        if self.startup_delay_s > 600 {
+            return Err(CoreError::ConfigurationError(
+                format!("Startup delay must be at most 600 s, got {}", self.startup_delay_s)
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Whether replacing the running configuration changes how hard the engine is boosted
+pub fn is_safety_relevant(running: &SystemConfig, applied: &SystemConfig) -> bool {
+    running.aggression != applied.aggression
+        || running.spring_pressure != applied.spring_pressure
+        || running.max_boost_psi != applied.max_boost_psi
+        || running.overboost_limit != applied.overboost_limit
+        || running.max_duty_percent != applied.max_duty_percent
+        || running.control_mode != applied.control_mode
+        || running.boost_targets != applied.boost_targets
+        || running.actuator_type != applied.actuator_type
+        || running.solenoid_topology != applied.solenoid_topology
+        || running.tuning != applied.tuning
+        || running.torque_following != applied.torque_following
+        || running.rev_limit_taper != applied.rev_limit_taper
+        || running.soft_ceiling != applied.soft_ceiling
+        || running.twin_turbo != applied.twin_turbo
+}
+
+/// Shake-down countdown (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShakedownStatus {
+    /// Conservative limits in effect
+    pub active: bool,
+    /// Armed driving time left (ms), if the period is time-limited
+    pub remaining_ms: Option<u32>,
+    /// Pulls left, if the period is pull-limited
+    pub remaining_pulls: Option<u16>,
+    /// Boost held at spring pressure for the startup delay
+    pub startup_hold: bool,
+}
+
+/// Runs the shake-down period and startup delay across control cycles
+#[derive(Debug, Clone)]
+pub struct Shakedown {
+    config: ShakedownConfig,
+    active: bool,
+    armed_ms: u32,
+    pulls: u16,
+    in_pull: bool,
+    startup_hold: bool,
+    last_ms: Option<u32>,
+}
+
+impl Shakedown {
+    /// Create with no period running
+    pub fn new(config: ShakedownConfig) -> Self {
+        Self {
+            config,
+            active: false,
+            armed_ms: 0,
+            pulls: 0,
+            in_pull: false,
+            startup_hold: false,
+            last_ms: None,
+        }
+    }
+    
+    /// Apply changed settings; a running period keeps its progress
+    pub fn reconfigure(&mut self, config: ShakedownConfig) {
+        if !config.enabled {
+            self.active = false;
+        }
+        self.config = config;
+    }
+    
+    /// Start a new period (does nothing when disabled)
+    pub fn begin(&mut self) {
+        if !self.config.enabled {
+            return;
+        }
+        self.active = true;
+        self.armed_ms = 0;
+        self.pulls = 0;
+        self.in_pull = false;
+    }
+    
+    /// Update from one cycle; only armed time and pulls count toward the period
+    pub fn update(&mut self, inputs: &SystemInputs, armed: bool, spring_psi: f32) {
+        let now_ms = inputs.timestamp_ms;
+        self.startup_hold = now_ms < self.config.startup_delay_s as u32 * 1000;
+        let elapsed_ms = self.last_ms.map_or(0, |last| now_ms.wrapping_sub(last));
+        self.last_ms = Some(now_ms);
+        if !self.active {
+            return;
+        }
+        
+        if armed {
+            self.armed_ms = self.armed_ms.saturating_add(elapsed_ms);
+        }
+        
+        // A pull counts once boost falls back to the spring after rising clear of it
+        if armed && inputs.manifold_pressure > spring_psi + PULL_THRESHOLD_PSI {
+            self.in_pull = true;
+        } else if self.in_pull && (!armed || inputs.manifold_pressure < spring_psi) {
+            self.in_pull = false;
+            self.pulls = self.pulls.saturating_add(1);
+        }
+        
+        let time_done = self.config.minutes > 0 && self.armed_ms >= self.config.minutes as u32 * 60_000;
+        let pulls_done = self.config.pulls > 0 && self.pulls >= self.config.pulls;
+        if time_done || pulls_done {
+            self.active = false;
+        }
+    }
+    
+    /// Whether the conservative limits are in effect
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+    
+    /// Boost cap from the startup delay or the shake-down period (PSI)
+    pub fn boost_cap_psi(&self, config: &SystemConfig) -> Option<f32> {
+        if self.startup_hold {
+            return Some(config.spring_pressure);
+        }
+        self.active.then(|| {
+            config.spring_pressure + (config.max_boost_psi - config.spring_pressure).max(0.0) * self.config.boost_fraction
+        })
+    }
+    
+    /// Aggression to control with
+    pub fn aggression(&self, aggression: f32) -> f32 {
+        if self.active { aggression * self.config.aggression_scale } else { aggression }
+    }
+    
+    /// Current countdown
+    pub fn status(&self) -> ShakedownStatus {
+        ShakedownStatus {
+            active: self.active,
+            remaining_ms: (self.active && self.config.minutes > 0)
+                .then(|| (self.config.minutes as u32 * 60_000).saturating_sub(self.armed_ms)),
+            remaining_pulls: (self.active && self.config.pulls > 0)
+                .then(|| self.config.pulls.saturating_sub(self.pulls)),
+            startup_hold: self.startup_hold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(manifold_pressure: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { manifold_pressure, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_period_ends_after_pulls() {
+        let config = SystemConfig::default();
+        let spring = config.spring_pressure;
+        let mut shakedown = Shakedown::new(ShakedownConfig { minutes: 0, pulls: 2, ..ShakedownConfig::default() });
+        shakedown.begin();
+        assert!(shakedown.boost_cap_psi(&config).unwrap() < config.max_boost_psi);
+        assert_eq!(shakedown.aggression(1.0), 0.7);
+        
+        let mut t = 0;
+        for _ in 0..2 {
+            assert!(shakedown.is_active());
+            for psi in [spring + 3.0, spring + 3.0, spring - 1.0] {
+                t += 100;
+                shakedown.update(&inputs(psi, t), true, spring);
+            }
+        }
+        assert!(!shakedown.is_active());
+        assert_eq!(shakedown.boost_cap_psi(&config), None);
+        assert_eq!(shakedown.aggression(1.0), 1.0);
+    }
+    
+    #[test]
+    fn test_only_armed_time_counts() {
+        let mut shakedown = Shakedown::new(ShakedownConfig { minutes: 1, pulls: 0, ..ShakedownConfig::default() });
+        shakedown.begin();
+        shakedown.update(&inputs(0.0, 0), false, 5.0);
+        shakedown.update(&inputs(0.0, 50_000), false, 5.0);
+        assert_eq!(shakedown.status().remaining_ms, Some(60_000));
+        
+        shakedown.update(&inputs(0.0, 80_000), true, 5.0);
+        assert_eq!(shakedown.status().remaining_ms, Some(30_000));
+        assert_eq!(shakedown.status().remaining_pulls, None);
+        shakedown.update(&inputs(0.0, 110_000), true, 5.0);
+        assert!(!shakedown.is_active());
+    }
+    
+    #[test]
+    fn test_startup_delay_holds_spring_pressure() {
+        let config = SystemConfig::default();
+        let mut shakedown = Shakedown::new(ShakedownConfig { startup_delay_s: 30, ..ShakedownConfig::default() });
+        shakedown.update(&inputs(0.0, 10_000), false, config.spring_pressure);
+        assert_eq!(shakedown.boost_cap_psi(&config), Some(config.spring_pressure));
+        
+        shakedown.update(&inputs(0.0, 30_000), false, config.spring_pressure);
+        assert_eq!(shakedown.boost_cap_psi(&config), None);
+    }
+    
+    #[test]
+    fn test_safety_relevant_changes() {
+        let running = SystemConfig::default();
+        assert!(!is_safety_relevant(&running, &running.clone()));
+        assert!(is_safety_relevant(&running, &SystemConfig { max_boost_psi: running.max_boost_psi - 1.0, ..running.clone() }));
+        
+        let mut maintenance = running.clone();
+        maintenance.maintenance_reminders.clear();
+        assert!(!is_safety_relevant(&running, &maintenance));
+    }
+    
+    #[test]
+    fn test_disabled_never_starts_and_needs_no_length() {
+        let config = ShakedownConfig { enabled: false, minutes: 0, pulls: 0, ..ShakedownConfig::default() };
+        assert!(config.validate().is_ok());
+        assert!(ShakedownConfig { enabled: true, ..config.clone() }.validate().is_err());
+        
+        let mut shakedown = Shakedown::new(config);
+        shakedown.begin();
+        assert!(!shakedown.is_active());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::TORQUE_MESSAGE_ID;
+    use crate::{ControlMode, RumbleDomeCore, DEFAULT_CONFIG_NAME};
+    use rumbledome_hal::MockHal;
+    
+    fn config() -> SystemConfig {
+        SystemConfig { control_mode: ControlMode::BoostByTarget, ..SystemConfig::default() }
+    }
+    
+    #[test]
+    fn test_saved_change_runs_conservatively() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), config());
+        core.initialize().unwrap();
+        core.skip_commissioning().unwrap();
+        
+        // Housekeeping changes leave the limits alone
+        let mut housekeeping = config();
+        housekeeping.maintenance_reminders.clear();
+        core.save_configuration(DEFAULT_CONFIG_NAME, housekeeping.clone()).unwrap();
+        assert!(!core.get_system_status().shakedown.active);
+        
+        let raised = SystemConfig { aggression: 1.0, ..housekeeping };
+        core.save_configuration(DEFAULT_CONFIG_NAME, raised).unwrap();
+        let status = core.get_system_status().shakedown;
+        assert!(status.active);
+        assert_eq!(status.remaining_pulls, Some(5));
+        
+        core.arm().unwrap();
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 5000;
+        inputs.timestamp_ms = 0;
+        core.execute_control_cycle_with_inputs(inputs).unwrap();
+        
+        let cap_psi = core.shakedown.boost_cap_psi(&core.config).unwrap();
+        assert!(cap_psi < core.config.max_boost_psi);
+        assert!(core.last_target_boost_psi <= cap_psi);
+    }
+}
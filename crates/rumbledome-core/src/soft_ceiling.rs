@@ -0,0 +1,159 @@
+//! Soft Boost Ceiling
+//! 
+//! 🔗 T4-CORE-102: Boost Taper Approaching Overboost Limit
+//! Derived From: T1-SAFETY-001 (Overboost as Fault Condition) + T4-CORE-042 (Boost Taper Approaching Redline)
+//! AI Traceability: An aggressive tune overshooting toward the limit backs off on its own instead of tripping the hard cut
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::CoreError;
+
+/// Target taper band below the overboost limit
+/// 
+/// Once measured boost enters the band, the above-spring portion of the
+/// target is scaled down in proportion to how far into the band it is -
+/// reaching the limit itself would target spring pressure. The overboost
+/// cut stays the backstop for boost that keeps climbing regardless.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SoftCeiling {
+    /// Enable target taper near the overboost limit
+    pub enabled: bool,
+    /// Width of the approach band below the overboost limit (PSI)
+    pub approach_band_psi: f32,
+}
+
+impl Default for SoftCeiling {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            approach_band_psi: 1.5,
+        }
+    }
+}
+
+impl SoftCeiling {
+    /// Validate band settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(0.5..=5.0).contains(&self.approach_band_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Soft ceiling approach band must be 0.5-5.0 PSI, got {}", self.approach_band_psi)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Fraction of above-spring boost authority allowed at this measured boost (0.0-1.0)
+    pub fn authority(&self, boost_psi: f32, overboost_limit: f32) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        
+        let band_start = overboost_limit - self.approach_band_psi;
+        if boost_psi <= band_start {
+            return 1.0;
+        }
+        
+        let into_band = (boost_psi - band_start) / self.approach_band_psi;
+        (1.0 - into_band).clamp(0.0, 1.0)
+    }
+    
+    /// Apply the soft ceiling to a boost target (PSI)
+    /// 
+    /// Spring pressure is the floor - the wastegate cannot open further than 0% duty.
+    pub fn apply(&self, target_psi: f32, boost_psi: f32, overboost_limit: f32, spring_pressure: f32) -> f32 {
+        if target_psi <= spring_pressure {
+            return target_psi;
+        }
+        
+        spring_pressure + (target_psi - spring_pressure) * self.authority(boost_psi, overboost_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn ceiling() -> SoftCeiling {
+        SoftCeiling { enabled: true, approach_band_psi: 2.0 }
+    }
+    
+    #[test]
+    fn test_full_target_below_band() {
+        assert_eq!(ceiling().apply(12.0, 13.0, 15.0, 5.0), 12.0);
+    }
+    
+    #[test]
+    fn test_target_tapers_to_spring_at_limit() {
+        let ceiling = ceiling();
+        assert!((ceiling.apply(12.0, 14.0, 15.0, 5.0) - 8.5).abs() < 1e-4);
+        assert_eq!(ceiling.apply(12.0, 15.0, 15.0, 5.0), 5.0);
+        assert_eq!(ceiling.apply(12.0, 16.0, 15.0, 5.0), 5.0);
+    }
+    
+    #[test]
+    fn test_disabled_ceiling_passes_through() {
+        let ceiling = SoftCeiling { enabled: false, ..ceiling() };
+        assert_eq!(ceiling.apply(12.0, 14.5, 15.0, 5.0), 12.0);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{ControlMode, SystemConfig, SystemInputs, SystemState};
+    use rumbledome_hal::SolenoidChannel;
+    
+    fn boosting(manifold_pressure: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { rpm: 5000, manifold_pressure, throttle_position: Some(100.0), aggression: 1.0, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_target_backs_off_approaching_overboost_limit() {
+        let config = SystemConfig {
+            control_mode: ControlMode::BoostByTarget,
+            soft_ceiling: SoftCeiling { enabled: true, approach_band_psi: 2.0 },
+            ..SystemConfig::default()
+        };
+        config.validate().unwrap();
+        let mut core = test_support::armed_core(config);
+        
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(10.0, 0)).unwrap();
+        let full_target = core.last_target_boost_psi;
+        assert!(full_target > core.config.spring_pressure);
+        assert!(!core.get_system_status().soft_ceiling_active);
+        
+        // Halfway into the band: half the above-spring target
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 10);
+        core.execute_control_cycle_with_inputs(boosting(14.0, 10)).unwrap();
+        let spring = core.config.spring_pressure;
+        assert!((core.last_target_boost_psi - (spring + (full_target - spring) * 0.5)).abs() < 1e-3);
+        assert_eq!(core.soft_ceiling_authority(), 0.5);
+        assert!(core.get_system_status().soft_ceiling_active);
+        assert_eq!(core.state, SystemState::Armed);
+    }
+    
+    #[test]
+    fn test_boost_past_the_limit_cuts_the_output() {
+        let mut core = test_support::armed_core(SystemConfig { control_mode: ControlMode::BoostByTarget, ..SystemConfig::default() });
+        let limit = core.limits_in_force().overboost_limit_psi;
+        
+        // Boost that keeps climbing past the taper still trips the hard cut
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(limit + 0.5, 0)).unwrap();
+        assert_eq!(core.state, SystemState::OverboostCut);
+        assert_eq!(core.hal.get_channel_duty(SolenoidChannel::Primary), 0.0);
+        assert_eq!(core.stats.safety_interventions, 1);
+        
+        // Held until boost is back below the limit with margin
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 10);
+        core.execute_control_cycle_with_inputs(boosting(limit - 0.2, 10)).unwrap();
+        assert_eq!(core.state, SystemState::OverboostCut);
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 20);
+        core.execute_control_cycle_with_inputs(boosting(limit - 1.0, 20)).unwrap();
+        assert_eq!(core.state, SystemState::Armed);
+    }
+}
@@ -0,0 +1,280 @@
+//! Solenoid Self-Cleaning Exercise
+//! 
+//! 🔗 T4-CORE-071: Periodic Solenoid Exercise
+//! Derived From: T4-CORE-059 (Off-Boost Idle Vent Stage) + Hardware.md MAC solenoid service life
+//! AI Traceability: Keep a rarely-actuated MAC valve from gumming up by cycling it while boost is impossible
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemInputs};
+
+/// Exercise records kept (oldest dropped first)
+pub const MAX_EXERCISE_RECORDS: usize = 8;
+
+/// Solenoid exercise settings
+/// 
+/// Exercise only runs while the idle vent stage has the domes vented - engine
+/// idling, throttle closed, manifold in vacuum - so cycling the valve cannot
+/// build boost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SolenoidExerciseConfig {
+    /// Periodically exercise the solenoid
+    pub enabled: bool,
+    /// Time between exercises (minutes)
+    pub interval_minutes: u16,
+    /// Open/close pulses per exercise
+    pub pulses: u8,
+    /// Length of each energized and de-energized half pulse (ms)
+    pub pulse_ms: u16,
+    /// Lowest RPM treated as engine running
+    pub min_rpm: u16,
+}
+
+impl Default for SolenoidExerciseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_minutes: 30,
+            pulses: 5,
+            pulse_ms: 250,
+            min_rpm: 500,
+        }
+    }
+}
+
+impl SolenoidExerciseConfig {
+    /// Validate exercise settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(1..=1440).contains(&self.interval_minutes) {
+            return Err(CoreError::ConfigurationError(
+                format!("Solenoid exercise interval must be 1-1440 minutes, got {}", self.interval_minutes)
+            ));
+        }
+        
+        if !(1..=20).contains(&self.pulses) {
+            return Err(CoreError::ConfigurationError(
+                format!("Solenoid exercise pulse count must be 1-20, got {}", self.pulses)
+            ));
+        }
+        
+        // Shorter than a couple of 30 Hz PWM periods would not move the spool
+        if !(100..=1000).contains(&self.pulse_ms) {
+            return Err(CoreError::ConfigurationError(
+                format!("Solenoid exercise pulse length must be 100-1000 ms, got {}", self.pulse_ms)
+            ));
+        }
+        
+        if !(300..=1000).contains(&self.min_rpm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Solenoid exercise minimum RPM must be 300-1000 RPM, got {}", self.min_rpm)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// One exercise run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExerciseRecord {
+    /// When the exercise started (ms)
+    pub started_ms: u32,
+    /// Pulses fully completed
+    pub pulses_completed: u8,
+    /// Cut short by tip-in or the engine stopping
+    pub aborted: bool,
+}
+
+/// Solenoid exercise status (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolenoidExerciseStatus {
+    /// Exercise currently driving the solenoid
+    pub active: bool,
+    /// Exercises run to completion
+    pub completed: u32,
+    /// Exercises cut short
+    pub aborted: u32,
+    /// Recent exercises, oldest first
+    pub recent: Vec<ExerciseRecord>,
+}
+
+/// Solenoid exercise scheduler
+/// 
+/// 🔗 T4-CORE-072: Exercise Pulse Sequencer
+/// Derived From: T4-CORE-071
+/// 
+/// The interval runs from the first vented cycle after power-on, then from the
+/// end of each exercise. An aborted exercise is retried at the next vented idle.
+#[derive(Debug, Clone)]
+pub struct SolenoidExercise {
+    config: SolenoidExerciseConfig,
+    last_exercise_ms: Option<u32>,
+    started_ms: Option<u32>,
+    pulses_completed: u8,
+    status: SolenoidExerciseStatus,
+}
+
+impl SolenoidExercise {
+    /// Create scheduler with no exercise due yet
+    pub fn new(config: SolenoidExerciseConfig) -> Self {
+        Self {
+            config,
+            last_exercise_ms: None,
+            started_ms: None,
+            pulses_completed: 0,
+            status: SolenoidExerciseStatus::default(),
+        }
+    }
+    
+    /// Apply new settings, keeping counters and log
+    pub fn reconfigure(&mut self, config: SolenoidExerciseConfig) {
+        self.config = config;
+    }
+    
+    /// Exercise currently driving the solenoid
+    pub fn is_active(&self) -> bool {
+        self.started_ms.is_some()
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &SolenoidExerciseStatus {
+        &self.status
+    }
+    
+    /// Feed one cycle with the domes vented; returns the duty to command while exercising
+    pub fn update(&mut self, inputs: &SystemInputs) -> Option<f32> {
+        let now_ms = inputs.timestamp_ms;
+        let engine_running = inputs.rpm >= self.config.min_rpm;
+        
+        let Some(started_ms) = self.started_ms else {
+            let last_ms = *self.last_exercise_ms.get_or_insert(now_ms);
+            let interval_ms = self.config.interval_minutes as u32 * 60_000;
+            if !self.config.enabled || !engine_running || now_ms.wrapping_sub(last_ms) < interval_ms {
+                return None;
+            }
+            
+            self.started_ms = Some(now_ms);
+            self.pulses_completed = 0;
+            self.status.active = true;
+            return Some(100.0);
+        };
+        
+        if !engine_running {
+            self.cancel();
+            return None;
+        }
+        
+        let half_pulse = now_ms.wrapping_sub(started_ms) / self.config.pulse_ms as u32;
+        self.pulses_completed = (half_pulse / 2).min(self.config.pulses as u32) as u8;
+        if half_pulse >= self.config.pulses as u32 * 2 {
+            self.finish(false);
+            self.last_exercise_ms = Some(now_ms);
+            return None;
+        }
+        
+        Some(if half_pulse.is_multiple_of(2) { 100.0 } else { 0.0 })
+    }
+    
+    /// Stop any exercise in progress (idle conditions lost)
+    /// 
+    /// Returns true if an exercise was cut short. The exercise is retried at
+    /// the next vented idle rather than waiting a full interval.
+    pub fn cancel(&mut self) -> bool {
+        if self.started_ms.is_none() {
+            return false;
+        }
+        
+        self.finish(true);
+        true
+    }
+    
+    fn finish(&mut self, aborted: bool) {
+        let Some(started_ms) = self.started_ms.take() else {
+            return;
+        };
+        
+        self.status.active = false;
+        if aborted {
+            self.status.aborted += 1;
+        } else {
+            self.status.completed += 1;
+        }
+        
+        if self.status.recent.len() >= MAX_EXERCISE_RECORDS {
+            self.status.recent.remove(0);
+        }
+        self.status.recent.push(ExerciseRecord {
+            started_ms,
+            pulses_completed: self.pulses_completed,
+            aborted,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::idle_inputs;
+    
+    const INTERVAL_MS: u32 = 30 * 60_000;
+    
+    #[test]
+    fn test_exercise_pulses_after_interval() {
+        let mut exercise = SolenoidExercise::new(SolenoidExerciseConfig::default());
+        assert_eq!(exercise.update(&idle_inputs(0)), None);
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS - 10)), None);
+        
+        // Energized, released, energized again on 250 ms half pulses
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS)), Some(100.0));
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 250)), Some(0.0));
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 500)), Some(100.0));
+        
+        // 5 pulses take 2.5 s
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 2490)), Some(0.0));
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 2500)), None);
+        assert!(!exercise.is_active());
+        assert_eq!(exercise.status().completed, 1);
+        assert_eq!(exercise.status().recent[0].pulses_completed, 5);
+        
+        // Next one a full interval later
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 3000)), None);
+    }
+    
+    #[test]
+    fn test_cancel_logs_abort_and_retries() {
+        let mut exercise = SolenoidExercise::new(SolenoidExerciseConfig::default());
+        exercise.update(&idle_inputs(0));
+        exercise.update(&idle_inputs(INTERVAL_MS));
+        exercise.update(&idle_inputs(INTERVAL_MS + 600));
+        
+        assert!(exercise.cancel());
+        assert!(!exercise.cancel());
+        assert_eq!(exercise.status().aborted, 1);
+        assert_eq!(exercise.status().recent[0], ExerciseRecord {
+            started_ms: INTERVAL_MS,
+            pulses_completed: 1,
+            aborted: true,
+        });
+        
+        // Still due at the next vented idle
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS + 20_000)), Some(100.0));
+        
+        // Engine stopping also aborts
+        let stalled = SystemInputs { rpm: 0, ..idle_inputs(INTERVAL_MS + 20_100) };
+        assert_eq!(exercise.update(&stalled), None);
+        assert_eq!(exercise.status().aborted, 2);
+    }
+    
+    #[test]
+    fn test_disabled_never_exercises() {
+        let config = SolenoidExerciseConfig { enabled: false, ..Default::default() };
+        let mut exercise = SolenoidExercise::new(config);
+        exercise.update(&idle_inputs(0));
+        assert_eq!(exercise.update(&idle_inputs(INTERVAL_MS * 2)), None);
+        
+        assert!(SolenoidExerciseConfig { pulse_ms: 20, ..Default::default() }.validate().is_err());
+        assert!(SolenoidExerciseConfig::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,300 @@
+//! Solenoid Failover Management
+//! 
+//! 🔗 T4-CORE-024: Dual-Solenoid Limp Control
+//! Derived From: T1-SAFETY-001 (Failsafe Design) + Architecture.md full-dome control
+//! AI Traceability: Degraded operation on solenoid channel loss, failsafe when limp control is unsafe
+
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{SolenoidChannel, SolenoidDriveStatus};
+use crate::SystemConfig;
+
+/// Physical solenoid plumbing topology
+/// 
+/// Hardware description rather than a tuning parameter - tells the core which
+/// outputs exist so it can decide what degraded operation is physically possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SolenoidTopology {
+    /// Single 4-port MAC valve feeding both domes (Primary channel only)
+    #[default]
+    SingleFourPort,
+    /// Independent upper dome (Primary) and lower dome (Secondary) solenoids
+    DualSolenoid,
+    /// One 4-port valve per turbo bank - bank 1 (Primary) and bank 2 (Secondary)
+    TwinFourPort,
+}
+
+/// Topology actually in use after failover decisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActiveOutputTopology {
+    /// Single 4-port valve operating normally
+    SingleFourPort,
+    /// Both dome solenoids operating normally
+    DualSolenoid,
+    /// Both bank valves operating normally
+    TwinFourPort,
+    /// Upper dome solenoid lost - lower dome solenoid modulates alone
+    LowerDomeOnly,
+    /// No usable output - all channels held at 0% duty
+    Disabled,
+}
+
+/// Output stage degradation level
+/// 
+/// 🔗 T4-CORE-025: Output Degradation Classification
+/// Derived From: Safety.md fault response hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputDegradation {
+    /// Full control authority available
+    Nominal,
+    /// Limp mode - boost authority limited to wastegate spring pressure
+    ReducedAuthority,
+    /// Safe control not physically possible - forced 0% duty (wastegate open)
+    Failsafe,
+}
+
+/// Output stage status for diagnostics and telemetry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputStageStatus {
+    /// Topology described by configuration
+    pub configured_topology: SolenoidTopology,
+    /// Topology actually being driven
+    pub active_topology: ActiveOutputTopology,
+    /// Current degradation level
+    pub degradation: OutputDegradation,
+    /// Last reported Primary channel drive status
+    pub primary_status: SolenoidDriveStatus,
+    /// Last reported Secondary channel drive status
+    pub secondary_status: SolenoidDriveStatus,
+}
+
+/// Per-channel duty commands produced from a single authority command
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelDuties {
+    /// Primary channel duty (%)
+    pub primary: f32,
+    /// Secondary channel duty (%)
+    pub secondary: f32,
+}
+
+/// Solenoid failover manager
+/// 
+/// 🔗 T4-CORE-026: Solenoid Failover State Machine
+/// Derived From: T2-HAL-004 (open coil / short circuit detection) + T1-SAFETY-001
+/// 
+/// Channel failures latch until the next `reset()` (system initialization) so an
+/// intermittent connector cannot bounce the controller in and out of limp mode.
+/// 
+/// Dual-solenoid physics (both channels failsafe at 0% duty):
+/// - Upper dome solenoid lost: upper dome vented, lower dome solenoid can still
+///   modulate between "forced open" and spring pressure → reduced authority
+/// - Lower dome solenoid lost: lower dome sits at full feed pressure, wastegate is
+///   forced open and fighting it with the upper dome is not permitted → failsafe
+#[derive(Debug, Clone)]
+pub struct SolenoidFailover {
+    topology: SolenoidTopology,
+    primary_status: SolenoidDriveStatus,
+    secondary_status: SolenoidDriveStatus,
+    degradation: OutputDegradation,
+}
+
+impl SolenoidFailover {
+    /// Create failover manager for configured topology
+    pub fn new(topology: SolenoidTopology) -> Self {
+        Self {
+            topology,
+            primary_status: SolenoidDriveStatus::Ok,
+            secondary_status: SolenoidDriveStatus::Ok,
+            degradation: OutputDegradation::Nominal,
+        }
+    }
+    
+    /// Clear latched channel failures (system re-initialization only)
+    pub fn reset(&mut self) {
+        *self = Self::new(self.topology);
+    }
+    
+    /// Configured topology
+    pub fn topology(&self) -> SolenoidTopology {
+        self.topology
+    }
+    
+    /// Current degradation level
+    pub fn degradation(&self) -> OutputDegradation {
+        self.degradation
+    }
+    
+    /// Update with latest drive status from the HAL
+    /// 
+    /// Returns the resulting degradation level. Degradation never improves
+    /// without an explicit `reset()`.
+    pub fn update(&mut self, primary: SolenoidDriveStatus, secondary: SolenoidDriveStatus) -> OutputDegradation {
+        // Latch the first fault reported on each channel
+        if self.primary_status.is_functional() {
+            self.primary_status = primary;
+        }
+        if self.secondary_status.is_functional() {
+            self.secondary_status = secondary;
+        }
+        
+        let primary_ok = self.primary_status.is_functional();
+        let secondary_ok = self.secondary_status.is_functional();
+        
+        let evaluated = match self.topology {
+            SolenoidTopology::SingleFourPort => {
+                if primary_ok {
+                    OutputDegradation::Nominal
+                } else {
+                    OutputDegradation::Failsafe
+                }
+            },
+            SolenoidTopology::DualSolenoid => match (primary_ok, secondary_ok) {
+                (true, true) => OutputDegradation::Nominal,
+                (false, true) => OutputDegradation::ReducedAuthority,
+                // Lower dome solenoid lost - cannot safely modulate
+                (_, false) => OutputDegradation::Failsafe,
+            },
+            // One bank alone would carry the whole engine on a wastegate sized for half
+            SolenoidTopology::TwinFourPort => {
+                if primary_ok && secondary_ok {
+                    OutputDegradation::Nominal
+                } else {
+                    OutputDegradation::Failsafe
+                }
+            },
+        };
+        
+        self.degradation = Self::worst(self.degradation, evaluated);
+        self.degradation
+    }
+    
+    /// Topology actually being driven
+    pub fn active_topology(&self) -> ActiveOutputTopology {
+        match (self.topology, self.degradation) {
+            (_, OutputDegradation::Failsafe) => ActiveOutputTopology::Disabled,
+            (SolenoidTopology::SingleFourPort, _) => ActiveOutputTopology::SingleFourPort,
+            (SolenoidTopology::DualSolenoid, OutputDegradation::Nominal) => ActiveOutputTopology::DualSolenoid,
+            (SolenoidTopology::DualSolenoid, OutputDegradation::ReducedAuthority) => ActiveOutputTopology::LowerDomeOnly,
+            (SolenoidTopology::TwinFourPort, _) => ActiveOutputTopology::TwinFourPort,
+        }
+    }
+    
+    /// Maximum boost target the remaining hardware can deliver (PSI)
+    /// 
+    /// In limp mode the wastegate can only be held shut by its spring, so targets
+    /// above spring pressure would just wind up the controller.
+    pub fn boost_ceiling_psi(&self, config: &SystemConfig) -> f32 {
+        match self.degradation {
+            OutputDegradation::Nominal => config.max_boost_psi,
+            OutputDegradation::ReducedAuthority => config.spring_pressure.min(config.max_boost_psi),
+            OutputDegradation::Failsafe => 0.0,
+        }
+    }
+    
+    /// Split a wastegate closing-authority command (0-100%) into channel duties
+    pub fn channel_duties(&self, duty_percent: f32) -> ChannelDuties {
+        let duty = duty_percent.clamp(0.0, 100.0);
+        
+        match self.active_topology() {
+            ActiveOutputTopology::SingleFourPort => ChannelDuties { primary: duty, secondary: 0.0 },
+            // Energizing the lower dome solenoid vents the lower dome
+            ActiveOutputTopology::DualSolenoid => ChannelDuties { primary: duty, secondary: duty },
+            ActiveOutputTopology::TwinFourPort => ChannelDuties { primary: duty, secondary: duty },
+            ActiveOutputTopology::LowerDomeOnly => ChannelDuties { primary: 0.0, secondary: duty },
+            ActiveOutputTopology::Disabled => ChannelDuties { primary: 0.0, secondary: 0.0 },
+        }
+    }
+    
+    /// Status snapshot for diagnostics
+    pub fn status(&self) -> OutputStageStatus {
+        OutputStageStatus {
+            configured_topology: self.topology,
+            active_topology: self.active_topology(),
+            degradation: self.degradation,
+            primary_status: self.primary_status,
+            secondary_status: self.secondary_status,
+        }
+    }
+    
+    /// Channel identifiers used by the configured topology
+    pub fn channels(&self) -> &'static [SolenoidChannel] {
+        match self.topology {
+            SolenoidTopology::SingleFourPort => &[SolenoidChannel::Primary],
+            SolenoidTopology::DualSolenoid | SolenoidTopology::TwinFourPort => &[SolenoidChannel::Primary, SolenoidChannel::Secondary],
+        }
+    }
+    
+    fn worst(a: OutputDegradation, b: OutputDegradation) -> OutputDegradation {
+        let rank = |d: OutputDegradation| match d {
+            OutputDegradation::Nominal => 0,
+            OutputDegradation::ReducedAuthority => 1,
+            OutputDegradation::Failsafe => 2,
+        };
+        if rank(b) > rank(a) { b } else { a }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_single_valve_open_circuit_forces_failsafe() {
+        let mut failover = SolenoidFailover::new(SolenoidTopology::SingleFourPort);
+        assert_eq!(failover.update(SolenoidDriveStatus::Ok, SolenoidDriveStatus::OpenCircuit), OutputDegradation::Nominal);
+        assert_eq!(failover.update(SolenoidDriveStatus::OpenCircuit, SolenoidDriveStatus::Ok), OutputDegradation::Failsafe);
+        assert_eq!(failover.active_topology(), ActiveOutputTopology::Disabled);
+        assert_eq!(failover.channel_duties(60.0), ChannelDuties { primary: 0.0, secondary: 0.0 });
+    }
+    
+    #[test]
+    fn test_upper_solenoid_loss_enters_limp_mode() {
+        let config = SystemConfig::default();
+        let mut failover = SolenoidFailover::new(SolenoidTopology::DualSolenoid);
+        
+        assert_eq!(failover.channel_duties(60.0), ChannelDuties { primary: 60.0, secondary: 60.0 });
+        
+        failover.update(SolenoidDriveStatus::OpenCircuit, SolenoidDriveStatus::Ok);
+        assert_eq!(failover.degradation(), OutputDegradation::ReducedAuthority);
+        assert_eq!(failover.active_topology(), ActiveOutputTopology::LowerDomeOnly);
+        assert_eq!(failover.channel_duties(60.0), ChannelDuties { primary: 0.0, secondary: 60.0 });
+        
+        // Boost authority limited to spring pressure
+        assert_eq!(failover.boost_ceiling_psi(&config), config.spring_pressure);
+    }
+    
+    #[test]
+    fn test_lower_solenoid_loss_forces_failsafe() {
+        let config = SystemConfig::default();
+        let mut failover = SolenoidFailover::new(SolenoidTopology::DualSolenoid);
+        
+        failover.update(SolenoidDriveStatus::Ok, SolenoidDriveStatus::OpenCircuit);
+        assert_eq!(failover.degradation(), OutputDegradation::Failsafe);
+        assert_eq!(failover.boost_ceiling_psi(&config), 0.0);
+    }
+    
+    #[test]
+    fn test_twin_bank_valve_loss_forces_failsafe() {
+        let mut failover = SolenoidFailover::new(SolenoidTopology::TwinFourPort);
+        assert_eq!(failover.channel_duties(60.0), ChannelDuties { primary: 60.0, secondary: 60.0 });
+        
+        assert_eq!(failover.update(SolenoidDriveStatus::Ok, SolenoidDriveStatus::OpenCircuit), OutputDegradation::Failsafe);
+        assert_eq!(failover.active_topology(), ActiveOutputTopology::Disabled);
+    }
+    
+    #[test]
+    fn test_channel_failure_latches_until_reset() {
+        let mut failover = SolenoidFailover::new(SolenoidTopology::DualSolenoid);
+        
+        failover.update(SolenoidDriveStatus::OpenCircuit, SolenoidDriveStatus::Ok);
+        // Intermittent connector reports healthy again - stay degraded
+        assert_eq!(failover.update(SolenoidDriveStatus::Ok, SolenoidDriveStatus::Ok), OutputDegradation::ReducedAuthority);
+        
+        // Escalation still applies while latched
+        assert_eq!(failover.update(SolenoidDriveStatus::Ok, SolenoidDriveStatus::ShortCircuit), OutputDegradation::Failsafe);
+        
+        failover.reset();
+        assert_eq!(failover.degradation(), OutputDegradation::Nominal);
+        assert_eq!(failover.active_topology(), ActiveOutputTopology::DualSolenoid);
+    }
+}
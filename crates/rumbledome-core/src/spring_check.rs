@@ -0,0 +1,292 @@
+//! Wastegate Spring Verification
+//! 
+//! 🔗 T4-CORE-127: Guided Spring Pressure Check
+//! Derived From: T4-CORE-073 (Commissioning Sequence) + T4-CORE-114 (Built-In Mechanical Fallback Profile)
+//! AI Traceability: A wrong spring value shifts every boost limit and learned cell - measure it instead of trusting what was typed in
+//! 
+//! The check holds the output at 0 % duty, so the wastegate opens at its
+//! spring pressure exactly as in the spring-only profile. The driver makes
+//! one wide-open pull in a high gear; once the turbo is spooled, boost
+//! levels off at the spring pressure. A plateau is boost held within a
+//! narrow band for half a second, and the highest plateau of the pull is the
+//! measurement. Lifting ends the run, and the measurement is compared with
+//! the configured spring pressure. Without a throttle signal, the ECU asking
+//! for far more torque than the engine makes is the wide-open pull.
+//! 
+//! Safety caps: the run is confirmed on the device display before it starts,
+//! the overboost cut stays in force throughout (a cut or fault abandons the
+//! run), and a run without a usable pull gives up after two minutes.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use crate::SystemInputs;
+
+/// Largest difference between measured and configured spring pressure accepted (PSI)
+pub const SPRING_CHECK_TOLERANCE_PSI: f32 = 0.5;
+
+/// Throttle treated as wide open (%)
+const WIDE_OPEN_THROTTLE_PERCENT: f32 = 90.0;
+
+/// Lowest RPM at which the turbo is taken as spooled
+const SPOOLED_RPM: u16 = 3000;
+
+/// Boost must stay within this band to count as a plateau (PSI, peak to peak)
+const PLATEAU_BAND_PSI: f32 = 0.6;
+
+/// Time boost must hold within the band (ms)
+const PLATEAU_HOLD_MS: u32 = 500;
+
+/// Run given up if no plateau is found in this time (ms)
+const CHECK_TIMEOUT_MS: u32 = 120_000;
+
+/// Where a spring check is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpringCheckPhase {
+    /// No check run since power-up
+    #[default]
+    NotRun,
+    /// Output held at 0 % - waiting for a wide-open pull
+    WaitingForPull,
+    /// Plateau found - lift to finish
+    PlateauFound,
+    /// Measured pressure matches the configured spring
+    Passed,
+    /// Measured pressure differs from the configured spring by more than the tolerance
+    Mismatch,
+    /// Run abandoned without a measurement
+    Aborted,
+}
+
+/// Measured against configured spring pressure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpringCheckReport {
+    /// Spring pressure in the running configuration (PSI)
+    pub configured_psi: f32,
+    /// Boost plateau at 0 % duty (PSI)
+    pub measured_psi: f32,
+    /// Measured - configured (PSI)
+    pub difference_psi: f32,
+    /// Difference within `SPRING_CHECK_TOLERANCE_PSI`
+    pub within_tolerance: bool,
+}
+
+impl SpringCheckReport {
+    /// Spring pressure to configure, rounded to 0.1 PSI
+    pub fn suggested_spring_psi(&self) -> f32 {
+        libm::roundf(self.measured_psi * 10.0) / 10.0
+    }
+    
+    /// Configuration fault text naming the correction
+    pub fn correction(&self) -> String {
+        format!(
+            "Wastegate spring measured {:.1} PSI but configured {:.1} PSI - set spring_pressure to {:.1}",
+            self.measured_psi, self.configured_psi, self.suggested_spring_psi()
+        )
+    }
+}
+
+/// Spring check progress (diagnostics and CLI)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpringCheckStatus {
+    pub phase: SpringCheckPhase,
+    /// Highest plateau found so far (PSI)
+    pub plateau_psi: Option<f32>,
+    /// Comparison, once the run has finished
+    pub report: Option<SpringCheckReport>,
+    /// Why the run was abandoned
+    pub abort_reason: Option<String>,
+}
+
+/// Boost samples held within the plateau band
+#[derive(Debug, Clone, Copy)]
+struct PlateauWindow {
+    started_ms: u32,
+    min_psi: f32,
+    max_psi: f32,
+    sum_psi: f32,
+    samples: u32,
+}
+
+impl PlateauWindow {
+    fn start(psi: f32, now_ms: u32) -> Self {
+        Self { started_ms: now_ms, min_psi: psi, max_psi: psi, sum_psi: psi, samples: 1 }
+    }
+}
+
+/// One guided spring pressure measurement
+#[derive(Debug, Clone)]
+pub struct SpringCheck {
+    configured_psi: f32,
+    started_ms: Option<u32>,
+    window: Option<PlateauWindow>,
+    status: SpringCheckStatus,
+}
+
+impl SpringCheck {
+    /// Start a check against the configured spring pressure
+    pub fn new(configured_psi: f32) -> Self {
+        Self {
+            configured_psi,
+            started_ms: None,
+            window: None,
+            status: SpringCheckStatus { phase: SpringCheckPhase::WaitingForPull, ..SpringCheckStatus::default() },
+        }
+    }
+    
+    /// Whether the output must stay at 0 % for the check
+    pub fn is_running(&self) -> bool {
+        matches!(self.status.phase, SpringCheckPhase::WaitingForPull | SpringCheckPhase::PlateauFound)
+    }
+    
+    /// Follow one control cycle at 0 % duty; returns the report when the run finishes
+    pub fn observe(&mut self, inputs: &SystemInputs) -> Option<SpringCheckReport> {
+        if !self.is_running() {
+            return None;
+        }
+        let now_ms = inputs.timestamp_ms;
+        let started_ms = *self.started_ms.get_or_insert(now_ms);
+        
+        let pulling = inputs.wide_open(WIDE_OPEN_THROTTLE_PERCENT) && inputs.rpm >= SPOOLED_RPM;
+        if pulling {
+            self.track_plateau(inputs.manifold_pressure, now_ms);
+            return None;
+        }
+        
+        self.window = None;
+        if let Some(measured_psi) = self.status.plateau_psi {
+            return Some(self.finish(measured_psi));
+        }
+        if now_ms.wrapping_sub(started_ms) >= CHECK_TIMEOUT_MS {
+            self.abort(&format!("No steady wide-open pull above {} RPM within {} s", SPOOLED_RPM, CHECK_TIMEOUT_MS / 1000));
+        }
+        None
+    }
+    
+    /// Abandon the run without a measurement
+    pub fn abort(&mut self, reason: &str) {
+        if self.is_running() {
+            self.status.phase = SpringCheckPhase::Aborted;
+            self.status.abort_reason = Some(reason.to_string());
+        }
+    }
+    
+    /// Current progress
+    pub fn status(&self) -> &SpringCheckStatus {
+        &self.status
+    }
+    
+    fn track_plateau(&mut self, psi: f32, now_ms: u32) {
+        let mut window = match self.window {
+            Some(window) => window,
+            None => PlateauWindow::start(psi, now_ms),
+        };
+        window.min_psi = window.min_psi.min(psi);
+        window.max_psi = window.max_psi.max(psi);
+        if window.max_psi - window.min_psi > PLATEAU_BAND_PSI {
+            // Still spooling (or surging) - the plateau starts over from here
+            window = PlateauWindow::start(psi, now_ms);
+        } else if self.window.is_some() {
+            window.sum_psi += psi;
+            window.samples += 1;
+        }
+        
+        if now_ms.wrapping_sub(window.started_ms) >= PLATEAU_HOLD_MS {
+            let plateau_psi = window.sum_psi / window.samples as f32;
+            self.status.plateau_psi = Some(self.status.plateau_psi.map_or(plateau_psi, |best| best.max(plateau_psi)));
+            self.status.phase = SpringCheckPhase::PlateauFound;
+        }
+        self.window = Some(window);
+    }
+    
+    fn finish(&mut self, measured_psi: f32) -> SpringCheckReport {
+        let difference_psi = measured_psi - self.configured_psi;
+        let report = SpringCheckReport {
+            configured_psi: self.configured_psi,
+            measured_psi,
+            difference_psi,
+            within_tolerance: libm::fabsf(difference_psi) <= SPRING_CHECK_TOLERANCE_PSI,
+        };
+        self.status.phase = if report.within_tolerance { SpringCheckPhase::Passed } else { SpringCheckPhase::Mismatch };
+        self.status.report = Some(report.clone());
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(rpm: u16, throttle_position: f32, manifold_pressure: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { rpm, throttle_position: Some(throttle_position), manifold_pressure, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    /// Wide-open pull spooling to `spring_psi` by 4000 RPM, then a lift
+    fn pull(check: &mut SpringCheck, spring_psi: f32) -> Option<SpringCheckReport> {
+        for t in (0..3_000).step_by(10) {
+            let rpm = 2500 + t as u16;
+            let boost = spring_psi * (t as f32 / 1_500.0).min(1.0);
+            assert_eq!(check.observe(&inputs(rpm, 100.0, boost, t)), None);
+        }
+        check.observe(&inputs(5500, 10.0, 0.0, 3_000))
+    }
+    
+    #[test]
+    fn test_matching_spring_passes() {
+        let mut check = SpringCheck::new(5.0);
+        let report = pull(&mut check, 5.2).unwrap();
+        assert!(report.within_tolerance);
+        assert!((report.measured_psi - 5.2).abs() < 0.05);
+        assert_eq!(check.status().phase, SpringCheckPhase::Passed);
+        assert!(!check.is_running());
+    }
+    
+    #[test]
+    fn test_wrong_spring_suggests_correction() {
+        let mut check = SpringCheck::new(5.0);
+        let report = pull(&mut check, 7.3).unwrap();
+        assert!(!report.within_tolerance);
+        assert_eq!(report.suggested_spring_psi(), 7.3);
+        assert_eq!(report.correction(), "Wastegate spring measured 7.3 PSI but configured 5.0 PSI - set spring_pressure to 7.3");
+        assert_eq!(check.status().phase, SpringCheckPhase::Mismatch);
+    }
+    
+    #[test]
+    fn test_spool_is_not_a_plateau() {
+        let mut check = SpringCheck::new(5.0);
+        // Boost still climbing when the driver lifts
+        for t in (0..1_000).step_by(10) {
+            check.observe(&inputs(3500, 100.0, t as f32 / 100.0, t));
+        }
+        assert_eq!(check.status().plateau_psi, None);
+        assert_eq!(check.observe(&inputs(4500, 0.0, 0.0, 1_000)), None);
+        assert!(check.is_running());
+    }
+    
+    #[test]
+    fn test_torque_request_stands_in_for_throttle() {
+        let mut check = SpringCheck::new(5.0);
+        let pulling = |t| SystemInputs {
+            throttle_position: None,
+            desired_torque: 450.0,
+            actual_torque: 300.0,
+            ..inputs(4000, 0.0, 5.2, t)
+        };
+        for t in (0..1_000).step_by(10) {
+            assert_eq!(check.observe(&pulling(t)), None);
+        }
+        
+        let lifted = SystemInputs { desired_torque: 100.0, ..pulling(1_000) };
+        assert!(check.observe(&lifted).unwrap().within_tolerance);
+    }
+    
+    #[test]
+    fn test_gives_up_without_a_pull() {
+        let mut check = SpringCheck::new(5.0);
+        check.observe(&inputs(2000, 30.0, -5.0, 0));
+        check.observe(&inputs(2000, 30.0, -5.0, CHECK_TIMEOUT_MS));
+        assert_eq!(check.status().phase, SpringCheckPhase::Aborted);
+        assert!(check.status().abort_reason.is_some());
+    }
+}
@@ -0,0 +1,134 @@
+//! Spring Pressure Only Profile
+//! 
+//! 🔗 T4-CORE-114: Built-In Mechanical Fallback Profile
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-048 (Named Configuration Library)
+//! AI Traceability: One definition of the safest operating mode - valet lock, CAN loss and faults all mean the same 0 % duty wastegate-spring operation
+//! 
+//! With 0 % duty on every output no pressure reaches the dome and the
+//! wastegate opens at its spring pressure, exactly as if the controller were
+//! not fitted. This profile is built in: it cannot be saved over or deleted,
+//! and `SPRING_ONLY_PROFILE` is accepted wherever a configuration name is -
+//! driver selection and profile schedule rules (a "valet lock" is a rule
+//! naming it).
+//! 
+//! The profile has no settings of its own. It runs on the entry that was
+//! active when it was selected, because spring pressure, PWM frequency and
+//! output topology describe the car rather than a tune; only boost control is
+//! withheld. Subsystems that fall back on their own - CAN loss, output stage
+//! failure, faults - are reported as running the same profile with their
+//! reason, so display and diagnostics show one mode however it was reached.
+
+use serde::{Deserialize, Serialize};
+use crate::SystemConfig;
+
+/// Name of the built-in spring pressure only profile
+pub const SPRING_ONLY_PROFILE: &str = "spring only";
+
+/// Why the wastegate is running on its spring alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpringOnlyReason {
+    /// Profile selected by the driver or a schedule rule
+    Selected,
+    /// CAN communication lost while torque following
+    CanLost,
+    /// Output stage cannot be driven safely (solenoid failover)
+    OutputFailed,
+    /// System fault
+    Fault,
+}
+
+impl SpringOnlyReason {
+    /// Short text for display
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            Self::Selected => "Spring only",
+            Self::CanLost => "Spring only - CAN lost",
+            Self::OutputFailed => "Spring only - output failed",
+            Self::Fault => "Spring only - fault",
+        }
+    }
+}
+
+/// Limits the spring-only profile runs with on `base` hardware
+/// 
+/// For comparing against other configurations (e.g. whether leaving the
+/// profile raises safety limits); the core never runs this configuration,
+/// it holds the output at 0 % instead.
+pub fn spring_only_config(base: &SystemConfig) -> SystemConfig {
+    SystemConfig {
+        max_boost_psi: base.spring_pressure,
+        scramble_enabled: false,
+        ..base.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProtectedAction;
+    
+    #[test]
+    fn test_leaving_spring_only_raises_limits() {
+        let daily = SystemConfig::default();
+        let spring_only = spring_only_config(&daily);
+        assert_eq!(spring_only.max_boost_psi, daily.spring_pressure);
+        assert!(!spring_only.scramble_enabled);
+        assert!(ProtectedAction::for_config_change(&daily, &spring_only).is_none());
+        assert!(ProtectedAction::for_config_change(&spring_only, &daily).is_some());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{ConfigLibrary, ControlMode, RumbleDomeCore, SystemInputs, SystemState};
+    use rumbledome_hal::MockHal;
+    
+    fn boosting(timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { rpm: 5000, manifold_pressure: 6.0, throttle_position: Some(100.0), aggression: 1.0, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    fn armed_core(select: &str) -> RumbleDomeCore<MockHal> {
+        let config = SystemConfig { control_mode: ControlMode::BoostByTarget, ..SystemConfig::default() };
+        let mut library = ConfigLibrary::single("daily", config);
+        library.select(select).unwrap();
+        let mut core = RumbleDomeCore::with_configurations(MockHal::new(), library);
+        core.initialize().unwrap();
+        core.skip_commissioning().unwrap();
+        core.arm().unwrap();
+        core
+    }
+    
+    #[test]
+    fn test_selected_profile_holds_zero_duty_while_armed() {
+        let mut core = armed_core("daily");
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(0)).unwrap();
+        assert!(core.cycle_outputs().target_boost_psi > core.config.spring_pressure);
+        assert_eq!(core.spring_only_reason(), None);
+        
+        let mut core = armed_core(SPRING_ONLY_PROFILE);
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(0)).unwrap();
+        assert_eq!(core.state, SystemState::Armed);
+        assert_eq!(core.cycle_outputs().duty_percent, 0.0);
+        assert_eq!(core.cycle_outputs().target_boost_psi, 0.0);
+        assert_eq!(core.get_system_status().spring_only, Some(SpringOnlyReason::Selected));
+        assert_eq!(core.get_system_status().active_configuration, SPRING_ONLY_PROFILE);
+    }
+    
+    #[test]
+    fn test_can_loss_reports_spring_only() {
+        let mut core = armed_core("daily");
+        core.config.control_mode = ControlMode::TorqueFollowing;
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        core.execute_control_cycle_with_inputs(boosting(0)).unwrap();
+        
+        // No torque frames for well past the loss threshold
+        core.execute_control_cycle_with_inputs(boosting(2_000)).unwrap();
+        assert!(matches!(core.state, SystemState::Fault(_)));
+        assert_eq!(core.cycle_outputs().duty_percent, 0.0);
+        assert_eq!(core.spring_only_reason(), Some(SpringOnlyReason::CanLost));
+    }
+}
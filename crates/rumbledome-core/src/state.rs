@@ -12,6 +12,8 @@ use std::{string::{String, ToString}, format};
 
 use serde::{Deserialize, Serialize};
 
+use crate::{CommissioningStep, DisplayString, ErrorCoded, Language};
+
 /// System operational states
 /// 
 /// 🔗 T4-CORE-018: System State Enumeration
@@ -31,6 +33,9 @@ pub enum SystemState {
     /// Auto-calibration in progress
     Calibrating(CalibrationProgress),
     
+    /// First-run commissioning step in progress - boost control disabled
+    Commissioning(CommissioningStep),
+    
     /// Overboost protection active - immediate 0% duty until pressure drops
     OverboostCut,
     
@@ -104,6 +109,12 @@ pub enum FaultCode {
     /// Safety response time validation failed
     SafetyResponseTooSlow,
     
+    /// Output command failed its integrity check before being applied
+    ControlFault(String),
+    
+    /// Stored copies of the overboost and duty limits disagree with no majority
+    SafetyLimitsCorrupted,
+    
     // Configuration Faults (Warning - continue with defaults)
     /// Invalid user configuration detected
     InvalidConfiguration(String),
@@ -118,6 +129,9 @@ pub enum FaultCode {
     /// Pressure sensor reading implausible
     ImplausibleSensorReading { sensor: String, value: f32 },
     
+    /// Redundant CAN and analog sources disagree beyond threshold
+    InputSourceMismatch { input: String, can_value: f32, analog_value: f32 },
+    
     // Learning System Faults (Warning - continue without learning)
     /// Auto-calibration failed to converge
     CalibrationFailed(String),
@@ -141,11 +155,16 @@ impl FaultCode {
             // Safety faults are always critical
             FaultCode::OverboostLimitExceeded { .. }
             | FaultCode::PneumaticSystemFailure
-            | FaultCode::SafetyResponseTooSlow => true,
+            | FaultCode::SafetyResponseTooSlow
+            | FaultCode::ControlFault(_)
+            | FaultCode::SafetyLimitsCorrupted => true,
             
             // CAN loss is critical for torque-following system
             FaultCode::CanCommunicationLost => true,
             
+            // Unknown which reading is correct - cannot trust boost measurement
+            FaultCode::InputSourceMismatch { .. } => true,
+            
             // Pressure sensor faults may be critical depending on which sensor
             FaultCode::PressureSensorFault(sensor) => {
                 sensor.contains("manifold") // Manifold pressure is critical for safety
@@ -156,6 +175,36 @@ impl FaultCode {
         }
     }
     
+    /// Stable numeric code shown on the display in every language
+    /// 
+    /// Codes are never reused or renumbered - a new fault takes the next free number.
+    pub fn number(&self) -> u8 {
+        match self {
+            FaultCode::SelfTestFailed => 1,
+            FaultCode::PwmHardwareFault => 2,
+            FaultCode::PressureSensorFault(_) => 3,
+            FaultCode::CanCommunicationLost => 4,
+            FaultCode::StorageSystemFault => 5,
+            FaultCode::OverboostLimitExceeded { .. } => 6,
+            FaultCode::PneumaticSystemFailure => 7,
+            FaultCode::SafetyResponseTooSlow => 8,
+            FaultCode::InvalidConfiguration(_) => 9,
+            FaultCode::CalibrationDataCorrupted => 10,
+            FaultCode::TorqueSignalsInvalid => 11,
+            FaultCode::ImplausibleSensorReading { .. } => 12,
+            FaultCode::InputSourceMismatch { .. } => 13,
+            FaultCode::CalibrationFailed(_) => 14,
+            FaultCode::LearningInconsistency => 15,
+            FaultCode::ControlFault(_) => 16,
+            FaultCode::SafetyLimitsCorrupted => 17,
+        }
+    }
+    
+    /// Fault code as shown on the display (e.g. "F04") - its error registry identifier
+    pub fn display_code(&self) -> String {
+        self.error_code().to_string()
+    }
+    
     /// Get human-readable description of fault
     pub fn description(&self) -> String {
         match self {
@@ -184,6 +233,12 @@ impl FaultCode {
             FaultCode::SafetyResponseTooSlow => 
                 "Safety response time exceeded specification - system unsafe".to_string(),
             
+            FaultCode::ControlFault(reason) => 
+                format!("Control output integrity check failed: {}", reason),
+            
+            FaultCode::SafetyLimitsCorrupted => 
+                "Stored safety limits disagree - boost control disabled".to_string(),
+            
             FaultCode::InvalidConfiguration(msg) => 
                 format!("Invalid configuration: {}", msg),
             
@@ -196,6 +251,9 @@ impl FaultCode {
             FaultCode::ImplausibleSensorReading { sensor, value } => 
                 format!("Implausible reading from {}: {:.2}", sensor, value),
             
+            FaultCode::InputSourceMismatch { input, can_value, analog_value } => 
+                format!("{} sources disagree: CAN {:.2}, analog {:.2}", input, can_value, analog_value),
+            
             FaultCode::CalibrationFailed(msg) => 
                 format!("Auto-calibration failed: {}", msg),
             
@@ -243,11 +301,20 @@ impl FaultCode {
             FaultCode::ImplausibleSensorReading { .. } => 
                 "Check sensor calibration and connections".to_string(),
             
+            FaultCode::InputSourceMismatch { .. } => 
+                "Check analog sensor air line and CAN signal scaling".to_string(),
+            
             FaultCode::CalibrationFailed(_) => 
                 "Check pneumatic system and retry calibration".to_string(),
             
             FaultCode::LearningInconsistency => 
                 "Reset learned data if problem persists".to_string(),
+            
+            FaultCode::ControlFault(_) => 
+                "Power cycle the controller; if it happens again, save a support bundle and report it".to_string(),
+            
+            FaultCode::SafetyLimitsCorrupted => 
+                "Check the overboost limit and max duty, then save the configuration again to rewrite them".to_string(),
         }
     }
 }
@@ -263,6 +330,7 @@ impl SystemState {
             SystemState::OverboostCut => false, // Must clear overboost first
             SystemState::Fault(fault) => !fault.is_critical(),
             SystemState::Calibrating(_) => false, // Must complete calibration
+            SystemState::Commissioning(_) => false, // Must finish or leave commissioning
             SystemState::Initializing => false, // Must complete initialization
             SystemState::Armed => true, // Already armed
         }
@@ -280,19 +348,28 @@ impl SystemState {
             SystemState::Fault(fault) => fault.is_critical(),
             SystemState::Armed => false,
             SystemState::Calibrating(_) => false, // Calibration controls PWM
+            SystemState::Commissioning(_) => true, // Observe only
         }
     }
     
     /// Get display status text for current state
     pub fn display_text(&self) -> String {
+        self.localized_text(Language::English)
+    }
+    
+    /// Display status text in the given language
+    pub fn localized_text(&self, language: Language) -> String {
+        let text = |id: DisplayString| id.text(language).to_string();
         match self {
-            SystemState::Initializing => "INIT".to_string(),
-            SystemState::Idle => "IDLE".to_string(),
-            SystemState::Armed => "ARMED".to_string(),
-            SystemState::Calibrating(progress) => 
-                format!("CAL {}%", (progress.overall_progress * 100.0) as u8),
-            SystemState::OverboostCut => "OVERBOOST".to_string(),
-            SystemState::Fault(_) => "FAULT".to_string(),
+            SystemState::Initializing => text(DisplayString::StateInit),
+            SystemState::Idle => text(DisplayString::StateIdle),
+            SystemState::Armed => text(DisplayString::StateArmed),
+            SystemState::Calibrating(progress) => format!("{} {}%",
+                DisplayString::StateCalibrating.text(language), (progress.overall_progress * 100.0) as u8),
+            SystemState::Commissioning(step) => format!("{} {}/{}",
+                DisplayString::StateSetup.text(language), step.number(), CommissioningStep::ALL.len()),
+            SystemState::OverboostCut => text(DisplayString::StateOverboost),
+            SystemState::Fault(_) => text(DisplayString::StateFault),
         }
     }
     
@@ -303,6 +380,7 @@ impl SystemState {
             SystemState::OverboostCut => 200,    // Very high - safety critical
             SystemState::Initializing => 150,    // High - startup state
             SystemState::Calibrating(_) => 100,  // Medium - user should know
+            SystemState::Commissioning(_) => 100, // Medium - user should know
             SystemState::Armed => 50,            // Low - normal operation
             SystemState::Idle => 25,             // Lowest - standby state
         }
@@ -328,6 +406,7 @@ mod tests {
         assert!(SystemState::OverboostCut.requires_failsafe_pwm());
         assert!(SystemState::Fault(FaultCode::SelfTestFailed).requires_failsafe_pwm());
         assert!(!SystemState::Armed.requires_failsafe_pwm());
+        assert!(SystemState::Commissioning(CommissioningStep::ObserveDrive).requires_failsafe_pwm());
     }
     
     #[test]
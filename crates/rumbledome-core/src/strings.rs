@@ -0,0 +1,187 @@
+//! Display Strings
+//! 
+//! 🔗 T4-CORE-085: Localized Display String Table
+//! Derived From: T4-CORE-035 (Semantic Screen Description) + Hardware.md display requirements
+//! AI Traceability: Alert banners and screen labels in the driver's language; fault codes stay numeric
+
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Display language (a device-wide preference, not part of any configuration profile)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    /// Every supported language, in string table column order
+    pub const ALL: [Language; 3] = [Language::English, Language::Spanish, Language::German];
+    
+    /// ISO 639-1 code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::German => "de",
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = ();
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL.into_iter().find(|language| language.code() == s).ok_or(())
+    }
+}
+
+/// Identifier of a display string
+/// 
+/// Strings are plain ASCII - the gauge font has no accented glyphs. Fault
+/// banners carry the numeric fault code rather than a translated description,
+/// so a code read off the gauge means the same thing in every language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayString {
+    // State text at the top of the gauge
+    StateInit,
+    StateIdle,
+    StateArmed,
+    StateCalibrating,
+    StateSetup,
+    StateOverboost,
+    StateFault,
+    StateDemo,
+    // Alert banners
+    AlertOverboostCut,
+    AlertSolenoidLimp,
+    AlertCanDegraded,
+    AlertLiveTune,
+    AlertCheckMapSensor,
+    AlertService,
+    AlertExerciseDone,
+    AlertExerciseCutShort,
+    AlertDemo,
+    AlertHeatDerate,
+    AlertBackpressure,
+    AlertBankImbalance,
+    AlertConfirmCode,
+    // Full-screen fault layout
+    ScreenSystemFault,
+    ScreenBoostDisabled,
+    // Gauge labels
+    LabelTarget,
+    LabelMax,
+    LabelAggression,
+    LabelMode,
+    LabelExhaust,
+    // Control modes
+    ModeTorqueFollowing,
+    ModeBoostByTarget,
+    // Limits shown with headroom
+    LimitOverboost,
+    LimitProfileMax,
+    LimitRevTaper,
+    LimitDuty,
+    LimitThermal,
+    // Commissioning steps and outcomes
+    StepSensorZero,
+    StepSpringPressure,
+    StepFrequencySweep,
+    StepLeakTest,
+    StepObserveDrive,
+    StepFirstCalibration,
+    OutcomeCompleted,
+    OutcomeSkipped,
+}
+
+impl DisplayString {
+    /// Text in the given language
+    pub fn text(self, language: Language) -> &'static str {
+        self.entry()[language as usize]
+    }
+    
+    /// Table row: English, Spanish, German
+    const fn entry(self) -> [&'static str; 3] {
+        use DisplayString::*;
+        
+        match self {
+            StateInit => ["INIT", "INICIO", "START"],
+            StateIdle => ["IDLE", "REPOSO", "BEREIT"],
+            StateArmed => ["ARMED", "ARMADO", "AKTIV"],
+            StateCalibrating => ["CAL", "CAL", "KAL"],
+            StateSetup => ["SETUP", "CONFIG", "EINRICHT"],
+            StateOverboost => ["OVERBOOST", "SOBREPRESION", "UBERDRUCK"],
+            StateFault => ["FAULT", "FALLO", "FEHLER"],
+            StateDemo => ["DEMO", "DEMO", "DEMO"],
+            AlertOverboostCut => ["OVERBOOST - BOOST CUT", "SOBREPRESION - CORTE", "UBERDRUCK - ABSCHALTUNG"],
+            AlertSolenoidLimp => ["SOLENOID FAULT - LIMP MODE", "FALLO SOLENOIDE - EMERGENCIA", "VENTILFEHLER - NOTLAUF"],
+            AlertCanDegraded => ["CAN DATA DEGRADED", "DATOS CAN DEGRADADOS", "CAN-DATEN GESTORT"],
+            AlertLiveTune => ["LIVE TUNE ACTIVE", "AJUSTE EN VIVO ACTIVO", "LIVE-TUNING AKTIV"],
+            AlertCheckMapSensor => ["CHECK MAP SENSOR", "REVISAR SENSOR MAP", "MAP-SENSOR PRUFEN"],
+            AlertService => ["SERVICE", "SERVICIO", "WARTUNG"],
+            AlertExerciseDone => ["SOLENOID EXERCISE DONE", "LIMPIEZA SOLENOIDE HECHA", "VENTILREINIGUNG FERTIG"],
+            AlertExerciseCutShort => ["SOLENOID EXERCISE CUT SHORT", "LIMPIEZA SOLENOIDE INTERRUMPIDA", "VENTILREINIGUNG ABGEBROCHEN"],
+            AlertDemo => ["DISPLAY TEST", "PRUEBA DE PANTALLA", "ANZEIGETEST"],
+            AlertHeatDerate => ["HEAT - BOOST REDUCED", "CALOR - PRESION REDUCIDA", "HITZE - LADEDRUCK REDUZIERT"],
+            AlertBackpressure => ["BACKPRESSURE - BOOST REDUCED", "CONTRAPRESION - PRESION REDUCIDA", "ABGASDRUCK - LADEDRUCK REDUZIERT"],
+            AlertBankImbalance => ["CHECK WASTEGATE BANK", "REVISAR WASTEGATE BANCO", "WASTEGATE BANK PRUFEN"],
+            AlertConfirmCode => ["CONFIRM CODE", "CODIGO DE CONFIRMACION", "BESTATIGUNGSCODE"],
+            ScreenSystemFault => ["SYSTEM FAULT", "FALLO DEL SISTEMA", "SYSTEMFEHLER"],
+            ScreenBoostDisabled => ["BOOST DISABLED", "TURBO DESACTIVADO", "LADEDRUCK AUS"],
+            LabelTarget => ["target", "objetivo", "Ziel"],
+            LabelMax => ["max", "max", "max"],
+            LabelAggression => ["aggression", "agresividad", "Aggressivitat"],
+            LabelMode => ["mode", "modo", "Modus"],
+            LabelExhaust => ["exhaust", "escape", "Abgas"],
+            ModeTorqueFollowing => ["TORQUE FOLLOW", "SEGUIR PAR", "DREHMOMENT"],
+            ModeBoostByTarget => ["BOOST TARGET", "PRESION OBJETIVO", "ZIELDRUCK"],
+            LimitOverboost => ["overboost", "sobrepresion", "Uberdruck"],
+            LimitProfileMax => ["profile max", "max perfil", "Profil max"],
+            LimitRevTaper => ["rev taper", "corte rpm", "Drehzahlgrenze"],
+            LimitDuty => ["duty", "ciclo", "Tastgrad"],
+            LimitThermal => ["heat derate", "calor", "Hitze"],
+            StepSensorZero => ["Sensor zeroing", "Puesta a cero del sensor", "Sensor-Nullabgleich"],
+            StepSpringPressure => ["Spring pressure detection", "Deteccion de presion de muelle", "Federdruck-Erkennung"],
+            StepFrequencySweep => ["Solenoid frequency sweep", "Barrido de frecuencia", "Frequenzdurchlauf"],
+            StepLeakTest => ["Leak test", "Prueba de fugas", "Dichtheitsprufung"],
+            StepObserveDrive => ["Observe-only drive", "Conduccion de observacion", "Beobachtungsfahrt"],
+            StepFirstCalibration => ["First calibration", "Primera calibracion", "Erstkalibrierung"],
+            OutcomeCompleted => ["Completed", "Completado", "Abgeschlossen"],
+            OutcomeSkipped => ["Skipped", "Omitido", "Ubersprungen"],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_language_codes_round_trip() {
+        for language in Language::ALL {
+            assert_eq!(language.code().parse::<Language>(), Ok(language));
+        }
+        assert!("fr".parse::<Language>().is_err());
+    }
+    
+    #[test]
+    fn test_table_lookup() {
+        assert_eq!(DisplayString::StateArmed.text(Language::English), "ARMED");
+        assert_eq!(DisplayString::StateArmed.text(Language::Spanish), "ARMADO");
+        assert_eq!(DisplayString::StateArmed.text(Language::German), "AKTIV");
+    }
+    
+    #[test]
+    fn test_strings_fit_display_font() {
+        for language in Language::ALL {
+            for id in [DisplayString::StateOverboost, DisplayString::AlertCanDegraded, DisplayString::LabelAggression,
+                DisplayString::StepLeakTest, DisplayString::AlertExerciseCutShort] {
+                let text = id.text(language);
+                assert!(!text.is_empty() && text.is_ascii(), "{:?} in {}", id, language.code());
+            }
+        }
+    }
+}
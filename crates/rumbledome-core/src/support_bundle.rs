@@ -0,0 +1,59 @@
+//! Support Bundle
+//! 
+//! 🔗 T4-CORE-056: Diagnostics Snapshot Bundle
+//! Derived From: T4-CORE-009 (System Status Reporting) + T4-CORE-053 + T4-CORE-055
+//! AI Traceability: Everything needed to diagnose a field report gathered in one structure
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{ConfigLibrary, FaultRecord, FreezeFrame, OverboostEvent, SessionSummary, SystemStatus};
+
+/// Bundle format version - bumped when fields change incompatibly
+pub const SUPPORT_BUNDLE_VERSION: u32 = 1;
+
+/// Firmware and platform identification
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FirmwareInfo {
+    /// Core firmware version
+    pub firmware_version: String,
+    /// Hardware platform name
+    pub platform: String,
+    /// Platform HAL version
+    pub platform_version: String,
+}
+
+/// Non-volatile storage health
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageHealth {
+    /// Storage self-test result from the last initialization (`None` before self-test)
+    pub self_test_passed: Option<bool>,
+    /// Whether the last configuration change fit and was written to the EEPROM configuration record
+    /// (`None` before any change)
+    #[serde(default)]
+    pub config_stored: Option<bool>,
+}
+
+/// Structured diagnostics snapshot for bug reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportBundle {
+    /// Bundle format version
+    pub bundle_version: u32,
+    /// Firmware and platform identification
+    pub firmware: FirmwareInfo,
+    /// All stored named configurations, including the active one
+    pub configurations: ConfigLibrary,
+    /// Current system status
+    pub status: SystemStatus,
+    /// Fault history, oldest first
+    pub fault_history: Vec<FaultRecord>,
+    /// Freeze frame of the most recent fault
+    pub last_freeze_frame: Option<FreezeFrame>,
+    /// Classified overboost events, oldest first
+    #[serde(default)]
+    pub overboost_events: Vec<OverboostEvent>,
+    /// Recent session summaries, oldest first (last entry is the current session)
+    pub sessions: Vec<SessionSummary>,
+    /// Storage health
+    pub storage: StorageHealth,
+}
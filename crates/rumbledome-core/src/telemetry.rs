@@ -0,0 +1,91 @@
+//! Live Telemetry Samples
+//! 
+//! 🔗 T4-CORE-121: Streaming Telemetry Sample
+//! Derived From: T4-CORE-009 (System Status Reporting) + T4-PROTOCOL-004 (Link-Aware Telemetry Throttling)
+//! AI Traceability: The handful of numbers worth watching while driving, small enough to stream at 20 Hz over Bluetooth
+//! 
+//! A full status is several kilobytes and a screen frame carries display
+//! text; a telemetry sample is only the control loop's headline values, so a
+//! monitor or logger can follow every pull without starving the link.
+
+use serde::{Deserialize, Serialize};
+use crate::{AggressionReading, AggressionSource, SystemInputs, SystemState};
+
+/// Headline control values of the most recent cycle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelemetrySample {
+    /// Time of the cycle's inputs (ms since power-up)
+    pub timestamp_ms: u32,
+    pub state: SystemState,
+    pub rpm: u16,
+    /// Manifold pressure (PSI gauge)
+    pub boost_psi: f32,
+    /// Boost target after all caps (PSI)
+    pub target_psi: f32,
+    /// Solenoid duty commanded (%)
+    pub duty_percent: f32,
+    /// Torque the ECU wants but is not getting (Nm, desired - actual)
+    pub torque_gap_nm: f32,
+    /// Aggression in control (0.0-1.0)
+    pub aggression: f32,
+    /// Where the aggression in control comes from
+    pub aggression_source: AggressionSource,
+}
+
+impl TelemetrySample {
+    /// Sample from a cycle's inputs; before the first cycle everything reads zero
+    pub fn capture(
+        state: &SystemState,
+        inputs: Option<&SystemInputs>,
+        target_psi: f32,
+        duty_percent: f32,
+        aggression: AggressionReading,
+    ) -> Self {
+        Self {
+            timestamp_ms: inputs.map(|inputs| inputs.timestamp_ms).unwrap_or(0),
+            state: state.clone(),
+            rpm: inputs.map(|inputs| inputs.rpm).unwrap_or(0),
+            boost_psi: inputs.map(|inputs| inputs.manifold_pressure).unwrap_or(0.0),
+            target_psi,
+            duty_percent,
+            torque_gap_nm: inputs.map(|inputs| inputs.desired_torque - inputs.actual_torque).unwrap_or(0.0),
+            aggression: aggression.value,
+            aggression_source: aggression.source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    #[test]
+    fn test_capture_before_first_cycle() {
+        let setting = AggressionReading { source: AggressionSource::Setting, value: 0.3 };
+        let sample = TelemetrySample::capture(&SystemState::Idle, None, 0.0, 0.0, setting);
+        assert_eq!(sample.timestamp_ms, 0);
+        assert_eq!(sample.torque_gap_nm, 0.0);
+    }
+    
+    #[test]
+    fn test_torque_gap_is_desired_minus_actual() {
+        let inputs = SystemInputs {
+            rpm: 4200,
+            desired_torque: 380.0,
+            actual_torque: 340.0,
+            manifold_pressure: 8.5,
+            throttle_position: Some(80.0),
+            upper_dome_pressure: 12.0,
+            lower_dome_pressure: 2.0,
+            ..test_support::inputs(1_250)
+        };
+        let knob = AggressionReading { source: AggressionSource::Knob, value: 0.5 };
+        let sample = TelemetrySample::capture(&SystemState::Armed, Some(&inputs), 9.0, 42.0, knob);
+        assert_eq!(sample.torque_gap_nm, 40.0);
+        assert_eq!(sample.boost_psi, 8.5);
+        assert_eq!(sample.rpm, 4200);
+        assert_eq!(sample.timestamp_ms, 1_250);
+        assert_eq!(sample.aggression_source, AggressionSource::Knob);
+    }
+}
@@ -0,0 +1,534 @@
+//! Telemetry Sink Registry
+//! 
+//! 🔗 T4-CORE-132: Telemetry Fan-Out
+//! Derived From: T4-CORE-121 (Streaming Telemetry Sample) + T4-CORE-080 (Core Event Bus) + T4-HAL-004 (CAN Interface)
+//! AI Traceability: One sample per cycle feeds the app stream, the datalogger and CAN broadcast - every consumer sees the same numbers
+//! 
+//! The core takes one `TelemetrySample` at the end of each control cycle and
+//! offers it to every registered sink. Each sink has its own interval and
+//! channel set: a dash display on CAN may want boost and target at 10 Hz,
+//! the datalogger every channel at 20 Hz. Intervals stretch with the
+//! performance tier like every other background output.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::{CanFrame, CanInterface, HalResult, CAN_MAX_PAYLOAD};
+use crate::{CoreError, DatalogSinkConfig, FlightState, TelemetrySample};
+
+/// Registered sinks
+pub const MAX_TELEMETRY_SINKS: usize = 4;
+
+/// Channels packed into one CAN broadcast frame (two bytes each)
+pub const CHANNELS_PER_CAN_FRAME: usize = CAN_MAX_PAYLOAD / 2;
+
+/// Value a sink can be given
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TelemetryChannel {
+    Rpm,
+    /// Manifold pressure (PSI gauge)
+    BoostPsi,
+    /// Boost target after all caps (PSI)
+    TargetPsi,
+    /// Solenoid duty commanded (%)
+    DutyPercent,
+    /// Torque the ECU wants but is not getting (Nm)
+    TorqueGapNm,
+    /// Aggression in control (0.0-1.0)
+    Aggression,
+    /// System state code (as the flight recorder stores it)
+    State,
+    /// Boost target minus manifold pressure (PSI)
+    BoostErrorPsi,
+}
+
+impl TelemetryChannel {
+    /// Every channel in column order
+    pub const ALL: [TelemetryChannel; 8] = [
+        TelemetryChannel::Rpm,
+        TelemetryChannel::BoostPsi,
+        TelemetryChannel::TargetPsi,
+        TelemetryChannel::DutyPercent,
+        TelemetryChannel::TorqueGapNm,
+        TelemetryChannel::Aggression,
+        TelemetryChannel::State,
+        TelemetryChannel::BoostErrorPsi,
+    ];
+    
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+    
+    /// Column name in logs
+    pub fn name(&self) -> &'static str {
+        match self {
+            TelemetryChannel::Rpm => "rpm",
+            TelemetryChannel::BoostPsi => "boost_psi",
+            TelemetryChannel::TargetPsi => "target_psi",
+            TelemetryChannel::DutyPercent => "duty_percent",
+            TelemetryChannel::TorqueGapNm => "torque_gap_nm",
+            TelemetryChannel::Aggression => "aggression",
+            TelemetryChannel::State => "state",
+            TelemetryChannel::BoostErrorPsi => "boost_error_psi",
+        }
+    }
+    
+    /// Channel value in a sample
+    pub fn value(&self, sample: &TelemetrySample) -> f32 {
+        match self {
+            TelemetryChannel::Rpm => sample.rpm as f32,
+            TelemetryChannel::BoostPsi => sample.boost_psi,
+            TelemetryChannel::TargetPsi => sample.target_psi,
+            TelemetryChannel::DutyPercent => sample.duty_percent,
+            TelemetryChannel::TorqueGapNm => sample.torque_gap_nm,
+            TelemetryChannel::Aggression => sample.aggression,
+            TelemetryChannel::State => FlightState::from_state(&sample.state).code() as f32,
+            TelemetryChannel::BoostErrorPsi => sample.target_psi - sample.boost_psi,
+        }
+    }
+    
+    /// Channel from its position in `ALL` (binary log headers)
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(code as usize).copied()
+    }
+    
+    /// Multiplier to the integer sent on CAN (0.1 PSI, 0.1 %, 0.01 aggression)
+    pub fn can_scale(&self) -> f32 {
+        match self {
+            TelemetryChannel::BoostPsi | TelemetryChannel::TargetPsi | TelemetryChannel::DutyPercent
+                | TelemetryChannel::BoostErrorPsi => 10.0,
+            TelemetryChannel::Aggression => 100.0,
+            TelemetryChannel::Rpm | TelemetryChannel::TorqueGapNm | TelemetryChannel::State => 1.0,
+        }
+    }
+}
+
+/// Channels a sink receives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSet(u8);
+
+impl ChannelSet {
+    /// Every channel
+    pub const ALL: ChannelSet = ChannelSet(u8::MAX);
+    
+    /// Only the listed channels
+    pub fn only(channels: &[TelemetryChannel]) -> Self {
+        Self(channels.iter().fold(0, |mask, channel| mask | channel.bit()))
+    }
+    
+    /// Whether the channel is included
+    pub fn contains(&self, channel: TelemetryChannel) -> bool {
+        self.0 & channel.bit() != 0
+    }
+    
+    /// Included channels in column order
+    pub fn channels(&self) -> impl Iterator<Item = TelemetryChannel> + '_ {
+        TelemetryChannel::ALL.into_iter().filter(|channel| self.contains(*channel))
+    }
+}
+
+/// One sample as a sink sees it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryFrame<'a> {
+    pub sample: &'a TelemetrySample,
+    pub channels: ChannelSet,
+}
+
+impl TelemetryFrame<'_> {
+    /// Values of the sink's channels in column order
+    pub fn values(&self) -> impl Iterator<Item = (TelemetryChannel, f32)> + '_ {
+        self.channels.channels().map(|channel| (channel, channel.value(self.sample)))
+    }
+}
+
+/// Consumer of telemetry samples (protocol stream, datalogger, CAN broadcast)
+/// 
+/// Sinks run at the end of the control cycle, so writing must be quick -
+/// buffer anything slow for the main loop. `bus` is the controller's CAN
+/// interface for sinks that broadcast.
+pub trait TelemetrySink {
+    /// Take one frame
+    fn write(&mut self, frame: &TelemetryFrame, bus: &mut dyn CanInterface) -> HalResult<()>;
+}
+
+/// Per-sink delivery state for status reports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelemetrySinkStatus {
+    pub name: String,
+    /// Interval before the performance tier stretches it (ms, 0 = every cycle)
+    pub interval_ms: u32,
+    pub channels: Vec<TelemetryChannel>,
+    /// Frames written
+    pub frames: u32,
+    /// Frames the sink failed to write
+    pub errors: u32,
+}
+
+struct Registration {
+    name: &'static str,
+    interval_ms: u32,
+    channels: ChannelSet,
+    last_ms: Option<u32>,
+    frames: u32,
+    errors: u32,
+    sink: Box<dyn TelemetrySink + Send>,
+}
+
+/// Fixed-capacity set of telemetry sinks fed from one sample per cycle
+#[derive(Default)]
+pub struct TelemetryRegistry {
+    sinks: heapless::Vec<Registration, MAX_TELEMETRY_SINKS>,
+}
+
+impl TelemetryRegistry {
+    /// Create registry with no sinks
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Register a sink under a name (replacing any sink already registered under it)
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        interval_ms: u32,
+        channels: ChannelSet,
+        sink: Box<dyn TelemetrySink + Send>,
+    ) -> Result<(), CoreError> {
+        self.remove(name);
+        self.sinks
+            .push(Registration { name, interval_ms, channels, last_ms: None, frames: 0, errors: 0, sink })
+            .map_err(|_| CoreError::ConfigurationError(format!("At most {} telemetry sinks", MAX_TELEMETRY_SINKS)))
+    }
+    
+    /// Remove a sink; returns whether one was registered
+    pub fn remove(&mut self, name: &str) -> bool {
+        match self.sinks.iter().position(|registration| registration.name == name) {
+            Some(index) => {
+                self.sinks.remove(index);
+                true
+            },
+            None => false,
+        }
+    }
+    
+    /// Whether a sink is registered under a name
+    pub fn contains(&self, name: &str) -> bool {
+        self.sinks.iter().any(|registration| registration.name == name)
+    }
+    
+    /// Offer a sample to every sink whose interval has passed; returns frames written
+    /// 
+    /// `load_multiplier` stretches every interval (from the performance tier).
+    /// A failed write is counted and the sink waits a full interval before the
+    /// next attempt, so a missing SD card or bus-off CAN does not cost time
+    /// every cycle.
+    pub fn publish(&mut self, sample: &TelemetrySample, load_multiplier: u32, bus: &mut dyn CanInterface) -> usize {
+        let now_ms = sample.timestamp_ms;
+        let mut written = 0;
+        for registration in self.sinks.iter_mut() {
+            let interval_ms = registration.interval_ms.saturating_mul(load_multiplier.max(1));
+            let due = registration.last_ms.is_none_or(|last_ms| now_ms.wrapping_sub(last_ms) >= interval_ms);
+            if !due {
+                continue;
+            }
+            registration.last_ms = Some(now_ms);
+            
+            let frame = TelemetryFrame { sample, channels: registration.channels };
+            match registration.sink.write(&frame, bus) {
+                Ok(()) => {
+                    registration.frames += 1;
+                    written += 1;
+                },
+                Err(_) => registration.errors += 1,
+            }
+        }
+        written
+    }
+    
+    /// Register, replace or remove the CAN broadcast sink to match its settings
+    pub fn configure_can_broadcast(&mut self, config: &CanBroadcastConfig) {
+        if config.enabled {
+            // Only fails with four other sinks registered - the broadcast then stays off
+            let sink = Box::new(CanBroadcastSink::new(config.base_id));
+            let _ = self.register(CAN_BROADCAST_SINK, config.interval_ms, ChannelSet::only(&config.channels), sink);
+        } else {
+            self.remove(CAN_BROADCAST_SINK);
+        }
+    }
+    
+    /// Delivery state of each sink in registration order
+    pub fn status(&self) -> Vec<TelemetrySinkStatus> {
+        self.sinks.iter().map(|registration| TelemetrySinkStatus {
+            name: String::from(registration.name),
+            interval_ms: registration.interval_ms,
+            channels: registration.channels.channels().collect(),
+            frames: registration.frames,
+            errors: registration.errors,
+        }).collect()
+    }
+}
+
+/// Name the configured CAN broadcast is registered under
+pub const CAN_BROADCAST_SINK: &str = "can_broadcast";
+
+/// Sink broadcasting channels as CAN frames for dash displays and loggers
+/// 
+/// Channels go out in column order, four to a frame as little-endian i16 in
+/// `TelemetryChannel::can_scale` units, on consecutive identifiers from
+/// `base_id`. Values beyond the i16 range are clamped.
+pub struct CanBroadcastSink {
+    base_id: u32,
+}
+
+impl CanBroadcastSink {
+    pub fn new(base_id: u32) -> Self {
+        Self { base_id }
+    }
+}
+
+impl TelemetrySink for CanBroadcastSink {
+    fn write(&mut self, frame: &TelemetryFrame, bus: &mut dyn CanInterface) -> HalResult<()> {
+        let values: Vec<(TelemetryChannel, f32)> = frame.values().collect();
+        for (index, group) in values.chunks(CHANNELS_PER_CAN_FRAME).enumerate() {
+            let mut payload = [0u8; CAN_MAX_PAYLOAD];
+            for (slot, (channel, value)) in group.iter().enumerate() {
+                let scaled = libm::roundf(value * channel.can_scale()).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+                payload[slot * 2..slot * 2 + 2].copy_from_slice(&scaled.to_le_bytes());
+            }
+            bus.send_frame(&CanFrame::new(self.base_id + index as u32, &payload[..group.len() * 2])?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Destination for text log lines (SD card file, serial console)
+pub trait LineWriter {
+    /// Append one line (without its terminator)
+    fn write_line(&mut self, line: &str) -> HalResult<()>;
+}
+
+/// Sink writing CSV rows: a header, then time and channels per frame
+pub struct CsvLogSink<W: LineWriter> {
+    writer: W,
+    header_written: bool,
+}
+
+impl<W: LineWriter> CsvLogSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, header_written: false }
+    }
+    
+    /// Destination being written
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W: LineWriter> TelemetrySink for CsvLogSink<W> {
+    fn write(&mut self, frame: &TelemetryFrame, _bus: &mut dyn CanInterface) -> HalResult<()> {
+        if !self.header_written {
+            self.writer.write_line(&csv_header(frame.channels))?;
+            self.header_written = true;
+        }
+        self.writer.write_line(&csv_row(frame))
+    }
+}
+
+/// CSV header line: time, then the channel names
+pub fn csv_header(channels: ChannelSet) -> String {
+    let mut header = String::from("timestamp_ms");
+    for channel in channels.channels() {
+        header.push(',');
+        header.push_str(channel.name());
+    }
+    header
+}
+
+/// CSV row for one frame, matching `csv_header`
+pub fn csv_row(frame: &TelemetryFrame) -> String {
+    let mut row = format!("{}", frame.sample.timestamp_ms);
+    for (channel, value) in frame.values() {
+        let _ = match channel {
+            TelemetryChannel::Rpm | TelemetryChannel::State => write!(row, ",{}", value as i32),
+            TelemetryChannel::Aggression => write!(row, ",{:.2}", value),
+            _ => write!(row, ",{:.1}", value),
+        };
+    }
+    row
+}
+
+/// CAN telemetry broadcast settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CanBroadcastConfig {
+    /// Broadcast telemetry on the vehicle CAN bus
+    pub enabled: bool,
+    /// Identifier of the first frame; later frames follow consecutively
+    pub base_id: u32,
+    /// Interval between broadcasts (ms)
+    pub interval_ms: u32,
+    /// Channels broadcast, in frame order
+    pub channels: Vec<TelemetryChannel>,
+}
+
+impl Default for CanBroadcastConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_id: 0x6F0,
+            interval_ms: 100,
+            channels: Vec::from([
+                TelemetryChannel::BoostPsi,
+                TelemetryChannel::TargetPsi,
+                TelemetryChannel::DutyPercent,
+                TelemetryChannel::State,
+            ]),
+        }
+    }
+}
+
+/// Per-sink telemetry output settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TelemetrySinkConfig {
+    #[serde(default)]
+    pub can_broadcast: CanBroadcastConfig,
+    #[serde(default)]
+    pub datalog: DatalogSinkConfig,
+}
+
+impl TelemetrySinkConfig {
+    /// Validate identifiers, intervals and channel lists
+    pub fn validate(&self) -> Result<(), CoreError> {
+        let can = &self.can_broadcast;
+        if can.enabled {
+            let frames = can.channels.len().div_ceil(CHANNELS_PER_CAN_FRAME) as u32;
+            if can.channels.is_empty() {
+                return Err(CoreError::ConfigurationError(String::from("CAN broadcast needs at least one channel")));
+            }
+            if can.base_id + frames - 1 > 0x7FF {
+                return Err(CoreError::ConfigurationError(
+                    format!("CAN broadcast frames from 0x{:X} run past the 11-bit identifier range", can.base_id)
+                ));
+            }
+            if !(10..=5_000).contains(&can.interval_ms) {
+                return Err(CoreError::ConfigurationError(
+                    format!("CAN broadcast interval must be 10-5000 ms, got {}", can.interval_ms)
+                ));
+            }
+        }
+        self.datalog.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_hal::MockHal;
+    use crate::{AggressionSource, SystemState};
+    
+    fn sample(timestamp_ms: u32) -> TelemetrySample {
+        TelemetrySample {
+            timestamp_ms,
+            state: SystemState::Armed,
+            rpm: 4200,
+            boost_psi: 8.46,
+            target_psi: 9.0,
+            duty_percent: 42.5,
+            torque_gap_nm: 35.0,
+            aggression: 0.7,
+            aggression_source: AggressionSource::Knob,
+        }
+    }
+    
+    #[derive(Default)]
+    struct Lines(Vec<String>);
+    
+    impl LineWriter for Lines {
+        fn write_line(&mut self, line: &str) -> HalResult<()> {
+            self.0.push(String::from(line));
+            Ok(())
+        }
+    }
+    
+    #[test]
+    fn test_sinks_run_at_their_own_rate() {
+        let mut registry = TelemetryRegistry::new();
+        let channels = ChannelSet::only(&[TelemetryChannel::BoostPsi]);
+        registry.register(CAN_BROADCAST_SINK, 50, channels, Box::new(CanBroadcastSink::new(0x6F0))).unwrap();
+        registry.register("every_cycle", 0, ChannelSet::ALL, Box::new(CanBroadcastSink::new(0x700))).unwrap();
+        
+        let mut hal = MockHal::new();
+        for t in (0..200).step_by(10) {
+            registry.publish(&sample(t), 1, &mut hal);
+        }
+        let broadcasts = |hal: &MockHal| hal.sent_can_frames().iter().filter(|frame| frame.id == 0x6F0).count();
+        assert_eq!(broadcasts(&hal), 4);
+        assert_eq!(registry.status()[1].frames, 20);
+        
+        // A stretched interval halves the rate
+        for t in (200..400).step_by(10) {
+            registry.publish(&sample(t), 2, &mut hal);
+        }
+        assert_eq!(broadcasts(&hal), 6);
+        assert_eq!(registry.status()[0].frames, 6);
+    }
+    
+    #[test]
+    fn test_can_broadcast_packs_scaled_values() {
+        let mut hal = MockHal::new();
+        let mut sink = CanBroadcastSink::new(0x6F0);
+        let sample = sample(0);
+        sink.write(&TelemetryFrame { sample: &sample, channels: ChannelSet::ALL }, &mut hal).unwrap();
+        
+        let sent = hal.sent_can_frames();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].id, 0x6F0);
+        assert_eq!(sent[0].payload()[..4], [0x68, 0x10, 85, 0]); // 4200 RPM, 8.5 PSI
+        assert_eq!(sent[1].id, 0x6F1);
+        assert_eq!(sent[1].payload().len(), 8);
+        assert_eq!(sent[1].payload()[2..4], 70i16.to_le_bytes());
+        assert_eq!(sent[1].payload()[6..8], 5i16.to_le_bytes()); // 0.5 PSI under target
+    }
+    
+    #[test]
+    fn test_csv_rows_follow_channel_set() {
+        let mut hal = MockHal::new();
+        let mut sink = CsvLogSink::new(Lines::default());
+        let channels = ChannelSet::only(&[TelemetryChannel::BoostPsi, TelemetryChannel::Rpm]);
+        for t in [0, 50] {
+            let sample = sample(t);
+            sink.write(&TelemetryFrame { sample: &sample, channels }, &mut hal).unwrap();
+        }
+        assert_eq!(sink.writer().0, ["timestamp_ms,rpm,boost_psi", "0,4200,8.5", "50,4200,8.5"]);
+    }
+    
+    #[test]
+    fn test_registry_capacity_and_replacement() {
+        let mut registry = TelemetryRegistry::new();
+        for name in ["a", "b", "c", "d"] {
+            registry.register(name, 0, ChannelSet::ALL, Box::new(CanBroadcastSink::new(0x700))).unwrap();
+        }
+        assert!(registry.register("e", 0, ChannelSet::ALL, Box::new(CanBroadcastSink::new(0x700))).is_err());
+        // Registering an existing name replaces it
+        registry.register("b", 20, ChannelSet::ALL, Box::new(CanBroadcastSink::new(0x710))).unwrap();
+        assert_eq!(registry.status().len(), 4);
+        assert!(registry.remove("b"));
+        assert!(!registry.contains("b"));
+        
+        let mut config = TelemetrySinkConfig::default();
+        assert!(config.validate().is_ok());
+        config.can_broadcast.enabled = true;
+        config.can_broadcast.base_id = 0x7FF;
+        // The default four channels fit in the last identifier
+        assert!(config.validate().is_ok());
+        config.can_broadcast.channels = TelemetryChannel::ALL.to_vec();
+        assert!(config.validate().is_err());
+        config.can_broadcast.base_id = 0x7FE;
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+//! Shared Test Fixtures
+//! 
+//! 🔗 T4-CORE-140: Shared Core Test Fixtures
+//! Derived From: T4-CORE-008 (Main Control Loop Implementation)
+//! AI Traceability: One armed core and one input sample for every module's tests, so a new SystemInputs field is added once
+//! 
+//! Tests override only the fields they exercise:
+//! `SystemInputs { manifold_pressure: 8.0, ..inputs(timestamp_ms) }`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use rumbledome_hal::{HalError, HalResult, NonVolatileStorage, EEPROM_SIZE, ERASED_BYTE};
+use crate::SystemInputs;
+
+#[cfg(feature = "mock")]
+use crate::{RumbleDomeCore, SystemConfig};
+#[cfg(feature = "mock")]
+use rumbledome_hal::MockHal;
+
+/// Torque frames keep CAN health good, so the core stays armed
+#[cfg(feature = "mock")]
+pub(crate) const TORQUE_MESSAGE_ID: u32 = 0x167;
+
+/// Part-throttle pull at 4000 RPM with the ECU asking for more torque than it's making
+pub(crate) fn inputs(timestamp_ms: u32) -> SystemInputs {
+    SystemInputs {
+        rpm: 4000,
+        desired_torque: 400.0,
+        actual_torque: 320.0,
+        manifold_pressure: 4.0,
+        throttle_position: Some(90.0),
+        dome_input_pressure: 60.0,
+        upper_dome_pressure: 0.0,
+        lower_dome_pressure: 0.0,
+        intake_air_temp_c: None,
+        coolant_temp_c: None,
+        bank_dome_pressure: None,
+        exhaust_backpressure_psi: None,
+        aggression: 0.5,
+        scramble_active: false,
+        timestamp_ms,
+    }
+}
+
+/// Closed-throttle idle, engine in vacuum
+pub(crate) fn idle_inputs(timestamp_ms: u32) -> SystemInputs {
+    SystemInputs {
+        rpm: 800,
+        desired_torque: 40.0,
+        actual_torque: 40.0,
+        manifold_pressure: -10.0,
+        throttle_position: Some(0.0),
+        ..inputs(timestamp_ms)
+    }
+}
+
+/// Core past commissioning and armed on `config`
+#[cfg(feature = "mock")]
+pub(crate) fn armed_core(config: SystemConfig) -> RumbleDomeCore<MockHal> {
+    let mut core = RumbleDomeCore::new(MockHal::new(), config);
+    core.initialize().unwrap();
+    core.skip_commissioning().unwrap();
+    core.arm().unwrap();
+    core
+}
+
+/// EEPROM in RAM, optionally losing power part way through the next write
+pub(crate) struct RamStorage {
+    pub(crate) bytes: Vec<u8>,
+    /// Bytes of the next write that reach the cells before power is lost
+    pub(crate) tear_after: Option<usize>,
+}
+
+impl RamStorage {
+    /// Erased EEPROM
+    pub(crate) fn new() -> Self {
+        Self { bytes: vec![ERASED_BYTE; EEPROM_SIZE], tear_after: None }
+    }
+}
+
+impl NonVolatileStorage for RamStorage {
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize> {
+        buffer.copy_from_slice(&self.bytes[offset..offset + buffer.len()]);
+        Ok(buffer.len())
+    }
+    
+    fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()> {
+        let len = self.tear_after.take().unwrap_or(data.len()).min(data.len());
+        self.bytes[offset..offset + len].copy_from_slice(&data[..len]);
+        if len < data.len() {
+            return Err(HalError::HardwareFault("Power lost".into()));
+        }
+        Ok(())
+    }
+    
+    fn erase_all(&mut self) -> HalResult<()> {
+        self.bytes.fill(ERASED_BYTE);
+        Ok(())
+    }
+    
+    fn sync(&mut self) -> HalResult<()> {
+        Ok(())
+    }
+    
+    fn get_size(&self) -> usize {
+        EEPROM_SIZE
+    }
+}
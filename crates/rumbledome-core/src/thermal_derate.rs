@@ -0,0 +1,291 @@
+//! Thermal Boost Derate
+//! 
+//! 🔗 T4-CORE-096: Temperature-Based Boost Derate
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-076 (Soft-Limit Headroom)
+//! AI Traceability: Heat-soaked intake air and a hot engine knock sooner - pull the boost ceiling down before the ECU has to pull timing
+//! 
+//! Intake air and coolant temperature each map through a curve to a
+//! multiplier on the controllable boost range (spring pressure up to the
+//! profile maximum). Deeper derate only engages once the temperature has
+//! stayed high for a while, so a brief heat-soak spike after a stop does not
+//! pull boost; recovery waits until the temperature has dropped a hysteresis
+//! band below where the derate started.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, SystemConfig, SystemInputs};
+
+/// Maximum number of points in a derate curve
+pub const MAX_DERATE_POINTS: usize = 8;
+
+/// One point of a derate curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DeratePoint {
+    /// Temperature (°C)
+    pub temp_c: f32,
+    /// Multiplier on the controllable boost range at this temperature (0.0-1.0)
+    pub multiplier: f32,
+}
+
+/// Temperature derate rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ThermalDerateConfig {
+    /// Derate boost on high temperatures
+    pub enabled: bool,
+    /// Intake air temperature curve, ascending temperature
+    pub intake_air: Vec<DeratePoint>,
+    /// Coolant temperature curve, ascending temperature
+    pub coolant: Vec<DeratePoint>,
+    /// Temperature must fall this far before derate is released (°C)
+    pub hysteresis_c: f32,
+    /// Temperature must stay high this long before derate deepens (ms)
+    pub sustain_ms: u32,
+}
+
+impl Default for ThermalDerateConfig {
+    fn default() -> Self {
+        let point = |temp_c, multiplier| DeratePoint { temp_c, multiplier };
+        Self {
+            enabled: true,
+            intake_air: alloc::vec![point(50.0, 1.0), point(60.0, 0.8), point(70.0, 0.5)],
+            coolant: alloc::vec![point(105.0, 1.0), point(110.0, 0.7), point(115.0, 0.3)],
+            hysteresis_c: 3.0,
+            sustain_ms: 5_000,
+        }
+    }
+}
+
+impl ThermalDerateConfig {
+    /// Validate curve shapes and timing
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for (name, curve) in [("Intake air", &self.intake_air), ("Coolant", &self.coolant)] {
+            if curve.is_empty() || curve.len() > MAX_DERATE_POINTS {
+                return Err(CoreError::ConfigurationError(
+                    format!("{} derate curve needs 1-{} points, got {}", name, MAX_DERATE_POINTS, curve.len())
+                ));
+            }
+            if curve.windows(2).any(|pair| pair[1].temp_c <= pair[0].temp_c) {
+                return Err(CoreError::ConfigurationError(
+                    format!("{} derate points must be in strictly ascending temperature order", name)
+                ));
+            }
+            if let Some(point) = curve.iter().find(|p| !(0.0..=1.0).contains(&p.multiplier)) {
+                return Err(CoreError::ConfigurationError(
+                    format!("{} derate multiplier {} at {} °C outside 0.0-1.0", name, point.multiplier, point.temp_c)
+                ));
+            }
+        }
+        
+        if !(0.0..=20.0).contains(&self.hysteresis_c) {
+            return Err(CoreError::ConfigurationError(
+                format!("Derate hysteresis must be 0-20 °C, got {}", self.hysteresis_c)
+            ));
+        }
+        if self.sustain_ms > 60_000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Derate sustain time must be at most 60000 ms, got {}", self.sustain_ms)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Multiplier at a temperature, linearly interpolated and held flat past the ends
+fn curve_multiplier(curve: &[DeratePoint], temp_c: f32) -> f32 {
+    let (Some(first), Some(last)) = (curve.first(), curve.last()) else {
+        return 1.0;
+    };
+    if temp_c <= first.temp_c {
+        return first.multiplier;
+    }
+    if temp_c >= last.temp_c {
+        return last.multiplier;
+    }
+    
+    curve
+        .windows(2)
+        .find(|pair| temp_c < pair[1].temp_c)
+        .map(|pair| {
+            let t = (temp_c - pair[0].temp_c) / (pair[1].temp_c - pair[0].temp_c);
+            pair[0].multiplier + (pair[1].multiplier - pair[0].multiplier) * t
+        })
+        .unwrap_or(last.multiplier)
+}
+
+/// Derate state for one temperature input
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DerateChannel {
+    multiplier: f32,
+    deeper_since_ms: Option<u32>,
+}
+
+impl Default for DerateChannel {
+    fn default() -> Self {
+        Self { multiplier: 1.0, deeper_since_ms: None }
+    }
+}
+
+impl DerateChannel {
+    fn update(&mut self, curve: &[DeratePoint], temp_c: Option<f32>, config: &ThermalDerateConfig, now_ms: u32) {
+        // A missing reading holds the current derate rather than releasing it
+        let Some(temp_c) = temp_c else {
+            self.deeper_since_ms = None;
+            return;
+        };
+        
+        let target = curve_multiplier(curve, temp_c);
+        if target < self.multiplier {
+            let since = *self.deeper_since_ms.get_or_insert(now_ms);
+            if now_ms.wrapping_sub(since) >= config.sustain_ms {
+                self.multiplier = target;
+                self.deeper_since_ms = None;
+            }
+        } else {
+            self.deeper_since_ms = None;
+            let released = curve_multiplier(curve, temp_c + config.hysteresis_c);
+            self.multiplier = self.multiplier.max(released);
+        }
+    }
+}
+
+/// Thermal derate status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThermalDerateStatus {
+    /// Multiplier in effect (lower of the two inputs)
+    pub multiplier: f32,
+    /// Multiplier from intake air temperature
+    pub intake_air_multiplier: f32,
+    /// Multiplier from coolant temperature
+    pub coolant_multiplier: f32,
+}
+
+/// Tracks temperature derate across control cycles
+#[derive(Debug, Clone)]
+pub struct ThermalDerate {
+    config: ThermalDerateConfig,
+    intake_air: DerateChannel,
+    coolant: DerateChannel,
+}
+
+impl ThermalDerate {
+    /// Create with no derate in effect
+    pub fn new(config: ThermalDerateConfig) -> Self {
+        Self { config, intake_air: DerateChannel::default(), coolant: DerateChannel::default() }
+    }
+    
+    /// Apply changed rules; derate in effect is kept until the new rules release it
+    pub fn reconfigure(&mut self, config: ThermalDerateConfig) {
+        self.config = config;
+    }
+    
+    /// Update from one cycle's temperatures and return the multiplier in effect
+    pub fn update(&mut self, inputs: &SystemInputs) -> f32 {
+        if !self.config.enabled {
+            self.intake_air = DerateChannel::default();
+            self.coolant = DerateChannel::default();
+            return 1.0;
+        }
+        
+        let now_ms = inputs.timestamp_ms;
+        self.intake_air.update(&self.config.intake_air, inputs.intake_air_temp_c, &self.config, now_ms);
+        self.coolant.update(&self.config.coolant, inputs.coolant_temp_c, &self.config, now_ms);
+        self.multiplier()
+    }
+    
+    /// Multiplier in effect (1.0 = no derate)
+    pub fn multiplier(&self) -> f32 {
+        self.intake_air.multiplier.min(self.coolant.multiplier)
+    }
+    
+    /// Derated boost cap, or `None` while no derate is in effect (PSI)
+    /// 
+    /// Only the range above spring pressure is controllable, so that is what
+    /// the multiplier scales.
+    pub fn boost_cap_psi(&self, config: &SystemConfig) -> Option<f32> {
+        let multiplier = self.multiplier();
+        (multiplier < 1.0).then(|| {
+            let controllable = (config.max_boost_psi - config.spring_pressure).max(0.0);
+            config.spring_pressure + controllable * multiplier
+        })
+    }
+    
+    /// Current status
+    pub fn status(&self) -> ThermalDerateStatus {
+        ThermalDerateStatus {
+            multiplier: self.multiplier(),
+            intake_air_multiplier: self.intake_air.multiplier,
+            coolant_multiplier: self.coolant.multiplier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(intake_air_temp_c: Option<f32>, coolant_temp_c: Option<f32>, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { intake_air_temp_c, coolant_temp_c, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_derate_needs_sustained_heat_and_releases_with_hysteresis() {
+        let config = ThermalDerateConfig::default();
+        assert!(config.validate().is_ok());
+        let mut derate = ThermalDerate::new(config);
+        
+        // Brief heat-soak spike: no derate
+        assert_eq!(derate.update(&inputs(Some(65.0), Some(90.0), 0)), 1.0);
+        assert_eq!(derate.update(&inputs(Some(45.0), Some(90.0), 2_000)), 1.0);
+        
+        // Sustained: 65 °C sits halfway between 0.8 and 0.5
+        derate.update(&inputs(Some(65.0), Some(90.0), 10_000));
+        assert_eq!(derate.update(&inputs(Some(65.0), Some(90.0), 14_999)), 1.0);
+        assert!((derate.update(&inputs(Some(65.0), Some(90.0), 15_000)) - 0.65).abs() < 0.001);
+        
+        // Cooling a little is inside the hysteresis band; the derate holds
+        assert!((derate.update(&inputs(Some(63.0), Some(90.0), 16_000)) - 0.65).abs() < 0.001);
+        // Missing reading holds too
+        assert!((derate.update(&inputs(None, Some(90.0), 17_000)) - 0.65).abs() < 0.001);
+        // 58 °C releases as if it were 61 °C
+        assert!((derate.update(&inputs(Some(58.0), Some(90.0), 18_000)) - 0.77).abs() < 0.001);
+        assert_eq!(derate.update(&inputs(Some(40.0), Some(90.0), 19_000)), 1.0);
+    }
+    
+    #[test]
+    fn test_worst_input_sets_boost_cap() {
+        let system = SystemConfig::default();
+        let mut derate = ThermalDerate::new(ThermalDerateConfig { sustain_ms: 0, ..ThermalDerateConfig::default() });
+        assert_eq!(derate.boost_cap_psi(&system), None);
+        
+        derate.update(&inputs(Some(60.0), Some(115.0), 0));
+        let status = derate.status();
+        assert_eq!(status.intake_air_multiplier, 0.8);
+        assert_eq!(status.coolant_multiplier, 0.3);
+        assert_eq!(status.multiplier, 0.3);
+        // 5 PSI spring, 12 PSI max: 30% of the 7 PSI above spring
+        assert!((derate.boost_cap_psi(&system).unwrap() - 7.1).abs() < 0.001);
+        
+        let disabled = ThermalDerateConfig { enabled: false, ..ThermalDerateConfig::default() };
+        derate.reconfigure(disabled);
+        assert_eq!(derate.update(&inputs(Some(80.0), Some(120.0), 10)), 1.0);
+    }
+    
+    #[test]
+    fn test_curve_validation() {
+        let mut config = ThermalDerateConfig::default();
+        config.coolant.swap(0, 1);
+        assert!(config.validate().is_err());
+        
+        let mut config = ThermalDerateConfig::default();
+        config.intake_air[2].multiplier = 1.5;
+        assert!(config.validate().is_err());
+        
+        config.intake_air.clear();
+        assert!(config.validate().is_err());
+    }
+}
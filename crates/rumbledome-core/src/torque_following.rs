@@ -0,0 +1,320 @@
+//! Torque Following
+//! 
+//! 🔗 T4-CORE-109: Level 1 Torque Gap Analysis and Boost Assistance
+//! Derived From: T2-CONTROL-004 (Torque-Based Boost Target Adjustment) + T2-CONTROL-010 (Core Control Decision Tree) + T2-CONTROL-011 (Rate Limiting and Transition Management)
+//! AI Traceability: Boost is the tool, ECU torque achievement is the objective - assistance builds while the ECU falls short and backs off before it has to intervene
+//! 
+//! The controller does not chase a boost number. It holds a boost assistance
+//! level above spring pressure and moves it by the torque still missing,
+//! converted to boost through the engine's torque-per-PSI. The torque aimed
+//! for is a fraction of the ECU's request (the torque ceiling), so the ECU
+//! never sees more than it asked for and never has to pull timing or close
+//! the throttle to get rid of it.
+//! 
+//! - Shortfall beyond the gap threshold: assistance ramps up, twice as fast
+//!   for an urgent gap
+//! - Actual torque at or over the ceiling: assistance backs off
+//! - Otherwise: assistance holds (steady state)
+//! 
+//! Aggression scales how far above spring assistance may go (none at 0.0,
+//! up to the profile maximum at 1.0) and how quickly it moves.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, ResponseProfile, SystemConfig, SystemInputs, TuningParameters};
+
+/// Urgent gaps ramp assistance this many times faster
+pub const URGENT_RAMP_FACTOR: f32 = 2.0;
+
+/// Tip-out decay rate (0.2-0.7) × this = back-off rate as a multiple of the ramp rate
+/// Releasing boost is never slower than building it.
+pub const TIP_OUT_RATE_SCALE: f32 = 5.0;
+
+/// Longest gap between updates before held assistance is dropped (ms)
+pub const STALE_UPDATE_MS: u32 = 1_000;
+
+/// Torque-to-boost conversion and urgency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TorqueFollowingConfig {
+    /// Engine torque gained per PSI of boost (Nm/PSI)
+    pub torque_per_psi_nm: f32,
+    /// Torque gap treated as urgent (Nm)
+    pub urgent_gap_nm: f32,
+}
+
+impl Default for TorqueFollowingConfig {
+    fn default() -> Self {
+        Self {
+            torque_per_psi_nm: 25.0,
+            urgent_gap_nm: 100.0,
+        }
+    }
+}
+
+impl TorqueFollowingConfig {
+    /// Validate conversion and urgency settings
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(5.0..=100.0).contains(&self.torque_per_psi_nm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Torque per PSI must be 5-100 Nm, got {}", self.torque_per_psi_nm)
+            ));
+        }
+        if !(20.0..=500.0).contains(&self.urgent_gap_nm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Urgent torque gap must be 20-500 Nm, got {}", self.urgent_gap_nm)
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Level 1 boost assistance state
+#[derive(Debug, Clone, PartialEq)]
+pub struct TorqueFollowing {
+    config: TorqueFollowingConfig,
+    spring_pressure: f32,
+    max_boost_psi: f32,
+    scramble_enabled: bool,
+    gap_threshold_nm: f32,
+    ceiling_fraction: f32,
+    assistance_psi: f32,
+    last_update_ms: Option<u32>,
+}
+
+impl TorqueFollowing {
+    /// Torque following for a configuration
+    pub fn new(config: &SystemConfig) -> Self {
+        Self {
+            config: config.torque_following.clone(),
+            spring_pressure: config.spring_pressure,
+            max_boost_psi: config.max_boost_psi,
+            scramble_enabled: config.scramble_enabled,
+            gap_threshold_nm: config.tuning.torque_gap_threshold_nm,
+            ceiling_fraction: config.tuning.torque_ceiling_fraction,
+            assistance_psi: 0.0,
+            last_update_ms: None,
+        }
+    }
+    
+    /// Take on a new configuration (held assistance is dropped)
+    pub fn reconfigure(&mut self, config: &SystemConfig) {
+        *self = Self::new(config);
+    }
+    
+    /// Follow the gap threshold and torque ceiling being live-tuned
+    pub fn set_tuning(&mut self, tuning: &TuningParameters) {
+        self.gap_threshold_nm = tuning.torque_gap_threshold_nm;
+        self.ceiling_fraction = tuning.torque_ceiling_fraction;
+    }
+    
+    /// Boost currently held above spring pressure (PSI)
+    pub fn assistance_psi(&self) -> f32 {
+        self.assistance_psi
+    }
+    
+    /// Whether the ECU needs help reaching its torque request
+    /// 
+    /// `torque_gap` is desired minus actual torque, already scaled back for CAN data quality (Nm).
+    pub fn analyze_assistance_need(&mut self, torque_gap: f32, inputs: &SystemInputs) -> Result<bool, CoreError> {
+        if self.aggression(inputs) <= 0.0 {
+            return Ok(false);
+        }
+        
+        // Approaching the ECU torque ceiling - back off to prevent ECU intervention
+        if inputs.actual_torque >= self.torque_ceiling(inputs) {
+            return Ok(false);
+        }
+        
+        Ok(torque_gap > self.gap_threshold_nm)
+    }
+    
+    /// Boost target with assistance raised toward the torque still missing (PSI)
+    pub fn calculate_boost_assistance(&mut self, torque_gap: f32, inputs: &SystemInputs) -> Result<f32, CoreError> {
+        let elapsed_s = self.elapsed_s(inputs.timestamp_ms);
+        let aggression = self.aggression(inputs);
+        let profile = ResponseProfile::for_aggression(aggression);
+        
+        let shortfall_nm = (self.torque_ceiling(inputs) - inputs.actual_torque).max(0.0);
+        let wanted_psi = self.assistance_psi + shortfall_nm / self.config.torque_per_psi_nm;
+        let urgency = if torque_gap >= self.config.urgent_gap_nm { URGENT_RAMP_FACTOR } else { 1.0 };
+        let max_step_psi = profile.boost_ramp_rate * urgency * elapsed_s;
+        
+        self.assistance_psi = (self.assistance_psi + max_step_psi).min(wanted_psi);
+        Ok(self.target_psi(aggression))
+    }
+    
+    /// Boost target with no assistance needed - held, or backed off when torque is over the ceiling (PSI)
+    pub fn get_baseline_boost(&mut self, inputs: &SystemInputs) -> Result<f32, CoreError> {
+        let elapsed_s = self.elapsed_s(inputs.timestamp_ms);
+        let aggression = self.aggression(inputs);
+        let profile = ResponseProfile::for_aggression(aggression);
+        
+        let excess_nm = inputs.actual_torque - self.torque_ceiling(inputs);
+        if excess_nm >= 0.0 {
+            let wanted_psi = (self.assistance_psi - excess_nm / self.config.torque_per_psi_nm).max(0.0);
+            let max_step_psi = profile.boost_ramp_rate * profile.tip_out_decay_rate * TIP_OUT_RATE_SCALE * elapsed_s;
+            self.assistance_psi = (self.assistance_psi - max_step_psi).max(wanted_psi);
+        }
+        
+        Ok(self.target_psi(aggression))
+    }
+    
+    /// Live aggression, with scramble at full
+    fn aggression(&self, inputs: &SystemInputs) -> f32 {
+        if inputs.scramble_active && self.scramble_enabled {
+            1.0
+        } else {
+            inputs.aggression.clamp(0.0, 1.0)
+        }
+    }
+    
+    /// Torque aimed for (Nm)
+    fn torque_ceiling(&self, inputs: &SystemInputs) -> f32 {
+        inputs.desired_torque * self.ceiling_fraction
+    }
+    
+    /// Seconds since the previous update; a stale update restarts from no assistance
+    fn elapsed_s(&mut self, now_ms: u32) -> f32 {
+        let elapsed_ms = match self.last_update_ms {
+            Some(last_ms) => now_ms.wrapping_sub(last_ms),
+            None => 0,
+        };
+        self.last_update_ms = Some(now_ms);
+        
+        if elapsed_ms > STALE_UPDATE_MS {
+            self.assistance_psi = 0.0;
+            return 0.0;
+        }
+        elapsed_ms as f32 / 1000.0
+    }
+    
+    /// Spring pressure plus assistance, limited to the aggression-scaled range
+    fn target_psi(&mut self, aggression: f32) -> f32 {
+        let ceiling_psi = (self.max_boost_psi - self.spring_pressure).max(0.0) * aggression;
+        self.assistance_psi = self.assistance_psi.clamp(0.0, ceiling_psi);
+        self.spring_pressure + self.assistance_psi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(desired_torque: f32, actual_torque: f32, timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { desired_torque, actual_torque, aggression: 1.0, ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_assistance_needed_only_below_torque_ceiling() {
+        let mut torque = TorqueFollowing::new(&SystemConfig::default());
+        
+        assert!(torque.analyze_assistance_need(80.0, &inputs(500.0, 420.0, 0)).unwrap());
+        // Gap within the threshold
+        assert!(!torque.analyze_assistance_need(8.0, &inputs(500.0, 492.0, 0)).unwrap());
+        // Gap over the threshold but already past 95% of the request
+        assert!(!torque.analyze_assistance_need(15.0, &inputs(500.0, 485.0, 0)).unwrap());
+        // OFF gives the ECU no help at all
+        let mut off = inputs(500.0, 420.0, 0);
+        off.aggression = 0.0;
+        assert!(!torque.analyze_assistance_need(80.0, &off).unwrap());
+    }
+    
+    #[test]
+    fn test_assistance_ramps_with_aggression_and_urgency() {
+        let config = SystemConfig::default();
+        let spring = config.spring_pressure;
+        let mut torque = TorqueFollowing::new(&config);
+        
+        // First update only starts the clock
+        assert_eq!(torque.calculate_boost_assistance(80.0, &inputs(500.0, 420.0, 0)).unwrap(), spring);
+        // Full aggression ramps at 4 PSI/s
+        let target = torque.calculate_boost_assistance(80.0, &inputs(500.0, 420.0, 100)).unwrap();
+        assert!((target - (spring + 0.4)).abs() < 1e-4);
+        // Urgent gap ramps twice as fast
+        let target = torque.calculate_boost_assistance(150.0, &inputs(500.0, 350.0, 200)).unwrap();
+        assert!((target - (spring + 1.2)).abs() < 1e-4);
+        
+        // Never more than the torque still missing, converted to boost
+        let mut torque = TorqueFollowing::new(&config);
+        torque.calculate_boost_assistance(20.0, &inputs(500.0, 455.0, 0)).unwrap();
+        let target = torque.calculate_boost_assistance(20.0, &inputs(500.0, 455.0, 900)).unwrap();
+        assert!((target - (spring + 20.0 / 25.0)).abs() < 1e-4);
+        
+        // Half aggression caps assistance at half the range above spring
+        let mut half = inputs(500.0, 200.0, 0);
+        half.aggression = 0.5;
+        for step in 0..50 {
+            half.timestamp_ms = step * 100;
+            torque.calculate_boost_assistance(300.0, &half).unwrap();
+        }
+        assert!((torque.assistance_psi() - (config.max_boost_psi - spring) * 0.5).abs() < 1e-4);
+    }
+    
+    #[test]
+    fn test_baseline_holds_then_backs_off_over_ceiling() {
+        let config = SystemConfig::default();
+        let spring = config.spring_pressure;
+        let mut torque = TorqueFollowing::new(&config);
+        for step in 0..10 {
+            torque.calculate_boost_assistance(200.0, &inputs(600.0, 300.0, step * 100)).unwrap();
+        }
+        let held = torque.assistance_psi();
+        assert!(held > 3.0);
+        
+        // ECU just under its ceiling - steady state holds
+        assert_eq!(torque.get_baseline_boost(&inputs(500.0, 470.0, 1000)).unwrap(), spring + held);
+        
+        // Driver lifts - torque over the ceiling, assistance released
+        torque.get_baseline_boost(&inputs(100.0, 300.0, 1100)).unwrap();
+        assert!(torque.assistance_psi() < held);
+        for step in 12..30 {
+            torque.get_baseline_boost(&inputs(100.0, 300.0, step * 100)).unwrap();
+        }
+        assert_eq!(torque.assistance_psi(), 0.0);
+        
+        // Updates stopping (disarmed) drop held assistance
+        for step in 0..10 {
+            torque.calculate_boost_assistance(200.0, &inputs(600.0, 300.0, 5000 + step * 100)).unwrap();
+        }
+        assert!(torque.assistance_psi() > 0.0);
+        assert_eq!(torque.get_baseline_boost(&inputs(500.0, 470.0, 20_000)).unwrap(), spring);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::test_support::{self, armed_core, TORQUE_MESSAGE_ID};
+    use crate::{SystemConfig, SystemInputs};
+    
+    #[test]
+    fn test_level1_target_builds_while_ecu_falls_short() {
+        let config = SystemConfig::default();
+        let spring = config.spring_pressure;
+        let max_boost = config.max_boost_psi;
+        let mut core = armed_core(config);
+        
+        let mut inputs = SystemInputs {
+            rpm: 4500,
+            desired_torque: 600.0,
+            actual_torque: 450.0,
+            manifold_pressure: 5.0,
+            throttle_position: Some(100.0),
+            aggression: 1.0,
+            ..test_support::inputs(0)
+        };
+        let mut targets = alloc::vec::Vec::new();
+        for cycle in 0..100 {
+            inputs.timestamp_ms = cycle * 10;
+            core.can_health.record_message(TORQUE_MESSAGE_ID, inputs.timestamp_ms);
+            core.execute_control_cycle_with_inputs(inputs.clone()).unwrap();
+            targets.push(core.level1_target_boost_psi);
+        }
+        
+        assert!(targets.windows(2).all(|pair| pair[1] >= pair[0]));
+        assert!(core.level1_target_boost_psi > spring + 2.0);
+        assert!(core.level1_target_boost_psi <= max_boost);
+    }
+}
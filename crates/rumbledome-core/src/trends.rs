@@ -0,0 +1,442 @@
+//! Long-Term Trends
+//! 
+//! 🔗 T4-CORE-116: Weekly and Monthly Trend Records
+//! Derived From: T4-CORE-055 (Drive Session Summary) + T4-CORE-082 (Boost Tracking Statistics)
+//! AI Traceability: Slow degradation (a tiring solenoid, a leaking dome line) hides inside per-drive views - compare weeks and months instead
+//! 
+//! Each session summary is folded into the record for the calendar week
+//! (Monday first) and calendar month it happened in. Records hold only sums
+//! and counts, so any number of sessions fits the same few bytes, and rates
+//! are derived when read.
+//! 
+//! The device has no real-time clock. The client supplies today's date when
+//! it reads the trends (days since 1970-01-01), and activity is filed under
+//! the last date supplied - driving between two reads lands in the period the
+//! earlier read fell in. Activity before any date was ever supplied is held
+//! back and filed with the first date that arrives.
+//! 
+//! The records are too large for the EEPROM, so they are kept on the SD card
+//! (`FileJournal::TRENDS`), restored at power-up and saved each time the
+//! engine stops - the end of a drive, just ahead of key-off. Without a card
+//! the history starts over at every power-up.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use crate::SessionSummary;
+
+/// Weekly records kept, including the current week
+pub const TREND_WEEKS: usize = 12;
+/// Monthly records kept, including the current month
+pub const TREND_MONTHS: usize = 12;
+
+/// Sums for one week or month
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrendRecord {
+    /// First day of the period (days since 1970-01-01)
+    pub start_day: u32,
+    /// Sessions started in the period
+    pub sessions: u16,
+    /// Powered-on time (s)
+    pub powered_s: u32,
+    /// Time armed (s)
+    pub armed_s: u32,
+    /// Settled tracking samples
+    pub tracking_samples: u32,
+    /// Sum of squared settled tracking error (PSI²)
+    pub tracking_error_sq_sum: f32,
+    /// Overboost cut activations
+    pub overboost_cuts: u32,
+    /// Learning updates applied
+    pub learning_updates: u32,
+    /// Records written to EEPROM
+    pub storage_writes: u32,
+}
+
+impl TrendRecord {
+    fn new(start_day: u32) -> Self {
+        Self { start_day, ..Self::default() }
+    }
+    
+    /// Settled RMS tracking error (PSI), None without settled tracking
+    pub fn rms_tracking_error_psi(&self) -> Option<f32> {
+        (self.tracking_samples > 0).then(|| libm::sqrtf(self.tracking_error_sq_sum / self.tracking_samples as f32))
+    }
+    
+    /// Overboost cuts per armed hour, None before an armed minute
+    pub fn overboost_per_hour(&self) -> Option<f32> {
+        self.per_armed_hour(self.overboost_cuts)
+    }
+    
+    /// Learning updates per armed hour, None before an armed minute
+    pub fn learning_per_hour(&self) -> Option<f32> {
+        self.per_armed_hour(self.learning_updates)
+    }
+    
+    fn per_armed_hour(&self, count: u32) -> Option<f32> {
+        (self.armed_s >= 60).then(|| count as f32 * 3600.0 / self.armed_s as f32)
+    }
+    
+    fn add(&mut self, delta: &SessionTotals) {
+        self.powered_s = self.powered_s.saturating_add(delta.duration_ms / 1000);
+        self.armed_s = self.armed_s.saturating_add(delta.armed_ms / 1000);
+        self.tracking_samples = self.tracking_samples.saturating_add(delta.tracking_samples);
+        self.tracking_error_sq_sum += delta.tracking_error_sq_sum;
+        self.overboost_cuts = self.overboost_cuts.saturating_add(delta.overboost_cuts);
+        self.learning_updates = self.learning_updates.saturating_add(delta.learning_updates);
+        self.storage_writes = self.storage_writes.saturating_add(delta.storage_writes);
+    }
+}
+
+/// Weekly and monthly records, oldest first, current period last
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrendReport {
+    pub weeks: Vec<TrendRecord>,
+    pub months: Vec<TrendRecord>,
+}
+
+/// Parts of a session summary that trends accumulate
+/// 
+/// Time is kept in ms here and only whole seconds are moved into records, so
+/// per-cycle folding loses nothing to rounding.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct SessionTotals {
+    duration_ms: u32,
+    armed_ms: u32,
+    tracking_samples: u32,
+    tracking_error_sq_sum: f32,
+    overboost_cuts: u32,
+    learning_updates: u32,
+    storage_writes: u32,
+}
+
+impl SessionTotals {
+    fn of(session: &SessionSummary) -> Self {
+        Self {
+            duration_ms: session.duration_ms,
+            armed_ms: session.armed_ms,
+            tracking_samples: session.tracking.bands.iter().map(|band| band.steady_samples).sum(),
+            tracking_error_sq_sum: session.tracking.bands.iter().map(|band| band.steady_error_sq_sum).sum(),
+            overboost_cuts: session.overboost_cuts,
+            learning_updates: session.learning_updates,
+            storage_writes: session.storage_writes,
+        }
+    }
+    
+    /// What `self` has gained since `earlier`, leaving sub-second time behind
+    fn since(&self, earlier: &Self) -> Self {
+        let whole_s = |now: u32, then: u32| (now.saturating_sub(then) / 1000) * 1000;
+        Self {
+            duration_ms: whole_s(self.duration_ms, earlier.duration_ms),
+            armed_ms: whole_s(self.armed_ms, earlier.armed_ms),
+            tracking_samples: self.tracking_samples.saturating_sub(earlier.tracking_samples),
+            tracking_error_sq_sum: (self.tracking_error_sq_sum - earlier.tracking_error_sq_sum).max(0.0),
+            overboost_cuts: self.overboost_cuts.saturating_sub(earlier.overboost_cuts),
+            learning_updates: self.learning_updates.saturating_sub(earlier.learning_updates),
+            storage_writes: self.storage_writes.saturating_sub(earlier.storage_writes),
+        }
+    }
+    
+    fn advance(&mut self, delta: &Self) {
+        self.duration_ms += delta.duration_ms;
+        self.armed_ms += delta.armed_ms;
+        self.tracking_samples += delta.tracking_samples;
+        self.tracking_error_sq_sum += delta.tracking_error_sq_sum;
+        self.overboost_cuts += delta.overboost_cuts;
+        self.learning_updates += delta.learning_updates;
+        self.storage_writes += delta.storage_writes;
+    }
+    
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Folds the current session into weekly and monthly records
+#[derive(Debug, Clone, Default)]
+pub struct TrendLog {
+    weeks: Vec<TrendRecord>,
+    months: Vec<TrendRecord>,
+    /// Last date supplied by a client
+    day: Option<u32>,
+    /// Part of the current session already in the records
+    folded: SessionTotals,
+    /// Activity seen before any date was supplied
+    undated: SessionTotals,
+    /// Current session counted in a record's `sessions`
+    session_counted: bool,
+    /// Records changed since they were last saved
+    unsaved: bool,
+    /// Engine speed seen since the last stop
+    engine_running: bool,
+}
+
+impl TrendLog {
+    /// Start with no history
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Resume with persisted records (oldest first)
+    pub fn restore(&mut self, report: TrendReport) {
+        self.weeks = report.weeks;
+        self.months = report.months;
+        trim(&mut self.weeks, TREND_WEEKS);
+        trim(&mut self.months, TREND_MONTHS);
+        self.day = self.months.last().map(|month| month.start_day)
+            .max(self.weeks.last().map(|week| week.start_day));
+    }
+    
+    /// Records to persist or display
+    pub fn report(&self) -> TrendReport {
+        TrendReport { weeks: self.weeks.clone(), months: self.months.clone() }
+    }
+    
+    /// Today's date from a client (days since 1970-01-01)
+    /// 
+    /// A date earlier than one already supplied (a wrong phone clock) keeps
+    /// filing under the later period rather than reordering history.
+    pub fn set_day(&mut self, day: u32) {
+        if matches!(self.day, Some(known) if day < known) {
+            return;
+        }
+        self.day = Some(day);
+        let undated = core::mem::take(&mut self.undated);
+        if !undated.is_empty() || !self.session_counted {
+            self.file(&undated);
+        }
+    }
+    
+    /// Fold what the current session gained since the last call
+    pub fn follow(&mut self, session: &SessionSummary) {
+        let totals = SessionTotals::of(session);
+        let delta = totals.since(&self.folded);
+        if delta.is_empty() && self.session_counted {
+            return;
+        }
+        self.folded.advance(&delta);
+        
+        if self.day.is_some() {
+            self.file(&delta);
+        } else {
+            self.undated.advance(&delta);
+        }
+    }
+    
+    /// Whether the records should be saved now: once each time the engine stops with changes
+    pub fn save_due(&mut self, rpm: u16) -> bool {
+        let stopped = self.engine_running && rpm == 0;
+        self.engine_running = rpm > 0;
+        let due = stopped && self.unsaved;
+        if due {
+            self.unsaved = false;
+        }
+        due
+    }
+    
+    fn file(&mut self, delta: &SessionTotals) {
+        let Some(day) = self.day else { return };
+        let new_session = !self.session_counted;
+        self.session_counted = true;
+        self.unsaved = true;
+        
+        for (records, start_day, limit) in [
+            (&mut self.weeks, week_start(day), TREND_WEEKS),
+            (&mut self.months, month_start(day), TREND_MONTHS),
+        ] {
+            if records.last().is_none_or(|record| record.start_day < start_day) {
+                records.push(TrendRecord::new(start_day));
+                trim(records, limit);
+            }
+            if let Some(record) = records.last_mut() {
+                record.add(delta);
+                if new_session {
+                    record.sessions = record.sessions.saturating_add(1);
+                }
+            }
+        }
+    }
+}
+
+fn trim(records: &mut Vec<TrendRecord>, limit: usize) {
+    let excess = records.len().saturating_sub(limit);
+    records.drain(..excess);
+}
+
+/// Monday on or before `day` (1970-01-01 was a Thursday)
+pub fn week_start(day: u32) -> u32 {
+    day - (day + 3) % 7
+}
+
+/// First of the calendar month containing `day`
+pub fn month_start(day: u32) -> u32 {
+    let (_, _, day_of_month) = civil_date(day);
+    day - (day_of_month - 1)
+}
+
+/// Year, month (1-12) and day of month (1-31) of a day since 1970-01-01
+/// 
+/// Proleptic Gregorian calendar, after Howard Hinnant's `civil_from_days`.
+pub fn civil_date(day: u32) -> (u32, u32, u32) {
+    let z = day + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + u32::from(month <= 2);
+    (year, month, day_of_month)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// 2026-10-16, a Friday
+    const FRIDAY: u32 = 20_742;
+    
+    fn session(armed_s: u32, overboost_cuts: u32, learning_updates: u32) -> SessionSummary {
+        let mut session = SessionSummary {
+            duration_ms: armed_s * 1000 + 500,
+            armed_ms: armed_s * 1000,
+            overboost_cuts,
+            learning_updates,
+            storage_writes: 2,
+            ..SessionSummary::default()
+        };
+        session.tracking.bands[2].steady_samples = 100;
+        session.tracking.bands[2].steady_error_sq_sum = 25.0;
+        session
+    }
+    
+    #[test]
+    fn test_calendar() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(FRIDAY), (2026, 10, 16));
+        assert_eq!(civil_date(11_016), (2000, 2, 29));
+        assert_eq!(week_start(FRIDAY), FRIDAY - 4);
+        assert_eq!(week_start(FRIDAY - 4), FRIDAY - 4);
+        assert_eq!(civil_date(month_start(FRIDAY)), (2026, 10, 1));
+    }
+    
+    #[test]
+    fn test_session_folds_into_week_and_month() {
+        let mut log = TrendLog::new();
+        log.set_day(FRIDAY);
+        
+        // Followed every cycle: the same session grows, it is not counted again
+        log.follow(&session(1800, 1, 50));
+        log.follow(&session(3600, 2, 100));
+        let report = log.report();
+        assert_eq!(report.weeks.len(), 1);
+        let week = &report.weeks[0];
+        assert_eq!(week.start_day, FRIDAY - 4);
+        assert_eq!((week.sessions, week.armed_s, week.powered_s), (1, 3600, 3600));
+        assert_eq!(week.overboost_per_hour(), Some(2.0));
+        assert_eq!(week.learning_per_hour(), Some(100.0));
+        assert_eq!(week.rms_tracking_error_psi(), Some(0.5));
+        assert_eq!(report.months[0], TrendRecord { start_day: month_start(FRIDAY), ..week.clone() });
+    }
+    
+    #[test]
+    fn test_new_week_starts_a_record_and_history_is_bounded() {
+        // One ten-second drive a week, the log persisted across power cycles
+        let mut report = TrendReport::default();
+        for week in 0..20 {
+            let mut log = TrendLog::new();
+            log.restore(report);
+            log.set_day(FRIDAY + week * 7);
+            log.follow(&SessionSummary { duration_ms: 10_000, armed_ms: 10_000, ..SessionSummary::default() });
+            report = log.report();
+        }
+        assert_eq!(report.weeks.len(), TREND_WEEKS);
+        assert!(report.weeks.windows(2).all(|pair| pair[1].start_day - pair[0].start_day == 7));
+        assert!(report.weeks.iter().all(|week| week.sessions == 1 && week.armed_s == 10));
+        assert_eq!(report.months.len(), 5);
+        assert_eq!(report.months.iter().map(|month| month.sessions as u32).sum::<u32>(), 20);
+    }
+    
+    #[test]
+    fn test_saved_once_when_the_engine_stops() {
+        let mut log = TrendLog::new();
+        log.set_day(FRIDAY);
+        log.follow(&session(600, 0, 10));
+        assert!(!log.save_due(0));
+        assert!(!log.save_due(2500));
+        assert!(log.save_due(0));
+        assert!(!log.save_due(0));
+        
+        // Another stop without new activity has nothing to save
+        assert!(!log.save_due(800));
+        assert!(!log.save_due(0));
+    }
+    
+    #[test]
+    fn test_undated_activity_waits_for_a_date() {
+        let mut log = TrendLog::new();
+        log.follow(&session(600, 0, 10));
+        assert!(log.report().weeks.is_empty());
+        
+        log.set_day(FRIDAY);
+        assert_eq!(log.report().weeks[0].armed_s, 600);
+        assert_eq!(log.report().weeks[0].sessions, 1);
+        
+        // A phone clock set back a month files under the current period
+        log.set_day(FRIDAY - 30);
+        log.follow(&session(1200, 0, 20));
+        let report = log.report();
+        assert_eq!(report.weeks.len(), 1);
+        assert_eq!(report.weeks[0].armed_s, 1200);
+        assert_eq!(report.weeks[0].learning_updates, 20);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use crate::test_support::idle_inputs;
+    use crate::{RumbleDomeCore, SystemConfig, SystemInputs};
+    use rumbledome_hal::MockHal;
+    
+    #[test]
+    fn test_drive_is_filed_when_the_client_reads_trends() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        for t in (0..=60_000).step_by(50) {
+            core.execute_control_cycle_with_inputs(idle_inputs(t)).unwrap();
+        }
+        
+        // 2026-10-16
+        let report = core.trend_report(20_742);
+        assert_eq!(report.weeks.len(), 1);
+        let week = &report.weeks[0];
+        assert_eq!((week.sessions, week.powered_s, week.armed_s), (1, 60, 0));
+        // One flight recorder block
+        assert_eq!(week.storage_writes, core.sessions.current().storage_writes);
+        assert!(week.storage_writes >= 1);
+    }
+    
+    #[test]
+    fn test_trends_survive_a_power_cycle() {
+        let mut core = RumbleDomeCore::new(MockHal::new(), SystemConfig::default());
+        core.initialize().unwrap();
+        core.trend_report(20_742);
+        for t in (0..=60_000).step_by(50) {
+            core.execute_control_cycle_with_inputs(idle_inputs(t)).unwrap();
+        }
+        // Engine off, then key-off
+        core.execute_control_cycle_with_inputs(SystemInputs { rpm: 0, ..idle_inputs(60_050) }).unwrap();
+        let before = core.trends.report();
+        
+        // Same card, new boot
+        let hal = core::mem::replace(&mut core.hal, MockHal::new());
+        let mut core = RumbleDomeCore::new(hal, SystemConfig::default());
+        core.initialize().unwrap();
+        assert_eq!(core.trends.report(), before);
+        
+        // The new drive adds to the same week
+        let report = core.trend_report(20_742);
+        assert_eq!(report.weeks.len(), 1);
+        assert_eq!((report.weeks[0].sessions, report.weeks[0].powered_s), (2, 60));
+    }
+}
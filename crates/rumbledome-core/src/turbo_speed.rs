@@ -0,0 +1,443 @@
+//! Turbo Shaft Speed Estimation
+//! 
+//! 🔗 T4-CORE-124: Model-Based Turbo Overspeed Protection
+//! Derived From: T1-SAFETY-002 (Defense in Depth) + T4-CORE-120 (Turbine Protection from Exhaust Backpressure)
+//! AI Traceability: The same boost spins the turbo much faster on thin or hot air - cap boost where the wheel would overspeed instead of trusting a sea-level boost limit
+//! 
+//! Nothing measures shaft speed, so it is estimated from where the engine
+//! sits on an approximate compressor map. The engine's airflow follows from
+//! RPM, displacement and manifold pressure; the pressure ratio follows from
+//! boost and ambient pressure. Both are corrected to the map's reference
+//! inlet conditions, and the speed line passing through that point gives the
+//! shaft speed.
+//! 
+//! Speed lines are parabolas scaled from the rated one: flow falls from its
+//! choke value at no pressure rise to nothing at the line's peak ratio, which
+//! grows with the square of shaft speed. A gauge boost sensor reads against
+//! the local atmosphere, so at altitude the same boost is a higher pressure
+//! ratio; a hot inlet lowers the corrected speed a given wheel speed stands
+//! for. Either way the turbo has to spin faster for the same boost.
+//! 
+//! When protection is enabled, the boost at which the estimate reaches the
+//! speed limit at the current RPM caps the target. Ambient pressure comes
+//! from a mapped barometric CAN signal or the configured value; inlet
+//! temperature from the intake air sensor or the configured value.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::PSI_PER_KPA;
+use crate::{CoreError, SystemInputs};
+
+/// Map reference inlet pressure (kPa absolute)
+pub const REFERENCE_PRESSURE_KPA: f32 = 101.325;
+
+/// Map reference inlet temperature (°C)
+pub const REFERENCE_TEMP_C: f32 = 25.0;
+
+/// Kelvin at 0 °C
+const ZERO_C_K: f32 = 273.15;
+
+/// Gas constant of air (J/kg·K)
+const R_AIR: f32 = 287.0;
+
+/// Fastest corrected speed searched, as a multiple of rated - past it the point is off the map
+const MAX_SPEED_FRACTION: f32 = 2.0;
+
+/// Bisection steps - enough for a few RPM of resolution
+const SOLVER_STEPS: usize = 24;
+
+/// Compressor map approximation and overspeed protection rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TurboSpeedConfig {
+    /// Estimate shaft speed and cap boost at the speed limit
+    pub enabled: bool,
+    /// Engine displacement (L)
+    pub displacement_l: f32,
+    /// Volumetric efficiency at full throttle
+    pub volumetric_efficiency: f32,
+    /// Charge temperature after the intercooler (°C)
+    pub charge_temp_c: f32,
+    /// Shaft speed the map's rated speed line is drawn for (RPM)
+    pub rated_shaft_rpm: f32,
+    /// Pressure ratio at which the rated speed line reaches zero flow
+    pub rated_pressure_ratio: f32,
+    /// Corrected flow at rated speed and no pressure rise (kg/s)
+    pub rated_flow_kg_s: f32,
+    /// Highest shaft speed allowed (RPM)
+    pub max_shaft_rpm: f32,
+    /// Ambient pressure used while no barometric CAN signal is received (kPa absolute)
+    pub ambient_pressure_kpa: f32,
+    /// Compressor inlet temperature used while no intake air temperature is read (°C)
+    pub ambient_temp_c: f32,
+    /// Boost below which the throttle may be part closed and nothing is estimated (PSI)
+    pub min_boost_psi: f32,
+}
+
+impl Default for TurboSpeedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            displacement_l: 5.0,
+            volumetric_efficiency: 0.9,
+            charge_temp_c: 45.0,
+            rated_shaft_rpm: 120_000.0,
+            rated_pressure_ratio: 3.0,
+            rated_flow_kg_s: 0.9,
+            max_shaft_rpm: 115_000.0,
+            ambient_pressure_kpa: REFERENCE_PRESSURE_KPA,
+            ambient_temp_c: REFERENCE_TEMP_C,
+            min_boost_psi: 4.0,
+        }
+    }
+}
+
+impl TurboSpeedConfig {
+    /// Validate the map approximation and protection rule
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if !(1.0..=10.0).contains(&self.displacement_l) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed displacement must be 1-10 L, got {}", self.displacement_l)
+            ));
+        }
+        if !(0.5..=1.2).contains(&self.volumetric_efficiency) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed volumetric efficiency must be 0.5-1.2, got {}", self.volumetric_efficiency)
+            ));
+        }
+        if !(0.0..=100.0).contains(&self.charge_temp_c) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed charge temperature must be 0-100 °C, got {}", self.charge_temp_c)
+            ));
+        }
+        if !(20_000.0..=350_000.0).contains(&self.rated_shaft_rpm) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo rated shaft speed must be 20000-350000 RPM, got {}", self.rated_shaft_rpm)
+            ));
+        }
+        if !(1.5..=5.0).contains(&self.rated_pressure_ratio) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo rated pressure ratio must be 1.5-5.0, got {}", self.rated_pressure_ratio)
+            ));
+        }
+        if !(0.05..=3.0).contains(&self.rated_flow_kg_s) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo rated flow must be 0.05-3.0 kg/s, got {}", self.rated_flow_kg_s)
+            ));
+        }
+        // Speeds past the modelled range would be read off an extrapolated map
+        let fastest = self.rated_shaft_rpm * MAX_SPEED_FRACTION;
+        if !(self.rated_shaft_rpm * 0.5..=fastest).contains(&self.max_shaft_rpm) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Turbo speed limit must be {:.0}-{:.0} RPM (half to twice rated), got {}",
+                self.rated_shaft_rpm * 0.5, fastest, self.max_shaft_rpm
+            )));
+        }
+        if !(50.0..=110.0).contains(&self.ambient_pressure_kpa) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed ambient pressure must be 50-110 kPa, got {}", self.ambient_pressure_kpa)
+            ));
+        }
+        if !(-40.0..=60.0).contains(&self.ambient_temp_c) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed ambient temperature must be -40-60 °C, got {}", self.ambient_temp_c)
+            ));
+        }
+        if !(1.0..=30.0).contains(&self.min_boost_psi) {
+            return Err(CoreError::ConfigurationError(
+                format!("Turbo speed minimum boost must be 1-30 PSI, got {}", self.min_boost_psi)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Corrected compressor flow per unit pressure ratio at an engine speed and inlet temperature (kg/s)
+    /// 
+    /// Manifold density scales with the pressure ratio and the map's
+    /// correction divides inlet pressure back out, so only the ratio is left.
+    fn corrected_flow_per_ratio(&self, rpm: f32, inlet_temp_c: f32) -> f32 {
+        let swept_m3_s = self.displacement_l / 1000.0 * rpm.max(0.0) / 120.0;
+        let reference_density = REFERENCE_PRESSURE_KPA * 1000.0 / (R_AIR * (self.charge_temp_c + ZERO_C_K));
+        let temperature_correction = libm::sqrtf((inlet_temp_c + ZERO_C_K) / (REFERENCE_TEMP_C + ZERO_C_K));
+        reference_density * swept_m3_s * self.volumetric_efficiency * temperature_correction
+    }
+    
+    /// Corrected flow on a speed line at a pressure ratio (kg/s)
+    fn map_flow_kg_s(&self, speed: f32, pressure_ratio: f32) -> f32 {
+        let peak_ratio = 1.0 + (self.rated_pressure_ratio - 1.0) * speed * speed;
+        let rise = ((pressure_ratio - 1.0) / (peak_ratio - 1.0).max(f32::EPSILON)).clamp(0.0, 1.0);
+        self.rated_flow_kg_s * speed * (1.0 - rise * rise)
+    }
+    
+    /// Factor taking physical shaft speed to corrected speed at an inlet temperature
+    fn speed_correction(inlet_temp_c: f32) -> f32 {
+        libm::sqrtf((REFERENCE_TEMP_C + ZERO_C_K) / (inlet_temp_c + ZERO_C_K))
+    }
+    
+    /// Shaft speed at an operating point (RPM); `None` off the right of the map
+    pub fn estimate_shaft_rpm(&self, rpm: f32, boost_psi: f32, ambient_kpa: f32, inlet_temp_c: f32) -> Option<f32> {
+        let pressure_ratio = 1.0 + boost_psi.max(0.0) / PSI_PER_KPA / ambient_kpa;
+        let flow = self.corrected_flow_per_ratio(rpm, inlet_temp_c) * pressure_ratio;
+        if self.map_flow_kg_s(MAX_SPEED_FRACTION, pressure_ratio) < flow {
+            return None;
+        }
+        
+        // Flow on a speed line at a fixed ratio only grows with speed
+        let (mut slow, mut fast) = (0.0, MAX_SPEED_FRACTION);
+        for _ in 0..SOLVER_STEPS {
+            let speed = (slow + fast) / 2.0;
+            if self.map_flow_kg_s(speed, pressure_ratio) < flow {
+                slow = speed;
+            } else {
+                fast = speed;
+            }
+        }
+        Some(fast * self.rated_shaft_rpm / Self::speed_correction(inlet_temp_c))
+    }
+    
+    /// Boost at which the shaft reaches the speed limit at an engine speed (PSI gauge)
+    pub fn boost_at_speed_limit_psi(&self, rpm: f32, ambient_kpa: f32, inlet_temp_c: f32) -> f32 {
+        let speed = self.max_shaft_rpm / self.rated_shaft_rpm * Self::speed_correction(inlet_temp_c);
+        let flow_per_ratio = self.corrected_flow_per_ratio(rpm, inlet_temp_c);
+        
+        // Along the limit's speed line, map flow falls with ratio while the engine's draw rises
+        let (mut low, mut high) = (1.0, 1.0 + (self.rated_pressure_ratio - 1.0) * speed * speed);
+        if self.map_flow_kg_s(speed, low) < flow_per_ratio * low {
+            return 0.0;
+        }
+        for _ in 0..SOLVER_STEPS {
+            let ratio = (low + high) / 2.0;
+            if self.map_flow_kg_s(speed, ratio) < flow_per_ratio * ratio {
+                high = ratio;
+            } else {
+                low = ratio;
+            }
+        }
+        (low - 1.0) * ambient_kpa * PSI_PER_KPA
+    }
+}
+
+/// Turbo speed estimate and overspeed protection status (diagnostics)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurboSpeedStatus {
+    /// Estimated shaft speed while on boost (RPM)
+    pub shaft_rpm: Option<f32>,
+    /// Ambient pressure the estimate used (kPa absolute)
+    pub ambient_kpa: Option<f32>,
+    /// Compressor inlet temperature the estimate used (°C)
+    pub inlet_temp_c: Option<f32>,
+    /// Boost cap in effect (PSI)
+    pub cap_psi: Option<f32>,
+    /// Pulls limited since power-up
+    pub interventions: u32,
+}
+
+/// Estimates shaft speed and the boost that keeps it under the limit, cycle by cycle
+#[derive(Debug, Clone)]
+pub struct TurboSpeedProtection {
+    config: TurboSpeedConfig,
+    status: TurboSpeedStatus,
+}
+
+impl TurboSpeedProtection {
+    /// Create with no estimate yet
+    pub fn new(config: TurboSpeedConfig) -> Self {
+        Self { config, status: TurboSpeedStatus::default() }
+    }
+    
+    /// Apply a changed map or limit; takes effect from the next update
+    pub fn reconfigure(&mut self, config: TurboSpeedConfig) {
+        self.config = config;
+    }
+    
+    /// Update from one cycle's inputs
+    /// 
+    /// `barometric_kpa` is the latest barometric CAN reading, if any. A cap is
+    /// only set while it is below `ceiling_psi`, and never below `spring_psi`.
+    pub fn update(&mut self, inputs: &SystemInputs, barometric_kpa: Option<f32>, ceiling_psi: f32, spring_psi: f32) {
+        let interventions = self.status.interventions;
+        if !self.config.enabled {
+            self.status = TurboSpeedStatus { interventions, ..TurboSpeedStatus::default() };
+            return;
+        }
+        
+        let ambient_kpa = barometric_kpa.filter(|kpa| (50.0..=110.0).contains(kpa)).unwrap_or(self.config.ambient_pressure_kpa);
+        let inlet_temp_c = inputs.intake_air_temp_c.unwrap_or(self.config.ambient_temp_c);
+        let mut status = TurboSpeedStatus {
+            ambient_kpa: Some(ambient_kpa),
+            inlet_temp_c: Some(inlet_temp_c),
+            interventions,
+            ..TurboSpeedStatus::default()
+        };
+        
+        // Off boost the throttle may be part closed, so the engine's airflow is unknown
+        if inputs.manifold_pressure >= self.config.min_boost_psi && inputs.rpm > 0 {
+            let rpm = inputs.rpm as f32;
+            // Off the map is past any speed the map can give - report the limit's worth
+            status.shaft_rpm = Some(
+                self.config.estimate_shaft_rpm(rpm, inputs.manifold_pressure, ambient_kpa, inlet_temp_c)
+                    .unwrap_or(self.config.rated_shaft_rpm * MAX_SPEED_FRACTION)
+            );
+            let cap_psi = self.config.boost_at_speed_limit_psi(rpm, ambient_kpa, inlet_temp_c).max(spring_psi);
+            if cap_psi < ceiling_psi {
+                if self.status.cap_psi.is_none() {
+                    status.interventions += 1;
+                }
+                status.cap_psi = Some(cap_psi);
+            }
+        }
+        self.status = status;
+    }
+    
+    /// Boost cap, or `None` while full boost keeps the shaft under its limit (PSI)
+    pub fn boost_cap_psi(&self) -> Option<f32> {
+        self.status.cap_psi
+    }
+    
+    /// Current status
+    pub fn status(&self) -> &TurboSpeedStatus {
+        &self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn inputs(rpm: u16, manifold_pressure: f32, intake_air_temp_c: Option<f32>) -> SystemInputs {
+        SystemInputs { rpm, manifold_pressure, intake_air_temp_c, ..test_support::inputs(0) }
+    }
+    
+    fn enabled() -> TurboSpeedConfig {
+        TurboSpeedConfig { enabled: true, ..TurboSpeedConfig::default() }
+    }
+    
+    #[test]
+    fn test_estimate_rises_with_boost_and_rpm() {
+        let config = enabled();
+        let estimate = |rpm, boost| config.estimate_shaft_rpm(rpm, boost, REFERENCE_PRESSURE_KPA, REFERENCE_TEMP_C).unwrap();
+        assert!(estimate(6000.0, 10.0) > estimate(6000.0, 5.0));
+        assert!(estimate(6000.0, 10.0) > estimate(3000.0, 10.0));
+        
+        // The limit boost lands the estimate on the limit
+        let limit_psi = config.boost_at_speed_limit_psi(6000.0, REFERENCE_PRESSURE_KPA, REFERENCE_TEMP_C);
+        assert!((estimate(6000.0, limit_psi) - config.max_shaft_rpm).abs() < 200.0);
+    }
+    
+    #[test]
+    fn test_altitude_and_heat_lower_the_limit_boost() {
+        let config = enabled();
+        let sea_level = config.boost_at_speed_limit_psi(6000.0, REFERENCE_PRESSURE_KPA, REFERENCE_TEMP_C);
+        let altitude = config.boost_at_speed_limit_psi(6000.0, 80.0, REFERENCE_TEMP_C);
+        let hot_day = config.boost_at_speed_limit_psi(6000.0, REFERENCE_PRESSURE_KPA, 45.0);
+        assert!(sea_level > 15.0 && sea_level < 20.0, "{}", sea_level);
+        assert!(altitude < sea_level - 3.0, "{} vs {}", altitude, sea_level);
+        assert!(hot_day < sea_level - 0.5, "{} vs {}", hot_day, sea_level);
+        
+        // Same boost, thinner air: the wheel spins faster
+        let thin = config.estimate_shaft_rpm(6000.0, 12.0, 80.0, REFERENCE_TEMP_C).unwrap();
+        let thick = config.estimate_shaft_rpm(6000.0, 12.0, REFERENCE_PRESSURE_KPA, REFERENCE_TEMP_C).unwrap();
+        assert!(thin > thick);
+    }
+    
+    #[test]
+    fn test_cap_only_below_the_ceiling() {
+        let mut protection = TurboSpeedProtection::new(enabled());
+        
+        // Sea level: the limit boost is above a 12 PSI ceiling
+        protection.update(&inputs(6000, 8.0, None), None, 12.0, 5.0);
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert!(protection.status().shaft_rpm.is_some());
+        assert_eq!(protection.status().ambient_kpa, Some(REFERENCE_PRESSURE_KPA));
+        
+        // High up on a hot day it is not
+        protection.update(&inputs(6000, 8.0, Some(40.0)), Some(75.0), 12.0, 5.0);
+        let cap_psi = protection.boost_cap_psi().unwrap();
+        assert!((5.0..12.0).contains(&cap_psi));
+        assert_eq!(protection.status().interventions, 1);
+        assert_eq!(protection.status().inlet_temp_c, Some(40.0));
+        
+        // Off boost nothing is estimated; the next pull counts again
+        protection.update(&inputs(3000, 1.0, Some(40.0)), Some(75.0), 12.0, 5.0);
+        assert_eq!(protection.boost_cap_psi(), None);
+        assert_eq!(protection.status().shaft_rpm, None);
+        protection.update(&inputs(6000, 8.0, Some(40.0)), Some(75.0), 12.0, 5.0);
+        assert_eq!(protection.status().interventions, 2);
+    }
+    
+    #[test]
+    fn test_implausible_barometer_uses_configured_ambient() {
+        let mut protection = TurboSpeedProtection::new(TurboSpeedConfig { ambient_pressure_kpa: 90.0, ..enabled() });
+        protection.update(&inputs(6000, 8.0, None), Some(0.0), 12.0, 5.0);
+        assert_eq!(protection.status().ambient_kpa, Some(90.0));
+        
+        protection.reconfigure(TurboSpeedConfig::default());
+        protection.update(&inputs(6000, 8.0, None), None, 12.0, 5.0);
+        assert_eq!(protection.status(), &TurboSpeedStatus::default());
+    }
+    
+    #[test]
+    fn test_validation() {
+        assert!(TurboSpeedConfig::default().validate().is_ok());
+        assert!(TurboSpeedConfig { max_shaft_rpm: 300_000.0, ..enabled() }.validate().is_err());
+        assert!(TurboSpeedConfig { ambient_pressure_kpa: 14.7, ..enabled() }.validate().is_err());
+        assert!(TurboSpeedConfig { rated_pressure_ratio: 1.0, ..enabled() }.validate().is_err());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{CanSignal, ByteOrder, ControlMode, RumbleDomeCore, SystemConfig, WatchValue, WatchVariable};
+    use rumbledome_hal::{CanFrame, MockHal};
+    
+    /// Message carrying the test barometric signal
+    const BARO_MESSAGE_ID: u32 = 0x3A0;
+    
+    fn armed_core() -> RumbleDomeCore<MockHal> {
+        let mut config = SystemConfig {
+            control_mode: ControlMode::BoostByTarget,
+            turbo_speed: TurboSpeedConfig { enabled: true, ..TurboSpeedConfig::default() },
+            ..SystemConfig::default()
+        };
+        config.can_signals.barometric_pressure = Some(CanSignal {
+            message_id: BARO_MESSAGE_ID,
+            start_bit: 0,
+            length: 8,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 0.5,
+            offset: 0.0,
+        });
+        config.validate().unwrap();
+        test_support::armed_core(config)
+    }
+    
+    fn pull(core: &mut RumbleDomeCore<MockHal>) {
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        let mut inputs = core.read_inputs().unwrap();
+        inputs.rpm = 6000;
+        inputs.manifold_pressure = 8.0;
+        inputs.timestamp_ms = 0;
+        core.execute_control_cycle_with_inputs(inputs).unwrap();
+    }
+    
+    #[test]
+    fn test_thin_air_from_the_bus_caps_the_boost_target() {
+        let mut core = armed_core();
+        pull(&mut core);
+        assert_eq!(core.get_system_status().turbo_speed.cap_psi, None);
+        assert!(matches!(core.read_watch(WatchVariable::TurboShaftRpm), WatchValue::Number(_)));
+        
+        // 65 kPa - about 3600 m up
+        core.hal.receive_can_frame(CanFrame::new(BARO_MESSAGE_ID, &[130]).unwrap());
+        pull(&mut core);
+        let status = core.get_system_status().turbo_speed;
+        assert_eq!(status.ambient_kpa, Some(65.0));
+        let cap_psi = status.cap_psi.unwrap();
+        assert!(cap_psi < 12.0);
+        assert!(core.last_target_boost_psi <= cap_psi);
+    }
+}
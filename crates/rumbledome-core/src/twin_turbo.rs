@@ -0,0 +1,470 @@
+//! Twin Turbo Banks
+//! 
+//! 🔗 T4-CORE-100: Twin Turbo Bank Control
+//! Derived From: T4-CORE-024 (Dual-Solenoid Limp Control) + T4-CORE-093 (Sensor Wiring Auto-Detect)
+//! AI Traceability: Twin setups rarely match bank for bank - each wastegate gets its own valve, trim and dome sensor so one tired side shows up before it costs boost
+//! 
+//! Each turbo bank has its own 4-port valve (bank 1 on the Primary channel,
+//! bank 2 on Secondary) and its own dome pressure sensor. Both banks follow
+//! the shared boost target, offset by a per-bank trim. In a sequential
+//! layout bank 2's wastegate is held open until the secondary turbo comes in.
+//! 
+//! A 4-port valve at a given duty should put roughly that fraction of the
+//! feed pressure on its dome. The shortfall, expressed as duty, is how much
+//! more duty that bank needs than it is given; when one bank's shortfall
+//! stays well above the other's, an advisory names it. Detection never
+//! changes the output.
+
+use alloc::format;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::SolenoidChannel;
+use crate::{CoreError, SystemInputs, PRESSURE_CHANNELS};
+
+/// Analog pressure inputs including the two expansion inputs bank dome sensors use
+pub const ANALOG_PRESSURE_INPUTS: usize = PRESSURE_CHANNELS + 2;
+
+/// Largest per-bank trim on the shared target (PSI)
+pub const MAX_BANK_TRIM_PSI: f32 = 3.0;
+
+/// Dome feed pressure below which dome readings say nothing about the valve (PSI)
+pub(crate) const MIN_FEED_PSI: f32 = 5.0;
+
+/// Duty range where dome pressure follows duty (ends saturate)
+pub(crate) const BALANCE_DUTY_RANGE: core::ops::RangeInclusive<f32> = 10.0..=90.0;
+
+/// Low-pass filter weight of each new shortfall sample
+const SHORTFALL_FILTER: f32 = 0.02;
+
+/// Turbocharger plumbing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TurboLayout {
+    /// One turbo, or twins sharing one wastegate valve
+    #[default]
+    Single,
+    /// Two turbos boosting together, one wastegate valve per bank
+    Parallel,
+    /// Secondary turbo (bank 2) comes in above a set RPM
+    Sequential,
+}
+
+impl TurboLayout {
+    /// Whether each bank has its own valve and dome sensor
+    pub fn is_twin(&self) -> bool {
+        !matches!(self, TurboLayout::Single)
+    }
+    
+    /// Short description for status displays
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            TurboLayout::Single => "Single",
+            TurboLayout::Parallel => "Parallel twin",
+            TurboLayout::Sequential => "Sequential twin",
+        }
+    }
+}
+
+/// Turbo bank in a twin layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurboBank {
+    /// Primary channel
+    Bank1,
+    /// Secondary channel (the secondary turbo in a sequential layout)
+    Bank2,
+}
+
+impl TurboBank {
+    /// Both banks
+    pub const ALL: [TurboBank; 2] = [TurboBank::Bank1, TurboBank::Bank2];
+    
+    /// Position in per-bank arrays
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+    
+    /// Solenoid channel driving this bank's valve
+    pub fn channel(&self) -> SolenoidChannel {
+        match self {
+            TurboBank::Bank1 => SolenoidChannel::Primary,
+            TurboBank::Bank2 => SolenoidChannel::Secondary,
+        }
+    }
+    
+    /// Short description for status displays
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            TurboBank::Bank1 => "Bank 1",
+            TurboBank::Bank2 => "Bank 2",
+        }
+    }
+}
+
+/// One bank's wiring and trim
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BankConfig {
+    /// Analog pressure input of this bank's dome sensor
+    pub dome_channel: u8,
+    /// Offset on the shared boost target for this bank (PSI)
+    pub trim_psi: f32,
+}
+
+/// Twin turbo plumbing and bank balance rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TwinTurboConfig {
+    /// Turbocharger plumbing
+    pub layout: TurboLayout,
+    /// Bank 1 and bank 2 wiring and trim
+    pub banks: [BankConfig; 2],
+    /// Sequential layout: bank 2 wastegate is driven from this RPM (below it, held open)
+    pub secondary_rpm: u16,
+    /// Shortfall difference between banks that counts as imbalance (% duty)
+    pub imbalance_threshold_percent: f32,
+    /// Imbalance must persist this long before the advisory (ms)
+    pub imbalance_hold_ms: u32,
+}
+
+impl Default for TwinTurboConfig {
+    fn default() -> Self {
+        Self {
+            layout: TurboLayout::Single,
+            banks: [
+                BankConfig { dome_channel: PRESSURE_CHANNELS as u8, trim_psi: 0.0 },
+                BankConfig { dome_channel: PRESSURE_CHANNELS as u8 + 1, trim_psi: 0.0 },
+            ],
+            secondary_rpm: 3000,
+            imbalance_threshold_percent: 8.0,
+            imbalance_hold_ms: 20_000,
+        }
+    }
+}
+
+impl TwinTurboConfig {
+    /// Validate bank wiring, trims and balance rules
+    pub fn validate(&self) -> Result<(), CoreError> {
+        for bank in TurboBank::ALL {
+            let config = self.bank(bank);
+            if config.dome_channel as usize >= ANALOG_PRESSURE_INPUTS {
+                return Err(CoreError::ConfigurationError(
+                    format!("{} dome sensor channel {} out of range (0-{})",
+                        bank.display_text(), config.dome_channel, ANALOG_PRESSURE_INPUTS - 1)
+                ));
+            }
+            if config.trim_psi.abs() > MAX_BANK_TRIM_PSI {
+                return Err(CoreError::ConfigurationError(
+                    format!("{} trim must be within ±{} PSI, got {}", bank.display_text(), MAX_BANK_TRIM_PSI, config.trim_psi)
+                ));
+            }
+        }
+        
+        if self.layout.is_twin() && self.banks[0].dome_channel == self.banks[1].dome_channel {
+            return Err(CoreError::ConfigurationError(
+                format!("Both banks use dome sensor channel {}", self.banks[0].dome_channel)
+            ));
+        }
+        if !(1.0..=50.0).contains(&self.imbalance_threshold_percent) {
+            return Err(CoreError::ConfigurationError(
+                format!("Bank imbalance threshold must be 1-50 %, got {}", self.imbalance_threshold_percent)
+            ));
+        }
+        if self.imbalance_hold_ms > 120_000 {
+            return Err(CoreError::ConfigurationError(
+                format!("Bank imbalance hold time must be at most 120000 ms, got {}", self.imbalance_hold_ms)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Wiring and trim of one bank
+    pub fn bank(&self, bank: TurboBank) -> &BankConfig {
+        &self.banks[bank.index()]
+    }
+    
+    /// Whether a bank's wastegate is driven at this RPM
+    pub fn bank_active(&self, bank: TurboBank, rpm: u16) -> bool {
+        match (self.layout, bank) {
+            (TurboLayout::Sequential, TurboBank::Bank2) => rpm >= self.secondary_rpm,
+            _ => true,
+        }
+    }
+    
+    /// Per-bank dome pressures from the analog inputs (twin layouts only)
+    pub fn bank_dome_pressures(&self, analog: &[f32; ANALOG_PRESSURE_INPUTS]) -> Option<[f32; 2]> {
+        self.layout.is_twin()
+            .then(|| TurboBank::ALL.map(|bank| analog[self.bank(bank).dome_channel as usize]))
+    }
+}
+
+/// Bank balance status (diagnostics)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BankBalanceStatus {
+    /// Turbocharger plumbing
+    pub layout: TurboLayout,
+    /// Filtered duty each bank needs beyond what it is given (%)
+    pub shortfall_percent: [f32; 2],
+    /// Bank flagged as needing more duty than the other
+    pub needs_more_duty: Option<TurboBank>,
+    /// Imbalance advisories raised since power-up
+    pub advisories: u32,
+}
+
+/// Watches twin banks for one side consistently needing more duty
+#[derive(Debug, Clone)]
+pub struct BankBalance {
+    config: TwinTurboConfig,
+    shortfall: Option<[f32; 2]>,
+    imbalanced_since_ms: Option<u32>,
+    flagged: Option<TurboBank>,
+    advisories: u32,
+}
+
+impl BankBalance {
+    /// Create with no history
+    pub fn new(config: TwinTurboConfig) -> Self {
+        Self { config, shortfall: None, imbalanced_since_ms: None, flagged: None, advisories: 0 }
+    }
+    
+    /// Apply changed rules; plumbing may have changed, so history is dropped
+    pub fn reconfigure(&mut self, config: TwinTurboConfig) {
+        *self = Self { advisories: self.advisories, ..Self::new(config) };
+    }
+    
+    /// Observe one cycle's per-bank authorities and dome pressures
+    /// 
+    /// Returns the bank when it is newly flagged as needing more duty.
+    pub fn observe(&mut self, inputs: &SystemInputs, authorities: [f32; 2]) -> Option<TurboBank> {
+        let Some(domes) = inputs.bank_dome_pressure else {
+            self.cancel();
+            return None;
+        };
+        let usable = self.config.layout.is_twin()
+            && inputs.dome_input_pressure >= MIN_FEED_PSI
+            && authorities.iter().all(|authority| BALANCE_DUTY_RANGE.contains(authority));
+        if !usable {
+            self.cancel();
+            return None;
+        }
+        
+        // Duty that would account for the dome pressure each bank actually reached
+        let sample = TurboBank::ALL.map(|bank| {
+            let i = bank.index();
+            authorities[i] - 100.0 * domes[i] / inputs.dome_input_pressure
+        });
+        let shortfall = match self.shortfall {
+            Some(previous) => TurboBank::ALL.map(|bank| {
+                let i = bank.index();
+                previous[i] + (sample[i] - previous[i]) * SHORTFALL_FILTER
+            }),
+            None => sample,
+        };
+        self.shortfall = Some(shortfall);
+        
+        let imbalance = shortfall[0] - shortfall[1];
+        let threshold = self.config.imbalance_threshold_percent;
+        if imbalance.abs() < threshold / 2.0 {
+            // Balanced again - clear with hysteresis so the advisory does not flicker
+            self.imbalanced_since_ms = None;
+            self.flagged = None;
+            return None;
+        }
+        if imbalance.abs() < threshold {
+            self.imbalanced_since_ms = None;
+            return None;
+        }
+        
+        let since = *self.imbalanced_since_ms.get_or_insert(inputs.timestamp_ms);
+        if self.flagged.is_some() || inputs.timestamp_ms.wrapping_sub(since) < self.config.imbalance_hold_ms {
+            return None;
+        }
+        
+        let bank = if imbalance > 0.0 { TurboBank::Bank1 } else { TurboBank::Bank2 };
+        self.flagged = Some(bank);
+        self.advisories += 1;
+        Some(bank)
+    }
+    
+    /// Nothing to judge this cycle (not boosting, or a bank not driven)
+    /// 
+    /// Breaks the hold timer; a raised advisory stays until the banks are seen balanced.
+    pub fn cancel(&mut self) {
+        self.imbalanced_since_ms = None;
+    }
+    
+    /// Bank currently flagged as needing more duty
+    pub fn flagged(&self) -> Option<TurboBank> {
+        self.flagged
+    }
+    
+    /// Status snapshot for diagnostics
+    pub fn status(&self) -> BankBalanceStatus {
+        BankBalanceStatus {
+            layout: self.config.layout,
+            shortfall_percent: self.shortfall.unwrap_or([0.0; 2]),
+            needs_more_duty: self.flagged,
+            advisories: self.advisories,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    
+    fn twin() -> TwinTurboConfig {
+        TwinTurboConfig { layout: TurboLayout::Parallel, ..TwinTurboConfig::default() }
+    }
+    
+    fn inputs(bank_domes: [f32; 2], timestamp_ms: u32) -> SystemInputs {
+        SystemInputs { dome_input_pressure: 40.0, bank_dome_pressure: Some(bank_domes), ..test_support::inputs(timestamp_ms) }
+    }
+    
+    #[test]
+    fn test_sustained_shortfall_flags_bank() {
+        let mut balance = BankBalance::new(twin());
+        
+        // Both banks at 50%; bank 2's dome only reaches 30% of feed
+        let mut flagged = None;
+        for t in (0..30_000).step_by(10) {
+            if let Some(bank) = balance.observe(&inputs([20.0, 12.0], t), [50.0, 50.0]) {
+                assert!(flagged.is_none(), "advisory raised twice");
+                flagged = Some((bank, t));
+            }
+        }
+        let (bank, t) = flagged.unwrap();
+        assert_eq!(bank, TurboBank::Bank2);
+        assert!(t >= 20_000);
+        assert_eq!(balance.status().advisories, 1);
+        
+        // Leaving boost keeps the advisory; balanced readings clear it
+        balance.cancel();
+        assert_eq!(balance.flagged(), Some(TurboBank::Bank2));
+        for t in (30_000..40_000).step_by(10) {
+            balance.observe(&inputs([20.0, 20.0], t), [50.0, 50.0]);
+        }
+        assert_eq!(balance.flagged(), None);
+    }
+    
+    #[test]
+    fn test_brief_imbalance_and_saturated_duty_ignored() {
+        let mut balance = BankBalance::new(twin());
+        for t in (0..10_000).step_by(10) {
+            assert_eq!(balance.observe(&inputs([20.0, 12.0], t), [50.0, 50.0]), None);
+        }
+        
+        // Duty pinned at the end of its range says nothing about the valve
+        for t in (10_000..40_000).step_by(10) {
+            assert_eq!(balance.observe(&inputs([40.0, 20.0], t), [100.0, 100.0]), None);
+        }
+        assert_eq!(balance.status().advisories, 0);
+    }
+    
+    #[test]
+    fn test_sequential_secondary_and_validation() {
+        let config = TwinTurboConfig { layout: TurboLayout::Sequential, ..TwinTurboConfig::default() };
+        assert!(!config.bank_active(TurboBank::Bank2, 2500));
+        assert!(config.bank_active(TurboBank::Bank2, 3500));
+        assert!(config.bank_active(TurboBank::Bank1, 1000));
+        
+        let analog = [0.0, 1.0, 2.0, 3.0, 14.0, 15.0];
+        assert_eq!(config.bank_dome_pressures(&analog), Some([14.0, 15.0]));
+        assert_eq!(TwinTurboConfig::default().bank_dome_pressures(&analog), None);
+        
+        let mut shared = config.clone();
+        shared.banks[1].dome_channel = shared.banks[0].dome_channel;
+        assert!(shared.validate().is_err());
+        let mut trimmed = config;
+        trimmed.banks[0].trim_psi = 4.0;
+        assert!(trimmed.validate().is_err());
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod core_tests {
+    use super::*;
+    use crate::test_support::{self, TORQUE_MESSAGE_ID};
+    use crate::{DensityCompensationConfig, DriveCommand, PulseShaper, RumbleDomeCore, SolenoidTopology, SystemConfig};
+    use rumbledome_hal::MockHal;
+    
+    fn twin_config(layout: TurboLayout) -> SystemConfig {
+        let mut twin_turbo = TwinTurboConfig { layout, ..TwinTurboConfig::default() };
+        twin_turbo.banks[0].trim_psi = 1.0;
+        twin_turbo.banks[1].trim_psi = -1.0;
+        SystemConfig {
+            solenoid_topology: SolenoidTopology::TwinFourPort,
+            twin_turbo,
+            ..SystemConfig::default()
+        }
+    }
+    
+    fn armed_twin_core(layout: TurboLayout) -> RumbleDomeCore<MockHal> {
+        let config = twin_config(layout);
+        config.validate().unwrap();
+        test_support::armed_core(config)
+    }
+    
+    fn bank_authorities(core: &mut RumbleDomeCore<MockHal>, inputs: SystemInputs) -> [f32; 2] {
+        match core.evaluate(inputs).unwrap().drive {
+            DriveCommand::BankAuthority(authorities) => authorities,
+            drive => panic!("expected per-bank authority, got {:?}", drive),
+        }
+    }
+    
+    fn boosting(rpm: u16) -> SystemInputs {
+        SystemInputs { rpm, ..test_support::inputs(0) }
+    }
+    
+    #[test]
+    fn test_banks_follow_trimmed_target() {
+        let mut core = armed_twin_core(TurboLayout::Parallel);
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        
+        let plan = core.evaluate(boosting(4000)).unwrap();
+        let DriveCommand::BankAuthority([bank1, bank2]) = plan.drive else {
+            panic!("expected per-bank authority, got {:?}", plan.drive);
+        };
+        assert!(bank1 > bank2);
+        
+        // Each valve's duty goes through its own pulse width shaping
+        let shaped = |authority| PulseShaper::new(&core.config.pulse_shaping, core.config.pwm_frequency_hz).apply(authority);
+        let (duty1, duty2) = (shaped(bank1), shaped(bank2));
+        core.apply_outputs(plan).unwrap();
+        assert_eq!(core.hal.get_channel_duty(TurboBank::Bank1.channel()), duty1);
+        assert_eq!(core.hal.get_channel_duty(TurboBank::Bank2.channel()), duty2);
+    }
+    
+    #[test]
+    fn test_banks_corrected_for_air_density() {
+        // Both banks trimmed above spring so each has duty to correct
+        let mut config = twin_config(TurboLayout::Parallel);
+        config.twin_turbo.banks[1].trim_psi = 1.0;
+        let mut plain = test_support::armed_core(config.clone());
+        let config = SystemConfig {
+            density_compensation: DensityCompensationConfig { enabled: true, ..DensityCompensationConfig::default() },
+            ..config
+        };
+        config.validate().unwrap();
+        let mut compensated = test_support::armed_core(config);
+        plain.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        compensated.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        
+        // Hot intake air is thin - both banks need their wastegates shut further
+        let hot = SystemInputs { intake_air_temp_c: Some(60.0), ..boosting(4000) };
+        let [plain1, plain2] = bank_authorities(&mut plain, hot.clone());
+        let [hot1, hot2] = bank_authorities(&mut compensated, hot);
+        assert!(compensated.density_compensation.status().duty_factor > 1.0);
+        assert!(hot1 > plain1);
+        assert!(hot2 > plain2);
+    }
+    
+    #[test]
+    fn test_sequential_secondary_held_open_until_it_comes_in() {
+        let mut core = armed_twin_core(TurboLayout::Sequential);
+        core.can_health.record_message(TORQUE_MESSAGE_ID, 0);
+        
+        let plan = core.evaluate(boosting(2000)).unwrap();
+        assert!(matches!(plan.drive, DriveCommand::BankAuthority([_, secondary]) if secondary == 0.0));
+    }
+}
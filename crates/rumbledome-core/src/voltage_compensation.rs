@@ -0,0 +1,181 @@
+//! Supply Voltage Compensation
+//! 
+//! 🔗 T4-CORE-092: Battery Voltage Duty Compensation
+//! Derived From: T4-CORE-070 (Actuator Driver Interface) + T4-HAL-014 (Supply Voltage Monitor)
+//! AI Traceability: Same commanded authority, same solenoid response - whether the battery is at 14.4 V or sagging to 12 V at idle
+//! 
+//! The solenoid coil sees duty × supply voltage, so the same duty opens the
+//! valve less when the supply sags. The commanded duty is multiplied by a
+//! factor from a voltage curve - by default nominal voltage over supply
+//! voltage - so the average coil voltage stays where the controller and the
+//! learned data expect it.
+
+use alloc::format;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use rumbledome_hal::NOMINAL_SUPPLY_VOLTAGE;
+use crate::CoreError;
+
+/// Maximum number of points in the compensation curve
+pub const MAX_VOLTAGE_POINTS: usize = 8;
+
+/// Readings outside this range are treated as a failed measurement (V)
+const PLAUSIBLE_VOLTAGE: core::ops::RangeInclusive<f32> = 6.0..=18.0;
+
+/// One point of the compensation curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoltagePoint {
+    /// Supply voltage (V)
+    pub volts: f32,
+    /// Duty multiplier at this voltage
+    pub factor: f32,
+}
+
+/// Voltage compensation settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoltageCompensationConfig {
+    /// Scale solenoid duty with supply voltage
+    pub enabled: bool,
+    /// Curve points in ascending voltage order
+    pub points: Vec<VoltagePoint>,
+}
+
+impl Default for VoltageCompensationConfig {
+    fn default() -> Self {
+        let point = |volts: f32| VoltagePoint { volts, factor: NOMINAL_SUPPLY_VOLTAGE / volts };
+        Self {
+            enabled: true,
+            points: alloc::vec![point(10.0), point(12.0), point(NOMINAL_SUPPLY_VOLTAGE), point(15.0)],
+        }
+    }
+}
+
+impl VoltageCompensationConfig {
+    /// Validate curve shape
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if self.points.len() < 2 || self.points.len() > MAX_VOLTAGE_POINTS {
+            return Err(CoreError::ConfigurationError(
+                format!("Voltage compensation curve needs 2-{} points, got {}", MAX_VOLTAGE_POINTS, self.points.len())
+            ));
+        }
+        
+        if self.points.windows(2).any(|pair| pair[1].volts <= pair[0].volts) {
+            return Err(CoreError::ConfigurationError(
+                "Voltage compensation points must be in strictly ascending voltage order".into()
+            ));
+        }
+        
+        if let Some(point) = self.points.iter().find(|p| !PLAUSIBLE_VOLTAGE.contains(&p.volts) || !(0.5..=2.0).contains(&p.factor)) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Voltage compensation point {} V x{} outside {}-{} V, x0.5-2.0",
+                point.volts, point.factor, PLAUSIBLE_VOLTAGE.start(), PLAUSIBLE_VOLTAGE.end()
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    /// Duty multiplier at a supply voltage, linearly interpolated and held flat past the ends
+    /// 
+    /// A missing or implausible reading leaves duty uncompensated.
+    pub fn factor(&self, volts: Option<f32>) -> f32 {
+        let Some(volts) = volts.filter(|volts| self.enabled && PLAUSIBLE_VOLTAGE.contains(volts)) else {
+            return 1.0;
+        };
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 1.0;
+        };
+        
+        if volts <= first.volts {
+            return first.factor;
+        }
+        if volts >= last.volts {
+            return last.factor;
+        }
+        
+        // A reading right on a point takes that point's factor exactly (t = 0)
+        self.points
+            .windows(2)
+            .find(|pair| volts < pair[1].volts)
+            .map(|pair| {
+                let t = (volts - pair[0].volts) / (pair[1].volts - pair[0].volts);
+                pair[0].factor + (pair[1].factor - pair[0].factor) * t
+            })
+            .unwrap_or(last.factor)
+    }
+}
+
+/// Applies voltage compensation in the solenoid output stage
+/// 
+/// Remembers the factor last applied so the driver can report authority in
+/// nominal-voltage duty - what learning and diagnostics compare against.
+#[derive(Debug, Clone)]
+pub struct VoltageCompensation {
+    config: VoltageCompensationConfig,
+    last_factor: f32,
+}
+
+impl VoltageCompensation {
+    /// Create compensation stage
+    pub fn new(config: VoltageCompensationConfig) -> Self {
+        Self { config, last_factor: 1.0 }
+    }
+    
+    /// Compensated duty (%) for a duty command at the measured supply voltage
+    pub fn apply(&mut self, duty_percent: f32, volts: Option<f32>) -> f32 {
+        self.last_factor = self.config.factor(volts);
+        (duty_percent * self.last_factor).clamp(0.0, 100.0)
+    }
+    
+    /// Factor applied to the latest command
+    pub fn last_factor(&self) -> f32 {
+        self.last_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_default_curve_holds_coil_voltage() {
+        let config = VoltageCompensationConfig::default();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.factor(Some(NOMINAL_SUPPLY_VOLTAGE)), 1.0);
+        assert_eq!(config.factor(Some(12.0)), NOMINAL_SUPPLY_VOLTAGE / 12.0);
+        // Between points and past the ends
+        assert!((config.factor(Some(11.0)) - 1.265).abs() < 0.01);
+        assert_eq!(config.factor(Some(16.0)), config.factor(Some(15.0)));
+        
+        // No reading, an implausible reading, or compensation off: duty untouched
+        assert_eq!(config.factor(None), 1.0);
+        assert_eq!(config.factor(Some(0.0)), 1.0);
+        let disabled = VoltageCompensationConfig { enabled: false, ..VoltageCompensationConfig::default() };
+        assert_eq!(disabled.factor(Some(12.0)), 1.0);
+    }
+    
+    #[test]
+    fn test_compensated_duty_is_clamped() {
+        let mut stage = VoltageCompensation::new(VoltageCompensationConfig::default());
+        assert!((stage.apply(50.0, Some(12.0)) - 57.5).abs() < 0.01);
+        assert_eq!(stage.apply(95.0, Some(10.0)), 100.0);
+        assert!((stage.last_factor() - 1.38).abs() < 0.001);
+        assert_eq!(stage.apply(0.0, Some(10.0)), 0.0);
+    }
+    
+    #[test]
+    fn test_curve_validation() {
+        let mut config = VoltageCompensationConfig::default();
+        config.points.swap(0, 1);
+        assert!(config.validate().is_err());
+        
+        let mut config = VoltageCompensationConfig::default();
+        config.points[0].factor = 3.0;
+        assert!(config.validate().is_err());
+        
+        config.points.truncate(1);
+        assert!(config.validate().is_err());
+    }
+}
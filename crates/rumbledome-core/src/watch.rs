@@ -0,0 +1,204 @@
+//! Watch Window
+//! 
+//! 🔗 T4-CORE-098: Live Variable Inspection
+//! Derived From: T4-CORE-005 (Performance Monitoring) + T4-CORE-035 (Semantic Screen Description)
+//! AI Traceability: Observe one internal value on a running device without building firmware just to print it
+//! 
+//! A client registers a short list of named internal variables; their
+//! current values ride along on every telemetry frame sent to that client.
+//! The set of variables is fixed at build time - registration only chooses
+//! which of them are sent.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use crate::{CoreError, InputSource};
+
+/// Most variables one client may watch at a time (keeps telemetry frames small)
+pub const MAX_WATCHES: usize = 8;
+
+/// Internal variable that can be watched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatchVariable {
+    /// Level 1 boost target, before hardware, rev-limit and heat caps (PSI)
+    Level1TargetPsi,
+    /// Boost target after every cap (PSI)
+    TargetPsi,
+    /// Solenoid duty actually commanded (%)
+    DutyPercent,
+    /// Learned duty ceiling at the current RPM (%)
+    DutyCeilingPercent,
+    /// Manifold pressure source chosen by arbitration
+    MapSource,
+    /// MAP zero offset applied to the analog sensor (PSI)
+    MapZeroPsi,
+    /// Torque assistance authority allowed by CAN health (0.0-1.0)
+    CanAuthority,
+    /// Heat derate multiplier on the controllable boost range
+    ThermalDerate,
+    /// Exhaust manifold pressure from the optional backpressure sensor (PSI)
+    ExhaustBackpressurePsi,
+    /// Exhaust-to-boost pressure ratio while on boost
+    BackpressureRatio,
+    /// Estimated turbo shaft speed while on boost (RPM)
+    TurboShaftRpm,
+    /// Above-spring target authority left by the soft ceiling (1.0 when not tapering)
+    SoftCeiling,
+    /// Domes vented at idle with PWM off
+    IdleVenting,
+    /// Rolling average control cycle time (microseconds)
+    CycleTimeUs,
+    /// Calculated engine load from the bus or OBD-II polling (%)
+    EngineLoadPercent,
+    /// Level 2 boost loop correction in the dome pressure setpoint, while the dome loop runs (PSI)
+    BoostTrimPsi,
+}
+
+impl WatchVariable {
+    /// Every watchable variable
+    pub const ALL: [WatchVariable; 16] = [
+        WatchVariable::Level1TargetPsi,
+        WatchVariable::TargetPsi,
+        WatchVariable::DutyPercent,
+        WatchVariable::DutyCeilingPercent,
+        WatchVariable::MapSource,
+        WatchVariable::MapZeroPsi,
+        WatchVariable::CanAuthority,
+        WatchVariable::ThermalDerate,
+        WatchVariable::ExhaustBackpressurePsi,
+        WatchVariable::BackpressureRatio,
+        WatchVariable::TurboShaftRpm,
+        WatchVariable::SoftCeiling,
+        WatchVariable::IdleVenting,
+        WatchVariable::CycleTimeUs,
+        WatchVariable::EngineLoadPercent,
+        WatchVariable::BoostTrimPsi,
+    ];
+    
+    /// Name clients register the variable by
+    pub fn name(&self) -> &'static str {
+        match self {
+            WatchVariable::Level1TargetPsi => "level1_target",
+            WatchVariable::TargetPsi => "target",
+            WatchVariable::DutyPercent => "duty",
+            WatchVariable::DutyCeilingPercent => "duty_ceiling",
+            WatchVariable::MapSource => "map_source",
+            WatchVariable::MapZeroPsi => "map_zero",
+            WatchVariable::CanAuthority => "can_authority",
+            WatchVariable::ThermalDerate => "thermal_derate",
+            WatchVariable::ExhaustBackpressurePsi => "backpressure",
+            WatchVariable::BackpressureRatio => "backpressure_ratio",
+            WatchVariable::TurboShaftRpm => "turbo_rpm",
+            WatchVariable::SoftCeiling => "soft_ceiling",
+            WatchVariable::IdleVenting => "idle_venting",
+            WatchVariable::CycleTimeUs => "cycle_time",
+            WatchVariable::EngineLoadPercent => "engine_load",
+            WatchVariable::BoostTrimPsi => "boost_trim",
+        }
+    }
+    
+    /// Look a variable up by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|variable| variable.name() == name)
+    }
+}
+
+/// Current value of a watched variable
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WatchValue {
+    Number(f32),
+    Flag(bool),
+    Source(InputSource),
+    /// Nothing to report yet (e.g. not learned)
+    Unavailable,
+}
+
+impl fmt::Display for WatchValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchValue::Number(value) => write!(f, "{:.3}", value),
+            WatchValue::Flag(value) => write!(f, "{}", value),
+            WatchValue::Source(source) => write!(f, "{:?}", source),
+            WatchValue::Unavailable => write!(f, "-"),
+        }
+    }
+}
+
+/// One watched variable's value on a telemetry frame
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WatchReading {
+    pub variable: WatchVariable,
+    pub value: WatchValue,
+}
+
+/// Variables one client has registered
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WatchList {
+    variables: Vec<WatchVariable>,
+}
+
+impl WatchList {
+    /// Create with nothing watched
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Replace the watched variables; an empty list stops watching
+    /// 
+    /// Repeats are dropped, keeping the first occurrence's position.
+    pub fn set(&mut self, variables: &[WatchVariable]) -> Result<(), CoreError> {
+        let mut unique: Vec<WatchVariable> = Vec::new();
+        for variable in variables {
+            if !unique.contains(variable) {
+                unique.push(*variable);
+            }
+        }
+        
+        if unique.len() > MAX_WATCHES {
+            return Err(CoreError::ConfigurationError(
+                format!("At most {} variables can be watched, got {}", MAX_WATCHES, unique.len())
+            ));
+        }
+        
+        self.variables = unique;
+        Ok(())
+    }
+    
+    /// Watched variables in registration order
+    pub fn variables(&self) -> &[WatchVariable] {
+        &self.variables
+    }
+    
+    /// Whether anything is watched
+    pub fn is_empty(&self) -> bool {
+        self.variables.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_names_round_trip() {
+        for variable in WatchVariable::ALL {
+            assert_eq!(WatchVariable::from_name(variable.name()), Some(variable));
+        }
+        assert_eq!(WatchVariable::from_name("pid_integral"), None);
+    }
+    
+    #[test]
+    fn test_watch_list_limits_and_dedupes() {
+        let mut list = WatchList::new();
+        list.set(&[WatchVariable::DutyPercent, WatchVariable::TargetPsi, WatchVariable::DutyPercent]).unwrap();
+        assert_eq!(list.variables(), &[WatchVariable::DutyPercent, WatchVariable::TargetPsi]);
+        
+        // Too many is refused and the previous list stays
+        assert!(list.set(&WatchVariable::ALL).is_err());
+        assert_eq!(list.variables().len(), 2);
+        
+        list.set(&[]).unwrap();
+        assert!(list.is_empty());
+    }
+}
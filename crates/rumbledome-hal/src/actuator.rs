@@ -0,0 +1,31 @@
+//! Electronic Wastegate Actuator Interface
+//! 
+//! 🔗 T4-HAL-013: Electronic Actuator Output
+//! Derived From: T2-HAL-001 (Platform-Independent Hardware Abstraction Design) + electronic wastegate installations
+//! AI Traceability: Position-commanded wastegate actuators as an alternative to pneumatic solenoid control
+
+use serde::{Deserialize, Serialize};
+
+use crate::HalResult;
+
+/// Electronic actuator connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActuatorInterface {
+    /// Motor driven directly by the controller (H-bridge DC motor with position feedback, or stepper)
+    MotorDriver,
+    /// Actuator with its own position control, commanded over CAN
+    Can,
+}
+
+/// Electronic wastegate actuator output
+/// 
+/// Positions are wastegate closure: 0% = fully open (failsafe state),
+/// 100% = fully closed (maximum boost authority). Platforms without an
+/// interface return `HalError::NotSupported`.
+pub trait ActuatorOutput {
+    /// Command actuator position (0.0-100.0 %)
+    fn set_actuator_position(&mut self, interface: ActuatorInterface, position_percent: f32) -> HalResult<()>;
+    
+    /// Last position reported by the actuator (0.0-100.0 %)
+    fn get_actuator_position(&self, interface: ActuatorInterface) -> HalResult<f32>;
+}
@@ -0,0 +1,169 @@
+//! Analog Input Interface
+//! 
+//! 🔗 T4-HAL-015: Pressure Sensor Analog Inputs
+//! Derived From: T2-HAL-006 (Pressure Sensor Specifications and Calibration) + Hardware.md Teensy 4.1 ADC interface
+//! AI Traceability: Core reads calibrated pressures; ADC counts, divider scaling and sensor curves stay in the HAL
+//! 
+//! The 0.5-4.5V sensors feed the 3.3V ADC through a divider. Voltages here
+//! are at the sensor output (divider already undone), so calibrations carry
+//! the sensor's own datasheet numbers. A voltage outside the sensor's valid
+//! window means an open or shorted sensor and is reported as a hardware
+//! fault rather than converted to a pressure.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+#[cfg(feature = "std")]
+use std::format;
+
+use crate::{HalError, HalResult};
+
+/// Number of analog input channels
+pub const ANALOG_CHANNELS: usize = 8;
+
+/// Highest ADC reading (12-bit)
+pub const ADC_FULL_SCALE_COUNTS: u16 = 4095;
+
+/// ADC reference voltage (V)
+pub const ADC_REFERENCE_VOLTAGE: f32 = 3.3;
+
+/// Divider ratio between sensor output and ADC input (5V to 3.3V)
+pub const SENSOR_DIVIDER_RATIO: f32 = 0.66;
+
+/// PSI per kPa
+pub const PSI_PER_KPA: f32 = 0.145_038;
+
+/// Sensor output voltage for an ADC reading (V)
+pub fn counts_to_sensor_voltage(counts: u16) -> f32 {
+    counts as f32 * ADC_REFERENCE_VOLTAGE / ADC_FULL_SCALE_COUNTS as f32 / SENSOR_DIVIDER_RATIO
+}
+
+/// ADC reading for a sensor output voltage (saturates at the ADC limits)
+pub fn sensor_voltage_to_counts(volts: f32) -> u16 {
+    let counts = volts * SENSOR_DIVIDER_RATIO / ADC_REFERENCE_VOLTAGE * ADC_FULL_SCALE_COUNTS as f32;
+    // no_std has no f32::round - adding 0.5 before truncating rounds the non-negative range
+    (counts + 0.5).clamp(0.0, ADC_FULL_SCALE_COUNTS as f32) as u16
+}
+
+/// Linear voltage-to-pressure curve for one sensor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PressureCalibration {
+    /// Sensor output at 0 PSI (V)
+    pub zero_voltage: f32,
+    /// Sensor output at full scale (V)
+    pub full_scale_voltage: f32,
+    /// Pressure at full scale (PSI)
+    pub full_scale_psi: f32,
+    /// Lowest plausible output - below is an open or shorted sensor (V)
+    pub min_valid_voltage: f32,
+    /// Highest plausible output - above is a shorted sensor (V)
+    pub max_valid_voltage: f32,
+}
+
+impl Default for PressureCalibration {
+    /// 0-30 PSI gauge sensor, 0.5-4.5V ratiometric (Hardware.md)
+    fn default() -> Self {
+        Self {
+            zero_voltage: 0.5,
+            full_scale_voltage: 4.5,
+            full_scale_psi: 30.0,
+            min_valid_voltage: 0.3,
+            max_valid_voltage: 4.7,
+        }
+    }
+}
+
+impl PressureCalibration {
+    /// Check the curve is usable
+    pub fn validate(&self) -> HalResult<()> {
+        if self.full_scale_voltage <= self.zero_voltage || self.full_scale_psi <= 0.0 {
+            return Err(HalError::InvalidParameter(format!(
+                "Calibration needs full scale above zero ({:.2}V at 0 PSI, {:.2}V at {:.1} PSI)",
+                self.zero_voltage, self.full_scale_voltage, self.full_scale_psi
+            )));
+        }
+        if self.min_valid_voltage >= self.max_valid_voltage {
+            return Err(HalError::InvalidParameter(format!(
+                "Calibration valid window {:.2}-{:.2}V is empty", self.min_valid_voltage, self.max_valid_voltage
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Pressure for a sensor output voltage (PSI)
+    pub fn voltage_to_psi(&self, volts: f32) -> HalResult<f32> {
+        if volts < self.min_valid_voltage || volts > self.max_valid_voltage {
+            return Err(HalError::HardwareFault(format!(
+                "Pressure sensor output {:.2}V outside {:.2}-{:.2}V", volts, self.min_valid_voltage, self.max_valid_voltage
+            )));
+        }
+        Ok((volts - self.zero_voltage) * self.psi_per_volt())
+    }
+    
+    /// Sensor output voltage for a pressure (V)
+    pub fn psi_to_voltage(&self, psi: f32) -> f32 {
+        self.zero_voltage + psi / self.psi_per_volt()
+    }
+    
+    fn psi_per_volt(&self) -> f32 {
+        self.full_scale_psi / (self.full_scale_voltage - self.zero_voltage)
+    }
+}
+
+/// Analog pressure sensor inputs
+/// 
+/// Channels are numbered in physical connector order; which sensor sits on
+/// which channel is the core's business (sensor channel map).
+pub trait AnalogInput {
+    /// Raw ADC reading (counts, 0-`ADC_FULL_SCALE_COUNTS`)
+    fn read_raw(&self, channel: u8) -> HalResult<u16>;
+    
+    /// Sensor output voltage (V)
+    fn read_voltage(&self, channel: u8) -> HalResult<f32> {
+        Ok(counts_to_sensor_voltage(self.read_raw(channel)?))
+    }
+    
+    /// Calibrated pressure (PSI gauge)
+    /// 
+    /// Fails with `HalError::HardwareFault` when the sensor output is outside
+    /// its valid window.
+    fn read_pressure_psi(&self, channel: u8) -> HalResult<f32> {
+        self.calibration(channel)?.voltage_to_psi(self.read_voltage(channel)?)
+    }
+    
+    /// Calibration curve in use on a channel
+    fn calibration(&self, channel: u8) -> HalResult<PressureCalibration>;
+    
+    /// Replace a channel's calibration curve
+    fn set_calibration(&mut self, channel: u8, calibration: PressureCalibration) -> HalResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_default_curve_matches_sensor_datasheet() {
+        let calibration = PressureCalibration::default();
+        assert_eq!(calibration.voltage_to_psi(0.5).unwrap(), 0.0);
+        assert_eq!(calibration.voltage_to_psi(2.5).unwrap(), 15.0);
+        assert_eq!(calibration.voltage_to_psi(4.5).unwrap(), 30.0);
+        assert_eq!(calibration.psi_to_voltage(15.0), 2.5);
+    }
+    
+    #[test]
+    fn test_open_or_shorted_sensor_is_a_fault() {
+        let calibration = PressureCalibration::default();
+        assert!(matches!(calibration.voltage_to_psi(0.05), Err(HalError::HardwareFault(_))));
+        assert!(matches!(calibration.voltage_to_psi(4.95), Err(HalError::HardwareFault(_))));
+    }
+    
+    #[test]
+    fn test_adc_counts_round_trip_within_one_count() {
+        let volts = counts_to_sensor_voltage(sensor_voltage_to_counts(2.5));
+        assert!((volts - 2.5).abs() < counts_to_sensor_voltage(1));
+        assert_eq!(sensor_voltage_to_counts(10.0), ADC_FULL_SCALE_COUNTS);
+    }
+}
@@ -0,0 +1,261 @@
+//! CAN Bus Interface
+//! 
+//! 🔗 T4-HAL-016: CAN Bus Interface and Coyote Frame Parsing
+//! Derived From: T2-HAL-005 (Ford S550 CAN Signal Integration) + CAN_Signals.md (T2-CAN-001, T2-CAN-002, T2-CAN-004)
+//! AI Traceability: Core gets engine speed and torque from the ECU broadcast; the stock platform's frame layouts stay in the HAL
+//! 
+//! The controller only listens to the ECU broadcast - it never requests data
+//! and never sends on the powertrain bus during normal operation. Frames are
+//! received through acceptance filters so only the messages the core decodes
+//! take up receive buffer space.
+//! 
+//! Gen2 (2015-2017) and Gen3 (2018+) Coyote ECUs broadcast engine speed and
+//! torque in the same frames. Which torque broadcast is desired and which is
+//! actual torque is still to be confirmed on a car (CAN_Signals.md T2-CAN-005);
+//! until then 0x167 is taken as the ECU's desired torque and the 0x43E engine
+//! load as actual torque, scaled by the generation's rated torque. A CAN
+//! signal map in the configuration overrides either.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+#[cfg(feature = "std")]
+use std::format;
+
+use crate::{HalError, HalResult};
+
+/// Classic CAN payload size (bytes)
+pub const CAN_MAX_PAYLOAD: usize = 8;
+
+/// Highest standard (11-bit) CAN identifier
+pub const MAX_STANDARD_CAN_ID: u32 = 0x7FF;
+
+/// Highest extended (29-bit) CAN identifier
+pub const MAX_EXTENDED_CAN_ID: u32 = 0x1FFF_FFFF;
+
+/// Engine speed broadcast (HS3)
+pub const COYOTE_RPM_ID: u32 = 0x109;
+
+/// Engine torque and MAP broadcast (HS1 and HS3)
+pub const COYOTE_TORQUE_ID: u32 = 0x167;
+
+/// Engine load broadcast (HS1 and HS3)
+pub const COYOTE_LOAD_ID: u32 = 0x43E;
+
+/// One classic CAN frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanFrame {
+    /// Identifier (11-bit standard or 29-bit extended)
+    pub id: u32,
+    /// Extended (29-bit) identifier
+    pub extended: bool,
+    /// Payload length (0-8 bytes)
+    pub len: u8,
+    /// Payload, zero past `len`
+    pub data: [u8; CAN_MAX_PAYLOAD],
+}
+
+impl CanFrame {
+    /// Frame with a payload; identifiers above 0x7FF are sent extended
+    pub fn new(id: u32, payload: &[u8]) -> HalResult<Self> {
+        if id > MAX_EXTENDED_CAN_ID {
+            return Err(HalError::InvalidParameter(format!("CAN identifier 0x{:X} is not a valid 29-bit identifier", id)));
+        }
+        if payload.len() > CAN_MAX_PAYLOAD {
+            return Err(HalError::InvalidParameter(format!("CAN payload of {} bytes exceeds {}", payload.len(), CAN_MAX_PAYLOAD)));
+        }
+        let mut data = [0; CAN_MAX_PAYLOAD];
+        data[..payload.len()].copy_from_slice(payload);
+        Ok(Self { id, extended: id > MAX_STANDARD_CAN_ID, len: payload.len() as u8, data })
+    }
+    
+    /// Payload bytes actually carried
+    pub fn payload(&self) -> &[u8] {
+        &self.data[..(self.len as usize).min(CAN_MAX_PAYLOAD)]
+    }
+}
+
+/// Acceptance filter - a frame passes when its identifier matches on every mask bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanFilter {
+    /// Identifier to match
+    pub id: u32,
+    /// Identifier bits compared (1 = must match)
+    pub mask: u32,
+}
+
+impl CanFilter {
+    /// Accept exactly one identifier
+    pub fn exact(id: u32) -> Self {
+        Self { id, mask: MAX_EXTENDED_CAN_ID }
+    }
+    
+    /// Whether a frame identifier passes the filter
+    pub fn matches(&self, id: u32) -> bool {
+        id & self.mask == self.id & self.mask
+    }
+}
+
+/// Controller counters since initialization
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanStats {
+    /// Frames accepted by the filters
+    pub frames_received: u32,
+    /// Frames transmitted
+    pub frames_sent: u32,
+    /// Frames lost because the receive buffer was full
+    pub rx_overruns: u32,
+    /// Error frames seen on the bus
+    pub error_frames: u32,
+    /// Times the controller went bus-off and recovered
+    pub bus_off_count: u32,
+}
+
+/// CAN bus controller
+pub trait CanInterface {
+    /// Set the bus bitrate (bit/s)
+    fn set_bitrate(&mut self, bitrate_bps: u32) -> HalResult<()>;
+    
+    /// Queue a frame for transmission
+    fn send_frame(&mut self, frame: &CanFrame) -> HalResult<()>;
+    
+    /// Oldest received frame, or `None` when the receive buffer is empty
+    fn receive_frame(&mut self) -> HalResult<Option<CanFrame>>;
+    
+    /// Replace the acceptance filters (an empty list accepts every frame)
+    fn set_filters(&mut self, filters: &[CanFilter]) -> HalResult<()>;
+    
+    /// Controller counters
+    fn can_stats(&self) -> CanStats;
+}
+
+/// Coyote engine generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CoyoteGeneration {
+    /// 2015-2017 Mustang GT
+    #[default]
+    Gen2,
+    /// 2018+ Mustang GT
+    Gen3,
+}
+
+impl CoyoteGeneration {
+    /// Stock rated torque - full engine load (Nm)
+    pub fn rated_torque_nm(&self) -> f32 {
+        match self {
+            // 400 lb-ft
+            CoyoteGeneration::Gen2 => 542.0,
+            // 420 lb-ft
+            CoyoteGeneration::Gen3 => 569.0,
+        }
+    }
+}
+
+/// Values carried by one Coyote broadcast frame
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoyoteSignals {
+    /// Engine speed (RPM)
+    pub rpm: Option<u16>,
+    /// ECU desired torque (Nm)
+    pub desired_torque_nm: Option<f32>,
+    /// Actual engine torque (Nm)
+    pub actual_torque_nm: Option<f32>,
+}
+
+/// Decodes the Coyote engine speed and torque broadcast
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoyoteCanParser {
+    generation: CoyoteGeneration,
+}
+
+impl CoyoteCanParser {
+    /// Messages the parser decodes
+    pub const MESSAGE_IDS: [u32; 3] = [COYOTE_RPM_ID, COYOTE_TORQUE_ID, COYOTE_LOAD_ID];
+    
+    pub fn new(generation: CoyoteGeneration) -> Self {
+        Self { generation }
+    }
+    
+    /// Acceptance filters for the decoded messages
+    pub fn filters() -> [CanFilter; 3] {
+        Self::MESSAGE_IDS.map(CanFilter::exact)
+    }
+    
+    /// Decode a frame; other messages and short payloads decode to nothing
+    pub fn parse(&self, frame: &CanFrame) -> CoyoteSignals {
+        let payload = frame.payload();
+        let mut signals = CoyoteSignals::default();
+        match frame.id {
+            COYOTE_RPM_ID if payload.len() >= 2 => {
+                // (b0<<8 + b1) / 4
+                signals.rpm = Some(u16::from_be_bytes([payload[0], payload[1]]) / 4);
+            },
+            COYOTE_TORQUE_ID if payload.len() >= 3 => {
+                // ((b1-128)<<8 + b2) / 4
+                let raw = ((payload[1] as i32 - 128) << 8) + payload[2] as i32;
+                signals.desired_torque_nm = Some(raw as f32 / 4.0);
+            },
+            COYOTE_LOAD_ID if payload.len() >= 7 => {
+                // (b5<<8 + b6) / 72 - 140
+                let load_percent = u16::from_be_bytes([payload[5], payload[6]]) as f32 / 72.0 - 140.0;
+                signals.actual_torque_nm = Some(load_percent / 100.0 * self.generation.rated_torque_nm());
+            },
+            _ => {},
+        }
+        signals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn frame(id: u32, payload: &[u8]) -> CanFrame {
+        CanFrame::new(id, payload).unwrap()
+    }
+    
+    #[test]
+    fn test_decodes_coyote_broadcast() {
+        let parser = CoyoteCanParser::new(CoyoteGeneration::Gen2);
+        
+        // 4000 RPM = 16000 raw
+        let rpm = parser.parse(&frame(COYOTE_RPM_ID, &[0x3E, 0x80, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(rpm, CoyoteSignals { rpm: Some(4000), ..Default::default() });
+        
+        // 400 Nm = 1600 raw, offset 128 in the high byte
+        let torque = parser.parse(&frame(COYOTE_TORQUE_ID, &[0, 128 + 6, 0x40, 0, 0, 0, 0, 0]));
+        assert_eq!(torque.desired_torque_nm, Some(400.0));
+        let braking = parser.parse(&frame(COYOTE_TORQUE_ID, &[0, 127, 0xD8, 0, 0, 0, 0, 0]));
+        assert_eq!(braking.desired_torque_nm, Some(-10.0));
+        
+        // 50% load = (50 + 140) * 72 = 13680 raw
+        let load = parser.parse(&frame(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]));
+        assert!((load.actual_torque_nm.unwrap() - 271.0).abs() < 0.01);
+        let gen3 = CoyoteCanParser::new(CoyoteGeneration::Gen3).parse(&frame(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35, 0x70, 0]));
+        assert!(gen3.actual_torque_nm.unwrap() > load.actual_torque_nm.unwrap());
+    }
+    
+    #[test]
+    fn test_short_and_unrelated_frames_ignored() {
+        let parser = CoyoteCanParser::new(CoyoteGeneration::Gen2);
+        assert_eq!(parser.parse(&frame(COYOTE_LOAD_ID, &[0, 0, 0, 0, 0, 0x35])), CoyoteSignals::default());
+        assert_eq!(parser.parse(&frame(0x204, &[0xFF; 8])), CoyoteSignals::default());
+    }
+    
+    #[test]
+    fn test_frames_and_filters() {
+        assert!(!frame(COYOTE_RPM_ID, &[1, 2]).extended);
+        assert!(frame(0x18DA_F110, &[]).extended);
+        assert_eq!(frame(COYOTE_RPM_ID, &[1, 2]).payload(), &[1, 2]);
+        assert!(CanFrame::new(0x2000_0000, &[]).is_err());
+        assert!(CanFrame::new(COYOTE_RPM_ID, &[0; 9]).is_err());
+        
+        let filters = CoyoteCanParser::filters();
+        assert!(filters.iter().any(|filter| filter.matches(COYOTE_TORQUE_ID)));
+        assert!(!filters.iter().any(|filter| filter.matches(0x204)));
+        assert!(CanFilter { id: 0x100, mask: 0x700 }.matches(0x1FF));
+    }
+}
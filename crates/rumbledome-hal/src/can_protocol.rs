@@ -0,0 +1,495 @@
+//! Vehicle CAN Protocols
+//! 
+//! 🔗 T4-HAL-019: Pluggable Vehicle CAN Protocols
+//! Derived From: T4-HAL-016 (CAN Bus Interface and Coyote Frame Parsing) + CAN_Signals.md platform support
+//! AI Traceability: Engine speed and torque come from whatever the car broadcasts - the core sees the same signals on every platform
+//! 
+//! Each supported platform has a decoder turning its engine frames into
+//! engine speed, ECU desired and actual torque and, where the platform has
+//! it, manifold pressure. Platforms that broadcast no usable torque pair
+//! report so, and the configuration has to fall back to MAP-only
+//! (boost-by-target) control unless the torque signals are mapped by hand.
+//! 
+//! Broadcast layouts other than the Coyote are taken from community bus
+//! captures and are still to be confirmed on a car; a CAN signal map in the
+//! configuration overrides any of them.
+//! 
+//! - **GM E38 / E92** (Gen IV / Gen V small block, GMLAN high-speed bus):
+//!   0x0C9 engine speed, 0x1C3 actual and driver-requested torque
+//! - **Mopar** (CAN C): 0x322 engine speed only
+//! - **OBD-II**: any car with a diagnostic port. Unlike every other protocol
+//!   this one transmits - it polls the standard PIDs for engine speed, driver
+//!   demand and actual torque (percent of reference torque), MAP and
+//!   calculated load, each at its configured rate. One request is in flight
+//!   at a time; the next goes out when it is answered or has timed out.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{boxed::Box, vec, vec::Vec};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CanFilter, CanFrame, CoyoteCanParser, CoyoteGeneration, PSI_PER_KPA};
+
+/// GM engine general status (engine speed)
+pub const GM_ENGINE_STATUS_ID: u32 = 0x0C9;
+
+/// GM engine torque status (actual and driver-requested torque)
+pub const GM_TORQUE_STATUS_ID: u32 = 0x1C3;
+
+/// Mopar engine speed and vehicle speed
+pub const MOPAR_ENGINE_SPEED_ID: u32 = 0x322;
+
+/// OBD-II functional (broadcast) request
+pub const OBD_REQUEST_ID: u32 = 0x7DF;
+
+/// First OBD-II ECU response identifier (0x7E8-0x7EF)
+pub const OBD_RESPONSE_BASE_ID: u32 = 0x7E8;
+
+/// Shortest time between OBD-II requests (ms)
+pub const OBD_POLL_INTERVAL_MS: u32 = 20;
+
+/// Time an ECU has to answer a request before the next one goes out (ms)
+pub const OBD_RESPONSE_TIMEOUT_MS: u32 = 100;
+
+/// Poll interval of slowly changing PIDs - reference torque, barometric pressure (ms)
+pub const OBD_SLOW_POLL_INTERVAL_MS: u32 = 5_000;
+
+/// OBD-II mode 01 PIDs
+pub const OBD_PID_ENGINE_LOAD: u8 = 0x04;
+pub const OBD_PID_MAP: u8 = 0x0B;
+pub const OBD_PID_RPM: u8 = 0x0C;
+pub const OBD_PID_BARO: u8 = 0x33;
+pub const OBD_PID_DEMAND_TORQUE: u8 = 0x61;
+pub const OBD_PID_ACTUAL_TORQUE: u8 = 0x62;
+pub const OBD_PID_REFERENCE_TORQUE: u8 = 0x63;
+
+/// Polled PIDs; on equal lateness the earlier one goes first (reference torque before any torque)
+const OBD_POLLED_PIDS: [u8; 7] = [
+    OBD_PID_REFERENCE_TORQUE, OBD_PID_RPM, OBD_PID_DEMAND_TORQUE, OBD_PID_ACTUAL_TORQUE, OBD_PID_MAP, OBD_PID_ENGINE_LOAD, OBD_PID_BARO,
+];
+
+/// Standard atmosphere, assumed until the ECU reports barometric pressure (kPa)
+const STANDARD_BARO_KPA: f32 = 101.3;
+
+/// Platform engine data broadcast
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VehicleProtocol {
+    /// Ford Coyote (S550 Mustang) powertrain broadcast
+    #[default]
+    FordCoyote,
+    /// GM E38 ECM (Gen IV small block)
+    GmE38,
+    /// GM E92 ECM (Gen V small block)
+    GmE92,
+    /// Chrysler/Dodge/Jeep CAN C
+    Mopar,
+    /// Generic OBD-II PID polling
+    Obd2,
+}
+
+impl VehicleProtocol {
+    /// Whether the platform reports both ECU desired and actual torque
+    pub fn provides_torque(&self) -> bool {
+        !matches!(self, VehicleProtocol::Mopar)
+    }
+    
+    /// Name for reports
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            VehicleProtocol::FordCoyote => "Ford Coyote",
+            VehicleProtocol::GmE38 => "GM E38",
+            VehicleProtocol::GmE92 => "GM E92",
+            VehicleProtocol::Mopar => "Mopar",
+            VehicleProtocol::Obd2 => "OBD-II",
+        }
+    }
+}
+
+/// OBD-II poll interval of each value (ms, 0 = not polled)
+/// 
+/// Requests are at least `OBD_POLL_INTERVAL_MS` apart and wait for the
+/// previous answer, so rates adding up to more than the ECU answers stretch
+/// every interval - the most overdue PID always goes next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct ObdPollRates {
+    /// Engine speed
+    pub rpm_interval_ms: u32,
+    /// Manifold absolute pressure
+    pub map_interval_ms: u32,
+    /// Calculated engine load
+    pub load_interval_ms: u32,
+    /// Driver demand and actual torque (each)
+    pub torque_interval_ms: u32,
+}
+
+impl Default for ObdPollRates {
+    fn default() -> Self {
+        Self {
+            rpm_interval_ms: 50,
+            map_interval_ms: 100,
+            load_interval_ms: 250,
+            torque_interval_ms: 100,
+        }
+    }
+}
+
+impl ObdPollRates {
+    /// Interval of a polled PID (ms, 0 = not polled)
+    pub fn interval_ms(&self, pid: u8) -> u32 {
+        match pid {
+            OBD_PID_RPM => self.rpm_interval_ms,
+            OBD_PID_MAP => self.map_interval_ms,
+            OBD_PID_ENGINE_LOAD => self.load_interval_ms,
+            OBD_PID_DEMAND_TORQUE | OBD_PID_ACTUAL_TORQUE => self.torque_interval_ms,
+            // Only worth reading when the values they convert are polled
+            OBD_PID_REFERENCE_TORQUE if self.torque_interval_ms > 0 => OBD_SLOW_POLL_INTERVAL_MS,
+            OBD_PID_BARO if self.map_interval_ms > 0 => OBD_SLOW_POLL_INTERVAL_MS,
+            _ => 0,
+        }
+    }
+}
+
+/// Engine values carried by one frame
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VehicleSignals {
+    /// Engine speed (RPM)
+    pub rpm: Option<u16>,
+    /// ECU desired torque (Nm)
+    pub desired_torque_nm: Option<f32>,
+    /// Actual engine torque (Nm)
+    pub actual_torque_nm: Option<f32>,
+    /// Manifold pressure (PSI gauge)
+    pub manifold_pressure_psi: Option<f32>,
+    /// Calculated engine load (0-100 %)
+    pub engine_load_pct: Option<f32>,
+}
+
+/// Decoder for one platform's engine frames
+pub trait VehicleCanProtocol: core::fmt::Debug {
+    /// Acceptance filters for the frames the decoder reads
+    fn filters(&self) -> Vec<CanFilter>;
+    
+    /// Decode a frame; other messages and short payloads decode to nothing
+    fn parse(&mut self, frame: &CanFrame) -> VehicleSignals;
+    
+    /// Whether the platform reports both ECU desired and actual torque
+    fn provides_torque(&self) -> bool;
+    
+    /// Frame to transmit now, for protocols that have to ask for their data
+    fn poll_request(&mut self, _now_ms: u32) -> Option<CanFrame> {
+        None
+    }
+}
+
+/// Decoder for a configured platform
+pub fn vehicle_can_protocol(protocol: VehicleProtocol, coyote_generation: CoyoteGeneration, obd_rates: ObdPollRates) -> Box<dyn VehicleCanProtocol> {
+    match protocol {
+        VehicleProtocol::FordCoyote => Box::new(CoyoteCanParser::new(coyote_generation)),
+        VehicleProtocol::GmE38 | VehicleProtocol::GmE92 => Box::new(GmCanParser),
+        VehicleProtocol::Mopar => Box::new(MoparCanParser),
+        VehicleProtocol::Obd2 => Box::new(Obd2Poller::new(obd_rates)),
+    }
+}
+
+impl VehicleCanProtocol for CoyoteCanParser {
+    fn filters(&self) -> Vec<CanFilter> {
+        CoyoteCanParser::filters().to_vec()
+    }
+    
+    fn parse(&mut self, frame: &CanFrame) -> VehicleSignals {
+        let signals = CoyoteCanParser::parse(self, frame);
+        VehicleSignals {
+            rpm: signals.rpm,
+            desired_torque_nm: signals.desired_torque_nm,
+            actual_torque_nm: signals.actual_torque_nm,
+            ..VehicleSignals::default()
+        }
+    }
+    
+    fn provides_torque(&self) -> bool {
+        true
+    }
+}
+
+/// GMLAN engine speed and torque broadcast (E38 and E92 share the layout)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GmCanParser;
+
+impl GmCanParser {
+    /// 12-bit torque field: raw × 0.5 - 848 Nm
+    fn torque_nm(high: u8, low: u8) -> f32 {
+        let raw = (((high & 0x0F) as u16) << 8) | low as u16;
+        raw as f32 * 0.5 - 848.0
+    }
+}
+
+impl VehicleCanProtocol for GmCanParser {
+    fn filters(&self) -> Vec<CanFilter> {
+        vec![CanFilter::exact(GM_ENGINE_STATUS_ID), CanFilter::exact(GM_TORQUE_STATUS_ID)]
+    }
+    
+    fn parse(&mut self, frame: &CanFrame) -> VehicleSignals {
+        let payload = frame.payload();
+        let mut signals = VehicleSignals::default();
+        match frame.id {
+            GM_ENGINE_STATUS_ID if payload.len() >= 3 => {
+                // (b1<<8 + b2) / 4
+                signals.rpm = Some(u16::from_be_bytes([payload[1], payload[2]]) / 4);
+            },
+            GM_TORQUE_STATUS_ID if payload.len() >= 4 => {
+                signals.actual_torque_nm = Some(Self::torque_nm(payload[0], payload[1]));
+                signals.desired_torque_nm = Some(Self::torque_nm(payload[2], payload[3]));
+            },
+            _ => {},
+        }
+        signals
+    }
+    
+    fn provides_torque(&self) -> bool {
+        true
+    }
+}
+
+/// Mopar CAN C engine speed (no torque broadcast identified)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MoparCanParser;
+
+impl VehicleCanProtocol for MoparCanParser {
+    fn filters(&self) -> Vec<CanFilter> {
+        vec![CanFilter::exact(MOPAR_ENGINE_SPEED_ID)]
+    }
+    
+    fn parse(&mut self, frame: &CanFrame) -> VehicleSignals {
+        let payload = frame.payload();
+        let mut signals = VehicleSignals::default();
+        if frame.id == MOPAR_ENGINE_SPEED_ID && payload.len() >= 2 {
+            // b0<<8 + b1, 1 RPM per bit; 0xFFFF while the engine is not running
+            let raw = u16::from_be_bytes([payload[0], payload[1]]);
+            signals.rpm = Some(if raw == u16::MAX { 0 } else { raw });
+        }
+        signals
+    }
+    
+    fn provides_torque(&self) -> bool {
+        false
+    }
+}
+
+/// OBD-II mode 01 PID poller
+/// 
+/// Torque PIDs are percentages of the engine reference torque, and MAP is
+/// absolute; both are converted once the reference torque and barometric
+/// pressure have been read.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Obd2Poller {
+    rates: ObdPollRates,
+    /// Next due time of each entry in `OBD_POLLED_PIDS` (`None` until first polled)
+    due_ms: [Option<u32>; OBD_POLLED_PIDS.len()],
+    in_flight: Option<(u8, u32)>,
+    last_request_ms: Option<u32>,
+    timeouts: u32,
+    reference_torque_nm: Option<f32>,
+    baro_kpa: Option<f32>,
+}
+
+impl Obd2Poller {
+    pub fn new(rates: ObdPollRates) -> Self {
+        Self { rates, ..Self::default() }
+    }
+    
+    /// Reference torque reported by the ECU (Nm)
+    pub fn reference_torque_nm(&self) -> Option<f32> {
+        self.reference_torque_nm
+    }
+    
+    /// Requests that went unanswered
+    pub fn timeouts(&self) -> u32 {
+        self.timeouts
+    }
+    
+    /// Most overdue PID, if any is due
+    fn next_pid(&self, now_ms: u32) -> Option<usize> {
+        let lateness = |index: usize| match self.due_ms[index] {
+            Some(due_ms) => now_ms.wrapping_sub(due_ms) as i32,
+            None => i32::MAX,
+        };
+        (0..OBD_POLLED_PIDS.len())
+            .filter(|&index| self.rates.interval_ms(OBD_POLLED_PIDS[index]) > 0 && lateness(index) >= 0)
+            .fold(None, |best: Option<usize>, index| match best {
+                Some(best) if lateness(best) >= lateness(index) => Some(best),
+                _ => Some(index),
+            })
+    }
+}
+
+impl VehicleCanProtocol for Obd2Poller {
+    fn filters(&self) -> Vec<CanFilter> {
+        // Any of the eight ECU response identifiers
+        vec![CanFilter { id: OBD_RESPONSE_BASE_ID, mask: 0x7F8 }]
+    }
+    
+    fn parse(&mut self, frame: &CanFrame) -> VehicleSignals {
+        let payload = frame.payload();
+        let mut signals = VehicleSignals::default();
+        // [length, 0x41 (mode 01 response), PID, A, B, ...]
+        if frame.id & 0x7F8 != OBD_RESPONSE_BASE_ID || payload.len() < 4 || payload[1] != 0x41 {
+            return signals;
+        }
+        if self.in_flight.is_some_and(|(pid, _)| pid == payload[2]) {
+            self.in_flight = None;
+        }
+        let a = payload[3];
+        let b = payload.get(4).copied().unwrap_or(0);
+        let percent_torque = |raw: u8| self.reference_torque_nm.map(|reference| (raw as f32 - 125.0) / 100.0 * reference);
+        match payload[2] {
+            OBD_PID_RPM if payload.len() >= 5 => signals.rpm = Some(u16::from_be_bytes([a, b]) / 4),
+            OBD_PID_DEMAND_TORQUE => signals.desired_torque_nm = percent_torque(a),
+            OBD_PID_ACTUAL_TORQUE => signals.actual_torque_nm = percent_torque(a),
+            OBD_PID_MAP => {
+                let baro_kpa = self.baro_kpa.unwrap_or(STANDARD_BARO_KPA);
+                signals.manifold_pressure_psi = Some((a as f32 - baro_kpa) * PSI_PER_KPA);
+            },
+            OBD_PID_ENGINE_LOAD => signals.engine_load_pct = Some(a as f32 * 100.0 / 255.0),
+            OBD_PID_REFERENCE_TORQUE if payload.len() >= 5 => {
+                let reference = u16::from_be_bytes([a, b]);
+                if reference > 0 {
+                    self.reference_torque_nm = Some(reference as f32);
+                }
+            },
+            OBD_PID_BARO => self.baro_kpa = Some(a as f32),
+            _ => {},
+        }
+        signals
+    }
+    
+    fn provides_torque(&self) -> bool {
+        self.rates.torque_interval_ms > 0
+    }
+    
+    fn poll_request(&mut self, now_ms: u32) -> Option<CanFrame> {
+        if let Some((_, sent_ms)) = self.in_flight {
+            if now_ms.wrapping_sub(sent_ms) < OBD_RESPONSE_TIMEOUT_MS {
+                return None;
+            }
+            self.in_flight = None;
+            self.timeouts = self.timeouts.saturating_add(1);
+        }
+        if self.last_request_ms.is_some_and(|last_ms| now_ms.wrapping_sub(last_ms) < OBD_POLL_INTERVAL_MS) {
+            return None;
+        }
+        
+        let index = self.next_pid(now_ms)?;
+        let pid = OBD_POLLED_PIDS[index];
+        let interval_ms = self.rates.interval_ms(pid);
+        // Keep the cadence through a late slot; restart it after falling a whole interval behind
+        self.due_ms[index] = Some(match self.due_ms[index] {
+            Some(due_ms) if now_ms.wrapping_sub(due_ms) < interval_ms => due_ms.wrapping_add(interval_ms),
+            _ => now_ms.wrapping_add(interval_ms),
+        });
+        self.in_flight = Some((pid, now_ms));
+        self.last_request_ms = Some(now_ms);
+        CanFrame::new(OBD_REQUEST_ID, &[0x02, 0x01, pid, 0, 0, 0, 0, 0]).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{COYOTE_RPM_ID, COYOTE_TORQUE_ID};
+    
+    fn frame(id: u32, payload: &[u8]) -> CanFrame {
+        CanFrame::new(id, payload).unwrap()
+    }
+    
+    #[test]
+    fn test_coyote_through_protocol_trait() {
+        let mut coyote = vehicle_can_protocol(VehicleProtocol::FordCoyote, CoyoteGeneration::Gen2, ObdPollRates::default());
+        assert_eq!(coyote.filters().len(), 3);
+        assert_eq!(coyote.parse(&frame(COYOTE_RPM_ID, &[0x3E, 0x80])).rpm, Some(4000));
+        assert_eq!(coyote.parse(&frame(COYOTE_TORQUE_ID, &[0, 134, 0x40])).desired_torque_nm, Some(400.0));
+        assert!(coyote.poll_request(0).is_none());
+    }
+    
+    #[test]
+    fn test_gm_and_mopar_broadcasts() {
+        let mut gm = vehicle_can_protocol(VehicleProtocol::GmE92, CoyoteGeneration::Gen2, ObdPollRates::default());
+        assert_eq!(gm.parse(&frame(GM_ENGINE_STATUS_ID, &[0, 0x5D, 0xC0, 0, 0, 0, 0, 0])).rpm, Some(6000));
+        // 400 Nm actual = 2496 raw (0x9C0), 450 Nm requested = 2596 raw (0xA24)
+        let torque = gm.parse(&frame(GM_TORQUE_STATUS_ID, &[0x09, 0xC0, 0x0A, 0x24, 0, 0, 0, 0]));
+        assert_eq!(torque.actual_torque_nm, Some(400.0));
+        assert_eq!(torque.desired_torque_nm, Some(450.0));
+        assert!(gm.parse(&frame(GM_TORQUE_STATUS_ID, &[0x09, 0xC0])).actual_torque_nm.is_none());
+        
+        let mut mopar = vehicle_can_protocol(VehicleProtocol::Mopar, CoyoteGeneration::Gen2, ObdPollRates::default());
+        assert!(!mopar.provides_torque());
+        assert!(!VehicleProtocol::Mopar.provides_torque());
+        assert_eq!(mopar.parse(&frame(MOPAR_ENGINE_SPEED_ID, &[0x0F, 0xA0, 0, 0])).rpm, Some(4000));
+        assert_eq!(mopar.parse(&frame(MOPAR_ENGINE_SPEED_ID, &[0xFF, 0xFF, 0, 0])).rpm, Some(0));
+    }
+    
+    #[test]
+    fn test_obd2_polls_and_decodes_pids() {
+        let mut obd = Obd2Poller::new(ObdPollRates::default());
+        assert!(obd.filters()[0].matches(0x7E8) && obd.filters()[0].matches(0x7EF));
+        assert!(!obd.filters()[0].matches(0x7DF));
+        
+        // First request reads the reference torque; the next waits for its answer
+        let request = obd.poll_request(0).unwrap();
+        assert_eq!(request.id, OBD_REQUEST_ID);
+        assert_eq!(request.payload()[..3], [0x02, 0x01, OBD_PID_REFERENCE_TORQUE]);
+        assert!(obd.poll_request(OBD_POLL_INTERVAL_MS).is_none());
+        
+        // Torque needs the reference torque first
+        assert_eq!(obd.parse(&frame(0x7E8, &[0x03, 0x41, OBD_PID_ACTUAL_TORQUE, 175, 0, 0, 0, 0])).actual_torque_nm, None);
+        obd.parse(&frame(0x7E8, &[0x04, 0x41, OBD_PID_REFERENCE_TORQUE, 0x02, 0x58, 0, 0, 0]));
+        assert_eq!(obd.poll_request(OBD_POLL_INTERVAL_MS).unwrap().payload()[2], OBD_PID_RPM);
+        assert_eq!(obd.reference_torque_nm(), Some(600.0));
+        assert_eq!(obd.parse(&frame(0x7E8, &[0x03, 0x41, OBD_PID_ACTUAL_TORQUE, 175, 0, 0, 0, 0])).actual_torque_nm, Some(300.0));
+        assert_eq!(obd.parse(&frame(0x7E9, &[0x04, 0x41, OBD_PID_RPM, 0x3E, 0x80, 0, 0, 0])).rpm, Some(4000));
+        
+        // MAP is gauge against the reported barometric pressure
+        obd.parse(&frame(0x7E8, &[0x03, 0x41, OBD_PID_BARO, 100, 0, 0, 0, 0]));
+        let map = obd.parse(&frame(0x7E8, &[0x03, 0x41, OBD_PID_MAP, 200, 0, 0, 0, 0])).manifold_pressure_psi.unwrap();
+        assert!((map - 14.5038).abs() < 1e-3);
+        
+        let load = obd.parse(&frame(0x7E8, &[0x03, 0x41, OBD_PID_ENGINE_LOAD, 255, 0, 0, 0, 0])).engine_load_pct;
+        assert_eq!(load, Some(100.0));
+        
+        // Negative responses and other modes are ignored
+        assert_eq!(obd.parse(&frame(0x7E8, &[0x03, 0x7F, 0x01, 0x12, 0, 0, 0, 0])), VehicleSignals::default());
+    }
+    
+    #[test]
+    fn test_obd2_schedule_follows_configured_rates() {
+        let rates = ObdPollRates { load_interval_ms: 0, torque_interval_ms: 200, ..ObdPollRates::default() };
+        let mut obd = Obd2Poller::new(rates);
+        
+        // An ECU answering every request within a cycle
+        let mut counts = [0u32; 256];
+        for now_ms in (0..2_000).step_by(10) {
+            if let Some(request) = obd.poll_request(now_ms) {
+                let pid = request.payload()[2];
+                counts[pid as usize] += 1;
+                obd.parse(&frame(0x7E8, &[0x04, 0x41, pid, 0x02, 0x58, 0, 0, 0]));
+            }
+        }
+        assert!((38..=41).contains(&counts[OBD_PID_RPM as usize]), "{}", counts[OBD_PID_RPM as usize]);
+        assert!((19..=21).contains(&counts[OBD_PID_MAP as usize]));
+        assert!((9..=11).contains(&counts[OBD_PID_ACTUAL_TORQUE as usize]));
+        assert_eq!(counts[OBD_PID_ENGINE_LOAD as usize], 0);
+        assert_eq!(counts[OBD_PID_REFERENCE_TORQUE as usize], 1);
+        
+        // An unanswered request holds the next one back until it times out
+        let sent_ms = 3_000;
+        obd.poll_request(sent_ms).unwrap();
+        assert!(obd.poll_request(sent_ms + OBD_RESPONSE_TIMEOUT_MS - 10).is_none());
+        assert!(obd.poll_request(sent_ms + OBD_RESPONSE_TIMEOUT_MS).is_some());
+        assert_eq!(obd.timeouts(), 1);
+    }
+}
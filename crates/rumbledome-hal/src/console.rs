@@ -0,0 +1,70 @@
+//! Console Serial Ports
+//! 
+//! 🔗 T4-HAL-024: USB and Bluetooth Serial Console
+//! Derived From: T2-HAL-012 (Wireless CLI Console Access) + Protocols.md Communication Transport
+//! AI Traceability: The same framed protocol reaches the device over a USB cable or a Bluetooth SPP link
+//! 
+//! Both ports are byte streams - framing, sessions and privileges are the
+//! protocol layer's business. A platform only reports who is connected and
+//! moves bytes. Each new connection gets a new identifier, so the layer above
+//! can tell a reconnect from a connection that never dropped.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::HalResult;
+
+/// Transport a console client arrives on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsolePort {
+    /// USB serial - a cable into the controller
+    Usb,
+    /// Bluetooth SPP (Serial Port Profile)
+    Bluetooth,
+}
+
+impl ConsolePort {
+    /// Ports in session table order
+    pub const ALL: [ConsolePort; 2] = [ConsolePort::Usb, ConsolePort::Bluetooth];
+}
+
+/// Client connected to a console port
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsoleConnection {
+    /// Changes with every new connection on the port
+    pub id: u32,
+    /// Paired device name or address (Bluetooth only)
+    pub peer: Option<String>,
+    /// Received signal strength (dBm, Bluetooth only)
+    pub signal_strength_dbm: Option<i8>,
+    /// When the client connected (ms since power-up)
+    pub connected_ms: u32,
+    /// Bytes moved in both directions on this connection
+    pub bytes_transferred: u64,
+}
+
+/// USB serial and Bluetooth SPP byte streams
+/// 
+/// Platforms without a port report no connection on it and return
+/// `HalError::NotSupported` for its transfers.
+pub trait ConsoleSerial {
+    /// Start advertising the Bluetooth port under a name, with a pairing PIN
+    fn start_bluetooth(&mut self, name: &str, pin: &str) -> HalResult<()>;
+    
+    /// Stop advertising and drop any Bluetooth connection
+    fn stop_bluetooth(&mut self) -> HalResult<()>;
+    
+    /// Client connected to a port, if any
+    fn console_connection(&self, port: ConsolePort) -> Option<ConsoleConnection>;
+    
+    /// Move received bytes into `buffer`; returns how many (0 when none are waiting)
+    fn console_receive(&mut self, port: ConsolePort, buffer: &mut [u8]) -> HalResult<usize>;
+    
+    /// Queue bytes for transmission on a port
+    fn console_send(&mut self, port: ConsolePort, data: &[u8]) -> HalResult<()>;
+}
@@ -0,0 +1,169 @@
+//! Display Output
+//! 
+//! 🔗 T4-HAL-022: Display Interface
+//! Derived From: T2-HAL-001 (Platform-Independent Hardware Abstraction Design) + Hardware.md display requirements (ST7735R gauge pod)
+//! AI Traceability: One renderer draws the gauge on the device and in the simulator, so what is tested is what the driver sees
+//! 
+//! The trait is the ST7735R's own drawing model: rectangles of RGB565 pixels
+//! written into a window, then shown. `Framebuffer` keeps the pixels in
+//! memory instead - the desktop mock draws into one, and a platform that
+//! renders off-screen can blit one to its panel in a single transfer.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{vec, vec::Vec};
+
+use crate::{HalError, HalResult};
+
+/// Panel width in portrait orientation (pixels)
+pub const DISPLAY_WIDTH: u16 = 128;
+
+/// Panel height in portrait orientation (pixels)
+pub const DISPLAY_HEIGHT: u16 = 160;
+
+/// Gauge pod display
+/// 
+/// Colors are RGB565, the panel's native format. Drawing may be buffered
+/// until `flush`. Platforms without a display return `HalError::NotSupported`.
+pub trait DisplayInterface {
+    /// Panel size (width, height) in pixels
+    fn display_size(&self) -> (u16, u16);
+    
+    /// Fill a rectangle with one color (clipped to the panel)
+    fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) -> HalResult<()>;
+    
+    /// Write a `width`-wide block of pixels, row by row, starting at (x, y)
+    fn write_pixels(&mut self, x: u16, y: u16, width: u16, pixels: &[u16]) -> HalResult<()>;
+    
+    /// Show everything drawn since the last flush
+    fn flush(&mut self) -> HalResult<()>;
+    
+    /// Backlight brightness (0-100 %)
+    fn set_backlight(&mut self, percent: u8) -> HalResult<()>;
+}
+
+/// In-memory RGB565 framebuffer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Framebuffer {
+    width: u16,
+    height: u16,
+    pixels: Vec<u16>,
+    backlight_percent: u8,
+    frames: u32,
+}
+
+impl Framebuffer {
+    /// Black framebuffer of the given size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height, pixels: vec![0; width as usize * height as usize], backlight_percent: 100, frames: 0 }
+    }
+    
+    /// Pixel at (x, y), `None` outside the panel
+    pub fn pixel(&self, x: u16, y: u16) -> Option<u16> {
+        (x < self.width && y < self.height).then(|| self.pixels[y as usize * self.width as usize + x as usize])
+    }
+    
+    /// Every pixel, row by row
+    pub fn pixels(&self) -> &[u16] {
+        &self.pixels
+    }
+    
+    /// Backlight brightness last set (%)
+    pub fn backlight_percent(&self) -> u8 {
+        self.backlight_percent
+    }
+    
+    /// Number of flushes - frames the panel would have shown
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+}
+
+impl Default for Framebuffer {
+    fn default() -> Self {
+        Self::new(DISPLAY_WIDTH, DISPLAY_HEIGHT)
+    }
+}
+
+impl DisplayInterface for Framebuffer {
+    fn display_size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+    
+    fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) -> HalResult<()> {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+        for row in y.min(y_end)..y_end {
+            let start = row as usize * self.width as usize;
+            self.pixels[start + x.min(x_end) as usize..start + x_end as usize].fill(color);
+        }
+        Ok(())
+    }
+    
+    fn write_pixels(&mut self, x: u16, y: u16, width: u16, pixels: &[u16]) -> HalResult<()> {
+        if width == 0 || !pixels.len().is_multiple_of(width as usize) {
+            return Err(HalError::InvalidParameter("Pixel block is not a whole number of rows".into()));
+        }
+        for (row, line) in pixels.chunks(width as usize).enumerate() {
+            let py = y as usize + row;
+            for (column, &color) in line.iter().enumerate() {
+                let px = x as usize + column;
+                if px < self.width as usize && py < self.height as usize {
+                    self.pixels[py * self.width as usize + px] = color;
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    fn flush(&mut self) -> HalResult<()> {
+        self.frames = self.frames.wrapping_add(1);
+        Ok(())
+    }
+    
+    fn set_backlight(&mut self, percent: u8) -> HalResult<()> {
+        self.backlight_percent = percent.min(100);
+        Ok(())
+    }
+}
+
+/// RGB565 from 8-bit channels
+pub const fn rgb565(r: u8, g: u8, b: u8) -> u16 {
+    ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+}
+
+/// 8-bit channels from RGB565 (low bits filled so white stays white)
+pub const fn rgb888(color: u16) -> (u8, u8, u8) {
+    let r = ((color >> 11) & 0x1F) as u8;
+    let g = ((color >> 5) & 0x3F) as u8;
+    let b = (color & 0x1F) as u8;
+    ((r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_fill_and_write_are_clipped() {
+        let mut fb = Framebuffer::new(8, 4);
+        fb.fill_rect(6, 2, 10, 10, 0xFFFF).unwrap();
+        assert_eq!(fb.pixel(7, 3), Some(0xFFFF));
+        assert_eq!(fb.pixel(5, 3), Some(0));
+        assert_eq!(fb.pixel(8, 3), None);
+        
+        fb.write_pixels(7, 0, 2, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(fb.pixel(7, 0), Some(1));
+        assert_eq!(fb.pixel(7, 1), Some(3));
+        assert!(fb.write_pixels(0, 0, 3, &[1, 2]).is_err());
+    }
+    
+    #[test]
+    fn test_color_round_trip() {
+        assert_eq!(rgb565(0xFF, 0x00, 0x00), 0xF800);
+        assert_eq!(rgb888(0xFFFF), (0xFF, 0xFF, 0xFF));
+        assert_eq!(rgb888(0), (0, 0, 0));
+    }
+}
@@ -0,0 +1,242 @@
+//! Digital Inputs and Outputs
+//! 
+//! 🔗 T4-HAL-025: Button and Status LED GPIO
+//! Derived From: T2-HAL-001 (Platform-Independent Hardware Abstraction Design) + Hardware.md control inputs
+//! AI Traceability: Scramble and profile buttons are mechanical contacts - bounce must never read as a second press
+//! 
+//! Platforms report button levels already corrected for wiring polarity
+//! (`true` = pressed). Debouncing and press classification happen here,
+//! above the trait, so every platform produces the same events from the
+//! same contact behavior.
+
+use serde::{Deserialize, Serialize};
+
+use crate::HalResult;
+
+/// Contact level must be stable this long before it is accepted (ms)
+pub const BUTTON_DEBOUNCE_MS: u32 = 20;
+
+/// Press held at least this long is a long press (ms)
+pub const LONG_PRESS_MS: u32 = 800;
+
+/// Second press must start within this time of the first release to make a double press (ms)
+pub const DOUBLE_PRESS_GAP_MS: u32 = 300;
+
+/// Button inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GpioInput {
+    /// Scramble button (temporary full aggression while held)
+    ScrambleButton,
+    /// Profile select button
+    ProfileButton,
+}
+
+/// Digital outputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GpioOutput {
+    /// Status LED on the controller enclosure
+    StatusLed,
+}
+
+/// Button and LED pins
+/// 
+/// Platforms without a pin return `HalError::NotSupported`.
+pub trait GpioControl {
+    /// Raw (undebounced) button level, `true` = pressed
+    fn read_input(&self, input: GpioInput) -> HalResult<bool>;
+    
+    /// Drive an output, `true` = on
+    fn write_output(&mut self, output: GpioOutput, on: bool) -> HalResult<()>;
+}
+
+/// Classified button press
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ButtonEvent {
+    /// Pressed and released once, with no second press following
+    ShortPress,
+    /// Held for `LONG_PRESS_MS` (reported while still held)
+    LongPress,
+    /// Two short presses in quick succession
+    DoublePress,
+}
+
+/// Debounced button with press classification
+/// 
+/// Fed the raw level once per control cycle. A short press is reported only
+/// once the double-press gap has passed without a second press, so a double
+/// press never also produces a short press.
+#[derive(Debug, Clone, Default)]
+pub struct Button {
+    raw: bool,
+    raw_since_ms: u32,
+    pressed: bool,
+    pressed_at_ms: u32,
+    long_reported: bool,
+    released_at_ms: Option<u32>,
+}
+
+impl Button {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Follow the raw level; returns a press once it is classified
+    pub fn update(&mut self, raw: bool, now_ms: u32) -> Option<ButtonEvent> {
+        if raw != self.raw {
+            self.raw = raw;
+            self.raw_since_ms = now_ms;
+        }
+        
+        if self.raw != self.pressed && now_ms.wrapping_sub(self.raw_since_ms) >= BUTTON_DEBOUNCE_MS {
+            self.pressed = self.raw;
+            if self.pressed {
+                self.pressed_at_ms = now_ms;
+                self.long_reported = false;
+            } else if !self.long_reported {
+                // Second release inside the gap completes a double press
+                if self.released_at_ms.take().is_some() {
+                    return Some(ButtonEvent::DoublePress);
+                }
+                self.released_at_ms = Some(now_ms);
+            }
+        }
+        
+        if self.pressed {
+            if !self.long_reported && now_ms.wrapping_sub(self.pressed_at_ms) >= LONG_PRESS_MS {
+                self.long_reported = true;
+                self.released_at_ms = None;
+                return Some(ButtonEvent::LongPress);
+            }
+        } else if let Some(released_ms) = self.released_at_ms {
+            if now_ms.wrapping_sub(released_ms) >= DOUBLE_PRESS_GAP_MS {
+                self.released_at_ms = None;
+                return Some(ButtonEvent::ShortPress);
+            }
+        }
+        None
+    }
+    
+    /// Debounced level
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+}
+
+/// Status LED blink patterns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LedPattern {
+    #[default]
+    Off,
+    /// Lit continuously
+    On,
+    /// 1 Hz, half on
+    SlowBlink,
+    /// 5 Hz, half on
+    FastBlink,
+    /// Two short flashes every 1.2 s
+    DoubleBlink,
+}
+
+impl LedPattern {
+    /// Whether the LED is lit at `now_ms`
+    pub fn is_lit(self, now_ms: u32) -> bool {
+        match self {
+            LedPattern::Off => false,
+            LedPattern::On => true,
+            LedPattern::SlowBlink => now_ms % 1000 < 500,
+            LedPattern::FastBlink => now_ms % 200 < 100,
+            LedPattern::DoubleBlink => matches!(now_ms % 1200, 0..=99 | 200..=299),
+        }
+    }
+}
+
+/// Status LED driven through a pattern
+/// 
+/// The pin is only written when the lit state changes.
+#[derive(Debug, Clone, Default)]
+pub struct StatusLed {
+    pattern: LedPattern,
+    lit: Option<bool>,
+}
+
+impl StatusLed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Show a pattern from the next update
+    pub fn set_pattern(&mut self, pattern: LedPattern) {
+        self.pattern = pattern;
+    }
+    
+    /// Pattern being shown
+    pub fn pattern(&self) -> LedPattern {
+        self.pattern
+    }
+    
+    /// Drive the LED for `now_ms`
+    pub fn update(&mut self, gpio: &mut impl GpioControl, now_ms: u32) -> HalResult<()> {
+        let lit = self.pattern.is_lit(now_ms);
+        if self.lit != Some(lit) {
+            gpio.write_output(GpioOutput::StatusLed, lit)?;
+            self.lit = Some(lit);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Feed a level every 5 ms from `from_ms` up to (not including) `to_ms`
+    fn hold(button: &mut Button, raw: bool, from_ms: u32, to_ms: u32) -> Option<ButtonEvent> {
+        let mut event = None;
+        for t in (from_ms..to_ms).step_by(5) {
+            event = event.or(button.update(raw, t));
+        }
+        event
+    }
+    
+    #[test]
+    fn test_bounce_is_one_press() {
+        let mut button = Button::new();
+        // Contact chatter on press and release
+        for (i, t) in (0..15).step_by(5).enumerate() {
+            assert_eq!(button.update(i % 2 == 0, t), None);
+        }
+        assert_eq!(hold(&mut button, true, 15, 200), None);
+        assert!(button.is_pressed());
+        for (i, t) in (200..215).step_by(5).enumerate() {
+            assert_eq!(button.update(i % 2 == 1, t), None);
+        }
+        assert_eq!(hold(&mut button, false, 215, 1000), Some(ButtonEvent::ShortPress));
+        assert_eq!(hold(&mut button, false, 1000, 2000), None);
+    }
+    
+    #[test]
+    fn test_long_press_reported_while_held() {
+        let mut button = Button::new();
+        assert_eq!(hold(&mut button, true, 0, 1000), Some(ButtonEvent::LongPress));
+        assert_eq!(hold(&mut button, true, 1000, 3000), None);
+        // Release after a long press is not a short press
+        assert_eq!(hold(&mut button, false, 3000, 4000), None);
+    }
+    
+    #[test]
+    fn test_double_press() {
+        let mut button = Button::new();
+        assert_eq!(hold(&mut button, true, 0, 100), None);
+        assert_eq!(hold(&mut button, false, 100, 250), None);
+        assert_eq!(hold(&mut button, true, 250, 350), None);
+        assert_eq!(hold(&mut button, false, 350, 1500), Some(ButtonEvent::DoublePress));
+    }
+    
+    #[test]
+    fn test_led_patterns() {
+        assert!(LedPattern::On.is_lit(12_345));
+        assert!(!LedPattern::Off.is_lit(12_345));
+        assert!(LedPattern::SlowBlink.is_lit(100) && !LedPattern::SlowBlink.is_lit(600));
+        assert!(LedPattern::DoubleBlink.is_lit(250) && !LedPattern::DoubleBlink.is_lit(150) && !LedPattern::DoubleBlink.is_lit(800));
+    }
+}
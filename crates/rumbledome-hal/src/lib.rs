@@ -21,25 +21,55 @@ use std::{vec::Vec, string::String, format};
 
 pub mod time;
 pub mod pwm;
+pub mod actuator;
+pub mod power;
+pub mod analog;
+pub mod can;
+pub mod can_protocol;
+pub mod storage;
+pub mod gpio;
+pub mod display;
+pub mod console;
+pub mod portable_storage;
 
 // Mock implementation for desktop testing
 #[cfg(feature = "mock")]
 pub mod simple_mock;
 
-// TODO: Create remaining HAL modules as needed
-// pub mod analog;
-// pub mod storage; 
-// pub mod can;
-// pub mod display;
-// pub mod gpio;
-// pub mod bluetooth;
+// File-backed EEPROM image for desktop builds
+#[cfg(feature = "std")]
+pub mod mock_storage;
+
+// PNG and terminal output of the mock display
+#[cfg(feature = "std")]
+pub mod mock_display;
+
+// SocketCAN/serial implementation for Linux bench rigs
+#[cfg(all(feature = "linux-hw", target_os = "linux"))]
+pub mod linux;
 
 pub use time::*;
 pub use pwm::*;
+pub use actuator::*;
+pub use power::*;
+pub use analog::*;
+pub use can::*;
+pub use can_protocol::*;
+pub use storage::*;
+pub use gpio::*;
+pub use display::*;
+pub use console::*;
+pub use portable_storage::*;
 
 #[cfg(feature = "mock")]
 pub use simple_mock::SimpleMockHal as MockHal;
 
+#[cfg(feature = "std")]
+pub use mock_storage::MockStorage;
+
+#[cfg(all(feature = "linux-hw", target_os = "linux"))]
+pub use linux::{LinuxHal, LinuxHalConfig, AnalogSource};
+
 /// Core error type for all HAL operations
 #[derive(Debug, Clone, PartialEq)]
 pub enum HalError {
@@ -66,14 +96,16 @@ pub type HalResult<T> = Result<T, HalError>;
 /// AI Traceability: Single point of hardware abstraction for core control logic
 pub trait HalTrait: 
     TimeProvider + 
-    PwmControl 
-    // TODO: Add remaining HAL interfaces as modules are implemented
-    // + AnalogInput + 
-    // + NonVolatileStorage + 
-    // + CanInterface + 
-    // + DisplayInterface + 
-    // + GpioControl + 
-    // + BluetoothSerial 
+    PwmControl + 
+    ActuatorOutput + 
+    PowerMonitor + 
+    AnalogInput + 
+    CanInterface + 
+    NonVolatileStorage + 
+    GpioControl + 
+    DisplayInterface + 
+    ConsoleSerial + 
+    PortableStorage 
 {
     /// Initialize all hardware subsystems
     fn init(&mut self) -> HalResult<()>;
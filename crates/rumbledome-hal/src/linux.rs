@@ -0,0 +1,922 @@
+//! Linux Bench HAL
+//! 
+//! 🔗 T4-HAL-018: Linux Host Hardware Implementation
+//! Derived From: T2-HAL-001 (Platform-Independent Hardware Abstraction Design) + bench rig testing without Teensy hardware
+//! AI Traceability: A Raspberry Pi (or any Linux host) runs the unmodified core against real sensors, a real CAN bus and real solenoids
+//! 
+//! CAN goes through SocketCAN, so any adapter with a kernel driver (MCP2515
+//! hats, USB adapters through gs_usb or slcan) works. The bus bitrate belongs
+//! to the network interface (`ip link set can0 type can bitrate 500000`) and
+//! cannot be changed from an unprivileged socket; `set_bitrate` only checks
+//! that the requested rate is the one the rig was configured for.
+//! 
+//! Analog inputs are read from files holding a single number - IIO ADC
+//! channels (`/sys/bus/iio/devices/iio:device0/in_voltage0_raw`) or any file
+//! a test script writes - scaled to the sensor output voltage.
+//! 
+//! A Linux host has no hardware PWM that can drive a solenoid, so the drive
+//! stage is a serial-attached driver board speaking a line protocol:
+//! 
+//! | Command          | Meaning                                   |
+//! |------------------|-------------------------------------------|
+//! | `F <hz>`         | PWM frequency                             |
+//! | `D <ch> <duty>`  | Duty in tenths of a percent, `ch` = P or S |
+//! | `E`              | Enable outputs                            |
+//! | `X`              | All outputs off                           |
+//! | `S`              | Status only                               |
+//! 
+//! Every command is answered with `OK <p> <s>` (each drive status one of
+//! `OK`, `OPEN`, `SHORT`) or `ERR <reason>`. The board must turn both outputs
+//! off by itself when no command arrives for 500 ms, so a crashed host falls
+//! back to wastegate-open like a reset Teensy would.
+//! 
+//! Storage is a 4 KB image file standing in for the EEPROM. A directory can
+//! stand in for the SD card; without one the card reads as not inserted.
+
+use std::{
+    ffi::CString,
+    format,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    mem,
+    path::PathBuf,
+    string::{String, ToString},
+    thread,
+    time::{Duration, Instant},
+    vec,
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+use serialport::SerialPort;
+
+use crate::{
+    HalTrait, HalResult, HalError, TestStatus, SelfTestResult, PlatformInfo, PlatformCapabilities,
+    TimeProvider, CallbackHandle, PwmControl, PwmTimingInfo, SolenoidChannel, SolenoidDriveStatus,
+    ActuatorOutput, ActuatorInterface, PowerMonitor, NOMINAL_SUPPLY_VOLTAGE,
+    AnalogInput, PressureCalibration, ANALOG_CHANNELS, sensor_voltage_to_counts,
+    CanInterface, CanFrame, CanFilter, CanStats, CAN_MAX_PAYLOAD,
+    NonVolatileStorage, EEPROM_SIZE, ERASED_BYTE,
+    GpioControl, GpioInput, GpioOutput, DisplayInterface, DISPLAY_WIDTH, DISPLAY_HEIGHT,
+    ConsoleSerial, ConsolePort, ConsoleConnection, PortableStorage, CardInfo,
+};
+
+/// Driver board reply timeout
+const DRIVER_REPLY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Lowest and highest PWM frequency the driver board accepts (Hz)
+const DRIVER_MIN_FREQUENCY_HZ: u32 = 10;
+const DRIVER_MAX_FREQUENCY_HZ: u32 = 1_000;
+
+/// One analog input read from a file
+/// 
+/// Sensor voltage = value in file × `scale` + `offset`. For an IIO channel
+/// `scale` is the channel's `in_voltageN_scale` (mV per count) / 1000 divided
+/// by any divider ratio in front of the ADC.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalogSource {
+    /// File holding the reading
+    pub path: String,
+    /// Volts per unit of the file value
+    pub scale: f32,
+    /// Volts added after scaling
+    #[serde(default)]
+    pub offset: f32,
+}
+
+impl AnalogSource {
+    /// Sensor output voltage from the file contents
+    fn voltage(&self, contents: &str) -> HalResult<f32> {
+        let value: f32 = contents.trim().parse()
+            .map_err(|_| HalError::HardwareFault(format!("{}: not a number: {:?}", self.path, contents.trim())))?;
+        Ok(value * self.scale + self.offset)
+    }
+    
+    fn read_voltage(&self) -> HalResult<f32> {
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|e| HalError::HardwareFault(format!("{}: {}", self.path, e)))?;
+        self.voltage(&contents)
+    }
+}
+
+/// Bench rig wiring
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinuxHalConfig {
+    /// SocketCAN interface (e.g. `can0`, `vcan0`)
+    pub can_interface: String,
+    /// Bitrate the CAN interface was brought up with (bit/s)
+    pub can_bitrate_bps: u32,
+    /// Serial device of the solenoid driver board
+    pub driver_port: String,
+    /// Driver board baud rate
+    pub driver_baud: u32,
+    /// Analog input per channel (unconnected channels read as a fault)
+    pub analog: Vec<Option<AnalogSource>>,
+    /// Supply voltage monitor (nominal voltage assumed when absent)
+    #[serde(default)]
+    pub supply_voltage: Option<AnalogSource>,
+    /// EEPROM image file (created erased when missing)
+    pub storage_path: String,
+    /// Directory standing in for the SD card root
+    #[serde(default)]
+    pub sd_card_dir: Option<String>,
+}
+
+impl Default for LinuxHalConfig {
+    fn default() -> Self {
+        Self {
+            can_interface: "can0".into(),
+            can_bitrate_bps: 500_000,
+            driver_port: "/dev/ttyUSB0".into(),
+            driver_baud: 115_200,
+            analog: vec![None; ANALOG_CHANNELS],
+            supply_voltage: None,
+            storage_path: "rumbledome-eeprom.bin".into(),
+            sd_card_dir: None,
+        }
+    }
+}
+
+/// Raw SocketCAN socket (classic frames only)
+#[derive(Debug)]
+struct SocketCan {
+    fd: libc::c_int,
+}
+
+impl SocketCan {
+    #[allow(unsafe_code)]
+    fn open(interface: &str) -> HalResult<Self> {
+        let name = CString::new(interface)
+            .map_err(|_| HalError::InvalidParameter("CAN interface name contains a NUL byte".into()))?;
+        // SAFETY: plain libc calls on a NUL-terminated name and a socket this struct owns;
+        // sockaddr_can is valid zeroed and the length passed matches its type
+        unsafe {
+            let ifindex = libc::if_nametoindex(name.as_ptr());
+            if ifindex == 0 {
+                return Err(HalError::InitializationFailed(format!("CAN interface {} not found", interface)));
+            }
+            let fd = libc::socket(libc::PF_CAN, libc::SOCK_RAW | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC, libc::CAN_RAW);
+            if fd < 0 {
+                return Err(HalError::InitializationFailed(format!("CAN socket: {}", std::io::Error::last_os_error())));
+            }
+            let socket = Self { fd };
+            let mut addr: libc::sockaddr_can = mem::zeroed();
+            addr.can_family = libc::AF_CAN as libc::sa_family_t;
+            addr.can_ifindex = ifindex as libc::c_int;
+            let bound = libc::bind(
+                fd,
+                &addr as *const libc::sockaddr_can as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_can>() as libc::socklen_t,
+            );
+            if bound < 0 {
+                return Err(HalError::InitializationFailed(format!("CAN bind {}: {}", interface, std::io::Error::last_os_error())));
+            }
+            Ok(socket)
+        }
+    }
+    
+    #[allow(unsafe_code)]
+    fn send(&self, frame: &CanFrame) -> HalResult<()> {
+        let raw = to_raw_frame(frame);
+        // SAFETY: writes exactly one can_frame from a live stack value
+        let written = unsafe {
+            libc::write(self.fd, &raw as *const libc::can_frame as *const libc::c_void, libc::CAN_MTU)
+        };
+        if written != libc::CAN_MTU as isize {
+            return Err(HalError::CommunicationError(format!("CAN send: {}", std::io::Error::last_os_error())));
+        }
+        Ok(())
+    }
+    
+    #[allow(unsafe_code)]
+    fn receive(&self) -> HalResult<Option<libc::can_frame>> {
+        // SAFETY: reads at most one can_frame into a zeroed stack value of that size
+        let (read, raw) = unsafe {
+            let mut raw: libc::can_frame = mem::zeroed();
+            let read = libc::read(self.fd, &mut raw as *mut libc::can_frame as *mut libc::c_void, libc::CAN_MTU);
+            (read, raw)
+        };
+        if read < 0 {
+            let error = std::io::Error::last_os_error();
+            if error.kind() == std::io::ErrorKind::WouldBlock {
+                return Ok(None);
+            }
+            return Err(HalError::CommunicationError(format!("CAN receive: {}", error)));
+        }
+        Ok(Some(raw))
+    }
+    
+    #[allow(unsafe_code)]
+    fn set_filters(&self, filters: &[CanFilter]) -> HalResult<()> {
+        // An empty list passes every frame, matching the trait contract
+        let raw: Vec<libc::can_filter> = if filters.is_empty() {
+            vec![libc::can_filter { can_id: 0, can_mask: 0 }]
+        } else {
+            filters.iter().map(to_raw_filter).collect()
+        };
+        // SAFETY: the option value is the filter slice and its exact byte length
+        let result = unsafe {
+            libc::setsockopt(
+                self.fd,
+                libc::SOL_CAN_RAW,
+                libc::CAN_RAW_FILTER,
+                raw.as_ptr() as *const libc::c_void,
+                (raw.len() * mem::size_of::<libc::can_filter>()) as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(HalError::CommunicationError(format!("CAN filters: {}", std::io::Error::last_os_error())));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SocketCan {
+    #[allow(unsafe_code)]
+    fn drop(&mut self) {
+        // SAFETY: the descriptor is owned by this struct and closed exactly once
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+#[allow(unsafe_code)]
+fn to_raw_frame(frame: &CanFrame) -> libc::can_frame {
+    // SAFETY: can_frame is plain data for which all-zero is valid
+    let mut raw: libc::can_frame = unsafe { mem::zeroed() };
+    raw.can_id = if frame.extended {
+        (frame.id & libc::CAN_EFF_MASK) | libc::CAN_EFF_FLAG
+    } else {
+        frame.id & libc::CAN_SFF_MASK
+    };
+    raw.can_dlc = frame.len.min(CAN_MAX_PAYLOAD as u8);
+    raw.data = frame.data;
+    raw
+}
+
+/// Data frame from a raw frame (`None` for error and remote frames)
+fn from_raw_frame(raw: &libc::can_frame) -> Option<CanFrame> {
+    if raw.can_id & (libc::CAN_ERR_FLAG | libc::CAN_RTR_FLAG) != 0 {
+        return None;
+    }
+    let extended = raw.can_id & libc::CAN_EFF_FLAG != 0;
+    let id = if extended { raw.can_id & libc::CAN_EFF_MASK } else { raw.can_id & libc::CAN_SFF_MASK };
+    let len = raw.can_dlc.min(CAN_MAX_PAYLOAD as u8);
+    let mut data = [0; CAN_MAX_PAYLOAD];
+    data[..len as usize].copy_from_slice(&raw.data[..len as usize]);
+    Some(CanFrame { id, extended, len, data })
+}
+
+fn to_raw_filter(filter: &CanFilter) -> libc::can_filter {
+    libc::can_filter { can_id: filter.id, can_mask: filter.mask }
+}
+
+/// Driver board reply to a command: drive status of both channels
+fn parse_driver_reply(line: &str) -> HalResult<(SolenoidDriveStatus, SolenoidDriveStatus)> {
+    let line = line.trim();
+    if let Some(reason) = line.strip_prefix("ERR") {
+        return Err(HalError::HardwareFault(format!("Driver board: {}", reason.trim())));
+    }
+    let not_understood = || HalError::CommunicationError(format!("Driver board reply not understood: {:?}", line));
+    let status = |field: Option<&str>| match field {
+        Some("OK") => Ok(SolenoidDriveStatus::Ok),
+        Some("OPEN") => Ok(SolenoidDriveStatus::OpenCircuit),
+        Some("SHORT") => Ok(SolenoidDriveStatus::ShortCircuit),
+        _ => Err(not_understood()),
+    };
+    let mut fields = line.split_whitespace();
+    if fields.next() != Some("OK") {
+        return Err(not_understood());
+    }
+    Ok((status(fields.next())?, status(fields.next())?))
+}
+
+/// Duty in the driver board's tenths of a percent
+fn duty_permille(duty_percent: f32) -> u16 {
+    (duty_percent * 10.0).round() as u16
+}
+
+/// HAL for a Linux bench rig
+pub struct LinuxHal {
+    config: LinuxHalConfig,
+    start: Instant,
+    can: Option<SocketCan>,
+    can_stats: CanStats,
+    driver: Option<BufReader<Box<dyn SerialPort>>>,
+    frequency_hz: u32,
+    duty_cycle: f32,
+    secondary_duty_cycle: f32,
+    primary_status: SolenoidDriveStatus,
+    secondary_status: SolenoidDriveStatus,
+    calibrations: [PressureCalibration; ANALOG_CHANNELS],
+    storage: Option<File>,
+    storage_image: Vec<u8>,
+}
+
+impl core::fmt::Debug for LinuxHal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LinuxHal")
+            .field("config", &self.config)
+            .field("can_open", &self.can.is_some())
+            .field("driver_open", &self.driver.is_some())
+            .field("duty_cycle", &self.duty_cycle)
+            .field("secondary_duty_cycle", &self.secondary_duty_cycle)
+            .finish()
+    }
+}
+
+impl LinuxHal {
+    /// Bench HAL for a rig; devices are opened by `init`
+    pub fn new(config: LinuxHalConfig) -> Self {
+        Self {
+            config,
+            start: Instant::now(),
+            can: None,
+            can_stats: CanStats::default(),
+            driver: None,
+            frequency_hz: 30,
+            duty_cycle: 0.0,
+            secondary_duty_cycle: 0.0,
+            primary_status: SolenoidDriveStatus::Ok,
+            secondary_status: SolenoidDriveStatus::Ok,
+            calibrations: [PressureCalibration::default(); ANALOG_CHANNELS],
+            storage: None,
+            storage_image: vec![ERASED_BYTE; EEPROM_SIZE],
+        }
+    }
+    
+    /// Rig wiring this HAL was created with
+    pub fn config(&self) -> &LinuxHalConfig {
+        &self.config
+    }
+    
+    fn open_storage(&mut self) -> HalResult<()> {
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false)
+            .open(&self.config.storage_path)
+            .map_err(|e| HalError::InitializationFailed(format!("{}: {}", self.config.storage_path, e)))?;
+        let mut image = Vec::new();
+        file.read_to_end(&mut image).map_err(|e| HalError::InitializationFailed(e.to_string()))?;
+        // A new or short image file reads as erased cells past its end
+        image.resize(EEPROM_SIZE, ERASED_BYTE);
+        image.truncate(EEPROM_SIZE);
+        file.seek(SeekFrom::Start(0)).and_then(|_| file.write_all(&image)).and_then(|_| file.sync_data())
+            .map_err(|e| HalError::InitializationFailed(e.to_string()))?;
+        self.storage_image = image;
+        self.storage = Some(file);
+        Ok(())
+    }
+    
+    fn open_driver(&mut self) -> HalResult<()> {
+        let port = serialport::new(&self.config.driver_port, self.config.driver_baud)
+            .timeout(DRIVER_REPLY_TIMEOUT)
+            .open()
+            .map_err(|e| HalError::InitializationFailed(format!("{}: {}", self.config.driver_port, e)))?;
+        self.driver = Some(BufReader::new(port));
+        Ok(())
+    }
+    
+    /// Send one command to the driver board and record the drive status it reports
+    fn driver_command(&mut self, command: &str) -> HalResult<()> {
+        let driver = self.driver.as_mut()
+            .ok_or_else(|| HalError::InitializationFailed("Driver board not open".into()))?;
+        driver.get_mut().write_all(format!("{}\n", command).as_bytes())
+            .map_err(|e| HalError::CommunicationError(format!("Driver board: {}", e)))?;
+        let mut reply = String::new();
+        driver.read_line(&mut reply).map_err(|e| match e.kind() {
+            std::io::ErrorKind::TimedOut => HalError::Timeout,
+            _ => HalError::CommunicationError(format!("Driver board: {}", e)),
+        })?;
+        let (primary, secondary) = parse_driver_reply(&reply)?;
+        self.primary_status = primary;
+        self.secondary_status = secondary;
+        Ok(())
+    }
+    
+    fn check_duty(duty_percent: f32) -> HalResult<()> {
+        if !(0.0..=100.0).contains(&duty_percent) {
+            return Err(HalError::InvalidParameter("Duty cycle out of range".into()));
+        }
+        Ok(())
+    }
+    
+    fn analog_source(&self, channel: u8) -> HalResult<&AnalogSource> {
+        if channel as usize >= ANALOG_CHANNELS {
+            return Err(HalError::InvalidParameter("Analog channel out of range".into()));
+        }
+        self.config.analog.get(channel as usize).and_then(Option::as_ref)
+            .ok_or_else(|| HalError::HardwareFault(format!("Analog channel {} not connected on this rig", channel)))
+    }
+}
+
+impl HalTrait for LinuxHal {
+    fn init(&mut self) -> HalResult<()> {
+        self.open_storage()?;
+        self.can = Some(SocketCan::open(&self.config.can_interface)?);
+        self.open_driver()?;
+        // Outputs off until the core commands otherwise
+        self.driver_command("X")?;
+        self.driver_command(&format!("F {}", self.frequency_hz))
+    }
+    
+    fn self_test(&mut self) -> HalResult<SelfTestResult> {
+        let mut failures = Vec::new();
+        let pwm_test = match self.driver_command("S") {
+            Ok(()) if self.primary_status.is_functional() => TestStatus::Pass,
+            Ok(()) => {
+                failures.push(format!("Primary solenoid drive: {:?}", self.primary_status));
+                TestStatus::Fail
+            }
+            Err(e) => {
+                failures.push(format!("Driver board: {:?}", e));
+                TestStatus::Fail
+            }
+        };
+        let mut analog_test = TestStatus::Pass;
+        for channel in 0..ANALOG_CHANNELS as u8 {
+            if let Ok(source) = self.analog_source(channel) {
+                if let Err(e) = source.read_voltage() {
+                    failures.push(format!("Analog channel {}: {:?}", channel, e));
+                    analog_test = TestStatus::Fail;
+                }
+            }
+        }
+        let storage_test = if self.storage.is_some() { TestStatus::Pass } else { TestStatus::Fail };
+        let can_test = if self.can.is_some() { TestStatus::Pass } else { TestStatus::Fail };
+        let overall_status = if failures.is_empty() && storage_test == TestStatus::Pass && can_test == TestStatus::Pass {
+            TestStatus::Pass
+        } else {
+            TestStatus::Fail
+        };
+        Ok(SelfTestResult {
+            overall_status,
+            pwm_test,
+            analog_test,
+            storage_test,
+            can_test,
+            display_test: TestStatus::NotTested,
+            bluetooth_test: TestStatus::NotTested,
+            failures,
+        })
+    }
+    
+    fn get_platform_info(&self) -> PlatformInfo {
+        PlatformInfo {
+            platform_name: "LinuxHal",
+            version: "0.1.0",
+            capabilities: PlatformCapabilities {
+                has_pwm: true,
+                analog_channels: self.config.analog.iter().filter(|source| source.is_some()).count() as u8,
+                storage_size: EEPROM_SIZE,
+                can_controllers: 1,
+                display_resolution: (0, 0),
+                has_bluetooth: false,
+            },
+        }
+    }
+    
+    fn emergency_shutdown(&mut self) -> HalResult<()> {
+        self.duty_cycle = 0.0;
+        self.secondary_duty_cycle = 0.0;
+        // The board's own command timeout still drops the outputs if this cannot get through
+        self.driver_command("X")
+    }
+}
+
+impl TimeProvider for LinuxHal {
+    fn now_ms(&self) -> u32 {
+        self.start.elapsed().as_millis() as u32
+    }
+    
+    fn now_us(&self) -> u64 {
+        self.start.elapsed().as_micros() as u64
+    }
+    
+    fn delay_ms(&mut self, duration_ms: u32) -> HalResult<()> {
+        thread::sleep(Duration::from_millis(duration_ms as u64));
+        Ok(())
+    }
+    
+    fn delay_us(&mut self, duration_us: u32) -> HalResult<()> {
+        thread::sleep(Duration::from_micros(duration_us as u64));
+        Ok(())
+    }
+    
+    fn schedule_callback(&mut self, _delay_ms: u32, _callback: fn()) -> HalResult<CallbackHandle> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn cancel_callback(&mut self, _handle: CallbackHandle) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn system_uptime_ms(&self) -> u32 {
+        self.now_ms()
+    }
+}
+
+impl PwmControl for LinuxHal {
+    fn set_frequency(&mut self, freq_hz: u32) -> HalResult<()> {
+        if !(DRIVER_MIN_FREQUENCY_HZ..=DRIVER_MAX_FREQUENCY_HZ).contains(&freq_hz) {
+            return Err(crate::PwmError::FrequencyOutOfRange {
+                requested: freq_hz,
+                min: DRIVER_MIN_FREQUENCY_HZ,
+                max: DRIVER_MAX_FREQUENCY_HZ,
+            }.into());
+        }
+        self.driver_command(&format!("F {}", freq_hz))?;
+        self.frequency_hz = freq_hz;
+        Ok(())
+    }
+    
+    fn set_duty_cycle(&mut self, duty_percent: f32) -> HalResult<()> {
+        self.set_channel_duty_cycle(SolenoidChannel::Primary, duty_percent)
+    }
+    
+    fn get_current_duty(&self) -> f32 {
+        self.duty_cycle
+    }
+    
+    fn enable(&mut self) -> HalResult<()> {
+        self.driver_command("E")
+    }
+    
+    fn disable(&mut self) -> HalResult<()> {
+        self.duty_cycle = 0.0;
+        self.secondary_duty_cycle = 0.0;
+        self.driver_command("X")
+    }
+    
+    fn get_timing_info(&self) -> HalResult<PwmTimingInfo> {
+        // The driver board latches new duty at its own cycle boundary
+        let period_us = 1_000_000 / self.frequency_hz;
+        Ok(PwmTimingInfo {
+            cycle_position: 0.0,
+            time_to_next_cycle_us: period_us,
+            time_to_optimal_window_us: 0,
+            in_optimal_window: true,
+        })
+    }
+    
+    fn set_duty_cycle_synchronized(&mut self, duty_percent: f32, _current_time_us: u64) -> HalResult<()> {
+        self.set_duty_cycle(duty_percent)
+    }
+    
+    fn set_duty_cycle_immediate(&mut self, duty_percent: f32) -> HalResult<()> {
+        self.set_duty_cycle(duty_percent)
+    }
+    
+    fn set_channel_duty_cycle(&mut self, channel: SolenoidChannel, duty_percent: f32) -> HalResult<()> {
+        Self::check_duty(duty_percent)?;
+        let name = match channel {
+            SolenoidChannel::Primary => "P",
+            SolenoidChannel::Secondary => "S",
+        };
+        self.driver_command(&format!("D {} {}", name, duty_permille(duty_percent)))?;
+        match channel {
+            SolenoidChannel::Primary => self.duty_cycle = duty_percent,
+            SolenoidChannel::Secondary => self.secondary_duty_cycle = duty_percent,
+        }
+        Ok(())
+    }
+    
+    fn get_channel_status(&self, channel: SolenoidChannel) -> HalResult<SolenoidDriveStatus> {
+        // As of the driver board's last reply - every command refreshes it
+        Ok(match channel {
+            SolenoidChannel::Primary => self.primary_status,
+            SolenoidChannel::Secondary => self.secondary_status,
+        })
+    }
+}
+
+impl ActuatorOutput for LinuxHal {
+    fn set_actuator_position(&mut self, _interface: ActuatorInterface, _position_percent: f32) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn get_actuator_position(&self, _interface: ActuatorInterface) -> HalResult<f32> {
+        Err(HalError::NotSupported)
+    }
+}
+
+impl PowerMonitor for LinuxHal {
+    fn supply_voltage(&self) -> HalResult<f32> {
+        match &self.config.supply_voltage {
+            Some(source) => source.read_voltage(),
+            None => Ok(NOMINAL_SUPPLY_VOLTAGE),
+        }
+    }
+    
+    fn mcu_temperature(&self) -> HalResult<f32> {
+        // A desktop's CPU temperature says nothing about the target's
+        Err(HalError::NotSupported)
+    }
+}
+
+impl AnalogInput for LinuxHal {
+    fn read_raw(&self, channel: u8) -> HalResult<u16> {
+        Ok(sensor_voltage_to_counts(self.read_voltage(channel)?))
+    }
+    
+    fn read_voltage(&self, channel: u8) -> HalResult<f32> {
+        self.analog_source(channel)?.read_voltage()
+    }
+    
+    fn calibration(&self, channel: u8) -> HalResult<PressureCalibration> {
+        self.calibrations.get(channel as usize).copied()
+            .ok_or_else(|| HalError::InvalidParameter("Analog channel out of range".into()))
+    }
+    
+    fn set_calibration(&mut self, channel: u8, calibration: PressureCalibration) -> HalResult<()> {
+        calibration.validate()?;
+        let slot = self.calibrations.get_mut(channel as usize)
+            .ok_or_else(|| HalError::InvalidParameter("Analog channel out of range".into()))?;
+        *slot = calibration;
+        Ok(())
+    }
+}
+
+impl CanInterface for LinuxHal {
+    fn set_bitrate(&mut self, bitrate_bps: u32) -> HalResult<()> {
+        if bitrate_bps != self.config.can_bitrate_bps {
+            return Err(HalError::InvalidParameter(format!(
+                "{} is up at {} bit/s - reconfigure the interface for {} bit/s",
+                self.config.can_interface, self.config.can_bitrate_bps, bitrate_bps,
+            )));
+        }
+        Ok(())
+    }
+    
+    fn send_frame(&mut self, frame: &CanFrame) -> HalResult<()> {
+        let can = self.can.as_ref().ok_or_else(|| HalError::InitializationFailed("CAN not open".into()))?;
+        can.send(frame)?;
+        self.can_stats.frames_sent += 1;
+        Ok(())
+    }
+    
+    fn receive_frame(&mut self) -> HalResult<Option<CanFrame>> {
+        let can = self.can.as_ref().ok_or_else(|| HalError::InitializationFailed("CAN not open".into()))?;
+        while let Some(raw) = can.receive()? {
+            if raw.can_id & libc::CAN_ERR_FLAG != 0 {
+                self.can_stats.error_frames += 1;
+                continue;
+            }
+            if let Some(frame) = from_raw_frame(&raw) {
+                self.can_stats.frames_received += 1;
+                return Ok(Some(frame));
+            }
+        }
+        Ok(None)
+    }
+    
+    fn set_filters(&mut self, filters: &[CanFilter]) -> HalResult<()> {
+        let can = self.can.as_ref().ok_or_else(|| HalError::InitializationFailed("CAN not open".into()))?;
+        can.set_filters(filters)
+    }
+    
+    fn can_stats(&self) -> CanStats {
+        self.can_stats
+    }
+}
+
+impl NonVolatileStorage for LinuxHal {
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize> {
+        let len = buffer.len().min(EEPROM_SIZE.saturating_sub(offset));
+        buffer[..len].copy_from_slice(&self.storage_image[offset.min(EEPROM_SIZE)..][..len]);
+        Ok(len)
+    }
+    
+    fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()> {
+        if offset + data.len() > EEPROM_SIZE {
+            return Err(HalError::InvalidParameter("Write past the end of storage".into()));
+        }
+        let file = self.storage.as_mut().ok_or_else(|| HalError::InitializationFailed("Storage not open".into()))?;
+        file.seek(SeekFrom::Start(offset as u64)).and_then(|_| file.write_all(data))
+            .map_err(|e| HalError::HardwareFault(format!("Storage write: {}", e)))?;
+        self.storage_image[offset..offset + data.len()].copy_from_slice(data);
+        Ok(())
+    }
+    
+    fn erase_all(&mut self) -> HalResult<()> {
+        let erased = vec![ERASED_BYTE; EEPROM_SIZE];
+        self.write(0, &erased)
+    }
+    
+    fn sync(&mut self) -> HalResult<()> {
+        match self.storage.as_mut() {
+            Some(file) => file.sync_data().map_err(|e| HalError::HardwareFault(format!("Storage sync: {}", e))),
+            None => Err(HalError::InitializationFailed("Storage not open".into())),
+        }
+    }
+    
+    fn get_size(&self) -> usize {
+        EEPROM_SIZE
+    }
+}
+
+impl GpioControl for LinuxHal {
+    fn read_input(&self, _input: GpioInput) -> HalResult<bool> {
+        // Bench rigs have no buttons; scramble and profile changes come over the protocol
+        Err(HalError::NotSupported)
+    }
+    
+    fn write_output(&mut self, _output: GpioOutput, _on: bool) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+}
+
+impl DisplayInterface for LinuxHal {
+    fn display_size(&self) -> (u16, u16) {
+        (DISPLAY_WIDTH, DISPLAY_HEIGHT)
+    }
+    
+    // No panel on a bench rig; the screen is mirrored over the protocol instead
+    fn fill_rect(&mut self, _x: u16, _y: u16, _width: u16, _height: u16, _color: u16) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn write_pixels(&mut self, _x: u16, _y: u16, _width: u16, _pixels: &[u16]) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn flush(&mut self) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn set_backlight(&mut self, _percent: u8) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+}
+
+impl LinuxHal {
+    /// Host path of a file on the card
+    fn card_path(&self, path: &str) -> HalResult<PathBuf> {
+        let root = self.config.sd_card_dir.as_ref()
+            .ok_or_else(|| HalError::HardwareFault("No SD card directory configured".into()))?;
+        if path.split('/').any(|part| part == "..") {
+            return Err(HalError::InvalidParameter(format!("{}: path leaves the card", path)));
+        }
+        Ok(PathBuf::from(root).join(path))
+    }
+}
+
+fn card_error(path: &str, e: std::io::Error) -> HalError {
+    HalError::HardwareFault(format!("SD card {}: {}", path, e))
+}
+
+impl PortableStorage for LinuxHal {
+    fn is_mounted(&self) -> bool {
+        self.config.sd_card_dir.as_ref().is_some_and(|root| fs::metadata(root).is_ok_and(|meta| meta.is_dir()))
+    }
+    
+    fn append_file(&mut self, path: &str, data: &[u8]) -> HalResult<()> {
+        let host_path = self.card_path(path)?;
+        if let Some(parent) = host_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| card_error(path, e))?;
+        }
+        OpenOptions::new().create(true).append(true).open(&host_path)
+            .and_then(|mut file| file.write_all(data))
+            .map_err(|e| card_error(path, e))
+    }
+    
+    fn read_file(&mut self, path: &str, offset: u64, buffer: &mut [u8]) -> HalResult<usize> {
+        let mut file = File::open(self.card_path(path)?).map_err(|e| card_error(path, e))?;
+        file.seek(SeekFrom::Start(offset)).and_then(|_| file.read(buffer)).map_err(|e| card_error(path, e))
+    }
+    
+    fn file_size(&self, path: &str) -> HalResult<Option<u64>> {
+        match fs::metadata(self.card_path(path)?) {
+            Ok(meta) => Ok(Some(meta.len())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(card_error(path, e)),
+        }
+    }
+    
+    fn list_files(&self, directory: &str) -> HalResult<Vec<String>> {
+        let entries = match fs::read_dir(self.card_path(directory)?) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(card_error(directory, e)),
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+    
+    fn remove_file(&mut self, path: &str) -> HalResult<()> {
+        match fs::remove_file(self.card_path(path)?) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(card_error(path, e)),
+            _ => Ok(()),
+        }
+    }
+    
+    #[allow(unsafe_code)]
+    fn card_info(&self) -> HalResult<CardInfo> {
+        let root = self.card_path("")?;
+        let root = CString::new(root.to_string_lossy().as_bytes())
+            .map_err(|_| HalError::InvalidParameter("SD card directory contains a NUL byte".into()))?;
+        // SAFETY: statvfs fills the struct it is given from a NUL-terminated path
+        let mut stats: libc::statvfs = unsafe { mem::zeroed() };
+        if unsafe { libc::statvfs(root.as_ptr(), &mut stats) } != 0 {
+            return Err(card_error("/", std::io::Error::last_os_error()));
+        }
+        let block = stats.f_frsize as u64;
+        Ok(CardInfo { capacity_bytes: stats.f_blocks as u64 * block, free_bytes: stats.f_bavail as u64 * block })
+    }
+}
+
+// The bench rig is reached through the simulator's protocol endpoint, not a console of its own
+impl ConsoleSerial for LinuxHal {
+    fn start_bluetooth(&mut self, _name: &str, _pin: &str) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn stop_bluetooth(&mut self) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn console_connection(&self, _port: ConsolePort) -> Option<ConsoleConnection> {
+        None
+    }
+    
+    fn console_receive(&mut self, _port: ConsolePort, _buffer: &mut [u8]) -> HalResult<usize> {
+        Err(HalError::NotSupported)
+    }
+    
+    fn console_send(&mut self, _port: ConsolePort, _data: &[u8]) -> HalResult<()> {
+        Err(HalError::NotSupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_can_frames_round_trip_through_socketcan_layout() {
+        let standard = CanFrame::new(0x109, &[0x12, 0x34]).unwrap();
+        let raw = to_raw_frame(&standard);
+        assert_eq!(raw.can_id, 0x109);
+        assert_eq!(raw.can_dlc, 2);
+        assert_eq!(from_raw_frame(&raw), Some(standard));
+        
+        let extended = CanFrame::new(0x18DA_F110, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let raw = to_raw_frame(&extended);
+        assert_ne!(raw.can_id & libc::CAN_EFF_FLAG, 0);
+        assert_eq!(from_raw_frame(&raw), Some(extended));
+        
+        // Remote and error frames carry no data for the core
+        let mut remote = to_raw_frame(&standard);
+        remote.can_id |= libc::CAN_RTR_FLAG;
+        assert_eq!(from_raw_frame(&remote), None);
+    }
+    
+    #[test]
+    fn test_driver_board_replies() {
+        assert_eq!(parse_driver_reply("OK OK OK\n").unwrap(), (SolenoidDriveStatus::Ok, SolenoidDriveStatus::Ok));
+        assert_eq!(
+            parse_driver_reply("OK SHORT OPEN").unwrap(),
+            (SolenoidDriveStatus::ShortCircuit, SolenoidDriveStatus::OpenCircuit),
+        );
+        assert!(matches!(parse_driver_reply("ERR overtemp"), Err(HalError::HardwareFault(_))));
+        assert!(matches!(parse_driver_reply("garbage"), Err(HalError::CommunicationError(_))));
+        assert!(matches!(parse_driver_reply("OK OK"), Err(HalError::CommunicationError(_))));
+        assert_eq!(duty_permille(37.25), 373);
+    }
+    
+    #[test]
+    fn test_analog_source_scaling() {
+        // IIO channel: 0.806 mV per count behind a 0.66 divider
+        let source = AnalogSource { path: "in_voltage0_raw".into(), scale: 0.000806 / 0.66, offset: 0.0 };
+        let volts = source.voltage("2048\n").unwrap();
+        assert!((volts - 2.501).abs() < 0.01);
+        assert!(source.voltage("").is_err());
+    }
+    
+    #[test]
+    fn test_storage_image_file_persists() {
+        let path = std::env::temp_dir().join(format!("rumbledome-linux-hal-{}.bin", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let config = LinuxHalConfig { storage_path: path.to_string_lossy().into_owned(), ..LinuxHalConfig::default() };
+        
+        let mut hal = LinuxHal::new(config.clone());
+        hal.open_storage().unwrap();
+        let mut buffer = [0; 4];
+        hal.read(100, &mut buffer).unwrap();
+        assert_eq!(buffer, [ERASED_BYTE; 4]);
+        hal.write(100, &[1, 2, 3, 4]).unwrap();
+        hal.sync().unwrap();
+        drop(hal);
+        
+        let mut hal = LinuxHal::new(config);
+        hal.open_storage().unwrap();
+        hal.read(100, &mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2, 3, 4]);
+        assert_eq!(fs::metadata(&path).unwrap().len(), EEPROM_SIZE as u64);
+        let _ = fs::remove_file(&path);
+    }
+}
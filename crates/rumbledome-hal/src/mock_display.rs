@@ -0,0 +1,148 @@
+//! Framebuffer Export for Desktop Builds
+//! 
+//! 🔗 T4-HAL-023: Mock Display Output
+//! Derived From: T4-HAL-022 (Display Interface)
+//! AI Traceability: See the device screen in the simulator and attach it to bug reports
+//! 
+//! The mock HAL draws into a `Framebuffer`; this turns it into a PNG file
+//! or into truecolor terminal text (two pixel rows per character cell).
+
+use std::fmt::Write;
+use std::string::String;
+use std::vec::Vec;
+
+use crate::{rgb888, Framebuffer, DisplayInterface};
+
+/// PNG file signature
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// Largest stored (uncompressed) deflate block
+const MAX_STORED_BLOCK: usize = 0xFFFF;
+
+impl Framebuffer {
+    /// Encode as a 24-bit RGB PNG image
+    /// 
+    /// The image data is stored uncompressed - a 128×160 screen is about
+    /// 60 KB, and the encoder needs nothing beyond the standard library.
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height) = self.display_size();
+        let mut raw = Vec::with_capacity(height as usize * (1 + width as usize * 3));
+        for row in self.pixels().chunks(width.max(1) as usize) {
+            raw.push(0); // Filter type: none
+            for &color in row {
+                let (r, g, b) = rgb888(color);
+                raw.extend_from_slice(&[r, g, b]);
+            }
+        }
+        
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(width as u32).to_be_bytes());
+        header.extend_from_slice(&(height as u32).to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]); // 8-bit RGB, deflate, no filter, no interlace
+        
+        let mut png = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+    
+    /// Render as ANSI truecolor text using upper half blocks
+    pub fn to_terminal(&self) -> String {
+        let (width, height) = self.display_size();
+        let mut text = String::new();
+        for y in (0..height).step_by(2) {
+            for x in 0..width {
+                let (tr, tg, tb) = rgb888(self.pixel(x, y).unwrap_or(0));
+                let (br, bg, bb) = rgb888(self.pixel(x, y + 1).unwrap_or(0));
+                let _ = write!(text, "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m▀", tr, tg, tb, br, bg, bb);
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+}
+
+/// Append a PNG chunk: length, type, data, CRC of type and data
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// zlib stream of stored deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+/// CRC-32 (ISO 3309, as PNG uses)
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Adler-32 (zlib trailer)
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+    
+    #[test]
+    fn test_png_layout() {
+        let mut fb = Framebuffer::new(2, 2);
+        fb.fill_rect(0, 0, 1, 1, 0xF800).unwrap();
+        let png = fb.to_png();
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(png[16..24], [0, 0, 0, 2, 0, 0, 0, 2]);
+        assert!(png.ends_with(&[b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]));
+        // First row: filter byte, then a red pixel
+        let idat = 8 + 25 + 8;
+        assert_eq!(png[idat + 2 + 5..idat + 2 + 5 + 4], [0, 0xFF, 0, 0]);
+    }
+    
+    #[test]
+    fn test_terminal_rows_pair_up() {
+        let fb = Framebuffer::new(3, 4);
+        let text = fb.to_terminal();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(text.matches('▀').count(), 6);
+    }
+}
@@ -0,0 +1,150 @@
+//! File-Backed Mock Storage
+//! 
+//! 🔗 T4-HAL-020: Desktop EEPROM Image
+//! Derived From: T4-HAL-017 (On-Chip EEPROM Storage) + T2-HAL-001 (Platform-Independent Hardware Abstraction Design)
+//! AI Traceability: Configuration and learned data round-trip across simulator runs, so persistence and backup/restore are testable on desktop
+//! 
+//! The file is a byte-for-byte image of the 4 KB EEPROM in the same fixed
+//! region layout (`StorageRegion`), so an image saved by the simulator can be
+//! inspected region by region or written to a car unchanged. Every write goes
+//! through to the file before it returns, matching the EEPROM promise that a
+//! completed write survives key-off.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::{format, vec, vec::Vec};
+
+use crate::{HalError, HalResult, NonVolatileStorage, StorageRegion, EEPROM_SIZE, ERASED_BYTE};
+
+/// EEPROM image persisted to a file
+#[derive(Debug)]
+pub struct MockStorage {
+    path: PathBuf,
+    file: File,
+    image: Vec<u8>,
+    writes: u32,
+}
+
+impl MockStorage {
+    /// Open an image file, creating an erased one if it does not exist
+    /// 
+    /// A short image (e.g. from an older, smaller layout) reads as erased
+    /// cells past its end; the file is padded to the full size.
+    pub fn open(path: impl AsRef<Path>) -> HalResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false)
+            .open(&path)
+            .map_err(|e| HalError::InitializationFailed(format!("{}: {}", path.display(), e)))?;
+        let mut image = Vec::new();
+        file.read_to_end(&mut image).map_err(|e| HalError::InitializationFailed(e.to_string()))?;
+        image.resize(EEPROM_SIZE, ERASED_BYTE);
+        image.truncate(EEPROM_SIZE);
+        file.seek(SeekFrom::Start(0)).and_then(|_| file.write_all(&image)).and_then(|_| file.set_len(EEPROM_SIZE as u64))
+            .map_err(|e| HalError::InitializationFailed(e.to_string()))?;
+        
+        Ok(Self { path, file, image, writes: 0 })
+    }
+    
+    /// Image file location
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    
+    /// Whole EEPROM image
+    pub fn image(&self) -> &[u8] {
+        &self.image
+    }
+    
+    /// Contents of one region
+    pub fn region(&self, region: StorageRegion) -> &[u8] {
+        &self.image[region.offset..region.offset + region.len]
+    }
+    
+    /// Writes since the image was opened
+    pub fn writes(&self) -> u32 {
+        self.writes
+    }
+}
+
+impl NonVolatileStorage for MockStorage {
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize> {
+        let len = buffer.len().min(EEPROM_SIZE.saturating_sub(offset));
+        buffer[..len].copy_from_slice(&self.image[offset.min(EEPROM_SIZE)..][..len]);
+        Ok(len)
+    }
+    
+    fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()> {
+        if offset + data.len() > EEPROM_SIZE {
+            return Err(HalError::InvalidParameter("Write past the end of storage".into()));
+        }
+        self.writes += 1;
+        self.file.seek(SeekFrom::Start(offset as u64)).and_then(|_| self.file.write_all(data))
+            .map_err(|e| HalError::HardwareFault(format!("Storage write: {}", e)))?;
+        self.image[offset..offset + data.len()].copy_from_slice(data);
+        Ok(())
+    }
+    
+    fn erase_all(&mut self) -> HalResult<()> {
+        let erased = vec![ERASED_BYTE; EEPROM_SIZE];
+        self.write(0, &erased)
+    }
+    
+    fn sync(&mut self) -> HalResult<()> {
+        self.file.sync_data().map_err(|e| HalError::HardwareFault(format!("Storage sync: {}", e)))
+    }
+    
+    fn get_size(&self) -> usize {
+        EEPROM_SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    
+    fn image_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("rumbledome-mock-{}-{}.bin", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+    
+    #[test]
+    fn test_image_persists_across_reopen() {
+        let path = image_path("reopen");
+        let mut storage = MockStorage::open(&path).unwrap();
+        assert!(storage.image().iter().all(|&byte| byte == ERASED_BYTE));
+        
+        let address = StorageRegion::LEARNED_DATA.address(8, 4).unwrap();
+        storage.write(address, &[1, 2, 3, 4]).unwrap();
+        drop(storage);
+        
+        let mut storage = MockStorage::open(&path).unwrap();
+        let mut buffer = [0; 4];
+        storage.read(address, &mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2, 3, 4]);
+        assert_eq!(storage.region(StorageRegion::LEARNED_DATA)[8..12], [1, 2, 3, 4]);
+        assert!(storage.region(StorageRegion::CONFIG).iter().all(|&byte| byte == ERASED_BYTE));
+        assert_eq!(fs::metadata(&path).unwrap().len(), EEPROM_SIZE as u64);
+        
+        storage.erase_all().unwrap();
+        drop(storage);
+        assert!(fs::read(&path).unwrap().iter().all(|&byte| byte == ERASED_BYTE));
+        let _ = fs::remove_file(&path);
+    }
+    
+    #[test]
+    fn test_short_image_reads_erased_past_its_end() {
+        let path = image_path("short");
+        fs::write(&path, [7; 16]).unwrap();
+        
+        let mut storage = MockStorage::open(&path).unwrap();
+        let mut buffer = [0; 4];
+        storage.read(14, &mut buffer).unwrap();
+        assert_eq!(buffer, [7, 7, ERASED_BYTE, ERASED_BYTE]);
+        assert!(storage.write(EEPROM_SIZE - 1, &[0, 0]).is_err());
+        assert_eq!(fs::metadata(&path).unwrap().len(), EEPROM_SIZE as u64);
+        let _ = fs::remove_file(&path);
+    }
+}
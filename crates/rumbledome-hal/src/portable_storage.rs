@@ -0,0 +1,52 @@
+//! Portable (SD Card) Storage Interface
+//!
+//! 🔗 T4-HAL-021: SD Card File Storage
+//! Derived From: T2-HAL-002 (Portable Storage Interface Design) + T2-STORAGE-001 (SD Card Primary Storage)
+//! AI Traceability: Datalogs and backups are files a laptop can read straight off the card
+//!
+//! Paths are relative to the card root, with `/` between directories
+//! (`RUMBLEDOME/logs/0001.csv`); directories are created as files are
+//! written into them. The card can be pulled at any time, so every call
+//! fails with `HalError::HardwareFault` while no card is mounted and
+//! callers are expected to carry on without it.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use crate::HalResult;
+
+/// Card size and space left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardInfo {
+    /// Card capacity (bytes)
+    pub capacity_bytes: u64,
+    /// Space left for files (bytes)
+    pub free_bytes: u64,
+}
+
+/// Removable FAT32 file storage
+pub trait PortableStorage {
+    /// Whether a card is inserted and mounted
+    fn is_mounted(&self) -> bool;
+
+    /// Append `data` to a file, creating it (and its directory) if missing
+    fn append_file(&mut self, path: &str, data: &[u8]) -> HalResult<()>;
+
+    /// Read into `buffer` from `offset` in a file, returning the bytes read (0 at the end)
+    fn read_file(&mut self, path: &str, offset: u64, buffer: &mut [u8]) -> HalResult<usize>;
+
+    /// Size of a file, or `None` if it does not exist (bytes)
+    fn file_size(&self, path: &str) -> HalResult<Option<u64>>;
+
+    /// Names of the files in a directory, sorted (empty if the directory does not exist)
+    fn list_files(&self, directory: &str) -> HalResult<Vec<String>>;
+
+    /// Delete a file (missing files are not an error)
+    fn remove_file(&mut self, path: &str) -> HalResult<()>;
+
+    /// Card size and space left
+    fn card_info(&self) -> HalResult<CardInfo>;
+}
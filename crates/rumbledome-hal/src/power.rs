@@ -0,0 +1,26 @@
+//! Supply Power Monitoring
+//! 
+//! 🔗 T4-HAL-014: Supply Voltage Monitor
+//! Derived From: T2-HAL-001 (Platform-Independent Hardware Abstraction Design) + Hardware.md power requirements
+//! AI Traceability: Solenoid drive depends on battery voltage - the output stage needs to know what it is driving with
+//! 
+//! The MCU's own die temperature is read here too: an enclosure heat-soaked
+//! under the hood is the other supply-side condition the firmware has to
+//! plan its work around.
+
+use crate::HalResult;
+
+/// Nominal supply voltage with the engine running and the alternator charging (V)
+pub const NOMINAL_SUPPLY_VOLTAGE: f32 = 13.8;
+
+/// Vehicle supply voltage measurement
+/// 
+/// Read from the power-monitor divider on an ADC channel. Platforms without
+/// one return `HalError::NotSupported`.
+pub trait PowerMonitor {
+    /// Supply voltage at the solenoid drivers (V)
+    fn supply_voltage(&self) -> HalResult<f32>;
+    
+    /// MCU die temperature from its on-chip sensor (°C)
+    fn mcu_temperature(&self) -> HalResult<f32>;
+}
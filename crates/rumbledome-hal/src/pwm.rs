@@ -10,6 +10,8 @@ use alloc::{string::{String, ToString}, format};
 #[cfg(feature = "std")]
 use std::{string::{String, ToString}, format};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{HalResult, HalError, time::PwmTimingInfo};
 
 /// PWM control interface for solenoid drive
@@ -59,6 +61,53 @@ pub trait PwmControl {
     /// Bypasses timing synchronization for safety-critical situations
     /// Used for overboost protection and fault responses
     fn set_duty_cycle_immediate(&mut self, duty_percent: f32) -> HalResult<()>;
+    
+    /// Set duty cycle on a specific solenoid drive channel
+    /// 
+    /// 🔗 T4-HAL-011: Per-Channel Solenoid Drive
+    /// Derived From: Architecture.md full-dome control + dual-solenoid plumbing option
+    /// Single 4-port valve setups only use `SolenoidChannel::Primary`, which is the
+    /// same output driven by `set_duty_cycle()`
+    fn set_channel_duty_cycle(&mut self, channel: SolenoidChannel, duty_percent: f32) -> HalResult<()>;
+    
+    /// Get drive circuit status for a solenoid channel
+    /// 
+    /// Reports open-circuit/short-circuit conditions detected by the driver's
+    /// current monitoring so the core can degrade or failsafe accordingly
+    fn get_channel_status(&self, channel: SolenoidChannel) -> HalResult<SolenoidDriveStatus>;
+}
+
+/// Solenoid drive channel identifier
+/// 
+/// Full-dome setups can be plumbed either with a single 4-port MAC valve
+/// (Primary only) or with two independent solenoids, one per dome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolenoidChannel {
+    /// 4-port valve, or upper dome (closing) solenoid in dual-solenoid setups
+    Primary,
+    /// Lower dome (opening) solenoid in dual-solenoid setups
+    Secondary,
+}
+
+/// Drive circuit status reported by solenoid current monitoring
+/// 
+/// 🔗 T4-HAL-012: Solenoid Drive Fault Detection
+/// Derived From: T2-HAL-004 (Current monitoring for open coil or short circuit conditions)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolenoidDriveStatus {
+    /// Coil current within expected range
+    Ok,
+    /// No coil current when energized - broken wire or failed coil
+    OpenCircuit,
+    /// Excessive coil current - driver has shut the channel down
+    ShortCircuit,
+}
+
+impl SolenoidDriveStatus {
+    /// Check if the channel can still be driven
+    pub fn is_functional(&self) -> bool {
+        matches!(self, SolenoidDriveStatus::Ok)
+    }
 }
 
 /// PWM-specific error types
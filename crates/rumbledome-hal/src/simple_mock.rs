@@ -3,27 +3,301 @@
 //! Minimal working version to get the build system functional
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{collections::{BTreeMap, VecDeque}, format, string::String, vec, vec::Vec};
 
 #[cfg(feature = "std")]
-use std::vec::Vec;
+use std::{collections::{BTreeMap, VecDeque}, format, string::String, vec, vec::Vec};
 
 use crate::{
     HalTrait, HalResult, HalError, TestStatus, SelfTestResult,
     TimeProvider, PwmControl, PlatformInfo, PlatformCapabilities,
+    SolenoidChannel, SolenoidDriveStatus, ActuatorOutput, ActuatorInterface, PowerMonitor, NOMINAL_SUPPLY_VOLTAGE,
+    AnalogInput, PressureCalibration, ANALOG_CHANNELS, sensor_voltage_to_counts,
+    CanInterface, CanFrame, CanFilter, CanStats,
+    NonVolatileStorage, EEPROM_SIZE, ERASED_BYTE,
+    GpioControl, GpioInput, GpioOutput, DisplayInterface, Framebuffer,
+    ConsoleSerial, ConsolePort, ConsoleConnection, PortableStorage, CardInfo,
 };
 
-/// Simplified mock HAL for basic functionality
+#[cfg(feature = "std")]
+use crate::MockStorage;
+
+/// Receive buffer depth of the simulated CAN controller (frames)
+const CAN_RX_BUFFER_FRAMES: usize = 16;
+
+/// One simulated console port
 #[derive(Debug, Default)]
+struct MockConsole {
+    connection: Option<ConsoleConnection>,
+    rx: VecDeque<u8>,
+    tx: Vec<u8>,
+}
+
+/// Simplified mock HAL for basic functionality
+#[derive(Debug)]
 pub struct SimpleMockHal {
     duty_cycle: f32,
+    secondary_duty_cycle: f32,
+    primary_status: SolenoidDriveStatus,
+    secondary_status: SolenoidDriveStatus,
+    motor_position: f32,
+    can_position: f32,
+    supply_voltage: f32,
+    mcu_temperature_c: f32,
+    analog_voltages: [f32; ANALOG_CHANNELS],
+    calibrations: [PressureCalibration; ANALOG_CHANNELS],
+    can_bitrate_bps: u32,
+    can_filters: Vec<CanFilter>,
+    can_rx: VecDeque<CanFrame>,
+    can_sent: Vec<CanFrame>,
+    can_stats: CanStats,
+    storage: Vec<u8>,
+    storage_writes: u32,
+    torn_write_bytes: Option<usize>,
+    scramble_button: bool,
+    profile_button: bool,
+    status_led: bool,
+    display: Framebuffer,
+    consoles: [MockConsole; 2],
+    console_connections: u32,
+    bluetooth_name: Option<String>,
+    sd_card: Option<BTreeMap<String, Vec<u8>>>,
+    /// Image file every storage write also goes to
+    #[cfg(feature = "std")]
+    storage_file: Option<MockStorage>,
     initialized: bool,
+    now_ms: u32,
+}
+
+impl Default for SimpleMockHal {
+    fn default() -> Self {
+        Self {
+            duty_cycle: 0.0,
+            secondary_duty_cycle: 0.0,
+            primary_status: SolenoidDriveStatus::Ok,
+            secondary_status: SolenoidDriveStatus::Ok,
+            motor_position: 0.0,
+            can_position: 0.0,
+            supply_voltage: NOMINAL_SUPPLY_VOLTAGE,
+            mcu_temperature_c: 45.0,
+            // Every sensor at atmosphere (0 PSI gauge)
+            analog_voltages: [PressureCalibration::default().zero_voltage; ANALOG_CHANNELS],
+            calibrations: [PressureCalibration::default(); ANALOG_CHANNELS],
+            can_bitrate_bps: 500_000,
+            can_filters: Vec::new(),
+            can_rx: VecDeque::new(),
+            can_sent: Vec::new(),
+            can_stats: CanStats::default(),
+            storage: vec![ERASED_BYTE; EEPROM_SIZE],
+            storage_writes: 0,
+            torn_write_bytes: None,
+            scramble_button: false,
+            profile_button: false,
+            status_led: false,
+            display: Framebuffer::default(),
+            consoles: Default::default(),
+            console_connections: 0,
+            bluetooth_name: None,
+            sd_card: Some(BTreeMap::new()),
+            #[cfg(feature = "std")]
+            storage_file: None,
+            initialized: false,
+            now_ms: 1000,
+        }
+    }
 }
 
 impl SimpleMockHal {
     pub fn new() -> Self {
         Self::default()
     }
+    
+    /// Mock HAL whose storage starts from and writes through to an image file
+    /// 
+    /// Configuration and learned data saved in one simulator run are there
+    /// at the next start, as across a key cycle.
+    #[cfg(feature = "std")]
+    pub fn with_storage(storage: MockStorage) -> Self {
+        let mut hal = Self::default();
+        hal.storage.copy_from_slice(storage.image());
+        hal.storage_file = Some(storage);
+        hal
+    }
+    
+    /// Inject a solenoid drive fault for testing failover behavior
+    pub fn set_channel_status(&mut self, channel: SolenoidChannel, status: SolenoidDriveStatus) {
+        match channel {
+            SolenoidChannel::Primary => self.primary_status = status,
+            SolenoidChannel::Secondary => self.secondary_status = status,
+        }
+    }
+    
+    /// Simulate a supply voltage (e.g. sag at idle with accessories loaded)
+    pub fn set_supply_voltage(&mut self, volts: f32) {
+        self.supply_voltage = volts;
+    }
+    
+    /// Simulate an MCU die temperature (e.g. a heat-soaked enclosure)
+    pub fn set_mcu_temperature(&mut self, celsius: f32) {
+        self.mcu_temperature_c = celsius;
+    }
+    
+    /// Simulate a button contact (raw level - bounce it to exercise debouncing)
+    pub fn set_button(&mut self, input: GpioInput, pressed: bool) {
+        match input {
+            GpioInput::ScrambleButton => self.scramble_button = pressed,
+            GpioInput::ProfileButton => self.profile_button = pressed,
+        }
+    }
+    
+    /// Whether the status LED is lit
+    pub fn status_led(&self) -> bool {
+        self.status_led
+    }
+    
+    /// What the display is showing
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.display
+    }
+    
+    /// Simulate a client connecting to a console port (replaces any client already there)
+    pub fn connect_console(&mut self, port: ConsolePort, peer: Option<&str>) {
+        self.console_connections += 1;
+        let connection = ConsoleConnection {
+            id: self.console_connections,
+            peer: peer.map(String::from),
+            signal_strength_dbm: (port == ConsolePort::Bluetooth).then_some(-60),
+            connected_ms: self.now_ms(),
+            bytes_transferred: 0,
+        };
+        let console = &mut self.consoles[port as usize];
+        console.rx.clear();
+        console.connection = Some(connection);
+    }
+    
+    /// Simulate the client on a console port going away
+    pub fn disconnect_console(&mut self, port: ConsolePort) {
+        let console = &mut self.consoles[port as usize];
+        console.connection = None;
+        console.rx.clear();
+    }
+    
+    /// Simulate bytes arriving from the client on a console port (dropped while disconnected)
+    pub fn push_console_input(&mut self, port: ConsolePort, data: &[u8]) {
+        let console = &mut self.consoles[port as usize];
+        if let Some(connection) = console.connection.as_mut() {
+            connection.bytes_transferred += data.len() as u64;
+            console.rx.extend(data.iter().copied());
+        }
+    }
+    
+    /// Bytes sent to the client on a console port since the last call
+    pub fn take_console_output(&mut self, port: ConsolePort) -> Vec<u8> {
+        core::mem::take(&mut self.consoles[port as usize].tx)
+    }
+    
+    /// Name the Bluetooth port is advertised under, while advertising
+    pub fn bluetooth_name(&self) -> Option<&str> {
+        self.bluetooth_name.as_deref()
+    }
+    
+    /// Simulate pulling the SD card (files on it are lost)
+    pub fn remove_sd_card(&mut self) {
+        self.sd_card = None;
+    }
+    
+    /// Simulate inserting an empty SD card
+    pub fn insert_sd_card(&mut self) {
+        self.sd_card.get_or_insert_with(BTreeMap::new);
+    }
+    
+    /// Contents of a file on the SD card
+    pub fn sd_file(&self, path: &str) -> Option<&[u8]> {
+        self.sd_card.as_ref()?.get(path).map(Vec::as_slice)
+    }
+    
+    fn card(&self) -> HalResult<&BTreeMap<String, Vec<u8>>> {
+        self.sd_card.as_ref().ok_or_else(|| HalError::HardwareFault(String::from("No SD card")))
+    }
+    
+    fn card_mut(&mut self) -> HalResult<&mut BTreeMap<String, Vec<u8>>> {
+        self.sd_card.as_mut().ok_or_else(|| HalError::HardwareFault(String::from("No SD card")))
+    }
+    
+    /// Simulate a sensor output voltage on an analog channel (e.g. 0.0 for an unplugged sensor)
+    pub fn set_analog_voltage(&mut self, channel: u8, volts: f32) {
+        self.analog_voltages[channel as usize] = volts;
+    }
+    
+    /// Simulate a pressure on an analog channel through its calibration curve
+    pub fn set_pressure_psi(&mut self, channel: u8, psi: f32) {
+        self.analog_voltages[channel as usize] = self.calibrations[channel as usize].psi_to_voltage(psi);
+    }
+    
+    /// Simulate a frame arriving on the bus (dropped unless it passes the filters)
+    pub fn receive_can_frame(&mut self, frame: CanFrame) {
+        if !self.can_filters.is_empty() && !self.can_filters.iter().any(|filter| filter.matches(frame.id)) {
+            return;
+        }
+        if self.can_rx.len() >= CAN_RX_BUFFER_FRAMES {
+            self.can_stats.rx_overruns += 1;
+            return;
+        }
+        self.can_stats.frames_received += 1;
+        self.can_rx.push_back(frame);
+    }
+    
+    /// Frames sent since creation
+    pub fn sent_can_frames(&self) -> &[CanFrame] {
+        &self.can_sent
+    }
+    
+    /// Bus bitrate last set (bit/s)
+    pub fn can_bitrate(&self) -> u32 {
+        self.can_bitrate_bps
+    }
+    
+    /// Storage contents, e.g. to carry across a simulated power cycle
+    pub fn storage_image(&self) -> &[u8] {
+        &self.storage
+    }
+    
+    /// Replace the storage contents (image shorter than the storage leaves the rest erased)
+    pub fn set_storage_image(&mut self, image: &[u8]) {
+        self.storage.fill(ERASED_BYTE);
+        let len = image.len().min(EEPROM_SIZE);
+        self.storage[..len].copy_from_slice(&image[..len]);
+    }
+    
+    /// Simulate power loss during the next write: only its first `bytes` reach the cells
+    pub fn tear_next_write(&mut self, bytes: usize) {
+        self.torn_write_bytes = Some(bytes);
+    }
+    
+    /// Writes since creation
+    pub fn storage_writes(&self) -> u32 {
+        self.storage_writes
+    }
+    
+    /// Set the clock every time reading returns (starts at 1000 ms)
+    pub fn set_now_ms(&mut self, now_ms: u32) {
+        self.now_ms = now_ms;
+    }
+    
+    fn analog_channel(channel: u8) -> HalResult<usize> {
+        if channel as usize >= ANALOG_CHANNELS {
+            return Err(HalError::InvalidParameter("Analog channel out of range".into()));
+        }
+        Ok(channel as usize)
+    }
+    
+    /// Get duty cycle last commanded on a specific channel
+    pub fn get_channel_duty(&self, channel: SolenoidChannel) -> f32 {
+        match channel {
+            SolenoidChannel::Primary => self.duty_cycle,
+            SolenoidChannel::Secondary => self.secondary_duty_cycle,
+        }
+    }
 }
 
 impl HalTrait for SimpleMockHal {
@@ -52,7 +326,7 @@ impl HalTrait for SimpleMockHal {
             capabilities: PlatformCapabilities {
                 has_pwm: true,
                 analog_channels: 8,
-                storage_size: 1024,
+                storage_size: EEPROM_SIZE,
                 can_controllers: 2,
                 display_resolution: (128, 160),
                 has_bluetooth: true,
@@ -62,18 +336,21 @@ impl HalTrait for SimpleMockHal {
 
     fn emergency_shutdown(&mut self) -> HalResult<()> {
         self.duty_cycle = 0.0;
+        self.secondary_duty_cycle = 0.0;
+        self.motor_position = 0.0;
+        self.can_position = 0.0;
         Ok(())
     }
 }
 
 impl TimeProvider for SimpleMockHal {
     fn now_us(&self) -> u64 {
-        // Simple mock time - just return a fixed value for now
-        1000000
+        // Simple mock time - stands still unless a test moves it
+        self.now_ms as u64 * 1000
     }
 
     fn now_ms(&self) -> u32 {
-        1000
+        self.now_ms
     }
 
     fn delay_us(&mut self, _microseconds: u32) -> HalResult<()> {
@@ -116,6 +393,7 @@ impl PwmControl for SimpleMockHal {
 
     fn disable(&mut self) -> HalResult<()> {
         self.duty_cycle = 0.0;
+        self.secondary_duty_cycle = 0.0;
         Ok(())
     }
 
@@ -139,6 +417,278 @@ impl PwmControl for SimpleMockHal {
     fn set_frequency(&mut self, _freq_hz: u32) -> HalResult<()> {
         Ok(())
     }
+
+    fn set_channel_duty_cycle(&mut self, channel: SolenoidChannel, duty_percent: f32) -> HalResult<()> {
+        match channel {
+            SolenoidChannel::Primary => self.set_duty_cycle(duty_percent),
+            SolenoidChannel::Secondary => {
+                if !(0.0..=100.0).contains(&duty_percent) {
+                    return Err(HalError::InvalidParameter("Duty cycle out of range".into()));
+                }
+                self.secondary_duty_cycle = duty_percent;
+                Ok(())
+            }
+        }
+    }
+
+    fn get_channel_status(&self, channel: SolenoidChannel) -> HalResult<SolenoidDriveStatus> {
+        Ok(match channel {
+            SolenoidChannel::Primary => self.primary_status,
+            SolenoidChannel::Secondary => self.secondary_status,
+        })
+    }
+}
+
+impl ActuatorOutput for SimpleMockHal {
+    fn set_actuator_position(&mut self, interface: ActuatorInterface, position_percent: f32) -> HalResult<()> {
+        if !(0.0..=100.0).contains(&position_percent) {
+            return Err(HalError::InvalidParameter("Actuator position out of range".into()));
+        }
+        match interface {
+            ActuatorInterface::MotorDriver => self.motor_position = position_percent,
+            ActuatorInterface::Can => self.can_position = position_percent,
+        }
+        Ok(())
+    }
+
+    fn get_actuator_position(&self, interface: ActuatorInterface) -> HalResult<f32> {
+        // Mock actuators reach the commanded position instantly
+        Ok(match interface {
+            ActuatorInterface::MotorDriver => self.motor_position,
+            ActuatorInterface::Can => self.can_position,
+        })
+    }
+}
+
+impl PowerMonitor for SimpleMockHal {
+    fn supply_voltage(&self) -> HalResult<f32> {
+        Ok(self.supply_voltage)
+    }
+    
+    fn mcu_temperature(&self) -> HalResult<f32> {
+        Ok(self.mcu_temperature_c)
+    }
+}
+
+impl AnalogInput for SimpleMockHal {
+    fn read_raw(&self, channel: u8) -> HalResult<u16> {
+        Ok(sensor_voltage_to_counts(self.analog_voltages[Self::analog_channel(channel)?]))
+    }
+    
+    fn read_voltage(&self, channel: u8) -> HalResult<f32> {
+        // Ideal ADC - no quantization, so simulated pressures read back exactly
+        Ok(self.analog_voltages[Self::analog_channel(channel)?])
+    }
+    
+    fn calibration(&self, channel: u8) -> HalResult<PressureCalibration> {
+        Ok(self.calibrations[Self::analog_channel(channel)?])
+    }
+    
+    fn set_calibration(&mut self, channel: u8, calibration: PressureCalibration) -> HalResult<()> {
+        calibration.validate()?;
+        self.calibrations[Self::analog_channel(channel)?] = calibration;
+        Ok(())
+    }
+}
+
+impl CanInterface for SimpleMockHal {
+    fn set_bitrate(&mut self, bitrate_bps: u32) -> HalResult<()> {
+        if bitrate_bps == 0 {
+            return Err(HalError::InvalidParameter("CAN bitrate must be above zero".into()));
+        }
+        self.can_bitrate_bps = bitrate_bps;
+        Ok(())
+    }
+    
+    fn send_frame(&mut self, frame: &CanFrame) -> HalResult<()> {
+        self.can_stats.frames_sent += 1;
+        self.can_sent.push(*frame);
+        Ok(())
+    }
+    
+    fn receive_frame(&mut self) -> HalResult<Option<CanFrame>> {
+        Ok(self.can_rx.pop_front())
+    }
+    
+    fn set_filters(&mut self, filters: &[CanFilter]) -> HalResult<()> {
+        self.can_filters = filters.to_vec();
+        Ok(())
+    }
+    
+    fn can_stats(&self) -> CanStats {
+        self.can_stats
+    }
+}
+
+impl NonVolatileStorage for SimpleMockHal {
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize> {
+        let len = buffer.len().min(EEPROM_SIZE.saturating_sub(offset));
+        buffer[..len].copy_from_slice(&self.storage[offset.min(EEPROM_SIZE)..][..len]);
+        Ok(len)
+    }
+    
+    fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()> {
+        if offset + data.len() > EEPROM_SIZE {
+            return Err(HalError::InvalidParameter("Write past the end of storage".into()));
+        }
+        self.storage_writes += 1;
+        if let Some(bytes) = self.torn_write_bytes.take() {
+            let torn = bytes.min(data.len());
+            self.storage[offset..offset + torn].copy_from_slice(&data[..torn]);
+            #[cfg(feature = "std")]
+            if let Some(file) = self.storage_file.as_mut() {
+                file.write(offset, &data[..torn])?;
+            }
+            return Err(HalError::HardwareFault("Power lost during storage write".into()));
+        }
+        #[cfg(feature = "std")]
+        if let Some(file) = self.storage_file.as_mut() {
+            file.write(offset, data)?;
+        }
+        self.storage[offset..offset + data.len()].copy_from_slice(data);
+        Ok(())
+    }
+    
+    fn erase_all(&mut self) -> HalResult<()> {
+        #[cfg(feature = "std")]
+        if let Some(file) = self.storage_file.as_mut() {
+            file.erase_all()?;
+        }
+        self.storage.fill(ERASED_BYTE);
+        Ok(())
+    }
+    
+    fn sync(&mut self) -> HalResult<()> {
+        #[cfg(feature = "std")]
+        if let Some(file) = self.storage_file.as_mut() {
+            file.sync()?;
+        }
+        Ok(())
+    }
+    
+    fn get_size(&self) -> usize {
+        EEPROM_SIZE
+    }
+}
+
+impl GpioControl for SimpleMockHal {
+    fn read_input(&self, input: GpioInput) -> HalResult<bool> {
+        Ok(match input {
+            GpioInput::ScrambleButton => self.scramble_button,
+            GpioInput::ProfileButton => self.profile_button,
+        })
+    }
+    
+    fn write_output(&mut self, output: GpioOutput, on: bool) -> HalResult<()> {
+        match output {
+            GpioOutput::StatusLed => self.status_led = on,
+        }
+        Ok(())
+    }
+}
+
+impl DisplayInterface for SimpleMockHal {
+    fn display_size(&self) -> (u16, u16) {
+        self.display.display_size()
+    }
+    
+    fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) -> HalResult<()> {
+        self.display.fill_rect(x, y, width, height, color)
+    }
+    
+    fn write_pixels(&mut self, x: u16, y: u16, width: u16, pixels: &[u16]) -> HalResult<()> {
+        self.display.write_pixels(x, y, width, pixels)
+    }
+    
+    fn flush(&mut self) -> HalResult<()> {
+        self.display.flush()
+    }
+    
+    fn set_backlight(&mut self, percent: u8) -> HalResult<()> {
+        self.display.set_backlight(percent)
+    }
+}
+
+impl ConsoleSerial for SimpleMockHal {
+    fn start_bluetooth(&mut self, name: &str, _pin: &str) -> HalResult<()> {
+        self.bluetooth_name = Some(String::from(name));
+        Ok(())
+    }
+    
+    fn stop_bluetooth(&mut self) -> HalResult<()> {
+        self.bluetooth_name = None;
+        self.disconnect_console(ConsolePort::Bluetooth);
+        Ok(())
+    }
+    
+    fn console_connection(&self, port: ConsolePort) -> Option<ConsoleConnection> {
+        self.consoles[port as usize].connection.clone()
+    }
+    
+    fn console_receive(&mut self, port: ConsolePort, buffer: &mut [u8]) -> HalResult<usize> {
+        let rx = &mut self.consoles[port as usize].rx;
+        let count = buffer.len().min(rx.len());
+        for (slot, byte) in buffer.iter_mut().zip(rx.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+    
+    fn console_send(&mut self, port: ConsolePort, data: &[u8]) -> HalResult<()> {
+        let console = &mut self.consoles[port as usize];
+        let Some(connection) = console.connection.as_mut() else {
+            return Err(HalError::CommunicationError(String::from("No client connected")));
+        };
+        connection.bytes_transferred += data.len() as u64;
+        console.tx.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Simulated card size (bytes)
+const MOCK_SD_CAPACITY: u64 = 8 * 1024 * 1024 * 1024;
+
+impl PortableStorage for SimpleMockHal {
+    fn is_mounted(&self) -> bool {
+        self.sd_card.is_some()
+    }
+    
+    fn append_file(&mut self, path: &str, data: &[u8]) -> HalResult<()> {
+        self.card_mut()?.entry(String::from(path)).or_default().extend_from_slice(data);
+        Ok(())
+    }
+    
+    fn read_file(&mut self, path: &str, offset: u64, buffer: &mut [u8]) -> HalResult<usize> {
+        let file = self.card()?.get(path)
+            .ok_or_else(|| HalError::InvalidParameter(format!("{}: no such file", path)))?;
+        let start = (offset as usize).min(file.len());
+        let count = buffer.len().min(file.len() - start);
+        buffer[..count].copy_from_slice(&file[start..start + count]);
+        Ok(count)
+    }
+    
+    fn file_size(&self, path: &str) -> HalResult<Option<u64>> {
+        Ok(self.card()?.get(path).map(|file| file.len() as u64))
+    }
+    
+    fn list_files(&self, directory: &str) -> HalResult<Vec<String>> {
+        let prefix = format!("{}/", directory.trim_end_matches('/'));
+        Ok(self.card()?.keys()
+            .filter_map(|path| path.strip_prefix(&prefix))
+            .filter(|name| !name.contains('/'))
+            .map(String::from)
+            .collect())
+    }
+    
+    fn remove_file(&mut self, path: &str) -> HalResult<()> {
+        self.card_mut()?.remove(path);
+        Ok(())
+    }
+    
+    fn card_info(&self) -> HalResult<CardInfo> {
+        let used: u64 = self.card()?.values().map(|file| file.len() as u64).sum();
+        Ok(CardInfo { capacity_bytes: MOCK_SD_CAPACITY, free_bytes: MOCK_SD_CAPACITY - used })
+    }
 }
 
 #[cfg(test)]
@@ -170,6 +720,143 @@ mod tests {
         assert_eq!(hal.get_current_duty(), 0.0);
     }
 
+    #[test]
+    fn test_channel_fault_injection() {
+        let mut hal = SimpleMockHal::new();
+        
+        assert_eq!(hal.get_channel_status(SolenoidChannel::Secondary).unwrap(), SolenoidDriveStatus::Ok);
+        hal.set_channel_status(SolenoidChannel::Secondary, SolenoidDriveStatus::OpenCircuit);
+        assert!(!hal.get_channel_status(SolenoidChannel::Secondary).unwrap().is_functional());
+        
+        // Secondary channel is independent of the primary output
+        hal.set_channel_duty_cycle(SolenoidChannel::Secondary, 40.0).unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 40.0);
+        assert_eq!(hal.get_current_duty(), 0.0);
+        
+        // Disable forces both channels to failsafe
+        hal.disable().unwrap();
+        assert_eq!(hal.get_channel_duty(SolenoidChannel::Secondary), 0.0);
+    }
+
+    #[test]
+    fn test_analog_pressure_through_calibration() {
+        let mut hal = SimpleMockHal::new();
+        assert_eq!(hal.read_pressure_psi(0).unwrap(), 0.0);
+        
+        hal.set_pressure_psi(1, 12.0);
+        assert!((hal.read_pressure_psi(1).unwrap() - 12.0).abs() < 1e-4);
+        assert!(hal.read_raw(1).unwrap() > hal.read_raw(0).unwrap());
+        
+        // 0-100 PSI feed sensor on the same output range
+        let feed = PressureCalibration { full_scale_psi: 100.0, ..PressureCalibration::default() };
+        hal.set_calibration(1, feed).unwrap();
+        assert!((hal.read_pressure_psi(1).unwrap() - 40.0).abs() < 1e-3);
+        
+        hal.set_analog_voltage(2, 0.0);
+        assert!(hal.read_pressure_psi(2).is_err());
+        assert!(hal.read_raw(ANALOG_CHANNELS as u8).is_err());
+    }
+    
+    #[test]
+    fn test_can_receive_through_filters() {
+        let mut hal = SimpleMockHal::new();
+        hal.set_filters(&[CanFilter::exact(0x109)]).unwrap();
+        
+        hal.receive_can_frame(CanFrame::new(0x204, &[1]).unwrap());
+        hal.receive_can_frame(CanFrame::new(0x109, &[2]).unwrap());
+        assert_eq!(hal.receive_frame().unwrap().map(|frame| frame.data[0]), Some(2));
+        assert_eq!(hal.receive_frame().unwrap(), None);
+        
+        for _ in 0..CAN_RX_BUFFER_FRAMES + 1 {
+            hal.receive_can_frame(CanFrame::new(0x109, &[0]).unwrap());
+        }
+        assert_eq!(hal.can_stats().rx_overruns, 1);
+    }
+    
+    #[test]
+    fn test_console_connections() {
+        let mut hal = SimpleMockHal::new();
+        assert!(hal.console_send(ConsolePort::Bluetooth, b"x").is_err());
+        
+        hal.connect_console(ConsolePort::Bluetooth, Some("Pixel 8"));
+        let first = hal.console_connection(ConsolePort::Bluetooth).unwrap();
+        hal.push_console_input(ConsolePort::Bluetooth, b"hello");
+        let mut buffer = [0; 3];
+        assert_eq!(hal.console_receive(ConsolePort::Bluetooth, &mut buffer).unwrap(), 3);
+        assert_eq!(&buffer, b"hel");
+        hal.console_send(ConsolePort::Bluetooth, b"ok").unwrap();
+        assert_eq!(hal.take_console_output(ConsolePort::Bluetooth), b"ok");
+        
+        // A reconnect is a new connection, and unread input from the old one is gone
+        hal.connect_console(ConsolePort::Bluetooth, Some("Pixel 8"));
+        assert_ne!(hal.console_connection(ConsolePort::Bluetooth).unwrap().id, first.id);
+        assert_eq!(hal.console_receive(ConsolePort::Bluetooth, &mut buffer).unwrap(), 0);
+        assert!(hal.console_connection(ConsolePort::Usb).is_none());
+    }
+    
+    #[test]
+    fn test_storage_round_trip_and_torn_write() {
+        let mut hal = SimpleMockHal::new();
+        let mut buffer = [0; 4];
+        assert_eq!(hal.read(EEPROM_SIZE - 2, &mut buffer).unwrap(), 2);
+        assert_eq!(buffer[..2], [ERASED_BYTE; 2]);
+        
+        hal.write(100, &[1, 2, 3, 4]).unwrap();
+        hal.tear_next_write(2);
+        assert!(hal.write(100, &[9, 9, 9, 9]).is_err());
+        hal.read(100, &mut buffer).unwrap();
+        assert_eq!(buffer, [9, 9, 3, 4]);
+        assert!(hal.write(EEPROM_SIZE - 1, &[0, 0]).is_err());
+        
+        // The image carries over to a fresh HAL, as across a power cycle
+        let mut restarted = SimpleMockHal::new();
+        restarted.set_storage_image(hal.storage_image());
+        restarted.read(100, &mut buffer).unwrap();
+        assert_eq!(buffer, [9, 9, 3, 4]);
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_storage_file_survives_restart() {
+        let path = std::env::temp_dir().join(format!("rumbledome-simple-mock-{}.bin", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        
+        let mut hal = SimpleMockHal::with_storage(MockStorage::open(&path).unwrap());
+        hal.write(100, &[1, 2, 3, 4]).unwrap();
+        hal.tear_next_write(1);
+        assert!(hal.write(100, &[9, 9]).is_err());
+        drop(hal);
+        
+        // Torn writes reach the file exactly as far as they reached the cells
+        let mut restarted = SimpleMockHal::with_storage(MockStorage::open(&path).unwrap());
+        let mut buffer = [0; 4];
+        restarted.read(100, &mut buffer).unwrap();
+        assert_eq!(buffer, [9, 2, 3, 4]);
+        let _ = std::fs::remove_file(&path);
+    }
+    
+    #[test]
+    fn test_sd_card_files() {
+        let mut hal = SimpleMockHal::new();
+        hal.append_file("RUMBLEDOME/logs/00002.csv", b"a,b\n").unwrap();
+        hal.append_file("RUMBLEDOME/logs/00001.csv", b"x").unwrap();
+        hal.append_file("RUMBLEDOME/logs/00002.csv", b"1,2\n").unwrap();
+        hal.append_file("RUMBLEDOME/backup.bin", b"x").unwrap();
+        
+        assert_eq!(hal.list_files("RUMBLEDOME/logs").unwrap(), ["00001.csv", "00002.csv"]);
+        let mut buffer = [0; 8];
+        assert_eq!(hal.read_file("RUMBLEDOME/logs/00002.csv", 4, &mut buffer).unwrap(), 4);
+        assert_eq!(&buffer[..4], b"1,2\n");
+        hal.remove_file("RUMBLEDOME/logs/00001.csv").unwrap();
+        assert_eq!(hal.file_size("RUMBLEDOME/logs/00001.csv").unwrap(), None);
+        
+        // A pulled card fails every call
+        hal.remove_sd_card();
+        assert!(!hal.is_mounted());
+        assert!(hal.append_file("RUMBLEDOME/logs/00003.csv", b"x").is_err());
+        assert!(hal.list_files("RUMBLEDOME/logs").is_err());
+    }
+    
     #[test]
     fn test_time_provider() {
         let hal = SimpleMockHal::new();
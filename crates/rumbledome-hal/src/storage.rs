@@ -0,0 +1,152 @@
+//! Non-Volatile Storage Interface
+//! 
+//! 🔗 T4-HAL-017: On-Chip EEPROM Storage
+//! Derived From: Hardware.md Storage (Non-Volatile Memory) + T2-HAL-001 (Platform-Independent Hardware Abstraction Design)
+//! AI Traceability: Small always-present records survive key-off without an SD card; the region layout is fixed so each record wears only its own cells
+//! 
+//! The Teensy 4.1 emulates 4 KB of EEPROM in flash. It is split into eight
+//! 512-byte regions with a fixed assignment. The configuration takes two of
+//! them, so each of its two copies holds around 500 bytes of changes from
+//! the defaults; the flight recorder makes do with one. The safety limits
+//! are kept in three copies, in the last bytes of regions 1, 5 and 6, which
+//! no other record covers; one worn or corrupted region can take out at most
+//! one copy, and no other record's write can reach them.
+//! Power can disappear mid-write at key-off, so a write is only guaranteed
+//! to have reached the cells it completed; records stored here carry their
+//! own integrity check and are laid out so a torn write damages only the
+//! record being written.
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+#[cfg(feature = "std")]
+use std::format;
+
+use crate::{HalError, HalResult};
+
+/// EEPROM capacity (bytes)
+pub const EEPROM_SIZE: usize = 4096;
+
+/// Wear tracking granularity (bytes)
+pub const EEPROM_REGION_SIZE: usize = 512;
+
+/// Rated erase/write cycles per cell
+pub const EEPROM_ENDURANCE_CYCLES: u32 = 100_000;
+
+/// Value of a byte that has never been written or has been erased
+pub const ERASED_BYTE: u8 = 0xFF;
+
+/// Size of one copy of the safety limits (bytes)
+pub const SAFETY_LIMITS_COPY_SIZE: usize = 8;
+
+/// Fixed area of the EEPROM assigned to one kind of record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageRegion {
+    /// First byte of the region
+    pub offset: usize,
+    /// Region length (bytes)
+    pub len: usize,
+}
+
+impl StorageRegion {
+    /// Active configuration (regions 0-1, up to the first safety limits copy)
+    pub const CONFIG: StorageRegion = StorageRegion { offset: 0, len: 2 * EEPROM_REGION_SIZE - SAFETY_LIMITS_COPY_SIZE };
+    /// Learned calibration data (regions 2-4)
+    pub const LEARNED_DATA: StorageRegion = StorageRegion { offset: 2 * EEPROM_REGION_SIZE, len: 3 * EEPROM_REGION_SIZE };
+    /// Sensor calibration (region 5, up to the second safety limits copy)
+    pub const CALIBRATION: StorageRegion = StorageRegion { offset: 5 * EEPROM_REGION_SIZE, len: EEPROM_REGION_SIZE - SAFETY_LIMITS_COPY_SIZE };
+    /// Safety event log (region 6, up to the third safety limits copy)
+    pub const SAFETY_LOG: StorageRegion = StorageRegion { offset: 6 * EEPROM_REGION_SIZE, len: EEPROM_REGION_SIZE - SAFETY_LIMITS_COPY_SIZE };
+    /// Always-on flight recorder ring (region 7)
+    pub const FLIGHT_RECORDER: StorageRegion = StorageRegion { offset: 7 * EEPROM_REGION_SIZE, len: EEPROM_REGION_SIZE };
+    /// Copies of the overboost and duty limits, each ending wear region 1, 5 or 6
+    pub const SAFETY_LIMITS: [StorageRegion; 3] = [
+        StorageRegion::safety_limits_copy(1),
+        StorageRegion::safety_limits_copy(5),
+        StorageRegion::safety_limits_copy(6),
+    ];
+    
+    /// Every region, in address order
+    pub const ALL: [StorageRegion; 8] = [
+        StorageRegion::CONFIG,
+        StorageRegion::SAFETY_LIMITS[0],
+        StorageRegion::LEARNED_DATA,
+        StorageRegion::CALIBRATION,
+        StorageRegion::SAFETY_LIMITS[1],
+        StorageRegion::SAFETY_LOG,
+        StorageRegion::SAFETY_LIMITS[2],
+        StorageRegion::FLIGHT_RECORDER,
+    ];
+    
+    /// Safety limits copy in the last bytes of wear region `region`
+    const fn safety_limits_copy(region: usize) -> StorageRegion {
+        StorageRegion { offset: (region + 1) * EEPROM_REGION_SIZE - SAFETY_LIMITS_COPY_SIZE, len: SAFETY_LIMITS_COPY_SIZE }
+    }
+    
+    /// Absolute address of `len` bytes at `offset` within the region
+    pub fn address(&self, offset: usize, len: usize) -> HalResult<usize> {
+        if offset + len > self.len {
+            return Err(HalError::InvalidParameter(format!(
+                "{} bytes at {} overrun a {}-byte storage region", len, offset, self.len
+            )));
+        }
+        Ok(self.offset + offset)
+    }
+}
+
+/// Non-volatile byte storage
+/// 
+/// Writes go straight to the cells - there is no cache to flush at key-off,
+/// so `write` returning `Ok` means the data is persistent.
+pub trait NonVolatileStorage {
+    /// Read into `buffer` from `offset`, returning the bytes read
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> HalResult<usize>;
+    
+    /// Write `data` at `offset`
+    fn write(&mut self, offset: usize, data: &[u8]) -> HalResult<()>;
+    
+    /// Erase the whole storage back to `ERASED_BYTE`
+    fn erase_all(&mut self) -> HalResult<()>;
+    
+    /// Wait for outstanding writes to complete
+    fn sync(&mut self) -> HalResult<()>;
+    
+    /// Storage capacity (bytes)
+    fn get_size(&self) -> usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_regions_fit_eeprom_without_overlap() {
+        let mut end = 0;
+        for region in StorageRegion::ALL {
+            assert_eq!(region.offset, end);
+            end = region.offset + region.len;
+        }
+        assert_eq!(end, EEPROM_SIZE);
+    }
+    
+    #[test]
+    fn test_safety_limit_copies_in_separate_regions() {
+        let wear_regions = StorageRegion::SAFETY_LIMITS.map(|copy| copy.offset / EEPROM_REGION_SIZE);
+        assert_eq!(wear_regions, [1, 5, 6]);
+        for copy in StorageRegion::SAFETY_LIMITS {
+            assert_eq!((copy.offset + copy.len) % EEPROM_REGION_SIZE, 0);
+            // Covered by no other region
+            let covering = StorageRegion::ALL.iter()
+                .filter(|region| region.offset < copy.offset + copy.len && copy.offset < region.offset + region.len)
+                .count();
+            assert_eq!(covering, 1);
+        }
+    }
+    
+    #[test]
+    fn test_region_addresses_stay_inside_region() {
+        let region = StorageRegion::SAFETY_LOG;
+        assert_eq!(region.address(16, 64).unwrap(), 6 * EEPROM_REGION_SIZE + 16);
+        assert!(region.address(EEPROM_REGION_SIZE - 8, 16).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Protocol Compatibility Suite
+//! 
+//! 🔗 T4-PROTOCOL-009: Cross-Version Compatibility Suite
+//! Derived From: T4-PROTOCOL-001 (Protocol Message Definitions) + T4-PROTOCOL-008 (COBS Framing with CRC)
+//! AI Traceability: A renamed field or retyped message breaks every client of an older release - fail the build when the change is made, not in the car
+//! 
+//! Each supported protocol version has a recorded session checked in under
+//! `fixtures/`: the bytes a client of that version sent (`vN-requests.bin`)
+//! and the bytes its device answered (`vN-responses.bin`). Every message in
+//! every fixture must decode with today's definitions, and encoding it again
+//! must keep every field it arrived with, at the same value - new fields may
+//! be added, none may be lost or changed.
+//! 
+//! Versions 1 and 2 were newline-delimited JSON; their streams are split on
+//! the newline here so their messages can still be checked. Fixtures are
+//! recorded by the simulator (`rumbledome-sim` `record_protocol_fixture`)
+//! when `PROTOCOL_VERSION` is bumped and are never re-recorded afterwards.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde_json::Value;
+
+use crate::framing::frame_payload;
+use crate::{ProtocolMessage, FRAME_DELIMITER, OLDEST_SUPPORTED_PROTOCOL_VERSION, PROTOCOL_VERSION};
+
+/// First version framed with COBS and a CRC
+const COBS_FRAMING_VERSION: u32 = 3;
+
+/// Recorded session of one protocol version
+struct Fixture {
+    version: u32,
+    requests: &'static [u8],
+    responses: &'static [u8],
+}
+
+/// Every recorded version, oldest first
+const FIXTURES: &[Fixture] = &[
+    Fixture {
+        version: 1,
+        requests: include_bytes!("../fixtures/v1-requests.bin"),
+        responses: include_bytes!("../fixtures/v1-responses.bin"),
+    },
+    Fixture {
+        version: 2,
+        requests: include_bytes!("../fixtures/v2-requests.bin"),
+        responses: include_bytes!("../fixtures/v2-responses.bin"),
+    },
+    Fixture {
+        version: 3,
+        requests: include_bytes!("../fixtures/v3-requests.bin"),
+        responses: include_bytes!("../fixtures/v3-responses.bin"),
+    },
+];
+
+/// JSON of each message in a stream, in the framing of its version
+fn messages(version: u32, stream: &[u8]) -> Vec<Vec<u8>> {
+    if version < COBS_FRAMING_VERSION {
+        return stream.split(|&byte| byte == b'\n').filter(|line| !line.is_empty()).map(<[u8]>::to_vec).collect();
+    }
+    stream
+        .split(|&byte| byte == FRAME_DELIMITER)
+        .filter(|frame| !frame.is_empty())
+        .map(|frame| frame_payload(frame).unwrap_or_else(|| panic!("v{} frame failed its CRC", version)))
+        .collect()
+}
+
+/// Path of the first field of `recorded` that `encoded` lost or changed
+fn first_difference(recorded: &Value, encoded: &Value, path: &str) -> Option<String> {
+    match (recorded, encoded) {
+        (Value::Object(recorded), Value::Object(encoded)) => recorded.iter().find_map(|(key, value)| {
+            let path = format!("{}.{}", path, key);
+            match encoded.get(key) {
+                Some(encoded) => first_difference(value, encoded, &path),
+                None => Some(path),
+            }
+        }),
+        (Value::Array(recorded), Value::Array(encoded)) if recorded.len() == encoded.len() => recorded
+            .iter()
+            .zip(encoded)
+            .enumerate()
+            .find_map(|(index, (recorded, encoded))| first_difference(recorded, encoded, &format!("{}[{}]", path, index))),
+        // Fields are f32 - decoding widens them to f64, which is not the number that was recorded
+        (Value::Number(recorded), Value::Number(encoded)) if recorded.is_f64() || encoded.is_f64() => {
+            let single = |number: &serde_json::Number| number.as_f64().map(|value| value as f32);
+            (single(recorded) != single(encoded)).then(|| String::from(path))
+        },
+        _ if recorded == encoded => None,
+        _ => Some(String::from(path)),
+    }
+}
+
+/// Decode every message of a stream and check re-encoding keeps what was recorded
+fn check_stream(version: u32, direction: &str, stream: &[u8]) -> usize {
+    let messages = messages(version, stream);
+    assert!(!messages.is_empty(), "v{} {} fixture is empty", version, direction);
+    
+    for (index, json) in messages.iter().enumerate() {
+        let context = format!("v{} {} message {}", version, direction, index);
+        let message: ProtocolMessage = serde_json::from_slice(json)
+            .unwrap_or_else(|e| panic!("{} no longer decodes: {}", context, e));
+        let recorded: Value = serde_json::from_slice(json).unwrap();
+        let encoded = serde_json::to_value(&message).unwrap();
+        if let Some(path) = first_difference(&recorded, &encoded, "") {
+            panic!("{} changed at `{}` when encoded again", context, path);
+        }
+    }
+    messages.len()
+}
+
+mod tests {
+    use super::*;
+    use crate::{FrameDecoder, MAX_REQUEST_BYTES, MAX_RESPONSE_BYTES};
+    
+    #[test]
+    fn test_every_supported_version_is_recorded() {
+        let versions: Vec<u32> = FIXTURES.iter().map(|fixture| fixture.version).collect();
+        let supported: Vec<u32> = (OLDEST_SUPPORTED_PROTOCOL_VERSION..=PROTOCOL_VERSION).collect();
+        assert_eq!(versions, supported, "record a fixture when bumping PROTOCOL_VERSION");
+    }
+    
+    #[test]
+    fn test_recorded_messages_still_decode() {
+        for fixture in FIXTURES {
+            let requests = check_stream(fixture.version, "request", fixture.requests);
+            let responses = check_stream(fixture.version, "response", fixture.responses);
+            assert_eq!(requests, responses, "v{} session has an unanswered request", fixture.version);
+        }
+    }
+    
+    #[test]
+    fn test_recorded_frames_pass_the_frame_decoder() {
+        for fixture in FIXTURES.iter().filter(|fixture| fixture.version >= COBS_FRAMING_VERSION) {
+            for (stream, max_len) in [(fixture.requests, MAX_REQUEST_BYTES), (fixture.responses, MAX_RESPONSE_BYTES)] {
+                let mut decoder = FrameDecoder::new(max_len);
+                let decoded = decoder.push(stream);
+                assert_eq!(decoded.len(), messages(fixture.version, stream).len());
+                assert_eq!(decoder.stats().malformed + decoder.stats().oversized, 0, "v{}", fixture.version);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_lost_or_changed_fields_are_found() {
+        let recorded: Value = serde_json::from_str(r#"{"Screen":{"boost_psi":7.5,"watches":[{"value":1}]}}"#).unwrap();
+        assert_eq!(first_difference(&recorded, &recorded, ""), None);
+        
+        let added: Value = serde_json::from_str(r#"{"Screen":{"boost_psi":7.5,"watches":[{"value":1}],"language":"en"}}"#).unwrap();
+        assert_eq!(first_difference(&recorded, &added, ""), None);
+        
+        let renamed: Value = serde_json::from_str(r#"{"Screen":{"boost":7.5,"watches":[{"value":1}]}}"#).unwrap();
+        assert_eq!(first_difference(&recorded, &renamed, "").as_deref(), Some(".Screen.boost_psi"));
+        
+        let widened: Value = serde_json::from_str(r#"{"Screen":{"boost_psi":7.5,"watches":[{"value":1}],"aggression":0.3}}"#).unwrap();
+        let encoded = serde_json::json!({"Screen": {"boost_psi": 7.5, "watches": [{"value": 1}], "aggression": 0.3f32 as f64}});
+        assert_eq!(first_difference(&widened, &encoded, ""), None);
+        
+        let changed: Value = serde_json::from_str(r#"{"Screen":{"boost_psi":7.5,"watches":[{"value":2}]}}"#).unwrap();
+        assert_eq!(first_difference(&recorded, &changed, "").as_deref(), Some(".Screen.watches[0].value"));
+    }
+}
@@ -0,0 +1,282 @@
+//! Message Framing
+//! 
+//! 🔗 T4-PROTOCOL-008: COBS Framing with CRC
+//! Derived From: T4-PROTOCOL-002 (Newline-Delimited JSON Framing) + Protocols.md Communication Transport
+//! AI Traceability: One byte-stream framing for USB serial and Bluetooth SPP that detects corruption and resynchronizes after lost data
+//! 
+//! Each frame is the compact JSON message followed by its CRC-16/CCITT-FALSE
+//! (little-endian), COBS-encoded so the frame holds no zero byte, then a zero
+//! delimiter. A frame damaged in transit fails its CRC instead of being parsed
+//! as a different message, and costs only itself: decoding resumes after the
+//! next delimiter.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use rumbledome_core::Crc16;
+
+use crate::ProtocolMessage;
+
+/// Frame delimiter - COBS leaves no zero byte inside a frame
+pub const FRAME_DELIMITER: u8 = 0x00;
+
+/// Largest request message the device accepts (Protocols.md request limits)
+pub const MAX_REQUEST_BYTES: usize = 1024;
+
+/// Largest message a client accepts (status responses carry the full configuration)
+pub const MAX_RESPONSE_BYTES: usize = 16 * 1024;
+
+/// CRC appended to each message
+const CRC_BYTES: usize = 2;
+
+/// Longest COBS block (code byte included)
+const MAX_BLOCK: usize = 0xFF;
+
+/// Protocol transport errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtocolError {
+    /// Encoded message exceeds the frame size limit
+    FrameTooLarge { len: usize, max: usize },
+    /// Message could not be serialized
+    Encode(String),
+    /// A request is already awaiting its response (one request at a time)
+    RequestInProgress,
+}
+
+/// Serialize a message into one delimited frame; `max_len` limits the JSON message
+pub fn encode_frame(message: &ProtocolMessage, max_len: usize) -> Result<Vec<u8>, ProtocolError> {
+    let mut payload = serde_json::to_vec(message)
+        .map_err(|e| ProtocolError::Encode(format!("{}", e)))?;
+    
+    if payload.len() > max_len {
+        return Err(ProtocolError::FrameTooLarge { len: payload.len(), max: max_len });
+    }
+    
+    let mut crc = Crc16::new();
+    crc.update(&payload);
+    payload.extend_from_slice(&crc.value().to_le_bytes());
+    
+    let mut frame = Vec::with_capacity(encoded_len(payload.len()) + 1);
+    cobs_encode(&payload, &mut frame);
+    frame.push(FRAME_DELIMITER);
+    Ok(frame)
+}
+
+/// Frame decoder statistics (link quality diagnostics)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FramingStats {
+    /// Frames decoded successfully
+    pub frames: u32,
+    /// Frames discarded because they failed their CRC or did not parse (lost or corrupted bytes)
+    pub malformed: u32,
+    /// Frames discarded for exceeding the size limit (usually a lost delimiter)
+    pub oversized: u32,
+}
+
+/// Incremental decoder for a delimited byte stream
+/// 
+/// A damaged frame costs only itself: everything up to the next delimiter is
+/// discarded and decoding resumes with the following frame.
+#[derive(Debug, Clone)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    max_encoded: usize,
+    overflowed: bool,
+    stats: FramingStats,
+}
+
+impl FrameDecoder {
+    /// Create decoder accepting messages up to `max_len` bytes
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_encoded: encoded_len(max_len + CRC_BYTES),
+            overflowed: false,
+            stats: FramingStats::default(),
+        }
+    }
+    
+    /// Decoder statistics
+    pub fn stats(&self) -> &FramingStats {
+        &self.stats
+    }
+    
+    /// Feed received bytes, returning every message completed by them
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<ProtocolMessage> {
+        let mut messages = Vec::new();
+        
+        for &byte in bytes {
+            if byte != FRAME_DELIMITER {
+                if self.buffer.len() < self.max_encoded {
+                    self.buffer.push(byte);
+                } else {
+                    self.overflowed = true;
+                }
+                continue;
+            }
+            
+            if self.overflowed {
+                self.stats.oversized += 1;
+            } else if !self.buffer.is_empty() {
+                match decode_message(&self.buffer) {
+                    Some(message) => {
+                        self.stats.frames += 1;
+                        messages.push(message);
+                    },
+                    None => self.stats.malformed += 1,
+                }
+            }
+            
+            self.buffer.clear();
+            self.overflowed = false;
+        }
+        
+        messages
+    }
+}
+
+/// Message carried by one frame (delimiter removed), if it arrived intact
+fn decode_message(encoded: &[u8]) -> Option<ProtocolMessage> {
+    serde_json::from_slice(&frame_payload(encoded)?).ok()
+}
+
+/// JSON carried by one frame (delimiter removed), if it passes its CRC
+pub(crate) fn frame_payload(encoded: &[u8]) -> Option<Vec<u8>> {
+    let mut payload = cobs_decode(encoded)?;
+    if payload.len() < CRC_BYTES {
+        return None;
+    }
+    
+    let (json, crc) = payload.split_at(payload.len() - CRC_BYTES);
+    let mut expected = Crc16::new();
+    expected.update(json);
+    if u16::from_le_bytes([crc[0], crc[1]]) != expected.value() {
+        return None;
+    }
+    payload.truncate(payload.len() - CRC_BYTES);
+    Some(payload)
+}
+
+/// Longest COBS encoding of `len` bytes
+fn encoded_len(len: usize) -> usize {
+    len + len / (MAX_BLOCK - 1) + 1
+}
+
+/// Append the COBS encoding of `data` to `out`
+fn cobs_encode(data: &[u8], out: &mut Vec<u8>) {
+    let mut code_at = out.len();
+    out.push(0);
+    let mut code = 1u8;
+    
+    for &byte in data {
+        if byte != 0 {
+            out.push(byte);
+            code += 1;
+        }
+        if byte == 0 || code as usize == MAX_BLOCK {
+            out[code_at] = code;
+            code_at = out.len();
+            out.push(0);
+            code = 1;
+        }
+    }
+    out[code_at] = code;
+}
+
+/// Reverse of `cobs_encode`; `None` when a block runs past the end
+fn cobs_decode(encoded: &[u8]) -> Option<Vec<u8>> {
+    let mut data = Vec::with_capacity(encoded.len());
+    let mut at = 0;
+    
+    while at < encoded.len() {
+        let code = encoded[at] as usize;
+        if code == 0 || at + code > encoded.len() {
+            return None;
+        }
+        data.extend_from_slice(&encoded[at + 1..at + code]);
+        at += code;
+        // A full block carries no implicit zero
+        if code < MAX_BLOCK && at < encoded.len() {
+            data.push(0);
+        }
+    }
+    Some(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_round_trip_split_across_reads() {
+        let frame = encode_frame(&ProtocolMessage::GetScreen, MAX_REQUEST_BYTES).unwrap();
+        let mut decoder = FrameDecoder::new(MAX_REQUEST_BYTES);
+        
+        let (first, second) = frame.split_at(4);
+        assert!(decoder.push(first).is_empty());
+        let messages = decoder.push(second);
+        assert!(matches!(messages[..], [ProtocolMessage::GetScreen]));
+    }
+    
+    #[test]
+    fn test_resync_after_lost_bytes() {
+        let mut stream = encode_frame(&ProtocolMessage::GetStatus, MAX_REQUEST_BYTES).unwrap();
+        stream.drain(2..6);
+        stream.extend(encode_frame(&ProtocolMessage::UnsubscribeScreen, MAX_REQUEST_BYTES).unwrap());
+        
+        let mut decoder = FrameDecoder::new(MAX_REQUEST_BYTES);
+        let messages = decoder.push(&stream);
+        assert!(matches!(messages[..], [ProtocolMessage::UnsubscribeScreen]));
+        assert_eq!(decoder.stats().malformed, 1);
+    }
+    
+    #[test]
+    fn test_oversized_frame_discarded() {
+        let mut stream = alloc::vec![b'x'; 40];
+        stream.push(FRAME_DELIMITER);
+        stream.extend(encode_frame(&ProtocolMessage::GetScreen, 32).unwrap());
+        
+        let mut decoder = FrameDecoder::new(32);
+        assert_eq!(decoder.push(&stream).len(), 1);
+        assert_eq!(decoder.stats().oversized, 1);
+    }
+    
+    #[test]
+    fn test_corrupted_byte_fails_crc() {
+        // A flipped digit still parses as JSON - only the CRC catches it
+        let request = ProtocolMessage::GetFlightRecord { offset: 12 };
+        let mut frame = encode_frame(&request, MAX_REQUEST_BYTES).unwrap();
+        let digit = frame.iter().position(|&byte| byte == b'2').unwrap();
+        frame[digit] = b'3';
+        
+        let mut decoder = FrameDecoder::new(MAX_REQUEST_BYTES);
+        assert!(decoder.push(&frame).is_empty());
+        assert_eq!(decoder.stats().malformed, 1);
+    }
+    
+    #[test]
+    fn test_frame_holds_no_delimiter() {
+        // Long enough for several full COBS blocks
+        let name = "n".repeat(600);
+        let frame = encode_frame(&ProtocolMessage::DeleteConfiguration { name: name.clone() }, MAX_REQUEST_BYTES).unwrap();
+        assert_eq!(frame.iter().filter(|&&byte| byte == FRAME_DELIMITER).count(), 1);
+        
+        let mut decoder = FrameDecoder::new(MAX_REQUEST_BYTES);
+        match &decoder.push(&frame)[..] {
+            [ProtocolMessage::DeleteConfiguration { name: decoded }] => assert_eq!(decoded, &name),
+            other => panic!("Unexpected messages {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_cobs_round_trip_with_zero_bytes() {
+        for data in [&[][..], &[0], &[0, 0], &[1, 0, 2], &[7; 254], &[7; 255]] {
+            let mut encoded = Vec::new();
+            cobs_encode(data, &mut encoded);
+            assert!(!encoded.contains(&0));
+            assert!(encoded.len() <= encoded_len(data.len()));
+            assert_eq!(cobs_decode(&encoded).unwrap(), data);
+        }
+    }
+}
@@ -9,6 +9,7 @@
 #![cfg_attr(not(feature = "std"), no_main)]
 
 extern crate alloc;
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 
@@ -18,20 +19,313 @@ use serde::{Deserialize, Serialize};
 // Re-export core types for protocol use
 pub use rumbledome_core::*;
 
+pub mod framing;
+pub mod presence;
+pub mod replay;
+pub mod retry;
+pub mod router;
+pub mod session;
+pub mod throttle;
+#[cfg(test)]
+mod compat;
+
+pub use framing::*;
+pub use replay::*;
+pub use retry::*;
+pub use router::*;
+pub use session::*;
+pub use throttle::*;
+
+/// Protocol revision advertised in the hello response
+/// 
+/// Bumped when a message changes incompatibly, so a client can refuse a device
+/// it cannot talk to instead of failing on the first unparseable response.
+pub const PROTOCOL_VERSION: u32 = 3;
+
+/// Oldest protocol revision whose recorded messages must still decode
+/// 
+/// Every revision from this one up to `PROTOCOL_VERSION` has a recorded
+/// session under `fixtures/` that the compatibility suite checks.
+pub const OLDEST_SUPPORTED_PROTOCOL_VERSION: u32 = 1;
+
 /// Protocol message types for RumbleDome communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ProtocolMessage {
+    /// Identify the device (first request on a new connection)
+    Hello,
+    /// Hello response
+    Identity { protocol_version: u32, identity: DeviceIdentity },
+    /// Rename the device
+    SetDeviceName { name: String },
+    /// Select the display language (device-wide preference)
+    SetLanguage { language: Language },
     /// Request current system status
     GetStatus,
     /// System status response
-    Status(SystemStatus),
+    Status(Box<SystemStatus>),
     /// Update system configuration
     SetConfig(SystemConfig),
     /// Configuration update response
     ConfigUpdated,
+    /// Configuration stored; these changes take effect at the next boot
+    RestartRequired { changes: Vec<StagedChange> },
+    /// Switch Level 1 control mode (rejected while armed)
+    SetControlMode { mode: ControlMode },
+    /// Request the stored named configurations
+    ListConfigurations,
+    /// Named configuration list response
+    Configurations { active: String, names: Vec<String> },
+    /// Store a complete configuration under a name (replaces an existing entry)
+    SaveConfiguration { name: String, config: SystemConfig },
+    /// Store a configuration inheriting from `parent` with only the given fields changed
+    SaveInheritedConfiguration { name: String, parent: String, overrides: serde_json::Map<String, serde_json::Value> },
+    /// Request one stored named configuration, resolved through its parents
+    GetConfiguration { name: String },
+    /// Named configuration response
+    Configuration(NamedConfig),
+    /// Switch to a stored named configuration (rejected while armed)
+    SelectConfiguration { name: String },
+    /// Delete a stored named configuration (not the active one)
+    DeleteConfiguration { name: String },
+    /// Replace the profile scheduling rules (first match wins)
+    SetProfileSchedule { rules: Vec<ProfileRule> },
+    /// Request the profile scheduling rules
+    GetProfileSchedule,
+    /// Profile scheduling rules response
+    ProfileSchedule { rules: Vec<ProfileRule> },
+    /// Context from a connected app (track day, geofence zone, time of day); runs the profile schedule
+    SetContextHints(ContextHints),
+    /// Profile schedule evaluation response
+    ScheduleStatus(ScheduleStatus),
+    /// Aggression from a connected app (0.0-1.0); drops out unless sent again within the input timeout
+    SetAggression { value: f32 },
+    /// Aggression arbitration response
+    AggressionInput(AggressionInputStatus),
+    /// Apply a volatile live-tune test value (takes effect immediately, not persisted)
+    SetTestValue { parameter: TuneParameter, value: f32 },
+    /// Persist all live-tune test values currently in effect
+    CommitTestValues,
+    /// Discard live-tune test values and restore stored tuning
+    RevertTestValues,
+    /// Request live-tune status (also refreshes the revert timeout)
+    GetLiveTuneStatus,
+    /// Live-tune status response
+    LiveTuneStatus(LiveTuneStatus),
+    /// Request a single description of the current display contents
+    GetScreen,
+    /// Stream display contents at the given interval until unsubscribed
+    SubscribeScreen { interval_ms: u32 },
+    /// Stop display mirroring stream
+    UnsubscribeScreen,
+    /// Display mirroring stream stopped
+    ScreenUnsubscribed,
+    /// Display contents (response to GetScreen or streamed while subscribed)
+    Screen(ScreenDescription),
+    /// Stream control loop telemetry at the given interval until unsubscribed
+    SubscribeTelemetry { interval_ms: u32 },
+    /// Stop the telemetry stream
+    UnsubscribeTelemetry,
+    /// Telemetry stream stopped
+    TelemetryUnsubscribed,
+    /// Control loop telemetry (first response to SubscribeTelemetry, then streamed while subscribed)
+    Telemetry(TelemetrySample),
+    /// Mark a maintenance reminder as serviced
+    AcknowledgeMaintenance { name: String },
+    /// Maintenance acknowledgement response
+    MaintenanceAcknowledged,
+    /// Accept the current MAP zero as the drift baseline (after sensor replacement)
+    ResetMapZeroBaseline,
+    /// MAP zero baseline reset response
+    MapZeroBaselineReset,
+    /// Request first-run commissioning progress
+    GetCommissioningStatus,
+    /// Enter commissioning at the next unfinished step (system must be idle)
+    BeginCommissioning,
+    /// Confirm the active commissioning step
+    CompleteCommissioningStep { step: CommissioningStep },
+    /// Skip the active commissioning step
+    SkipCommissioningStep { step: CommissioningStep },
+    /// Skip all remaining commissioning steps (permits arming without commissioning)
+    SkipCommissioning,
+    /// Leave commissioning, keeping progress so far
+    ExitCommissioning,
+    /// Commissioning progress response
+    CommissioningStatus(CommissioningStatus),
+    /// Start or stop the bench display demo (engine stopped, system idle)
+    SetDemoMode { active: bool },
+    /// Demo mode response
+    DemoMode { active: bool },
+    /// Request pressure sensor wiring assistant progress
+    GetSensorMappingStatus,
+    /// Start the pressure sensor wiring assistant (engine stopped, system idle or commissioning)
+    BeginSensorMapping,
+    /// Mark the prompted sensor role as not responding
+    SkipSensorRole,
+    /// Abandon the wiring assistant, keeping the configured channels
+    CancelSensorMapping,
+    /// Sensor mapping progress response
+    SensorMappingStatus(SensorMappingStatus),
+    /// Store the detected channels in the configuration
+    FinishSensorMapping,
+    /// Detected channels with swapped and dead channels flagged
+    SensorMappingReport(SensorMappingReport),
+    /// Start a wastegate spring check: 0 % duty through one wide-open pull (presence-confirmed)
+    StartSpringCheck,
+    /// Request spring check progress or the last result
+    GetSpringCheckStatus,
+    /// Abandon a running spring check
+    CancelSpringCheck,
+    /// Spring check progress response
+    SpringCheckStatus(SpringCheckStatus),
+    /// Capture every control value at the full loop rate for `window_ms` (replaces the previous capture)
+    StartBurstLog { window_ms: u32 },
+    /// Request burst capture progress
+    GetBurstLogStatus,
+    /// Burst capture progress response
+    BurstLogStatus(BurstLogStatus),
+    /// Request captured samples from `offset`
+    GetBurstLog { offset: u32 },
+    /// One chunk of the burst capture
+    BurstLog(BurstLogChunk),
+    /// Log telemetry to the SD card until stopped
+    StartDatalog,
+    /// Write out and close the running SD card datalog
+    StopDatalog,
+    /// Request the SD card datalog state
+    GetDatalogStatus,
+    /// SD card datalog state response
+    DatalogStatus(DatalogStatus),
+    /// Request one block of the EEPROM flight recording from `offset` (oldest first)
+    GetFlightRecord { offset: u32 },
+    /// One block of the flight recording
+    FlightRecord(FlightRecordChunk),
+    /// Start an auto-calibration session (system idle, commissioning complete)
+    StartCalibration,
+    /// Request calibration progress
+    GetCalibrationStatus,
+    /// Abandon the running calibration session (learned cells are kept)
+    AbortCalibration,
+    /// Calibration progress response (`None` when no session is running)
+    CalibrationStatus { progress: Option<CalibrationProgress> },
+    /// Request the learned-data image for backup or transfer to another device
+    ExportLearnedData,
+    /// Learned-data image, in the stored format
+    LearnedDataImage { image: Vec<u8> },
+    /// One chunk of a learned-data image from `offset` (offset 0 starts a new import)
+    ImportLearnedData { offset: u32, chunk: Vec<u8> },
+    /// Import progress; the map is replaced once the whole image has arrived
+    LearnedDataImported(LearnedDataImport),
+    /// Forget all learned data
+    ResetLearnedData,
+    /// Learned data reset response
+    LearnedDataReset,
+    /// Request the overboost cuts and faults logged to EEPROM
+    GetSafetyLog,
+    /// Logged safety events, oldest first
+    SafetyLog { events: Vec<SafetyLogEntry> },
+    /// Erase the safety log
+    ClearSafetyLog,
+    /// Safety log erased
+    SafetyLogCleared,
+    /// Request a diagnostics snapshot for a bug report
+    GetSupportBundle,
+    /// Diagnostics snapshot response
+    SupportBundle(Box<SupportBundle>),
+    /// Request torque gap and boost error distributions by RPM band
+    GetDistribution,
+    /// Distribution response
+    Distribution(DistributionReport),
+    /// Request weekly and monthly trends; `day` is today on the client's clock (days since 1970-01-01)
+    GetTrends { day: u32 },
+    /// Trends response
+    Trends(TrendReport),
+    /// Mutating request numbered for replay protection (applied at most once)
+    Sequenced { sequence: u32, request: Box<ProtocolMessage> },
+    /// Register internal variables to append to this session's screen frames (empty list stops watching)
+    SetWatchList { variables: Vec<WatchVariable> },
+    /// Variables now watched by this session
+    WatchList { variables: Vec<WatchVariable> },
+    /// Protected request held until the code on the device display is sent back
+    ConfirmationRequired { action: ProtectedAction, expires_in_ms: u32 },
+    /// Code read off the device display - applies the held request and returns its response
+    ConfirmPresence { code: u16 },
+    /// Ask for full access on this connection (a Bluetooth connection confirms it with the presence code)
+    Authenticate,
+    /// Connection authenticated; `safety_writes` is whether safety-relevant changes are allowed on it
+    Authenticated { safety_writes: bool },
     /// Error response
     Error(String),
 }
 
-// TODO: Implement full protocol message definitions
-// This is a placeholder for the protocol system
\ No newline at end of file
+impl ProtocolMessage {
+    /// Error response carrying a registered error identifier
+    pub fn coded_error(code: ErrorCode, detail: &str) -> Self {
+        ProtocolMessage::Error(code.message(detail))
+    }
+    
+    /// Error response for a failed request
+    pub fn error_from(error: &impl ErrorCoded) -> Self {
+        ProtocolMessage::Error(error.coded_message())
+    }
+    
+    /// Registered error an error response carries, if any
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            ProtocolMessage::Error(message) => ErrorCode::of_message(message),
+            _ => None,
+        }
+    }
+    
+    /// Whether this message is a response to `request`
+    /// 
+    /// One request is outstanding at a time, so the response kind identifies the
+    /// request it answers. An error answers any request.
+    pub fn answers(&self, request: &ProtocolMessage) -> bool {
+        use ProtocolMessage::*;
+        
+        match (request, self) {
+            (_, Error(_)) => true,
+            (Sequenced { request, .. }, _) => self.answers(request),
+            (Hello, Identity { .. }) => true,
+            (GetStatus, Status(_)) => true,
+            (SetConfig(_) | SetDeviceName { .. } | SetLanguage { .. } | SetControlMode { .. } | SaveConfiguration { .. } | SaveInheritedConfiguration { .. }
+                | SelectConfiguration { .. } | DeleteConfiguration { .. } | SetProfileSchedule { .. }, ConfigUpdated) => true,
+            (SetConfig(_) | SaveConfiguration { .. } | SaveInheritedConfiguration { .. } | SelectConfiguration { .. }, RestartRequired { .. } | ConfirmationRequired { .. }) => true,
+            (ConfirmPresence { .. }, _) => true,
+            (Authenticate, Authenticated { .. } | ConfirmationRequired { .. }) => true,
+            (ListConfigurations, Configurations { .. }) => true,
+            (GetConfiguration { .. }, Configuration(_)) => true,
+            (GetProfileSchedule, ProfileSchedule { .. }) => true,
+            (SetContextHints(_), ScheduleStatus(_)) => true,
+            (SetAggression { .. }, AggressionInput(_)) => true,
+            (SetTestValue { .. } | CommitTestValues | RevertTestValues | GetLiveTuneStatus, LiveTuneStatus(_)) => true,
+            (GetScreen | SubscribeScreen { .. }, Screen(_)) => true,
+            (UnsubscribeScreen, ScreenUnsubscribed) => true,
+            (SubscribeTelemetry { .. }, Telemetry(_)) => true,
+            (UnsubscribeTelemetry, TelemetryUnsubscribed) => true,
+            (AcknowledgeMaintenance { .. }, MaintenanceAcknowledged) => true,
+            (ResetMapZeroBaseline, MapZeroBaselineReset) => true,
+            (GetCommissioningStatus | BeginCommissioning | CompleteCommissioningStep { .. } | SkipCommissioningStep { .. }
+                | SkipCommissioning | ExitCommissioning, CommissioningStatus(_)) => true,
+            (SetDemoMode { .. }, DemoMode { .. }) => true,
+            (GetSensorMappingStatus | BeginSensorMapping | SkipSensorRole | CancelSensorMapping, SensorMappingStatus(_)) => true,
+            (FinishSensorMapping, SensorMappingReport(_)) => true,
+            (StartSpringCheck | GetSpringCheckStatus | CancelSpringCheck, SpringCheckStatus(_)) => true,
+            (StartBurstLog { .. } | GetBurstLogStatus, BurstLogStatus(_)) => true,
+            (GetBurstLog { .. }, BurstLog(_)) => true,
+            (StartDatalog | StopDatalog | GetDatalogStatus, DatalogStatus(_)) => true,
+            (GetFlightRecord { .. }, FlightRecord(_)) => true,
+            (StartCalibration | GetCalibrationStatus | AbortCalibration, CalibrationStatus { .. }) => true,
+            (ExportLearnedData, LearnedDataImage { .. }) => true,
+            (ImportLearnedData { .. }, LearnedDataImported(_) | ConfirmationRequired { .. }) => true,
+            (ResetLearnedData, LearnedDataReset | ConfirmationRequired { .. }) => true,
+            (GetSafetyLog, SafetyLog { .. }) => true,
+            (ClearSafetyLog, SafetyLogCleared) => true,
+            (GetSupportBundle, SupportBundle(_)) => true,
+            (GetDistribution, Distribution(_)) => true,
+            (GetTrends { .. }, Trends(_)) => true,
+            (SetWatchList { .. }, WatchList { .. }) => true,
+            _ => false,
+        }
+    }
+}
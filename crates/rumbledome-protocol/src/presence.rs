@@ -0,0 +1,157 @@
+//! Presence-Confirmed Requests
+//! 
+//! 🔗 T4-PROTOCOL-007: Physical Presence Authorization
+//! Derived From: T4-CORE-103 (Presence-Confirmed Dangerous Commands) + T4-PROTOCOL-006 (Session Privileges)
+//! AI Traceability: Which requests the device holds for a code shown on its display
+//! 
+//! Flow:
+//! - A protected request is answered with `ConfirmationRequired` instead of
+//!   being applied; the device display shows a 4-digit code.
+//! - The client sends `ConfirmPresence` with that code on the same session
+//!   within `CONFIRMATION_TIMEOUT_MS`. The held request is then applied and
+//!   its normal response returned.
+//! - A wrong code, a timeout, a new protected request or `Hello` drops the
+//!   held request.
+
+use crate::{spring_only_config, ConfigLibrary, CoreError, ProtectedAction, ProtocolMessage, SystemConfig};
+
+impl ProtocolMessage {
+    /// Action needing presence confirmation if this request were applied now
+    /// 
+    /// Only changes to the running configuration count - saving another named
+    /// configuration is checked when it is selected. Saving a configuration
+    /// the running one inherits from changes the running one too. A spring
+    /// check takes boost control away for a pull, so it is always protected.
+    /// So is `Authenticate`: the code is what proves the client is at the car
+    /// (a session that already has full access is answered without asking).
+    /// Resetting the learned map is protected, and so is the first chunk of an
+    /// import - the rest can only follow on from a confirmed start.
+    pub fn protected_action(&self, running: &SystemConfig, configurations: &ConfigLibrary) -> Option<ProtectedAction> {
+        use ProtocolMessage::*;
+        
+        let affects_running = |name: &str| configurations.inherits_from(&configurations.active().name, name);
+        // Leaving the spring-only profile raises the limits back to the active entry's
+        let spring_only;
+        let running = if configurations.is_spring_only() {
+            spring_only = spring_only_config(running);
+            &spring_only
+        } else {
+            running
+        };
+        let resolved_after = |update: &dyn Fn(&mut ConfigLibrary) -> Result<(), CoreError>| {
+            let mut updated = configurations.clone();
+            update(&mut updated).ok()?;
+            Some(updated.active().config.clone())
+        };
+        
+        let applied = match self {
+            SetConfig(config) => config.clone(),
+            SaveConfiguration { name, config } if affects_running(name) => {
+                resolved_after(&|library| library.save(name, config.clone()))?
+            },
+            SaveInheritedConfiguration { name, parent, overrides } if affects_running(name) => {
+                resolved_after(&|library| library.save_inherited(name, parent, overrides.clone()))?
+            },
+            SelectConfiguration { name } => configurations.limits_of(name)?,
+            Sequenced { request, .. } => return request.protected_action(running, configurations),
+            StartSpringCheck => return Some(ProtectedAction::SpringCheck { configured_psi: running.spring_pressure }),
+            Authenticate => return Some(ProtectedAction::ConsoleAccess),
+            ResetLearnedData => return Some(ProtectedAction::ResetLearnedData),
+            ImportLearnedData { offset: 0, .. } => return Some(ProtectedAction::ImportLearnedData),
+            _ => return None,
+        };
+        ProtectedAction::for_config_change(running, &applied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use crate::{DEFAULT_CONFIG_NAME, SPRING_ONLY_PROFILE};
+    
+    #[test]
+    fn test_limit_raise_on_running_config_is_protected() {
+        let running = SystemConfig::default();
+        let mut library = ConfigLibrary::single(DEFAULT_CONFIG_NAME, running.clone());
+        let raised = SystemConfig { overboost_limit: 16.0, ..running.clone() };
+        library.save("track", raised.clone()).unwrap();
+        
+        assert!(ProtocolMessage::SetConfig(raised.clone()).protected_action(&running, &library).is_some());
+        assert!(ProtocolMessage::SelectConfiguration { name: String::from("track") }.protected_action(&running, &library).is_some());
+        let sequenced = ProtocolMessage::Sequenced { sequence: 1, request: Box::new(ProtocolMessage::SetConfig(raised.clone())) };
+        assert!(sequenced.protected_action(&running, &library).is_some());
+        
+        // Stored for later, or not raising anything
+        let save = ProtocolMessage::SaveConfiguration { name: String::from("track"), config: raised };
+        assert!(save.protected_action(&running, &library).is_none());
+        assert!(ProtocolMessage::SetConfig(running.clone()).protected_action(&running, &library).is_none());
+    }
+    
+    #[test]
+    fn test_limit_raise_inherited_by_running_config_is_protected() {
+        let base = SystemConfig::default();
+        let mut library = ConfigLibrary::single("street", base.clone());
+        library.save_inherited("street e85", "street", serde_json::Map::new()).unwrap();
+        library.select("street e85").unwrap();
+        let raised = SystemConfig { overboost_limit: 16.0, ..base.clone() };
+        
+        // Raising the parent raises the running child
+        let save_parent = ProtocolMessage::SaveConfiguration { name: String::from("street"), config: raised };
+        assert!(save_parent.protected_action(&base, &library).is_some());
+        
+        let mut overrides = serde_json::Map::new();
+        overrides.insert(String::from("overboost_limit"), serde_json::json!(16.0));
+        let inherit = ProtocolMessage::SaveInheritedConfiguration {
+            name: String::from("street e85"), parent: String::from("street"), overrides,
+        };
+        assert!(inherit.protected_action(&base, &library).is_some());
+    }
+    
+    #[test]
+    fn test_leaving_spring_only_is_protected() {
+        let running = SystemConfig::default();
+        let mut library = ConfigLibrary::single(DEFAULT_CONFIG_NAME, running.clone());
+        let select = |name: &str| ProtocolMessage::SelectConfiguration { name: String::from(name) };
+        assert!(select(SPRING_ONLY_PROFILE).protected_action(&running, &library).is_none());
+        
+        library.select(SPRING_ONLY_PROFILE).unwrap();
+        assert!(select(DEFAULT_CONFIG_NAME).protected_action(&running, &library).is_some());
+    }
+    
+    #[test]
+    fn test_spring_check_is_protected() {
+        let running = SystemConfig::default();
+        let library = ConfigLibrary::single(DEFAULT_CONFIG_NAME, running.clone());
+        assert_eq!(
+            ProtocolMessage::StartSpringCheck.protected_action(&running, &library),
+            Some(ProtectedAction::SpringCheck { configured_psi: running.spring_pressure })
+        );
+        assert!(ProtocolMessage::GetSpringCheckStatus.protected_action(&running, &library).is_none());
+    }
+    
+    #[test]
+    fn test_learned_data_reset_and_import_are_protected() {
+        let running = SystemConfig::default();
+        let library = ConfigLibrary::single(DEFAULT_CONFIG_NAME, running.clone());
+        assert_eq!(
+            ProtocolMessage::ResetLearnedData.protected_action(&running, &library),
+            Some(ProtectedAction::ResetLearnedData)
+        );
+        
+        // Only the chunk that starts an import asks; the rest follow on from it
+        let chunk = |offset| ProtocolMessage::ImportLearnedData { offset, chunk: alloc::vec![0; 128] };
+        assert_eq!(chunk(0).protected_action(&running, &library), Some(ProtectedAction::ImportLearnedData));
+        assert!(chunk(128).protected_action(&running, &library).is_none());
+        assert!(ProtocolMessage::ExportLearnedData.protected_action(&running, &library).is_none());
+    }
+    
+    #[test]
+    fn test_duty_ceiling_raise_on_running_config_is_protected() {
+        let running = SystemConfig { max_duty_percent: 80.0, ..SystemConfig::default() };
+        let library = ConfigLibrary::single(DEFAULT_CONFIG_NAME, running.clone());
+        let raised = SystemConfig { max_duty_percent: 95.0, ..running.clone() };
+        assert!(ProtocolMessage::SetConfig(raised).protected_action(&running, &library).is_some());
+    }
+}
@@ -0,0 +1,161 @@
+//! Replay Protection
+//! 
+//! 🔗 T4-PROTOCOL-005: Idempotent Commands
+//! Derived From: T4-PROTOCOL-003 (Request Timeout and Retry) + Protocols.md Message Timing and Constraints
+//! AI Traceability: A resent or duplicated command is applied at most once, however the link mangles it
+//! 
+//! Idempotency rules:
+//! - Read-only requests (status, screen, listings) are always safe to repeat
+//!   and are sent bare.
+//! - Mutating requests are wrapped in `ProtocolMessage::Sequenced` with a
+//!   client sequence number. Resends of the same request reuse its number.
+//! - The device applies each sequence number at most once. A repeat of the
+//!   last applied number gets the original response back without being
+//!   applied again; an older number is rejected as a stale replay.
+//! - Sequence numbers are per connection - `Hello` clears the device's record.
+//! - A bare mutating request is applied every time (older clients, bench tools).
+
+use alloc::boxed::Box;
+
+use crate::ProtocolMessage;
+
+impl ProtocolMessage {
+    /// Whether handling this request changes device state
+    /// 
+    /// Screen subscription is left out - subscribing twice leaves the same
+    /// stream running. So are context hints: the schedule only acts when the
+    /// context changes. A trends read supplies a date, which only ever moves
+    /// forward, so repeating it changes nothing either.
+    pub fn is_mutating(&self) -> bool {
+        use ProtocolMessage::*;
+        
+        matches!(self,
+            SetDeviceName { .. } | SetLanguage { .. } | SetConfig(_) | SetControlMode { .. } | SaveConfiguration { .. }
+            | SaveInheritedConfiguration { .. } | SelectConfiguration { .. } | DeleteConfiguration { .. } | SetProfileSchedule { .. }
+            | SetTestValue { .. } | CommitTestValues
+            | RevertTestValues | AcknowledgeMaintenance { .. } | ResetMapZeroBaseline | BeginCommissioning
+            | CompleteCommissioningStep { .. } | SkipCommissioningStep { .. } | SkipCommissioning | ExitCommissioning
+            | SetDemoMode { .. } | BeginSensorMapping | SkipSensorRole | CancelSensorMapping | FinishSensorMapping
+            | StartSpringCheck | CancelSpringCheck
+            | StartBurstLog { .. } | StartDatalog | StopDatalog | StartCalibration | AbortCalibration | ImportLearnedData { .. } | ResetLearnedData
+            | ClearSafetyLog | ConfirmPresence { .. } | SetAggression { .. }
+        )
+    }
+}
+
+/// Client side: numbers mutating requests
+#[derive(Debug, Clone)]
+pub struct RequestSequencer {
+    next: u32,
+}
+
+impl Default for RequestSequencer {
+    fn default() -> Self {
+        Self { next: 1 }
+    }
+}
+
+impl RequestSequencer {
+    /// Wrap a mutating request with the next sequence number; other requests pass through
+    pub fn prepare(&mut self, request: ProtocolMessage) -> ProtocolMessage {
+        if !request.is_mutating() {
+            return request;
+        }
+        
+        let sequence = self.next;
+        self.next = self.next.wrapping_add(1);
+        ProtocolMessage::Sequenced { sequence, request: Box::new(request) }
+    }
+}
+
+/// What the device must do with a sequenced request
+#[derive(Debug, Clone)]
+pub enum ReplayCheck {
+    /// Not seen before - apply it and record the response
+    Apply,
+    /// Retry of the last applied request - send this response again
+    Repeat(Box<ProtocolMessage>),
+    /// Older than the last applied request - reject
+    Stale { last: u32 },
+}
+
+/// Device side: remembers the last applied sequence number and its response
+#[derive(Debug, Clone, Default)]
+pub struct ReplayGuard {
+    last: Option<(u32, ProtocolMessage)>,
+    replays: u32,
+}
+
+impl ReplayGuard {
+    /// Decide whether a sequenced request is applied
+    pub fn check(&mut self, sequence: u32) -> ReplayCheck {
+        let Some((last, response)) = &self.last else {
+            return ReplayCheck::Apply;
+        };
+        
+        // Wrapping comparison - the counter may roll over on a long session
+        match sequence.wrapping_sub(*last) as i32 {
+            0 => {
+                self.replays += 1;
+                ReplayCheck::Repeat(Box::new(response.clone()))
+            },
+            delta if delta < 0 => {
+                self.replays += 1;
+                ReplayCheck::Stale { last: *last }
+            },
+            _ => ReplayCheck::Apply,
+        }
+    }
+    
+    /// Record the response sent for an applied request
+    pub fn record(&mut self, sequence: u32, response: ProtocolMessage) {
+        self.last = Some((sequence, response));
+    }
+    
+    /// Forget the last applied request (new connection)
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+    
+    /// Duplicate or stale requests not applied
+    pub fn replays(&self) -> u32 {
+        self.replays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_only_mutating_requests_are_sequenced() {
+        let mut sequencer = RequestSequencer::default();
+        assert!(matches!(sequencer.prepare(ProtocolMessage::GetStatus), ProtocolMessage::GetStatus));
+        assert!(matches!(sequencer.prepare(ProtocolMessage::ResetMapZeroBaseline),
+            ProtocolMessage::Sequenced { sequence: 1, .. }));
+        assert!(matches!(sequencer.prepare(ProtocolMessage::SkipCommissioning),
+            ProtocolMessage::Sequenced { sequence: 2, .. }));
+    }
+    
+    #[test]
+    fn test_repeat_returns_recorded_response() {
+        let mut guard = ReplayGuard::default();
+        assert!(matches!(guard.check(7), ReplayCheck::Apply));
+        guard.record(7, ProtocolMessage::MapZeroBaselineReset);
+        
+        assert!(matches!(guard.check(7), ReplayCheck::Repeat(response) if matches!(*response, ProtocolMessage::MapZeroBaselineReset)));
+        assert!(matches!(guard.check(6), ReplayCheck::Stale { last: 7 }));
+        assert!(matches!(guard.check(8), ReplayCheck::Apply));
+        assert_eq!(guard.replays(), 2);
+        
+        guard.reset();
+        assert!(matches!(guard.check(1), ReplayCheck::Apply));
+    }
+    
+    #[test]
+    fn test_sequence_rollover_is_not_stale() {
+        let mut guard = ReplayGuard::default();
+        guard.record(u32::MAX, ProtocolMessage::ConfigUpdated);
+        assert!(matches!(guard.check(0), ReplayCheck::Apply));
+    }
+}
@@ -0,0 +1,154 @@
+//! Request Retry
+//! 
+//! 🔗 T4-PROTOCOL-003: Request Timeout and Retry
+//! Derived From: Protocols.md Message Timing and Constraints (5 s request timeout, 1 concurrent request)
+//! AI Traceability: Recover from requests or responses lost on a degraded serial/Bluetooth link
+
+use crate::{ProtocolError, ProtocolMessage};
+
+/// Retry policy for client requests
+/// 
+/// Defaults keep all attempts inside the 5 s request timeout. Resending is safe:
+/// read-only requests can be repeated freely and mutating requests are
+/// sequenced, so the device applies a resent command at most once (see
+/// `replay`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Time to wait for a response before resending (ms)
+    pub attempt_timeout_ms: u32,
+    /// Total attempts including the first send
+    pub max_attempts: u8,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempt_timeout_ms: 1500,
+            max_attempts: 3,
+        }
+    }
+}
+
+/// What the client must do for the pending request
+#[derive(Debug, Clone)]
+pub enum RetryAction {
+    /// Send the request again
+    Resend(ProtocolMessage),
+    /// All attempts timed out - request abandoned
+    GiveUp(ProtocolMessage),
+}
+
+/// Request retry statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RetryStats {
+    /// Requests started
+    pub requests: u32,
+    /// Requests answered
+    pub completed: u32,
+    /// Resends after an attempt timed out
+    pub retries: u32,
+    /// Requests abandoned after the last attempt
+    pub failures: u32,
+}
+
+#[derive(Debug, Clone)]
+struct PendingRequest {
+    request: ProtocolMessage,
+    sent_ms: u32,
+    attempts: u8,
+}
+
+/// Tracks the single outstanding client request
+#[derive(Debug, Clone, Default)]
+pub struct RequestTracker {
+    policy: RetryPolicy,
+    pending: Option<PendingRequest>,
+    stats: RetryStats,
+}
+
+impl RequestTracker {
+    /// Create tracker with a retry policy
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy, pending: None, stats: RetryStats::default() }
+    }
+    
+    /// Retry statistics
+    pub fn stats(&self) -> &RetryStats {
+        &self.stats
+    }
+    
+    /// Whether a request is awaiting its response
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+    
+    /// Record a request as sent
+    pub fn begin(&mut self, request: ProtocolMessage, now_ms: u32) -> Result<(), ProtocolError> {
+        if self.pending.is_some() {
+            return Err(ProtocolError::RequestInProgress);
+        }
+        
+        self.stats.requests += 1;
+        self.pending = Some(PendingRequest { request, sent_ms: now_ms, attempts: 1 });
+        Ok(())
+    }
+    
+    /// Offer a received message; true if it answered the pending request
+    pub fn accept(&mut self, message: &ProtocolMessage) -> bool {
+        let answered = self.pending.as_ref().is_some_and(|pending| message.answers(&pending.request));
+        if answered {
+            self.pending = None;
+            self.stats.completed += 1;
+        }
+        answered
+    }
+    
+    /// Check the pending request for an attempt timeout
+    pub fn poll(&mut self, now_ms: u32) -> Option<RetryAction> {
+        let pending = self.pending.as_mut()?;
+        if now_ms.wrapping_sub(pending.sent_ms) < self.policy.attempt_timeout_ms {
+            return None;
+        }
+        
+        if pending.attempts >= self.policy.max_attempts {
+            self.stats.failures += 1;
+            return self.pending.take().map(|pending| RetryAction::GiveUp(pending.request));
+        }
+        
+        pending.attempts += 1;
+        pending.sent_ms = now_ms;
+        self.stats.retries += 1;
+        Some(RetryAction::Resend(pending.request.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_resend_then_give_up() {
+        let mut tracker = RequestTracker::new(RetryPolicy::default());
+        tracker.begin(ProtocolMessage::GetScreen, 0).unwrap();
+        assert!(tracker.begin(ProtocolMessage::GetStatus, 10).is_err());
+        
+        assert!(tracker.poll(1499).is_none());
+        assert!(matches!(tracker.poll(1500), Some(RetryAction::Resend(ProtocolMessage::GetScreen))));
+        assert!(matches!(tracker.poll(3000), Some(RetryAction::Resend(_))));
+        assert!(matches!(tracker.poll(4500), Some(RetryAction::GiveUp(_))));
+        
+        assert!(!tracker.is_pending());
+        assert_eq!(tracker.stats().retries, 2);
+        assert_eq!(tracker.stats().failures, 1);
+    }
+    
+    #[test]
+    fn test_only_matching_response_completes() {
+        let mut tracker = RequestTracker::new(RetryPolicy::default());
+        tracker.begin(ProtocolMessage::AcknowledgeMaintenance { name: "Inspect dome lines".into() }, 0).unwrap();
+        
+        assert!(!tracker.accept(&ProtocolMessage::ConfigUpdated));
+        assert!(tracker.accept(&ProtocolMessage::MaintenanceAcknowledged));
+        assert_eq!(tracker.stats().completed, 1);
+    }
+}
@@ -0,0 +1,220 @@
+//! Console Router
+//! 
+//! 🔗 T4-PROTOCOL-010: USB and Bluetooth Console Router
+//! Derived From: T4-HAL-024 (USB and Bluetooth Serial Console) + T4-PROTOCOL-006 (Session Privileges) + T4-CORE-133 (Bluetooth Console Access)
+//! AI Traceability: The CLI and the phone app speak the same framed protocol whether the link is a cable or Bluetooth
+//! 
+//! The comms task polls the router once per loop. The router moves bytes
+//! between the console ports and one frame decoder per port, and keeps each
+//! port's connection state:
+//! - A new connection identifier starts a fresh session - framing restarts
+//!   and authentication is dropped - and is reported, so the request handler
+//!   can drop its own per-session state (replay record, held request, streams).
+//! - A Bluetooth connection is authenticated once the handler has confirmed
+//!   its `Authenticate` request with the presence code.
+//! - With Bluetooth disabled in the configuration the port is not advertised,
+//!   and anything still arriving on it is read and dropped.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use rumbledome_hal::{ConsolePort, ConsoleSerial, HalError, HalResult};
+
+use crate::{encode_frame, ConsoleConfig, FrameDecoder, FramingStats, ProtocolMessage, SessionRole, MAX_REQUEST_BYTES, MAX_RESPONSE_BYTES};
+
+/// Bytes read from one port per poll - the rest waits for the next loop
+pub const CONSOLE_READ_BUDGET: usize = 512;
+
+/// Bytes read from a port at a time
+const READ_CHUNK: usize = 64;
+
+/// Something that happened on a console port
+#[derive(Debug, Clone)]
+pub enum ConsoleEvent {
+    /// A client connected (any earlier session on the port is over)
+    Connected(ConsolePort),
+    /// The client went away
+    Disconnected(ConsolePort),
+    /// A complete request arrived (boxed - requests dwarf the other events)
+    Request { port: ConsolePort, request: Box<ProtocolMessage> },
+}
+
+/// One port's session state
+#[derive(Debug, Clone)]
+struct ConsoleLink {
+    decoder: FrameDecoder,
+    connection: Option<u32>,
+    authenticated: bool,
+}
+
+impl ConsoleLink {
+    fn new(connection: Option<u32>) -> Self {
+        Self { decoder: FrameDecoder::new(MAX_REQUEST_BYTES), connection, authenticated: false }
+    }
+}
+
+/// Multiplexes the framed protocol over USB serial and Bluetooth
+#[derive(Debug, Clone)]
+pub struct ConsoleRouter {
+    links: [ConsoleLink; 2],
+}
+
+impl Default for ConsoleRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConsoleRouter {
+    /// Create router with no client on either port
+    pub fn new() -> Self {
+        Self { links: ConsolePort::ALL.map(|_| ConsoleLink::new(None)) }
+    }
+    
+    /// Start or stop advertising Bluetooth to match the configuration
+    /// 
+    /// The port is advertised under the device name and paired without a PIN
+    /// - the presence code is what grants more than read-only access.
+    pub fn configure(&mut self, serial: &mut impl ConsoleSerial, config: &ConsoleConfig, device_name: &str) -> HalResult<()> {
+        if config.bluetooth_enabled {
+            serial.start_bluetooth(device_name, "")
+        } else {
+            self.links[ConsolePort::Bluetooth as usize] = ConsoleLink::new(None);
+            serial.stop_bluetooth()
+        }
+    }
+    
+    /// Read every port; returns connection changes and complete requests in arrival order
+    pub fn poll(&mut self, serial: &mut impl ConsoleSerial, config: &ConsoleConfig) -> Vec<ConsoleEvent> {
+        let mut events = Vec::new();
+        for port in ConsolePort::ALL {
+            let connection = serial.console_connection(port).map(|connection| connection.id);
+            let link = &mut self.links[port as usize];
+            if connection != link.connection {
+                if link.connection.is_some() {
+                    events.push(ConsoleEvent::Disconnected(port));
+                }
+                *link = ConsoleLink::new(connection);
+                if connection.is_some() {
+                    events.push(ConsoleEvent::Connected(port));
+                }
+            }
+            if connection.is_none() {
+                continue;
+            }
+            
+            let accept = port == ConsolePort::Usb || config.bluetooth_enabled;
+            let mut buffer = [0u8; READ_CHUNK];
+            let mut read = 0;
+            while read < CONSOLE_READ_BUDGET {
+                // A port that cannot be read is treated as idle until the next poll
+                let count = serial.console_receive(port, &mut buffer).unwrap_or(0);
+                if count == 0 {
+                    break;
+                }
+                read += count;
+                if accept {
+                    events.extend(link.decoder.push(&buffer[..count]).into_iter().map(|request| ConsoleEvent::Request { port, request: Box::new(request) }));
+                }
+            }
+        }
+        events
+    }
+    
+    /// Encode a message and send it on a port; returns the bytes sent
+    pub fn send(&mut self, serial: &mut impl ConsoleSerial, port: ConsolePort, message: &ProtocolMessage) -> HalResult<usize> {
+        let frame = encode_frame(message, MAX_RESPONSE_BYTES)
+            .map_err(|e| HalError::InvalidParameter(format!("Response could not be framed: {:?}", e)))?;
+        serial.console_send(port, &frame)?;
+        Ok(frame.len())
+    }
+    
+    /// Privilege level of the session on a port
+    pub fn role(&self, port: ConsolePort, config: &ConsoleConfig) -> SessionRole {
+        SessionRole::of_console(port, self.links[port as usize].authenticated, config)
+    }
+    
+    /// Mark the connection on a port authenticated (its presence code matched)
+    /// 
+    /// Lasts until the client disconnects.
+    pub fn authenticate(&mut self, port: ConsolePort) {
+        let link = &mut self.links[port as usize];
+        link.authenticated = link.connection.is_some();
+    }
+    
+    /// Whether the connection on a port has authenticated
+    pub fn is_authenticated(&self, port: ConsolePort) -> bool {
+        self.links[port as usize].authenticated
+    }
+    
+    /// Request framing statistics of the connection on a port
+    pub fn framing_stats(&self, port: ConsolePort) -> &FramingStats {
+        self.links[port as usize].decoder.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_hal::MockHal;
+    
+    fn frame(message: &ProtocolMessage) -> Vec<u8> {
+        encode_frame(message, MAX_REQUEST_BYTES).unwrap()
+    }
+    
+    #[test]
+    fn test_requests_from_both_ports() {
+        let mut hal = MockHal::new();
+        let mut router = ConsoleRouter::new();
+        let config = ConsoleConfig::default();
+        hal.connect_console(ConsolePort::Usb, None);
+        hal.connect_console(ConsolePort::Bluetooth, Some("phone"));
+        
+        let events = router.poll(&mut hal, &config);
+        assert!(matches!(events[..], [ConsoleEvent::Connected(ConsolePort::Usb), ConsoleEvent::Connected(ConsolePort::Bluetooth)]));
+        
+        hal.push_console_input(ConsolePort::Usb, &frame(&ProtocolMessage::GetStatus));
+        hal.push_console_input(ConsolePort::Bluetooth, &frame(&ProtocolMessage::Hello));
+        let events = router.poll(&mut hal, &config);
+        assert!(matches!(&events[..], [
+            ConsoleEvent::Request { port: ConsolePort::Usb, request: usb },
+            ConsoleEvent::Request { port: ConsolePort::Bluetooth, request: bluetooth },
+        ] if matches!(**usb, ProtocolMessage::GetStatus) && matches!(**bluetooth, ProtocolMessage::Hello)));
+        
+        let sent = router.send(&mut hal, ConsolePort::Bluetooth, &ProtocolMessage::ConfigUpdated).unwrap();
+        assert_eq!(hal.take_console_output(ConsolePort::Bluetooth).len(), sent);
+    }
+    
+    #[test]
+    fn test_authentication_ends_with_the_connection() {
+        let mut hal = MockHal::new();
+        let mut router = ConsoleRouter::new();
+        let config = ConsoleConfig::default();
+        hal.connect_console(ConsolePort::Bluetooth, Some("phone"));
+        router.poll(&mut hal, &config);
+        assert_eq!(router.role(ConsolePort::Bluetooth, &config), SessionRole::Guest);
+        
+        router.authenticate(ConsolePort::Bluetooth);
+        assert_eq!(router.role(ConsolePort::Bluetooth, &config), SessionRole::Paired);
+        
+        // Reconnecting - even the same phone - starts read-only again
+        hal.connect_console(ConsolePort::Bluetooth, Some("phone"));
+        let events = router.poll(&mut hal, &config);
+        assert!(matches!(events[..], [ConsoleEvent::Disconnected(ConsolePort::Bluetooth), ConsoleEvent::Connected(ConsolePort::Bluetooth)]));
+        assert!(!router.is_authenticated(ConsolePort::Bluetooth));
+    }
+    
+    #[test]
+    fn test_disabled_bluetooth_is_dropped() {
+        let mut hal = MockHal::new();
+        let mut router = ConsoleRouter::new();
+        let config = ConsoleConfig { bluetooth_enabled: false, ..ConsoleConfig::default() };
+        router.configure(&mut hal, &config, "RumbleDome").unwrap();
+        assert_eq!(hal.bluetooth_name(), None);
+        
+        hal.connect_console(ConsolePort::Bluetooth, None);
+        router.poll(&mut hal, &config);
+        hal.push_console_input(ConsolePort::Bluetooth, &frame(&ProtocolMessage::Hello));
+        assert!(router.poll(&mut hal, &config).is_empty());
+    }
+}
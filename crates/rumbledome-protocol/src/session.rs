@@ -0,0 +1,242 @@
+//! Concurrent Sessions
+//! 
+//! 🔗 T4-PROTOCOL-006: Session Privileges and Bandwidth Arbitration
+//! Derived From: Protocols.md Communication Transport + T4-PROTOCOL-004 (Link-Aware Telemetry Throttling)
+//! AI Traceability: A passenger's phone can watch the gauges while the laptop configures, without either getting in the way
+//! 
+//! Session rules:
+//! - An admin session (USB) may send any request.
+//! - A guest session (Bluetooth, until authenticated) is read-only:
+//!   identification, status, the display mirror, configuration names and
+//!   analytics. Everything else is refused, including reads with side effects
+//!   such as the live-tune keepalive. The exceptions are context hints from
+//!   the driver's phone - the profile schedule they feed only ever steps down
+//!   from the driver's own configuration - and authentication itself.
+//! - A paired session (authenticated Bluetooth) may send anything except
+//!   safety-relevant writes, unless `console.bluetooth_safety_writes` makes it
+//!   an admin session.
+//! - Each session has its own framing, replay record and screen subscription.
+//! - Responses are always sent. Stream frames share one byte budget, with part
+//!   of it held back for the admin session so a guest stream never slows
+//!   configuration down.
+
+use rumbledome_hal::ConsolePort;
+
+use crate::{ConsoleConfig, ProtocolMessage};
+
+/// Byte budget the comms task spends on outgoing traffic per second
+pub const COMMS_BUDGET_BYTES_PER_S: u32 = 8 * 1024;
+
+/// Burst the budget can bank while idle (ms of budget)
+const BURST_MS: u32 = 250;
+
+/// Share of the burst a guest stream may not dip into
+const ADMIN_RESERVE_FRACTION: f32 = 0.5;
+
+/// Privilege level of a protocol session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionRole {
+    /// Full access - configuration, tuning, commissioning
+    Admin,
+    /// Everything but safety-relevant writes
+    Paired,
+    /// Read-only - gauges and status
+    Guest,
+}
+
+impl SessionRole {
+    /// Role of a console connection
+    /// 
+    /// USB always has full access. Bluetooth is read-only until the connection
+    /// authenticates, then paired or admin as the configuration allows.
+    pub fn of_console(port: ConsolePort, authenticated: bool, config: &ConsoleConfig) -> Self {
+        match port {
+            ConsolePort::Usb => SessionRole::Admin,
+            ConsolePort::Bluetooth if !authenticated => SessionRole::Guest,
+            ConsolePort::Bluetooth if config.bluetooth_safety_writes => SessionRole::Admin,
+            ConsolePort::Bluetooth => SessionRole::Paired,
+        }
+    }
+}
+
+impl ProtocolMessage {
+    /// Whether a session with this role may send the request
+    pub fn permitted_for(&self, role: SessionRole) -> bool {
+        use ProtocolMessage::*;
+        
+        match role {
+            SessionRole::Admin => true,
+            SessionRole::Paired => !self.is_safety_relevant(),
+            SessionRole::Guest => matches!(self,
+                Hello | GetStatus | GetScreen | SubscribeScreen { .. } | UnsubscribeScreen | SubscribeTelemetry { .. }
+                | UnsubscribeTelemetry | ListConfigurations
+                | GetCommissioningStatus | GetCalibrationStatus | GetDatalogStatus | GetDistribution | GetTrends { .. } | SetContextHints(_)
+                | Authenticate | ConfirmPresence { .. }
+            ),
+        }
+    }
+    
+    /// Whether the request changes how boost is controlled or limited
+    /// 
+    /// Configuration, live tuning, commissioning, calibration, learned data
+    /// and the safety log. Cancelling or reverting something, cosmetic
+    /// settings and app aggression (bounded by the configured limits) are not.
+    pub fn is_safety_relevant(&self) -> bool {
+        use ProtocolMessage::*;
+        
+        match self {
+            Sequenced { request, .. } => request.is_safety_relevant(),
+            _ => matches!(self,
+                SetConfig(_) | SetControlMode { .. } | SaveConfiguration { .. } | SaveInheritedConfiguration { .. }
+                | SelectConfiguration { .. } | DeleteConfiguration { .. } | SetProfileSchedule { .. }
+                | SetTestValue { .. } | CommitTestValues | ResetMapZeroBaseline
+                | BeginCommissioning | CompleteCommissioningStep { .. } | SkipCommissioningStep { .. } | SkipCommissioning | ExitCommissioning
+                | BeginSensorMapping | SkipSensorRole | FinishSensorMapping | StartSpringCheck
+                | StartCalibration | ImportLearnedData { .. } | ResetLearnedData | ClearSafetyLog
+            ),
+        }
+    }
+}
+
+/// Bandwidth arbitration statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArbiterStats {
+    /// Stream frames allowed out
+    pub granted: u32,
+    /// Stream frames held back for lack of budget
+    pub deferred: u32,
+}
+
+/// Shared outgoing byte budget of the comms task
+/// 
+/// A token bucket refilled at `COMMS_BUDGET_BYTES_PER_S`. Responses are charged
+/// unconditionally and may drive the bucket into debt; stream frames only go
+/// out when the bucket covers them, and guest frames only from the part above
+/// the admin reserve.
+#[derive(Debug, Clone)]
+pub struct BandwidthArbiter {
+    budget_bytes_per_s: u32,
+    tokens: f32,
+    last_refill_ms: Option<u32>,
+    stats: ArbiterStats,
+}
+
+impl Default for BandwidthArbiter {
+    fn default() -> Self {
+        Self::new(COMMS_BUDGET_BYTES_PER_S)
+    }
+}
+
+impl BandwidthArbiter {
+    /// Create arbiter with a full burst available
+    pub fn new(budget_bytes_per_s: u32) -> Self {
+        let mut arbiter = Self { budget_bytes_per_s, tokens: 0.0, last_refill_ms: None, stats: ArbiterStats::default() };
+        arbiter.tokens = arbiter.capacity();
+        arbiter
+    }
+    
+    /// Arbitration statistics
+    pub fn stats(&self) -> &ArbiterStats {
+        &self.stats
+    }
+    
+    /// Bytes currently available (negative while paying off a large response)
+    pub fn available_bytes(&self) -> f32 {
+        self.tokens
+    }
+    
+    fn capacity(&self) -> f32 {
+        self.budget_bytes_per_s as f32 * BURST_MS as f32 / 1000.0
+    }
+    
+    fn refill(&mut self, now_ms: u32) {
+        let elapsed_ms = self.last_refill_ms.map(|last| now_ms.wrapping_sub(last)).unwrap_or(0);
+        self.last_refill_ms = Some(now_ms);
+        self.tokens = (self.tokens + self.budget_bytes_per_s as f32 * elapsed_ms as f32 / 1000.0).min(self.capacity());
+    }
+    
+    /// Account for a response - responses are never held back
+    pub fn charge(&mut self, bytes: usize, now_ms: u32) {
+        self.refill(now_ms);
+        self.tokens -= bytes as f32;
+    }
+    
+    /// Whether a stream frame for a session with this role may go out now (charged if so)
+    pub fn grant_stream(&mut self, role: SessionRole, bytes: usize, now_ms: u32) -> bool {
+        self.refill(now_ms);
+        let floor = match role {
+            SessionRole::Admin => 0.0,
+            SessionRole::Paired | SessionRole::Guest => self.capacity() * ADMIN_RESERVE_FRACTION,
+        };
+        
+        if self.tokens - bytes as f32 >= floor {
+            self.tokens -= bytes as f32;
+            self.stats.granted += 1;
+            true
+        } else {
+            self.stats.deferred += 1;
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use crate::{ContextHints, SystemConfig};
+    
+    #[test]
+    fn test_guest_is_read_only() {
+        assert!(ProtocolMessage::GetScreen.permitted_for(SessionRole::Guest));
+        assert!(ProtocolMessage::SubscribeScreen { interval_ms: 100 }.permitted_for(SessionRole::Guest));
+        assert!(ProtocolMessage::SubscribeTelemetry { interval_ms: 50 }.permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::SetConfig(SystemConfig::default()).permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::GetLiveTuneStatus.permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::GetSupportBundle.permitted_for(SessionRole::Guest));
+        assert!(ProtocolMessage::SetContextHints(ContextHints::default()).permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::SetProfileSchedule { rules: Vec::new() }.permitted_for(SessionRole::Guest));
+        assert!(ProtocolMessage::GetCalibrationStatus.permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::StartCalibration.permitted_for(SessionRole::Guest));
+        assert!(!ProtocolMessage::ExportLearnedData.permitted_for(SessionRole::Guest));
+        assert!(ProtocolMessage::SetConfig(SystemConfig::default()).permitted_for(SessionRole::Admin));
+        assert!(ProtocolMessage::Authenticate.permitted_for(SessionRole::Guest));
+    }
+    
+    #[test]
+    fn test_paired_session_is_kept_from_safety_writes() {
+        let config = ConsoleConfig::default();
+        assert_eq!(SessionRole::of_console(ConsolePort::Usb, false, &config), SessionRole::Admin);
+        assert_eq!(SessionRole::of_console(ConsolePort::Bluetooth, false, &config), SessionRole::Guest);
+        let role = SessionRole::of_console(ConsolePort::Bluetooth, true, &config);
+        assert_eq!(role, SessionRole::Paired);
+        
+        assert!(ProtocolMessage::SetAggression { value: 0.5 }.permitted_for(role));
+        assert!(ProtocolMessage::CancelSpringCheck.permitted_for(role));
+        assert!(!ProtocolMessage::SetConfig(SystemConfig::default()).permitted_for(role));
+        let sequenced = ProtocolMessage::Sequenced { sequence: 1, request: Box::new(ProtocolMessage::ResetLearnedData) };
+        assert!(!sequenced.permitted_for(role));
+        
+        let trusted = ConsoleConfig { bluetooth_safety_writes: true, ..config };
+        assert_eq!(SessionRole::of_console(ConsolePort::Bluetooth, true, &trusted), SessionRole::Admin);
+    }
+    
+    #[test]
+    fn test_guest_stream_yields_to_admin_traffic() {
+        let mut arbiter = BandwidthArbiter::new(8000);
+        // 2000 byte burst; guest may use the top 1000
+        assert!(arbiter.grant_stream(SessionRole::Guest, 600, 0));
+        assert!(!arbiter.grant_stream(SessionRole::Guest, 600, 0));
+        assert!(arbiter.grant_stream(SessionRole::Admin, 600, 0));
+        
+        // A large admin response puts the bucket in debt and pauses every stream
+        arbiter.charge(4000, 0);
+        assert!(!arbiter.grant_stream(SessionRole::Admin, 200, 100));
+        assert!(!arbiter.grant_stream(SessionRole::Guest, 200, 400));
+        
+        // Budget recovers with time
+        assert!(arbiter.grant_stream(SessionRole::Guest, 200, 1000));
+        assert_eq!(arbiter.stats().deferred, 3);
+    }
+}
@@ -0,0 +1,143 @@
+//! Telemetry Throttling
+//! 
+//! 🔗 T4-PROTOCOL-004: Link-Aware Telemetry Throttling
+//! Derived From: Protocols.md Bluetooth Interface (SPP) + status polling limits
+//! AI Traceability: Streamed telemetry backs off on slow links instead of building unbounded latency
+
+/// Fastest stream interval a client may request (ms)
+pub const MIN_STREAM_INTERVAL_MS: u32 = 50;
+
+/// Slowest interval the throttle backs off to (ms)
+pub const MAX_STREAM_INTERVAL_MS: u32 = 2000;
+
+/// Unsent bytes above which the link is treated as congested (about one screen frame)
+pub const BACKLOG_HIGH_WATER_BYTES: usize = 256;
+
+/// Consecutive sends onto an empty link before the interval is shortened again
+const RECOVERY_SENDS: u8 = 10;
+
+/// Telemetry throttle statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThrottleStats {
+    /// Stream frames sent
+    pub sent: u32,
+    /// Stream frames skipped because the link was congested
+    pub skipped: u32,
+}
+
+/// Stream rate controller for one telemetry subscription
+/// 
+/// Queued telemetry is stale by the time it arrives, so a congested link skips
+/// frames and doubles the interval rather than queueing behind the backlog.
+/// The interval recovers toward the requested rate once the link stays clear.
+#[derive(Debug, Clone)]
+pub struct TelemetryThrottle {
+    requested_interval_ms: u32,
+    effective_interval_ms: u32,
+    last_due_ms: Option<u32>,
+    clear_sends: u8,
+    load_multiplier: u32,
+    stats: ThrottleStats,
+}
+
+impl TelemetryThrottle {
+    /// Create throttle for a requested stream interval
+    pub fn new(requested_interval_ms: u32) -> Self {
+        let requested_interval_ms = requested_interval_ms.clamp(MIN_STREAM_INTERVAL_MS, MAX_STREAM_INTERVAL_MS);
+        
+        Self {
+            requested_interval_ms,
+            effective_interval_ms: requested_interval_ms,
+            last_due_ms: None,
+            clear_sends: 0,
+            load_multiplier: 1,
+            stats: ThrottleStats::default(),
+        }
+    }
+    
+    /// Interval currently in effect (ms)
+    pub fn effective_interval_ms(&self) -> u32 {
+        self.effective_interval_ms
+    }
+    
+    /// Stretch the interval by a multiple while the device sheds background work (1 = as requested)
+    pub fn set_load_multiplier(&mut self, multiplier: u32) {
+        self.load_multiplier = multiplier.max(1);
+    }
+    
+    /// Throttle statistics
+    pub fn stats(&self) -> &ThrottleStats {
+        &self.stats
+    }
+    
+    /// Whether a stream frame should be sent now, given unsent bytes on the link
+    pub fn poll(&mut self, now_ms: u32, backlog_bytes: usize) -> bool {
+        let due = self.last_due_ms
+            .map(|last| now_ms.wrapping_sub(last) >= self.effective_interval_ms.saturating_mul(self.load_multiplier))
+            .unwrap_or(true);
+        if !due {
+            return false;
+        }
+        self.last_due_ms = Some(now_ms);
+        
+        if backlog_bytes > BACKLOG_HIGH_WATER_BYTES {
+            self.effective_interval_ms = (self.effective_interval_ms * 2).min(MAX_STREAM_INTERVAL_MS);
+            self.clear_sends = 0;
+            self.stats.skipped += 1;
+            return false;
+        }
+        
+        if backlog_bytes == 0 {
+            self.clear_sends += 1;
+            if self.clear_sends >= RECOVERY_SENDS && self.effective_interval_ms > self.requested_interval_ms {
+                self.effective_interval_ms = (self.effective_interval_ms / 2).max(self.requested_interval_ms);
+                self.clear_sends = 0;
+            }
+        } else {
+            self.clear_sends = 0;
+        }
+        
+        self.stats.sent += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_congestion_backs_off_and_recovers() {
+        let mut throttle = TelemetryThrottle::new(100);
+        assert!(throttle.poll(0, 0));
+        assert!(!throttle.poll(50, 0));
+        
+        assert!(!throttle.poll(100, 1000));
+        assert_eq!(throttle.effective_interval_ms(), 200);
+        
+        let mut t = 100;
+        for _ in 0..RECOVERY_SENDS {
+            t += 200;
+            assert!(throttle.poll(t, 0));
+        }
+        assert_eq!(throttle.effective_interval_ms(), 100);
+        assert_eq!(throttle.stats().skipped, 1);
+    }
+    
+    #[test]
+    fn test_load_multiplier_stretches_interval() {
+        let mut throttle = TelemetryThrottle::new(100);
+        throttle.set_load_multiplier(4);
+        assert!(throttle.poll(0, 0));
+        assert!(!throttle.poll(300, 0));
+        assert!(throttle.poll(400, 0));
+        
+        throttle.set_load_multiplier(1);
+        assert!(throttle.poll(500, 0));
+    }
+    
+    #[test]
+    fn test_requested_interval_clamped() {
+        assert_eq!(TelemetryThrottle::new(1).effective_interval_ms(), MIN_STREAM_INTERVAL_MS);
+    }
+}
@@ -0,0 +1,359 @@
+//! Safety Path Coverage
+//! 
+//! 🔗 T4-SIMULATOR-010: Scenario Suite Safety Coverage
+//! Derived From: T4-SIMULATOR-005 (Headless Scenario Execution) + Safety.md fault response hierarchy
+//! AI Traceability: Show which system states, safety actions and fault codes a scenario suite actually exercises
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{
+    ControlMode, CoreError, FaultCode, OutputDegradation, RumbleDomeCore, SystemConfig, SystemState,
+};
+use rumbledome_hal::MockHal;
+
+use crate::runner::{ScenarioResult, ScenarioRunner};
+use crate::scenario::TestScenario;
+
+/// Every `SystemState` variant
+pub const STATE_NAMES: [&str; 7] = ["Initializing", "Idle", "Armed", "Calibrating", "Commissioning", "OverboostCut", "Fault"];
+
+/// Every `FaultCode` variant
+pub const FAULT_NAMES: [&str; 17] = [
+    "SelfTestFailed", "PwmHardwareFault", "PressureSensorFault", "CanCommunicationLost",
+    "StorageSystemFault", "OverboostLimitExceeded", "PneumaticSystemFailure", "SafetyResponseTooSlow",
+    "InvalidConfiguration", "CalibrationDataCorrupted", "TorqueSignalsInvalid", "ImplausibleSensorReading",
+    "InputSourceMismatch", "CalibrationFailed", "LearningInconsistency", "ControlFault",
+    "SafetyLimitsCorrupted",
+];
+
+/// Protective responses the core can take, as observed from outside
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SafetyAction {
+    /// Overboost cut - 0% duty until pressure drops
+    OverboostCut,
+    /// Fault state holding the output failsafe
+    FaultFailsafe,
+    /// Solenoid channel lost - boost limited to spring pressure
+    LimpMode,
+    /// No usable output stage
+    OutputDisabled,
+    /// Boost target tapered approaching the rev limiter
+    RevLimitTaper,
+    /// Torque assistance reduced for degraded CAN data
+    CanTorqueDerate,
+    /// Domes vented with PWM off while idling off-boost
+    IdleVent,
+}
+
+impl SafetyAction {
+    /// Every safety action
+    pub const ALL: [SafetyAction; 7] = [
+        SafetyAction::OverboostCut,
+        SafetyAction::FaultFailsafe,
+        SafetyAction::LimpMode,
+        SafetyAction::OutputDisabled,
+        SafetyAction::RevLimitTaper,
+        SafetyAction::CanTorqueDerate,
+        SafetyAction::IdleVent,
+    ];
+    
+    /// Name used in coverage reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            SafetyAction::OverboostCut => "OverboostCut",
+            SafetyAction::FaultFailsafe => "FaultFailsafe",
+            SafetyAction::LimpMode => "LimpMode",
+            SafetyAction::OutputDisabled => "OutputDisabled",
+            SafetyAction::RevLimitTaper => "RevLimitTaper",
+            SafetyAction::CanTorqueDerate => "CanTorqueDerate",
+            SafetyAction::IdleVent => "IdleVent",
+        }
+    }
+}
+
+/// Variant name of a system state
+pub fn state_name(state: &SystemState) -> &'static str {
+    match state {
+        SystemState::Initializing => "Initializing",
+        SystemState::Idle => "Idle",
+        SystemState::Armed => "Armed",
+        SystemState::Calibrating(_) => "Calibrating",
+        SystemState::Commissioning(_) => "Commissioning",
+        SystemState::OverboostCut => "OverboostCut",
+        SystemState::Fault(_) => "Fault",
+    }
+}
+
+/// Variant name of a fault code
+/// 
+/// Deliberately exhaustive - a new fault code fails to compile here until it
+/// is added to `FAULT_NAMES` and shows up in coverage reports.
+pub fn fault_name(fault: &FaultCode) -> &'static str {
+    match fault {
+        FaultCode::SelfTestFailed => "SelfTestFailed",
+        FaultCode::PwmHardwareFault => "PwmHardwareFault",
+        FaultCode::PressureSensorFault(_) => "PressureSensorFault",
+        FaultCode::CanCommunicationLost => "CanCommunicationLost",
+        FaultCode::StorageSystemFault => "StorageSystemFault",
+        FaultCode::OverboostLimitExceeded { .. } => "OverboostLimitExceeded",
+        FaultCode::PneumaticSystemFailure => "PneumaticSystemFailure",
+        FaultCode::SafetyResponseTooSlow => "SafetyResponseTooSlow",
+        FaultCode::InvalidConfiguration(_) => "InvalidConfiguration",
+        FaultCode::CalibrationDataCorrupted => "CalibrationDataCorrupted",
+        FaultCode::TorqueSignalsInvalid => "TorqueSignalsInvalid",
+        FaultCode::ImplausibleSensorReading { .. } => "ImplausibleSensorReading",
+        FaultCode::InputSourceMismatch { .. } => "InputSourceMismatch",
+        FaultCode::CalibrationFailed(_) => "CalibrationFailed",
+        FaultCode::LearningInconsistency => "LearningInconsistency",
+        FaultCode::ControlFault(_) => "ControlFault",
+        FaultCode::SafetyLimitsCorrupted => "SafetyLimitsCorrupted",
+    }
+}
+
+/// Safety actions in effect after a control cycle
+pub(crate) fn active_safety_actions(core: &RumbleDomeCore<MockHal>, rpm: u16, t_ms: u32) -> Vec<SafetyAction> {
+    let mut actions = Vec::new();
+    
+    match core.state {
+        SystemState::OverboostCut => actions.push(SafetyAction::OverboostCut),
+        SystemState::Fault(_) => actions.push(SafetyAction::FaultFailsafe),
+        _ => {}
+    }
+    
+    match core.solenoid_failover.degradation() {
+        OutputDegradation::Nominal => {}
+        OutputDegradation::ReducedAuthority => actions.push(SafetyAction::LimpMode),
+        OutputDegradation::Failsafe => actions.push(SafetyAction::OutputDisabled),
+    }
+    
+    if core.state == SystemState::Armed {
+        let config = &core.config;
+        let untapered = core.level1_target_boost_psi;
+        if config.rev_limit_taper.apply(untapered, rpm, config.spring_pressure) < untapered {
+            actions.push(SafetyAction::RevLimitTaper);
+        }
+        
+        if config.control_mode == ControlMode::TorqueFollowing
+            && core.can_health.level(t_ms).torque_authority() < 1.0
+        {
+            actions.push(SafetyAction::CanTorqueDerate);
+        }
+        
+        if core.idle_vent.is_venting() {
+            actions.push(SafetyAction::IdleVent);
+        }
+    }
+    
+    actions
+}
+
+/// Coverage of one state, action or fault code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageItem {
+    /// Variant name
+    pub name: String,
+    /// Control cycles spent in it across the suite
+    pub cycles: u32,
+    /// Scenarios that reached it
+    pub scenarios: Vec<String>,
+}
+
+impl CoverageItem {
+    /// Reached by at least one scenario
+    pub fn covered(&self) -> bool {
+        self.cycles > 0
+    }
+}
+
+/// Coverage accumulated across scenario runs
+#[derive(Debug, Clone, Default)]
+pub struct CoverageTracker {
+    scenario: String,
+    scenarios_run: u32,
+    states: BTreeMap<&'static str, CoverageItem>,
+    actions: BTreeMap<&'static str, CoverageItem>,
+    faults: BTreeMap<&'static str, CoverageItem>,
+}
+
+impl CoverageTracker {
+    /// Create empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Attribute following observations to a scenario
+    pub fn begin_scenario(&mut self, name: &str) {
+        self.scenario = name.to_string();
+        self.scenarios_run += 1;
+    }
+    
+    /// Record the core's state after one control cycle
+    pub fn observe(&mut self, core: &RumbleDomeCore<MockHal>, rpm: u16, t_ms: u32) {
+        Self::hit(&mut self.states, state_name(&core.state), &self.scenario);
+        
+        if let SystemState::Fault(fault) = &core.state {
+            Self::hit(&mut self.faults, fault_name(fault), &self.scenario);
+        }
+        
+        for action in active_safety_actions(core, rpm, t_ms) {
+            Self::hit(&mut self.actions, action.name(), &self.scenario);
+        }
+    }
+    
+    /// Coverage of everything the suite could have reached
+    pub fn report(&self) -> CoverageReport {
+        let items = |names: &[&'static str], hits: &BTreeMap<&'static str, CoverageItem>| -> Vec<CoverageItem> {
+            names.iter()
+                .map(|name| hits.get(name).cloned().unwrap_or_else(|| CoverageItem {
+                    name: name.to_string(),
+                    cycles: 0,
+                    scenarios: Vec::new(),
+                }))
+                .collect()
+        };
+        let action_names: Vec<&'static str> = SafetyAction::ALL.iter().map(|action| action.name()).collect();
+        
+        CoverageReport {
+            scenarios_run: self.scenarios_run,
+            states: items(&STATE_NAMES, &self.states),
+            safety_actions: items(&action_names, &self.actions),
+            fault_codes: items(&FAULT_NAMES, &self.faults),
+        }
+    }
+    
+    fn hit(items: &mut BTreeMap<&'static str, CoverageItem>, name: &'static str, scenario: &str) {
+        let item = items.entry(name).or_insert_with(|| CoverageItem {
+            name: name.to_string(),
+            cycles: 0,
+            scenarios: Vec::new(),
+        });
+        item.cycles += 1;
+        if !item.scenarios.iter().any(|s| s == scenario) {
+            item.scenarios.push(scenario.to_string());
+        }
+    }
+}
+
+/// Safety path coverage of a scenario suite
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// Scenarios in the suite
+    pub scenarios_run: u32,
+    /// Every system state
+    pub states: Vec<CoverageItem>,
+    /// Every safety action
+    pub safety_actions: Vec<CoverageItem>,
+    /// Every fault code
+    pub fault_codes: Vec<CoverageItem>,
+}
+
+impl CoverageReport {
+    /// Names of states, actions and fault codes no scenario reached
+    pub fn gaps(&self) -> Vec<&str> {
+        self.states.iter()
+            .chain(&self.safety_actions)
+            .chain(&self.fault_codes)
+            .filter(|item| !item.covered())
+            .map(|item| item.name.as_str())
+            .collect()
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Safety coverage over {} scenarios", self.scenarios_run)?;
+        
+        for (title, items) in [
+            ("System states", &self.states),
+            ("Safety actions", &self.safety_actions),
+            ("Fault codes", &self.fault_codes),
+        ] {
+            let covered = items.iter().filter(|item| item.covered()).count();
+            writeln!(f, "\n{}: {}/{} covered", title, covered, items.len())?;
+            for item in items {
+                if item.covered() {
+                    writeln!(f, "  ✅ {:<26} {:>7} cycles  {}", item.name, item.cycles, item.scenarios.join(", "))?;
+                } else {
+                    writeln!(f, "  ❌ {:<26} not exercised", item.name)?;
+                }
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+/// Run a scenario suite and collect its safety coverage
+pub fn suite_coverage(
+    config: &SystemConfig,
+    scenarios: &[TestScenario],
+) -> Result<(Vec<ScenarioResult>, CoverageReport), CoreError> {
+    let runner = ScenarioRunner::new(config.clone());
+    let mut tracker = CoverageTracker::new();
+    
+    let results = scenarios.iter()
+        .map(|scenario| runner.run_with_coverage(scenario, &mut tracker))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok((results, tracker.report()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{builtin_scenarios, rev_limiter_bounce};
+    
+    #[test]
+    fn test_fault_names_complete() {
+        let faults = [
+            FaultCode::SelfTestFailed,
+            FaultCode::PwmHardwareFault,
+            FaultCode::PressureSensorFault(String::new()),
+            FaultCode::CanCommunicationLost,
+            FaultCode::StorageSystemFault,
+            FaultCode::OverboostLimitExceeded { pressure_psi: 0.0, limit_psi: 0.0 },
+            FaultCode::PneumaticSystemFailure,
+            FaultCode::SafetyResponseTooSlow,
+            FaultCode::InvalidConfiguration(String::new()),
+            FaultCode::CalibrationDataCorrupted,
+            FaultCode::TorqueSignalsInvalid,
+            FaultCode::ImplausibleSensorReading { sensor: String::new(), value: 0.0 },
+            FaultCode::InputSourceMismatch { input: String::new(), can_value: 0.0, analog_value: 0.0 },
+            FaultCode::CalibrationFailed(String::new()),
+            FaultCode::LearningInconsistency,
+            FaultCode::ControlFault(String::new()),
+            FaultCode::SafetyLimitsCorrupted,
+        ];
+        for fault in &faults {
+            assert!(FAULT_NAMES.contains(&fault_name(fault)));
+        }
+    }
+    
+    #[test]
+    fn test_builtin_suite_reports_reached_and_missed_paths() {
+        let config = SystemConfig::default();
+        let (results, report) = suite_coverage(&config, &builtin_scenarios(&config)).unwrap();
+        assert_eq!(report.scenarios_run as usize, results.len());
+        
+        let armed = report.states.iter().find(|item| item.name == "Armed").unwrap();
+        assert_eq!(armed.scenarios.len(), results.len());
+        
+        // Nothing in the built-in suite fails hardware self-test
+        assert!(report.gaps().contains(&"SelfTestFailed"));
+        assert!(report.to_string().contains("not exercised"));
+    }
+    
+    #[test]
+    fn test_rev_limiter_scenario_covers_taper() {
+        let mut config = SystemConfig { control_mode: ControlMode::BoostByTarget, ..SystemConfig::default() };
+        config.rev_limit_taper.enabled = true;
+        let scenario = rev_limiter_bounce(config.rev_limit_taper.redline_rpm as f32);
+        let (_, report) = suite_coverage(&config, &[scenario]).unwrap();
+        
+        let taper = report.safety_actions.iter().find(|item| item.name == "RevLimitTaper").unwrap();
+        assert!(taper.covered());
+    }
+}
@@ -0,0 +1,375 @@
+//! Single-Cycle Debugger
+//! 
+//! 🔗 T4-SIMULATOR-020: Conditional Breakpoints and Cycle Stepping
+//! Derived From: T4-SIMULATOR-005 (Headless Scenario Execution) + T4-SIMULATOR-010 (Scenario Suite Safety Coverage) + T4-CORE-098 (Live Variable Inspection)
+//! AI Traceability: Stop a scenario on the cycle the control logic misbehaves and look at every stage's value there
+//! 
+//! A scenario is run one control cycle at a time. After each cycle every
+//! breakpoint is checked against the cycle's snapshot; a breakpoint fires on
+//! the cycle its condition becomes true, so continuing from a stop runs to the
+//! next occurrence rather than stopping again on every cycle of the same one.
+//! 
+//! Breakpoint syntax:
+//! - `<variable> <op> <value>` - e.g. `boost_error > 2.0`, `duty >= 95`. The
+//!   variables are `boost`, `boost_error` (target minus boost), `rpm`,
+//!   `throttle` (%), `dome_feed` and every watch window variable (`target`,
+//!   `level1_target`, `duty`, `soft_ceiling`, ...; flags read as 0 or 1).
+//!   The operators are `>`, `>=`, `<`, `<=`, `==` and `!=`.
+//! - `state change` - the system state changed
+//! - `safety action` (or `SafetyAction != Continue`) - a protective response
+//!   came into effect
+
+use std::fmt;
+
+use rumbledome_core::{CoreError, Headroom, SystemConfig, SystemInputs, SystemState, WatchReading, WatchValue, WatchVariable};
+
+use crate::coverage::{active_safety_actions, state_name, SafetyAction};
+use crate::engine_sim::TurboSize;
+use crate::runner::{SimSession, CYCLE_MS};
+use crate::scenario::TestScenario;
+
+/// Variables a condition can name besides the watch window variables
+pub const SNAPSHOT_VARIABLES: [&str; 5] = ["boost", "boost_error", "rpm", "throttle", "dome_feed"];
+
+/// Everything the control stages produced in one cycle
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleSnapshot {
+    /// Cycle time (ms into the scenario)
+    pub t_ms: u32,
+    /// Inputs the core ran the cycle on
+    pub inputs: SystemInputs,
+    /// State after the cycle
+    pub state: SystemState,
+    /// Level 1 boost target (PSI)
+    pub level1_target_psi: f32,
+    /// Boost target after every cap (PSI)
+    pub target_psi: f32,
+    /// Target minus manifold pressure (PSI)
+    pub boost_error_psi: f32,
+    /// Solenoid duty commanded (%)
+    pub duty_percent: f32,
+    /// Protective responses in effect
+    pub safety_actions: Vec<SafetyAction>,
+    /// Margin to each limit
+    pub headroom: Option<Headroom>,
+    /// Every watch window variable
+    pub watches: Vec<WatchReading>,
+}
+
+impl CycleSnapshot {
+    /// Capture the session's core after a control cycle
+    pub fn capture(session: &SimSession, t_ms: u32) -> Option<Self> {
+        let core = &session.core;
+        let inputs = core.last_inputs.clone()?;
+        Some(Self {
+            t_ms,
+            state: core.state.clone(),
+            level1_target_psi: core.level1_target_boost_psi,
+            target_psi: core.last_target_boost_psi,
+            boost_error_psi: core.last_target_boost_psi - inputs.manifold_pressure,
+            duty_percent: session.duty(),
+            safety_actions: active_safety_actions(core, inputs.rpm, t_ms),
+            headroom: core.headroom.clone(),
+            watches: core.watch_readings(&WatchVariable::ALL),
+            inputs,
+        })
+    }
+    
+    /// Numeric value of a named variable (`None` for unknown or unavailable ones)
+    pub fn value(&self, variable: &str) -> Option<f32> {
+        match variable {
+            "boost" => Some(self.inputs.manifold_pressure),
+            "boost_error" => Some(self.boost_error_psi),
+            "rpm" => Some(self.inputs.rpm as f32),
+            "throttle" => self.inputs.throttle_position,
+            "dome_feed" => Some(self.inputs.dome_input_pressure),
+            name => {
+                let variable = WatchVariable::from_name(name)?;
+                match self.watches.iter().find(|reading| reading.variable == variable)?.value {
+                    WatchValue::Number(value) => Some(value),
+                    WatchValue::Flag(value) => Some(if value { 1.0 } else { 0.0 }),
+                    WatchValue::Source(_) | WatchValue::Unavailable => None,
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for CycleSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inputs = &self.inputs;
+        let throttle = inputs.throttle_position.map_or("--".to_string(), |throttle| format!("{:.0}", throttle));
+        writeln!(f, "Cycle at {} ms - {}", self.t_ms, self.state.display_text())?;
+        writeln!(f, "  Inputs:  throttle {}%  {} RPM  boost {:.2} PSI  dome feed {:.1} PSI  torque {:.0}/{:.0} Nm",
+            throttle, inputs.rpm, inputs.manifold_pressure, inputs.dome_input_pressure,
+            inputs.actual_torque, inputs.desired_torque)?;
+        writeln!(f, "  Target:  level 1 {:.2} PSI  final {:.2} PSI  error {:+.2} PSI",
+            self.level1_target_psi, self.target_psi, self.boost_error_psi)?;
+        writeln!(f, "  Output:  duty {:.1}%", self.duty_percent)?;
+        
+        let actions: Vec<&str> = self.safety_actions.iter().map(|action| action.name()).collect();
+        writeln!(f, "  Safety:  {}", if actions.is_empty() { "Continue".to_string() } else { actions.join(", ") })?;
+        
+        if let Some(headroom) = &self.headroom {
+            for margin in headroom.margins() {
+                writeln!(f, "  Margin:  {:<12} {:>8.2} {}", margin.limit.display_text(), margin.margin, margin.limit.unit())?;
+            }
+        }
+        for reading in &self.watches {
+            writeln!(f, "  Watch:   {:<20} {}", reading.variable.name(), reading.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Comparison in a breakpoint condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    /// Every comparison, two-character operators first so they parse before their prefixes
+    const ALL: [Comparison; 6] = [
+        Comparison::GreaterOrEqual,
+        Comparison::LessOrEqual,
+        Comparison::Equal,
+        Comparison::NotEqual,
+        Comparison::Greater,
+        Comparison::Less,
+    ];
+    
+    /// Operator as written in a condition
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+        }
+    }
+    
+    fn holds(&self, value: f32, threshold: f32) -> bool {
+        match self {
+            Comparison::Greater => value > threshold,
+            Comparison::GreaterOrEqual => value >= threshold,
+            Comparison::Less => value < threshold,
+            Comparison::LessOrEqual => value <= threshold,
+            Comparison::Equal => value == threshold,
+            Comparison::NotEqual => value != threshold,
+        }
+    }
+}
+
+/// Condition that pauses a debugged scenario
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    /// A variable compared against a value
+    Condition { variable: String, comparison: Comparison, threshold: f32 },
+    /// The system state changed
+    StateChange,
+    /// A safety action came into effect
+    SafetyAction,
+}
+
+impl Breakpoint {
+    /// Parse a breakpoint as typed (see the module documentation)
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let normalized = text.to_lowercase().replace(' ', "");
+        match normalized.trim_start_matches("on") {
+            "statechange" => return Ok(Breakpoint::StateChange),
+            "safetyaction" | "safetyaction!=continue" => return Ok(Breakpoint::SafetyAction),
+            _ => {}
+        }
+        
+        let (variable, comparison, threshold) = Comparison::ALL.iter()
+            .find_map(|comparison| text.split_once(comparison.symbol()).map(|(variable, threshold)| (variable, *comparison, threshold)))
+            .ok_or_else(|| format!("Not a breakpoint: \"{}\" (expected e.g. \"boost_error > 2.0\", \"state change\" or \"safety action\")", text))?;
+        
+        let variable = variable.trim();
+        if !SNAPSHOT_VARIABLES.contains(&variable) && WatchVariable::from_name(variable).is_none() {
+            return Err(format!("Unknown variable \"{}\"", variable));
+        }
+        let threshold = threshold.trim().parse()
+            .map_err(|_| format!("Not a number: \"{}\"", threshold.trim()))?;
+        
+        Ok(Breakpoint::Condition { variable: variable.to_string(), comparison, threshold })
+    }
+    
+    /// Whether the breakpoint fires on `current`, given the cycle before it
+    pub fn hit(&self, previous: Option<&CycleSnapshot>, current: &CycleSnapshot) -> bool {
+        match self {
+            Breakpoint::Condition { variable, comparison, threshold } => {
+                let holds = |snapshot: &CycleSnapshot| snapshot.value(variable).is_some_and(|value| comparison.holds(value, *threshold));
+                holds(current) && !previous.is_some_and(holds)
+            },
+            Breakpoint::StateChange => previous.is_some_and(|previous| state_name(&previous.state) != state_name(&current.state)),
+            Breakpoint::SafetyAction => current.safety_actions.iter()
+                .any(|action| !previous.is_some_and(|previous| previous.safety_actions.contains(action))),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Condition { variable, comparison, threshold } => write!(f, "{} {} {}", variable, comparison.symbol(), threshold),
+            Breakpoint::StateChange => write!(f, "state change"),
+            Breakpoint::SafetyAction => write!(f, "safety action"),
+        }
+    }
+}
+
+/// Why a debugged run stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugStop {
+    /// A breakpoint fired on the last cycle (index of the first that did)
+    Breakpoint(usize),
+    /// The scenario ran to its end
+    Finished,
+}
+
+/// Scenario run that can be stepped one control cycle at a time
+pub struct Debugger {
+    session: SimSession,
+    scenario: TestScenario,
+    breakpoints: Vec<Breakpoint>,
+    t_ms: u32,
+    previous: Option<CycleSnapshot>,
+    current: Option<CycleSnapshot>,
+}
+
+impl Debugger {
+    /// Start a scenario, stopped before its first cycle
+    pub fn new(config: &SystemConfig, scenario: TestScenario, turbo: Option<TurboSize>) -> Result<Self, CoreError> {
+        let mut session = SimSession::new(config)?;
+        if let Some(turbo) = turbo {
+            session.use_engine_sim(config, turbo);
+        }
+        Ok(Self { session, scenario, breakpoints: Vec::new(), t_ms: 0, previous: None, current: None })
+    }
+    
+    /// Add a breakpoint, returning its index
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> usize {
+        self.breakpoints.push(breakpoint);
+        self.breakpoints.len() - 1
+    }
+    
+    /// Remove a breakpoint by index (later ones move down)
+    pub fn remove_breakpoint(&mut self, index: usize) -> Option<Breakpoint> {
+        (index < self.breakpoints.len()).then(|| self.breakpoints.remove(index))
+    }
+    
+    /// Breakpoints in the order added
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+    
+    /// Snapshot of the last cycle run, if any
+    pub fn snapshot(&self) -> Option<&CycleSnapshot> {
+        self.current.as_ref()
+    }
+    
+    /// Session being debugged
+    pub fn session(&self) -> &SimSession {
+        &self.session
+    }
+    
+    /// Scenario being debugged
+    pub fn scenario(&self) -> &TestScenario {
+        &self.scenario
+    }
+    
+    /// Whether the scenario has run to its end
+    pub fn finished(&self) -> bool {
+        self.t_ms >= self.scenario.duration_ms
+    }
+    
+    /// Run one control cycle; `None` once the scenario has ended
+    pub fn step(&mut self) -> Result<Option<&CycleSnapshot>, CoreError> {
+        if self.finished() {
+            return Ok(None);
+        }
+        
+        let (throttle, rpm) = self.scenario.driver_input(self.t_ms);
+        self.session.step(throttle, rpm, self.t_ms)?;
+        let snapshot = CycleSnapshot::capture(&self.session, self.t_ms);
+        self.previous = std::mem::replace(&mut self.current, snapshot);
+        self.t_ms += CYCLE_MS;
+        Ok(self.current.as_ref())
+    }
+    
+    /// Breakpoints that fired on the last cycle
+    pub fn hits(&self) -> Vec<usize> {
+        let Some(current) = &self.current else {
+            return Vec::new();
+        };
+        self.breakpoints.iter()
+            .enumerate()
+            .filter(|(_, breakpoint)| breakpoint.hit(self.previous.as_ref(), current))
+            .map(|(index, _)| index)
+            .collect()
+    }
+    
+    /// Run until a breakpoint fires or the scenario ends
+    /// 
+    /// Always runs at least one cycle, so continuing from a stop moves on.
+    pub fn resume(&mut self) -> Result<DebugStop, CoreError> {
+        while self.step()?.is_some() {
+            if let Some(&index) = self.hits().first() {
+                return Ok(DebugStop::Breakpoint(index));
+            }
+        }
+        Ok(DebugStop::Finished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::wot_pull;
+    
+    #[test]
+    fn test_parse_breakpoints() {
+        let condition = Breakpoint::parse("boost_error > 2.0").unwrap();
+        assert_eq!(condition, Breakpoint::Condition { variable: "boost_error".to_string(), comparison: Comparison::Greater, threshold: 2.0 });
+        assert_eq!(Breakpoint::parse(&condition.to_string()).unwrap(), condition);
+        assert!(matches!(Breakpoint::parse("duty>=95"), Ok(Breakpoint::Condition { comparison: Comparison::GreaterOrEqual, .. })));
+        
+        assert_eq!(Breakpoint::parse("on state change").unwrap(), Breakpoint::StateChange);
+        assert_eq!(Breakpoint::parse("SafetyAction != Continue").unwrap(), Breakpoint::SafetyAction);
+        
+        assert!(Breakpoint::parse("pid_integral > 1").is_err());
+        assert!(Breakpoint::parse("boost > lots").is_err());
+        assert!(Breakpoint::parse("boost").is_err());
+    }
+    
+    #[test]
+    fn test_stops_where_the_condition_becomes_true() {
+        // Full aggression - at the default the conservative first duty is below the valve's shortest pulse
+        let config = SystemConfig { aggression: 1.0, ..SystemConfig::default() };
+        let mut debugger = Debugger::new(&config, wot_pull(), None).unwrap();
+        debugger.add_breakpoint(Breakpoint::parse("boost > 5").unwrap());
+        
+        assert_eq!(debugger.resume().unwrap(), DebugStop::Breakpoint(0));
+        let first = debugger.snapshot().unwrap().clone();
+        assert!(first.inputs.manifold_pressure > 5.0);
+        assert!(first.to_string().contains("level1_target"));
+        
+        debugger.step().unwrap();
+        assert_eq!(debugger.snapshot().unwrap().t_ms, first.t_ms + CYCLE_MS);
+        assert!(debugger.hits().is_empty());
+        
+        assert_eq!(debugger.remove_breakpoint(0), Some(Breakpoint::parse("boost > 5").unwrap()));
+        assert_eq!(debugger.resume().unwrap(), DebugStop::Finished);
+        assert!(debugger.step().unwrap().is_none());
+    }
+}
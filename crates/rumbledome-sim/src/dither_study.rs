@@ -0,0 +1,178 @@
+//! Dither Effect Study
+//! 
+//! 🔗 T4-SIMULATOR-011: Dither Duty Sweep
+//! Derived From: T4-CORE-078 (Solenoid Duty Dither) + T4-SIMULATOR-003 (First-Order Boost Plant)
+//! AI Traceability: Quantify what dither buys against valve stiction before enabling it on a car
+
+use std::fmt;
+
+use rumbledome_core::{PwmDither, PwmDitherConfig, SystemConfig};
+
+use crate::plant::BoostPlant;
+
+/// Valve stiction the study assumes (duty %)
+pub const STUDY_STICTION_PERCENT: f32 = 1.5;
+
+/// Duty range swept (%) - the low-duty region where stiction matters most
+/// 0% is left out: dither never touches a failsafe command
+const SWEEP_MIN_DUTY: f32 = 2.0;
+const SWEEP_MAX_DUTY: f32 = 40.0;
+
+/// Duty step between sweep points (%)
+const SWEEP_STEP_DUTY: f32 = 2.0;
+
+/// Time each duty is held (ms)
+const HOLD_MS: u32 = 1500;
+
+/// Boost is averaged over the end of each hold, after the plant has settled (ms)
+const SETTLED_WINDOW_MS: u32 = 500;
+
+/// Operating point - full throttle at full spool, so duty alone sets boost
+const SWEEP_THROTTLE: f32 = 1.0;
+const SWEEP_RPM: f32 = 5000.0;
+
+/// Settled boost at one duty
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepPoint {
+    /// Commanded duty (%)
+    pub duty_percent: f32,
+    /// Boost reached sweeping up (PSI)
+    pub rising_psi: f32,
+    /// Boost reached sweeping down (PSI)
+    pub falling_psi: f32,
+    /// Boost an ideal valve would give (PSI)
+    pub ideal_psi: f32,
+}
+
+/// Open-loop duty sweep result
+#[derive(Debug, Clone, PartialEq)]
+pub struct DutySweepResult {
+    pub points: Vec<SweepPoint>,
+    /// Largest rising/falling difference at the same duty (PSI)
+    pub max_hysteresis_psi: f32,
+    /// Largest departure from the ideal valve (PSI)
+    pub max_linearity_error_psi: f32,
+}
+
+/// Sweep duty up and back down through a sticky valve and record settled boost
+/// 
+/// The sweep is open loop so the result shows the valve alone, not how well
+/// the controller compensates for it.
+pub fn duty_sweep(config: &SystemConfig, stiction_percent: f32, dither: &PwmDitherConfig) -> DutySweepResult {
+    let mut plant = BoostPlant::new(config);
+    plant.valve_stiction_percent = stiction_percent;
+    let mut dither = PwmDither::new(dither.clone(), config.control_rates.inner_hz);
+    let cycle_ms = 1000.0 / config.control_rates.inner_hz as f32;
+    let cycles = (HOLD_MS as f32 / cycle_ms) as u32;
+    let settled_cycles = (SETTLED_WINDOW_MS as f32 / cycle_ms) as u32;
+    
+    let mut hold = |duty: f32| -> f32 {
+        let mut settled_sum = 0.0;
+        for cycle in 0..cycles {
+            let outputs = plant.step(SWEEP_THROTTLE, SWEEP_RPM, dither.apply(duty), cycle_ms);
+            if cycle >= cycles - settled_cycles {
+                settled_sum += outputs.manifold_psi;
+            }
+        }
+        settled_sum / settled_cycles as f32
+    };
+    
+    let steps = ((SWEEP_MAX_DUTY - SWEEP_MIN_DUTY) / SWEEP_STEP_DUTY) as usize;
+    let duties: Vec<f32> = (0..=steps).map(|step| SWEEP_MIN_DUTY + step as f32 * SWEEP_STEP_DUTY).collect();
+    
+    // Settle from a standing start so the first point is not a spool-up transient
+    hold(SWEEP_MIN_DUTY);
+    let rising: Vec<f32> = duties.iter().map(|&duty| hold(duty)).collect();
+    let mut falling: Vec<f32> = duties.iter().rev().map(|&duty| hold(duty)).collect();
+    falling.reverse();
+    
+    let ideal = BoostPlant::new(config);
+    let points: Vec<SweepPoint> = duties
+        .iter()
+        .zip(rising.iter().zip(falling.iter()))
+        .map(|(&duty_percent, (&rising_psi, &falling_psi))| SweepPoint {
+            duty_percent,
+            rising_psi,
+            falling_psi,
+            ideal_psi: ideal.equilibrium_psi(SWEEP_THROTTLE, SWEEP_RPM, duty_percent),
+        })
+        .collect();
+    
+    let max_hysteresis_psi = points
+        .iter()
+        .map(|point| (point.rising_psi - point.falling_psi).abs())
+        .fold(0.0, f32::max);
+    let max_linearity_error_psi = points
+        .iter()
+        .flat_map(|point| [point.rising_psi - point.ideal_psi, point.falling_psi - point.ideal_psi])
+        .map(f32::abs)
+        .fold(0.0, f32::max);
+    
+    DutySweepResult { points, max_hysteresis_psi, max_linearity_error_psi }
+}
+
+/// Same sticky valve swept with and without dither
+#[derive(Debug, Clone, PartialEq)]
+pub struct DitherStudy {
+    /// Valve stiction assumed (duty %)
+    pub stiction_percent: f32,
+    /// Dither settings compared
+    pub dither: PwmDitherConfig,
+    pub without_dither: DutySweepResult,
+    pub with_dither: DutySweepResult,
+}
+
+/// Compare the configuration's dither settings against no dither
+pub fn dither_study(config: &SystemConfig) -> DitherStudy {
+    let dither = PwmDitherConfig { enabled: true, ..config.pwm_dither.clone() };
+    let disabled = PwmDitherConfig { enabled: false, ..dither.clone() };
+    
+    DitherStudy {
+        stiction_percent: STUDY_STICTION_PERCENT,
+        without_dither: duty_sweep(config, STUDY_STICTION_PERCENT, &disabled),
+        with_dither: duty_sweep(config, STUDY_STICTION_PERCENT, &dither),
+        dither,
+    }
+}
+
+impl fmt::Display for DitherStudy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Dither study: ±{:.1}% at {} Hz, valve stiction {:.1}% duty",
+            self.dither.amplitude_percent, self.dither.frequency_hz, self.stiction_percent)?;
+        writeln!(f, "\n  duty   ideal   off ↑   off ↓    on ↑    on ↓  (PSI)")?;
+        for (off, on) in self.without_dither.points.iter().zip(&self.with_dither.points) {
+            writeln!(f, "  {:>3.0}%  {:>6.2}  {:>6.2}  {:>6.2}  {:>6.2}  {:>6.2}",
+                off.duty_percent, off.ideal_psi, off.rising_psi, off.falling_psi, on.rising_psi, on.falling_psi)?;
+        }
+        
+        writeln!(f)?;
+        for (label, result) in [("Without dither", &self.without_dither), ("With dither", &self.with_dither)] {
+            writeln!(f, "{:<15} hysteresis {:.2} PSI, worst linearity error {:.2} PSI",
+                label, result.max_hysteresis_psi, result.max_linearity_error_psi)?;
+        }
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_ideal_valve_has_no_hysteresis() {
+        let result = duty_sweep(&SystemConfig::default(), 0.0, &PwmDitherConfig::default());
+        assert!(result.max_hysteresis_psi < 0.02);
+        assert!(result.max_linearity_error_psi < 0.02);
+    }
+    
+    #[test]
+    fn test_dither_reduces_stiction_hysteresis() {
+        let study = dither_study(&SystemConfig::default());
+        
+        // Backlash of 1.5% duty each way over a 17 PSI wastegate span
+        assert!(study.without_dither.max_hysteresis_psi > 0.4);
+        assert!(study.with_dither.max_hysteresis_psi < study.without_dither.max_hysteresis_psi / 2.0);
+        assert!(study.with_dither.max_linearity_error_psi < study.without_dither.max_linearity_error_psi);
+    }
+}
@@ -0,0 +1,320 @@
+//! Synthetic Drive Cycles
+//! 
+//! 🔗 T4-SIMULATOR-012: Drive-Cycle Generator
+//! Derived From: T4-SIMULATOR-004 (Scenario Definitions) + T2-SIM-001 (Physics Modeling)
+//! AI Traceability: Long, realistic driver traces for soak and learning-convergence runs without hand-authored keyframes
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::rng::SimRng;
+use crate::runner::CYCLE_MS;
+use crate::scenario::{DriverKeyframe, SuccessCriterion, TestScenario};
+
+/// Engine idle speed the generated traces settle to
+const IDLE_RPM: f32 = 800.0;
+
+/// RPM kept below redline at the top of a pull (the driver shifts before the limiter)
+const SHIFT_MARGIN_RPM: f32 = 400.0;
+
+/// Kind of driving a cycle imitates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriveCycleKind {
+    /// Stop-and-go: idle at lights, gentle pulls through the gears, short cruises
+    City,
+    /// Long steady cruise broken up by passing manoeuvres
+    Highway,
+    /// Corner after corner: brake, roll through the apex, full-throttle exit
+    MountainRoad,
+}
+
+impl DriveCycleKind {
+    /// Name used on the command line and in scenario names
+    pub fn name(&self) -> &'static str {
+        match self {
+            DriveCycleKind::City => "city",
+            DriveCycleKind::Highway => "highway",
+            DriveCycleKind::MountainRoad => "mountain",
+        }
+    }
+}
+
+impl fmt::Display for DriveCycleKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DriveCycleKind {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "city" => Ok(DriveCycleKind::City),
+            "highway" => Ok(DriveCycleKind::Highway),
+            "mountain" => Ok(DriveCycleKind::MountainRoad),
+            other => Err(format!("unknown drive cycle '{}' (city, highway or mountain)", other)),
+        }
+    }
+}
+
+/// Drive-cycle generator settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriveCycleConfig {
+    pub kind: DriveCycleKind,
+    /// Trace length (ms)
+    pub duration_ms: u32,
+    /// How far segment lengths, throttle and RPM stray from nominal (0.0-1.0)
+    /// 
+    /// 0.0 repeats the same nominal segment pattern for the whole trace.
+    pub randomness: f32,
+    /// Random seed - identical settings and seed generate identical traces
+    pub seed: u32,
+}
+
+impl DriveCycleConfig {
+    /// Moderately random cycle of the given kind and length
+    pub fn new(kind: DriveCycleKind, duration_ms: u32) -> Self {
+        Self { kind, duration_ms, randomness: 0.5, seed: 1 }
+    }
+}
+
+/// Criteria a long drive is held to
+/// 
+/// The oscillation reversal budget of `standard_criteria` is sized for a few
+/// seconds of scripted input and is left out - every tip-in on a long drive
+/// legitimately swings the boost error.
+pub fn soak_criteria() -> Vec<SuccessCriterion> {
+    vec![
+        SuccessCriterion::NoOverboost,
+        SuccessCriterion::NoFault,
+        SuccessCriterion::MaxOvershootPsi(2.0),
+    ]
+}
+
+/// Keyframe trace under construction
+struct TraceBuilder {
+    randomness: f32,
+    rng: SimRng,
+    redline_rpm: f32,
+    t_ms: u32,
+    throttle: f32,
+    rpm: f32,
+    keyframes: Vec<DriverKeyframe>,
+}
+
+impl TraceBuilder {
+    fn new(config: &DriveCycleConfig, redline_rpm: f32) -> Self {
+        Self {
+            randomness: config.randomness.clamp(0.0, 1.0),
+            rng: SimRng::new(config.seed),
+            redline_rpm,
+            t_ms: 0,
+            throttle: 0.0,
+            rpm: IDLE_RPM,
+            keyframes: vec![DriverKeyframe { at_ms: 0, throttle: 0.0, rpm: IDLE_RPM }],
+        }
+    }
+    
+    /// Value in `low..high`, centred on the midpoint and spread by the randomness
+    fn vary(&mut self, low: f32, high: f32) -> f32 {
+        let mid = (low + high) / 2.0;
+        mid + (self.rng.next_unit() - 0.5) * (high - low) * self.randomness
+    }
+    
+    /// Segment length in `low_ms..high_ms`
+    fn vary_ms(&mut self, low_ms: u32, high_ms: u32) -> u32 {
+        (self.vary(low_ms as f32, high_ms as f32) as u32).max(CYCLE_MS)
+    }
+    
+    /// Whether an event with probability `p` happens
+    /// 
+    /// With no randomness events happen every time they are more likely than
+    /// not, so the nominal pattern is still representative.
+    fn chance(&mut self, p: f32) -> bool {
+        if self.randomness == 0.0 {
+            return p >= 0.5;
+        }
+        self.rng.next_unit() < p
+    }
+    
+    /// Move linearly to the given input over `duration_ms`
+    fn ramp(&mut self, duration_ms: u32, throttle: f32, rpm: f32) {
+        self.t_ms += duration_ms.max(CYCLE_MS);
+        self.throttle = throttle.clamp(0.0, 1.0);
+        self.rpm = rpm.clamp(IDLE_RPM, self.redline_rpm - SHIFT_MARGIN_RPM);
+        self.keyframes.push(DriverKeyframe { at_ms: self.t_ms, throttle: self.throttle, rpm: self.rpm });
+    }
+    
+    /// Keep the current input for `duration_ms`
+    fn hold(&mut self, duration_ms: u32) {
+        let (throttle, rpm) = (self.throttle, self.rpm);
+        self.ramp(duration_ms, throttle, rpm);
+    }
+    
+    /// Stop at a light, pull away through the gears, cruise, slow down again
+    fn city_block(&mut self) {
+        let stop_ms = self.vary_ms(4_000, 30_000);
+        self.ramp(1_000, 0.0, IDLE_RPM);
+        self.hold(stop_ms);
+        
+        let gears = if self.chance(0.7) { 3 } else { 2 };
+        for _ in 0..gears {
+            let throttle = self.vary(0.25, 0.65);
+            let shift_rpm = self.vary(2_800.0, 4_000.0);
+            let launch_rpm = self.rpm.max(1_400.0);
+            self.ramp(300, throttle, launch_rpm);
+            let pull_ms = self.vary_ms(1_500, 3_500);
+            self.ramp(pull_ms, throttle, shift_rpm);
+            // Lift for the shift, RPM drops into the next gear
+            let next_gear_rpm = shift_rpm * self.vary(0.6, 0.7);
+            self.ramp(300, 0.05, next_gear_rpm);
+        }
+        
+        let cruise_throttle = self.vary(0.12, 0.25);
+        let cruise_rpm = self.vary(1_800.0, 2_500.0);
+        self.ramp(800, cruise_throttle, cruise_rpm);
+        let cruise_ms = self.vary_ms(5_000, 25_000);
+        self.hold(cruise_ms);
+        
+        let coast_ms = self.vary_ms(3_000, 6_000);
+        self.ramp(coast_ms, 0.0, IDLE_RPM + 400.0);
+    }
+    
+    /// Steady cruise, sometimes ending in a downshift and full-throttle pass
+    fn highway_stretch(&mut self) {
+        let cruise_throttle = self.vary(0.18, 0.35);
+        let cruise_rpm = self.vary(2_200.0, 3_000.0);
+        self.ramp(2_000, cruise_throttle, cruise_rpm);
+        let cruise_ms = self.vary_ms(20_000, 90_000);
+        self.hold(cruise_ms);
+        
+        if self.chance(0.6) {
+            let downshift_rpm = self.vary(3_500.0, 4_500.0);
+            let pass_throttle = self.vary(0.75, 1.0);
+            self.ramp(400, pass_throttle, downshift_rpm);
+            let pass_ms = self.vary_ms(3_000, 8_000);
+            let pass_rpm = downshift_rpm + self.vary(1_000.0, 2_000.0);
+            self.ramp(pass_ms, pass_throttle, pass_rpm);
+            // Back off and upshift to cruise
+            self.ramp(600, cruise_throttle, cruise_rpm);
+        }
+    }
+    
+    /// Brake into a corner, roll through the apex, drive out hard
+    fn mountain_corner(&mut self) {
+        let entry_rpm = self.vary(3_000.0, 4_000.0);
+        let brake_ms = self.vary_ms(800, 2_500);
+        self.ramp(200, 0.0, self.rpm);
+        self.ramp(brake_ms, 0.0, entry_rpm);
+        
+        let apex_throttle = self.vary(0.15, 0.4);
+        let apex_ms = self.vary_ms(500, 2_500);
+        self.ramp(300, apex_throttle, entry_rpm);
+        self.hold(apex_ms);
+        
+        // Short chutes between tight corners are only part-throttle
+        let exit_throttle = if self.chance(0.75) { self.vary(0.9, 1.0) } else { self.vary(0.5, 0.75) };
+        let exit_ms = self.vary_ms(1_500, 5_000);
+        let exit_rpm = entry_rpm + self.vary(1_500.0, 3_000.0);
+        self.ramp(250, exit_throttle, entry_rpm);
+        self.ramp(exit_ms, exit_throttle, exit_rpm);
+        
+        if self.chance(0.3) {
+            // Upshift on a longer straight and keep pulling
+            let upshift_rpm = self.rpm * 0.72;
+            self.ramp(250, 0.05, upshift_rpm);
+            let straight_ms = self.vary_ms(1_500, 3_500);
+            let top_rpm = self.redline_rpm;
+            self.ramp(250, exit_throttle, upshift_rpm);
+            self.ramp(straight_ms, exit_throttle, top_rpm);
+        }
+    }
+}
+
+/// Generate a drive-cycle scenario
+/// 
+/// Segments are appended until the trace covers `duration_ms`; RPM stays
+/// between idle and just under `redline_rpm`.
+pub fn generate_drive_cycle(config: &DriveCycleConfig, redline_rpm: f32) -> TestScenario {
+    let mut trace = TraceBuilder::new(config, redline_rpm);
+    
+    while trace.t_ms < config.duration_ms {
+        match config.kind {
+            DriveCycleKind::City => trace.city_block(),
+            DriveCycleKind::Highway => trace.highway_stretch(),
+            DriveCycleKind::MountainRoad => trace.mountain_corner(),
+        }
+    }
+    
+    TestScenario {
+        name: format!("{}_drive_{}", config.kind, config.seed),
+        description: format!("Generated {} drive, {} min, randomness {:.2}, seed {}",
+            config.kind, config.duration_ms / 60_000, config.randomness, config.seed),
+        duration_ms: config.duration_ms,
+        keyframes: trace.keyframes,
+        criteria: soak_criteria(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemConfig;
+    use crate::runner::ScenarioRunner;
+    
+    const REDLINE_RPM: f32 = 7000.0;
+    
+    /// Fraction of the trace spent at or above the given throttle
+    fn time_above(scenario: &TestScenario, throttle: f32) -> f32 {
+        let samples: Vec<f32> = (0..scenario.duration_ms).step_by(100).map(|t| scenario.driver_input(t).0).collect();
+        samples.iter().filter(|&&sample| sample >= throttle).count() as f32 / samples.len() as f32
+    }
+    
+    #[test]
+    fn test_same_seed_generates_same_trace() {
+        let config = DriveCycleConfig::new(DriveCycleKind::City, 600_000);
+        let reseeded = DriveCycleConfig { seed: 2, ..config.clone() };
+        
+        assert_eq!(generate_drive_cycle(&config, REDLINE_RPM), generate_drive_cycle(&config, REDLINE_RPM));
+        assert_ne!(generate_drive_cycle(&config, REDLINE_RPM).keyframes, generate_drive_cycle(&reseeded, REDLINE_RPM).keyframes);
+    }
+    
+    #[test]
+    fn test_traces_are_well_formed() {
+        for kind in [DriveCycleKind::City, DriveCycleKind::Highway, DriveCycleKind::MountainRoad] {
+            for randomness in [0.0, 0.5, 1.0] {
+                let config = DriveCycleConfig { randomness, ..DriveCycleConfig::new(kind, 900_000) };
+                let scenario = generate_drive_cycle(&config, REDLINE_RPM);
+                
+                assert!(scenario.keyframes.last().unwrap().at_ms >= scenario.duration_ms, "{} ends early", kind);
+                assert!(scenario.keyframes.windows(2).all(|pair| pair[0].at_ms < pair[1].at_ms), "{} out of order", kind);
+                assert!(scenario.keyframes.iter().all(|frame| (0.0..=1.0).contains(&frame.throttle)
+                    && frame.rpm >= IDLE_RPM && frame.rpm < REDLINE_RPM), "{} input out of range", kind);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_cycle_character() {
+        let city = generate_drive_cycle(&DriveCycleConfig::new(DriveCycleKind::City, 900_000), REDLINE_RPM);
+        let highway = generate_drive_cycle(&DriveCycleConfig::new(DriveCycleKind::Highway, 900_000), REDLINE_RPM);
+        let mountain = generate_drive_cycle(&DriveCycleConfig::new(DriveCycleKind::MountainRoad, 900_000), REDLINE_RPM);
+        
+        // Spirited driving spends far more time near full throttle
+        assert!(time_above(&mountain, 0.85) > 4.0 * time_above(&city, 0.85));
+        assert!(time_above(&mountain, 0.85) > 4.0 * time_above(&highway, 0.85));
+        // City driving spends time stopped, the highway none
+        assert!(time_above(&city, 0.01) < 0.8);
+        assert!(time_above(&highway, 0.1) > 0.9);
+    }
+    
+    #[test]
+    fn test_mountain_drive_soak() {
+        let config = DriveCycleConfig::new(DriveCycleKind::MountainRoad, 120_000);
+        let scenario = generate_drive_cycle(&config, REDLINE_RPM);
+        let result = ScenarioRunner::new(SystemConfig::default()).run(&scenario).unwrap();
+        
+        assert!(result.passed, "{:?}", result.failures);
+    }
+}
@@ -0,0 +1,94 @@
+//! Configuration Dry Run
+//! 
+//! 🔗 T4-SIMULATOR-006: Simulate-Then-Apply Config Gate
+//! Derived From: T4-SIMULATOR-005 + T1-SAFETY-001 (Overboost as Fault Condition)
+//! AI Traceability: Catch overboost/instability on the desktop twin before a config reaches the car
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{CoreError, SystemConfig};
+
+use crate::runner::{ScenarioResult, ScenarioRunner};
+use crate::scenario::builtin_scenarios;
+
+/// Dry-run results for a candidate configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// Per-scenario results
+    pub results: Vec<ScenarioResult>,
+}
+
+impl DryRunReport {
+    /// Every scenario passed
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed)
+    }
+    
+    /// Scenarios that failed
+    pub fn failed(&self) -> impl Iterator<Item = &ScenarioResult> {
+        self.results.iter().filter(|r| !r.passed)
+    }
+}
+
+/// Validate a configuration and run the built-in scenario set against it
+/// 
+/// With a learned-data export, every scenario starts from that map, as the
+/// car would; without one the core simulates with the conservative estimates.
+pub fn dry_run(config: &SystemConfig, learned_data: Option<&[u8]>) -> Result<DryRunReport, CoreError> {
+    config.validate()?;
+    
+    let mut runner = ScenarioRunner::new(config.clone());
+    if let Some(image) = learned_data {
+        runner = runner.with_learned_data(image.to_vec());
+    }
+    let results = builtin_scenarios(config)
+        .iter()
+        .map(|scenario| runner.run(scenario))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(DryRunReport { results })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::SystemState;
+    use crate::runner::{SimSession, CYCLE_MS};
+    use crate::scenario::wot_pull;
+    
+    #[test]
+    fn test_invalid_config_rejected_before_simulation() {
+        let config = SystemConfig { aggression: 2.0, ..SystemConfig::default() };
+        assert!(dry_run(&config, None).is_err());
+    }
+    
+    #[test]
+    fn test_report_runs_every_builtin_scenario() {
+        let config = SystemConfig::default();
+        let report = dry_run(&config, None).unwrap();
+        assert_eq!(report.results.len(), builtin_scenarios(&config).len());
+    }
+    
+    #[test]
+    fn test_learned_data_seeds_the_simulation() {
+        let config = SystemConfig { aggression: 1.0, ..SystemConfig::default() };
+        let mut session = SimSession::new(&config).unwrap();
+        let scenario = wot_pull();
+        let mut t_ms = 0;
+        while t_ms < scenario.duration_ms {
+            let (throttle, rpm) = scenario.driver_input(t_ms);
+            session.step(throttle, rpm, t_ms).unwrap();
+            t_ms += CYCLE_MS;
+        }
+        let image = session.core.export_learned_data();
+        
+        let mut seeded = SimSession::new(&config).unwrap();
+        seeded.import_learned_data(&image).unwrap();
+        assert_eq!(seeded.core.state, SystemState::Armed);
+        assert_eq!(seeded.core.learned_data.status().cells_learned, session.core.learned_data.status().cells_learned);
+        
+        assert!(dry_run(&config, Some(&image)).is_ok());
+        // A truncated export is refused rather than silently simulating without it
+        assert!(dry_run(&config, Some(&image[..image.len() / 2])).is_err());
+    }
+}
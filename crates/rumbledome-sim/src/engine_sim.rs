@@ -0,0 +1,432 @@
+//! Engine and Turbo Physics Model
+//! 
+//! 🔗 T4-SIMULATOR-018: Physics-Based Engine Simulation
+//! Derived From: T2-SIM-002 (Turbocharger Physics Modeling) + T2-SIM-010 (Parametric Turbocharger Modeling) + T2-SIM-008 (Pneumatic System Modeling)
+//! AI Traceability: Validate control against boost that lags, overshoots and saturates the way a real turbo does
+//! 
+//! Where BoostPlant eases boost toward a chosen equilibrium, this model
+//! integrates the physics that produce it. Exhaust flow drives the turbine,
+//! the shaft spins up against its inertia and the compressor's load, the
+//! compressor fills the intake against what the engine draws, and the
+//! wastegate - positioned by spring, both domes and boost - decides how much
+//! exhaust reaches the turbine. Spool threshold, lag and overshoot come out
+//! of the model instead of being parameters.
+//! 
+//! The intake is one volume from compressor to valves. The ECU is torque
+//! based: the pedal asks for torque and the throttle opens as far as needed to
+//! deliver it, so a boost shortfall shows up as a torque gap.
+
+use std::fmt;
+use std::str::FromStr;
+
+use rumbledome_core::SystemConfig;
+use rumbledome_hal::PSI_PER_KPA;
+
+use crate::plant::PlantOutputs;
+
+/// Standard atmosphere (kPa)
+const ATMOSPHERE_KPA: f32 = 101.325;
+
+/// Ambient air temperature at the compressor inlet (K)
+const AMBIENT_K: f32 = 298.0;
+
+/// Charge temperature after the intercooler (K)
+const INTAKE_K: f32 = 320.0;
+
+/// Exhaust temperature at the turbine inlet (K)
+const EXHAUST_K: f32 = 1100.0;
+
+/// Gas constant of air (J/kg·K)
+const R_AIR: f32 = 287.0;
+
+/// Specific heat of air (J/kg·K)
+const CP_AIR: f32 = 1005.0;
+
+/// Specific heat of exhaust gas (J/kg·K)
+const CP_EXHAUST: f32 = 1150.0;
+
+/// (γ-1)/γ for air
+const AIR_EXPONENT: f32 = 0.2857;
+
+/// (γ-1)/γ for exhaust gas
+const EXHAUST_EXPONENT: f32 = 0.25;
+
+/// Turbine isentropic efficiency
+const TURBINE_EFFICIENCY: f32 = 0.7;
+
+/// Flow a stopped compressor passes per √(pressure drop) - an NA engine breathes through it (kg/s)
+const PASSIVE_FLOW_KG_S: f32 = 1.5;
+
+/// Throttle opening the ECU holds at closed pedal (fraction)
+const IDLE_THROTTLE: f32 = 0.03;
+
+/// Integration step (ms) - intake filling is too quick to integrate at the control period
+const SUBSTEP_MS: f32 = 1.0;
+
+/// Turbocharger size class, each with its own spool and capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurboSize {
+    /// Quick to spool, runs out of flow at high RPM
+    Small,
+    /// Balanced street turbo
+    Medium,
+    /// Laggy big single with a lot of headroom
+    Large,
+}
+
+impl TurboSize {
+    /// Name used on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            TurboSize::Small => "small",
+            TurboSize::Medium => "medium",
+            TurboSize::Large => "large",
+        }
+    }
+}
+
+impl fmt::Display for TurboSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TurboSize {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "small" => Ok(TurboSize::Small),
+            "medium" => Ok(TurboSize::Medium),
+            "large" => Ok(TurboSize::Large),
+            other => Err(format!("unknown turbo size '{}' (small, medium or large)", other)),
+        }
+    }
+}
+
+/// Engine, turbocharger, wastegate and ECU integrated from first principles
+#[derive(Debug, Clone)]
+pub struct EngineSim {
+    /// Engine displacement (L)
+    pub displacement_l: f32,
+    /// Volumetric efficiency at full throttle
+    pub volumetric_efficiency: f32,
+    /// Volume from compressor outlet to intake valves (L)
+    pub intake_volume_l: f32,
+    /// Full-throttle torque at atmospheric manifold pressure (Nm)
+    pub na_torque_nm: f32,
+    /// Torque the ECU asks for at full pedal (Nm)
+    pub peak_torque_nm: f32,
+    /// Combustion torque response time constant (ms)
+    pub torque_time_constant_ms: f32,
+    /// ECU rev limiter - fuel/spark cut at or above this RPM
+    pub redline_rpm: f32,
+    /// Rotating inertia of the turbo shaft (kg·m²)
+    pub shaft_inertia: f32,
+    /// Shaft bearing friction (W per (rad/s)²)
+    pub shaft_friction: f32,
+    /// Compressor speed line reference - the shaft speed the map is scaled to (RPM)
+    pub rated_shaft_rpm: f32,
+    /// Pressure ratio at which the rated speed line reaches zero flow
+    pub rated_pressure_ratio: f32,
+    /// Flow through the compressor at rated speed and no pressure rise (kg/s)
+    pub rated_flow_kg_s: f32,
+    /// Compressor isentropic efficiency
+    pub compressor_efficiency: f32,
+    /// Turbine flow at an expansion ratio of 2 - bigger housings pass more for the same back-pressure (kg/s)
+    pub turbine_flow_kg_s: f32,
+    /// Wastegate spring pressure (PSI)
+    pub spring_pressure: f32,
+    /// Force balance past cracking that opens the wastegate fully (PSI)
+    pub wastegate_travel_psi: f32,
+    /// Share of the exhaust a fully open wastegate bypasses around the turbine
+    pub wastegate_bypass: f32,
+    /// Wastegate valve movement time constant (ms)
+    pub wastegate_time_constant_ms: f32,
+    /// Dome filling and venting time constant through solenoid and lines (ms)
+    pub dome_time_constant_ms: f32,
+    /// Intake pressure (kPa absolute)
+    manifold_kpa: f32,
+    /// Turbo shaft speed (rad/s)
+    shaft_rad_s: f32,
+    /// Wastegate opening (0.0 shut - 1.0 fully open)
+    wastegate_open: f32,
+    /// Upper dome pressure (PSI)
+    upper_dome_psi: f32,
+    /// Lower dome pressure (PSI)
+    lower_dome_psi: f32,
+    /// Torque delivered (Nm)
+    torque_nm: f32,
+}
+
+impl EngineSim {
+    /// V8 with a turbo of the given size, wastegate spring and limiter from the configuration
+    pub fn new(config: &SystemConfig, turbo: TurboSize) -> Self {
+        let (shaft_inertia, rated_pressure_ratio, rated_flow_kg_s, turbine_flow_kg_s) = match turbo {
+            TurboSize::Small => (6.0e-5, 2.4, 0.55, 0.35),
+            TurboSize::Medium => (1.0e-4, 3.0, 0.9, 0.5),
+            TurboSize::Large => (2.5e-4, 3.6, 1.3, 0.6),
+        };
+        Self {
+            displacement_l: 5.0,
+            volumetric_efficiency: 0.9,
+            intake_volume_l: 8.0,
+            na_torque_nm: 450.0,
+            peak_torque_nm: 1100.0,
+            torque_time_constant_ms: 50.0,
+            redline_rpm: config.rev_limit_taper.redline_rpm as f32,
+            shaft_inertia,
+            shaft_friction: 5.0e-5,
+            rated_shaft_rpm: 120_000.0,
+            rated_pressure_ratio,
+            rated_flow_kg_s,
+            compressor_efficiency: 0.72,
+            turbine_flow_kg_s,
+            spring_pressure: config.spring_pressure,
+            wastegate_travel_psi: 2.0,
+            wastegate_bypass: 0.85,
+            wastegate_time_constant_ms: 15.0,
+            dome_time_constant_ms: 65.0,
+            manifold_kpa: ATMOSPHERE_KPA,
+            shaft_rad_s: 0.0,
+            wastegate_open: 0.0,
+            upper_dome_psi: 0.0,
+            lower_dome_psi: 0.0,
+            torque_nm: 0.0,
+        }
+    }
+    
+    /// Current intake pressure (PSI gauge)
+    pub fn manifold_psi(&self) -> f32 {
+        (self.manifold_kpa - ATMOSPHERE_KPA) * PSI_PER_KPA
+    }
+    
+    /// Turbo shaft speed (RPM)
+    pub fn shaft_rpm(&self) -> f32 {
+        self.shaft_rad_s * 60.0 / core::f32::consts::TAU
+    }
+    
+    /// Wastegate opening (0.0 shut - 1.0 fully open)
+    pub fn wastegate_open(&self) -> f32 {
+        self.wastegate_open
+    }
+    
+    /// Upper and lower dome pressures (PSI)
+    pub fn dome_pressures(&self) -> (f32, f32) {
+        (self.upper_dome_psi, self.lower_dome_psi)
+    }
+    
+    /// Air the engine pumps at an intake pressure and throttle opening (kg/s)
+    fn engine_flow_kg_s(&self, rpm: f32, manifold_kpa: f32, throttle: f32) -> f32 {
+        let density = manifold_kpa * 1000.0 / (R_AIR * INTAKE_K);
+        let swept_m3_s = self.displacement_l / 1000.0 * rpm.max(0.0) / 120.0;
+        density * swept_m3_s * self.volumetric_efficiency * throttle
+    }
+    
+    /// Compressor flow at the current shaft speed and pressure ratio (kg/s)
+    /// 
+    /// Speed lines are parabolas scaled from the rated one: flow falls from
+    /// its choke value at no pressure rise to nothing at the line's peak
+    /// ratio, which grows with the square of shaft speed. Past rated speed
+    /// the wheel is choked and the map stops growing. Below atmospheric the
+    /// engine also draws air past the wheel.
+    fn compressor_flow_kg_s(&self, pressure_ratio: f32) -> f32 {
+        let speed = (self.shaft_rpm() / self.rated_shaft_rpm).min(1.0);
+        let peak_ratio = 1.0 + (self.rated_pressure_ratio - 1.0) * speed * speed;
+        let rise = ((pressure_ratio - 1.0) / (peak_ratio - 1.0).max(f32::EPSILON)).clamp(0.0, 1.0);
+        let map_flow = self.rated_flow_kg_s * speed * (1.0 - rise * rise);
+        map_flow + PASSIVE_FLOW_KG_S * (1.0 - pressure_ratio).max(0.0).sqrt()
+    }
+    
+    /// Power the compressor absorbs (W)
+    /// 
+    /// A choked wheel spun past rated speed only churns the air, so its
+    /// efficiency falls away with the square of the overspeed.
+    fn compressor_power_w(&self, flow_kg_s: f32, pressure_ratio: f32) -> f32 {
+        if pressure_ratio <= 1.0 {
+            return 0.0;
+        }
+        let overspeed = (self.shaft_rpm() / self.rated_shaft_rpm).max(1.0);
+        let efficiency = self.compressor_efficiency / (overspeed * overspeed);
+        flow_kg_s * CP_AIR * AMBIENT_K * (pressure_ratio.powf(AIR_EXPONENT) - 1.0) / efficiency
+    }
+    
+    /// Power the exhaust delivers to the turbine (W)
+    /// 
+    /// The turbine housing acts as an orifice: expansion ratio grows with the
+    /// square of the flow through it, so little exhaust makes almost no work.
+    fn turbine_power_w(&self, flow_kg_s: f32) -> f32 {
+        let expansion_ratio = 1.0 + flow_kg_s / self.turbine_flow_kg_s;
+        flow_kg_s * CP_EXHAUST * EXHAUST_K * TURBINE_EFFICIENCY * (1.0 - expansion_ratio.powf(-EXHAUST_EXPONENT))
+    }
+    
+    /// Wastegate opening the force balance calls for (0.0-1.0)
+    /// 
+    /// Boost and the lower dome push it open; spring and upper dome hold it
+    /// shut. Exhaust pressure on the valve is taken to track boost, so the
+    /// spring alone cracks it at its rated pressure.
+    fn wastegate_demand(&self) -> f32 {
+        let net_open_psi = self.manifold_psi() + self.lower_dome_psi - self.spring_pressure - self.upper_dome_psi;
+        (net_open_psi / self.wastegate_travel_psi).clamp(0.0, 1.0)
+    }
+    
+    /// Advance the model by `dt_ms`
+    /// 
+    /// The 4-port solenoid passes `duty_percent` of the dome supply to the
+    /// upper dome and the rest to the lower; without supply both domes vent
+    /// and the spring alone sets the wastegate.
+    pub fn step(&mut self, pedal: f32, rpm: f32, duty_percent: f32, dome_feed_psi: f32, dt_ms: f32) -> PlantOutputs {
+        let pedal = pedal.clamp(0.0, 1.0);
+        let duty = duty_percent.clamp(0.0, 100.0) / 100.0;
+        let feed = dome_feed_psi.max(0.0);
+        let desired_torque = self.peak_torque_nm * pedal;
+        let firing = rpm < self.redline_rpm;
+        
+        let mut remaining = dt_ms;
+        while remaining > 0.0 {
+            let dt = remaining.min(SUBSTEP_MS);
+            remaining -= dt;
+            let dt_s = dt / 1000.0;
+            
+            let dome_alpha = (dt / self.dome_time_constant_ms).clamp(0.0, 1.0);
+            self.upper_dome_psi += (feed * duty - self.upper_dome_psi) * dome_alpha;
+            self.lower_dome_psi += (feed * (1.0 - duty) - self.lower_dome_psi) * dome_alpha;
+            let gate_alpha = (dt / self.wastegate_time_constant_ms).clamp(0.0, 1.0);
+            self.wastegate_open += (self.wastegate_demand() - self.wastegate_open) * gate_alpha;
+            
+            // Torque-based ECU: open the throttle as far as the torque request needs
+            let pressure_ratio = self.manifold_kpa / ATMOSPHERE_KPA;
+            let full_throttle_torque = self.na_torque_nm * pressure_ratio;
+            let throttle = (desired_torque / full_throttle_torque.max(1.0)).clamp(IDLE_THROTTLE, 1.0);
+            let produced = if firing { full_throttle_torque * throttle } else { 0.0 };
+            let torque_alpha = (dt / self.torque_time_constant_ms).clamp(0.0, 1.0);
+            self.torque_nm += (produced - self.torque_nm) * torque_alpha;
+            
+            // Intake filling: compressor delivery against engine draw
+            let engine_flow = self.engine_flow_kg_s(rpm, self.manifold_kpa, throttle);
+            let compressor_flow = self.compressor_flow_kg_s(pressure_ratio);
+            let kpa_per_kg = R_AIR * INTAKE_K / (self.intake_volume_l / 1000.0) / 1000.0;
+            self.manifold_kpa = (self.manifold_kpa + (compressor_flow - engine_flow) * kpa_per_kg * dt_s).max(1.0);
+            
+            // Shaft: turbine drive against compressor load and bearing friction;
+            // a limiter cut leaves the exhaust too cool to do work
+            let turbine_flow = engine_flow * (1.0 - self.wastegate_bypass * self.wastegate_open);
+            let drive = if firing { self.turbine_power_w(turbine_flow) } else { 0.0 };
+            let load = self.compressor_power_w(compressor_flow, pressure_ratio) + self.shaft_friction * self.shaft_rad_s.powi(2);
+            let energy = 0.5 * self.shaft_inertia * self.shaft_rad_s.powi(2) + (drive - load) * dt_s;
+            self.shaft_rad_s = (2.0 * energy.max(0.0) / self.shaft_inertia).sqrt();
+        }
+        
+        PlantOutputs {
+            manifold_psi: self.manifold_psi(),
+            desired_torque,
+            actual_torque: self.torque_nm.min(desired_torque),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::ScenarioRunner;
+    use crate::scenario::builtin_scenarios;
+    
+    /// Hold an operating point for `ms` in 10 ms steps, returning the last outputs
+    fn hold(engine: &mut EngineSim, pedal: f32, rpm: f32, duty: f32, feed: f32, ms: u32) -> PlantOutputs {
+        let mut outputs = engine.step(pedal, rpm, duty, feed, 10.0);
+        for _ in 1..ms / 10 {
+            outputs = engine.step(pedal, rpm, duty, feed, 10.0);
+        }
+        outputs
+    }
+    
+    #[test]
+    fn test_spring_alone_holds_near_spring_pressure() {
+        let config = SystemConfig::default();
+        let mut engine = EngineSim::new(&config, TurboSize::Medium);
+        let outputs = hold(&mut engine, 1.0, 5000.0, 0.0, 0.0, 4000);
+        
+        // The wastegate cannot bypass everything, so boost creeps a little past the spring
+        assert!(engine.wastegate_open() > 0.0);
+        assert!(outputs.manifold_psi >= config.spring_pressure);
+        assert!(outputs.manifold_psi < config.spring_pressure + 1.5);
+    }
+    
+    #[test]
+    fn test_light_cruise_stays_under_spring_pressure() {
+        let config = SystemConfig::default();
+        let mut engine = EngineSim::new(&config, TurboSize::Medium);
+        let outputs = hold(&mut engine, 0.2, 2500.0, 0.0, 0.0, 3000);
+        assert!(outputs.manifold_psi < config.spring_pressure - 2.0);
+        assert_eq!(engine.wastegate_open(), 0.0);
+        assert!((outputs.actual_torque - outputs.desired_torque).abs() < 1.0);
+    }
+    
+    #[test]
+    fn test_bigger_turbo_spools_slower() {
+        let config = SystemConfig::default();
+        let spool_ms = |turbo| {
+            let mut engine = EngineSim::new(&config, turbo);
+            (1..=500).map(|step| step * 10).find(|_| engine.step(1.0, 4000.0, 100.0, 20.0, 10.0).manifold_psi >= 8.0)
+        };
+        let (small, medium, large) = (spool_ms(TurboSize::Small), spool_ms(TurboSize::Medium), spool_ms(TurboSize::Large));
+        assert!(small.unwrap() < medium.unwrap());
+        assert!(medium.unwrap() < large.unwrap());
+    }
+    
+    #[test]
+    fn test_domes_follow_duty_with_lag() {
+        let mut engine = EngineSim::new(&SystemConfig::default(), TurboSize::Medium);
+        engine.step(0.0, 1000.0, 100.0, 20.0, 10.0);
+        let (upper, _) = engine.dome_pressures();
+        assert!(upper > 0.0 && upper < 10.0);
+        
+        hold(&mut engine, 0.0, 1000.0, 75.0, 20.0, 1000);
+        let (upper, lower) = engine.dome_pressures();
+        assert!((upper - 15.0).abs() < 0.1);
+        assert!((lower - 5.0).abs() < 0.1);
+    }
+    
+    #[test]
+    fn test_duty_sets_boost_through_the_wastegate() {
+        let config = SystemConfig::default();
+        let boost_at = |duty| {
+            let mut engine = EngineSim::new(&config, TurboSize::Medium);
+            (hold(&mut engine, 1.0, 5000.0, duty, 20.0, 4000).manifold_psi, engine.wastegate_open())
+        };
+        
+        // The lower dome forces the wastegate open below spring pressure; the upper dome holds it shut past it
+        let (vented, open) = boost_at(0.0);
+        let (held, shut) = boost_at(100.0);
+        assert!(vented < config.spring_pressure);
+        assert!(open > 0.99);
+        assert!(held > config.spring_pressure + 10.0);
+        assert!(shut < open);
+    }
+    
+    #[test]
+    fn test_limiter_cuts_torque_and_spool() {
+        let mut engine = EngineSim::new(&SystemConfig::default(), TurboSize::Medium);
+        let redline = engine.redline_rpm;
+        let spooled = hold(&mut engine, 1.0, redline - 100.0, 100.0, 20.0, 2000);
+        assert!(spooled.actual_torque > engine.na_torque_nm);
+        let shaft_rpm = engine.shaft_rpm();
+        
+        let cut = hold(&mut engine, 1.0, redline, 100.0, 20.0, 500);
+        assert!(cut.actual_torque < 1.0);
+        assert!(engine.shaft_rpm() < shaft_rpm);
+    }
+    
+    #[test]
+    fn test_builtin_scenarios_stay_safe_on_every_turbo() {
+        let config = SystemConfig { aggression: 1.0, ..SystemConfig::default() };
+        for turbo in [TurboSize::Small, TurboSize::Medium, TurboSize::Large] {
+            let runner = ScenarioRunner::new(config.clone()).with_engine_sim(turbo);
+            for scenario in builtin_scenarios(&config) {
+                let result = runner.run(&scenario).unwrap();
+                assert!(result.faults.is_empty(), "{} on {} turbo: {:?}", scenario.name, turbo, result.faults);
+                assert!(result.peak_boost_psi < config.overboost_limit, "{} on {} turbo peaked at {:.1} PSI", scenario.name, turbo, result.peak_boost_psi);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Golden Trace Capture and Verification
+//! 
+//! 🔗 T4-SIMULATOR-013: Golden Trace Tooling
+//! Derived From: T4-CORE-087 (Golden Trace Format) + T4-SIMULATOR-005 (Headless Scenario Execution)
+//! AI Traceability: Record a known-good closed-loop run once, then check every control change against it
+
+use std::error::Error;
+use std::fs;
+
+use rumbledome_core::{CoreError, GoldenTrace, SystemConfig, TraceReport};
+
+use crate::runner::{SimSession, CYCLE_MS, SIM_TORQUE_MESSAGE_ID};
+use crate::scenario::TestScenario;
+
+/// Run a scenario closed-loop and record every cycle as a golden trace
+pub fn capture_golden_trace(config: &SystemConfig, scenario: &TestScenario) -> Result<GoldenTrace, CoreError> {
+    let mut session = SimSession::new(config)?;
+    let mut trace = GoldenTrace::new(&scenario.name, config.clone(), session.core.state.clone());
+    
+    let mut t_ms = 0;
+    while t_ms < scenario.duration_ms {
+        let (throttle, rpm) = scenario.driver_input(t_ms);
+        session.step(throttle, rpm, t_ms)?;
+        if let Some(inputs) = session.core.last_inputs.clone() {
+            trace.record(&[SIM_TORQUE_MESSAGE_ID], inputs, session.core.cycle_outputs());
+        }
+        t_ms += CYCLE_MS;
+    }
+    Ok(trace)
+}
+
+/// Replay a golden trace against a fresh core set up the way the simulator runs it
+/// 
+/// The plant is not involved - recorded inputs are replayed as they were.
+pub fn verify_golden_trace(trace: &GoldenTrace) -> Result<TraceReport, CoreError> {
+    let mut session = SimSession::new(&trace.config)?;
+    trace.replay(&mut session.core)
+}
+
+/// Load a golden trace from JSON
+pub fn load_golden_trace(path: &str) -> Result<GoldenTrace, Box<dyn Error>> {
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Save a golden trace as JSON
+pub fn save_golden_trace(path: &str, trace: &GoldenTrace) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string(trace)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::tip_in_tip_out;
+    
+    #[test]
+    fn test_captured_trace_verifies() {
+        let config = SystemConfig::default();
+        let trace = capture_golden_trace(&config, &tip_in_tip_out()).unwrap();
+        assert_eq!(trace.cycles.len(), 600);
+        
+        let report = verify_golden_trace(&trace).unwrap();
+        assert_eq!(report.cycles_checked, 600);
+        assert!(report.passed(), "{:?}", report.mismatches.first());
+    }
+    
+    #[test]
+    fn test_drift_outside_tolerance_is_reported() {
+        let mut trace = capture_golden_trace(&SystemConfig::default(), &tip_in_tip_out()).unwrap();
+        let tolerance = trace.tolerance.duty_percent;
+        trace.cycles[100].expected.duty_percent += tolerance * 0.5;
+        trace.cycles[200].expected.duty_percent += tolerance * 2.0;
+        
+        let report = verify_golden_trace(&trace).unwrap();
+        assert_eq!(report.mismatch_count, 1);
+        assert_eq!(report.mismatches[0].cycle, 200);
+    }
+    
+    #[test]
+    fn test_trace_file_round_trip() {
+        let trace = capture_golden_trace(&SystemConfig::default(), &tip_in_tip_out()).unwrap();
+        let path = std::env::temp_dir().join("rumbledome-golden-round-trip.json");
+        let path = path.to_str().unwrap();
+        
+        save_golden_trace(path, &trace).unwrap();
+        let restored = load_golden_trace(path).unwrap();
+        fs::remove_file(path).unwrap();
+        
+        assert!(verify_golden_trace(&restored).unwrap().passed());
+    }
+}
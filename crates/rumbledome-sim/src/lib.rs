@@ -0,0 +1,45 @@
+//! RumbleDome Simulation Library
+//! 
+//! 🔗 T4-SIMULATOR-002: Headless Simulation Components
+//! Derived From: T3-BUILD-006 (Desktop Simulation) + T2-SIM-001 (Physics Modeling)
+//! AI Traceability: Shared by the interactive simulator and CLI config dry runs
+
+pub mod plant;
+pub mod scenario;
+pub mod runner;
+pub mod dry_run;
+pub mod link;
+pub mod rng;
+pub mod virtual_device;
+pub mod recorder;
+pub mod coverage;
+pub mod dither_study;
+pub mod drive_cycle;
+pub mod golden;
+pub mod config_watch;
+pub mod sensor_robustness;
+pub mod protocol_fixture;
+pub mod engine_sim;
+pub mod regression;
+pub mod debugger;
+#[cfg(feature = "python")]
+pub mod python;
+
+pub use plant::*;
+pub use scenario::*;
+pub use runner::*;
+pub use dry_run::*;
+pub use link::*;
+pub use rng::*;
+pub use virtual_device::*;
+pub use recorder::*;
+pub use coverage::*;
+pub use dither_study::*;
+pub use drive_cycle::*;
+pub use golden::*;
+pub use config_watch::*;
+pub use sensor_robustness::*;
+pub use protocol_fixture::*;
+pub use engine_sim::*;
+pub use regression::*;
+pub use debugger::*;
@@ -0,0 +1,245 @@
+//! Impaired Link Model
+//! 
+//! 🔗 T4-SIMULATOR-007: Serial/Bluetooth Link Impairment
+//! Derived From: Protocols.md Communication Transport (Bluetooth SPP) + T4-PROTOCOL-002
+//! AI Traceability: Reproducible bad links for validating framing, retry and telemetry throttling
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rng::SimRng;
+
+/// Link impairment settings for one direction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkImpairment {
+    /// Fixed one-way latency (ms)
+    pub latency_ms: u32,
+    /// Additional random latency, uniformly 0..=jitter (ms)
+    pub jitter_ms: u32,
+    /// Probability a chunk is lost (0.0-1.0)
+    pub drop_probability: f32,
+    /// Throughput cap (bytes/s), 0 = unlimited
+    pub throughput_bytes_per_s: u32,
+    /// Bytes carried per radio packet - drops and jitter apply per chunk
+    pub chunk_bytes: usize,
+    /// Random seed - identical seeds replay identical impairment
+    pub seed: u32,
+}
+
+impl Default for LinkImpairment {
+    fn default() -> Self {
+        Self::ideal()
+    }
+}
+
+impl LinkImpairment {
+    /// Perfect link (USB serial)
+    pub fn ideal() -> Self {
+        Self {
+            latency_ms: 0,
+            jitter_ms: 0,
+            drop_probability: 0.0,
+            throughput_bytes_per_s: 0,
+            chunk_bytes: 1024,
+            seed: 1,
+        }
+    }
+    
+    /// Bluetooth SPP module bridged at 115200 baud, good signal
+    pub fn spp_typical() -> Self {
+        Self {
+            latency_ms: 30,
+            jitter_ms: 20,
+            drop_probability: 0.0,
+            throughput_bytes_per_s: 11_520,
+            chunk_bytes: 127,
+            seed: 1,
+        }
+    }
+    
+    /// Bluetooth SPP at the edge of range (phone in a pocket, through a firewall)
+    pub fn spp_marginal() -> Self {
+        Self {
+            latency_ms: 80,
+            jitter_ms: 150,
+            drop_probability: 0.05,
+            throughput_bytes_per_s: 1_500,
+            chunk_bytes: 127,
+            seed: 1,
+        }
+    }
+}
+
+/// Link statistics for one direction
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LinkStats {
+    /// Bytes handed to the link
+    pub bytes_sent: u64,
+    /// Bytes delivered to the far end
+    pub bytes_delivered: u64,
+    /// Chunks lost
+    pub chunks_dropped: u32,
+}
+
+/// One direction of an impaired byte-stream link
+/// 
+/// Bytes wait in a transmit queue until the throughput cap lets them onto the
+/// air, then arrive after latency plus jitter. Delivery stays in order, as with
+/// SPP over RFCOMM - a jittered chunk holds back the chunks behind it.
+#[derive(Debug, Clone)]
+pub struct ImpairedLink {
+    impairment: LinkImpairment,
+    rng: SimRng,
+    tx_queue: VecDeque<u8>,
+    in_flight: VecDeque<(u32, Vec<u8>)>,
+    last_step_ms: Option<u32>,
+    tx_credit_bytes: f32,
+    last_delivery_ms: u32,
+    stats: LinkStats,
+}
+
+impl ImpairedLink {
+    /// Create link with the given impairment
+    pub fn new(impairment: LinkImpairment) -> Self {
+        let rng = SimRng::new(impairment.seed);
+        
+        Self {
+            impairment,
+            rng,
+            tx_queue: VecDeque::new(),
+            in_flight: VecDeque::new(),
+            last_step_ms: None,
+            tx_credit_bytes: 0.0,
+            last_delivery_ms: 0,
+            stats: LinkStats::default(),
+        }
+    }
+    
+    /// Link statistics
+    pub fn stats(&self) -> &LinkStats {
+        &self.stats
+    }
+    
+    /// Bytes written but not yet transmitted (what the sender sees as backlog)
+    pub fn backlog_bytes(&self) -> usize {
+        self.tx_queue.len()
+    }
+    
+    /// Queue bytes for transmission
+    pub fn send(&mut self, bytes: &[u8]) {
+        self.stats.bytes_sent += bytes.len() as u64;
+        self.tx_queue.extend(bytes);
+    }
+    
+    /// Advance the link to `now_ms` and return bytes arriving at the far end
+    pub fn step(&mut self, now_ms: u32) -> Vec<u8> {
+        self.transmit(now_ms);
+        
+        let mut delivered = Vec::new();
+        while self.in_flight.front().is_some_and(|(deliver_ms, _)| *deliver_ms <= now_ms) {
+            if let Some((_, chunk)) = self.in_flight.pop_front() {
+                delivered.extend(chunk);
+            }
+        }
+        
+        self.stats.bytes_delivered += delivered.len() as u64;
+        delivered
+    }
+    
+    fn transmit(&mut self, now_ms: u32) {
+        let elapsed_ms = self.last_step_ms.map(|last| now_ms.wrapping_sub(last)).unwrap_or(0);
+        self.last_step_ms = Some(now_ms);
+        
+        let mut budget = if self.impairment.throughput_bytes_per_s == 0 {
+            usize::MAX
+        } else {
+            self.tx_credit_bytes += self.impairment.throughput_bytes_per_s as f32 * elapsed_ms as f32 / 1000.0;
+            // Idle time does not bank unlimited burst capacity
+            self.tx_credit_bytes = self.tx_credit_bytes.min(self.impairment.chunk_bytes as f32);
+            self.tx_credit_bytes as usize
+        };
+        
+        while !self.tx_queue.is_empty() {
+            // A radio packet goes out whole once the cap allows it
+            let len = self.tx_queue.len().min(self.impairment.chunk_bytes);
+            if len > budget {
+                break;
+            }
+            let chunk: Vec<u8> = self.tx_queue.drain(..len).collect();
+            budget -= len;
+            if self.impairment.throughput_bytes_per_s > 0 {
+                self.tx_credit_bytes -= len as f32;
+            }
+            
+            if self.rng.next_unit() < self.impairment.drop_probability {
+                self.stats.chunks_dropped += 1;
+                continue;
+            }
+            
+            let jitter = (self.rng.next_unit() * (self.impairment.jitter_ms + 1) as f32) as u32;
+            let deliver_ms = (now_ms + self.impairment.latency_ms + jitter).max(self.last_delivery_ms);
+            self.last_delivery_ms = deliver_ms;
+            self.in_flight.push_back((deliver_ms, chunk));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_ideal_link_delivers_immediately() {
+        let mut link = ImpairedLink::new(LinkImpairment::ideal());
+        link.send(b"hello\n");
+        assert_eq!(link.step(0), b"hello\n");
+    }
+    
+    #[test]
+    fn test_throughput_cap_builds_backlog() {
+        let mut link = ImpairedLink::new(LinkImpairment { throughput_bytes_per_s: 1000, ..LinkImpairment::ideal() });
+        link.step(0);
+        link.send(&[0u8; 500]);
+        
+        let mut received = 0;
+        for t in 1..=100 {
+            received += link.step(t * 10).len();
+        }
+        
+        // Payload leaves as one packet once half a second of credit has built up
+        assert_eq!(received, 500);
+        assert_eq!(link.backlog_bytes(), 0);
+    }
+    
+    #[test]
+    fn test_latency_and_in_order_delivery() {
+        let mut link = ImpairedLink::new(LinkImpairment {
+            latency_ms: 50,
+            jitter_ms: 100,
+            chunk_bytes: 4,
+            ..LinkImpairment::ideal()
+        });
+        link.send(b"0123456789");
+        
+        let mut received = link.step(0);
+        assert!(received.is_empty());
+        for t in 1..=20 {
+            received.extend(link.step(t * 10));
+        }
+        assert_eq!(received, b"0123456789");
+    }
+    
+    #[test]
+    fn test_same_seed_replays_same_drops() {
+        let run = || {
+            let mut link = ImpairedLink::new(LinkImpairment { drop_probability: 0.3, chunk_bytes: 1, ..LinkImpairment::ideal() });
+            link.send(&[7u8; 200]);
+            link.step(0).len()
+        };
+        
+        let delivered = run();
+        assert_eq!(run(), delivered);
+        assert!(delivered > 100 && delivered < 180);
+    }
+}
@@ -1,48 +1,556 @@
 //! RumbleDome Desktop Simulator
-//! 
+//!
 //! 🔗 T4-SIMULATOR-001: Desktop Simulation Implementation
 //! Derived From: T3-BUILD-006 (Desktop Simulation) + T2-SIM-001 (Physics Modeling)
 //! Decision Type: 🔗 Direct Derivation - Desktop simulation for algorithm validation
 //! AI Traceability: Enables safe algorithm development, physics-based testing, performance validation
 
-use std::time::Duration;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::{Parser, Subcommand};
+use console::{Key, Term};
 use tokio::time;
 
-use rumbledome_hal::MockHal;
-use rumbledome_core::{RumbleDomeCore, SystemConfig};
+use rumbledome_core::SystemConfig;
+use rumbledome_hal::{MockHal, MockStorage};
+use rumbledome_sim::{
+    builtin_scenarios, calibration_study, capture_golden_trace, dither_study, generate_drive_cycle, load_golden_trace, load_scenario,
+    load_scenario_dir, resolve_scenario, run_regression, save_golden_trace, save_run_report, save_scenario, save_templates,
+    suite_coverage, verify_golden_trace, Breakpoint, DebugStop, Debugger, DriveCycleConfig, DriveCycleKind, InputRecorder, ScenarioFormat, TestScenario,
+    ScenarioResult, ScenarioRunner, SimSession, ConfigWatcher, CalibrationStudyConfig, TurboSize, CYCLE_MS,
+    RECORDING_WINDOW_MS, SPEC_GAIN_TOLERANCE, SPEC_OFFSET_TOLERANCE_PSI, WATCH_EVERY_CYCLES,
+};
+
+/// Throttle change per key press (fraction of full throttle)
+const THROTTLE_STEP: f32 = 0.1;
+
+/// RPM change per key press
+const RPM_STEP: f32 = 250.0;
+
+/// Lowest RPM the driver controls can select
+const MIN_RPM: f32 = 800.0;
+
+/// Status line refresh interval (control cycles)
+const STATUS_EVERY_CYCLES: u32 = 10;
+
+#[derive(Parser)]
+#[command(name = "rumbledome-sim")]
+#[command(about = "Desktop simulator for RumbleDome boost controller")]
+#[command(version = "0.1.0")]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Configuration file to simulate (defaults to the stock configuration)
+    #[arg(short, long, global = true)]
+    config: Option<String>,
+    /// Run a scenario file headless and report the result instead of driving interactively
+    #[arg(long)]
+    run: Option<String>,
+    /// Directory recorded scenarios are written to
+    #[arg(long, default_value = ".")]
+    record_dir: String,
+    /// EEPROM image file the interactive session loads at start and saves to (learned data carries over between runs)
+    #[arg(long)]
+    storage: Option<String>,
+    /// Run the scenario suite and report which safety states, actions and fault codes it exercises
+    #[arg(long)]
+    coverage: bool,
+    /// Scenario library (*.json, *.yaml) for the coverage suite and for `run --scenario` names
+    #[arg(long, global = true)]
+    scenario_dir: Option<String>,
+    /// Sweep duty through a sticky valve with and without dither and report the difference
+    #[arg(long)]
+    dither_study: bool,
+    /// Rerun the built-in scenarios with manifold pressure sensor calibration errors and report the degradation
+    #[arg(long)]
+    calibration_study: bool,
+    /// Calibration study sensor offset tolerance (± PSI)
+    #[arg(long, default_value_t = SPEC_OFFSET_TOLERANCE_PSI)]
+    sensor_offset_psi: f32,
+    /// Calibration study sensor gain tolerance (± %)
+    #[arg(long, default_value_t = SPEC_GAIN_TOLERANCE * 100.0)]
+    sensor_gain_percent: f32,
+    /// Generate a drive cycle (city, highway or mountain) and run it headless
+    #[arg(long)]
+    drive_cycle: Option<DriveCycleKind>,
+    /// Drive-cycle length (minutes)
+    #[arg(long, default_value_t = 20)]
+    drive_minutes: u32,
+    /// Drive-cycle randomness (0.0 repeats the nominal pattern, 1.0 is the widest spread)
+    #[arg(long, default_value_t = 0.5)]
+    randomness: f32,
+    /// Drive-cycle random seed
+    #[arg(long, default_value_t = 1)]
+    seed: u32,
+    /// Also save the generated drive cycle as a scenario file
+    #[arg(long)]
+    save_cycle: Option<String>,
+    /// Record the --run scenario as a golden trace file instead of judging it
+    #[arg(long, requires = "run")]
+    capture_golden: Option<String>,
+    /// Replay a golden trace and report cycles whose outputs drifted out of tolerance
+    #[arg(long)]
+    verify_golden: Option<String>,
+    /// Drive, --run and --drive-cycle against the physics engine model with a small, medium or large turbo
+    #[arg(long, global = true)]
+    engine_sim: Option<TurboSize>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run scenarios headless, judge every success criterion and exit non-zero if any fails
+    Run {
+        /// Scenario file or built-in scenario name (repeat to run several)
+        #[arg(long, required = true)]
+        scenario: Vec<String>,
+        /// Write the results, criterion by criterion, to this JSON file
+        #[arg(long)]
+        report: Option<String>,
+    },
+    /// Step a scenario one control cycle at a time, stopping at breakpoints
+    Debug {
+        /// Scenario file or built-in scenario name
+        #[arg(long)]
+        scenario: String,
+        /// Breakpoint, e.g. "boost_error > 2.0", "state change" or "safety action" (repeat to set several)
+        #[arg(long = "break")]
+        breakpoints: Vec<String>,
+    },
+    /// Write the built-in scenarios to a directory as templates to customise
+    SaveTemplate {
+        /// Directory to write into (created if missing)
+        #[arg(long, default_value = "scenarios")]
+        dir: String,
+        /// File format: json or yaml
+        #[arg(long, default_value = "yaml")]
+        format: ScenarioFormat,
+    },
+}
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> Result<(), Box<dyn Error>> {
     env_logger::init();
-    
+    let args = Args::parse();
+
     println!("RumbleDome Desktop Simulator v0.1.0");
     println!("🔗 Physics-based boost controller simulation");
+
+    let config = match &args.config {
+        Some(path) => SystemConfig::from_json(&fs::read_to_string(path)?)
+            .map_err(|e| format!("Invalid configuration: {:?}", e))?,
+        None => SystemConfig::default(),
+    };
+
+    let library = match &args.scenario_dir {
+        Some(dir) => load_scenario_dir(dir)?,
+        None => Vec::new(),
+    };
+    
+    match &args.command {
+        Some(Command::Run { scenario, report }) => {
+            return run_suite(&config, &library, scenario, report.as_deref(), args.engine_sim);
+        }
+        Some(Command::Debug { scenario, breakpoints }) => {
+            return run_debugger(&config, &library, scenario, breakpoints, args.engine_sim);
+        }
+        Some(Command::SaveTemplate { dir, format }) => {
+            for path in save_templates(dir, &config, *format)? {
+                println!("Wrote {}", path);
+            }
+            return Ok(());
+        }
+        None => {}
+    }
+    
+    if args.coverage {
+        return run_coverage(&config, library);
+    }
+    
+    if args.dither_study {
+        println!("\n{}", dither_study(&config));
+        return Ok(());
+    }
     
-    // TODO: Implement physics simulation engine
-    // TODO: Implement interactive control interface  
+    if args.calibration_study {
+        let settings = CalibrationStudyConfig {
+            offset_tolerance_psi: args.sensor_offset_psi,
+            gain_tolerance: args.sensor_gain_percent / 100.0,
+            ..CalibrationStudyConfig::default()
+        };
+        let study = calibration_study(&config, &settings).map_err(|e| format!("Scenario could not start: {:?}", e))?;
+        println!("\n{}", study);
+        return Ok(());
+    }
+    
+    if let Some(kind) = args.drive_cycle {
+        let cycle = DriveCycleConfig {
+            kind,
+            duration_ms: args.drive_minutes * 60_000,
+            randomness: args.randomness,
+            seed: args.seed,
+        };
+        return run_drive_cycle(&config, &cycle, args.save_cycle.as_deref(), args.engine_sim);
+    }
+    
+    if let Some(path) = &args.verify_golden {
+        return run_golden_verify(path);
+    }
+    
+    if let (Some(path), Some(trace_path)) = (&args.run, &args.capture_golden) {
+        return run_golden_capture(&config, path, trace_path);
+    }
+    
+    if let Some(path) = &args.run {
+        return run_scenario(&config, path, args.engine_sim);
+    }
+
     // TODO: Implement real-time metrics collection
-    // TODO: Implement scenario loading/saving
+    run_interactive(&config, args.config.as_deref(), &args.record_dir, args.storage.as_deref(), args.engine_sim).await
+}
+
+/// Scenario runner on the first-order plant, or on the physics model with a turbo size
+fn scenario_runner(config: &SystemConfig, turbo: Option<TurboSize>) -> ScenarioRunner {
+    let runner = ScenarioRunner::new(config.clone());
+    match turbo {
+        Some(turbo) => runner.with_engine_sim(turbo),
+        None => runner,
+    }
+}
+
+/// Replay a scenario file and print its result
+fn run_scenario(config: &SystemConfig, path: &str, turbo: Option<TurboSize>) -> Result<(), Box<dyn Error>> {
+    let scenario = load_scenario(path)?;
+    let result = scenario_runner(config, turbo)
+        .run(&scenario)
+        .map_err(|e| format!("Scenario could not start: {:?}", e))?;
+    report_result(&result)
+}
+
+/// Run scenarios for a regression suite, optionally writing a JSON report; any failure is an error
+fn run_suite(config: &SystemConfig, library: &[TestScenario], names: &[String], report_path: Option<&str>, turbo: Option<TurboSize>) -> Result<(), Box<dyn Error>> {
+    let scenarios = names.iter().map(|name| resolve_scenario(config, library, name)).collect::<Result<Vec<_>, _>>()?;
+    let report = run_regression(config, &scenarios, turbo).map_err(|e| format!("Scenario could not start: {:?}", e))?;
+    
+    for result in &report.results {
+        // Failures are printed with their result and counted below
+        let _ = report_result(result);
+    }
+    if let Some(path) = report_path {
+        save_run_report(path, &report)?;
+        println!("Report saved to {}", path);
+    }
+    
+    match report.failed().count() {
+        0 => Ok(()),
+        failed => Err(format!("{} of {} scenarios failed", failed, report.results.len()).into()),
+    }
+}
+
+/// Generate a drive cycle, optionally save it, and run it
+fn run_drive_cycle(config: &SystemConfig, cycle: &DriveCycleConfig, save_path: Option<&str>, turbo: Option<TurboSize>) -> Result<(), Box<dyn Error>> {
+    let scenario = generate_drive_cycle(cycle, config.rev_limit_taper.redline_rpm as f32);
+    println!("{}: {} keyframes", scenario.description, scenario.keyframes.len());
+    if let Some(path) = save_path {
+        save_scenario(path, &scenario)?;
+        println!("Drive cycle saved to {}", path);
+    }
     
-    // Initialize with mock hardware
-    let hal = MockHal::new();
-    let config = SystemConfig::default();
-    let mut core = RumbleDomeCore::new(hal, config);
+    let result = scenario_runner(config, turbo)
+        .run(&scenario)
+        .map_err(|e| format!("Drive cycle could not start: {:?}", e))?;
+    report_result(&result)
+}
+
+/// Run a scenario file and save the run as a golden trace
+fn run_golden_capture(config: &SystemConfig, scenario_path: &str, trace_path: &str) -> Result<(), Box<dyn Error>> {
+    let scenario = load_scenario(scenario_path)?;
+    let trace = capture_golden_trace(config, &scenario)
+        .map_err(|e| format!("Scenario could not start: {:?}", e))?;
+    save_golden_trace(trace_path, &trace)?;
+    println!("Golden trace of {} ({} cycles) saved to {}", trace.name, trace.cycles.len(), trace_path);
+    Ok(())
+}
+
+/// Replay a golden trace and print where outputs drifted; any drift is an error
+fn run_golden_verify(path: &str) -> Result<(), Box<dyn Error>> {
+    let trace = load_golden_trace(path)?;
+    let report = verify_golden_trace(&trace).map_err(|e| format!("Trace could not be replayed: {:?}", e))?;
+    
+    if report.passed() {
+        println!("[PASS] {}: {} cycles within tolerance", trace.name, report.cycles_checked);
+        return Ok(());
+    }
     
-    // Initialize system
-    core.initialize()?;
+    println!("[FAIL] {}: {} of {} cycles out of tolerance", trace.name, report.mismatch_count, report.cycles_checked);
+    for mismatch in &report.mismatches {
+        println!("       {} ms: expected {} target {:.2} duty {:.1}%, got {} target {:.2} duty {:.1}%",
+            mismatch.timestamp_ms,
+            mismatch.expected.state.display_text(), mismatch.expected.target_boost_psi, mismatch.expected.duty_percent,
+            mismatch.actual.state.display_text(), mismatch.actual.target_boost_psi, mismatch.actual.duty_percent);
+    }
+    Err("Golden trace mismatch".into())
+}
+
+/// Print a scenario result; a failed scenario is an error
+fn report_result(result: &ScenarioResult) -> Result<(), Box<dyn Error>> {
+    let verdict = if result.passed { "PASS" } else { "FAIL" };
+    println!("[{}] {}: peak {:.1} PSI  overshoot {:.1} PSI  reversals {}",
+        verdict, result.name, result.peak_boost_psi, result.max_overshoot_psi, result.oscillation_reversals);
+    for failure in &result.failures {
+        println!("       {}", failure);
+    }
+
+    if result.passed { Ok(()) } else { Err("Scenario failed".into()) }
+}
+
+/// Step a scenario under the debugger, taking commands from the terminal
+fn run_debugger(config: &SystemConfig, library: &[TestScenario], name: &str, breakpoints: &[String], turbo: Option<TurboSize>) -> Result<(), Box<dyn Error>> {
+    let scenario = resolve_scenario(config, library, name)?;
+    let mut debugger = Debugger::new(config, scenario, turbo).map_err(|e| format!("Scenario could not start: {:?}", e))?;
+    for text in breakpoints {
+        debugger.add_breakpoint(Breakpoint::parse(text)?);
+    }
     
-    // Simulation loop placeholder
-    let mut interval = time::interval(Duration::from_millis(10)); // 100Hz
+    println!("Debugging {} ({} ms)", debugger.scenario().name, debugger.scenario().duration_ms);
+    println!("s [n] step  c continue  p print cycle  b <condition> add breakpoint  d <n> delete breakpoint  l list  q quit");
     
+    let mut line = String::new();
     loop {
-        interval.tick().await;
+        print!("(debug) ");
+        io::stdout().flush()?;
+        line.clear();
+        if io::stdin().lock().read_line(&mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+        let (command, argument) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        let argument = argument.trim();
         
-        // Execute control cycle
-        if let Err(e) = core.execute_control_cycle() {
-            eprintln!("Control cycle error: {:?}", e);
+        match command {
+            "s" | "step" => {
+                let Ok(count) = (if argument.is_empty() { Ok(1) } else { argument.parse::<u32>() }) else {
+                    println!("Not a cycle count: {}", argument);
+                    continue;
+                };
+                for _ in 0..count {
+                    if debugger.step().map_err(|e| format!("Control cycle error: {:?}", e))?.is_none() {
+                        break;
+                    }
+                }
+                if let Some(snapshot) = debugger.snapshot() {
+                    print!("{}", snapshot);
+                }
+                if debugger.finished() {
+                    println!("Scenario finished");
+                }
+            }
+            "c" | "continue" => match debugger.resume().map_err(|e| format!("Control cycle error: {:?}", e))? {
+                DebugStop::Breakpoint(index) => {
+                    println!("Breakpoint {}: {}", index, debugger.breakpoints()[index]);
+                    if let Some(snapshot) = debugger.snapshot() {
+                        print!("{}", snapshot);
+                    }
+                }
+                DebugStop::Finished => println!("Scenario finished"),
+            },
+            "p" | "print" => match debugger.snapshot() {
+                Some(snapshot) => print!("{}", snapshot),
+                None => println!("No cycle run yet"),
+            },
+            "b" | "break" => match Breakpoint::parse(argument) {
+                Ok(breakpoint) => {
+                    let index = debugger.add_breakpoint(breakpoint);
+                    println!("Breakpoint {}: {}", index, debugger.breakpoints()[index]);
+                }
+                Err(e) => println!("{}", e),
+            },
+            "d" | "delete" => match argument.parse().ok().and_then(|index| debugger.remove_breakpoint(index)) {
+                Some(breakpoint) => println!("Deleted: {}", breakpoint),
+                None => println!("No breakpoint {}", argument),
+            },
+            "l" | "list" => {
+                for (index, breakpoint) in debugger.breakpoints().iter().enumerate() {
+                    println!("{}: {}", index, breakpoint);
+                }
+            }
+            "q" | "quit" => return Ok(()),
+            "" => {}
+            other => println!("Unknown command: {}", other),
+        }
+    }
+}
+
+/// Run the built-in and library scenarios and print safety path coverage
+fn run_coverage(config: &SystemConfig, library: Vec<TestScenario>) -> Result<(), Box<dyn Error>> {
+    let mut scenarios = builtin_scenarios(config);
+    scenarios.extend(library);
+    
+    let (results, report) = suite_coverage(config, &scenarios)
+        .map_err(|e| format!("Scenario could not start: {:?}", e))?;
+    
+    for result in &results {
+        println!("[{}] {}", if result.passed { "PASS" } else { "FAIL" }, result.name);
+    }
+    println!();
+    print!("{}", report);
+    
+    Ok(())
+}
+
+/// Drive the simulated car from the keyboard
+///
+/// Input is recorded continuously; 'r' saves the recent trace as a scenario
+/// file that `--run` replays. Edits to the configuration file are applied
+/// without restarting; 'c' reloads it immediately. With a storage image,
+/// learned data is saved to it exactly as to the car's EEPROM. With a turbo
+/// size, the physics model stands in for the first-order plant.
+async fn run_interactive(
+    config: &SystemConfig,
+    config_path: Option<&str>,
+    record_dir: &str,
+    storage_path: Option<&str>,
+    turbo: Option<TurboSize>,
+) -> Result<(), Box<dyn Error>> {
+    let hal = match storage_path {
+        Some(path) => MockHal::with_storage(MockStorage::open(path).map_err(|e| format!("Storage image: {:?}", e))?),
+        None => MockHal::new(),
+    };
+    let mut session = SimSession::with_hal(config, hal).map_err(|e| format!("Simulation could not start: {:?}", e))?;
+    if let Some(turbo) = turbo {
+        session.use_engine_sim(config, turbo);
+        println!("Physics model: {} turbo", turbo);
+    }
+    let mut recorder = InputRecorder::new(RECORDING_WINDOW_MS);
+    let mut watcher = config_path.map(ConfigWatcher::new);
+    let mut redline_rpm = config.rev_limit_taper.redline_rpm as f32;
+    let mut throttle: f32 = 0.0;
+    let mut rpm: f32 = MIN_RPM;
+
+    println!("↑/↓ throttle  ←/→ RPM  space lift  r save last {} s as scenario  d show screen  p save screen PNG  c reload config  q quit",
+        RECORDING_WINDOW_MS / 1000);
+
+    // Key reads block, so they run on their own thread
+    let (keys_tx, keys) = mpsc::channel();
+    thread::spawn(move || {
+        let term = Term::stdout();
+        while let Ok(key) = term.read_key() {
+            if keys_tx.send(key).is_err() {
+                break;
+            }
         }
+    });
+
+    let term = Term::stdout();
+    let mut interval = time::interval(Duration::from_millis(CYCLE_MS as u64));
+    let mut t_ms: u32 = 0;
+    let mut cycles: u32 = 0;
+
+    loop {
+        interval.tick().await;
+
+        let mut reload = watcher.as_mut().and_then(|watcher| {
+            if cycles.is_multiple_of(WATCH_EVERY_CYCLES) { watcher.poll() } else { None }
+        });
         
-        // TODO: Update physics simulation
-        // TODO: Update UI/metrics
+        while let Ok(key) = keys.try_recv() {
+            match key {
+                Key::ArrowUp => throttle = (throttle + THROTTLE_STEP).min(1.0),
+                Key::ArrowDown => throttle = (throttle - THROTTLE_STEP).max(0.0),
+                Key::ArrowRight => rpm = (rpm + RPM_STEP).min(redline_rpm),
+                Key::ArrowLeft => rpm = (rpm - RPM_STEP).max(MIN_RPM),
+                Key::Char(' ') => throttle = 0.0,
+                Key::Char('r') => {
+                    term.clear_line()?;
+                    match save_recording(&recorder, record_dir) {
+                        Ok(path) => println!("Recorded scenario saved to {}", path),
+                        Err(e) => println!("Recording not saved: {}", e),
+                    }
+                }
+                Key::Char('d') => {
+                    term.clear_line()?;
+                    session.core.refresh_display().map_err(|e| format!("Display: {:?}", e))?;
+                    print!("{}", session.core.hal.framebuffer().to_terminal());
+                }
+                Key::Char('p') => {
+                    term.clear_line()?;
+                    match save_screenshot(&mut session, record_dir) {
+                        Ok(path) => println!("Screen saved to {}", path),
+                        Err(e) => println!("Screen not saved: {}", e),
+                    }
+                }
+                Key::Char('c') => match &watcher {
+                    Some(watcher) => reload = Some(watcher.load()),
+                    None => {
+                        term.clear_line()?;
+                        println!("No configuration file to reload (start with --config)");
+                    }
+                },
+                Key::Char('q') | Key::Escape => {
+                    println!();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        // Applied between cycles, so the next cycle already runs the new configuration
+        if let Some(loaded) = reload {
+            term.clear_line()?;
+            match loaded.and_then(|config| {
+                session.reconfigure(config.clone()).map(|_| config).map_err(|e| format!("{:?}", e))
+            }) {
+                Ok(config) => {
+                    redline_rpm = config.rev_limit_taper.redline_rpm as f32;
+                    rpm = rpm.min(redline_rpm);
+                    println!("Configuration reloaded");
+                }
+                Err(e) => println!("Configuration not applied: {}", e),
+            }
+        }
+
+        // Round away float accumulation so unchanged input records as unchanged
+        throttle = (throttle * 10.0).round() / 10.0;
+        recorder.record(t_ms, throttle, rpm);
+
+        let outputs = session.step(throttle, rpm, t_ms).map_err(|e| format!("Control cycle error: {:?}", e))?;
+
+        if cycles.is_multiple_of(STATUS_EVERY_CYCLES) {
+            term.clear_line()?;
+            term.write_str(&format!(
+                "throttle {:>3.0}%  {:>4.0} RPM  boost {:>5.1} PSI  target {:>5.1} PSI  duty {:>5.1}%  {}",
+                throttle * 100.0, rpm, outputs.manifold_psi, session.core.last_target_boost_psi,
+                session.duty(), session.core.state.display_text(),
+            ))?;
+        }
+
+        t_ms += CYCLE_MS;
+        cycles += 1;
     }
-}
\ No newline at end of file
+}
+
+/// Write the recorded trace as a scenario file, returning its path
+fn save_recording(recorder: &InputRecorder, record_dir: &str) -> Result<String, Box<dyn Error>> {
+    let unix_s = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let name = format!("recorded_{}", unix_s);
+    let scenario = recorder.to_scenario(&name).ok_or("nothing recorded yet")?;
+
+    let path = Path::new(record_dir).join(format!("{}.json", name));
+    let path = path.to_string_lossy().into_owned();
+    save_scenario(&path, &scenario)?;
+    Ok(path)
+}
+
+/// Draw the device screen and write it as a PNG, returning its path
+fn save_screenshot(session: &mut SimSession, dir: &str) -> Result<String, Box<dyn Error>> {
+    session.core.refresh_display().map_err(|e| format!("{:?}", e))?;
+    let unix_s = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let path = Path::new(dir).join(format!("screen_{}.png", unix_s));
+    fs::write(&path, session.core.hal.framebuffer().to_png())?;
+    Ok(path.to_string_lossy().into_owned())
+}
@@ -0,0 +1,167 @@
+//! Boost Plant Model
+//! 
+//! 🔗 T4-SIMULATOR-003: First-Order Boost Plant
+//! Derived From: T2-SIM-001 (Physics Modeling) - simplified spool and wastegate behavior
+//! AI Traceability: Closed-loop headless scenarios without a full engine/turbo model
+
+use rumbledome_core::SystemConfig;
+
+/// Simplified engine, turbo and wastegate model
+/// 
+/// Manifold pressure approaches an equilibrium set by turbo spool (RPM and
+/// throttle) and wastegate authority (spring pressure plus dome duty) through a
+/// first-order lag. Good enough to expose overboost and oscillation in a config,
+/// not a substitute for the physics requirements in SimulationRequirements.md.
+#[derive(Debug, Clone)]
+pub struct BoostPlant {
+    /// Wastegate spring pressure (PSI)
+    pub spring_pressure: f32,
+    /// Boost the turbo can make with the wastegate held shut (PSI)
+    pub max_turbo_psi: f32,
+    /// RPM at which the turbo starts building boost
+    pub spool_start_rpm: f32,
+    /// RPM at which full boost is available
+    pub full_spool_rpm: f32,
+    /// Manifold pressure time constant (ms)
+    pub time_constant_ms: f32,
+    /// Naturally aspirated torque at full throttle (Nm)
+    pub na_torque_nm: f32,
+    /// Additional torque per PSI of boost (Nm/PSI)
+    pub torque_per_psi: f32,
+    /// ECU rev limiter - fuel/spark cut at or above this RPM
+    pub redline_rpm: f32,
+    /// Solenoid valve stiction - duty change needed before the spool moves (%)
+    /// 0 models an ideal valve
+    pub valve_stiction_percent: f32,
+    /// Current manifold pressure (PSI gauge)
+    manifold_psi: f32,
+    /// Duty the valve spool is actually passing (%)
+    valve_duty: f32,
+}
+
+/// Plant outputs for one step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlantOutputs {
+    /// Manifold pressure (PSI gauge)
+    pub manifold_psi: f32,
+    /// Driver-requested torque (Nm)
+    pub desired_torque: f32,
+    /// Delivered torque (Nm)
+    pub actual_torque: f32,
+}
+
+impl BoostPlant {
+    /// Create plant matching the wastegate spring in a configuration
+    pub fn new(config: &SystemConfig) -> Self {
+        Self {
+            spring_pressure: config.spring_pressure,
+            max_turbo_psi: 22.0,
+            spool_start_rpm: 1800.0,
+            full_spool_rpm: 3500.0,
+            time_constant_ms: 250.0,
+            na_torque_nm: 450.0,
+            torque_per_psi: 30.0,
+            redline_rpm: config.rev_limit_taper.redline_rpm as f32,
+            valve_stiction_percent: 0.0,
+            manifold_psi: 0.0,
+            valve_duty: 0.0,
+        }
+    }
+    
+    /// Current manifold pressure (PSI gauge)
+    pub fn manifold_psi(&self) -> f32 {
+        self.manifold_psi
+    }
+    
+    /// Boost the plant settles at for a given operating point (PSI gauge)
+    pub fn equilibrium_psi(&self, throttle: f32, rpm: f32, duty_percent: f32) -> f32 {
+        let spool = ((rpm - self.spool_start_rpm) / (self.full_spool_rpm - self.spool_start_rpm)).clamp(0.0, 1.0);
+        let available = self.max_turbo_psi * throttle.clamp(0.0, 1.0) * spool;
+        
+        // 0% duty leaves the wastegate on its spring; 100% holds it fully shut
+        let wastegate_limit = self.spring_pressure
+            + (self.max_turbo_psi - self.spring_pressure) * (duty_percent.clamp(0.0, 100.0) / 100.0);
+        
+        available.min(wastegate_limit)
+    }
+    
+    /// Duty passed by a sticky valve spool (%)
+    /// 
+    /// The spool stays put until the command moves more than the stiction band
+    /// away from it, then trails the command by the band (backlash). Small duty
+    /// changes are lost and the valve lags differently rising and falling.
+    fn valve_response(&mut self, duty_percent: f32) -> f32 {
+        let error = duty_percent - self.valve_duty;
+        if error.abs() > self.valve_stiction_percent {
+            self.valve_duty = duty_percent - self.valve_stiction_percent * error.signum();
+        }
+        self.valve_duty
+    }
+    
+    /// Advance the plant by `dt_ms`
+    pub fn step(&mut self, throttle: f32, rpm: f32, duty_percent: f32, dt_ms: f32) -> PlantOutputs {
+        let duty_percent = self.valve_response(duty_percent);
+        let target = self.equilibrium_psi(throttle, rpm, duty_percent);
+        let alpha = (dt_ms / self.time_constant_ms).clamp(0.0, 1.0);
+        self.manifold_psi += (target - self.manifold_psi) * alpha;
+        
+        let throttle = throttle.clamp(0.0, 1.0);
+        let max_torque = self.na_torque_nm + self.torque_per_psi * self.max_turbo_psi;
+        let desired_torque = max_torque * throttle;
+        let produced = if rpm >= self.redline_rpm {
+            // Limiter cut - no combustion torque while the driver still demands it
+            0.0
+        } else {
+            self.na_torque_nm * throttle + self.torque_per_psi * self.manifold_psi.max(0.0)
+        };
+        
+        PlantOutputs {
+            manifold_psi: self.manifold_psi,
+            desired_torque,
+            actual_torque: produced.min(desired_torque),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_zero_duty_settles_at_spring_pressure() {
+        let config = SystemConfig::default();
+        let mut plant = BoostPlant::new(&config);
+        
+        for _ in 0..500 {
+            plant.step(1.0, 5000.0, 0.0, 10.0);
+        }
+        
+        assert!((plant.manifold_psi() - config.spring_pressure).abs() < 0.1);
+    }
+    
+    #[test]
+    fn test_no_boost_below_spool() {
+        let plant = BoostPlant::new(&SystemConfig::default());
+        assert_eq!(plant.equilibrium_psi(1.0, 1000.0, 100.0), 0.0);
+    }
+    
+    #[test]
+    fn test_sticky_valve_ignores_small_duty_changes() {
+        let mut plant = BoostPlant::new(&SystemConfig::default());
+        plant.valve_stiction_percent = 3.0;
+        
+        assert_eq!(plant.valve_response(20.0), 17.0);
+        assert_eq!(plant.valve_response(19.0), 17.0);
+        assert_eq!(plant.valve_response(15.0), 17.0);
+        assert_eq!(plant.valve_response(12.0), 15.0);
+    }
+    
+    #[test]
+    fn test_limiter_cuts_torque() {
+        let mut plant = BoostPlant::new(&SystemConfig::default());
+        let redline = plant.redline_rpm;
+        
+        assert!(plant.step(1.0, redline - 100.0, 50.0, 10.0).actual_torque > 0.0);
+        assert_eq!(plant.step(1.0, redline, 50.0, 10.0).actual_torque, 0.0);
+    }
+}
@@ -0,0 +1,118 @@
+//! Protocol Compatibility Fixture Recording
+//! 
+//! 🔗 T4-SIMULATOR-017: Protocol Fixture Recording
+//! Derived From: T4-SIMULATOR-008 (Virtual Device Endpoint) + T4-PROTOCOL-009 (Cross-Version Compatibility Suite)
+//! AI Traceability: Capture what a client and device of this release put on the wire, so later releases can be held to it
+//! 
+//! A scripted session against the virtual device: the client's requests and
+//! the device's answers, each as the exact byte stream the link would carry.
+//! When `PROTOCOL_VERSION` is bumped, record the new version's fixture with
+//! `cargo test -p rumbledome-sim -- --ignored record_protocol_fixture`; the
+//! protocol crate's compatibility suite then checks every later change
+//! against it. Fixtures of released versions are never re-recorded.
+
+use std::error::Error;
+use std::fs;
+
+use rumbledome_core::{ControlMode, SystemConfig, TuneParameter, WatchVariable};
+use rumbledome_protocol::{encode_frame, ProtocolMessage, RequestSequencer, MAX_REQUEST_BYTES, MAX_RESPONSE_BYTES, PROTOCOL_VERSION};
+
+use crate::virtual_device::VirtualDevice;
+
+/// Directory the protocol crate reads its fixtures from
+pub const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../rumbledome-protocol/fixtures");
+
+/// Both directions of a recorded session
+#[derive(Debug, Clone, Default)]
+pub struct RecordedSession {
+    /// Framed requests, client to device
+    pub requests: Vec<u8>,
+    /// Framed responses, device to client
+    pub responses: Vec<u8>,
+}
+
+/// Requests a client of this release sends, covering each family of messages
+pub fn fixture_requests() -> Vec<ProtocolMessage> {
+    use ProtocolMessage::*;
+    
+    let mut overrides = serde_json::Map::new();
+    overrides.insert("aggression".to_string(), serde_json::json!(0.6));
+    vec![
+        Hello,
+        SetDeviceName { name: "Garage".to_string() },
+        ListConfigurations,
+        GetConfiguration { name: "default".to_string() },
+        SaveInheritedConfiguration { name: "Track".to_string(), parent: "default".to_string(), overrides },
+        SelectConfiguration { name: "Track".to_string() },
+        SetControlMode { mode: ControlMode::BoostByTarget },
+        SetTestValue { parameter: TuneParameter::TorqueGapThreshold, value: 12.0 },
+        RevertTestValues,
+        GetLiveTuneStatus,
+        SetWatchList { variables: vec![WatchVariable::TargetPsi, WatchVariable::DutyPercent] },
+        GetScreen,
+        SubscribeScreen { interval_ms: 250 },
+        UnsubscribeScreen,
+        AcknowledgeMaintenance { name: "Inspect dome lines".to_string() },
+        ResetMapZeroBaseline,
+        GetCommissioningStatus,
+        GetCalibrationStatus,
+        GetSafetyLog,
+        GetTrends { day: 20_742 },
+        DeleteConfiguration { name: "Track".to_string() },
+    ]
+}
+
+/// Play the fixture requests against a fresh virtual device
+/// 
+/// Mutating requests are sequenced as the CLI sends them.
+pub fn record_session() -> Result<RecordedSession, Box<dyn Error>> {
+    let mut device = VirtualDevice::new(SystemConfig::default()).map_err(|e| format!("{:?}", e))?;
+    let mut sequencer = RequestSequencer::default();
+    let mut session = RecordedSession::default();
+    
+    for request in fixture_requests() {
+        let request = sequencer.prepare(request);
+        session.requests.extend(encode_frame(&request, MAX_REQUEST_BYTES).map_err(|e| format!("{:?}", e))?);
+        let response = device.handle(request, 0);
+        session.responses.extend(encode_frame(&response, MAX_RESPONSE_BYTES).map_err(|e| format!("{:?}", e))?);
+    }
+    Ok(session)
+}
+
+/// Write a session as the current protocol version's fixture
+pub fn save_protocol_fixture(dir: &str, session: &RecordedSession) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    fs::write(format!("{}/v{}-requests.bin", dir, PROTOCOL_VERSION), &session.requests)?;
+    fs::write(format!("{}/v{}-responses.bin", dir, PROTOCOL_VERSION), &session.responses)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_protocol::FrameDecoder;
+    
+    #[test]
+    fn test_every_request_is_answered() {
+        let session = record_session().unwrap();
+        let requests = FrameDecoder::new(MAX_REQUEST_BYTES).push(&session.requests);
+        let responses = FrameDecoder::new(MAX_RESPONSE_BYTES).push(&session.responses);
+        assert_eq!(requests.len(), fixture_requests().len());
+        assert_eq!(responses.len(), requests.len());
+        
+        for (request, response) in requests.iter().zip(&responses) {
+            assert!(response.answers(request), "{:?} does not answer {:?}", response, request);
+            // Only the last request is refused: the selected configuration cannot be deleted
+            if !matches!(request, ProtocolMessage::Sequenced { request, .. } if matches!(**request, ProtocolMessage::DeleteConfiguration { .. })) {
+                assert!(!matches!(response, ProtocolMessage::Error(_)), "{:?} refused: {:?}", request, response);
+            }
+        }
+    }
+    
+    /// Records the current version's fixture into the protocol crate
+    #[test]
+    #[ignore]
+    fn record_protocol_fixture() {
+        save_protocol_fixture(FIXTURE_DIR, &record_session().unwrap()).unwrap();
+    }
+}
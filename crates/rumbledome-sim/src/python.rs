@@ -0,0 +1,253 @@
+//! Python Bindings
+//! 
+//! 🔗 T4-SIMULATOR-015: Python Simulation API
+//! Derived From: T4-SIMULATOR-005 (Headless Scenario Execution) + T2-SIM-020 (Simulation Reporting and Export)
+//! AI Traceability: Parameter sweeps, plots and optimization studies run against the exact production control code
+//! 
+//! Built only with the `python` feature. `maturin develop --features python`
+//! (from this crate's directory) installs a `rumbledome_sim` module:
+//! 
+//! ```python
+//! import json, rumbledome_sim
+//! 
+//! config = json.loads(rumbledome_sim.default_config_json())
+//! config["aggression"] = 0.8
+//! sim = rumbledome_sim.Simulation(json.dumps(config))
+//! trace = sim.run([1.0] * 300, [2500.0 + 10.0 * i for i in range(300)])
+//! ```
+//! 
+//! Configurations and scenarios cross the boundary as the same JSON the
+//! simulator and CLI read, so a configuration tuned in Python can be loaded
+//! onto a device unchanged. Results come back as dicts of plain values (a
+//! run's dict of columns drops straight into `pandas.DataFrame`).
+
+// pyo3 0.22's generated wrappers convert each returned PyErr into itself, outside the items they wrap
+#![allow(clippy::useless_conversion)]
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use rumbledome_core::{CoreError, SystemConfig, SystemState};
+
+use crate::coverage::state_name;
+use crate::plant::PlantOutputs;
+use crate::runner::{ScenarioResult, ScenarioRunner, SimSession, CYCLE_MS};
+use crate::scenario::TestScenario;
+
+/// One control cycle as seen from Python
+struct CycleRecord {
+    time_ms: u32,
+    throttle: f32,
+    rpm: f32,
+    manifold_psi: f32,
+    target_psi: f32,
+    duty_percent: f32,
+    state: &'static str,
+    fault: Option<String>,
+}
+
+impl CycleRecord {
+    fn capture(session: &SimSession, time_ms: u32, throttle: f32, rpm: f32, outputs: &PlantOutputs) -> Self {
+        let fault = match &session.core.state {
+            SystemState::Fault(fault) => Some(fault.description()),
+            _ => None,
+        };
+        Self {
+            time_ms,
+            throttle,
+            rpm,
+            manifold_psi: outputs.manifold_psi,
+            target_psi: session.core.last_target_boost_psi,
+            duty_percent: session.duty(),
+            state: state_name(&session.core.state),
+            fault,
+        }
+    }
+    
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("time_ms", self.time_ms)?;
+        dict.set_item("throttle", self.throttle)?;
+        dict.set_item("rpm", self.rpm)?;
+        dict.set_item("manifold_psi", self.manifold_psi)?;
+        dict.set_item("target_psi", self.target_psi)?;
+        dict.set_item("duty_percent", self.duty_percent)?;
+        dict.set_item("state", self.state)?;
+        dict.set_item("fault", self.fault.clone())?;
+        Ok(dict)
+    }
+}
+
+/// Production core closed around the plant model, stepped from Python
+/// 
+/// Time advances one 10ms control cycle per step.
+#[pyclass(name = "Simulation", module = "rumbledome_sim", unsendable)]
+pub struct PySimulation {
+    session: SimSession,
+    time_ms: u32,
+}
+
+#[pymethods]
+impl PySimulation {
+    /// Start an armed core; `config_json` defaults to the default configuration
+    #[new]
+    #[pyo3(signature = (config_json=None))]
+    fn new(config_json: Option<&str>) -> PyResult<Self> {
+        let config = parse_config(config_json)?;
+        let session = SimSession::new(&config).map_err(core_error)?;
+        Ok(Self { session, time_ms: 0 })
+    }
+    
+    /// Run one control cycle; throttle is 0.0-1.0
+    fn step<'py>(&mut self, py: Python<'py>, throttle: f32, rpm: f32) -> PyResult<Bound<'py, PyDict>> {
+        self.cycle(throttle, rpm)?.to_dict(py)
+    }
+    
+    /// Run one cycle per (throttle, rpm) pair and return a dict of columns
+    fn run<'py>(&mut self, py: Python<'py>, throttle: Vec<f32>, rpm: Vec<f32>) -> PyResult<Bound<'py, PyDict>> {
+        if throttle.len() != rpm.len() {
+            return Err(PyValueError::new_err(format!(
+                "throttle and rpm traces differ in length ({} vs {})", throttle.len(), rpm.len()
+            )));
+        }
+        
+        let records = throttle.into_iter().zip(rpm)
+            .map(|(throttle, rpm)| self.cycle(throttle, rpm))
+            .collect::<PyResult<Vec<CycleRecord>>>()?;
+        
+        let columns = PyDict::new_bound(py);
+        columns.set_item("time_ms", records.iter().map(|r| r.time_ms).collect::<Vec<_>>())?;
+        columns.set_item("throttle", records.iter().map(|r| r.throttle).collect::<Vec<_>>())?;
+        columns.set_item("rpm", records.iter().map(|r| r.rpm).collect::<Vec<_>>())?;
+        columns.set_item("manifold_psi", records.iter().map(|r| r.manifold_psi).collect::<Vec<_>>())?;
+        columns.set_item("target_psi", records.iter().map(|r| r.target_psi).collect::<Vec<_>>())?;
+        columns.set_item("duty_percent", records.iter().map(|r| r.duty_percent).collect::<Vec<_>>())?;
+        columns.set_item("state", records.iter().map(|r| r.state).collect::<Vec<_>>())?;
+        columns.set_item("fault", records.into_iter().map(|r| r.fault).collect::<Vec<_>>())?;
+        Ok(columns)
+    }
+    
+    /// Swap in a new configuration between cycles, keeping the plant running
+    fn reconfigure(&mut self, config_json: &str) -> PyResult<()> {
+        let config = parse_config(Some(config_json))?;
+        self.session.reconfigure(config).map_err(core_error)
+    }
+    
+    /// Simulated time of the next cycle (ms)
+    #[getter]
+    fn time_ms(&self) -> u32 {
+        self.time_ms
+    }
+    
+    /// Core state name
+    #[getter]
+    fn state(&self) -> &'static str {
+        state_name(&self.session.core.state)
+    }
+    
+    /// Configuration in effect, as JSON
+    #[getter]
+    fn config_json(&self) -> PyResult<String> {
+        to_json(&self.session.core.config)
+    }
+}
+
+impl PySimulation {
+    fn cycle(&mut self, throttle: f32, rpm: f32) -> PyResult<CycleRecord> {
+        let time_ms = self.time_ms;
+        let outputs = self.session.step(throttle, rpm, time_ms).map_err(core_error)?;
+        self.time_ms += CYCLE_MS;
+        Ok(CycleRecord::capture(&self.session, time_ms, throttle, rpm, &outputs))
+    }
+}
+
+/// Default configuration as JSON, to edit and pass back
+#[pyfunction]
+fn default_config_json() -> PyResult<String> {
+    to_json(&SystemConfig::default())
+}
+
+/// Run a scenario (simulator scenario JSON) on a fresh core and evaluate its criteria
+#[pyfunction]
+#[pyo3(signature = (scenario_json, config_json=None))]
+fn run_scenario<'py>(py: Python<'py>, scenario_json: &str, config_json: Option<&str>) -> PyResult<Bound<'py, PyDict>> {
+    let scenario: TestScenario = serde_json::from_str(scenario_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid scenario: {}", e)))?;
+    let config = parse_config(config_json)?;
+    let result = ScenarioRunner::new(config).run(&scenario).map_err(core_error)?;
+    scenario_result_dict(py, result)
+}
+
+fn scenario_result_dict(py: Python<'_>, result: ScenarioResult) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("name", result.name)?;
+    dict.set_item("passed", result.passed)?;
+    dict.set_item("peak_boost_psi", result.peak_boost_psi)?;
+    dict.set_item("max_overshoot_psi", result.max_overshoot_psi)?;
+    dict.set_item("oscillation_reversals", result.oscillation_reversals)?;
+    dict.set_item("faults", result.faults)?;
+    dict.set_item("failures", result.failures)?;
+    Ok(dict)
+}
+
+fn parse_config(config_json: Option<&str>) -> PyResult<SystemConfig> {
+    match config_json {
+        Some(json) => SystemConfig::from_json(json)
+            .map_err(|e| PyValueError::new_err(format!("Invalid configuration: {:?}", e))),
+        None => Ok(SystemConfig::default()),
+    }
+}
+
+fn to_json(config: &SystemConfig) -> PyResult<String> {
+    serde_json::to_string_pretty(config).map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+fn core_error(error: CoreError) -> PyErr {
+    PyRuntimeError::new_err(format!("{:?}", error))
+}
+
+/// `rumbledome_sim` Python module
+#[pymodule]
+fn rumbledome_sim(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySimulation>()?;
+    m.add_function(wrap_pyfunction!(default_config_json, m)?)?;
+    m.add_function(wrap_pyfunction!(run_scenario, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::wot_pull;
+    
+    #[test]
+    fn test_run_returns_one_column_entry_per_cycle() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let mut sim = PySimulation::new(None).unwrap();
+            let columns = sim.run(py, vec![1.0; 50], vec![4000.0; 50]).unwrap();
+            
+            for column in ["time_ms", "throttle", "rpm", "manifold_psi", "target_psi", "duty_percent", "state", "fault"] {
+                assert_eq!(columns.get_item(column).unwrap().unwrap().len().unwrap(), 50, "{}", column);
+            }
+            assert_eq!(sim.time_ms(), 500);
+            assert!(sim.run(py, vec![1.0; 2], vec![4000.0]).is_err());
+        });
+    }
+    
+    #[test]
+    fn test_scenario_and_config_round_trip_as_json() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let config = default_config_json().unwrap();
+            assert!(PySimulation::new(Some(&config)).is_ok());
+            assert!(PySimulation::new(Some("{}")).is_err());
+            
+            let scenario = serde_json::to_string(&wot_pull()).unwrap();
+            let result = run_scenario(py, &scenario, Some(&config)).unwrap();
+            let passed: bool = result.get_item("passed").unwrap().unwrap().extract().unwrap();
+            assert!(passed);
+        });
+    }
+}
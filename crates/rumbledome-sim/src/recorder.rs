@@ -0,0 +1,131 @@
+//! Driver Input Recording
+//! 
+//! 🔗 T4-SIMULATOR-009: Recordable Driver Input Traces
+//! Derived From: T4-SIMULATOR-004 (Scenario Definitions) + T2-SIM-017 (Interactive Real-Time Simulation Interface)
+//! AI Traceability: Turn a behavior found by hand in an interactive session into a reproducible regression scenario
+
+use std::collections::VecDeque;
+
+use crate::runner::CYCLE_MS;
+use crate::scenario::{standard_criteria, DriverKeyframe, TestScenario};
+
+/// Driver input history kept for a recording (ms)
+pub const RECORDING_WINDOW_MS: u32 = 60_000;
+
+/// Rolling record of interactive driver input
+/// 
+/// Always recording, so the trace that led up to an interesting behavior is
+/// already captured by the time the user notices it. Only input changes are
+/// stored - keyboard input is a series of steps, each kept as a hold keyframe
+/// one cycle before the change plus the new value, which linear interpolation
+/// in `TestScenario::driver_input` replays exactly.
+#[derive(Debug, Clone)]
+pub struct InputRecorder {
+    window_ms: u32,
+    keyframes: VecDeque<DriverKeyframe>,
+    current: Option<DriverKeyframe>,
+}
+
+impl InputRecorder {
+    /// Create recorder keeping the last `window_ms` of input
+    pub fn new(window_ms: u32) -> Self {
+        Self { window_ms, keyframes: VecDeque::new(), current: None }
+    }
+    
+    /// Record the driver input for one control cycle
+    pub fn record(&mut self, t_ms: u32, throttle: f32, rpm: f32) {
+        let input = DriverKeyframe { at_ms: t_ms, throttle, rpm };
+        
+        match self.current {
+            Some(current) if current.throttle == throttle && current.rpm == rpm => {}
+            Some(current) => {
+                let hold_ms = t_ms.saturating_sub(CYCLE_MS);
+                if self.keyframes.back().is_some_and(|last| hold_ms > last.at_ms) {
+                    self.keyframes.push_back(DriverKeyframe { at_ms: hold_ms, ..current });
+                }
+                self.keyframes.push_back(input);
+            }
+            None => self.keyframes.push_back(input),
+        }
+        self.current = Some(input);
+        
+        // Keep the keyframe in effect at the start of the window
+        let window_start = t_ms.saturating_sub(self.window_ms);
+        while self.keyframes.len() >= 2 && self.keyframes[1].at_ms <= window_start {
+            self.keyframes.pop_front();
+        }
+    }
+    
+    /// Scenario replaying the recorded window, rebased to start at zero
+    pub fn to_scenario(&self, name: &str) -> Option<TestScenario> {
+        let current = self.current?;
+        let start_ms = current.at_ms.saturating_sub(self.window_ms).max(self.keyframes[0].at_ms);
+        let end_ms = current.at_ms - start_ms;
+        
+        // The keyframe in effect at the window start may predate it
+        let mut keyframes: Vec<DriverKeyframe> = self.keyframes.iter()
+            .map(|keyframe| DriverKeyframe { at_ms: keyframe.at_ms.saturating_sub(start_ms), ..*keyframe })
+            .collect();
+        if keyframes.last().is_some_and(|last| last.at_ms < end_ms) {
+            keyframes.push(DriverKeyframe { at_ms: end_ms, ..current });
+        }
+        
+        Some(TestScenario {
+            name: name.to_string(),
+            description: format!("Recorded interactive session ({:.1} s)", end_ms as f32 / 1000.0),
+            // Runs include the last recorded cycle
+            duration_ms: end_ms + CYCLE_MS,
+            keyframes,
+            criteria: standard_criteria(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Throttle/RPM a keyboard session produced at each cycle
+    fn session_input(t_ms: u32) -> (f32, f32) {
+        match t_ms {
+            0..=990 => (0.2, 2000.0),
+            1000..=2490 => (1.0, 2000.0),
+            _ => (1.0, 3500.0),
+        }
+    }
+    
+    #[test]
+    fn test_recording_replays_step_inputs_exactly() {
+        let mut recorder = InputRecorder::new(RECORDING_WINDOW_MS);
+        for t in (0..=4000).step_by(CYCLE_MS as usize) {
+            let (throttle, rpm) = session_input(t);
+            recorder.record(t, throttle, rpm);
+        }
+        
+        let scenario = recorder.to_scenario("recorded").unwrap();
+        assert!(scenario.keyframes.len() <= 6);
+        for t in (0..=4000).step_by(CYCLE_MS as usize) {
+            assert_eq!(scenario.driver_input(t), session_input(t), "at {} ms", t);
+        }
+    }
+    
+    #[test]
+    fn test_window_keeps_only_recent_input() {
+        let mut recorder = InputRecorder::new(1000);
+        for t in (0..=4000).step_by(CYCLE_MS as usize) {
+            let (throttle, rpm) = session_input(t);
+            recorder.record(t, throttle, rpm);
+        }
+        
+        // Last second of the session: held at (1.0, 3500) throughout
+        let scenario = recorder.to_scenario("recorded").unwrap();
+        assert!(scenario.duration_ms <= 1000 + CYCLE_MS);
+        assert_eq!(scenario.driver_input(0), (1.0, 3500.0));
+        assert_eq!(scenario.keyframes[0].at_ms, 0);
+    }
+    
+    #[test]
+    fn test_nothing_recorded() {
+        assert!(InputRecorder::new(RECORDING_WINDOW_MS).to_scenario("empty").is_none());
+    }
+}
@@ -0,0 +1,137 @@
+//! Scenario Regression Runs
+//! 
+//! 🔗 T4-SIMULATOR-019: Headless Scenario Runner with Pass/Fail Report
+//! Derived From: T4-SIMULATOR-005 (Headless Scenario Execution) + T4-SIMULATOR-006 (Simulate-Then-Apply Config Gate)
+//! AI Traceability: Run scenarios from a script or CI job and fail the job when any criterion breaks
+//! 
+//! A run takes scenario files or built-in scenario names, runs each against a
+//! fresh core, and judges every success criterion. The report records each
+//! criterion's verdict with the value it was judged on, so a regression
+//! suite can follow margins as well as failures.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{CoreError, SystemConfig};
+
+use crate::engine_sim::TurboSize;
+use crate::runner::{ScenarioResult, ScenarioRunner};
+use crate::scenario::{builtin_scenarios, load_scenario, TestScenario};
+
+/// Results of one regression run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    /// Every criterion of every scenario held
+    pub passed: bool,
+    /// Turbo size of the physics engine model, or `None` for the first-order plant
+    pub engine_sim: Option<String>,
+    /// Per-scenario results in the order run
+    pub results: Vec<ScenarioResult>,
+}
+
+impl RunReport {
+    /// Scenarios that failed
+    pub fn failed(&self) -> impl Iterator<Item = &ScenarioResult> {
+        self.results.iter().filter(|r| !r.passed)
+    }
+}
+
+/// Scenario file at `name`, or the scenario of that name in the library, or the built-in one
+/// 
+/// Library scenarios (from `--scenario-dir`) shadow built-ins of the same
+/// name, so a customised template replaces the stock one.
+pub fn resolve_scenario(config: &SystemConfig, library: &[TestScenario], name: &str) -> Result<TestScenario, Box<dyn Error>> {
+    if Path::new(name).exists() {
+        return load_scenario(name);
+    }
+    if let Some(scenario) = library.iter().find(|scenario| scenario.name == name) {
+        return Ok(scenario.clone());
+    }
+    let builtins = builtin_scenarios(config);
+    let mut names: Vec<String> = library.iter().chain(&builtins).map(|scenario| scenario.name.clone()).collect();
+    names.dedup();
+    builtins.into_iter().find(|scenario| scenario.name == name).ok_or_else(|| {
+        format!("No scenario file, library or built-in scenario '{}' (known: {})", name, names.join(", ")).into()
+    })
+}
+
+/// Run every scenario and judge its criteria
+pub fn run_regression(config: &SystemConfig, scenarios: &[TestScenario], turbo: Option<TurboSize>) -> Result<RunReport, CoreError> {
+    config.validate()?;
+    
+    let runner = ScenarioRunner::new(config.clone());
+    let runner = match turbo {
+        Some(turbo) => runner.with_engine_sim(turbo),
+        None => runner,
+    };
+    let results = scenarios.iter().map(|scenario| runner.run(scenario)).collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(RunReport {
+        passed: results.iter().all(|r| r.passed),
+        engine_sim: turbo.map(|turbo| turbo.name().to_string()),
+        results,
+    })
+}
+
+/// Save a report as JSON
+pub fn save_run_report(path: &str, report: &RunReport) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string_pretty(report)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::{save_scenario, wot_pull, SuccessCriterion};
+    
+    #[test]
+    fn test_every_criterion_reported_with_its_measurement() {
+        let config = SystemConfig::default();
+        let report = run_regression(&config, &[wot_pull()], None).unwrap();
+        assert!(report.passed);
+        assert_eq!(report.engine_sim, None);
+        
+        let result = &report.results[0];
+        assert_eq!(result.criteria.len(), wot_pull().criteria.len());
+        assert!(result.criteria.iter().all(|outcome| outcome.passed));
+        assert_eq!(result.criteria[0].criterion, SuccessCriterion::NoOverboost);
+        assert_eq!(result.criteria[0].measured, result.peak_boost_psi);
+    }
+    
+    #[test]
+    fn test_one_broken_criterion_fails_the_run() {
+        let config = SystemConfig::default();
+        let mut strict = wot_pull();
+        strict.name = "strict".to_string();
+        strict.criteria.push(SuccessCriterion::MaxOvershootPsi(-100.0));
+        
+        let report = run_regression(&config, &[wot_pull(), strict], None).unwrap();
+        assert!(!report.passed);
+        let failed: Vec<&str> = report.failed().map(|r| r.name.as_str()).collect();
+        assert_eq!(failed, vec!["strict"]);
+        let outcome = report.results[1].criteria.last().unwrap();
+        assert!(!outcome.passed);
+        assert_eq!(report.results[1].failures.len(), 1);
+    }
+    
+    #[test]
+    fn test_resolve_file_or_builtin_name() {
+        let config = SystemConfig::default();
+        assert_eq!(resolve_scenario(&config, &[], "wot_pull").unwrap(), wot_pull());
+        assert!(resolve_scenario(&config, &[], "launch_control").unwrap_err().to_string().contains("tip_in_tip_out"));
+        
+        let mut custom = wot_pull();
+        custom.duration_ms = 8000;
+        assert_eq!(resolve_scenario(&config, &[custom.clone()], "wot_pull").unwrap(), custom);
+        
+        let path = std::env::temp_dir().join(format!("rumbledome-sim-regression-{}.json", std::process::id()));
+        let mut saved = wot_pull();
+        saved.name = "saved".to_string();
+        save_scenario(&path.to_string_lossy(), &saved).unwrap();
+        assert_eq!(resolve_scenario(&config, &[], &path.to_string_lossy()).unwrap().name, "saved");
+        let _ = fs::remove_file(&path);
+    }
+}
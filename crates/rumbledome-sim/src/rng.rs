@@ -0,0 +1,48 @@
+//! Simulation Random Numbers
+//! 
+//! 🔗 T4-SIMULATOR-021: Seeded Simulation RNG
+//! Derived From: T4-SIMULATOR-007 (Link Impairment) + T4-SIMULATOR-012 (Drive-Cycle Generator)
+//! AI Traceability: One reproducible generator for every seeded simulation feature
+
+/// Seeded xorshift32 generator - identical seeds give identical sequences
+#[derive(Debug, Clone)]
+pub struct SimRng {
+    state: u32,
+}
+
+impl SimRng {
+    /// Create generator from a seed (0 is bumped to 1, which xorshift needs)
+    pub fn new(seed: u32) -> Self {
+        Self { state: seed.max(1) }
+    }
+    
+    /// Uniform random value in 0.0..1.0
+    pub fn next_unit(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        (self.state >> 8) as f32 / (1u32 << 24) as f32
+    }
+    
+    /// Uniform random value in -1.0..1.0
+    pub fn next_signed(&mut self) -> f32 {
+        self.next_unit() * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_seeded_sequence_in_range() {
+        let mut rng = SimRng::new(0);
+        let mut replay = SimRng::new(1);
+        for _ in 0..1000 {
+            let value = rng.next_unit();
+            assert!((0.0..1.0).contains(&value));
+            assert_eq!(replay.next_unit(), value);
+        }
+        assert!((-1.0..1.0).contains(&rng.next_signed()));
+    }
+}
@@ -0,0 +1,369 @@
+//! Headless Scenario Runner
+//! 
+//! 🔗 T4-SIMULATOR-005: Headless Scenario Execution
+//! Derived From: T4-SIMULATOR-003 + T4-SIMULATOR-004
+//! AI Traceability: Closed-loop runs of the production core against the plant model
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::{
+    EventFilter, LearnedDataImport, LogSink, RumbleDomeCore, SystemConfig, SystemInputs, SystemState, CoreError,
+    LEARNED_DATA_CHUNK_BYTES, LEARNED_DATA_SIZE,
+};
+use rumbledome_hal::{MockHal, SolenoidChannel};
+
+use crate::coverage::CoverageTracker;
+use crate::engine_sim::{EngineSim, TurboSize};
+use crate::plant::{BoostPlant, PlantOutputs};
+use crate::scenario::{SuccessCriterion, TestScenario};
+use crate::sensor_robustness::SensorCalibration;
+
+/// Control loop period (100 Hz)
+pub const CYCLE_MS: u32 = 10;
+
+/// CAN ID the simulated ECU torque broadcast is recorded under
+pub(crate) const SIM_TORQUE_MESSAGE_ID: u32 = 0x167;
+
+/// Outcome of one scenario run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioResult {
+    /// Scenario name
+    pub name: String,
+    /// All criteria held
+    pub passed: bool,
+    /// Highest manifold pressure seen (PSI)
+    pub peak_boost_psi: f32,
+    /// Largest boost above target (PSI)
+    pub max_overshoot_psi: f32,
+    /// Boost error sign reversals beyond the oscillation amplitude
+    pub oscillation_reversals: u32,
+    /// Fault descriptions raised by the core
+    pub faults: Vec<String>,
+    /// Every criterion with the value it was judged on
+    pub criteria: Vec<CriterionOutcome>,
+    /// Human-readable failed criteria
+    pub failures: Vec<String>,
+}
+
+/// One success criterion's verdict
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriterionOutcome {
+    /// Criterion judged
+    pub criterion: SuccessCriterion,
+    /// Criterion held
+    pub passed: bool,
+    /// Value it was judged on - peak boost (PSI), faults raised, overshoot (PSI) or error reversals
+    pub measured: f32,
+}
+
+/// Production core closed around the plant model, advanced one control cycle at a time
+/// 
+/// Shared by headless scenario runs and the interactive simulator.
+pub struct SimSession {
+    /// Core under test
+    pub core: RumbleDomeCore<MockHal>,
+    /// Plant model
+    pub plant: BoostPlant,
+    /// Physics engine and turbo model driven instead of `plant` when set
+    pub engine: Option<EngineSim>,
+    /// Manifold pressure sensor error the core reads through
+    pub map_calibration: SensorCalibration,
+    /// Dome supply pressure reported to the core (PSI) - below 5 PSI the core holds boost on the spring
+    pub dome_feed_psi: f32,
+    aggression: f32,
+    duty: f32,
+}
+
+impl SimSession {
+    /// Start an armed core and a plant matching the configuration
+    pub fn new(config: &SystemConfig) -> Result<Self, CoreError> {
+        Self::with_hal(config, MockHal::new())
+    }
+    
+    /// Start on a prepared mock HAL, e.g. one backed by an EEPROM image file
+    pub fn with_hal(config: &SystemConfig, hal: MockHal) -> Result<Self, CoreError> {
+        let mut core = RumbleDomeCore::new(hal, config.clone());
+        core.initialize()?;
+        core.events.subscribe(EventFilter::ALL, Box::new(LogSink))?;
+        
+        // Scenarios exercise boost control, not installation
+        core.skip_commissioning()?;
+        core.arm()?;
+        
+        // Dome air sized to the plant's wastegate span, so the core can hold the gate shut at full boost
+        let plant = BoostPlant::new(config);
+        let dome_feed_psi = plant.max_turbo_psi - plant.spring_pressure;
+        Ok(Self {
+            core,
+            plant,
+            engine: None,
+            map_calibration: SensorCalibration::IDEAL,
+            dome_feed_psi,
+            aggression: config.aggression,
+            duty: 0.0,
+        })
+    }
+    
+    /// Drive the physics engine model instead of the first-order plant
+    pub fn use_engine_sim(&mut self, config: &SystemConfig, turbo: TurboSize) {
+        self.engine = Some(EngineSim::new(config, turbo));
+    }
+    
+    /// Solenoid duty commanded in the last cycle (%)
+    pub fn duty(&self) -> f32 {
+        self.duty
+    }
+    
+    /// Swap in a new configuration between cycles, keeping the plant running
+    /// 
+    /// The core only accepts a new active configuration while not controlling
+    /// boost, so an armed session is briefly taken to idle and re-armed - a
+    /// shortcut only the desktop simulator may take. An invalid configuration
+    /// is rejected and the current one stays in effect.
+    pub fn reconfigure(&mut self, config: SystemConfig) -> Result<(), CoreError> {
+        config.validate()?;
+        
+        let was_armed = self.core.state == SystemState::Armed;
+        if was_armed {
+            self.core.state = SystemState::Idle;
+        }
+        let active = self.core.configurations.active().name.clone();
+        let applied = self.core.save_configuration(&active, config.clone());
+        if was_armed {
+            self.core.arm()?;
+        }
+        applied?;
+        
+        self.plant.spring_pressure = config.spring_pressure;
+        self.plant.redline_rpm = config.rev_limit_taper.redline_rpm as f32;
+        if let Some(engine) = self.engine.as_mut() {
+            engine.spring_pressure = config.spring_pressure;
+            engine.redline_rpm = config.rev_limit_taper.redline_rpm as f32;
+        }
+        self.aggression = config.aggression;
+        Ok(())
+    }
+    
+    /// Load an exported learned-data image into the core between cycles
+    /// 
+    /// Like `reconfigure`, an armed session is taken to idle for the import
+    /// and re-armed. A corrupt or incomplete image is rejected and the
+    /// current map stays in effect.
+    pub fn import_learned_data(&mut self, image: &[u8]) -> Result<(), CoreError> {
+        let was_armed = self.core.state == SystemState::Armed;
+        if was_armed {
+            self.core.state = SystemState::Idle;
+        }
+        let imported = image
+            .chunks(LEARNED_DATA_CHUNK_BYTES)
+            .enumerate()
+            .try_fold(LearnedDataImport::default(), |_, (index, chunk)| {
+                self.core.import_learned_data((index * LEARNED_DATA_CHUNK_BYTES) as u32, chunk)
+            });
+        if was_armed {
+            self.core.arm()?;
+        }
+        
+        let progress = imported?;
+        if progress.received as usize != LEARNED_DATA_SIZE {
+            return Err(CoreError::LearningError(format!(
+                "Learned data image holds {} of the {} bytes expected", image.len(), LEARNED_DATA_SIZE
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Run one control cycle with the given driver input
+    pub fn step(&mut self, throttle: f32, rpm: f32, t_ms: u32) -> Result<PlantOutputs, CoreError> {
+        let outputs = match self.engine.as_mut() {
+            Some(engine) => engine.step(throttle, rpm, self.duty, self.dome_feed_psi, CYCLE_MS as f32),
+            None => self.plant.step(throttle, rpm, self.duty, CYCLE_MS as f32),
+        };
+        let (upper_dome_pressure, lower_dome_pressure) = self.engine.as_ref().map(EngineSim::dome_pressures).unwrap_or((0.0, 0.0));
+        
+        self.core.can_health.record_message(SIM_TORQUE_MESSAGE_ID, t_ms);
+        self.core.execute_control_cycle_with_inputs(SystemInputs {
+            rpm: rpm as u16,
+            desired_torque: outputs.desired_torque,
+            actual_torque: outputs.actual_torque,
+            manifold_pressure: self.map_calibration.reading(outputs.manifold_psi),
+            throttle_position: Some(throttle * 100.0),
+            dome_input_pressure: self.dome_feed_psi,
+            upper_dome_pressure,
+            lower_dome_pressure,
+            intake_air_temp_c: None,
+            coolant_temp_c: None,
+            bank_dome_pressure: None,
+            exhaust_backpressure_psi: None,
+            aggression: self.aggression,
+            scramble_active: false,
+            timestamp_ms: t_ms,
+        })?;
+        self.duty = self.core.hal.get_channel_duty(SolenoidChannel::Primary);
+        
+        Ok(outputs)
+    }
+}
+
+/// Runs scenarios against a fresh core instance per scenario
+pub struct ScenarioRunner {
+    config: SystemConfig,
+    turbo: Option<TurboSize>,
+    learned_data: Option<Vec<u8>>,
+}
+
+impl ScenarioRunner {
+    /// Create runner for a configuration
+    pub fn new(config: SystemConfig) -> Self {
+        Self { config, turbo: None, learned_data: None }
+    }
+    
+    /// Run against the physics engine model with a turbo of this size instead of the first-order plant
+    pub fn with_engine_sim(mut self, turbo: TurboSize) -> Self {
+        self.turbo = Some(turbo);
+        self
+    }
+    
+    /// Start every scenario from an exported learned-data image instead of an empty map
+    pub fn with_learned_data(mut self, image: Vec<u8>) -> Self {
+        self.learned_data = Some(image);
+        self
+    }
+    
+    /// Execute a scenario to completion and evaluate its criteria
+    pub fn run(&self, scenario: &TestScenario) -> Result<ScenarioResult, CoreError> {
+        self.run_with_coverage(scenario, &mut CoverageTracker::new())
+    }
+    
+    /// Execute a scenario, also recording the safety paths it exercises
+    pub fn run_with_coverage(&self, scenario: &TestScenario, coverage: &mut CoverageTracker) -> Result<ScenarioResult, CoreError> {
+        let mut session = SimSession::new(&self.config)?;
+        if let Some(turbo) = self.turbo {
+            session.use_engine_sim(&self.config, turbo);
+        }
+        if let Some(image) = &self.learned_data {
+            session.import_learned_data(image)?;
+        }
+        coverage.begin_scenario(&scenario.name);
+        let oscillation_amplitude = scenario.criteria.iter().find_map(|c| match c {
+            SuccessCriterion::MaxOscillation { amplitude_psi, .. } => Some(*amplitude_psi),
+            _ => None,
+        });
+        
+        let mut peak_boost_psi: f32 = 0.0;
+        let mut max_overshoot_psi: f32 = 0.0;
+        let mut oscillation_reversals = 0;
+        let mut last_error_sign = 0i8;
+        let mut faults: Vec<String> = Vec::new();
+        
+        let mut t_ms = 0;
+        while t_ms < scenario.duration_ms {
+            let (throttle, rpm) = scenario.driver_input(t_ms);
+            let outputs = session.step(throttle, rpm, t_ms)?;
+            coverage.observe(&session.core, rpm as u16, t_ms);
+            
+            if let SystemState::Fault(fault) = &session.core.state {
+                let description = fault.description();
+                if !faults.contains(&description) {
+                    faults.push(description);
+                }
+            }
+            
+            // Boost cannot be held below spring pressure, so measure overshoot from there
+            let target = session.core.last_target_boost_psi.max(session.plant.spring_pressure);
+            let error = outputs.manifold_psi - target;
+            peak_boost_psi = peak_boost_psi.max(outputs.manifold_psi);
+            max_overshoot_psi = max_overshoot_psi.max(error);
+            
+            if let Some(amplitude) = oscillation_amplitude {
+                let sign = if error > amplitude { 1 } else if error < -amplitude { -1 } else { 0 };
+                if sign != 0 {
+                    if last_error_sign != 0 && sign != last_error_sign {
+                        oscillation_reversals += 1;
+                    }
+                    last_error_sign = sign;
+                }
+            }
+            
+            t_ms += CYCLE_MS;
+        }
+        
+        let mut criteria = Vec::new();
+        let mut failures = Vec::new();
+        for criterion in &scenario.criteria {
+            let (measured, failure) = match criterion {
+                SuccessCriterion::NoOverboost => (peak_boost_psi, (peak_boost_psi >= self.config.overboost_limit).then(|| format!(
+                    "overboost: peak {:.1} PSI reached limit {:.1} PSI", peak_boost_psi, self.config.overboost_limit
+                ))),
+                SuccessCriterion::NoFault => (faults.len() as f32, (!faults.is_empty()).then(|| format!("fault: {}", faults.join("; ")))),
+                SuccessCriterion::MaxOvershootPsi(limit) => (max_overshoot_psi, (max_overshoot_psi > *limit).then(|| format!(
+                    "overshoot: {:.1} PSI exceeds {:.1} PSI", max_overshoot_psi, limit
+                ))),
+                SuccessCriterion::MaxOscillation { max_reversals, .. } => (
+                    oscillation_reversals as f32,
+                    (oscillation_reversals > *max_reversals).then(|| format!(
+                        "instability: {} boost error reversals (max {})", oscillation_reversals, max_reversals
+                    )),
+                ),
+            };
+            criteria.push(CriterionOutcome { criterion: criterion.clone(), passed: failure.is_none(), measured });
+            failures.extend(failure);
+        }
+        
+        Ok(ScenarioResult {
+            name: scenario.name.clone(),
+            passed: failures.is_empty(),
+            peak_boost_psi,
+            max_overshoot_psi,
+            oscillation_reversals,
+            faults,
+            criteria,
+            failures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_hal::{MockStorage, NonVolatileStorage, StorageRegion, ERASED_BYTE};
+    use crate::scenario::wot_pull;
+    
+    #[test]
+    fn test_default_plant_builds_boost() {
+        // The first-order plant, not the engine model - the dome feed alone must give the core authority
+        let config = SystemConfig { aggression: 1.0, ..SystemConfig::default() };
+        let result = ScenarioRunner::new(config.clone()).run(&wot_pull()).unwrap();
+        assert!(result.peak_boost_psi > config.spring_pressure + 2.0, "peak {} PSI", result.peak_boost_psi);
+    }
+    
+    #[test]
+    fn test_learned_data_persists_in_storage_file() {
+        let path = std::env::temp_dir().join(format!("rumbledome-sim-learning-{}.bin", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let config = SystemConfig { aggression: 1.0, ..SystemConfig::default() };
+        
+        let mut session = SimSession::with_hal(&config, MockHal::with_storage(MockStorage::open(&path).unwrap())).unwrap();
+        let scenario = wot_pull();
+        let mut t_ms = 0;
+        while t_ms < scenario.duration_ms {
+            let (throttle, rpm) = scenario.driver_input(t_ms);
+            session.step(throttle, rpm, t_ms).unwrap();
+            t_ms += CYCLE_MS;
+        }
+        assert!(session.core.learned_data.status().cells_learned > 0);
+        session.core.learned_data.save_to_storage(&mut session.core.hal, t_ms).unwrap();
+        let learned = session.core.learned_data.to_bytes();
+        drop(session);
+        
+        // The next run starts from what the last one learned
+        let restarted = SimSession::with_hal(&config, MockHal::with_storage(MockStorage::open(&path).unwrap())).unwrap();
+        assert_eq!(restarted.core.learned_data.to_bytes(), learned);
+        
+        let mut image = MockStorage::open(&path).unwrap();
+        let mut header = [0; 1];
+        image.read(StorageRegion::LEARNED_DATA.offset, &mut header).unwrap();
+        assert_ne!(header[0], ERASED_BYTE);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,380 @@
+//! Test Scenarios
+//! 
+//! 🔗 T4-SIMULATOR-004: Scenario Definitions
+//! Derived From: T2-SIM-001 + SimulationRequirements.md interactive test scenarios
+//! AI Traceability: Reproducible driver input traces with pass/fail criteria
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use rumbledome_core::SystemConfig;
+
+/// Driver input at a point in time
+/// 
+/// Inputs are linearly interpolated between keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DriverKeyframe {
+    /// Time from scenario start (ms)
+    pub at_ms: u32,
+    /// Throttle position (0.0-1.0)
+    pub throttle: f32,
+    /// Engine RPM
+    pub rpm: f32,
+}
+
+/// Pass/fail criterion evaluated over a scenario run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SuccessCriterion {
+    /// Manifold pressure never reaches the configured overboost limit
+    NoOverboost,
+    /// Core never enters a fault state
+    NoFault,
+    /// Boost never exceeds target by more than this (PSI)
+    MaxOvershootPsi(f32),
+    /// Boost error changes sign (beyond `amplitude_psi`) at most `max_reversals` times
+    MaxOscillation { amplitude_psi: f32, max_reversals: u32 },
+}
+
+/// Scripted simulation scenario
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestScenario {
+    /// Short identifier
+    pub name: String,
+    /// What the scenario exercises
+    pub description: String,
+    /// Total run time (ms)
+    pub duration_ms: u32,
+    /// Driver input trace (sorted by time)
+    pub keyframes: Vec<DriverKeyframe>,
+    /// Criteria that must all hold for the scenario to pass
+    pub criteria: Vec<SuccessCriterion>,
+}
+
+impl TestScenario {
+    /// Check the scenario can be run, naming the first field that is wrong
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is empty".to_string());
+        }
+        if self.duration_ms == 0 {
+            return Err("duration_ms must be above 0".to_string());
+        }
+        if self.keyframes.is_empty() {
+            return Err("keyframes is empty - a scenario needs at least one driver input".to_string());
+        }
+        
+        for (i, frame) in self.keyframes.iter().enumerate() {
+            if !(0.0..=1.0).contains(&frame.throttle) {
+                return Err(format!("keyframes[{}].throttle is {} - throttle is a fraction, 0.0-1.0", i, frame.throttle));
+            }
+            if !(0.0..=MAX_SCENARIO_RPM).contains(&frame.rpm) {
+                return Err(format!("keyframes[{}].rpm is {} - must be 0-{}", i, frame.rpm, MAX_SCENARIO_RPM));
+            }
+            if i > 0 && frame.at_ms < self.keyframes[i - 1].at_ms {
+                return Err(format!("keyframes[{}].at_ms is {} ms, before the keyframe ahead of it ({} ms) - keyframes must be in time order",
+                    i, frame.at_ms, self.keyframes[i - 1].at_ms));
+            }
+            if frame.at_ms > self.duration_ms {
+                return Err(format!("keyframes[{}].at_ms is {} ms, past the end of the scenario ({} ms)", i, frame.at_ms, self.duration_ms));
+            }
+        }
+        
+        for (i, criterion) in self.criteria.iter().enumerate() {
+            match criterion {
+                SuccessCriterion::MaxOvershootPsi(psi) if !(psi.is_finite() && *psi >= 0.0) => {
+                    return Err(format!("criteria[{}]: MaxOvershootPsi must be 0 or more, got {}", i, psi));
+                }
+                SuccessCriterion::MaxOscillation { amplitude_psi, .. } if !(amplitude_psi.is_finite() && *amplitude_psi > 0.0) => {
+                    return Err(format!("criteria[{}]: MaxOscillation amplitude_psi must be above 0, got {}", i, amplitude_psi));
+                }
+                _ => {}
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Driver input (throttle, rpm) at a given time
+    pub fn driver_input(&self, t_ms: u32) -> (f32, f32) {
+        let Some(first) = self.keyframes.first() else {
+            return (0.0, 0.0);
+        };
+        if t_ms <= first.at_ms {
+            return (first.throttle, first.rpm);
+        }
+        
+        for pair in self.keyframes.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if t_ms <= b.at_ms {
+                let span = (b.at_ms - a.at_ms).max(1) as f32;
+                let f = (t_ms - a.at_ms) as f32 / span;
+                return (a.throttle + (b.throttle - a.throttle) * f, a.rpm + (b.rpm - a.rpm) * f);
+            }
+        }
+        
+        let last = self.keyframes[self.keyframes.len() - 1];
+        (last.throttle, last.rpm)
+    }
+}
+
+/// Highest engine speed a scenario may script (RPM)
+pub const MAX_SCENARIO_RPM: f32 = 10_000.0;
+
+/// Scenario file format, chosen by extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioFormat {
+    Json,
+    Yaml,
+}
+
+impl ScenarioFormat {
+    /// Format of a file by its extension (`.yaml`/`.yml` or `.json`)
+    pub fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ScenarioFormat::Json),
+            "yaml" | "yml" => Some(ScenarioFormat::Yaml),
+            _ => None,
+        }
+    }
+    
+    /// File extension written for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ScenarioFormat::Json => "json",
+            ScenarioFormat::Yaml => "yaml",
+        }
+    }
+}
+
+impl FromStr for ScenarioFormat {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ScenarioFormat::Json),
+            "yaml" => Ok(ScenarioFormat::Yaml),
+            other => Err(format!("unknown scenario format '{}' (json or yaml)", other)),
+        }
+    }
+}
+
+fn keyframe(at_ms: u32, throttle: f32, rpm: f32) -> DriverKeyframe {
+    DriverKeyframe { at_ms, throttle, rpm }
+}
+
+/// Criteria every built-in and recorded scenario is held to
+pub fn standard_criteria() -> Vec<SuccessCriterion> {
+    vec![
+        SuccessCriterion::NoOverboost,
+        SuccessCriterion::NoFault,
+        SuccessCriterion::MaxOvershootPsi(2.0),
+        SuccessCriterion::MaxOscillation { amplitude_psi: 0.75, max_reversals: 6 },
+    ]
+}
+
+/// Full-throttle pull from low RPM to redline
+pub fn wot_pull() -> TestScenario {
+    TestScenario {
+        name: "wot_pull".to_string(),
+        description: "Full throttle 2000-6500 RPM in third gear".to_string(),
+        duration_ms: 6000,
+        keyframes: vec![
+            keyframe(0, 0.2, 2000.0),
+            keyframe(300, 1.0, 2100.0),
+            keyframe(5500, 1.0, 6500.0),
+            keyframe(6000, 0.0, 6000.0),
+        ],
+        criteria: standard_criteria(),
+    }
+}
+
+/// Repeated tip-in/tip-out at mid RPM
+pub fn tip_in_tip_out() -> TestScenario {
+    TestScenario {
+        name: "tip_in_tip_out".to_string(),
+        description: "Throttle stabs between 20% and 90% at 4000 RPM".to_string(),
+        duration_ms: 6000,
+        keyframes: vec![
+            keyframe(0, 0.2, 4000.0),
+            keyframe(500, 0.9, 4000.0),
+            keyframe(2000, 0.9, 4200.0),
+            keyframe(2300, 0.2, 4000.0),
+            keyframe(3500, 0.2, 4000.0),
+            keyframe(3800, 0.9, 4000.0),
+            keyframe(5500, 0.9, 4300.0),
+            keyframe(6000, 0.2, 4000.0),
+        ],
+        criteria: standard_criteria(),
+    }
+}
+
+/// Steady part-throttle cruise in the spool region
+pub fn part_throttle_hold() -> TestScenario {
+    TestScenario {
+        name: "part_throttle_hold".to_string(),
+        description: "Steady 50% throttle at 3000 RPM".to_string(),
+        duration_ms: 5000,
+        keyframes: vec![
+            keyframe(0, 0.5, 3000.0),
+            keyframe(5000, 0.5, 3000.0),
+        ],
+        criteria: standard_criteria(),
+    }
+}
+
+/// Full throttle bouncing off the ECU rev limiter
+pub fn rev_limiter_bounce(redline_rpm: f32) -> TestScenario {
+    let mut keyframes = vec![
+        keyframe(0, 1.0, redline_rpm - 2500.0),
+        keyframe(2500, 1.0, redline_rpm),
+    ];
+    
+    // Limiter cut drops RPM, combustion resumes, RPM climbs back into the cut
+    for bounce in 0..6 {
+        let t = 2500 + bounce * 400;
+        keyframes.push(keyframe(t + 200, 1.0, redline_rpm - 150.0));
+        keyframes.push(keyframe(t + 400, 1.0, redline_rpm));
+    }
+    keyframes.push(keyframe(5400, 0.0, redline_rpm - 500.0));
+    
+    TestScenario {
+        name: "rev_limiter_bounce".to_string(),
+        description: "Full throttle held against the rev limiter".to_string(),
+        duration_ms: 6000,
+        keyframes,
+        criteria: standard_criteria(),
+    }
+}
+
+/// Load and validate a scenario saved as JSON or YAML (`.yaml`/`.yml`)
+pub fn load_scenario(path: &str) -> Result<TestScenario, Box<dyn Error>> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let scenario: TestScenario = match ScenarioFormat::of(Path::new(path)) {
+        Some(ScenarioFormat::Yaml) => serde_yaml::from_str(&text).map_err(|e| format!("{}: {}", path, e))?,
+        _ => serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))?,
+    };
+    scenario.validate().map_err(|e| format!("{}: scenario '{}': {}", path, scenario.name, e))?;
+    Ok(scenario)
+}
+
+/// Save a scenario as YAML for a `.yaml`/`.yml` path, otherwise as JSON
+pub fn save_scenario(path: &str, scenario: &TestScenario) -> Result<(), Box<dyn Error>> {
+    let text = match ScenarioFormat::of(Path::new(path)) {
+        Some(ScenarioFormat::Yaml) => serde_yaml::to_string(scenario)?,
+        _ => serde_json::to_string_pretty(scenario)?,
+    };
+    fs::write(path, text)?;
+    Ok(())
+}
+
+/// Load every scenario file (`*.json`, `*.yaml`, `*.yml`) in a directory, in file name order
+/// 
+/// Any file that fails to parse or validate fails the whole library, so a
+/// typo never silently drops a scenario from a suite.
+pub fn load_scenario_dir(dir: &str) -> Result<Vec<TestScenario>, Box<dyn Error>> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("Scenario directory {}: {}", dir, e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| ScenarioFormat::of(path).is_some())
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| load_scenario(&path.to_string_lossy())).collect()
+}
+
+/// Write the built-in scenarios into a directory as editable templates, returning the paths written
+pub fn save_templates(dir: &str, config: &SystemConfig, format: ScenarioFormat) -> Result<Vec<String>, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for scenario in builtin_scenarios(config) {
+        let path = Path::new(dir).join(format!("{}.{}", scenario.name, format.extension()));
+        let path = path.to_string_lossy().into_owned();
+        save_scenario(&path, &scenario)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Built-in scenario set for a configuration
+pub fn builtin_scenarios(config: &SystemConfig) -> Vec<TestScenario> {
+    vec![
+        wot_pull(),
+        tip_in_tip_out(),
+        part_throttle_hold(),
+        rev_limiter_bounce(config.rev_limit_taper.redline_rpm as f32),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_driver_input_interpolation() {
+        let scenario = wot_pull();
+        assert_eq!(scenario.driver_input(0), (0.2, 2000.0));
+        
+        let (throttle, rpm) = scenario.driver_input(150);
+        assert!((throttle - 0.6).abs() < 1e-4);
+        assert!((rpm - 2050.0).abs() < 1e-2);
+        
+        // Holds last keyframe after the trace ends
+        assert_eq!(scenario.driver_input(10_000), (0.0, 6000.0));
+    }
+    
+    #[test]
+    fn test_rev_limiter_bounce_hits_redline_repeatedly() {
+        let scenario = rev_limiter_bounce(7000.0);
+        let hits = (0..scenario.duration_ms)
+            .step_by(10)
+            .filter(|t| scenario.driver_input(*t).1 >= 7000.0)
+            .count();
+        
+        assert!(hits >= 6);
+    }
+    
+    #[test]
+    fn test_builtin_scenarios_validate() {
+        for scenario in builtin_scenarios(&SystemConfig::default()) {
+            assert_eq!(scenario.validate(), Ok(()), "{}", scenario.name);
+        }
+    }
+    
+    #[test]
+    fn test_validation_names_the_field() {
+        let mut scenario = wot_pull();
+        scenario.keyframes[2].throttle = 100.0;
+        assert!(scenario.validate().unwrap_err().starts_with("keyframes[2].throttle"));
+        
+        let mut scenario = wot_pull();
+        scenario.keyframes.swap(1, 2);
+        assert!(scenario.validate().unwrap_err().contains("time order"));
+        
+        let mut scenario = wot_pull();
+        scenario.criteria.push(SuccessCriterion::MaxOscillation { amplitude_psi: 0.0, max_reversals: 2 });
+        assert!(scenario.validate().unwrap_err().starts_with("criteria[4]"));
+    }
+    
+    #[test]
+    fn test_templates_round_trip_through_a_library() {
+        let dir = std::env::temp_dir().join(format!("rumbledome-sim-templates-{}", std::process::id()));
+        let dir = dir.to_string_lossy().into_owned();
+        let config = SystemConfig::default();
+        
+        let written = save_templates(&dir, &config, ScenarioFormat::Yaml).unwrap();
+        assert!(written.iter().all(|path| path.ends_with(".yaml")));
+        save_scenario(&Path::new(&dir).join("zz_custom.json").to_string_lossy(), &part_throttle_hold()).unwrap();
+        
+        let library = load_scenario_dir(&dir).unwrap();
+        assert_eq!(library.len(), builtin_scenarios(&config).len() + 1);
+        assert!(library.contains(&wot_pull()));
+        
+        // A broken file is reported by path, not skipped
+        fs::write(Path::new(&dir).join("broken.yml"), "name: broken\nduration_ms: soon\n").unwrap();
+        assert!(load_scenario_dir(&dir).unwrap_err().to_string().contains("broken.yml"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
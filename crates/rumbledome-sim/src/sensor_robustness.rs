@@ -0,0 +1,283 @@
+//! Sensor Calibration Robustness Study
+//! 
+//! 🔗 T4-SIMULATOR-016: Calibration Error Sweep
+//! Derived From: T4-SIMULATOR-005 (Headless Scenario Execution) + Hardware.md pressure sensor accuracy
+//! AI Traceability: Tell a user whether a cheap manifold pressure sensor is good enough for the boost they want to run
+//! 
+//! The controller only ever sees the sensor reading. A sensor that reads low
+//! makes it push real boost above the target - and above the overboost limit,
+//! which is enforced on the reading too. The study repeats the built-in
+//! scenarios with the manifold pressure sensor's offset and gain perturbed
+//! within the spec tolerance and compares true boost against the ideal-sensor
+//! run.
+
+use std::fmt;
+
+use rumbledome_core::{CoreError, SystemConfig, SystemState};
+
+use crate::rng::SimRng;
+use crate::runner::{SimSession, CYCLE_MS};
+use crate::scenario::{builtin_scenarios, TestScenario};
+
+/// Offset tolerance of the reference sensor - ±2% of a 30 PSI span (PSI)
+pub const SPEC_OFFSET_TOLERANCE_PSI: f32 = 0.6;
+
+/// Gain tolerance of the reference sensor (fraction)
+pub const SPEC_GAIN_TOLERANCE: f32 = 0.02;
+
+/// Manifold pressure sensor calibration error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorCalibration {
+    /// Added to the reading (PSI)
+    pub offset_psi: f32,
+    /// Reading scale (1.0 is exact)
+    pub gain: f32,
+}
+
+impl SensorCalibration {
+    /// A perfectly calibrated sensor
+    pub const IDEAL: Self = Self { offset_psi: 0.0, gain: 1.0 };
+    
+    /// Reading for a true pressure (PSI)
+    pub fn reading(&self, true_psi: f32) -> f32 {
+        true_psi * self.gain + self.offset_psi
+    }
+}
+
+impl Default for SensorCalibration {
+    fn default() -> Self {
+        Self::IDEAL
+    }
+}
+
+/// Study settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationStudyConfig {
+    /// Perturbed runs - the first four are the tolerance corners, the rest random within it
+    pub runs: u32,
+    /// Largest offset error (± PSI)
+    pub offset_tolerance_psi: f32,
+    /// Largest gain error (± fraction)
+    pub gain_tolerance: f32,
+    /// Random seed - identical settings and seed perturb identically
+    pub seed: u32,
+}
+
+impl Default for CalibrationStudyConfig {
+    fn default() -> Self {
+        Self {
+            runs: 12,
+            offset_tolerance_psi: SPEC_OFFSET_TOLERANCE_PSI,
+            gain_tolerance: SPEC_GAIN_TOLERANCE,
+            seed: 1,
+        }
+    }
+}
+
+/// Built-in scenarios run with one calibration error
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationRun {
+    pub calibration: SensorCalibration,
+    /// RMS of true boost minus target while boost is being controlled (PSI)
+    pub tracking_rms_psi: f32,
+    /// Highest true manifold pressure (PSI)
+    pub peak_boost_psi: f32,
+    /// Overboost limit minus the highest true pressure - negative is a real overboost (PSI)
+    pub overboost_margin_psi: f32,
+    /// Overboost cuts tripped - a sensor reading high trips them early
+    pub overboost_cuts: u32,
+    /// Scenarios that ended up in a fault state
+    pub faulted_scenarios: Vec<String>,
+}
+
+impl CalibrationRun {
+    /// True boost reached the overboost limit
+    pub fn overboosted(&self) -> bool {
+        self.overboost_margin_psi <= 0.0
+    }
+}
+
+/// Ideal-sensor baseline and the perturbed runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationStudy {
+    pub settings: CalibrationStudyConfig,
+    pub ideal: CalibrationRun,
+    pub runs: Vec<CalibrationRun>,
+}
+
+impl CalibrationStudy {
+    /// Largest increase in tracking error over the ideal sensor (PSI)
+    pub fn worst_tracking_degradation_psi(&self) -> f32 {
+        self.runs
+            .iter()
+            .map(|run| run.tracking_rms_psi - self.ideal.tracking_rms_psi)
+            .fold(0.0, f32::max)
+    }
+    
+    /// Smallest margin to the overboost limit in any run (PSI)
+    pub fn min_overboost_margin_psi(&self) -> f32 {
+        self.runs
+            .iter()
+            .map(|run| run.overboost_margin_psi)
+            .fold(self.ideal.overboost_margin_psi, f32::min)
+    }
+    
+    /// No run overboosted or faulted where the ideal sensor did not
+    /// 
+    /// Extra cuts from a sensor reading high are a nuisance, not a hazard.
+    pub fn sensors_adequate(&self) -> bool {
+        self.runs.iter().all(|run| {
+            (!run.overboosted() || self.ideal.overboosted())
+                && run.faulted_scenarios.iter().all(|name| self.ideal.faulted_scenarios.contains(name))
+        })
+    }
+}
+
+/// Calibration errors to try: the four tolerance corners, then uniform random picks
+fn perturbations(settings: &CalibrationStudyConfig) -> Vec<SensorCalibration> {
+    let mut rng = SimRng::new(settings.seed);
+    
+    let (offset, gain) = (settings.offset_tolerance_psi, settings.gain_tolerance);
+    let corners = [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)];
+    (0..settings.runs as usize)
+        .map(|run| {
+            let (offset_sign, gain_sign) = corners.get(run).copied().unwrap_or_else(|| (rng.next_signed(), rng.next_signed()));
+            SensorCalibration { offset_psi: offset * offset_sign, gain: 1.0 + gain * gain_sign }
+        })
+        .collect()
+}
+
+/// Run the scenarios with a manifold pressure sensor that reads with the given error
+pub fn calibration_run(config: &SystemConfig, scenarios: &[TestScenario], calibration: SensorCalibration) -> Result<CalibrationRun, CoreError> {
+    let mut squared_error_sum = 0.0;
+    let mut tracked_cycles = 0u32;
+    let mut peak_boost_psi: f32 = 0.0;
+    let mut faulted_scenarios = Vec::new();
+    let mut overboost_cuts = 0;
+    
+    for scenario in scenarios {
+        let mut session = SimSession::new(config)?;
+        session.map_calibration = calibration;
+        
+        let mut t_ms = 0;
+        while t_ms < scenario.duration_ms {
+            let (throttle, rpm) = scenario.driver_input(t_ms);
+            let was_cut = session.core.state == SystemState::OverboostCut;
+            let outputs = session.step(throttle, rpm, t_ms)?;
+            if !was_cut && session.core.state == SystemState::OverboostCut {
+                overboost_cuts += 1;
+            }
+            peak_boost_psi = peak_boost_psi.max(outputs.manifold_psi);
+            
+            // Below spring pressure the wastegate cannot hold boost down, so there is nothing to track
+            let target = session.core.last_target_boost_psi;
+            if session.core.state == SystemState::Armed && target > session.plant.spring_pressure {
+                squared_error_sum += (outputs.manifold_psi - target).powi(2);
+                tracked_cycles += 1;
+            }
+            t_ms += CYCLE_MS;
+        }
+        
+        if matches!(session.core.state, SystemState::Fault(_)) {
+            faulted_scenarios.push(scenario.name.clone());
+        }
+    }
+    
+    Ok(CalibrationRun {
+        calibration,
+        tracking_rms_psi: (squared_error_sum / tracked_cycles.max(1) as f32).sqrt(),
+        peak_boost_psi,
+        overboost_margin_psi: config.overboost_limit - peak_boost_psi,
+        overboost_cuts,
+        faulted_scenarios,
+    })
+}
+
+/// Compare the built-in scenarios with an ideal sensor against sensors within the given tolerance
+pub fn calibration_study(config: &SystemConfig, settings: &CalibrationStudyConfig) -> Result<CalibrationStudy, CoreError> {
+    let scenarios = builtin_scenarios(config);
+    let ideal = calibration_run(config, &scenarios, SensorCalibration::IDEAL)?;
+    let runs = perturbations(settings)
+        .into_iter()
+        .map(|calibration| calibration_run(config, &scenarios, calibration))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(CalibrationStudy { settings: settings.clone(), ideal, runs })
+}
+
+impl fmt::Display for CalibrationStudy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Sensor calibration study: offset ±{:.2} PSI, gain ±{:.1}%, {} runs",
+            self.settings.offset_tolerance_psi, self.settings.gain_tolerance * 100.0, self.runs.len())?;
+        writeln!(f, "\n  offset    gain  tracking    peak  margin  cuts  (PSI)")?;
+        for (label, run) in std::iter::once(("ideal", &self.ideal)).chain(self.runs.iter().map(|run| ("", run))) {
+            writeln!(f, "  {:>+6.2}  {:>5.1}%  {:>8.2}  {:>6.2}  {:>6.2}  {:>4}  {:<5} {}",
+                run.calibration.offset_psi, (run.calibration.gain - 1.0) * 100.0, run.tracking_rms_psi,
+                run.peak_boost_psi, run.overboost_margin_psi, run.overboost_cuts, label,
+                if run.overboosted() { "OVERBOOST" } else { "" })?;
+        }
+        
+        writeln!(f)?;
+        writeln!(f, "Worst tracking degradation {:.2} PSI RMS, smallest overboost margin {:.2} PSI",
+            self.worst_tracking_degradation_psi(), self.min_overboost_margin_psi())?;
+        if self.ideal.overboosted() {
+            writeln!(f, "Boost reaches the overboost limit even with an ideal sensor - fix the configuration first")?;
+        }
+        if self.sensors_adequate() {
+            writeln!(f, "Sensors within this tolerance are adequate for this configuration")
+        } else {
+            writeln!(f, "Sensors within this tolerance are NOT adequate - lower the boost target or use a more accurate sensor")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_perturbations_cover_corners_then_stay_in_tolerance() {
+        let settings = CalibrationStudyConfig::default();
+        let calibrations = perturbations(&settings);
+        assert_eq!(calibrations.len(), 12);
+        assert_eq!(calibrations[0], SensorCalibration { offset_psi: -0.6, gain: 0.98 });
+        assert_eq!(calibrations[3], SensorCalibration { offset_psi: 0.6, gain: 1.02 });
+        assert!(calibrations.iter().all(|c| c.offset_psi.abs() <= 0.6 && (c.gain - 1.0).abs() <= 0.02 + 1e-6));
+        assert_eq!(calibrations, perturbations(&settings));
+    }
+    
+    #[test]
+    fn test_low_reading_sensor_runs_real_boost_high() {
+        let config = SystemConfig { aggression: 1.0, overboost_limit: 15.5, ..SystemConfig::default() };
+        let scenarios = builtin_scenarios(&config);
+        let ideal = calibration_run(&config, &scenarios, SensorCalibration::IDEAL).unwrap();
+        let low = calibration_run(&config, &scenarios, SensorCalibration { offset_psi: -1.0, gain: 0.95 }).unwrap();
+        let high = calibration_run(&config, &scenarios, SensorCalibration { offset_psi: 1.0, gain: 1.05 }).unwrap();
+        
+        // The overboost cut acts on the reading, so it trips late
+        assert!(!ideal.overboosted());
+        assert!(low.peak_boost_psi > ideal.peak_boost_psi + 0.5);
+        assert!(low.overboosted());
+        
+        // Reading high trips the cut early, holding real boost under the target
+        assert!(high.overboost_cuts > ideal.overboost_cuts);
+        assert!(high.peak_boost_psi < ideal.peak_boost_psi - 0.5);
+        assert!(!high.overboosted());
+    }
+    
+    #[test]
+    fn test_study_judges_sensor_tolerance() {
+        let config = SystemConfig { aggression: 1.0, overboost_limit: 15.5, ..SystemConfig::default() };
+        let spec = CalibrationStudyConfig { runs: 4, ..CalibrationStudyConfig::default() };
+        let study = calibration_study(&config, &spec).unwrap();
+        assert_eq!(study.runs.len(), 4);
+        assert!(study.sensors_adequate());
+        assert!(study.min_overboost_margin_psi() > 0.0);
+        
+        let cheap = CalibrationStudyConfig { offset_tolerance_psi: 1.0, gain_tolerance: 0.05, ..spec };
+        let study = calibration_study(&config, &cheap).unwrap();
+        assert!(!study.sensors_adequate());
+        assert!(study.runs.iter().any(|run| run.overboost_cuts > study.ideal.overboost_cuts));
+        assert!(study.to_string().contains("NOT adequate"));
+    }
+}
@@ -0,0 +1,1011 @@
+//! Virtual Device Protocol Endpoint
+//! 
+//! 🔗 T4-SIMULATOR-008: Virtual Device Endpoint
+//! Derived From: T4-SIMULATOR-007 + T4-PROTOCOL-002/003/004/006
+//! AI Traceability: Production core behind the real protocol stack, reachable over a simulated link
+
+use std::sync::{Arc, Mutex};
+
+use log::warn;
+use rumbledome_core::{
+    ChannelSet, CoreError, ErrorCode, RumbleDomeCore, ScreenDescription, SystemConfig, TelemetryFrame, TelemetrySample, TelemetrySink,
+    WatchList, CONFIRMATION_TIMEOUT_MS, SPRING_ONLY_PROFILE,
+};
+use rumbledome_hal::{CanInterface, ConsolePort, HalResult, MockHal};
+use rumbledome_protocol::{
+    encode_frame, BandwidthArbiter, FrameDecoder, FramingStats, ProtocolError, ProtocolMessage, ReplayCheck, ReplayGuard,
+    RequestSequencer, RequestTracker, RetryAction, RetryPolicy, RetryStats, SessionRole, TelemetryThrottle,
+    MAX_REQUEST_BYTES, MAX_RESPONSE_BYTES, PROTOCOL_VERSION,
+};
+
+use crate::link::{ImpairedLink, LinkImpairment};
+
+/// Name the protocol stream is registered under in the core's telemetry sinks
+pub const PROTOCOL_STREAM_SINK: &str = "protocol_stream";
+
+/// Protocol telemetry stream as a core telemetry sink
+/// 
+/// Takes every cycle's sample; each session's throttle then picks from the
+/// latest one at its own rate.
+struct ProtocolStreamSink(Arc<Mutex<Option<TelemetrySample>>>);
+
+impl TelemetrySink for ProtocolStreamSink {
+    fn write(&mut self, frame: &TelemetryFrame, _bus: &mut dyn CanInterface) -> HalResult<()> {
+        if let Ok(mut latest) = self.0.lock() {
+            *latest = Some(frame.sample.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Per-session protocol state
+pub struct DeviceSession {
+    authenticated: bool,
+    decoder: FrameDecoder,
+    screen_stream: Option<TelemetryThrottle>,
+    telemetry_stream: Option<TelemetryThrottle>,
+    watches: WatchList,
+    replay: ReplayGuard,
+    held: Option<ProtocolMessage>,
+    refused: u32,
+}
+
+impl DeviceSession {
+    fn new() -> Self {
+        Self {
+            authenticated: false,
+            decoder: FrameDecoder::new(MAX_REQUEST_BYTES),
+            screen_stream: None,
+            telemetry_stream: None,
+            watches: WatchList::new(),
+            replay: ReplayGuard::default(),
+            held: None,
+            refused: 0,
+        }
+    }
+    
+    /// Whether the client has confirmed an `Authenticate` request with the presence code
+    pub fn authenticated(&self) -> bool {
+        self.authenticated
+    }
+    
+    /// Request framing statistics
+    pub fn framing_stats(&self) -> &FramingStats {
+        self.decoder.stats()
+    }
+    
+    /// Sequenced request replay protection
+    pub fn replay_guard(&self) -> &ReplayGuard {
+        &self.replay
+    }
+    
+    /// Active display mirroring stream, if subscribed
+    pub fn screen_stream(&self) -> Option<&TelemetryThrottle> {
+        self.screen_stream.as_ref()
+    }
+    
+    /// Active telemetry stream, if subscribed
+    pub fn telemetry_stream(&self) -> Option<&TelemetryThrottle> {
+        self.telemetry_stream.as_ref()
+    }
+    
+    /// Internal variables appended to this session's screen frames
+    pub fn watches(&self) -> &WatchList {
+        &self.watches
+    }
+    
+    /// Protected request waiting for its presence code
+    pub fn held(&self) -> Option<&ProtocolMessage> {
+        self.held.as_ref()
+    }
+    
+    /// Requests refused for lack of privilege
+    pub fn refused(&self) -> u32 {
+        self.refused
+    }
+}
+
+/// Device end of the protocol: request handling and telemetry streaming
+/// 
+/// Models the firmware comms task - one session on USB and one on Bluetooth
+/// served side by side, sharing one outgoing byte budget. USB has full access;
+/// Bluetooth is read-only until the client authenticates.
+pub struct VirtualDevice {
+    core: RumbleDomeCore<MockHal>,
+    sessions: [DeviceSession; 2],
+    arbiter: BandwidthArbiter,
+    latest_sample: Arc<Mutex<Option<TelemetrySample>>>,
+}
+
+impl VirtualDevice {
+    /// Create and initialize a virtual device
+    pub fn new(config: SystemConfig) -> Result<Self, CoreError> {
+        let mut core = RumbleDomeCore::new(MockHal::new(), config);
+        core.initialize()?;
+        
+        let latest_sample = Arc::new(Mutex::new(None));
+        let sink = Box::new(ProtocolStreamSink(Arc::clone(&latest_sample)));
+        core.telemetry_sinks.register(PROTOCOL_STREAM_SINK, 0, ChannelSet::ALL, sink)?;
+        
+        Ok(Self {
+            core,
+            sessions: ConsolePort::ALL.map(|_| DeviceSession::new()),
+            arbiter: BandwidthArbiter::default(),
+            latest_sample,
+        })
+    }
+    
+    /// Sample the core last published to the protocol stream
+    /// 
+    /// Before the first control cycle nothing has been published, and the
+    /// core's (all zero) sample stands in.
+    fn telemetry_sample(&self) -> TelemetrySample {
+        self.latest_sample.lock().ok().and_then(|latest| latest.clone()).unwrap_or_else(|| self.core.telemetry_sample())
+    }
+    
+    /// Core behind the endpoint
+    pub fn core(&self) -> &RumbleDomeCore<MockHal> {
+        &self.core
+    }
+    
+    /// Session on a port
+    pub fn session(&self, port: ConsolePort) -> &DeviceSession {
+        &self.sessions[port as usize]
+    }
+    
+    /// Privilege level of the session on a port
+    pub fn role(&self, port: ConsolePort) -> SessionRole {
+        SessionRole::of_console(port, self.sessions[port as usize].authenticated, &self.core.config.console)
+    }
+    
+    /// Outgoing bandwidth arbitration
+    pub fn arbiter(&self) -> &BandwidthArbiter {
+        &self.arbiter
+    }
+    
+    /// Request framing statistics (USB session)
+    pub fn framing_stats(&self) -> &FramingStats {
+        self.session(ConsolePort::Usb).framing_stats()
+    }
+    
+    /// Sequenced request replay protection (USB session)
+    pub fn replay_guard(&self) -> &ReplayGuard {
+        self.session(ConsolePort::Usb).replay_guard()
+    }
+    
+    /// Active display mirroring stream, if subscribed (USB session)
+    pub fn screen_stream(&self) -> Option<&TelemetryThrottle> {
+        self.session(ConsolePort::Usb).screen_stream()
+    }
+    
+    /// Handle bytes received on USB and stream due telemetry onto the link
+    pub fn step(&mut self, received: &[u8], link: &mut ImpairedLink, now_ms: u32) {
+        self.step_session(ConsolePort::Usb, received, link, now_ms);
+    }
+    
+    /// Handle bytes received on a port and stream its due telemetry onto the link
+    pub fn step_session(&mut self, port: ConsolePort, received: &[u8], link: &mut ImpairedLink, now_ms: u32) {
+        // A disabled Bluetooth console is not advertised - nothing arriving on it is answered
+        if port == ConsolePort::Bluetooth && !self.core.config.console.bluetooth_enabled {
+            return;
+        }
+        
+        for request in self.sessions[port as usize].decoder.push(received) {
+            let response = self.handle_on(port, request, now_ms);
+            if let Some(frame) = Self::encode(&response) {
+                self.arbiter.charge(frame.len(), now_ms);
+                link.send(&frame);
+            }
+        }
+        
+        // Display and telemetry streams are background work - they stretch with the performance tier
+        let multiplier = self.core.tier_governor.tier().interval_multiplier();
+        let session = &mut self.sessions[port as usize];
+        for stream in [session.screen_stream.as_mut(), session.telemetry_stream.as_mut()].into_iter().flatten() {
+            stream.set_load_multiplier(multiplier);
+        }
+        
+        let backlog = link.backlog_bytes();
+        let due = self.sessions[port as usize].screen_stream.as_mut().is_some_and(|stream| stream.poll(now_ms, backlog));
+        if due {
+            self.send_stream_frame(port, &ProtocolMessage::Screen(self.screen_for(port)), link, now_ms);
+        }
+        
+        let backlog = link.backlog_bytes();
+        let due = self.sessions[port as usize].telemetry_stream.as_mut().is_some_and(|stream| stream.poll(now_ms, backlog));
+        if due {
+            self.send_stream_frame(port, &ProtocolMessage::Telemetry(self.telemetry_sample()), link, now_ms);
+        }
+    }
+    
+    /// Send one stream frame if the session's share of the budget covers it
+    fn send_stream_frame(&mut self, port: ConsolePort, message: &ProtocolMessage, link: &mut ImpairedLink, now_ms: u32) {
+        // A frame the budget cannot cover is skipped - the next one is fresher anyway
+        if let Some(frame) = Self::encode(message) {
+            if self.arbiter.grant_stream(self.role(port), frame.len(), now_ms) {
+                link.send(&frame);
+            }
+        }
+    }
+    
+    /// Screen description with the session's watched variables appended
+    fn screen_for(&self, port: ConsolePort) -> ScreenDescription {
+        let mut screen = self.core.describe_screen();
+        screen.watches = self.core.watch_readings(self.sessions[port as usize].watches.variables());
+        screen
+    }
+    
+    /// Answer to a configuration change, flagging stored values that wait for a restart
+    fn config_updated(&self) -> ProtocolMessage {
+        let changes = self.core.pending_restart();
+        if changes.is_empty() {
+            ProtocolMessage::ConfigUpdated
+        } else {
+            ProtocolMessage::RestartRequired { changes }
+        }
+    }
+    
+    /// Produce the response to one USB request
+    pub fn handle(&mut self, request: ProtocolMessage, now_ms: u32) -> ProtocolMessage {
+        self.handle_on(ConsolePort::Usb, request, now_ms)
+    }
+    
+    /// Produce the response to one request received on a port
+    pub fn handle_on(&mut self, port: ConsolePort, request: ProtocolMessage, now_ms: u32) -> ProtocolMessage {
+        self.handle_request(port, request, now_ms, false)
+    }
+    
+    /// Produce the response to one request; `confirmed` once its presence code matched
+    fn handle_request(&mut self, port: ConsolePort, request: ProtocolMessage, now_ms: u32, confirmed: bool) -> ProtocolMessage {
+        use ProtocolMessage::*;
+        
+        let role = self.role(port);
+        let session = &mut self.sessions[port as usize];
+        if !request.permitted_for(role) {
+            session.refused += 1;
+            let reason = match role {
+                SessionRole::Paired => "Safety-relevant changes are disabled over Bluetooth",
+                _ => "Not permitted in a read-only session",
+            };
+            return ProtocolMessage::coded_error(ErrorCode::NOT_PERMITTED, reason);
+        }
+        
+        // A wired or already authenticated session has nothing to prove
+        if matches!(request, Authenticate) && (port == ConsolePort::Usb || session.authenticated) {
+            return Authenticated { safety_writes: role == SessionRole::Admin };
+        }
+        
+        // Dangerous requests wait for the code shown on the device display
+        if !confirmed && !matches!(request, Sequenced { .. }) {
+            if let Some(action) = request.protected_action(&self.core.config, &self.core.configurations) {
+                self.core.request_presence(action, now_ms);
+                self.sessions[port as usize].held = Some(request);
+                return ConfirmationRequired { action, expires_in_ms: CONFIRMATION_TIMEOUT_MS };
+            }
+        }
+        let session = &mut self.sessions[port as usize];
+        
+        let result = match request {
+            Sequenced { sequence, request } => {
+                if matches!(*request, Sequenced { .. }) {
+                    return ProtocolMessage::coded_error(ErrorCode::NESTED_SEQUENCE, "Nested sequenced request");
+                }
+                
+                return match session.replay.check(sequence) {
+                    ReplayCheck::Apply => {
+                        let response = self.handle_request(port, *request, now_ms, confirmed);
+                        self.sessions[port as usize].replay.record(sequence, response.clone());
+                        response
+                    },
+                    ReplayCheck::Repeat(response) => *response,
+                    ReplayCheck::Stale { last } => ProtocolMessage::coded_error(
+                        ErrorCode::STALE_SEQUENCE, &format!("Stale request sequence {} (last applied {})", sequence, last)
+                    ),
+                };
+            },
+            Hello => {
+                // New connection - the client numbers its requests from the start again and authenticates anew
+                session.replay.reset();
+                session.authenticated = false;
+                if session.held.take().is_some() {
+                    self.core.presence.cancel();
+                }
+                Ok(Identity { protocol_version: PROTOCOL_VERSION, identity: self.core.identity() })
+            },
+            SetDeviceName { name } => self.core.set_device_name(&name).map(|_| ConfigUpdated),
+            SetLanguage { language } => {
+                self.core.set_language(language);
+                Ok(ConfigUpdated)
+            },
+            GetStatus => Ok(Status(Box::new(self.core.get_system_status()))),
+            GetScreen => Ok(Screen(self.screen_for(port))),
+            SubscribeScreen { interval_ms } => {
+                // The response is the first frame of the stream
+                let mut stream = TelemetryThrottle::new(interval_ms);
+                stream.poll(now_ms, 0);
+                self.sessions[port as usize].screen_stream = Some(stream);
+                Ok(Screen(self.screen_for(port)))
+            },
+            UnsubscribeScreen => {
+                self.sessions[port as usize].screen_stream = None;
+                Ok(ScreenUnsubscribed)
+            },
+            SubscribeTelemetry { interval_ms } => {
+                // The response is the first sample of the stream
+                let mut stream = TelemetryThrottle::new(interval_ms);
+                stream.poll(now_ms, 0);
+                self.sessions[port as usize].telemetry_stream = Some(stream);
+                Ok(Telemetry(self.telemetry_sample()))
+            },
+            UnsubscribeTelemetry => {
+                self.sessions[port as usize].telemetry_stream = None;
+                Ok(TelemetryUnsubscribed)
+            },
+            SetTestValue { parameter, value } => self.core
+                .set_test_value(parameter, value, now_ms)
+                .map(|_| LiveTuneStatus(self.core.live_tune.status(now_ms))),
+            CommitTestValues => self.core.commit_live_tune()
+                .map(|_| LiveTuneStatus(self.core.live_tune.status(now_ms))),
+            RevertTestValues => {
+                self.core.live_tune.revert();
+                Ok(LiveTuneStatus(self.core.live_tune.status(now_ms)))
+            },
+            GetLiveTuneStatus => {
+                self.core.live_tune.keepalive(now_ms);
+                Ok(LiveTuneStatus(self.core.live_tune.status(now_ms)))
+            },
+            SetConfig(config) => {
+                let active = self.core.configurations.active().name.clone();
+                self.core.save_configuration(&active, config).map(|_| self.config_updated())
+            },
+            SetControlMode { mode } => self.core.set_control_mode(mode).map(|_| ConfigUpdated),
+            ListConfigurations => Ok(Configurations {
+                active: self.core.configurations.active_name().to_string(),
+                names: self.core.configurations.names().into_iter().chain([SPRING_ONLY_PROFILE.to_string()]).collect(),
+            }),
+            SaveConfiguration { name, config } => self.core.save_configuration(&name, config).map(|_| self.config_updated()),
+            SaveInheritedConfiguration { name, parent, overrides } => {
+                self.core.save_inherited_configuration(&name, &parent, overrides).map(|_| self.config_updated())
+            },
+            GetConfiguration { name } => self.core.configurations.get(&name)
+                .map(|entry| Configuration(entry.clone()))
+                .ok_or_else(|| CoreError::ConfigurationError(format!("No configuration named \"{}\"", name))),
+            SelectConfiguration { name } => self.core.switch_configuration(&name).map(|_| self.config_updated()),
+            DeleteConfiguration { name } => self.core.delete_configuration(&name).map(|_| ConfigUpdated),
+            SetProfileSchedule { rules } => self.core.set_profile_schedule(rules).map(|_| ConfigUpdated),
+            GetProfileSchedule => Ok(ProfileSchedule { rules: self.core.configurations.schedule().to_vec() }),
+            SetContextHints(hints) => self.core.apply_context_hints(hints).map(ScheduleStatus),
+            SetAggression { value } => self.core.set_app_aggression(value).map(AggressionInput),
+            AcknowledgeMaintenance { name } => self.core.acknowledge_maintenance(&name).map(|_| MaintenanceAcknowledged),
+            ResetMapZeroBaseline => {
+                self.core.reset_map_zero_baseline();
+                Ok(MapZeroBaselineReset)
+            },
+            GetCommissioningStatus => Ok(CommissioningStatus(self.core.commissioning.status())),
+            BeginCommissioning => self.core.begin_commissioning()
+                .map(|_| CommissioningStatus(self.core.commissioning.status())),
+            CompleteCommissioningStep { step } => self.core.complete_commissioning_step(step)
+                .map(|_| CommissioningStatus(self.core.commissioning.status())),
+            SkipCommissioningStep { step } => self.core.skip_commissioning_step(step)
+                .map(|_| CommissioningStatus(self.core.commissioning.status())),
+            SkipCommissioning => self.core.skip_commissioning()
+                .map(|_| CommissioningStatus(self.core.commissioning.status())),
+            ExitCommissioning => {
+                self.core.exit_commissioning();
+                Ok(CommissioningStatus(self.core.commissioning.status()))
+            },
+            SetDemoMode { active } => self.core.set_demo_mode(active)
+                .map(|_| DemoMode { active: self.core.demo.is_active() }),
+            GetSensorMappingStatus => Ok(SensorMappingStatus(self.core.sensor_mapping_status())),
+            BeginSensorMapping => self.core.begin_sensor_mapping()
+                .map(|_| SensorMappingStatus(self.core.sensor_mapping_status())),
+            SkipSensorRole => self.core.skip_sensor_role()
+                .map(|_| SensorMappingStatus(self.core.sensor_mapping_status())),
+            CancelSensorMapping => {
+                self.core.cancel_sensor_mapping();
+                Ok(SensorMappingStatus(self.core.sensor_mapping_status()))
+            },
+            FinishSensorMapping => self.core.finish_sensor_mapping().map(SensorMappingReport),
+            StartSpringCheck => self.core.start_spring_check()
+                .map(|_| SpringCheckStatus(self.core.spring_check_status())),
+            GetSpringCheckStatus => Ok(SpringCheckStatus(self.core.spring_check_status())),
+            CancelSpringCheck => {
+                self.core.cancel_spring_check();
+                Ok(SpringCheckStatus(self.core.spring_check_status()))
+            },
+            StartBurstLog { window_ms } => self.core.start_burst_log(window_ms)
+                .map(|_| BurstLogStatus(self.core.burst_log.status())),
+            GetBurstLogStatus => Ok(BurstLogStatus(self.core.burst_log.status())),
+            GetBurstLog { offset } => Ok(BurstLog(self.core.burst_log.chunk(offset))),
+            StartDatalog => self.core.start_datalog()
+                .map(|_| DatalogStatus(self.core.datalogger.status())),
+            StopDatalog => {
+                self.core.stop_datalog();
+                Ok(DatalogStatus(self.core.datalogger.status()))
+            },
+            GetDatalogStatus => Ok(DatalogStatus(self.core.datalogger.status())),
+            GetFlightRecord { offset } => self.core.flight_record(offset).map(FlightRecord),
+            StartCalibration => self.core.start_calibration()
+                .map(|_| CalibrationStatus { progress: self.core.calibration_progress() }),
+            GetCalibrationStatus => Ok(CalibrationStatus { progress: self.core.calibration_progress() }),
+            AbortCalibration => self.core.abort_calibration()
+                .map(|_| CalibrationStatus { progress: self.core.calibration_progress() }),
+            ExportLearnedData => Ok(LearnedDataImage { image: self.core.export_learned_data() }),
+            ImportLearnedData { offset, chunk } => self.core.import_learned_data(offset, &chunk).map(LearnedDataImported),
+            ResetLearnedData => self.core.reset_learned_data().map(|_| LearnedDataReset),
+            GetSafetyLog => self.core.safety_log_entries().map(|events| SafetyLog { events }),
+            ClearSafetyLog => self.core.clear_safety_log().map(|_| SafetyLogCleared),
+            GetSupportBundle => Ok(SupportBundle(Box::new(self.core.support_bundle()))),
+            GetDistribution => Ok(Distribution(self.core.distribution_report())),
+            GetTrends { day } => Ok(Trends(self.core.trend_report(day))),
+            SetWatchList { variables } => session.watches.set(&variables)
+                .map(|_| WatchList { variables: session.watches.variables().to_vec() }),
+            Authenticate => {
+                session.authenticated = true;
+                Ok(Authenticated { safety_writes: self.role(port) == SessionRole::Admin })
+            },
+            ConfirmPresence { code } => {
+                let Some(held) = session.held.take() else {
+                    return ProtocolMessage::coded_error(ErrorCode::NO_PENDING_CONFIRMATION, "No request waiting for confirmation");
+                };
+                return match self.core.presence.confirm(code, now_ms) {
+                    Ok(_) => self.handle_request(port, held, now_ms, true),
+                    Err(e) => ProtocolMessage::error_from(&e),
+                };
+            },
+            Identity { .. } | Status(_) | ConfigUpdated | RestartRequired { .. } | Configurations { .. } | Configuration(_) | ProfileSchedule { .. } | ScheduleStatus(_) | AggressionInput(_) | LiveTuneStatus(_) | Screen(_)
+            | ScreenUnsubscribed | TelemetryUnsubscribed | Telemetry(_) | MaintenanceAcknowledged | MapZeroBaselineReset | CommissioningStatus(_) | DemoMode { .. }
+            | SensorMappingStatus(_) | SensorMappingReport(_) | SpringCheckStatus(_) | BurstLogStatus(_) | BurstLog(_) | DatalogStatus(_) | FlightRecord(_) | CalibrationStatus { .. }
+            | LearnedDataImage { .. } | LearnedDataImported(_) | LearnedDataReset | SafetyLog { .. } | SafetyLogCleared | SupportBundle(_) | Distribution(_) | Trends(_) | WatchList { .. }
+            | Authenticated { .. } | ConfirmationRequired { .. } | Error(_) => {
+                return ProtocolMessage::coded_error(ErrorCode::NOT_A_REQUEST, "Not a request");
+            },
+        };
+        
+        result.unwrap_or_else(|e| ProtocolMessage::error_from(&e))
+    }
+    
+    fn encode(message: &ProtocolMessage) -> Option<Vec<u8>> {
+        match encode_frame(message, MAX_RESPONSE_BYTES) {
+            Ok(frame) => Some(frame),
+            Err(e) => {
+                warn!("Virtual device could not send response: {:?}", e);
+                None
+            },
+        }
+    }
+}
+
+/// Something the client observed during a step
+#[derive(Debug, Clone)]
+pub enum ConnectionEvent {
+    /// Response to the pending request
+    Response(ProtocolMessage),
+    /// Message not answering a pending request (stream frame or late duplicate)
+    Unsolicited(ProtocolMessage),
+    /// Pending request abandoned after its last attempt
+    RequestFailed(ProtocolMessage),
+}
+
+/// Client connected to a virtual device over an impaired link
+pub struct VirtualConnection {
+    device: VirtualDevice,
+    uplink: ImpairedLink,
+    downlink: ImpairedLink,
+    decoder: FrameDecoder,
+    tracker: RequestTracker,
+    sequencer: RequestSequencer,
+}
+
+impl VirtualConnection {
+    /// Connect to a fresh virtual device
+    /// 
+    /// `uplink` impairs client → device traffic, `downlink` device → client.
+    pub fn new(config: SystemConfig, uplink: LinkImpairment, downlink: LinkImpairment, retry: RetryPolicy) -> Result<Self, CoreError> {
+        Ok(Self {
+            device: VirtualDevice::new(config)?,
+            uplink: ImpairedLink::new(uplink),
+            downlink: ImpairedLink::new(downlink),
+            decoder: FrameDecoder::new(MAX_RESPONSE_BYTES),
+            tracker: RequestTracker::new(retry),
+            sequencer: RequestSequencer::default(),
+        })
+    }
+    
+    /// Device end of the connection
+    pub fn device(&self) -> &VirtualDevice {
+        &self.device
+    }
+    
+    /// Client → device link
+    pub fn uplink(&self) -> &ImpairedLink {
+        &self.uplink
+    }
+    
+    /// Device → client link
+    pub fn downlink(&self) -> &ImpairedLink {
+        &self.downlink
+    }
+    
+    /// Client request retry statistics
+    pub fn retry_stats(&self) -> &RetryStats {
+        self.tracker.stats()
+    }
+    
+    /// Client response framing statistics
+    pub fn framing_stats(&self) -> &FramingStats {
+        self.decoder.stats()
+    }
+    
+    /// Send a request (one at a time)
+    pub fn request(&mut self, request: ProtocolMessage, now_ms: u32) -> Result<(), ProtocolError> {
+        let request = self.sequencer.prepare(request);
+        let frame = encode_frame(&request, MAX_REQUEST_BYTES)?;
+        self.tracker.begin(request, now_ms)?;
+        self.uplink.send(&frame);
+        Ok(())
+    }
+    
+    /// Advance both link directions, the device and the client to `now_ms`
+    pub fn step(&mut self, now_ms: u32) -> Vec<ConnectionEvent> {
+        let to_device = self.uplink.step(now_ms);
+        self.device.step(&to_device, &mut self.downlink, now_ms);
+        
+        let mut events = Vec::new();
+        for message in self.decoder.push(&self.downlink.step(now_ms)) {
+            if self.tracker.accept(&message) {
+                events.push(ConnectionEvent::Response(message));
+            } else {
+                events.push(ConnectionEvent::Unsolicited(message));
+            }
+        }
+        
+        match self.tracker.poll(now_ms) {
+            Some(RetryAction::Resend(request)) => {
+                if let Ok(frame) = encode_frame(&request, MAX_REQUEST_BYTES) {
+                    self.uplink.send(&frame);
+                }
+            },
+            Some(RetryAction::GiveUp(request)) => events.push(ConnectionEvent::RequestFailed(request)),
+            None => {},
+        }
+        
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumbledome_core::{
+        ContextHints, Language, ProfileRule, RestartParameter, StagedChange, SystemState, WatchValue, WatchVariable, DEFAULT_CONFIG_NAME,
+        LEARNED_DATA_CHUNK_BYTES,
+    };
+    
+    const STEP_MS: u32 = 10;
+    
+    fn connect(uplink: LinkImpairment, downlink: LinkImpairment) -> VirtualConnection {
+        VirtualConnection::new(SystemConfig::default(), uplink, downlink, RetryPolicy::default()).unwrap()
+    }
+    
+    /// Step until the pending request resolves; returns (event, time)
+    fn await_response(connection: &mut VirtualConnection, mut t: u32) -> (ConnectionEvent, u32) {
+        loop {
+            for event in connection.step(t) {
+                if !matches!(event, ConnectionEvent::Unsolicited(_)) {
+                    return (event, t);
+                }
+            }
+            t += STEP_MS;
+            assert!(t < 60_000, "request never resolved");
+        }
+    }
+    
+    #[test]
+    fn test_status_round_trip_on_ideal_link() {
+        let mut connection = connect(LinkImpairment::ideal(), LinkImpairment::ideal());
+        connection.request(ProtocolMessage::GetStatus, 0).unwrap();
+        
+        let (event, t) = await_response(&mut connection, 0);
+        assert!(matches!(event, ConnectionEvent::Response(ProtocolMessage::Status(_))));
+        assert_eq!(t, 0);
+    }
+    
+    #[test]
+    fn test_language_applies_to_screen() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let selected = device.handle(ProtocolMessage::SetLanguage { language: Language::Spanish }, 0);
+        assert!(matches!(selected, ProtocolMessage::ConfigUpdated));
+        
+        let ProtocolMessage::Screen(screen) = device.handle(ProtocolMessage::GetScreen, 0) else {
+            panic!("screen not returned");
+        };
+        assert_eq!(screen.language, Language::Spanish);
+        assert_eq!(screen.status_text, device.core.state.localized_text(Language::Spanish));
+    }
+    
+    #[test]
+    fn test_demo_mode_drives_screen_until_armed() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let started = device.handle(ProtocolMessage::SetDemoMode { active: true }, 0);
+        assert!(matches!(started, ProtocolMessage::DemoMode { active: true }));
+        
+        let ProtocolMessage::Screen(screen) = device.handle(ProtocolMessage::GetScreen, 0) else {
+            panic!("screen not returned");
+        };
+        assert_eq!(screen.status_text, "DEMO");
+        
+        // Arming hands the display back to the real gauge, and the demo cannot restart while armed
+        device.core.skip_commissioning().unwrap();
+        device.core.arm().unwrap();
+        assert!(!device.core.demo.is_active());
+        let refused = device.handle(ProtocolMessage::SetDemoMode { active: true }, 0);
+        assert!(matches!(refused, ProtocolMessage::Error(_)));
+    }
+    
+    #[test]
+    fn test_hello_advertises_device_name() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let renamed = device.handle(ProtocolMessage::SetDeviceName { name: "Mustang".to_string() }, 0);
+        assert!(matches!(renamed, ProtocolMessage::ConfigUpdated));
+        
+        let ProtocolMessage::Identity { protocol_version, identity } = device.handle(ProtocolMessage::Hello, 0) else {
+            panic!("hello not answered with identity");
+        };
+        assert_eq!(protocol_version, PROTOCOL_VERSION);
+        assert_eq!(identity.device_name, "Mustang");
+        assert_eq!(identity.active_configuration, "default");
+    }
+    
+    #[test]
+    fn test_sequenced_command_applied_once() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let begin = || ProtocolMessage::Sequenced { sequence: 5, request: Box::new(ProtocolMessage::BeginCommissioning) };
+        
+        // Applied bare a second time this fails (already commissioning); the retry gets the original answer
+        assert!(matches!(device.handle(begin(), 0), ProtocolMessage::CommissioningStatus(_)));
+        assert!(matches!(device.handle(begin(), 1500), ProtocolMessage::CommissioningStatus(_)));
+        assert!(matches!(device.handle(ProtocolMessage::BeginCommissioning, 3000), ProtocolMessage::Error(_)));
+        
+        let stale = ProtocolMessage::Sequenced { sequence: 4, request: Box::new(ProtocolMessage::SkipCommissioning) };
+        assert!(matches!(device.handle(stale, 3000), ProtocolMessage::Error(_)));
+        assert!(!device.core().commissioning.is_complete());
+        assert_eq!(device.replay_guard().replays(), 2);
+    }
+    
+    #[test]
+    fn test_watched_variables_ride_on_session_screens() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let variables = vec![WatchVariable::MapSource, WatchVariable::TargetPsi];
+        let registered = device.handle(ProtocolMessage::SetWatchList { variables: variables.clone() }, 0);
+        assert!(matches!(registered, ProtocolMessage::WatchList { variables: ref watched } if *watched == variables));
+        
+        let ProtocolMessage::Screen(screen) = device.handle(ProtocolMessage::SubscribeScreen { interval_ms: 100 }, 0) else {
+            panic!("screen not returned");
+        };
+        assert_eq!(screen.watches.len(), 2);
+        assert_eq!(screen.watches[0].value, WatchValue::Source(device.core.map_arbiter.selected()));
+        
+        // Watches belong to the session that registered them; a guest may not register any
+        let ProtocolMessage::Screen(guest_screen) = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::GetScreen, 0) else {
+            panic!("screen not returned");
+        };
+        assert!(guest_screen.watches.is_empty());
+        let refused = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::SetWatchList { variables }, 0);
+        assert!(matches!(refused, ProtocolMessage::Error(_)));
+        
+        let too_many = device.handle(ProtocolMessage::SetWatchList { variables: WatchVariable::ALL.to_vec() }, 0);
+        assert!(matches!(too_many, ProtocolMessage::Error(_)));
+        assert_eq!(device.session(ConsolePort::Usb).watches().variables().len(), 2);
+    }
+    
+    #[test]
+    fn test_restart_parameters_staged_until_reboot() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let faster = SystemConfig { pwm_frequency_hz: 40, ..SystemConfig::default() };
+        
+        let ProtocolMessage::RestartRequired { changes } = device.handle(ProtocolMessage::SetConfig(faster), 0) else {
+            panic!("restart not flagged");
+        };
+        assert_eq!(changes, vec![StagedChange { parameter: RestartParameter::PwmFrequency, running: 30, pending: 40 }]);
+        assert_eq!(device.core.config.pwm_frequency_hz, 30);
+        
+        // The pending diff stays visible in status until the next boot
+        let ProtocolMessage::Status(status) = device.handle(ProtocolMessage::GetStatus, 0) else {
+            panic!("status not returned");
+        };
+        assert_eq!(status.pending_restart, changes);
+        
+        let rebooted = RumbleDomeCore::with_configurations(MockHal::new(), device.core.configurations.clone());
+        assert_eq!(rebooted.config.pwm_frequency_hz, 40);
+        assert!(rebooted.pending_restart().is_empty());
+    }
+    
+    #[test]
+    fn test_inherited_configuration_served_resolved() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut overrides = serde_json::Map::new();
+        overrides.insert("max_boost_psi".to_string(), serde_json::json!(10.0));
+        let save = ProtocolMessage::SaveInheritedConfiguration {
+            name: "cold".to_string(), parent: "default".to_string(), overrides,
+        };
+        assert!(matches!(device.handle(save, 0), ProtocolMessage::ConfigUpdated));
+        
+        let ProtocolMessage::Configuration(entry) = device.handle(ProtocolMessage::GetConfiguration { name: "cold".to_string() }, 0) else {
+            panic!("configuration not returned");
+        };
+        assert_eq!(entry.parent.as_deref(), Some("default"));
+        assert_eq!(entry.config.max_boost_psi, 10.0);
+        assert_eq!(entry.config.spring_pressure, SystemConfig::default().spring_pressure);
+        
+        let missing = device.handle(ProtocolMessage::GetConfiguration { name: "hot".to_string() }, 0);
+        assert!(matches!(missing, ProtocolMessage::Error(_)));
+    }
+    
+    #[test]
+    fn test_limit_raise_waits_for_code_from_display() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let raised = || SystemConfig { overboost_limit: 16.0, ..SystemConfig::default() };
+        let displayed_code = |device: &VirtualDevice| -> u16 {
+            let alert = device.core.describe_screen().alert.unwrap();
+            alert.text.rsplit(' ').next().unwrap().parse().unwrap()
+        };
+        
+        assert!(matches!(device.handle(ProtocolMessage::SetConfig(raised()), 0), ProtocolMessage::ConfirmationRequired { .. }));
+        assert_eq!(device.core.config.overboost_limit, 15.0);
+        
+        // A wrong code cancels the held request
+        let wrong = displayed_code(&device).wrapping_add(1);
+        assert!(matches!(device.handle(ProtocolMessage::ConfirmPresence { code: wrong }, 100), ProtocolMessage::Error(_)));
+        assert!(device.session(ConsolePort::Usb).held().is_none());
+        assert!(device.core.describe_screen().alert.is_none());
+        
+        device.handle(ProtocolMessage::SetConfig(raised()), 200);
+        let code = displayed_code(&device);
+        assert!(matches!(device.handle(ProtocolMessage::ConfirmPresence { code }, 300), ProtocolMessage::ConfigUpdated));
+        assert_eq!(device.core.config.overboost_limit, 16.0);
+        
+        // Lowering a limit needs no confirmation
+        let lowered = SystemConfig { overboost_limit: 14.0, ..SystemConfig::default() };
+        assert!(matches!(device.handle(ProtocolMessage::SetConfig(lowered), 400), ProtocolMessage::ConfigUpdated));
+    }
+    
+    #[test]
+    fn test_guest_session_is_read_only() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let rename = || ProtocolMessage::SetDeviceName { name: "Passenger".to_string() };
+        let sequenced = ProtocolMessage::Sequenced { sequence: 1, request: Box::new(rename()) };
+        
+        let refused = device.handle_on(ConsolePort::Bluetooth, rename(), 0);
+        assert_eq!(refused.error_code(), Some(ErrorCode::NOT_PERMITTED));
+        assert!(matches!(device.handle_on(ConsolePort::Bluetooth, sequenced, 0), ProtocolMessage::Error(_)));
+        assert!(matches!(device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::GetScreen, 0), ProtocolMessage::Screen(_)));
+        assert_eq!(device.session(ConsolePort::Bluetooth).refused(), 2);
+        
+        assert!(matches!(device.handle_on(ConsolePort::Usb, rename(), 0), ProtocolMessage::ConfigUpdated));
+        assert_eq!(device.core().identity().device_name, "Passenger");
+    }
+    
+    #[test]
+    fn test_bluetooth_session_authenticates_with_displayed_code() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let rename = || ProtocolMessage::SetDeviceName { name: "Passenger".to_string() };
+        let lowered = || SystemConfig { overboost_limit: 14.0, ..SystemConfig::default() };
+        
+        assert!(matches!(device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::Authenticate, 0), ProtocolMessage::ConfirmationRequired { .. }));
+        let alert = device.core.describe_screen().alert.unwrap();
+        let code = alert.text.rsplit(' ').next().unwrap().parse().unwrap();
+        let confirmed = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::ConfirmPresence { code }, 100);
+        assert!(matches!(confirmed, ProtocolMessage::Authenticated { safety_writes: false }));
+        assert_eq!(device.role(ConsolePort::Bluetooth), SessionRole::Paired);
+        
+        // Everyday changes go through; boost limits stay wired-only
+        assert!(matches!(device.handle_on(ConsolePort::Bluetooth, rename(), 200), ProtocolMessage::ConfigUpdated));
+        let refused = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::SetConfig(lowered()), 200);
+        assert_eq!(refused.error_code(), Some(ErrorCode::NOT_PERMITTED));
+        assert_eq!(device.core().config.overboost_limit, 15.0);
+        
+        // A new connection starts read-only again
+        device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::Hello, 300);
+        assert_eq!(device.role(ConsolePort::Bluetooth), SessionRole::Guest);
+        assert!(matches!(device.handle_on(ConsolePort::Usb, ProtocolMessage::Authenticate, 300), ProtocolMessage::Authenticated { safety_writes: true }));
+    }
+    
+    #[test]
+    fn test_phone_context_schedules_valet_mode() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let valet = SystemConfig { max_boost_psi: 6.0, ..SystemConfig::default() };
+        let save = ProtocolMessage::SaveConfiguration { name: "valet".to_string(), config: valet };
+        assert!(matches!(device.handle(save, 0), ProtocolMessage::ConfigUpdated));
+        let rule = ProfileRule { config: "valet".to_string(), track_day: None, zone: Some("home".to_string()), time: None };
+        let schedule = ProtocolMessage::SetProfileSchedule { rules: vec![rule] };
+        assert!(matches!(device.handle(schedule, 0), ProtocolMessage::ConfigUpdated));
+        
+        // The passenger's phone may push context, not configure
+        let home = ContextHints { zone: Some("home".to_string()), ..ContextHints::default() };
+        match device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::SetContextHints(home), 0) {
+            ProtocolMessage::ScheduleStatus(status) => assert_eq!(status.active, "valet"),
+            other => panic!("unexpected response {:?}", other),
+        }
+        assert_eq!(device.core().config.max_boost_psi, 6.0);
+        assert_eq!(device.core().configurations.driver_selection(), DEFAULT_CONFIG_NAME);
+    }
+    
+    #[test]
+    fn test_guest_stream_runs_beside_admin_session() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut usb = ImpairedLink::new(LinkImpairment::ideal());
+        let mut bluetooth = ImpairedLink::new(LinkImpairment::ideal());
+        let subscribe = encode_frame(&ProtocolMessage::SubscribeScreen { interval_ms: 200 }, MAX_REQUEST_BYTES).unwrap();
+        let bundle = encode_frame(&ProtocolMessage::GetSupportBundle, MAX_REQUEST_BYTES).unwrap();
+        
+        let mut usb_decoder = FrameDecoder::new(MAX_RESPONSE_BYTES);
+        let mut bluetooth_decoder = FrameDecoder::new(MAX_RESPONSE_BYTES);
+        let mut guest_frames = 0;
+        let mut admin_frames = 0;
+        for t in (0..3000).step_by(STEP_MS as usize) {
+            let usb_request: &[u8] = match t {
+                0 => &subscribe,
+                1000 => &bundle,
+                _ => &[],
+            };
+            let bluetooth_request: &[u8] = if t == 0 { &subscribe } else { &[] };
+            device.step_session(ConsolePort::Usb, usb_request, &mut usb, t);
+            device.step_session(ConsolePort::Bluetooth, bluetooth_request, &mut bluetooth, t);
+            
+            admin_frames += usb_decoder.push(&usb.step(t)).iter().filter(|m| matches!(m, ProtocolMessage::Screen(_))).count();
+            guest_frames += bluetooth_decoder.push(&bluetooth.step(t)).iter().filter(|m| matches!(m, ProtocolMessage::Screen(_))).count();
+        }
+        
+        // Each session keeps its own subscription
+        assert!(admin_frames >= 5, "admin frames {}", admin_frames);
+        assert!(guest_frames >= 5, "guest frames {}", guest_frames);
+        // The support bundle took the shared budget, so streams gave way for a while
+        assert!(device.arbiter().stats().deferred > 0);
+    }
+    
+    #[test]
+    fn test_calibration_start_and_abort() {
+        let mut device = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let refused = device.handle(ProtocolMessage::StartCalibration, 0);
+        assert!(matches!(refused, ProtocolMessage::Error(_)));
+        
+        device.core.skip_commissioning().unwrap();
+        let started = device.handle(ProtocolMessage::StartCalibration, 0);
+        assert!(matches!(started, ProtocolMessage::CalibrationStatus { progress: Some(_) }));
+        assert!(matches!(device.core.state, SystemState::Calibrating(_)));
+        
+        // A phone may watch progress but not stop it
+        let watched = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::GetCalibrationStatus, 0);
+        assert!(matches!(watched, ProtocolMessage::CalibrationStatus { progress: Some(_) }));
+        let refused = device.handle_on(ConsolePort::Bluetooth, ProtocolMessage::AbortCalibration, 0);
+        assert_eq!(refused.error_code(), Some(ErrorCode::NOT_PERMITTED));
+        
+        let aborted = device.handle(ProtocolMessage::AbortCalibration, 0);
+        assert!(matches!(aborted, ProtocolMessage::CalibrationStatus { progress: None }));
+        assert_eq!(device.core.state, SystemState::Idle);
+    }
+    
+    #[test]
+    fn test_learned_data_moves_between_devices() {
+        let mut donor = VirtualDevice::new(SystemConfig::default()).unwrap();
+        let mut inputs = donor.core.read_inputs().unwrap();
+        inputs.rpm = 4500;
+        inputs.manifold_pressure = 11.0;
+        inputs.dome_input_pressure = 25.0;
+        donor.core.learned_data.update_from_operation(&inputs, 11.0, 38.0).unwrap();
+        let ProtocolMessage::LearnedDataImage { image } = donor.handle(ProtocolMessage::ExportLearnedData, 0) else {
+            panic!("learned data not exported");
+        };
+        
+        // Each chunk goes over the real framing within the request size limit
+        let mut connection = connect(LinkImpairment::ideal(), LinkImpairment::ideal());
+        let mut t = 0;
+        // Replacing or forgetting the map takes the code from the display
+        fn confirmed(connection: &mut VirtualConnection, request: ProtocolMessage, t: u32) -> (ProtocolMessage, u32) {
+            connection.request(request, t).unwrap();
+            let (event, done) = await_response(connection, t);
+            let ConnectionEvent::Response(response) = event else { panic!("no response: {:?}", event) };
+            let ProtocolMessage::ConfirmationRequired { .. } = response else { return (response, done) };
+            
+            let code = connection.device().core().presence.display_code().unwrap();
+            connection.request(ProtocolMessage::ConfirmPresence { code }, done + STEP_MS).unwrap();
+            let (event, done) = await_response(connection, done + STEP_MS);
+            let ConnectionEvent::Response(response) = event else { panic!("no response: {:?}", event) };
+            (response, done)
+        }
+        for (index, chunk) in image.chunks(LEARNED_DATA_CHUNK_BYTES).enumerate() {
+            let offset = (index * LEARNED_DATA_CHUNK_BYTES) as u32;
+            let (response, done) = confirmed(&mut connection, ProtocolMessage::ImportLearnedData { offset, chunk: chunk.to_vec() }, t);
+            assert!(matches!(response, ProtocolMessage::LearnedDataImported(_)), "{:?}", response);
+            t = done + STEP_MS;
+        }
+        assert_eq!(connection.device().core().learned_data.status().cells_learned, 2);
+        
+        connection.request(ProtocolMessage::ResetLearnedData, t).unwrap();
+        let (event, done) = await_response(&mut connection, t);
+        assert!(matches!(event, ConnectionEvent::Response(ProtocolMessage::ConfirmationRequired { .. })));
+        assert_eq!(connection.device().core().learned_data.status().cells_learned, 2);
+        let (response, _) = confirmed(&mut connection, ProtocolMessage::ResetLearnedData, done + STEP_MS);
+        assert!(matches!(response, ProtocolMessage::LearnedDataReset));
+        assert_eq!(connection.device().core().learned_data.status().cells_learned, 0);
+    }
+    
+    #[test]
+    fn test_support_bundle_fits_in_one_frame() {
+        let mut connection = connect(LinkImpairment::ideal(), LinkImpairment::ideal());
+        connection.request(ProtocolMessage::GetSupportBundle, 0).unwrap();
+        
+        let (event, _) = await_response(&mut connection, 0);
+        assert!(matches!(event, ConnectionEvent::Response(ProtocolMessage::SupportBundle(_))));
+    }
+    
+    #[test]
+    fn test_lossy_link_recovers_through_retries() {
+        let lossy = LinkImpairment { drop_probability: 0.6, chunk_bytes: 16, ..LinkImpairment::spp_marginal() };
+        let mut connection = connect(lossy, LinkImpairment::spp_typical());
+        
+        let mut t = 0;
+        let mut answered = 0;
+        for _ in 0..5 {
+            connection.request(ProtocolMessage::GetLiveTuneStatus, t).unwrap();
+            let (event, done) = await_response(&mut connection, t);
+            if matches!(event, ConnectionEvent::Response(_)) {
+                answered += 1;
+            }
+            t = done + STEP_MS;
+        }
+        
+        // Damaged requests are discarded whole by the device and resent by the client
+        assert!(connection.device().framing_stats().malformed > 0);
+        assert!(connection.retry_stats().retries > 0);
+        assert!(answered > 0);
+    }
+    
+    #[test]
+    fn test_slow_link_throttles_screen_stream() {
+        let slow = LinkImpairment { throughput_bytes_per_s: 1_000, ..LinkImpairment::spp_typical() };
+        let mut connection = connect(LinkImpairment::spp_typical(), slow);
+        connection.request(ProtocolMessage::SubscribeScreen { interval_ms: 50 }, 0).unwrap();
+        
+        let mut frames = 0;
+        let mut max_backlog = 0;
+        for step in 0..1000 {
+            for event in connection.step(step * STEP_MS) {
+                if let ConnectionEvent::Response(ProtocolMessage::Screen(_)) | ConnectionEvent::Unsolicited(ProtocolMessage::Screen(_)) = event {
+                    frames += 1;
+                }
+            }
+            max_backlog = max_backlog.max(connection.downlink().backlog_bytes());
+        }
+        
+        let stream = connection.device().screen_stream().unwrap();
+        assert!(stream.effective_interval_ms() > 50);
+        assert!(stream.stats().skipped > 0);
+        assert!(frames > 10);
+        // Backlog stays around one frame instead of growing for the whole run
+        assert!(max_backlog < 1024, "backlog grew to {} bytes", max_backlog);
+    }
+    
+    #[test]
+    fn test_telemetry_stream_until_unsubscribed() {
+        let mut connection = connect(LinkImpairment::ideal(), LinkImpairment::ideal());
+        connection.request(ProtocolMessage::SubscribeTelemetry { interval_ms: 50 }, 0).unwrap();
+        
+        let mut samples = 0;
+        for step in 0..100 {
+            for event in connection.step(step * STEP_MS) {
+                if let ConnectionEvent::Response(ProtocolMessage::Telemetry(_)) | ConnectionEvent::Unsolicited(ProtocolMessage::Telemetry(_)) = event {
+                    samples += 1;
+                }
+            }
+        }
+        // One second at 50 ms, plus the subscription response
+        assert!((20..=21).contains(&samples), "{} samples", samples);
+        
+        connection.request(ProtocolMessage::UnsubscribeTelemetry, 1000).unwrap();
+        let (event, _) = await_response(&mut connection, 1000);
+        assert!(matches!(event, ConnectionEvent::Response(ProtocolMessage::TelemetryUnsubscribed)));
+        assert!(connection.device().session(ConsolePort::Usb).telemetry_stream().is_none());
+    }
+}